@@ -0,0 +1,107 @@
+//! 자주 반복 호출되는 계산 경로의 criterion 벤치마크.
+//! `cargo bench --bench hot_paths` (GUI/CLI 없이: `--no-default-features`)
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use steam_engineering_toolbox::plant_piping::network::{FluidProps, Link, Node, PipeNetwork};
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::steam_piping::{pressure_loss, PressureLossInput};
+use steam_engineering_toolbox::steam::steam_valves::required_kv;
+
+fn bench_if97(c: &mut Criterion) {
+    let mut g = c.benchmark_group("if97");
+    g.bench_function("region_props_uncached/region1", |b| {
+        b.iter(|| if97::region_props_uncached(black_box(30.0), black_box(26.85)))
+    });
+    g.bench_function("region_props_uncached/region2", |b| {
+        b.iter(|| if97::region_props_uncached(black_box(10.0), black_box(250.0)))
+    });
+    g.bench_function("region_props_cached", |b| {
+        b.iter(|| if97::region_props(black_box(10.0), black_box(250.0)))
+    });
+    g.bench_function("saturation_temp/exact", |b| {
+        b.iter(|| if97::saturation_temp_c_from_pressure_bar_abs(black_box(7.3)))
+    });
+    g.bench_function("saturation_temp/spline", |b| {
+        b.iter(|| if97::saturation_temp_c_fast(black_box(7.3)))
+    });
+    g.bench_function("saturation_enthalpies", |b| {
+        b.iter(|| if97::saturation_enthalpies(black_box(7.3)))
+    });
+    g.finish();
+}
+
+fn steam_line() -> PressureLossInput {
+    PressureLossInput {
+        mass_flow_kg_per_h: 5_000.0,
+        steam_density_kg_per_m3: 5.2,
+        diameter_m: 0.1,
+        length_m: 120.0,
+        fittings_k_sum: 4.5,
+        equivalent_length_m: 0.0,
+        roughness_m: 4.5e-5,
+        dynamic_viscosity_pa_s: 1.5e-5,
+        sound_speed_m_per_s: 480.0,
+        state_pressure_bar_abs: None,
+        state_temperature_c: None,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    }
+}
+
+fn bench_piping_and_valves(c: &mut Criterion) {
+    let fixed = steam_line();
+    let with_state = PressureLossInput {
+        state_pressure_bar_abs: Some(10.0),
+        state_temperature_c: Some(220.0),
+        ..steam_line()
+    };
+    c.bench_function("pressure_loss/fixed_props", |b| {
+        b.iter(|| pressure_loss(black_box(fixed.clone())))
+    });
+    c.bench_function("pressure_loss/if97_state", |b| {
+        b.iter(|| pressure_loss(black_box(with_state.clone())))
+    });
+    c.bench_function("required_kv", |b| {
+        b.iter(|| required_kv(black_box(12.0), black_box(1.5), black_box(5.2)))
+    });
+}
+
+fn ring_main(nodes: usize) -> PipeNetwork {
+    let mut net = PipeNetwork::new(FluidProps {
+        density_kg_per_m3: 998.0,
+        dynamic_viscosity_pa_s: 1.0e-3,
+    });
+    let src = net.add_node(Node::fixed("src", 0.0, 6.0));
+    let ids: Vec<usize> = (0..nodes)
+        .map(|i| net.add_node(Node::junction(format!("J{i}"), 0.0, 5.0)))
+        .collect();
+    let pipe = |name: String, from, to| Link::Pipe {
+        name,
+        from,
+        to,
+        length_m: 80.0,
+        diameter_m: 0.15,
+        roughness_m: 4.5e-5,
+        fittings_k_sum: 1.0,
+    };
+    net.add_link(pipe("feed".into(), src, ids[0]));
+    for i in 0..nodes {
+        net.add_link(pipe(format!("P{i}"), ids[i], ids[(i + 1) % nodes]));
+    }
+    net
+}
+
+fn bench_pipe_network(c: &mut Criterion) {
+    let net = ring_main(40);
+    c.bench_function("pipe_network/ring_40", |b| {
+        b.iter(|| black_box(&net).solve())
+    });
+}
+
+criterion_group!(
+    hot_paths,
+    bench_if97,
+    bench_piping_and_valves,
+    bench_pipe_network
+);
+criterion_main!(hot_paths);
@@ -1,3 +1,5 @@
+use crate::flow_coefficient::FlowCoefficientKind;
+
 /// 공기 배관 압력손실 입력(약압축성 근사).
 #[derive(Debug, Clone)]
 pub struct AirPressureLossInput {
@@ -56,6 +58,6 @@ pub fn air_pressure_loss(input: AirPressureLossInput) -> AirPressureLossResult {
 /// 오리피스 유량 근사(비압축성)로 공기 유량을 계산한다. 간단 참고용.
 pub fn air_orifice_flow_cv(cv: f64, delta_p_bar: f64, density_kg_per_m3: f64) -> f64 {
     let rho_ref = 1000.0;
-    let kv = cv * 0.865;
+    let kv = FlowCoefficientKind::CvUs.to_kv(cv);
     kv * (delta_p_bar * density_kg_per_m3 / rho_ref).sqrt()
 }
@@ -0,0 +1,171 @@
+//! 직접 증발식 공기 냉각기(evaporative cooler) 성능 계산.
+//! 포화 효율 ε로 출구 건구온도 T_out = T_db − ε·(T_db − T_wb)를 구하고,
+//! 습구온도 일정(단열 포화) 과정으로 출구 습도비/상대습도와 증발·블로다운·보충수량을 계산한다.
+//! 습공기 물성은 `humid_air`의 습공기 선도 함수를 쓰므로 입출구 엔탈피가 거의 같은지로 검산할 수 있다.
+
+use crate::air::humid_air;
+use crate::goal_seek::GoalSeekError;
+
+/// 이 값을 넘는 포화 효율은 직접 증발식 매체로 달성하기 어렵다.
+const MAX_PRACTICAL_EFFECTIVENESS: f64 = 0.95;
+/// 출구 상대습도 경고 기준 [%]
+const HIGH_OUTLET_RH_PCT: f64 = 90.0;
+
+/// 입구 공기 습도 지정 방식.
+#[derive(Debug, Clone, Copy)]
+pub enum InletHumidity {
+    /// 습구온도 [°C]
+    WetBulb(f64),
+    /// 상대습도 [%]
+    RelativeHumidity(f64),
+}
+
+/// 증발식 냉각기 입력.
+#[derive(Debug, Clone)]
+pub struct EvaporativeCoolerInput {
+    /// 입구 건구온도 [°C]
+    pub dry_bulb_c: f64,
+    pub inlet_humidity: InletHumidity,
+    /// 포화 효율 ε (0~1). 셀룰로오스 패드 100 mm 약 0.8~0.9.
+    pub effectiveness: f64,
+    /// 입구 공기 풍량 [m³/h]
+    pub air_flow_m3_per_h: f64,
+    /// 대기압 [kPa]
+    pub total_pressure_kpa: f64,
+    /// 농축 배수(cycles of concentration). 블로다운 = 증발량 / (배수 − 1).
+    pub cycles_of_concentration: f64,
+}
+
+/// 증발식 냉각기 결과.
+#[derive(Debug, Clone)]
+pub struct EvaporativeCoolerResult {
+    pub inlet_wet_bulb_c: f64,
+    pub inlet_rh_pct: f64,
+    pub inlet_humidity_ratio: f64,
+    pub outlet_dry_bulb_c: f64,
+    pub outlet_rh_pct: f64,
+    pub outlet_humidity_ratio: f64,
+    /// 입구/출구 습공기 엔탈피 [kJ/kg건공기]. 단열 과정이므로 거의 같아야 한다.
+    pub inlet_enthalpy_kj_per_kg: f64,
+    pub outlet_enthalpy_kj_per_kg: f64,
+    /// 건공기 유량 [kg/h]
+    pub dry_air_kg_per_h: f64,
+    /// 증발량 [kg/h]
+    pub evaporation_kg_per_h: f64,
+    /// 블로다운 [kg/h]
+    pub bleed_kg_per_h: f64,
+    /// 보충수 = 증발 + 블로다운 [kg/h]
+    pub makeup_kg_per_h: f64,
+    /// 공기 현열 냉각량 [kW]
+    pub sensible_cooling_kw: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 증발식 냉각기 계산 오류.
+#[derive(Debug, Clone)]
+pub enum EvaporativeCoolerError {
+    InvalidInput(&'static str),
+    Psychrometric(GoalSeekError),
+}
+
+impl std::fmt::Display for EvaporativeCoolerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvaporativeCoolerError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            EvaporativeCoolerError::Psychrometric(e) => write!(f, "습공기 계산 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for EvaporativeCoolerError {}
+
+/// 직접 증발식 냉각기의 출구 상태와 물 소비량을 계산한다.
+pub fn compute_evaporative_cooler(
+    input: EvaporativeCoolerInput,
+) -> Result<EvaporativeCoolerResult, EvaporativeCoolerError> {
+    if !(0.0..=1.0).contains(&input.effectiveness) {
+        return Err(EvaporativeCoolerError::InvalidInput(
+            "포화 효율은 0~1 사이여야 합니다.",
+        ));
+    }
+    if input.air_flow_m3_per_h <= 0.0 || input.total_pressure_kpa <= 0.0 {
+        return Err(EvaporativeCoolerError::InvalidInput(
+            "풍량과 대기압은 0보다 커야 합니다.",
+        ));
+    }
+    if input.cycles_of_concentration <= 1.0 {
+        return Err(EvaporativeCoolerError::InvalidInput(
+            "농축 배수는 1보다 커야 합니다.",
+        ));
+    }
+    let p = input.total_pressure_kpa;
+    let t_db = input.dry_bulb_c;
+    let (t_wb, w_in) = match input.inlet_humidity {
+        InletHumidity::WetBulb(t_wb) => {
+            if t_wb > t_db {
+                return Err(EvaporativeCoolerError::InvalidInput(
+                    "습구온도는 건구온도 이하여야 합니다.",
+                ));
+            }
+            (t_wb, humid_air::humidity_ratio_from_wet_bulb(t_db, t_wb, p))
+        }
+        InletHumidity::RelativeHumidity(rh) => {
+            if !(0.0..=100.0).contains(&rh) {
+                return Err(EvaporativeCoolerError::InvalidInput(
+                    "상대습도는 0~100 % 사이여야 합니다.",
+                ));
+            }
+            let w = humid_air::humidity_ratio_from_rh(t_db, rh, p).humidity_ratio;
+            let t_wb = humid_air::wet_bulb_from_humidity_ratio(t_db, w, p)
+                .map_err(EvaporativeCoolerError::Psychrometric)?;
+            (t_wb, w)
+        }
+    };
+    if w_in < 0.0 {
+        return Err(EvaporativeCoolerError::InvalidInput(
+            "입구 습구온도가 너무 낮아 습도비가 음수입니다.",
+        ));
+    }
+
+    let t_out = t_db - input.effectiveness * (t_db - t_wb);
+    let w_out = humid_air::humidity_ratio_from_wet_bulb(t_out, t_wb, p);
+    let v_in = humid_air::moist_air_specific_volume_m3_per_kg(t_db, w_in, p);
+    let m_da = input.air_flow_m3_per_h / v_in;
+    let evaporation = m_da * (w_out - w_in);
+    let bleed = evaporation / (input.cycles_of_concentration - 1.0);
+    let cp_moist = 1.006 + 1.86 * w_in;
+    let rh_out = humid_air::relative_humidity_from_humidity_ratio(t_out, w_out, p);
+
+    let mut warnings = Vec::new();
+    if input.effectiveness > MAX_PRACTICAL_EFFECTIVENESS {
+        warnings.push(format!(
+            "포화 효율 {:.0}%는 직접 증발식 매체로 달성하기 어렵습니다.",
+            input.effectiveness * 100.0
+        ));
+    }
+    if rh_out > HIGH_OUTLET_RH_PCT {
+        warnings.push(format!(
+            "출구 상대습도 {rh_out:.0}%가 높아 급기 덕트 결로와 미생물 번식에 주의하세요."
+        ));
+    }
+    if t_db - t_wb < 3.0 {
+        warnings.push("건구-습구 온도차가 작아 증발 냉각 효과가 거의 없습니다.".into());
+    }
+
+    Ok(EvaporativeCoolerResult {
+        inlet_wet_bulb_c: t_wb,
+        inlet_rh_pct: humid_air::relative_humidity_from_humidity_ratio(t_db, w_in, p),
+        inlet_humidity_ratio: w_in,
+        outlet_dry_bulb_c: t_out,
+        outlet_rh_pct: rh_out,
+        outlet_humidity_ratio: w_out,
+        inlet_enthalpy_kj_per_kg: humid_air::moist_air_enthalpy_kj_per_kg(t_db, w_in),
+        outlet_enthalpy_kj_per_kg: humid_air::moist_air_enthalpy_kj_per_kg(t_out, w_out),
+        dry_air_kg_per_h: m_da,
+        evaporation_kg_per_h: evaporation,
+        bleed_kg_per_h: bleed,
+        makeup_kg_per_h: evaporation + bleed,
+        sensible_cooling_kw: m_da * cp_moist * (t_db - t_out) / 3600.0,
+        warnings,
+    })
+}
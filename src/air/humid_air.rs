@@ -1,3 +1,5 @@
+use crate::goal_seek::{goal_seek, GoalSeekError};
+
 /// 습도비 계산 결과.
 #[derive(Debug, Clone)]
 pub struct HumidAirState {
@@ -28,6 +30,81 @@ pub fn humidity_ratio_from_rh(
     }
 }
 
+/// 습구온도에서의 단열 포화 관계(ASHRAE Fundamentals)로 습도비를 구한다.
+/// W = ((2501 − 2.326·Twb)·Ws(Twb) − 1.006·(Tdb − Twb)) / (2501 + 1.86·Tdb − 4.186·Twb)
+pub fn humidity_ratio_from_wet_bulb(
+    dry_bulb_c: f64,
+    wet_bulb_c: f64,
+    total_pressure_kpa: f64,
+) -> f64 {
+    let ws = saturation_humidity_ratio(wet_bulb_c, total_pressure_kpa);
+    ((2501.0 - 2.326 * wet_bulb_c) * ws - 1.006 * (dry_bulb_c - wet_bulb_c))
+        / (2501.0 + 1.86 * dry_bulb_c - 4.186 * wet_bulb_c)
+}
+
+/// 건구온도와 습도비로 습구온도를 역산한다.
+pub fn wet_bulb_from_humidity_ratio(
+    dry_bulb_c: f64,
+    humidity_ratio: f64,
+    total_pressure_kpa: f64,
+) -> Result<f64, GoalSeekError> {
+    if humidity_ratio < 0.0
+        || humidity_ratio > saturation_humidity_ratio(dry_bulb_c, total_pressure_kpa) + 1e-9
+    {
+        return Err(GoalSeekError::InvalidInput(
+            "습도비는 0 이상, 건구온도의 포화 습도비 이하여야 합니다.",
+        ));
+    }
+    goal_seek(
+        |wb| humidity_ratio_from_wet_bulb(dry_bulb_c, wb, total_pressure_kpa),
+        humidity_ratio,
+        dry_bulb_c - 60.0,
+        dry_bulb_c,
+    )
+    .map(|r| r.x)
+}
+
+/// 건구온도와 상대습도로 습구온도를 구한다.
+pub fn wet_bulb_from_rh(
+    dry_bulb_c: f64,
+    relative_humidity_pct: f64,
+    total_pressure_kpa: f64,
+) -> Result<f64, GoalSeekError> {
+    let w = humidity_ratio_from_rh(dry_bulb_c, relative_humidity_pct, total_pressure_kpa)
+        .humidity_ratio;
+    wet_bulb_from_humidity_ratio(dry_bulb_c, w, total_pressure_kpa)
+}
+
+/// 건구온도와 습도비로 상대습도 [%]를 구한다.
+pub fn relative_humidity_from_humidity_ratio(
+    dry_bulb_c: f64,
+    humidity_ratio: f64,
+    total_pressure_kpa: f64,
+) -> f64 {
+    let pv = humidity_ratio * total_pressure_kpa / (0.622 + humidity_ratio);
+    100.0 * pv / saturation_pressure_tetens_kpa(dry_bulb_c)
+}
+
+/// 습공기 비엔탈피 [kJ/kg건공기].
+pub fn moist_air_enthalpy_kj_per_kg(dry_bulb_c: f64, humidity_ratio: f64) -> f64 {
+    1.006 * dry_bulb_c + humidity_ratio * (2501.0 + 1.86 * dry_bulb_c)
+}
+
+/// 습공기 비체적 [m³/kg건공기].
+pub fn moist_air_specific_volume_m3_per_kg(
+    dry_bulb_c: f64,
+    humidity_ratio: f64,
+    total_pressure_kpa: f64,
+) -> f64 {
+    0.287_042 * (dry_bulb_c + 273.15) * (1.0 + 1.607_858 * humidity_ratio) / total_pressure_kpa
+}
+
+/// 포화 습도비 Ws(T) [kg/kg].
+pub fn saturation_humidity_ratio(t_c: f64, total_pressure_kpa: f64) -> f64 {
+    let p_sat = saturation_pressure_tetens_kpa(t_c);
+    0.622 * p_sat / (total_pressure_kpa.max(p_sat + 1e-6) - p_sat)
+}
+
 fn saturation_pressure_tetens_kpa(t_c: f64) -> f64 {
     // Tetens: Psat(kPa) = 0.61078 * exp(17.27*T / (T+237.3))
     0.61078 * (17.27 * t_c / (t_c + 237.3)).exp()
@@ -1,6 +1,7 @@
-//! 공기 배관 및 습공기 계산 모듈 모음. 현재는 stub 상태다.
+//! 공기 배관, 습공기 선도 및 증발식 냉각기 계산 모듈 모음.
 
 pub mod air_piping;
+pub mod evaporative_cooler;
 pub mod humid_air;
 
 pub use air_piping::*;
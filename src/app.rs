@@ -1,9 +1,21 @@
+use crate::cli_commands::{self, OutputFormat};
 use crate::config::Config;
 use crate::conversion;
+use crate::error::ToolboxError;
+use crate::flow_units;
 use crate::i18n::{self, Translator};
-use crate::steam::{steam_piping, steam_tables, steam_valves};
+use crate::plant_piping::{line_fill, pipe_class};
+use crate::quantity_arg::QuantityArgError;
+use crate::report::{CalcReport, ReportCell, ReportColumn, ReportTable, ReportValue};
+use crate::steam::steam_leak::{self, LeakEconomics, LeakFormula, LeakKind, LeakSurveyResult};
+use crate::steam::{
+    meter_compensation, relief_scenario, steam_dryness, steam_piping, steam_tables, steam_valves,
+    table_booklet, totalizer,
+};
+use crate::strapping_table;
 use crate::ui_cli;
 use crate::ui_cli::MenuChoice;
+use crate::warning::Severity;
 
 /// 애플리케이션 실행 중 발생 가능한 오류를 표현한다.
 #[derive(Debug)]
@@ -14,12 +26,33 @@ pub enum AppError {
     Config(crate::config::ConfigError),
     /// 단위 변환 오류
     Conversion(conversion::ConversionError),
+    /// 유량/동력 단위 변환 오류
+    FlowUnit(flow_units::FlowUnitError),
     /// 증기표 계산 오류
     SteamTable(steam_tables::SteamTableError),
     /// 배관 계산 오류
     Pipe(steam_piping::PipeCalcError),
     /// 밸브/오리피스 계산 오류
     Valve(steam_valves::ValveCalcError),
+    /// 증기 누설/트랩 조사 계산 오류
+    Leak(steam_leak::LeakError),
+    /// 건도 열량계 계산 오류
+    Calorimeter(steam_dryness::CalorimeterError),
+    /// 유량계 밀도 보정 오류
+    MeterCompensation(meter_compensation::MeterCompensationError),
+    /// 시계열 적산 오류
+    Totalizer(totalizer::TotalizerError),
+    TableBooklet(table_booklet::BookletError),
+    /// 충수량 계산 오류
+    LineFill(line_fill::LineFillError),
+    /// 배관 등급 검토 오류
+    PipeClass(pipe_class::PipeClassError),
+    /// 검량표 생성 오류
+    StrappingTable(strapping_table::StrappingError),
+    /// 분출 시나리오 평가 오류
+    ReliefScenario(relief_scenario::ReliefScenarioError),
+    /// 명령줄 값/단위 인자 오류
+    QuantityArg(QuantityArgError),
     /// 아직 구현되지 않은 기능 호출
     Unimplemented(&'static str),
 }
@@ -30,9 +63,20 @@ impl std::fmt::Display for AppError {
             AppError::Io(e) => write!(f, "입출력 오류: {e}"),
             AppError::Config(e) => write!(f, "설정 오류: {e}"),
             AppError::Conversion(e) => write!(f, "단위 변환 오류: {e}"),
+            AppError::FlowUnit(e) => write!(f, "단위 변환 오류: {e}"),
             AppError::SteamTable(e) => write!(f, "증기표 계산 오류: {e}"),
             AppError::Pipe(e) => write!(f, "배관 계산 오류: {e}"),
             AppError::Valve(e) => write!(f, "밸브 계산 오류: {e}"),
+            AppError::Leak(e) => write!(f, "누설 분석 오류: {e}"),
+            AppError::Calorimeter(e) => write!(f, "건도 열량계 오류: {e}"),
+            AppError::MeterCompensation(e) => write!(f, "유량계 보정 오류: {e}"),
+            AppError::Totalizer(e) => write!(f, "시계열 적산 오류: {e}"),
+            AppError::TableBooklet(e) => write!(f, "증기표 책자 생성 오류: {e}"),
+            AppError::LineFill(e) => write!(f, "충수량 계산 오류: {e}"),
+            AppError::PipeClass(e) => write!(f, "배관 등급 검토 오류: {e}"),
+            AppError::StrappingTable(e) => write!(f, "검량표 생성 오류: {e}"),
+            AppError::ReliefScenario(e) => write!(f, "분출 시나리오 평가 오류: {e}"),
+            AppError::QuantityArg(e) => write!(f, "인자 오류: {e}"),
             AppError::Unimplemented(msg) => write!(f, "아직 구현되지 않음: {msg}"),
         }
     }
@@ -58,6 +102,12 @@ impl From<conversion::ConversionError> for AppError {
     }
 }
 
+impl From<flow_units::FlowUnitError> for AppError {
+    fn from(value: flow_units::FlowUnitError) -> Self {
+        AppError::FlowUnit(value)
+    }
+}
+
 impl From<steam_tables::SteamTableError> for AppError {
     fn from(value: steam_tables::SteamTableError) -> Self {
         AppError::SteamTable(value)
@@ -76,14 +126,100 @@ impl From<steam_valves::ValveCalcError> for AppError {
     }
 }
 
+impl From<steam_leak::LeakError> for AppError {
+    fn from(value: steam_leak::LeakError) -> Self {
+        AppError::Leak(value)
+    }
+}
+
+impl From<steam_dryness::CalorimeterError> for AppError {
+    fn from(value: steam_dryness::CalorimeterError) -> Self {
+        AppError::Calorimeter(value)
+    }
+}
+
+impl From<meter_compensation::MeterCompensationError> for AppError {
+    fn from(value: meter_compensation::MeterCompensationError) -> Self {
+        AppError::MeterCompensation(value)
+    }
+}
+
+impl From<totalizer::TotalizerError> for AppError {
+    fn from(value: totalizer::TotalizerError) -> Self {
+        AppError::Totalizer(value)
+    }
+}
+
+impl From<table_booklet::BookletError> for AppError {
+    fn from(value: table_booklet::BookletError) -> Self {
+        AppError::TableBooklet(value)
+    }
+}
+
+impl From<line_fill::LineFillError> for AppError {
+    fn from(value: line_fill::LineFillError) -> Self {
+        AppError::LineFill(value)
+    }
+}
+
+impl From<pipe_class::PipeClassError> for AppError {
+    fn from(value: pipe_class::PipeClassError) -> Self {
+        AppError::PipeClass(value)
+    }
+}
+
+impl From<strapping_table::StrappingError> for AppError {
+    fn from(value: strapping_table::StrappingError) -> Self {
+        AppError::StrappingTable(value)
+    }
+}
+
+impl From<relief_scenario::ReliefScenarioError> for AppError {
+    fn from(value: relief_scenario::ReliefScenarioError) -> Self {
+        AppError::ReliefScenario(value)
+    }
+}
+
+impl From<QuantityArgError> for AppError {
+    fn from(value: QuantityArgError) -> Self {
+        AppError::QuantityArg(value)
+    }
+}
+
+impl From<AppError> for ToolboxError {
+    fn from(value: AppError) -> Self {
+        match value {
+            AppError::Io(e) => ToolboxError::Io(e),
+            AppError::Config(e) => ToolboxError::Config(e),
+            AppError::Conversion(e) => ToolboxError::Conversion(e),
+            AppError::FlowUnit(e) => ToolboxError::FlowUnit(e),
+            AppError::SteamTable(e) => ToolboxError::SteamTable(e),
+            AppError::Pipe(e) => ToolboxError::SteamPiping(e),
+            AppError::Valve(e) => ToolboxError::Valve(e),
+            AppError::Leak(e) => ToolboxError::Leak(e),
+            AppError::Calorimeter(e) => ToolboxError::Calorimeter(e),
+            AppError::MeterCompensation(e) => ToolboxError::MeterCompensation(e),
+            AppError::Totalizer(e) => ToolboxError::Totalizer(e),
+            AppError::TableBooklet(e) => ToolboxError::TableBooklet(e),
+            AppError::LineFill(e) => ToolboxError::LineFill(e),
+            AppError::PipeClass(e) => ToolboxError::PipeClass(e),
+            AppError::StrappingTable(e) => ToolboxError::StrappingTable(e),
+            AppError::ReliefScenario(e) => ToolboxError::ReliefScenario(e),
+            AppError::QuantityArg(e) => ToolboxError::QuantityArg(e),
+            AppError::Unimplemented(msg) => ToolboxError::Unimplemented(msg),
+        }
+    }
+}
+
 /// CLI 애플리케이션의 메인 루프를 실행한다.
 pub fn run(config: &mut Config, tr: &Translator) -> Result<(), AppError> {
     loop {
         match ui_cli::main_menu(tr)? {
-            MenuChoice::UnitConversion => ui_cli::handle_unit_conversion(tr, config)?,
-            MenuChoice::SteamTables => ui_cli::handle_steam_tables(tr, config)?,
-            MenuChoice::SteamPiping => ui_cli::handle_steam_piping(tr, config)?,
-            MenuChoice::SteamValves => ui_cli::handle_steam_valves(tr, config)?,
+            MenuChoice::UnitConversion => ui_cli::handle_unit_conversion(tr)?,
+            MenuChoice::SteamTables => ui_cli::handle_steam_tables(tr)?,
+            MenuChoice::SteamPiping => ui_cli::handle_steam_piping(tr)?,
+            MenuChoice::SteamValves => ui_cli::handle_steam_valves(tr)?,
+            MenuChoice::SteamDryness => ui_cli::handle_steam_dryness(tr)?,
             MenuChoice::Settings => {
                 ui_cli::handle_settings(tr, config)?;
                 config.save()?;
@@ -97,3 +233,154 @@ pub fn run(config: &mut Config, tr: &Translator) -> Result<(), AppError> {
     }
     Ok(())
 }
+
+/// 트랩 조사 CSV를 읽어 연간 비용 손실 순으로 정렬한 절감 보고서를 출력한다.
+/// `format`이 JSON/YAML이면 `CalcReport` 문서(항목 목록은 `tables`)로 출력한다.
+pub fn run_trap_survey(
+    csv_path: &str,
+    economics: &LeakEconomics,
+    format: OutputFormat,
+    tr: &Translator,
+) -> Result<(), AppError> {
+    let text = std::fs::read_to_string(csv_path)?;
+    let items = steam_leak::parse_trap_survey_csv(&text)?;
+    let survey = steam_leak::analyze_leaks(&items, economics, LeakFormula::Napier)?;
+    if format != OutputFormat::Text {
+        let report = trap_survey_report(&survey, economics);
+        return cli_commands::print_report(&report, format, tr.number_format());
+    }
+
+    let nf = tr.number_format();
+    println!("{}", tr.t(i18n::keys::TRAP_SURVEY_HEADING));
+    println!(
+        "{} {}",
+        tr.t(i18n::keys::TRAP_SURVEY_STEAM_COST),
+        nf.format(survey.steam_cost_per_t, 0)
+    );
+    println!("{}", tr.t(i18n::keys::TRAP_SURVEY_COLUMNS));
+    for (rank, item) in survey.items.iter().enumerate() {
+        let kind = match item.kind {
+            LeakKind::Leak => tr.t(i18n::keys::TRAP_SURVEY_KIND_LEAK),
+            LeakKind::FailedTrap => tr.t(i18n::keys::TRAP_SURVEY_KIND_TRAP),
+        };
+        println!(
+            "{:>3} | {} | {} | {} | {} | {} | {} | {} | {}",
+            rank + 1,
+            item.tag,
+            kind,
+            nf.format(item.pressure_bar_abs, 2),
+            nf.format(item.orifice_diameter_m * 1000.0, 1),
+            nf.format(item.steam_loss_kg_per_h, 1),
+            nf.format(item.annual_steam_t, 1),
+            nf.format(item.annual_energy_gj, 1),
+            nf.format(item.annual_cost, 0)
+        );
+    }
+    println!(
+        "{} {} kg/h | {} t | {} GJ | {}",
+        tr.t(i18n::keys::TRAP_SURVEY_TOTAL),
+        nf.format(survey.total_steam_kg_per_h, 1),
+        nf.format(survey.total_annual_steam_t, 1),
+        nf.format(survey.total_annual_energy_gj, 1),
+        nf.format(survey.total_annual_cost, 0)
+    );
+    for w in &survey.warnings {
+        println!("{} {w}", tr.t(i18n::keys::TRAP_SURVEY_WARNING));
+    }
+    Ok(())
+}
+
+fn trap_survey_report(survey: &LeakSurveyResult, economics: &LeakEconomics) -> CalcReport {
+    let columns = [
+        ("tag", ""),
+        ("kind", ""),
+        ("pressure", "bar(a)"),
+        ("orifice_diameter", "mm"),
+        ("steam_loss", "kg/h"),
+        ("annual_steam", "t"),
+        ("annual_energy", "GJ"),
+        ("annual_cost", ""),
+    ];
+    let rows = survey
+        .items
+        .iter()
+        .map(|item| {
+            let kind = match item.kind {
+                LeakKind::Leak => "leak",
+                LeakKind::FailedTrap => "failed_trap",
+            };
+            vec![
+                ReportCell::Text(item.tag.clone()),
+                ReportCell::Text(kind.to_string()),
+                ReportCell::Number(item.pressure_bar_abs),
+                ReportCell::Number(item.orifice_diameter_m * 1000.0),
+                ReportCell::Number(item.steam_loss_kg_per_h),
+                ReportCell::Number(item.annual_steam_t),
+                ReportCell::Number(item.annual_energy_gj),
+                ReportCell::Number(item.annual_cost),
+            ]
+        })
+        .collect();
+    let energy = &economics.energy;
+    let mut report = CalcReport::new("trap survey")
+        .input(ReportValue::new(
+            "fuel_price",
+            energy.fuel_price_per_unit,
+            "",
+        ))
+        .input(ReportValue::new(
+            "fuel_lhv",
+            energy.fuel_lhv_kj_per_unit,
+            "kJ",
+        ))
+        .input(ReportValue::new(
+            "boiler_efficiency",
+            energy.boiler_efficiency,
+            "",
+        ))
+        .input(ReportValue::new(
+            "makeup_water_temperature",
+            economics.makeup_water_temp_c,
+            "°C",
+        ))
+        .intermediate(ReportValue::new(
+            "steam_cost_per_t",
+            survey.steam_cost_per_t,
+            "",
+        ))
+        .result(ReportValue::new(
+            "total_steam_loss",
+            survey.total_steam_kg_per_h,
+            "kg/h",
+        ))
+        .result(ReportValue::new(
+            "total_annual_steam",
+            survey.total_annual_steam_t,
+            "t",
+        ))
+        .result(ReportValue::new(
+            "total_annual_energy",
+            survey.total_annual_energy_gj,
+            "GJ",
+        ))
+        .result(ReportValue::new(
+            "total_annual_cost",
+            survey.total_annual_cost,
+            "",
+        ));
+    for w in &survey.warnings {
+        report = report.warning(Severity::Caution, w.clone());
+    }
+    report.tables.push(ReportTable {
+        name: "items".to_string(),
+        columns: columns
+            .iter()
+            .map(|(name, unit)| ReportColumn {
+                name: name.to_string(),
+                unit: unit.to_string(),
+            })
+            .collect(),
+        rows,
+    });
+    report
+}
@@ -0,0 +1,96 @@
+//! 계산 카드 위젯.
+//! 카드마다 입력과 마지막 결과를 스스로 가지고 있어서 `GuiApp`과 떨어져 있고,
+//! 탭 본문·대시보드·분리 창 어디에든 같은 모습으로 그릴 수 있다.
+//! 다른 카드와 입력이나 결과를 주고받는 카드(스트림 보내기, 라인 케이스, 밸브 DB,
+//! 배관 클래스, 공용 배관 데이터, 단위계 프리셋·현장 조건 반영 등)는
+//! 아직 `GuiApp`의 `ui_*` 메서드로 남아 있다.
+
+mod air_cooled_condenser;
+mod air_preheater;
+mod attemperator;
+mod compressor;
+mod cooling_comparison;
+mod drum;
+mod dryness;
+mod ejector;
+mod emissions;
+mod flow_conversion;
+mod gas_throttling;
+mod heat_balance;
+mod hrsg;
+mod letdown_noise;
+mod meter_compensation;
+mod rankine_cycle;
+mod return_line;
+mod saturated_npsh;
+mod sparger;
+mod tank_heating;
+mod totalizer;
+mod unit_conversion;
+mod valve_actuator;
+mod vent_silencer;
+
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs},
+    i18n,
+};
+
+/// `GuiApp` 밖에서 그릴 수 있는 계산 카드.
+/// 그룹 틀과 머리 줄(대시보드 고정·기본값)은 호출하는 쪽이 그린다.
+pub(crate) trait CardWidget {
+    fn id(&self) -> CardId;
+
+    /// 입력 칸, 계산 버튼, 결과를 그린다.
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator);
+
+    /// 현재 입력으로 다시 계산해 결과를 바꾼다.
+    fn compute(&mut self, tr: &i18n::Translator);
+
+    /// 마지막 계산 결과 (결과 내보내기용). 계산 전이면 비어 있다.
+    fn export(&self) -> Vec<&str>;
+
+    /// 입력을 기본값으로 되돌린다.
+    fn reset(&mut self);
+
+    /// 보존 파일 구조체에 입력을 옮겨 적는다.
+    fn save_inputs(&self, inputs: &mut GuiInputs);
+
+    /// 보존 파일 구조체에서 입력을 읽는다.
+    fn load_inputs(&mut self, inputs: &GuiInputs);
+}
+
+/// 위젯으로 옮긴 카드 전부 (탭 순서).
+pub(crate) fn all() -> Vec<Box<dyn CardWidget>> {
+    vec![
+        Box::<unit_conversion::UnitConversionCard>::default(),
+        Box::<flow_conversion::FlowConversionCard>::default(),
+        Box::<dryness::DrynessCard>::default(),
+        Box::<valve_actuator::ValveActuatorCard>::default(),
+        Box::<drum::DrumCard>::default(),
+        Box::<rankine_cycle::RankineCycleCard>::default(),
+        Box::<heat_balance::HeatBalanceCard>::default(),
+        Box::<hrsg::HrsgCard>::default(),
+        Box::<attemperator::AttemperatorCard>::default(),
+        Box::<air_preheater::AirPreheaterCard>::default(),
+        Box::<emissions::EmissionsCard>::default(),
+        Box::<air_cooled_condenser::AirCooledCondenserCard>::default(),
+        Box::<cooling_comparison::CoolingComparisonCard>::default(),
+        Box::<saturated_npsh::SaturatedNpshCard>::default(),
+        Box::<ejector::EjectorCard>::default(),
+        Box::<meter_compensation::MeterCompensationCard>::default(),
+        Box::<totalizer::TotalizerCard>::default(),
+        Box::<gas_throttling::GasThrottlingCard>::default(),
+        Box::<compressor::CompressorCard>::default(),
+        Box::<letdown_noise::LetdownNoiseCard>::default(),
+        Box::<vent_silencer::VentSilencerCard>::default(),
+        Box::<return_line::ReturnLineCard>::default(),
+        Box::<tank_heating::TankHeatingCard>::default(),
+        Box::<sparger::SpargerCard>::default(),
+    ]
+}
+
+/// 번역기로 `txt(key, default)` 조회 함수를 만든다.
+fn lookup(tr: &i18n::Translator) -> impl Fn(&str, &str) -> String + '_ {
+    move |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string())
+}
@@ -0,0 +1,190 @@
+//! 공랭식 복수기/핀팬 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{AirCooledCondenserInputs, CardId, GuiInputs},
+    cooling::air_cooled,
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct AirCooledCondenserCard {
+    inputs: AirCooledCondenserInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for AirCooledCondenserCard {
+    fn id(&self) -> CardId {
+        CardId::AirCooledCondenser
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.cooling.acc.heading", "Air-cooled condenser / fin-fan"),
+            &txt(
+                "gui.cooling.acc.tip",
+                "Bare-tube area, fan air flow and ITD at the design point, and back pressure on a hot day with fixed fan volume",
+            ),
+        );
+        egui::Grid::new("acc_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.steam", "Exhaust steam [t/h] / quality"),
+                    &txt("gui.cooling.acc.steam_tip", "Turbine exhaust flow and dryness fraction"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.steam_t_h).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.quality).speed(0.01));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.bp", "Design back pressure [bar(a)]"),
+                    &txt("gui.cooling.acc.bp_tip", "Condensing pressure at the design ambient"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.back_pressure_bar_abs).speed(0.005));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.ambient", "Design ambient [°C] / air ΔT [K]"),
+                    &txt("gui.cooling.acc.ambient_tip", "Design dry bulb and air-side temperature rise (typically 15-25 K)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.ambient_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.air_rise_k).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.h", "h condensing / h air [W/m²·K]"),
+                    &txt("gui.cooling.acc.h_tip", "In-tube condensing film and air-side coefficient on the finned surface"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.h_cond).speed(100.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.h_air).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.fin", "Fin area ratio / fin efficiency"),
+                    &txt("gui.cooling.acc.fin_tip", "Finned to bare outside area and assumed fin efficiency"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.fin_ratio).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.fin_eff).speed(0.01));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.area", "Installed bare area [m²] (0 = size)"),
+                    &txt("gui.cooling.acc.area_tip", "Rate an existing unit; 0 uses the required design area"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.area_m2).speed(10.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.acc.hot", "Hot day [°C] / max back pressure [bar(a)]"),
+                    &txt("gui.cooling.acc.hot_tip", "High-ambient check with the same fan volume flow (lower air density)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.hot_ambient_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.max_bp_bar_abs).speed(0.005));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.cooling.acc.run", "Estimate air-cooled condenser")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = air_cooled::AirCooledCondenserInput {
+            steam_flow_kg_per_h: self.inputs.steam_t_h * 1000.0,
+            exhaust_quality: self.inputs.quality,
+            design_back_pressure_bar_abs: self.inputs.back_pressure_bar_abs,
+            design_ambient_c: self.inputs.ambient_c,
+            air_temp_rise_k: self.inputs.air_rise_k,
+            condensing_h_w_m2k: self.inputs.h_cond,
+            air_side_h_w_m2k: self.inputs.h_air,
+            fin_area_ratio: self.inputs.fin_ratio,
+            fin_efficiency: self.inputs.fin_eff,
+            bare_tube_area_m2: (self.inputs.area_m2 > 0.0).then_some(self.inputs.area_m2),
+            hot_day_ambient_c: Some(self.inputs.hot_ambient_c),
+            max_back_pressure_bar_abs: (self.inputs.max_bp_bar_abs > 0.0)
+                .then_some(self.inputs.max_bp_bar_abs),
+        };
+        self.result = Some(match air_cooled::compute_air_cooled_condenser(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.cooling.acc.result",
+                        "Duty {q} MW, ITD {itd} K, LMTD {lmtd} K; air {m} kg/s ({v} m³/s); U(bare) {u} W/m²·K → required bare area {areq} m²; at {area} m²: {tc} °C / {pb} bar(a), approach to dry bulb {app} K",
+                    ),
+                    &[
+                        ("q", fmt_num(res.heat_duty_kw / 1000.0, 2)),
+                        ("itd", fmt_qty(res.design_itd_k, QuantityKind::TemperatureDifference)),
+                        ("lmtd", fmt_qty(res.lmtd_k, QuantityKind::TemperatureDifference)),
+                        ("m", fmt_num(res.air_mass_flow_kg_per_s, 0)),
+                        ("v", fmt_num(res.fan_air_flow_m3_per_s, 0)),
+                        ("u", fmt_num(res.u_bare_w_m2k, 1)),
+                        ("areq", fmt_qty(res.required_bare_area_m2, QuantityKind::Area)),
+                        ("area", fmt_qty(res.bare_area_m2, QuantityKind::Area)),
+                        ("tc", fmt_qty(res.condensing_temp_c, QuantityKind::Temperature)),
+                        ("pb", fmt_qty(res.back_pressure_bar_abs, QuantityKind::Pressure)),
+                        ("app", fmt_qty(res.approach_to_dry_bulb_k, QuantityKind::TemperatureDifference)),
+                    ],
+                );
+                if let Some(hot) = &res.hot_day {
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.cooling.acc.hot_result",
+                            "\nHot day {t} °C: air {m} kg/s → {tc} °C / {pb} bar(a)",
+                        ),
+                        &[
+                            ("t", fmt_qty(hot.ambient_c, QuantityKind::Temperature)),
+                            ("m", fmt_num(hot.air_mass_flow_kg_per_s, 0)),
+                            ("tc", fmt_qty(hot.condensing_temp_c, QuantityKind::Temperature)),
+                            ("pb", fmt_qty(hot.back_pressure_bar_abs, QuantityKind::Pressure)),
+                        ],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.cooling.acc.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = AirCooledCondenserInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.cooling.air_cooled = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.cooling.air_cooled.clone();
+    }
+}
@@ -0,0 +1,194 @@
+//! 연소 공기 예열기 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{AirPreheaterInputs, CardId, GuiInputs},
+    combustion::{air_preheater, fuel::FuelType},
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct AirPreheaterCard {
+    inputs: AirPreheaterInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for AirPreheaterCard {
+    fn id(&self) -> CardId {
+        CardId::AirPreheater
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.aph.heading", "Combustion air preheater"),
+            &txt(
+                "gui.boiler.aph.tip",
+                "Gas-air heat exchanger: gas outlet temperature with air leakage, X-ratio, efficiency gain per 20 °C stack temperature reduction and the sulfuric acid dew point check at the cold end.",
+            ),
+        );
+        egui::Grid::new("boiler_aph_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.aph.fuel", "Fuel / sulfur [%]"),
+                    &txt(
+                        "gui.boiler.aph.fuel_tip",
+                        "Choosing a fuel loads its typical analysis, sulfur and LHV; sulfur can then be edited",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    let before = self.inputs.fuel;
+                    egui::ComboBox::from_id_source("aph_fuel")
+                        .selected_text(txt(self.inputs.fuel.key(), self.inputs.fuel.name()))
+                        .show_ui(ui, |ui| {
+                            for f in FuelType::ALL {
+                                ui.selectable_value(&mut self.inputs.fuel, f, txt(f.key(), f.name()));
+                            }
+                        });
+                    if before != self.inputs.fuel {
+                        self.inputs.sulfur_pct = self.inputs.fuel.analysis().sulfur * 100.0;
+                        self.inputs.lhv_kj_kg = self.inputs.fuel.lhv_kj_per_kg();
+                    }
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.sulfur_pct)
+                            .speed(0.05)
+                            .clamp_range(0.0..=6.0),
+                    );
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.aph.firing", "Fuel flow [kg/h] / LHV [kJ/kg]"),
+                    &txt("gui.boiler.aph.firing_tip", "Fuel heat input basis for the efficiency gain"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.fuel_kg_h).speed(10.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.lhv_kj_kg).speed(100.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.aph.air", "Excess air [%] / humidity [kg/kg]"),
+                    &txt(
+                        "gui.boiler.aph.air_tip",
+                        "Excess air at the preheater gas inlet and combustion air humidity ratio",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.excess_air_pct).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.humidity).speed(0.001));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.aph.temps", "Gas in / air in / air out [°C]"),
+                    &txt("gui.boiler.aph.temps_tip", "Gas inlet, ambient air inlet and hot air outlet temperatures"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.gas_in_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.air_in_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.air_out_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.aph.leak", "Air leakage [% of gas]"),
+                    &txt(
+                        "gui.boiler.aph.leak_tip",
+                        "Air leaking to the gas side, % of inlet gas mass (regenerative 5–10%, tubular 0–2%)",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.leak_pct).speed(0.1));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.aph.run", "Calculate air preheater")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = air_preheater::AirPreheaterInput {
+            fuel: self.inputs.fuel.analysis().with_sulfur(self.inputs.sulfur_pct / 100.0),
+            fuel_flow_kg_per_h: self.inputs.fuel_kg_h,
+            fuel_lhv_kj_per_kg: self.inputs.lhv_kj_kg,
+            excess_air_frac: self.inputs.excess_air_pct / 100.0,
+            air_humidity_ratio: self.inputs.humidity,
+            gas_inlet_temp_c: self.inputs.gas_in_c,
+            air_inlet_temp_c: self.inputs.air_in_c,
+            air_outlet_temp_c: self.inputs.air_out_c,
+            air_leakage_frac: self.inputs.leak_pct / 100.0,
+        };
+        self.result = Some(match air_preheater::compute_air_preheater(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.aph.result",
+                        "Heat recovered {q} kW (air +{dta} K), flue gas {mg} kg/h, air {ma} kg/h\nGas outlet {tg} °C (no leakage {tgnl} °C), X-ratio {x}, gas-side efficiency {eff}%\nEfficiency gain {gain}% (stack loss {lb}% → {la}%), {step}% per 20 °C stack reduction",
+                    ),
+                    &[
+                        ("q", fmt_num(res.heat_recovered_kw, 0)),
+                        ("dta", fmt_qty(res.air_temp_rise_k, QuantityKind::TemperatureDifference)),
+                        ("mg", fmt_num(res.flue_gas_flow_kg_per_h, 0)),
+                        ("ma", fmt_num(res.combustion_air_flow_kg_per_h, 0)),
+                        ("tg", fmt_qty(res.gas_outlet_temp_c, QuantityKind::Temperature)),
+                        ("tgnl", fmt_qty(res.gas_outlet_temp_no_leakage_c, QuantityKind::Temperature)),
+                        ("x", fmt_num(res.x_ratio, 3)),
+                        ("eff", fmt_num(res.gas_side_efficiency * 100.0, 1)),
+                        ("gain", fmt_num(res.efficiency_gain * 100.0, 2)),
+                        ("lb", fmt_num(res.stack_loss_before_frac * 100.0, 2)),
+                        ("la", fmt_num(res.stack_loss_after_frac * 100.0, 2)),
+                        ("step", fmt_num(res.efficiency_gain_per_step * 100.0, 2)),
+                    ],
+                );
+                if let Some(adp) = res.acid_dew_point_c {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.boiler.aph.acid_dew_point",
+                            "Acid dew point {adp} °C, cold-end average {acet} °C",
+                        ),
+                        &[
+                            ("adp", fmt_num(adp, 0)),
+                            ("acet", fmt_qty(res.cold_end_average_temp_c, QuantityKind::Temperature)),
+                        ],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.aph.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = AirPreheaterInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.air_preheater = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.air_preheater.clone();
+    }
+}
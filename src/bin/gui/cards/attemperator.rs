@@ -0,0 +1,187 @@
+//! 과열 저감기 스프레이·보일러 출력 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{AttemperatorInputs, CardId, GuiInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct AttemperatorCard {
+    inputs: AttemperatorInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for AttemperatorCard {
+    fn id(&self) -> CardId {
+        CardId::Attemperator
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.attemp.heading", "Attemperator spray / boiler output"),
+            &txt(
+                "gui.boiler.attemp.tip",
+                "Spray water (economizer outlet feedwater) needed to hold the attemperator outlet setpoint, the temperature control range, and the boiler output/efficiency error when spray is left out of the steam flow (PTC 4 output basis).",
+            ),
+        );
+        egui::Grid::new("boiler_attemp_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.main_flow", "Main steam flow [kg/h]"),
+                    &txt("gui.boiler.attemp.main_flow_tip", "Final superheater outlet flow, spray included"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.main_flow_kg_h).speed(100.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.station", "Attemperator P [bar(a)] / inlet T / setpoint [°C]"),
+                    &txt(
+                        "gui.boiler.attemp.station_tip",
+                        "Steam pressure at the attemperator, primary superheater outlet temperature and the desired outlet temperature",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p_bar_abs).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.inlet_t_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.setpoint_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.spray", "Spray water T [°C] / valve max [kg/h]"),
+                    &txt(
+                        "gui.boiler.attemp.spray_tip",
+                        "Economizer outlet water temperature and spray valve capacity (0 = no limit)",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.spray_t_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.max_spray_kg_h).speed(100.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.main_steam", "Main steam P [bar(a)] / T [°C]"),
+                    &txt("gui.boiler.attemp.main_steam_tip", "Final steam state at the boiler outlet boundary"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.ms_p_bar_abs).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.ms_t_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.feedwater", "Feedwater P [bar(a)] / T [°C]"),
+                    &txt(
+                        "gui.boiler.attemp.feedwater_tip",
+                        "Economizer inlet (boundary) feedwater; the spray water enthalpy uses this pressure",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.fw_p_bar_abs).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.fw_t_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.attemp.fuel", "Fuel heat input [kW]"),
+                    &txt("gui.boiler.attemp.fuel_tip", "0 skips the efficiency comparison"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.fuel_kw).speed(100.0));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.attemp.run", "Calculate attemperator")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::attemperator::AttemperatorInput {
+            main_steam_flow_kg_per_h: self.inputs.main_flow_kg_h,
+            attemperator_pressure_bar_abs: self.inputs.p_bar_abs,
+            inlet_steam_temp_c: self.inputs.inlet_t_c,
+            outlet_setpoint_c: self.inputs.setpoint_c,
+            spray_water_temp_c: self.inputs.spray_t_c,
+            max_spray_flow_kg_per_h: (self.inputs.max_spray_kg_h > 0.0).then_some(self.inputs.max_spray_kg_h),
+            main_steam_pressure_bar_abs: self.inputs.ms_p_bar_abs,
+            main_steam_temp_c: self.inputs.ms_t_c,
+            feedwater_pressure_bar_abs: self.inputs.fw_p_bar_abs,
+            feedwater_temp_c: self.inputs.fw_t_c,
+            fuel_heat_input_kw: (self.inputs.fuel_kw > 0.0).then_some(self.inputs.fuel_kw),
+        };
+        self.result = Some(match steam::attemperator::compute_attemperator(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.attemp.result",
+                        "Spray {spray} kg/h ({frac}% of main steam), primary SH steam {up} kg/h\nOutlet superheat {sh} K (Tsat {tsat} °C); control range {tmin}–{tmax} °C, max spray at minimum superheat {safe} kg/h\nBoiler output {q} kW; with spray left out {qx} kW (−{under}%)",
+                    ),
+                    &[
+                        ("spray", fmt_num(res.spray_flow_kg_per_h, 0)),
+                        ("frac", fmt_num(res.spray_fraction * 100.0, 2)),
+                        ("up", fmt_num(res.upstream_steam_flow_kg_per_h, 0)),
+                        ("sh", fmt_qty(res.outlet_superheat_k, QuantityKind::TemperatureDifference)),
+                        ("tsat", fmt_qty(res.saturation_temp_c, QuantityKind::Temperature)),
+                        ("tmin", fmt_qty(res.control_min_outlet_temp_c, QuantityKind::Temperature)),
+                        ("tmax", fmt_qty(res.control_max_outlet_temp_c, QuantityKind::Temperature)),
+                        ("safe", fmt_num(res.max_safe_spray_flow_kg_per_h, 0)),
+                        ("q", fmt_num(res.boiler_output_kw, 0)),
+                        ("qx", fmt_num(res.output_excluding_spray_kw, 0)),
+                        ("under", fmt_num(res.output_understatement_pct, 2)),
+                    ],
+                );
+                if let Some((eta, eta_x)) = res.efficiency {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.boiler.attemp.efficiency",
+                            "Efficiency {eta}% (spray left out: {eta_x}%)",
+                        ),
+                        &[
+                            ("eta", fmt_num(eta * 100.0, 2)),
+                            ("eta_x", fmt_num(eta_x * 100.0, 2)),
+                        ],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.attemp.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = AttemperatorInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.attemperator = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.attemperator.clone();
+    }
+}
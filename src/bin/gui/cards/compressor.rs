@@ -0,0 +1,213 @@
+//! 압축기 동력/토출 온도 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, CompressorInputs, GuiInputs},
+    gas::{compressor, gas_properties},
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct CompressorCard {
+    inputs: CompressorInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for CompressorCard {
+    fn id(&self) -> CardId {
+        CardId::Compressor
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.compressor.heading", "Compressor power / discharge temperature"),
+            &txt(
+                "gui.plant.compressor.tip",
+                "Isentropic or polytropic power, discharge temperature and intercooled stages for air or fuel gas compression (real-gas k and Z per stage)",
+            ),
+        );
+        egui::Grid::new("plant_compressor_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.gas", "Gas"),
+                    &txt("gui.plant.compressor.gas_tip", "Air for compressed-air systems, NG or CH₄ for fuel gas boosters"),
+                );
+                let idx = self.inputs.gas.min(gas_properties::Gas::ALL.len() - 1);
+                egui::ComboBox::from_id_source("plant_comp_gas")
+                    .selected_text(gas_properties::Gas::ALL[idx].formula())
+                    .show_ui(ui, |ui| {
+                        for (i, g) in gas_properties::Gas::ALL.iter().enumerate() {
+                            ui.selectable_value(&mut self.inputs.gas, i, g.formula());
+                        }
+                    });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.suction", "Suction P [bar(a)] / T [°C]"),
+                    &txt("gui.plant.compressor.suction_tip", "Gas state at the first-stage inlet"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p1_bar_abs).speed(0.01));
+                    ui.add(egui::DragValue::new(&mut self.inputs.t1_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.discharge", "Discharge P [bar(a)]"),
+                    &txt("gui.plant.compressor.discharge_tip", "Final discharge pressure"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.p2_bar_abs).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.flow", "Mass flow [kg/h]"),
+                    &txt("gui.plant.compressor.flow_tip", "Compressed gas mass flow"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.flow_kg_h).speed(10.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.efficiency", "Efficiency / polytropic basis"),
+                    &txt(
+                        "gui.plant.compressor.efficiency_tip",
+                        "Unchecked: isentropic efficiency (screw/reciprocating data sheets); checked: polytropic efficiency (centrifugal)",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.efficiency).speed(0.01).clamp_range(0.05..=1.0));
+                    ui.checkbox(&mut self.inputs.polytropic, "");
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.stages", "Stages (0 = auto)"),
+                    &txt(
+                        "gui.plant.compressor.stages_tip",
+                        "0 picks the fewest stages that keep every stage discharge at or below the limit",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.stages).clamp_range(0..=8));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.intercooler", "Intercooler outlet / discharge limit [°C]"),
+                    &txt(
+                        "gui.plant.compressor.intercooler_tip",
+                        "Gas temperature after each intercooler, and the maximum stage discharge temperature",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.intercooler_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.max_discharge_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.compressor.mech_efficiency", "Mechanical/drive efficiency"),
+                    &txt("gui.plant.compressor.mech_efficiency_tip", "Shaft power = gas power / this efficiency"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.mech_efficiency).speed(0.01).clamp_range(0.05..=1.0));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.plant.compressor.run", "Calculate compressor")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let efficiency = self.inputs.efficiency;
+        let input = compressor::CompressorInput {
+            gas: gas_properties::Gas::ALL[self.inputs.gas.min(gas_properties::Gas::ALL.len() - 1)],
+            suction_pressure_bar_abs: self.inputs.p1_bar_abs,
+            suction_temp_c: self.inputs.t1_c,
+            discharge_pressure_bar_abs: self.inputs.p2_bar_abs,
+            mass_flow_kg_per_h: self.inputs.flow_kg_h,
+            model: if self.inputs.polytropic {
+                compressor::CompressionModel::Polytropic { efficiency }
+            } else {
+                compressor::CompressionModel::Isentropic { efficiency }
+            },
+            stages: (self.inputs.stages > 0).then_some(self.inputs.stages),
+            intercooler_outlet_temp_c: self.inputs.intercooler_c,
+            max_discharge_temp_c: self.inputs.max_discharge_c,
+            mechanical_efficiency: self.inputs.mech_efficiency,
+        };
+        self.result = Some(match compressor::compute_compressor(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.plant.compressor.result",
+                        "{n} stage(s): gas power {gas} kW, shaft power {shaft} kW, discharge {t2} °C\nSuction flow {q} m³/min, specific power {sp} kW/(m³/min), intercooler duty {ic} kW",
+                    ),
+                    &[
+                        ("n", res.stages.len().to_string()),
+                        ("gas", fmt_num(res.total_gas_power_kw, 1)),
+                        ("shaft", fmt_num(res.shaft_power_kw, 1)),
+                        ("t2", fmt_qty(res.discharge_temp_c, QuantityKind::Temperature)),
+                        ("q", fmt_num(res.suction_volume_flow_m3_per_min, 2)),
+                        ("sp", fmt_num(res.specific_power_kw_per_m3_per_min, 2)),
+                        ("ic", fmt_num(res.intercooler_duty_kw, 1)),
+                    ],
+                );
+                let stage_tpl = txt(
+                    "gui.plant.compressor.stage",
+                    "Stage {i}: {p1} → {p2} bar(a) (r {r}), {t1} → {t2} °C, k {k}, Z {z}, head {h} kJ/kg, {w} kW",
+                );
+                for (i, s) in res.stages.iter().enumerate() {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &stage_tpl,
+                        &[
+                            ("i", (i + 1).to_string()),
+                            ("p1", fmt_qty(s.suction_pressure_bar_abs, QuantityKind::Pressure)),
+                            ("p2", fmt_qty(s.discharge_pressure_bar_abs, QuantityKind::Pressure)),
+                            ("r", fmt_num(s.pressure_ratio, 2)),
+                            ("t1", fmt_qty(s.suction_temp_c, QuantityKind::Temperature)),
+                            ("t2", fmt_qty(s.discharge_temp_c, QuantityKind::Temperature)),
+                            ("k", fmt_num(s.k, 3)),
+                            ("z", fmt_num(s.z_avg, 3)),
+                            ("h", fmt_qty(s.head_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                            ("w", fmt_num(s.gas_power_kw, 1)),
+                        ],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.plant.compressor.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = CompressorInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.compressor = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.compressor.clone();
+    }
+}
@@ -0,0 +1,169 @@
+//! 관류식 대 순환식 냉각 비교 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, CoolingComparisonInputs, GuiInputs},
+    cooling::cooling_comparison,
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct CoolingComparisonCard {
+    inputs: CoolingComparisonInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for CoolingComparisonCard {
+    fn id(&self) -> CardId {
+        CardId::CoolingComparison
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.cooling.cmp.heading", "Once-through vs recirculating cooling"),
+            &txt(
+                "gui.cooling.cmp.tip",
+                "Side-by-side circulating flow, water withdrawal, tower losses and pumping power for a feasibility study",
+            ),
+        );
+        egui::Grid::new("cmp_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.cmp.duty", "Heat duty [MW]"),
+                    &txt("gui.cooling.cmp.duty_tip", "Heat rejected to cooling water"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.duty_mw).speed(1.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.cmp.source", "Source [°C] / allowed rise [K] / max discharge [°C]"),
+                    &txt("gui.cooling.cmp.source_tip", "Once-through: river/sea temperature, permitted temperature rise and discharge limit (0 = none)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.source_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.ot_rise_k).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.max_discharge_c).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.cmp.tower", "WB [°C] / approach [K] / range [K]"),
+                    &txt("gui.cooling.cmp.tower_tip", "Recirculating: design wet bulb, tower approach and range"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.wb_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.approach_k).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.range_k).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.cmp.water", "Cycles / drift [%]"),
+                    &txt("gui.cooling.cmp.water_tip", "Cycles of concentration and drift loss as % of circulating flow"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.cycles).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.drift_pct).speed(0.005));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.cmp.pumps", "Head once-through / recirculating [m] / η"),
+                    &txt("gui.cooling.cmp.pumps_tip", "Pump total heads (intake/outfall vs tower static lift) and pump efficiency"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.ot_head_m).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.rc_head_m).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.pump_eff).speed(0.01));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.cooling.cmp.run", "Compare cooling options")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, true);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = cooling_comparison::CoolingComparisonInput {
+            heat_duty_kw: self.inputs.duty_mw * 1000.0,
+            source_water_temp_c: self.inputs.source_c,
+            once_through_max_rise_k: self.inputs.ot_rise_k,
+            max_discharge_temp_c: (self.inputs.max_discharge_c > 0.0)
+                .then_some(self.inputs.max_discharge_c),
+            once_through_head_m: self.inputs.ot_head_m,
+            wet_bulb_c: self.inputs.wb_c,
+            tower_approach_k: self.inputs.approach_k,
+            tower_range_k: self.inputs.range_k,
+            cycles_of_concentration: self.inputs.cycles,
+            drift_fraction: self.inputs.drift_pct / 100.0,
+            recirculating_head_m: self.inputs.rc_head_m,
+            pump_efficiency: self.inputs.pump_eff,
+        };
+        self.result = Some(match cooling_comparison::compare_cooling_options(input) {
+            Ok(res) => {
+                let ot = &res.once_through;
+                let rc = &res.recirculating;
+                let mut out = fill_template(
+                    &txt(
+                        "gui.cooling.cmp.result",
+                        "                     once-through | recirculating\nFlow [m³/h]:         {ot_q} | {rc_q} (×{fr})\nSupply/return [°C]:  {ot_s}/{ot_r} | {rc_s}/{rc_r}\nWithdrawal [m³/h]:   {ot_w} | {rc_w} (×{wr})\nPumping [kW]:        {ot_p} | {rc_p} (×{pr})\nTower losses [m³/h]: evaporation {e}, drift {d}, blowdown {b}",
+                    ),
+                    &[
+                        ("ot_q", fmt_num(ot.circulating_flow_m3_per_h, 0)),
+                        ("rc_q", fmt_num(rc.circulating_flow_m3_per_h, 0)),
+                        ("fr", fmt_num(res.flow_ratio, 2)),
+                        ("ot_s", fmt_qty(ot.supply_temp_c, QuantityKind::Temperature)),
+                        ("ot_r", fmt_qty(ot.return_temp_c, QuantityKind::Temperature)),
+                        ("rc_s", fmt_qty(rc.supply_temp_c, QuantityKind::Temperature)),
+                        ("rc_r", fmt_qty(rc.return_temp_c, QuantityKind::Temperature)),
+                        ("ot_w", fmt_num(ot.withdrawal_m3_per_h, 0)),
+                        ("rc_w", fmt_num(rc.withdrawal_m3_per_h, 1)),
+                        ("wr", fmt_num(res.withdrawal_ratio, 3)),
+                        ("ot_p", fmt_num(ot.pumping_power_kw, 0)),
+                        ("rc_p", fmt_num(rc.pumping_power_kw, 0)),
+                        ("pr", fmt_num(res.pumping_power_ratio, 2)),
+                        ("e", fmt_num(rc.evaporation_m3_per_h, 1)),
+                        ("d", fmt_num(rc.drift_m3_per_h, 1)),
+                        ("b", fmt_num(rc.blowdown_m3_per_h, 1)),
+                    ],
+                );
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.cooling.cmp.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = CoolingComparisonInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.cooling.comparison = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.cooling.comparison.clone();
+    }
+}
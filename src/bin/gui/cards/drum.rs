@@ -0,0 +1,245 @@
+//! 드럼 스웰/쉬링크·블로다운 배관 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, fmt_signed, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, DrumInputs, GuiInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct DrumCard {
+    inputs: DrumInputs,
+    swell_result: Option<String>,
+    line_result: Option<String>,
+}
+
+impl DrumCard {
+    /// 스웰/쉬링크를 다시 계산한다.
+    fn compute_swell(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::drum::DrumSwellInput {
+            drum_inner_diameter_m: self.inputs.diameter_m,
+            drum_length_m: self.inputs.length_m,
+            normal_water_level_m: self.inputs.nwl_m,
+            riser_volume_m3: self.inputs.riser_volume_m3,
+            circulation_flow_kg_per_h: self.inputs.circulation_kg_h,
+            before: steam::drum::DrumOperatingPoint {
+                pressure_bar_abs: self.inputs.p_before_bar_abs,
+                steam_flow_kg_per_h: self.inputs.flow_before_kg_h,
+            },
+            after: steam::drum::DrumOperatingPoint {
+                pressure_bar_abs: self.inputs.p_after_bar_abs,
+                steam_flow_kg_per_h: self.inputs.flow_after_kg_h,
+            },
+        };
+        self.swell_result = Some(match steam::drum::drum_swell(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.drum.result",
+                        "Mean riser void {a1}% → {a2}%, displaced volume {dv} m³, level change {dl} mm",
+                    ),
+                    &[
+                        ("a1", fmt_num(res.mean_void_before * 100.0, 1)),
+                        ("a2", fmt_num(res.mean_void_after * 100.0, 1)),
+                        ("dv", fmt_qty(res.displaced_volume_m3, QuantityKind::Volume)),
+                        ("dl", fmt_signed(res.level_change_mm, 0)),
+                    ],
+                );
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.drum.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    /// 블로다운 배관을 다시 점검한다.
+    fn compute_blowdown_line(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::drum::BlowdownLineInput {
+            drum_pressure_bar_abs: self.inputs.p_before_bar_abs,
+            blowdown_flow_kg_per_h: self.inputs.blowdown_flow_kg_h,
+            line_diameter_m: self.inputs.blowdown_id_mm / 1000.0,
+            flash_tank_pressure_bar_abs: self.inputs.blowdown_flash_p_bar_abs,
+        };
+        self.line_result = Some(match steam::drum::blowdown_line_check(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.drum.bd_result",
+                        "Flash {flash}% ({flash_kg} kg/h), choked capacity {cap} kg/h ({util}% used), outlet two-phase velocity {vel} m/s",
+                    ),
+                    &[
+                        ("flash", fmt_num(res.flash_fraction * 100.0, 1)),
+                        ("flash_kg", fmt_num(res.flash_steam_kg_per_h, 1)),
+                        ("cap", fmt_num(res.choked_capacity_kg_per_h, 0)),
+                        ("util", fmt_num(res.capacity_utilization_percent, 0)),
+                        ("vel", fmt_qty(res.outlet_two_phase_velocity_m_per_s, QuantityKind::Velocity)),
+                    ],
+                );
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.drum.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+}
+
+impl CardWidget for DrumCard {
+    fn id(&self) -> CardId {
+        CardId::Drum
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt(
+                "gui.boiler.drum.heading",
+                "Drum swell/shrink & blowdown line check",
+            ),
+            &txt(
+                "gui.boiler.drum.tip",
+                "Estimate drum level change from riser void change on a load step, and check the blowdown line against flashing (choked) flow.",
+            ),
+        );
+        egui::Grid::new("boiler_drum_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.diameter", "Drum inner diameter [m]"),
+                    &txt("gui.boiler.drum.diameter_tip", "Horizontal drum inside diameter"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.diameter_m).speed(0.01));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.length", "Drum length [m]"),
+                    &txt("gui.boiler.drum.length_tip", "Shell length between heads"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.length_m).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.nwl", "Normal water level [m]"),
+                    &txt("gui.boiler.drum.nwl_tip", "Normal water level measured from drum bottom"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.nwl_m).speed(0.01));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.riser_volume", "Riser volume [m³]"),
+                    &txt("gui.boiler.drum.riser_volume_tip", "Total internal volume of risers (heated tubes)"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.riser_volume_m3).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.circulation", "Circulation flow [kg/h]"),
+                    &txt("gui.boiler.drum.circulation_tip", "Natural circulation flow, assumed constant with load"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.circulation_kg_h).speed(100.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.p_before", "Drum pressure before/after [bar(a)]"),
+                    &txt("gui.boiler.drum.p_before_tip", "Drum pressure before and after the load change"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p_before_bar_abs).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.p_after_bar_abs).speed(0.1));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.flow_before", "Steam flow before/after [kg/h]"),
+                    &txt("gui.boiler.drum.flow_before_tip", "Evaporation rate before and after the load change"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.flow_before_kg_h).speed(100.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.flow_after_kg_h).speed(100.0));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.drum.run", "Calculate swell/shrink")) {
+            self.compute_swell(tr);
+        }
+        if let Some(res) = &self.swell_result {
+            result_block(ui, &txt, res, false);
+        }
+        ui.separator();
+        egui::Grid::new("boiler_blowdown_line_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.bd_flow", "Blowdown flow [kg/h]"),
+                    &txt("gui.boiler.drum.bd_flow_tip", "Continuous blowdown flow from the drum (saturated water at drum pressure 'before')"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.blowdown_flow_kg_h).speed(10.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.bd_diameter", "Line/orifice ID [mm]"),
+                    &txt("gui.boiler.drum.bd_diameter_tip", "Smallest inside diameter in the blowdown line"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.blowdown_id_mm).speed(0.5));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.drum.bd_flash_p", "Flash tank pressure [bar(a)]"),
+                    &txt("gui.boiler.drum.bd_flash_p_tip", "Blowdown flash tank operating pressure"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.blowdown_flash_p_bar_abs).speed(0.1));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.drum.bd_run", "Check blowdown line")) {
+            self.compute_blowdown_line(tr);
+        }
+        if let Some(res) = &self.line_result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        self.compute_swell(tr);
+        self.compute_blowdown_line(tr);
+    }
+
+    fn export(&self) -> Vec<&str> {
+        [&self.swell_result, &self.line_result].into_iter().filter_map(|r| r.as_deref()).collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = DrumInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.drum = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.drum.clone();
+    }
+}
@@ -0,0 +1,160 @@
+//! 건도 측정(열량계) 카드.
+
+use super::{lookup, CardWidget};
+use crate::{fill_template, fmt_num, heading_with_tip, label_with_tip, result_block, run_button};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, DrynessInputs, GuiInputs},
+    i18n,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct DrynessCard {
+    inputs: DrynessInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for DrynessCard {
+    fn id(&self) -> CardId {
+        CardId::Dryness
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.steam.dryness.heading", "Dryness fraction calorimeters"),
+            &txt(
+                "gui.steam.dryness.tip",
+                "Evaluate throttling, separating or combined calorimeter readings to get the dryness fraction of sampled steam.",
+            ),
+        );
+        egui::Grid::new("steam_dryness_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.steam.dryness.mode", "Calorimeter"),
+                    &txt("gui.steam.dryness.mode_tip", "Throttling needs ≥ 5 K superheat after the throttle; use separating + throttling for wetter steam"),
+                );
+                let modes = [
+                    txt("gui.steam.dryness.mode_throttling", "Throttling"),
+                    txt("gui.steam.dryness.mode_separating", "Separating"),
+                    txt("gui.steam.dryness.mode_combined", "Separating + throttling"),
+                ];
+                egui::ComboBox::from_id_source("steam_dry_mode")
+                    .selected_text(modes[self.inputs.mode.min(2)].clone())
+                    .show_ui(ui, |ui| {
+                        for (i, label) in modes.iter().enumerate() {
+                            ui.selectable_value(&mut self.inputs.mode, i, label.clone());
+                        }
+                    });
+                ui.end_row();
+                if self.inputs.mode != 0 {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.steam.dryness.masses", "Separated water / condensate [kg]"),
+                        &txt("gui.steam.dryness.masses_tip", "Masses collected over the same test period"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.sep_water_kg).speed(0.01));
+                        ui.add(egui::DragValue::new(&mut self.inputs.condensate_kg).speed(0.1));
+                    });
+                    ui.end_row();
+                }
+                if self.inputs.mode != 1 {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.steam.dryness.main_p", "Main steam P [bar(a)]"),
+                        &txt("gui.steam.dryness.main_p_tip", "Pressure in the steam main at the sampling point"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.main_p_bar_abs).speed(0.1));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.steam.dryness.cal", "Calorimeter P [bar(a)] / T [°C]"),
+                        &txt("gui.steam.dryness.cal_tip", "State after the throttle, usually near atmospheric"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.cal_p_bar_abs).speed(0.01));
+                        ui.add(egui::DragValue::new(&mut self.inputs.cal_t_c).speed(0.5));
+                    });
+                    ui.end_row();
+                }
+            });
+        if run_button(ui, txt("gui.steam.dryness.run", "Calculate dryness")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let separating = steam::steam_dryness::SeparatingCalorimeterInput {
+            separated_water_kg: self.inputs.sep_water_kg,
+            condensed_steam_kg: self.inputs.condensate_kg,
+        };
+        let throttling = steam::steam_dryness::ThrottlingCalorimeterInput {
+            main_pressure_bar_abs: self.inputs.main_p_bar_abs,
+            calorimeter_pressure_bar_abs: self.inputs.cal_p_bar_abs,
+            calorimeter_temp_c: self.inputs.cal_t_c,
+        };
+        let result = match self.inputs.mode {
+            0 => steam::steam_dryness::throttling_calorimeter(throttling),
+            1 => steam::steam_dryness::separating_calorimeter(separating),
+            _ => steam::steam_dryness::separating_throttling_calorimeter(separating, throttling),
+        };
+        self.result = Some(match result {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt("gui.steam.dryness.result", "Dryness fraction x = {x} ({pct} %)"),
+                    &[
+                        ("x", fmt_num(res.dryness, 4)),
+                        ("pct", fmt_num(res.dryness * 100.0, 2)),
+                    ],
+                );
+                if let Some(dt) = res.superheat_after_throttling_k {
+                    out.push_str(&fill_template(
+                        &txt("gui.steam.dryness.superheat", "\nSuperheat after throttling {dt} K"),
+                        &[("dt", fmt_num(dt, 1))],
+                    ));
+                }
+                if let Some(x_min) = res.min_measurable_dryness {
+                    out.push_str(&fill_template(
+                        &txt("gui.steam.dryness.min", "\nLowest measurable dryness at this calorimeter pressure {x}"),
+                        &[("x", fmt_num(x_min, 4))],
+                    ));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.steam.dryness.error", "Error: {e}"),
+                &[("e", e.to_string())],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = DrynessInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.steam_tables.dryness = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.steam_tables.dryness.clone();
+    }
+}
@@ -0,0 +1,193 @@
+//! 증기 이젝터 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, EjectorInputs, GuiInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::{self, critical_flow},
+};
+
+#[derive(Default)]
+pub(crate) struct EjectorCard {
+    inputs: EjectorInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for EjectorCard {
+    fn id(&self) -> CardId {
+        CardId::Ejector
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.cooling.ejector.heading", "Steam Jet Ejector"),
+            &txt(
+                "gui.cooling.ejector.tip",
+                "Motive steam for single/two-stage ejectors (HEI air equivalent, energy-ratio method) and intercondenser duty",
+            ),
+        );
+        egui::Grid::new("ejector_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.stages", "Stages"),
+                    &txt("gui.cooling.ejector.stages_tip", "Two stages with an intercondenser at equal compression ratios"),
+                );
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.inputs.two_stage, false, txt("gui.cooling.ejector.single", "Single"));
+                    ui.selectable_value(&mut self.inputs.two_stage, true, txt("gui.cooling.ejector.two", "Two + intercondenser"));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.suction", "Suction [bar(a)] / [°C]"),
+                    &txt("gui.cooling.ejector.suction_tip", "Suction pressure and gas temperature"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.suction_p_bar_abs).speed(0.005));
+                    ui.add(egui::DragValue::new(&mut self.inputs.suction_temp_c).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.discharge", "Discharge [bar(a)]"),
+                    &txt("gui.cooling.ejector.discharge_tip", "Final stage discharge (atmosphere or after-condenser)"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.discharge_p_bar_abs).speed(0.01));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.load", "Air / vapor load [kg/h]"),
+                    &txt("gui.cooling.ejector.load_tip", "Non-condensable air and water vapour drawn at suction"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.air_kg_h).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.vapor_kg_h).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.motive", "Motive steam [bar(a)], saturated"),
+                    &txt("gui.cooling.ejector.motive_tip", "Dry saturated motive steam pressure"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.motive_p_bar_abs).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.ic_temp", "Intercondenser outlet [°C]"),
+                    &txt("gui.cooling.ejector.ic_temp_tip", "Gas outlet temperature, typically cooling water inlet + 3–5 K"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.intercondenser_temp_c).speed(0.5));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.ejector.efficiency", "Ejector efficiency [-]"),
+                    &txt("gui.cooling.ejector.efficiency_tip", "Compression work over motive isentropic enthalpy drop; 0.15 matches typical vendor curves"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.efficiency).speed(0.005).clamp_range(0.01..=1.0));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.cooling.ejector.run", "Size ejector")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::ejector::EjectorInput {
+            arrangement: if self.inputs.two_stage {
+                steam::ejector::EjectorArrangement::TwoStage
+            } else {
+                steam::ejector::EjectorArrangement::SingleStage
+            },
+            suction_pressure_bar_abs: self.inputs.suction_p_bar_abs,
+            suction_temp_c: self.inputs.suction_temp_c,
+            discharge_pressure_bar_abs: self.inputs.discharge_p_bar_abs,
+            air_load_kg_per_h: self.inputs.air_kg_h,
+            vapor_load_kg_per_h: self.inputs.vapor_kg_h,
+            motive_pressure_bar_abs: self.inputs.motive_p_bar_abs,
+            motive_state: critical_flow::SteamStagnation::Quality(1.0),
+            interstage_pressure_bar_abs: None,
+            intercondenser_outlet_temp_c: self.inputs.intercondenser_temp_c,
+            efficiency: Some(self.inputs.efficiency),
+        };
+        self.result = Some(match steam::ejector::size_ejector(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt("gui.cooling.ejector.result", "Total motive steam {m} kg/h"),
+                    &[("m", fmt_num(res.total_motive_steam_kg_per_h, 1))],
+                );
+                for (i, st) in res.stages.iter().enumerate() {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.cooling.ejector.stage",
+                            "Stage {n}: {p1}→{p2} bar(a) (CR {cr}), air eq. {aeq} kg/h, motive {m} kg/h",
+                        ),
+                        &[
+                            ("n", (i + 1).to_string()),
+                            ("p1", fmt_qty(st.suction_pressure_bar_abs, QuantityKind::Pressure)),
+                            ("p2", fmt_qty(st.discharge_pressure_bar_abs, QuantityKind::Pressure)),
+                            ("cr", fmt_num(st.compression_ratio, 1)),
+                            ("aeq", fmt_num(st.air_equivalent_kg_per_h, 1)),
+                            ("m", fmt_num(st.motive_steam_kg_per_h, 1)),
+                        ],
+                    ));
+                }
+                if let Some(ic) = &res.intercondenser {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.cooling.ejector.intercondenser",
+                            "Intercondenser: {duty} kW, condensed {c} kg/h, vapour carry-over {v} kg/h ({t_in}→{t_out} °C)",
+                        ),
+                        &[
+                            ("duty", fmt_num(ic.duty_kw, 1)),
+                            ("c", fmt_num(ic.condensed_kg_per_h, 1)),
+                            ("v", fmt_num(ic.vapor_carryover_kg_per_h, 2)),
+                            ("t_in", fmt_qty(ic.inlet_temp_c, QuantityKind::Temperature)),
+                            ("t_out", fmt_qty(ic.outlet_temp_c, QuantityKind::Temperature)),
+                        ],
+                    ));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.cooling.ejector.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = EjectorInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.cooling.ejector = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.cooling.ejector.clone();
+    }
+}
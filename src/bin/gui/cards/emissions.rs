@@ -0,0 +1,198 @@
+//! 배출량(CO₂/SO₂/NOx) 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, EmissionsInputs, GuiInputs},
+    combustion::{emissions::{self, BurnerType}, fuel::FuelType},
+    i18n,
+};
+
+#[derive(Default)]
+pub(crate) struct EmissionsCard {
+    inputs: EmissionsInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for EmissionsCard {
+    fn id(&self) -> CardId {
+        CardId::Emissions
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.emis.heading", "Emissions (CO₂ / SO₂ / NOx)"),
+            &txt(
+                "gui.boiler.emis.tip",
+                "CO₂ and SO₂ from the fuel carbon and sulfur balance, NOx (as NO₂) from EPA AP-42 factors by fuel and burner type, in kg/h and t/yr.",
+            ),
+        );
+        egui::Grid::new("boiler_emis_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.emis.fuel", "Fuel / sulfur [%]"),
+                    &txt(
+                        "gui.boiler.emis.fuel_tip",
+                        "Choosing a fuel loads its typical analysis, sulfur and LHV; enter the sulfur from the fuel certificate",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    let before = self.inputs.fuel;
+                    egui::ComboBox::from_id_source("emis_fuel")
+                        .selected_text(txt(self.inputs.fuel.key(), self.inputs.fuel.name()))
+                        .show_ui(ui, |ui| {
+                            for f in FuelType::ALL {
+                                ui.selectable_value(&mut self.inputs.fuel, f, txt(f.key(), f.name()));
+                            }
+                        });
+                    if before != self.inputs.fuel {
+                        self.inputs.sulfur_pct = self.inputs.fuel.analysis().sulfur * 100.0;
+                        self.inputs.lhv_kj_kg = self.inputs.fuel.lhv_kj_per_kg();
+                    }
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.sulfur_pct)
+                            .speed(0.05)
+                            .clamp_range(0.0..=6.0),
+                    );
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.emis.firing", "Fuel flow [kg/h] / LHV [kJ/kg]"),
+                    &txt("gui.boiler.emis.firing_tip", "Average firing rate over the operating hours"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.fuel_kg_h).speed(10.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.lhv_kj_kg).speed(100.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.emis.burner", "Burner"),
+                    &txt(
+                        "gui.boiler.emis.burner_tip",
+                        "Selects the AP-42 NOx factor; oil and coal have no FGR factor, so the low-NOx value is used",
+                    ),
+                );
+                egui::ComboBox::from_id_source("emis_burner")
+                    .selected_text(txt(self.inputs.burner.key(), self.inputs.burner.name()))
+                    .show_ui(ui, |ui| {
+                        for b in BurnerType::ALL {
+                            ui.selectable_value(&mut self.inputs.burner, b, txt(b.key(), b.name()));
+                        }
+                    });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.emis.hours", "Operating hours [h/yr]"),
+                    &txt("gui.boiler.emis.hours_tip", "Annual hours at this firing rate (max 8760)"),
+                );
+                ui.add(
+                    egui::DragValue::new(&mut self.inputs.hours)
+                        .speed(10.0)
+                        .clamp_range(0.0..=8760.0),
+                );
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.emis.nox", "NOx factor [g/GJ HHV] / useful output [kW]"),
+                    &txt(
+                        "gui.boiler.emis.nox_tip",
+                        "Measured or guaranteed NOx factor (0 = AP-42) and boiler useful output for CO₂ per MWh (0 = skip)",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.nox_g_gj).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.useful_kw).speed(100.0));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.emis.run", "Calculate emissions")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = emissions::EmissionsInput {
+            fuel_type: self.inputs.fuel,
+            fuel: self.inputs.fuel.analysis().with_sulfur(self.inputs.sulfur_pct / 100.0),
+            fuel_flow_kg_per_h: self.inputs.fuel_kg_h,
+            fuel_lhv_kj_per_kg: self.inputs.lhv_kj_kg,
+            burner: self.inputs.burner,
+            operating_hours_per_year: self.inputs.hours,
+            nox_factor_kg_per_gj: (self.inputs.nox_g_gj > 0.0).then_some(self.inputs.nox_g_gj / 1000.0),
+            useful_output_kw: (self.inputs.useful_kw > 0.0).then_some(self.inputs.useful_kw),
+        };
+        self.result = Some(match emissions::compute_emissions(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.emis.result",
+                        "Heat input {qh} kW (HHV) / {ql} kW (LHV)\nCO₂ {co2} kg/h, {co2y} t/yr ({co2f} kg/GJ)\nSO₂ {so2} kg/h, {so2y} t/yr\nNOx (as NO₂) {nox} kg/h, {noxy} t/yr (factor {noxf} g/GJ, {src})",
+                    ),
+                    &[
+                        ("qh", fmt_num(res.heat_input_hhv_kw, 0)),
+                        ("ql", fmt_num(res.heat_input_lhv_kw, 0)),
+                        ("co2", fmt_num(res.co2.kg_per_h, 0)),
+                        ("co2y", fmt_num(res.co2.tonnes_per_year, 0)),
+                        ("co2f", fmt_num(res.co2.kg_per_gj, 1)),
+                        ("so2", fmt_num(res.so2.kg_per_h, 2)),
+                        ("so2y", fmt_num(res.so2.tonnes_per_year, 1)),
+                        ("nox", fmt_num(res.nox.kg_per_h, 2)),
+                        ("noxy", fmt_num(res.nox.tonnes_per_year, 1)),
+                        ("noxf", fmt_num(res.nox_factor_kg_per_gj * 1000.0, 1)),
+                        (
+                            "src",
+                            if res.nox_from_ap42 {
+                                txt("gui.boiler.emis.src_ap42", "AP-42")
+                            } else {
+                                txt("gui.boiler.emis.src_input", "entered")
+                            },
+                        ),
+                    ],
+                );
+                if let Some(per_mwh) = res.co2_per_useful_mwh_kg {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt("gui.boiler.emis.intensity", "CO₂ per useful output {v} kg/MWh"),
+                        &[("v", fmt_num(per_mwh, 0))],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.emis.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = EmissionsInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.emissions = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.emissions.clone();
+    }
+}
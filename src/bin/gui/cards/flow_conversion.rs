@@ -0,0 +1,127 @@
+//! 유량·동력 변환 카드.
+
+use super::{lookup, CardWidget};
+use crate::{error_text, fmt_num, label_with_tip, result_block, run_button, unit_combo};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, FlowConversionInputs, GuiInputs},
+    flow_units::{self, FlowKind},
+    i18n,
+};
+
+#[derive(Default)]
+pub(crate) struct FlowConversionCard {
+    inputs: FlowConversionInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for FlowConversionCard {
+    fn id(&self) -> CardId {
+        CardId::FlowConversion
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        label_with_tip(
+            ui,
+            &txt("gui.unit.flow.heading", "Flow / power"),
+            &txt(
+                "gui.unit.flow.tip",
+                "Mass flow, volumetric flow and power (heat rate) conversions",
+            ),
+        );
+        egui::Grid::new("flow_conv_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(txt("gui.unit.quantity.label", "Quantity"));
+                let before = self.inputs.kind;
+                let kinds = [
+                    (FlowKind::MassFlow, txt("gui.unit.quantity.mass_flow", "Mass flow")),
+                    (
+                        FlowKind::VolumetricFlow,
+                        txt("gui.unit.quantity.volumetric_flow", "Volumetric flow"),
+                    ),
+                    (FlowKind::Power, txt("gui.unit.quantity.power", "Power / heat rate")),
+                    (FlowKind::Density, txt("gui.unit.quantity.density", "Density")),
+                    (
+                        FlowKind::SpecificVolume,
+                        txt("gui.unit.quantity.specific_volume", "Specific volume"),
+                    ),
+                    (
+                        FlowKind::SpecificHeat,
+                        txt("gui.unit.quantity.specific_heat", "Specific heat"),
+                    ),
+                ];
+                let selected = kinds
+                    .iter()
+                    .find(|(k, _)| *k == self.inputs.kind)
+                    .map(|(_, l)| l.clone())
+                    .unwrap_or_default();
+                egui::ComboBox::from_id_source("flow_conv_kind")
+                    .selected_text(selected)
+                    .show_ui(ui, |ui| {
+                        for (k, label) in &kinds {
+                            ui.selectable_value(&mut self.inputs.kind, *k, label.clone());
+                        }
+                    });
+                if before != self.inputs.kind {
+                    let units = flow_units::unit_options(self.inputs.kind);
+                    self.inputs.from = units[0].1.to_string();
+                    self.inputs.to = units[1].1.to_string();
+                }
+                ui.end_row();
+
+                ui.label(txt("gui.unit.value", "Value"));
+                ui.add(egui::DragValue::new(&mut self.inputs.value).speed(1.0));
+                ui.end_row();
+
+                ui.label(txt("gui.unit.from", "From unit"));
+                unit_combo(ui, &mut self.inputs.from, flow_units::unit_options(self.inputs.kind));
+                ui.end_row();
+
+                ui.label(txt("gui.unit.to", "To unit"));
+                unit_combo(ui, &mut self.inputs.to, flow_units::unit_options(self.inputs.kind));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.unit.run", "Convert")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        self.result = match flow_units::convert(
+            self.inputs.kind,
+            self.inputs.value,
+            &self.inputs.from,
+            &self.inputs.to,
+        ) {
+            Ok(v) => Some(format!("{} {}", fmt_num(v, 6), self.inputs.to)),
+            Err(e) => Some(format!(
+                "{}: {}",
+                txt("gui.unit.error_prefix", "Error"),
+                error_text(tr, e)
+            )),
+        };
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = FlowConversionInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.unit_conv.flow = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.unit_conv.flow.clone();
+    }
+}
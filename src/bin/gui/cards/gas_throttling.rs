@@ -0,0 +1,164 @@
+//! 가스 감압 온도(줄-톰슨) 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GasThrottlingInputs, GuiInputs},
+    gas::{gas_properties, throttling},
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct GasThrottlingCard {
+    inputs: GasThrottlingInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for GasThrottlingCard {
+    fn id(&self) -> CardId {
+        CardId::GasThrottling
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.throttling.heading", "Gas letdown temperature (Joule-Thomson)"),
+            &txt(
+                "gui.plant.throttling.tip",
+                "Outlet temperature of a constant-enthalpy pressure letdown (fuel gas station, regulator) from the Peng-Robinson enthalpy or a given JT coefficient, with hydrate and condensation warnings",
+            ),
+        );
+        egui::Grid::new("plant_throttling_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.throttling.gas", "Gas"),
+                    &txt(
+                        "gui.plant.throttling.gas_tip",
+                        "NG = pipeline natural gas (CH₄ 90%, C₂H₆ 7%, N₂ 3%); hydrate check applies to NG and CH₄",
+                    ),
+                );
+                let idx = self.inputs.gas.min(gas_properties::Gas::ALL.len() - 1);
+                egui::ComboBox::from_id_source("plant_jt_gas")
+                    .selected_text(gas_properties::Gas::ALL[idx].formula())
+                    .show_ui(ui, |ui| {
+                        for (i, g) in gas_properties::Gas::ALL.iter().enumerate() {
+                            ui.selectable_value(&mut self.inputs.gas, i, g.formula());
+                        }
+                    });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.throttling.inlet", "Inlet P [bar(a)] / T [°C]"),
+                    &txt("gui.plant.throttling.inlet_tip", "Gas state upstream of the regulator or valve"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p1_bar_abs).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.t1_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.throttling.outlet", "Outlet P [bar(a)]"),
+                    &txt("gui.plant.throttling.outlet_tip", "Pressure downstream of the letdown"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.p2_bar_abs).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.throttling.use_coefficient", "Use JT coefficient [K/bar]"),
+                    &txt(
+                        "gui.plant.throttling.use_coefficient_tip",
+                        "Unchecked: Peng-Robinson enthalpy balance; checked: T₂ = T₁ − μJT·(P₁ − P₂) with a known average coefficient",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut self.inputs.use_coefficient, "");
+                    ui.add_enabled(
+                        self.inputs.use_coefficient,
+                        egui::DragValue::new(&mut self.inputs.coefficient_k_per_bar).speed(0.01),
+                    );
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.plant.throttling.run", "Calculate outlet temperature")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = throttling::ThrottlingInput {
+            gas: gas_properties::Gas::ALL[self.inputs.gas.min(gas_properties::Gas::ALL.len() - 1)],
+            inlet_pressure_bar_abs: self.inputs.p1_bar_abs,
+            inlet_temp_c: self.inputs.t1_c,
+            outlet_pressure_bar_abs: self.inputs.p2_bar_abs,
+            method: if self.inputs.use_coefficient {
+                throttling::JouleThomsonMethod::Coefficient {
+                    k_per_bar: self.inputs.coefficient_k_per_bar,
+                }
+            } else {
+                throttling::JouleThomsonMethod::Eos
+            },
+        };
+        self.result = Some(match throttling::throttle(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.plant.throttling.result",
+                        "Outlet {t2} °C (ΔT {dt} K, mean μJT {mu} K/bar), Z {z}, density {rho} kg/m³",
+                    ),
+                    &[
+                        ("t2", fmt_qty(res.outlet_temp_c, QuantityKind::Temperature)),
+                        ("dt", fmt_num(-res.temperature_drop_k, 1)),
+                        ("mu", fmt_num(res.mean_jt_coefficient_k_per_bar, 3)),
+                        ("z", fmt_num(res.outlet_z, 4)),
+                        ("rho", fmt_num(res.outlet_density_kg_per_m3, 2)),
+                    ],
+                );
+                if let Some(t_hyd) = res.hydrate_temp_c {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt(
+                            "gui.plant.throttling.hydrate",
+                            "Hydrate formation temperature at outlet pressure ≈ {t} °C (if free water is present)",
+                        ),
+                        &[("t", fmt_num(t_hyd, 1))],
+                    ));
+                }
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.plant.throttling.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = GasThrottlingInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.throttling = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.throttling.clone();
+    }
+}
@@ -0,0 +1,261 @@
+//! 템플릿 구성의 열정산도 카드: 입력·가열기 표, 노드 다이어그램, 스트림 표.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, heat_balance_diagram, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, HeatBalanceInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::{self, heat_balance},
+};
+
+#[derive(Default)]
+pub(crate) struct HeatBalanceCard {
+    inputs: HeatBalanceInputs,
+    solved: Option<heat_balance::HeatBalanceResult>,
+    result: Option<String>,
+}
+
+impl CardWidget for HeatBalanceCard {
+    fn id(&self) -> CardId {
+        CardId::HeatBalance
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.hb.heading", "Heat balance diagram"),
+            &txt(
+                "gui.boiler.hb.tip",
+                "Template flowsheet (boiler, HP/LP turbine, reheater, condenser, pumps, cascading feedwater heaters and one deaerator) solved per stream with the Rankine cycle models",
+            ),
+        );
+        ui.horizontal(|ui| {
+            ui.label(txt("gui.boiler.hb.template", "Template"));
+            egui::ComboBox::from_id_source("hb_template")
+                .selected_text(txt(self.inputs.template.i18n_key(), self.inputs.template.label()))
+                .show_ui(ui, |ui| {
+                    for t in heat_balance::HeatBalanceTemplate::ALL {
+                        ui.selectable_value(&mut self.inputs.template, t, txt(t.i18n_key(), t.label()));
+                    }
+                });
+            if ui.button(txt("gui.boiler.hb.load", "Load template")).clicked() {
+                self.inputs.input = self.inputs.template.input();
+                self.solved = None;
+                self.result = None;
+            }
+        });
+        let input = &mut self.inputs.input;
+        egui::Grid::new("boiler_hb_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                ui.label(txt("gui.boiler.cycle.throttle", "Throttle [bar(a)] / [°C]"));
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut input.throttle_pressure_bar_abs).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut input.throttle_temp_c).speed(1.0));
+                });
+                ui.end_row();
+                ui.label(txt("gui.boiler.cycle.condenser", "Condenser pressure [bar(a)]"));
+                ui.add(egui::DragValue::new(&mut input.condenser_pressure_bar_abs).speed(0.005));
+                ui.end_row();
+                ui.label(txt("gui.boiler.cycle.eff", "Turbine / pump efficiency"));
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut input.turbine_efficiency).speed(0.01).clamp_range(0.01..=1.0));
+                    ui.add(egui::DragValue::new(&mut input.pump_efficiency).speed(0.01).clamp_range(0.01..=1.0));
+                });
+                ui.end_row();
+                let mut reheat = input.reheat.is_some();
+                if ui.checkbox(&mut reheat, txt("gui.boiler.cycle.reheat", "Reheat [bar(a)] / [°C]")).changed() {
+                    input.reheat = reheat.then_some(steam::cycle::ReheatInput {
+                        pressure_bar_abs: 20.0,
+                        temp_c: input.throttle_temp_c,
+                    });
+                }
+                ui.horizontal(|ui| {
+                    if let Some(rh) = input.reheat.as_mut() {
+                        ui.add(egui::DragValue::new(&mut rh.pressure_bar_abs).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut rh.temp_c).speed(1.0));
+                    }
+                });
+                ui.end_row();
+                ui.label(txt("gui.boiler.cycle.flow", "Main steam flow [kg/h]"));
+                ui.add(egui::DragValue::new(&mut input.steam_flow_kg_per_h).speed(100.0));
+                ui.end_row();
+            });
+        label_with_tip(
+            ui,
+            &txt("gui.boiler.hb.heaters", "Feedwater heaters"),
+            &txt(
+                "gui.boiler.hb.heaters_tip",
+                "Extraction pressure [bar(a)], type and TTD [K]. Closed heater drains cascade to the next lower heater; at most one open heater (deaerator).",
+            ),
+        );
+        let kind_labels = [
+            txt("gui.boiler.hb.closed", "Closed"),
+            txt("gui.boiler.hb.open", "Open (DA)"),
+        ];
+        let mut remove = None;
+        egui::Grid::new("boiler_hb_heaters")
+            .num_columns(4)
+            .spacing([12.0, 4.0])
+            .show(ui, |ui| {
+                for (i, fwh) in input.heaters.iter_mut().enumerate() {
+                    ui.add(egui::DragValue::new(&mut fwh.extraction_pressure_bar_abs).speed(0.1));
+                    let open = matches!(fwh.kind, steam::cycle::FeedwaterHeaterKind::Open);
+                    egui::ComboBox::from_id_source(("hb_heater_kind", i))
+                        .selected_text(kind_labels[open as usize].clone())
+                        .show_ui(ui, |ui| {
+                            if ui.selectable_label(!open, kind_labels[0].as_str()).clicked() && open {
+                                fwh.kind = steam::cycle::FeedwaterHeaterKind::Closed {
+                                    terminal_temp_difference_k: 3.0,
+                                };
+                            }
+                            if ui.selectable_label(open, kind_labels[1].as_str()).clicked() {
+                                fwh.kind = steam::cycle::FeedwaterHeaterKind::Open;
+                            }
+                        });
+                    match &mut fwh.kind {
+                        steam::cycle::FeedwaterHeaterKind::Closed {
+                            terminal_temp_difference_k,
+                        } => {
+                            ui.add(egui::DragValue::new(terminal_temp_difference_k).speed(0.1).prefix("TTD "));
+                        }
+                        steam::cycle::FeedwaterHeaterKind::Open => {
+                            ui.label("");
+                        }
+                    }
+                    if ui.small_button("✖").clicked() {
+                        remove = Some(i);
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(i) = remove {
+            input.heaters.remove(i);
+        }
+        ui.horizontal(|ui| {
+            if ui.small_button(txt("gui.boiler.hb.add", "+ Heater")).clicked() {
+                let input = &mut self.inputs.input;
+                let p_min = input
+                    .heaters
+                    .iter()
+                    .map(|h| h.extraction_pressure_bar_abs)
+                    .fold(input.throttle_pressure_bar_abs, f64::min);
+                input.heaters.push(steam::cycle::FeedwaterHeaterInput {
+                    extraction_pressure_bar_abs: (p_min / 2.0).max(input.condenser_pressure_bar_abs * 2.0),
+                    kind: steam::cycle::FeedwaterHeaterKind::Closed {
+                        terminal_temp_difference_k: 3.0,
+                    },
+                });
+            }
+            if run_button(ui, txt("gui.boiler.hb.run", "Solve heat balance")) {
+                self.compute(tr);
+            }
+        });
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+        if let Some(res) = &self.solved {
+            let node_name = |n: &heat_balance::Node| match n.kind {
+                heat_balance::NodeKind::ClosedHeater | heat_balance::NodeKind::Deaerator => n.label.clone(),
+                kind => txt(kind.i18n_key(), &n.label),
+            };
+            heat_balance_diagram(ui, res, &node_name);
+            egui::CollapsingHeader::new(txt("gui.boiler.hb.streams", "Streams"))
+                .id_source("boiler_hb_streams")
+                .show(ui, |ui| {
+                    egui::Grid::new("boiler_hb_stream_grid")
+                        .num_columns(8)
+                        .striped(true)
+                        .spacing([10.0, 4.0])
+                        .show(ui, |ui| {
+                            for head in [
+                                "#".to_string(),
+                                txt("gui.boiler.hb.col.stream", "Stream"),
+                                txt("gui.boiler.hb.col.route", "From → to"),
+                                "t/h".to_string(),
+                                "bar(a)".to_string(),
+                                "°C".to_string(),
+                                "kJ/kg".to_string(),
+                                "x".to_string(),
+                            ] {
+                                ui.strong(head);
+                            }
+                            ui.end_row();
+                            for (i, s) in res.streams.iter().enumerate() {
+                                ui.label(format!("S{}", i + 1));
+                                ui.label(txt(s.kind.i18n_key(), s.kind.label()));
+                                ui.label(format!(
+                                    "{} → {}",
+                                    node_name(&res.nodes[s.from]),
+                                    node_name(&res.nodes[s.to])
+                                ));
+                                ui.label(fmt_num(s.mass_flow_kg_per_h / 1000.0, 2));
+                                ui.label(fmt_qty(s.pressure_bar_abs, QuantityKind::Pressure));
+                                ui.label(fmt_qty(s.temp_c, QuantityKind::Temperature));
+                                ui.label(fmt_qty(s.enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy));
+                                ui.label(s.quality.map_or(String::new(), |x| fmt_num(x, 3)));
+                                ui.end_row();
+                            }
+                        });
+                });
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        match heat_balance::solve_heat_balance(&self.inputs.input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.hb.result",
+                        "Turbine {wt} MW, pumps {wp} kW, net {net} MW\nHeat input {q} MW, condenser {qc} MW\nEfficiency {eta}%, heat rate {hr} kJ/kWh, exhaust quality {x}, feedwater {tfw} °C\nEnergy balance residual {res} kW",
+                    ),
+                    &[
+                        ("wt", fmt_num(res.turbine_power_kw / 1000.0, 2)),
+                        ("wp", fmt_num(res.pump_power_kw, 0)),
+                        ("net", fmt_num(res.net_power_kw / 1000.0, 2)),
+                        ("q", fmt_num(res.heat_input_kw / 1000.0, 2)),
+                        ("qc", fmt_num(res.condenser_duty_kw / 1000.0, 2)),
+                        ("eta", fmt_num(res.thermal_efficiency * 100.0, 2)),
+                        ("hr", fmt_num(res.heat_rate_kj_per_kwh, 0)),
+                        ("x", fmt_num(res.exhaust_quality, 3)),
+                        ("tfw", fmt_qty(res.feedwater_temp_c, QuantityKind::Temperature)),
+                        ("res", fmt_num(res.energy_residual_kw, 3)),
+                    ],
+                );
+                push_warnings(&mut out, tr, &res.warnings);
+                self.result = Some(out);
+                self.solved = Some(res);
+            }
+            Err(e) => {
+                self.result = Some(fill_template(
+                    &txt("gui.boiler.hb.error", "Error: {e}"),
+                    &[("e", error_text(tr, e))],
+                ));
+                self.solved = None;
+            }
+        }
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = HeatBalanceInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.heat_balance = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.heat_balance.clone();
+    }
+}
@@ -0,0 +1,178 @@
+//! HRSG 핀치/어프로치 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    dew_point_fuel_combo, dew_point_line, error_text, fill_template, flue_gas_dew_points_for, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, HrsgInputs},
+    combustion::fuel::FuelType,
+    i18n,
+    quantity::QuantityKind,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct HrsgCard {
+    inputs: HrsgInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for HrsgCard {
+    fn id(&self) -> CardId {
+        CardId::Hrsg
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.hrsg.heading", "HRSG pinch / approach"),
+            &txt(
+                "gui.boiler.hrsg.tip",
+                "Single-pressure heat recovery steam generator: steam production from the pinch and approach points and the resulting stack temperature.",
+            ),
+        );
+        egui::Grid::new("boiler_hrsg_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.hrsg.gas", "Exhaust flow [kg/s] / T [°C] / cp [kJ/kgK]"),
+                    &txt("gui.boiler.hrsg.gas_tip", "Gas turbine exhaust; natural gas exhaust cp ≈ 1.1 kJ/kgK"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.gas_kg_s).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.gas_t_c).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.gas_cp).speed(0.01));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.hrsg.steam", "Drum P [bar(a)] / superheat T [°C]"),
+                    &txt("gui.boiler.hrsg.steam_tip", "Uncheck for saturated steam (no superheater)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p_bar_abs).speed(0.5));
+                    ui.checkbox(&mut self.inputs.superheat, "");
+                    ui.add_enabled(
+                        self.inputs.superheat,
+                        egui::DragValue::new(&mut self.inputs.steam_t_c).speed(1.0),
+                    );
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.hrsg.feedwater", "Feedwater T [°C]"),
+                    &txt("gui.boiler.hrsg.feedwater_tip", "Economizer inlet water temperature"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.fw_t_c).speed(1.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.hrsg.pinch", "Pinch / approach [K]"),
+                    &txt("gui.boiler.hrsg.pinch_tip", "Pinch: evaporator gas outlet − Tsat (typ. 8–15 K); approach: Tsat − economizer water outlet (typ. 3–10 K)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.pinch_k).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.approach_k).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.hrsg.fuel", "GT fuel / excess air [%] (dew point)"),
+                    &txt(
+                        "gui.boiler.hrsg.fuel_tip",
+                        "Gas turbine fuel and exhaust excess air (typ. 200–300%) for the stack dew point check",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    dew_point_fuel_combo(ui, &txt, "hrsg_fuel", &mut self.inputs.fuel);
+                    ui.add_enabled(
+                        self.inputs.fuel.is_some(),
+                        egui::DragValue::new(&mut self.inputs.excess_air_pct).speed(5.0),
+                    );
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.hrsg.run", "Calculate HRSG")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::hrsg::HrsgInput {
+            gas_flow_kg_per_s: self.inputs.gas_kg_s,
+            gas_inlet_temp_c: self.inputs.gas_t_c,
+            gas_cp_kj_per_kgk: self.inputs.gas_cp,
+            steam_pressure_bar_abs: self.inputs.p_bar_abs,
+            steam_temp_c: self.inputs.superheat.then_some(self.inputs.steam_t_c),
+            feedwater_temp_c: self.inputs.fw_t_c,
+            pinch_k: self.inputs.pinch_k,
+            approach_k: self.inputs.approach_k,
+            flue_gas_dew_points: flue_gas_dew_points_for(
+                self.inputs.fuel.map(FuelType::analysis),
+                self.inputs.excess_air_pct / 100.0,
+            ),
+        };
+        let dew_points = input.flue_gas_dew_points;
+        self.result = Some(match steam::hrsg::compute_hrsg(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.hrsg.result",
+                        "Steam {steam} kg/h at Tsat {tsat} °C; stack {stack} °C, heat recovery {rec}%\nDuty SH {qsh} / EVAP {qev} / ECO {qec} kW (total {q} kW)\nGas after SH {tsh} °C, at pinch {tp} °C; economizer outlet water {tw} °C",
+                    ),
+                    &[
+                        ("steam", fmt_num(res.steam_flow_kg_per_h, 0)),
+                        ("tsat", fmt_qty(res.saturation_temp_c, QuantityKind::Temperature)),
+                        ("stack", fmt_qty(res.stack_temp_c, QuantityKind::Temperature)),
+                        ("rec", fmt_num(res.heat_recovery_fraction * 100.0, 1)),
+                        ("qsh", fmt_num(res.superheater_duty_kw, 0)),
+                        ("qev", fmt_num(res.evaporator_duty_kw, 0)),
+                        ("qec", fmt_num(res.economizer_duty_kw, 0)),
+                        ("q", fmt_num(res.total_duty_kw, 0)),
+                        ("tsh", fmt_qty(res.gas_temp_after_superheater_c, QuantityKind::Temperature)),
+                        ("tp", fmt_qty(res.gas_temp_at_pinch_c, QuantityKind::Temperature)),
+                        ("tw", fmt_qty(res.economizer_outlet_temp_c, QuantityKind::Temperature)),
+                    ],
+                );
+                if let Some(dp) = &dew_points {
+                    out.push('\n');
+                    out.push_str(&dew_point_line(&txt, dp));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.hrsg.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = HrsgInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.hrsg = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.hrsg.clone();
+    }
+}
@@ -0,0 +1,146 @@
+//! 감압 소음/AIV 선별 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, LetdownNoiseInputs},
+    gas::aiv,
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct LetdownNoiseCard {
+    inputs: LetdownNoiseInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for LetdownNoiseCard {
+    fn id(&self) -> CardId {
+        CardId::LetdownNoise
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.aiv.heading", "Letdown noise / AIV screening"),
+            &txt(
+                "gui.plant.aiv.tip",
+                "Carucci-Mueller sound power of a high-ΔP gas/steam letdown (PRV, restriction orifice, blowdown valve) ranked against the downstream pipe D/t",
+            ),
+        );
+        egui::Grid::new("plant_aiv_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.aiv.pressures", "P1 / P2 [bar(a)]"),
+                    &txt("gui.plant.aiv.pressures_tip", "Pressure upstream and downstream of the letdown device"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p1_bar_abs).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.p2_bar_abs).speed(0.1));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.aiv.flow", "Mass flow [kg/h] / T1 [°C]"),
+                    &txt("gui.plant.aiv.flow_tip", "Relief, blowdown or letdown flow and upstream temperature"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.flow_kg_h).speed(100.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.t1_c).speed(1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.aiv.molar_mass", "Molar mass [kg/kmol]"),
+                    &txt("gui.plant.aiv.molar_mass_tip", "Steam 18.015; natural gas about 17-19"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.molar_mass).speed(0.1));
+                    if ui.small_button(txt("gui.plant.aiv.steam", "Steam")).clicked() {
+                        self.inputs.molar_mass = aiv::STEAM_MOLAR_MASS;
+                    }
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.aiv.pipe", "Downstream OD / wall [mm]"),
+                    &txt("gui.plant.aiv.pipe_tip", "Main pipe directly downstream of the letdown; D/t sets the allowable sound power"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.od_mm).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.wall_mm).speed(0.1));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.plant.aiv.run", "Screen AIV")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = aiv::AivInput {
+            upstream_pressure_bar_abs: self.inputs.p1_bar_abs,
+            downstream_pressure_bar_abs: self.inputs.p2_bar_abs,
+            upstream_temp_c: self.inputs.t1_c,
+            mass_flow_kg_per_h: self.inputs.flow_kg_h,
+            molar_mass_kg_per_kmol: self.inputs.molar_mass,
+            outer_diameter_m: self.inputs.od_mm / 1000.0,
+            wall_thickness_m: self.inputs.wall_mm / 1000.0,
+        };
+        self.result = Some(match aiv::screen_aiv(&input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.plant.aiv.result",
+                        "PWL {pwl} dB, D/t {dt}, limit {limit} dB (margin {margin} dB) → risk {risk}; review {len} m downstream",
+                    ),
+                    &[
+                        ("pwl", fmt_num(res.sound_power_db, 1)),
+                        ("dt", fmt_num(res.d_over_t, 0)),
+                        ("limit", fmt_num(res.limit_db, 1)),
+                        ("margin", fmt_num(res.margin_db, 1)),
+                        ("risk", txt(res.risk.i18n_key(), res.risk.label())),
+                        ("len", fmt_qty(res.screening_length_m, QuantityKind::Length)),
+                    ],
+                );
+                for r in res.recommendations {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&r);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.plant.aiv.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = LetdownNoiseInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.aiv = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.aiv.clone();
+    }
+}
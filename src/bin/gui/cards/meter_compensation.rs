@@ -0,0 +1,250 @@
+//! 증기 유량계 밀도 보정 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button, site_atmosphere,
+};
+use eframe::egui;
+use rfd::FileDialog;
+use std::fs;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, MeterCompensationInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::meter_compensation::{self, MeterPrinciple, SteamCondition},
+};
+
+#[derive(Default)]
+pub(crate) struct MeterCompensationCard {
+    inputs: MeterCompensationInputs,
+    result: Option<String>,
+}
+
+impl MeterCompensationCard {
+    /// 유량계 설계 조건. 온도가 0이면 건포화 증기로 본다.
+    fn design_condition(&self, atm: f64) -> SteamCondition {
+        SteamCondition {
+            pressure_bar_abs: self.inputs.design_p_barg + atm,
+            temperature_c: (self.inputs.design_t_c > 0.0).then_some(self.inputs.design_t_c),
+        }
+    }
+}
+
+impl CardWidget for MeterCompensationCard {
+    fn id(&self) -> CardId {
+        CardId::MeterCompensation
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.mcomp.heading", "Steam meter density compensation"),
+            &txt(
+                "gui.plant.mcomp.tip",
+                "Corrects the indicated flow of a DP or vortex steam meter for actual vs design pressure/temperature with IF97 density",
+            ),
+        );
+        egui::Grid::new("plant_meter_comp_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.mcomp.meter", "Meter type"),
+                    &txt(
+                        "gui.plant.mcomp.meter_tip",
+                        "DP meters: mass flow ∝ √ρ. Vortex meters measure volume: mass flow ∝ ρ",
+                    ),
+                );
+                egui::ComboBox::from_id_source("plant_mcomp_meter")
+                    .selected_text(txt(self.inputs.meter.key(), self.inputs.meter.name()))
+                    .show_ui(ui, |ui| {
+                        for m in MeterPrinciple::ALL {
+                            ui.selectable_value(&mut self.inputs.meter, m, txt(m.key(), m.name()));
+                        }
+                    });
+                ui.end_row();
+
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.mcomp.design", "Design P [bar g] / T [°C]"),
+                    &txt(
+                        "gui.plant.mcomp.design_tip",
+                        "Conditions the meter was configured for. T = 0 means dry saturated steam",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.design_p_barg)
+                            .speed(0.1)
+                            .clamp_range(0.0..=200.0),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.design_t_c)
+                            .speed(1.0)
+                            .clamp_range(0.0..=800.0),
+                    );
+                });
+                ui.end_row();
+
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.mcomp.actual", "Actual P [bar g] / T [°C]"),
+                    &txt(
+                        "gui.plant.mcomp.actual_tip",
+                        "Current line conditions. T = 0 or below saturation means dry saturated steam",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.p_barg)
+                            .speed(0.1)
+                            .clamp_range(0.0..=200.0),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.t_c)
+                            .speed(1.0)
+                            .clamp_range(0.0..=800.0),
+                    );
+                });
+                ui.end_row();
+
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.mcomp.flow", "Indicated flow [kg/h]"),
+                    &txt(
+                        "gui.plant.mcomp.flow_tip",
+                        "Mass flow shown by the meter with its design density",
+                    ),
+                );
+                ui.add(
+                    egui::DragValue::new(&mut self.inputs.flow_kg_h)
+                        .speed(10.0)
+                        .clamp_range(0.0..=f64::MAX),
+                );
+                ui.end_row();
+            });
+        let atm = site_atmosphere().bar_abs();
+        let design = self.design_condition(atm);
+        ui.horizontal(|ui| {
+            if run_button(ui, txt("gui.plant.mcomp.run", "Compensate")) {
+                self.compute(tr);
+            }
+            if ui
+                .small_button(txt("gui.plant.mcomp.load_csv", "Historian CSV…"))
+                .on_hover_text(txt(
+                    "gui.plant.mcomp.load_csv_tip",
+                    "timestamp,flow_kg_h,pressure_bar_g[,temperature_c]. Every row is corrected and saved to a new CSV",
+                ))
+                .clicked()
+            {
+                if let Some(path) = FileDialog::new().add_filter("CSV", &["csv"]).pick_file() {
+                    let series = fs::read_to_string(&path)
+                        .map_err(|e| error_text(tr, e))
+                        .and_then(|text| {
+                            meter_compensation::parse_historian_csv(&text, atm)
+                                .and_then(|rows| {
+                                    meter_compensation::compensate_series(self.inputs.meter, design, &rows)
+                                })
+                                .map_err(|e| error_text(tr, e))
+                        });
+                    self.result = Some(match series {
+                        Ok(series) => {
+                            let mut out = fill_template(
+                                &txt(
+                                    "gui.plant.mcomp.series_result",
+                                    "{n} rows: mean indicated {mi} kg/h → corrected {m} kg/h, factor {kmin}–{kmax}",
+                                ),
+                                &[
+                                    ("n", series.rows.len().to_string()),
+                                    ("mi", fmt_num(series.mean_indicated_flow_kg_per_h, 1)),
+                                    ("m", fmt_num(series.mean_corrected_flow_kg_per_h, 1)),
+                                    ("kmin", fmt_num(series.min_correction_factor, 4)),
+                                    ("kmax", fmt_num(series.max_correction_factor, 4)),
+                                ],
+                            );
+                            if let Some(out_path) = FileDialog::new()
+                                .add_filter("CSV", &["csv"])
+                                .set_file_name("compensated.csv")
+                                .save_file()
+                            {
+                                out.push('\n');
+                                out.push_str(&match fs::write(
+                                    &out_path,
+                                    meter_compensation::compensated_series_csv(&series),
+                                ) {
+                                    Ok(()) => fill_template(
+                                        &txt("gui.export.saved", "Saved: {path}"),
+                                        &[("path", out_path.display().to_string())],
+                                    ),
+                                    Err(e) => error_text(tr, e),
+                                });
+                            }
+                            push_warnings(&mut out, tr, &series.warnings);
+                            out
+                        }
+                        Err(e) => e,
+                    });
+                }
+            }
+        });
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let atm = site_atmosphere().bar_abs();
+        let design = self.design_condition(atm);
+        let actual = SteamCondition {
+            pressure_bar_abs: self.inputs.p_barg + atm,
+            temperature_c: (self.inputs.t_c > 0.0).then_some(self.inputs.t_c),
+        };
+        self.result = Some(
+            match meter_compensation::compensate_flow(
+                self.inputs.meter,
+                design,
+                actual,
+                self.inputs.flow_kg_h,
+            ) {
+                Ok(r) => {
+                    let mut out = fill_template(
+                        &txt(
+                            "gui.plant.mcomp.result",
+                            "ρ design {rho_d} kg/m³, ρ actual {rho} kg/m³ at {t} °C\nCorrection factor {k}\nCorrected flow {m} kg/h (indicated {mi} kg/h)",
+                        ),
+                        &[
+                            ("rho_d", fmt_num(r.design.density_kg_per_m3, 4)),
+                            ("rho", fmt_num(r.actual.density_kg_per_m3, 4)),
+                            ("t", fmt_qty(r.actual.temperature_c, QuantityKind::Temperature)),
+                            ("k", fmt_num(r.correction_factor, 4)),
+                            ("m", fmt_num(r.corrected_flow_kg_per_h, 1)),
+                            ("mi", fmt_num(r.indicated_flow_kg_per_h, 1)),
+                        ],
+                    );
+                    push_warnings(&mut out, tr, &r.warnings);
+                    out
+                }
+                Err(e) => error_text(tr, e),
+            },
+        );
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = MeterCompensationInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.meter_comp = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.meter_comp.clone();
+    }
+}
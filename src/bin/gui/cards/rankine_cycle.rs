@@ -0,0 +1,194 @@
+//! 랭킨 사이클 열소비율 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, label_with_tip, quantity_input, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, RankineCycleInputs},
+    i18n,
+    quantity::QuantityKind,
+    quantity_arg::FieldQuantity,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct RankineCycleCard {
+    inputs: RankineCycleInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for RankineCycleCard {
+    fn id(&self) -> CardId {
+        CardId::RankineCycle
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let fwh_labels = [
+            txt("gui.boiler.cycle.fwh_none", "None"),
+            txt("gui.boiler.cycle.fwh_open", "Open (deaerator)"),
+            txt(
+                "gui.boiler.cycle.fwh_closed",
+                "Closed (drains to condenser)",
+            ),
+        ];
+        egui::Grid::new("boiler_cycle_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.cycle.throttle", "Throttle [bar(a)] / [°C]"),
+                    &txt("gui.boiler.cycle.throttle_tip", "Main steam pressure and temperature at the turbine stop valve"),
+                );
+                ui.horizontal(|ui| {
+                    quantity_input(ui, &txt, "cycle_throttle_p", &mut self.inputs.throttle_p_bar_abs, FieldQuantity::PressureBarAbs, 1.0);
+                    quantity_input(ui, &txt, "cycle_throttle_t", &mut self.inputs.throttle_t_c, FieldQuantity::TemperatureC, 1.0);
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.cycle.condenser", "Condenser pressure [bar(a)]"),
+                    &txt("gui.boiler.cycle.condenser_tip", "Turbine exhaust (condenser) absolute pressure"),
+                );
+                quantity_input(ui, &txt, "cycle_condenser_p", &mut self.inputs.condenser_p_bar_abs, FieldQuantity::PressureBarAbs, 0.005);
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.cycle.eff", "Turbine / pump efficiency"),
+                    &txt("gui.boiler.cycle.eff_tip", "Isentropic efficiencies (0–1)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.turbine_eff).speed(0.01).clamp_range(0.01..=1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.pump_eff).speed(0.01).clamp_range(0.01..=1.0));
+                });
+                ui.end_row();
+                ui.checkbox(&mut self.inputs.reheat, txt("gui.boiler.cycle.reheat", "Reheat [bar(a)] / [°C]"))
+                    .on_hover_text(txt("gui.boiler.cycle.reheat_tip", "HP exhaust pressure and hot reheat temperature"));
+                ui.add_enabled_ui(self.inputs.reheat, |ui| {
+                    ui.horizontal(|ui| {
+                        quantity_input(ui, &txt, "cycle_reheat_p", &mut self.inputs.reheat_p_bar_abs, FieldQuantity::PressureBarAbs, 0.5);
+                        quantity_input(ui, &txt, "cycle_reheat_t", &mut self.inputs.reheat_t_c, FieldQuantity::TemperatureC, 1.0);
+                    });
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.cycle.fwh", "Feedwater heater"),
+                    &txt("gui.boiler.cycle.fwh_tip", "Extraction pressure [bar(a)] and terminal temperature difference [K] for a closed heater"),
+                );
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source("cycle_fwh_mode")
+                        .selected_text(fwh_labels[self.inputs.fwh_mode].clone())
+                        .show_ui(ui, |ui| {
+                            for (i, label) in fwh_labels.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.fwh_mode, i, label.as_str());
+                            }
+                        });
+                    if self.inputs.fwh_mode > 0 {
+                        quantity_input(ui, &txt, "cycle_fwh_p", &mut self.inputs.fwh_p_bar_abs, FieldQuantity::PressureBarAbs, 0.1);
+                    }
+                    if self.inputs.fwh_mode == 2 {
+                        ui.label("TTD");
+                        ui.add(egui::DragValue::new(&mut self.inputs.fwh_ttd_k).speed(0.1));
+                    }
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.boiler.cycle.flow", "Main steam flow [kg/h]"),
+                    &txt("gui.boiler.cycle.flow_tip", "Used for net power output (0 = skip)"),
+                );
+                quantity_input(ui, &txt, "cycle_steam_flow", &mut self.inputs.steam_flow_kg_h, FieldQuantity::MassFlowKgPerH, 100.0);
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.boiler.cycle.run", "Calculate heat rate")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::cycle::RankineCycleInput {
+            throttle_pressure_bar_abs: self.inputs.throttle_p_bar_abs,
+            throttle_temp_c: self.inputs.throttle_t_c,
+            condenser_pressure_bar_abs: self.inputs.condenser_p_bar_abs,
+            turbine_efficiency: self.inputs.turbine_eff,
+            pump_efficiency: self.inputs.pump_eff,
+            reheat: self.inputs.reheat.then_some(steam::cycle::ReheatInput {
+                pressure_bar_abs: self.inputs.reheat_p_bar_abs,
+                temp_c: self.inputs.reheat_t_c,
+            }),
+            feedwater_heater: match self.inputs.fwh_mode {
+                1 => Some(steam::cycle::FeedwaterHeaterKind::Open),
+                2 => Some(steam::cycle::FeedwaterHeaterKind::Closed {
+                    terminal_temp_difference_k: self.inputs.fwh_ttd_k,
+                }),
+                _ => None,
+            }
+            .map(|kind| steam::cycle::FeedwaterHeaterInput {
+                extraction_pressure_bar_abs: self.inputs.fwh_p_bar_abs,
+                kind,
+            }),
+            steam_flow_kg_per_h: (self.inputs.steam_flow_kg_h > 0.0)
+                .then_some(self.inputs.steam_flow_kg_h),
+        };
+        self.result = Some(match steam::cycle::compute_rankine_cycle(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.cycle.result",
+                        "Efficiency {eta}%, heat rate {hr} kJ/kWh, steam rate {sr} kg/kWh\nTurbine {wt} kJ/kg, pumps {wp} kJ/kg, heat input {q} kJ/kg\nExhaust quality {x}, extraction {y}%, feedwater {tfw} °C",
+                    ),
+                    &[
+                        ("eta", fmt_num(res.thermal_efficiency * 100.0, 2)),
+                        ("hr", fmt_num(res.heat_rate_kj_per_kwh, 0)),
+                        ("sr", fmt_num(res.steam_rate_kg_per_kwh, 2)),
+                        ("wt", fmt_qty(res.turbine_work_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                        ("wp", fmt_qty(res.pump_work_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                        ("q", fmt_qty(res.heat_input_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                        ("x", fmt_num(res.exhaust_quality, 3)),
+                        ("y", fmt_num(res.extraction_fraction * 100.0, 1)),
+                        ("tfw", fmt_qty(res.feedwater_temp_c, QuantityKind::Temperature)),
+                    ],
+                );
+                if let Some(kw) = res.net_power_kw {
+                    out.push_str(&fill_template(
+                        &txt("gui.boiler.cycle.power", "\nNet output {mw} MW"),
+                        &[("mw", fmt_num(kw / 1000.0, 2))],
+                    ));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.boiler.cycle.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = RankineCycleInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.boiler.cycle = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.boiler.cycle.clone();
+    }
+}
@@ -0,0 +1,222 @@
+//! 응축수 회수 배관 구경 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, ReturnLineInputs},
+    condensate_recovery::{self, ReturnLineKind},
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct ReturnLineCard {
+    inputs: ReturnLineInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for ReturnLineCard {
+    fn id(&self) -> CardId {
+        CardId::ReturnLine
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.condensate.line.heading", "Return line sizing"),
+            &txt(
+                "gui.condensate.line.tip",
+                "Flash fraction at receiver pressure, line volume flow and recommended size from a velocity limit",
+            ),
+        );
+        egui::Grid::new("condensate_return_line_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.kind_label", "Line type"),
+                    &txt(
+                        "gui.condensate.line.kind_tip",
+                        "Trap discharge carries flash steam (two-phase); pumped and gravity lines carry liquid after the flash is separated",
+                    ),
+                );
+                let kinds = [
+                    (
+                        ReturnLineKind::TrapDischarge,
+                        txt("gui.condensate.line.kind.trap", "Trap discharge (two-phase)"),
+                    ),
+                    (
+                        ReturnLineKind::Pumped,
+                        txt("gui.condensate.line.kind.pumped", "Pumped return"),
+                    ),
+                    (
+                        ReturnLineKind::Gravity,
+                        txt("gui.condensate.line.kind.gravity", "Gravity return"),
+                    ),
+                ];
+                let before = self.inputs.kind;
+                let selected_label = kinds
+                    .iter()
+                    .find(|(k, _)| *k == self.inputs.kind)
+                    .map(|(_, l)| l.clone())
+                    .unwrap_or_default();
+                egui::ComboBox::from_id_source("condensate_line_kind")
+                    .selected_text(selected_label)
+                    .show_ui(ui, |ui| {
+                        for (k, label) in &kinds {
+                            ui.selectable_value(&mut self.inputs.kind, *k, label.clone());
+                        }
+                    });
+                if before != self.inputs.kind {
+                    self.inputs.max_velocity =
+                        self.inputs.kind.default_velocity_limit_m_per_s();
+                }
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.flow", "Condensate flow [kg/h]"),
+                    &txt("gui.condensate.line.flow_tip", "Condensate load entering the line"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.flow_kg_h).speed(10.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.source_p", "Source pressure [bar(a)]"),
+                    &txt(
+                        "gui.condensate.line.source_p_tip",
+                        "Steam space pressure upstream of the trap (condensate assumed saturated)",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.source_p_bar_abs).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.receiver_p", "Receiver pressure [bar(a)]"),
+                    &txt(
+                        "gui.condensate.line.receiver_p_tip",
+                        "Receiver / flash vessel pressure where the condensate flashes",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.receiver_p_bar_abs).speed(0.05));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.length", "Line length [m]"),
+                    &txt("gui.condensate.line.length_tip", "Equivalent length including fittings"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.length_m).speed(1.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.roughness", "Roughness [mm]"),
+                    &txt("gui.condensate.line.roughness_tip", "Absolute pipe roughness"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.roughness_mm).speed(0.005));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.lift", "Lift [m]"),
+                    &txt(
+                        "gui.condensate.line.lift_tip",
+                        "Line end elevation minus start elevation (positive for a lift after the trap)",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.lift_m).speed(0.5));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.line.max_velocity", "Velocity limit [m/s]"),
+                    &txt(
+                        "gui.condensate.line.max_velocity_tip",
+                        "Allowable velocity; reset to the type default when the line type changes",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.max_velocity).speed(0.1));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.condensate.line.run", "Size return line")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = condensate_recovery::ReturnLineInput {
+            kind: self.inputs.kind,
+            condensate_flow_kg_per_h: self.inputs.flow_kg_h,
+            source_pressure_bar_abs: self.inputs.source_p_bar_abs,
+            receiver_pressure_bar_abs: self.inputs.receiver_p_bar_abs,
+            length_m: self.inputs.length_m,
+            roughness_m: self.inputs.roughness_mm / 1000.0,
+            lift_m: self.inputs.lift_m,
+            max_velocity_m_per_s: Some(self.inputs.max_velocity),
+        };
+        self.result = Some(match condensate_recovery::size_return_line(input) {
+            Ok(res) => {
+                let size = match res.recommended_dn {
+                    Some(dn) => format!("DN{dn}"),
+                    None => txt("gui.condensate.line.oversize", "above DN300"),
+                };
+                let mut out = fill_template(
+                    &txt(
+                        "gui.condensate.line.result",
+                        "Flash {flash}% ({flash_kg} kg/h), line flow {q} m³/h, min ID {dmin} mm → {size} (ID {d} mm), velocity {v} m/s, ΔP {dp} bar",
+                    ),
+                    &[
+                        ("flash", fmt_num(res.flash_fraction * 100.0, 1)),
+                        ("flash_kg", fmt_num(res.flash_steam_kg_per_h, 1)),
+                        ("q", fmt_num(res.volumetric_flow_m3_per_h, 2)),
+                        ("dmin", fmt_num(res.min_inner_diameter_m * 1000.0, 1)),
+                        ("size", size),
+                        ("d", fmt_num(res.recommended_inner_diameter_m * 1000.0, 1)),
+                        ("v", fmt_qty(res.velocity_m_per_s, QuantityKind::Velocity)),
+                        ("dp", fmt_qty(res.pressure_drop_bar, QuantityKind::Pressure)),
+                    ],
+                );
+                if res.elevation_drop_bar != 0.0 {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt("gui.condensate.line.lift_result", "Lift static head {dz} bar, total {total} bar"),
+                        &[
+                            ("dz", fmt_qty(res.elevation_drop_bar, QuantityKind::Pressure)),
+                            ("total", fmt_num(res.pressure_drop_bar + res.elevation_drop_bar, 4)),
+                        ],
+                    ));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.condensate.line.error", "Error: {e}"),
+                &[("e", e.to_string())],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = ReturnLineInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.condensate.return_line = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.condensate.return_line.clone();
+    }
+}
@@ -0,0 +1,158 @@
+//! 핫웰/플래시 탱크 펌프 NPSH 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, SaturatedNpshInputs},
+    cooling::pump_npsh,
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct SaturatedNpshCard {
+    inputs: SaturatedNpshInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for SaturatedNpshCard {
+    fn id(&self) -> CardId {
+        CardId::SaturatedNpsh
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.cooling.sat_npsh.heading", "Hotwell / Flash Tank Pump NPSH"),
+            &txt(
+                "gui.cooling.sat_npsh.tip",
+                "Saturated suction: NPSHa is submergence minus losses; checks the transient loss when vessel pressure decays",
+            ),
+        );
+        egui::Grid::new("sat_npsh_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.sat_npsh.vessel_p", "Vessel pressure [bar(a)]"),
+                    &txt(
+                        "gui.cooling.sat_npsh.vessel_p_tip",
+                        "Hotwell / flash tank pressure (liquid at saturation)",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.vessel_p_bar_abs).speed(0.01));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.sat_npsh.submergence", "Submergence [m]"),
+                    &txt(
+                        "gui.cooling.sat_npsh.submergence_tip",
+                        "Liquid level above pump centreline",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.submergence_m).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.sat_npsh.friction_npshr", "Friction loss / NPSHr [m]"),
+                    &txt(
+                        "gui.cooling.sat_npsh.friction_npshr_tip",
+                        "Suction line friction head and manufacturer NPSHr",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.friction_m).speed(0.05));
+                    ui.add(egui::DragValue::new(&mut self.inputs.required_m).speed(0.1));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.sat_npsh.line", "Suction line length [m] / velocity [m/s]"),
+                    &txt(
+                        "gui.cooling.sat_npsh.line_tip",
+                        "Sets the transit time during which the pump still sees the old saturation temperature",
+                    ),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.line_length_m).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.velocity_m_s).speed(0.05));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.cooling.sat_npsh.decay", "Pressure decay rate [bar/s]"),
+                    &txt(
+                        "gui.cooling.sat_npsh.decay_tip",
+                        "Vessel pressure fall rate after a load rejection or extraction trip",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.decay_bar_s).speed(0.001));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.cooling.sat_npsh.run", "Check saturated suction")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = pump_npsh::SaturatedSuctionInput {
+            vessel_pressure_bar_abs: self.inputs.vessel_p_bar_abs,
+            submergence_m: self.inputs.submergence_m,
+            friction_loss_m: self.inputs.friction_m,
+            npshr_m: self.inputs.required_m,
+            suction_line_length_m: self.inputs.line_length_m,
+            suction_velocity_m_per_s: self.inputs.velocity_m_s,
+            pressure_decay_bar_per_s: self.inputs.decay_bar_s,
+        };
+        self.result = Some(match pump_npsh::compute_saturated_suction_npsh(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.cooling.sat_npsh.result",
+                        "NPSHa steady {npsha} m (margin {m}), transit {t} s, transient loss {loss} m → NPSHa {npsha_t} m (margin {m_t}), min submergence {smin} m",
+                    ),
+                    &[
+                        ("npsha", fmt_qty(res.npsha_steady_m, QuantityKind::Length)),
+                        ("m", fmt_num(res.margin_ratio_steady, 2)),
+                        ("t", fmt_num(res.transit_time_s, 1)),
+                        ("loss", fmt_qty(res.transient_loss_m, QuantityKind::Length)),
+                        ("npsha_t", fmt_qty(res.npsha_transient_m, QuantityKind::Length)),
+                        ("m_t", fmt_num(res.margin_ratio_transient, 2)),
+                        ("smin", fmt_qty(res.min_submergence_m, QuantityKind::Length)),
+                    ],
+                );
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.cooling.sat_npsh.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = SaturatedNpshInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.cooling.saturated_npsh = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.cooling.saturated_npsh.clone();
+    }
+}
@@ -0,0 +1,155 @@
+//! 직접 증기 주입(스파저) 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, SpargerInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::{self, critical_flow},
+};
+
+#[derive(Default)]
+pub(crate) struct SpargerCard {
+    inputs: SpargerInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for SpargerCard {
+    fn id(&self) -> CardId {
+        CardId::Sparger
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.condensate.sparger.heading", "Direct steam injection (sparger)"),
+            &txt(
+                "gui.condensate.sparger.tip",
+                "Steam flow to heat a water stream, dilution, and hammer / blow-through checks at the injection holes",
+            ),
+        );
+        egui::Grid::new("condensate_sparger_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.sparger.water", "Water flow [kg/h]"),
+                    &txt("gui.condensate.sparger.water_tip", "Water stream to be heated"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.water_kg_h).speed(100.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.sparger.temps", "Inlet / target [°C]"),
+                    &txt("gui.condensate.sparger.temps_tip", "Water inlet and outlet temperatures"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.inlet_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.target_c).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.sparger.liquid", "Liquid surface [bar(a)] / submergence [m]"),
+                    &txt("gui.condensate.sparger.liquid_tip", "Pressure above the liquid and depth of the sparger (0 for in-line injection)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.liquid_p_bar_abs).speed(0.01));
+                    ui.add(egui::DragValue::new(&mut self.inputs.submergence_m).speed(0.1));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.sparger.steam_p", "Steam pressure [bar(a)], saturated"),
+                    &txt("gui.condensate.sparger.steam_p_tip", "Steam pressure upstream of the injection holes"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.steam_p_bar_abs).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.sparger.holes", "Hole Ø [mm] / count / Cd"),
+                    &txt("gui.condensate.sparger.holes_tip", "Injection hole diameter, number of holes and discharge coefficient"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.hole_mm).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.hole_count).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cd).speed(0.01).clamp_range(0.1..=1.0));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.condensate.sparger.run", "Calculate injection")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::sparger::SpargerInput {
+            water_flow_kg_per_h: self.inputs.water_kg_h,
+            water_inlet_temp_c: self.inputs.inlet_c,
+            target_temp_c: self.inputs.target_c,
+            liquid_pressure_bar_abs: self.inputs.liquid_p_bar_abs,
+            submergence_m: self.inputs.submergence_m,
+            steam_pressure_bar_abs: self.inputs.steam_p_bar_abs,
+            steam_state: critical_flow::SteamStagnation::Quality(1.0),
+            hole_diameter_m: self.inputs.hole_mm / 1000.0,
+            hole_count: self.inputs.hole_count,
+            discharge_coefficient: self.inputs.cd,
+        };
+        self.result = Some(match steam::sparger::compute_sparger(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.condensate.sparger.result",
+                        "Steam {ms} kg/h, outlet {out} kg/h (dilution {dil}%), local {p} bar(a) / Tsat {tsat} °C, subcooling {sub} K, hole flux {g} kg/m²s (capacity {cap} kg/h), min submergence {hmin} m",
+                    ),
+                    &[
+                        ("ms", fmt_num(res.steam_flow_kg_per_h, 1)),
+                        ("out", fmt_num(res.outlet_flow_kg_per_h, 0)),
+                        ("dil", fmt_num(res.dilution_fraction * 100.0, 2)),
+                        ("p", fmt_qty(res.local_pressure_bar_abs, QuantityKind::Pressure)),
+                        ("tsat", fmt_qty(res.local_saturation_temp_c, QuantityKind::Temperature)),
+                        ("sub", fmt_qty(res.subcooling_k, QuantityKind::TemperatureDifference)),
+                        ("g", fmt_num(res.hole_mass_flux_kg_per_m2_s, 0)),
+                        ("cap", fmt_num(res.hole_capacity_kg_per_h, 0)),
+                        ("hmin", fmt_qty(res.min_submergence_m, QuantityKind::Length)),
+                    ],
+                );
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.condensate.sparger.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = SpargerInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.condensate.sparger = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.condensate.sparger.clone();
+    }
+}
@@ -0,0 +1,200 @@
+//! 탱크 가열 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, TankHeatingInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam,
+};
+
+#[derive(Default)]
+pub(crate) struct TankHeatingCard {
+    inputs: TankHeatingInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for TankHeatingCard {
+    fn id(&self) -> CardId {
+        CardId::TankHeating
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.condensate.tank.heading", "Tank heat-up"),
+            &txt(
+                "gui.condensate.tank.tip",
+                "Steam coil area or direct injection rate to heat a tank in a given time, with wall heat losses",
+            ),
+        );
+        egui::Grid::new("condensate_tank_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.method", "Heating method"),
+                    &txt("gui.condensate.tank.method_tip", "Indirect coil (condensate returned) or direct steam injection"),
+                );
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.inputs.injection, false, txt("gui.condensate.tank.coil", "Steam coil"));
+                    ui.selectable_value(&mut self.inputs.injection, true, txt("gui.condensate.tank.injection", "Direct injection"));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.liquid", "Liquid mass [kg] / cp [kJ/kgK]"),
+                    &txt("gui.condensate.tank.liquid_tip", "Initial liquid inventory and specific heat"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.mass_kg).speed(100.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cp_kj_kgk).speed(0.01));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.temps", "Initial / target [°C]"),
+                    &txt("gui.condensate.tank.temps_tip", "Target must stay below the steam saturation temperature"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.initial_temp_c).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.target_temp_c).speed(0.5));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.time", "Heat-up time [h]"),
+                    &txt("gui.condensate.tank.time_tip", "Required heat-up time used to size the coil or injection rate"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.time_h).speed(0.1));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.steam_p", "Steam pressure [bar(a)]"),
+                    &txt("gui.condensate.tank.steam_p_tip", "Saturated steam pressure at the coil / sparger"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.steam_p_bar_abs).speed(0.1));
+                ui.end_row();
+                if !self.inputs.injection {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.condensate.tank.coil_u", "Coil U [W/m²K]"),
+                        &txt("gui.condensate.tank.coil_u_tip", "Overall coefficient, steam to liquid"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.coil_u_w_m2k).speed(10.0));
+                    ui.end_row();
+                }
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.wall", "Wall area [m²] / U [W/m²K]"),
+                    &txt("gui.condensate.tank.wall_tip", "Heat loss surface and coefficient (insulated ≈ 0.5–1, bare ≈ 8–12)"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.wall_area_m2).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.wall_u_w_m2k).speed(0.1));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.condensate.tank.ambient", "Ambient [°C]"),
+                    &txt("gui.condensate.tank.ambient_tip", "Surrounding air temperature"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.ambient_c).speed(0.5));
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.condensate.tank.run", "Calculate heat-up")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = steam::tank_heating::TankHeatingInput {
+            liquid_mass_kg: self.inputs.mass_kg,
+            specific_heat_kj_per_kgk: self.inputs.cp_kj_kgk,
+            initial_temp_c: self.inputs.initial_temp_c,
+            target_temp_c: self.inputs.target_temp_c,
+            heat_up_time_h: Some(self.inputs.time_h),
+            steam_pressure_bar_abs: self.inputs.steam_p_bar_abs,
+            method: if self.inputs.injection {
+                steam::tank_heating::HeatingMethod::DirectInjection {
+                    steam_flow_kg_per_h: None,
+                }
+            } else {
+                steam::tank_heating::HeatingMethod::Coil {
+                    u_w_m2k: self.inputs.coil_u_w_m2k,
+                    area_m2: None,
+                }
+            },
+            wall_area_m2: self.inputs.wall_area_m2,
+            wall_loss_u_w_m2k: self.inputs.wall_u_w_m2k,
+            ambient_temp_c: self.inputs.ambient_c,
+        };
+        self.result = Some(match steam::tank_heating::compute_tank_heating(input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.condensate.tank.result",
+                        "Steam {steam} kg total (mean {mean} kg/h, peak {peak} kg/h), heat-up {q} kWh, mean loss {loss} kW; holding {hold} kW ({hold_steam} kg/h)",
+                    ),
+                    &[
+                        ("steam", fmt_qty(res.total_steam_kg, QuantityKind::Mass)),
+                        ("mean", fmt_num(res.mean_steam_kg_per_h, 1)),
+                        ("peak", fmt_num(res.peak_steam_kg_per_h, 1)),
+                        ("q", fmt_num(res.heat_up_energy_kwh, 1)),
+                        ("loss", fmt_num(res.mean_heat_loss_kw, 2)),
+                        ("hold", fmt_num(res.holding_loss_kw, 2)),
+                        ("hold_steam", fmt_num(res.holding_steam_kg_per_h, 2)),
+                    ],
+                );
+                if let (Some(area), Some(lmtd)) = (res.coil_area_m2, res.effective_lmtd_k) {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt("gui.condensate.tank.coil_result", "Coil area {a} m² (effective LMTD {lmtd} K)"),
+                        &[("a", fmt_num(area, 2)), ("lmtd", fmt_num(lmtd, 1))],
+                    ));
+                } else {
+                    out.push('\n');
+                    out.push_str(&fill_template(
+                        &txt("gui.condensate.tank.injection_result", "Final liquid mass {m} kg"),
+                        &[("m", fmt_qty(res.final_liquid_mass_kg, QuantityKind::Mass))],
+                    ));
+                }
+                for w in res.warnings {
+                    out.push_str("\n⚠ ");
+                    out.push_str(&w);
+                }
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.condensate.tank.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = TankHeatingInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.condensate.tank = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.condensate.tank.clone();
+    }
+}
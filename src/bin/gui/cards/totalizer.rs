@@ -0,0 +1,157 @@
+//! 히스토리안 적산 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button, site_atmosphere,
+};
+use eframe::egui;
+use rfd::FileDialog;
+use std::fs;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, TotalizerInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::totalizer,
+};
+
+#[derive(Default)]
+pub(crate) struct TotalizerCard {
+    inputs: TotalizerInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for TotalizerCard {
+    fn id(&self) -> CardId {
+        CardId::Totalizer
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.tot.heading", "Historian totalizer"),
+            &txt(
+                "gui.plant.tot.tip",
+                "Integrates a timestamped flow/pressure/temperature CSV into steam mass and energy with IF97 enthalpy, for MCR checks and energy accounting",
+            ),
+        );
+        egui::Grid::new("plant_totalizer_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.tot.ref_t", "Feedwater temperature [°C]"),
+                    &txt(
+                        "gui.plant.tot.ref_t_tip",
+                        "Energy is counted above saturated water at this temperature. 0 = IF97 datum (0.01 °C)",
+                    ),
+                );
+                ui.add(
+                    egui::DragValue::new(&mut self.inputs.ref_t_c)
+                        .speed(1.0)
+                        .clamp_range(0.0..=350.0),
+                );
+                ui.end_row();
+
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.tot.mcr", "Boiler MCR [kg/h]"),
+                    &txt(
+                        "gui.plant.tot.mcr_tip",
+                        "Maximum continuous rating for the load check. 0 = no check",
+                    ),
+                );
+                ui.add(
+                    egui::DragValue::new(&mut self.inputs.mcr_kg_h)
+                        .speed(100.0)
+                        .clamp_range(0.0..=f64::MAX),
+                );
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.plant.tot.load_csv", "Load historian CSV…")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        if let Some(path) = FileDialog::new().add_filter("CSV", &["csv"]).pick_file() {
+            let result = fs::read_to_string(&path)
+                .map_err(|e| error_text(tr, e))
+                .and_then(|text| {
+                    totalizer::parse_totalizer_csv(&text, site_atmosphere().bar_abs())
+                        .and_then(|rows| {
+                            totalizer::totalize(&totalizer::TotalizerInput {
+                                rows,
+                                reference_temp_c: (self.inputs.ref_t_c > 0.0).then_some(self.inputs.ref_t_c),
+                                mcr_kg_per_h: (self.inputs.mcr_kg_h > 0.0).then_some(self.inputs.mcr_kg_h),
+                            })
+                        })
+                        .map_err(|e| error_text(tr, e))
+                });
+            self.result = Some(match result {
+                Ok(r) => {
+                    let mut out = fill_template(
+                        &txt(
+                            "gui.plant.tot.result",
+                            "{n} rows over {hours} h: {mass} t steam, {energy} MWh (mean h {h} kJ/kg)\nFlow min/mean/max {fmin} / {fmean} / {fmax} kg/h\nPressure {pmin} / {pmean} / {pmax} bar(a), temperature {tmin} / {tmean} / {tmax} °C",
+                        ),
+                        &[
+                            ("n", r.rows.len().to_string()),
+                            ("hours", fmt_num(r.duration_h, 2)),
+                            ("mass", fmt_num(r.total_mass_t, 2)),
+                            ("energy", fmt_num(r.total_energy_mwh, 3)),
+                            ("h", fmt_qty(r.average_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                            ("fmin", fmt_num(r.flow_kg_per_h.min, 0)),
+                            ("fmean", fmt_num(r.flow_kg_per_h.mean, 0)),
+                            ("fmax", fmt_num(r.flow_kg_per_h.max, 0)),
+                            ("pmin", fmt_num(r.pressure_bar_abs.min, 2)),
+                            ("pmean", fmt_num(r.pressure_bar_abs.mean, 2)),
+                            ("pmax", fmt_num(r.pressure_bar_abs.max, 2)),
+                            ("tmin", fmt_num(r.temperature_c.min, 1)),
+                            ("tmean", fmt_num(r.temperature_c.mean, 1)),
+                            ("tmax", fmt_num(r.temperature_c.max, 1)),
+                        ],
+                    );
+                    if let Some(mcr) = r.mcr {
+                        out.push('\n');
+                        out.push_str(&fill_template(
+                            &txt(
+                                "gui.plant.tot.mcr_result",
+                                "Load vs MCR: mean {avg} %, peak {peak} %, {hours} h above MCR",
+                            ),
+                            &[
+                                ("avg", fmt_num(mcr.average_load_frac * 100.0, 1)),
+                                ("peak", fmt_num(mcr.peak_load_frac * 100.0, 1)),
+                                ("hours", fmt_num(mcr.hours_above_mcr, 2)),
+                            ],
+                        ));
+                    }
+                    push_warnings(&mut out, tr, &r.warnings);
+                    out
+                }
+                Err(e) => e,
+            });
+        }
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = TotalizerInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.totalizer = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.totalizer.clone();
+    }
+}
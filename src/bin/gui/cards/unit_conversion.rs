@@ -0,0 +1,195 @@
+//! 단위 변환 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    default_units_for_kind, error_text, fmt_num, label_with_tip, result_block, run_button, unit_label, unit_text_entry,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, UnitConversionInputs},
+    custom_units,
+    i18n,
+    quantity::QuantityKind,
+};
+
+#[derive(Default)]
+pub(crate) struct UnitConversionCard {
+    inputs: UnitConversionInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for UnitConversionCard {
+    fn id(&self) -> CardId {
+        CardId::UnitConversion
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        ui.vertical(|ui| {
+            egui::Grid::new("conv_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.unit.quantity.label", "Quantity"),
+                    &txt("gui.unit.quantity_tip", "Select the quantity type"),
+                );
+                    let before = self.inputs.kind;
+                    let q_options = vec![
+                        (
+                            QuantityKind::Temperature,
+                            txt("gui.unit.quantity.temperature", "Temperature"),
+                        ),
+                        (
+                            QuantityKind::TemperatureDifference,
+                            txt("gui.unit.quantity.temperature_diff", "ΔTemperature"),
+                        ),
+                        (
+                            QuantityKind::Pressure,
+                            txt("gui.unit.quantity.pressure", "Pressure"),
+                        ),
+                        (QuantityKind::Length, txt("gui.unit.quantity.length", "Length")),
+                        (QuantityKind::Area, txt("gui.unit.quantity.area", "Area")),
+                        (QuantityKind::Volume, txt("gui.unit.quantity.volume", "Volume")),
+                        (
+                            QuantityKind::Velocity,
+                            txt("gui.unit.quantity.velocity", "Velocity"),
+                        ),
+                        (QuantityKind::Mass, txt("gui.unit.quantity.mass", "Mass")),
+                        (
+                            QuantityKind::Viscosity,
+                            txt("gui.unit.quantity.viscosity", "Viscosity"),
+                        ),
+                        (QuantityKind::Energy, txt("gui.unit.quantity.energy", "Energy")),
+                        (
+                            QuantityKind::HeatTransferCoeff,
+                            txt(
+                                "gui.unit.quantity.heat_transfer_coeff",
+                                "Heat transfer coeff.",
+                            ),
+                        ),
+                        (
+                            QuantityKind::ThermalConductivity,
+                            txt(
+                                "gui.unit.quantity.thermal_conductivity",
+                                "Thermal conductivity",
+                            ),
+                        ),
+                        (
+                            QuantityKind::SpecificEnthalpy,
+                            txt("gui.unit.quantity.specific_enthalpy", "Specific enthalpy"),
+                        ),
+                    ];
+                    let selected_label = q_options
+                        .iter()
+                        .find(|(k, _)| *k == self.inputs.kind)
+                        .map(|(_, l)| l.clone())
+                        .unwrap_or_else(|| txt("gui.unit.quantity.label", "Quantity"));
+                    egui::ComboBox::from_id_source("conv_kind")
+                        .selected_text(selected_label)
+                        .show_ui(ui, |ui| {
+                            for (k, label) in &q_options {
+                                ui.selectable_value(&mut self.inputs.kind, *k, label.clone());
+                            }
+                        });
+                    if before != self.inputs.kind {
+                        let (f, t) = default_units_for_kind(self.inputs.kind);
+                        self.inputs.from = f.to_string();
+                        self.inputs.to = t.to_string();
+                        self.inputs.from_text.clear();
+                        self.inputs.to_text.clear();
+                    }
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.unit.value", "Value"),
+                        &txt("gui.unit.value_tip", "Enter the value to convert"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.value).speed(1.0));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.unit.from", "From unit"),
+                        &txt("gui.unit.from_tip", "Current unit of the value"),
+                    );
+                    let choices = custom_units::unit_choices(self.inputs.kind);
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source("conv_from")
+                            .selected_text(unit_label(&self.inputs.from, self.inputs.kind))
+                            .show_ui(ui, |ui| {
+                                for (label, code) in &choices {
+                                    ui.selectable_value(&mut self.inputs.from, code.clone(), label);
+                                }
+                            });
+                        if let Some(hint) = unit_text_entry(ui, &txt, &mut self.inputs.from_text, &mut self.inputs.from, &choices) {
+                            ui.colored_label(ui.visuals().warn_fg_color, hint);
+                        }
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.unit.to", "To unit"),
+                        &txt("gui.unit.to_tip", "Desired unit after conversion"),
+                    );
+                    let choices = custom_units::unit_choices(self.inputs.kind);
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source("conv_to")
+                            .selected_text(unit_label(&self.inputs.to, self.inputs.kind))
+                            .show_ui(ui, |ui| {
+                                for (label, code) in &choices {
+                                    ui.selectable_value(&mut self.inputs.to, code.clone(), label);
+                                }
+                            });
+                        if let Some(hint) = unit_text_entry(ui, &txt, &mut self.inputs.to_text, &mut self.inputs.to, &choices) {
+                            ui.colored_label(ui.visuals().warn_fg_color, hint);
+                        }
+                    });
+                    ui.end_row();
+                });
+            ui.add_space(8.0);
+            if run_button(ui, txt("gui.unit.run", "Convert")) {
+                self.compute(tr);
+            }
+            if let Some(res) = &self.result {
+                result_block(ui, &txt, res, false);
+            }
+        });
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        self.result = match custom_units::convert(
+            self.inputs.kind,
+            self.inputs.value,
+            self.inputs.from.trim(),
+            self.inputs.to.trim(),
+        ) {
+            Ok(v) => Some(format!("{} {}", fmt_num(v, 6), self.inputs.to.trim())),
+            Err(e) => Some(format!(
+                "{}: {}",
+                txt("gui.unit.error_prefix", "Error"),
+                error_text(tr, e)
+            )),
+        };
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = UnitConversionInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.unit_conv.quantity = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.unit_conv.quantity.clone();
+    }
+}
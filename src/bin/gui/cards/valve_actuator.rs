@@ -0,0 +1,204 @@
+//! 밸브 액추에이터 추력/토크 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, heading_with_tip, label_with_tip, push_warnings, quantity_input, result_block, run_button,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, ValveActuatorInputs},
+    i18n,
+    quantity_arg::FieldQuantity,
+    steam::valve_actuator,
+};
+
+#[derive(Default)]
+pub(crate) struct ValveActuatorCard {
+    inputs: ValveActuatorInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for ValveActuatorCard {
+    fn id(&self) -> CardId {
+        CardId::ValveActuator
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.valve.actuator.heading", "Actuator sizing"),
+            &txt(
+                "gui.valve.actuator.tip",
+                "Required thrust from shutoff unbalance force, seat load and packing friction; picks the smallest diaphragm/spring range or piston bore with margin",
+            ),
+        );
+        egui::Grid::new("valve_actuator_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.valve.actuator.dp", "Shutoff ΔP [bar]"),
+                    &txt("gui.valve.actuator.dp_tip", "Maximum differential the valve must close against"),
+                );
+                quantity_input(ui, &txt, "valve_actuator_dp", &mut self.inputs.dp_bar, FieldQuantity::PressureDifferenceBar, 0.5);
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.valve.actuator.geometry", "Port / balance seal / stem [mm]"),
+                    &txt("gui.valve.actuator.geometry_tip", "Seat port diameter, balance seal diameter (0 = unbalanced plug) and stem diameter"),
+                );
+                ui.horizontal(|ui| {
+                    quantity_input(ui, &txt, "valve_actuator_port", &mut self.inputs.port_mm, FieldQuantity::LengthMm, 1.0);
+                    quantity_input(ui, &txt, "valve_actuator_seal", &mut self.inputs.seal_mm, FieldQuantity::LengthMm, 1.0);
+                    quantity_input(ui, &txt, "valve_actuator_stem", &mut self.inputs.stem_mm, FieldQuantity::LengthMm, 0.5);
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.valve.actuator.class", "Leakage class / packing"),
+                    &txt("gui.valve.actuator.class_tip", "ANSI/FCI 70-2 class sets the seat load per port circumference; graphite packing has about 3× the friction of PTFE"),
+                );
+                ui.horizontal(|ui| {
+                    let classes = valve_actuator::LeakageClass::ALL;
+                    egui::ComboBox::from_id_source("valve_actuator_class")
+                        .selected_text(classes[self.inputs.class.min(classes.len() - 1)].label())
+                        .show_ui(ui, |ui| {
+                            for (i, c) in classes.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.class, i, c.label());
+                            }
+                        });
+                    let packings = valve_actuator::PackingType::ALL;
+                    egui::ComboBox::from_id_source("valve_actuator_packing")
+                        .selected_text(packings[self.inputs.packing.min(packings.len() - 1)].label())
+                        .show_ui(ui, |ui| {
+                            for (i, p) in packings.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.packing, i, p.label());
+                            }
+                        });
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.valve.actuator.kind", "Actuator"),
+                    &txt("gui.valve.actuator.kind_tip", "Fail-close diaphragms seat on the spring preload; fail-open diaphragms seat on supply minus the spring upper bench"),
+                );
+                let kinds = valve_actuator::ActuatorKind::ALL;
+                let kind = kinds[self.inputs.kind.min(kinds.len() - 1)];
+                egui::ComboBox::from_id_source("valve_actuator_kind")
+                    .selected_text(txt(kind.i18n_key(), kind.label()))
+                    .show_ui(ui, |ui| {
+                        for (i, k) in kinds.iter().enumerate() {
+                            ui.selectable_value(&mut self.inputs.kind, i, txt(k.i18n_key(), k.label()));
+                        }
+                    });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.valve.actuator.supply", "Supply [bar(g)] / margin"),
+                    &txt("gui.valve.actuator.supply_tip", "Instrument air supply pressure and required available/required thrust ratio"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.supply_bar).speed(0.1));
+                    ui.add(egui::DragValue::new(&mut self.inputs.margin).speed(0.05).clamp_range(1.0..=3.0));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.valve.actuator.run", "Size actuator")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let thrust_input = valve_actuator::ThrustInput {
+            shutoff_dp_bar: self.inputs.dp_bar,
+            port_diameter_m: self.inputs.port_mm / 1000.0,
+            balance_seal_diameter_m: (self.inputs.seal_mm > 0.0)
+                .then_some(self.inputs.seal_mm / 1000.0),
+            stem_diameter_m: self.inputs.stem_mm / 1000.0,
+            leakage_class: valve_actuator::LeakageClass::ALL[self.inputs.class.min(4)],
+            packing: valve_actuator::PackingType::ALL[self.inputs.packing.min(1)],
+        };
+        let outcome = valve_actuator::required_thrust(&thrust_input).and_then(|thrust| {
+            valve_actuator::select_actuator(&valve_actuator::ActuatorSelectionInput {
+                kind: valve_actuator::ActuatorKind::ALL[self.inputs.kind.min(2)],
+                required_thrust_n: thrust.required_thrust_n,
+                supply_pressure_bar_g: self.inputs.supply_bar,
+                margin: self.inputs.margin,
+            })
+            .map(|sel| (thrust, sel))
+        });
+        self.result = Some(match outcome {
+            Ok((thrust, sel)) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.valve.actuator.thrust",
+                        "Required thrust {total} kN = unbalance {ub} + seat {seat} + packing {pack} kN",
+                    ),
+                    &[
+                        ("total", fmt_num(thrust.required_thrust_n / 1000.0, 2)),
+                        ("ub", fmt_num(thrust.unbalance_force_n / 1000.0, 2)),
+                        ("seat", fmt_num(thrust.seat_load_n / 1000.0, 2)),
+                        ("pack", fmt_num(thrust.packing_friction_n / 1000.0, 2)),
+                    ],
+                );
+                out.push('\n');
+                out.push_str(&match (sel.piston_bore_mm, sel.spring_range_bar) {
+                    (Some(bore), _) => fill_template(
+                        &txt("gui.valve.actuator.piston", "Piston bore {bore} mm"),
+                        &[("bore", fmt_num(bore, 0))],
+                    ),
+                    (None, Some((lo, hi))) => fill_template(
+                        &txt(
+                            "gui.valve.actuator.diaphragm",
+                            "Diaphragm {area} cm², spring {lo}–{hi} bar",
+                        ),
+                        &[
+                            ("area", fmt_num(sel.effective_area_m2 * 1e4, 0)),
+                            ("lo", fmt_num(lo, 1)),
+                            ("hi", fmt_num(hi, 1)),
+                        ],
+                    ),
+                    (None, None) => String::new(),
+                });
+                out.push_str(&fill_template(
+                    &txt(
+                        "gui.valve.actuator.margin",
+                        ": available {avail} kN, margin {m}",
+                    ),
+                    &[
+                        ("avail", fmt_num(sel.available_thrust_n / 1000.0, 2)),
+                        ("m", fmt_num(sel.margin, 2)),
+                    ],
+                ));
+                push_warnings(&mut out, tr, &sel.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.valve.actuator.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = ValveActuatorInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.valves.actuator = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.valves.actuator.clone();
+    }
+}
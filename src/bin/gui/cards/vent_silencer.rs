@@ -0,0 +1,150 @@
+//! 증기 벤트/사일런서 카드.
+
+use super::{lookup, CardWidget};
+use crate::{
+    error_text, fill_template, fmt_num, fmt_qty, heading_with_tip, label_with_tip, push_warnings, result_block, run_button, site_atmosphere,
+};
+use eframe::egui;
+use steam_engineering_toolbox::{
+    card_inputs::{CardId, GuiInputs, VentSilencerInputs},
+    i18n,
+    quantity::QuantityKind,
+    steam::{critical_flow, vent_silencer},
+};
+
+#[derive(Default)]
+pub(crate) struct VentSilencerCard {
+    inputs: VentSilencerInputs,
+    result: Option<String>,
+}
+
+impl CardWidget for VentSilencerCard {
+    fn id(&self) -> CardId {
+        CardId::VentSilencer
+    }
+
+    fn render(&mut self, ui: &mut egui::Ui, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.vent.heading", "Steam vent / silencer sizing"),
+            &txt(
+                "gui.plant.vent.tip",
+                "Atmospheric steam vent or steam blow: choked vent valve flow, vent exit velocity, jet noise at a distance and the silencer attenuation needed to meet the limit",
+            ),
+        );
+        egui::Grid::new("plant_vent_grid")
+            .num_columns(2)
+            .spacing([12.0, 8.0])
+            .show(ui, |ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.vent.steam", "P0 [bar(a)] / T0 [°C]"),
+                    &txt("gui.plant.vent.steam_tip", "Steam conditions upstream of the vent valve; tick Saturated for dry saturated steam"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.p0_bar_abs).speed(0.1));
+                    ui.add_enabled(
+                        !self.inputs.saturated,
+                        egui::DragValue::new(&mut self.inputs.t0_c).speed(1.0),
+                    );
+                    ui.checkbox(&mut self.inputs.saturated, txt("gui.plant.vent.saturated", "Saturated"));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.vent.valve", "Valve throat [mm] / Cd"),
+                    &txt("gui.plant.vent.valve_tip", "Vent valve seat bore and discharge coefficient"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.throat_mm).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cd).speed(0.01).clamp_range(0.1..=1.0));
+                });
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.vent.pipe", "Vent pipe ID [mm]"),
+                    &txt("gui.plant.vent.pipe_tip", "Inner diameter of the vent stack outlet"),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.pipe_id_mm).speed(1.0));
+                ui.end_row();
+                label_with_tip(
+                    ui,
+                    &txt("gui.plant.vent.receiver", "Distance [m] / limit [dB(A)]"),
+                    &txt("gui.plant.vent.receiver_tip", "Distance to the assessment point and the permitted noise level there"),
+                );
+                ui.horizontal(|ui| {
+                    ui.add(egui::DragValue::new(&mut self.inputs.distance_m).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.limit_dba).speed(1.0));
+                });
+                ui.end_row();
+            });
+        if run_button(ui, txt("gui.plant.vent.run", "Estimate vent noise")) {
+            self.compute(tr);
+        }
+        if let Some(res) = &self.result {
+            result_block(ui, &txt, res, false);
+        }
+    }
+
+    fn compute(&mut self, tr: &i18n::Translator) {
+        let txt = lookup(tr);
+        let input = vent_silencer::VentInput {
+            upstream_pressure_bar_abs: self.inputs.p0_bar_abs,
+            stagnation: if self.inputs.saturated {
+                critical_flow::SteamStagnation::Quality(1.0)
+            } else {
+                critical_flow::SteamStagnation::Temperature(self.inputs.t0_c)
+            },
+            valve_throat_diameter_m: self.inputs.throat_mm / 1000.0,
+            discharge_coefficient: self.inputs.cd,
+            vent_pipe_id_m: self.inputs.pipe_id_mm / 1000.0,
+            atmospheric_pressure_bar_abs: site_atmosphere().bar_abs(),
+            distance_m: self.inputs.distance_m,
+            noise_limit_dba: self.inputs.limit_dba,
+        };
+        self.result = Some(match vent_silencer::vent_silencer(&input) {
+            Ok(res) => {
+                let mut out = fill_template(
+                    &txt(
+                        "gui.plant.vent.result",
+                        "Flow {flow} kg/h; exit {v} m/s (Mach {mach}, {p_exit} bar(a)); PWL {pwl} dB at {freq} Hz; {spl} dB(A) at {dist} m → silencer attenuation {att} dB",
+                    ),
+                    &[
+                        ("flow", fmt_num(res.mass_flow_kg_per_h, 0)),
+                        ("v", fmt_qty(res.exit_velocity_m_per_s, QuantityKind::Velocity)),
+                        ("mach", fmt_num(res.exit_mach, 2)),
+                        ("p_exit", fmt_qty(res.exit_pressure_bar_abs, QuantityKind::Pressure)),
+                        ("pwl", fmt_num(res.sound_power_db, 1)),
+                        ("freq", fmt_num(res.peak_frequency_hz, 0)),
+                        ("spl", fmt_num(res.sound_pressure_dba, 1)),
+                        ("dist", fmt_qty(self.inputs.distance_m, QuantityKind::Length)),
+                        ("att", fmt_num(res.required_attenuation_db, 0)),
+                    ],
+                );
+                push_warnings(&mut out, tr, &res.warnings);
+                out
+            }
+            Err(e) => fill_template(
+                &txt("gui.plant.vent.error", "Error: {e}"),
+                &[("e", error_text(tr, e))],
+            ),
+        });
+    }
+
+    fn export(&self) -> Vec<&str> {
+        self.result.as_deref().into_iter().collect()
+    }
+
+    fn reset(&mut self) {
+        self.inputs = VentSilencerInputs::default();
+    }
+
+    fn save_inputs(&self, inputs: &mut GuiInputs) {
+        inputs.plant_piping.vent = self.inputs.clone();
+    }
+
+    fn load_inputs(&mut self, inputs: &GuiInputs) {
+        self.inputs = inputs.plant_piping.vent.clone();
+    }
+}
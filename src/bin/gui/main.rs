@@ -0,0 +1,9616 @@
+#![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
+
+//! eframe/egui 기반 데스크톱 GUI 진입점.
+
+mod cards;
+
+use cards::CardWidget;
+use eframe::{egui, App, Frame};
+use image::GenericImageView;
+use rfd::FileDialog;
+use std::{
+    cell::{Cell, RefCell},
+    env, fs,
+    path::{Path, PathBuf},
+};
+use steam_engineering_toolbox::{
+    air::evaporative_cooler,
+    card_inputs::{self, CardId, SteamMode, ValveMode},
+    combustion::{
+        dew_point,
+        fuel::{flue_gas, FuelAnalysis, FuelType},
+    },
+    config, conversion,
+    cooling::{condenser, cooling_tower, drain_cooler, pump_npsh},
+    custom_units,
+    error::ToolboxError,
+    flow_coefficient::{self, FlowCoefficientKind},
+    flow_units::{self, FlowKind},
+    fuzzy,
+    gas::{blowdown, gas_properties},
+    i18n,
+    material_db,
+    number_format::NumberFormat,
+    plant_piping::{
+        corrosion, expansion_loop, flow_meter, line_fill, pipe_class, pressure_test, wall_thickness,
+    },
+    result_text,
+    show_work::{self, WorkStep},
+    quantity::QuantityKind,
+    quantity_arg::{self, FieldQuantity, QuantityArgError},
+    steam,
+    stream_state::{StreamField, StreamState, StreamTarget},
+    steam::critical_flow,
+    steam::heat_balance,
+    steam::line_case,
+    steam::line_condensation,
+    steam::reference_table::{self, ReferenceComparison},
+    steam::steam_blow,
+    steam::steam_piping::{
+        fittings, pipe_run,
+        velocity_check::{self, PipeService},
+        PipeCalcError, PipeSizingByVelocityInput,
+    },
+    steam::steam_valves,
+    steam::turbine_bypass,
+    steam::valve_authority,
+    sweep,
+    table_edit,
+    typed_quantity::{
+        Atmosphere, PressureDifference, PressureReference, PressureValue, PRESSURE_DIFFERENCE_UNITS,
+    },
+    units::{PressureUnit, TemperatureUnit},
+    valve_db,
+    warning::{CalcWarning, Severity},
+    water::freeze_protection,
+};
+
+fn main() -> Result<(), eframe::Error> {
+    // CLI 언어 옵션 처리: --lang xx 또는 --lang=xx (xx: auto/en-us/en-uk/ko-kr/ko)
+    let mut cli_lang: Option<String> = None;
+    let args: Vec<String> = env::args().collect();
+    let mut i = 1;
+    while i < args.len() {
+        let a = &args[i];
+        if let Some(val) = a.strip_prefix("--lang=") {
+            cli_lang = Some(val.to_string());
+        } else if a == "--lang" || a == "-L" {
+            if i + 1 < args.len() {
+                cli_lang = Some(args[i + 1].clone());
+                i += 1;
+            }
+        }
+        i += 1;
+    }
+
+    let icon_data = load_app_icon();
+    let mut viewport = egui::ViewportBuilder::default()
+        .with_always_on_top()
+        .with_transparent(true);
+    if let Some(icon) = icon_data.clone() {
+        viewport = viewport.with_icon(icon);
+    }
+    let cfg = eframe::NativeOptions {
+        viewport,
+        ..Default::default()
+    };
+    let mut app_cfg = config::load_or_default().unwrap_or_default();
+    if let Err(e) = custom_units::load_default() {
+        eprintln!("Custom units error: {e}");
+    }
+    if let Some(lang_cli) = cli_lang {
+        let resolved = i18n::resolve_language(&lang_cli, Some(app_cfg.language.as_str()));
+        app_cfg.language = resolved;
+    }
+    eframe::run_native(
+        "Steam Engineering Toolbox",
+        cfg,
+        Box::new(move |cc| {
+            let mut app = GuiApp::new(app_cfg.clone());
+            app.restore_inputs();
+            match setup_fonts(&cc.egui_ctx) {
+                Ok(source) => app.font_source = source,
+                Err(e) => {
+                    eprintln!("Font error: {e}");
+                    app.font_load_error = Some(e);
+                }
+            }
+            Box::new(app)
+        }),
+    )
+}
+
+fn load_app_icon() -> Option<egui::IconData> {
+    let search = [
+        "SE_Cal.png",
+        "icon.png",
+        "assets/icon.png",
+        "../SE_Cal.png",
+        "../../SE_Cal.png",
+    ];
+    let path = search.iter().find(|p| Path::new(*p).exists()).map(|s| s.to_string())?;
+    let bytes = fs::read(&path).ok()?;
+    let img = image::load_from_memory(&bytes).ok()?;
+    let rgba = img.to_rgba8();
+    let (w, h) = img.dimensions();
+    Some(egui::IconData {
+        rgba: rgba.into_raw(),
+        width: w,
+        height: h,
+    })
+}
+
+fn stroke_based_kv_available(strokes: &[f64], cvs: &[f64]) -> bool {
+    let mut count = 0;
+    for i in 0..strokes.len().min(cvs.len()) {
+        if cvs[i] > 0.0 {
+            count += 1;
+        }
+    }
+    count >= 2
+}
+
+fn interpolate_stroke_cv(strokes: &[f64], cvs: &[f64], target: f64) -> f64 {
+    let mut best_cv = 0.0;
+    let mut prev_s = None;
+    let mut prev_cv = None;
+    let t = target.clamp(0.0, 100.0);
+    let mut pairs: Vec<(f64, f64)> = strokes
+        .iter()
+        .zip(cvs.iter())
+        .filter(|(_, cv)| **cv > 0.0)
+        .map(|(s, cv)| (*s, *cv))
+        .collect();
+    if pairs.is_empty() {
+        return 0.0;
+    }
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    for (s, cv) in pairs {
+        if let (Some(ps), Some(pcv)) = (prev_s, prev_cv) {
+            if t >= ps && t <= s {
+                let ratio = if (s - ps).abs() < f64::EPSILON {
+                    0.0
+                } else {
+                    (t - ps) / (s - ps)
+                };
+                return pcv + (cv - pcv) * ratio;
+            }
+        }
+        prev_s = Some(s);
+        prev_cv = Some(cv);
+        best_cv = cv;
+    }
+    best_cv
+}
+
+fn label_with_tip(ui: &mut egui::Ui, text: &str, tip: &str) -> egui::Response {
+    scroll_if_target(ui.label(text).on_hover_text(tip), text)
+}
+
+fn heading_with_tip(ui: &mut egui::Ui, text: &str, tip: &str) -> egui::Response {
+    scroll_if_target(ui.heading(text).on_hover_text(tip), text)
+}
+
+/// 명령 팔레트에서 고른 카드 제목이면 화면에 보이게 스크롤한다 (한 번만).
+fn scroll_if_target(resp: egui::Response, text: &str) -> egui::Response {
+    SCROLL_TARGET.with(|t| {
+        let mut target = t.borrow_mut();
+        if target.as_deref() == Some(text) {
+            resp.scroll_to_me(Some(egui::Align::TOP));
+            *target = None;
+        }
+    });
+    resp
+}
+
+thread_local! {
+    /// 결과 템플릿에 채우는 숫자의 표시 형식 (설정 저장 시 갱신)
+    static NUMBER_FORMAT: RefCell<NumberFormat> = RefCell::new(NumberFormat::default());
+    /// 게이지↔절대 환산에 쓰는 현장 대기압 (설정 저장 시 갱신)
+    static SITE_ATMOSPHERE: Cell<Atmosphere> = const { Cell::new(Atmosphere::STANDARD) };
+    /// 이번 프레임에 Enter가 눌렸을 때 포커스를 가진 위젯 (레이어, 중심 y)
+    static ENTER_FOCUS: Cell<Option<(egui::LayerId, f32)>> = const { Cell::new(None) };
+    /// 결과 영역 텍스트 선택 허용 (설정값, 매 프레임 갱신)
+    static RESULT_SELECTABLE: Cell<bool> = const { Cell::new(false) };
+    /// 단위 고정 입력 칸을 "150 psig"처럼 단위를 붙여 받는지 (설정값, 매 프레임 갱신)
+    static TYPED_QUANTITY: Cell<bool> = const { Cell::new(false) };
+    /// 이번 프레임에 마지막으로 그린 계산 버튼 (레이어, 아래쪽 y)
+    static LAST_RUN_BUTTON: Cell<Option<(egui::LayerId, f32)>> = const { Cell::new(None) };
+    /// 명령 팔레트가 스크롤해 보여 줄 카드 제목 (번역된 문구, 다음 프레임에 소비)
+    static SCROLL_TARGET: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// 프레임 시작 시 호출. Enter가 눌렸으면 포커스 위젯 위치를 기록한다.
+fn capture_enter_focus(ctx: &egui::Context) {
+    let pressed = ctx.input(|i| i.key_pressed(egui::Key::Enter) && i.modifiers.is_none());
+    let focus = pressed
+        .then(|| ctx.memory(|m| m.focused()))
+        .flatten()
+        .and_then(|id| ctx.read_response(id))
+        .map(|r| (r.layer_id, r.rect.center().y));
+    ENTER_FOCUS.with(|c| c.set(focus));
+    LAST_RUN_BUTTON.with(|c| c.set(None));
+}
+
+/// 분리 창(별도 뷰포트) 프레임에서 `f`를 실행한다.
+/// 뷰포트마다 입력과 포커스가 따로라서 Enter 기록도 창 안에서 다시 잡고, 끝나면 본 창 값으로 되돌린다.
+fn with_viewport_enter_focus<R>(ctx: &egui::Context, f: impl FnOnce() -> R) -> R {
+    let saved = (ENTER_FOCUS.with(Cell::get), LAST_RUN_BUTTON.with(Cell::get));
+    capture_enter_focus(ctx);
+    let out = f();
+    ENTER_FOCUS.with(|c| c.set(saved.0));
+    LAST_RUN_BUTTON.with(|c| c.set(saved.1));
+    out
+}
+
+/// 계산 버튼 `button` 차례의 Enter인지 판단하고, 맞으면 소비한다.
+/// 카드는 위에서 아래로 "입력 → 계산 버튼" 순서라서, 직전 계산 버튼과 이 버튼 사이에
+/// 포커스가 있으면 이 카드의 입력이다.
+fn take_enter_for(button: &egui::Response) -> bool {
+    let layer = button.layer_id;
+    let top = LAST_RUN_BUTTON.with(|c| match c.replace(Some((layer, button.rect.bottom()))) {
+        Some((l, y)) if l == layer => y,
+        _ => f32::NEG_INFINITY,
+    });
+    ENTER_FOCUS.with(|c| match c.get() {
+        Some((l, y)) if l == layer && y > top && y <= button.rect.bottom() => {
+            c.set(None);
+            true
+        }
+        _ => false,
+    })
+}
+
+/// 계산 실행 버튼. 클릭하거나 카드 입력칸에서 Enter를 누르면 true.
+fn run_button(ui: &mut egui::Ui, label: impl Into<egui::WidgetText>) -> bool {
+    let resp = ui.button(label);
+    let enter = take_enter_for(&resp);
+    resp.clicked() || enter
+}
+
+fn set_site_atmosphere(atm: Atmosphere) {
+    SITE_ATMOSPHERE.with(|cell| cell.set(atm));
+}
+
+fn site_atmosphere() -> Atmosphere {
+    SITE_ATMOSPHERE.with(|cell| cell.get())
+}
+
+fn set_number_format(nf: &NumberFormat) {
+    NUMBER_FORMAT.with(|cell| *cell.borrow_mut() = nf.clone());
+}
+
+/// 템플릿에 인자를 채운다. 숫자 인자는 `fmt_num`/`fmt_qty`로 미리 표시해 넘긴다.
+fn fill_template(template: &str, vars: &[(&str, String)]) -> String {
+    NUMBER_FORMAT.with(|nf| i18n::fill_template_formatted(template, vars, &nf.borrow()))
+}
+
+/// 계산값을 숫자 형식 설정으로 소수 `decimals`자리(유효 숫자 설정이 있으면 그것) 표시한다.
+fn fmt_num(value: f64, decimals: usize) -> String {
+    NUMBER_FORMAT.with(|nf| nf.borrow().format(value, decimals))
+}
+
+/// 물리량별 자릿수(설정의 덮어쓰기 → 기본값)로 표시한다. 값은 그 물리량의 기준 단위여야 한다.
+fn fmt_qty(value: f64, kind: QuantityKind) -> String {
+    NUMBER_FORMAT.with(|nf| nf.borrow().format_quantity(value, kind))
+}
+
+/// 편차·변화량처럼 양수에도 `+`를 붙여 표시한다.
+fn fmt_signed(value: f64, decimals: usize) -> String {
+    let text = fmt_num(value, decimals);
+    if value >= 0.0 {
+        format!("+{text}")
+    } else {
+        text
+    }
+}
+
+/// 계산 오류를 "[코드] 번역된 요약: 상세" 형식으로 표시한다.
+fn error_text(tr: &i18n::Translator, e: impl Into<ToolboxError>) -> String {
+    e.into().localized(tr)
+}
+
+/// 계산 경고를 심각도 기호와 함께 한 줄씩 덧붙인다.
+fn push_warnings<W: CalcWarning>(out: &mut String, tr: &i18n::Translator, warnings: &[W]) {
+    for w in warnings {
+        let tpl = tr.lookup_or_bundled(w.i18n_key()).unwrap_or_else(|| w.to_string());
+        out.push('\n');
+        out.push_str(w.severity().marker());
+        out.push(' ');
+        out.push_str(&fill_template(&tpl, &w.args()));
+    }
+}
+
+/// 결과 문자열을 줄 단위로 표시하고, 경고 줄은 심각도별 색으로 칠한다.
+fn result_lines(ui: &mut egui::Ui, text: &str, monospace: bool) {
+    for line in text.lines() {
+        let mut rich = egui::RichText::new(line);
+        if monospace {
+            rich = rich.monospace();
+        }
+        let color = match Severity::from_line(line) {
+            Some(Severity::Critical) => Some(ui.visuals().error_fg_color),
+            Some(Severity::Caution) => Some(ui.visuals().warn_fg_color),
+            Some(Severity::Info) => Some(ui.visuals().hyperlink_color),
+            None => None,
+        };
+        if let Some(color) = color {
+            rich = rich.color(color);
+        }
+        ui.label(rich);
+    }
+}
+
+/// 계산 결과 블록. 결과 줄 아래에 복사 버튼(원문 / 표 형식 TSV)을 단다.
+/// 설정에서 허용하면 결과 줄만 드래그로 선택할 수 있다 (다른 라벨은 계속 선택 불가).
+/// 결과 아래 "결과 보내기…" 메뉴. 받을 항목이 없는 카드는 비활성으로 둔다.
+fn use_in_menu<F>(ui: &mut egui::Ui, txt: &F, state: &StreamState) -> Option<StreamTarget>
+where
+    F: Fn(&str, &str) -> String,
+{
+    let mut picked = None;
+    ui.menu_button(txt("gui.stream.use_in", "Use in…"), |ui| {
+        for target in StreamTarget::ALL {
+            let enabled = !state.transfer(target).is_empty();
+            if ui
+                .add_enabled(enabled, egui::Button::new(txt(target.i18n_key(), target.label())))
+                .clicked()
+            {
+                picked = Some(target);
+                ui.close_menu();
+            }
+        }
+    })
+    .response
+    .on_hover_text(state.to_string());
+    picked
+}
+
+/// 열정산 결과를 노드 상자와 스트림 화살표로 그린다.
+/// 윗줄은 보일러 → 터빈 → 재열기 → 저압 터빈, 아랫줄은 복수기에서 급수 가열기를 거쳐 보일러로 돌아오는 급수 경로다.
+/// 화살표 번호(S1…)는 스트림 표의 번호와 같다.
+fn heat_balance_diagram(
+    ui: &mut egui::Ui,
+    res: &heat_balance::HeatBalanceResult,
+    node_name: &dyn Fn(&heat_balance::Node) -> String,
+) {
+    use heat_balance::NodeKind;
+    // 윗줄 (왼쪽부터)과 아랫줄 (오른쪽부터) 배치 순서
+    let top: Vec<usize> = [NodeKind::Boiler, NodeKind::HpTurbine, NodeKind::Reheater, NodeKind::LpTurbine]
+        .iter()
+        .filter_map(|k| res.nodes.iter().position(|n| n.kind == *k))
+        .collect();
+    let mut bottom: Vec<usize> = [NodeKind::Condenser, NodeKind::CondensatePump]
+        .iter()
+        .filter_map(|k| res.nodes.iter().position(|n| n.kind == *k))
+        .collect();
+    let feed_pump = res.nodes.iter().position(|n| n.kind == NodeKind::FeedPump);
+    for (i, n) in res.nodes.iter().enumerate() {
+        if matches!(n.kind, NodeKind::ClosedHeater | NodeKind::Deaerator) {
+            bottom.push(i);
+            if n.kind == NodeKind::Deaerator {
+                bottom.extend(feed_pump);
+            }
+        }
+    }
+    let slots = top.len().max(bottom.len() + 1) as f32;
+    let width = ui.available_width().max(320.0);
+    let (response, painter) = ui.allocate_painter(egui::vec2(width, 190.0), egui::Sense::hover());
+    let area = response.rect;
+    let step = area.width() / slots;
+    let box_size = egui::vec2((step - 12.0).clamp(36.0, 96.0), 36.0);
+    let mut centers = vec![area.center(); res.nodes.len()];
+    for (col, &i) in top.iter().enumerate() {
+        centers[i] = egui::pos2(area.left() + step * (col as f32 + 0.5), area.top() + 30.0);
+    }
+    for (col, &i) in bottom.iter().enumerate() {
+        centers[i] = egui::pos2(area.right() - step * (col as f32 + 0.5), area.bottom() - 30.0);
+    }
+    let visuals = ui.visuals();
+    let text_color = visuals.text_color();
+    let steam_color = egui::Color32::from_rgb(214, 110, 40);
+    let water_color = egui::Color32::from_rgb(50, 120, 200);
+    let font = egui::FontId::proportional(11.0);
+    // 상자 테두리에서 화살표가 시작·끝나도록 중심 사이 선분을 자른다.
+    let edge = |c: egui::Pos2, toward: egui::Pos2| {
+        let d = toward - c;
+        let t = (box_size.x * 0.5 / d.x.abs().max(1e-3)).min(box_size.y * 0.5 / d.y.abs().max(1e-3));
+        c + d * t.min(1.0)
+    };
+    for (i, s) in res.streams.iter().enumerate() {
+        use heat_balance::StreamKind;
+        let color = match s.kind {
+            StreamKind::Drain | StreamKind::Condensate | StreamKind::Feedwater => water_color,
+            _ => steam_color,
+        };
+        let (a, b) = (centers[s.from], centers[s.to]);
+        // 드레인은 같은 줄의 급수와 겹치지 않도록 아래로 비켜 그린다.
+        let offset = if s.kind == StreamKind::Drain { egui::vec2(0.0, 8.0) } else { egui::Vec2::ZERO };
+        let start = edge(a, b) + offset;
+        let end = edge(b, a) + offset;
+        painter.arrow(start, end - start, egui::Stroke::new(1.5, color));
+        painter.text(
+            start + (end - start) * 0.5 + offset,
+            egui::Align2::CENTER_BOTTOM,
+            format!("S{}", i + 1),
+            font.clone(),
+            color,
+        );
+    }
+    for (i, n) in res.nodes.iter().enumerate() {
+        let rect = egui::Rect::from_center_size(centers[i], box_size);
+        painter.rect(rect, 4.0, visuals.extreme_bg_color, visuals.widgets.noninteractive.fg_stroke);
+        painter.text(
+            rect.center() - egui::vec2(0.0, 7.0),
+            egui::Align2::CENTER_CENTER,
+            node_name(n),
+            font.clone(),
+            text_color,
+        );
+        painter.text(
+            rect.center() + egui::vec2(0.0, 8.0),
+            egui::Align2::CENTER_CENTER,
+            format!("{} kW", fmt_num(n.duty_kw, 0)),
+            font.clone(),
+            text_color,
+        );
+    }
+}
+
+/// 카드 머리 줄: 대시보드 고정 토글과 기본값 버튼. 기본값 버튼을 누르면 `true`.
+fn card_toolbar<F>(ui: &mut egui::Ui, txt: &F, dashboard: &mut card_inputs::DashboardTab, card: CardId) -> bool
+where
+    F: Fn(&str, &str) -> String,
+{
+    pin_button(ui, txt, dashboard, card);
+    reset_card_button(ui, txt)
+}
+
+/// 대시보드 고정 토글. 저장할 입력이 없는 카드(배관 경로, 라인 케이스)는 이것만 둔다.
+fn pin_button<F>(ui: &mut egui::Ui, txt: &F, dashboard: &mut card_inputs::DashboardTab, card: CardId)
+where
+    F: Fn(&str, &str) -> String,
+{
+    let pinned = dashboard.is_pinned(card);
+    let tip = if pinned {
+        txt("gui.dashboard.unpin", "Remove from the dashboard")
+    } else {
+        txt("gui.dashboard.pin", "Pin to the dashboard")
+    };
+    if ui.selectable_label(pinned, "📌").on_hover_text(tip).clicked() {
+        dashboard.toggle(card);
+    }
+}
+
+/// 위젯 카드를 그룹 틀과 머리 줄로 감싸 그린다.
+fn card_frame<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    tr: &i18n::Translator,
+    dashboard: &mut card_inputs::DashboardTab,
+    card: &mut dyn CardWidget,
+) where
+    F: Fn(&str, &str) -> String,
+{
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        if ui.horizontal(|ui| card_toolbar(ui, txt, dashboard, card.id())).inner {
+            card.reset();
+        }
+        card.render(ui, tr);
+    });
+}
+
+/// 카드 입력을 기본값으로 되돌리는 작은 버튼.
+fn reset_card_button<F>(ui: &mut egui::Ui, txt: &F) -> bool
+where
+    F: Fn(&str, &str) -> String,
+{
+    ui.small_button(txt("gui.inputs.reset", "↺ Defaults"))
+        .on_hover_text(txt("gui.inputs.reset_tip", "Reset this card's inputs to the defaults"))
+        .clicked()
+}
+
+/// 노점 계산용 연료 선택 (없음 = 노점 생략). 선택이 바뀌면 true.
+fn dew_point_fuel_combo<F>(ui: &mut egui::Ui, txt: &F, id: &str, fuel: &mut Option<FuelType>) -> bool
+where
+    F: Fn(&str, &str) -> String,
+{
+    let label = |f: Option<FuelType>| match f {
+        Some(f) => txt(f.key(), f.name()),
+        None => txt("gui.boiler.dew.no_fuel", "— (no dew point check)"),
+    };
+    let before = *fuel;
+    egui::ComboBox::from_id_source(id)
+        .selected_text(label(*fuel))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(fuel, None, label(None));
+            for f in FuelType::ALL {
+                ui.selectable_value(fuel, Some(f), label(Some(f)));
+            }
+        });
+    before != *fuel
+}
+
+/// 연료 분석과 과잉공기율로 대기압 배가스 노점을 구한다 (계산 불가면 `None`).
+fn flue_gas_dew_points_for(
+    fuel: Option<FuelAnalysis>,
+    excess_air_frac: f64,
+) -> Option<dew_point::FlueGasDewPoints> {
+    let gas = flue_gas(&fuel?, excess_air_frac, dew_point::STANDARD_AIR_HUMIDITY_RATIO);
+    dew_point::flue_gas_dew_points(
+        &gas,
+        dew_point::DEFAULT_SO3_CONVERSION,
+        dew_point::ATMOSPHERIC_PRESSURE_BAR_ABS,
+    )
+    .ok()
+}
+
+/// 노점 결과 한 줄
+fn dew_point_line<F>(txt: &F, dp: &dew_point::FlueGasDewPoints) -> String
+where
+    F: Fn(&str, &str) -> String,
+{
+    fill_template(
+        &txt(
+            "gui.boiler.dew.result",
+            "Flue gas dew point: water {wdp} °C, sulfuric acid {adp} °C (SO₃ {so3} ppmv)",
+        ),
+        &[
+            ("wdp", fmt_qty(dp.water_dew_point_c, QuantityKind::Temperature)),
+            (
+                "adp",
+                dp.acid_dew_point_c
+                    .map_or_else(|| "—".to_string(), |t| fmt_num(t, 0)),
+            ),
+            ("so3", fmt_num(dp.so3_ppmv, 1)),
+        ],
+    )
+}
+
+fn result_block<F>(ui: &mut egui::Ui, txt: &F, text: &str, monospace: bool)
+where
+    F: Fn(&str, &str) -> String,
+{
+    ui.scope(|ui| {
+        ui.style_mut().interaction.selectable_labels = RESULT_SELECTABLE.with(|c| c.get());
+        result_lines(ui, text, monospace);
+    });
+    ui.horizontal(|ui| {
+        if ui
+            .small_button(txt("gui.result.copy", "Copy"))
+            .on_hover_text(txt("gui.result.copy_tip", "Copy the result text to the clipboard"))
+            .clicked()
+        {
+            ui.ctx().copy_text(text.to_string());
+        }
+        if ui
+            .small_button(txt("gui.result.copy_tsv", "Copy as table"))
+            .on_hover_text(txt(
+                "gui.result.copy_tsv_tip",
+                "Copy as tab-separated item/value/unit rows for pasting into Excel",
+            ))
+            .clicked()
+        {
+            ui.ctx().copy_text(result_text::result_to_tsv(text));
+        }
+    });
+}
+
+/// 참조 증기표 비교 결과: 비교한 표 점과 최대 편차, 물성별 참조값/IF97 값/편차 줄.
+fn reference_comparison_text<F>(txt: &F, comparison: &ReferenceComparison) -> String
+where
+    F: Fn(&str, &str) -> String,
+{
+    let mut lines = vec![fill_template(
+        &txt(
+            "gui.steam.reference.point",
+            "Reference ({source}) at P={p} bar(a), T={t} °C: max. deviation {max}%",
+        ),
+        &[
+            ("source", reference_table::REFERENCE_SOURCE.to_string()),
+            ("p", fmt_qty(comparison.point.p_bar_abs, QuantityKind::Pressure)),
+            ("t", fmt_qty(comparison.point.t_c, QuantityKind::Temperature)),
+            ("max", fmt_num(comparison.max_abs_deviation_pct(), 3)),
+        ],
+    )];
+    // 표 값과 같은 유효 숫자 5자리로 맞춰 보인다
+    let sig5 = |x: f64| {
+        let decimals = if x.is_finite() && x != 0.0 {
+            (4 - x.abs().log10().floor() as i32).clamp(0, 8) as usize
+        } else {
+            0
+        };
+        fmt_num(x, decimals)
+    };
+    let row = txt(
+        "gui.steam.reference.row",
+        "{property}: reference {reference}, IF97 {if97} {unit} ({deviation}%)",
+    );
+    for d in &comparison.properties {
+        lines.push(fill_template(
+            &row,
+            &[
+                ("property", d.property.to_string()),
+                ("reference", sig5(d.reference)),
+                ("if97", sig5(d.if97)),
+                ("unit", d.unit.to_string()),
+                ("deviation", fmt_signed(d.deviation_pct, 3)),
+            ],
+        ));
+    }
+    lines.join("\n")
+}
+
+/// 결과 아래의 "계산 과정" 펼침 목록. 값을 대입한 식을 고정폭 글꼴로 보이고 복사할 수 있다.
+fn show_work_block<F>(ui: &mut egui::Ui, txt: &F, id: &str, steps: &[WorkStep])
+where
+    F: Fn(&str, &str) -> String,
+{
+    if steps.is_empty() {
+        return;
+    }
+    let text = NUMBER_FORMAT.with(|nf| show_work::to_text(steps, &nf.borrow()));
+    egui::CollapsingHeader::new(txt("gui.show_work.heading", "Show work (formulas with your values)"))
+        .id_source(id)
+        .show(ui, |ui| {
+            result_block(ui, txt, &text, true);
+        });
+}
+
+fn legend_toggle(ui: &mut egui::Ui, title: &str, body: &str, state: &mut bool) {
+    ui.horizontal(|ui| {
+        ui.checkbox(state, title);
+    });
+    if *state {
+        ui.add(
+            egui::Label::new(egui::RichText::new(body).small())
+                .wrap(true),
+        );
+    }
+}
+
+struct GuiApp {
+    config: config::Config,
+    tr: i18n::Translator,
+    lang_input: String,
+    lang_pack_dir_input: String,
+    lang_save_status: Option<String>,
+    number_format_input: NumberFormat,
+    site_input: config::SiteConditions,
+    profile_choice: String,
+    profile_new_name: String,
+    tab: Tab,
+    /// 결과 내보내기(Ctrl+E) 상태 메시지
+    export_status: Option<String>,
+    window_alpha: f32,
+    show_formula_modal: bool,
+    // 해설 토글
+    show_legend_steam: bool,
+    show_legend_pipe: bool,
+    show_legend_pipe_loss: bool,
+    show_legend_valve: bool,
+    show_legend_plant: bool,
+    // 증기표
+    steam_result: Option<String>,
+    /// 결과를 참조 증기표(IAPWS-95)와 비교할지 여부
+    steam_compare_reference: bool,
+    steam_reference_result: Option<String>,
+    show_vacuum_table_window: bool,
+    /// 진공 포화표 (현장 대기압이 바뀔 때만 다시 계산)
+    vacuum_table: Option<Vec<steam::VacuumTableRow>>,
+    /// 분리 창으로 띄운 패널과 창 위치/크기 (종료·닫기 시 config에 저장)
+    detached_windows: Vec<config::DetachedWindow>,
+    apply_initial_view_size: bool,
+    // 배관
+    pipe_service: Option<PipeService>,
+    /// 프로젝트 배관 등급 (`pipe_classes.toml`)
+    pipe_classes: pipe_class::PipeClassCatalog,
+    pipe_class_choice: Option<String>,
+    pipe_result: Option<String>,
+    pipe_loss_fittings: Vec<FittingRow>,
+    pipe_loss_result: Option<String>,
+    pipe_loss_sweep: SweepUi,
+    pipe_run_rows: Vec<PipeRunRow>,
+    pipe_run_result: Option<String>,
+    pipe_cond_result: Option<String>,
+    blow_result: Option<String>,
+    /// 마지막 배관 ΔP 계산 결과 (라인 케이스에 넣을 수 있다)
+    pipe_loss_last: Option<line_case::CasePipe>,
+    /// 마지막 배관 ΔP의 계산 과정
+    pipe_loss_work: Vec<WorkStep>,
+    // 밸브
+    valve_result: Option<String>,
+    /// 마지막 요구 Kv/Cv의 계산 과정 (유량 모드에서는 비어 있다)
+    valve_work: Vec<WorkStep>,
+    valve_sweep: SweepUi,
+    /// 밸브 트림 데이터베이스 (`valve_db.toml`)
+    valve_db: valve_db::ValveDb,
+    valve_db_ui: ValveDbUi,
+    valve_auth_points: Vec<valve_authority::CharacteristicPoint>,
+    valve_auth_result: Option<String>,
+    /// 위젯으로 옮긴 카드는 입력과 결과를 스스로 가진다
+    /// 마지막 밸브 계산의 Kv·차압 (라인 케이스에 넣을 수 있다)
+    valve_last: Option<line_case::CaseValve>,
+    /// 밸브와 앞뒤 배관을 묶은 현재 라인 케이스
+    line_case: line_case::LineCase,
+    /// 재시작 후에도 남는 카드 입력 (탭/카드별 묶음)
+    inputs: card_inputs::GuiInputs,
+    /// 카드별 "기본값으로" 복원에 쓰는 시작 시점 입력 (단위 프리셋·유체 기본값 반영)
+    input_defaults: card_inputs::GuiInputs,
+    line_case_result: Option<String>,
+    /// 카드 사이 "결과 보내기…"로 마지막에 보낸 흐름 상태 (다른 카드에 다시 붙여 넣을 수 있다)
+    stream_clip: Option<StreamState>,
+    steam_stream: Option<StreamState>,
+    pipe_stream: Option<StreamState>,
+    bypass_table_paste: TablePasteUi,
+    bypass_valve_db: ValveDbUi,
+    bypass_result: Option<String>,
+    spray_table_paste: TablePasteUi,
+    spray_valve_db: ValveDbUi,
+    spray_calc_result: Option<String>,
+    plant_result: Option<String>,
+    plant_expansion_result: Option<String>,
+    plant_freeze_result: Option<String>,
+    plant_gas_result: Option<String>,
+    plant_bd_result: Option<String>,
+    plant_pressure_result: Option<String>,
+    plant_test_result: Option<String>,
+    /// 마지막 계산 충수량 [m³] (시험 체적으로 넘길 때 사용)
+    plant_fill_volume_m3: Option<f64>,
+    plant_fill_result: Option<String>,
+    boiler_result: Option<String>,
+    purity_result: Option<String>,
+    /// `GuiApp` 밖으로 옮긴 계산 카드.
+    widgets: Vec<Box<dyn CardWidget>>,
+    condenser_result: Option<String>,
+    condenser_last_input: Option<condenser::CondenserInput>,
+    condenser_work: Vec<WorkStep>,
+    condenser_sweep: SweepUi,
+
+    ct_result: Option<String>,
+    evap_result: Option<String>,
+
+    npsh_result: Option<String>,
+
+    drain_result: Option<String>,
+    // 설정
+    font_size: f32,
+    ui_scale: f32,
+    always_on_top: bool,
+    selectable_results: bool,
+    typed_quantity_fields: bool,
+    show_settings_modal: bool,
+    show_help_modal: bool,
+    /// 명령 팔레트 (Ctrl+K)
+    palette_open: bool,
+    palette_query: String,
+    palette_selected: usize,
+    palette_recent: Vec<PaletteAction>,
+    theme: config::ThemeSettings,
+    custom_font_path: String,
+    /// 현재 적용된 폰트 출처 (파일 경로 또는 "embedded")
+    font_source: String,
+    font_load_error: Option<String>,
+}
+
+/// 명령 팔레트(Ctrl+K)에서 고를 수 있는 동작.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PaletteAction {
+    Tab(Tab),
+    /// 탭으로 옮긴 뒤 카드 제목(i18n 키, 기본 문구)이 보이게 스크롤한다
+    Card(Tab, &'static str, &'static str),
+    Settings,
+    Help,
+    Formulas,
+    Export,
+    VacuumTable,
+}
+
+/// 팔레트에 올리는 카드. 이름이 곧 카드 제목(또는 카드 라벨)이다.
+const PALETTE_CARDS: &[(Tab, &str, &str)] = &[
+    (Tab::UnitConv, "gui.unit.flow.heading", "Flow / power"),
+    (Tab::SteamTables, "gui.steam.card_label", "Saturation/Superheat card"),
+    (Tab::SteamTables, "gui.steam.dryness.heading", "Dryness fraction calorimeters"),
+    (Tab::SteamPiping, "gui.pipe.card_label", "Pipe sizing card"),
+    (Tab::SteamPiping, "gui.pipe.loss.heading", "Pressure Loss (Darcy-Weisbach)"),
+    (Tab::SteamPiping, "gui.pipe.run.heading", "Pipe Run (multi-segment)"),
+    (Tab::SteamPiping, "gui.pipe.blow.heading", "Steam blow cleaning force ratio"),
+    (Tab::SteamValves, "gui.valve.card_label", "Cv/Kv calculator"),
+    (Tab::SteamValves, "gui.valve.authority.heading", "Valve authority & installed characteristic"),
+    (Tab::SteamValves, "gui.valve.actuator.heading", "Actuator sizing"),
+    (Tab::SteamValves, "gui.line_case.heading", "Line case cross-check"),
+    (Tab::SteamValves, "gui.bypass.steam.heading", "Bypass Valve (steam)"),
+    (Tab::SteamValves, "gui.bypass.water.heading", "Bypass TCV (water)"),
+    (Tab::Boiler, "gui.boiler.ptc.heading", "PTC 4.0 extended (stack/radiation/blowdown losses)"),
+    (Tab::Boiler, "gui.boiler.drum.heading", "Drum swell/shrink & blowdown line check"),
+    (Tab::Boiler, "gui.boiler.purity.heading", "Steam purity / carryover"),
+    (Tab::Boiler, "gui.boiler.cycle.heading", "Rankine cycle heat rate"),
+    (Tab::Boiler, "gui.boiler.hb.heading", "Heat balance diagram"),
+    (Tab::Boiler, "gui.boiler.hrsg.heading", "HRSG pinch / approach"),
+    (Tab::Cooling, "gui.cooling.cond.heading", "Condenser Heat Balance / Vacuum"),
+    (Tab::Cooling, "gui.cooling.ct.heading", "Cooling Tower (Range / Approach)"),
+    (Tab::Cooling, "gui.cooling.evap.heading", "Direct evaporative air cooler"),
+    (Tab::Cooling, "gui.cooling.acc.heading", "Air-cooled condenser / fin-fan"),
+    (Tab::Cooling, "gui.cooling.cmp.heading", "Once-through vs recirculating cooling"),
+    (Tab::Cooling, "gui.cooling.npsh.heading", "Circulating Pump NPSH / Margin"),
+    (Tab::Cooling, "gui.cooling.sat_npsh.heading", "Hotwell / Flash Tank Pump NPSH"),
+    (Tab::Cooling, "gui.cooling.ejector.heading", "Steam Jet Ejector"),
+    (Tab::Cooling, "gui.cooling.drain.heading", "Drain Cooler / Reheater Heat Balance"),
+    (Tab::PlantPiping, "gui.plant.orifice.heading", "Orifice / Nozzle flow check"),
+    (Tab::PlantPiping, "gui.plant.mcomp.heading", "Steam meter density compensation"),
+    (Tab::PlantPiping, "gui.plant.tot.heading", "Historian totalizer"),
+    (Tab::PlantPiping, "gui.plant.loop.heading", "Expansion loop sizing"),
+    (Tab::PlantPiping, "gui.plant.freeze.heading", "Freeze protection (stagnant water line)"),
+    (Tab::PlantPiping, "gui.plant.corrosion.heading", "Corrosion allowance & remaining life"),
+    (Tab::PlantPiping, "gui.plant.ptest.heading", "Hydrotest / pneumatic test pressure"),
+    (Tab::PlantPiping, "gui.plant.fill.heading", "Line fill volume and water-filled weight"),
+    (Tab::PlantPiping, "gui.plant.gasprop.heading", "Industrial gas properties"),
+    (Tab::PlantPiping, "gui.plant.blowdown.heading", "Vessel / header blowdown time"),
+    (Tab::PlantPiping, "gui.plant.aiv.heading", "Letdown noise / AIV screening"),
+    (Tab::PlantPiping, "gui.plant.vent.heading", "Steam vent / silencer sizing"),
+    (Tab::Condensate, "gui.condensate.line.heading", "Return line sizing"),
+    (Tab::Condensate, "gui.condensate.tank.heading", "Tank heat-up"),
+    (Tab::Condensate, "gui.condensate.sparger.heading", "Direct steam injection (sparger)"),
+];
+
+/// 팔레트에 올리는 설정·명령.
+const PALETTE_COMMANDS: [(PaletteAction, &str, &str); 5] = [
+    (PaletteAction::Settings, "gui.settings.title", "Settings"),
+    (PaletteAction::Help, "gui.about.title", "Help / About"),
+    (PaletteAction::Formulas, "gui.formula.button", "Formula reference"),
+    (PaletteAction::Export, "gui.export.button", "Export results"),
+    (PaletteAction::VacuumTable, "gui.steam.vacuum_window", "Open vacuum table in new window"),
+];
+
+/// 최근 고른 팔레트 항목을 몇 개까지 기억할지
+const PALETTE_RECENT_MAX: usize = 5;
+
+impl PaletteAction {
+    /// 전체 목록: 메뉴, 카드, 설정·명령 순.
+    fn all() -> Vec<(PaletteAction, &'static str, &'static str)> {
+        Tab::NAV_ORDER
+            .into_iter()
+            .map(|tab| {
+                let (key, default) = tab.label_key();
+                (PaletteAction::Tab(tab), key, default)
+            })
+            .chain(
+                PALETTE_CARDS
+                    .iter()
+                    .map(|&(tab, key, default)| (PaletteAction::Card(tab, key, default), key, default)),
+            )
+            .chain(PALETTE_COMMANDS)
+            .collect()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Tab {
+    Dashboard,
+    UnitConv,
+    SteamTables,
+    SteamPiping,
+    SteamValves,
+    Boiler,
+    Cooling,
+    PlantPiping,
+    Condensate,
+}
+
+impl Tab {
+    /// 메뉴 표시 순서. Ctrl+1..9 단축키도 이 순서를 따른다.
+    const NAV_ORDER: [Tab; 9] = [
+        Tab::Dashboard,
+        Tab::SteamTables,
+        Tab::UnitConv,
+        Tab::SteamPiping,
+        Tab::SteamValves,
+        Tab::Boiler,
+        Tab::Cooling,
+        Tab::PlantPiping,
+        Tab::Condensate,
+    ];
+
+    /// 메뉴 라벨 (i18n 키, 기본 문구)
+    fn label_key(self) -> (&'static str, &'static str) {
+        match self {
+            Tab::Dashboard => ("gui.tab.dashboard", "Dashboard"),
+            Tab::SteamTables => ("gui.tab.steam_tables", "Steam Tables"),
+            Tab::UnitConv => ("gui.tab.unit_conv", "Unit Converter"),
+            Tab::SteamPiping => ("gui.tab.steam_piping", "Steam Piping"),
+            Tab::SteamValves => ("gui.tab.steam_valves", "Steam Valves"),
+            Tab::Boiler => ("gui.tab.boiler", "Boiler Efficiency"),
+            Tab::Cooling => ("gui.tab.cooling", "Cooling/Condensing"),
+            Tab::PlantPiping => ("gui.tab.plant_piping", "Plant Piping"),
+            Tab::Condensate => ("gui.tab.condensate", "Condensate Recovery"),
+        }
+    }
+
+    /// 탭에 있는 카드. 결과 내보내기도 이 순서를 따른다. 대시보드는 고정 목록을 쓴다.
+    fn cards(self) -> &'static [CardId] {
+        match self {
+            Tab::Dashboard => &[],
+            Tab::UnitConv => &[CardId::UnitConversion, CardId::FlowConversion],
+            Tab::SteamTables => &[CardId::SteamProperties, CardId::Dryness],
+            Tab::SteamPiping => &[
+                CardId::PipeSizing,
+                CardId::PipeLoss,
+                CardId::PipeRun,
+                CardId::LineCondensation,
+                CardId::SteamBlow,
+            ],
+            Tab::SteamValves => &[
+                CardId::ValveSizing,
+                CardId::ValveAuthority,
+                CardId::LineCase,
+                CardId::TurbineBypass,
+                CardId::ValveActuator,
+            ],
+            Tab::Boiler => &[
+                CardId::BoilerEfficiency,
+                CardId::Drum,
+                CardId::SteamPurity,
+                CardId::HeatBalance,
+                CardId::Hrsg,
+                CardId::Attemperator,
+                CardId::AirPreheater,
+                CardId::Emissions,
+                CardId::RankineCycle,
+            ],
+            Tab::Cooling => &[
+                CardId::Condenser,
+                CardId::CoolingTower,
+                CardId::EvaporativeCooler,
+                CardId::AirCooledCondenser,
+                CardId::CoolingComparison,
+                CardId::PumpNpsh,
+                CardId::SaturatedNpsh,
+                CardId::Ejector,
+                CardId::DrainCooler,
+            ],
+            Tab::PlantPiping => &[
+                CardId::Orifice,
+                CardId::MeterCompensation,
+                CardId::Totalizer,
+                CardId::ExpansionLoop,
+                CardId::FreezeProtection,
+                CardId::GasProperties,
+                CardId::VesselBlowdown,
+                CardId::GasThrottling,
+                CardId::Compressor,
+                CardId::LetdownNoise,
+                CardId::VentSilencer,
+                CardId::Corrosion,
+                CardId::PressureTest,
+                CardId::LineFill,
+                CardId::TurbineBypass,
+            ],
+            Tab::Condensate => &[CardId::ReturnLine, CardId::TankHeating, CardId::Sparger],
+        }
+    }
+
+    /// 분리 창 배치 저장에 쓰는 식별자
+    fn id(self) -> &'static str {
+        match self {
+            Tab::Dashboard => "dashboard",
+            Tab::SteamTables => "steam_tables",
+            Tab::UnitConv => "unit_conv",
+            Tab::SteamPiping => "steam_piping",
+            Tab::SteamValves => "steam_valves",
+            Tab::Boiler => "boiler",
+            Tab::Cooling => "cooling",
+            Tab::PlantPiping => "plant_piping",
+            Tab::Condensate => "condensate",
+        }
+    }
+}
+
+/// 분리 창으로 띄울 수 있는 패널 (계산기 탭 전체 또는 진공 포화온도 표).
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Panel {
+    Tab(Tab),
+    VacuumTable,
+}
+
+impl Panel {
+    fn id(self) -> &'static str {
+        match self {
+            Panel::Tab(tab) => tab.id(),
+            Panel::VacuumTable => "vacuum_table",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Panel> {
+        if id == Panel::VacuumTable.id() {
+            return Some(Panel::VacuumTable);
+        }
+        Tab::NAV_ORDER.into_iter().find(|t| t.id() == id).map(Panel::Tab)
+    }
+
+    /// 창 제목 (i18n 키, 기본 문구)
+    fn title_key(self) -> (&'static str, &'static str) {
+        match self {
+            Panel::Tab(tab) => tab.label_key(),
+            Panel::VacuumTable => (
+                "gui.steam.vacuum_title",
+                "Vacuum saturation table (mmHg gauge: 0=atm, -760=vacuum)",
+            ),
+        }
+    }
+}
+
+/// 테마 설정으로 egui Visuals를 만든다.
+/// `system_dark`는 운영체제가 다크 모드인지 여부 (System 모드에서만 사용).
+fn theme_visuals(theme: &config::ThemeSettings, system_dark: bool) -> egui::Visuals {
+    use config::ThemeMode;
+    let rgb = |c: [u8; 3]| egui::Color32::from_rgb(c[0], c[1], c[2]);
+    let mut v = match theme.mode {
+        ThemeMode::Dark => egui::Visuals::dark(),
+        ThemeMode::System if system_dark => egui::Visuals::dark(),
+        ThemeMode::Light | ThemeMode::System => egui::Visuals::light(),
+        ThemeMode::SoftBlue => {
+            let mut v = egui::Visuals::light();
+            v.panel_fill = egui::Color32::from_rgb(236, 242, 250);
+            v.window_fill = egui::Color32::from_rgb(244, 248, 253);
+            v.faint_bg_color = egui::Color32::from_rgb(226, 235, 246);
+            v.selection.bg_fill = egui::Color32::from_rgb(150, 190, 235);
+            v.hyperlink_color = egui::Color32::from_rgb(30, 90, 170);
+            v
+        }
+    };
+    if let Some(accent) = theme.accent_rgb().map(rgb) {
+        v.selection.bg_fill = accent.linear_multiply(0.6);
+        v.selection.stroke.color = accent;
+        v.hyperlink_color = accent;
+        v.widgets.hovered.bg_stroke.color = accent;
+        v.widgets.active.bg_fill = accent;
+    }
+    if let Some(bg) = theme.background_rgb().map(rgb) {
+        v.panel_fill = bg;
+        v.window_fill = bg;
+    }
+    if theme.high_contrast {
+        let (fg, bg) = if v.dark_mode {
+            (egui::Color32::WHITE, egui::Color32::BLACK)
+        } else {
+            (egui::Color32::BLACK, egui::Color32::WHITE)
+        };
+        v.override_text_color = Some(fg);
+        if theme.background.is_none() {
+            v.panel_fill = bg;
+            v.window_fill = bg;
+        }
+        v.extreme_bg_color = bg;
+        v.window_stroke = egui::Stroke::new(2.0, fg);
+        for w in [
+            &mut v.widgets.noninteractive,
+            &mut v.widgets.inactive,
+            &mut v.widgets.hovered,
+            &mut v.widgets.active,
+        ] {
+            w.bg_stroke = egui::Stroke::new(w.bg_stroke.width.max(1.5), fg);
+            w.fg_stroke.color = fg;
+        }
+    }
+    v
+}
+
+/// 공통: 바이너리 폰트 바이트를 egui에 등록.
+fn apply_font_bytes(ctx: &egui::Context, bytes: Vec<u8>, name: &str) {
+    let mut fonts = egui::FontDefinitions::default();
+    let font_name = name.to_string();
+    fonts
+        .font_data
+        .insert(font_name.clone(), egui::FontData::from_owned(bytes));
+    fonts
+        .families
+        .entry(egui::FontFamily::Proportional)
+        .or_default()
+        .insert(0, font_name.clone());
+    fonts
+        .families
+        .entry(egui::FontFamily::Monospace)
+        .or_default()
+        .insert(0, font_name);
+    ctx.set_fonts(fonts);
+}
+
+/// 한글을 표시하기 위해 기본 폰트를 우선 적용한다.
+/// 1) assets/fonts/malgun.ttf
+/// 2) 운영체제별 시스템 폰트 (`system_font_candidates`)
+/// 3) 바이너리에 내장된 malgun.ttf
+///
+/// 성공 시 적용한 폰트 출처(경로 또는 "embedded")를 돌려준다.
+fn setup_fonts(ctx: &egui::Context) -> Result<String, String> {
+    // 1) 프로젝트 내 폰트
+    let asset_path = Path::new("assets/fonts/malgun.ttf");
+    if asset_path.exists() {
+        let bytes = fs::read(asset_path).map_err(|e| format!("Failed to read font file: {e}"))?;
+        apply_font_bytes(ctx, bytes, "korean_font");
+        return Ok(asset_path.display().to_string());
+    }
+
+    // 2) 시스템 폰트 탐색. 읽기 실패한 후보는 건너뛴다.
+    for p in system_font_candidates() {
+        if let Ok(bytes) = fs::read(&p) {
+            apply_font_bytes(ctx, bytes, "korean_font");
+            return Ok(p.display().to_string());
+        }
+    }
+
+    // 3) 내장 폰트 (빌드 시 assets/fonts/malgun.ttf 존재 필요)
+    const EMBED_MALGUN: &[u8] = include_bytes!("../../../assets/fonts/malgun.ttf");
+    apply_font_bytes(ctx, EMBED_MALGUN.to_vec(), "embedded_malgun");
+    Ok("embedded".into())
+}
+
+/// 한글 글리프가 있는 시스템 폰트 후보 (존재하는 파일만, 우선순위 순).
+/// - Windows: %WINDIR%\Fonts의 맑은 고딕/굴림/바탕
+/// - macOS: Apple SD Gothic Neo, AppleGothic (시스템/사용자 Library)
+/// - Linux 등: fontconfig(`fc-match`/`fc-list`) 결과 → Noto CJK/나눔 고정 경로
+fn system_font_candidates() -> Vec<PathBuf> {
+    let mut out: Vec<PathBuf> = Vec::new();
+    if cfg!(target_os = "windows") {
+        if let Some(windir) = env::var_os("WINDIR") {
+            let fonts = Path::new(&windir).join("Fonts");
+            for cand in ["malgun.ttf", "malgunsl.ttf", "malgunbd.ttf", "gulim.ttc", "batang.ttc", "gungsuh.ttc"] {
+                out.push(fonts.join(cand));
+            }
+        }
+    } else if cfg!(target_os = "macos") {
+        let mut dirs = vec![
+            PathBuf::from("/System/Library/Fonts"),
+            PathBuf::from("/System/Library/Fonts/Supplemental"),
+            PathBuf::from("/Library/Fonts"),
+        ];
+        if let Some(home) = env::var_os("HOME") {
+            dirs.push(Path::new(&home).join("Library/Fonts"));
+        }
+        for dir in &dirs {
+            for cand in ["AppleSDGothicNeo.ttc", "AppleGothic.ttf", "NotoSansCJK-Regular.ttc", "NanumGothic.ttf"] {
+                out.push(dir.join(cand));
+            }
+        }
+    } else {
+        out.extend(fontconfig_fonts());
+        for cand in [
+            "/usr/share/fonts/opentype/noto/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/noto-cjk/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/google-noto-cjk/NotoSansCJK-Regular.ttc",
+            "/usr/share/fonts/truetype/nanum/NanumGothic.ttf",
+            "/usr/share/fonts/nanum/NanumGothic.ttf",
+            "/usr/share/fonts/truetype/unfonts-core/UnDotum.ttf",
+        ] {
+            out.push(PathBuf::from(cand));
+        }
+    }
+    out.retain(|p| p.is_file());
+    out.dedup();
+    out
+}
+
+/// fontconfig에 한국어(`:lang=ko`) 폰트를 묻는다. fc-match가 고른 폰트를 먼저,
+/// 그다음 fc-list 결과를 돌려준다. fontconfig가 없으면 빈 목록.
+/// fc-match는 한글이 없어도 가장 가까운 폰트를 내주므로 fc-list에 있을 때만 쓴다.
+fn fontconfig_fonts() -> Vec<PathBuf> {
+    let run = |args: &[&str]| -> Vec<PathBuf> {
+        std::process::Command::new(args[0])
+            .args(&args[1..])
+            .output()
+            .ok()
+            .filter(|o| o.status.success())
+            .map(|o| {
+                String::from_utf8_lossy(&o.stdout)
+                    .lines()
+                    .map(str::trim)
+                    .filter(|l| l.ends_with(".ttf") || l.ends_with(".ttc") || l.ends_with(".otf"))
+                    .map(PathBuf::from)
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    // fc-list는 순서가 일정하지 않으므로 정렬해 실행마다 같은 폰트를 고른다.
+    let mut listed = run(&["fc-list", "-f", "%{file}\\n", ":lang=ko"]);
+    listed.sort();
+    let mut out: Vec<PathBuf> = run(&["fc-match", "-f", "%{file}\\n", ":lang=ko"])
+        .into_iter()
+        .filter(|p| listed.contains(p))
+        .collect();
+    out.extend(listed);
+    out
+}
+
+/// 사용자가 선택한 경로의 폰트를 egui에 등록한다.
+fn load_custom_font(ctx: &egui::Context, path: &str) -> Result<(), String> {
+    let p = Path::new(path);
+    if !p.exists() {
+        return Err(format!("Font file not found: {path}"));
+    }
+    let bytes = fs::read(p).map_err(|e| format!("Failed to read font file: {e}"))?;
+    apply_font_bytes(ctx, bytes, "user_font");
+    Ok(())
+}
+
+/// 현장 대기압 기준 진공 포화표를 만든다.
+fn build_vacuum_table() -> Vec<steam::VacuumTableRow> {
+    steam::vacuum_saturation_table(&steam::VACUUM_TABLE_MMHG_GAUGE, site_atmosphere().bar_abs())
+}
+
+fn vacuum_table_ui<F>(ui: &mut egui::Ui, rows: &[steam::VacuumTableRow], txt: &F)
+where
+    F: Fn(&str, &str) -> String,
+{
+    ui.small(txt(
+        "gui.steam.vacuum_table.intro",
+        "Fix pressure to mmHg (gauge) and show IF97 saturation temps.",
+    ));
+    egui::Grid::new(ui.next_auto_id())
+        .num_columns(3)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            ui.strong("mmHg(g)");
+            ui.strong("P(bar a)");
+            ui.strong("Tsat(°C)");
+            ui.end_row();
+            for row in rows {
+                ui.label(fmt_num(row.mmhg_gauge, 0));
+                ui.label(fmt_qty(row.pressure_bar_abs, QuantityKind::Pressure));
+                if let Some(t) = row.saturation_temperature_c {
+                    ui.label(fmt_num(t, 2));
+                } else {
+                    ui.label("N/A");
+                }
+                ui.end_row();
+            }
+        });
+}
+
+/// 배관 구간 피팅 표의 한 행 (카탈로그 코드와 개수).
+#[derive(Clone)]
+struct FittingRow {
+    code: String,
+    count: u32,
+}
+
+/// 표의 행으로 피팅 목록을 만든다. 카탈로그에 없는 코드는 건너뛴다.
+fn fitting_list(rows: &[FittingRow]) -> fittings::FittingList {
+    rows.iter().fold(fittings::FittingList::new(), |list, row| {
+        match fittings::find_fitting(&row.code) {
+            Some(f) => list.add(f, row.count),
+            None => list,
+        }
+    })
+}
+
+/// 편집 가능한 피팅 표. K 열은 완전 난류(Re→∞) 기준 1개당 값이다.
+fn fittings_table_ui<F>(
+    ui: &mut egui::Ui,
+    id: &str,
+    rows: &mut Vec<FittingRow>,
+    method: &mut fittings::FittingMethod,
+    diameter_m: f64,
+    txt: &F,
+) where
+    F: Fn(&str, &str) -> String,
+{
+    ui.horizontal(|ui| {
+        ui.label(txt("gui.fittings.method", "K method"));
+        for m in fittings::FittingMethod::ALL {
+            ui.selectable_value(method, m, m.label());
+        }
+    });
+    let mut remove_idx: Option<usize> = None;
+    egui::Grid::new(id)
+        .num_columns(4)
+        .spacing([8.0, 4.0])
+        .show(ui, |ui| {
+            ui.strong(txt("gui.fittings.fitting", "Fitting"));
+            ui.strong(txt("gui.fittings.count", "Count"));
+            ui.strong(txt("gui.fittings.k_each", "K each (turbulent)"));
+            ui.label("");
+            ui.end_row();
+            for (i, row) in rows.iter_mut().enumerate() {
+                let current = fittings::find_fitting(&row.code);
+                egui::ComboBox::from_id_source((id, i))
+                    .width(240.0)
+                    .selected_text(current.map(|f| txt(f.key, f.name)).unwrap_or_default())
+                    .show_ui(ui, |ui| {
+                        for f in fittings::catalog() {
+                            if ui.selectable_label(f.code == row.code, txt(f.key, f.name)).clicked() {
+                                row.code = f.code.to_string();
+                            }
+                        }
+                    });
+                ui.add(egui::DragValue::new(&mut row.count).speed(0.1).clamp_range(0..=999));
+                match current {
+                    Some(f) => ui.label(fmt_num(f.k(*method, f64::INFINITY, diameter_m), 3)),
+                    None => ui.label("-"),
+                };
+                if ui.small_button("-").clicked() {
+                    remove_idx = Some(i);
+                }
+                ui.end_row();
+            }
+        });
+    if let Some(i) = remove_idx {
+        rows.remove(i);
+    }
+    ui.horizontal(|ui| {
+        if ui.small_button(txt("gui.fittings.add_row", "+ Add fitting")).clicked() {
+            rows.push(FittingRow {
+                code: "elbow90-lr".into(),
+                count: 1,
+            });
+        }
+        let list = fitting_list(rows);
+        if !list.is_empty() {
+            ui.label(fill_template(
+                &txt("gui.fittings.total", "ΣK≈{k:.2} (turbulent)"),
+                &[("k", fmt_num(list.k_sum(*method, f64::INFINITY, diameter_m), 3))],
+            ));
+        }
+    });
+}
+
+/// 배관 경로 편집기의 구간 한 행. 피팅은 CSV와 같은 `elbow90-lr:4, gate` 형식 문자열이다.
+#[derive(Clone)]
+struct PipeRunRow {
+    name: String,
+    diameter_mm: f64,
+    length_m: f64,
+    rise_m: f64,
+    roughness_mm: f64,
+    extra_k: f64,
+    fittings: String,
+}
+
+impl PipeRunRow {
+    fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            diameter_mm: 102.3,
+            length_m: 50.0,
+            rise_m: 0.0,
+            roughness_mm: 0.045,
+            extra_k: 0.0,
+            fittings: String::new(),
+        }
+    }
+
+    fn from_segment(s: &pipe_run::PipeSegment) -> Self {
+        Self {
+            name: s.name.clone(),
+            diameter_mm: s.diameter_m * 1000.0,
+            length_m: s.length_m,
+            rise_m: s.elevation_change_m,
+            roughness_mm: s.roughness_m * 1000.0,
+            extra_k: s.extra_k,
+            fittings: s
+                .fittings
+                .items()
+                .iter()
+                .map(|(f, n)| format!("{}:{n}", f.code))
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+
+    fn segment(&self) -> Result<pipe_run::PipeSegment, PipeCalcError> {
+        Ok(pipe_run::PipeSegment {
+            name: self.name.clone(),
+            diameter_m: self.diameter_mm / 1000.0,
+            length_m: self.length_m,
+            elevation_change_m: self.rise_m,
+            roughness_m: self.roughness_mm / 1000.0,
+            fittings: fittings::FittingList::parse(&self.fittings)?,
+            extra_k: self.extra_k,
+        })
+    }
+}
+
+/// 카드별 스윕(What-if) 설정과 결과.
+struct SweepUi {
+    param: usize,
+    start: f64,
+    end: f64,
+    step: f64,
+    plot_column: usize,
+    table: Option<sweep::SweepTable>,
+    error: Option<String>,
+}
+
+impl SweepUi {
+    fn new(start: f64, end: f64, step: f64) -> Self {
+        Self {
+            param: 0,
+            start,
+            end,
+            step,
+            plot_column: 0,
+            table: None,
+            error: None,
+        }
+    }
+
+    fn range(&self) -> sweep::SweepRange {
+        sweep::SweepRange::new(self.start, self.end, self.step)
+    }
+
+    fn set_result(&mut self, res: Result<sweep::SweepTable, sweep::SweepError>) {
+        match res {
+            Ok(table) => {
+                if self.plot_column >= table.columns.len() {
+                    self.plot_column = 0;
+                }
+                self.table = Some(table);
+                self.error = None;
+            }
+            Err(e) => {
+                self.table = None;
+                self.error = Some(e.to_string());
+            }
+        }
+    }
+}
+
+/// 스윕 파라미터/범위 입력과 Sweep 버튼을 그린다. 버튼이 눌리면 true.
+fn sweep_controls_ui<F>(
+    ui: &mut egui::Ui,
+    id: &str,
+    state: &mut SweepUi,
+    params: &[String],
+    txt: &F,
+) -> bool
+where
+    F: Fn(&str, &str) -> String,
+{
+    let mut clicked = false;
+    ui.horizontal_wrapped(|ui| {
+        ui.label(txt("gui.sweep.param", "Sweep parameter"));
+        egui::ComboBox::from_id_source(format!("{id}_param"))
+            .selected_text(params.get(state.param).cloned().unwrap_or_default())
+            .show_ui(ui, |ui| {
+                for (i, p) in params.iter().enumerate() {
+                    ui.selectable_value(&mut state.param, i, p);
+                }
+            });
+        ui.label(txt("gui.sweep.start", "from"));
+        ui.add(egui::DragValue::new(&mut state.start).speed(0.1));
+        ui.label(txt("gui.sweep.end", "to"));
+        ui.add(egui::DragValue::new(&mut state.end).speed(0.1));
+        ui.label(txt("gui.sweep.step", "step"));
+        ui.add(egui::DragValue::new(&mut state.step).speed(0.01));
+        let resp = ui
+            .button(txt("gui.sweep.run", "Sweep"))
+            .on_hover_text(txt(
+                "gui.sweep.run_tip",
+                "Repeat the calculation over the range and show a table and plot.",
+            ));
+        clicked = take_enter_for(&resp) || resp.clicked();
+    });
+    clicked
+}
+
+/// 스윕 결과 표와 그래프를 그린다.
+fn sweep_result_ui(ui: &mut egui::Ui, id: &str, state: &mut SweepUi, tr: &i18n::Translator) {
+    let txt = |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string());
+    if let Some(err) = &state.error {
+        ui.colored_label(ui.visuals().error_fg_color, err);
+    }
+    let Some(table) = &state.table else {
+        return;
+    };
+    ui.separator();
+    ui.horizontal(|ui| {
+        ui.label(txt("gui.sweep.plot_column", "Plot"));
+        egui::ComboBox::from_id_source(format!("{id}_plot_col"))
+            .selected_text(
+                table
+                    .columns
+                    .get(state.plot_column)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+            .show_ui(ui, |ui| {
+                for (i, c) in table.columns.iter().enumerate() {
+                    ui.selectable_value(&mut state.plot_column, i, c);
+                }
+            });
+        let failed = table.failed_count();
+        if failed > 0 {
+            ui.colored_label(
+                ui.visuals().warn_fg_color,
+                tr.plural("gui.sweep.failed", failed as u64, "{n} point(s) failed", &[]),
+            );
+        }
+    });
+    let series = table.series(state.plot_column);
+    let name = table
+        .columns
+        .get(state.plot_column)
+        .cloned()
+        .unwrap_or_default();
+    egui_plot::Plot::new(format!("{id}_plot"))
+        .height(180.0)
+        .x_axis_label(table.parameter.clone())
+        .y_axis_label(name.clone())
+        .show(ui, |plot_ui| {
+            plot_ui.line(egui_plot::Line::new(series.clone()).name(&name));
+            plot_ui.points(egui_plot::Points::new(series).radius(2.5));
+        });
+    egui::ScrollArea::vertical()
+        .id_source(format!("{id}_table"))
+        .max_height(200.0)
+        .show(ui, |ui| {
+            egui::Grid::new(format!("{id}_grid"))
+                .striped(true)
+                .spacing([12.0, 4.0])
+                .show(ui, |ui| {
+                    ui.strong(&table.parameter);
+                    for c in &table.columns {
+                        ui.strong(c);
+                    }
+                    ui.end_row();
+                    for row in &table.rows {
+                        ui.label(fmt_num(row.parameter, 4));
+                        match &row.values {
+                            Ok(values) => {
+                                for v in values {
+                                    ui.label(fmt_num(*v, 4));
+                                }
+                            }
+                            Err(e) => {
+                                ui.colored_label(ui.visuals().warn_fg_color, e);
+                            }
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+}
+
+/// 숫자 표 입력의 붙여넣기 칸 내용과 마지막 적용 결과 (성공 메시지 또는 오류).
+#[derive(Default)]
+struct TablePasteUi {
+    /// 붙여넣기 칸이 열려 있으면 그 내용
+    text: Option<String>,
+    message: Option<Result<String, String>>,
+}
+
+/// 숫자 표 입력 아래에 표 붙여넣기/복사 버튼과 붙여넣기 칸을 그린다.
+/// 엑셀이나 CSV에서 복사한 표를 적용하면 `columns`를 붙여 넣은 열로 통째로 바꾼다.
+fn table_paste_ui<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    state: &mut TablePasteUi,
+    headers: &[&str],
+    columns: &mut [&mut Vec<f64>],
+) where
+    F: Fn(&str, &str) -> String,
+{
+    ui.horizontal(|ui| {
+        if ui
+            .small_button(txt("gui.table.paste", "Paste table…"))
+            .on_hover_text(txt(
+                "gui.table.paste_tip",
+                "Replace the rows with a table copied from Excel or a CSV file",
+            ))
+            .clicked()
+        {
+            state.text = match state.text {
+                Some(_) => None,
+                None => Some(String::new()),
+            };
+            state.message = None;
+        }
+        if ui
+            .small_button(txt("gui.table.copy", "Copy table"))
+            .on_hover_text(txt(
+                "gui.table.copy_tip",
+                "Copy the rows as tab-separated text for pasting into Excel",
+            ))
+            .clicked()
+        {
+            let cols: Vec<&[f64]> = columns.iter().map(|c| c.as_slice()).collect();
+            ui.ctx().copy_text(table_edit::table_to_tsv(headers, &cols));
+        }
+    });
+    let (mut apply, mut cancel) = (false, false);
+    if let Some(text) = &mut state.text {
+        ui.add(
+            egui::TextEdit::multiline(text)
+                .desired_rows(4)
+                .hint_text(txt(
+                    "gui.table.paste_hint",
+                    "Paste rows here (Ctrl+V): one row per line, columns separated by tab, ; or ,",
+                )),
+        );
+        ui.horizontal(|ui| {
+            apply = ui.small_button(txt("gui.table.apply", "Apply")).clicked();
+            cancel = ui.small_button(txt("gui.table.cancel", "Cancel")).clicked();
+        });
+    }
+    if cancel {
+        state.text = None;
+    }
+    if apply {
+        let text = state.text.take().unwrap_or_default();
+        match table_edit::parse_table(&text, columns.len()) {
+            Ok(parsed) => {
+                let rows = parsed[0].len();
+                for (column, values) in columns.iter_mut().zip(parsed) {
+                    **column = values;
+                }
+                state.message = Some(Ok(fill_template(
+                    &txt("gui.table.applied", "{rows} rows imported"),
+                    &[("rows", rows.to_string())],
+                )));
+            }
+            Err(e) => {
+                state.message = Some(Err(format!(
+                    "{}: {e}",
+                    txt("gui.table.paste_error", "Paste failed")
+                )));
+                state.text = Some(text);
+            }
+        }
+    }
+    match &state.message {
+        Some(Ok(msg)) => {
+            ui.label(msg);
+        }
+        Some(Err(msg)) => {
+            ui.colored_label(ui.visuals().error_fg_color, msg);
+        }
+        None => {}
+    }
+}
+
+/// 밸브 데이터베이스 불러오기/저장 줄의 저장 태그와 마지막 결과 (성공 메시지 또는 오류).
+#[derive(Default)]
+struct ValveDbUi {
+    save_tag: String,
+    status: Option<Result<String, String>>,
+}
+
+/// 밸브 데이터베이스 줄에서 고른 동작.
+enum ValveDbAction {
+    Load(valve_db::ValveRecord),
+    /// 입력 태그로 현재 값을 저장
+    Save(String),
+}
+
+/// 밸브 데이터베이스에서 불러오는 드롭다운과 (`can_save`면) 태그 저장 칸을 그린다.
+fn valve_db_ui<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    id: &str,
+    db: &valve_db::ValveDb,
+    state: &mut ValveDbUi,
+    can_save: bool,
+) -> Option<ValveDbAction>
+where
+    F: Fn(&str, &str) -> String,
+{
+    let mut action = None;
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_source(id)
+            .selected_text(txt("gui.valve_db.load", "Load from database"))
+            .show_ui(ui, |ui| {
+                if db.is_empty() {
+                    ui.label(txt(
+                        "gui.valve_db.empty",
+                        "No valves yet (saved to valve_db.toml)",
+                    ));
+                }
+                for record in db.valves() {
+                    let label = if record.description.is_empty() {
+                        format!("{} (Cv {})", record.tag, fmt_num(record.rated_cv, 1))
+                    } else {
+                        format!(
+                            "{} – {} (Cv {})",
+                            record.tag,
+                            record.description,
+                            fmt_num(record.rated_cv, 1),
+                        )
+                    };
+                    if ui.selectable_label(false, label).clicked() {
+                        action = Some(ValveDbAction::Load(record.clone()));
+                    }
+                }
+            });
+        if can_save {
+            ui.add(
+                egui::TextEdit::singleline(&mut state.save_tag)
+                    .desired_width(90.0)
+                    .hint_text(txt("gui.valve_db.tag_hint", "Valve tag")),
+            );
+            if ui
+                .small_button(txt("gui.valve_db.save", "Save to database"))
+                .on_hover_text(txt(
+                    "gui.valve_db.save_tip",
+                    "Store the Cv and stroke-Cv table under this tag (an existing tag is overwritten)",
+                ))
+                .clicked()
+            {
+                action = Some(ValveDbAction::Save(state.save_tag.trim().to_string()));
+            }
+        }
+    });
+    if let Some(ValveDbAction::Load(record)) = &action {
+        state.save_tag = record.tag.clone();
+        state.status = Some(Ok(fill_template(
+            &txt("gui.valve_db.loaded", "Loaded {tag}"),
+            &[("tag", record.tag.clone())],
+        )));
+    }
+    match &state.status {
+        Some(Ok(msg)) => {
+            ui.small(msg);
+        }
+        Some(Err(msg)) => {
+            ui.colored_label(ui.visuals().error_fg_color, msg);
+        }
+        None => {}
+    }
+    action
+}
+
+/// 스트로크-Cv 입력으로 저장할 밸브 자료를 만든다. 같은 태그의 특성, FL/xT와 설명은 유지한다.
+fn valve_record_from_table(
+    db: &valve_db::ValveDb,
+    tag: &str,
+    cv: f64,
+    kind: FlowCoefficientKind,
+    strokes: &[f64],
+    cvs: &[f64],
+) -> valve_db::ValveRecord {
+    let to_cv_us = |v: f64| flow_coefficient::convert(v, kind, FlowCoefficientKind::CvUs);
+    let stroke_cv: Vec<[f64; 2]> = if stroke_based_kv_available(strokes, cvs) {
+        strokes
+            .iter()
+            .zip(cvs)
+            .map(|(&s, &c)| [s, to_cv_us(c)])
+            .collect()
+    } else {
+        Vec::new()
+    };
+    let table_max = stroke_cv.iter().map(|p| p[1]).fold(0.0, f64::max);
+    let mut record = db
+        .get(tag)
+        .cloned()
+        .unwrap_or_else(|| valve_db::ValveRecord::new(tag, 0.0));
+    record.tag = tag.to_string();
+    record.rated_cv = if cv > 0.0 { to_cv_us(cv) } else { table_max };
+    record.stroke_cv = stroke_cv;
+    record
+}
+
+/// 밸브 자료를 데이터베이스에 넣고 `valve_db.toml`에 저장한 결과를 상태 줄에 남긴다.
+fn save_valve_record(
+    tr: &i18n::Translator,
+    db: &mut valve_db::ValveDb,
+    state: &mut ValveDbUi,
+    record: valve_db::ValveRecord,
+) {
+    let tag = record.tag.clone();
+    state.status = Some(match db.upsert(record).and_then(|_| db.save_default()) {
+        Ok(()) => Ok(fill_template(
+            &tr.lookup("gui.valve_db.saved")
+                .unwrap_or_else(|| "Saved {tag} to valve_db.toml".to_string()),
+            &[("tag", tag)],
+        )),
+        Err(e) => Err(error_text(tr, e)),
+    });
+}
+
+/// 배관 등급 선택 콤보. `None`은 등급 검토 안 함.
+fn pipe_class_combo<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    id: &str,
+    catalog: &pipe_class::PipeClassCatalog,
+    choice: &mut Option<String>,
+) where
+    F: Fn(&str, &str) -> String,
+{
+    let none = txt("gui.pipe_class.none", "None (no class check)");
+    egui::ComboBox::from_id_source(id)
+        .selected_text(choice.clone().unwrap_or_else(|| none.clone()))
+        .show_ui(ui, |ui| {
+            ui.selectable_value(choice, None, none.as_str());
+            for class in catalog.classes() {
+                let label = if class.description.is_empty() {
+                    class.name.clone()
+                } else {
+                    format!("{} – {}", class.name, class.description)
+                };
+                ui.selectable_value(choice, Some(class.name.clone()), label);
+            }
+        });
+}
+
+/// 배관 등급 검토 결과를 결과 줄로 만든다. 등급 한계를 벗어난 항목은 경고 줄로 붙인다.
+fn pipe_class_check_text<F>(txt: &F, check: &pipe_class::PipeClassCheck) -> String
+where
+    F: Fn(&str, &str) -> String,
+{
+    let mut out = fill_template(
+        &txt(
+            "gui.pipe_class.result",
+            "Class {class}: DN{dn} {schedule}, ID {id} mm, wall {wall} mm (required {req} mm)",
+        ),
+        &[
+            ("class", check.class.clone()),
+            ("dn", check.pipe.dn.to_string()),
+            ("schedule", check.pipe.schedule.clone()),
+            ("id", fmt_num(check.pipe.inner_diameter_m * 1000.0, 1)),
+            ("wall", fmt_num(check.pipe.wall_m * 1000.0, 2)),
+            ("req", fmt_num(check.required_wall_m * 1000.0, 2)),
+        ],
+    );
+    if check.acceptable() {
+        out.push('\n');
+        out.push_str(&txt("gui.pipe_class.ok", "Acceptable for this class"));
+    }
+    for v in &check.violations {
+        out.push_str("\n⚠ ");
+        out.push_str(v);
+    }
+    out
+}
+
+/// 차압식 유량계의 영구 손실, 회복 거리, 펌핑 비용과 세 계량기 비교를 결과 줄로 만든다.
+fn flow_meter_loss_text<F>(
+    txt: &F,
+    tr: &i18n::Translator,
+    input: &flow_meter::MeterLossInput,
+) -> String
+where
+    F: Fn(&str, &str) -> String,
+{
+    let results = match flow_meter::compare_meters(input) {
+        Ok(r) => r,
+        Err(e) => return format!("\n{e}"),
+    };
+    let mut out = String::new();
+    if let Some(sel) = results.iter().find(|r| r.meter == input.meter) {
+        out.push('\n');
+        out.push_str(&fill_template(
+            &txt(
+                "gui.plant.meter.loss",
+                "Permanent loss Δϖ = {loss} bar ({ratio}% of ΔP), {rec} bar recovered within {dist} m downstream",
+            ),
+            &[
+                ("loss", fmt_qty(sel.permanent_loss_bar, QuantityKind::Pressure)),
+                ("ratio", fmt_num(sel.loss_ratio * 100.0, 0)),
+                ("rec", fmt_qty(sel.recovered_bar, QuantityKind::Pressure)),
+                ("dist", fmt_qty(sel.recovery_distance_m, QuantityKind::Length)),
+            ],
+        ));
+        out.push('\n');
+        out.push_str(&fill_template(
+            &txt(
+                "gui.plant.meter.energy",
+                "Pumping energy: {kw} kW, {kwh} kWh/yr, cost {cost}/yr",
+            ),
+            &[
+                ("kw", fmt_num(sel.pumping_power_kw, 3)),
+                ("kwh", fmt_num(sel.annual_energy_kwh, 0)),
+                ("cost", fmt_num(sel.annual_cost, 0)),
+            ],
+        ));
+        push_warnings(&mut out, tr, &sel.warnings);
+    }
+    out.push('\n');
+    out.push_str(&txt(
+        "gui.plant.meter.compare",
+        "Same flow and beta, typical Cd:",
+    ));
+    for r in &results {
+        out.push('\n');
+        out.push_str(&fill_template(
+            &txt(
+                "gui.plant.meter.compare_row",
+                "  {meter}: ΔP={dp} bar, Δϖ={loss} bar, {cost}/yr",
+            ),
+            &[
+                ("meter", txt(r.meter.i18n_key(), r.meter.label())),
+                ("dp", fmt_qty(r.differential_bar, QuantityKind::Pressure)),
+                ("loss", fmt_qty(r.permanent_loss_bar, QuantityKind::Pressure)),
+                ("cost", fmt_num(r.annual_cost, 0)),
+            ],
+        ));
+    }
+    out
+}
+
+impl GuiApp {
+    fn new(config: config::Config) -> Self {
+        let lang_code = i18n::resolve_language("auto", Some(config.language.as_str()));
+        let mut tr = i18n::Translator::new_with_pack(&lang_code, config.language_pack_dir.as_deref());
+        tr.set_number_format(&config.number_format);
+        let has_overrides = tr.lookup("gui.nav.app_title").is_some();
+        eprintln!("GUI language resolved: {lang_code}, overrides_loaded={has_overrides}");
+        let lang_input = config.language.clone();
+        let lang_pack_dir_input = config.language_pack_dir.clone().unwrap_or_default();
+        set_number_format(tr.number_format());
+        set_site_atmosphere(config.site.atmosphere());
+        let valve_db = valve_db::ValveDb::load_default().unwrap_or_else(|e| {
+            eprintln!("Valve database error: {e}");
+            valve_db::ValveDb::default()
+        });
+        let pipe_classes = pipe_class::PipeClassCatalog::load_default().unwrap_or_else(|e| {
+            eprintln!("Piping class error: {e}");
+            pipe_class::PipeClassCatalog::default()
+        });
+        let mut s = Self {
+            config: config.clone(),
+            tr,
+            lang_input,
+            lang_pack_dir_input,
+            lang_save_status: None,
+            number_format_input: config.number_format.clone(),
+            site_input: config.site.clone(),
+            profile_choice: config.active_profile.clone().unwrap_or_default(),
+            profile_new_name: String::new(),
+            tab: Tab::UnitConv,
+            export_status: None,
+            window_alpha: config.window_alpha.clamp(0.3, 1.0),
+            show_formula_modal: false,
+            show_legend_steam: false,
+            show_legend_pipe: false,
+            show_legend_pipe_loss: false,
+            show_legend_valve: false,
+            show_legend_plant: false,
+            steam_result: None,
+            steam_compare_reference: false,
+            steam_reference_result: None,
+            show_vacuum_table_window: false,
+            vacuum_table: None,
+            detached_windows: config
+                .detached_windows
+                .iter()
+                .filter(|w| Panel::from_id(&w.panel).is_some())
+                .cloned()
+                .collect(),
+            apply_initial_view_size: true,
+            pipe_service: None,
+            pipe_classes,
+            pipe_class_choice: None,
+            pipe_result: None,
+            pipe_loss_fittings: Vec::new(),
+            pipe_loss_result: None,
+            pipe_loss_sweep: SweepUi::new(0.025, 0.3, 0.025),
+            pipe_run_rows: vec![PipeRunRow::new("1")],
+            pipe_run_result: None,
+            pipe_cond_result: None,
+            blow_result: None,
+            pipe_loss_last: None,
+            pipe_loss_work: Vec::new(),
+            valve_result: None,
+            valve_work: Vec::new(),
+            valve_sweep: SweepUi::new(0.5, 10.0, 0.5),
+            valve_db,
+            valve_db_ui: ValveDbUi::default(),
+            valve_auth_points: Vec::new(),
+            valve_auth_result: None,
+            valve_last: None,
+            inputs: card_inputs::GuiInputs::for_fluid(&config.fluid).with_site(&config.site),
+            input_defaults: card_inputs::GuiInputs::default(),
+            line_case: line_case::LineCase {
+                source_pressure_bar_abs: 11.0,
+                sink_pressure_bar_abs: 6.5,
+                source_temp_c: 250.0,
+                mass_flow_kg_per_h: 3000.0,
+                ..Default::default()
+            },
+            line_case_result: None,
+            stream_clip: None,
+            steam_stream: None,
+            pipe_stream: None,
+            bypass_table_paste: TablePasteUi::default(),
+            bypass_valve_db: ValveDbUi::default(),
+            bypass_result: None,
+            spray_table_paste: TablePasteUi::default(),
+            spray_valve_db: ValveDbUi::default(),
+            spray_calc_result: None,
+            plant_result: None,
+            plant_expansion_result: None,
+            plant_freeze_result: None,
+            plant_gas_result: None,
+            plant_bd_result: None,
+            plant_pressure_result: None,
+            plant_test_result: None,
+            plant_fill_volume_m3: None,
+            plant_fill_result: None,
+            boiler_result: None,
+            purity_result: None,
+            widgets: cards::all(),
+            condenser_result: None,
+            condenser_last_input: None,
+            condenser_work: Vec::new(),
+            condenser_sweep: SweepUi::new(15.0, 35.0, 2.5),
+            ct_result: None,
+            evap_result: None,
+            npsh_result: None,
+            drain_result: None,
+            font_size: 16.0,
+            ui_scale: 1.0,
+            always_on_top: true,
+            selectable_results: config.selectable_results,
+            typed_quantity_fields: config.typed_quantity_fields,
+            show_settings_modal: false,
+            show_help_modal: false,
+            palette_open: false,
+            palette_query: String::new(),
+            palette_selected: 0,
+            palette_recent: Vec::new(),
+            theme: config.theme.clone(),
+            custom_font_path: String::new(),
+            font_source: String::new(),
+            font_load_error: None,
+        };
+        s.apply_unit_preset(s.config.unit_system);
+        s.input_defaults = s.inputs.clone();
+        s
+    }
+
+    /// 지난 실행에서 저장한 카드 입력을 불러온다. 파일이 없으면 기본값을 그대로 둔다.
+    fn restore_inputs(&mut self) {
+        match card_inputs::GuiInputs::load(Path::new(card_inputs::INPUTS_FILE)) {
+            Ok(Some(saved)) => {
+                // 고정한 카드가 있으면 대시보드에서 시작한다
+                if !saved.dashboard.pinned.is_empty() {
+                    self.tab = Tab::Dashboard;
+                }
+                self.inputs = saved;
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!("Input restore error: {e}"),
+        }
+        for card in &mut self.widgets {
+            card.load_inputs(&self.inputs);
+        }
+    }
+
+    /// 카드 입력을 config.toml 옆 파일에 기록한다.
+    fn save_inputs(&self) {
+        let mut inputs = self.inputs.clone();
+        for card in &self.widgets {
+            card.save_inputs(&mut inputs);
+        }
+        if let Err(e) = inputs.save(Path::new(card_inputs::INPUTS_FILE)) {
+            eprintln!("Input save error: {e}");
+        }
+    }
+
+    /// 설정 언어/언어팩/숫자 형식으로 번역기를 다시 만든다.
+    fn reload_translator(&mut self) {
+        let resolved = i18n::resolve_language(&self.config.language, self.config.language_pack_dir.as_deref());
+        self.tr = i18n::Translator::new_with_pack(&resolved, self.config.language_pack_dir.as_deref());
+        self.tr.set_number_format(&self.config.number_format);
+        set_number_format(self.tr.number_format());
+    }
+
+    /// 현장 조건을 대기압 환산과 대기 온도 입력 기본값에 반영한다.
+    fn apply_site_conditions(&mut self) {
+        let site = &self.config.site;
+        set_site_atmosphere(site.atmosphere());
+        self.vacuum_table = None;
+        self.inputs.cooling.tower.db = convert_temperature_gui(site.design_dry_bulb_c, "C", &self.inputs.cooling.tower.temp_unit);
+        self.inputs.cooling.tower.wb = convert_temperature_gui(site.design_wet_bulb_c, "C", &self.inputs.cooling.tower.temp_unit);
+        self.inputs.cooling.evaporative.db_c = site.design_dry_bulb_c;
+        self.inputs.cooling.evaporative.wb_c = site.design_wet_bulb_c;
+    }
+
+    /// 프로파일 적용 후 언어/단위/현장 조건/유체 기본값을 화면 상태에 반영한다.
+    fn apply_profile_defaults(&mut self) {
+        self.lang_input = self.config.language.clone();
+        self.reload_translator();
+        self.apply_unit_preset(self.config.unit_system);
+        self.site_input = self.config.site.clone();
+        self.apply_site_conditions();
+        let fluid = &self.config.fluid;
+        self.inputs.cooling.npsh.rho = convert_density_gui(fluid.liquid_density_kg_per_m3, "kg/m3", &self.inputs.cooling.npsh.rho_unit);
+        self.inputs.valves.sizing.rho = convert_density_gui(fluid.gas_density_kg_per_m3, "kg/m3", &self.inputs.valves.sizing.rho_unit);
+    }
+
+    /// 단위 시스템 프리셋을 UI 기본 단위에 적용한다.
+    pub(crate) fn apply_unit_preset(&mut self, system: config::UnitSystem) {
+        match system {
+            config::UnitSystem::SIBar => {
+                self.inputs.steam_tables.properties.p_unit = "bar".into();
+                self.inputs.steam_tables.properties.p_mode = conversion::PressureMode::Gauge;
+                self.inputs.steam_tables.properties.p_unit_out = "bar".into();
+                self.inputs.steam_tables.properties.p_mode_out = conversion::PressureMode::Absolute;
+                self.inputs.steam_tables.properties.t_unit = "C".into();
+                self.inputs.steam_tables.properties.t_unit_out = "C".into();
+                self.inputs.piping.sizing.pressure_unit = "bar".into();
+                self.inputs.piping.sizing.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.piping.sizing.temp_unit = "C".into();
+                self.inputs.piping.sizing.velocity_unit = "m/s".into();
+                self.inputs.piping.sizing.diam_out_unit = "m".into();
+                self.inputs.piping.sizing.vel_out_unit = "m/s".into();
+                self.inputs.piping.loss.dp_out_unit = "bar".into();
+                self.inputs.piping.sizing.mass_unit = "kg/h".into();
+                self.inputs.valves.sizing.flow_unit = "m3/h".into();
+                self.inputs.valves.sizing.dp_unit = "bar".into();
+                self.inputs.valves.sizing.rho_unit = "kg/m3".into();
+                self.inputs.cooling.condenser.pressure_unit = "bar".into();
+                self.inputs.cooling.condenser.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.condenser.cw_temp_unit = "C".into();
+                self.inputs.cooling.condenser.cw_flow_unit = "m3/h".into();
+                self.inputs.cooling.condenser.duty_unit = "kW".into();
+                self.inputs.boiler.efficiency.heat_unit = "kW".into();
+                self.inputs.cooling.condenser.backpressure_unit = "bar".into();
+                self.inputs.cooling.condenser.backpressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.tower.temp_unit = "C".into();
+                self.inputs.cooling.tower.flow_unit = "m3/h".into();
+                self.inputs.cooling.npsh.suction_unit = "bar".into();
+                self.inputs.cooling.npsh.suction_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.npsh.temp_unit = "C".into();
+                self.inputs.cooling.npsh.rho_unit = "kg/m3".into();
+                self.inputs.cooling.drain_cooler.temp_unit = "C".into();
+                self.inputs.cooling.drain_cooler.flow_unit = "m3/h".into();
+                self.inputs.plant_piping.orifice.dp_unit = "bar".into();
+            }
+            config::UnitSystem::SI => {
+                self.inputs.steam_tables.properties.p_unit = "kPa".into();
+                self.inputs.steam_tables.properties.p_mode = conversion::PressureMode::Gauge;
+                self.inputs.steam_tables.properties.p_unit_out = "kPa".into();
+                self.inputs.steam_tables.properties.p_mode_out = conversion::PressureMode::Absolute;
+                self.inputs.steam_tables.properties.t_unit = "C".into();
+                self.inputs.steam_tables.properties.t_unit_out = "C".into();
+                self.inputs.piping.sizing.pressure_unit = "kPa".into();
+                self.inputs.piping.sizing.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.piping.sizing.temp_unit = "C".into();
+                self.inputs.piping.sizing.velocity_unit = "m/s".into();
+                self.inputs.piping.sizing.diam_out_unit = "m".into();
+                self.inputs.piping.sizing.vel_out_unit = "m/s".into();
+                self.inputs.piping.loss.dp_out_unit = "kPa".into();
+                self.inputs.piping.sizing.mass_unit = "kg/h".into();
+                self.inputs.valves.sizing.flow_unit = "m3/h".into();
+                self.inputs.valves.sizing.dp_unit = "kPa".into();
+                self.inputs.valves.sizing.rho_unit = "kg/m3".into();
+                self.inputs.cooling.condenser.pressure_unit = "kPa".into();
+                self.inputs.cooling.condenser.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.condenser.cw_temp_unit = "C".into();
+                self.inputs.cooling.condenser.cw_flow_unit = "m3/h".into();
+                self.inputs.cooling.condenser.duty_unit = "kW".into();
+                self.inputs.boiler.efficiency.heat_unit = "kW".into();
+                self.inputs.cooling.condenser.backpressure_unit = "kPa".into();
+                self.inputs.cooling.condenser.backpressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.tower.temp_unit = "C".into();
+                self.inputs.cooling.tower.flow_unit = "m3/h".into();
+                self.inputs.cooling.npsh.suction_unit = "kPa".into();
+                self.inputs.cooling.npsh.suction_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.npsh.temp_unit = "C".into();
+                self.inputs.cooling.npsh.rho_unit = "kg/m3".into();
+                self.inputs.cooling.drain_cooler.temp_unit = "C".into();
+                self.inputs.cooling.drain_cooler.flow_unit = "m3/h".into();
+                self.inputs.plant_piping.orifice.dp_unit = "kPa".into();
+            }
+            config::UnitSystem::MKS => {
+                self.inputs.steam_tables.properties.p_unit = "bar".into();
+                self.inputs.steam_tables.properties.p_mode = conversion::PressureMode::Absolute;
+                self.inputs.steam_tables.properties.p_unit_out = "bar".into();
+                self.inputs.steam_tables.properties.p_mode_out = conversion::PressureMode::Absolute;
+                self.inputs.steam_tables.properties.t_unit = "C".into();
+                self.inputs.steam_tables.properties.t_unit_out = "C".into();
+                self.inputs.piping.sizing.pressure_unit = "bar".into();
+                self.inputs.piping.sizing.pressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.piping.sizing.temp_unit = "C".into();
+                self.inputs.piping.sizing.velocity_unit = "m/s".into();
+                self.inputs.piping.sizing.diam_out_unit = "m".into();
+                self.inputs.piping.sizing.vel_out_unit = "m/s".into();
+                self.inputs.piping.loss.dp_out_unit = "bar".into();
+                self.inputs.piping.sizing.mass_unit = "kg/h".into();
+                self.inputs.valves.sizing.flow_unit = "m3/h".into();
+                self.inputs.valves.sizing.dp_unit = "bar".into();
+                self.inputs.valves.sizing.rho_unit = "kg/m3".into();
+                self.inputs.cooling.condenser.pressure_unit = "bar".into();
+                self.inputs.cooling.condenser.pressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.condenser.cw_temp_unit = "C".into();
+                self.inputs.cooling.condenser.cw_flow_unit = "m3/h".into();
+                self.inputs.cooling.condenser.duty_unit = "kW".into();
+                self.inputs.boiler.efficiency.heat_unit = "kW".into();
+                self.inputs.cooling.condenser.backpressure_unit = "bar".into();
+                self.inputs.cooling.condenser.backpressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.tower.temp_unit = "C".into();
+                self.inputs.cooling.tower.flow_unit = "m3/h".into();
+                self.inputs.cooling.npsh.suction_unit = "bar".into();
+                self.inputs.cooling.npsh.suction_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.npsh.temp_unit = "C".into();
+                self.inputs.cooling.npsh.rho_unit = "kg/m3".into();
+                self.inputs.cooling.drain_cooler.temp_unit = "C".into();
+                self.inputs.cooling.drain_cooler.flow_unit = "m3/h".into();
+                self.inputs.plant_piping.orifice.dp_unit = "bar".into();
+            }
+            config::UnitSystem::Imperial => {
+                self.inputs.steam_tables.properties.p_unit = "psi".into();
+                self.inputs.steam_tables.properties.p_mode = conversion::PressureMode::Gauge;
+                self.inputs.steam_tables.properties.p_unit_out = "psi".into();
+                self.inputs.steam_tables.properties.p_mode_out = conversion::PressureMode::Gauge;
+                self.inputs.steam_tables.properties.t_unit = "F".into();
+                self.inputs.steam_tables.properties.t_unit_out = "F".into();
+                self.inputs.piping.sizing.pressure_unit = "psi".into();
+                self.inputs.piping.sizing.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.piping.sizing.temp_unit = "F".into();
+                self.inputs.piping.sizing.velocity_unit = "ft/s".into();
+                self.inputs.piping.sizing.diam_out_unit = "in".into();
+                self.inputs.piping.sizing.vel_out_unit = "ft/s".into();
+                self.inputs.piping.loss.dp_out_unit = "psi".into();
+                self.inputs.piping.sizing.mass_unit = "lb/h".into();
+                self.inputs.valves.sizing.flow_unit = "gpm".into();
+                self.inputs.valves.sizing.dp_unit = "psi".into();
+                self.inputs.valves.sizing.rho_unit = "lb/ft3".into();
+                self.inputs.valves.bypass.up_unit = "psi".into();
+                self.inputs.valves.bypass.down_unit = "psi".into();
+                self.inputs.valves.bypass.up_mode = conversion::PressureMode::Gauge;
+                self.inputs.valves.bypass.down_mode = conversion::PressureMode::Gauge;
+                self.inputs.valves.bypass.t_unit = "F".into();
+                self.inputs.valves.bypass.spray_temp_unit = "F".into();
+                self.inputs.valves.bypass.cv_kind = FlowCoefficientKind::CvUs;
+                self.inputs.valves.spray.up_unit = "psi".into();
+                self.inputs.valves.spray.down_unit = "psi".into();
+                self.inputs.valves.spray.up_mode = conversion::PressureMode::Gauge;
+                self.inputs.valves.spray.down_mode = conversion::PressureMode::Gauge;
+                self.inputs.valves.spray.temp_unit = "F".into();
+                self.inputs.valves.spray.cv_kind = FlowCoefficientKind::CvUs;
+                // 보일러/에너지 단위
+                self.inputs.boiler.efficiency.fuel_unit = "lb/h".into();
+                self.inputs.boiler.efficiency.lhv_unit = "Btu/lb".into();
+                self.inputs.boiler.efficiency.steam_unit = "lb/h".into();
+                self.inputs.boiler.efficiency.h_steam_unit = "Btu/lb".into();
+                self.inputs.boiler.efficiency.h_fw_unit = "Btu/lb".into();
+                self.inputs.boiler.efficiency.fg_flow_unit = "lb/h".into();
+                self.inputs.boiler.efficiency.temp_unit = "F".into();
+                self.inputs.cooling.condenser.pressure_unit = "psi".into();
+                self.inputs.cooling.condenser.pressure_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.condenser.cw_temp_unit = "F".into();
+                self.inputs.cooling.condenser.cw_flow_unit = "gpm".into();
+                self.inputs.cooling.condenser.duty_unit = "Btu/h".into();
+                self.inputs.boiler.efficiency.heat_unit = "Btu/h".into();
+                self.inputs.cooling.condenser.backpressure_unit = "psi".into();
+                self.inputs.cooling.condenser.backpressure_mode = conversion::PressureMode::Absolute;
+                self.inputs.cooling.tower.temp_unit = "F".into();
+                self.inputs.cooling.tower.flow_unit = "gpm".into();
+                self.inputs.cooling.npsh.suction_unit = "psi".into();
+                self.inputs.cooling.npsh.suction_mode = conversion::PressureMode::Gauge;
+                self.inputs.cooling.npsh.temp_unit = "F".into();
+                self.inputs.cooling.npsh.rho_unit = "lb/ft3".into();
+                self.inputs.cooling.drain_cooler.temp_unit = "F".into();
+                self.inputs.cooling.drain_cooler.flow_unit = "gpm".into();
+                self.inputs.plant_piping.orifice.dp_unit = "psi".into();
+            }
+        }
+    }
+    fn is_detached(&self, panel: Panel) -> bool {
+        self.detached_windows.iter().any(|w| w.panel == panel.id())
+    }
+
+    /// 패널을 분리 창으로 띄운다 (이미 떠 있으면 그대로).
+    fn detach_panel(&mut self, panel: Panel) {
+        if self.is_detached(panel) {
+            return;
+        }
+        let mut win = config::DetachedWindow::new(panel.id());
+        if panel == Panel::VacuumTable {
+            win.size = [420.0, 640.0];
+        }
+        self.detached_windows.push(win);
+        self.save_window_layout();
+    }
+
+    fn close_detached(&mut self, panel: Panel) {
+        self.detached_windows.retain(|w| w.panel != panel.id());
+        self.save_window_layout();
+    }
+
+    /// 분리 창 배치를 config.toml에 기록한다.
+    fn save_window_layout(&mut self) {
+        self.config.detached_windows = self.detached_windows.clone();
+        if let Err(e) = self.config.save() {
+            eprintln!("Layout save error: {e}");
+        }
+    }
+
+    /// 탭 본문 (본 창과 분리 창 공용)
+    fn ui_tab(&mut self, ui: &mut egui::Ui, tab: Tab) {
+        match tab {
+            Tab::Dashboard => self.ui_dashboard(ui),
+            Tab::UnitConv => self.ui_unit_conv(ui),
+            Tab::SteamTables => self.ui_steam_tables(ui),
+            Tab::SteamPiping => self.ui_steam_piping(ui),
+            Tab::SteamValves => self.ui_steam_valves(ui),
+            Tab::Boiler => self.ui_boiler(ui),
+            Tab::Cooling => self.ui_cooling(ui),
+            Tab::PlantPiping => self.ui_plant_piping(ui),
+            Tab::Condensate => self.ui_condensate(ui),
+        }
+    }
+
+    fn ui_panel<F>(&mut self, ui: &mut egui::Ui, panel: Panel, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        match panel {
+            Panel::Tab(tab) => self.ui_tab(ui, tab),
+            Panel::VacuumTable => {
+                vacuum_table_ui(ui, self.vacuum_table.get_or_insert_with(build_vacuum_table), txt)
+            }
+        }
+    }
+
+    /// 분리 창을 모두 그린다. 창마다 독립 뷰포트이며, 다중 창을 지원하지 않는
+    /// 백엔드에서는 본 창 안의 떠 있는 창으로 대신 보여 준다.
+    fn show_detached_windows<F>(&mut self, ctx: &egui::Context, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        let mut layout_changed = false;
+        for idx in (0..self.detached_windows.len()).rev() {
+            let win = self.detached_windows[idx].clone();
+            let Some(panel) = Panel::from_id(&win.panel) else {
+                continue;
+            };
+            let (key, default) = panel.title_key();
+            let title = txt(key, default);
+            let mut builder = egui::ViewportBuilder::default()
+                .with_title(title.clone())
+                .with_inner_size(win.size);
+            if let Some(pos) = win.position {
+                builder = builder.with_position(pos);
+            }
+            let mut open = true;
+            let mut geometry = None;
+            ctx.show_viewport_immediate(
+                egui::ViewportId::from_hash_of(("detached", panel.id())),
+                builder,
+                |ctx, class| {
+                    if class == egui::ViewportClass::Embedded {
+                        egui::Window::new(&title)
+                            .id(egui::Id::new(("detached_embedded", panel.id())))
+                            .open(&mut open)
+                            .vscroll(true)
+                            .show(ctx, |ui| self.ui_panel(ui, panel, txt));
+                        return;
+                    }
+                    with_viewport_enter_focus(ctx, || {
+                        egui::CentralPanel::default().show(ctx, |ui| {
+                            egui::ScrollArea::vertical()
+                                .auto_shrink([false; 2])
+                                .show(ui, |ui| self.ui_panel(ui, panel, txt));
+                        });
+                    });
+                    ctx.input(|i| {
+                        let vp = i.viewport();
+                        open = !vp.close_requested();
+                        geometry = Some((
+                            vp.outer_rect.map(|r| [r.min.x, r.min.y]),
+                            vp.inner_rect.map(|r| [r.width(), r.height()]),
+                        ));
+                    });
+                },
+            );
+            if !open {
+                self.detached_windows.remove(idx);
+                layout_changed = true;
+                continue;
+            }
+            if let Some((pos, size)) = geometry {
+                let w = &mut self.detached_windows[idx];
+                w.position = pos.or(w.position);
+                w.size = size.unwrap_or(w.size);
+            }
+        }
+        if layout_changed {
+            self.save_window_layout();
+        }
+    }
+
+    /// 탭에 속한 계산 결과 (아직 계산하지 않은 카드는 빠진다).
+    fn tab_results(&self, tab: Tab) -> Vec<&str> {
+        let cards = match tab {
+            Tab::Dashboard => self.inputs.dashboard.pinned.as_slice(),
+            _ => tab.cards(),
+        };
+        cards.iter().flat_map(|&card| self.card_results(card)).collect()
+    }
+
+    /// 카드의 마지막 계산 결과.
+    fn card_results(&self, card: CardId) -> Vec<&str> {
+        let slots: &[&Option<String>] = match card {
+            CardId::ValveActuator
+            | CardId::RankineCycle
+            | CardId::UnitConversion
+            | CardId::FlowConversion
+            | CardId::Dryness
+            | CardId::HeatBalance
+            | CardId::Drum
+            | CardId::Hrsg
+            | CardId::Attemperator
+            | CardId::AirPreheater
+            | CardId::Emissions
+            | CardId::AirCooledCondenser
+            | CardId::CoolingComparison
+            | CardId::SaturatedNpsh
+            | CardId::Ejector
+            | CardId::MeterCompensation
+            | CardId::Totalizer
+            | CardId::GasThrottling
+            | CardId::Compressor
+            | CardId::LetdownNoise
+            | CardId::VentSilencer
+            | CardId::ReturnLine
+            | CardId::TankHeating
+            | CardId::Sparger => {
+                return self.widget(card).map_or_else(Vec::new, |w| w.export());
+            }
+            CardId::SteamProperties => &[&self.steam_result, &self.steam_reference_result],
+            CardId::PipeSizing => &[&self.pipe_result],
+            CardId::PipeLoss => &[&self.pipe_loss_result],
+            CardId::ValveSizing => &[&self.valve_result],
+            CardId::PipeRun => &[&self.pipe_run_result],
+            CardId::SteamBlow => &[&self.blow_result],
+            CardId::LineCondensation => &[&self.pipe_cond_result],
+            CardId::ValveAuthority => &[&self.valve_auth_result],
+            CardId::LineCase => &[&self.line_case_result],
+            CardId::TurbineBypass => &[&self.bypass_result, &self.spray_calc_result],
+            CardId::BoilerEfficiency => &[&self.boiler_result],
+            CardId::SteamPurity => &[&self.purity_result],
+            CardId::Condenser => &[&self.condenser_result],
+            CardId::CoolingTower => &[&self.ct_result],
+            CardId::EvaporativeCooler => &[&self.evap_result],
+            CardId::PumpNpsh => &[&self.npsh_result],
+            CardId::DrainCooler => &[&self.drain_result],
+            CardId::Orifice => &[&self.plant_result],
+            CardId::ExpansionLoop => &[&self.plant_expansion_result],
+            CardId::FreezeProtection => &[&self.plant_freeze_result],
+            CardId::Corrosion => &[&self.plant_pressure_result],
+            CardId::PressureTest => &[&self.plant_test_result],
+            CardId::LineFill => &[&self.plant_fill_result],
+            CardId::GasProperties => &[&self.plant_gas_result],
+            CardId::VesselBlowdown => &[&self.plant_bd_result],
+        };
+        slots.iter().filter_map(|s| s.as_deref()).collect()
+    }
+
+    /// 명령 팔레트 창. 이름·기본 문구·탭 이름을 함께 퍼지 검색하고,
+    /// 검색어가 없으면 최근 고른 항목을 먼저 보여 준다. ↑/↓로 고르고 Enter로 실행한다.
+    fn ui_palette<F>(&mut self, ctx: &egui::Context, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        if !self.palette_open {
+            return;
+        }
+        let entries = PaletteAction::all();
+        let context = |action: PaletteAction| match action {
+            PaletteAction::Tab(_) => txt("gui.palette.menu", "Menu"),
+            PaletteAction::Card(tab, _, _) => {
+                let (key, default) = tab.label_key();
+                txt(key, default)
+            }
+            _ => txt("gui.palette.command", "Command"),
+        };
+        let haystacks: Vec<String> = entries
+            .iter()
+            .map(|&(action, key, default)| format!("{} {} {}", txt(key, default), default, context(action)))
+            .collect();
+        let show_recent = self.palette_query.trim().is_empty();
+        let order: Vec<usize> = if show_recent {
+            let recent: Vec<usize> = self
+                .palette_recent
+                .iter()
+                .filter_map(|r| entries.iter().position(|e| e.0 == *r))
+                .collect();
+            let rest = (0..entries.len()).filter(|i| !recent.contains(i));
+            recent.iter().copied().chain(rest).collect()
+        } else {
+            fuzzy::rank(&self.palette_query, haystacks.iter().map(String::as_str))
+        };
+
+        let (down, up, enter, escape) = ctx.input_mut(|i| {
+            (
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowDown),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::ArrowUp),
+                i.key_pressed(egui::Key::Enter),
+                i.consume_key(egui::Modifiers::NONE, egui::Key::Escape),
+            )
+        });
+        if down {
+            self.palette_selected = (self.palette_selected + 1).min(order.len().saturating_sub(1));
+        }
+        if up {
+            self.palette_selected = self.palette_selected.saturating_sub(1);
+        }
+        let mut chosen = (enter && !order.is_empty()).then(|| order[self.palette_selected.min(order.len() - 1)]);
+
+        egui::Window::new(txt("gui.palette.title", "Command palette"))
+            .id(egui::Id::new("command_palette"))
+            .title_bar(false)
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 60.0])
+            .default_width(460.0)
+            .show(ctx, |ui| {
+                let edit = ui.add(
+                    egui::TextEdit::singleline(&mut self.palette_query)
+                        .hint_text(txt("gui.palette.hint", "Search cards, menus and settings…"))
+                        .desired_width(f32::INFINITY),
+                );
+                edit.request_focus();
+                if edit.changed() {
+                    self.palette_selected = 0;
+                }
+                ui.separator();
+                if order.is_empty() {
+                    ui.weak(txt("gui.palette.none", "No matches"));
+                }
+                let recent_count = if show_recent { self.palette_recent.len() } else { 0 };
+                egui::ScrollArea::vertical().max_height(360.0).show(ui, |ui| {
+                    for (row, &idx) in order.iter().enumerate() {
+                        if row == 0 && recent_count > 0 {
+                            ui.small(txt("gui.palette.recent", "Recent"));
+                        }
+                        if row == recent_count && recent_count > 0 {
+                            ui.separator();
+                        }
+                        let (action, key, default) = entries[idx];
+                        let selected = row == self.palette_selected;
+                        let resp = ui.horizontal(|ui| {
+                            let resp = ui.selectable_label(selected, txt(key, default));
+                            ui.weak(context(action));
+                            resp
+                        });
+                        if selected && (up || down) {
+                            resp.response.scroll_to_me(None);
+                        }
+                        if resp.inner.clicked() {
+                            chosen = Some(idx);
+                        }
+                    }
+                });
+            });
+
+        if let Some(idx) = chosen {
+            self.run_palette_action(entries[idx].0);
+        } else if escape {
+            self.palette_open = false;
+        }
+    }
+
+    fn run_palette_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::Tab(tab) => self.tab = tab,
+            PaletteAction::Card(tab, key, default) => {
+                self.tab = tab;
+                let title = self.tr.lookup(key).unwrap_or_else(|| default.to_string());
+                SCROLL_TARGET.with(|t| *t.borrow_mut() = Some(title));
+            }
+            PaletteAction::Settings => self.show_settings_modal = true,
+            PaletteAction::Help => self.show_help_modal = true,
+            PaletteAction::Formulas => self.show_formula_modal = true,
+            PaletteAction::Export => self.export_tab_results(),
+            PaletteAction::VacuumTable => self.detach_panel(Panel::VacuumTable),
+        }
+        self.palette_recent.retain(|a| *a != action);
+        self.palette_recent.insert(0, action);
+        self.palette_recent.truncate(PALETTE_RECENT_MAX);
+        self.palette_open = false;
+        self.palette_query.clear();
+        self.palette_selected = 0;
+    }
+
+    /// 현재 탭의 계산 결과를 텍스트 파일로 저장한다 (Ctrl+E).
+    fn export_tab_results(&mut self) {
+        let txt = |key: &str, default: &str| self.tr.lookup(key).unwrap_or_else(|| default.to_string());
+        let (key, default) = self.tab.label_key();
+        let title = txt(key, default);
+        let results = self.tab_results(self.tab);
+        if results.is_empty() {
+            self.export_status = Some(txt("gui.export.empty", "No results on this tab yet."));
+            return;
+        }
+        let body = format!("Steam Engineering Toolbox - {title}\n\n{}\n", results.join("\n\n"));
+        let saved_tpl = txt("gui.export.saved", "Saved: {path}");
+        let Some(path) = FileDialog::new()
+            .add_filter("Text", &["txt"])
+            .set_file_name(format!("{}.txt", title.replace(['/', '\\'], "-")))
+            .save_file()
+        else {
+            return;
+        };
+        self.export_status = Some(match fs::write(&path, body) {
+            Ok(()) => fill_template(&saved_tpl, &[("path", path.display().to_string())]),
+            Err(e) => error_text(&self.tr, e),
+        });
+    }
+
+    /// 사이드 메뉴를 제공한다.
+    fn ui_nav(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string());
+        ui.style_mut().wrap = Some(false);
+        ui.vertical_centered(|ui| {
+            ui.heading(txt("gui.nav.heading", "Menu"));
+            ui.add_space(8.0);
+        });
+        for (i, tab) in Tab::NAV_ORDER.into_iter().enumerate() {
+            let (key, default) = tab.label_key();
+            let selected = self.tab == tab;
+            let detached = self.is_detached(Panel::Tab(tab));
+            ui.horizontal(|ui| {
+                let button = egui::Button::new(txt(key, default))
+                    .fill(if selected {
+                        ui.visuals().selection.bg_fill
+                    } else {
+                        ui.visuals().extreme_bg_color
+                    })
+                    .min_size(egui::vec2((ui.available_width() - 32.0).max(60.0), 32.0));
+                let resp = ui.add(button).on_hover_text(fill_template(
+                    &txt("gui.nav.switch_tip_shortcut", "Switch menu (Ctrl+{n})"),
+                    &[("n", (i + 1).to_string())],
+                ));
+                if resp.clicked() {
+                    self.tab = tab;
+                }
+                let pop = ui
+                    .add_enabled(!detached, egui::Button::new("⧉").min_size(egui::vec2(24.0, 32.0)))
+                    .on_hover_text(txt("gui.nav.detach_tip", "Open in a separate window"));
+                if pop.clicked() {
+                    self.detach_panel(Panel::Tab(tab));
+                }
+            });
+            ui.add_space(4.0);
+        }
+    }
+
+    /// 고정한 카드를 한 화면의 격자로 모은다. 입력은 원래 탭의 카드와 공유한다.
+    fn ui_dashboard(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string());
+        heading_with_tip(
+            ui,
+            &txt("gui.dashboard.heading", "Dashboard"),
+            &txt(
+                "gui.dashboard.tip",
+                "Pinned cards with their last inputs. Inputs are shared with the card in its own menu.",
+            ),
+        );
+        ui.add_space(8.0);
+        let pinned = self.inputs.dashboard.pinned.clone();
+        if pinned.is_empty() {
+            ui.label(txt(
+                "gui.dashboard.empty",
+                "Nothing pinned yet. Use the 📌 button on a card to add it here.",
+            ));
+            return;
+        }
+        // 카드 한 장에 420 px 정도를 잡고 열 수를 정한다
+        let columns = ((ui.available_width() / 420.0) as usize).clamp(1, 3);
+        ui.columns(columns, |cols| {
+            for (i, card) in pinned.into_iter().enumerate() {
+                let ui = &mut cols[i % columns];
+                ui.strong(txt(card.i18n_key(), card.label()));
+                self.ui_card(ui, &txt, card);
+                ui.add_space(8.0);
+            }
+        });
+    }
+
+    /// 고정 가능한 카드를 주어진 영역에 그린다 (탭 본문과 대시보드 공용).
+    fn ui_card<F>(&mut self, ui: &mut egui::Ui, txt: &F, card: CardId)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        match card {
+            CardId::ValveActuator
+            | CardId::RankineCycle
+            | CardId::UnitConversion
+            | CardId::FlowConversion
+            | CardId::Dryness
+            | CardId::HeatBalance
+            | CardId::Drum
+            | CardId::Hrsg
+            | CardId::Attemperator
+            | CardId::AirPreheater
+            | CardId::Emissions
+            | CardId::AirCooledCondenser
+            | CardId::CoolingComparison
+            | CardId::SaturatedNpsh
+            | CardId::Ejector
+            | CardId::MeterCompensation
+            | CardId::Totalizer
+            | CardId::GasThrottling
+            | CardId::Compressor
+            | CardId::LetdownNoise
+            | CardId::VentSilencer
+            | CardId::ReturnLine
+            | CardId::TankHeating
+            | CardId::Sparger => self.ui_widget(ui, txt, card),
+            CardId::SteamProperties => self.ui_steam_properties(ui, txt),
+            CardId::PipeSizing => self.ui_pipe_sizing(ui, txt),
+            CardId::PipeLoss => self.ui_pipe_loss(ui, txt),
+            CardId::ValveSizing => self.ui_valve_sizing(ui, txt),
+            CardId::PipeRun => self.ui_pipe_run(ui, txt),
+            CardId::SteamBlow => self.ui_steam_blow(ui, txt),
+            CardId::LineCondensation => self.ui_line_condensation(ui, txt),
+            CardId::ValveAuthority => self.ui_valve_authority(ui, txt),
+            CardId::LineCase => self.ui_line_case(ui, txt),
+            CardId::BoilerEfficiency => self.ui_boiler_efficiency(ui, txt),
+            CardId::SteamPurity => self.ui_steam_purity(ui, txt),
+            CardId::Condenser => self.ui_condenser(ui, txt),
+            CardId::CoolingTower => self.ui_cooling_tower(ui, txt),
+            CardId::EvaporativeCooler => self.ui_evaporative_cooler(ui, txt),
+            CardId::PumpNpsh => self.ui_pump_npsh(ui, txt),
+            CardId::DrainCooler => self.ui_drain_cooler(ui, txt),
+            CardId::Orifice => self.ui_orifice(ui, txt),
+            CardId::ExpansionLoop => self.ui_expansion_loop(ui, txt),
+            CardId::FreezeProtection => self.ui_freeze_protection(ui, txt),
+            CardId::Corrosion => self.ui_corrosion(ui, txt),
+            CardId::PressureTest => self.ui_pressure_test(ui, txt),
+            CardId::LineFill => self.ui_line_fill(ui, txt),
+            CardId::GasProperties => self.ui_gas_properties(ui, txt),
+            CardId::VesselBlowdown => self.ui_vessel_blowdown(ui, txt),
+            CardId::TurbineBypass => self.ui_bypass_panels(ui),
+        }
+    }
+
+    /// 위젯 카드를 찾는다.
+    fn widget(&self, card: CardId) -> Option<&dyn CardWidget> {
+        self.widgets.iter().find(|w| w.id() == card).map(|w| w.as_ref())
+    }
+
+    /// 위젯 카드를 그룹 틀에 넣어 그린다.
+    fn ui_widget<F>(&mut self, ui: &mut egui::Ui, txt: &F, card: CardId)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        if let Some(widget) = self.widgets.iter_mut().find(|w| w.id() == card) {
+            card_frame(ui, txt, &self.tr, &mut self.inputs.dashboard, widget.as_mut());
+        }
+    }
+
+    fn ui_unit_conv(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string());
+        heading_with_tip(
+            ui,
+            &txt("gui.unit.heading", "Unit Converter"),
+            &txt(
+                "gui.unit.tip",
+                "Convert various physical quantities between units.",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt("gui.unit.card_label", "Card-style input"),
+            &txt(
+                "gui.unit.card_tip",
+                "Enter value and select units, then run conversion.",
+            ),
+        );
+        ui.add_space(8.0);
+        self.ui_card(ui, &txt, CardId::UnitConversion);
+
+        ui.add_space(12.0);
+        self.ui_card(ui, &txt, CardId::FlowConversion);
+    }
+
+    fn ui_steam_tables(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = |key: &str, default: &str| tr.lookup(key).unwrap_or_else(|| default.to_string());
+        heading_with_tip(
+            ui,
+            &txt("gui.steam.heading", "Steam Tables"),
+            &txt(
+                "gui.steam.tip",
+                "Steam/water properties (sat/superheated) based on IF97.",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt("gui.steam.card_label", "Saturation/Superheat card"),
+            &txt(
+                "gui.steam.card_tip",
+                "Enter pressure/temperature to get Psat/Tsat/h/s/v.",
+            ),
+        );
+        ui.add_space(8.0);
+        if ui
+            .button(txt("gui.steam.vacuum_open", "Open vacuum table"))
+            .on_hover_text(txt(
+                "gui.steam.vacuum_open_tip",
+                "Show built-in vacuum saturation table (mmHg gauge).",
+            ))
+            .clicked()
+        {
+            self.show_vacuum_table_window = true;
+        }
+        ui.horizontal(|ui| {
+            if ui
+                .button(txt("gui.steam.vacuum_window", "Open vacuum table in new window"))
+                .on_hover_text(txt(
+                    "gui.steam.vacuum_window_tip",
+                    "Open vacuum table in a separate window.",
+                ))
+                .clicked()
+            {
+                self.detach_panel(Panel::VacuumTable);
+            }
+            ui.small(txt(
+                "gui.steam.vacuum_note",
+                "You can keep the external window open while using other menus.",
+            ));
+        });
+        if self.show_vacuum_table_window {
+            let rows = self.vacuum_table.get_or_insert_with(build_vacuum_table);
+            egui::Window::new(txt(
+                "gui.steam.vacuum_title",
+                "Vacuum saturation table (mmHg gauge: 0=atm, -760=vacuum)",
+            ))
+                .open(&mut self.show_vacuum_table_window)
+                .scroll2([true, true])
+                .resizable(true)
+                .show(ui.ctx(), |ui| {
+                    vacuum_table_ui(ui, rows, &txt);
+                });
+        }
+
+        self.ui_steam_properties(ui, &txt);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Dryness);
+    }
+
+    /// 증기표(포화/과열) 카드.
+    fn ui_steam_properties<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::SteamProperties)).inner {
+                self.inputs.steam_tables.properties = self.input_defaults.steam_tables.properties.clone();
+            }
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    &mut self.inputs.steam_tables.properties.mode,
+                    SteamMode::ByPressure,
+                    txt("gui.steam.mode.pressure", "By pressure"),
+                )
+                .on_hover_text(txt(
+                    "gui.steam.mode.pressure_tip",
+                    "Enter pressure to get Psat/Tsat/h/s/v.",
+                ));
+                ui.selectable_value(
+                    &mut self.inputs.steam_tables.properties.mode,
+                    SteamMode::ByTemperature,
+                    txt("gui.steam.mode.temperature", "By temperature"),
+                )
+                .on_hover_text(txt(
+                    "gui.steam.mode.temperature_tip",
+                    "Enter temperature to get Psat/Tsat/h/s/v.",
+                ));
+                ui.selectable_value(
+                    &mut self.inputs.steam_tables.properties.mode,
+                    SteamMode::Superheated,
+                    txt("gui.steam.mode.superheated", "Superheated"),
+                )
+                .on_hover_text(txt(
+                    "gui.steam.mode.superheated_tip",
+                    "Enter P+superheat to get superheated properties.",
+                ));
+            });
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.steam.value", "Value"),
+                    &txt(
+                        "gui.steam.value_tip",
+                        "Pressure or temperature depending on mode",
+                    ),
+                );
+                ui.add(egui::DragValue::new(&mut self.inputs.steam_tables.properties.value).speed(0.5));
+                if matches!(self.inputs.steam_tables.properties.mode, SteamMode::ByPressure | SteamMode::Superheated) {
+                    unit_combo(ui, &mut self.inputs.steam_tables.properties.p_unit, &pressure_unit_options());
+                    ui.selectable_value(
+                        &mut self.inputs.steam_tables.properties.p_mode,
+                        conversion::PressureMode::Gauge,
+                        "Gauge (G)",
+                    );
+                    ui.selectable_value(
+                        &mut self.inputs.steam_tables.properties.p_mode,
+                        conversion::PressureMode::Absolute,
+                        "Absolute (A)",
+                    );
+                } else {
+                    unit_combo(ui, &mut self.inputs.steam_tables.properties.t_unit, &temperature_unit_options());
+                }
+            });
+            if self.inputs.steam_tables.properties.mode == SteamMode::Superheated {
+                ui.horizontal(|ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.steam.superheat", "Superheat [°C]"),
+                        &txt(
+                            "gui.steam.superheat_tip",
+                            "Superheat above saturation (not absolute temperature)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.steam_tables.properties.temp_input).speed(1.0));
+                    unit_combo(ui, &mut self.inputs.steam_tables.properties.t_unit, &temperature_unit_options());
+                });
+            }
+            ui.add_space(6.0);
+            ui.horizontal(|ui| {
+                label_with_tip(
+                    ui,
+                    &txt("gui.steam.output_pressure", "Output pressure unit"),
+                    &txt("gui.steam.output_pressure_tip", "Pressure unit for results"),
+                );
+                unit_combo(ui, &mut self.inputs.steam_tables.properties.p_unit_out, &pressure_unit_options());
+                ui.selectable_value(
+                    &mut self.inputs.steam_tables.properties.p_mode_out,
+                    conversion::PressureMode::Gauge,
+                    "Gauge (G)",
+                );
+                ui.selectable_value(
+                    &mut self.inputs.steam_tables.properties.p_mode_out,
+                    conversion::PressureMode::Absolute,
+                    "Absolute (A)",
+                );
+                label_with_tip(
+                    ui,
+                    &txt("gui.steam.output_temperature", "Output temperature unit"),
+                    &txt("gui.steam.output_temperature_tip", "Temperature unit for results"),
+                );
+                unit_combo(ui, &mut self.inputs.steam_tables.properties.t_unit_out, &temperature_unit_options());
+            });
+            ui.small(txt(
+                "gui.steam.tip_mmhg",
+                "Tip: mmHg is treated as gauge (0=atm, -760=vacuum).",
+            ));
+            ui.add_space(6.0);
+            ui.checkbox(
+                &mut self.steam_compare_reference,
+                txt("gui.steam.compare_reference", "Compare with reference steam table"),
+            )
+            .on_hover_text(txt(
+                "gui.steam.compare_reference_tip",
+                "Also evaluate the nearest tabulated IAPWS-95 point and show the percent deviation of IF97",
+            ));
+            if run_button(ui, txt("gui.steam.run", "Calculate")) {
+                self.steam_stream = None;
+                self.steam_reference_result = None;
+                self.steam_result = Some(match self.inputs.steam_tables.properties.mode {
+                    SteamMode::ByPressure => match steam::saturation_by_pressure_mode(
+                        convert_pressure_mode_gui(
+                            self.inputs.steam_tables.properties.value,
+                            &self.inputs.steam_tables.properties.p_unit,
+                            self.inputs.steam_tables.properties.p_mode,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                        ),
+                        PressureUnit::BarA,
+                        conversion::PressureMode::Absolute,
+                    ) {
+                        Ok(s) => {
+                        self.steam_stream = Some(StreamState::from_steam_state("gui.steam.heading", &s));
+                        if self.steam_compare_reference {
+                            self.steam_reference_result =
+                                Some(reference_comparison_text(txt, &reference_table::compare_state(&s)));
+                        }
+                        let p_out = convert_pressure_mode_gui(
+                            s.pressure_bar,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                            &self.inputs.steam_tables.properties.p_unit_out,
+                            self.inputs.steam_tables.properties.p_mode_out,
+                        );
+                        let t_out =
+                            convert_temperature_gui(s.saturation_temperature_c, "C", &self.inputs.steam_tables.properties.t_unit_out);
+                        let tpl = txt(
+                            "gui.steam.result.sat_full",
+                            "Psat={psat} {p_unit}, Tsat={tsat} {t_unit}, hs(v)={hs} kJ/kg, vs={vs} m3/kg, ss={ss} kJ/kgK | hf={hf} kJ/kg, vf={vf} m3/kg, sf={sf} kJ/kgK",
+                        );
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("psat", fmt_num(p_out, 3)),
+                                ("p_unit", self.inputs.steam_tables.properties.p_unit_out.clone()),
+                                ("tsat", fmt_num(t_out, 2)),
+                                ("t_unit", self.inputs.steam_tables.properties.t_unit_out.clone()),
+                                ("hs", fmt_qty(s.saturation_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                                ("vs", fmt_num(s.saturation_specific_volume, 3)),
+                                ("ss", fmt_num(s.saturation_entropy_kj_per_kgk, 3)),
+                                ("hf", fmt_qty(s.sat_liquid_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                                ("vf", fmt_num(s.sat_liquid_specific_volume, 4)),
+                                ("sf", fmt_num(s.sat_liquid_entropy_kj_per_kgk, 3)),
+                            ],
+                        )
+                    }
+                    Err(e) => {
+                        let tpl = txt(
+                            "gui.steam.error.pressure",
+                            "Error(P={p} {p_unit}{mode}): {e}",
+                        );
+                        let mode = if self.inputs.steam_tables.properties.p_mode == conversion::PressureMode::Gauge {
+                            "g"
+                        } else {
+                            "a"
+                        };
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("p", fmt_num(self.inputs.steam_tables.properties.value, 3)),
+                                ("p_unit", self.inputs.steam_tables.properties.p_unit.clone()),
+                                ("mode", mode.to_string()),
+                                ("e", error_text(&self.tr, e)),
+                            ],
+                        )
+                    }
+                },
+            SteamMode::ByTemperature => match steam::saturation_by_temperature(
+                convert_temperature_gui(self.inputs.steam_tables.properties.value, &self.inputs.steam_tables.properties.t_unit, "C"),
+                TemperatureUnit::Celsius,
+            ) {
+                Ok(s) => {
+                        self.steam_stream = Some(StreamState::from_steam_state("gui.steam.heading", &s));
+                        if self.steam_compare_reference {
+                            self.steam_reference_result =
+                                Some(reference_comparison_text(txt, &reference_table::compare_state(&s)));
+                        }
+                        let p_out = convert_pressure_mode_gui(
+                            s.pressure_bar,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                            &self.inputs.steam_tables.properties.p_unit_out,
+                            self.inputs.steam_tables.properties.p_mode_out,
+                        );
+                        let tpl = txt(
+                            "gui.steam.result.sat_temp",
+                            "Psat={psat} {p_unit}, hs={hs} kJ/kg, v={v} m3/kg",
+                        );
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("psat", fmt_num(p_out, 3)),
+                                ("p_unit", self.inputs.steam_tables.properties.p_unit_out.clone()),
+                                ("hs", fmt_qty(s.saturation_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                                ("v", fmt_num(s.saturation_specific_volume, 3)),
+                            ],
+                        )
+                    }
+                    Err(e) => {
+                        let tpl = txt("gui.steam.error.temperature", "Error(T={t} {t_unit}): {e}");
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("t", fmt_num(self.inputs.steam_tables.properties.value, 2)),
+                                ("t_unit", self.inputs.steam_tables.properties.t_unit.clone()),
+                                ("e", error_text(&self.tr, e)),
+                            ],
+                        )
+                    }
+            },
+            SteamMode::Superheated => match steam::superheated_at(
+                convert_pressure_mode_gui(
+                    self.inputs.steam_tables.properties.value,
+                    &self.inputs.steam_tables.properties.p_unit,
+                            self.inputs.steam_tables.properties.p_mode,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                        ),
+                    PressureUnit::BarA,
+                    convert_temperature_gui(self.inputs.steam_tables.properties.temp_input, &self.inputs.steam_tables.properties.t_unit, "C"),
+                    TemperatureUnit::Celsius,
+            ) {
+                Ok(s) => {
+                        self.steam_stream = Some(StreamState::from_steam_state("gui.steam.heading", &s));
+                        if self.steam_compare_reference {
+                            self.steam_reference_result =
+                                Some(reference_comparison_text(txt, &reference_table::compare_state(&s)));
+                        }
+                        let p_out = convert_pressure_mode_gui(
+                            s.pressure_bar,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                            &self.inputs.steam_tables.properties.p_unit_out,
+                            self.inputs.steam_tables.properties.p_mode_out,
+                        );
+                        let t_out = convert_temperature_gui(s.temperature_c, "C", &self.inputs.steam_tables.properties.t_unit_out);
+                        let tpl = txt(
+                            "gui.steam.result.superheat",
+                            "P={p} {p_unit}, T={t} {t_unit}, h={h} kJ/kg",
+                        );
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("p", fmt_num(p_out, 2)),
+                                ("p_unit", self.inputs.steam_tables.properties.p_unit_out.clone()),
+                                ("t", fmt_num(t_out, 1)),
+                                ("t_unit", self.inputs.steam_tables.properties.t_unit_out.clone()),
+                                (
+                                    "h",
+                                    fmt_num(s.superheated_enthalpy_kj_per_kg.unwrap_or(0.0), 1),
+                                ),
+                            ],
+                        )
+                    }
+                    Err(e) => {
+                        let tpl = txt(
+                            "gui.steam.error.superheat",
+                            "Error(P={p} {p_unit}{mode}, T={t} {t_unit}): {e}",
+                        );
+                        let mode = if self.inputs.steam_tables.properties.p_mode == conversion::PressureMode::Gauge {
+                            "g"
+                        } else {
+                            "a"
+                        };
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("p", fmt_num(self.inputs.steam_tables.properties.value, 3)),
+                                ("p_unit", self.inputs.steam_tables.properties.p_unit.clone()),
+                                ("mode", mode.to_string()),
+                                ("t", fmt_num(self.inputs.steam_tables.properties.temp_input, 1)),
+                                ("t_unit", self.inputs.steam_tables.properties.t_unit.clone()),
+                                ("e", error_text(&self.tr, e)),
+                            ],
+                        )
+                    }
+            },
+        });
+    }
+    if let Some(res) = &self.steam_result {
+        ui.separator();
+        result_block(ui, txt, res, false);
+        if let Some(reference) = &self.steam_reference_result {
+            ui.add_space(4.0);
+            result_block(ui, txt, reference, true);
+        }
+        legend_toggle(
+            ui,
+            &txt("legend.steam.title", "Legend / notes"),
+            &txt(
+                "legend.steam.body",
+                "Psat=sat pressure, Tsat=sat temperature, hs/vs/ss=sat vapor, hf/vf/sf=sat liquid",
+            ),
+            &mut self.show_legend_steam,
+        );
+    }
+    if let Some(state) = self.steam_stream.clone() {
+        if let Some(target) = use_in_menu(ui, txt, &state) {
+            self.send_stream(target, state);
+        }
+    }
+});
+    }
+
+    fn ui_steam_piping(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt("gui.pipe.heading", "Steam Piping"),
+            &txt(
+                "gui.pipe.tip",
+                "Pipe sizing and pressure-drop calculator for steam/gas.",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt("gui.pipe.card_label", "Pipe sizing card"),
+            &txt(
+                "gui.pipe.card_tip",
+                "Enter mass flow, pressure/temperature, and target velocity to size ID and Reynolds.",
+            ),
+        );
+        ui.add_space(8.0);
+        self.ui_pipe_sizing(ui, &txt);
+        ui.add_space(6.0);
+        self.ui_pipe_loss(ui, &txt);
+        ui.add_space(6.0);
+        self.ui_card(ui, &txt, CardId::PipeRun);
+        ui.add_space(6.0);
+        self.ui_card(ui, &txt, CardId::SteamBlow);
+        ui.add_space(6.0);
+        self.ui_card(ui, &txt, CardId::LineCondensation);
+    }
+
+    /// 다구간 배관 경로 카드. 유량·입구 상태는 압력 손실 카드에서 가져온다.
+    fn ui_pipe_run<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            label_with_tip(
+                ui,
+                &txt("gui.pipe.run.heading", "Pipe Run (multi-segment)"),
+                &txt(
+                    "gui.pipe.run.tip",
+                    "Segments in flow order. Mass flow, inlet state, viscosity, sound speed and K method are taken from the pressure-loss card; the state is carried isenthalpically from segment to segment.",
+                ),
+            );
+            pin_button(ui, txt, &mut self.inputs.dashboard, CardId::PipeRun);
+            let mut remove_idx: Option<usize> = None;
+            egui::Grid::new("pipe_run_grid")
+                .num_columns(8)
+                .spacing([6.0, 4.0])
+                .show(ui, |ui| {
+                    ui.strong(txt("gui.pipe.run.name", "Segment"));
+                    ui.strong(txt("gui.pipe.run.diameter", "D [mm]"));
+                    ui.strong(txt("gui.pipe.run.length", "L [m]"));
+                    ui.strong(txt("gui.pipe.run.rise", "Rise Δz [m]"));
+                    ui.strong(txt("gui.pipe.run.roughness", "ε [mm]"));
+                    ui.strong(txt("gui.pipe.run.extra_k", "Extra K"));
+                    ui.strong(txt("gui.pipe.run.fittings", "Fittings"))
+                        .on_hover_text(txt("gui.pipe.run.fittings_tip", "e.g. elbow90-lr:4, gate:2, exit"));
+                    ui.label("");
+                    ui.end_row();
+                    for (i, row) in self.pipe_run_rows.iter_mut().enumerate() {
+                        ui.add(egui::TextEdit::singleline(&mut row.name).desired_width(80.0));
+                        ui.add(egui::DragValue::new(&mut row.diameter_mm).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut row.length_m).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut row.rise_m).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut row.roughness_mm).speed(0.001));
+                        ui.add(egui::DragValue::new(&mut row.extra_k).speed(0.1));
+                        ui.add(egui::TextEdit::singleline(&mut row.fittings).desired_width(200.0));
+                        if ui.small_button("-").clicked() {
+                            remove_idx = Some(i);
+                        }
+                        ui.end_row();
+                    }
+                });
+            if let Some(i) = remove_idx {
+                self.pipe_run_rows.remove(i);
+            }
+            ui.horizontal(|ui| {
+                if ui.small_button(txt("gui.pipe.run.add_row", "+ Add segment")).clicked() {
+                    let name = (self.pipe_run_rows.len() + 1).to_string();
+                    let row = match self.pipe_run_rows.last() {
+                        Some(last) => PipeRunRow {
+                            name,
+                            rise_m: 0.0,
+                            extra_k: 0.0,
+                            fittings: String::new(),
+                            ..last.clone()
+                        },
+                        None => PipeRunRow::new(&name),
+                    };
+                    self.pipe_run_rows.push(row);
+                }
+                if ui
+                    .small_button(txt("gui.pipe.run.load_csv", "Load CSV…"))
+                    .on_hover_text(txt(
+                        "gui.pipe.run.load_csv_tip",
+                        "name,diameter_mm,length_m,rise_m[,roughness_mm|material][,extra_k][,fittings]",
+                    ))
+                    .clicked()
+                {
+                    if let Some(path) = FileDialog::new().add_filter("CSV", &["csv"]).pick_file() {
+                        let loaded = fs::read_to_string(&path)
+                            .map_err(|e| error_text(&self.tr, e))
+                            .and_then(|text| {
+                                pipe_run::parse_pipe_run_csv(&text).map_err(|e| error_text(&self.tr, e))
+                            });
+                        match loaded {
+                            Ok(segments) => {
+                                self.pipe_run_rows = segments.iter().map(PipeRunRow::from_segment).collect();
+                                self.pipe_run_result = None;
+                            }
+                            Err(e) => self.pipe_run_result = Some(e),
+                        }
+                    }
+                }
+            });
+            if run_button(ui, txt("gui.pipe.run.run", "Calculate run")) {
+                let result = self.pipe_run_input().and_then(|input| pipe_run::pipe_run(&input));
+                self.pipe_run_result = Some(match result {
+                    Ok(r) => {
+                        let line_tpl = txt(
+                            "gui.pipe.run.segment_line",
+                            "{name}: {p1} → {p2} bar(a), v={v} m/s, ΔPf={dpf} bar, ρgΔz={dpz} bar, Mach={mach}",
+                        );
+                        let mut lines: Vec<String> = r
+                            .segments
+                            .iter()
+                            .map(|s| {
+                                fill_template(
+                                    &line_tpl,
+                                    &[
+                                        ("name", s.name.clone()),
+                                        ("p1", fmt_qty(s.inlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                        ("p2", fmt_qty(s.outlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                        ("v", fmt_qty(s.velocity_m_per_s, QuantityKind::Velocity)),
+                                        ("dpf", fmt_qty(s.friction_drop_bar, QuantityKind::Pressure)),
+                                        ("dpz", fmt_qty(s.elevation_drop_bar, QuantityKind::Pressure)),
+                                        ("mach", fmt_num(s.mach, 3)),
+                                    ],
+                                )
+                            })
+                            .collect();
+                        lines.push(fill_template(
+                            &txt(
+                                "gui.pipe.run.total",
+                                "Total: friction {dpf} bar + elevation {dpz} bar = ΔP {dp} bar, outlet P={p2} bar(a)",
+                            ),
+                            &[
+                                ("dpf", fmt_qty(r.friction_drop_bar, QuantityKind::Pressure)),
+                                ("dpz", fmt_qty(r.elevation_drop_bar, QuantityKind::Pressure)),
+                                ("dp", fmt_qty(r.total_drop_bar, QuantityKind::Pressure)),
+                                ("p2", fmt_qty(r.outlet_pressure_bar_abs, QuantityKind::Pressure)),
+                            ],
+                        ));
+                        lines.join("\n")
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.pipe.run.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.pipe_run_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 시운전 스팀 블로 세정력비(CFR) 카드. 블로 경로는 배관 경로 표의 구간을 쓴다.
+    fn ui_steam_blow<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.pipe.blow.heading", "Steam blow cleaning force ratio"),
+                &txt(
+                    "gui.pipe.blow.tip",
+                    "Commissioning steam blow: momentum flux (W/A)²·v against design operation along the pipe run segments above (permanent + temporary piping, ending at atmosphere). Viscosity and K method come from the pressure-loss card.",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::SteamBlow)).inner {
+                self.inputs.piping.blow = self.input_defaults.piping.blow.clone();
+            }
+            egui::Grid::new("pipe_blow_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.blow.design_flow", "Design flow [kg/h] / ID [mm]"),
+                        &txt("gui.pipe.blow.design_flow_tip", "Maximum continuous flow of the permanent line being cleaned and its inner diameter"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.design_flow_kg_h).speed(100.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.design_id_mm).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.blow.design_state", "Design P [bar(a)] / T [°C]"),
+                        &txt("gui.pipe.blow.design_state_tip", "Steam conditions in that line at design flow"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.design_p_bar_abs).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.design_t_c).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.blow.boiler", "Blow boiler P [bar(a)] / T [°C]"),
+                        &txt("gui.pipe.blow.boiler_tip", "Superheater outlet conditions held during the blow"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.boiler_p_bar_abs).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.boiler_t_c).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.blow.valve", "Blow valve capacity [kg/h] / target CFR"),
+                        &txt("gui.pipe.blow.valve_tip", "Flow the blow valve passes fully open; CFR target is usually 1.0 or more (1.2-1.4 with margin)"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.valve_capacity_kg_h).speed(100.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.blow.target_cfr).speed(0.01).clamp_range(0.1..=5.0));
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.pipe.blow.run", "Check steam blow")) {
+                let result = self
+                    .pipe_run_rows
+                    .iter()
+                    .map(PipeRunRow::segment)
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(steam_blow::SteamBlowError::from)
+                    .and_then(|segments| {
+                        steam_blow::steam_blow(&steam_blow::SteamBlowInput {
+                            design_flow_kg_per_h: self.inputs.piping.blow.design_flow_kg_h,
+                            design_pressure_bar_abs: self.inputs.piping.blow.design_p_bar_abs,
+                            design_temperature_c: self.inputs.piping.blow.design_t_c,
+                            design_diameter_m: self.inputs.piping.blow.design_id_mm / 1000.0,
+                            boiler_pressure_bar_abs: self.inputs.piping.blow.boiler_p_bar_abs,
+                            boiler_temperature_c: self.inputs.piping.blow.boiler_t_c,
+                            blow_valve_capacity_kg_per_h: self.inputs.piping.blow.valve_capacity_kg_h,
+                            atmospheric_pressure_bar_abs: site_atmosphere().bar_abs(),
+                            target_cfr: self.inputs.piping.blow.target_cfr,
+                            fitting_method: self.inputs.piping.loss.fitting_method,
+                            dynamic_viscosity_pa_s: self.inputs.piping.loss.visc,
+                            segments,
+                        })
+                    });
+                self.blow_result = Some(match result {
+                    Ok(res) => {
+                        let limit = if res.valve_limited {
+                            txt("gui.pipe.blow.valve_limited", "blow valve")
+                        } else {
+                            txt("gui.pipe.blow.boiler_limited", "boiler pressure")
+                        };
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.pipe.blow.result",
+                                "Blow flow {flow} kg/h (set by {limit}), route inlet {p_in} bar(a), exit {p_exit} bar(a); minimum CFR {cfr} at {station}",
+                            ),
+                            &[
+                                ("flow", fmt_num(res.blow_flow_kg_per_h, 0)),
+                                ("limit", limit),
+                                ("p_in", fmt_qty(res.route_inlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                ("p_exit", fmt_qty(res.exit_pressure_bar_abs, QuantityKind::Pressure)),
+                                ("cfr", fmt_num(res.min_cfr, 2)),
+                                ("station", res.stations[res.worst_station].name.clone()),
+                            ],
+                        );
+                        let line_tpl = txt(
+                            "gui.pipe.blow.station",
+                            "{name}: {p1} → {p2} bar(a), v={v} m/s, Mach={mach}, CFR={cfr}",
+                        );
+                        for s in &res.stations {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &line_tpl,
+                                &[
+                                    ("name", s.name.clone()),
+                                    ("p1", fmt_qty(s.inlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                    ("p2", fmt_qty(s.outlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                    ("v", fmt_qty(s.outlet_velocity_m_per_s, QuantityKind::Velocity)),
+                                    ("mach", fmt_num(s.mach, 2)),
+                                    ("cfr", fmt_num(s.cfr, 2)),
+                                ],
+                            ));
+                        }
+                        if let (Some(flow), Some(p)) =
+                            (res.recommended_flow_kg_per_h, res.recommended_inlet_pressure_bar_abs)
+                        {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.pipe.blow.recommended",
+                                    "CFR {target} needs at least {flow} kg/h at {p} bar(a) route inlet",
+                                ),
+                                &[
+                                    ("target", fmt_num(self.inputs.piping.blow.target_cfr, 2)),
+                                    ("flow", fmt_num(flow, 0)),
+                                    ("p", fmt_num(p, 2)),
+                                ],
+                            ));
+                        }
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.pipe.blow.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.blow_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 보온 증기 배관의 열손실·응축(출구 건도) 카드.
+    fn ui_line_condensation<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.pipe.condensation.heading", "Line condensation (heat loss along the run)"),
+                &txt(
+                    "gui.pipe.condensation.tip",
+                    "Marches along an insulated steam line: heat loss per segment lowers the enthalpy, condensate forms once saturated, and the two-phase pressure drop follows the changing dryness",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::LineCondensation)).inner {
+                self.inputs.piping.condensation = self.input_defaults.piping.condensation.clone();
+            }
+            egui::Grid::new("pipe_condensation_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(txt("gui.pipe.condensation.flow", "Mass flow [kg/h]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.flow_kg_h).speed(10.0));
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.condensation.pressure", "Inlet pressure [bar(a)]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.p_bar_abs).speed(0.1));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.condensation.inlet", "Superheated: T [°C] / wet: dryness"),
+                        &txt(
+                            "gui.pipe.condensation.inlet_tip",
+                            "Checked: superheated steam at the given temperature; unchecked: wet or saturated steam at the given dryness fraction",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut self.inputs.piping.condensation.superheated, "");
+                        if self.inputs.piping.condensation.superheated {
+                            ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.temp_c).speed(1.0));
+                        } else {
+                            ui.add(
+                                egui::DragValue::new(&mut self.inputs.piping.condensation.dryness)
+                                    .speed(0.005)
+                                    .clamp_range(0.01..=1.0),
+                            );
+                        }
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.condensation.diameters", "ID / OD [m]"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.id_m).speed(0.001));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.od_m).speed(0.001));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.condensation.length", "Length / rise [m]"),
+                        &txt(
+                            "gui.pipe.condensation.length_tip",
+                            "Run length and outlet elevation minus inlet elevation; roughness is taken from the pressure-loss card",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.length_m).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.rise_m).speed(0.5));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.condensation.insulation", "Insulation [m] / k [W/m·K]"),
+                        &txt(
+                            "gui.pipe.condensation.insulation_tip",
+                            "Insulation thickness (0 = bare pipe) and conductivity (mineral wool ≈ 0.04–0.05)",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.insulation_m).speed(0.005));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.insulation_k).speed(0.001));
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.condensation.ambient", "Ambient [°C] / wind [m/s]"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.ambient_c).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.wind_m_s).speed(0.5));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.condensation.segments", "Segments"),
+                        &txt(
+                            "gui.pipe.condensation.segments_tip",
+                            "Properties, heat loss and pressure drop are re-evaluated at each segment inlet",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.condensation.segments).clamp_range(1..=1000));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.pipe.condensation.run", "Calculate line condensation")) {
+                let input = line_condensation::LineCondensationInput {
+                    mass_flow_kg_per_h: self.inputs.piping.condensation.flow_kg_h,
+                    inlet_pressure_bar_abs: self.inputs.piping.condensation.p_bar_abs,
+                    inlet: if self.inputs.piping.condensation.superheated {
+                        line_condensation::LineInletState::Superheated { temp_c: self.inputs.piping.condensation.temp_c }
+                    } else {
+                        line_condensation::LineInletState::Wet { dryness: self.inputs.piping.condensation.dryness }
+                    },
+                    inner_diameter_m: self.inputs.piping.condensation.id_m,
+                    outer_diameter_m: self.inputs.piping.condensation.od_m,
+                    length_m: self.inputs.piping.condensation.length_m,
+                    roughness_m: self.inputs.piping.loss.roughness,
+                    inlet_elevation_m: 0.0,
+                    outlet_elevation_m: self.inputs.piping.condensation.rise_m,
+                    insulation_thickness_m: self.inputs.piping.condensation.insulation_m,
+                    insulation_conductivity_w_mk: self.inputs.piping.condensation.insulation_k,
+                    ambient_temp_c: self.inputs.piping.condensation.ambient_c,
+                    wind_speed_m_per_s: self.inputs.piping.condensation.wind_m_s,
+                    segments: self.inputs.piping.condensation.segments,
+                };
+                self.pipe_cond_result = Some(match line_condensation::compute_line_condensation(input) {
+                    Ok(res) => {
+                        let state = |dryness: Option<f64>| match dryness {
+                            Some(x) => fill_template(
+                                &txt("gui.pipe.condensation.state_wet", "x={x}"),
+                                &[("x", fmt_num(x, 4))],
+                            ),
+                            None => txt("gui.pipe.condensation.state_superheated", "superheated"),
+                        };
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.pipe.condensation.result",
+                                "Heat loss {q} kW ({qm} W/m, UA {ua} W/m·K), condensate {c} kg/h\nOutlet {p2} bar(a), {t2} °C, {state}, ΔP {dp} bar",
+                            ),
+                            &[
+                                ("q", fmt_num(res.heat_loss_kw, 2)),
+                                ("qm", fmt_num(res.heat_loss_kw * 1000.0 / self.inputs.piping.condensation.length_m, 1)),
+                                ("ua", fmt_num(res.ua_w_per_mk, 3)),
+                                ("c", fmt_num(res.condensate_kg_per_h, 1)),
+                                ("p2", fmt_qty(res.outlet_pressure_bar_abs, QuantityKind::Pressure)),
+                                ("t2", fmt_qty(res.outlet_temp_c, QuantityKind::Temperature)),
+                                ("state", state(res.outlet_dryness)),
+                                ("dp", fmt_qty(res.pressure_drop_bar, QuantityKind::Pressure)),
+                            ],
+                        );
+                        // 구간이 많으면 약 10줄로 솎아 보여 준다 (마지막 구간은 항상 포함)
+                        let stride = res.segments.len().div_ceil(10);
+                        let line_tpl = txt(
+                            "gui.pipe.condensation.segment",
+                            "{l} m: {p} bar(a), {t} °C, {state}, v={v} m/s",
+                        );
+                        for (i, s) in res.segments.iter().enumerate() {
+                            if (i + 1) % stride != 0 && i + 1 != res.segments.len() {
+                                continue;
+                            }
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &line_tpl,
+                                &[
+                                    ("l", fmt_qty(s.distance_m, QuantityKind::Length)),
+                                    ("p", fmt_qty(s.pressure_bar_abs, QuantityKind::Pressure)),
+                                    ("t", fmt_qty(s.temp_c, QuantityKind::Temperature)),
+                                    ("state", state(s.dryness)),
+                                    ("v", fmt_qty(s.velocity_m_per_s, QuantityKind::Velocity)),
+                                ],
+                            ));
+                        }
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.pipe.condensation.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.pipe_cond_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 유속 기준 배관 구경 선정 카드.
+    fn ui_pipe_sizing<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                if card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::PipeSizing) {
+                    self.inputs.piping.sizing = self.input_defaults.piping.sizing.clone();
+                }
+                self.paste_stream_button(ui, txt, StreamTarget::PipeSizing);
+            });
+            egui::Grid::new("pipe_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.mass_flow", "Mass flow"),
+                        &txt("gui.pipe.mass_flow_tip", "Steam/gas mass flow (kg/h etc.)"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.sizing.mass_flow).speed(10.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.piping.sizing.mass_unit,
+                        flow_units::MASS_FLOW_UNITS,
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.pressure", "Pressure [bar]"),
+                        &txt(
+                            "gui.pipe.pressure_tip",
+                            "Operating pressure (select gauge/absolute).",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.sizing.pressure).speed(0.1));
+                    unit_combo(ui, &mut self.inputs.piping.sizing.pressure_unit, &pressure_unit_options());
+                    ui.selectable_value(
+                        &mut self.inputs.piping.sizing.pressure_mode,
+                        conversion::PressureMode::Gauge,
+                        "Gauge (G)",
+                    );
+                    ui.selectable_value(
+                        &mut self.inputs.piping.sizing.pressure_mode,
+                        conversion::PressureMode::Absolute,
+                        "Absolute (A)",
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.temperature", "Temperature [°C]"),
+                        &txt(
+                            "gui.pipe.temperature_tip",
+                            "Operating steam temperature.",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.sizing.temp).speed(1.0));
+                    unit_combo(ui, &mut self.inputs.piping.sizing.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.velocity", "Target velocity [m/s]"),
+                        &txt(
+                            "gui.pipe.velocity_tip",
+                            "Design target velocity (higher → smaller ID but more noise/erosion).",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.sizing.velocity).speed(1.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.piping.sizing.velocity_unit,
+                        &[("m/s", "m/s"), ("ft/s", "ft/s")],
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.service", "Service"),
+                        &txt(
+                            "gui.pipe.service_tip",
+                            "Service for the velocity/erosion check. Auto picks saturated, superheated or water from P/T.",
+                        ),
+                    );
+                    let auto = txt("gui.pipe.service_auto", "Auto (from P/T)");
+                    egui::ComboBox::from_id_source("pipe_service")
+                        .width(200.0)
+                        .selected_text(match self.pipe_service {
+                            Some(s) => txt(s.i18n_key(), s.label()),
+                            None => auto.clone(),
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.pipe_service, None, auto.as_str());
+                            for s in PipeService::ALL {
+                                ui.selectable_value(
+                                    &mut self.pipe_service,
+                                    Some(s),
+                                    txt(s.i18n_key(), s.label()),
+                                );
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe_class.label", "Piping class"),
+                        &txt(
+                            "gui.pipe_class.sizing_tip",
+                            "Picks the smallest size of the class (pipe_classes.toml) whose ID fits and checks it at this P/T",
+                        ),
+                    );
+                    pipe_class_combo(ui, txt, "pipe_sizing_class", &self.pipe_classes, &mut self.pipe_class_choice);
+                    ui.end_row();
+                });
+            ui.small(txt(
+                "gui.pipe.tip_mmhg",
+                "Tip: mmHg is treated as gauge (0=atm, -760mmHg=vacuum).",
+            ));
+            ui.add_space(8.0);
+            if run_button(ui, txt("gui.pipe.run_sizing", "Run sizing")) {
+                let p_bar_abs = convert_pressure_mode_gui(
+                    self.inputs.piping.sizing.pressure,
+                    &self.inputs.piping.sizing.pressure_unit,
+                    self.inputs.piping.sizing.pressure_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let t_c = convert_temperature_gui(self.inputs.piping.sizing.temp, &self.inputs.piping.sizing.temp_unit, "C");
+                let density = steam::estimate_density(
+                    p_bar_abs,
+                    PressureUnit::BarA,
+                    t_c,
+                    TemperatureUnit::Celsius,
+                );
+                let input = PipeSizingByVelocityInput {
+                    mass_flow_kg_per_h: convert_massflow_gui(
+                        self.inputs.piping.sizing.mass_flow,
+                        &self.inputs.piping.sizing.mass_unit,
+                        "kg/h",
+                    ),
+                    steam_density_kg_per_m3: density,
+                    target_velocity_m_per_s: convert_velocity_gui(
+                        self.inputs.piping.sizing.velocity,
+                        &self.inputs.piping.sizing.velocity_unit,
+                        "m/s",
+                    ),
+                };
+                let mass_flow_kg_per_h = input.mass_flow_kg_per_h;
+                self.pipe_stream = None;
+                self.pipe_result = Some(match steam::size_by_velocity(input) {
+                    Ok(r) => {
+                        self.pipe_stream = Some(
+                            StreamState {
+                                pressure_bar_abs: Some(p_bar_abs),
+                                temp_c: Some(t_c),
+                                density_kg_per_m3: Some(density),
+                                mass_flow_kg_per_h: Some(mass_flow_kg_per_h),
+                                ..StreamState::new("gui.pipe.card_label")
+                            }
+                            .complete_from_if97(),
+                        );
+                        let d_out =
+                            convert_length_gui(r.inner_diameter_m, "m", &self.inputs.piping.sizing.diam_out_unit);
+                        let v_out = convert_velocity_gui(
+                            r.velocity_m_per_s,
+                            "m/s",
+                            &self.inputs.piping.sizing.vel_out_unit,
+                        );
+                        let mut out = format!(
+                            "Pipe ID = {} {}, Velocity = {} {}, Reynolds (Re) = {:.2e}",
+                            fmt_num(d_out, 4),
+                            self.inputs.piping.sizing.diam_out_unit,
+                            fmt_num(v_out, 2),
+                            self.inputs.piping.sizing.vel_out_unit,
+                            r.reynolds_number,
+                        );
+                        if let Some(name) = &self.pipe_class_choice {
+                            let p_bar_g = p_bar_abs - site_atmosphere().bar_abs();
+                            out.push('\n');
+                            out.push_str(
+                                &match self
+                                    .pipe_classes
+                                    .find(name)
+                                    .and_then(|c| pipe_class::select_size(c, r.inner_diameter_m, p_bar_g, t_c))
+                                {
+                                    Ok(check) => pipe_class_check_text(txt, &check),
+                                    Err(e) => error_text(&self.tr, e),
+                                },
+                            );
+                        }
+                        match velocity_check::check_sized_pipe(
+                            &r,
+                            self.pipe_service,
+                            p_bar_abs,
+                            t_c,
+                            density,
+                        ) {
+                            Ok(check) => {
+                                out.push('\n');
+                                out.push_str(&fill_template(
+                                    &txt(
+                                        "gui.pipe.service_result",
+                                        "Velocity check ({service}): erosional Ve = {ve} m/s, {util}% of limit",
+                                    ),
+                                    &[
+                                        ("service", txt(check.service.i18n_key(), check.service.label())),
+                                        ("ve", fmt_qty(check.erosional_m_per_s, QuantityKind::Velocity)),
+                                        ("util", fmt_num(check.utilization() * 100.0, 0)),
+                                    ],
+                                ));
+                                push_warnings(&mut out, &self.tr, &[check]);
+                            }
+                            Err(e) => {
+                                out.push('\n');
+                                out.push_str(&error_text(&self.tr, e));
+                            }
+                        }
+                        out
+                    }
+                    Err(e) => {
+                        let tpl = txt(
+                            "gui.pipe.error.sizing",
+                            "Error(mdot={mdot} {m_unit}, P={p} {p_unit}{mode}, T={t} {t_unit}): {e}",
+                        );
+                        let mode = if self.inputs.piping.sizing.pressure_mode == conversion::PressureMode::Gauge {
+                            "g"
+                        } else {
+                            "a"
+                        };
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("mdot", fmt_num(self.inputs.piping.sizing.mass_flow, 2)),
+                                ("m_unit", self.inputs.piping.sizing.mass_unit.clone()),
+                                ("p", fmt_num(self.inputs.piping.sizing.pressure, 2)),
+                                ("p_unit", self.inputs.piping.sizing.pressure_unit.clone()),
+                                ("mode", mode.to_string()),
+                                ("t", fmt_num(self.inputs.piping.sizing.temp, 1)),
+                                ("t_unit", self.inputs.piping.sizing.temp_unit.clone()),
+                                ("e", error_text(&self.tr, e)),
+                            ],
+                        )
+                    }
+                });
+            }
+            if let Some(res) = &self.pipe_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                legend_toggle(
+                    ui,
+                    &txt("legend.pipe.title", "Legend / notes"),
+                    &txt(
+                        "legend.pipe.body",
+                        "ID=inner diameter, Velocity=flow velocity, Re=Reynolds number",
+                    ),
+                    &mut self.show_legend_pipe,
+                );
+            }
+            if let Some(state) = self.pipe_stream.clone() {
+                if let Some(target) = use_in_menu(ui, txt, &state) {
+                    self.send_stream(target, state);
+                }
+            }
+        });
+    }
+
+    /// 배관 압력 손실(Darcy-Weisbach) 카드. 스윕 결과도 카드 안에 그린다.
+    fn ui_pipe_loss<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.label(txt(
+                "gui.pipe.loss.heading",
+                "Pressure Loss (Darcy-Weisbach)",
+            ));
+            ui.horizontal(|ui| {
+                if card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::PipeLoss) {
+                    self.inputs.piping.loss = self.input_defaults.piping.loss.clone();
+                }
+                self.paste_stream_button(ui, txt, StreamTarget::PipeLoss);
+            });
+            egui::Grid::new("pipe_loss_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(txt(
+                        "gui.pipe.loss.mass_flow",
+                        "Mass flow [kg/h]",
+                    ));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.sizing.mass_flow).speed(10.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.piping.sizing.mass_unit,
+                        flow_units::MASS_FLOW_UNITS,
+                    );
+                    ui.end_row();
+                    ui.label(txt(
+                        "gui.pipe.loss.pressure",
+                        "State pressure [bar(a)] (IF97)",
+                    ));
+                    quantity_input(ui, txt, "pipe_loss_pressure", &mut self.inputs.piping.loss.pressure_bar_abs, FieldQuantity::PressureBarAbs, 0.1);
+                    ui.end_row();
+                    ui.label(txt(
+                        "gui.pipe.loss.temperature",
+                        "State temperature [°C] (IF97)",
+                    ));
+                    quantity_input(ui, txt, "pipe_loss_temperature", &mut self.inputs.piping.loss.temperature_c, FieldQuantity::TemperatureC, 1.0);
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.density", "Density [kg/m3]"));
+                    quantity_input(ui, txt, "pipe_loss_density", &mut self.inputs.piping.loss.density, FieldQuantity::DensityKgPerM3, 0.1);
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.diameter", "Inner diameter [m]"));
+                    quantity_input(ui, txt, "pipe_loss_diameter", &mut self.inputs.piping.loss.diameter, FieldQuantity::LengthM, 0.001);
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.length", "Length [m]"));
+                    quantity_input(ui, txt, "pipe_loss_length", &mut self.inputs.piping.loss.length, FieldQuantity::LengthM, 1.0);
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.eq_length", "Equivalent length [m]"));
+                    quantity_input(ui, txt, "pipe_loss_eq_length", &mut self.inputs.piping.loss.eq_length, FieldQuantity::LengthM, 1.0);
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.loss.elevation", "Elevation in / out [m]"),
+                        &txt(
+                            "gui.pipe.loss.elevation_tip",
+                            "Inlet and outlet elevation; the static head ρgΔz is shown apart from the friction loss",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.z_in).speed(0.5));
+                        ui.label("→");
+                        ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.z_out).speed(0.5));
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.fittings", "Additional K sum"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.fittings_k).speed(0.1));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe.loss.material", "Pipe material"),
+                        &txt("gui.pipe.loss.material_tip", "Fills roughness ε from the material table; editing ε switches to Custom"),
+                    );
+                    let preset = material_db::find_roughness(&self.inputs.piping.loss.material)
+                        .filter(|r| (r.roughness_m() - self.inputs.piping.loss.roughness).abs() <= 1e-12);
+                    let selected = preset
+                        .map(|r| txt(r.key, r.name))
+                        .unwrap_or_else(|| txt("gui.pipe.loss.material_custom", "Custom"));
+                    egui::ComboBox::from_id_source("pipe_loss_material")
+                        .selected_text(selected)
+                        .show_ui(ui, |ui| {
+                            for r in material_db::roughness_presets() {
+                                let label = format!("{} (ε {} mm)", txt(r.key, r.name), r.roughness_mm);
+                                let current = preset.is_some_and(|p| p.code == r.code);
+                                if ui.selectable_label(current, label).clicked() {
+                                    self.inputs.piping.loss.material = r.code.to_string();
+                                    self.inputs.piping.loss.roughness = r.roughness_m();
+                                }
+                            }
+                        });
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.roughness", "Roughness ε [m]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.roughness).speed(0.00001));
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.viscosity", "Viscosity [Pa·s]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.visc).speed(1e-6));
+                    ui.end_row();
+                    ui.label(txt("gui.pipe.loss.sound_speed", "Speed of sound [m/s]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.piping.loss.sound_speed).speed(5.0));
+                    ui.end_row();
+                    ui.label(txt(
+                        "gui.pipe.loss.output",
+                        "Output ΔP unit",
+                    ));
+                    unit_combo(ui, &mut self.inputs.piping.loss.dp_out_unit, &dp_unit_options());
+                    ui.end_row();
+                });
+            egui::CollapsingHeader::new(txt("gui.pipe.loss.fittings_table", "Fittings"))
+                .id_source("pipe_loss_fittings")
+                .default_open(!self.pipe_loss_fittings.is_empty())
+                .show(ui, |ui| {
+                    fittings_table_ui(
+                        ui,
+                        "pipe_loss_fittings_grid",
+                        &mut self.pipe_loss_fittings,
+                        &mut self.inputs.piping.loss.fitting_method,
+                        self.inputs.piping.loss.diameter,
+                        txt,
+                    );
+                });
+            if run_button(ui, txt("gui.pipe.loss.run", "Calculate ΔP")) {
+                let input = self.pipe_loss_input();
+                let fittings = fitting_list(&self.pipe_loss_fittings);
+                let method = self.inputs.piping.loss.fitting_method;
+                let mut work_input = input.clone();
+                self.pipe_loss_work.clear();
+                self.pipe_loss_result = Some(match steam::steam_piping::pressure_loss_with_fittings(input, &fittings, method) {
+                    Ok(r) => {
+                        work_input.fittings_k_sum += fittings.k_sum(method, r.reynolds_number, work_input.diameter_m);
+                        self.pipe_loss_work =
+                            NUMBER_FORMAT.with(|nf| show_work::pressure_loss_work(&work_input, &r, &nf.borrow()));
+                        self.pipe_loss_last = Some(line_case::CasePipe {
+                            inner_diameter_m: self.inputs.piping.loss.diameter,
+                            pressure_drop_bar: r.total_drop_bar(),
+                        });
+                        let dp_out = dp_convert_gui(r.pressure_drop_bar, "bar", &self.inputs.piping.loss.dp_out_unit);
+                        let mut out = format!(
+                            "ΔP={} {}, v={} m/s, Re={:.2e}, f={}, Mach={}",
+                            fmt_num(dp_out, 4),
+                            self.inputs.piping.loss.dp_out_unit,
+                            fmt_qty(r.velocity_m_per_s, QuantityKind::Velocity),
+                            r.reynolds_number,
+                            fmt_num(r.friction_factor, 4),
+                            fmt_num(r.mach, 3),
+                        );
+                        if r.elevation_drop_bar != 0.0 {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.pipe.loss.elevation_result",
+                                    "Static head ρgΔz={dz} {unit}, total ΔP (friction + static)={total} {unit}",
+                                ),
+                                &[
+                                    ("dz", fmt_num(dp_convert_gui(r.elevation_drop_bar, "bar", &self.inputs.piping.loss.dp_out_unit), 4)),
+                                    ("total", fmt_num(dp_convert_gui(r.total_drop_bar(), "bar", &self.inputs.piping.loss.dp_out_unit), 4)),
+                                    ("unit", self.inputs.piping.loss.dp_out_unit.clone()),
+                                ],
+                            ));
+                        }
+                        if !fittings.is_empty() {
+                            let d = self.inputs.piping.loss.diameter;
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt("gui.pipe.loss.fittings_result", "Fittings ΣK={k:.3}, equivalent length={leq:.1} m"),
+                                &[
+                                    ("k", format!("{}", fittings.k_sum(method, r.reynolds_number, d))),
+                                    (
+                                        "leq",
+                                        format!("{}", fittings.equivalent_length_m(method, r.reynolds_number, d, r.friction_factor)),
+                                    ),
+                                ],
+                            ));
+                        }
+                        out
+                    }
+                    Err(e) => {
+                        self.pipe_loss_last = None;
+                        let tpl = txt(
+                            "gui.pipe.loss.error",
+                            "Error(mdot={mdot} {m_unit}, rho={rho} kg/m3, D={d} m, L={l} m): {e}",
+                        );
+                        fill_template(
+                            &tpl,
+                            &[
+                                ("mdot", fmt_num(self.inputs.piping.sizing.mass_flow, 2)),
+                                ("m_unit", self.inputs.piping.sizing.mass_unit.clone()),
+                                ("rho", fmt_num(self.inputs.piping.loss.density, 2)),
+                                ("d", fmt_num(self.inputs.piping.loss.diameter, 4)),
+                                ("l", fmt_num(self.inputs.piping.loss.length, 1)),
+                                ("e", error_text(&self.tr, e)),
+                            ],
+                        )
+                    }
+                });
+            }
+            if let Some(res) = &self.pipe_loss_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                show_work_block(ui, txt, "pipe_loss_work", &self.pipe_loss_work);
+                if let Some(pipe) = self.pipe_loss_last {
+                    ui.horizontal(|ui| {
+                        if ui
+                            .small_button(txt("gui.line_case.to_upstream", "→ Line case: upstream pipe"))
+                            .clicked()
+                        {
+                            self.line_case.upstream_pipe = Some(pipe);
+                        }
+                        if ui
+                            .small_button(txt("gui.line_case.to_downstream", "→ Line case: downstream pipe"))
+                            .clicked()
+                        {
+                            self.line_case.downstream_pipe = Some(pipe);
+                        }
+                    });
+                }
+                legend_toggle(
+                    ui,
+                    &txt("legend.pipe_loss.title", "Legend / notes"),
+                    &txt("legend.pipe_loss.body", "ΔP=pressure drop, v=velocity, Re=Reynolds, f=friction factor, Mach=speed ratio"),
+                    &mut self.show_legend_pipe_loss,
+                );
+            }
+            ui.separator();
+            let params = [
+                txt("gui.pipe.loss.diameter", "Inner diameter [m]"),
+                txt("gui.pipe.loss.mass_flow", "Mass flow [kg/h]"),
+                txt("gui.pipe.loss.length", "Length [m]"),
+                txt("gui.pipe.loss.fittings", "Additional K sum"),
+            ];
+            if sweep_controls_ui(ui, "pipe_loss_sweep", &mut self.pipe_loss_sweep, &params, txt) {
+                let base = self.pipe_loss_input();
+                let range = self.pipe_loss_sweep.range();
+                // 피팅 표의 K는 점마다 Re/D에 맞춰 다시 계산한다.
+                let fittings = fitting_list(&self.pipe_loss_fittings);
+                let method = self.inputs.piping.loss.fitting_method;
+                let calc = |i| steam::steam_piping::pressure_loss_with_fittings(i, &fittings, method);
+                let res = match self.pipe_loss_sweep.param {
+                    0 => sweep::run_sweep(
+                        &base,
+                        "D [m]",
+                        range,
+                        |i, x| i.diameter_m = x,
+                        calc,
+                        sweep::PRESSURE_LOSS_COLUMNS,
+                    ),
+                    1 => sweep::run_sweep(
+                        &base,
+                        "ṁ [kg/h]",
+                        range,
+                        |i, x| i.mass_flow_kg_per_h = x,
+                        calc,
+                        sweep::PRESSURE_LOSS_COLUMNS,
+                    ),
+                    2 => sweep::run_sweep(
+                        &base,
+                        "L [m]",
+                        range,
+                        |i, x| i.length_m = x,
+                        calc,
+                        sweep::PRESSURE_LOSS_COLUMNS,
+                    ),
+                    _ => sweep::run_sweep(
+                        &base,
+                        "ΣK",
+                        range,
+                        |i, x| i.fittings_k_sum = x,
+                        calc,
+                        sweep::PRESSURE_LOSS_COLUMNS,
+                    ),
+                };
+                self.pipe_loss_sweep.set_result(res);
+            }
+            sweep_result_ui(ui, "pipe_loss_sweep", &mut self.pipe_loss_sweep, &self.tr);
+        });
+    }
+
+    /// 압력손실 카드의 현재 입력값으로 계산 입력을 만든다.
+    fn pipe_loss_input(&self) -> steam::steam_piping::PressureLossInput {
+        steam::steam_piping::PressureLossInput {
+            mass_flow_kg_per_h: convert_massflow_gui(self.inputs.piping.sizing.mass_flow, &self.inputs.piping.sizing.mass_unit, "kg/h"),
+            steam_density_kg_per_m3: self.inputs.piping.loss.density,
+            diameter_m: self.inputs.piping.loss.diameter,
+            length_m: self.inputs.piping.loss.length,
+            equivalent_length_m: self.inputs.piping.loss.eq_length,
+            fittings_k_sum: self.inputs.piping.loss.fittings_k,
+            roughness_m: self.inputs.piping.loss.roughness,
+            dynamic_viscosity_pa_s: self.inputs.piping.loss.visc,
+            sound_speed_m_per_s: self.inputs.piping.loss.sound_speed,
+            state_pressure_bar_abs: Some(self.inputs.piping.loss.pressure_bar_abs),
+            state_temperature_c: Some(self.inputs.piping.loss.temperature_c),
+            inlet_elevation_m: self.inputs.piping.loss.z_in,
+            outlet_elevation_m: self.inputs.piping.loss.z_out,
+        }
+    }
+
+    /// 배관 경로 표와 압력손실 카드의 유량·입구 상태로 배관 경로 계산 입력을 만든다.
+    fn pipe_run_input(&self) -> Result<pipe_run::PipeRunInput, PipeCalcError> {
+        Ok(pipe_run::PipeRunInput {
+            mass_flow_kg_per_h: convert_massflow_gui(self.inputs.piping.sizing.mass_flow, &self.inputs.piping.sizing.mass_unit, "kg/h"),
+            inlet_pressure_bar_abs: self.inputs.piping.loss.pressure_bar_abs,
+            inlet_temperature_c: self.inputs.piping.loss.temperature_c,
+            fitting_method: self.inputs.piping.loss.fitting_method,
+            dynamic_viscosity_pa_s: self.inputs.piping.loss.visc,
+            sound_speed_m_per_s: self.inputs.piping.loss.sound_speed,
+            segments: self
+                .pipe_run_rows
+                .iter()
+                .map(PipeRunRow::segment)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    fn ui_steam_valves(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt("gui.valve.heading", "Steam Valves & Orifices"),
+            &txt(
+                "gui.valve.tip",
+                "Compute required Cv/Kv or flow for given Cv/Kv.",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt("gui.valve.card_label", "Cv/Kv calculator"),
+            &txt(
+                "gui.valve.card_tip",
+                "Use ΔP/upstream P/flow/density to size or check flow.",
+            ),
+        );
+        ui.add_space(8.0);
+        self.ui_valve_sizing(ui, &txt);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::ValveAuthority);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::ValveActuator);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::LineCase);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::TurbineBypass);
+    }
+
+    /// 밸브 권한·설치 특성 카드.
+    fn ui_valve_authority<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.valve.authority.heading", "Valve authority & installed characteristic"),
+                &txt(
+                    "gui.valve.authority.tip",
+                    "Combines the inherent characteristic with the circuit ΔP split; authority N = ΔP_valve / (ΔP_valve + ΔP_circuit) below 0.25 is flagged",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::ValveAuthority)).inner {
+                self.inputs.valves.authority = self.input_defaults.valves.authority.clone();
+            }
+            egui::Grid::new("valve_authority_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.valve.authority.characteristic", "Inherent characteristic / R"),
+                        &txt("gui.valve.authority.characteristic_tip", "Rangeability R is used by the equal-percentage curve R^(x−1)"),
+                    );
+                    ui.horizontal(|ui| {
+                        let all = valve_authority::InherentCharacteristic::ALL;
+                        let current = all[self.inputs.valves.authority.characteristic.min(all.len() - 1)];
+                        egui::ComboBox::from_id_source("valve_authority_char")
+                            .selected_text(txt(current.i18n_key(), current.label()))
+                            .show_ui(ui, |ui| {
+                                for (i, c) in all.iter().enumerate() {
+                                    ui.selectable_value(&mut self.inputs.valves.authority.characteristic, i, txt(c.i18n_key(), c.label()));
+                                }
+                            });
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.authority.rangeability).speed(1.0).clamp_range(2.0..=200.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.valve.authority.dp", "ΔP valve / circuit [bar]"),
+                        &txt("gui.valve.authority.dp_tip", "At design flow: fully open valve ΔP and the rest of the circuit (pipe, exchanger, fittings)"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.authority.valve_dp).speed(0.05));
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.authority.circuit_dp).speed(0.05));
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.valve.authority.run", "Analyse authority")) {
+                let all = valve_authority::InherentCharacteristic::ALL;
+                let input = valve_authority::AuthorityInput {
+                    characteristic: all[self.inputs.valves.authority.characteristic.min(all.len() - 1)],
+                    rangeability: self.inputs.valves.authority.rangeability,
+                    valve_dp_bar: self.inputs.valves.authority.valve_dp,
+                    circuit_dp_bar: self.inputs.valves.authority.circuit_dp,
+                    steps: 20,
+                };
+                match valve_authority::analyze_authority(&input) {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.valve.authority.result",
+                                "Authority N = {n}, installed gain varies {ratio}× over 10–90 % stroke",
+                            ),
+                            &[
+                                ("n", fmt_num(res.authority, 2)),
+                                ("ratio", fmt_num(res.gain_ratio, 1)),
+                            ],
+                        );
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        self.valve_auth_points = res.points;
+                        self.valve_auth_result = Some(out);
+                    }
+                    Err(e) => {
+                        self.valve_auth_points.clear();
+                        self.valve_auth_result = Some(fill_template(
+                            &txt("gui.valve.authority.error", "Error: {e}"),
+                            &[("e", error_text(&self.tr, e))],
+                        ));
+                    }
+                }
+            }
+            if let Some(res) = &self.valve_auth_result {
+                result_block(ui, txt, res, false);
+            }
+            if !self.valve_auth_points.is_empty() {
+                let series = |f: fn(&valve_authority::CharacteristicPoint) -> f64| -> Vec<[f64; 2]> {
+                    self.valve_auth_points
+                        .iter()
+                        .map(|p| [p.stroke * 100.0, f(p) * 100.0])
+                        .collect()
+                };
+                let inherent = series(|p| p.inherent_flow);
+                let installed = series(|p| p.installed_flow);
+                let inherent_name = txt("gui.valve.authority.inherent", "Inherent");
+                let installed_name = txt("gui.valve.authority.installed", "Installed");
+                egui_plot::Plot::new("valve_authority_plot")
+                    .height(200.0)
+                    .include_x(0.0)
+                    .include_x(100.0)
+                    .include_y(0.0)
+                    .include_y(100.0)
+                    .legend(egui_plot::Legend::default())
+                    .x_axis_label(txt("gui.valve.authority.x_axis", "Stroke [%]"))
+                    .y_axis_label(txt("gui.valve.authority.y_axis", "Flow [% of max]"))
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(egui_plot::Line::new(inherent).name(inherent_name));
+                        plot_ui.line(egui_plot::Line::new(installed).name(installed_name));
+                    });
+            }
+        });
+    }
+
+    /// 밸브 Cv/Kv 산정·유량 확인 카드.
+    fn ui_valve_sizing<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.horizontal(|ui| {
+                ui.selectable_value(
+                    &mut self.inputs.valves.sizing.mode,
+                    ValveMode::RequiredCvKv,
+                    txt("gui.valve.mode.required", "Required Cv/Kv"),
+                )
+                .on_hover_text(txt(
+                    "gui.valve.mode.required_tip",
+                    "Compute Cv/Kv to achieve the target flow.",
+                ));
+                ui.selectable_value(
+                    &mut self.inputs.valves.sizing.mode,
+                    ValveMode::FlowFromCvKv,
+                    txt("gui.valve.mode.flow", "Flow from Cv/Kv"),
+                )
+                .on_hover_text(txt(
+                    "gui.valve.mode.flow_tip",
+                    "Compute flow when Cv/Kv is given.",
+                ));
+            });
+            ui.horizontal(|ui| {
+                if card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::ValveSizing) {
+                    self.inputs.valves.sizing = self.input_defaults.valves.sizing.clone();
+                }
+                self.paste_stream_button(ui, txt, StreamTarget::ValveSizing);
+            });
+            if let Some(ValveDbAction::Load(record)) =
+                valve_db_ui(ui, txt, "valve_sizing_db", &self.valve_db, &mut self.valve_db_ui, false)
+            {
+                // 정격 Cv로 유량 모드를 채우고 특성은 권한 해석 입력으로 넘긴다
+                self.inputs.valves.sizing.mode = ValveMode::FlowFromCvKv;
+                self.inputs.valves.sizing.cv_kv = record.rated_kv();
+                let all = valve_authority::InherentCharacteristic::ALL;
+                if let Some(i) = record.characteristic.and_then(|c| all.iter().position(|&a| a == c)) {
+                    self.inputs.valves.authority.characteristic = i;
+                }
+                if let Some(r) = record.rangeability {
+                    self.inputs.valves.authority.rangeability = r;
+                }
+            }
+            egui::Grid::new("valve_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &match self.inputs.valves.sizing.mode {
+                            ValveMode::RequiredCvKv => {
+                                txt("gui.valve.input.flow", "Volumetric flow")
+                            }
+                            ValveMode::FlowFromCvKv => {
+                                txt("gui.valve.input.cv", "Cv/Kv input")
+                            }
+                        },
+                        &txt(
+                            "gui.valve.input.flow_tip",
+                            "Enter flow to size Cv/Kv, or enter Cv/Kv to compute flow.",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.sizing.flow).speed(1.0));
+                    if matches!(self.inputs.valves.sizing.mode, ValveMode::RequiredCvKv) {
+                        unit_combo(
+                            ui,
+                            &mut self.inputs.valves.sizing.flow_unit,
+                            &flow_unit_options(),
+                        );
+                    }
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.valve.input.dp", "ΔP [bar]"),
+                        &txt(
+                            "gui.valve.input.dp_tip",
+                            "Pressure drop across valve (a difference, no gauge/absolute reference); check choking for steam/gas.",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.sizing.dp).speed(0.1));
+                    unit_combo(ui, &mut self.inputs.valves.sizing.dp_unit, &dp_unit_options());
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.valve.input.upstream", "Upstream pressure"),
+                        &txt(
+                            "gui.valve.input.upstream_tip",
+                            "Absolute upstream pressure when computing flow (for choking check).",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.sizing.upstream_p).speed(0.1));
+                    unit_combo(ui, &mut self.inputs.valves.sizing.upstream_unit, &pressure_unit_options());
+                    ui.selectable_value(&mut self.inputs.valves.sizing.upstream_mode, conversion::PressureMode::Gauge, "Gauge (G)");
+                    ui.selectable_value(&mut self.inputs.valves.sizing.upstream_mode, conversion::PressureMode::Absolute, "Absolute (A)");
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.valve.input.density", "Density [kg/m3]"),
+                        &txt(
+                            "gui.valve.input.density_tip",
+                            "Fluid density (use condition-based density; IF97 recommended for steam).",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.sizing.rho).speed(0.1));
+                    unit_combo(ui, &mut self.inputs.valves.sizing.rho_unit, flow_units::DENSITY_UNITS);
+                    ui.end_row();
+                    if let ValveMode::FlowFromCvKv = self.inputs.valves.sizing.mode {
+                        label_with_tip(
+                            ui,
+                            &txt("gui.valve.input.cv_value", "Cv/Kv value"),
+                            &txt("gui.valve.input.cv_tip", "Manufacturer Cv or Kv value"),
+                        );
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.sizing.cv_kv).speed(0.5));
+                        ui.end_row();
+                    }
+                });
+            ui.small(txt(
+                "gui.valve.tip_mmhg",
+                "Tip: mmHg is treated as gauge (0=atm, -760mmHg=vacuum).",
+            ));
+            ui.add_space(8.0);
+            if run_button(ui, txt("gui.valve.run", "Calculate")) {
+                self.valve_work.clear();
+                self.valve_result = Some(match self.inputs.valves.sizing.mode {
+                    ValveMode::RequiredCvKv => {
+                        let q = convert_flow_gui(self.inputs.valves.sizing.flow, &self.inputs.valves.sizing.flow_unit, &self.inputs.valves.sizing.rho_unit, self.inputs.valves.sizing.rho);
+                        let dp = dp_convert_gui(self.inputs.valves.sizing.dp, &self.inputs.valves.sizing.dp_unit, "bar");
+                        let rho = convert_density_gui(self.inputs.valves.sizing.rho, &self.inputs.valves.sizing.rho_unit, "kg/m3");
+                        match steam_valves::required_kv(q, dp, rho) {
+                            Ok(kv) => {
+                                self.valve_last = Some(line_case::CaseValve { kv, pressure_drop_bar: dp });
+                                self.valve_work =
+                                    NUMBER_FORMAT.with(|nf| show_work::valve_kv_work(q, dp, rho, kv, &nf.borrow()));
+                                let tpl = txt("gui.valve.result.required", "Kv={kv}, Cv={cv}");
+                                fill_template(
+                                    &tpl,
+                                    &[
+                                        ("kv", fmt_num(kv, 3)),
+                                        ("cv", fmt_num(steam_valves::cv_from_kv(kv), 3)),
+                                    ],
+                                )
+                            }
+                            Err(e) => {
+                                self.valve_last = None;
+                                let tpl = txt(
+                                    "gui.valve.error.required",
+                                    "Error(Q={q} {q_unit}, ΔP={dp} {dp_unit}, rho={rho} {rho_unit}): {e}",
+                                );
+                                fill_template(
+                                    &tpl,
+                                    &[
+                                        ("q", fmt_num(self.inputs.valves.sizing.flow, 2)),
+                                        ("q_unit", self.inputs.valves.sizing.flow_unit.clone()),
+                                        ("dp", fmt_num(self.inputs.valves.sizing.dp, 2)),
+                                        ("dp_unit", self.inputs.valves.sizing.dp_unit.clone()),
+                                        ("rho", fmt_num(self.inputs.valves.sizing.rho, 2)),
+                                        ("rho_unit", self.inputs.valves.sizing.rho_unit.clone()),
+                                        ("e", error_text(&self.tr, e)),
+                                    ],
+                                )
+                            }
+                        }
+                    }
+                    ValveMode::FlowFromCvKv => {
+                        let upstream_bar_abs = convert_pressure_mode_gui(
+                            self.inputs.valves.sizing.upstream_p,
+                            &self.inputs.valves.sizing.upstream_unit,
+                            self.inputs.valves.sizing.upstream_mode,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                        );
+                        let dp_bar = dp_convert_gui(self.inputs.valves.sizing.dp, &self.inputs.valves.sizing.dp_unit, "bar");
+                        let kv = self.inputs.valves.sizing.cv_kv;
+                        // 온도 입력이 없으므로 상류 압력의 건포화 증기로 임계 압력비를 구한다.
+                        match steam_valves::steam_flow_from_kv(
+                            kv,
+                            dp_bar,
+                            convert_density_gui(self.inputs.valves.sizing.rho, &self.inputs.valves.sizing.rho_unit, "kg/m3"),
+                            upstream_bar_abs,
+                            critical_flow::SteamStagnation::Quality(1.0),
+                        ) {
+                            Ok(flow) => {
+                                let q_m3h = flow.volumetric_flow_m3_per_h;
+                                self.valve_last = Some(line_case::CaseValve {
+                                    kv,
+                                    pressure_drop_bar: dp_bar,
+                                });
+                                let q_out = convert_flow_from_m3h(
+                                    q_m3h,
+                                    &self.inputs.valves.sizing.flow_unit,
+                                    &self.inputs.valves.sizing.rho_unit,
+                                    self.inputs.valves.sizing.rho,
+                                );
+                                let mass_kg_h =
+                                    q_m3h * convert_density_gui(self.inputs.valves.sizing.rho, &self.inputs.valves.sizing.rho_unit, "kg/m3");
+                                let downstream_abs = (upstream_bar_abs - dp_bar).max(0.0);
+                                let warn = if flow.choked {
+                                    txt("gui.valve.warn.choked", " [Warning: potential choked flow]").to_string()
+                                } else {
+                                    String::new()
+                                };
+                                let tpl = txt(
+                                    "gui.valve.result.flow",
+                                    "Flow {flow} {flow_unit}{warn}, mass {mass} kg/h (Pu={pu} bar(a), Pd={pd} bar(a))",
+                                );
+                                fill_template(
+                                    &tpl,
+                                    &[
+                                        ("flow", fmt_num(q_out, 3)),
+                                        ("flow_unit", self.inputs.valves.sizing.flow_unit.clone()),
+                                        ("warn", warn),
+                                        ("mass", fmt_num(mass_kg_h, 3)),
+                                        ("pu", fmt_qty(upstream_bar_abs, QuantityKind::Pressure)),
+                                        ("pd", fmt_num(downstream_abs, 2)),
+                                    ],
+                                )
+                            }
+                            Err(e) => {
+                                self.valve_last = None;
+                                let tpl = txt(
+                                    "gui.valve.error.flow",
+                                    "Error(Cv/Kv={cv}, ΔP={dp} {dp_unit}, rho={rho} {rho_unit}): {e}",
+                                );
+                                fill_template(
+                                    &tpl,
+                                    &[
+                                        ("cv", fmt_num(kv, 2)),
+                                        ("dp", fmt_num(self.inputs.valves.sizing.dp, 2)),
+                                        ("dp_unit", self.inputs.valves.sizing.dp_unit.clone()),
+                                        ("rho", fmt_num(self.inputs.valves.sizing.rho, 2)),
+                                        ("rho_unit", self.inputs.valves.sizing.rho_unit.clone()),
+                                        ("e", error_text(&self.tr, e)),
+                                    ],
+                                )
+                            }
+                        }
+                    }
+                });
+            }
+            if let Some(res) = &self.valve_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                show_work_block(ui, txt, "valve_work", &self.valve_work);
+                if let Some(valve) = self.valve_last {
+                    if ui
+                        .small_button(txt("gui.line_case.to_valve", "→ Line case: valve"))
+                        .clicked()
+                    {
+                        self.line_case.valve = Some(valve);
+                    }
+                }
+                legend_toggle(
+                    ui,
+                    &txt("legend.valve.title", "Legend / notes"),
+                    &txt("legend.valve.body", "Cv/Kv: flow coefficient, ΔP: pressure drop; note density and choking limits."),
+                    &mut self.show_legend_valve,
+                );
+            }
+            ui.separator();
+            let params: Vec<String> = match self.inputs.valves.sizing.mode {
+                ValveMode::RequiredCvKv => vec![
+                    txt("gui.sweep.valve.dp", "ΔP [bar]"),
+                    txt("gui.sweep.valve.flow", "Volumetric flow [m3/h]"),
+                ],
+                ValveMode::FlowFromCvKv => vec![txt("gui.sweep.valve.dp", "ΔP [bar]")],
+            };
+            if self.valve_sweep.param >= params.len() {
+                self.valve_sweep.param = 0;
+            }
+            if sweep_controls_ui(ui, "valve_sweep", &mut self.valve_sweep, &params, txt) {
+                let rho = convert_density_gui(self.inputs.valves.sizing.rho, &self.inputs.valves.sizing.rho_unit, "kg/m3");
+                let range = self.valve_sweep.range();
+                let res = match self.inputs.valves.sizing.mode {
+                    ValveMode::RequiredCvKv => {
+                        let q_m3h = convert_flow_gui(
+                            self.inputs.valves.sizing.flow,
+                            &self.inputs.valves.sizing.flow_unit,
+                            &self.inputs.valves.sizing.rho_unit,
+                            self.inputs.valves.sizing.rho,
+                        );
+                        if self.valve_sweep.param == 0 {
+                            sweep::required_cv_vs_dp(q_m3h, rho, range)
+                        } else {
+                            let dp_bar = dp_convert_gui(self.inputs.valves.sizing.dp, &self.inputs.valves.sizing.dp_unit, "bar");
+                            sweep::run_sweep(
+                                &(q_m3h, dp_bar, rho),
+                                "Q [m³/h]",
+                                range,
+                                |i, x| i.0 = x,
+                                |(q, dp, rho)| steam_valves::required_kv(q, dp, rho),
+                                sweep::VALVE_KV_COLUMNS,
+                            )
+                        }
+                    }
+                    ValveMode::FlowFromCvKv => {
+                        let upstream_bar_abs = convert_pressure_mode_gui(
+                            self.inputs.valves.sizing.upstream_p,
+                            &self.inputs.valves.sizing.upstream_unit,
+                            self.inputs.valves.sizing.upstream_mode,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                        );
+                        sweep::flow_from_kv_vs_dp(self.inputs.valves.sizing.cv_kv, rho, Some(upstream_bar_abs), range)
+                    }
+                };
+                self.valve_sweep.set_result(res);
+            }
+            sweep_result_ui(ui, "valve_sweep", &mut self.valve_sweep, &self.tr);
+        });
+    }
+
+    /// 흐름 상태를 대상 카드 입력에 옮겨 쓴다. 단위는 상태의 기준 단위(bar(a), °C, kg/h, kg/m3, m3/h)로 바꾼다.
+    fn apply_stream(&mut self, target: StreamTarget, state: &StreamState) {
+        for (field, value) in state.transfer(target) {
+            match (target, field) {
+                (StreamTarget::PipeSizing | StreamTarget::PipeLoss, StreamField::MassFlow) => {
+                    self.inputs.piping.sizing.mass_flow = value;
+                    self.inputs.piping.sizing.mass_unit = "kg/h".into();
+                }
+                (StreamTarget::PipeSizing, StreamField::Pressure) => {
+                    self.inputs.piping.sizing.pressure = value;
+                    self.inputs.piping.sizing.pressure_unit = "bar".into();
+                    self.inputs.piping.sizing.pressure_mode = conversion::PressureMode::Absolute;
+                }
+                (StreamTarget::PipeSizing, StreamField::Temperature) => {
+                    self.inputs.piping.sizing.temp = value;
+                    self.inputs.piping.sizing.temp_unit = "C".into();
+                }
+                (StreamTarget::PipeLoss, StreamField::Pressure) => self.inputs.piping.loss.pressure_bar_abs = value,
+                (StreamTarget::PipeLoss, StreamField::Temperature) => self.inputs.piping.loss.temperature_c = value,
+                (StreamTarget::PipeLoss, StreamField::Density) => self.inputs.piping.loss.density = value,
+                (StreamTarget::ValveSizing, StreamField::VolumeFlow) => {
+                    self.inputs.valves.sizing.mode = ValveMode::RequiredCvKv;
+                    self.inputs.valves.sizing.flow = value;
+                    self.inputs.valves.sizing.flow_unit = "m3/h".into();
+                }
+                (StreamTarget::ValveSizing, StreamField::Pressure) => {
+                    self.inputs.valves.sizing.upstream_p = value;
+                    self.inputs.valves.sizing.upstream_unit = "bar".into();
+                    self.inputs.valves.sizing.upstream_mode = conversion::PressureMode::Absolute;
+                }
+                (StreamTarget::ValveSizing, StreamField::Density) => {
+                    self.inputs.valves.sizing.rho = value;
+                    self.inputs.valves.sizing.rho_unit = "kg/m3".into();
+                }
+                (StreamTarget::LineCase, StreamField::MassFlow) => self.line_case.mass_flow_kg_per_h = value,
+                (StreamTarget::LineCase, StreamField::Pressure) => self.line_case.source_pressure_bar_abs = value,
+                (StreamTarget::LineCase, StreamField::Temperature) => self.line_case.source_temp_c = value,
+                _ => {}
+            }
+        }
+    }
+
+    /// "결과 보내기…": 대상 카드에 옮겨 쓰고, 붙여 넣기용으로 보관한 뒤 그 카드가 있는 탭으로 넘어간다.
+    fn send_stream(&mut self, target: StreamTarget, state: StreamState) {
+        self.apply_stream(target, &state);
+        self.stream_clip = Some(state);
+        self.tab = match target {
+            StreamTarget::PipeSizing | StreamTarget::PipeLoss => Tab::SteamPiping,
+            StreamTarget::ValveSizing | StreamTarget::LineCase => Tab::SteamValves,
+        };
+    }
+
+    /// 보관된 흐름 상태가 있으면 카드 위에 붙여 넣기 버튼을 보인다.
+    fn paste_stream_button<F>(&mut self, ui: &mut egui::Ui, txt: &F, target: StreamTarget)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        let Some(clip) = self.stream_clip.clone() else {
+            return;
+        };
+        let enabled = !clip.transfer(target).is_empty();
+        let hover = format!("{}: {}", txt(clip.source_key, clip.source_key), clip);
+        if ui
+            .add_enabled(enabled, egui::Button::new(txt("gui.stream.paste", "⇩ Paste stream")).small())
+            .on_hover_text(hover)
+            .clicked()
+        {
+            self.apply_stream(target, &clip);
+        }
+    }
+
+    /// 밸브와 앞뒤 배관 카드 결과를 한 라인 케이스로 묶어 교차 점검하는 카드.
+    fn ui_line_case<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.line_case.heading", "Line case cross-check"),
+                &txt(
+                    "gui.line_case.tip",
+                    "Combines the valve and its upstream/downstream pipes sent from the valve and pipe ΔP cards: checks the ΔP allocation against the available pressure, pipe velocities, valve choking and Kv capacity",
+                ),
+            );
+            ui.horizontal(|ui| {
+                pin_button(ui, txt, &mut self.inputs.dashboard, CardId::LineCase);
+                self.paste_stream_button(ui, txt, StreamTarget::LineCase);
+            });
+            egui::Grid::new("line_case_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(txt("gui.line_case.pressures", "Source / sink pressure [bar(a)]"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.line_case.source_pressure_bar_abs).speed(0.1));
+                        ui.add(egui::DragValue::new(&mut self.line_case.sink_pressure_bar_abs).speed(0.1));
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.line_case.state", "Source temperature [°C] / flow [kg/h]"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.line_case.source_temp_c).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.line_case.mass_flow_kg_per_h).speed(10.0));
+                    });
+                    ui.end_row();
+                    let not_set = txt("gui.line_case.not_set", "not set");
+                    let pipe_text = |pipe: Option<line_case::CasePipe>| match pipe {
+                        Some(p) => format!("D={} m, ΔP={} bar", fmt_qty(p.inner_diameter_m, QuantityKind::Length), fmt_qty(p.pressure_drop_bar, QuantityKind::Pressure)),
+                        None => not_set.clone(),
+                    };
+                    ui.label(txt("gui.line_case.upstream", "Upstream pipe"));
+                    ui.label(pipe_text(self.line_case.upstream_pipe));
+                    ui.end_row();
+                    ui.label(txt("gui.line_case.valve", "Valve"));
+                    ui.label(match self.line_case.valve {
+                        Some(v) => format!("Kv={}, ΔP={} bar", fmt_num(v.kv, 2), fmt_qty(v.pressure_drop_bar, QuantityKind::Pressure)),
+                        None => not_set.clone(),
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.line_case.downstream", "Downstream pipe"));
+                    ui.label(pipe_text(self.line_case.downstream_pipe));
+                    ui.end_row();
+                });
+            ui.horizontal(|ui| {
+                let run = ui
+                    .add_enabled(
+                        self.line_case.is_complete(),
+                        egui::Button::new(txt("gui.line_case.run", "Cross-check")),
+                    )
+                    .on_disabled_hover_text(txt(
+                        "gui.line_case.incomplete",
+                        "Send a valve and both pipes to the case first",
+                    ))
+                    .clicked();
+                if run {
+                    self.line_case_result = Some(match line_case::check_line_case(&self.line_case) {
+                        Ok(report) => {
+                            let mut out = fill_template(
+                                &txt(
+                                    "gui.line_case.result",
+                                    "ΔP allocated {allocated} / available {available} bar; valve {pin} → {pout} bar(a), {tin} → {tout} °C, required Kv {kv}",
+                                ),
+                                &[
+                                    ("allocated", fmt_qty(report.allocated_dp_bar, QuantityKind::Pressure)),
+                                    ("available", fmt_qty(report.available_dp_bar, QuantityKind::Pressure)),
+                                    ("pin", fmt_qty(report.valve_inlet.pressure_bar_abs, QuantityKind::Pressure)),
+                                    ("pout", fmt_qty(report.valve_outlet.pressure_bar_abs, QuantityKind::Pressure)),
+                                    ("tin", fmt_qty(report.valve_inlet.temp_c, QuantityKind::Temperature)),
+                                    ("tout", fmt_qty(report.valve_outlet.temp_c, QuantityKind::Temperature)),
+                                    ("kv", fmt_num(report.required_kv, 2)),
+                                ],
+                            );
+                            for (label, check) in [
+                                (txt("gui.line_case.upstream", "Upstream pipe"), &report.upstream_velocity),
+                                (txt("gui.line_case.downstream", "Downstream pipe"), &report.downstream_velocity),
+                            ] {
+                                out.push('\n');
+                                out.push_str(check.severity().marker());
+                                out.push(' ');
+                                out.push_str(&label);
+                                out.push_str(": ");
+                                out.push_str(&check.localized(&self.tr));
+                            }
+                            push_warnings(&mut out, &self.tr, &report.findings);
+                            if report.max_severity() == Severity::Info {
+                                out.push('\n');
+                                out.push_str(&txt("gui.line_case.consistent", "All cross-checks passed."));
+                            }
+                            out
+                        }
+                        Err(e) => fill_template(
+                            &txt("gui.line_case.error", "Error: {e}"),
+                            &[("e", error_text(&self.tr, e))],
+                        ),
+                    });
+                }
+                if ui.small_button(txt("gui.line_case.clear", "Clear case")).clicked() {
+                    self.line_case.upstream_pipe = None;
+                    self.line_case.valve = None;
+                    self.line_case.downstream_pipe = None;
+                    self.line_case_result = None;
+                }
+            });
+            if let Some(res) = &self.line_case_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// ST 바이패스 및 TCV 계산 패널.
+    /// - Bypass Valve(증기): Cv/Kv 혹은 Stroke-Cv 테이블로 증기 유량을 계산하고, 필요 시 TCV(물) 결과를 합산해 엔탈피를 본다.
+    /// - TCV(물): 별도 물 밸브 유량 계산을 제공하며, 결과가 바이패스 스프레이 값으로 자동 반영된다.
+    fn ui_bypass_panels(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        ui.heading(txt(
+            "gui.bypass.heading",
+            "Bypass Valve (steam) / TCV (water)",
+        ));
+        ui.label(txt(
+            "gui.bypass.tip",
+            "Use stroke-Cv table if available, otherwise single Cv/Kv.",
+        ));
+        ui.add_space(6.0);
+
+        // ---------- ST Bypass Valve (증기) ----------
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.heading(txt("gui.bypass.steam.heading", "Bypass Valve (steam)"));
+            if ui.horizontal(|ui| card_toolbar(ui, &txt, &mut self.inputs.dashboard, CardId::TurbineBypass)).inner {
+                self.inputs.valves.bypass = self.input_defaults.valves.bypass.clone();
+            }
+            match valve_db_ui(ui, &txt, "bypass_valve_db", &self.valve_db, &mut self.bypass_valve_db, true) {
+                Some(ValveDbAction::Load(record)) => {
+                    let kind = self.inputs.valves.bypass.cv_kind;
+                    let from_cv_us = |v: f64| flow_coefficient::convert(v, FlowCoefficientKind::CvUs, kind);
+                    self.inputs.valves.bypass.cv = from_cv_us(record.rated_cv);
+                    (self.inputs.valves.bypass.stroke_points, self.inputs.valves.bypass.cv_points) = record
+                        .stroke_cv_points()
+                        .into_iter()
+                        .map(|(s, cv)| (s, from_cv_us(cv)))
+                        .unzip();
+                }
+                Some(ValveDbAction::Save(tag)) => {
+                    let record = valve_record_from_table(
+                        &self.valve_db,
+                        &tag,
+                        self.inputs.valves.bypass.cv,
+                        self.inputs.valves.bypass.cv_kind,
+                        &self.inputs.valves.bypass.stroke_points,
+                        &self.inputs.valves.bypass.cv_points,
+                    );
+                    save_valve_record(&self.tr, &mut self.valve_db, &mut self.bypass_valve_db, record);
+                }
+                None => {}
+            }
+            let mut solve_spray = false;
+            egui::Grid::new("bypass_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(txt("gui.bypass.steam.up_p", "Upstream pressure"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.up_p).speed(0.5));
+                        unit_combo(ui, &mut self.inputs.valves.bypass.up_unit, &pressure_unit_options());
+                        ui.selectable_value(
+                            &mut self.inputs.valves.bypass.up_mode,
+                            conversion::PressureMode::Gauge,
+                            "Gauge (G)",
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.valves.bypass.up_mode,
+                            conversion::PressureMode::Absolute,
+                            "Absolute (A)",
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.steam.up_t", "Upstream temperature"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.up_t).speed(1.0));
+                        unit_combo(ui, &mut self.inputs.valves.bypass.t_unit, &temperature_unit_options());
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.steam.down_p", "Downstream pressure"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.down_p).speed(0.5));
+                        unit_combo(ui, &mut self.inputs.valves.bypass.down_unit, &pressure_unit_options());
+                        ui.selectable_value(
+                            &mut self.inputs.valves.bypass.down_mode,
+                            conversion::PressureMode::Gauge,
+                            "Gauge (G)",
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.valves.bypass.down_mode,
+                            conversion::PressureMode::Absolute,
+                            "Absolute (A)",
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.steam.cv", "Cv/Kv"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.cv).speed(1.0));
+                        egui::ComboBox::from_id_source("bypass_cv_kind")
+                            .selected_text(self.inputs.valves.bypass.cv_kind.label())
+                            .show_ui(ui, |ui| {
+                                for kind in FlowCoefficientKind::ALL {
+                                    ui.selectable_value(&mut self.inputs.valves.bypass.cv_kind, kind, kind.label());
+                                }
+                            });
+                        ui.label(txt("gui.bypass.steam.stroke", "Stroke (%)"));
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.valves.bypass.open_pct)
+                                .speed(1.0)
+                                .clamp_range(0.0..=100.0),
+                        );
+                    });
+                    ui.end_row();
+                    ui.label(txt(
+                        "gui.bypass.steam.h_override",
+                        "Steam enthalpy input (kJ/kg, 0=auto IF97)",
+                    ));
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.h_override_kj_per_kg).speed(10.0));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.bypass.steam.target", "Target downstream temperature"),
+                        &txt(
+                            "gui.bypass.steam.target_tip",
+                            "Spray flow that brings the mixed steam to this temperature (same unit as upstream temperature)",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.bypass.target_t).speed(1.0));
+                        if ui
+                            .button(txt("gui.bypass.steam.solve", "Solve spray for target T"))
+                            .clicked()
+                        {
+                            solve_spray = true;
+                        }
+                    });
+                    ui.end_row();
+                    if stroke_based_kv_available(&self.inputs.valves.bypass.stroke_points, &self.inputs.valves.bypass.cv_points) {
+                        let cv_interp = interpolate_stroke_cv(
+                            &self.inputs.valves.bypass.stroke_points,
+                            &self.inputs.valves.bypass.cv_points,
+                            self.inputs.valves.bypass.open_pct,
+                        );
+                        ui.label(format!(
+                            "{}",
+                            fill_template(
+                                &txt(
+                                    "gui.bypass.steam.cv_interp",
+                                    "Interpolated Cv/Kv≈{cv:.3} (stroke {stroke:.1}%)",
+                                ),
+                                &[
+                                    ("cv", fmt_num(cv_interp, 3)),
+                                    ("stroke", fmt_num(self.inputs.valves.bypass.open_pct, 1)),
+                                ],
+                            )
+                        ));
+                        ui.end_row();
+                    }
+                });
+
+            ui.label(txt(
+                "gui.bypass.steam.table",
+                "Stroke-Cv/Kv table (bypass)",
+            ));
+            let bypass_suffix = self.inputs.valves.bypass.cv_kind.symbol();
+            let mut remove_idx: Option<usize> = None;
+            for i in 0..self.inputs.valves.bypass.stroke_points.len() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Stroke {}:", i + 1));
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.valves.bypass.stroke_points[i])
+                            .speed(1.0)
+                            .clamp_range(0.0..=100.0)
+                            .suffix("%"),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.valves.bypass.cv_points[i])
+                            .speed(1.0)
+                            .suffix(bypass_suffix),
+                    );
+                    if ui.small_button("-").clicked() {
+                        remove_idx = Some(i);
+                    }
+                });
+            }
+            ui.horizontal(|ui| {
+                if ui
+                    .small_button(txt("gui.bypass.table.add_row", "+ Add row"))
+                    .clicked()
+                {
+                    self.inputs.valves.bypass.stroke_points.push(100.0);
+                    self.inputs.valves.bypass.cv_points.push(0.0);
+                }
+                ui.label(txt(
+                    "gui.bypass.table.note",
+                    "Interpolation uses Cv for the matching stroke percent.",
+                ));
+            });
+            if let Some(idx) = remove_idx {
+                if self.inputs.valves.bypass.stroke_points.len() > 1 {
+                    self.inputs.valves.bypass.stroke_points.remove(idx);
+                    self.inputs.valves.bypass.cv_points.remove(idx);
+                }
+            }
+            table_paste_ui(
+                ui,
+                &txt,
+                &mut self.bypass_table_paste,
+                &["Stroke %", bypass_suffix],
+                &mut [&mut self.inputs.valves.bypass.stroke_points, &mut self.inputs.valves.bypass.cv_points],
+            );
+
+            ui.add_space(6.0);
+            let run = run_button(ui, txt("gui.bypass.run", "Calculate bypass"));
+            if run || solve_spray {
+                let up_abs = convert_pressure_mode_gui(
+                    self.inputs.valves.bypass.up_p,
+                    &self.inputs.valves.bypass.up_unit,
+                    self.inputs.valves.bypass.up_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let down_abs = convert_pressure_mode_gui(
+                    self.inputs.valves.bypass.down_p,
+                    &self.inputs.valves.bypass.down_unit,
+                    self.inputs.valves.bypass.down_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let t_c = convert_temperature_gui(self.inputs.valves.bypass.up_t, &self.inputs.valves.bypass.t_unit, "C");
+                let kv = {
+                    let mut cv_use = self.inputs.valves.bypass.cv;
+                    if stroke_based_kv_available(&self.inputs.valves.bypass.stroke_points, &self.inputs.valves.bypass.cv_points)
+                    {
+                        cv_use = interpolate_stroke_cv(
+                            &self.inputs.valves.bypass.stroke_points,
+                            &self.inputs.valves.bypass.cv_points,
+                            self.inputs.valves.bypass.open_pct,
+                        );
+                    }
+                    self.inputs.valves.bypass.cv_kind.to_kv(cv_use)
+                };
+                let mut input = turbine_bypass::BypassInput {
+                    upstream_pressure_bar_abs: up_abs,
+                    upstream_temp_c: t_c,
+                    steam_enthalpy_j_per_kg: (self.inputs.valves.bypass.h_override_kj_per_kg > 0.0)
+                        .then_some(self.inputs.valves.bypass.h_override_kj_per_kg * 1000.0),
+                    downstream_pressure_bar_abs: down_abs,
+                    kv,
+                    spray_flow_kg_per_h: self.inputs.valves.bypass.spray_kg_h.max(0.0),
+                    spray_temp_c: convert_temperature_gui(
+                        self.inputs.valves.bypass.spray_temp,
+                        &self.inputs.valves.bypass.spray_temp_unit,
+                        "C",
+                    ),
+                    spray_enthalpy_j_per_kg: (self.inputs.valves.spray.h_override_kj_per_kg > 0.0)
+                        .then_some(self.inputs.valves.spray.h_override_kj_per_kg * 1000.0),
+                };
+                let target_c = convert_temperature_gui(self.inputs.valves.bypass.target_t, &self.inputs.valves.bypass.t_unit, "C");
+                let outcome = solve_spray
+                    .then(|| turbine_bypass::spray_for_target_temperature(&input, target_c))
+                    .transpose()
+                    .and_then(|spray| {
+                        // 역산한 스프레이량으로 하류 상태를 다시 계산한다.
+                        if let Some(req) = &spray {
+                            input.spray_flow_kg_per_h = req.spray_flow_kg_per_h;
+                        }
+                        Ok((turbine_bypass::compute_bypass(&input)?, spray))
+                    });
+                self.bypass_result = Some(match outcome {
+                    Ok((res, spray)) => {
+                        let warn = if res.choked {
+                            txt(
+                                "gui.bypass.steam.warn.choked",
+                                " [Warning: potential choked flow]",
+                            )
+                        } else {
+                            String::new()
+                        };
+                        let mixed = res.profile[res.profile.len() - 1];
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.bypass.steam.result",
+                                "Steam Q={q:.3} m³/h, m={m:.2} kg/h{warn}; spray={spray:.1} kg/h → mixed h≈{h_mix:.1} kJ/kg, total heat≈{heat:.1} kW (Pu={pu:.2} bar(a), Pd={pd:.2} bar(a), Kv={kv:.2})",
+                            ),
+                            &[
+                                ("q", fmt_num(res.steam_volume_flow_m3_per_h, 3)),
+                                ("m", fmt_num(res.steam_flow_kg_per_h, 2)),
+                                ("spray", fmt_num(input.spray_flow_kg_per_h, 1)),
+                                ("h_mix", fmt_num(mixed.enthalpy_j_per_kg / 1000.0, 1)),
+                                ("heat", fmt_num(res.total_heat_kw, 1)),
+                                ("pu", fmt_num(up_abs, 2)),
+                                ("pd", fmt_num(down_abs, 2)),
+                                ("kv", fmt_num(kv, 2)),
+                                ("warn", warn),
+                            ],
+                        );
+                        for point in &res.profile {
+                            out.push('\n');
+                            let stage = txt(point.stage.i18n_key(), &format!("{:?}", point.stage));
+                            out.push_str(&match point.quality {
+                                Some(x) => fill_template(
+                                    &txt(
+                                        "gui.bypass.steam.profile_wet",
+                                        "{stage}: {p} bar(a), {t} °C, wet x={x}",
+                                    ),
+                                    &[
+                                        ("stage", stage),
+                                        ("p", fmt_qty(point.pressure_bar_abs, QuantityKind::Pressure)),
+                                        ("t", fmt_qty(point.temp_c, QuantityKind::Temperature)),
+                                        ("x", fmt_num(x, 3)),
+                                    ],
+                                ),
+                                None => fill_template(
+                                    &txt(
+                                        "gui.bypass.steam.profile",
+                                        "{stage}: {p} bar(a), {t} °C, superheat {sh} K",
+                                    ),
+                                    &[
+                                        ("stage", stage),
+                                        ("p", fmt_qty(point.pressure_bar_abs, QuantityKind::Pressure)),
+                                        ("t", fmt_qty(point.temp_c, QuantityKind::Temperature)),
+                                        ("sh", fmt_qty(point.superheat_k, QuantityKind::TemperatureDifference)),
+                                    ],
+                                ),
+                            });
+                        }
+                        if let Some(req) = &spray {
+                            self.inputs.valves.bypass.spray_kg_h = req.spray_flow_kg_per_h;
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.bypass.steam.spray_required",
+                                    "Spray for {t} °C: {m} kg/h (spray/steam {r}, superheat {sh} K), {n} iterations, residual {res} K - copied to spray flow",
+                                ),
+                                &[
+                                    ("t", fmt_qty(target_c, QuantityKind::Temperature)),
+                                    ("m", fmt_num(req.spray_flow_kg_per_h, 1)),
+                                    ("r", fmt_num(req.spray_ratio, 3)),
+                                    ("sh", fmt_qty(req.target_superheat_k, QuantityKind::TemperatureDifference)),
+                                    ("n", req.iterations.to_string()),
+                                    ("res", fmt_qty(req.residual_k, QuantityKind::TemperatureDifference)),
+                                ],
+                            ));
+                            // 스프레이 물 밸브(TCV) 패널의 차압·밀도로 필요한 Kv
+                            let tcv_dp = convert_pressure_mode_gui(
+                                self.inputs.valves.spray.up_p,
+                                &self.inputs.valves.spray.up_unit,
+                                self.inputs.valves.spray.up_mode,
+                                "bar",
+                                conversion::PressureMode::Absolute,
+                            ) - convert_pressure_mode_gui(
+                                self.inputs.valves.spray.down_p,
+                                &self.inputs.valves.spray.down_unit,
+                                self.inputs.valves.spray.down_mode,
+                                "bar",
+                                conversion::PressureMode::Absolute,
+                            );
+                            if let Ok(kv_req) = steam_valves::required_kv(
+                                req.spray_flow_kg_per_h / self.inputs.valves.spray.density,
+                                tcv_dp,
+                                self.inputs.valves.spray.density,
+                            ) {
+                                out.push('\n');
+                                out.push_str(&fill_template(
+                                    &txt(
+                                        "gui.bypass.steam.spray_kv",
+                                        "Spray valve (TCV panel ΔP={dp} bar): Kv≈{kv}, Cv≈{cv}",
+                                    ),
+                                    &[
+                                        ("dp", fmt_num(tcv_dp, 2)),
+                                        ("kv", fmt_num(kv_req, 2)),
+                                        ("cv", fmt_num(steam_valves::cv_from_kv(kv_req), 2)),
+                                    ],
+                                ));
+                            }
+                        }
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        if let Some(spray) = &spray {
+                            push_warnings(&mut out, &self.tr, &spray.warnings);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.bypass.steam.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.bypass_result {
+                result_block(ui, &txt, res, false);
+            }
+        });
+
+        ui.add_space(12.0);
+
+        // ---------- {t_head} ----------
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.heading(txt("gui.bypass.water.heading", "Bypass TCV (water)"));
+            if reset_card_button(ui, &txt) {
+                self.inputs.valves.spray = self.input_defaults.valves.spray.clone();
+            }
+            match valve_db_ui(ui, &txt, "spray_valve_db", &self.valve_db, &mut self.spray_valve_db, true) {
+                Some(ValveDbAction::Load(record)) => {
+                    let kind = self.inputs.valves.spray.cv_kind;
+                    let from_cv_us = |v: f64| flow_coefficient::convert(v, FlowCoefficientKind::CvUs, kind);
+                    self.inputs.valves.spray.cv = from_cv_us(record.rated_cv);
+                    (self.inputs.valves.spray.stroke_points, self.inputs.valves.spray.cv_points) = record
+                        .stroke_cv_points()
+                        .into_iter()
+                        .map(|(s, cv)| (s, from_cv_us(cv)))
+                        .unzip();
+                }
+                Some(ValveDbAction::Save(tag)) => {
+                    let record = valve_record_from_table(
+                        &self.valve_db,
+                        &tag,
+                        self.inputs.valves.spray.cv,
+                        self.inputs.valves.spray.cv_kind,
+                        &self.inputs.valves.spray.stroke_points,
+                        &self.inputs.valves.spray.cv_points,
+                    );
+                    save_valve_record(&self.tr, &mut self.valve_db, &mut self.spray_valve_db, record);
+                }
+                None => {}
+            }
+            egui::Grid::new("spray_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    ui.label(txt("gui.bypass.water.up_p", "Upstream pressure"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.up_p).speed(0.2));
+                        unit_combo(ui, &mut self.inputs.valves.spray.up_unit, &pressure_unit_options());
+                        ui.selectable_value(
+                            &mut self.inputs.valves.spray.up_mode,
+                            conversion::PressureMode::Gauge,
+                            "Gauge (G)",
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.valves.spray.up_mode,
+                            conversion::PressureMode::Absolute,
+                            "Absolute (A)",
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.water.down_p", "Downstream pressure"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.down_p).speed(0.2));
+                        unit_combo(ui, &mut self.inputs.valves.spray.down_unit, &pressure_unit_options());
+                        ui.selectable_value(
+                            &mut self.inputs.valves.spray.down_mode,
+                            conversion::PressureMode::Gauge,
+                            "Gauge (G)",
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.valves.spray.down_mode,
+                            conversion::PressureMode::Absolute,
+                            "Absolute (A)",
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.water.temp", "Water temperature"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.temp).speed(0.5));
+                        unit_combo(ui, &mut self.inputs.valves.spray.temp_unit, &temperature_unit_options());
+                    });
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.water.density", "Density [kg/m3]"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.density).speed(1.0));
+                    ui.end_row();
+
+                    ui.label(txt("gui.bypass.steam.cv", "Cv/Kv"));
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.cv).speed(1.0));
+                        egui::ComboBox::from_id_source("spray_cv_kind")
+                            .selected_text(self.inputs.valves.spray.cv_kind.label())
+                            .show_ui(ui, |ui| {
+                                for kind in FlowCoefficientKind::ALL {
+                                    ui.selectable_value(&mut self.inputs.valves.spray.cv_kind, kind, kind.label());
+                                }
+                            });
+                        ui.label(txt("gui.bypass.water.stroke", "Stroke (%)"));
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.valves.spray.open_pct)
+                                .speed(1.0)
+                                .clamp_range(0.0..=100.0),
+                        );
+                    });
+                    ui.end_row();
+                    ui.label(txt("gui.bypass.water.h_override", "Water enthalpy input (kJ/kg, 0=auto)"));
+                    ui.add(egui::DragValue::new(&mut self.inputs.valves.spray.h_override_kj_per_kg).speed(10.0));
+                    ui.end_row();
+                    if stroke_based_kv_available(&self.inputs.valves.spray.stroke_points, &self.inputs.valves.spray.cv_points) {
+                        let cv_interp = interpolate_stroke_cv(
+                            &self.inputs.valves.spray.stroke_points,
+                            &self.inputs.valves.spray.cv_points,
+                            self.inputs.valves.spray.open_pct,
+                        );
+                        ui.label(fill_template(&txt("gui.bypass.water.cv_interp", "Interpolated Cv/Kv≈{cv:.3} (stroke {stroke:.1}%)"), &[("cv", fmt_num(cv_interp, 3)), ("stroke", fmt_num(self.inputs.valves.spray.open_pct, 1))]));
+                        ui.end_row();
+                    }
+                });
+
+            ui.label(txt("gui.bypass.water.table", "Stroke-Cv/Kv table (water)"));
+            let spray_suffix = self.inputs.valves.spray.cv_kind.symbol();
+            let mut remove_idx: Option<usize> = None;
+            for i in 0..self.inputs.valves.spray.stroke_points.len() {
+                ui.horizontal(|ui| {
+                    ui.label(format!("Stroke {}:", i + 1));
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.valves.spray.stroke_points[i])
+                            .speed(1.0)
+                            .clamp_range(0.0..=100.0)
+                            .suffix("%"),
+                    );
+                    ui.add(
+                        egui::DragValue::new(&mut self.inputs.valves.spray.cv_points[i])
+                            .speed(1.0)
+                            .suffix(spray_suffix),
+                    );
+                    if ui.small_button("-").clicked() {
+                        remove_idx = Some(i);
+                    }
+                });
+            }
+            ui.horizontal(|ui| {
+                if ui.small_button(txt("gui.bypass.table.add_row", "+ Add row")).clicked() {
+                    self.inputs.valves.spray.stroke_points.push(100.0);
+                    self.inputs.valves.spray.cv_points.push(0.0);
+                }
+                ui.label(txt("gui.bypass.water.tip_table", "Empty rows are ignored; use +/- to add/remove."));
+            });
+            if let Some(idx) = remove_idx {
+                if self.inputs.valves.spray.stroke_points.len() > 1 {
+                    self.inputs.valves.spray.stroke_points.remove(idx);
+                    self.inputs.valves.spray.cv_points.remove(idx);
+                }
+            }
+            table_paste_ui(
+                ui,
+                &txt,
+                &mut self.spray_table_paste,
+                &["Stroke %", spray_suffix],
+                &mut [&mut self.inputs.valves.spray.stroke_points, &mut self.inputs.valves.spray.cv_points],
+            );
+
+            ui.add_space(6.0);
+            if run_button(ui, txt("gui.bypass.water.run", "Calculate TCV flow")) {
+                let up_abs = convert_pressure_mode_gui(
+                    self.inputs.valves.spray.up_p,
+                    &self.inputs.valves.spray.up_unit,
+                    self.inputs.valves.spray.up_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let down_abs = convert_pressure_mode_gui(
+                    self.inputs.valves.spray.down_p,
+                    &self.inputs.valves.spray.down_unit,
+                    self.inputs.valves.spray.down_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let dp = (up_abs - down_abs).max(0.0);
+                let rho = self.inputs.valves.spray.density;
+                let mut cv_use = self.inputs.valves.spray.cv;
+                if stroke_based_kv_available(&self.inputs.valves.spray.stroke_points, &self.inputs.valves.spray.cv_points) {
+                    cv_use = interpolate_stroke_cv(
+                        &self.inputs.valves.spray.stroke_points,
+                        &self.inputs.valves.spray.cv_points,
+                        self.inputs.valves.spray.open_pct,
+                    );
+                }
+                let kv = self.inputs.valves.spray.cv_kind.to_kv(cv_use);
+                self.spray_calc_result = if dp <= 0.0 || rho <= 0.0 {
+                    Some(
+                        txt(
+                            "gui.bypass.water.error.input",
+                            "Error: ΔP and density must be > 0",
+                        )
+                        .to_string(),
+                    )
+                } else {
+                    match steam_valves::flow_from_kv(kv, dp, rho) {
+                        Ok(q_m3h) => {
+                            let mass = q_m3h * rho;
+                            self.inputs.valves.bypass.spray_kg_h = mass;
+                            self.inputs.valves.bypass.spray_temp = convert_temperature_gui(
+                                self.inputs.valves.spray.temp,
+                                &self.inputs.valves.spray.temp_unit,
+                                &self.inputs.valves.bypass.spray_temp_unit,
+                            );
+                            Some(fill_template(
+                                &txt(
+                                    "gui.bypass.water.result",
+                                    "TCV flow Q={q:.3} m³/h, m={m:.2} kg/h (ΔP={dp:.2} bar, Kv={kv:.2}) - used for bypass spray input",
+                                ),
+                                &[
+                                    ("q", fmt_num(q_m3h, 3)),
+                                    ("m", fmt_num(mass, 2)),
+                                    ("dp", fmt_num(dp, 2)),
+                                    ("kv", fmt_num(kv, 2)),
+                                ],
+                            ))
+                        }
+                        Err(e) => Some(fill_template(
+                            &txt("gui.bypass.water.error.generic", "Error: {e}"),
+                            &[("e", error_text(&self.tr, e))],
+                        )),
+                    }
+                };
+            }
+            if let Some(res) = &self.spray_calc_result {
+                result_block(ui, &txt, res, false);
+            }
+        });
+    }
+    fn ui_boiler(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.heading", "Boiler Efficiency"),
+            &txt(
+                "gui.boiler.tip",
+                "Compute basic boiler efficiency (PTC) from fuel input and steam/feedwater enthalpy.",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt(
+                "gui.boiler.subheading",
+                "Basic efficiency from fuel LHV, steam/feedwater enthalpy, losses.",
+            ),
+            &txt(
+                "gui.boiler.subhint",
+                "Enter LHV, steam/feedwater flows/enthalpy and losses to estimate efficiency.",
+            ),
+        );
+        ui.add_space(8.0);
+        self.ui_card(ui, &txt, CardId::BoilerEfficiency);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Drum);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::SteamPurity);
+        ui.add_space(10.0);
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.cycle.heading", "Rankine cycle heat rate"),
+            &txt(
+                "gui.boiler.cycle.tip",
+                "Sanity-check cycle efficiency and heat rate from throttle/condenser conditions with optional reheat and one feedwater heater (IF97).",
+            ),
+        );
+        self.ui_card(ui, &txt, CardId::RankineCycle);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::HeatBalance);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Hrsg);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Attemperator);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::AirPreheater);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Emissions);
+    }
+
+    /// 보일러 효율 카드 (기본식과 PTC 4.0 손실법).
+    fn ui_boiler_efficiency<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::BoilerEfficiency)).inner {
+                self.inputs.boiler.efficiency = self.input_defaults.boiler.efficiency.clone();
+            }
+            egui::Grid::new("boiler_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.fuel_flow", "Fuel flow [unit/h]"),
+                        &txt(
+                            "gui.boiler.fuel_flow_tip",
+                            "Fuel mass or volume flow (kg/h, Nm3/h, etc.)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.fuel_flow).speed(1.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.fuel_unit,
+                        &[
+                            ("kg/h", "kg/h"),
+                            ("t/h", "t/h"),
+                            ("kg/s", "kg/s"),
+                            ("lb/h", "lb/h"),
+                            ("Nm3/h", "Nm3/h"),
+                        ],
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.lhv", "Fuel LHV [kJ/unit]"),
+                        &txt("gui.boiler.lhv_tip", "Lower heating value per fuel unit"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.lhv).speed(100.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.lhv_unit,
+                        &[
+                            ("kJ/kg", "kJ/kg"),
+                            ("kcal/kg", "kcal/kg"),
+                            ("kJ/Nm3", "kJ/Nm3"),
+                            ("Btu/lb", "Btu/lb"),
+                        ],
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.steam_flow", "Steam production [kg/h]"),
+                        &txt("gui.boiler.steam_flow_tip", "Produced steam mass flow"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.steam_flow).speed(10.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.steam_unit,
+                        flow_units::MASS_FLOW_UNITS,
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.h_steam", "Steam enthalpy [kJ/kg]"),
+                        &txt(
+                            "gui.boiler.h_steam_tip",
+                            "Enthalpy of produced steam (IF97 result is fine)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.h_steam).speed(10.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.h_steam_unit,
+                        &[
+                            ("kJ/kg", "kJ/kg"),
+                            ("kcal/kg", "kcal/kg"),
+                            ("Btu/lb", "Btu/lb"),
+                        ],
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.h_fw", "Feedwater enthalpy [kJ/kg]"),
+                        &txt("gui.boiler.h_fw_tip", "Feedwater enthalpy"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.h_fw).speed(5.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.h_fw_unit,
+                        &[
+                            ("kJ/kg", "kJ/kg"),
+                            ("kcal/kg", "kcal/kg"),
+                            ("Btu/lb", "Btu/lb"),
+                        ],
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.heat_unit", "Heat output unit"),
+                        &txt(
+                            "gui.boiler.heat_unit_tip",
+                            "Unit for the useful and fuel heat in the result",
+                        ),
+                    );
+                    unit_combo(ui, &mut self.inputs.boiler.efficiency.heat_unit, flow_units::POWER_UNITS);
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.boiler.run_basic", "Calculate efficiency")) {
+                let input = steam::boiler_efficiency::BoilerEfficiencyInput {
+                    fuel_flow_per_h: self.inputs.boiler.efficiency.fuel_flow, // 단위 변환 필요 시 확장
+                    fuel_lhv_kj_per_unit: convert_energy_gui(
+                        self.inputs.boiler.efficiency.lhv,
+                        &self.inputs.boiler.efficiency.lhv_unit,
+                        "kJ/kg",
+                    ),
+                    steam_flow_kg_per_h: convert_massflow_gui(
+                        self.inputs.boiler.efficiency.steam_flow,
+                        &self.inputs.boiler.efficiency.steam_unit,
+                        "kg/h",
+                    ),
+                    steam_enthalpy_kj_per_kg: convert_specific_enthalpy_gui(
+                        self.inputs.boiler.efficiency.h_steam,
+                        &self.inputs.boiler.efficiency.h_steam_unit,
+                        "kJ/kg",
+                    ),
+                    feedwater_enthalpy_kj_per_kg: convert_specific_enthalpy_gui(
+                        self.inputs.boiler.efficiency.h_fw,
+                        &self.inputs.boiler.efficiency.h_fw_unit,
+                        "kJ/kg",
+                    ),
+                };
+                let res = steam::boiler_efficiency::boiler_efficiency(input);
+                self.boiler_result = Some(fill_template(
+                    &txt(
+                        "gui.boiler.result_basic",
+                        "Efficiency={eff:.2} %, Useful heat={useful:.1} {q_unit}, Fuel heat={fuel:.1} {q_unit}",
+                    ),
+                    &[
+                        ("eff", fmt_num(res.efficiency * 100.0, 2)),
+                        (
+                            "useful",
+                            fmt_num(convert_power_gui(res.useful_heat_kw, "kW", &self.inputs.boiler.efficiency.heat_unit), 1),
+                        ),
+                        (
+                            "fuel",
+                            fmt_num(convert_power_gui(res.fuel_heat_kw, "kW", &self.inputs.boiler.efficiency.heat_unit), 1),
+                        ),
+                        ("q_unit", self.inputs.boiler.efficiency.heat_unit.clone()),
+                    ],
+                ));
+            }
+            if let Some(res) = &self.boiler_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+        ui.add_space(10.0);
+        heading_with_tip(
+            ui,
+            &txt(
+                "gui.boiler.ptc.heading",
+                "PTC 4.0 extended (stack/radiation/blowdown losses)",
+            ),
+            &txt(
+                "gui.boiler.ptc.tip",
+                "Include flue gas losses, excess air, radiation and blowdown.",
+            ),
+        );
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            egui::Grid::new("boiler_ptc_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.fg_flow", "Flue gas flow"),
+                        &txt("gui.boiler.ptc.fg_flow_tip", "Flue gas mass flow"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.fg_flow).speed(10.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.fg_flow_unit,
+                        flow_units::MASS_FLOW_UNITS,
+                    );
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.fg_cp", "Flue gas cp [kJ/kgK]"),
+                        &txt("gui.boiler.ptc.fg_cp_tip", "Average flue gas cp"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.fg_cp).speed(0.01));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.stack_temp", "Stack temperature"),
+                        &txt(
+                            "gui.boiler.ptc.stack_temp_tip",
+                            "Stack/duct outlet temperature",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.stack_temp).speed(1.0));
+                    unit_combo(ui, &mut self.inputs.boiler.efficiency.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.ambient_temp", "Ambient temperature"),
+                        &txt(
+                            "gui.boiler.ptc.ambient_temp_tip",
+                            "Reference/combustion air temperature",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.ambient_temp).speed(1.0));
+                    unit_combo(ui, &mut self.inputs.boiler.efficiency.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+
+                    ui.small(txt(
+                "gui.valve.tip_mmhg",
+                "Tip: mmHg is treated as gauge (0=atm, -760mmHg=vacuum).",
+            ));
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.excess_air", "Excess air ratio"),
+                        &txt(
+                            "gui.boiler.ptc.excess_air_tip",
+                            "Actual air vs theoretical air ratio",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.excess_air).speed(0.01));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.dew.fuel", "Fuel / sulfur [%] (dew point)"),
+                        &txt(
+                            "gui.boiler.dew.fuel_tip",
+                            "Fuel for the flue gas water and sulfuric acid dew points at this excess air; the stack temperature is checked against them",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        if dew_point_fuel_combo(ui, txt, "boiler_dew_fuel", &mut self.inputs.boiler.efficiency.dew_fuel) {
+                            self.inputs.boiler.efficiency.dew_sulfur_pct =
+                                self.inputs.boiler.efficiency.dew_fuel.map_or(0.0, |f| f.analysis().sulfur * 100.0);
+                        }
+                        ui.add_enabled(
+                            self.inputs.boiler.efficiency.dew_fuel.is_some(),
+                            egui::DragValue::new(&mut self.inputs.boiler.efficiency.dew_sulfur_pct)
+                                .speed(0.05)
+                                .clamp_range(0.0..=6.0),
+                        );
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.rad_loss", "Radiation/surface loss [%]"),
+                        &txt(
+                            "gui.boiler.ptc.rad_loss_tip",
+                            "Surface radiation/convection loss fraction",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.rad_loss).speed(0.005));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.blowdown_rate", "Blowdown rate"),
+                        &txt(
+                            "gui.boiler.ptc.blowdown_rate_tip",
+                            "Boiler blowdown fraction",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.blowdown_rate).speed(0.005));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.ptc.blowdown_h", "Blowdown enthalpy"),
+                        &txt("gui.boiler.ptc.blowdown_h_tip", "Blowdown effluent enthalpy"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.efficiency.blowdown_h).speed(5.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.boiler.efficiency.blowdown_h_unit,
+                        &[("kJ/kg", "kJ/kg"), ("Btu/lb", "Btu/lb")],
+                    );
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.heat_unit", "Heat output unit"),
+                        &txt(
+                            "gui.boiler.heat_unit_tip",
+                            "Unit for the useful and fuel heat in the result",
+                        ),
+                    );
+                    unit_combo(ui, &mut self.inputs.boiler.efficiency.heat_unit, flow_units::POWER_UNITS);
+                    ui.end_row();
+                });
+
+            if run_button(ui, txt("gui.boiler.ptc.run", "Calculate PTC 4.0 efficiency")) {
+                let input = steam::boiler_efficiency::BoilerEfficiencyPtcInput {
+                    fuel_flow_per_h: self.inputs.boiler.efficiency.fuel_flow,
+                    fuel_lhv_kj_per_unit: convert_energy_gui(
+                        self.inputs.boiler.efficiency.lhv,
+                        &self.inputs.boiler.efficiency.lhv_unit,
+                        "kJ/kg",
+                    ),
+                    steam_flow_kg_per_h: convert_massflow_gui(
+                        self.inputs.boiler.efficiency.steam_flow,
+                        &self.inputs.boiler.efficiency.steam_unit,
+                        "kg/h",
+                    ),
+                    steam_enthalpy_kj_per_kg: convert_specific_enthalpy_gui(
+                        self.inputs.boiler.efficiency.h_steam,
+                        &self.inputs.boiler.efficiency.h_steam_unit,
+                        "kJ/kg",
+                    ),
+                    feedwater_enthalpy_kj_per_kg: convert_specific_enthalpy_gui(
+                        self.inputs.boiler.efficiency.h_fw,
+                        &self.inputs.boiler.efficiency.h_fw_unit,
+                        "kJ/kg",
+                    ),
+                    flue_gas_flow_kg_per_h: convert_massflow_gui(
+                        self.inputs.boiler.efficiency.fg_flow,
+                        &self.inputs.boiler.efficiency.fg_flow_unit,
+                        "kg/h",
+                    ),
+                    flue_gas_cp_kj_per_kgk: self.inputs.boiler.efficiency.fg_cp,
+                    stack_temp_c: convert_temperature_gui(
+                        self.inputs.boiler.efficiency.stack_temp,
+                        &self.inputs.boiler.efficiency.temp_unit,
+                        "C",
+                    ),
+                    ambient_temp_c: convert_temperature_gui(
+                        self.inputs.boiler.efficiency.ambient_temp,
+                        &self.inputs.boiler.efficiency.temp_unit,
+                        "C",
+                    ),
+                    excess_air_frac: self.inputs.boiler.efficiency.excess_air,
+                    radiation_loss_frac: self.inputs.boiler.efficiency.rad_loss,
+                    blowdown_rate_frac: self.inputs.boiler.efficiency.blowdown_rate,
+                    blowdown_enthalpy_kj_per_kg: convert_specific_enthalpy_gui(
+                        self.inputs.boiler.efficiency.blowdown_h,
+                        &self.inputs.boiler.efficiency.blowdown_h_unit,
+                        "kJ/kg",
+                    ),
+                    flue_gas_dew_points: flue_gas_dew_points_for(
+                        self.inputs.boiler.efficiency.dew_fuel
+                            .map(|f| f.analysis().with_sulfur(self.inputs.boiler.efficiency.dew_sulfur_pct / 100.0)),
+                        self.inputs.boiler.efficiency.excess_air,
+                    ),
+                };
+                let dew_points = input.flue_gas_dew_points;
+                let res = steam::boiler_efficiency::boiler_efficiency_ptc(input);
+                let mut out = fill_template(
+                    &txt(
+                        "gui.boiler.ptc.result",
+                        "PTC efficiency={eff:.2} %, Useful heat={useful:.1} {q_unit}, Fuel heat={fuel:.1} {q_unit}",
+                    ),
+                    &[
+                        ("eff", fmt_num(res.efficiency * 100.0, 2)),
+                        (
+                            "useful",
+                            fmt_num(convert_power_gui(res.useful_heat_kw, "kW", &self.inputs.boiler.efficiency.heat_unit), 1),
+                        ),
+                        (
+                            "fuel",
+                            fmt_num(convert_power_gui(res.fuel_heat_kw, "kW", &self.inputs.boiler.efficiency.heat_unit), 1),
+                        ),
+                        ("q_unit", self.inputs.boiler.efficiency.heat_unit.clone()),
+                    ],
+                );
+                if let Some(dp) = &dew_points {
+                    out.push('\n');
+                    out.push_str(&dew_point_line(txt, dp));
+                }
+                push_warnings(&mut out, &self.tr, &res.warnings);
+                self.boiler_result = Some(out);
+            }
+            if let Some(res) = &self.boiler_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 증기 순도·캐리오버 카드.
+    fn ui_steam_purity<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        heading_with_tip(
+            ui,
+            &txt("gui.boiler.purity.heading", "Steam purity / carryover"),
+            &txt(
+                "gui.boiler.purity.tip",
+                "Mechanical (droplet) and vaporous carryover of boiler water solids and silica into steam, checked against turbine deposition limits.",
+            ),
+        );
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::SteamPurity)).inner {
+                self.inputs.boiler.purity = self.input_defaults.boiler.purity.clone();
+            }
+            egui::Grid::new("boiler_purity_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.purity.pressure", "Drum pressure [bar(a)]"),
+                        &txt("gui.boiler.purity.pressure_tip", "Vaporous carryover rises steeply with drum pressure"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.p_bar_abs).speed(0.5));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.purity.feedwater", "Feedwater TDS / SiO₂ [mg/kg]"),
+                        &txt("gui.boiler.purity.feedwater_tip", "Used with the blowdown rate above to estimate boiler water concentrations"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.fw_tds).speed(0.1));
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.fw_silica).speed(0.01));
+                        if ui
+                            .button(txt("gui.boiler.purity.from_blowdown", "→ boiler water"))
+                            .on_hover_text(txt(
+                                "gui.boiler.purity.from_blowdown_tip",
+                                "Concentrate feedwater by the PTC blowdown rate: C_bw = C_fw·(1+b)/b",
+                            ))
+                            .clicked()
+                        {
+                            self.inputs.boiler.purity.bw_tds = steam::steam_purity::boiler_water_concentration(
+                                self.inputs.boiler.purity.fw_tds,
+                                self.inputs.boiler.efficiency.blowdown_rate,
+                            );
+                            self.inputs.boiler.purity.bw_silica = steam::steam_purity::boiler_water_concentration(
+                                self.inputs.boiler.purity.fw_silica,
+                                self.inputs.boiler.efficiency.blowdown_rate,
+                            );
+                        }
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.purity.boiler_water", "Boiler water TDS / SiO₂ [mg/kg]"),
+                        &txt("gui.boiler.purity.boiler_water_tip", "Drum boiler water concentrations"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.bw_tds).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.bw_silica).speed(0.1));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.boiler.purity.mech", "Mechanical carryover [%] / steam flow [kg/h]"),
+                        &txt("gui.boiler.purity.mech_tip", "Boiler water entrained as droplets (0.01–0.1 % with healthy separators); flow 0 = skip annual solids"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.mech_pct).speed(0.005));
+                        ui.add(egui::DragValue::new(&mut self.inputs.boiler.purity.steam_kg_h).speed(100.0));
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.boiler.purity.run", "Estimate steam purity")) {
+                let input = steam::steam_purity::SteamPurityInput {
+                    drum_pressure_bar_abs: self.inputs.boiler.purity.p_bar_abs,
+                    boiler_water_tds_mg_per_kg: self.inputs.boiler.purity.bw_tds,
+                    boiler_water_silica_mg_per_kg: self.inputs.boiler.purity.bw_silica,
+                    mechanical_carryover_percent: self.inputs.boiler.purity.mech_pct,
+                    steam_flow_kg_per_h: (self.inputs.boiler.purity.steam_kg_h > 0.0)
+                        .then_some(self.inputs.boiler.purity.steam_kg_h),
+                };
+                self.purity_result = Some(match steam::steam_purity::estimate_steam_purity(input) {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.boiler.purity.result",
+                                "Steam TDS {tds} mg/kg, SiO₂ {si} mg/kg (vaporous {vsi}); SiO₂ distribution ratio {kd}%\nMax boiler water for turbine limits: TDS {max_tds}, SiO₂ {max_si} mg/kg",
+                            ),
+                            &[
+                                ("tds", fmt_num(res.steam_tds_mg_per_kg, 4)),
+                                ("si", fmt_num(res.steam_silica_mg_per_kg, 4)),
+                                ("vsi", fmt_num(res.vaporous_silica_mg_per_kg, 4)),
+                                ("kd", fmt_num(res.silica_distribution_ratio * 100.0, 3)),
+                                ("max_tds", fmt_num(res.max_boiler_water_tds_mg_per_kg, 0)),
+                                ("max_si", fmt_num(res.max_boiler_water_silica_mg_per_kg, 2)),
+                            ],
+                        );
+                        if let Some((tds_kg, si_kg)) = res.annual_solids_to_turbine_kg {
+                            out.push_str(&fill_template(
+                                &txt("gui.boiler.purity.annual", "\nSolids to turbine per year: TDS {tds} kg, SiO₂ {si} kg"),
+                                &[("tds", fmt_qty(tds_kg, QuantityKind::Mass)), ("si", fmt_qty(si_kg, QuantityKind::Mass))],
+                            ));
+                        }
+                        for w in res.warnings {
+                            out.push_str("\n⚠ ");
+                            out.push_str(&w);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.boiler.purity.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.purity_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 콘덴서/냉각탑/펌프 NPSH/드레인 쿨러 계산을 묶은 화면.
+    fn ui_cooling(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt(
+                "gui.cooling.heading",
+                "Cooling / Condenser / NPSH / Drain Cooler",
+            ),
+            &txt(
+                "gui.cooling.tip",
+                "Condenser heat balance, cooling tower range/approach, pump NPSH, drain/reheater LMTD",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt(
+                "gui.cooling.subheading",
+                "Condenser heat balance, cooling tower range/approach, pump NPSH, drain/reheater LMTD",
+            ),
+            &txt(
+                "gui.cooling.subhint",
+                "Fill each card to compute instantly.",
+            ),
+        );
+        ui.add_space(8.0);
+
+        // 콘덴서
+        self.ui_card(ui, &txt, CardId::Condenser);
+
+        ui.add_space(8.0);
+        // 냉각탑
+        self.ui_card(ui, &txt, CardId::CoolingTower);
+
+        ui.add_space(8.0);
+        // 증발식 냉각기
+        self.ui_card(ui, &txt, CardId::EvaporativeCooler);
+
+        ui.add_space(8.0);
+        // 공랭식 복수기
+        self.ui_card(ui, &txt, CardId::AirCooledCondenser);
+
+        ui.add_space(8.0);
+        // 관류식 vs 순환식 비교
+        self.ui_card(ui, &txt, CardId::CoolingComparison);
+
+        ui.add_space(8.0);
+        // 펌프 NPSH
+        self.ui_card(ui, &txt, CardId::PumpNpsh);
+
+        ui.add_space(8.0);
+        // 포화수 흡입(핫웰/플래시 탱크) NPSH
+        self.ui_card(ui, &txt, CardId::SaturatedNpsh);
+
+        ui.add_space(8.0);
+        // 증기 이젝터
+        self.ui_card(ui, &txt, CardId::Ejector);
+
+        ui.add_space(8.0);
+        // 드레인/재열기
+        self.ui_card(ui, &txt, CardId::DrainCooler);
+    }
+
+    /// 복수기 열정산/진공 카드.
+    fn ui_condenser<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt(
+                    "gui.cooling.cond.heading",
+                    "Condenser Heat Balance / Vacuum",
+                ),
+                &txt(
+                    "gui.cooling.cond.tip",
+                    "Card to compute steam Tsat/vacuum/LMTD together",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::Condenser)).inner {
+                self.inputs.cooling.condenser = self.input_defaults.cooling.condenser.clone();
+            }
+            ui.small(txt(
+                "gui.cooling.cond.note",
+                "Steam Tsat/LMTD auto calc; mmHg is gauge (0=atm).",
+            ));
+            egui::Grid::new("condenser_grid")
+                .num_columns(4)
+                .spacing([8.0, 6.0])
+                .show(ui, |ui| {
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_condensing_from_pressure,
+                        txt("gui.cooling.cond.auto_tsat", "auto Tsat"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.cooling.cond.auto_tsat_tip",
+                        "Use pressure to auto-calc Tsat/Psat.",
+                    ));
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.steam_p", "Steam pressure"),
+                        &txt(
+                            "gui.cooling.cond.steam_p_tip",
+                            "Condenser steam/non-condensable pressure",
+                        ),
+                    );
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.pressure).speed(0.05))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_condensing_from_pressure = true;
+                    }
+                    unit_combo(ui, &mut self.inputs.cooling.condenser.pressure_unit, &pressure_unit_options());
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(
+                            &mut self.inputs.cooling.condenser.pressure_mode,
+                            conversion::PressureMode::Gauge,
+                            txt("gui.steam.mode.gauge", "Gauge (G)"),
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.cooling.condenser.pressure_mode,
+                            conversion::PressureMode::Absolute,
+                            txt("gui.steam.mode.absolute", "Absolute (A)"),
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_backpressure_from_temp,
+                        txt("gui.cooling.cond.auto_psat", "auto Psat"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.cooling.cond.auto_psat_tip",
+                        "Use Tsat to auto-calc Psat.",
+                    ));
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.steam_t", "Steam temperature"),
+                        &txt(
+                            "gui.cooling.cond.steam_t_tip",
+                            "Condenser steam temperature (auto Tsat possible)",
+                        ),
+                    );
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.temp_c).speed(0.5))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_condensing_from_pressure = false;
+                        self.inputs.cooling.condenser.auto_backpressure_from_temp = false;
+                        self.inputs.cooling.condenser.use_manual_temp = true;
+                    }
+                    unit_combo(ui, &mut self.inputs.cooling.condenser.cw_temp_unit, &temperature_unit_options());
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.use_manual_temp,
+                        txt("gui.cooling.cond.manual_input", "Manual input"),
+                    );
+                    ui.end_row();
+
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_cw_out_from_range,
+                        txt("gui.cooling.cond.auto_tout", "auto Tout"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.cooling.cond.auto_tout_tip",
+                        "Use range target to auto-calc outlet temp.",
+                    ));
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.cw_in_out", "Cooling water in/out"),
+                        &txt(
+                            "gui.cooling.cond.cw_in_out_tip",
+                            "Circulating cooling water inlet/outlet temps (auto range supported)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.condenser.cw_in).speed(0.5));
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.cw_out).speed(0.5))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_cw_out_from_range = false;
+                    }
+                    unit_combo(ui, &mut self.inputs.cooling.condenser.cw_temp_unit, &temperature_unit_options());
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.range_target", "Range target (in-out)"),
+                        &txt(
+                            "gui.cooling.cond.range_target_tip",
+                            "Cooling water inlet-outlet temperature difference target",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.range_target).speed(0.2));
+                    ui.label("°C");
+                    ui.end_row();
+
+                    ui.label("");
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.cw_flow", "Cooling water flow"),
+                        &txt(
+                            "gui.cooling.cond.cw_flow_tip",
+                            "Circulating cooling water flow",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.condenser.cw_flow).speed(5.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.cooling.condenser.cw_flow_unit,
+                        &flow_unit_options(),
+                    );
+                    ui.end_row();
+
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_ua_from_area_u,
+                        txt("gui.cooling.cond.auto_ua", "auto UA"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.cooling.cond.auto_ua_tip",
+                        "Auto-calc UA from area × U",
+                    ));
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.ua", "UA [kW/K]"),
+                        &txt("gui.cooling.cond.ua_tip", "Area × U"),
+                    );
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.ua).speed(1.0))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_ua_from_area_u = false;
+                    }
+                    ui.end_row();
+
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_area_required,
+                        txt("gui.cooling.cond.auto_area", "auto area (required)"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.cooling.cond.auto_area_tip",
+                        "Auto-calc required area; uncheck to validate entered area.",
+                    ));
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.area_u", "Area / U"),
+                        &txt(
+                            "gui.cooling.cond.area_u_tip",
+                            "Enter heat transfer area and U to validate",
+                        ),
+                    );
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.area).speed(0.5))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_area_required = false;
+                    }
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.u).speed(5.0))
+                        .changed()
+                    {
+                        // keep flag as-is; user may still want auto area from new U
+                    }
+                    ui.label("area[m²], U[W/m²K]");
+                    ui.end_row();
+
+                    ui.checkbox(
+                        &mut self.inputs.cooling.condenser.auto_backpressure_from_temp,
+                        txt("gui.cooling.cond.auto_backpressure", "auto backpressure"),
+                    );
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.backpressure", "Target backpressure"),
+                        &txt(
+                            "gui.cooling.cond.backpressure_tip",
+                            "Enter compressor/turbine backpressure target or auto-calc from Tsat",
+                        ),
+                    );
+                    if ui
+                        .add(egui::DragValue::new(&mut self.inputs.cooling.condenser.backpressure).speed(0.05))
+                        .changed()
+                    {
+                        self.inputs.cooling.condenser.auto_backpressure_from_temp = false;
+                    }
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.cooling.condenser.backpressure_unit,
+                        &pressure_unit_options(),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(
+                            &mut self.inputs.cooling.condenser.backpressure_mode,
+                            conversion::PressureMode::Absolute,
+                            txt("gui.steam.mode.absolute", "Absolute (A)"),
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.cooling.condenser.backpressure_mode,
+                            conversion::PressureMode::Gauge,
+                            txt("gui.steam.mode.gauge", "Gauge (G)"),
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label("");
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.cond.duty_unit", "Heat duty unit"),
+                        &txt(
+                            "gui.cooling.cond.duty_unit_tip",
+                            "Unit for the heat duty and capacity in the result",
+                        ),
+                    );
+                    unit_combo(ui, &mut self.inputs.cooling.condenser.duty_unit, flow_units::POWER_UNITS);
+                    ui.end_row();
+                });
+            ui.collapsing(txt("gui.cooling.cond.help", "Input help"), |ui| {
+                ui.label(txt(
+                    "gui.cooling.cond.help_backpressure",
+                    "Backpressure/Psat: Psat = condenser vacuum. Gauge is atm-referenced.",
+                ));
+                ui.label(txt(
+                    "gui.cooling.cond.help_ua",
+                    "UA: U[W/m²K] × Area[m²] / 1000 = kW/K.",
+                ));
+                ui.label(txt(
+                    "gui.cooling.cond.help_range",
+                    "Range: CW inlet-outlet ΔT. Auto checked → outlet auto-calculated.",
+                ));
+                ui.label(txt(
+                    "gui.cooling.cond.help_mmhg",
+                    "mmHg is gauge (0=atm, -760=vacuum).",
+                ));
+            });
+            if run_button(ui, txt("gui.cooling.cond.run", "Run condenser calc")) {
+                // 입력값 보정/자동산출
+                let mut steam_temp_c = if self.inputs.cooling.condenser.use_manual_temp {
+                    Some(convert_temperature_gui(
+                        self.inputs.cooling.condenser.temp_c,
+                        &self.inputs.cooling.condenser.cw_temp_unit,
+                        "C",
+                    ))
+                } else {
+                    None
+                };
+                let cw_flow_m3h = convert_flow_gui(
+                    self.inputs.cooling.condenser.cw_flow,
+                    &self.inputs.cooling.condenser.cw_flow_unit,
+                    "kg/m3",
+                    1000.0,
+                );
+                // 증기 압력 절대값
+                let steam_p_abs = convert_pressure_mode_gui(
+                    self.inputs.cooling.condenser.pressure,
+                    &self.inputs.cooling.condenser.pressure_unit,
+                    self.inputs.cooling.condenser.pressure_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                if self.inputs.cooling.condenser.auto_condensing_from_pressure {
+                    if let Ok(tsat) = steam::if97::saturation_temp_c_from_pressure_bar_abs(steam_p_abs) {
+                        steam_temp_c = Some(tsat);
+                        // UI 표시 업데이트
+                        self.inputs.cooling.condenser.temp_c =
+                            convert_temperature_gui(tsat, "C", &self.inputs.cooling.condenser.cw_temp_unit);
+                    }
+                }
+                // 배압 자동: 포화온도에서 포화압력 계산
+                let mut backpressure_abs = if self.inputs.cooling.condenser.backpressure > 0.0 {
+                    Some(convert_pressure_mode_gui(
+                        self.inputs.cooling.condenser.backpressure,
+                        &self.inputs.cooling.condenser.backpressure_unit,
+                        self.inputs.cooling.condenser.backpressure_mode,
+                        "bar",
+                        conversion::PressureMode::Absolute,
+                    ))
+                } else {
+                    None
+                };
+                if self.inputs.cooling.condenser.auto_backpressure_from_temp {
+                    if let Some(t) = steam_temp_c {
+                        if let Ok(psat) = steam::if97::saturation_pressure_bar_abs_from_temp_c(t) {
+                            backpressure_abs = Some(psat);
+                            // UI 업데이트
+                            let p_disp = convert_pressure_mode_gui(
+                                psat,
+                                "bar",
+                                conversion::PressureMode::Absolute,
+                                &self.inputs.cooling.condenser.backpressure_unit,
+                                self.inputs.cooling.condenser.backpressure_mode,
+                            );
+                            self.inputs.cooling.condenser.backpressure = p_disp;
+                        }
+                    }
+                }
+
+                // Range 기반 냉각수 출구 자동
+                let cw_in_c =
+                    convert_temperature_gui(self.inputs.cooling.condenser.cw_in, &self.inputs.cooling.condenser.cw_temp_unit, "C");
+                let mut cw_out_c = convert_temperature_gui(
+                    self.inputs.cooling.condenser.cw_out,
+                    &self.inputs.cooling.condenser.cw_temp_unit,
+                    "C",
+                );
+                if self.inputs.cooling.condenser.auto_cw_out_from_range {
+                    cw_out_c = cw_in_c - self.inputs.cooling.tower.range_target;
+                    self.inputs.cooling.condenser.cw_out =
+                        convert_temperature_gui(cw_out_c, "C", &self.inputs.cooling.condenser.cw_temp_unit);
+                }
+
+                // UA 자동
+                let mut ua = if self.inputs.cooling.condenser.ua > 0.0 {
+                    Some(self.inputs.cooling.condenser.ua)
+                } else {
+                    None
+                };
+                if self.inputs.cooling.condenser.auto_ua_from_area_u && self.inputs.cooling.condenser.area > 0.0 && self.inputs.cooling.condenser.u > 0.0 {
+                    ua = Some(self.inputs.cooling.condenser.area * self.inputs.cooling.condenser.u / 1000.0);
+                    self.inputs.cooling.condenser.ua = ua.unwrap();
+                }
+                let area = if self.inputs.cooling.condenser.area > 0.0 {
+                    Some(self.inputs.cooling.condenser.area)
+                } else {
+                    None
+                };
+                let u = if self.inputs.cooling.condenser.u > 0.0 {
+                    Some(self.inputs.cooling.condenser.u)
+                } else {
+                    None
+                };
+
+                let input = condenser::CondenserInput {
+                    steam_pressure: self.inputs.cooling.condenser.pressure,
+                    steam_pressure_unit: parse_pressure_unit_gui(&self.inputs.cooling.condenser.pressure_unit),
+                    steam_pressure_mode: self.inputs.cooling.condenser.pressure_mode,
+                    steam_temp_c,
+                    cw_inlet_temp_c: cw_in_c,
+                    cw_outlet_temp_c: cw_out_c,
+                    cw_flow_m3_per_h: cw_flow_m3h,
+                    ua_kw_per_k: ua,
+                    area_m2: area,
+                    overall_u_w_m2k: u,
+                    target_back_pressure_bar_abs: backpressure_abs,
+                };
+                self.condenser_last_input = Some(input.clone());
+                self.condenser_work.clear();
+                let result = condenser::compute_condenser(input.clone());
+                self.condenser_result = Some(match result {
+                    Ok(res) => {
+                        self.condenser_work =
+                            NUMBER_FORMAT.with(|nf| show_work::condenser_work(&input, &res, &nf.borrow()));
+                        let cond_temp_out =
+                            convert_temperature_gui(res.condensing_temp_c, "C", &self.inputs.cooling.condenser.cw_temp_unit);
+                        let cond_press_out = convert_pressure_mode_gui(
+                            res.condensing_pressure_bar_abs,
+                            "bar",
+                            conversion::PressureMode::Absolute,
+                            &self.inputs.cooling.condenser.pressure_unit,
+                            self.inputs.cooling.condenser.pressure_mode,
+                        );
+                        let mut text = fill_template(
+                            &txt(
+                                "gui.cooling.cond.result",
+                                "Tsat={tsat:.2} {t_unit}, Psat={psat:.4} {p_unit}{mode}, LMTD={lmtd:.2} K, Q≈{q:.1} {q_unit}",
+                            ),
+                            &[
+                                ("tsat", fmt_num(cond_temp_out, 2)),
+                                ("t_unit", self.inputs.cooling.condenser.cw_temp_unit.clone()),
+                                ("psat", fmt_num(cond_press_out, 4)),
+                                ("p_unit", self.inputs.cooling.condenser.pressure_unit.clone()),
+                                (
+                                    "mode",
+                                    if self.inputs.cooling.condenser.pressure_mode == conversion::PressureMode::Gauge {
+                                        "g".into()
+                                    } else {
+                                        "a".into()
+                                    },
+                                ),
+                                ("lmtd", fmt_qty(res.lmtd_k, QuantityKind::TemperatureDifference)),
+                                (
+                                    "q",
+                                    fmt_num(convert_power_gui(res.heat_duty_kw, "kW", &self.inputs.cooling.condenser.duty_unit), 1),
+                                ),
+                                ("q_unit", self.inputs.cooling.condenser.duty_unit.clone()),
+                            ],
+                        );
+                        push_warnings(&mut text, &self.tr, &res.warnings);
+                        // 면적/UA 관련 추가 정보
+                        if self.inputs.cooling.condenser.auto_area_required && self.inputs.cooling.condenser.u > 0.0 {
+                            let area_req =
+                                (res.heat_duty_kw * 1000.0) / (self.inputs.cooling.condenser.u * res.lmtd_k.max(1e-6));
+                            self.inputs.cooling.condenser.area = area_req;
+                            text.push_str(&fill_template(
+                                &txt(
+                                    "gui.cooling.cond.area_req",
+                                    "\nRequired area≈{area:.2} m² (U={u:.1} W/m²K)",
+                                ),
+                                &[
+                                    ("area", fmt_num(area_req, 2)),
+                                    ("u", fmt_num(self.inputs.cooling.condenser.u, 1)),
+                                ],
+                            ));
+                        } else if !self.inputs.cooling.condenser.auto_area_required
+                            && self.inputs.cooling.condenser.area > 0.0
+                            && self.inputs.cooling.condenser.u > 0.0
+                        {
+                            let q_cap = self.inputs.cooling.condenser.area * self.inputs.cooling.condenser.u * res.lmtd_k / 1000.0;
+                            let load_ratio = if q_cap.abs() > 1e-6 {
+                                res.heat_duty_kw / q_cap
+                            } else {
+                                0.0
+                            };
+                            let capable_pct = if res.heat_duty_kw.abs() > 1e-6 {
+                                (q_cap / res.heat_duty_kw).clamp(0.0, 10.0) * 100.0
+                            } else {
+                                0.0
+                            };
+                            text.push_str(&fill_template(
+                                &txt(
+                                    "gui.cooling.cond.area_check",
+                                    "\nArea={area:.2} m², U={u:.1} W/m²K → Qcap≈{qcap:.1} {q_unit}, load ratio≈{lr:.2}x",
+                                ),
+                                &[
+                                    ("area", fmt_num(self.inputs.cooling.condenser.area, 2)),
+                                    ("u", fmt_num(self.inputs.cooling.condenser.u, 1)),
+                                    (
+                                        "qcap",
+                                        fmt_num(convert_power_gui(q_cap, "kW", &self.inputs.cooling.condenser.duty_unit), 1),
+                                    ),
+                                    ("q_unit", self.inputs.cooling.condenser.duty_unit.clone()),
+                                    ("lr", fmt_num(load_ratio, 2)),
+                                ],
+                            ));
+                            if load_ratio > 1.0 {
+                                text.push_str(&fill_template(
+                                    &txt(
+                                        "gui.cooling.cond.area_warn_over",
+                                        "\n⚠ Load exceeds design. Operable to about {pct:.0}% (Qcap basis). Lower CW temp/raise flow or increase area/U.",
+                                    ),
+                                    &[("pct", fmt_num(capable_pct, 0))],
+                                ));
+                            } else {
+                                text.push_str(&txt(
+                                    "gui.cooling.cond.area_warn_ok",
+                                    "\nWithin design load (load ≤ capacity).",
+                                ));
+                            }
+                        }
+                        text
+                    }
+                    Err(e) => match e {
+                        condenser::CoolingError::NegativeDeltaT => {
+                            txt(
+                                "gui.cooling.cond.error.delta_t",
+                                "Error: cooling water temperature crosses saturation temperature.",
+                            )
+                            .to_string()
+                        }
+                        condenser::CoolingError::If97(msg) => fill_template(
+                            &txt("gui.cooling.cond.error.if97", "Saturation calc error: {msg}"),
+                            &[("msg", msg)],
+                        ),
+                        other => other.to_string(),
+                    },
+                });
+            }
+            if let Some(res) = &self.condenser_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                show_work_block(ui, txt, "condenser_work", &self.condenser_work);
+            }
+            ui.separator();
+            let params = [
+                txt("gui.sweep.cond.cw_in", "CW inlet [°C] (rise kept)"),
+                txt("gui.sweep.cond.cw_flow", "CW flow [m3/h]"),
+            ];
+            if sweep_controls_ui(ui, "condenser_sweep", &mut self.condenser_sweep, &params, txt) {
+                match &self.condenser_last_input {
+                    Some(base) => {
+                        let range = self.condenser_sweep.range();
+                        let res = if self.condenser_sweep.param == 0 {
+                            sweep::condenser_vs_cw_inlet(base, range)
+                        } else {
+                            sweep::run_sweep(
+                                base,
+                                "CW [m³/h]",
+                                range,
+                                |i, x| i.cw_flow_m3_per_h = x,
+                                condenser::compute_condenser,
+                                sweep::CONDENSER_COLUMNS,
+                            )
+                        };
+                        self.condenser_sweep.set_result(res);
+                    }
+                    None => {
+                        self.condenser_sweep.table = None;
+                        self.condenser_sweep.error = Some(txt(
+                            "gui.sweep.cond.need_calc",
+                            "Run the condenser calculation first; the sweep starts from its inputs.",
+                        ));
+                    }
+                }
+            }
+            sweep_result_ui(ui, "condenser_sweep", &mut self.condenser_sweep, &self.tr);
+        });
+    }
+
+    /// 냉각탑(레인지/어프로치) 카드.
+    fn ui_cooling_tower<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.cooling.ct.heading", "Cooling Tower (Range / Approach)"),
+                "순환수 Range/Approach, 열량, 경고를 산출하는 간단 냉각탑 카드",
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::CoolingTower)).inner {
+                self.inputs.cooling.tower = self.input_defaults.cooling.tower.clone();
+            }
+            egui::Grid::new("ct_grid")
+                .num_columns(3)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    label_with_tip(ui, "순환수 입구/출구", "Cooling tower 입구/출구 순환수 온도");
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.water_in).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.water_out).speed(0.5));
+                    unit_combo(ui, &mut self.inputs.cooling.tower.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+                    label_with_tip(ui, "대기 DB/WB", "건구/습구 온도");
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.db).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.wb).speed(0.5));
+                    unit_combo(ui, &mut self.inputs.cooling.tower.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+                    label_with_tip(ui, "순환수 유량", "순환수 유량 (m3/h 또는 gpm)");
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.flow).speed(5.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.cooling.tower.flow_unit,
+                        flow_units::VOLUMETRIC_FLOW_UNITS,
+                    );
+                    ui.end_row();
+                    label_with_tip(ui, "Range/Approach 목표", "목표 Range(입구-출구)와 Approach(출구-습구)");
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.range_target).speed(0.2));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.tower.approach_target).speed(0.2));
+                    ui.label("°C");
+                    ui.end_row();
+                });
+            if run_button(ui, "냉각탑 계산") {
+                let t_in = convert_temperature_gui(self.inputs.cooling.tower.water_in, &self.inputs.cooling.tower.temp_unit, "C");
+                let t_out = convert_temperature_gui(self.inputs.cooling.tower.water_out, &self.inputs.cooling.tower.temp_unit, "C");
+                let wb = convert_temperature_gui(self.inputs.cooling.tower.wb, &self.inputs.cooling.tower.temp_unit, "C");
+                let db = convert_temperature_gui(self.inputs.cooling.tower.db, &self.inputs.cooling.tower.temp_unit, "C");
+                let flow_m3h = convert_volumetric_flow_gui(self.inputs.cooling.tower.flow, &self.inputs.cooling.tower.flow_unit, "m3/h");
+                let res = cooling_tower::compute_cooling_tower(cooling_tower::CoolingTowerInput {
+                    water_in_c: t_in,
+                    water_out_c: t_out,
+                    dry_bulb_c: db,
+                    wet_bulb_c: wb,
+                    water_flow_m3_per_h: flow_m3h,
+                    target_range_c: Some(self.inputs.cooling.tower.range_target),
+                    target_approach_c: Some(self.inputs.cooling.tower.approach_target),
+                });
+                let mut msg = format!(
+                    "Range={} K, Approach={} K, 열량~{} kW",
+                    fmt_qty(res.range_c, QuantityKind::TemperatureDifference),
+                    fmt_qty(res.approach_c, QuantityKind::TemperatureDifference),
+                    fmt_num(res.heat_rejected_kw, 1),
+                );
+                push_warnings(&mut msg, &self.tr, &res.warnings);
+                self.ct_result = Some(msg);
+            }
+            if let Some(res) = &self.ct_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                ui.small("참고: Range=입구-출구, Approach=출구-습구. Approach<2°C는 비현실적일 수 있습니다.");
+            }
+        });
+    }
+
+    /// 직접 증발식 공기 냉각기 카드.
+    fn ui_evaporative_cooler<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.cooling.evap.heading", "Direct evaporative air cooler"),
+                &txt(
+                    "gui.cooling.evap.tip",
+                    "Outlet dry bulb from saturation effectiveness, outlet humidity and water consumption (evaporation + bleed)",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::EvaporativeCooler)).inner {
+                self.inputs.cooling.evaporative = self.input_defaults.cooling.evaporative.clone();
+            }
+            egui::Grid::new("evap_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.evap.db", "Inlet dry bulb [°C]"),
+                        &txt("gui.cooling.evap.db_tip", "Outdoor/inlet air dry-bulb temperature"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.db_c).speed(0.5));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.evap.use_rh", "Specify RH instead of WB"),
+                        &txt("gui.cooling.evap.use_rh_tip", "Wet bulb is then derived from the psychrometric relations"),
+                    );
+                    ui.checkbox(&mut self.inputs.cooling.evaporative.use_rh, "");
+                    ui.end_row();
+                    if self.inputs.cooling.evaporative.use_rh {
+                        label_with_tip(
+                            ui,
+                            &txt("gui.cooling.evap.rh", "Inlet RH [%]"),
+                            &txt("gui.cooling.evap.rh_tip", "Inlet relative humidity"),
+                        );
+                        ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.rh_pct).speed(1.0));
+                    } else {
+                        label_with_tip(
+                            ui,
+                            &txt("gui.cooling.evap.wb", "Inlet wet bulb [°C]"),
+                            &txt("gui.cooling.evap.wb_tip", "Inlet wet-bulb temperature (the limit the air can be cooled to)"),
+                        );
+                        ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.wb_c).speed(0.5));
+                    }
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.evap.effectiveness", "Saturation effectiveness (0-1)"),
+                        &txt("gui.cooling.evap.effectiveness_tip", "ε = (Tdb − Tout) / (Tdb − Twb); 100 mm cellulose pad ≈ 0.8-0.9"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.effectiveness).speed(0.01));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.evap.air", "Air flow [m³/h]"),
+                        &txt("gui.cooling.evap.air_tip", "Inlet air volume flow"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.air_m3_h).speed(100.0));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.evap.cycles", "Cycles of concentration"),
+                        &txt("gui.cooling.evap.cycles_tip", "Bleed = evaporation / (cycles − 1)"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.evaporative.cycles).speed(0.1));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.cooling.evap.run", "Calculate evaporative cooler")) {
+                let input = evaporative_cooler::EvaporativeCoolerInput {
+                    dry_bulb_c: self.inputs.cooling.evaporative.db_c,
+                    inlet_humidity: if self.inputs.cooling.evaporative.use_rh {
+                        evaporative_cooler::InletHumidity::RelativeHumidity(self.inputs.cooling.evaporative.rh_pct)
+                    } else {
+                        evaporative_cooler::InletHumidity::WetBulb(self.inputs.cooling.evaporative.wb_c)
+                    },
+                    effectiveness: self.inputs.cooling.evaporative.effectiveness,
+                    air_flow_m3_per_h: self.inputs.cooling.evaporative.air_m3_h,
+                    total_pressure_kpa: site_atmosphere().pa() / 1000.0,
+                    cycles_of_concentration: self.inputs.cooling.evaporative.cycles,
+                };
+                self.evap_result = Some(match evaporative_cooler::compute_evaporative_cooler(input) {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.cooling.evap.result",
+                                "Inlet WB {wb} °C / {rh_in}%RH → outlet {tout} °C / {rh_out}%RH (h {h_in} → {h_out} kJ/kg), cooling {q} kW; evaporation {evap} kg/h + bleed {bleed} kg/h = make-up {makeup} kg/h",
+                            ),
+                            &[
+                                ("wb", fmt_qty(res.inlet_wet_bulb_c, QuantityKind::Temperature)),
+                                ("rh_in", fmt_num(res.inlet_rh_pct, 0)),
+                                ("tout", fmt_qty(res.outlet_dry_bulb_c, QuantityKind::Temperature)),
+                                ("rh_out", fmt_num(res.outlet_rh_pct, 0)),
+                                ("h_in", fmt_qty(res.inlet_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                                ("h_out", fmt_qty(res.outlet_enthalpy_kj_per_kg, QuantityKind::SpecificEnthalpy)),
+                                ("q", fmt_num(res.sensible_cooling_kw, 1)),
+                                ("evap", fmt_num(res.evaporation_kg_per_h, 1)),
+                                ("bleed", fmt_num(res.bleed_kg_per_h, 1)),
+                                ("makeup", fmt_num(res.makeup_kg_per_h, 1)),
+                            ],
+                        );
+                        for w in res.warnings {
+                            out.push_str("\n⚠ ");
+                            out.push_str(&w);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.cooling.evap.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.evap_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 순환 펌프 NPSH 카드.
+    fn ui_pump_npsh<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.cooling.npsh.heading", "Circulating Pump NPSH / Margin"),
+                "흡입 조건에서 NPSHa를 계산하고 NPSHr 대비 여유를 확인",
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::PumpNpsh)).inner {
+                self.inputs.cooling.npsh = self.input_defaults.cooling.npsh.clone();
+            }
+            egui::Grid::new("npsh_grid")
+                .num_columns(3)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.npsh.suction_p", "Suction pressure"),
+                        &txt(
+                            "gui.cooling.npsh.suction_p_tip",
+                            "Pump suction pressure (gauge/absolute)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.suction_p).speed(0.1));
+                    unit_combo(ui, &mut self.inputs.cooling.npsh.suction_unit, &pressure_unit_options());
+                    ui.selectable_value(
+                        &mut self.inputs.cooling.npsh.suction_mode,
+                        conversion::PressureMode::Gauge,
+                        txt("gui.steam.mode.gauge", "Gauge (G)"),
+                    );
+                    ui.selectable_value(
+                        &mut self.inputs.cooling.npsh.suction_mode,
+                        conversion::PressureMode::Absolute,
+                        txt("gui.steam.mode.absolute", "Absolute (A)"),
+                    );
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.npsh.temp", "Liquid temperature"),
+                        &txt(
+                            "gui.cooling.npsh.temp_tip",
+                            "Suction liquid temperature (for vapor pressure)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.temp).speed(0.5));
+                    unit_combo(ui, &mut self.inputs.cooling.npsh.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.npsh.head_friction", "Static head / friction [m]"),
+                        &txt(
+                            "gui.cooling.npsh.head_friction_tip",
+                            "Static head from surface to pump / friction head loss",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.static_head).speed(0.2));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.friction).speed(0.2));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.npsh.rho_npshr", "Density / NPSHr"),
+                        &txt(
+                            "gui.cooling.npsh.rho_npshr_tip",
+                            "Suction liquid density and manufacturer NPSHr",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.rho).speed(1.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.cooling.npsh.rho_unit,
+                        flow_units::DENSITY_UNITS,
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.npsh.required).speed(0.2));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.cooling.npsh.run", "Run NPSH calc")) {
+                let rho = convert_density_gui(self.inputs.cooling.npsh.rho, &self.inputs.cooling.npsh.rho_unit, "kg/m3");
+                let p_bar = convert_pressure_mode_gui(
+                    self.inputs.cooling.npsh.suction_p,
+                    &self.inputs.cooling.npsh.suction_unit,
+                    self.inputs.cooling.npsh.suction_mode,
+                    "bar",
+                    self.inputs.cooling.npsh.suction_mode,
+                );
+                let t_c = convert_temperature_gui(self.inputs.cooling.npsh.temp, &self.inputs.cooling.npsh.temp_unit, "C");
+                let res = pump_npsh::compute_pump_npsh(pump_npsh::PumpNpshInput {
+                    suction_pressure_bar: p_bar,
+                    suction_is_abs: self.inputs.cooling.npsh.suction_mode == conversion::PressureMode::Absolute,
+                    atmospheric_pressure_bar_abs: site_atmosphere().bar_abs(),
+                    liquid_temp_c: t_c,
+                    static_head_m: self.inputs.cooling.npsh.static_head,
+                    friction_loss_m: self.inputs.cooling.npsh.friction,
+                    npshr_m: self.inputs.cooling.npsh.required,
+                    rho_kg_m3: rho,
+                });
+                let mut msg = fill_template(
+                    &txt(
+                        "gui.cooling.npsh.result",
+                        "NPSHa={npsha:.2} m, Margin={margin:.2}",
+                    ),
+                    &[
+                        ("npsha", fmt_qty(res.npsha_m, QuantityKind::Length)),
+                        ("margin", fmt_num(res.margin_ratio, 2)),
+                    ],
+                );
+                push_warnings(&mut msg, &self.tr, &res.warnings);
+                self.npsh_result = Some(msg);
+            }
+            if let Some(res) = &self.npsh_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+                ui.small(txt(
+                    "gui.cooling.npsh.note",
+                    "Note: Margin<1.1 ⇒ high cavitation risk. Raise suction pressure / lower temperature / cut friction.",
+                ));
+            }
+        });
+    }
+
+    /// 드레인 쿨러/재열기 열정산 카드.
+    fn ui_drain_cooler<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt(
+                    "gui.cooling.drain.heading",
+                    "Drain Cooler / Reheater Heat Balance",
+                ),
+                &txt(
+                    "gui.cooling.drain.tip",
+                    "Compute LMTD and heat balance from shell/tube inlet/outlet temps and flows",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::DrainCooler)).inner {
+                self.inputs.cooling.drain_cooler = self.input_defaults.cooling.drain_cooler.clone();
+            }
+            egui::Grid::new("drain_grid")
+                .num_columns(3)
+                .spacing([10.0, 6.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.drain.shell_in_out", "Shell IN/OUT"),
+                        &txt("gui.cooling.drain.shell_in_out_tip", "Shell-side inlet/outlet temperature"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.shell_in).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.shell_out).speed(0.5));
+                    unit_combo(ui, &mut self.inputs.cooling.drain_cooler.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.drain.tube_in_out", "Tube IN/OUT"),
+                        &txt("gui.cooling.drain.tube_in_out_tip", "Tube-side inlet/outlet temperature"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.tube_in).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.tube_out).speed(0.5));
+                    unit_combo(ui, &mut self.inputs.cooling.drain_cooler.temp_unit, &temperature_unit_options());
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.drain.flow", "Shell/Tube flow"),
+                        &txt("gui.cooling.drain.flow_tip", "Shell-side / tube-side flow"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.shell_flow).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.tube_flow).speed(1.0));
+                    unit_combo(
+                        ui,
+                        &mut self.inputs.cooling.drain_cooler.flow_unit,
+                        flow_units::VOLUMETRIC_FLOW_UNITS,
+                    );
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.cooling.drain.ua_area_u", "UA or Area/U"),
+                        &txt(
+                            "gui.cooling.drain.ua_area_u_tip",
+                            "Enter UA directly or area/U to compute UA",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.ua).speed(1.0));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.area).speed(0.5));
+                    ui.add(egui::DragValue::new(&mut self.inputs.cooling.drain_cooler.u).speed(5.0));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.cooling.drain.run", "Run heat balance")) {
+                let flow_shell_m3h =
+                    convert_volumetric_flow_gui(self.inputs.cooling.drain_cooler.shell_flow, &self.inputs.cooling.drain_cooler.flow_unit, "m3/h");
+                let flow_tube_m3h =
+                    convert_volumetric_flow_gui(self.inputs.cooling.drain_cooler.tube_flow, &self.inputs.cooling.drain_cooler.flow_unit, "m3/h");
+                let t_in_shell =
+                    convert_temperature_gui(self.inputs.cooling.drain_cooler.shell_in, &self.inputs.cooling.drain_cooler.temp_unit, "C");
+                let t_out_shell =
+                    convert_temperature_gui(self.inputs.cooling.drain_cooler.shell_out, &self.inputs.cooling.drain_cooler.temp_unit, "C");
+                let t_in_tube =
+                    convert_temperature_gui(self.inputs.cooling.drain_cooler.tube_in, &self.inputs.cooling.drain_cooler.temp_unit, "C");
+                let t_out_tube =
+                    convert_temperature_gui(self.inputs.cooling.drain_cooler.tube_out, &self.inputs.cooling.drain_cooler.temp_unit, "C");
+                let res = drain_cooler::compute_drain_cooler(drain_cooler::DrainCoolerInput {
+                    shell_in_c: t_in_shell,
+                    shell_out_c: t_out_shell,
+                    shell_flow_m3_per_h: flow_shell_m3h,
+                    tube_in_c: t_in_tube,
+                    tube_out_c: t_out_tube,
+                    tube_flow_m3_per_h: flow_tube_m3h,
+                    ua_kw_per_k: if self.inputs.cooling.drain_cooler.ua > 0.0 {
+                        Some(self.inputs.cooling.drain_cooler.ua)
+                    } else {
+                        None
+                    },
+                    area_m2: if self.inputs.cooling.drain_cooler.area > 0.0 {
+                        Some(self.inputs.cooling.drain_cooler.area)
+                    } else {
+                        None
+                    },
+                    overall_u_w_m2k: if self.inputs.cooling.drain_cooler.u > 0.0 {
+                        Some(self.inputs.cooling.drain_cooler.u)
+                    } else {
+                        None
+                    },
+                });
+                let mut msg = fill_template(
+                    &txt(
+                        "gui.cooling.drain.result",
+                        "LMTD={lmtd:.2} K, Shell Q={shell:.1} kW, Tube Q={tube:.1} kW, Imbalance={imb:.1} kW",
+                    ),
+                    &[
+                        ("lmtd", fmt_qty(res.lmtd_k, QuantityKind::TemperatureDifference)),
+                        ("shell", fmt_num(res.shell_heat_kw, 1)),
+                        ("tube", fmt_num(res.tube_heat_kw, 1)),
+                        ("imb", fmt_num(res.imbalance_kw, 1)),
+                    ],
+                );
+                push_warnings(&mut msg, &self.tr, &res.warnings);
+                self.drain_result = Some(msg);
+            }
+            if let Some(res) = &self.drain_result {
+                ui.separator();
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 플랜트 배관: 오리피스/노즐 유량 점검 + 재질별 열팽창 계산
+    fn ui_plant_piping(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt("gui.plant.heading", "Plant Piping"),
+            &txt(
+                "gui.plant.tip",
+                "Orifice/nozzle flow, thermal expansion, pressure rating",
+            ),
+        );
+        label_with_tip(
+            ui,
+            &txt("gui.plant.subheading", "Orifice/nozzle check, thermal expansion, pressure rating"),
+            &txt(
+                "gui.plant.subhint",
+                "Compressibility(Y), expansion, and pressure rating on one screen",
+            ),
+        );
+        ui.add_space(8.0);
+
+        self.ui_card(ui, &txt, CardId::Orifice);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::MeterCompensation);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Totalizer);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::ExpansionLoop);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::FreezeProtection);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Corrosion);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::PressureTest);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::LineFill);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::GasProperties);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::VesselBlowdown);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::GasThrottling);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Compressor);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::LetdownNoise);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::VentSilencer);
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::TurbineBypass);
+    }
+
+    /// 오리피스/노즐 유량 카드.
+    fn ui_orifice<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.orifice.heading", "Orifice / Nozzle flow check"),
+                &txt(
+                    "gui.plant.orifice.tip",
+                    "Verify differential-pressure meter or nozzle flow",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::Orifice)).inner {
+                self.inputs.plant_piping.orifice = self.input_defaults.plant_piping.orifice.clone();
+            }
+            egui::Grid::new("plant_orifice")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.up_p", "{t_up_p}"),
+                        &txt(
+                            "gui.plant.orifice.up_p_tip",
+                            "Nozzle/orifice {t_up_p} (gauge/absolute)",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.orifice.up_p).speed(0.1));
+                        unit_combo(ui, &mut self.inputs.plant_piping.orifice.up_unit, &pressure_unit_options());
+                        ui.selectable_value(
+                            &mut self.inputs.plant_piping.orifice.up_mode,
+                            conversion::PressureMode::Gauge,
+                            txt("gui.steam.mode.gauge", "Gauge (G)"),
+                        );
+                        ui.selectable_value(
+                            &mut self.inputs.plant_piping.orifice.up_mode,
+                            conversion::PressureMode::Absolute,
+                            txt("gui.steam.mode.absolute", "Absolute (A)"),
+                        );
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.dp", "ΔP"),
+                        &txt(
+                            "gui.plant.orifice.dp_tip",
+                            "Pressure drop across orifice/nozzle",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.orifice.dp).speed(0.1));
+                        unit_combo(ui, &mut self.inputs.plant_piping.orifice.dp_unit, &dp_unit_options());
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.rho", "Fluid density"),
+                        &txt("gui.plant.orifice.rho_tip", "Density at operating condition"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.orifice.rho).speed(1.0));
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.diameter", "Diameter"),
+                        &txt(
+                            "gui.plant.orifice.diameter_tip",
+                            "Orifice/nozzle effective diameter (m or mm)",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.diameter_m)
+                                .speed(0.001)
+                                .clamp_range(0.0..=5.0),
+                        );
+                        unit_combo(ui, &mut self.inputs.plant_piping.orifice.diam_unit, &[("m", "m"), ("mm", "mm")]);
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.shape_cd", "Shape / Cd"),
+                        &txt(
+                            "gui.plant.orifice.shape_cd_tip",
+                            "Select shape to set Cd; adjust if needed",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source("plant_shape")
+                            .selected_text(&self.inputs.plant_piping.orifice.shape)
+                            .show_ui(ui, |ui| {
+                                if ui
+                                    .selectable_value(&mut self.inputs.plant_piping.orifice.shape, "Orifice (sharp)".into(), "Orifice (sharp)")
+                                    .clicked()
+                                {
+                                    self.inputs.plant_piping.orifice.cd = 0.62;
+                                }
+                                if ui
+                                    .selectable_value(
+                                        &mut self.inputs.plant_piping.orifice.shape,
+                                        "Nozzle (ISA)".into(),
+                                        "Nozzle (ISA)",
+                                    )
+                                    .clicked()
+                                {
+                                    self.inputs.plant_piping.orifice.cd = 0.98;
+                                }
+                                if ui
+                                    .selectable_value(
+                                        &mut self.inputs.plant_piping.orifice.shape,
+                                        "Venturi".into(),
+                                        "Venturi",
+                                    )
+                                    .clicked()
+                                {
+                                    self.inputs.plant_piping.orifice.cd = 0.97;
+                                }
+                            });
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.cd)
+                                .speed(0.01)
+                                .clamp_range(0.1..=1.5),
+                        );
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.beta_k", "Beta(diameter ratio) / k(specific heat ratio)"),
+                        &txt(
+                            "gui.plant.orifice.beta_k_tip",
+                            "beta=orifice/pipe diameter ratio, k=gamma",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.beta)
+                                .speed(0.01)
+                                .clamp_range(0.1..=0.99),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.gamma)
+                                .speed(0.05)
+                                .clamp_range(1.0..=1.7),
+                        );
+                    });
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.orifice.compressible", "Use compressible correction"),
+                        &txt(
+                            "gui.plant.orifice.compressible_tip",
+                            "Apply Y-factor for steam/gas flow",
+                        ),
+                    );
+                    ui.checkbox(
+                        &mut self.inputs.plant_piping.orifice.compressible,
+                        txt("gui.plant.orifice.compressible_toggle", "Compressible (Y)"),
+                    );
+                    ui.end_row();
+
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.meter.cost_inputs", "Pump eff. / hours / price"),
+                        &txt(
+                            "gui.plant.meter.cost_inputs_tip",
+                            "Pump or blower efficiency, operating hours per year and energy price per kWh for the permanent-loss cost",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.meter_pump_eff)
+                                .speed(0.01)
+                                .clamp_range(0.1..=1.0),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.meter_hours)
+                                .speed(100.0)
+                                .clamp_range(0.0..=8760.0)
+                                .suffix(" h/yr"),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut self.inputs.plant_piping.orifice.meter_price)
+                                .speed(0.01)
+                                .clamp_range(0.0..=f64::MAX)
+                                .suffix(" /kWh"),
+                        );
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.orifice.run", "Calculate flow")) {
+                let dp_bar = dp_convert_gui(self.inputs.plant_piping.orifice.dp, &self.inputs.plant_piping.orifice.dp_unit, "bar");
+                let pu_bar_abs = convert_pressure_mode_gui(
+                    self.inputs.plant_piping.orifice.up_p,
+                    &self.inputs.plant_piping.orifice.up_unit,
+                    self.inputs.plant_piping.orifice.up_mode,
+                    "bar",
+                    conversion::PressureMode::Absolute,
+                );
+                let d_m = convert_length_gui(self.inputs.plant_piping.orifice.diameter_m, &self.inputs.plant_piping.orifice.diam_unit, "m");
+                if dp_bar <= 0.0 || self.inputs.plant_piping.orifice.rho <= 0.0 || d_m <= 0.0 {
+                    self.plant_result = Some(txt(
+                        "gui.plant.orifice.error.input",
+                        "Error: ΔP, density, and diameter must be > 0.",
+                    )
+                    .to_string());
+                } else {
+                    let dp_pa = dp_bar * 1.0e5;
+                    let area = std::f64::consts::PI * (d_m.powi(2)) / 4.0;
+                    let meter = flow_meter::MeterType::ALL
+                        .into_iter()
+                        .find(|m| m.label() == self.inputs.plant_piping.orifice.shape)
+                        .unwrap_or(flow_meter::MeterType::Orifice);
+                    let beta = self.inputs.plant_piping.orifice.beta.clamp(0.1, 0.99);
+                    let meter_input = |q_m3_h: f64| flow_meter::MeterLossInput {
+                        meter,
+                        beta,
+                        pipe_diameter_m: d_m / beta,
+                        differential_bar: dp_bar,
+                        volume_flow_m3_per_h: q_m3_h,
+                        discharge_coefficient: Some(self.inputs.plant_piping.orifice.cd.min(1.0)),
+                        pump_efficiency: self.inputs.plant_piping.orifice.meter_pump_eff,
+                        hours_per_year: self.inputs.plant_piping.orifice.meter_hours,
+                        energy_price_per_kwh: self.inputs.plant_piping.orifice.meter_price,
+                    };
+                    if self.inputs.plant_piping.orifice.compressible {
+                        if pu_bar_abs <= dp_bar {
+                            self.plant_result = Some(txt(
+                                "gui.plant.orifice.error.up_lt_dp",
+                                "Error: upstream pressure must exceed ΔP (compressible).",
+                            )
+                            .to_string());
+                        } else {
+                            let k = self.inputs.plant_piping.orifice.gamma.clamp(1.0, 1.7);
+                            let ratio = (dp_bar / pu_bar_abs).min(0.9);
+                            let y = (1.0 - (0.41 + 0.35 * beta * beta) * ratio).clamp(0.1, 1.0);
+                            let c = self.inputs.plant_piping.orifice.cd / (1.0 - beta.powi(4)).sqrt();
+                            let m_kg_s = c * y * area * (2.0 * self.inputs.plant_piping.orifice.rho * dp_pa).sqrt();
+                            let m_kg_h = m_kg_s * 3600.0;
+                            let q_m3_h = m_kg_h / self.inputs.plant_piping.orifice.rho;
+                            let mut out = fill_template(
+                                &txt(
+                                    "gui.plant.orifice.result.comp",
+                                    "Compressible: Q≈{q:.3} m³/h, m≈{m:.2} kg/h (Cd={cd:.2}, Y={y:.3}, beta={beta:.2}, k={k:.2}, dp={dp:.3} bar)",
+                                ),
+                                &[
+                                    ("q", fmt_num(q_m3_h, 3)),
+                                    ("m", fmt_num(m_kg_h, 2)),
+                                    ("cd", fmt_num(self.inputs.plant_piping.orifice.cd, 2)),
+                                    ("y", fmt_num(y, 3)),
+                                    ("beta", fmt_num(beta, 2)),
+                                    ("k", fmt_num(k, 2)),
+                                    ("dp", fmt_qty(dp_bar, QuantityKind::Pressure)),
+                                ],
+                            );
+                            out.push_str(&flow_meter_loss_text(txt, &self.tr, &meter_input(q_m3_h)));
+                            self.plant_result = Some(out);
+                        }
+                    } else {
+                        let q_m3_s = self.inputs.plant_piping.orifice.cd * area * (2.0 * dp_pa / self.inputs.plant_piping.orifice.rho).sqrt();
+                        let q_m3_h = q_m3_s * 3600.0;
+                        let m_kg_h = q_m3_h * self.inputs.plant_piping.orifice.rho;
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.orifice.result.incomp",
+                                "Incompressible: Q≈{q:.3} m³/h, m≈{m:.2} kg/h (Cd={cd:.2}, dp={dp:.3} bar)",
+                            ),
+                            &[
+                                ("q", fmt_num(q_m3_h, 3)),
+                                ("m", fmt_num(m_kg_h, 2)),
+                                ("cd", fmt_num(self.inputs.plant_piping.orifice.cd, 2)),
+                                ("dp", fmt_qty(dp_bar, QuantityKind::Pressure)),
+                            ],
+                        );
+                        out.push_str(&flow_meter_loss_text(txt, &self.tr, &meter_input(q_m3_h)));
+                        self.plant_result = Some(out);
+                    }
+                }
+            }
+            if let Some(res) = &self.plant_result {
+                result_block(ui, txt, res, false);
+                legend_toggle(
+                    ui,
+                    &txt("legend.plant.title", "Legend / notes"),
+                    &txt("legend.plant.body", "Formula: incompressible Q = Cd·A·√(2·ΔP/ρ); compressible uses Y·C(1-β⁴)^-0.5"),
+                    &mut self.show_legend_plant,
+                );
+            }
+        });
+    }
+
+    /// 신축 루프 카드.
+    fn ui_expansion_loop<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.loop.heading", "Expansion loop sizing"),
+                &txt(
+                    "gui.plant.loop.tip",
+                    "Guided-cantilever leg length, stress and anchor force for L-bend, Z-bend and U-loop layouts",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::ExpansionLoop)).inner {
+                self.inputs.plant_piping.expansion_loop = self.input_defaults.plant_piping.expansion_loop.clone();
+                self.inputs.plant_piping.line = self.input_defaults.plant_piping.line.clone();
+            }
+            egui::Grid::new("plant_expansion_loop_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.layout_label", "Layout"),
+                        &txt(
+                            "gui.plant.loop.layout_tip",
+                            "L-bend: short leg absorbs the long leg; Z-bend: offset leg absorbs both runs; U-loop: two legs share the anchor-to-anchor expansion",
+                        ),
+                    );
+                    let layouts = [
+                        txt("gui.plant.loop.layout.l", "L-bend"),
+                        txt("gui.plant.loop.layout.z", "Z-bend"),
+                        txt("gui.plant.loop.layout.u", "U-loop"),
+                    ];
+                    egui::ComboBox::from_id_source("plant_loop_layout")
+                        .selected_text(layouts[self.inputs.plant_piping.expansion_loop.layout.min(2)].clone())
+                        .show_ui(ui, |ui| {
+                            for (i, label) in layouts.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.plant_piping.expansion_loop.layout, i, label.clone());
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.material", "Material"),
+                        &txt("gui.plant.loop.material_tip", "Modulus, expansion coefficient and allowable stress from the material database"),
+                    );
+                    egui::ComboBox::from_id_source("plant_loop_material")
+                        .selected_text(self.inputs.plant_piping.line.material.clone())
+                        .show_ui(ui, |ui| {
+                            for m in material_db::materials() {
+                                ui.selectable_value(&mut self.inputs.plant_piping.line.material, m.code.to_string(), m.name);
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.od", "Outer diameter [m]"),
+                        &txt("gui.plant.loop.od_tip", "Pipe outside diameter"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.outer_diameter_m).speed(0.001));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.wall", "Wall thickness [m]"),
+                        &txt("gui.plant.loop.wall_tip", "Used for the moment of inertia in the anchor force"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.wall_thickness_m).speed(0.0005));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.run", "Expanding run length [m]"),
+                        &txt(
+                            "gui.plant.loop.run_tip",
+                            "Long leg (L-bend), sum of both runs (Z-bend) or anchor spacing (U-loop)",
+                        ),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.expansion_loop.run_length_m).speed(0.5));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.delta_t", "ΔT from 20 °C installation [K]"),
+                        &txt("gui.plant.loop.delta_t_tip", "Operating temperature minus installation temperature (20 °C)"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.expansion_loop.delta_t_k).speed(1.0));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.loop.available", "Available leg [m] (0 = size)"),
+                        &txt("gui.plant.loop.available_tip", "Check stress and force for an existing leg; 0 returns the required length"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.expansion_loop.available_leg_m).speed(0.1));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.loop.run_button", "Size expansion leg")) {
+                let run = self.inputs.plant_piping.expansion_loop.run_length_m;
+                let layout = match self.inputs.plant_piping.expansion_loop.layout {
+                    1 => expansion_loop::LoopLayout::ZBend {
+                        leg1_m: run / 2.0,
+                        leg2_m: run / 2.0,
+                    },
+                    2 => expansion_loop::LoopLayout::ULoop {
+                        anchor_spacing_m: run,
+                    },
+                    _ => expansion_loop::LoopLayout::LBend { long_leg_m: run },
+                };
+                let input = expansion_loop::ExpansionLoopInput {
+                    outer_diameter_m: self.inputs.plant_piping.line.outer_diameter_m,
+                    wall_thickness_m: self.inputs.plant_piping.line.wall_thickness_m,
+                    material: self.inputs.plant_piping.line.material.clone(),
+                    install_temp_c: 20.0,
+                    design_temp_c: 20.0 + self.inputs.plant_piping.expansion_loop.delta_t_k,
+                    layout,
+                    available_leg_m: (self.inputs.plant_piping.expansion_loop.available_leg_m > 0.0)
+                        .then_some(self.inputs.plant_piping.expansion_loop.available_leg_m),
+                };
+                self.plant_expansion_result = Some(match expansion_loop::size_expansion_loop(input) {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.loop.result",
+                                "ΔL {dl} mm (leg {dleg} mm), S_A {sa} MPa → required leg {req} m; at {leg} m: stress {s} MPa, anchor force {f} kN",
+                            ),
+                            &[
+                                ("dl", fmt_num(res.total_expansion_mm, 1)),
+                                ("dleg", fmt_num(res.leg_displacement_mm, 1)),
+                                ("sa", fmt_num(res.allowable_range_mpa, 0)),
+                                ("req", fmt_qty(res.required_leg_m, QuantityKind::Length)),
+                                ("leg", fmt_qty(res.leg_m, QuantityKind::Length)),
+                                ("s", fmt_num(res.stress_mpa, 0)),
+                                ("f", fmt_num(res.anchor_force_n / 1000.0, 2)),
+                            ],
+                        );
+                        if let Some(w) = res.loop_width_m {
+                            out.push_str(&fill_template(
+                                &txt("gui.plant.loop.width", ", loop width ≥ {w} m"),
+                                &[("w", fmt_num(w, 2))],
+                            ));
+                        }
+                        out.push('\n');
+                        out.push_str(&fill_template(
+                            &txt(
+                                "gui.plant.loop.properties",
+                                "α {alpha}e-6 1/K, E {ec} → {eh} GPa (cold → hot), hot anchor force {fh} kN",
+                            ),
+                            &[
+                                ("alpha", fmt_num(res.expansion_coeff_per_k * 1e6, 2)),
+                                ("ec", fmt_num(res.modulus_cold_mpa / 1000.0, 0)),
+                                ("eh", fmt_num(res.modulus_hot_mpa / 1000.0, 0)),
+                                ("fh", fmt_num(res.hot_anchor_force_n / 1000.0, 2)),
+                            ],
+                        ));
+                        for w in res.warnings {
+                            out.push_str("\n⚠ ");
+                            out.push_str(&w);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.loop.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.plant_expansion_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 동결 방지(정체 수배관) 카드.
+    fn ui_freeze_protection<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.freeze.heading", "Freeze protection (stagnant water line)"),
+                &txt(
+                    "gui.plant.freeze.tip",
+                    "Time to cool to 0 °C and to freeze solid for a stagnant insulated line, plus heat tracing to hold 5 °C",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::FreezeProtection)).inner {
+                self.inputs.plant_piping.freeze = self.input_defaults.plant_piping.freeze.clone();
+            }
+            egui::Grid::new("plant_freeze_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.freeze.pipe", "OD / wall [m]"),
+                        &txt("gui.plant.freeze.pipe_tip", "Pipe outside diameter and wall thickness; size and material are shared with the expansion loop card"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.outer_diameter_m).speed(0.001));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.wall_thickness_m).speed(0.0005));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.freeze.insulation", "Insulation [m] / k [W/m·K]"),
+                        &txt("gui.plant.freeze.insulation_tip", "Insulation thickness (0 = bare pipe) and conductivity (mineral wool ≈ 0.04)"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.freeze.insulation_m).speed(0.005));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.freeze.k_w_mk).speed(0.005));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.freeze.wind", "Wind speed [m/s]"),
+                        &txt("gui.plant.freeze.wind_tip", "Outside film h = 10.45 − v + 10√v W/m²·K"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.freeze.wind_m_s).speed(0.5));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.freeze.temps", "Ambient / initial water [°C]"),
+                        &txt("gui.plant.freeze.temps_tip", "Design winter ambient (below 0 °C) and water temperature when flow stops"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.freeze.ambient_c).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.freeze.initial_c).speed(1.0));
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.freeze.run", "Calculate time to freeze")) {
+                let input = freeze_protection::FreezeProtectionInput {
+                    outer_diameter_m: self.inputs.plant_piping.line.outer_diameter_m,
+                    wall_thickness_m: self.inputs.plant_piping.line.wall_thickness_m,
+                    insulation_thickness_m: self.inputs.plant_piping.freeze.insulation_m,
+                    insulation_conductivity_w_mk: self.inputs.plant_piping.freeze.k_w_mk,
+                    wind_speed_m_per_s: self.inputs.plant_piping.freeze.wind_m_s,
+                    ambient_temp_c: self.inputs.plant_piping.freeze.ambient_c,
+                    initial_temp_c: self.inputs.plant_piping.freeze.initial_c,
+                    pipe_material: Some(self.inputs.plant_piping.line.material.clone()),
+                };
+                self.plant_freeze_result = Some(match freeze_protection::compute_freeze_protection(input) {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.freeze.result",
+                                "UA {ua} W/m·K (loss {q} W/m), water {m} kg/m, τ {tau} h → 0 °C in {t0} h, frozen solid after {tf} h more (total {tt} h); heat tracing for 5 °C: {ht} W/m",
+                            ),
+                            &[
+                                ("ua", fmt_num(res.ua_w_per_mk, 3)),
+                                ("q", fmt_num(res.initial_heat_loss_w_per_m, 1)),
+                                ("m", fmt_num(res.water_mass_kg_per_m, 2)),
+                                ("tau", fmt_num(res.time_constant_h, 1)),
+                                ("t0", fmt_num(res.time_to_zero_h, 1)),
+                                ("tf", fmt_num(res.freezing_duration_h, 1)),
+                                ("tt", fmt_num(res.time_to_complete_freeze_h, 1)),
+                                ("ht", fmt_num(res.heat_trace_w_per_m, 1)),
+                            ],
+                        );
+                        if let Some(k) = res.wall_conductivity_w_mk {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt("gui.plant.freeze.wall", "Pipe wall {mat}: k {k} W/m·K included in UA"),
+                                &[("mat", self.inputs.plant_piping.line.material.clone()), ("k", fmt_num(k, 1))],
+                            ));
+                        }
+                        for w in res.warnings {
+                            out.push_str("\n⚠ ");
+                            out.push_str(&w);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.freeze.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.plant_freeze_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 부식 여유·잔여 수명 카드.
+    fn ui_corrosion<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.corrosion.heading", "Corrosion allowance & remaining life"),
+                &txt(
+                    "gui.plant.corrosion.tip",
+                    "B31 pressure design thickness, retirement thickness, remaining corrosion allowance and remaining life from a thickness reading",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::Corrosion)).inner {
+                self.inputs.plant_piping.corrosion = self.input_defaults.plant_piping.corrosion.clone();
+            }
+            egui::Grid::new("plant_corrosion_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.pipe_class.label_dn", "Piping class / DN"),
+                        &txt(
+                            "gui.pipe_class.wall_tip",
+                            "Checks the class schedule at the design P/T; Apply fills OD, wall, material, E, CA and mill tolerance from the class",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        pipe_class_combo(ui, txt, "plant_corrosion_class", &self.pipe_classes, &mut self.inputs.plant_piping.corrosion.class_choice);
+                        let class = self
+                            .inputs
+                            .plant_piping
+                            .corrosion
+                            .class_choice
+                            .as_deref()
+                            .and_then(|name| self.pipe_classes.find(name).ok());
+                        if let Some(class) = class {
+                            egui::ComboBox::from_id_source("plant_corrosion_class_dn")
+                                .selected_text(format!("DN{}", self.inputs.plant_piping.corrosion.class_dn))
+                                .show_ui(ui, |ui| {
+                                    for size in &class.sizes {
+                                        ui.selectable_value(&mut self.inputs.plant_piping.corrosion.class_dn, size.dn, format!("DN{}", size.dn));
+                                    }
+                                });
+                            if ui
+                                .small_button(txt("gui.pipe_class.apply", "Apply"))
+                                .clicked()
+                            {
+                                if let Ok(pipe) = class.pipe(self.inputs.plant_piping.corrosion.class_dn) {
+                                    self.inputs.plant_piping.line.outer_diameter_m = pipe.outer_diameter_m;
+                                    self.inputs.plant_piping.line.wall_thickness_m = pipe.wall_m;
+                                    self.inputs.plant_piping.line.material = class.material.clone();
+                                    self.inputs.plant_piping.corrosion.weld_eff = class.weld_joint_efficiency;
+                                    self.inputs.plant_piping.corrosion.corrosion_allowance_m = class.corrosion_allowance_mm / 1000.0;
+                                    self.inputs.plant_piping.corrosion.mill_tolerance_frac = class.mill_tolerance;
+                                }
+                            }
+                        }
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.design", "Design P [bar(g)] / T [°C]"),
+                        &txt("gui.plant.corrosion.design_tip", "Design pressure and temperature; S and y are taken at the design temperature"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.design_pressure_bar_g).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.design_temp_c).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.pipe", "OD / nominal wall [m]"),
+                        &txt("gui.plant.corrosion.pipe_tip", "Pipe outside diameter and nominal wall; shared with the expansion loop card"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.outer_diameter_m).speed(0.001));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.wall_thickness_m).speed(0.0005));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.material", "Material"),
+                        &txt("gui.plant.corrosion.material_tip", "Allowable stress from the material database"),
+                    );
+                    egui::ComboBox::from_id_source("plant_corrosion_material")
+                        .selected_text(self.inputs.plant_piping.line.material.clone())
+                        .show_ui(ui, |ui| {
+                            for m in material_db::materials() {
+                                ui.selectable_value(&mut self.inputs.plant_piping.line.material, m.code.to_string(), m.name);
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.factors", "E / CA [m] / mill tolerance"),
+                        &txt("gui.plant.corrosion.factors_tip", "Weld joint efficiency, design corrosion allowance and mill under-tolerance fraction (0.125 for A106)"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.weld_eff).speed(0.01).clamp_range(0.5..=1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.corrosion_allowance_m).speed(0.0005));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.mill_tolerance_frac).speed(0.005));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.measured", "Measured wall [m]"),
+                        &txt("gui.plant.corrosion.measured_tip", "Minimum UT reading at the condition monitoring location"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.measured_m).speed(0.0001));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.rate", "Rate [mm/yr] / years in service"),
+                        &txt("gui.plant.corrosion.rate_tip", "Rate 0 = long-term rate (nominal − measured) / years in service"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.rate_mm_yr).speed(0.01));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.years).speed(0.5));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.corrosion.structural", "Structural minimum [m] (0 = none)"),
+                        &txt("gui.plant.corrosion.structural_tip", "Minimum thickness for mechanical loads (e.g. API 574 table); the retirement thickness is the larger of this and the pressure thickness"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.corrosion.structural_m).speed(0.0001));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.corrosion.run", "Assess remaining life")) {
+                let design = wall_thickness::WallThicknessInput {
+                    design_pressure_bar_g: self.inputs.plant_piping.line.design_pressure_bar_g,
+                    design_temp_c: self.inputs.plant_piping.line.design_temp_c,
+                    outer_diameter_m: self.inputs.plant_piping.line.outer_diameter_m,
+                    material: self.inputs.plant_piping.line.material.clone(),
+                    weld_joint_efficiency: self.inputs.plant_piping.corrosion.weld_eff,
+                    weld_strength_reduction: 1.0,
+                    corrosion_allowance_m: self.inputs.plant_piping.corrosion.corrosion_allowance_m,
+                    mill_tolerance_frac: self.inputs.plant_piping.corrosion.mill_tolerance_frac,
+                };
+                let input = corrosion::CorrosionInput {
+                    design: design.clone(),
+                    nominal_thickness_m: self.inputs.plant_piping.line.wall_thickness_m,
+                    measured_thickness_m: self.inputs.plant_piping.corrosion.measured_m,
+                    corrosion_rate_mm_per_yr: (self.inputs.plant_piping.corrosion.rate_mm_yr > 0.0)
+                        .then_some(self.inputs.plant_piping.corrosion.rate_mm_yr),
+                    years_in_service: Some(self.inputs.plant_piping.corrosion.years),
+                    structural_minimum_m: (self.inputs.plant_piping.corrosion.structural_m > 0.0)
+                        .then_some(self.inputs.plant_piping.corrosion.structural_m),
+                };
+                let outcome = wall_thickness::required_wall_thickness(&design)
+                    .and_then(|req| corrosion::assess_corrosion(&input).map(|res| (req, res)));
+                self.plant_pressure_result = Some(match outcome {
+                    Ok((req, res)) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.corrosion.result",
+                                "S {s} MPa, y {y} → t {t} mm, t + CA {tm} mm, order ≥ {tn} mm",
+                            ),
+                            &[
+                                ("s", fmt_num(req.allowable_stress_mpa, 0)),
+                                ("y", fmt_num(req.y_coefficient, 1)),
+                                ("t", fmt_num(req.pressure_design_thickness_m * 1000.0, 2)),
+                                ("tm", fmt_num(req.minimum_required_m * 1000.0, 2)),
+                                ("tn", fmt_num(req.nominal_required_m * 1000.0, 2)),
+                            ],
+                        );
+                        out.push('\n');
+                        out.push_str(&fill_template(
+                            &txt(
+                                "gui.plant.corrosion.life",
+                                "Retirement {tr} mm, remaining allowance {ca} mm at {rate} mm/yr, MAWP at measured wall {mawp} bar(g)",
+                            ),
+                            &[
+                                ("tr", fmt_num(res.retirement_thickness_m * 1000.0, 2)),
+                                ("ca", fmt_num(res.remaining_allowance_m * 1000.0, 2)),
+                                ("rate", fmt_num(res.corrosion_rate_mm_per_yr, 3)),
+                                ("mawp", fmt_qty(res.mawp_bar_g, QuantityKind::Pressure)),
+                            ],
+                        ));
+                        out.push('\n');
+                        out.push_str(&match (res.below_retirement, res.remaining_life_years) {
+                            (true, _) => txt("gui.plant.corrosion.retire", "RETIRE: measured wall is below the retirement thickness"),
+                            (false, Some(life)) => fill_template(
+                                &txt(
+                                    "gui.plant.corrosion.remaining",
+                                    "Remaining life {life} yr, next inspection within {next} yr",
+                                ),
+                                &[
+                                    ("life", fmt_num(life, 1)),
+                                    ("next", fmt_num(life / 2.0, 1)),
+                                ],
+                            ),
+                            (false, None) => txt("gui.plant.corrosion.no_rate", "No measurable corrosion; remaining life not limited by thinning"),
+                        });
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        if let Some(name) = &self.inputs.plant_piping.corrosion.class_choice {
+                            out.push('\n');
+                            out.push_str(
+                                &match self.pipe_classes.find(name).and_then(|c| {
+                                    pipe_class::check_size(
+                                        c,
+                                        self.inputs.plant_piping.corrosion.class_dn,
+                                        self.inputs.plant_piping.line.design_pressure_bar_g,
+                                        self.inputs.plant_piping.line.design_temp_c,
+                                    )
+                                }) {
+                                    Ok(check) => pipe_class_check_text(txt, &check),
+                                    Err(e) => error_text(&self.tr, e),
+                                },
+                            );
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.corrosion.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.plant_pressure_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 수압/기압 시험 카드.
+    fn ui_pressure_test<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.ptest.heading", "Hydrotest / pneumatic test pressure"),
+                &txt(
+                    "gui.plant.ptest.tip",
+                    "B31.1/B31.3 test pressure, hold time check, pneumatic pressure steps and stored energy with exclusion zone (ASME PCC-2)",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::PressureTest)).inner {
+                self.inputs.plant_piping.pressure_test = self.input_defaults.plant_piping.pressure_test.clone();
+            }
+            egui::Grid::new("plant_ptest_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.ptest.code", "Code / medium"),
+                        &txt("gui.plant.ptest.code_tip", "B31.3 hydrotest is 1.5·P·S_T/S; B31.3 pneumatic 1.1·P; B31.1 hydrotest 1.5·P and pneumatic 1.2·P"),
+                    );
+                    ui.horizontal(|ui| {
+                        let code = pressure_test::TestCode::ALL[self.inputs.plant_piping.pressure_test.code.min(1)];
+                        egui::ComboBox::from_id_source("plant_ptest_code")
+                            .selected_text(code.label())
+                            .show_ui(ui, |ui| {
+                                for (i, c) in pressure_test::TestCode::ALL.iter().enumerate() {
+                                    ui.selectable_value(&mut self.inputs.plant_piping.pressure_test.code, i, c.label());
+                                }
+                            });
+                        let medium = pressure_test::TestMedium::ALL[self.inputs.plant_piping.pressure_test.medium.min(1)];
+                        egui::ComboBox::from_id_source("plant_ptest_medium")
+                            .selected_text(txt(medium.i18n_key(), medium.label()))
+                            .show_ui(ui, |ui| {
+                                for (i, m) in pressure_test::TestMedium::ALL.iter().enumerate() {
+                                    ui.selectable_value(&mut self.inputs.plant_piping.pressure_test.medium, i, txt(m.i18n_key(), m.label()));
+                                }
+                            });
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.ptest.design", "Design P [bar(g)] / T [°C]"),
+                        &txt("gui.plant.ptest.design_tip", "Shared with the corrosion card; material is the one selected above"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.design_pressure_bar_g).speed(0.5));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line.design_temp_c).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.ptest.test", "Test T [°C] / hold [min]"),
+                        &txt("gui.plant.ptest.test_tip", "Metal temperature during the test and planned hold time at test pressure (minimum 10 min)"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.pressure_test.temp_c).speed(1.0));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.pressure_test.hold_min).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.ptest.volume", "Test volume [m³]"),
+                        &txt("gui.plant.ptest.volume_tip", "Internal volume of the test section; used for the pneumatic stored energy"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.pressure_test.volume_m3).speed(0.1));
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.ptest.run", "Calculate test pressure")) {
+                let medium = pressure_test::TestMedium::ALL[self.inputs.plant_piping.pressure_test.medium.min(1)];
+                let input = pressure_test::TestPressureInput {
+                    code: pressure_test::TestCode::ALL[self.inputs.plant_piping.pressure_test.code.min(1)],
+                    medium,
+                    design_pressure_bar_g: self.inputs.plant_piping.line.design_pressure_bar_g,
+                    design_temp_c: self.inputs.plant_piping.line.design_temp_c,
+                    test_temp_c: self.inputs.plant_piping.pressure_test.temp_c,
+                    material: self.inputs.plant_piping.line.material.clone(),
+                    planned_hold_minutes: self.inputs.plant_piping.pressure_test.hold_min,
+                };
+                let outcome = pressure_test::test_pressure(&input).and_then(|res| {
+                    let energy = match medium {
+                        pressure_test::TestMedium::Pneumatic => {
+                            Some(pressure_test::pneumatic_stored_energy(&pressure_test::StoredEnergyInput {
+                                test_pressure_bar_g: res.test_pressure_bar_g,
+                                volume_m3: self.inputs.plant_piping.pressure_test.volume_m3,
+                                gamma: 1.4,
+                                ambient_pressure_bar_abs: site_atmosphere().bar_abs(),
+                            })?)
+                        }
+                        pressure_test::TestMedium::Hydrostatic => None,
+                    };
+                    Ok((res, energy))
+                });
+                self.plant_test_result = Some(match outcome {
+                    Ok((res, energy)) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.ptest.result",
+                                "P_T = {factor} × {ratio} × P = {pt} bar(g), hold ≥ {hold} min, examine at {exam} bar(g)",
+                            ),
+                            &[
+                                ("factor", fmt_num(res.factor, 1)),
+                                ("ratio", fmt_num(res.stress_ratio, 3)),
+                                ("pt", fmt_qty(res.test_pressure_bar_g, QuantityKind::Pressure)),
+                                ("hold", fmt_num(res.min_hold_minutes, 0)),
+                                ("exam", fmt_qty(res.examination_pressure_bar_g, QuantityKind::Pressure)),
+                            ],
+                        );
+                        if !res.pressure_steps_bar_g.is_empty() {
+                            let steps: Vec<String> =
+                                res.pressure_steps_bar_g.iter().map(|p| fmt_num(*p, 2)).collect();
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt("gui.plant.ptest.steps", "Pressurise in steps [bar(g)]: {steps}"),
+                                &[("steps", steps.join(" → "))],
+                            ));
+                        }
+                        if let Some(e) = energy {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.plant.ptest.energy",
+                                    "Stored energy {e} kJ (TNT {tnt} kg), exclusion radius {r} m",
+                                ),
+                                &[
+                                    ("e", fmt_num(e.energy_j / 1000.0, 0)),
+                                    ("tnt", fmt_qty(e.tnt_equivalent_kg, QuantityKind::Mass)),
+                                    ("r", fmt_qty(e.exclusion_radius_m, QuantityKind::Length)),
+                                ],
+                            ));
+                            for g in e.guidance {
+                                out.push_str("\n⚠ ");
+                                out.push_str(&g);
+                            }
+                        }
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.ptest.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(res) = &self.plant_test_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 충수량·만수 중량 카드.
+    fn ui_line_fill<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.fill.heading", "Line fill volume and water-filled weight"),
+                &txt(
+                    "gui.plant.fill.tip",
+                    "Water to fill a piping system for hydrotest, water-filled weight per metre for support checks, and drain time through a drain valve",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::LineFill)).inner {
+                self.inputs.plant_piping.line_fill = self.input_defaults.plant_piping.line_fill.clone();
+            }
+            egui::Grid::new("plant_fill_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.fill.lines", "Lengths per size"),
+                        &txt(
+                            "gui.plant.fill.lines_tip",
+                            "DN:length [m] pairs separated by commas, e.g. DN100:120, DN50:35 (DN15-DN300, Sch40)",
+                        ),
+                    );
+                    ui.add(egui::TextEdit::singleline(&mut self.inputs.plant_piping.line_fill.lines).desired_width(220.0));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.fill.temp", "Water temperature [°C]"),
+                        &txt("gui.plant.fill.temp_tip", "Fill water temperature; sets the water density (IF97)"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line_fill.temp_c).speed(1.0).clamp_range(1.0..=99.0));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.fill.drain", "Drain valve Cv / head [m]"),
+                        &txt(
+                            "gui.plant.fill.drain_tip",
+                            "Drain valve Cv (US) and static head from the high point to the drain; Cv 0 skips the drain time",
+                        ),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line_fill.drain_cv).speed(0.5).clamp_range(0.0..=f64::MAX));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.line_fill.head_m).speed(0.5).clamp_range(0.0..=f64::MAX));
+                    });
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.fill.run", "Calculate fill volume")) {
+                let outcome = line_fill::parse_segments(&self.inputs.plant_piping.line_fill.lines).and_then(|segments| {
+                    line_fill::line_fill(&line_fill::LineFillInput {
+                        segments,
+                        water_temp_c: self.inputs.plant_piping.line_fill.temp_c,
+                        steel_density_kg_per_m3: line_fill::CARBON_STEEL_DENSITY,
+                        drain: (self.inputs.plant_piping.line_fill.drain_cv > 0.0).then_some(line_fill::DrainValve {
+                            cv: self.inputs.plant_piping.line_fill.drain_cv,
+                            static_head_m: self.inputs.plant_piping.line_fill.head_m,
+                        }),
+                    })
+                });
+                self.plant_fill_volume_m3 = outcome.as_ref().ok().map(|r| r.volume_m3);
+                self.plant_fill_result = Some(match outcome {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.fill.result",
+                                "Fill volume {v} m³ over {l} m, water {mw} kg ({rho} kg/m³)\nSteel {ms} kg, water-filled total {mt} kg, heaviest line {wmax} kg/m",
+                            ),
+                            &[
+                                ("v", fmt_qty(res.volume_m3, QuantityKind::Volume)),
+                                ("l", fmt_qty(res.total_length_m, QuantityKind::Length)),
+                                ("mw", fmt_qty(res.water_mass_kg, QuantityKind::Mass)),
+                                ("rho", fmt_num(res.water_density_kg_per_m3, 1)),
+                                ("ms", fmt_qty(res.steel_mass_kg, QuantityKind::Mass)),
+                                ("mt", fmt_qty(res.filled_mass_kg, QuantityKind::Mass)),
+                                ("wmax", fmt_num(res.max_filled_kg_per_m, 1)),
+                            ],
+                        );
+                        for s in &res.segments {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.plant.fill.segment",
+                                    "DN{dn} × {l} m: {v} m³, {ws} kg/m empty, {wf} kg/m filled",
+                                ),
+                                &[
+                                    ("dn", s.dn.to_string()),
+                                    ("l", fmt_qty(s.length_m, QuantityKind::Length)),
+                                    ("v", fmt_qty(s.volume_m3, QuantityKind::Volume)),
+                                    ("ws", fmt_num(s.steel_kg_per_m, 1)),
+                                    ("wf", fmt_num(s.filled_kg_per_m, 1)),
+                                ],
+                            ));
+                        }
+                        if let Some(d) = &res.drain {
+                            out.push('\n');
+                            out.push_str(&fill_template(
+                                &txt(
+                                    "gui.plant.fill.drain_result",
+                                    "Drain Kv {kv}: initial flow {q} m³/h, drain time ≈ {t} h",
+                                ),
+                                &[
+                                    ("kv", fmt_num(d.kv, 2)),
+                                    ("q", fmt_num(d.initial_flow_m3_per_h, 2)),
+                                    ("t", fmt_num(d.drain_time_h, 2)),
+                                ],
+                            ));
+                        }
+                        push_warnings(&mut out, &self.tr, &res.warnings);
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.fill.error", "Error: {e}"),
+                        &[("e", error_text(&self.tr, e))],
+                    ),
+                });
+            }
+            if let Some(v) = self.plant_fill_volume_m3 {
+                if ui
+                    .small_button(txt("gui.plant.fill.use_volume", "Use as test volume"))
+                    .on_hover_text(txt(
+                        "gui.plant.fill.use_volume_tip",
+                        "Copy the fill volume into the hydrotest / pneumatic test card",
+                    ))
+                    .clicked()
+                {
+                    self.inputs.plant_piping.pressure_test.volume_m3 = v;
+                }
+            }
+            if let Some(res) = &self.plant_fill_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 산업용 가스 물성 카드.
+    fn ui_gas_properties<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.gasprop.heading", "Industrial gas properties"),
+                &txt(
+                    "gui.plant.gasprop.tip",
+                    "Real-gas Z, density, cp/cv, k and viscosity of common gases (Peng-Robinson) for valve, orifice and blowdown sizing",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::GasProperties)).inner {
+                self.inputs.plant_piping.gas_properties = self.input_defaults.plant_piping.gas_properties.clone();
+            }
+            egui::Grid::new("plant_gasprop_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.gasprop.gas", "Gas"),
+                        &txt("gui.plant.gasprop.gas_tip", "Air and NG (natural gas) use pseudo-critical constants"),
+                    );
+                    let idx = self.inputs.plant_piping.gas_properties.gas.min(gas_properties::Gas::ALL.len() - 1);
+                    egui::ComboBox::from_id_source("plant_gas_kind")
+                        .selected_text(gas_properties::Gas::ALL[idx].formula())
+                        .show_ui(ui, |ui| {
+                            for (i, g) in gas_properties::Gas::ALL.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.plant_piping.gas_properties.gas, i, g.formula());
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.gasprop.state", "P [bar(a)] / T [°C]"),
+                        &txt("gui.plant.gasprop.state_tip", "Gas state at the valve or orifice inlet"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.gas_properties.p_bar_abs).speed(0.1));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.gas_properties.t_c).speed(1.0));
+                    });
+                    ui.end_row();
+                });
+            ui.horizontal(|ui| {
+                if run_button(ui, txt("gui.plant.gasprop.run", "Calculate properties")) {
+                    let gas = gas_properties::Gas::ALL[self.inputs.plant_piping.gas_properties.gas.min(gas_properties::Gas::ALL.len() - 1)];
+                    self.plant_gas_result = Some(
+                        match gas_properties::gas_properties(gas, self.inputs.plant_piping.gas_properties.p_bar_abs, self.inputs.plant_piping.gas_properties.t_c) {
+                            Ok(res) => {
+                                let mut out = fill_template(
+                                    &txt(
+                                        "gui.plant.gasprop.result",
+                                        "Z {z}, density {rho} kg/m³, M {m} kg/kmol\ncp {cp} / cv {cv} kJ/kgK, k {k}, isentropic exponent {n}\nViscosity {mu} µPa·s, speed of sound {a} m/s (Tr {tr}, Pr {pr})",
+                                    ),
+                                    &[
+                                        ("z", fmt_num(res.z, 4)),
+                                        ("rho", fmt_num(res.density_kg_per_m3, 3)),
+                                        ("m", fmt_num(res.molar_mass_kg_per_kmol, 3)),
+                                        ("cp", fmt_num(res.cp_kj_per_kgk, 4)),
+                                        ("cv", fmt_num(res.cv_kj_per_kgk, 4)),
+                                        ("k", fmt_num(res.k, 3)),
+                                        ("n", fmt_num(res.isentropic_exponent, 3)),
+                                        ("mu", fmt_num(res.viscosity_pa_s * 1e6, 2)),
+                                        ("a", fmt_qty(res.speed_of_sound_m_per_s, QuantityKind::Velocity)),
+                                        ("tr", fmt_num(res.reduced_temperature, 2)),
+                                        ("pr", fmt_num(res.reduced_pressure, 3)),
+                                    ],
+                                );
+                                for w in res.warnings {
+                                    out.push_str("\n⚠ ");
+                                    out.push_str(&w);
+                                }
+                                out
+                            }
+                            Err(e) => fill_template(
+                                &txt("gui.plant.gasprop.error", "Error: {e}"),
+                                &[("e", error_text(&self.tr, e))],
+                            ),
+                        },
+                    );
+                }
+                if ui
+                    .button(txt("gui.plant.gasprop.to_blowdown", "→ blowdown"))
+                    .on_hover_text(txt(
+                        "gui.plant.gasprop.to_blowdown_tip",
+                        "Use this gas and state as the blowdown initial condition below",
+                    ))
+                    .clicked()
+                {
+                    self.inputs.plant_piping.blowdown.gas = 2 + self.inputs.plant_piping.gas_properties.gas.min(gas_properties::Gas::ALL.len() - 1);
+                    self.inputs.plant_piping.blowdown.p0_bar_abs = self.inputs.plant_piping.gas_properties.p_bar_abs;
+                    self.inputs.plant_piping.blowdown.t0_c = self.inputs.plant_piping.gas_properties.t_c;
+                }
+            });
+            if let Some(res) = &self.plant_gas_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 용기/헤더 블로다운 시간 카드.
+    fn ui_vessel_blowdown<F>(&mut self, ui: &mut egui::Ui, txt: &F)
+    where
+        F: Fn(&str, &str) -> String,
+    {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            heading_with_tip(
+                ui,
+                &txt("gui.plant.blowdown.heading", "Vessel / header blowdown time"),
+                &txt(
+                    "gui.plant.blowdown.tip",
+                    "Pressure vs time of an ideal-gas vessel venting to atmosphere through an orifice or valve, with choked/subsonic transition",
+                ),
+            );
+            if ui.horizontal(|ui| card_toolbar(ui, txt, &mut self.inputs.dashboard, CardId::VesselBlowdown)).inner {
+                self.inputs.plant_piping.blowdown = self.input_defaults.plant_piping.blowdown.clone();
+            }
+            egui::Grid::new("plant_blowdown_grid")
+                .num_columns(2)
+                .spacing([12.0, 8.0])
+                .show(ui, |ui| {
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.gas_label", "Gas"),
+                        &txt("gui.plant.blowdown.gas_tip", "Air (k 1.4), superheated steam approximation (k 1.3, condensation ignored) or a real gas evaluated at the initial state"),
+                    );
+                    let mut gases = vec![
+                        txt("gui.plant.blowdown.gas.air", "Air"),
+                        txt("gui.plant.blowdown.gas.steam", "Steam (ideal gas)"),
+                    ];
+                    for g in gas_properties::Gas::ALL {
+                        gases.push(fill_template(
+                            &txt("gui.plant.blowdown.gas.real", "{gas} (real gas)"),
+                            &[("gas", g.formula().to_string())],
+                        ));
+                    }
+                    egui::ComboBox::from_id_source("plant_bd_gas")
+                        .selected_text(gases[self.inputs.plant_piping.blowdown.gas.min(gases.len() - 1)].clone())
+                        .show_ui(ui, |ui| {
+                            for (i, label) in gases.iter().enumerate() {
+                                ui.selectable_value(&mut self.inputs.plant_piping.blowdown.gas, i, label.clone());
+                            }
+                        });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.volume", "Volume [m³]"),
+                        &txt("gui.plant.blowdown.volume_tip", "Vessel plus connected header volume"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.volume_m3).speed(0.1));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.initial", "Initial P [bar(a)] / T [°C]"),
+                        &txt("gui.plant.blowdown.initial_tip", "Vessel state when the vent opens"),
+                    );
+                    ui.horizontal(|ui| {
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.p0_bar_abs).speed(0.1));
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.t0_c).speed(1.0));
+                    });
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.end", "End pressure [bar(a)]"),
+                        &txt("gui.plant.blowdown.end_tip", "Blowdown is complete at this pressure (≥ 1.01325 atmospheric back pressure)"),
+                    );
+                    ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.end_bar_abs).speed(0.01));
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.adiabatic", "Adiabatic expansion"),
+                        &txt("gui.plant.blowdown.adiabatic_tip", "Checked: gas cools isentropically (fast blowdown); unchecked: isothermal (slow, wall heat)"),
+                    );
+                    ui.checkbox(&mut self.inputs.plant_piping.blowdown.adiabatic, "");
+                    ui.end_row();
+                    label_with_tip(
+                        ui,
+                        &txt("gui.plant.blowdown.use_cv", "Use valve Cv"),
+                        &txt("gui.plant.blowdown.use_cv_tip", "Unchecked: orifice diameter and Cd; checked: valve Cv converted to an equivalent area"),
+                    );
+                    ui.checkbox(&mut self.inputs.plant_piping.blowdown.use_cv, "");
+                    ui.end_row();
+                    if self.inputs.plant_piping.blowdown.use_cv {
+                        label_with_tip(
+                            ui,
+                            &txt("gui.plant.blowdown.cv", "Valve Cv"),
+                            &txt("gui.plant.blowdown.cv_tip", "Rated Cv of the vent/drain valve"),
+                        );
+                        ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.cv).speed(0.1));
+                    } else {
+                        label_with_tip(
+                            ui,
+                            &txt("gui.plant.blowdown.orifice", "Orifice Ø [mm] / Cd"),
+                            &txt("gui.plant.blowdown.orifice_tip", "Restriction orifice bore and discharge coefficient"),
+                        );
+                        ui.horizontal(|ui| {
+                            ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.orifice_mm).speed(0.5));
+                            ui.add(egui::DragValue::new(&mut self.inputs.plant_piping.blowdown.cd).speed(0.01));
+                        });
+                    }
+                    ui.end_row();
+                });
+            if run_button(ui, txt("gui.plant.blowdown.run", "Calculate blowdown")) {
+                let gas = match self.inputs.plant_piping.blowdown.gas {
+                    0 => Ok(blowdown::BlowdownGas::air()),
+                    1 => Ok(blowdown::BlowdownGas::superheated_steam()),
+                    i => gas_properties::gas_properties(
+                        gas_properties::Gas::ALL[(i - 2).min(gas_properties::Gas::ALL.len() - 1)],
+                        self.inputs.plant_piping.blowdown.p0_bar_abs,
+                        self.inputs.plant_piping.blowdown.t0_c,
+                    )
+                    .map(|props| props.blowdown_gas()),
+                };
+                let input = gas.map(|gas| blowdown::BlowdownInput {
+                    volume_m3: self.inputs.plant_piping.blowdown.volume_m3,
+                    initial_pressure_bar_abs: self.inputs.plant_piping.blowdown.p0_bar_abs,
+                    initial_temp_c: self.inputs.plant_piping.blowdown.t0_c,
+                    back_pressure_bar_abs: 1.01325,
+                    end_pressure_bar_abs: self.inputs.plant_piping.blowdown.end_bar_abs,
+                    gas,
+                    process: if self.inputs.plant_piping.blowdown.adiabatic {
+                        blowdown::BlowdownProcess::Adiabatic
+                    } else {
+                        blowdown::BlowdownProcess::Isothermal
+                    },
+                    restriction: if self.inputs.plant_piping.blowdown.use_cv {
+                        blowdown::BlowdownRestriction::ValveCv { cv: self.inputs.plant_piping.blowdown.cv }
+                    } else {
+                        blowdown::BlowdownRestriction::Orifice {
+                            diameter_m: self.inputs.plant_piping.blowdown.orifice_mm / 1000.0,
+                            discharge_coefficient: self.inputs.plant_piping.blowdown.cd,
+                        }
+                    },
+                });
+                let result = input.map_err(|e| e.to_string()).and_then(|input| {
+                    blowdown::compute_blowdown(input).map_err(|e| e.to_string())
+                });
+                self.plant_bd_result = Some(match result {
+                    Ok(res) => {
+                        let mut out = fill_template(
+                            &txt(
+                                "gui.plant.blowdown.result",
+                                "Inventory {m0} kg → {m1} kg, initial flow {w} kg/s; choked until {pu} bar(a) ({tc} s), total {tt} s ({tmin} min), final {tf} °C",
+                            ),
+                            &[
+                                ("m0", fmt_qty(res.initial_mass_kg, QuantityKind::Mass)),
+                                ("m1", fmt_qty(res.final_mass_kg, QuantityKind::Mass)),
+                                ("w", fmt_num(res.initial_mass_flow_kg_per_s, 3)),
+                                ("pu", fmt_qty(res.unchoke_pressure_bar_abs, QuantityKind::Pressure)),
+                                ("tc", fmt_num(res.choked_duration_s, 0)),
+                                ("tt", fmt_num(res.total_duration_s, 0)),
+                                ("tmin", fmt_num(res.total_duration_s / 60.0, 1)),
+                                ("tf", fmt_qty(res.final_temperature_c, QuantityKind::Temperature)),
+                            ],
+                        );
+                        let step = (res.profile.len() / 5).max(1);
+                        for (i, pt) in res.profile.iter().enumerate() {
+                            if i % step == 0 || i + 1 == res.profile.len() {
+                                out.push_str(&format!(
+                                    "\n  t = {} s: {} bar(a), {} °C, {} kg/s",
+                                    fmt_num(pt.time_s, 0),
+                                    fmt_qty(pt.pressure_bar_abs, QuantityKind::Pressure),
+                                    fmt_qty(pt.temperature_c, QuantityKind::Temperature),
+                                    fmt_num(pt.mass_flow_kg_per_s, 3),
+                                ));
+                            }
+                        }
+                        for w in res.warnings {
+                            out.push_str("\n⚠ ");
+                            out.push_str(&w);
+                        }
+                        out
+                    }
+                    Err(e) => fill_template(
+                        &txt("gui.plant.blowdown.error", "Error: {e}"),
+                        &[("e", e.to_string())],
+                    ),
+                });
+            }
+            if let Some(res) = &self.plant_bd_result {
+                result_block(ui, txt, res, false);
+            }
+        });
+    }
+
+    /// 응축수 회수 배관(트랩 토출/펌프 압송/중력) 사이징 화면.
+    fn ui_condensate(&mut self, ui: &mut egui::Ui) {
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+        heading_with_tip(
+            ui,
+            &txt("gui.condensate.heading", "Condensate Recovery"),
+            &txt(
+                "gui.condensate.tip",
+                "Flash steam and return line sizing for hot condensate",
+            ),
+        );
+        ui.add_space(8.0);
+
+        self.ui_card(ui, &txt, CardId::ReturnLine);
+
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::TankHeating);
+
+        ui.add_space(10.0);
+        self.ui_card(ui, &txt, CardId::Sparger);
+    }
+
+}
+
+impl App for GuiApp {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut Frame) {
+        // 최초 1회 화면 크기 조정
+        if self.apply_initial_view_size {
+            if let Some(screen) = ctx.input(|i| {
+                let r = i.screen_rect();
+                if r.is_positive() {
+                    Some(r.size())
+                } else {
+                    None
+                }
+            }) {
+                let target = egui::vec2((screen.x * 0.60).max(1000.0), (screen.y * 0.60).max(700.0));
+                ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(target));
+                self.apply_initial_view_size = false;
+            }
+        }
+
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(if self.always_on_top {
+            egui::WindowLevel::AlwaysOnTop
+        } else {
+            egui::WindowLevel::Normal
+        }));
+
+        // 키보드: Ctrl+1..9 탭 전환, Ctrl+E 결과 내보내기, Ctrl+K 명령 팔레트, 카드 입력칸 Enter = 계산
+        capture_enter_focus(ctx);
+        const TAB_KEYS: [egui::Key; 9] = [
+            egui::Key::Num1,
+            egui::Key::Num2,
+            egui::Key::Num3,
+            egui::Key::Num4,
+            egui::Key::Num5,
+            egui::Key::Num6,
+            egui::Key::Num7,
+            egui::Key::Num8,
+            egui::Key::Num9,
+        ];
+        let (tab_shortcut, export_shortcut, palette_shortcut) = ctx.input_mut(|i| {
+            let tab = TAB_KEYS
+                .iter()
+                .position(|k| i.consume_key(egui::Modifiers::COMMAND, *k));
+            (
+                tab,
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::E),
+                i.consume_key(egui::Modifiers::COMMAND, egui::Key::K),
+            )
+        });
+        if palette_shortcut {
+            self.palette_open = !self.palette_open;
+            self.palette_query.clear();
+            self.palette_selected = 0;
+        }
+        if let Some(idx) = tab_shortcut {
+            self.tab = Tab::NAV_ORDER[idx];
+        }
+        if export_shortcut {
+            self.export_tab_results();
+        }
+
+        // 테마 + 투명도 적용, 라벨 복사 방지 스타일.
+        // 스타일은 Context 공용이라 분리된 뷰포트 창에도 같은 테마가 적용된다.
+        let system_dark = frame
+            .info()
+            .system_theme
+            .is_none_or(|t| t == eframe::Theme::Dark);
+        let mut style = (*ctx.style()).clone();
+        style.interaction.selectable_labels = false;
+        RESULT_SELECTABLE.with(|c| c.set(self.selectable_results));
+        TYPED_QUANTITY.with(|c| c.set(self.typed_quantity_fields));
+        style.visuals = theme_visuals(&self.theme, system_dark);
+        style.visuals.window_fill = style.visuals.window_fill.linear_multiply(self.window_alpha);
+        style.visuals.panel_fill = style.visuals.panel_fill.linear_multiply(self.window_alpha);
+        ctx.set_style(style);
+
+        let tr = self.tr.clone();
+        let txt = move |key: &str, default: &str| {
+            tr.lookup(key).unwrap_or_else(|| default.to_string())
+        };
+
+        // 분리 창 (계산기 탭, 진공 포화온도 표)
+        self.show_detached_windows(ctx, &txt);
+        if ctx.input(|i| i.viewport().close_requested()) {
+            self.save_window_layout();
+            self.save_inputs();
+        }
+
+        // 상단 바
+        egui::TopBottomPanel::top("top_bar").show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.heading(txt("gui.nav.app_title", "Steam Engineering Toolbox"));
+                ui.label(" | Desktop GUI");
+                ui.separator();
+                if ui.button(txt("gui.formula.button", "Formula reference")).clicked() {
+                    self.show_formula_modal = true;
+                }
+                if ui.button(txt("gui.settings.title", "Settings")).clicked() {
+                    self.show_settings_modal = true;
+                }
+                if ui.button(txt("gui.about.title", "Help / About")).clicked() {
+                    self.show_help_modal = true;
+                }
+                ui.separator();
+                if ui
+                    .button(txt("gui.export.button", "Export results"))
+                    .on_hover_text(txt("gui.export.tip", "Save this tab's results to a text file (Ctrl+E)"))
+                    .clicked()
+                {
+                    self.export_tab_results();
+                }
+                if let Some(msg) = &self.export_status {
+                    ui.small(msg);
+                }
+            });
+        });
+
+        // 설정 모달
+        if self.show_settings_modal {
+            let mut new_unit_system = self.config.unit_system;
+            let mut site_changed = false;
+            let mut profile_to_apply: Option<String> = None;
+            let mut reload_requested = false;
+            egui::Window::new(txt("gui.settings.title", "Program Settings"))
+                .collapsible(false)
+                .resizable(true)
+                .open(&mut self.show_settings_modal)
+                .show(ctx, |ui| {
+                    ui.heading(txt("gui.settings.general", "General"));
+                    ui.separator();
+                    ui.label(txt("gui.settings.unit_preset", "Unit system preset"));
+                    ui.horizontal(|ui| {
+                        for (label, us) in [
+                            ("SI(Bar)", config::UnitSystem::SIBar),
+                            ("SI(Pa)", config::UnitSystem::SI),
+                            ("MKS", config::UnitSystem::MKS),
+                            ("Imperial", config::UnitSystem::Imperial),
+                        ] {
+                            ui.selectable_value(&mut new_unit_system, us, label);
+                        }
+                    });
+                    ui.separator();
+                    ui.label(txt("gui.settings.ui_scale", "UI scale"));
+                    let scale_slider = egui::Slider::new(&mut self.ui_scale, 0.8..=1.6).suffix(" x");
+                    if ui.add(scale_slider).changed() {
+                        ctx.set_pixels_per_point(self.ui_scale);
+                    }
+                    ui.separator();
+                    ui.checkbox(&mut self.always_on_top, txt("gui.settings.always_on_top", "Always on top"));
+                    ui.checkbox(
+                        &mut self.selectable_results,
+                        txt("gui.settings.selectable_results", "Allow selecting result text"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.settings.selectable_results_tip",
+                        "Result areas only; other labels stay non-selectable.",
+                    ));
+                    ui.checkbox(
+                        &mut self.typed_quantity_fields,
+                        txt("gui.settings.typed_quantity", "Type units into input fields"),
+                    )
+                    .on_hover_text(txt(
+                        "gui.settings.typed_quantity_tip",
+                        "Fixed-unit fields (pipe loss, Rankine cycle, actuator) take a number with its unit, e.g. 150 psig, 10 barg or 4in, and convert it into the field unit.",
+                    ));
+                    ui.separator();
+                    ui.label(txt("gui.settings.theme.label", "Theme"));
+                    ui.horizontal(|ui| {
+                        for (mode, key, default) in [
+                            (config::ThemeMode::System, "gui.settings.theme.system", "System"),
+                            (config::ThemeMode::Light, "gui.settings.theme.light", "Light"),
+                            (config::ThemeMode::Dark, "gui.settings.theme.dark", "Dark"),
+                            (config::ThemeMode::SoftBlue, "gui.settings.theme.soft_blue", "Soft blue"),
+                        ] {
+                            ui.selectable_value(&mut self.theme.mode, mode, txt(key, default));
+                        }
+                    });
+                    for (slot, key, default, fallback) in [
+                        (&mut self.theme.accent, "gui.settings.theme.accent", "Custom accent color", [30, 90, 170]),
+                        (&mut self.theme.background, "gui.settings.theme.background", "Custom background color", [240, 240, 240]),
+                    ] {
+                        ui.horizontal(|ui| {
+                            let mut custom = slot.is_some();
+                            if ui.checkbox(&mut custom, txt(key, default)).changed() {
+                                *slot = custom.then(|| config::format_hex_color(fallback));
+                            }
+                            if let Some(hex) = slot.as_mut() {
+                                let mut rgb = config::parse_hex_color(hex).unwrap_or(fallback);
+                                if ui.color_edit_button_srgb(&mut rgb).changed() {
+                                    *hex = config::format_hex_color(rgb);
+                                }
+                            }
+                        });
+                    }
+                    ui.checkbox(
+                        &mut self.theme.high_contrast,
+                        txt("gui.settings.theme.high_contrast", "High contrast"),
+                    );
+                    ui.separator();
+                    ui.label(txt("gui.settings.alpha", "Window transparency"));
+                    ui.add(egui::Slider::new(&mut self.window_alpha, 0.3..=1.0).text("alpha"));
+
+                    ui.separator();
+                    ui.label(txt("gui.settings.number.title", "Number format"));
+                    let nf = &mut self.number_format_input;
+                    egui::Grid::new("number_format_grid").num_columns(2).show(ui, |ui| {
+                        let mut use_sig = nf.significant_figures.is_some();
+                        ui.checkbox(&mut use_sig, txt("gui.settings.number.sig_figs", "Significant figures"));
+                        let mut sig = nf.significant_figures.unwrap_or(4);
+                        ui.add_enabled(use_sig, egui::DragValue::new(&mut sig).clamp_range(1..=12));
+                        nf.significant_figures = use_sig.then_some(sig);
+                        ui.end_row();
+
+                        ui.label(txt("gui.settings.number.decimal_sep", "Decimal separator"));
+                        egui::ComboBox::from_id_source("nf_decimal_sep")
+                            .selected_text(nf.decimal_separator.to_string())
+                            .show_ui(ui, |ui| {
+                                ui.selectable_value(&mut nf.decimal_separator, '.', ". (1.5)");
+                                ui.selectable_value(&mut nf.decimal_separator, ',', ", (1,5)");
+                            });
+                        ui.end_row();
+
+                        ui.label(txt("gui.settings.number.thousands_sep", "Thousands separator"));
+                        let none_label = txt("gui.settings.number.none", "None");
+                        let sep_label = |sep: Option<char>| match sep {
+                            None => none_label.clone(),
+                            Some(' ') => "1 000".to_string(),
+                            Some(c) => format!("1{c}000"),
+                        };
+                        egui::ComboBox::from_id_source("nf_thousands_sep")
+                            .selected_text(sep_label(nf.thousands_separator))
+                            .show_ui(ui, |ui| {
+                                for sep in [None, Some(','), Some('.'), Some(' '), Some('\'')] {
+                                    ui.selectable_value(&mut nf.thousands_separator, sep, sep_label(sep));
+                                }
+                            });
+                        ui.end_row();
+
+                        // 지수 표기 경계는 10의 거듭제곱으로만 고른다.
+                        ui.label(txt("gui.settings.number.sci_above", "Scientific notation at |x| ≥ 10^"));
+                        let mut above_exp = nf.scientific_above.log10().round() as i32;
+                        if ui.add(egui::DragValue::new(&mut above_exp).clamp_range(3..=15)).changed() {
+                            nf.scientific_above = 10f64.powi(above_exp);
+                        }
+                        ui.end_row();
+
+                        ui.label(txt("gui.settings.number.sci_below", "Scientific notation at |x| < 10^"));
+                        let mut below_exp = nf.scientific_below.log10().round() as i32;
+                        if ui.add(egui::DragValue::new(&mut below_exp).clamp_range(-12..=-1)).changed() {
+                            nf.scientific_below = 10f64.powi(below_exp);
+                        }
+                        ui.end_row();
+                    });
+                    ui.label(format!(
+                        "{} {}",
+                        txt("gui.settings.number.preview", "Preview:"),
+                        self.number_format_input.format(1234567.891, 3)
+                    ));
+
+                    ui.separator();
+                    ui.label(txt("gui.settings.site.title", "Site conditions"));
+                    let site = &mut self.site_input;
+                    egui::Grid::new("site_grid").num_columns(2).show(ui, |ui| {
+                        ui.label(txt("gui.settings.site.elevation", "Elevation [m]"));
+                        ui.add(egui::DragValue::new(&mut site.elevation_m).speed(10.0).clamp_range(-500.0..=5000.0));
+                        ui.end_row();
+
+                        let mut use_baro = site.barometric_pressure_bar_abs.is_some();
+                        ui.checkbox(&mut use_baro, txt("gui.settings.site.barometric", "Barometric pressure [bar(a)]"))
+                            .on_hover_text(txt(
+                                "gui.settings.site.barometric_tip",
+                                "Measured local pressure; when off it is estimated from elevation (ISA)",
+                            ));
+                        let mut baro = site
+                            .barometric_pressure_bar_abs
+                            .unwrap_or_else(|| Atmosphere::from_elevation_m(site.elevation_m).bar_abs());
+                        ui.add_enabled(use_baro, egui::DragValue::new(&mut baro).speed(0.001).clamp_range(0.5..=1.1));
+                        site.barometric_pressure_bar_abs = use_baro.then_some(baro);
+                        ui.end_row();
+
+                        ui.label(txt("gui.settings.site.dry_bulb", "Design dry bulb [°C]"));
+                        ui.add(egui::DragValue::new(&mut site.design_dry_bulb_c).speed(0.5));
+                        ui.end_row();
+
+                        ui.label(txt("gui.settings.site.wet_bulb", "Design wet bulb [°C]"));
+                        ui.add(egui::DragValue::new(&mut site.design_wet_bulb_c).speed(0.5));
+                        ui.end_row();
+                    });
+                    ui.label(fill_template(
+                        &txt("gui.settings.site.atm", "Atmospheric pressure used for gauge values: {p} bar(a)"),
+                        &[("p", fmt_num(self.site_input.atmosphere().bar_abs(), 4))],
+                    ));
+
+                    ui.separator();
+                    ui.label(txt("gui.settings.profile.title", "Profile (per-plant defaults)"));
+                    ui.horizontal(|ui| {
+                        egui::ComboBox::from_id_source("profile_choice")
+                            .selected_text(if self.profile_choice.is_empty() {
+                                txt("gui.settings.profile.none", "(none)")
+                            } else {
+                                self.profile_choice.clone()
+                            })
+                            .show_ui(ui, |ui| {
+                                for name in self.config.profile_names() {
+                                    ui.selectable_value(&mut self.profile_choice, name.clone(), name);
+                                }
+                            });
+                        if ui
+                            .add_enabled(
+                                !self.profile_choice.is_empty(),
+                                egui::Button::new(txt("gui.settings.profile.apply", "Apply")),
+                            )
+                            .clicked()
+                        {
+                            profile_to_apply = Some(self.profile_choice.clone());
+                        }
+                    });
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.profile_new_name)
+                                .hint_text(txt("gui.settings.profile.name_hint", "Profile name")),
+                        );
+                        let name = self.profile_new_name.trim().to_string();
+                        if ui
+                            .add_enabled(
+                                !name.is_empty(),
+                                egui::Button::new(txt("gui.settings.profile.save", "Save current as profile")),
+                            )
+                            .on_hover_text(txt(
+                                "gui.settings.profile.save_tip",
+                                "Stores language, unit system, site conditions, fluid and steam cost defaults",
+                            ))
+                            .clicked()
+                        {
+                            self.config.language = self.lang_input.clone();
+                            self.config.site = self.site_input.clone();
+                            self.config.save_profile(&name);
+                            self.profile_choice = name.clone();
+                            self.lang_save_status = Some(match self.config.save() {
+                                Ok(()) => fill_template(
+                                    &txt("gui.settings.profile.saved", "Profile '{name}' saved."),
+                                    &[("name", name)],
+                                ),
+                                Err(e) => format!("Save error: {e}"),
+                            });
+                        }
+                    });
+
+                    ui.separator();
+                    ui.label(txt("gui.settings.font.title", "Font"));
+                    ui.small(fill_template(
+                        &txt("gui.settings.font.current", "Current font: {source}"),
+                        &[("source", self.font_source.clone())],
+                    ));
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::TextEdit::singleline(&mut self.custom_font_path)
+                                .hint_text(txt("gui.settings.font.path_hint", "Font file (.ttf/.ttc/.otf)"))
+                                .desired_width(260.0),
+                        );
+                        if ui.button(txt("gui.settings.font.browse", "Browse…")).clicked() {
+                            if let Some(p) = FileDialog::new()
+                                .add_filter("Font", &["ttf", "ttc", "otf"])
+                                .pick_file()
+                            {
+                                self.custom_font_path = p.display().to_string();
+                            }
+                        }
+                        if ui.button(txt("gui.settings.font.load", "Load")).clicked() {
+                            match load_custom_font(ctx, &self.custom_font_path) {
+                                Ok(()) => {
+                                    self.font_source = self.custom_font_path.clone();
+                                    self.font_load_error = None;
+                                }
+                                Err(e) => self.font_load_error = Some(e),
+                            }
+                        }
+                    });
+                    if let Some(e) = &self.font_load_error {
+                        ui.colored_label(ui.visuals().error_fg_color, e);
+                    }
+                    egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.small(txt("gui.settings.font.preview", "Preview"));
+                        ui.label("가나다라 한글 증기 · ABC abc 0123456789");
+                        ui.label("°C ΔP ± ≥ ≤ × ³ ² μ → ⚠ ℹ");
+                        ui.monospace("P=10.0 bar(a)  T=179.9 °C  h=2777 kJ/kg");
+                    });
+
+                    ui.separator();
+                    ui.label(txt("gui.settings.lang", "Language"));
+                    egui::ComboBox::from_id_source("lang_choice")
+                        .selected_text(&self.lang_input)
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.lang_input, "auto".into(), txt("gui.settings.lang.auto", "System"));
+                            ui.selectable_value(&mut self.lang_input, "en-us".into(), "English (US)");
+                            ui.selectable_value(&mut self.lang_input, "ko-kr".into(), "한국어");
+                            ui.selectable_value(&mut self.lang_input, "de-de".into(), "Deutsch");
+                        });
+                    if ui.button(txt("gui.settings.save", "Save settings")).clicked() {
+                        self.config.language = self.lang_input.clone();
+                        self.config.window_alpha = self.window_alpha;
+                        self.config.theme = self.theme.clone();
+                        self.config.selectable_results = self.selectable_results;
+                        self.config.typed_quantity_fields = self.typed_quantity_fields;
+                        self.config.number_format = self.number_format_input.clone();
+                        if self.site_input != self.config.site {
+                            self.config.site = self.site_input.clone();
+                            site_changed = true;
+                        }
+                        // 번역기는 show()가 끝난 뒤 반영한다 (창이 show_settings_modal을 빌리고 있다)
+                        reload_requested = true;
+                        if let Err(e) = self.config.save() {
+                            self.lang_save_status = Some(format!("Save error: {e}"));
+                        } else {
+                            self.lang_save_status = Some(txt("gui.settings.saved", "Saved."));
+                        }
+                    }
+                    if let Some(msg) = &self.lang_save_status {
+                        ui.label(msg);
+                    }
+                });
+            if reload_requested {
+                self.reload_translator();
+            }
+            if new_unit_system != self.config.unit_system {
+                self.config.unit_system = new_unit_system;
+                self.apply_unit_preset(new_unit_system);
+            }
+            if site_changed {
+                self.apply_site_conditions();
+            }
+            if let Some(name) = profile_to_apply {
+                self.lang_save_status = Some(match self.config.apply_profile(&name) {
+                    Ok(()) => {
+                        self.apply_profile_defaults();
+                        match self.config.save() {
+                            Ok(()) => fill_template(
+                                &txt("gui.settings.profile.applied", "Profile '{name}' applied."),
+                                &[("name", name)],
+                            ),
+                            Err(e) => format!("Save error: {e}"),
+                        }
+                    }
+                    Err(e) => format!("{e}"),
+                });
+            }
+        }
+
+        // 도움말 모달
+        if self.show_help_modal {
+            egui::Window::new(txt("gui.about.title", "Help / About"))
+                .collapsible(false)
+                .resizable(true)
+                .open(&mut self.show_help_modal)
+                .show(ctx, |ui| {
+                    ui.heading(txt("gui.about.app", "Offline calculator for steam/water/piping/valves"));
+                    ui.label(txt("gui.about.version", "Version: 0.1a"));
+                    ui.label(txt("gui.about.author", "Author: ruassu"));
+                    ui.separator();
+                    ui.label(txt("gui.about.units.title", "Unit guide"));
+                    ui.label(txt("gui.about.units.mmHg", "- Pressure mmHg: gauge basis (0=atm, -760mmHg=vacuum)"));
+                    ui.label(txt("gui.about.units.ga", "- g=gauge, a=absolute"));
+                    ui.label(txt("gui.about.hint", "Adjust units/font in settings if you see issues."));
+                    ui.separator();
+                    ui.label(txt("gui.about.keys.title", "Keyboard shortcuts"));
+                    ui.label(txt("gui.about.keys.tabs", "- Ctrl+1..9: switch menu (in menu order)"));
+                    ui.label(txt("gui.about.keys.focus", "- Tab / Shift+Tab: move between inputs of a card, top to bottom, then its calculate button"));
+                    ui.label(txt("gui.about.keys.enter", "- Enter in an input: run that card's calculation"));
+                    ui.label(txt("gui.about.keys.export", "- Ctrl+E: export the current tab's results"));
+                    ui.label(txt("gui.about.keys.palette", "- Ctrl+K: command palette (search cards, menus and settings)"));
+                });
+        }
+
+        if self.show_formula_modal {
+            egui::Window::new(txt("gui.formula.title", "Formula reference"))
+                .collapsible(true)
+                .resizable(true)
+                .open(&mut self.show_formula_modal)
+                .show(ctx, |ui| {
+                    ui.style_mut().wrap = Some(true);
+                    ui.heading(txt("gui.formula.steam", "Steam: IF97 saturation/superheat; mmHg treated as gauge."));
+                    ui.separator();
+                    ui.heading(txt("gui.formula.pipe_sizing", "Pipe sizing: mdot = rho * Q, v = Q/A, Re = rho * v * D / mu."));
+                    ui.label(txt("gui.formula.pipe_loss", "Pressure loss: ΔP = f (L/D) (rho v^2/2) + ΣK (rho v^2/2); f=64/Re (laminar) else Haaland/Petukhov."));
+                    ui.separator();
+                    ui.heading(txt("gui.formula.valve", "Valve Cv/Kv: Q = Cv * sqrt(ΔP / SG) (incompressible); mass = rho*Q."));
+                    ui.label(txt("gui.formula.orifice", "Orifice/nozzle: incompressible Q = Cd·A·√(2·ΔP/ρ); compressible uses Y·C(1-β^4)^-0.5."));
+                    ui.label(txt("gui.formula.pressure_rating", "Pressure rating: thin-wall (Barlow) vs thick-wall (Lame) using allowable stress S(T), weld eff. E, design factor F, CA, mill tolerance."));
+                    ui.label(txt("gui.formula.expansion", "Thermal expansion: ΔL = α * L * ΔT."));
+                    ui.separator();
+                    ui.heading(txt("gui.formula.boiler_basic", "Boiler basic eff.: η = (m_s*h_s - m_fw*h_fw) / (Fuel_LHV*Fuel_flow)."));
+                    ui.label(txt("gui.formula.boiler_ptc", "PTC: include flue-gas sensible losses, excess air, radiation, blowdown enthalpy."));
+                    ui.separator();
+                    ui.heading(txt("gui.formula.cooling_cond", "Condenser/vacuum: LMTD with Tsat(P) from IF97; Q = m·cp·ΔT; mmHg gauge = vacuum."));
+                    ui.label(txt("gui.formula.cooling_ct", "Cooling tower: Range = T_hot - T_cold, Approach = T_cold - T_wb; simple heat balance."));
+                    ui.label(txt("gui.formula.npsh", "NPSH: NPSHa = (Psuction - Pvap)/ρg + z - h_loss; compare to NPSHr."));
+                    ui.label(txt("gui.formula.drain", "Drain/reheater: LMTD; UA or Area/U to compute Q_shell and Q_tube, check imbalance."));
+                });
+        }
+
+        // 좌측 네비 + 본문
+        egui::SidePanel::left("nav")
+            .resizable(true)
+            .min_width(140.0)
+            .default_width(200.0)
+            .max_width(400.0)
+            .show(ctx, |ui| {
+                self.ui_nav(ui);
+            });
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, |ui| {
+                    let tab = self.tab;
+                    if self.is_detached(Panel::Tab(tab)) {
+                        ui.label(txt(
+                            "gui.nav.detached_here",
+                            "This calculator is open in a separate window.",
+                        ));
+                        if ui.button(txt("gui.nav.attach", "Bring back to main window")).clicked() {
+                            self.close_detached(Panel::Tab(tab));
+                        }
+                    } else {
+                        self.ui_tab(ui, tab);
+                    }
+                });
+        });
+        // 이번 프레임에 제목을 못 찾았으면 버린다 (다음에 엉뚱한 곳으로 스크롤하지 않도록)
+        SCROLL_TARGET.with(|t| t.borrow_mut().take());
+        self.ui_palette(ctx, &txt);
+    }
+}
+
+fn quantity_options() -> Vec<(QuantityKind, &'static str)> {
+    vec![
+        (QuantityKind::Temperature, "온도"),
+        (QuantityKind::TemperatureDifference, "온도차"),
+        (QuantityKind::Pressure, "압력"),
+        (QuantityKind::Length, "길이"),
+        (QuantityKind::Area, "면적"),
+        (QuantityKind::Volume, "체적"),
+        (QuantityKind::Velocity, "속도"),
+        (QuantityKind::Mass, "질량"),
+        (QuantityKind::Viscosity, "점도"),
+        (QuantityKind::Energy, "에너지"),
+        (QuantityKind::HeatTransferCoeff, "열전달율"),
+        (QuantityKind::ThermalConductivity, "열전도율"),
+        (QuantityKind::SpecificEnthalpy, "비엔탈피"),
+    ]
+}
+
+fn kind_label(kind: QuantityKind) -> &'static str {
+    quantity_options()
+        .into_iter()
+        .find(|(k, _)| *k == kind)
+        .map(|(_, l)| l)
+        .unwrap_or("미정")
+}
+
+fn default_units_for_kind(kind: QuantityKind) -> (&'static str, &'static str) {
+    match kind {
+        QuantityKind::Temperature => ("C", "K"),
+        QuantityKind::TemperatureDifference => ("C", "K"),
+        QuantityKind::Pressure => ("bar", "kPa"),
+        QuantityKind::Length => ("m", "ft"),
+        QuantityKind::Area => ("m2", "ft2"),
+        QuantityKind::Volume => ("m3", "l"),
+        QuantityKind::Velocity => ("m/s", "km/h"),
+        QuantityKind::Mass => ("kg", "lb"),
+        QuantityKind::Viscosity => ("Pa·s", "cps"),
+        QuantityKind::Energy => ("J", "kJ"),
+        QuantityKind::HeatTransferCoeff => ("W/m2K", "Btu/h-ft2-F"),
+        QuantityKind::ThermalConductivity => ("W/mK", "Btu/h-ft-F"),
+        QuantityKind::SpecificEnthalpy => ("kJ/kg", "kcal/kg"),
+    }
+}
+
+fn unit_label(code: &str, kind: QuantityKind) -> String {
+    for (label, c) in custom_units::builtin_unit_options(kind) {
+        if code.eq_ignore_ascii_case(c) {
+            return label.to_string();
+        }
+    }
+    code.to_string()
+}
+
+/// 단위 콤보 옆 자유 입력 칸. 입력이 바뀌면 `parse_unit_fuzzy`로 찾아 `code`를 바꾸고,
+/// 찾지 못한 입력에는 가장 가까운 단위를 제안하는 안내문을 돌려준다.
+fn unit_text_entry<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    text: &mut String,
+    code: &mut String,
+    options: &[(String, String)],
+) -> Option<String>
+where
+    F: Fn(&str, &str) -> String,
+{
+    let resp = ui.add(
+        egui::TextEdit::singleline(text)
+            .desired_width(72.0)
+            .hint_text(txt("gui.unit.type_hint", "type unit")),
+    );
+    if text.trim().is_empty() {
+        return None;
+    }
+    match quantity_arg::parse_unit_fuzzy(text, options) {
+        Ok(found) => {
+            if resp.changed() {
+                *code = found.to_string();
+            }
+            None
+        }
+        Err(QuantityArgError::UnitSuggestion { unit, suggestion }) => Some(fill_template(
+            &txt("unit.did_you_mean", "Unknown unit '{unit}'. Did you mean '{suggestion}'?"),
+            &[("unit", unit), ("suggestion", suggestion)],
+        )),
+        Err(_) => Some(fill_template(
+            &txt("gui.unit.unknown", "Unknown unit '{unit}'"),
+            &[("unit", text.trim().to_string())],
+        )),
+    }
+}
+
+/// 단위가 정해진 입력 칸. 설정의 "단위 붙여 입력"이 켜져 있으면 [`quantity_field`], 아니면 DragValue.
+fn quantity_input<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    id_source: &str,
+    value: &mut f64,
+    quantity: FieldQuantity,
+    speed: f64,
+) -> egui::Response
+where
+    F: Fn(&str, &str) -> String,
+{
+    if TYPED_QUANTITY.with(Cell::get) {
+        quantity_field(ui, txt, id_source, value, quantity)
+    } else {
+        ui.add(egui::DragValue::new(value).speed(speed))
+    }
+}
+
+/// 숫자와 단위를 함께 받는 입력 칸 ("150 psig", "10 barg", "4in").
+/// Enter를 누르거나 포커스가 빠질 때 `FieldQuantity::parse`로 읽어 칸의 작업 단위 값으로 바꾼다.
+/// 읽지 못하면 입력을 남겨 두고 경고색과 툴팁으로 이유를 보이며, 값은 바꾸지 않는다.
+fn quantity_field<F>(
+    ui: &mut egui::Ui,
+    txt: &F,
+    id_source: &str,
+    value: &mut f64,
+    quantity: FieldQuantity,
+) -> egui::Response
+where
+    F: Fn(&str, &str) -> String,
+{
+    let id = ui.make_persistent_id(id_source);
+    let (text_id, error_id) = (id.with("text"), id.with("error"));
+    let mut text = ui
+        .data_mut(|d| d.get_temp::<String>(text_id))
+        .unwrap_or_else(|| format!("{} {}", plain_number(*value), quantity.unit()));
+    let error = ui.data_mut(|d| d.get_temp::<String>(error_id));
+    let mut edit = egui::TextEdit::singleline(&mut text).id(id).desired_width(110.0);
+    if error.is_some() {
+        edit = edit.text_color(ui.visuals().warn_fg_color);
+    }
+    let mut resp = ui.add(edit);
+    if resp.has_focus() {
+        ui.data_mut(|d| d.insert_temp(text_id, text.clone()));
+    } else if resp.lost_focus() {
+        match quantity.parse(&text, site_atmosphere()) {
+            Ok(v) => {
+                *value = v;
+                ui.data_mut(|d| {
+                    d.remove::<String>(text_id);
+                    d.remove::<String>(error_id);
+                });
+                resp.mark_changed();
+            }
+            Err(e) => {
+                let message = quantity_error_text(txt, &text, e);
+                ui.data_mut(|d| {
+                    d.insert_temp(text_id, text.clone());
+                    d.insert_temp(error_id, message);
+                });
+            }
+        }
+    } else if error.is_none() {
+        // 다른 곳(흐름 붙여넣기, 기본값)에서 바뀐 값을 다시 보인다.
+        ui.data_mut(|d| d.remove::<String>(text_id));
+    }
+    let tip = ui.data_mut(|d| d.get_temp::<String>(error_id)).unwrap_or_else(|| {
+        fill_template(
+            &txt(
+                "gui.quantity_field.hint",
+                "Number with unit, e.g. 150 psig, 10 barg, 4in; converted to {unit}",
+            ),
+            &[("unit", quantity.unit().to_string())],
+        )
+    });
+    resp.on_hover_text(tip)
+}
+
+fn quantity_error_text<F>(txt: &F, input: &str, e: QuantityArgError) -> String
+where
+    F: Fn(&str, &str) -> String,
+{
+    match e {
+        QuantityArgError::UnitSuggestion { unit, suggestion } => fill_template(
+            &txt("unit.did_you_mean", "Unknown unit '{unit}'. Did you mean '{suggestion}'?"),
+            &[("unit", unit), ("suggestion", suggestion)],
+        ),
+        QuantityArgError::UnknownUnit(unit) => fill_template(
+            &txt("gui.unit.unknown", "Unknown unit '{unit}'"),
+            &[("unit", unit)],
+        ),
+        QuantityArgError::InvalidNumber(_) => fill_template(
+            &txt("gui.quantity_field.invalid_number", "No number in '{text}'"),
+            &[("text", input.trim().to_string())],
+        ),
+    }
+}
+
+/// 입력 칸에 다시 읽을 수 있게 쓰는 숫자 ('.' 소수점, 끝의 0 생략).
+fn plain_number(v: f64) -> String {
+    let s = fmt_num(v, 6);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" {
+        "0".to_string()
+    } else {
+        s.to_string()
+    }
+}
+
+fn unit_combo<S: AsRef<str>>(ui: &mut egui::Ui, value: &mut String, options: &[(S, S)]) {
+    let current = options
+        .iter()
+        .find(|(_, c)| value.eq_ignore_ascii_case(c.as_ref()))
+        .map(|(l, _)| l.as_ref())
+        .unwrap_or(value.as_str())
+        .to_string();
+    egui::ComboBox::from_id_source(ui.next_auto_id())
+        .selected_text(current)
+        .show_ui(ui, |ui| {
+            for (label, code) in options {
+                ui.selectable_value(value, code.as_ref().to_string(), label.as_ref());
+            }
+        });
+}
+
+/// 내장 단위 뒤에 등록된 사용자 단위를 붙인 콤보 항목 (라벨을 짧게 바꾼 목록용)
+fn with_custom_units(kind: QuantityKind, builtin: &[(&str, &str)]) -> Vec<(String, String)> {
+    builtin
+        .iter()
+        .map(|(l, c)| (l.to_string(), c.to_string()))
+        .chain(custom_units::units_for(kind).into_iter().map(|u| (u.name.clone(), u.name)))
+        .collect()
+}
+
+fn pressure_unit_options() -> Vec<(String, String)> {
+    custom_units::unit_choices(QuantityKind::Pressure)
+}
+
+fn temperature_unit_options() -> Vec<(String, String)> {
+    with_custom_units(QuantityKind::Temperature, &[("°C", "C"), ("K", "K"), ("°F", "F"), ("R", "R")])
+}
+
+/// ΔP 콤보 항목: 차압 단위 뒤에 사용자 압력 단위
+fn dp_unit_options() -> Vec<(String, String)> {
+    with_custom_units(QuantityKind::Pressure, PRESSURE_DIFFERENCE_UNITS)
+}
+
+/// ΔP 단위 변환. 차이값이므로 게이지/절대 변환을 거치지 않고 배율만 적용한다
+/// (사용자 단위도 offset 없이 scale만 쓴다).
+fn dp_convert_gui(v: f64, from_unit: &str, to_unit: &str) -> f64 {
+    let (v, from_unit) = match custom_units::find(QuantityKind::Pressure, from_unit) {
+        Some(u) => (v * u.scale, u.base_unit),
+        None => (v, from_unit.to_string()),
+    };
+    let target = custom_units::find(QuantityKind::Pressure, to_unit);
+    let to_code = target.as_ref().map(|u| u.base_unit.as_str()).unwrap_or(to_unit);
+    let out = PressureDifference::from_unit(v, &from_unit)
+        .and_then(|dp| dp.in_unit(to_code))
+        .unwrap_or(v);
+    target.map(|u| out / u.scale).unwrap_or(out)
+}
+
+fn convert_pressure_mode_gui(
+    v: f64,
+    from_unit: &str,
+    from_mode: conversion::PressureMode,
+    to_unit: &str,
+    to_mode: conversion::PressureMode,
+) -> f64 {
+    // 사용자 단위는 기준 단위로 바꿔 내장 변환을 거친다.
+    let (v, from_unit) = custom_units::to_base(QuantityKind::Pressure, v, from_unit);
+    let target = custom_units::find(QuantityKind::Pressure, to_unit);
+    let to_unit = target.as_ref().map(|u| u.base_unit.as_str()).unwrap_or(to_unit);
+    let from = parse_pressure_unit_gui(&from_unit);
+    let to = parse_pressure_unit_gui(to_unit);
+    // 게이지 기준은 설정의 현장 대기압을 쓴다.
+    let out = PressureValue::new(v, from, pressure_reference_gui(from, from_mode))
+        .convert(to, pressure_reference_gui(to, to_mode), site_atmosphere())
+        .value;
+    target.map(|u| u.from_base(out)).unwrap_or(out)
+}
+
+/// bar(a)는 모드와 무관하게 절대압이다.
+fn pressure_reference_gui(unit: PressureUnit, mode: conversion::PressureMode) -> PressureReference {
+    match (unit, mode) {
+        (PressureUnit::BarA, _) | (_, conversion::PressureMode::Absolute) => {
+            PressureReference::Absolute
+        }
+        (_, conversion::PressureMode::Gauge) => PressureReference::Gauge,
+    }
+}
+
+fn convert_temperature_gui(v: f64, from: &str, to: &str) -> f64 {
+    custom_units::convert(QuantityKind::Temperature, v, from, to).unwrap_or(v)
+}
+
+fn convert_massflow_gui(v: f64, from: &str, to: &str) -> f64 {
+    flow_units::convert(FlowKind::MassFlow, v, from, to).unwrap_or(v)
+}
+
+fn convert_volumetric_flow_gui(v: f64, from: &str, to: &str) -> f64 {
+    flow_units::convert(FlowKind::VolumetricFlow, v, from, to).unwrap_or(v)
+}
+
+/// 체적 유량 뒤에 질량 유량 단위를 붙인 콤보 항목 (밀도로 환산하는 입력용)
+fn flow_unit_options() -> Vec<(&'static str, &'static str)> {
+    flow_units::VOLUMETRIC_FLOW_UNITS
+        .iter()
+        .chain(flow_units::MASS_FLOW_UNITS)
+        .copied()
+        .collect()
+}
+
+fn convert_power_gui(v: f64, from: &str, to: &str) -> f64 {
+    flow_units::convert(FlowKind::Power, v, from, to).unwrap_or(v)
+}
+
+fn convert_velocity_gui(v: f64, from: &str, to: &str) -> f64 {
+    conversion::convert(QuantityKind::Velocity, v, from, to).unwrap_or(v)
+}
+
+fn convert_length_gui(v: f64, from: &str, to: &str) -> f64 {
+    conversion::convert(QuantityKind::Length, v, from, to).unwrap_or(v)
+}
+
+fn convert_flow_gui(v: f64, from: &str, rho_unit: &str, rho: f64) -> f64 {
+    if flow_units::is_unit(FlowKind::MassFlow, from) {
+        convert_massflow_gui(v, from, "kg/h") / convert_density_gui(rho, rho_unit, "kg/m3")
+    } else {
+        convert_volumetric_flow_gui(v, from, "m3/h")
+    }
+}
+
+fn convert_flow_from_m3h(v_m3h: f64, to: &str, rho_unit: &str, rho: f64) -> f64 {
+    if flow_units::is_unit(FlowKind::MassFlow, to) {
+        let kg_h = v_m3h * convert_density_gui(rho, rho_unit, "kg/m3");
+        convert_massflow_gui(kg_h, "kg/h", to)
+    } else {
+        convert_volumetric_flow_gui(v_m3h, "m3/h", to)
+    }
+}
+
+fn convert_density_gui(v: f64, from: &str, to: &str) -> f64 {
+    flow_units::convert(FlowKind::Density, v, from, to).unwrap_or(v)
+}
+
+fn convert_energy_gui(v: f64, from: &str, to: &str) -> f64 {
+    conversion::convert(QuantityKind::Energy, v, from, to).unwrap_or(v)
+}
+
+fn convert_specific_enthalpy_gui(v: f64, from: &str, to: &str) -> f64 {
+    conversion::convert(QuantityKind::SpecificEnthalpy, v, from, to).unwrap_or(v)
+}
+
+fn parse_pressure_unit_gui(s: &str) -> PressureUnit {
+    match s.to_lowercase().as_str() {
+        "bar" => PressureUnit::Bar,
+        "bara" | "bar(a)" => PressureUnit::BarA,
+        "kpa" => PressureUnit::KiloPascal,
+        "mpa" => PressureUnit::MegaPascal,
+        "psi" => PressureUnit::Psi,
+        "atm" => PressureUnit::Atm,
+        "pa" => PressureUnit::Pascal,
+        "mmhg" => PressureUnit::MmHg,
+        _ => PressureUnit::Bar,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steam_engineering_toolbox::steam::steam_piping::{pressure_loss, PressureLossInput};
+
+    #[test]
+    fn preset_sibar_applies_si_defaults() {
+        let mut app = GuiApp::new(config::Config::default());
+        app.apply_unit_preset(config::UnitSystem::SIBar);
+        assert_eq!(app.inputs.steam_tables.properties.p_unit, "bar");
+        assert_eq!(app.inputs.steam_tables.properties.p_mode, conversion::PressureMode::Gauge);
+        assert_eq!(app.inputs.piping.sizing.pressure_unit, "bar");
+        assert_eq!(app.inputs.piping.sizing.pressure_mode, conversion::PressureMode::Gauge);
+        assert_eq!(app.inputs.valves.sizing.flow_unit, "m3/h");
+        assert_eq!(app.inputs.boiler.efficiency.lhv_unit, "kJ/kg");
+    }
+
+    #[test]
+    fn preset_imperial_applies_imperial_defaults() {
+        let mut app = GuiApp::new(config::Config::default());
+        app.apply_unit_preset(config::UnitSystem::Imperial);
+        assert_eq!(app.inputs.steam_tables.properties.p_unit, "psi");
+        assert_eq!(app.inputs.steam_tables.properties.p_mode, conversion::PressureMode::Gauge);
+        assert_eq!(app.inputs.piping.sizing.pressure_unit, "psi");
+        assert_eq!(app.inputs.piping.sizing.diam_out_unit, "in");
+        assert_eq!(app.inputs.piping.sizing.velocity_unit, "ft/s");
+        assert_eq!(app.inputs.valves.sizing.flow_unit, "gpm");
+        assert_eq!(app.inputs.boiler.efficiency.lhv_unit, "Btu/lb");
+        assert_eq!(app.inputs.boiler.efficiency.temp_unit, "F");
+    }
+
+    #[test]
+    fn convert_energy_kcal_to_kj() {
+        let out = conversion::convert(QuantityKind::Energy, 1.0, "kcal", "kJ").unwrap();
+        assert!((out - 4.184).abs() < 1e-6);
+    }
+
+    #[test]
+    fn convert_specific_enthalpy_kcal_per_kg() {
+        let out =
+            conversion::convert(QuantityKind::SpecificEnthalpy, 1.0, "kcal/kg", "kJ/kg").unwrap();
+        assert!((out - 4.184).abs() < 1e-6);
+    }
+
+    #[test]
+    fn convert_flow_gpm_roundtrip() {
+        let v_m3h = super::convert_flow_gui(10.0, "gpm", "kg/m3", 1000.0);
+        let back = super::convert_flow_from_m3h(v_m3h, "gpm", "kg/m3", 1000.0);
+        assert!((back - 10.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn pressure_loss_mach_check() {
+        let input = PressureLossInput {
+            mass_flow_kg_per_h: 3600.0, // 1 kg/s
+            steam_density_kg_per_m3: 1.0,
+            diameter_m: 0.1,
+            length_m: 10.0,
+            equivalent_length_m: 0.0,
+            fittings_k_sum: 0.0,
+            roughness_m: 0.000045,
+            dynamic_viscosity_pa_s: 1.2e-5,
+            sound_speed_m_per_s: 300.0,
+            state_pressure_bar_abs: Some(1.01325),
+            state_temperature_c: Some(100.0),
+            inlet_elevation_m: 0.0,
+            outlet_elevation_m: 0.0,
+        };
+        let res = pressure_loss(input).unwrap();
+        assert!((res.mach - 0.71).abs() < 0.02, "mach={}", res.mach);
+    }
+}
@@ -0,0 +1,2151 @@
+//! GUI 카드 입력값 묶음.
+//! 탭별 구조체 안에 카드별 구조체를 두어 카드 단위로 기본값 복원이 가능하고,
+//! 통째로 TOML(`gui_inputs.toml`)에 직렬화해 재시작 후에도 입력이 남는다.
+//! 결과 문자열, 계산 캐시, 스윕/표 붙여넣기 같은 보조 창 상태는 담지 않는다 (입력만 보존).
+//! 모든 구조체는 `#[serde(default)]`라서 필드가 추가·삭제돼도 예전 파일을 읽을 수 있다.
+
+use crate::combustion::emissions::BurnerType;
+use crate::combustion::fuel::FuelType;
+use crate::condensate_recovery::ReturnLineKind;
+use crate::config::{ConfigError, FluidDefaults, SiteConditions};
+use crate::conversion::PressureMode;
+use crate::flow_coefficient::FlowCoefficientKind;
+use crate::flow_units::FlowKind;
+use crate::gas::aiv::STEAM_MOLAR_MASS;
+use crate::gas::gas_properties::Gas;
+use crate::quantity::QuantityKind;
+use crate::steam::ejector::DEFAULT_EJECTOR_EFFICIENCY;
+use crate::steam::heat_balance::{HeatBalanceInput, HeatBalanceTemplate};
+use crate::steam::meter_compensation::MeterPrinciple;
+use crate::steam::steam_piping::fittings::FittingMethod;
+use crate::steam::valve_actuator;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// config.toml과 같은 디렉터리에 두는 입력값 파일 이름.
+pub const INPUTS_FILE: &str = "gui_inputs.toml";
+
+/// `PressureMode`를 "gauge"/"absolute" 문자열로 저장한다.
+mod pressure_mode {
+    use super::PressureMode;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(mode: &PressureMode, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(match mode {
+            PressureMode::Gauge => "gauge",
+            PressureMode::Absolute => "absolute",
+        })
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<PressureMode, D::Error> {
+        match String::deserialize(d)?.as_str() {
+            "gauge" => Ok(PressureMode::Gauge),
+            "absolute" => Ok(PressureMode::Absolute),
+            other => Err(serde::de::Error::unknown_variant(
+                other,
+                &["gauge", "absolute"],
+            )),
+        }
+    }
+}
+
+/// `QuantityKind`를 snake_case 문자열로 저장한다.
+mod quantity_kind {
+    use super::QuantityKind;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    const NAMES: [(QuantityKind, &str); 13] = [
+        (QuantityKind::Temperature, "temperature"),
+        (
+            QuantityKind::TemperatureDifference,
+            "temperature_difference",
+        ),
+        (QuantityKind::Pressure, "pressure"),
+        (QuantityKind::Length, "length"),
+        (QuantityKind::Area, "area"),
+        (QuantityKind::Volume, "volume"),
+        (QuantityKind::Velocity, "velocity"),
+        (QuantityKind::Mass, "mass"),
+        (QuantityKind::Viscosity, "viscosity"),
+        (QuantityKind::Energy, "energy"),
+        (QuantityKind::HeatTransferCoeff, "heat_transfer_coeff"),
+        (QuantityKind::ThermalConductivity, "thermal_conductivity"),
+        (QuantityKind::SpecificEnthalpy, "specific_enthalpy"),
+    ];
+
+    pub fn serialize<S: Serializer>(kind: &QuantityKind, s: S) -> Result<S::Ok, S::Error> {
+        let name = NAMES
+            .iter()
+            .find(|(k, _)| k == kind)
+            .map(|(_, name)| *name)
+            .ok_or_else(|| serde::ser::Error::custom("unknown quantity kind"))?;
+        s.serialize_str(name)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<QuantityKind, D::Error> {
+        let text = String::deserialize(d)?;
+        NAMES
+            .iter()
+            .find(|(_, name)| *name == text)
+            .map(|(kind, _)| *kind)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown quantity kind `{text}`")))
+    }
+}
+
+/// 선택 연료를 저장한다. 없음은 "none"으로 적어, 기본값이 `Some`인 카드에서도
+/// 다시 읽을 때 빈 값이 기본 연료로 바뀌지 않는다.
+mod optional_fuel {
+    use super::FuelType;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Fuel(FuelType),
+        None(String),
+    }
+
+    pub fn serialize<S: Serializer>(fuel: &Option<FuelType>, s: S) -> Result<S::Ok, S::Error> {
+        match fuel {
+            Some(fuel) => fuel.serialize(s),
+            None => s.serialize_str("none"),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<FuelType>, D::Error> {
+        match Repr::deserialize(d)? {
+            Repr::Fuel(fuel) => Ok(Some(fuel)),
+            Repr::None(text) if text == "none" => Ok(None),
+            Repr::None(text) => Err(serde::de::Error::unknown_variant(&text, &["none"])),
+        }
+    }
+}
+
+/// 증기표 카드의 입력 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SteamMode {
+    ByPressure,
+    ByTemperature,
+    Superheated,
+}
+
+/// 밸브 카드의 계산 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValveMode {
+    RequiredCvKv,
+    FlowFromCvKv,
+}
+
+/// 단위 변환 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitConversionInputs {
+    #[serde(with = "quantity_kind")]
+    pub kind: QuantityKind,
+    pub value: f64,
+    pub from: String,
+    pub to: String,
+    /// 단위 변환 탭의 자유 입력 단위 (콤보 옆 칸)
+    pub from_text: String,
+    pub to_text: String,
+}
+
+impl Default for UnitConversionInputs {
+    fn default() -> Self {
+        Self {
+            kind: QuantityKind::Temperature,
+            value: 100.0,
+            from: "C".into(),
+            to: "K".into(),
+            from_text: String::new(),
+            to_text: String::new(),
+        }
+    }
+}
+
+/// 유량·동력 변환 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FlowConversionInputs {
+    pub kind: FlowKind,
+    pub value: f64,
+    pub from: String,
+    pub to: String,
+}
+
+impl Default for FlowConversionInputs {
+    fn default() -> Self {
+        Self {
+            kind: FlowKind::MassFlow,
+            value: 10.0,
+            from: "t/h".into(),
+            to: "klb/h".into(),
+        }
+    }
+}
+
+/// 단위 변환 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UnitConvTab {
+    pub quantity: UnitConversionInputs,
+    pub flow: FlowConversionInputs,
+}
+
+/// 증기표(포화/과열) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SteamPropertiesInputs {
+    pub mode: SteamMode,
+    /// 모드에 따라 압력 또는 온도
+    pub value: f64,
+    pub p_unit: String,
+    pub t_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub p_mode: PressureMode,
+    pub p_unit_out: String,
+    #[serde(with = "pressure_mode")]
+    pub p_mode_out: PressureMode,
+    pub t_unit_out: String,
+    /// 과열 모드의 온도
+    pub temp_input: f64,
+}
+
+impl Default for SteamPropertiesInputs {
+    fn default() -> Self {
+        Self {
+            mode: SteamMode::ByPressure,
+            value: 1.0,
+            p_unit: "bar".into(),
+            t_unit: "C".into(),
+            p_mode: PressureMode::Gauge,
+            p_unit_out: "bar".into(),
+            p_mode_out: PressureMode::Absolute,
+            t_unit_out: "C".into(),
+            temp_input: 200.0,
+        }
+    }
+}
+
+/// 건도 측정(열량계) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrynessInputs {
+    pub mode: usize,
+    pub main_p_bar_abs: f64,
+    pub cal_p_bar_abs: f64,
+    pub cal_t_c: f64,
+    pub sep_water_kg: f64,
+    pub condensate_kg: f64,
+}
+
+impl Default for DrynessInputs {
+    fn default() -> Self {
+        Self {
+            mode: 0,
+            main_p_bar_abs: 10.0,
+            cal_p_bar_abs: 1.01325,
+            cal_t_c: 120.0,
+            sep_water_kg: 0.5,
+            condensate_kg: 9.5,
+        }
+    }
+}
+
+/// 증기표 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SteamTablesTab {
+    pub properties: SteamPropertiesInputs,
+    pub dryness: DrynessInputs,
+}
+
+/// 유속 기준 배관 구경 선정 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipeSizingInputs {
+    /// 질량 유량 (배관 압력 손실 카드와 공용)
+    pub mass_flow: f64,
+    pub mass_unit: String,
+    pub pressure: f64,
+    pub pressure_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub pressure_mode: PressureMode,
+    pub temp: f64,
+    pub temp_unit: String,
+    pub velocity: f64,
+    pub velocity_unit: String,
+    pub diam_out_unit: String,
+    pub vel_out_unit: String,
+}
+
+impl Default for PipeSizingInputs {
+    fn default() -> Self {
+        Self {
+            mass_flow: 500.0,
+            mass_unit: "kg/h".into(),
+            pressure: 5.0,
+            pressure_unit: "bar".into(),
+            pressure_mode: PressureMode::Gauge,
+            temp: 180.0,
+            temp_unit: "C".into(),
+            velocity: 25.0,
+            velocity_unit: "m/s".into(),
+            diam_out_unit: "m".into(),
+            vel_out_unit: "m/s".into(),
+        }
+    }
+}
+
+/// 배관 압력 손실(Darcy-Weisbach) 카드. 피팅 목록은 담지 않는다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipeLossInputs {
+    pub density: f64,
+    pub pressure_bar_abs: f64,
+    pub temperature_c: f64,
+    pub diameter: f64,
+    pub length: f64,
+    pub eq_length: f64,
+    pub z_in: f64,
+    pub z_out: f64,
+    pub fittings_k: f64,
+    pub roughness: f64,
+    pub material: String,
+    pub visc: f64,
+    pub sound_speed: f64,
+    pub dp_out_unit: String,
+    pub fitting_method: FittingMethod,
+}
+
+impl Default for PipeLossInputs {
+    fn default() -> Self {
+        Self {
+            density: 2.5,
+            pressure_bar_abs: 6.0,
+            temperature_c: 180.0,
+            diameter: 0.1,
+            length: 50.0,
+            eq_length: 0.0,
+            z_in: 0.0,
+            z_out: 0.0,
+            fittings_k: 0.0,
+            roughness: 0.000045,
+            material: "steel-new".into(),
+            visc: 1.2e-5,
+            sound_speed: 450.0,
+            dp_out_unit: "bar".into(),
+            fitting_method: FittingMethod::TwoK,
+        }
+    }
+}
+
+/// 배관 응축(길이 방향 열손실) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LineCondensationInputs {
+    pub flow_kg_h: f64,
+    pub p_bar_abs: f64,
+    /// 입구 과열(온도 입력) / 습증기(건도 입력)
+    pub superheated: bool,
+    pub temp_c: f64,
+    pub dryness: f64,
+    pub id_m: f64,
+    pub od_m: f64,
+    pub length_m: f64,
+    /// 출구 높이 − 입구 높이 [m]
+    pub rise_m: f64,
+    pub insulation_m: f64,
+    pub insulation_k: f64,
+    pub ambient_c: f64,
+    pub wind_m_s: f64,
+    pub segments: usize,
+}
+
+impl Default for LineCondensationInputs {
+    fn default() -> Self {
+        Self {
+            flow_kg_h: 2000.0,
+            p_bar_abs: 10.0,
+            superheated: false,
+            temp_c: 200.0,
+            dryness: 1.0,
+            id_m: 0.1023,
+            od_m: 0.1143,
+            length_m: 200.0,
+            rise_m: 0.0,
+            insulation_m: 0.05,
+            insulation_k: 0.045,
+            ambient_c: 0.0,
+            wind_m_s: 3.0,
+            segments: 20,
+        }
+    }
+}
+
+/// 스팀 블로 CFR 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SteamBlowInputs {
+    pub design_flow_kg_h: f64,
+    pub design_p_bar_abs: f64,
+    pub design_t_c: f64,
+    pub design_id_mm: f64,
+    pub boiler_p_bar_abs: f64,
+    pub boiler_t_c: f64,
+    pub valve_capacity_kg_h: f64,
+    pub target_cfr: f64,
+}
+
+impl Default for SteamBlowInputs {
+    fn default() -> Self {
+        Self {
+            design_flow_kg_h: 200_000.0,
+            design_p_bar_abs: 100.0,
+            design_t_c: 540.0,
+            design_id_mm: 220.0,
+            boiler_p_bar_abs: 40.0,
+            boiler_t_c: 420.0,
+            valve_capacity_kg_h: 150_000.0,
+            target_cfr: 1.2,
+        }
+    }
+}
+
+/// 증기 배관 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PipingTab {
+    pub sizing: PipeSizingInputs,
+    pub loss: PipeLossInputs,
+    pub condensation: LineCondensationInputs,
+    pub blow: SteamBlowInputs,
+}
+
+/// 밸브 Cv/Kv 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValveInputs {
+    pub mode: ValveMode,
+    /// 산정 모드에서는 유량, 유량 모드에서는 Cv/Kv 입력
+    pub flow: f64,
+    pub flow_unit: String,
+    pub upstream_p: f64,
+    pub upstream_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub upstream_mode: PressureMode,
+    pub dp: f64,
+    pub dp_unit: String,
+    pub rho: f64,
+    pub rho_unit: String,
+    pub cv_kv: f64,
+}
+
+impl ValveInputs {
+    /// 밀도 기본값은 설정의 기체 밀도를 따른다.
+    pub fn for_fluid(fluid: &FluidDefaults) -> Self {
+        Self {
+            mode: ValveMode::RequiredCvKv,
+            flow: 10.0,
+            flow_unit: "m3/h".into(),
+            upstream_p: 5.0,
+            upstream_unit: "bar".into(),
+            upstream_mode: PressureMode::Gauge,
+            dp: 1.0,
+            dp_unit: "bar".into(),
+            rho: fluid.gas_density_kg_per_m3,
+            rho_unit: "kg/m3".into(),
+            cv_kv: 10.0,
+        }
+    }
+}
+
+impl Default for ValveInputs {
+    fn default() -> Self {
+        Self::for_fluid(&FluidDefaults::default())
+    }
+}
+
+/// 밸브 액추에이터 추력/토크 카드. 선택 목록은 색인으로 저장한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValveActuatorInputs {
+    pub dp_bar: f64,
+    pub port_mm: f64,
+    /// 0 = 불평형 플러그
+    pub seal_mm: f64,
+    pub stem_mm: f64,
+    pub class: usize,
+    pub packing: usize,
+    pub kind: usize,
+    pub supply_bar: f64,
+    pub margin: f64,
+}
+
+impl Default for ValveActuatorInputs {
+    fn default() -> Self {
+        Self {
+            dp_bar: 10.0,
+            port_mm: 50.0,
+            seal_mm: 0.0,
+            stem_mm: 12.7,
+            class: 2, // Class IV
+            packing: 0,
+            kind: 0,
+            supply_bar: 4.0,
+            margin: valve_actuator::DEFAULT_MARGIN,
+        }
+    }
+}
+
+/// 밸브 권한·설치 특성 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValveAuthorityInputs {
+    pub characteristic: usize,
+    pub rangeability: f64,
+    pub valve_dp: f64,
+    pub circuit_dp: f64,
+}
+
+impl Default for ValveAuthorityInputs {
+    fn default() -> Self {
+        Self {
+            characteristic: 1, // 등비율
+            rangeability: 50.0,
+            valve_dp: 1.0,
+            circuit_dp: 2.0,
+        }
+    }
+}
+
+/// 터빈 바이패스 밸브(증기) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BypassValveInputs {
+    pub up_p: f64,
+    pub up_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub up_mode: PressureMode,
+    pub up_t: f64,
+    pub t_unit: String,
+    pub down_p: f64,
+    pub down_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub down_mode: PressureMode,
+    pub cv: f64,
+    pub cv_kind: FlowCoefficientKind,
+    pub open_pct: f64,
+    pub h_override_kj_per_kg: f64,
+    pub spray_kg_h: f64,
+    pub spray_temp: f64,
+    pub spray_temp_unit: String,
+    pub target_t: f64,
+    pub stroke_points: Vec<f64>,
+    pub cv_points: Vec<f64>,
+}
+
+impl Default for BypassValveInputs {
+    fn default() -> Self {
+        Self {
+            up_p: 60.0,
+            up_unit: "bar".into(),
+            up_mode: PressureMode::Gauge,
+            up_t: 520.0,
+            t_unit: "C".into(),
+            down_p: 10.0,
+            down_unit: "bar".into(),
+            down_mode: PressureMode::Gauge,
+            cv: 200.0,
+            cv_kind: FlowCoefficientKind::CvUs,
+            open_pct: 50.0,
+            h_override_kj_per_kg: 0.0,
+            spray_kg_h: 0.0,
+            spray_temp: 40.0,
+            spray_temp_unit: "C".into(),
+            target_t: 250.0,
+            stroke_points: vec![0.0, 25.0, 50.0, 75.0, 100.0],
+            cv_points: vec![0.0, 0.0, 0.0, 0.0, 0.0],
+        }
+    }
+}
+
+/// 바이패스 스프레이 물 밸브(TCV) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SprayValveInputs {
+    pub up_p: f64,
+    pub up_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub up_mode: PressureMode,
+    pub down_p: f64,
+    pub down_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub down_mode: PressureMode,
+    pub temp: f64,
+    pub temp_unit: String,
+    pub density: f64,
+    pub cv: f64,
+    pub cv_kind: FlowCoefficientKind,
+    pub open_pct: f64,
+    pub h_override_kj_per_kg: f64,
+    pub stroke_points: Vec<f64>,
+    pub cv_points: Vec<f64>,
+}
+
+impl Default for SprayValveInputs {
+    fn default() -> Self {
+        Self {
+            up_p: 15.0,
+            up_unit: "bar".into(),
+            up_mode: PressureMode::Gauge,
+            down_p: 10.0,
+            down_unit: "bar".into(),
+            down_mode: PressureMode::Gauge,
+            temp: 30.0,
+            temp_unit: "C".into(),
+            density: 1000.0,
+            cv: 20.0,
+            cv_kind: FlowCoefficientKind::CvUs,
+            open_pct: 100.0,
+            h_override_kj_per_kg: 0.0,
+            stroke_points: vec![0.0, 25.0, 50.0, 75.0, 100.0],
+            cv_points: vec![0.0, 0.0, 0.0, 0.0, 0.0],
+        }
+    }
+}
+
+/// 밸브 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ValvesTab {
+    pub sizing: ValveInputs,
+    pub actuator: ValveActuatorInputs,
+    pub authority: ValveAuthorityInputs,
+    pub bypass: BypassValveInputs,
+    pub spray: SprayValveInputs,
+}
+
+/// 랭킨 사이클 열소비율 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RankineCycleInputs {
+    pub throttle_p_bar_abs: f64,
+    pub throttle_t_c: f64,
+    pub condenser_p_bar_abs: f64,
+    pub turbine_eff: f64,
+    pub pump_eff: f64,
+    pub reheat: bool,
+    pub reheat_p_bar_abs: f64,
+    pub reheat_t_c: f64,
+    /// 0 = 없음, 1 = 개방형, 2 = 밀폐형
+    pub fwh_mode: usize,
+    pub fwh_p_bar_abs: f64,
+    pub fwh_ttd_k: f64,
+    pub steam_flow_kg_h: f64,
+}
+
+impl Default for RankineCycleInputs {
+    fn default() -> Self {
+        Self {
+            throttle_p_bar_abs: 100.0,
+            throttle_t_c: 540.0,
+            condenser_p_bar_abs: 0.08,
+            turbine_eff: 0.85,
+            pump_eff: 0.8,
+            reheat: false,
+            reheat_p_bar_abs: 20.0,
+            reheat_t_c: 540.0,
+            fwh_mode: 1,
+            fwh_p_bar_abs: 5.0,
+            fwh_ttd_k: 3.0,
+            steam_flow_kg_h: 100_000.0,
+        }
+    }
+}
+
+/// 보일러 효율(기본·PTC 4.0 손실법) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoilerEfficiencyInputs {
+    pub fuel_flow: f64,
+    pub fuel_unit: String,
+    pub lhv: f64,
+    pub lhv_unit: String,
+    pub steam_flow: f64,
+    pub steam_unit: String,
+    /// 보일러 유효열/연료열 출력 단위
+    pub heat_unit: String,
+    pub h_steam: f64,
+    pub h_steam_unit: String,
+    pub h_fw: f64,
+    pub h_fw_unit: String,
+    pub fg_flow: f64,
+    pub fg_flow_unit: String,
+    pub fg_cp: f64,
+    pub stack_temp: f64,
+    pub ambient_temp: f64,
+    pub excess_air: f64,
+    /// 노점 검토 연료 (None = 생략)
+    #[serde(with = "optional_fuel")]
+    pub dew_fuel: Option<FuelType>,
+    pub dew_sulfur_pct: f64,
+    pub rad_loss: f64,
+    pub blowdown_rate: f64,
+    pub blowdown_h: f64,
+    pub blowdown_h_unit: String,
+    pub temp_unit: String,
+}
+
+impl Default for BoilerEfficiencyInputs {
+    fn default() -> Self {
+        Self {
+            fuel_flow: 100.0,
+            fuel_unit: "kg/h".into(),
+            lhv: 42000.0,
+            lhv_unit: "kJ/kg".into(),
+            steam_flow: 800.0,
+            steam_unit: "kg/h".into(),
+            heat_unit: "kW".into(),
+            h_steam: 2770.0,
+            h_steam_unit: "kJ/kg".into(),
+            h_fw: 500.0,
+            h_fw_unit: "kJ/kg".into(),
+            fg_flow: 1500.0,
+            fg_flow_unit: "kg/h".into(),
+            fg_cp: 1.05,
+            stack_temp: 220.0,
+            ambient_temp: 30.0,
+            excess_air: 0.15,
+            dew_fuel: None,
+            dew_sulfur_pct: 0.0,
+            rad_loss: 0.02,
+            blowdown_rate: 0.05,
+            blowdown_h: 600.0,
+            blowdown_h_unit: "kJ/kg".into(),
+            temp_unit: "C".into(),
+        }
+    }
+}
+
+/// 드럼 스웰/쉬링크·블로다운 배관 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrumInputs {
+    pub diameter_m: f64,
+    pub length_m: f64,
+    pub nwl_m: f64,
+    pub riser_volume_m3: f64,
+    pub circulation_kg_h: f64,
+    pub p_before_bar_abs: f64,
+    pub p_after_bar_abs: f64,
+    pub flow_before_kg_h: f64,
+    pub flow_after_kg_h: f64,
+    pub blowdown_flow_kg_h: f64,
+    pub blowdown_id_mm: f64,
+    pub blowdown_flash_p_bar_abs: f64,
+}
+
+impl Default for DrumInputs {
+    fn default() -> Self {
+        Self {
+            diameter_m: 1.5,
+            length_m: 8.0,
+            nwl_m: 0.75,
+            riser_volume_m3: 6.0,
+            circulation_kg_h: 500_000.0,
+            p_before_bar_abs: 41.0,
+            p_after_bar_abs: 38.0,
+            flow_before_kg_h: 40_000.0,
+            flow_after_kg_h: 50_000.0,
+            blowdown_flow_kg_h: 800.0,
+            blowdown_id_mm: 15.0,
+            blowdown_flash_p_bar_abs: 1.5,
+        }
+    }
+}
+
+/// 증기 순도·캐리오버 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SteamPurityInputs {
+    pub p_bar_abs: f64,
+    pub fw_tds: f64,
+    pub fw_silica: f64,
+    pub bw_tds: f64,
+    pub bw_silica: f64,
+    pub mech_pct: f64,
+    pub steam_kg_h: f64,
+}
+
+impl Default for SteamPurityInputs {
+    fn default() -> Self {
+        Self {
+            p_bar_abs: 60.0,
+            fw_tds: 5.0,
+            fw_silica: 0.1,
+            bw_tds: 500.0,
+            bw_silica: 5.0,
+            mech_pct: 0.05,
+            steam_kg_h: 50_000.0,
+        }
+    }
+}
+
+/// 열정산도 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HeatBalanceInputs {
+    pub template: HeatBalanceTemplate,
+    pub input: HeatBalanceInput,
+}
+
+impl Default for HeatBalanceInputs {
+    fn default() -> Self {
+        Self {
+            template: HeatBalanceTemplate::SingleReheatThreeHeaters,
+            input: HeatBalanceTemplate::SingleReheatThreeHeaters.input(),
+        }
+    }
+}
+
+/// HRSG 핀치/어프로치 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HrsgInputs {
+    pub gas_kg_s: f64,
+    pub gas_t_c: f64,
+    pub gas_cp: f64,
+    pub p_bar_abs: f64,
+    pub superheat: bool,
+    pub steam_t_c: f64,
+    pub fw_t_c: f64,
+    pub pinch_k: f64,
+    pub approach_k: f64,
+    /// 배기 노점 검토 연료 (None = 생략)
+    #[serde(with = "optional_fuel")]
+    pub fuel: Option<FuelType>,
+    /// 가스터빈 배기 과잉공기율 [%]
+    pub excess_air_pct: f64,
+}
+
+impl Default for HrsgInputs {
+    fn default() -> Self {
+        Self {
+            gas_kg_s: 100.0,
+            gas_t_c: 550.0,
+            gas_cp: 1.1,
+            p_bar_abs: 40.0,
+            superheat: true,
+            steam_t_c: 480.0,
+            fw_t_c: 105.0,
+            pinch_k: 10.0,
+            approach_k: 5.0,
+            fuel: Some(FuelType::NaturalGas),
+            excess_air_pct: 250.0,
+        }
+    }
+}
+
+/// 과열 저감기 스프레이·보일러 출력 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AttemperatorInputs {
+    pub main_flow_kg_h: f64,
+    pub p_bar_abs: f64,
+    pub inlet_t_c: f64,
+    pub setpoint_c: f64,
+    pub spray_t_c: f64,
+    /// 스프레이 밸브 최대 유량 [kg/h] (0 = 제한 없음)
+    pub max_spray_kg_h: f64,
+    pub ms_p_bar_abs: f64,
+    pub ms_t_c: f64,
+    pub fw_p_bar_abs: f64,
+    pub fw_t_c: f64,
+    /// 연료 투입 열량 [kW] (0 = 효율 생략)
+    pub fuel_kw: f64,
+}
+
+impl Default for AttemperatorInputs {
+    fn default() -> Self {
+        Self {
+            main_flow_kg_h: 100_000.0,
+            p_bar_abs: 100.0,
+            inlet_t_c: 480.0,
+            setpoint_c: 450.0,
+            spray_t_c: 230.0,
+            max_spray_kg_h: 15_000.0,
+            ms_p_bar_abs: 95.0,
+            ms_t_c: 540.0,
+            fw_p_bar_abs: 120.0,
+            fw_t_c: 220.0,
+            fuel_kw: 80_000.0,
+        }
+    }
+}
+
+/// 연소 공기 예열기 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AirPreheaterInputs {
+    pub fuel: FuelType,
+    pub sulfur_pct: f64,
+    pub fuel_kg_h: f64,
+    pub lhv_kj_kg: f64,
+    pub excess_air_pct: f64,
+    /// 연소용 공기 절대습도 [kg/kg 건공기]
+    pub humidity: f64,
+    pub gas_in_c: f64,
+    pub air_in_c: f64,
+    pub air_out_c: f64,
+    pub leak_pct: f64,
+}
+
+impl Default for AirPreheaterInputs {
+    fn default() -> Self {
+        Self {
+            fuel: FuelType::FuelOil6,
+            sulfur_pct: 2.5,
+            fuel_kg_h: 5_000.0,
+            lhv_kj_kg: FuelType::FuelOil6.lhv_kj_per_kg(),
+            excess_air_pct: 15.0,
+            humidity: 0.01,
+            gas_in_c: 350.0,
+            air_in_c: 30.0,
+            air_out_c: 280.0,
+            leak_pct: 0.0,
+        }
+    }
+}
+
+/// 배출량(CO₂/SO₂/NOx) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmissionsInputs {
+    pub fuel: FuelType,
+    pub sulfur_pct: f64,
+    pub fuel_kg_h: f64,
+    pub lhv_kj_kg: f64,
+    pub burner: BurnerType,
+    pub hours: f64,
+    /// NOx 계수 [g/GJ] (0 = AP-42)
+    pub nox_g_gj: f64,
+    /// 유효 출력 [kW] (0 = 원단위 생략)
+    pub useful_kw: f64,
+}
+
+impl Default for EmissionsInputs {
+    fn default() -> Self {
+        Self {
+            fuel: FuelType::NaturalGas,
+            sulfur_pct: 0.0,
+            fuel_kg_h: 1_000.0,
+            lhv_kj_kg: FuelType::NaturalGas.lhv_kj_per_kg(),
+            burner: BurnerType::LowNox,
+            hours: 8_000.0,
+            nox_g_gj: 0.0,
+            useful_kw: 0.0,
+        }
+    }
+}
+
+/// 보일러 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BoilerTab {
+    pub cycle: RankineCycleInputs,
+    pub efficiency: BoilerEfficiencyInputs,
+    pub drum: DrumInputs,
+    pub purity: SteamPurityInputs,
+    pub heat_balance: HeatBalanceInputs,
+    pub hrsg: HrsgInputs,
+    pub attemperator: AttemperatorInputs,
+    pub air_preheater: AirPreheaterInputs,
+    pub emissions: EmissionsInputs,
+}
+
+/// 복수기 열정산/진공 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CondenserInputs {
+    pub pressure: f64,
+    pub pressure_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub pressure_mode: PressureMode,
+    pub temp_c: f64,
+    pub use_manual_temp: bool,
+    pub cw_in: f64,
+    pub cw_out: f64,
+    pub cw_temp_unit: String,
+    pub cw_flow: f64,
+    pub cw_flow_unit: String,
+    /// 복수기 열부하 출력 단위
+    pub duty_unit: String,
+    pub ua: f64,
+    pub area: f64,
+    pub u: f64,
+    pub backpressure: f64,
+    pub backpressure_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub backpressure_mode: PressureMode,
+    pub auto_condensing_from_pressure: bool,
+    pub auto_backpressure_from_temp: bool,
+    pub auto_cw_out_from_range: bool,
+    pub auto_ua_from_area_u: bool,
+    pub auto_area_required: bool,
+}
+
+impl Default for CondenserInputs {
+    fn default() -> Self {
+        Self {
+            pressure: 0.2,
+            pressure_unit: "bar".into(),
+            pressure_mode: PressureMode::Absolute,
+            temp_c: 45.0,
+            use_manual_temp: false,
+            cw_in: 30.0,
+            cw_out: 38.0,
+            cw_temp_unit: "C".into(),
+            cw_flow: 500.0,
+            cw_flow_unit: "m3/h".into(),
+            duty_unit: "kW".into(),
+            ua: 0.0,
+            area: 0.0,
+            u: 0.0,
+            backpressure: 0.2,
+            backpressure_unit: "bar".into(),
+            backpressure_mode: PressureMode::Absolute,
+            auto_condensing_from_pressure: true,
+            auto_backpressure_from_temp: true,
+            auto_cw_out_from_range: false,
+            auto_ua_from_area_u: true,
+            auto_area_required: false,
+        }
+    }
+}
+
+/// 냉각탑(레인지/어프로치) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoolingTowerInputs {
+    pub water_in: f64,
+    pub water_out: f64,
+    pub wb: f64,
+    pub db: f64,
+    pub temp_unit: String,
+    pub flow: f64,
+    pub flow_unit: String,
+    pub range_target: f64,
+    pub approach_target: f64,
+}
+
+impl CoolingTowerInputs {
+    /// 건구·습구 온도 기본값은 설정의 현장 설계 조건을 따른다.
+    pub fn for_site(site: &SiteConditions) -> Self {
+        Self {
+            water_in: 40.0,
+            water_out: 32.0,
+            wb: site.design_wet_bulb_c,
+            db: site.design_dry_bulb_c,
+            temp_unit: "C".into(),
+            flow: 500.0,
+            flow_unit: "m3/h".into(),
+            range_target: 8.0,
+            approach_target: 4.0,
+        }
+    }
+}
+
+impl Default for CoolingTowerInputs {
+    fn default() -> Self {
+        Self::for_site(&SiteConditions::default())
+    }
+}
+
+/// 직접 증발식 공기 냉각기 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvaporativeCoolerInputs {
+    pub db_c: f64,
+    pub use_rh: bool,
+    pub wb_c: f64,
+    pub rh_pct: f64,
+    pub effectiveness: f64,
+    pub air_m3_h: f64,
+    pub cycles: f64,
+}
+
+impl EvaporativeCoolerInputs {
+    /// 건구·습구 온도 기본값은 설정의 현장 설계 조건을 따른다.
+    pub fn for_site(site: &SiteConditions) -> Self {
+        Self {
+            db_c: site.design_dry_bulb_c,
+            use_rh: false,
+            wb_c: site.design_wet_bulb_c,
+            rh_pct: 30.0,
+            effectiveness: 0.85,
+            air_m3_h: 10_000.0,
+            cycles: 3.0,
+        }
+    }
+}
+
+impl Default for EvaporativeCoolerInputs {
+    fn default() -> Self {
+        Self::for_site(&SiteConditions::default())
+    }
+}
+
+/// 공랭식 복수기/핀팬 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AirCooledCondenserInputs {
+    pub steam_t_h: f64,
+    pub quality: f64,
+    pub back_pressure_bar_abs: f64,
+    pub ambient_c: f64,
+    pub air_rise_k: f64,
+    pub h_cond: f64,
+    pub h_air: f64,
+    pub fin_ratio: f64,
+    pub fin_eff: f64,
+    pub area_m2: f64,
+    pub hot_ambient_c: f64,
+    pub max_bp_bar_abs: f64,
+}
+
+impl Default for AirCooledCondenserInputs {
+    fn default() -> Self {
+        Self {
+            steam_t_h: 100.0,
+            quality: 0.92,
+            back_pressure_bar_abs: 0.15,
+            ambient_c: 20.0,
+            air_rise_k: 18.0,
+            h_cond: 10_000.0,
+            h_air: 45.0,
+            fin_ratio: 20.0,
+            fin_eff: 0.85,
+            area_m2: 0.0,
+            hot_ambient_c: 40.0,
+            max_bp_bar_abs: 0.3,
+        }
+    }
+}
+
+/// 관류식 대 순환식 냉각 비교 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoolingComparisonInputs {
+    pub duty_mw: f64,
+    pub source_c: f64,
+    pub ot_rise_k: f64,
+    pub max_discharge_c: f64,
+    pub ot_head_m: f64,
+    pub wb_c: f64,
+    pub approach_k: f64,
+    pub range_k: f64,
+    pub cycles: f64,
+    pub drift_pct: f64,
+    pub rc_head_m: f64,
+    pub pump_eff: f64,
+}
+
+impl Default for CoolingComparisonInputs {
+    fn default() -> Self {
+        Self {
+            duty_mw: 100.0,
+            source_c: 20.0,
+            ot_rise_k: 8.0,
+            max_discharge_c: 32.0,
+            ot_head_m: 15.0,
+            wb_c: 24.0,
+            approach_k: 5.0,
+            range_k: 10.0,
+            cycles: 4.0,
+            drift_pct: 0.05,
+            rc_head_m: 25.0,
+            pump_eff: 0.85,
+        }
+    }
+}
+
+/// 순환 펌프 NPSH 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PumpNpshInputs {
+    pub suction_p: f64,
+    pub suction_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub suction_mode: PressureMode,
+    pub temp: f64,
+    pub temp_unit: String,
+    pub static_head: f64,
+    pub friction: f64,
+    pub rho: f64,
+    pub rho_unit: String,
+    pub required: f64,
+}
+
+impl PumpNpshInputs {
+    /// 밀도 기본값은 설정의 액체 밀도를 따른다.
+    pub fn for_fluid(fluid: &FluidDefaults) -> Self {
+        Self {
+            suction_p: 0.5,
+            suction_unit: "bar".into(),
+            suction_mode: PressureMode::Gauge,
+            temp: 30.0,
+            temp_unit: "C".into(),
+            static_head: 2.0,
+            friction: 0.5,
+            rho: fluid.liquid_density_kg_per_m3,
+            rho_unit: "kg/m3".into(),
+            required: 3.0,
+        }
+    }
+}
+
+impl Default for PumpNpshInputs {
+    fn default() -> Self {
+        Self::for_fluid(&FluidDefaults::default())
+    }
+}
+
+/// 핫웰/플래시 탱크 펌프 NPSH 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SaturatedNpshInputs {
+    pub vessel_p_bar_abs: f64,
+    pub submergence_m: f64,
+    pub friction_m: f64,
+    pub required_m: f64,
+    pub line_length_m: f64,
+    pub velocity_m_s: f64,
+    pub decay_bar_s: f64,
+}
+
+impl Default for SaturatedNpshInputs {
+    fn default() -> Self {
+        Self {
+            vessel_p_bar_abs: 0.1,
+            submergence_m: 3.0,
+            friction_m: 0.3,
+            required_m: 1.5,
+            line_length_m: 10.0,
+            velocity_m_s: 1.5,
+            decay_bar_s: 0.005,
+        }
+    }
+}
+
+/// 증기 이젝터 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EjectorInputs {
+    pub two_stage: bool,
+    pub suction_p_bar_abs: f64,
+    pub suction_temp_c: f64,
+    pub discharge_p_bar_abs: f64,
+    pub air_kg_h: f64,
+    pub vapor_kg_h: f64,
+    pub motive_p_bar_abs: f64,
+    pub intercondenser_temp_c: f64,
+    pub efficiency: f64,
+}
+
+impl Default for EjectorInputs {
+    fn default() -> Self {
+        Self {
+            two_stage: true,
+            suction_p_bar_abs: 0.08,
+            suction_temp_c: 35.0,
+            discharge_p_bar_abs: 1.03,
+            air_kg_h: 10.0,
+            vapor_kg_h: 20.0,
+            motive_p_bar_abs: 10.0,
+            intercondenser_temp_c: 35.0,
+            efficiency: DEFAULT_EJECTOR_EFFICIENCY,
+        }
+    }
+}
+
+/// 드레인 쿨러/재열기 열정산 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DrainCoolerInputs {
+    pub shell_in: f64,
+    pub shell_out: f64,
+    pub shell_flow: f64,
+    pub tube_in: f64,
+    pub tube_out: f64,
+    pub tube_flow: f64,
+    pub temp_unit: String,
+    pub flow_unit: String,
+    pub ua: f64,
+    pub area: f64,
+    pub u: f64,
+}
+
+impl Default for DrainCoolerInputs {
+    fn default() -> Self {
+        Self {
+            shell_in: 120.0,
+            shell_out: 90.0,
+            shell_flow: 10.0,
+            tube_in: 30.0,
+            tube_out: 60.0,
+            tube_flow: 20.0,
+            temp_unit: "C".into(),
+            flow_unit: "m3/h".into(),
+            ua: 0.0,
+            area: 0.0,
+            u: 0.0,
+        }
+    }
+}
+
+/// 냉각/복수 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CoolingTab {
+    pub condenser: CondenserInputs,
+    pub tower: CoolingTowerInputs,
+    pub evaporative: EvaporativeCoolerInputs,
+    pub air_cooled: AirCooledCondenserInputs,
+    pub comparison: CoolingComparisonInputs,
+    pub npsh: PumpNpshInputs,
+    pub saturated_npsh: SaturatedNpshInputs,
+    pub ejector: EjectorInputs,
+    pub drain_cooler: DrainCoolerInputs,
+}
+
+/// 신축 루프·동결·부식·압력 시험 카드가 함께 쓰는 배관 제원과 설계 조건.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlantLineInputs {
+    pub material: String,
+    pub outer_diameter_m: f64,
+    pub wall_thickness_m: f64,
+    pub design_pressure_bar_g: f64,
+    pub design_temp_c: f64,
+}
+
+impl Default for PlantLineInputs {
+    fn default() -> Self {
+        Self {
+            material: "A106B".into(),
+            outer_diameter_m: 0.114, // NPS 4" OD 약 114mm
+            wall_thickness_m: 0.006, // 6mm
+            design_pressure_bar_g: 40.0,
+            design_temp_c: 20.0,
+        }
+    }
+}
+
+/// 오리피스/노즐 유량 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct OrificeInputs {
+    pub dp: f64,
+    pub dp_unit: String,
+    pub up_p: f64,
+    pub up_unit: String,
+    #[serde(with = "pressure_mode")]
+    pub up_mode: PressureMode,
+    pub rho: f64,
+    pub cd: f64,
+    pub shape: String,
+    pub diam_unit: String,
+    pub diameter_m: f64,
+    pub beta: f64,
+    pub gamma: f64,
+    pub compressible: bool,
+    pub meter_pump_eff: f64,
+    pub meter_hours: f64,
+    pub meter_price: f64,
+}
+
+impl Default for OrificeInputs {
+    fn default() -> Self {
+        Self {
+            dp: 1.0,
+            dp_unit: "bar".into(),
+            up_p: 2.0,
+            up_unit: "bar".into(),
+            up_mode: PressureMode::Gauge,
+            rho: 1000.0,
+            cd: 0.62,
+            shape: "Orifice (sharp)".into(),
+            diam_unit: "m".into(),
+            diameter_m: 0.05,
+            beta: 0.3,
+            gamma: 1.3,
+            compressible: false,
+            meter_pump_eff: 0.75,
+            meter_hours: 8000.0,
+            meter_price: 0.1,
+        }
+    }
+}
+
+/// 증기 유량계 밀도 보정 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MeterCompensationInputs {
+    pub meter: MeterPrinciple,
+    pub design_p_barg: f64,
+    pub design_t_c: f64,
+    pub p_barg: f64,
+    pub t_c: f64,
+    pub flow_kg_h: f64,
+}
+
+impl Default for MeterCompensationInputs {
+    fn default() -> Self {
+        Self {
+            meter: MeterPrinciple::DifferentialPressure,
+            design_p_barg: 10.0,
+            design_t_c: 0.0,
+            p_barg: 8.0,
+            t_c: 0.0,
+            flow_kg_h: 5000.0,
+        }
+    }
+}
+
+/// 히스토리안 적산 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TotalizerInputs {
+    pub ref_t_c: f64,
+    pub mcr_kg_h: f64,
+}
+
+impl Default for TotalizerInputs {
+    fn default() -> Self {
+        Self {
+            ref_t_c: 105.0,
+            mcr_kg_h: 0.0,
+        }
+    }
+}
+
+/// 신축 루프 카드. 관 제원은 `line`에 있다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ExpansionLoopInputs {
+    pub layout: usize,
+    pub run_length_m: f64,
+    pub delta_t_k: f64,
+    pub available_leg_m: f64,
+}
+
+impl Default for ExpansionLoopInputs {
+    fn default() -> Self {
+        Self {
+            layout: 0,
+            run_length_m: 10.0,
+            delta_t_k: 50.0,
+            available_leg_m: 0.0,
+        }
+    }
+}
+
+/// 동결 방지(정체 수배관) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FreezeProtectionInputs {
+    pub insulation_m: f64,
+    pub k_w_mk: f64,
+    pub wind_m_s: f64,
+    pub ambient_c: f64,
+    pub initial_c: f64,
+}
+
+impl Default for FreezeProtectionInputs {
+    fn default() -> Self {
+        Self {
+            insulation_m: 0.025,
+            k_w_mk: 0.04,
+            wind_m_s: 5.0,
+            ambient_c: -20.0,
+            initial_c: 20.0,
+        }
+    }
+}
+
+/// 부식 여유·잔여 수명 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CorrosionInputs {
+    pub class_choice: Option<String>,
+    pub class_dn: u32,
+    pub weld_eff: f64,
+    pub corrosion_allowance_m: f64,
+    pub mill_tolerance_frac: f64,
+    pub measured_m: f64,
+    pub rate_mm_yr: f64,
+    pub years: f64,
+    pub structural_m: f64,
+}
+
+impl Default for CorrosionInputs {
+    fn default() -> Self {
+        Self {
+            class_choice: None,
+            class_dn: 100,
+            weld_eff: 1.0,
+            corrosion_allowance_m: 0.001, // 1 mm
+            mill_tolerance_frac: 0.125,   // 12.5% 밀 톨
+            measured_m: 0.0052,
+            rate_mm_yr: 0.0, // 0 = 사용 연수로 장기 부식 속도 계산
+            years: 10.0,
+            structural_m: 0.0,
+        }
+    }
+}
+
+/// 수압/기압 시험 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PressureTestInputs {
+    pub code: usize,
+    pub medium: usize,
+    pub temp_c: f64,
+    pub hold_min: f64,
+    pub volume_m3: f64,
+}
+
+impl Default for PressureTestInputs {
+    fn default() -> Self {
+        Self {
+            code: 1, // B31.3
+            medium: 0,
+            temp_c: 20.0,
+            hold_min: 10.0,
+            volume_m3: 1.0,
+        }
+    }
+}
+
+/// 충수량·만수 중량 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LineFillInputs {
+    /// 충수량 카드: 관경별 길이 목록 ("DN100:120, DN50:35")
+    pub lines: String,
+    pub temp_c: f64,
+    /// 배수 밸브 Cv (0 = 배수 시간 생략)
+    pub drain_cv: f64,
+    pub head_m: f64,
+}
+
+impl Default for LineFillInputs {
+    fn default() -> Self {
+        Self {
+            lines: "DN100:120, DN50:35".into(),
+            temp_c: 15.0,
+            drain_cv: 10.0,
+            head_m: 5.0,
+        }
+    }
+}
+
+/// 산업용 가스 물성 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GasPropertiesInputs {
+    pub gas: usize,
+    pub p_bar_abs: f64,
+    pub t_c: f64,
+}
+
+impl Default for GasPropertiesInputs {
+    fn default() -> Self {
+        Self {
+            gas: 0,
+            p_bar_abs: 10.0,
+            t_c: 20.0,
+        }
+    }
+}
+
+/// 용기/헤더 블로다운 시간 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VesselBlowdownInputs {
+    pub volume_m3: f64,
+    pub p0_bar_abs: f64,
+    pub t0_c: f64,
+    pub end_bar_abs: f64,
+    pub gas: usize,
+    pub adiabatic: bool,
+    pub use_cv: bool,
+    pub orifice_mm: f64,
+    pub cd: f64,
+    pub cv: f64,
+}
+
+impl Default for VesselBlowdownInputs {
+    fn default() -> Self {
+        Self {
+            volume_m3: 1.0,
+            p0_bar_abs: 10.0,
+            t0_c: 20.0,
+            end_bar_abs: 1.1,
+            gas: 0,
+            adiabatic: false,
+            use_cv: false,
+            orifice_mm: 10.0,
+            cd: 0.62,
+            cv: 5.0,
+        }
+    }
+}
+
+/// 가스 감압 온도(줄-톰슨) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GasThrottlingInputs {
+    pub gas: usize,
+    pub p1_bar_abs: f64,
+    pub t1_c: f64,
+    pub p2_bar_abs: f64,
+    /// 상태식 대신 사용자 JT 계수 사용
+    pub use_coefficient: bool,
+    pub coefficient_k_per_bar: f64,
+}
+
+impl Default for GasThrottlingInputs {
+    fn default() -> Self {
+        Self {
+            gas: Gas::ALL.len() - 1,
+            p1_bar_abs: 70.0,
+            t1_c: 15.0,
+            p2_bar_abs: 20.0,
+            use_coefficient: false,
+            coefficient_k_per_bar: 0.5,
+        }
+    }
+}
+
+/// 압축기 동력/토출 온도 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CompressorInputs {
+    pub gas: usize,
+    pub p1_bar_abs: f64,
+    pub t1_c: f64,
+    pub p2_bar_abs: f64,
+    pub flow_kg_h: f64,
+    /// 효율 기준: 폴리트로픽(true) / 등엔트로피(false)
+    pub polytropic: bool,
+    pub efficiency: f64,
+    /// 단수 (0 = 토출 온도 한계로 자동)
+    pub stages: u32,
+    pub intercooler_c: f64,
+    pub max_discharge_c: f64,
+    pub mech_efficiency: f64,
+}
+
+impl Default for CompressorInputs {
+    fn default() -> Self {
+        Self {
+            gas: Gas::ALL.iter().position(|&g| g == Gas::Air).unwrap_or(0),
+            p1_bar_abs: 1.013,
+            t1_c: 20.0,
+            p2_bar_abs: 8.0,
+            flow_kg_h: 1000.0,
+            polytropic: false,
+            efficiency: 0.8,
+            stages: 0,
+            intercooler_c: 35.0,
+            max_discharge_c: 200.0,
+            mech_efficiency: 0.95,
+        }
+    }
+}
+
+/// 감압 소음/AIV 선별 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LetdownNoiseInputs {
+    pub p1_bar_abs: f64,
+    pub p2_bar_abs: f64,
+    pub t1_c: f64,
+    pub flow_kg_h: f64,
+    pub molar_mass: f64,
+    pub od_mm: f64,
+    pub wall_mm: f64,
+}
+
+impl Default for LetdownNoiseInputs {
+    fn default() -> Self {
+        Self {
+            p1_bar_abs: 40.0,
+            p2_bar_abs: 5.0,
+            t1_c: 250.0,
+            flow_kg_h: 20_000.0,
+            molar_mass: STEAM_MOLAR_MASS,
+            od_mm: 323.9,
+            wall_mm: 9.53,
+        }
+    }
+}
+
+/// 증기 벤트/사일런서 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VentSilencerInputs {
+    pub p0_bar_abs: f64,
+    pub t0_c: f64,
+    pub saturated: bool,
+    pub throat_mm: f64,
+    pub cd: f64,
+    pub pipe_id_mm: f64,
+    pub distance_m: f64,
+    pub limit_dba: f64,
+}
+
+impl Default for VentSilencerInputs {
+    fn default() -> Self {
+        Self {
+            p0_bar_abs: 41.0,
+            t0_c: 400.0,
+            saturated: false,
+            throat_mm: 50.0,
+            cd: 0.9,
+            pipe_id_mm: 154.1,
+            distance_m: 30.0,
+            limit_dba: 85.0,
+        }
+    }
+}
+
+/// 플랜트 배관 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlantPipingTab {
+    pub line: PlantLineInputs,
+    pub orifice: OrificeInputs,
+    pub meter_comp: MeterCompensationInputs,
+    pub totalizer: TotalizerInputs,
+    pub expansion_loop: ExpansionLoopInputs,
+    pub freeze: FreezeProtectionInputs,
+    pub corrosion: CorrosionInputs,
+    pub pressure_test: PressureTestInputs,
+    pub line_fill: LineFillInputs,
+    pub gas_properties: GasPropertiesInputs,
+    pub blowdown: VesselBlowdownInputs,
+    pub throttling: GasThrottlingInputs,
+    pub compressor: CompressorInputs,
+    pub aiv: LetdownNoiseInputs,
+    pub vent: VentSilencerInputs,
+}
+
+/// 회수 배관 구경 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReturnLineInputs {
+    pub kind: ReturnLineKind,
+    pub flow_kg_h: f64,
+    pub source_p_bar_abs: f64,
+    pub receiver_p_bar_abs: f64,
+    pub length_m: f64,
+    pub roughness_mm: f64,
+    pub lift_m: f64,
+    pub max_velocity: f64,
+}
+
+impl Default for ReturnLineInputs {
+    fn default() -> Self {
+        Self {
+            kind: ReturnLineKind::TrapDischarge,
+            flow_kg_h: 2_000.0,
+            source_p_bar_abs: 11.0,
+            receiver_p_bar_abs: 1.2,
+            length_m: 50.0,
+            roughness_mm: 0.045,
+            lift_m: 0.0,
+            max_velocity: ReturnLineKind::TrapDischarge.default_velocity_limit_m_per_s(),
+        }
+    }
+}
+
+/// 탱크 가열 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TankHeatingInputs {
+    pub injection: bool,
+    pub mass_kg: f64,
+    pub cp_kj_kgk: f64,
+    pub initial_temp_c: f64,
+    pub target_temp_c: f64,
+    pub time_h: f64,
+    pub steam_p_bar_abs: f64,
+    pub coil_u_w_m2k: f64,
+    pub wall_area_m2: f64,
+    pub wall_u_w_m2k: f64,
+    pub ambient_c: f64,
+}
+
+impl Default for TankHeatingInputs {
+    fn default() -> Self {
+        Self {
+            injection: false,
+            mass_kg: 10_000.0,
+            cp_kj_kgk: 4.19,
+            initial_temp_c: 15.0,
+            target_temp_c: 80.0,
+            time_h: 2.0,
+            steam_p_bar_abs: 5.0,
+            coil_u_w_m2k: 500.0,
+            wall_area_m2: 40.0,
+            wall_u_w_m2k: 1.0,
+            ambient_c: 15.0,
+        }
+    }
+}
+
+/// 직접 증기 주입(스파저) 카드.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpargerInputs {
+    pub water_kg_h: f64,
+    pub inlet_c: f64,
+    pub target_c: f64,
+    pub liquid_p_bar_abs: f64,
+    pub submergence_m: f64,
+    pub steam_p_bar_abs: f64,
+    pub hole_mm: f64,
+    pub hole_count: u32,
+    pub cd: f64,
+}
+
+impl Default for SpargerInputs {
+    fn default() -> Self {
+        Self {
+            water_kg_h: 10_000.0,
+            inlet_c: 20.0,
+            target_c: 80.0,
+            liquid_p_bar_abs: 1.01325,
+            submergence_m: 1.0,
+            steam_p_bar_abs: 5.0,
+            hole_mm: 6.0,
+            hole_count: 30,
+            cd: 0.7,
+        }
+    }
+}
+
+/// 응축수 회수 탭.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CondensateTab {
+    pub return_line: ReturnLineInputs,
+    pub tank: TankHeatingInputs,
+    pub sparger: SpargerInputs,
+}
+
+/// 대시보드에 고정할 수 있는 카드. 계산기마다 하나씩 있다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardId {
+    UnitConversion,
+    FlowConversion,
+    SteamProperties,
+    Dryness,
+    PipeSizing,
+    PipeLoss,
+    PipeRun,
+    SteamBlow,
+    LineCondensation,
+    ValveSizing,
+    ValveAuthority,
+    ValveActuator,
+    LineCase,
+    TurbineBypass,
+    BoilerEfficiency,
+    Drum,
+    SteamPurity,
+    RankineCycle,
+    HeatBalance,
+    Hrsg,
+    Attemperator,
+    AirPreheater,
+    Emissions,
+    Condenser,
+    CoolingTower,
+    EvaporativeCooler,
+    AirCooledCondenser,
+    CoolingComparison,
+    PumpNpsh,
+    SaturatedNpsh,
+    Ejector,
+    DrainCooler,
+    Orifice,
+    MeterCompensation,
+    Totalizer,
+    ExpansionLoop,
+    FreezeProtection,
+    Corrosion,
+    PressureTest,
+    LineFill,
+    GasProperties,
+    VesselBlowdown,
+    GasThrottling,
+    Compressor,
+    LetdownNoise,
+    VentSilencer,
+    ReturnLine,
+    TankHeating,
+    Sparger,
+}
+
+impl CardId {
+    pub const ALL: [CardId; 49] = [
+        CardId::UnitConversion,
+        CardId::FlowConversion,
+        CardId::SteamProperties,
+        CardId::Dryness,
+        CardId::PipeSizing,
+        CardId::PipeLoss,
+        CardId::PipeRun,
+        CardId::SteamBlow,
+        CardId::LineCondensation,
+        CardId::ValveSizing,
+        CardId::ValveAuthority,
+        CardId::ValveActuator,
+        CardId::LineCase,
+        CardId::TurbineBypass,
+        CardId::BoilerEfficiency,
+        CardId::Drum,
+        CardId::SteamPurity,
+        CardId::RankineCycle,
+        CardId::HeatBalance,
+        CardId::Hrsg,
+        CardId::Attemperator,
+        CardId::AirPreheater,
+        CardId::Emissions,
+        CardId::Condenser,
+        CardId::CoolingTower,
+        CardId::EvaporativeCooler,
+        CardId::AirCooledCondenser,
+        CardId::CoolingComparison,
+        CardId::PumpNpsh,
+        CardId::SaturatedNpsh,
+        CardId::Ejector,
+        CardId::DrainCooler,
+        CardId::Orifice,
+        CardId::MeterCompensation,
+        CardId::Totalizer,
+        CardId::ExpansionLoop,
+        CardId::FreezeProtection,
+        CardId::Corrosion,
+        CardId::PressureTest,
+        CardId::LineFill,
+        CardId::GasProperties,
+        CardId::VesselBlowdown,
+        CardId::GasThrottling,
+        CardId::Compressor,
+        CardId::LetdownNoise,
+        CardId::VentSilencer,
+        CardId::ReturnLine,
+        CardId::TankHeating,
+        CardId::Sparger,
+    ];
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            CardId::UnitConversion => "card.unit_conversion",
+            CardId::FlowConversion => "card.flow_conversion",
+            CardId::SteamProperties => "card.steam_properties",
+            CardId::Dryness => "card.dryness",
+            CardId::PipeSizing => "card.pipe_sizing",
+            CardId::PipeLoss => "card.pipe_loss",
+            CardId::PipeRun => "card.pipe_run",
+            CardId::SteamBlow => "card.steam_blow",
+            CardId::LineCondensation => "card.line_condensation",
+            CardId::ValveSizing => "card.valve_sizing",
+            CardId::ValveAuthority => "card.valve_authority",
+            CardId::ValveActuator => "card.valve_actuator",
+            CardId::LineCase => "card.line_case",
+            CardId::TurbineBypass => "card.turbine_bypass",
+            CardId::BoilerEfficiency => "card.boiler_efficiency",
+            CardId::Drum => "card.drum",
+            CardId::SteamPurity => "card.steam_purity",
+            CardId::RankineCycle => "card.rankine_cycle",
+            CardId::HeatBalance => "card.heat_balance",
+            CardId::Hrsg => "card.hrsg",
+            CardId::Attemperator => "card.attemperator",
+            CardId::AirPreheater => "card.air_preheater",
+            CardId::Emissions => "card.emissions",
+            CardId::Condenser => "card.condenser",
+            CardId::CoolingTower => "card.cooling_tower",
+            CardId::EvaporativeCooler => "card.evaporative_cooler",
+            CardId::AirCooledCondenser => "card.air_cooled_condenser",
+            CardId::CoolingComparison => "card.cooling_comparison",
+            CardId::PumpNpsh => "card.pump_npsh",
+            CardId::SaturatedNpsh => "card.saturated_npsh",
+            CardId::Ejector => "card.ejector",
+            CardId::DrainCooler => "card.drain_cooler",
+            CardId::Orifice => "card.orifice",
+            CardId::MeterCompensation => "card.meter_compensation",
+            CardId::Totalizer => "card.totalizer",
+            CardId::ExpansionLoop => "card.expansion_loop",
+            CardId::FreezeProtection => "card.freeze_protection",
+            CardId::Corrosion => "card.corrosion",
+            CardId::PressureTest => "card.pressure_test",
+            CardId::LineFill => "card.line_fill",
+            CardId::GasProperties => "card.gas_properties",
+            CardId::VesselBlowdown => "card.vessel_blowdown",
+            CardId::GasThrottling => "card.gas_throttling",
+            CardId::Compressor => "card.compressor",
+            CardId::LetdownNoise => "card.letdown_noise",
+            CardId::VentSilencer => "card.vent_silencer",
+            CardId::ReturnLine => "card.return_line",
+            CardId::TankHeating => "card.tank_heating",
+            CardId::Sparger => "card.sparger",
+        }
+    }
+
+    /// 언어팩이 없을 때 쓰는 이름.
+    pub fn label(self) -> &'static str {
+        match self {
+            CardId::UnitConversion => "Unit conversion",
+            CardId::FlowConversion => "Flow / power conversion",
+            CardId::SteamProperties => "Saturation/Superheat",
+            CardId::Dryness => "Dryness fraction calorimeters",
+            CardId::PipeSizing => "Pipe sizing",
+            CardId::PipeLoss => "Pipe pressure loss",
+            CardId::PipeRun => "Pipe run (multi-segment)",
+            CardId::SteamBlow => "Steam blow cleaning force ratio",
+            CardId::LineCondensation => "Line condensation",
+            CardId::ValveSizing => "Valve Cv/Kv",
+            CardId::ValveAuthority => "Valve authority & installed characteristic",
+            CardId::ValveActuator => "Valve actuator thrust/torque",
+            CardId::LineCase => "Line case cross-check",
+            CardId::TurbineBypass => "Turbine bypass valve / spray TCV",
+            CardId::BoilerEfficiency => "Boiler efficiency",
+            CardId::Drum => "Drum swell/shrink & blowdown line check",
+            CardId::SteamPurity => "Steam purity / carryover",
+            CardId::RankineCycle => "Rankine cycle heat rate",
+            CardId::HeatBalance => "Heat balance diagram",
+            CardId::Hrsg => "HRSG pinch / approach",
+            CardId::Attemperator => "Attemperator spray / boiler output",
+            CardId::AirPreheater => "Combustion air preheater",
+            CardId::Emissions => "Emissions (CO₂ / SO₂ / NOx)",
+            CardId::Condenser => "Condenser heat balance / vacuum",
+            CardId::CoolingTower => "Cooling tower (range / approach)",
+            CardId::EvaporativeCooler => "Direct evaporative air cooler",
+            CardId::AirCooledCondenser => "Air-cooled condenser / fin-fan",
+            CardId::CoolingComparison => "Once-through vs recirculating cooling",
+            CardId::PumpNpsh => "Circulating pump NPSH",
+            CardId::SaturatedNpsh => "Hotwell / flash tank pump NPSH",
+            CardId::Ejector => "Steam jet ejector",
+            CardId::DrainCooler => "Drain cooler / reheater heat balance",
+            CardId::Orifice => "Orifice / nozzle flow check",
+            CardId::MeterCompensation => "Steam meter density compensation",
+            CardId::Totalizer => "Historian totalizer",
+            CardId::ExpansionLoop => "Expansion loop sizing",
+            CardId::FreezeProtection => "Freeze protection (stagnant water line)",
+            CardId::Corrosion => "Corrosion allowance & remaining life",
+            CardId::PressureTest => "Hydrotest / pneumatic test pressure",
+            CardId::LineFill => "Line fill volume and water-filled weight",
+            CardId::GasProperties => "Industrial gas properties",
+            CardId::VesselBlowdown => "Vessel / header blowdown time",
+            CardId::GasThrottling => "Gas letdown temperature (Joule-Thomson)",
+            CardId::Compressor => "Compressor power / discharge temperature",
+            CardId::LetdownNoise => "Letdown noise / AIV screening",
+            CardId::VentSilencer => "Steam vent / silencer sizing",
+            CardId::ReturnLine => "Return line sizing",
+            CardId::TankHeating => "Tank heat-up",
+            CardId::Sparger => "Direct steam injection (sparger)",
+        }
+    }
+}
+
+/// 대시보드 탭. 고정한 순서대로 보여 준다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DashboardTab {
+    pub pinned: Vec<CardId>,
+}
+
+impl DashboardTab {
+    pub fn is_pinned(&self, card: CardId) -> bool {
+        self.pinned.contains(&card)
+    }
+
+    /// 고정되어 있으면 빼고, 아니면 맨 뒤에 붙인다.
+    pub fn toggle(&mut self, card: CardId) {
+        if self.is_pinned(card) {
+            self.pinned.retain(|&c| c != card);
+        } else {
+            self.pinned.push(card);
+        }
+    }
+}
+
+/// 보존 대상 GUI 입력 전체.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GuiInputs {
+    pub dashboard: DashboardTab,
+    pub unit_conv: UnitConvTab,
+    pub steam_tables: SteamTablesTab,
+    pub piping: PipingTab,
+    pub valves: ValvesTab,
+    pub boiler: BoilerTab,
+    pub cooling: CoolingTab,
+    pub plant_piping: PlantPipingTab,
+    pub condensate: CondensateTab,
+}
+
+impl GuiInputs {
+    /// 설정의 유체 기본값을 반영한 초기 입력.
+    pub fn for_fluid(fluid: &FluidDefaults) -> Self {
+        Self {
+            valves: ValvesTab {
+                sizing: ValveInputs::for_fluid(fluid),
+                ..ValvesTab::default()
+            },
+            cooling: CoolingTab {
+                npsh: PumpNpshInputs::for_fluid(fluid),
+                ..CoolingTab::default()
+            },
+            ..Self::default()
+        }
+    }
+
+    /// 대기 온도 입력을 설정의 현장 설계 조건으로 바꾼다.
+    pub fn with_site(mut self, site: &SiteConditions) -> Self {
+        self.cooling.tower = CoolingTowerInputs::for_site(site);
+        self.cooling.evaporative = EvaporativeCoolerInputs::for_site(site);
+        self
+    }
+
+    pub fn from_toml_str(content: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(content)?)
+    }
+
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// 파일에서 읽는다. 파일이 없으면 `None`.
+    pub fn load(path: &Path) -> Result<Option<Self>, ConfigError> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        Self::from_toml_str(&fs::read_to_string(path)?).map(Some)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        fs::write(path, self.to_toml_string()?)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,2033 @@
+//! 프롬프트 없이 한 번에 계산하는 CLI 서브커맨드 (`steam sat --p 10bar_a` 등).
+//! 값 인자는 `quantity_arg` 형식(숫자+단위)으로 받고, 결과는 `report::CalcReport`로 만들어
+//! `--format text|json|yaml`에 맞춰 출력한다.
+
+use clap::{Args, Subcommand, ValueEnum};
+
+use crate::app::AppError;
+use crate::config::UnitSystem;
+use crate::custom_units;
+use crate::flow_units::{self, FlowKind};
+use crate::i18n::{self, Translator};
+use crate::material_db;
+use crate::number_format::NumberFormat;
+use crate::plant_piping::{line_fill, pipe_class};
+use crate::quantity::QuantityKind;
+use crate::quantity_arg::{self, QuantityArgError};
+use crate::report::{CalcReport, ReportCell, ReportColumn, ReportTable, ReportValue};
+use crate::show_work;
+use crate::steam::{
+    self,
+    critical_flow::SteamStagnation,
+    meter_compensation::{self, MeterPrinciple, SteamCondition},
+    reference_table,
+    relief_scenario::{self, ReliefStudy},
+    steam_dryness::{self, SeparatingCalorimeterInput, ThrottlingCalorimeterInput},
+    steam_piping::{
+        fittings::{FittingList, FittingMethod},
+        pipe_run::{self, PipeRunInput},
+        velocity_check::{self, PipeService},
+        PipeSizingByVelocityInput, PressureLossInput,
+    },
+    table_booklet::{self, BookletOptions},
+    totalizer,
+};
+use crate::strapping_table::{self, LiquidExpansion, StrappingInput};
+use crate::typed_quantity::{Atmosphere, Pressure, Temperature};
+use crate::vessel_geometry::{HeadType, Vessel, VesselOrientation};
+use crate::warning::{CalcWarning, Severity};
+
+/// 최상위 서브커맨드.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Steam tables (saturation / superheated state)
+    #[command(subcommand)]
+    Steam(SteamCommand),
+    /// Steam pipe sizing and pressure drop
+    #[command(subcommand)]
+    Pipe(PipeCommand),
+    /// Valve Kv/Cv sizing and flow capacity
+    #[command(subcommand)]
+    Valve(ValveCommand),
+    /// Steam dryness from calorimeter readings
+    #[command(subcommand)]
+    Dryness(DrynessCommand),
+    /// Unit conversion, e.g. `convert pressure 10bar psi`
+    Convert(ConvertArgs),
+    /// Steam flow meter density compensation for one reading or a historian CSV
+    #[command(name = "meter-comp")]
+    MeterComp(MeterCompArgs),
+    /// Totalize a historian CSV into steam mass, energy and average conditions
+    Totalize(TotalizeArgs),
+    /// Relief scenarios from a TOML file: loads per protected system and the governing PSV load
+    Relief(ReliefArgs),
+    /// Level vs volume strapping table for a vessel, corrected to a reference temperature; --out writes CSV
+    Strapping(StrappingArgs),
+    /// Print-ready saturation and superheated steam table booklet as an HTML file (print to PDF from a browser)
+    Booklet(BookletArgs),
+    /// Compare the IF97 implementation against the official IAPWS-IF97 verification tables
+    #[command(name = "verify-if97", hide = true)]
+    VerifyIf97,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SteamCommand {
+    /// Saturation state from pressure (--p) or temperature (--t)
+    Sat {
+        /// Pressure, e.g. 10bar_a, 9barg, 1MPa (no suffix = absolute)
+        #[arg(long = "p", required_unless_present = "t", conflicts_with = "t")]
+        p: Option<String>,
+        /// Temperature, e.g. 180C, 453K
+        #[arg(long = "t")]
+        t: Option<String>,
+        /// Also compare IF97 with the bundled IAPWS-95 reference table (nearest tabulated point)
+        #[arg(long)]
+        compare: bool,
+    },
+    /// Superheated state at pressure and temperature
+    Superheat {
+        #[arg(long = "p")]
+        p: String,
+        #[arg(long = "t")]
+        t: String,
+        /// Also compare IF97 with the bundled IAPWS-95 reference table (nearest tabulated point)
+        #[arg(long)]
+        compare: bool,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PipeCommand {
+    /// Inner diameter for a target velocity
+    Size {
+        /// Mass flow, e.g. 5t/h, 1.2kg/s (no unit = kg/h)
+        #[arg(long = "mdot")]
+        mdot: String,
+        /// Operating pressure, e.g. 10barg
+        #[arg(long = "p")]
+        p: String,
+        /// Operating temperature, e.g. 200C
+        #[arg(long = "t")]
+        t: String,
+        /// Target velocity (no unit = m/s)
+        #[arg(long = "v")]
+        v: String,
+        /// Density override (no unit = kg/m3); default from IF97
+        #[arg(long = "rho")]
+        rho: Option<String>,
+        /// Service for the velocity check; default from pressure and temperature
+        #[arg(long = "service", value_enum)]
+        service: Option<PipeServiceArg>,
+        /// Piping class from pipe_classes.toml; picks the smallest class size that fits
+        /// and checks it against the class limits at --p/--t
+        #[arg(long = "class")]
+        class: Option<String>,
+    },
+    /// Darcy-Weisbach pressure drop
+    Dp {
+        #[arg(long = "mdot")]
+        mdot: String,
+        #[arg(long = "p")]
+        p: String,
+        #[arg(long = "t")]
+        t: String,
+        /// Inner diameter (no unit = mm), e.g. 102.3mm, 4in
+        #[arg(long = "d")]
+        d: String,
+        /// Straight length (no unit = m)
+        #[arg(long = "l")]
+        l: String,
+        /// Additional equivalent length (no unit = m)
+        #[arg(long = "eq-len", default_value = "0")]
+        eq_len: String,
+        /// Inlet elevation (no unit = m)
+        #[arg(long = "z-in", default_value = "0")]
+        z_in: String,
+        /// Outlet elevation (no unit = m); ρgΔz is reported apart from friction
+        #[arg(long = "z-out", default_value = "0")]
+        z_out: String,
+        /// Additional K sum on top of `--fittings`
+        #[arg(long = "k", default_value_t = 0.0)]
+        k: f64,
+        /// Fitting counts from the catalog, e.g. "elbow90-lr:4, gate:2, exit"
+        #[arg(long = "fittings", default_value = "")]
+        fittings: String,
+        /// K method for `--fittings`
+        #[arg(long = "fitting-method", value_enum, default_value_t = FittingMethodArg::TwoK)]
+        fitting_method: FittingMethodArg,
+        /// Absolute roughness (no unit = mm) or a material preset:
+        /// steel-new, steel-corroded, stainless, copper, pvc, concrete
+        #[arg(long = "roughness", default_value = "0.045mm")]
+        roughness: String,
+        /// Dynamic viscosity [Pa·s]
+        #[arg(long = "visc", default_value_t = 1.5e-5)]
+        visc: f64,
+        /// Speed of sound for Mach (no unit = m/s)
+        #[arg(long = "sound-speed", default_value = "450")]
+        sound_speed: String,
+        /// Density override (no unit = kg/m3); default from IF97
+        #[arg(long = "rho")]
+        rho: Option<String>,
+    },
+    /// Multi-segment pipe run from a CSV of segments
+    /// (name,diameter_mm,length_m,rise_m[,roughness][,extra_k][,fittings])
+    Run {
+        /// Segment CSV file
+        #[arg(long = "csv")]
+        csv: String,
+        #[arg(long = "mdot")]
+        mdot: String,
+        /// Inlet pressure
+        #[arg(long = "p")]
+        p: String,
+        /// Inlet temperature
+        #[arg(long = "t")]
+        t: String,
+        /// K method for the fittings column
+        #[arg(long = "fitting-method", value_enum, default_value_t = FittingMethodArg::TwoK)]
+        fitting_method: FittingMethodArg,
+        /// Dynamic viscosity [Pa·s]
+        #[arg(long = "visc", default_value_t = 1.5e-5)]
+        visc: f64,
+        /// Speed of sound for Mach (no unit = m/s)
+        #[arg(long = "sound-speed", default_value = "450")]
+        sound_speed: String,
+    },
+    /// Fill water volume, water-filled weight and drain time
+    Fill {
+        /// Lengths per size, e.g. "DN100:120, 50:35" (DN15-DN300, Sch40)
+        #[arg(long = "lines")]
+        lines: String,
+        /// Fill water temperature
+        #[arg(long = "t", default_value = "15C")]
+        t: String,
+        /// Drain valve Cv (US); drain time is skipped without it
+        #[arg(long = "drain-cv")]
+        drain_cv: Option<f64>,
+        /// Static head from the high point to the drain (no unit = m)
+        #[arg(long = "head", default_value = "1")]
+        head: String,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ValveCommand {
+    /// Required Kv/Cv for a volumetric flow
+    Kv {
+        /// Volumetric flow, e.g. 12m3/h, 50gpm (no unit = m3/h)
+        #[arg(long = "q")]
+        q: String,
+        /// Pressure differential (no unit = bar)
+        #[arg(long = "dp")]
+        dp: String,
+        /// Fluid density (no unit = kg/m3)
+        #[arg(long = "rho")]
+        rho: String,
+    },
+    /// Flow through a valve of given Kv or Cv
+    Flow {
+        #[arg(long = "kv", required_unless_present = "cv", conflicts_with = "cv")]
+        kv: Option<f64>,
+        #[arg(long = "cv")]
+        cv: Option<f64>,
+        #[arg(long = "dp")]
+        dp: String,
+        #[arg(long = "rho")]
+        rho: String,
+        /// Upstream pressure for the choked-flow check, e.g. 10bar_a
+        #[arg(long = "p1")]
+        p1: Option<String>,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum DrynessCommand {
+    /// Throttling calorimeter
+    Throttling(ThrottlingArgs),
+    /// Separating calorimeter
+    Separating(SeparatingArgs),
+    /// Separating + throttling calorimeter in series
+    Combined {
+        #[command(flatten)]
+        separating: SeparatingArgs,
+        #[command(flatten)]
+        throttling: ThrottlingArgs,
+    },
+}
+
+#[derive(Args, Debug)]
+pub struct ThrottlingArgs {
+    /// Main steam pressure, e.g. 10bar_a
+    #[arg(long = "p-main")]
+    pub p_main: String,
+    /// Calorimeter pressure after throttling, e.g. 1.013bar_a
+    #[arg(long = "p-cal")]
+    pub p_cal: String,
+    /// Calorimeter temperature after throttling, e.g. 120C
+    #[arg(long = "t-cal")]
+    pub t_cal: String,
+}
+
+#[derive(Args, Debug)]
+pub struct SeparatingArgs {
+    /// Water collected in the separator [kg]
+    #[arg(long = "water-kg")]
+    pub water_kg: f64,
+    /// Condensate collected after the separator [kg]
+    #[arg(long = "condensate-kg")]
+    pub condensate_kg: f64,
+}
+
+#[derive(Args, Debug)]
+pub struct ConvertArgs {
+    /// Quantity kind: temperature, pressure, length, ..., mass_flow, volumetric_flow, power (heat_flow), density, specific_volume, specific_heat
+    pub kind: String,
+    /// Value with source unit, e.g. 10bar, 5t/h
+    pub value: String,
+    /// Target unit
+    pub to: String,
+}
+
+#[derive(Args, Debug)]
+pub struct MeterCompArgs {
+    /// Meter principle
+    #[arg(long = "meter", value_enum)]
+    pub meter: MeterPrincipleArg,
+    /// Design (calibration) pressure, e.g. 10barg
+    #[arg(long = "design-p")]
+    pub design_p: String,
+    /// Design temperature, e.g. 250C; omit for dry saturated steam
+    #[arg(long = "design-t")]
+    pub design_t: Option<String>,
+    /// Indicated mass flow (no unit = kg/h)
+    #[arg(long = "flow", required_unless_present = "csv", conflicts_with = "csv")]
+    pub flow: Option<String>,
+    /// Actual pressure for --flow
+    #[arg(long = "p", required_unless_present = "csv", conflicts_with = "csv")]
+    pub p: Option<String>,
+    /// Actual temperature for --flow; omit for dry saturated steam
+    #[arg(long = "t", conflicts_with = "csv")]
+    pub t: Option<String>,
+    /// Historian CSV (timestamp,flow_kg_h,pressure_bar_g[,temperature_c]); every row is corrected
+    #[arg(long = "csv")]
+    pub csv: Option<String>,
+    /// Write the corrected rows to this CSV file (with --csv)
+    #[arg(long = "out", requires = "csv")]
+    pub out: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct TotalizeArgs {
+    /// Historian CSV (timestamp,flow_kg_h,pressure_bar_g[,temperature_c])
+    #[arg(long = "csv")]
+    pub csv: String,
+    /// Feedwater temperature for the energy reference, e.g. 105C; omit for the IF97 datum
+    #[arg(long = "ref-t")]
+    pub ref_t: Option<String>,
+    /// Boiler MCR for the load check (no unit = kg/h)
+    #[arg(long = "mcr")]
+    pub mcr: Option<String>,
+}
+
+#[derive(Args, Debug)]
+pub struct ReliefArgs {
+    /// Scenario file ([[system]] and [[scenario]] tables, see steam::relief_scenario)
+    #[arg(long = "file")]
+    pub file: String,
+}
+
+#[derive(Args, Debug)]
+pub struct StrappingArgs {
+    #[arg(long = "orientation", value_enum)]
+    pub orientation: OrientationArg,
+    #[arg(long = "heads", value_enum, default_value = "flat")]
+    pub heads: HeadsArg,
+    /// Inner diameter (no unit = m)
+    #[arg(long = "d")]
+    pub d: String,
+    /// Shell length tangent to tangent (no unit = m)
+    #[arg(long = "l")]
+    pub l: String,
+    /// Level increment (no unit = mm)
+    #[arg(long = "step", default_value = "10mm")]
+    pub step: String,
+    /// Liquid temperature, e.g. 60C
+    #[arg(long = "t", default_value = "15C")]
+    pub t: String,
+    /// Reference temperature for the corrected volume
+    #[arg(long = "ref-t", default_value = "15C")]
+    pub ref_t: String,
+    /// Volumetric expansion coefficient [1/K]; omit for water (IF97 at site atmosphere)
+    #[arg(long = "beta")]
+    pub beta: Option<f64>,
+    /// Write the table to this CSV file
+    #[arg(long = "out")]
+    pub out: Option<String>,
+}
+
+/// `strapping --orientation` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrientationArg {
+    Vertical,
+    Horizontal,
+}
+
+impl From<OrientationArg> for VesselOrientation {
+    fn from(value: OrientationArg) -> Self {
+        match value {
+            OrientationArg::Vertical => VesselOrientation::Vertical,
+            OrientationArg::Horizontal => VesselOrientation::Horizontal,
+        }
+    }
+}
+
+/// `strapping --heads` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeadsArg {
+    Flat,
+    /// 2:1 ellipsoidal
+    Ellipsoidal,
+    Hemispherical,
+}
+
+impl From<HeadsArg> for HeadType {
+    fn from(value: HeadsArg) -> Self {
+        match value {
+            HeadsArg::Flat => HeadType::Flat,
+            HeadsArg::Ellipsoidal => HeadType::Ellipsoidal,
+            HeadsArg::Hemispherical => HeadType::Hemispherical,
+        }
+    }
+}
+
+#[derive(Args, Debug)]
+pub struct BookletArgs {
+    /// Output HTML file
+    #[arg(long = "out")]
+    pub out: String,
+    /// Unit system for the tables; default is unit_system from config.toml
+    #[arg(long = "units", value_enum)]
+    pub units: Option<UnitSystemArg>,
+    /// Title printed at the top of every page
+    #[arg(long = "title", default_value = "Steam tables (IAPWS-IF97)")]
+    pub title: String,
+}
+
+/// `booklet --units` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum UnitSystemArg {
+    /// bar(a), °C, kJ/kg
+    #[default]
+    #[value(name = "si-bar")]
+    SiBar,
+    /// kPa(a), °C, kJ/kg
+    Si,
+    /// kgf/cm²(a), °C, kcal/kg
+    Mks,
+    /// psia, °F, Btu/lb
+    Imperial,
+}
+
+impl From<UnitSystem> for UnitSystemArg {
+    fn from(value: UnitSystem) -> Self {
+        match value {
+            UnitSystem::SIBar => UnitSystemArg::SiBar,
+            UnitSystem::SI => UnitSystemArg::Si,
+            UnitSystem::MKS => UnitSystemArg::Mks,
+            UnitSystem::Imperial => UnitSystemArg::Imperial,
+        }
+    }
+}
+
+impl From<UnitSystemArg> for UnitSystem {
+    fn from(value: UnitSystemArg) -> Self {
+        match value {
+            UnitSystemArg::SiBar => UnitSystem::SIBar,
+            UnitSystemArg::Si => UnitSystem::SI,
+            UnitSystemArg::Mks => UnitSystem::MKS,
+            UnitSystemArg::Imperial => UnitSystem::Imperial,
+        }
+    }
+}
+
+/// `meter-comp --meter` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeterPrincipleArg {
+    /// Orifice, nozzle, venturi or other differential-pressure meter
+    Dp,
+    /// Vortex (volumetric) meter
+    Vortex,
+}
+
+impl From<MeterPrincipleArg> for MeterPrinciple {
+    fn from(value: MeterPrincipleArg) -> Self {
+        match value {
+            MeterPrincipleArg::Dp => MeterPrinciple::DifferentialPressure,
+            MeterPrincipleArg::Vortex => MeterPrinciple::Vortex,
+        }
+    }
+}
+
+/// `pipe dp --fitting-method` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum FittingMethodArg {
+    /// Hooper 2-K (Reynolds and size dependent)
+    #[default]
+    #[value(name = "2k")]
+    TwoK,
+    /// Crane TP-410 L/D × f_T
+    Crane,
+}
+
+impl From<FittingMethodArg> for FittingMethod {
+    fn from(value: FittingMethodArg) -> Self {
+        match value {
+            FittingMethodArg::TwoK => FittingMethod::TwoK,
+            FittingMethodArg::Crane => FittingMethod::Crane,
+        }
+    }
+}
+
+/// `pipe size --service` 값.
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipeServiceArg {
+    /// Saturated steam
+    Saturated,
+    /// Superheated steam
+    Superheated,
+    /// Wet, exhaust or flash steam
+    Wet,
+    /// Pumped condensate
+    Condensate,
+    /// Cooling or feed water
+    Water,
+}
+
+impl From<PipeServiceArg> for PipeService {
+    fn from(value: PipeServiceArg) -> Self {
+        match value {
+            PipeServiceArg::Saturated => PipeService::SaturatedSteam,
+            PipeServiceArg::Superheated => PipeService::SuperheatedSteam,
+            PipeServiceArg::Wet => PipeService::WetSteam,
+            PipeServiceArg::Condensate => PipeService::Condensate,
+            PipeServiceArg::Water => PipeService::Water,
+        }
+    }
+}
+
+/// 결과 출력 형식.
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// 번역된 라벨이 붙은 사람이 읽는 텍스트
+    #[default]
+    Text,
+    /// `report::CalcReport` JSON 문서
+    Json,
+    /// `report::CalcReport` YAML 문서
+    Yaml,
+}
+
+/// 계산 결과 문서를 지정 형식으로 출력한다.
+pub fn print_report(
+    report: &CalcReport,
+    format: OutputFormat,
+    nf: &NumberFormat,
+) -> Result<(), AppError> {
+    let text = match format {
+        OutputFormat::Text => report.to_text(nf),
+        OutputFormat::Json => serde_json::to_string_pretty(report)
+            .map_err(|e| AppError::Io(std::io::Error::other(e)))?,
+        OutputFormat::Yaml => {
+            serde_yaml::to_string(report).map_err(|e| AppError::Io(std::io::Error::other(e)))?
+        }
+    };
+    println!("{}", text.trim_end());
+    Ok(())
+}
+
+/// 서브커맨드를 실행하고 결과를 출력한다. 게이지/진공 압력은 `atm` 기준으로 환산한다.
+/// `show_work`가 아니면 계산 과정을 빼고 출력한다.
+pub fn run(
+    command: &Command,
+    atm: Atmosphere,
+    tr: &Translator,
+    format: OutputFormat,
+    show_work: bool,
+) -> Result<(), AppError> {
+    let mut report = report(command, atm, tr)?;
+    if !show_work {
+        report.work.clear();
+    }
+    print_report(&report, format, tr.number_format())
+}
+
+/// 서브커맨드를 계산해 결과 문서를 만든다 (출력 없음). TUI도 같은 경로로 계산한다.
+pub fn report(command: &Command, atm: Atmosphere, tr: &Translator) -> Result<CalcReport, AppError> {
+    match command {
+        Command::Steam(cmd) => steam_report(cmd, atm, tr),
+        Command::Pipe(cmd) => pipe_report(cmd, atm, tr),
+        Command::Valve(cmd) => valve_report(cmd, atm, tr),
+        Command::Dryness(cmd) => dryness_report(cmd, atm, tr),
+        Command::Convert(args) => convert_report(args, tr),
+        Command::MeterComp(args) => meter_comp_report(args, atm, tr),
+        Command::Totalize(args) => totalize_report(args, atm, tr),
+        Command::Relief(args) => relief_report(args, atm, tr),
+        Command::Strapping(args) => strapping_report(args, atm),
+        Command::Booklet(args) => booklet_report(args, tr),
+        Command::VerifyIf97 => Ok(verify_if97_report()),
+    }
+}
+
+fn steam_report(
+    cmd: &SteamCommand,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    let (report, state) = match cmd {
+        SteamCommand::Sat { p: Some(p), .. } => {
+            let p = quantity_arg::parse_pressure(p, atm)?;
+            (
+                CalcReport::new("steam sat").input(ReportValue::new(
+                    "pressure",
+                    p.bar_abs(),
+                    "bar(a)",
+                )),
+                steam::saturation_at(p)?,
+            )
+        }
+        SteamCommand::Sat { t: Some(t), .. } => {
+            let t = quantity_arg::parse_temperature(t)?;
+            (
+                CalcReport::new("steam sat").input(ReportValue::new(
+                    "temperature",
+                    t.celsius(),
+                    "°C",
+                )),
+                steam::saturation_at_temperature(t)?,
+            )
+        }
+        SteamCommand::Sat { .. } => {
+            return Err(QuantityArgError::InvalidNumber("--p/--t".into()).into());
+        }
+        SteamCommand::Superheat { p, t, .. } => {
+            let p = quantity_arg::parse_pressure(p, atm)?;
+            let t = quantity_arg::parse_temperature(t)?;
+            (
+                CalcReport::new("steam superheat")
+                    .input(ReportValue::new("pressure", p.bar_abs(), "bar(a)"))
+                    .input(ReportValue::new("temperature", t.celsius(), "°C")),
+                steam::superheated_at_state(p, t)?,
+            )
+        }
+    };
+    // SteamState의 엔탈피·엔트로피는 IF97 그대로 J/kg, J/kgK다
+    let mut report = report
+        .result(
+            ReportValue::new(
+                "saturation_temperature",
+                state.saturation_temperature_c,
+                "°C",
+            )
+            .labeled(tr.t(i18n::keys::STATE_SATURATION_T), 2),
+        )
+        .result(
+            ReportValue::new("saturation_pressure", state.pressure_bar, "bar(a)")
+                .labeled(tr.t(i18n::keys::STATE_SATURATION_P), 4),
+        )
+        .result(
+            ReportValue::new(
+                "sat_vapor_enthalpy",
+                state.saturation_enthalpy_kj_per_kg / 1000.0,
+                "kJ/kg",
+            )
+            .labeled("h_g", 1),
+        )
+        .result(
+            ReportValue::new(
+                "sat_vapor_specific_volume",
+                state.saturation_specific_volume,
+                "m3/kg",
+            )
+            .labeled("v_g", 4),
+        )
+        .result(
+            ReportValue::new(
+                "sat_vapor_entropy",
+                state.saturation_entropy_kj_per_kgk / 1000.0,
+                "kJ/kgK",
+            )
+            .labeled("s_g", 4),
+        )
+        .result(
+            ReportValue::new(
+                "sat_liquid_enthalpy",
+                state.sat_liquid_enthalpy_kj_per_kg / 1000.0,
+                "kJ/kg",
+            )
+            .labeled("h_f", 1),
+        )
+        .result(
+            ReportValue::new(
+                "sat_liquid_specific_volume",
+                state.sat_liquid_specific_volume,
+                "m3/kg",
+            )
+            .labeled("v_f", 6),
+        )
+        .result(
+            ReportValue::new(
+                "sat_liquid_entropy",
+                state.sat_liquid_entropy_kj_per_kgk / 1000.0,
+                "kJ/kgK",
+            )
+            .labeled("s_f", 4),
+        );
+    if let Some(h) = state.superheated_enthalpy_kj_per_kg {
+        report = report
+            .intermediate(
+                ReportValue::new(
+                    "superheat",
+                    state.temperature_c - state.saturation_temperature_c,
+                    "K",
+                )
+                .labeled("ΔT_sh", 1),
+            )
+            .result(
+                ReportValue::new("superheated_enthalpy", h / 1000.0, "kJ/kg")
+                    .labeled(tr.t(i18n::keys::STATE_SUPERHEATED_ENTHALPY), 1),
+            );
+    }
+    let compare = match cmd {
+        SteamCommand::Sat { compare, .. } | SteamCommand::Superheat { compare, .. } => *compare,
+    };
+    if compare {
+        let comparison = reference_table::compare_state(&state);
+        report = reference_comparison_section(report, &comparison, tr);
+    }
+    Ok(report)
+}
+
+/// 참조 증기표 비교: 비교에 쓴 표 점, 최대 편차, 물성별 편차 표.
+fn reference_comparison_section(
+    report: CalcReport,
+    comparison: &reference_table::ReferenceComparison,
+    tr: &Translator,
+) -> CalcReport {
+    let mut report = report
+        .intermediate(
+            ReportValue::new(
+                "reference_point_pressure",
+                comparison.point.p_bar_abs,
+                "bar(a)",
+            )
+            .labeled(
+                &tr.format(
+                    "state.reference_point_pressure",
+                    "Reference point pressure:",
+                    &[],
+                ),
+                4,
+            ),
+        )
+        .intermediate(
+            ReportValue::new("reference_point_temperature", comparison.point.t_c, "°C")
+                .labeled(
+                    &tr.format(
+                        "state.reference_point_temperature",
+                        "Reference point temperature:",
+                        &[],
+                    ),
+                    2,
+                ),
+        )
+        .result(
+            ReportValue::new(
+                "max_reference_deviation",
+                comparison.max_abs_deviation_pct(),
+                "%",
+            )
+            .labeled(
+                &tr.format(
+                    "state.reference_max_deviation",
+                    "Max. deviation IF97 vs. reference:",
+                    &[],
+                ),
+                3,
+            ),
+        )
+        .warning(
+            Severity::Info,
+            tr.format(
+                "state.reference_source",
+                "IF97 compared with the {source} table at the nearest tabulated point; deviations around 0.05% come from the table's rounding.",
+                &[("source", reference_table::REFERENCE_SOURCE.to_string())],
+            ),
+        );
+    report.tables.push(ReportTable {
+        name: "reference_comparison".to_string(),
+        columns: [
+            ("property", ""),
+            ("unit", ""),
+            ("reference", ""),
+            ("if97", ""),
+            ("deviation", "%"),
+        ]
+        .iter()
+        .map(|(name, unit)| ReportColumn {
+            name: name.to_string(),
+            unit: unit.to_string(),
+        })
+        .collect(),
+        rows: comparison
+            .properties
+            .iter()
+            .map(|d| {
+                vec![
+                    ReportCell::Text(d.property.to_string()),
+                    ReportCell::Text(d.unit.to_string()),
+                    ReportCell::Number(d.reference),
+                    ReportCell::Number(d.if97),
+                    ReportCell::Number(d.deviation_pct),
+                ]
+            })
+            .collect(),
+    });
+    report
+}
+
+/// 밀도 인자가 있으면 그 값, 없으면 IF97(실패 시 근사식)로 증기 밀도를 구한다.
+fn steam_density(rho: Option<&str>, p: Pressure, t_c: f64) -> Result<f64, QuantityArgError> {
+    if let Some(rho) = rho {
+        return Ok(quantity_arg::parse_density(rho)?.kg_per_m3());
+    }
+    Ok(steam::if97::region_props(p.bar_abs(), t_c)
+        .ok()
+        .map(|(_, v, _)| 1.0 / v.max(1e-9))
+        .unwrap_or_else(|| {
+            steam::estimate_density_typed(p, Temperature::from_celsius(t_c)).kg_per_m3()
+        }))
+}
+
+/// 거칠기 인자가 재질 프리셋 이름이면 그 ε, 아니면 길이(단위 없으면 mm)로 읽는다.
+fn parse_roughness_m(roughness: &str) -> Result<f64, QuantityArgError> {
+    match material_db::find_roughness(roughness) {
+        Some(preset) => Ok(preset.roughness_m()),
+        None => Ok(quantity_arg::parse_length(roughness, "mm")?.m()),
+    }
+}
+
+/// 관경 선정 결과에 맞는 배관 등급 관경과 등급 검토 결과를 보고서에 더한다.
+fn pipe_class_results(
+    report: CalcReport,
+    class_name: &str,
+    inner_diameter_m: f64,
+    pressure_bar_g: f64,
+    temp_c: f64,
+) -> Result<CalcReport, AppError> {
+    let catalog = pipe_class::PipeClassCatalog::load_default()?;
+    let class = catalog.find(class_name)?;
+    let check = pipe_class::select_size(class, inner_diameter_m, pressure_bar_g, temp_c)?;
+    let label = format!("DN ({}, {})", check.class, check.pipe.schedule);
+    let mut report = report
+        .result(ReportValue::new("class_dn", check.pipe.dn as f64, "").labeled(&label, 0))
+        .result(
+            ReportValue::new(
+                "class_inner_diameter",
+                check.pipe.inner_diameter_m * 1000.0,
+                "mm",
+            )
+            .labeled("ID", 1),
+        )
+        .result(ReportValue::new("class_wall", check.pipe.wall_m * 1000.0, "mm").labeled("t", 2))
+        .result(
+            ReportValue::new("class_required_wall", check.required_wall_m * 1000.0, "mm")
+                .labeled("t req", 2),
+        );
+    for violation in check.violations {
+        report = report.warning(Severity::Critical, violation);
+    }
+    Ok(report)
+}
+
+fn pipe_report(
+    cmd: &PipeCommand,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    match cmd {
+        PipeCommand::Size {
+            mdot,
+            p,
+            t,
+            v,
+            rho,
+            service,
+            class,
+        } => {
+            let mass_flow = quantity_arg::parse_mass_flow(mdot)?;
+            let p = quantity_arg::parse_pressure(p, atm)?;
+            let t_c = quantity_arg::parse_temperature(t)?.celsius();
+            let target_v = quantity_arg::parse_velocity(v)?.m_per_s();
+            let density = steam_density(rho.as_deref(), p, t_c)?;
+            let result = steam::size_by_velocity(PipeSizingByVelocityInput {
+                mass_flow_kg_per_h: mass_flow.kg_per_h(),
+                steam_density_kg_per_m3: density,
+                target_velocity_m_per_s: target_v,
+            })?;
+            let check = velocity_check::check_sized_pipe(
+                &result,
+                service.map(PipeService::from),
+                p.bar_abs(),
+                t_c,
+                density,
+            )?;
+            let report = CalcReport::new("pipe size")
+                .input(ReportValue::new("mass_flow", mass_flow.kg_per_h(), "kg/h"))
+                .input(ReportValue::new("pressure", p.bar_abs(), "bar(a)"))
+                .input(ReportValue::new("temperature", t_c, "°C"))
+                .input(ReportValue::new("target_velocity", target_v, "m/s"))
+                .intermediate(ReportValue::new("density", density, "kg/m3").labeled("ρ", 3))
+                .intermediate(
+                    ReportValue::new("volumetric_flow", mass_flow.kg_per_h() / density, "m3/h")
+                        .labeled("Q", 1),
+                )
+                .result(
+                    ReportValue::new("inner_diameter", result.inner_diameter_m * 1000.0, "mm")
+                        .labeled(tr.t(i18n::keys::RESULT_RECOMMENDED_ID), 1),
+                )
+                .result(
+                    ReportValue::new("velocity", result.velocity_m_per_s, "m/s")
+                        .labeled(tr.t(i18n::keys::RESULT_EXPECTED_VELOCITY), 2),
+                )
+                .result(ReportValue::new("reynolds", result.reynolds_number, "").labeled("Re", 0))
+                .result(
+                    ReportValue::new(
+                        "recommended_velocity",
+                        check.guidance.recommended_m_per_s,
+                        "m/s",
+                    )
+                    .labeled(tr.t(i18n::keys::RESULT_RECOMMENDED_VELOCITY), 1),
+                )
+                .result(
+                    ReportValue::new("erosional_velocity", check.erosional_m_per_s, "m/s")
+                        .labeled("Ve (API RP 14E)", 1),
+                )
+                .result(
+                    ReportValue::new("velocity_utilization", check.utilization() * 100.0, "%")
+                        .labeled(tr.t(i18n::keys::RESULT_VELOCITY_UTILIZATION), 0),
+                )
+                .warning(check.severity(), check.localized(tr));
+            match class {
+                Some(name) => pipe_class_results(
+                    report,
+                    name,
+                    result.inner_diameter_m,
+                    p.bar_gauge_at(atm),
+                    t_c,
+                ),
+                None => Ok(report),
+            }
+        }
+        PipeCommand::Dp {
+            mdot,
+            p,
+            t,
+            d,
+            l,
+            eq_len,
+            z_in,
+            z_out,
+            k,
+            fittings,
+            fitting_method,
+            roughness,
+            visc,
+            sound_speed,
+            rho,
+        } => {
+            let fittings = FittingList::parse(fittings)?;
+            let method = FittingMethod::from(*fitting_method);
+            let mass_flow = quantity_arg::parse_mass_flow(mdot)?;
+            let p = quantity_arg::parse_pressure(p, atm)?;
+            let t_c = quantity_arg::parse_temperature(t)?.celsius();
+            let density = steam_density(rho.as_deref(), p, t_c)?;
+            let input = PressureLossInput {
+                mass_flow_kg_per_h: mass_flow.kg_per_h(),
+                steam_density_kg_per_m3: density,
+                diameter_m: quantity_arg::parse_length(d, "mm")?.m(),
+                length_m: quantity_arg::parse_length(l, "m")?.m(),
+                fittings_k_sum: *k,
+                equivalent_length_m: quantity_arg::parse_length(eq_len, "m")?.m(),
+                roughness_m: parse_roughness_m(roughness)?,
+                dynamic_viscosity_pa_s: *visc,
+                sound_speed_m_per_s: quantity_arg::parse_velocity(sound_speed)?.m_per_s(),
+                state_pressure_bar_abs: Some(p.bar_abs()),
+                state_temperature_c: Some(t_c),
+                inlet_elevation_m: quantity_arg::parse_length(z_in, "m")?.m(),
+                outlet_elevation_m: quantity_arg::parse_length(z_out, "m")?.m(),
+            };
+            let report = CalcReport::new("pipe dp")
+                .input(ReportValue::new(
+                    "mass_flow",
+                    input.mass_flow_kg_per_h,
+                    "kg/h",
+                ))
+                .input(ReportValue::new("pressure", p.bar_abs(), "bar(a)"))
+                .input(ReportValue::new("temperature", t_c, "°C"))
+                .input(ReportValue::new(
+                    "diameter",
+                    input.diameter_m * 1000.0,
+                    "mm",
+                ))
+                .input(ReportValue::new("length", input.length_m, "m"))
+                .input(ReportValue::new(
+                    "equivalent_length",
+                    input.equivalent_length_m,
+                    "m",
+                ))
+                .input(ReportValue::new(
+                    "inlet_elevation",
+                    input.inlet_elevation_m,
+                    "m",
+                ))
+                .input(ReportValue::new(
+                    "outlet_elevation",
+                    input.outlet_elevation_m,
+                    "m",
+                ))
+                .input(ReportValue::new("fittings_k_sum", input.fittings_k_sum, ""))
+                .input(ReportValue::new(
+                    "roughness",
+                    input.roughness_m * 1000.0,
+                    "mm",
+                ))
+                .input(ReportValue::new(
+                    "viscosity",
+                    input.dynamic_viscosity_pa_s,
+                    "Pa·s",
+                ))
+                .input(ReportValue::new(
+                    "sound_speed",
+                    input.sound_speed_m_per_s,
+                    "m/s",
+                ))
+                .intermediate(ReportValue::new("density", density, "kg/m3").labeled("ρ", 3));
+            let diameter_m = input.diameter_m;
+            let mut work_input = input.clone();
+            let result = steam::pressure_loss_with_fittings(input, &fittings, method)?;
+            work_input.fittings_k_sum += fittings.k_sum(method, result.reynolds_number, diameter_m);
+            let mut report = report.work(show_work::pressure_loss_work(
+                &work_input,
+                &result,
+                tr.number_format(),
+            ));
+            if !fittings.is_empty() {
+                let re = result.reynolds_number;
+                report.tables.push(ReportTable {
+                    name: "fittings".to_string(),
+                    columns: vec![
+                        ReportColumn {
+                            name: "fitting".to_string(),
+                            unit: String::new(),
+                        },
+                        ReportColumn {
+                            name: "count".to_string(),
+                            unit: String::new(),
+                        },
+                        ReportColumn {
+                            name: "k_each".to_string(),
+                            unit: String::new(),
+                        },
+                    ],
+                    rows: fittings
+                        .items()
+                        .iter()
+                        .map(|(f, n)| {
+                            vec![
+                                ReportCell::Text(f.code.to_string()),
+                                ReportCell::Number(f64::from(*n)),
+                                ReportCell::Number(f.k(method, re, diameter_m)),
+                            ]
+                        })
+                        .collect(),
+                });
+                report = report
+                    .intermediate(
+                        ReportValue::new("fittings_k", fittings.k_sum(method, re, diameter_m), "")
+                            .labeled("ΣK", 3),
+                    )
+                    .intermediate(
+                        ReportValue::new(
+                            "fittings_equivalent_length",
+                            fittings.equivalent_length_m(
+                                method,
+                                re,
+                                diameter_m,
+                                result.friction_factor,
+                            ),
+                            "m",
+                        )
+                        .labeled("Leq", 1),
+                    );
+            }
+            Ok(report
+                .result(
+                    ReportValue::new("velocity", result.velocity_m_per_s, "m/s").labeled("v", 2),
+                )
+                .result(
+                    ReportValue::new("pressure_drop", result.pressure_drop_bar, "bar")
+                        .labeled(tr.t(i18n::keys::RESULT_PRESSURE_DROP), 4),
+                )
+                .result(
+                    ReportValue::new("elevation_drop", result.elevation_drop_bar, "bar")
+                        .labeled("ρgΔz", 4),
+                )
+                .result(
+                    ReportValue::new("total_drop", result.total_drop_bar(), "bar").labeled("ΔP", 4),
+                )
+                .result(
+                    ReportValue::new("friction_factor", result.friction_factor, "").labeled("f", 4),
+                )
+                .result(ReportValue::new("reynolds", result.reynolds_number, "").labeled("Re", 0))
+                .result(ReportValue::new("mach", result.mach, "").labeled("Mach", 3)))
+        }
+        PipeCommand::Run {
+            csv,
+            mdot,
+            p,
+            t,
+            fitting_method,
+            visc,
+            sound_speed,
+        } => {
+            let segments = pipe_run::parse_pipe_run_csv(&std::fs::read_to_string(csv)?)?;
+            let mass_flow = quantity_arg::parse_mass_flow(mdot)?;
+            let p = quantity_arg::parse_pressure(p, atm)?;
+            let t_c = quantity_arg::parse_temperature(t)?.celsius();
+            let input = PipeRunInput {
+                mass_flow_kg_per_h: mass_flow.kg_per_h(),
+                inlet_pressure_bar_abs: p.bar_abs(),
+                inlet_temperature_c: t_c,
+                fitting_method: FittingMethod::from(*fitting_method),
+                dynamic_viscosity_pa_s: *visc,
+                sound_speed_m_per_s: quantity_arg::parse_velocity(sound_speed)?.m_per_s(),
+                segments,
+            };
+            let result = pipe_run::pipe_run(&input)?;
+            Ok(pipe_run_report(&input, &result, tr))
+        }
+        PipeCommand::Fill {
+            lines,
+            t,
+            drain_cv,
+            head,
+        } => {
+            let input = line_fill::LineFillInput {
+                segments: line_fill::parse_segments(lines)?,
+                water_temp_c: quantity_arg::parse_temperature(t)?.celsius(),
+                steel_density_kg_per_m3: line_fill::CARBON_STEEL_DENSITY,
+                drain: match drain_cv {
+                    Some(cv) => Some(line_fill::DrainValve {
+                        cv: *cv,
+                        static_head_m: quantity_arg::parse_length(head, "m")?.m(),
+                    }),
+                    None => None,
+                },
+            };
+            let result = line_fill::line_fill(&input)?;
+            Ok(line_fill_report(&input, &result, tr))
+        }
+    }
+}
+
+/// 충수량 결과 문서. 관경별 값은 `segments` 표로 넣는다.
+fn line_fill_report(
+    input: &line_fill::LineFillInput,
+    result: &line_fill::LineFillResult,
+    tr: &Translator,
+) -> CalcReport {
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    let mut report = CalcReport::new("pipe fill")
+        .input(ReportValue::new(
+            "water_temperature",
+            input.water_temp_c,
+            "°C",
+        ))
+        .input(ReportValue::new(
+            "steel_density",
+            input.steel_density_kg_per_m3,
+            "kg/m3",
+        ))
+        .intermediate(ReportValue::new(
+            "water_density",
+            result.water_density_kg_per_m3,
+            "kg/m3",
+        ))
+        .result(ReportValue::new("total_length", result.total_length_m, "m").labeled("L", 1))
+        .result(ReportValue::new("volume", result.volume_m3, "m3").labeled("V", 3))
+        .result(ReportValue::new("water_mass", result.water_mass_kg, "kg").labeled("m_w", 0))
+        .result(ReportValue::new("steel_mass", result.steel_mass_kg, "kg").labeled("m_s", 0))
+        .result(ReportValue::new("filled_mass", result.filled_mass_kg, "kg").labeled("m_filled", 0))
+        .result(
+            ReportValue::new("max_filled_weight", result.max_filled_kg_per_m, "kg/m")
+                .labeled("w_max", 1),
+        );
+    if let (Some(valve), Some(drain)) = (&input.drain, &result.drain) {
+        report = report
+            .input(ReportValue::new("drain_cv", valve.cv, ""))
+            .input(ReportValue::new("drain_head", valve.static_head_m, "m"))
+            .intermediate(ReportValue::new("drain_kv", drain.kv, ""))
+            .result(
+                ReportValue::new("initial_drain_flow", drain.initial_flow_m3_per_h, "m3/h")
+                    .labeled("Q0", 2),
+            )
+            .result(ReportValue::new("drain_time", drain.drain_time_h, "h").labeled("t", 2));
+    }
+    for w in &result.warnings {
+        report = report.warning(w.severity(), w.localized(tr));
+    }
+    report.tables.push(ReportTable {
+        name: "segments".to_string(),
+        columns: vec![
+            column("dn", ""),
+            column("length", "m"),
+            column("outer_diameter", "mm"),
+            column("inner_diameter", "mm"),
+            column("volume", "m3"),
+            column("steel_weight", "kg/m"),
+            column("filled_weight", "kg/m"),
+        ],
+        rows: result
+            .segments
+            .iter()
+            .map(|s| {
+                vec![
+                    ReportCell::Text(format!("DN{}", s.dn)),
+                    ReportCell::Number(s.length_m),
+                    ReportCell::Number(s.outer_diameter_mm),
+                    ReportCell::Number(s.inner_diameter_mm),
+                    ReportCell::Number(s.volume_m3),
+                    ReportCell::Number(s.steel_kg_per_m),
+                    ReportCell::Number(s.filled_kg_per_m),
+                ]
+            })
+            .collect(),
+    });
+    report
+}
+
+/// 배관 경로 결과 문서. 구간별 값은 `segments` 표로 넣는다.
+fn pipe_run_report(
+    input: &PipeRunInput,
+    result: &pipe_run::PipeRunResult,
+    tr: &Translator,
+) -> CalcReport {
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    let mut report = CalcReport::new("pipe run")
+        .input(ReportValue::new(
+            "mass_flow",
+            input.mass_flow_kg_per_h,
+            "kg/h",
+        ))
+        .input(ReportValue::new(
+            "inlet_pressure",
+            input.inlet_pressure_bar_abs,
+            "bar(a)",
+        ))
+        .input(ReportValue::new(
+            "inlet_temperature",
+            input.inlet_temperature_c,
+            "°C",
+        ))
+        .input(ReportValue::new(
+            "segments",
+            input.segments.len() as f64,
+            "",
+        ))
+        .result(
+            ReportValue::new("friction_drop", result.friction_drop_bar, "bar")
+                .labeled(tr.t(i18n::keys::RESULT_PRESSURE_DROP), 4),
+        )
+        .result(
+            ReportValue::new("elevation_drop", result.elevation_drop_bar, "bar").labeled("ρgΔz", 4),
+        )
+        .result(ReportValue::new("total_drop", result.total_drop_bar, "bar").labeled("ΔP", 4))
+        .result(
+            ReportValue::new("outlet_pressure", result.outlet_pressure_bar_abs, "bar(a)")
+                .labeled("P2", 3),
+        );
+    report.tables.push(ReportTable {
+        name: "segments".to_string(),
+        columns: vec![
+            column("segment", ""),
+            column("inlet_pressure", "bar(a)"),
+            column("velocity", "m/s"),
+            column("reynolds", ""),
+            column("fittings_k", ""),
+            column("friction_drop", "bar"),
+            column("elevation_drop", "bar"),
+            column("outlet_pressure", "bar(a)"),
+            column("mach", ""),
+        ],
+        rows: result
+            .segments
+            .iter()
+            .map(|s| {
+                vec![
+                    ReportCell::Text(s.name.clone()),
+                    ReportCell::Number(s.inlet_pressure_bar_abs),
+                    ReportCell::Number(s.velocity_m_per_s),
+                    ReportCell::Number(s.reynolds_number),
+                    ReportCell::Number(s.fittings_k),
+                    ReportCell::Number(s.friction_drop_bar),
+                    ReportCell::Number(s.elevation_drop_bar),
+                    ReportCell::Number(s.outlet_pressure_bar_abs),
+                    ReportCell::Number(s.mach),
+                ]
+            })
+            .collect(),
+    });
+    report
+}
+
+fn valve_report(
+    cmd: &ValveCommand,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    match cmd {
+        ValveCommand::Kv { q, dp, rho } => {
+            let q = quantity_arg::parse_volume_flow(q)?;
+            let dp = quantity_arg::parse_pressure_difference(dp)?;
+            let rho = quantity_arg::parse_density(rho)?;
+            let kv = steam::required_kv_typed(q, dp, rho)?;
+            let label = tr.t(i18n::keys::RESULT_REQUIRED_KV_CV);
+            Ok(CalcReport::new("valve kv")
+                .input(ReportValue::new("volumetric_flow", q.m3_per_h(), "m3/h"))
+                .input(ReportValue::new("pressure_difference", dp.bar(), "bar"))
+                .input(ReportValue::new("density", rho.kg_per_m3(), "kg/m3"))
+                .intermediate(
+                    ReportValue::new("specific_gravity", rho.kg_per_m3() / 1000.0, "")
+                        .labeled("SG", 4),
+                )
+                .result(ReportValue::new("kv", kv, "").labeled(&format!("{label} Kv"), 3))
+                .result(
+                    ReportValue::new("cv", steam::cv_from_kv(kv), "")
+                        .labeled(&format!("{label} Cv"), 3),
+                )
+                .work(show_work::valve_kv_work(
+                    q.m3_per_h(),
+                    dp.bar(),
+                    rho.kg_per_m3(),
+                    kv,
+                    tr.number_format(),
+                )))
+        }
+        ValveCommand::Flow {
+            kv,
+            cv,
+            dp,
+            rho,
+            p1,
+        } => {
+            let kv = match (kv, cv) {
+                (Some(kv), _) => *kv,
+                (None, Some(cv)) => steam::kv_from_cv(*cv),
+                (None, None) => {
+                    return Err(QuantityArgError::InvalidNumber("--kv/--cv".into()).into())
+                }
+            };
+            let dp_bar = quantity_arg::parse_pressure_difference(dp)?.bar();
+            let density = quantity_arg::parse_density(rho)?.kg_per_m3();
+            let p1_bar_abs = p1
+                .as_deref()
+                .map(|p| quantity_arg::parse_pressure(p, atm).map(|p| p.bar_abs()))
+                .transpose()?;
+            let label = tr.t(i18n::keys::RESULT_POSSIBLE_FLOW);
+            let mut report = CalcReport::new("valve flow")
+                .input(ReportValue::new("kv", kv, ""))
+                .input(ReportValue::new("pressure_difference", dp_bar, "bar"))
+                .input(ReportValue::new("density", density, "kg/m3"));
+            let flow = match p1_bar_abs {
+                Some(p1) => {
+                    let steam_flow = steam::steam_flow_from_kv(
+                        kv,
+                        dp_bar,
+                        density,
+                        p1,
+                        SteamStagnation::Quality(1.0),
+                    )?;
+                    report = report
+                        .input(ReportValue::new("upstream_pressure", p1, "bar(a)"))
+                        .intermediate(
+                            ReportValue::new("pressure_ratio", steam_flow.pressure_ratio, "")
+                                .labeled("p2/p1", 3),
+                        )
+                        .intermediate(
+                            ReportValue::new(
+                                "critical_pressure_ratio",
+                                steam_flow.critical_pressure_ratio,
+                                "",
+                            )
+                            .labeled("p*/p1", 3),
+                        );
+                    if let Some(w) = steam_flow.warning() {
+                        report = report.warning(w.severity(), w.localized(tr));
+                    }
+                    steam_flow.volumetric_flow_m3_per_h
+                }
+                None => steam::flow_from_kv(kv, dp_bar, density)?,
+            };
+            Ok(report
+                .result(ReportValue::new("volumetric_flow", flow, "m3/h").labeled(label, 3))
+                .result(ReportValue::new("mass_flow", flow * density, "kg/h").labeled(label, 3)))
+        }
+    }
+}
+
+fn throttling_input(
+    args: &ThrottlingArgs,
+    atm: Atmosphere,
+) -> Result<ThrottlingCalorimeterInput, QuantityArgError> {
+    Ok(ThrottlingCalorimeterInput {
+        main_pressure_bar_abs: quantity_arg::parse_pressure(&args.p_main, atm)?.bar_abs(),
+        calorimeter_pressure_bar_abs: quantity_arg::parse_pressure(&args.p_cal, atm)?.bar_abs(),
+        calorimeter_temp_c: quantity_arg::parse_temperature(&args.t_cal)?.celsius(),
+    })
+}
+
+fn separating_input(args: &SeparatingArgs) -> SeparatingCalorimeterInput {
+    SeparatingCalorimeterInput {
+        separated_water_kg: args.water_kg,
+        condensed_steam_kg: args.condensate_kg,
+    }
+}
+
+fn throttling_values(report: CalcReport, input: &ThrottlingCalorimeterInput) -> CalcReport {
+    report
+        .input(ReportValue::new(
+            "main_pressure",
+            input.main_pressure_bar_abs,
+            "bar(a)",
+        ))
+        .input(ReportValue::new(
+            "calorimeter_pressure",
+            input.calorimeter_pressure_bar_abs,
+            "bar(a)",
+        ))
+        .input(ReportValue::new(
+            "calorimeter_temperature",
+            input.calorimeter_temp_c,
+            "°C",
+        ))
+}
+
+fn separating_values(report: CalcReport, input: &SeparatingCalorimeterInput) -> CalcReport {
+    report
+        .input(ReportValue::new(
+            "separated_water",
+            input.separated_water_kg,
+            "kg",
+        ))
+        .input(ReportValue::new(
+            "condensed_steam",
+            input.condensed_steam_kg,
+            "kg",
+        ))
+}
+
+fn dryness_report(
+    cmd: &DrynessCommand,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    let (report, result) = match cmd {
+        DrynessCommand::Throttling(args) => {
+            let input = throttling_input(args, atm)?;
+            (
+                throttling_values(CalcReport::new("dryness throttling"), &input),
+                steam_dryness::throttling_calorimeter(input)?,
+            )
+        }
+        DrynessCommand::Separating(args) => {
+            let input = separating_input(args);
+            (
+                separating_values(CalcReport::new("dryness separating"), &input),
+                steam_dryness::separating_calorimeter(input)?,
+            )
+        }
+        DrynessCommand::Combined {
+            separating,
+            throttling,
+        } => {
+            let sep = separating_input(separating);
+            let thr = throttling_input(throttling, atm)?;
+            let report = throttling_values(
+                separating_values(CalcReport::new("dryness combined"), &sep),
+                &thr,
+            );
+            (
+                report,
+                steam_dryness::separating_throttling_calorimeter(sep, thr)?,
+            )
+        }
+    };
+    let mut report = report.result(
+        ReportValue::new("dryness", result.dryness, "")
+            .labeled(tr.t(i18n::keys::RESULT_DRYNESS), 4),
+    );
+    if let Some(dt) = result.superheat_after_throttling_k {
+        report = report.intermediate(
+            ReportValue::new("superheat_after_throttling", dt, "K")
+                .labeled(tr.t(i18n::keys::RESULT_THROTTLING_SUPERHEAT), 1),
+        );
+    }
+    if let Some(x_min) = result.min_measurable_dryness {
+        report = report.result(
+            ReportValue::new("min_measurable_dryness", x_min, "")
+                .labeled(tr.t(i18n::keys::RESULT_MIN_MEASURABLE_DRYNESS), 4),
+        );
+    }
+    for w in result.warnings {
+        report = report.warning(Severity::Caution, w);
+    }
+    Ok(report)
+}
+
+fn convert_report(args: &ConvertArgs, tr: &Translator) -> Result<CalcReport, AppError> {
+    let (value, from) = quantity_arg::split_value_unit(&args.value)?;
+    let to = args.to.trim();
+    let key = args.kind.trim().to_ascii_lowercase().replace('-', "_");
+    let flow_kind = match key.as_str() {
+        "mass_flow" => Some(FlowKind::MassFlow),
+        "volumetric_flow" | "volume_flow" => Some(FlowKind::VolumetricFlow),
+        "power" | "heat_flow" | "heat_rate" => Some(FlowKind::Power),
+        "density" => Some(FlowKind::Density),
+        "specific_volume" => Some(FlowKind::SpecificVolume),
+        "specific_heat" | "cp" => Some(FlowKind::SpecificHeat),
+        _ => None,
+    };
+    let (result, decimals) = match flow_kind {
+        Some(kind) => (flow_units::convert(kind, value, from, to)?, 3),
+        None => {
+            let kind: QuantityKind = custom_units::kind_from_key(&key)
+                .ok_or_else(|| QuantityArgError::UnknownUnit(args.kind.clone()))?;
+            (custom_units::convert(kind, value, from, to)?, 4)
+        }
+    };
+    Ok(CalcReport::new("convert")
+        .input(ReportValue::new("value", value, from))
+        .result(
+            ReportValue::new("value", result, to)
+                .labeled(tr.t(i18n::keys::UNIT_CONVERSION_RESULT), decimals),
+        ))
+}
+
+fn steam_condition(
+    p: &str,
+    t: Option<&String>,
+    atm: Atmosphere,
+) -> Result<SteamCondition, AppError> {
+    Ok(SteamCondition {
+        pressure_bar_abs: quantity_arg::parse_pressure(p, atm)?.bar_abs(),
+        temperature_c: t
+            .map(|t| quantity_arg::parse_temperature(t).map(|t| t.celsius()))
+            .transpose()?,
+    })
+}
+
+/// 유량계 밀도 보정. `--csv`면 행별 결과를 `rows` 표로 넣고 `--out`에 보정 CSV를 쓴다.
+fn meter_comp_report(
+    args: &MeterCompArgs,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    let principle = MeterPrinciple::from(args.meter);
+    let design = steam_condition(&args.design_p, args.design_t.as_ref(), atm)?;
+    let mut report = CalcReport::new("meter-comp").input(ReportValue::new(
+        "design_pressure",
+        design.pressure_bar_abs,
+        "bar(a)",
+    ));
+    if let Some(t) = design.temperature_c {
+        report = report.input(ReportValue::new("design_temperature", t, "°C"));
+    }
+
+    let Some(csv) = &args.csv else {
+        let (Some(flow), Some(p)) = (&args.flow, &args.p) else {
+            return Err(QuantityArgError::InvalidNumber("--flow/--p".into()).into());
+        };
+        let flow = quantity_arg::parse_mass_flow(flow)?.kg_per_h();
+        let actual = steam_condition(p, args.t.as_ref(), atm)?;
+        let result = meter_compensation::compensate_flow(principle, design, actual, flow)?;
+        report = report
+            .input(ReportValue::new("indicated_flow", flow, "kg/h"))
+            .input(ReportValue::new(
+                "pressure",
+                actual.pressure_bar_abs,
+                "bar(a)",
+            ))
+            .intermediate(
+                ReportValue::new("design_density", result.design.density_kg_per_m3, "kg/m3")
+                    .labeled("ρ_design", 4),
+            )
+            .intermediate(
+                ReportValue::new("actual_density", result.actual.density_kg_per_m3, "kg/m3")
+                    .labeled("ρ", 4),
+            )
+            .intermediate(
+                ReportValue::new("actual_temperature", result.actual.temperature_c, "°C")
+                    .labeled("T", 1),
+            )
+            .result(
+                ReportValue::new("correction_factor", result.correction_factor, "").labeled("K", 5),
+            )
+            .result(
+                ReportValue::new("corrected_flow", result.corrected_flow_kg_per_h, "kg/h")
+                    .labeled("ṁ", 1),
+            );
+        for w in &result.warnings {
+            report = report.warning(w.severity(), w.localized(tr));
+        }
+        return Ok(report);
+    };
+
+    let rows =
+        meter_compensation::parse_historian_csv(&std::fs::read_to_string(csv)?, atm.bar_abs())?;
+    let series = meter_compensation::compensate_series(principle, design, &rows)?;
+    if let Some(out) = &args.out {
+        std::fs::write(out, meter_compensation::compensated_series_csv(&series))?;
+    }
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    report = report
+        .input(ReportValue::new("rows", series.rows.len() as f64, ""))
+        .intermediate(
+            ReportValue::new("design_density", series.design.density_kg_per_m3, "kg/m3")
+                .labeled("ρ_design", 4),
+        )
+        .result(ReportValue::new(
+            "mean_indicated_flow",
+            series.mean_indicated_flow_kg_per_h,
+            "kg/h",
+        ))
+        .result(ReportValue::new(
+            "mean_corrected_flow",
+            series.mean_corrected_flow_kg_per_h,
+            "kg/h",
+        ))
+        .result(ReportValue::new(
+            "min_correction_factor",
+            series.min_correction_factor,
+            "",
+        ))
+        .result(ReportValue::new(
+            "max_correction_factor",
+            series.max_correction_factor,
+            "",
+        ));
+    report.tables.push(ReportTable {
+        name: "rows".to_string(),
+        columns: vec![
+            column("timestamp", ""),
+            column("indicated_flow", "kg/h"),
+            column("pressure", "bar(a)"),
+            column("temperature", "°C"),
+            column("density", "kg/m3"),
+            column("correction_factor", ""),
+            column("corrected_flow", "kg/h"),
+        ],
+        rows: series
+            .rows
+            .iter()
+            .map(|r| {
+                vec![
+                    ReportCell::Text(r.timestamp.clone()),
+                    ReportCell::Number(r.indicated_flow_kg_per_h),
+                    ReportCell::Number(r.pressure_bar_abs),
+                    ReportCell::Number(r.temperature_c),
+                    ReportCell::Number(r.density_kg_per_m3),
+                    ReportCell::Number(r.correction_factor),
+                    ReportCell::Number(r.corrected_flow_kg_per_h),
+                ]
+            })
+            .collect(),
+    });
+    for w in &series.warnings {
+        report = report.warning(w.severity(), w.localized(tr));
+    }
+    Ok(report)
+}
+
+/// 히스토리안 시계열 적산. 행별 물성은 `rows` 표로 넣는다.
+fn totalize_report(
+    args: &TotalizeArgs,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    let input = totalizer::TotalizerInput {
+        rows: totalizer::parse_totalizer_csv(&std::fs::read_to_string(&args.csv)?, atm.bar_abs())?,
+        reference_temp_c: args
+            .ref_t
+            .as_deref()
+            .map(|t| quantity_arg::parse_temperature(t).map(|t| t.celsius()))
+            .transpose()?,
+        mcr_kg_per_h: args
+            .mcr
+            .as_deref()
+            .map(|m| quantity_arg::parse_mass_flow(m).map(|m| m.kg_per_h()))
+            .transpose()?,
+    };
+    let result = totalizer::totalize(&input)?;
+    let mut report =
+        CalcReport::new("totalize").input(ReportValue::new("rows", input.rows.len() as f64, ""));
+    if let Some(t) = input.reference_temp_c {
+        report = report.input(ReportValue::new("reference_temperature", t, "°C"));
+    }
+    report = report
+        .intermediate(ReportValue::new(
+            "reference_enthalpy",
+            result.reference_enthalpy_kj_per_kg,
+            "kJ/kg",
+        ))
+        .result(ReportValue::new("duration", result.duration_h, "h"))
+        .result(ReportValue::new("total_mass", result.total_mass_t, "t"))
+        .result(ReportValue::new(
+            "total_energy",
+            result.total_energy_mwh,
+            "MWh",
+        ))
+        .result(ReportValue::new(
+            "average_enthalpy",
+            result.average_enthalpy_kj_per_kg,
+            "kJ/kg",
+        ));
+    for (name, stats, unit) in [
+        ("flow", result.flow_kg_per_h, "kg/h"),
+        ("pressure", result.pressure_bar_abs, "bar(a)"),
+        ("temperature", result.temperature_c, "°C"),
+    ] {
+        report = report
+            .result(ReportValue::new(&format!("{name}_min"), stats.min, unit))
+            .result(ReportValue::new(&format!("{name}_mean"), stats.mean, unit))
+            .result(ReportValue::new(&format!("{name}_max"), stats.max, unit));
+    }
+    if let Some(mcr) = result.mcr {
+        report = report
+            .input(ReportValue::new("mcr", mcr.mcr_kg_per_h, "kg/h"))
+            .result(ReportValue::new("average_load", mcr.average_load_frac, ""))
+            .result(ReportValue::new("peak_load", mcr.peak_load_frac, ""))
+            .result(ReportValue::new(
+                "hours_above_mcr",
+                mcr.hours_above_mcr,
+                "h",
+            ));
+    }
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    report.tables.push(ReportTable {
+        name: "rows".to_string(),
+        columns: vec![
+            column("timestamp", ""),
+            column("elapsed", "h"),
+            column("flow", "kg/h"),
+            column("pressure", "bar(a)"),
+            column("temperature", "°C"),
+            column("enthalpy", "kJ/kg"),
+            column("density", "kg/m3"),
+            column("heat_flow", "kW"),
+        ],
+        rows: result
+            .rows
+            .iter()
+            .map(|r| {
+                vec![
+                    ReportCell::Text(r.timestamp.clone()),
+                    ReportCell::Number(r.elapsed_h),
+                    ReportCell::Number(r.flow_kg_per_h),
+                    ReportCell::Number(r.pressure_bar_abs),
+                    ReportCell::Number(r.temperature_c),
+                    ReportCell::Number(r.enthalpy_kj_per_kg),
+                    ReportCell::Number(r.density_kg_per_m3),
+                    ReportCell::Number(r.heat_flow_kw),
+                ]
+            })
+            .collect(),
+    });
+    for w in &result.warnings {
+        report = report.warning(w.severity(), w.localized(tr));
+    }
+    Ok(report)
+}
+
+/// 분출 시나리오 집계. 시나리오별 부하는 `loads` 표로, 계통별 지배 부하는 결과 값으로 넣는다.
+fn relief_report(
+    args: &ReliefArgs,
+    atm: Atmosphere,
+    tr: &Translator,
+) -> Result<CalcReport, AppError> {
+    let study = ReliefStudy::load(std::path::Path::new(&args.file))?;
+    let result = relief_scenario::evaluate(&study, atm.bar_abs())?;
+    let mut report = CalcReport::new("relief")
+        .input(ReportValue::new("systems", study.systems.len() as f64, ""))
+        .input(ReportValue::new(
+            "scenarios",
+            study.scenarios.len() as f64,
+            "",
+        ));
+    for system in &result.systems {
+        if let Some(load) = system.governing_load() {
+            report = report
+                .result(
+                    ReportValue::new(
+                        &format!("{}_governing_load", system.system),
+                        load.load_kg_per_h,
+                        "kg/h",
+                    )
+                    .labeled(&format!("{} ({})", system.system, load.scenario), 0),
+                )
+                .result(ReportValue::new(
+                    &format!("{}_relieving_pressure", system.system),
+                    load.relieving_pressure_bar_abs,
+                    "bar(a)",
+                ));
+        }
+    }
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    report.tables.push(ReportTable {
+        name: "loads".to_string(),
+        columns: vec![
+            column("system", ""),
+            column("scenario", ""),
+            column("type", ""),
+            column("relieving_pressure", "bar(a)"),
+            column("load", "kg/h"),
+            column("w_over_p1", "kg/h/bar"),
+            column("governing", ""),
+            column("basis", ""),
+        ],
+        rows: result
+            .systems
+            .iter()
+            .flat_map(|system| {
+                system.loads.iter().enumerate().map(move |(i, l)| {
+                    vec![
+                        ReportCell::Text(system.system.clone()),
+                        ReportCell::Text(l.scenario.clone()),
+                        ReportCell::Text(l.kind.to_string()),
+                        ReportCell::Number(l.relieving_pressure_bar_abs),
+                        ReportCell::Number(l.load_kg_per_h),
+                        ReportCell::Number(l.sizing_basis),
+                        ReportCell::Text(if system.governing == Some(i) { "*" } else { "" }.into()),
+                        ReportCell::Text(l.note.clone()),
+                    ]
+                })
+            })
+            .collect(),
+    });
+    for w in &result.warnings {
+        report = report.warning(w.severity(), w.localized(tr));
+    }
+    Ok(report)
+}
+
+/// 검량표. 행은 `rows` 표로 넣고 `--out`에 CSV를 쓴다.
+fn strapping_report(args: &StrappingArgs, atm: Atmosphere) -> Result<CalcReport, AppError> {
+    let input = StrappingInput {
+        vessel: Vessel {
+            orientation: args.orientation.into(),
+            inner_diameter_m: quantity_arg::parse_length(&args.d, "m")?.m(),
+            length_m: quantity_arg::parse_length(&args.l, "m")?.m(),
+            heads: args.heads.into(),
+        },
+        increment_m: quantity_arg::parse_length(&args.step, "mm")?.m(),
+        liquid_temp_c: quantity_arg::parse_temperature(&args.t)?.celsius(),
+        reference_temp_c: quantity_arg::parse_temperature(&args.ref_t)?.celsius(),
+        expansion: match args.beta {
+            Some(beta_per_k) => LiquidExpansion::Coefficient { beta_per_k },
+            None => LiquidExpansion::Water {
+                pressure_bar_abs: atm.bar_abs(),
+            },
+        },
+    };
+    let table = strapping_table::strapping_table(&input)?;
+    if let Some(out) = &args.out {
+        std::fs::write(out, strapping_table::strapping_table_csv(&table))?;
+    }
+    let mut report = CalcReport::new("strapping")
+        .input(ReportValue::new(
+            "inner_diameter",
+            input.vessel.inner_diameter_m,
+            "m",
+        ))
+        .input(ReportValue::new("length", input.vessel.length_m, "m"))
+        .input(ReportValue::new(
+            "increment",
+            input.increment_m * 1000.0,
+            "mm",
+        ))
+        .input(ReportValue::new(
+            "liquid_temperature",
+            input.liquid_temp_c,
+            "°C",
+        ))
+        .input(ReportValue::new(
+            "reference_temperature",
+            input.reference_temp_c,
+            "°C",
+        ))
+        .result(ReportValue::new("height", input.vessel.height_m(), "m"))
+        .result(ReportValue::new(
+            "total_volume",
+            table.total_volume_m3,
+            "m3",
+        ))
+        .result(
+            ReportValue::new(
+                "volume_correction_factor",
+                table.volume_correction_factor,
+                "",
+            )
+            .labeled("VCF", 5),
+        );
+    let column = |name: &str, unit: &str| ReportColumn {
+        name: name.to_string(),
+        unit: unit.to_string(),
+    };
+    report.tables.push(ReportTable {
+        name: "rows".to_string(),
+        columns: vec![
+            column("level", "mm"),
+            column("volume", "m3"),
+            column("corrected_volume", "m3"),
+            column("percent_full", "%"),
+        ],
+        rows: table
+            .rows
+            .iter()
+            .map(|r| {
+                vec![
+                    ReportCell::Number(r.level_m * 1000.0),
+                    ReportCell::Number(r.volume_m3),
+                    ReportCell::Number(r.corrected_volume_m3),
+                    ReportCell::Number(r.percent_full),
+                ]
+            })
+            .collect(),
+    });
+    Ok(report)
+}
+
+/// 인쇄용 증기표 책자를 HTML 파일로 쓰고, 페이지 목록을 `pages` 표로 넣는다.
+fn booklet_report(args: &BookletArgs, tr: &Translator) -> Result<CalcReport, AppError> {
+    let system = UnitSystem::from(args.units.unwrap_or_default());
+    let booklet = table_booklet::generate(&BookletOptions::standard(system, &args.title))?;
+    std::fs::write(&args.out, booklet.to_html(tr.number_format()))?;
+    let titles = booklet.page_titles();
+    let mut report = CalcReport::new("booklet")
+        .result(ReportValue::new("pages", titles.len() as f64, "").labeled("pages", 0))
+        .result(
+            ReportValue::new(
+                "saturation_rows",
+                (booklet.saturation_by_temperature.len() + booklet.saturation_by_pressure.len())
+                    as f64,
+                "",
+            )
+            .labeled("saturation_rows", 0),
+        )
+        .result(
+            ReportValue::new("superheat_pressures", booklet.superheat.len() as f64, "")
+                .labeled("superheat_pressures", 0),
+        );
+    report.tables.push(ReportTable {
+        name: "pages".to_string(),
+        columns: vec![
+            ReportColumn {
+                name: "page".to_string(),
+                unit: String::new(),
+            },
+            ReportColumn {
+                name: "table".to_string(),
+                unit: String::new(),
+            },
+        ],
+        rows: titles
+            .into_iter()
+            .enumerate()
+            .map(|(i, title)| {
+                vec![
+                    ReportCell::Text((i + 1).to_string()),
+                    ReportCell::Text(title),
+                ]
+            })
+            .collect(),
+    });
+    Ok(report)
+}
+
+/// IF97 진단: 검증점별 편차 표와 영역/경계선별 최대 상대편차.
+fn verify_if97_report() -> CalcReport {
+    let verification = steam::if97::verify();
+    let mut report = CalcReport::new("verify-if97");
+    for (group, max) in verification.max_deviations() {
+        let name = format!(
+            "max_rel_deviation_{}",
+            group.to_ascii_lowercase().replace(' ', "_")
+        );
+        report = report.result(ReportValue::new(&name, max, "").labeled(group, 12));
+    }
+    report.tables.push(ReportTable {
+        name: "checks".to_string(),
+        columns: [
+            "group",
+            "case",
+            "property",
+            "expected",
+            "actual",
+            "rel_deviation",
+            "status",
+        ]
+        .iter()
+        .map(|name| ReportColumn {
+            name: name.to_string(),
+            unit: String::new(),
+        })
+        .collect(),
+        rows: verification
+            .checks
+            .iter()
+            .map(|c| {
+                vec![
+                    ReportCell::Text(c.group.to_string()),
+                    ReportCell::Text(c.case.to_string()),
+                    ReportCell::Text(c.property.to_string()),
+                    ReportCell::Text(format!("{:.9e}", c.expected)),
+                    ReportCell::Text(format!("{:.9e}", c.actual)),
+                    ReportCell::Text(format!("{:.2e}", c.rel_deviation)),
+                    ReportCell::Text(if c.passed() { "ok" } else { "FAIL" }.to_string()),
+                ]
+            })
+            .collect(),
+    });
+    let failed = verification.checks.iter().filter(|c| !c.passed()).count();
+    if failed > 0 {
+        report = report.warning(
+            Severity::Critical,
+            format!("{failed} IF97 verification point(s) exceed the tolerance"),
+        );
+    }
+    report
+}
@@ -0,0 +1,286 @@
+//! 연소용 공기예열기(가스-공기 열교환기) 성능 계산.
+//! 공기 측 온도 상승으로 회수 열량을 구하고, 배가스 측 열수지로 누설 없는 가스 출구 온도를,
+//! 공기 누설(공기 입구 온도로 가스 측에 섞인다고 봄, ASME PTC 4.3)을 섞어 실제 가스 출구 온도를 구한다.
+//! X-비 = (ṁa·cpa)/(ṁg·cpg) = (Tg,in − Tg,out,nl)/(Ta,out − Ta,in), 가스 측 효율 = (Tg,in − Tg,out,nl)/(Tg,in − Ta,in).
+//! 노점은 `combustion::dew_point`로 대기압 배가스 기준으로 구하고, 저온부 평균 온도를 황산 노점과 비교한다.
+
+use crate::combustion::dew_point::{self, DewPointError, DewPointWarning};
+use crate::combustion::fuel::{self, FuelAnalysis};
+use crate::warning::{CalcWarning, Severity};
+
+/// 효율 이득을 보여 주는 연돌 온도 저하 폭 [K]
+pub const STACK_TEMP_STEP_K: f64 = 20.0;
+
+/// 공기예열기 입력.
+#[derive(Debug, Clone)]
+pub struct AirPreheaterInput {
+    pub fuel: FuelAnalysis,
+    pub fuel_flow_kg_per_h: f64,
+    /// 저위발열량 [kJ/kg]
+    pub fuel_lhv_kj_per_kg: f64,
+    /// 공기예열기 가스 입구 과잉공기율 (예: 0.15)
+    pub excess_air_frac: f64,
+    /// 연소용 공기 절대습도 [kg/kg 건공기]
+    pub air_humidity_ratio: f64,
+    pub gas_inlet_temp_c: f64,
+    pub air_inlet_temp_c: f64,
+    /// 공기 출구(열풍) 온도 [°C]
+    pub air_outlet_temp_c: f64,
+    /// 공기 누설률: 누설 공기 / 가스 입구 질량 (재생식 0.05~0.10, 관형 0~0.02)
+    pub air_leakage_frac: f64,
+}
+
+/// 공기예열기 결과.
+#[derive(Debug, Clone)]
+pub struct AirPreheaterResult {
+    /// 가스 입구 습배가스 유량 [kg/h]
+    pub flue_gas_flow_kg_per_h: f64,
+    /// 연소용 공기(습공기) 유량 [kg/h]
+    pub combustion_air_flow_kg_per_h: f64,
+    /// 가스 측으로 새는 공기 [kg/h]
+    pub leakage_air_flow_kg_per_h: f64,
+    /// 회수 열량 [kW]
+    pub heat_recovered_kw: f64,
+    /// 공기 측 온도 상승 [K]
+    pub air_temp_rise_k: f64,
+    /// 누설 없는 가스 출구 온도 [°C]
+    pub gas_outlet_temp_no_leakage_c: f64,
+    /// 누설 공기가 섞인 실제 가스 출구 온도 [°C]
+    pub gas_outlet_temp_c: f64,
+    pub x_ratio: f64,
+    /// 가스 측 효율 (0~1)
+    pub gas_side_efficiency: f64,
+    /// 연료 열량 대비 회수 열량 = 보일러 효율 상승분 (0~1)
+    pub efficiency_gain: f64,
+    /// 연돌 온도 `STACK_TEMP_STEP_K` 저하당 효율 상승 (0~1)
+    pub efficiency_gain_per_step: f64,
+    /// 공기 입구 온도 기준 배가스 현열 손실 (예열기 입구 / 출구, 연료 열량 대비)
+    pub stack_loss_before_frac: f64,
+    pub stack_loss_after_frac: f64,
+    /// 수분 노점 [°C]
+    pub water_dew_point_c: f64,
+    /// 황산 노점 [°C] (황이 없으면 `None`)
+    pub acid_dew_point_c: Option<f64>,
+    /// 저온부 평균 온도 (가스 출구 + 공기 입구)/2 [°C]
+    pub cold_end_average_temp_c: f64,
+    pub warnings: Vec<AirPreheaterWarning>,
+}
+
+/// 공기예열기 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AirPreheaterWarning {
+    /// 저온부 평균 온도가 황산 노점 미만
+    ColdEndBelowAcidDewPoint {
+        cold_end_temp_c: f64,
+        dew_point_c: f64,
+    },
+    /// 가스 출구(연돌) 노점 경고
+    DewPoint(DewPointWarning),
+    /// 공기 누설률이 큼
+    HighLeakage { leakage_frac: f64 },
+}
+
+impl CalcWarning for AirPreheaterWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            AirPreheaterWarning::ColdEndBelowAcidDewPoint { .. } => Severity::Critical,
+            AirPreheaterWarning::DewPoint(w) => w.severity(),
+            AirPreheaterWarning::HighLeakage { .. } => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            AirPreheaterWarning::ColdEndBelowAcidDewPoint { .. } => {
+                "warning.air_preheater.cold_end_below_acid"
+            }
+            AirPreheaterWarning::DewPoint(w) => w.i18n_key(),
+            AirPreheaterWarning::HighLeakage { .. } => "warning.air_preheater.high_leakage",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            AirPreheaterWarning::ColdEndBelowAcidDewPoint {
+                cold_end_temp_c,
+                dew_point_c,
+            } => vec![
+                ("cold_end", format!("{cold_end_temp_c:.0}")),
+                ("dew_point", format!("{dew_point_c:.0}")),
+            ],
+            AirPreheaterWarning::DewPoint(w) => w.args(),
+            AirPreheaterWarning::HighLeakage { leakage_frac } => {
+                vec![("pct", format!("{:.1}", leakage_frac * 100.0))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for AirPreheaterWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AirPreheaterWarning::ColdEndBelowAcidDewPoint {
+                cold_end_temp_c,
+                dew_point_c,
+            } => write!(
+                f,
+                "저온부 평균 온도 {cold_end_temp_c:.0} °C가 황산 노점 {dew_point_c:.0} °C보다 낮아 저온부 전열소자 부식이 우려됩니다. 증기 공기예열기(SCAH)나 열풍 재순환으로 공기 입구 온도를 올리세요."
+            ),
+            AirPreheaterWarning::DewPoint(w) => w.fmt(f),
+            AirPreheaterWarning::HighLeakage { leakage_frac } => write!(
+                f,
+                "공기 누설률 {:.1}%가 큽니다. 재생식 공기예열기 씰 간격을 점검하세요.",
+                leakage_frac * 100.0
+            ),
+        }
+    }
+}
+
+/// 공기예열기 계산 오류.
+#[derive(Debug, Clone)]
+pub enum AirPreheaterError {
+    InvalidInput(&'static str),
+    /// 가스 출구 온도 [°C]가 공기 입구 온도 이하 (열수지 불가)
+    TemperatureCross(f64),
+}
+
+impl std::fmt::Display for AirPreheaterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AirPreheaterError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            AirPreheaterError::TemperatureCross(t) => write!(
+                f,
+                "온도 교차: 가스 출구 {t:.1} °C가 공기 입구 온도 이하입니다. 공기 출구 온도를 낮추세요."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AirPreheaterError {}
+
+impl From<DewPointError> for AirPreheaterError {
+    fn from(e: DewPointError) -> Self {
+        match e {
+            DewPointError::InvalidInput(msg) => AirPreheaterError::InvalidInput(msg),
+        }
+    }
+}
+
+/// 공기예열기 가스 출구 온도, X-비, 효율 이득과 산노점 경고를 계산한다.
+pub fn compute_air_preheater(
+    input: AirPreheaterInput,
+) -> Result<AirPreheaterResult, AirPreheaterError> {
+    input
+        .fuel
+        .validate()
+        .map_err(AirPreheaterError::InvalidInput)?;
+    if input.fuel_flow_kg_per_h <= 0.0 || input.fuel_lhv_kj_per_kg <= 0.0 {
+        return Err(AirPreheaterError::InvalidInput(
+            "연료 유량과 발열량은 0보다 커야 합니다.",
+        ));
+    }
+    if input.excess_air_frac < 0.0 || input.air_humidity_ratio < 0.0 {
+        return Err(AirPreheaterError::InvalidInput(
+            "과잉공기율과 절대습도는 0 이상이어야 합니다.",
+        ));
+    }
+    if !(0.0..0.5).contains(&input.air_leakage_frac) {
+        return Err(AirPreheaterError::InvalidInput(
+            "공기 누설률은 0 이상 0.5 미만이어야 합니다.",
+        ));
+    }
+    if input.air_outlet_temp_c <= input.air_inlet_temp_c
+        || input.air_outlet_temp_c >= input.gas_inlet_temp_c
+    {
+        return Err(AirPreheaterError::InvalidInput(
+            "공기 출구 온도는 공기 입구보다 높고 가스 입구보다 낮아야 합니다.",
+        ));
+    }
+
+    let gas = fuel::flue_gas(&input.fuel, input.excess_air_frac, input.air_humidity_ratio);
+    let m_fuel = input.fuel_flow_kg_per_h;
+    let m_gas = gas.total_kg_per_kg_fuel() * m_fuel;
+    let m_dry_air = gas.dry_air * m_fuel;
+    let m_leak = input.air_leakage_frac * m_gas;
+    let w = input.air_humidity_ratio;
+
+    let ta_in = input.air_inlet_temp_c;
+    let ta_out = input.air_outlet_temp_c;
+    let tg_in = input.gas_inlet_temp_c;
+    let cp_air = fuel::moist_air_cp_kj_per_kgk((ta_in + ta_out) / 2.0, w);
+    let q_kw = m_dry_air * cp_air * (ta_out - ta_in) / 3600.0;
+
+    // 가스 출구 온도: 평균 온도의 cp로 몇 번 되풀이한다
+    let mut tg_nl = tg_in - (ta_out - ta_in);
+    for _ in 0..5 {
+        let cp_gas = gas.cp_kj_per_kgk((tg_in + tg_nl) / 2.0);
+        tg_nl = tg_in - q_kw * 3600.0 / (m_gas * cp_gas);
+    }
+    if tg_nl <= ta_in {
+        return Err(AirPreheaterError::TemperatureCross(tg_nl));
+    }
+    // 누설 공기(공기 입구 온도)와 혼합
+    let cp_gas_out = gas.cp_kj_per_kgk(tg_nl);
+    let cp_leak = fuel::moist_air_cp_kj_per_kgk(ta_in, w) / (1.0 + w);
+    let tg_out = (m_gas * cp_gas_out * tg_nl + m_leak * cp_leak * ta_in)
+        / (m_gas * cp_gas_out + m_leak * cp_leak);
+
+    let fuel_heat_kw = m_fuel * input.fuel_lhv_kj_per_kg / 3600.0;
+    let efficiency_gain_per_step =
+        gas.total_kg_per_kg_fuel() * gas.cp_kj_per_kgk(tg_out) * STACK_TEMP_STEP_K
+            / input.fuel_lhv_kj_per_kg;
+
+    // 누설 공기도 공기 입구 온도에서 출발하므로 손실 차이는 회수 열량과 같다
+    let cp_before = gas.cp_kj_per_kgk((tg_in + ta_in) / 2.0);
+    let cp_after = gas.cp_kj_per_kgk((tg_out + ta_in) / 2.0);
+    let stack_loss_before_frac = m_gas * cp_before * (tg_in - ta_in) / 3600.0 / fuel_heat_kw;
+    let stack_loss_after_frac =
+        (m_gas + m_leak) * cp_after * (tg_out - ta_in) / 3600.0 / fuel_heat_kw;
+
+    let dew_points = dew_point::flue_gas_dew_points(
+        &gas,
+        dew_point::DEFAULT_SO3_CONVERSION,
+        dew_point::ATMOSPHERIC_PRESSURE_BAR_ABS,
+    )?;
+    let cold_end_average_temp_c = (tg_out + ta_in) / 2.0;
+
+    let mut warnings = Vec::new();
+    if let Some(adp) = dew_points.acid_dew_point_c {
+        if cold_end_average_temp_c < adp {
+            warnings.push(AirPreheaterWarning::ColdEndBelowAcidDewPoint {
+                cold_end_temp_c: cold_end_average_temp_c,
+                dew_point_c: adp,
+            });
+        }
+    }
+    warnings.extend(
+        dew_point::stack_dew_point_warnings(tg_out, &dew_points)
+            .into_iter()
+            .map(AirPreheaterWarning::DewPoint),
+    );
+    if input.air_leakage_frac > 0.10 {
+        warnings.push(AirPreheaterWarning::HighLeakage {
+            leakage_frac: input.air_leakage_frac,
+        });
+    }
+
+    Ok(AirPreheaterResult {
+        flue_gas_flow_kg_per_h: m_gas,
+        combustion_air_flow_kg_per_h: m_dry_air * (1.0 + w),
+        leakage_air_flow_kg_per_h: m_leak,
+        heat_recovered_kw: q_kw,
+        air_temp_rise_k: ta_out - ta_in,
+        gas_outlet_temp_no_leakage_c: tg_nl,
+        gas_outlet_temp_c: tg_out,
+        x_ratio: (tg_in - tg_nl) / (ta_out - ta_in),
+        gas_side_efficiency: (tg_in - tg_nl) / (tg_in - ta_in),
+        efficiency_gain: q_kw / fuel_heat_kw,
+        efficiency_gain_per_step,
+        stack_loss_before_frac,
+        stack_loss_after_frac,
+        water_dew_point_c: dew_points.water_dew_point_c,
+        acid_dew_point_c: dew_points.acid_dew_point_c,
+        cold_end_average_temp_c,
+        warnings,
+    })
+}
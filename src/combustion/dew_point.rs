@@ -0,0 +1,213 @@
+//! 배가스 수분 노점과 황산 노점.
+//! 수분 노점은 H₂O 분압의 포화 온도(IF97)이고, 황산 노점은 Verhoff-Banchero 식
+//! 1000/T = 2.276 − 0.0294 ln pH₂O − 0.0858 ln pSO₃ + 0.0062 ln pH₂O·ln pSO₃ (p: mmHg, T: K)로 구한다.
+//! SO₃는 연료 황이 만든 SO₂의 일정 비율(전환율)로 본다.
+//! 보일러/HRSG/공기예열기 계산기는 `stack_dew_point_warnings`로 같은 경고를 낸다.
+
+use crate::combustion::fuel::FlueGas;
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// 연료 황의 SO₃ 전환율 기본값 (보일러 일반값 1~4%)
+pub const DEFAULT_SO3_CONVERSION: f64 = 0.02;
+/// 배가스 온도를 산노점보다 이만큼 높게 두기를 권한다 [K]
+pub const ACID_DEW_POINT_MARGIN_K: f64 = 10.0;
+/// 표준 연소용 공기 절대습도 [kg/kg 건공기] (ASME PTC 4 기본값)
+pub const STANDARD_AIR_HUMIDITY_RATIO: f64 = 0.013;
+/// 대기압 [bar abs]
+pub const ATMOSPHERIC_PRESSURE_BAR_ABS: f64 = 1.01325;
+
+const MMHG_PER_BAR: f64 = 750.062;
+/// 물의 삼중점 압력 [bar abs]
+const TRIPLE_POINT_BAR_ABS: f64 = 0.006_116_57;
+
+/// 배가스 노점 계산 오류.
+#[derive(Debug, Clone)]
+pub enum DewPointError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for DewPointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DewPointError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for DewPointError {}
+
+/// 수분 노점 [°C]: H₂O 분압의 포화 온도
+pub fn water_dew_point_c(
+    h2o_mole_fraction: f64,
+    pressure_bar_abs: f64,
+) -> Result<f64, DewPointError> {
+    if pressure_bar_abs <= 0.0 || !(0.0..=1.0).contains(&h2o_mole_fraction) {
+        return Err(DewPointError::InvalidInput(
+            "압력은 0보다 크고 H₂O 몰 분율은 0~1이어야 합니다.",
+        ));
+    }
+    let p_h2o = h2o_mole_fraction * pressure_bar_abs;
+    if p_h2o < TRIPLE_POINT_BAR_ABS {
+        return Err(DewPointError::InvalidInput(
+            "H₂O 분압이 삼중점보다 낮아 노점을 구할 수 없습니다.",
+        ));
+    }
+    if97::saturation_temp_c_from_pressure_bar_abs(p_h2o).map_err(DewPointError::InvalidInput)
+}
+
+/// 황산 노점 [°C] (Verhoff-Banchero)
+pub fn acid_dew_point_c(
+    h2o_mole_fraction: f64,
+    so3_mole_fraction: f64,
+    pressure_bar_abs: f64,
+) -> Result<f64, DewPointError> {
+    if pressure_bar_abs <= 0.0 || h2o_mole_fraction <= 0.0 || so3_mole_fraction <= 0.0 {
+        return Err(DewPointError::InvalidInput(
+            "압력, H₂O와 SO₃ 몰 분율은 0보다 커야 합니다.",
+        ));
+    }
+    let a = (h2o_mole_fraction * pressure_bar_abs * MMHG_PER_BAR).ln();
+    let b = (so3_mole_fraction * pressure_bar_abs * MMHG_PER_BAR).ln();
+    Ok(1000.0 / (2.276 - 0.0294 * a - 0.0858 * b + 0.0062 * a * b) - 273.15)
+}
+
+/// 배가스 노점.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlueGasDewPoints {
+    pub water_dew_point_c: f64,
+    /// 황이 없으면 `None`
+    pub acid_dew_point_c: Option<f64>,
+    /// 습배가스 SO₃ 농도 [ppmv]
+    pub so3_ppmv: f64,
+}
+
+/// 배가스 조성에서 수분 노점과 황산 노점을 구한다.
+pub fn flue_gas_dew_points(
+    gas: &FlueGas,
+    so3_conversion: f64,
+    pressure_bar_abs: f64,
+) -> Result<FlueGasDewPoints, DewPointError> {
+    if !(0.0..=1.0).contains(&so3_conversion) {
+        return Err(DewPointError::InvalidInput(
+            "SO₃ 전환율은 0~1이어야 합니다.",
+        ));
+    }
+    let y_h2o = gas.h2o_mole_fraction();
+    let y_so3 = gas.so2_mole_fraction() * so3_conversion;
+    let acid_dew_point_c = if y_so3 > 0.0 {
+        Some(acid_dew_point_c(y_h2o, y_so3, pressure_bar_abs)?)
+    } else {
+        None
+    };
+    Ok(FlueGasDewPoints {
+        water_dew_point_c: water_dew_point_c(y_h2o, pressure_bar_abs)?,
+        acid_dew_point_c,
+        so3_ppmv: y_so3 * 1e6,
+    })
+}
+
+/// 연돌 노점 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DewPointWarning {
+    /// 배가스 온도가 수분 노점 미만
+    BelowWaterDewPoint { stack_temp_c: f64, dew_point_c: f64 },
+    /// 배가스 온도가 황산 노점 미만
+    BelowAcidDewPoint { stack_temp_c: f64, dew_point_c: f64 },
+    /// 배가스 온도가 황산 노점에 [`ACID_DEW_POINT_MARGIN_K`] 이내로 가까움
+    NearAcidDewPoint { stack_temp_c: f64, dew_point_c: f64 },
+}
+
+impl CalcWarning for DewPointWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            DewPointWarning::BelowAcidDewPoint { .. } => Severity::Critical,
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            DewPointWarning::BelowWaterDewPoint { .. } => "warning.dew_point.below_water",
+            DewPointWarning::BelowAcidDewPoint { .. } => "warning.dew_point.below_acid",
+            DewPointWarning::NearAcidDewPoint { .. } => "warning.dew_point.near_acid",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            DewPointWarning::BelowWaterDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            }
+            | DewPointWarning::BelowAcidDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            }
+            | DewPointWarning::NearAcidDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            } => vec![
+                ("stack", format!("{stack_temp_c:.0}")),
+                ("dew_point", format!("{dew_point_c:.0}")),
+                ("margin", format!("{ACID_DEW_POINT_MARGIN_K:.0}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for DewPointWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            DewPointWarning::BelowWaterDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            } => write!(
+                f,
+                "배가스 온도 {stack_temp_c:.0} °C가 수분 노점 {dew_point_c:.0} °C보다 낮아 응축수가 생깁니다. 응축식 설계가 아니면 내식 재질이 필요합니다."
+            ),
+            DewPointWarning::BelowAcidDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            } => write!(
+                f,
+                "배가스 온도 {stack_temp_c:.0} °C가 황산 노점 {dew_point_c:.0} °C보다 낮아 산 응축 부식이 생깁니다."
+            ),
+            DewPointWarning::NearAcidDewPoint {
+                stack_temp_c,
+                dew_point_c,
+            } => write!(
+                f,
+                "배가스 온도 {stack_temp_c:.0} °C가 황산 노점 {dew_point_c:.0} °C에 {ACID_DEW_POINT_MARGIN_K:.0} K 이내로 가깝습니다."
+            ),
+        }
+    }
+}
+
+/// 배가스(연돌) 온도가 노점에 닿거나 가까우면 경고를 낸다.
+pub fn stack_dew_point_warnings(
+    stack_temp_c: f64,
+    dew_points: &FlueGasDewPoints,
+) -> Vec<DewPointWarning> {
+    let mut warnings = Vec::new();
+    if stack_temp_c < dew_points.water_dew_point_c {
+        warnings.push(DewPointWarning::BelowWaterDewPoint {
+            stack_temp_c,
+            dew_point_c: dew_points.water_dew_point_c,
+        });
+    }
+    if let Some(adp) = dew_points.acid_dew_point_c {
+        if stack_temp_c < adp {
+            warnings.push(DewPointWarning::BelowAcidDewPoint {
+                stack_temp_c,
+                dew_point_c: adp,
+            });
+        } else if stack_temp_c < adp + ACID_DEW_POINT_MARGIN_K {
+            warnings.push(DewPointWarning::NearAcidDewPoint {
+                stack_temp_c,
+                dew_point_c: adp,
+            });
+        }
+    }
+    warnings
+}
@@ -0,0 +1,298 @@
+//! 연료 사용량에서 CO₂, SO₂, NOx 배출량 추정.
+//! CO₂와 SO₂는 연료 탄소/황이 모두 산화된다고 보는 물질수지(CO₂ = 44.01/12.011·C, SO₂ = 64.066/32.06·S)로,
+//! NOx(NO₂ 환산)는 US EPA AP-42 배출계수(1.1 역청탄, 1.3 연료유, 1.4 천연가스 보일러)를
+//! 고위발열량 기준 [kg/GJ]로 바꿔 연료 투입 열량에 곱한다.
+//! 천연가스는 AP-42처럼 투입 열량 100 MMBtu/h(29.3 MW) 기준으로 소형/대형 계수를 나눈다.
+//! 연간량은 연간 운전 시간을 곱한 톤/년(미터톤)이다.
+
+use crate::combustion::fuel::{FuelAnalysis, FuelType};
+use crate::warning::{CalcWarning, Severity};
+use serde::{Deserialize, Serialize};
+
+const M_C: f64 = 12.011;
+const M_CO2: f64 = 44.01;
+const M_S: f64 = 32.06;
+const M_SO2: f64 = 64.066;
+/// 1 lb/MMBtu = 0.453592 kg / 1.055056 GJ
+const KG_PER_GJ_PER_LB_PER_MMBTU: f64 = 0.429_923;
+/// AP-42 천연가스 소형/대형 보일러 경계 (100 MMBtu/h) [kW]
+const LARGE_BOILER_HEAT_INPUT_KW: f64 = 29_307.0;
+/// 1년 시간
+const HOURS_PER_YEAR: f64 = 8760.0;
+
+/// 버너/연소 방식 (AP-42 계수 구분).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BurnerType {
+    /// 일반 버너 (비제어)
+    Conventional,
+    /// 저NOx 버너
+    LowNox,
+    /// 저NOx 버너 + 배가스 재순환
+    LowNoxFgr,
+}
+
+impl BurnerType {
+    pub const ALL: [BurnerType; 3] = [
+        BurnerType::Conventional,
+        BurnerType::LowNox,
+        BurnerType::LowNoxFgr,
+    ];
+
+    /// i18n 키
+    pub fn key(self) -> &'static str {
+        match self {
+            BurnerType::Conventional => "burner.conventional",
+            BurnerType::LowNox => "burner.low_nox",
+            BurnerType::LowNoxFgr => "burner.low_nox_fgr",
+        }
+    }
+
+    /// 영어 기본 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            BurnerType::Conventional => "Conventional (uncontrolled)",
+            BurnerType::LowNox => "Low-NOx burner",
+            BurnerType::LowNoxFgr => "Low-NOx burner + FGR",
+        }
+    }
+}
+
+/// AP-42 NOx 배출계수 [kg/GJ, 고위발열량 기준].
+/// 연료유와 석탄은 FGR 계수가 없어 저NOx 버너 값을 쓴다.
+pub fn ap42_nox_factor_kg_per_gj(fuel: FuelType, burner: BurnerType, heat_input_kw: f64) -> f64 {
+    let large = heat_input_kw > LARGE_BOILER_HEAT_INPUT_KW;
+    // (AP-42 계수, 그 단위의 기준 고위발열량 MMBtu) → lb/MMBtu
+    let (factor, hhv_mmbtu) = match (fuel, burner) {
+        // lb/10⁶ scf, 1020 Btu/scf
+        (FuelType::NaturalGas, BurnerType::Conventional) => {
+            (if large { 190.0 } else { 100.0 }, 1020.0)
+        }
+        (FuelType::NaturalGas, BurnerType::LowNox) => (if large { 140.0 } else { 50.0 }, 1020.0),
+        (FuelType::NaturalGas, BurnerType::LowNoxFgr) => (if large { 100.0 } else { 32.0 }, 1020.0),
+        // lb/10³ gal, 경유 140 / 중유 150 MMBtu/10³ gal
+        (FuelType::FuelOil2, BurnerType::Conventional) => (20.0, 140.0),
+        (FuelType::FuelOil2, _) => (10.0, 140.0),
+        (FuelType::FuelOil6, BurnerType::Conventional) => (47.0, 150.0),
+        (FuelType::FuelOil6, _) => (40.0, 150.0),
+        // lb/ton, 미분탄 벽면 연소, 26 MMBtu/ton
+        (FuelType::BituminousCoal, BurnerType::Conventional) => (22.0, 26.0),
+        (FuelType::BituminousCoal, _) => (11.0, 26.0),
+    };
+    factor / hhv_mmbtu * KG_PER_GJ_PER_LB_PER_MMBTU
+}
+
+/// 배출량 계산 입력.
+#[derive(Debug, Clone)]
+pub struct EmissionsInput {
+    /// NOx 계수를 고를 연료 종류
+    pub fuel_type: FuelType,
+    /// 원소 분석 (보통 `fuel_type.analysis()`에 실측 황을 넣은 값)
+    pub fuel: FuelAnalysis,
+    pub fuel_flow_kg_per_h: f64,
+    /// 저위발열량 [kJ/kg]
+    pub fuel_lhv_kj_per_kg: f64,
+    pub burner: BurnerType,
+    /// 연간 운전 시간 [h/yr]
+    pub operating_hours_per_year: f64,
+    /// 측정/보증 NOx 계수 [kg/GJ, 고위발열량 기준]. 없으면 AP-42 계수.
+    pub nox_factor_kg_per_gj: Option<f64>,
+    /// 보일러 유효 출력 [kW]. 있으면 출력당 CO₂ 원단위를 낸다.
+    pub useful_output_kw: Option<f64>,
+}
+
+/// 한 오염물질의 배출량.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmissionRate {
+    pub kg_per_h: f64,
+    /// 톤/년 (미터톤)
+    pub tonnes_per_year: f64,
+    /// 연료 투입 열량(고위발열량) 당 [kg/GJ]
+    pub kg_per_gj: f64,
+}
+
+/// 배출량 계산 결과.
+#[derive(Debug, Clone)]
+pub struct EmissionsResult {
+    /// 연료 투입 열량 (저위 / 고위발열량 기준) [kW]
+    pub heat_input_lhv_kw: f64,
+    pub heat_input_hhv_kw: f64,
+    pub co2: EmissionRate,
+    pub so2: EmissionRate,
+    /// NO₂ 환산
+    pub nox: EmissionRate,
+    /// 사용한 NOx 계수 [kg/GJ, 고위발열량 기준]
+    pub nox_factor_kg_per_gj: f64,
+    /// AP-42 계수를 썼는지 (입력 계수면 false)
+    pub nox_from_ap42: bool,
+    /// 유효 출력당 CO₂ [kg/MWh] (유효 출력이 있을 때)
+    pub co2_per_useful_mwh_kg: Option<f64>,
+    pub warnings: Vec<EmissionsWarning>,
+}
+
+/// 배출량 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum EmissionsWarning {
+    /// 유효 출력이 연료 투입(저위)보다 큼
+    OutputExceedsHeatInput { output_kw: f64, heat_input_kw: f64 },
+    /// 탄소 분율이 대표 연료와 많이 달라 AP-42 NOx 계수가 맞지 않을 수 있음
+    AtypicalCarbon {
+        carbon: f64,
+        fuel_type: FuelType,
+        typical_carbon: f64,
+    },
+}
+
+impl CalcWarning for EmissionsWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            EmissionsWarning::OutputExceedsHeatInput { .. } => {
+                "warning.emissions.output_exceeds_input"
+            }
+            EmissionsWarning::AtypicalCarbon { .. } => "warning.emissions.atypical_carbon",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            EmissionsWarning::OutputExceedsHeatInput {
+                output_kw,
+                heat_input_kw,
+            } => vec![
+                ("output", format!("{output_kw:.0}")),
+                ("input", format!("{heat_input_kw:.0}")),
+            ],
+            EmissionsWarning::AtypicalCarbon {
+                carbon,
+                fuel_type,
+                typical_carbon,
+            } => vec![
+                ("carbon", format!("{:.1}", carbon * 100.0)),
+                ("fuel", fuel_type.name().to_string()),
+                ("typical", format!("{:.1}", typical_carbon * 100.0)),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for EmissionsWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            EmissionsWarning::OutputExceedsHeatInput {
+                output_kw,
+                heat_input_kw,
+            } => write!(
+                f,
+                "유효 출력 {output_kw:.0} kW가 연료 투입 {heat_input_kw:.0} kW(저위)보다 큽니다. 입력을 확인하세요."
+            ),
+            EmissionsWarning::AtypicalCarbon {
+                carbon,
+                fuel_type,
+                typical_carbon,
+            } => write!(
+                f,
+                "탄소 분율 {:.1}%가 {} 대표값 {:.1}%와 많이 달라 AP-42 NOx 계수가 맞지 않을 수 있습니다.",
+                carbon * 100.0,
+                fuel_type.name(),
+                typical_carbon * 100.0
+            ),
+        }
+    }
+}
+
+/// 배출량 계산 오류.
+#[derive(Debug, Clone)]
+pub enum EmissionsError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for EmissionsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EmissionsError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EmissionsError {}
+
+/// 연료 사용량에서 CO₂, SO₂, NOx 배출량을 계산한다.
+pub fn compute_emissions(input: EmissionsInput) -> Result<EmissionsResult, EmissionsError> {
+    input
+        .fuel
+        .validate()
+        .map_err(EmissionsError::InvalidInput)?;
+    if input.fuel_flow_kg_per_h <= 0.0 || input.fuel_lhv_kj_per_kg <= 0.0 {
+        return Err(EmissionsError::InvalidInput(
+            "연료 유량과 발열량은 0보다 커야 합니다.",
+        ));
+    }
+    if !(0.0..=HOURS_PER_YEAR).contains(&input.operating_hours_per_year) {
+        return Err(EmissionsError::InvalidInput(
+            "연간 운전 시간은 0~8760 h여야 합니다.",
+        ));
+    }
+    if input.nox_factor_kg_per_gj.is_some_and(|f| f < 0.0) {
+        return Err(EmissionsError::InvalidInput(
+            "NOx 계수는 0 이상이어야 합니다.",
+        ));
+    }
+
+    let m_fuel = input.fuel_flow_kg_per_h;
+    let heat_input_lhv_kw = m_fuel * input.fuel_lhv_kj_per_kg / 3600.0;
+    let hhv = input.fuel.hhv_from_lhv_kj_per_kg(input.fuel_lhv_kj_per_kg);
+    let heat_input_hhv_kw = m_fuel * hhv / 3600.0;
+    let gj_per_h = heat_input_hhv_kw * 3600.0 / 1e6;
+
+    let rate = |kg_per_h: f64| EmissionRate {
+        kg_per_h,
+        tonnes_per_year: kg_per_h * input.operating_hours_per_year / 1000.0,
+        kg_per_gj: kg_per_h / gj_per_h,
+    };
+    let co2 = rate(m_fuel * input.fuel.carbon * M_CO2 / M_C);
+    let so2 = rate(m_fuel * input.fuel.sulfur * M_SO2 / M_S);
+    let (nox_factor_kg_per_gj, nox_from_ap42) = match input.nox_factor_kg_per_gj {
+        Some(f) => (f, false),
+        None => (
+            ap42_nox_factor_kg_per_gj(input.fuel_type, input.burner, heat_input_hhv_kw),
+            true,
+        ),
+    };
+    let nox = rate(nox_factor_kg_per_gj * gj_per_h);
+    let co2_per_useful_mwh_kg = input
+        .useful_output_kw
+        .filter(|&q| q > 0.0)
+        .map(|q| co2.kg_per_h / (q / 1000.0));
+
+    let mut warnings = Vec::new();
+    if let Some(q) = input.useful_output_kw.filter(|&q| q > heat_input_lhv_kw) {
+        warnings.push(EmissionsWarning::OutputExceedsHeatInput {
+            output_kw: q,
+            heat_input_kw: heat_input_lhv_kw,
+        });
+    }
+    let typical = input.fuel_type.analysis();
+    if nox_from_ap42 && (input.fuel.carbon - typical.carbon).abs() > 0.1 {
+        warnings.push(EmissionsWarning::AtypicalCarbon {
+            carbon: input.fuel.carbon,
+            fuel_type: input.fuel_type,
+            typical_carbon: typical.carbon,
+        });
+    }
+
+    Ok(EmissionsResult {
+        heat_input_lhv_kw,
+        heat_input_hhv_kw,
+        co2,
+        so2,
+        nox,
+        nox_factor_kg_per_gj,
+        nox_from_ap42,
+        co2_per_useful_mwh_kg,
+        warnings,
+    })
+}
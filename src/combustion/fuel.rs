@@ -0,0 +1,249 @@
+//! 연료 원소 분석과 배가스 조성.
+//! 원소 분석(질량 분율, 도착 기준)에서 완전 연소를 가정해 이론 공기량과 연료 1 kg당 배가스 성분을 구한다.
+//! 연소용 공기는 건공기 O₂ 질량 분율 0.2314로 보고 아르곤은 N₂에 포함한다.
+//! 비열은 0~500 °C 범위의 성분별 선형 근사를 질량 가중한다.
+
+use serde::{Deserialize, Serialize};
+
+/// 25 °C 물의 증발 잠열 [kJ/kg] (고위/저위 발열량 환산)
+const WATER_LATENT_HEAT_KJ_PER_KG: f64 = 2442.0;
+/// 건공기 중 O₂ 질량 분율
+const O2_MASS_FRACTION_IN_AIR: f64 = 0.2314;
+/// 원소 분석 합계 허용 오차
+const ANALYSIS_SUM_TOLERANCE: f64 = 0.02;
+
+const M_CO2: f64 = 44.01;
+const M_H2O: f64 = 18.015;
+const M_SO2: f64 = 64.066;
+const M_N2: f64 = 28.013;
+const M_O2: f64 = 31.999;
+
+/// 연료 원소 분석 (질량 분율, 도착 기준).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FuelAnalysis {
+    pub carbon: f64,
+    pub hydrogen: f64,
+    pub sulfur: f64,
+    pub oxygen: f64,
+    pub nitrogen: f64,
+    pub moisture: f64,
+    pub ash: f64,
+}
+
+impl FuelAnalysis {
+    /// 음수가 없고 합계가 1 ± 0.02인지 확인한다.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        let parts = [
+            self.carbon,
+            self.hydrogen,
+            self.sulfur,
+            self.oxygen,
+            self.nitrogen,
+            self.moisture,
+            self.ash,
+        ];
+        if parts.iter().any(|&x| x < 0.0 || x.is_nan()) {
+            return Err("연료 원소 분석 값은 0 이상이어야 합니다.");
+        }
+        if (parts.iter().sum::<f64>() - 1.0).abs() > ANALYSIS_SUM_TOLERANCE {
+            return Err("연료 원소 분석 합계가 1(100%)이 아닙니다.");
+        }
+        Ok(())
+    }
+
+    /// 황 분율을 바꾼 분석. 합계를 유지하도록 차이만큼 탄소를 조정한다.
+    pub fn with_sulfur(self, sulfur: f64) -> Self {
+        Self {
+            carbon: self.carbon + self.sulfur - sulfur,
+            sulfur,
+            ..self
+        }
+    }
+
+    /// 이론 O₂ [kg/kg 연료] = 2.664 C + 7.937 H + 0.998 S − O
+    pub fn stoichiometric_o2_kg_per_kg(&self) -> f64 {
+        (2.664 * self.carbon + 7.937 * self.hydrogen + 0.998 * self.sulfur - self.oxygen).max(0.0)
+    }
+
+    /// 저위발열량에서 고위발열량 [kJ/kg]: 연소 생성수와 연료 수분의 잠열을 더한다.
+    pub fn hhv_from_lhv_kj_per_kg(&self, lhv_kj_per_kg: f64) -> f64 {
+        lhv_kj_per_kg + WATER_LATENT_HEAT_KJ_PER_KG * (8.937 * self.hydrogen + self.moisture)
+    }
+
+    /// 이론 건공기량 [kg/kg 연료]
+    pub fn stoichiometric_air_kg_per_kg(&self) -> f64 {
+        self.stoichiometric_o2_kg_per_kg() / O2_MASS_FRACTION_IN_AIR
+    }
+}
+
+/// 대표 연료.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FuelType {
+    NaturalGas,
+    /// 경유(No.2 연료유)
+    FuelOil2,
+    /// 중유(No.6, 벙커C)
+    FuelOil6,
+    /// 역청탄
+    BituminousCoal,
+}
+
+impl FuelType {
+    pub const ALL: [FuelType; 4] = [
+        FuelType::NaturalGas,
+        FuelType::FuelOil2,
+        FuelType::FuelOil6,
+        FuelType::BituminousCoal,
+    ];
+
+    /// i18n 키
+    pub fn key(self) -> &'static str {
+        match self {
+            FuelType::NaturalGas => "fuel.natural_gas",
+            FuelType::FuelOil2 => "fuel.fuel_oil_2",
+            FuelType::FuelOil6 => "fuel.fuel_oil_6",
+            FuelType::BituminousCoal => "fuel.bituminous_coal",
+        }
+    }
+
+    /// 영어 기본 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            FuelType::NaturalGas => "Natural gas",
+            FuelType::FuelOil2 => "Fuel oil No.2",
+            FuelType::FuelOil6 => "Fuel oil No.6",
+            FuelType::BituminousCoal => "Bituminous coal",
+        }
+    }
+
+    /// 대표 원소 분석
+    pub fn analysis(self) -> FuelAnalysis {
+        match self {
+            FuelType::NaturalGas => FuelAnalysis {
+                carbon: 0.730,
+                hydrogen: 0.235,
+                sulfur: 0.0,
+                oxygen: 0.010,
+                nitrogen: 0.025,
+                moisture: 0.0,
+                ash: 0.0,
+            },
+            FuelType::FuelOil2 => FuelAnalysis {
+                carbon: 0.866,
+                hydrogen: 0.130,
+                sulfur: 0.003,
+                oxygen: 0.001,
+                nitrogen: 0.0,
+                moisture: 0.0,
+                ash: 0.0,
+            },
+            FuelType::FuelOil6 => FuelAnalysis {
+                carbon: 0.857,
+                hydrogen: 0.105,
+                sulfur: 0.025,
+                oxygen: 0.005,
+                nitrogen: 0.003,
+                moisture: 0.004,
+                ash: 0.001,
+            },
+            FuelType::BituminousCoal => FuelAnalysis {
+                carbon: 0.670,
+                hydrogen: 0.045,
+                sulfur: 0.015,
+                oxygen: 0.070,
+                nitrogen: 0.013,
+                moisture: 0.080,
+                ash: 0.107,
+            },
+        }
+    }
+
+    /// 대표 저위발열량 [kJ/kg]
+    pub fn lhv_kj_per_kg(self) -> f64 {
+        match self {
+            FuelType::NaturalGas => 47_100.0,
+            FuelType::FuelOil2 => 42_800.0,
+            FuelType::FuelOil6 => 40_500.0,
+            FuelType::BituminousCoal => 26_000.0,
+        }
+    }
+}
+
+/// 연료 1 kg당 배가스 성분 [kg/kg 연료].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FlueGas {
+    pub co2: f64,
+    pub h2o: f64,
+    pub so2: f64,
+    pub n2: f64,
+    pub o2: f64,
+    /// 연소용 건공기 [kg/kg 연료]
+    pub dry_air: f64,
+}
+
+impl FlueGas {
+    /// 습배가스 전체 [kg/kg 연료]
+    pub fn total_kg_per_kg_fuel(&self) -> f64 {
+        self.co2 + self.h2o + self.so2 + self.n2 + self.o2
+    }
+
+    fn moles(&self) -> [f64; 5] {
+        [
+            self.co2 / M_CO2,
+            self.h2o / M_H2O,
+            self.so2 / M_SO2,
+            self.n2 / M_N2,
+            self.o2 / M_O2,
+        ]
+    }
+
+    /// 습배가스 중 H₂O 몰 분율
+    pub fn h2o_mole_fraction(&self) -> f64 {
+        let n = self.moles();
+        n[1] / n.iter().sum::<f64>()
+    }
+
+    /// 습배가스 중 SO₂ 몰 분율
+    pub fn so2_mole_fraction(&self) -> f64 {
+        let n = self.moles();
+        n[2] / n.iter().sum::<f64>()
+    }
+
+    /// 건배가스 중 CO₂ 몰 분율 (연소 분석계 표시값)
+    pub fn co2_dry_mole_fraction(&self) -> f64 {
+        let n = self.moles();
+        n[0] / (n.iter().sum::<f64>() - n[1])
+    }
+
+    /// 습배가스 정압비열 [kJ/kg·K] (질량 가중)
+    pub fn cp_kj_per_kgk(&self, t_c: f64) -> f64 {
+        let t = t_c.clamp(0.0, 600.0);
+        let sum = self.co2 * (0.840 + 0.000_60 * t)
+            + self.h2o * (1.855 + 0.000_45 * t)
+            + self.so2 * (0.610 + 0.000_45 * t)
+            + self.n2 * (1.036 + 0.000_08 * t)
+            + self.o2 * (0.912 + 0.000_26 * t);
+        sum / self.total_kg_per_kg_fuel()
+    }
+}
+
+/// 습공기 정압비열 [kJ/kg 건공기·K] (수증기 포함)
+pub fn moist_air_cp_kj_per_kgk(t_c: f64, humidity_ratio: f64) -> f64 {
+    let t = t_c.clamp(0.0, 600.0);
+    (1.002 + 0.000_13 * t) + humidity_ratio * (1.855 + 0.000_45 * t)
+}
+
+/// 과잉공기율과 공기 절대습도로 연료 1 kg당 배가스 성분을 구한다.
+pub fn flue_gas(fuel: &FuelAnalysis, excess_air_frac: f64, humidity_ratio: f64) -> FlueGas {
+    let o2_stoich = fuel.stoichiometric_o2_kg_per_kg();
+    let dry_air = fuel.stoichiometric_air_kg_per_kg() * (1.0 + excess_air_frac.max(0.0));
+    FlueGas {
+        co2: 3.664 * fuel.carbon,
+        h2o: 8.937 * fuel.hydrogen + fuel.moisture + humidity_ratio.max(0.0) * dry_air,
+        so2: 1.998 * fuel.sulfur,
+        n2: (1.0 - O2_MASS_FRACTION_IN_AIR) * dry_air + fuel.nitrogen,
+        o2: excess_air_frac.max(0.0) * o2_stoich,
+        dry_air,
+    }
+}
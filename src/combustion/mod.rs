@@ -0,0 +1,8 @@
+//! 연소 계산 모듈 모음.
+//! 연료 원소 분석(C, H, S, O, N, 수분, 회분)에서 이론 공기량과 배가스 조성을 구하고,
+//! 이를 바탕으로 배가스 노점, 공기예열기 성능과 배출량(CO₂, SO₂, NOx)을 계산한다.
+
+pub mod air_preheater;
+pub mod dew_point;
+pub mod emissions;
+pub mod fuel;
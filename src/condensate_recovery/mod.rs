@@ -2,8 +2,12 @@
 
 pub mod economics;
 pub mod flash_steam;
+pub mod receiver;
 pub mod recovery_piping;
+pub mod return_line;
 
 pub use economics::*;
 pub use flash_steam::*;
+pub use receiver::*;
 pub use recovery_piping::*;
+pub use return_line::*;
@@ -0,0 +1,286 @@
+//! 벤트식 응축수 리시버 사이징.
+//! 여러 압력의 응축수가 리시버로 들어올 때 흐름별 플래시 증기를 합해 벤트 관경을 허용 유속으로
+//! 고르고, 남은 포화수로 서지 시간에 맞는 리시버 용적과 펌프 용량을 정한다.
+//! 각 흐름은 발생 압력의 포화수로 보고, 리시버 안 액체는 리시버 압력의 포화수다.
+
+use crate::condensate_recovery::flash_steam::{flash_steam, FlashSteamInput};
+use crate::condensate_recovery::return_line::STANDARD_PIPE_SIZES;
+use crate::cooling::pump_npsh::MIN_MARGIN_RATIO;
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// 플래시 벤트 기본 허용 유속 [m/s]
+pub const DEFAULT_VENT_VELOCITY_M_PER_S: f64 = 15.0;
+
+/// 리시버로 들어오는 응축수 흐름.
+#[derive(Debug, Clone)]
+pub struct CondensateStream {
+    pub name: String,
+    /// 응축수 유량 [kg/h]
+    pub flow_kg_per_h: f64,
+    /// 응축수 발생(트랩 입구) 압력 [bar abs]
+    pub pressure_bar_abs: f64,
+}
+
+/// 리시버 사이징 입력.
+#[derive(Debug, Clone)]
+pub struct ReceiverInput {
+    pub streams: Vec<CondensateStream>,
+    /// 리시버 압력 [bar abs]. 대기 벤트면 현장 대기압.
+    pub receiver_pressure_bar_abs: f64,
+    /// 벤트 허용 유속 [m/s]. `None`이면 15 m/s.
+    pub vent_velocity_limit_m_per_s: Option<f64>,
+    /// 펌프가 멈춰도 받아낼 서지 시간 [min]
+    pub surge_time_min: f64,
+    /// 서지에 쓰는 리시버 용적 비율 (나머지는 플래시 분리 공간과 펌프 정지 액위)
+    pub working_fraction: f64,
+    /// 펌프 용량 / 응축수 유량 배수 (1 이상)
+    pub pump_capacity_factor: f64,
+    /// 펌프 요구 NPSH [m]. 있으면 필요한 리시버 설치 높이를 낸다.
+    pub pump_npshr_m: Option<f64>,
+}
+
+/// 흐름별 플래시 결과.
+#[derive(Debug, Clone)]
+pub struct StreamFlash {
+    pub name: String,
+    /// 리시버 압력에서의 플래시 증기 비율
+    pub flash_fraction: f64,
+    pub flash_steam_kg_per_h: f64,
+}
+
+/// 리시버 사이징 결과.
+#[derive(Debug, Clone)]
+pub struct ReceiverResult {
+    pub streams: Vec<StreamFlash>,
+    pub total_condensate_kg_per_h: f64,
+    /// 벤트로 나가는 플래시 증기 [kg/h]
+    pub flash_steam_kg_per_h: f64,
+    /// 펌프로 보낼 포화수 [kg/h]
+    pub liquid_kg_per_h: f64,
+    /// 리시버 액체 온도 [°C]
+    pub liquid_temp_c: f64,
+    /// 벤트 증기 체적유량 [m³/h]
+    pub vent_volumetric_flow_m3_per_h: f64,
+    pub vent_velocity_limit_m_per_s: f64,
+    /// 허용 유속을 만족하는 벤트 최소 내경 [m]
+    pub vent_min_inner_diameter_m: f64,
+    /// 추천 벤트 관경 (DN). 표준 관경 범위를 넘으면 `None`.
+    pub vent_dn: Option<u32>,
+    pub vent_inner_diameter_m: f64,
+    /// 추천 관경에서의 벤트 유속 [m/s]
+    pub vent_velocity_m_per_s: f64,
+    /// 서지 시간 동안 들어오는 액체 용적 [m³]
+    pub surge_volume_m3: f64,
+    /// 필요 리시버 용적 [m³]
+    pub receiver_volume_m3: f64,
+    /// 펌프 용량 [m³/h]
+    pub pump_flow_m3_per_h: f64,
+    /// 펌프 중심 위 필요 최소 액면 높이 [m] (흡입 마찰손실 제외)
+    pub min_static_head_m: Option<f64>,
+    pub warnings: Vec<ReceiverWarning>,
+}
+
+/// 응축수 리시버 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReceiverWarning {
+    /// 벤트 필요 내경이 표준 관경 범위를 넘음
+    VentBeyondStandardSizes { min_diameter_mm: f64 },
+    /// 플래시 증기가 응축수의 10%를 넘음
+    HighFlash { flash_pct: f64 },
+    /// 펌프 요구 NPSH 미입력: 설치 높이 확인 안내
+    NpshReminder { saturation_temp_c: f64 },
+}
+
+impl CalcWarning for ReceiverWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            ReceiverWarning::VentBeyondStandardSizes { .. } => Severity::Caution,
+            _ => Severity::Info,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ReceiverWarning::VentBeyondStandardSizes { .. } => "warning.receiver.vent_too_large",
+            ReceiverWarning::HighFlash { .. } => "warning.receiver.high_flash",
+            ReceiverWarning::NpshReminder { .. } => "warning.receiver.npsh_reminder",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            ReceiverWarning::VentBeyondStandardSizes { min_diameter_mm } => {
+                vec![("d", format!("{min_diameter_mm:.0}"))]
+            }
+            ReceiverWarning::HighFlash { flash_pct } => vec![("pct", format!("{flash_pct:.0}"))],
+            ReceiverWarning::NpshReminder { saturation_temp_c } => {
+                vec![("t_sat", format!("{saturation_temp_c:.0}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ReceiverWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ReceiverWarning::VentBeyondStandardSizes { min_diameter_mm } => write!(
+                f,
+                "벤트 필요 내경 {min_diameter_mm:.0} mm가 표준 관경 범위(DN300)를 넘습니다. 플래시 탱크로 중압 플래시를 먼저 회수하는 방안을 검토하세요."
+            ),
+            ReceiverWarning::HighFlash { flash_pct } => write!(
+                f,
+                "플래시 증기가 응축수의 {flash_pct:.0}%입니다. 벤트로 버리는 대신 저압 증기로 회수하는 방안을 검토하세요."
+            ),
+            ReceiverWarning::NpshReminder { saturation_temp_c } => write!(
+                f,
+                "리시버 액체가 {saturation_temp_c:.0} °C 포화수이므로 NPSHa는 정수두에서 흡입 마찰손실을 뺀 값뿐입니다. 펌프 요구 NPSH로 리시버 설치 높이를 확인하세요."
+            ),
+        }
+    }
+}
+
+/// 리시버 계산 오류.
+#[derive(Debug, Clone)]
+pub enum ReceiverError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for ReceiverError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReceiverError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            ReceiverError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ReceiverError {}
+
+/// 플래시 벤트, 리시버 용적, 펌프 용량을 계산한다.
+pub fn size_receiver(input: &ReceiverInput) -> Result<ReceiverResult, ReceiverError> {
+    if input.streams.is_empty() {
+        return Err(ReceiverError::InvalidInput(
+            "응축수 흐름이 하나 이상 있어야 합니다.",
+        ));
+    }
+    let p_rcv = input.receiver_pressure_bar_abs;
+    if p_rcv <= 0.0 {
+        return Err(ReceiverError::InvalidInput(
+            "리시버 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input
+        .streams
+        .iter()
+        .any(|s| s.flow_kg_per_h < 0.0 || s.pressure_bar_abs < p_rcv)
+    {
+        return Err(ReceiverError::InvalidInput(
+            "응축수 유량은 0 이상이고 발생 압력은 리시버 압력 이상이어야 합니다.",
+        ));
+    }
+    let limit = input
+        .vent_velocity_limit_m_per_s
+        .unwrap_or(DEFAULT_VENT_VELOCITY_M_PER_S);
+    if limit <= 0.0 || input.surge_time_min <= 0.0 {
+        return Err(ReceiverError::InvalidInput(
+            "벤트 허용 유속과 서지 시간은 0보다 커야 합니다.",
+        ));
+    }
+    if !(input.working_fraction > 0.0 && input.working_fraction <= 1.0) {
+        return Err(ReceiverError::InvalidInput(
+            "서지 용적 비율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if input.pump_capacity_factor < 1.0 {
+        return Err(ReceiverError::InvalidInput(
+            "펌프 용량 배수는 1 이상이어야 합니다.",
+        ));
+    }
+    if input.pump_npshr_m.is_some_and(|n| n < 0.0) {
+        return Err(ReceiverError::InvalidInput(
+            "펌프 요구 NPSH는 음수일 수 없습니다.",
+        ));
+    }
+
+    let if97_err = |e: &str| ReceiverError::If97(e.into());
+    let (hf_rcv, hg_rcv) = if97::saturation_enthalpies(p_rcv).map_err(if97_err)?;
+    let mut streams = Vec::with_capacity(input.streams.len());
+    for s in &input.streams {
+        let (hf_src, _) = if97::saturation_enthalpies(s.pressure_bar_abs).map_err(if97_err)?;
+        let flash_fraction = flash_steam(FlashSteamInput {
+            condensate_enthalpy_high_kj_per_kg: hf_src / 1000.0,
+            saturated_liquid_low_kj_per_kg: hf_rcv / 1000.0,
+            saturated_vapor_low_kj_per_kg: hg_rcv / 1000.0,
+        })
+        .flash_fraction;
+        streams.push(StreamFlash {
+            name: s.name.clone(),
+            flash_fraction,
+            flash_steam_kg_per_h: s.flow_kg_per_h * flash_fraction,
+        });
+    }
+    let total: f64 = input.streams.iter().map(|s| s.flow_kg_per_h).sum();
+    if total <= 0.0 {
+        return Err(ReceiverError::InvalidInput(
+            "전체 응축수 유량은 0보다 커야 합니다.",
+        ));
+    }
+    let flash: f64 = streams.iter().map(|s| s.flash_steam_kg_per_h).sum();
+    let liquid = total - flash;
+
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_rcv).map_err(if97_err)?;
+    let (_, vf, _) = if97::props_from_px(p_rcv, 0.0).map_err(if97_err)?;
+    let (_, vg, _) = if97::props_from_px(p_rcv, 1.0).map_err(if97_err)?;
+
+    let mut warnings = Vec::new();
+    let vent_q = flash * vg;
+    let min_d = (4.0 * vent_q / 3600.0 / (std::f64::consts::PI * limit)).sqrt();
+    let (vent_dn, vent_d) = match STANDARD_PIPE_SIZES
+        .iter()
+        .find(|(_, id_mm)| id_mm / 1000.0 >= min_d)
+    {
+        Some(&(dn, id_mm)) => (Some(dn), id_mm / 1000.0),
+        None => {
+            warnings.push(ReceiverWarning::VentBeyondStandardSizes {
+                min_diameter_mm: min_d * 1000.0,
+            });
+            (None, min_d)
+        }
+    };
+    let vent_velocity = vent_q / 3600.0 / (std::f64::consts::PI * vent_d * vent_d / 4.0);
+
+    let liquid_q = liquid * vf;
+    let surge = liquid_q * input.surge_time_min / 60.0;
+    let min_static_head_m = input.pump_npshr_m.map(|n| n * MIN_MARGIN_RATIO);
+    if flash > 0.1 * total {
+        warnings.push(ReceiverWarning::HighFlash {
+            flash_pct: flash / total * 100.0,
+        });
+    }
+    if min_static_head_m.is_none() {
+        warnings.push(ReceiverWarning::NpshReminder {
+            saturation_temp_c: t_sat,
+        });
+    }
+
+    Ok(ReceiverResult {
+        streams,
+        total_condensate_kg_per_h: total,
+        flash_steam_kg_per_h: flash,
+        liquid_kg_per_h: liquid,
+        liquid_temp_c: t_sat,
+        vent_volumetric_flow_m3_per_h: vent_q,
+        vent_velocity_limit_m_per_s: limit,
+        vent_min_inner_diameter_m: min_d,
+        vent_dn,
+        vent_inner_diameter_m: vent_d,
+        vent_velocity_m_per_s: vent_velocity,
+        surge_volume_m3: surge,
+        receiver_volume_m3: surge / input.working_fraction,
+        pump_flow_m3_per_h: liquid_q * input.pump_capacity_factor,
+        min_static_head_m,
+        warnings,
+    })
+}
@@ -1,3 +1,7 @@
+use crate::water::water_piping::darcy_friction_factor;
+
+const G: f64 = 9.80665;
+
 /// 응축수 회수 배관 압력손실 입력.
 #[derive(Debug, Clone)]
 pub struct RecoveryPressureLossInput {
@@ -7,16 +11,30 @@ pub struct RecoveryPressureLossInput {
     pub diameter_m: f64,
     pub length_m: f64,
     pub roughness_m: f64,
+    /// 입구 높이 [m]
+    pub inlet_elevation_m: f64,
+    /// 출구 높이 [m]. 트랩 뒤 상향 리프트면 입구보다 높다.
+    pub outlet_elevation_m: f64,
 }
 
 /// 압력손실 결과.
 #[derive(Debug, Clone)]
 pub struct RecoveryPressureLossResult {
     pub velocity_m_per_s: f64,
+    /// 마찰 압력손실 [bar] (정수두 제외)
     pub pressure_drop_bar: f64,
+    /// 정수두 ρ·g·(z출구 - z입구) [bar]. 오르막이 +.
+    pub elevation_drop_bar: f64,
     pub reynolds: f64,
 }
 
+impl RecoveryPressureLossResult {
+    /// 마찰 손실과 정수두를 합한 입구-출구 압력 차 [bar]
+    pub fn total_drop_bar(&self) -> f64 {
+        self.pressure_drop_bar + self.elevation_drop_bar
+    }
+}
+
 /// Darcy-Weisbach를 사용한 응축수 회수 라인 압력손실 계산.
 pub fn recovery_pressure_loss(input: RecoveryPressureLossInput) -> RecoveryPressureLossResult {
     let flow_m3_s = (input.condensate_flow_kg_per_h / input.density_kg_per_m3) / 3600.0;
@@ -38,9 +56,76 @@ pub fn recovery_pressure_loss(input: RecoveryPressureLossInput) -> RecoveryPress
         * velocity
         * velocity
         / 2.0;
+    let elevation_drop_pa =
+        input.density_kg_per_m3 * G * (input.outlet_elevation_m - input.inlet_elevation_m);
     RecoveryPressureLossResult {
         velocity_m_per_s: velocity,
         pressure_drop_bar: delta_p_pa / 100_000.0,
+        elevation_drop_bar: elevation_drop_pa / 100_000.0,
+        reynolds,
+    }
+}
+
+/// 2상(응축수 + 플래시 증기) 압력손실 입력.
+#[derive(Debug, Clone)]
+pub struct TwoPhasePressureLossInput {
+    /// 전체 질량유량 [kg/h]
+    pub mass_flow_kg_per_h: f64,
+    /// 증기 건도 (0~1)
+    pub quality: f64,
+    pub liquid_density_kg_per_m3: f64,
+    pub vapor_density_kg_per_m3: f64,
+    pub liquid_viscosity_pa_s: f64,
+    pub vapor_viscosity_pa_s: f64,
+    pub diameter_m: f64,
+    pub length_m: f64,
+    pub roughness_m: f64,
+    /// 입구 높이 [m]
+    pub inlet_elevation_m: f64,
+    /// 출구 높이 [m]
+    pub outlet_elevation_m: f64,
+}
+
+/// 2상 압력손실 결과.
+#[derive(Debug, Clone)]
+pub struct TwoPhasePressureLossResult {
+    /// 균질 혼합 밀도 [kg/m³]
+    pub mixture_density_kg_per_m3: f64,
+    /// 균질 혼합 유속 [m/s]
+    pub velocity_m_per_s: f64,
+    /// 마찰 압력손실 [bar] (정수두 제외)
+    pub pressure_drop_bar: f64,
+    /// 혼합 밀도 기준 정수두 [bar]. 상향 리프트에서는 액 체류로 실제 값이 더 클 수 있다.
+    pub elevation_drop_bar: f64,
+    pub reynolds: f64,
+}
+
+impl TwoPhasePressureLossResult {
+    /// 마찰 손실과 정수두를 합한 입구-출구 압력 차 [bar]
+    pub fn total_drop_bar(&self) -> f64 {
+        self.pressure_drop_bar + self.elevation_drop_bar
+    }
+}
+
+/// 균질 모델 2상 압력손실. 혼합 밀도는 비체적 가중, 점도는 McAdams 식(1/μ = x/μg + (1-x)/μl)을 쓴다.
+pub fn two_phase_pressure_loss(input: TwoPhasePressureLossInput) -> TwoPhasePressureLossResult {
+    let x = input.quality.clamp(0.0, 1.0);
+    let v_mix = x / input.vapor_density_kg_per_m3 + (1.0 - x) / input.liquid_density_kg_per_m3;
+    let rho_mix = 1.0 / v_mix;
+    let mu_mix = 1.0 / (x / input.vapor_viscosity_pa_s + (1.0 - x) / input.liquid_viscosity_pa_s);
+    let area = std::f64::consts::PI * input.diameter_m * input.diameter_m / 4.0;
+    let mass_flux = input.mass_flow_kg_per_h / 3600.0 / area;
+    let velocity = mass_flux * v_mix;
+    let reynolds = mass_flux * input.diameter_m / mu_mix;
+    let friction_factor = darcy_friction_factor(reynolds, input.roughness_m, input.diameter_m);
+    let delta_p_pa =
+        friction_factor * (input.length_m / input.diameter_m) * rho_mix * velocity * velocity / 2.0;
+    let elevation_drop_pa = rho_mix * G * (input.outlet_elevation_m - input.inlet_elevation_m);
+    TwoPhasePressureLossResult {
+        mixture_density_kg_per_m3: rho_mix,
+        velocity_m_per_s: velocity,
+        pressure_drop_bar: delta_p_pa / 100_000.0,
+        elevation_drop_bar: elevation_drop_pa / 100_000.0,
         reynolds,
     }
 }
@@ -0,0 +1,245 @@
+//! 응축수 회수 배관 사이징.
+//! 트랩 토출(플래시 2상), 펌프 압송, 중력 회수 배관을 허용 유속 기준으로 고르고
+//! 선정된 관경에서 압력손실을 확인한다.
+
+use crate::condensate_recovery::flash_steam::{flash_steam, FlashSteamInput};
+use crate::condensate_recovery::recovery_piping::{
+    recovery_pressure_loss, two_phase_pressure_loss, RecoveryPressureLossInput,
+    TwoPhasePressureLossInput,
+};
+use crate::steam::if97;
+use serde::{Deserialize, Serialize};
+
+/// 표준 관경 (DN, Sch40 내경 mm)
+pub const STANDARD_PIPE_SIZES: [(u32, f64); 14] = [
+    (15, 15.8),
+    (20, 20.9),
+    (25, 26.6),
+    (32, 35.1),
+    (40, 40.9),
+    (50, 52.5),
+    (65, 62.7),
+    (80, 77.9),
+    (100, 102.3),
+    (125, 128.2),
+    (150, 154.1),
+    (200, 202.7),
+    (250, 254.5),
+    (300, 303.2),
+];
+
+/// 회수 배관 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReturnLineKind {
+    /// 트랩 토출 ~ 리시버: 플래시 증기가 섞인 2상 유동 (기본 허용 유속 15 m/s)
+    TrapDischarge,
+    /// 리시버 이후 펌프 압송: 플래시를 분리한 포화수 (기본 허용 유속 2 m/s)
+    Pumped,
+    /// 중력 회수: 플래시를 대기로 분리한 응축수, 만관 기준 (기본 허용 유속 1 m/s)
+    Gravity,
+}
+
+impl ReturnLineKind {
+    /// 형식별 기본 허용 유속 [m/s]
+    pub fn default_velocity_limit_m_per_s(self) -> f64 {
+        match self {
+            ReturnLineKind::TrapDischarge => 15.0,
+            ReturnLineKind::Pumped => 2.0,
+            ReturnLineKind::Gravity => 1.0,
+        }
+    }
+}
+
+/// 회수 배관 사이징 입력.
+#[derive(Debug, Clone)]
+pub struct ReturnLineInput {
+    pub kind: ReturnLineKind,
+    /// 응축수 유량 [kg/h]
+    pub condensate_flow_kg_per_h: f64,
+    /// 응축수 발생(트랩 입구) 압력 [bar abs]. 포화수로 본다.
+    pub source_pressure_bar_abs: f64,
+    /// 리시버(회수 탱크) 압력 [bar abs]
+    pub receiver_pressure_bar_abs: f64,
+    pub length_m: f64,
+    pub roughness_m: f64,
+    /// 배관 끝 높이 - 시작 높이 [m]. 트랩 뒤 상향 리프트면 +.
+    pub lift_m: f64,
+    /// 허용 유속 [m/s]. `None`이면 형식별 기본값.
+    pub max_velocity_m_per_s: Option<f64>,
+}
+
+/// 회수 배관 사이징 결과.
+#[derive(Debug, Clone)]
+pub struct ReturnLineResult {
+    /// 리시버 압력에서의 플래시 증기 비율
+    pub flash_fraction: f64,
+    pub flash_steam_kg_per_h: f64,
+    /// 배관 안 체적유량 [m³/h] (트랩 토출은 2상 혼합, 그 외는 액체)
+    pub volumetric_flow_m3_per_h: f64,
+    pub velocity_limit_m_per_s: f64,
+    /// 허용 유속을 만족하는 최소 내경 [m]
+    pub min_inner_diameter_m: f64,
+    /// 추천 관경 (DN). 표준 관경 범위를 넘으면 `None`.
+    pub recommended_dn: Option<u32>,
+    pub recommended_inner_diameter_m: f64,
+    /// 추천 관경에서의 유속 [m/s]
+    pub velocity_m_per_s: f64,
+    /// 추천 관경에서의 마찰 압력손실 [bar]
+    pub pressure_drop_bar: f64,
+    /// 리프트 정수두 [bar] (트랩 토출은 균질 혼합 밀도 기준)
+    pub elevation_drop_bar: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 회수 배관 계산 오류.
+#[derive(Debug, Clone)]
+pub enum ReturnLineError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for ReturnLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReturnLineError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            ReturnLineError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for ReturnLineError {}
+
+/// 플래시 비율을 구하고 허용 유속으로 회수 배관 관경을 선정한다.
+pub fn size_return_line(input: ReturnLineInput) -> Result<ReturnLineResult, ReturnLineError> {
+    if input.condensate_flow_kg_per_h <= 0.0 || input.length_m < 0.0 || input.roughness_m < 0.0 {
+        return Err(ReturnLineError::InvalidInput(
+            "유량은 0보다 크고 길이/조도는 0 이상이어야 합니다.",
+        ));
+    }
+    if !(input.receiver_pressure_bar_abs > 0.0
+        && input.receiver_pressure_bar_abs <= input.source_pressure_bar_abs)
+    {
+        return Err(ReturnLineError::InvalidInput(
+            "리시버 압력은 0보다 크고 응축수 발생 압력 이하여야 합니다.",
+        ));
+    }
+    let limit = input
+        .max_velocity_m_per_s
+        .unwrap_or_else(|| input.kind.default_velocity_limit_m_per_s());
+    if limit <= 0.0 {
+        return Err(ReturnLineError::InvalidInput(
+            "허용 유속은 0보다 커야 합니다.",
+        ));
+    }
+
+    let if97_err = |e: &str| ReturnLineError::If97(e.into());
+    let (hf_src, _) =
+        if97::saturation_enthalpies(input.source_pressure_bar_abs).map_err(if97_err)?;
+    let p_rcv = input.receiver_pressure_bar_abs;
+    let (hf_rcv, hg_rcv) = if97::saturation_enthalpies(p_rcv).map_err(if97_err)?;
+    let flash = flash_steam(FlashSteamInput {
+        condensate_enthalpy_high_kj_per_kg: hf_src / 1000.0,
+        saturated_liquid_low_kj_per_kg: hf_rcv / 1000.0,
+        saturated_vapor_low_kj_per_kg: hg_rcv / 1000.0,
+    })
+    .flash_fraction;
+    let (_, vf, _) = if97::props_from_px(p_rcv, 0.0).map_err(if97_err)?;
+    let (_, vg, _) = if97::props_from_px(p_rcv, 1.0).map_err(if97_err)?;
+    let (mu_f, mu_g) = if97::saturation_viscosities(p_rcv).map_err(if97_err)?;
+
+    let m = input.condensate_flow_kg_per_h;
+    let (line_mass_kg_h, specific_volume) = match input.kind {
+        ReturnLineKind::TrapDischarge => (m, vf + flash * (vg - vf)),
+        ReturnLineKind::Pumped | ReturnLineKind::Gravity => (m * (1.0 - flash), vf),
+    };
+    let q_m3_h = line_mass_kg_h * specific_volume;
+    let min_d = (4.0 * q_m3_h / 3600.0 / (std::f64::consts::PI * limit)).sqrt();
+
+    let mut warnings = Vec::new();
+    let (dn, d) = match STANDARD_PIPE_SIZES
+        .iter()
+        .find(|(_, id_mm)| id_mm / 1000.0 >= min_d)
+    {
+        Some(&(dn, id_mm)) => (Some(dn), id_mm / 1000.0),
+        None => {
+            warnings.push(format!(
+                "필요 내경 {:.0} mm가 표준 관경 범위(DN300)를 넘습니다. 최소 내경 기준으로 계산합니다.",
+                min_d * 1000.0
+            ));
+            (None, min_d)
+        }
+    };
+
+    let (velocity, dp_bar, dz_bar) = match input.kind {
+        ReturnLineKind::TrapDischarge => {
+            let res = two_phase_pressure_loss(TwoPhasePressureLossInput {
+                mass_flow_kg_per_h: line_mass_kg_h,
+                quality: flash,
+                liquid_density_kg_per_m3: 1.0 / vf,
+                vapor_density_kg_per_m3: 1.0 / vg,
+                liquid_viscosity_pa_s: mu_f,
+                vapor_viscosity_pa_s: mu_g,
+                diameter_m: d,
+                length_m: input.length_m,
+                roughness_m: input.roughness_m,
+                inlet_elevation_m: 0.0,
+                outlet_elevation_m: input.lift_m,
+            });
+            (
+                res.velocity_m_per_s,
+                res.pressure_drop_bar,
+                res.elevation_drop_bar,
+            )
+        }
+        ReturnLineKind::Pumped | ReturnLineKind::Gravity => {
+            let res = recovery_pressure_loss(RecoveryPressureLossInput {
+                condensate_flow_kg_per_h: line_mass_kg_h,
+                density_kg_per_m3: 1.0 / vf,
+                dynamic_viscosity_pa_s: mu_f,
+                diameter_m: d,
+                length_m: input.length_m,
+                roughness_m: input.roughness_m,
+                inlet_elevation_m: 0.0,
+                outlet_elevation_m: input.lift_m,
+            });
+            (
+                res.velocity_m_per_s,
+                res.pressure_drop_bar,
+                res.elevation_drop_bar,
+            )
+        }
+    };
+
+    let available_dp = input.source_pressure_bar_abs - p_rcv;
+    let line_dp = dp_bar + dz_bar;
+    if input.kind == ReturnLineKind::TrapDischarge && line_dp > 0.5 * available_dp {
+        warnings.push(format!(
+            "배관 압력손실(리프트 포함) {line_dp:.3} bar가 트랩 전후 가용 차압 {available_dp:.2} bar의 절반을 넘습니다. 트랩 용량 감소에 주의하세요."
+        ));
+    }
+    if input.kind == ReturnLineKind::Gravity && input.lift_m > 0.0 {
+        warnings.push(
+            "중력 회수 배관에 상향 리프트가 있으면 응축수가 흐르지 않습니다. 펌프 압송으로 바꾸세요.".into(),
+        );
+    }
+    if input.kind == ReturnLineKind::Gravity && flash > 0.0 {
+        warnings.push(
+            "중력 회수 배관은 플래시 증기를 먼저 분리(벤트)했다고 가정합니다. 배관 구배를 확보하세요.".into(),
+        );
+    }
+
+    Ok(ReturnLineResult {
+        flash_fraction: flash,
+        flash_steam_kg_per_h: m * flash,
+        volumetric_flow_m3_per_h: q_m3_h,
+        velocity_limit_m_per_s: limit,
+        min_inner_diameter_m: min_d,
+        recommended_dn: dn,
+        recommended_inner_diameter_m: d,
+        velocity_m_per_s: velocity,
+        pressure_drop_bar: dp_bar,
+        elevation_drop_bar: dz_bar,
+        warnings,
+    })
+}
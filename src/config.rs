@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
+use crate::number_format::NumberFormat;
+use crate::typed_quantity::Atmosphere;
 use crate::units::*;
 
 /// 사용 가능한 단위 시스템 프리셋을 정의한다.
@@ -62,6 +65,184 @@ impl Default for DefaultUnits {
     }
 }
 
+/// 현장(사이트) 대기 조건. 게이지↔절대 환산과 대기 조건이 필요한 계산의 기본값이 된다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SiteConditions {
+    /// 해발 고도 [m]
+    pub elevation_m: f64,
+    /// 현장 대기압 [bar abs]. 없으면 고도에서 ISA 표준대기로 계산한다.
+    pub barometric_pressure_bar_abs: Option<f64>,
+    /// 설계 대기 건구 온도 [°C]
+    pub design_dry_bulb_c: f64,
+    /// 설계 습구 온도 [°C]
+    pub design_wet_bulb_c: f64,
+}
+
+impl Default for SiteConditions {
+    fn default() -> Self {
+        Self {
+            elevation_m: 0.0,
+            barometric_pressure_bar_abs: None,
+            design_dry_bulb_c: 32.0,
+            design_wet_bulb_c: 28.0,
+        }
+    }
+}
+
+impl SiteConditions {
+    /// 현장 대기압 (직접 입력값 우선, 없으면 고도 기준).
+    pub fn atmosphere(&self) -> Atmosphere {
+        match self.barometric_pressure_bar_abs {
+            Some(bar) if bar > 0.0 => Atmosphere::from_bar_abs(bar),
+            _ => Atmosphere::from_elevation_m(self.elevation_m),
+        }
+    }
+}
+
+/// 유체 물성 기본값 (입력 카드 초기값).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FluidDefaults {
+    /// 액체 밀도 [kg/m³] (NPSH 등)
+    pub liquid_density_kg_per_m3: f64,
+    /// 기체/증기 밀도 [kg/m³] (밸브 등)
+    pub gas_density_kg_per_m3: f64,
+}
+
+impl Default for FluidDefaults {
+    fn default() -> Self {
+        Self {
+            liquid_density_kg_per_m3: 998.0,
+            gas_density_kg_per_m3: 1.2,
+        }
+    }
+}
+
+/// 증기 원가 기본값 (트랩 조사 보고서 등).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SteamCostDefaults {
+    /// 연료 단가 [통화 / 연료단위]
+    pub fuel_price_per_unit: f64,
+    /// 연료 발열량 [kJ / 연료단위]
+    pub fuel_lhv_kj_per_unit: f64,
+    /// 보일러 효율(0~1)
+    pub boiler_efficiency: f64,
+    /// 보충수 온도 [°C]
+    pub makeup_water_temp_c: f64,
+}
+
+impl Default for SteamCostDefaults {
+    fn default() -> Self {
+        Self {
+            fuel_price_per_unit: 1000.0,
+            fuel_lhv_kj_per_unit: 39000.0,
+            boiler_efficiency: 0.85,
+            makeup_water_temp_c: 20.0,
+        }
+    }
+}
+
+/// GUI 기본 테마.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeMode {
+    /// 운영체제 밝기 설정을 따른다.
+    System,
+    Light,
+    Dark,
+    /// 밝은 바탕 + 청색 강조 (기본값)
+    #[default]
+    SoftBlue,
+}
+
+/// GUI 테마 설정 (`[theme]`). 색상은 `#RRGGBB` 문자열.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeSettings {
+    pub mode: ThemeMode,
+    /// 강조색(선택 영역, 링크). 없으면 테마 기본값
+    pub accent: Option<String>,
+    /// 창/패널 배경색. 없으면 테마 기본값
+    pub background: Option<String>,
+    /// 고대비 모드 (글자색 고정, 테두리 강조)
+    pub high_contrast: bool,
+}
+
+impl ThemeSettings {
+    /// 강조색 RGB (형식이 틀리면 `None`).
+    pub fn accent_rgb(&self) -> Option<[u8; 3]> {
+        self.accent.as_deref().and_then(parse_hex_color)
+    }
+
+    /// 배경색 RGB (형식이 틀리면 `None`).
+    pub fn background_rgb(&self) -> Option<[u8; 3]> {
+        self.background.as_deref().and_then(parse_hex_color)
+    }
+}
+
+/// `#RRGGBB` (또는 `RRGGBB`) 문자열을 RGB로 읽는다.
+pub fn parse_hex_color(s: &str) -> Option<[u8; 3]> {
+    let hex = s.trim().trim_start_matches('#');
+    if hex.len() != 6 || !hex.is_ascii() {
+        return None;
+    }
+    let ch = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+    Some([ch(0)?, ch(2)?, ch(4)?])
+}
+
+/// RGB를 `#RRGGBB` 문자열로 만든다.
+pub fn format_hex_color(rgb: [u8; 3]) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb[0], rgb[1], rgb[2])
+}
+
+/// GUI에서 분리 창으로 띄운 패널 하나 (위치/크기는 논리 픽셀).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetachedWindow {
+    /// 패널 식별자 (예: "steam_tables", "cooling", "vacuum_table")
+    pub panel: String,
+    /// 창 왼쪽 위 위치. 없으면 운영체제가 정한다.
+    #[serde(default)]
+    pub position: Option<[f32; 2]>,
+    /// 창 내부 크기
+    #[serde(default = "default_detached_size")]
+    pub size: [f32; 2],
+}
+
+impl DetachedWindow {
+    pub fn new(panel: &str) -> Self {
+        Self {
+            panel: panel.to_string(),
+            position: None,
+            size: default_detached_size(),
+        }
+    }
+}
+
+/// 이름 붙은 설정 프로파일. 플랜트마다 다른 기본값을 한 번에 바꿀 때 쓴다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub language: String,
+    pub unit_system: UnitSystem,
+    pub site: SiteConditions,
+    pub fluid: FluidDefaults,
+    pub steam_cost: SteamCostDefaults,
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            language: default_language(),
+            unit_system: UnitSystem::default(),
+            site: SiteConditions::default(),
+            fluid: FluidDefaults::default(),
+            steam_cost: SteamCostDefaults::default(),
+        }
+    }
+}
+
 /// 애플리케이션 설정을 표현한다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -77,6 +258,36 @@ pub struct Config {
     /// 창 투명도(1.0=불투명, 0.3=높은 투명)
     #[serde(default = "default_window_alpha")]
     pub window_alpha: f32,
+    /// 결과 숫자 표시 형식
+    #[serde(default)]
+    pub number_format: NumberFormat,
+    /// GUI 테마
+    #[serde(default)]
+    pub theme: ThemeSettings,
+    /// GUI 결과 영역의 텍스트 선택(드래그 복사) 허용
+    #[serde(default)]
+    pub selectable_results: bool,
+    /// GUI 단위 고정 입력 칸에 "150 psig"처럼 숫자와 단위를 함께 입력
+    #[serde(default)]
+    pub typed_quantity_fields: bool,
+    /// GUI 분리 창 배치 (다음 실행 때 복원)
+    #[serde(default)]
+    pub detached_windows: Vec<DetachedWindow>,
+    /// 현장 대기 조건
+    #[serde(default)]
+    pub site: SiteConditions,
+    /// 유체 물성 기본값
+    #[serde(default)]
+    pub fluid: FluidDefaults,
+    /// 증기 원가 기본값
+    #[serde(default)]
+    pub steam_cost: SteamCostDefaults,
+    /// 마지막으로 적용한 프로파일 이름
+    #[serde(default)]
+    pub active_profile: Option<String>,
+    /// 이름별 프로파일 (`[profiles.<이름>]`)
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
 }
 
 impl Default for Config {
@@ -87,6 +298,16 @@ impl Default for Config {
             unit_system: UnitSystem::SIBar,
             default_units: DefaultUnits::default(),
             window_alpha: default_window_alpha(),
+            number_format: NumberFormat::default(),
+            theme: ThemeSettings::default(),
+            selectable_results: false,
+            typed_quantity_fields: false,
+            detached_windows: Vec::new(),
+            site: SiteConditions::default(),
+            fluid: FluidDefaults::default(),
+            steam_cost: SteamCostDefaults::default(),
+            active_profile: None,
+            profiles: BTreeMap::new(),
         }
     }
 }
@@ -100,6 +321,8 @@ pub enum ConfigError {
     Serde(toml::de::Error),
     /// TOML 직렬화 오류
     Serialize(toml::ser::Error),
+    /// 없는 프로파일 이름
+    UnknownProfile(String),
 }
 
 impl std::fmt::Display for ConfigError {
@@ -108,6 +331,7 @@ impl std::fmt::Display for ConfigError {
             ConfigError::Io(e) => write!(f, "파일 입출력 오류: {e}"),
             ConfigError::Serde(e) => write!(f, "설정 파싱 오류: {e}"),
             ConfigError::Serialize(e) => write!(f, "설정 직렬화 오류: {e}"),
+            ConfigError::UnknownProfile(name) => write!(f, "프로파일을 찾을 수 없습니다: {name}"),
         }
     }
 }
@@ -137,8 +361,7 @@ pub fn load_or_default() -> Result<Config, ConfigError> {
     let path = Path::new("config.toml");
     if path.exists() {
         let content = fs::read_to_string(path)?;
-        let cfg: Config = toml::from_str(&content)?;
-        Ok(cfg)
+        Config::from_toml_str(&content)
     } else {
         let cfg = Config::default();
         save_config(&cfg)?;
@@ -147,16 +370,59 @@ pub fn load_or_default() -> Result<Config, ConfigError> {
 }
 
 fn save_config(cfg: &Config) -> Result<(), ConfigError> {
-    let content = toml::to_string_pretty(cfg)?;
-    fs::write("config.toml", content)?;
+    fs::write("config.toml", cfg.to_toml_string()?)?;
     Ok(())
 }
 
 impl Config {
+    /// TOML 문자열에서 설정을 읽는다. 파일 입출력 없이 사용할 수 있어 wasm 등에서도 쓴다.
+    pub fn from_toml_str(content: &str) -> Result<Config, ConfigError> {
+        Ok(toml::from_str(content)?)
+    }
+
+    /// 설정을 TOML 문자열로 직렬화한다.
+    pub fn to_toml_string(&self) -> Result<String, ConfigError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
     /// 설정을 config.toml에 저장한다.
     pub fn save(&self) -> Result<(), ConfigError> {
         save_config(self)
     }
+
+    /// 저장된 프로파일 이름 목록(정렬됨).
+    pub fn profile_names(&self) -> Vec<String> {
+        self.profiles.keys().cloned().collect()
+    }
+
+    /// 프로파일 값을 현재 설정에 적용하고 활성 프로파일로 기록한다.
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), ConfigError> {
+        let profile = self
+            .profiles
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ConfigError::UnknownProfile(name.to_string()))?;
+        self.language = profile.language;
+        self.unit_system = profile.unit_system;
+        self.site = profile.site;
+        self.fluid = profile.fluid;
+        self.steam_cost = profile.steam_cost;
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
+
+    /// 현재 설정을 이름 붙은 프로파일로 저장(덮어쓰기)한다.
+    pub fn save_profile(&mut self, name: &str) {
+        let profile = Profile {
+            language: self.language.clone(),
+            unit_system: self.unit_system,
+            site: self.site.clone(),
+            fluid: self.fluid.clone(),
+            steam_cost: self.steam_cost.clone(),
+        };
+        self.profiles.insert(name.to_string(), profile);
+        self.active_profile = Some(name.to_string());
+    }
 }
 
 fn default_language() -> String {
@@ -166,3 +432,7 @@ fn default_language() -> String {
 fn default_window_alpha() -> f32 {
     1.0
 }
+
+fn default_detached_size() -> [f32; 2] {
+    [640.0, 720.0]
+}
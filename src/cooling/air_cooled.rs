@@ -0,0 +1,201 @@
+//! 공랭식 복수기(ACC)/핀-팬 냉각기 성능 추정.
+//! 설계점에서 응축 열량, 공기측 온도 상승, 팬 풍량, LMTD로 필요 나관(bare tube) 면적을 구하고,
+//! 면적·팬 체적 풍량을 고정한 채 ε-NTU(한쪽 상변화)로 고온 외기일의 응축 온도/배압을 평가한다.
+//! 핀 측 유효 면적은 A_b·(1 + η_f·(면적비 − 1))로 보고 U는 나관 면적 기준이다.
+
+use crate::cooling::condenser::{CoolingError, CoolingWarning};
+use crate::steam::if97;
+
+/// 건공기 정압비열 [kJ/kg·K]
+const AIR_CP_KJ_PER_KGK: f64 = 1.006;
+/// 건공기 기체상수 [kJ/kg·K]
+const AIR_R_KJ_PER_KGK: f64 = 0.287_042;
+const ATM_KPA: f64 = 101.325;
+/// 설계 ITD(응축 온도 − 외기)가 이보다 작으면 면적이 과대해진다 [K]
+const MIN_PRACTICAL_ITD_K: f64 = 15.0;
+
+/// 공랭식 복수기 입력.
+#[derive(Debug, Clone)]
+pub struct AirCooledCondenserInput {
+    /// 배기 증기 유량 [kg/h]
+    pub steam_flow_kg_per_h: f64,
+    /// 배기 건도 (0~1)
+    pub exhaust_quality: f64,
+    /// 설계 응축 압력(배압) [bar abs]
+    pub design_back_pressure_bar_abs: f64,
+    /// 설계 외기 건구온도 [°C]
+    pub design_ambient_c: f64,
+    /// 설계 공기측 온도 상승 [K]
+    pub air_temp_rise_k: f64,
+    /// 관내 응축 열전달계수 [W/m²·K]
+    pub condensing_h_w_m2k: f64,
+    /// 공기측 열전달계수(핀 표면 기준) [W/m²·K]
+    pub air_side_h_w_m2k: f64,
+    /// 핀 포함 외표면적 / 나관 외표면적
+    pub fin_area_ratio: f64,
+    /// 가정 핀 효율 (0~1)
+    pub fin_efficiency: f64,
+    /// 설치(기존) 나관 면적 [m²]. `None`이면 설계점 필요 면적을 쓴다.
+    pub bare_tube_area_m2: Option<f64>,
+    /// 평가할 고온 외기 [°C]
+    pub hot_day_ambient_c: Option<f64>,
+    /// 터빈 배압 한계 [bar abs]. 고온일 배압이 넘으면 경고한다.
+    pub max_back_pressure_bar_abs: Option<f64>,
+}
+
+/// 고온 외기일 평가 결과.
+#[derive(Debug, Clone)]
+pub struct HotDayResult {
+    pub ambient_c: f64,
+    /// 팬 체적 풍량 고정 시 공기 질량유량 [kg/s]
+    pub air_mass_flow_kg_per_s: f64,
+    pub condensing_temp_c: f64,
+    pub back_pressure_bar_abs: f64,
+}
+
+/// 공랭식 복수기 결과.
+#[derive(Debug, Clone)]
+pub struct AirCooledCondenserResult {
+    pub heat_duty_kw: f64,
+    pub design_condensing_temp_c: f64,
+    /// 설계 ITD = 응축 온도 − 외기 (외기 대비 접근) [K]
+    pub design_itd_k: f64,
+    pub lmtd_k: f64,
+    pub air_mass_flow_kg_per_s: f64,
+    /// 설계 외기 기준 팬 풍량 [m³/s]
+    pub fan_air_flow_m3_per_s: f64,
+    /// 나관 기준 총괄 전열계수 [W/m²·K]
+    pub u_bare_w_m2k: f64,
+    pub required_bare_area_m2: f64,
+    pub bare_area_m2: f64,
+    /// 적용 면적·설계 풍량·설계 외기에서의 응축 온도 [°C]와 배압 [bar abs]
+    pub condensing_temp_c: f64,
+    pub back_pressure_bar_abs: f64,
+    /// 응축 온도 − 외기 건구온도 [K]
+    pub approach_to_dry_bulb_k: f64,
+    pub hot_day: Option<HotDayResult>,
+    pub warnings: Vec<CoolingWarning>,
+}
+
+fn air_density_kg_per_m3(t_c: f64) -> f64 {
+    ATM_KPA / (AIR_R_KJ_PER_KGK * (t_c + 273.15))
+}
+
+/// 면적·공기 유량 고정 시 응축 온도. Tsat = Ta + Q / (ε·ṁ·cp), ε = 1 − exp(−UA/(ṁ·cp))
+fn condensing_temp_for_air(q_kw: f64, ambient_c: f64, m_air: f64, ua_kw_per_k: f64) -> f64 {
+    let m_cp = m_air * AIR_CP_KJ_PER_KGK;
+    let effectiveness = 1.0 - (-ua_kw_per_k / m_cp).exp();
+    ambient_c + q_kw / (effectiveness * m_cp)
+}
+
+/// 공랭식 복수기 설계 면적과 외기 조건별 배압을 계산한다.
+pub fn compute_air_cooled_condenser(
+    input: AirCooledCondenserInput,
+) -> Result<AirCooledCondenserResult, CoolingError> {
+    if input.steam_flow_kg_per_h <= 0.0 || !(0.0..=1.0).contains(&input.exhaust_quality) {
+        return Err(CoolingError::InvalidInput(
+            "증기 유량은 0보다 크고 건도는 0~1 사이여야 합니다.",
+        ));
+    }
+    if input.air_temp_rise_k <= 0.0
+        || input.condensing_h_w_m2k <= 0.0
+        || input.air_side_h_w_m2k <= 0.0
+    {
+        return Err(CoolingError::InvalidInput(
+            "공기 온도 상승과 열전달계수는 0보다 커야 합니다.",
+        ));
+    }
+    if input.fin_area_ratio < 1.0 || !(0.0..=1.0).contains(&input.fin_efficiency) {
+        return Err(CoolingError::InvalidInput(
+            "핀 면적비는 1 이상, 핀 효율은 0~1 사이여야 합니다.",
+        ));
+    }
+    if matches!(input.bare_tube_area_m2, Some(a) if a <= 0.0) {
+        return Err(CoolingError::InvalidInput("나관 면적은 0보다 커야 합니다."));
+    }
+    let if97_err = |e: &str| CoolingError::If97(e.to_string());
+
+    let p_design = input.design_back_pressure_bar_abs;
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_design).map_err(if97_err)?;
+    let (hf, hg) = if97::saturation_enthalpies(p_design).map_err(if97_err)?;
+    let q_kw = input.steam_flow_kg_per_h / 3600.0 * input.exhaust_quality * (hg - hf) / 1000.0;
+
+    let itd = t_sat - input.design_ambient_c;
+    if itd <= input.air_temp_rise_k {
+        return Err(CoolingError::NegativeDeltaT);
+    }
+    let dt_air = input.air_temp_rise_k;
+    let lmtd = dt_air / (itd / (itd - dt_air)).ln();
+    let m_air = q_kw / (AIR_CP_KJ_PER_KGK * dt_air);
+    let fan_volume = m_air / air_density_kg_per_m3(input.design_ambient_c);
+
+    let area_gain = 1.0 + input.fin_efficiency * (input.fin_area_ratio - 1.0);
+    let u_bare =
+        1.0 / (1.0 / input.condensing_h_w_m2k + 1.0 / (input.air_side_h_w_m2k * area_gain));
+    let required_area = q_kw * 1000.0 / (u_bare * lmtd);
+    let area = input.bare_tube_area_m2.unwrap_or(required_area);
+    let ua = u_bare * area / 1000.0;
+
+    let t_cond = condensing_temp_for_air(q_kw, input.design_ambient_c, m_air, ua);
+    let p_cond = if97::saturation_pressure_bar_abs_from_temp_c(t_cond).map_err(if97_err)?;
+
+    let mut warnings = Vec::new();
+    if itd < MIN_PRACTICAL_ITD_K {
+        warnings.push(CoolingWarning::SmallDesignItd { itd_k: itd });
+    }
+    if area < required_area {
+        warnings.push(CoolingWarning::AreaBelowRequired {
+            area_m2: area,
+            required_m2: required_area,
+            back_pressure_bar_abs: p_cond,
+        });
+    }
+
+    let hot_day = match input.hot_day_ambient_c {
+        Some(t_hot) => {
+            let m_hot = fan_volume * air_density_kg_per_m3(t_hot);
+            let t_cond_hot = condensing_temp_for_air(q_kw, t_hot, m_hot, ua);
+            let p_hot =
+                if97::saturation_pressure_bar_abs_from_temp_c(t_cond_hot).map_err(if97_err)?;
+            if let Some(p_max) = input.max_back_pressure_bar_abs {
+                if p_hot > p_max {
+                    warnings.push(CoolingWarning::HotDayBackPressureLimit {
+                        ambient_c: t_hot,
+                        back_pressure_bar_abs: p_hot,
+                        limit_bar_abs: p_max,
+                    });
+                }
+            }
+            if p_hot > 2.0 * p_cond {
+                warnings.push(CoolingWarning::HotDayBackPressureRise {
+                    ambient_c: t_hot,
+                    ratio: p_hot / p_cond,
+                });
+            }
+            Some(HotDayResult {
+                ambient_c: t_hot,
+                air_mass_flow_kg_per_s: m_hot,
+                condensing_temp_c: t_cond_hot,
+                back_pressure_bar_abs: p_hot,
+            })
+        }
+        None => None,
+    };
+
+    Ok(AirCooledCondenserResult {
+        heat_duty_kw: q_kw,
+        design_condensing_temp_c: t_sat,
+        design_itd_k: itd,
+        lmtd_k: lmtd,
+        air_mass_flow_kg_per_s: m_air,
+        fan_air_flow_m3_per_s: fan_volume,
+        u_bare_w_m2k: u_bare,
+        required_bare_area_m2: required_area,
+        bare_area_m2: area,
+        condensing_temp_c: t_cond,
+        back_pressure_bar_abs: p_cond,
+        approach_to_dry_bulb_k: t_cond - input.design_ambient_c,
+        hot_day,
+        warnings,
+    })
+}
@@ -1,6 +1,9 @@
 use crate::conversion::PressureMode;
+use crate::cooling::pump_npsh::MIN_MARGIN_RATIO;
+use crate::goal_seek::{self, GoalSeekError};
 use crate::steam;
 use crate::units::PressureUnit;
+use crate::warning::{CalcWarning, Severity};
 
 /// 콘덴서(복수기) 열수지를 계산하기 위한 입력 값.
 #[derive(Debug, Clone)]
@@ -40,8 +43,8 @@ pub struct CondenserResult {
     pub lmtd_k: f64,
     /// 열량(kW)
     pub heat_duty_kw: f64,
-    /// 경고/주의 메시지
-    pub warnings: Vec<String>,
+    /// 경고/주의
+    pub warnings: Vec<CoolingWarning>,
 }
 
 /// 콘덴서 계산 중 발생 가능한 오류.
@@ -51,6 +54,400 @@ pub enum CoolingError {
     NegativeDeltaT,
     /// IF97 포화 계산 실패
     If97(String),
+    /// 입력값이 잘못된 경우
+    InvalidInput(&'static str),
+    /// 역산(goal-seek) 실패
+    GoalSeek(GoalSeekError),
+}
+
+impl std::fmt::Display for CoolingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CoolingError::NegativeDeltaT => {
+                write!(
+                    f,
+                    "냉각수 온도가 포화온도를 넘어 LMTD를 계산할 수 없습니다."
+                )
+            }
+            CoolingError::If97(msg) => write!(f, "IF97 포화 계산 오류: {msg}"),
+            CoolingError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            CoolingError::GoalSeek(e) => write!(f, "역산 실패: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CoolingError {}
+
+/// 냉각 계열 계산(복수기, NPSH, 냉각탑, 드레인 쿨러, 공랭식 복수기, 냉각 방식 비교) 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CoolingWarning {
+    /// 냉각수 온도가 포화온도 이상 (역류/센서 오류)
+    CoolingWaterAboveSaturation,
+    /// 배압이 목표보다 높음
+    BackPressureAboveTarget {
+        back_pressure_bar_abs: f64,
+        target_bar_abs: f64,
+    },
+    /// UA 기반 열량과 냉각수 열수지 불일치
+    UaHeatBalanceMismatch,
+    /// NPSH 여유비 부족
+    NpshMarginLow { margin: f64 },
+    /// 압력 감소 과도 상태의 NPSH 여유비 부족
+    TransientNpshMarginLow { margin: f64, min_submergence_m: f64 },
+    /// 포화수 흡입인데 액면이 펌프보다 낮음
+    SaturatedSuctionLift,
+    /// 냉각탑 접근이 음수
+    NegativeApproach,
+    /// 냉각탑 접근이 2 °C 미만
+    TightApproach { approach_c: f64 },
+    /// Range가 목표보다 작음
+    RangeBelowTarget { range_c: f64, target_c: f64 },
+    /// Approach가 목표보다 큼
+    ApproachAboveTarget { approach_c: f64, target_c: f64 },
+    /// 드레인 쿨러 LMTD가 0 이하
+    NonPositiveLmtd,
+    /// 쉘/튜브 열수지 불균형 5% 초과
+    HeatBalanceImbalance,
+    /// 공랭식 설계 ITD가 작음
+    SmallDesignItd { itd_k: f64 },
+    /// 설치 면적이 필요 면적보다 작음
+    AreaBelowRequired {
+        area_m2: f64,
+        required_m2: f64,
+        back_pressure_bar_abs: f64,
+    },
+    /// 고온일 배압이 한계 초과
+    HotDayBackPressureLimit {
+        ambient_c: f64,
+        back_pressure_bar_abs: f64,
+        limit_bar_abs: f64,
+    },
+    /// 고온일 배압이 설계 대비 크게 상승
+    HotDayBackPressureRise { ambient_c: f64, ratio: f64 },
+    /// 방류 온도 상한으로 관류식 온도 상승 제한
+    DischargeLimitsRise {
+        max_discharge_c: f64,
+        allowed_rise_k: f64,
+    },
+    /// 순환식 공급 온도가 취수원보다 높음
+    RecirculatingSupplyWarmer { supply_c: f64, source_c: f64 },
+    /// 냉각탑 접근 2.5 K 미만 (탑 크기 과대)
+    TowerApproachOversized,
+    /// 비산 손실만으로 농축 배수 유지 (블로다운 불필요)
+    DriftExceedsBlowdown,
+    /// 펌프 운전 유량이 최소 연속 유량보다 작음
+    PumpBelowMinimumFlow {
+        flow_m3_per_h: f64,
+        min_flow_m3_per_h: f64,
+    },
+    /// 펌프 온도 상승이 허용값 초과
+    PumpTemperatureRiseHigh { rise_k: f64, limit_k: f64 },
+    /// 펌프 온도 상승이 포화 여유 이상 (펌프 안 플래시)
+    PumpLiquidFlashing { rise_k: f64, margin_k: f64 },
+    /// 재순환 오리피스 β가 ISO 5167 범위보다 작음
+    OrificeBetaSmall { beta: f64, stages: u32 },
+    /// 재순환 오리피스 하류에서 플래시
+    OrificeOutletFlashing {
+        return_pressure_bar_abs: f64,
+        saturation_pressure_bar_abs: f64,
+    },
+}
+
+impl CalcWarning for CoolingWarning {
+    fn severity(&self) -> Severity {
+        use CoolingWarning::*;
+        match self {
+            CoolingWaterAboveSaturation
+            | NpshMarginLow { .. }
+            | SaturatedSuctionLift
+            | NegativeApproach
+            | NonPositiveLmtd
+            | HotDayBackPressureLimit { .. }
+            | PumpBelowMinimumFlow { .. }
+            | PumpLiquidFlashing { .. } => Severity::Critical,
+            BackPressureAboveTarget { .. }
+            | UaHeatBalanceMismatch
+            | TransientNpshMarginLow { .. }
+            | TightApproach { .. }
+            | RangeBelowTarget { .. }
+            | ApproachAboveTarget { .. }
+            | HeatBalanceImbalance
+            | SmallDesignItd { .. }
+            | AreaBelowRequired { .. }
+            | RecirculatingSupplyWarmer { .. }
+            | TowerApproachOversized
+            | PumpTemperatureRiseHigh { .. }
+            | OrificeBetaSmall { .. }
+            | OrificeOutletFlashing { .. } => Severity::Caution,
+            HotDayBackPressureRise { .. } | DischargeLimitsRise { .. } | DriftExceedsBlowdown => {
+                Severity::Info
+            }
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        use CoolingWarning::*;
+        match self {
+            CoolingWaterAboveSaturation => "warning.cooling.cw_above_saturation",
+            BackPressureAboveTarget { .. } => "warning.cooling.back_pressure_above_target",
+            UaHeatBalanceMismatch => "warning.cooling.ua_mismatch",
+            NpshMarginLow { .. } => "warning.cooling.npsh_margin_low",
+            TransientNpshMarginLow { .. } => "warning.cooling.npsh_transient_margin_low",
+            SaturatedSuctionLift => "warning.cooling.saturated_suction_lift",
+            NegativeApproach => "warning.cooling.negative_approach",
+            TightApproach { .. } => "warning.cooling.tight_approach",
+            RangeBelowTarget { .. } => "warning.cooling.range_below_target",
+            ApproachAboveTarget { .. } => "warning.cooling.approach_above_target",
+            NonPositiveLmtd => "warning.cooling.non_positive_lmtd",
+            HeatBalanceImbalance => "warning.cooling.heat_imbalance",
+            SmallDesignItd { .. } => "warning.cooling.small_itd",
+            AreaBelowRequired { .. } => "warning.cooling.area_below_required",
+            HotDayBackPressureLimit { .. } => "warning.cooling.hot_day_limit",
+            HotDayBackPressureRise { .. } => "warning.cooling.hot_day_rise",
+            DischargeLimitsRise { .. } => "warning.cooling.discharge_limits_rise",
+            RecirculatingSupplyWarmer { .. } => "warning.cooling.supply_warmer",
+            TowerApproachOversized => "warning.cooling.tower_approach_oversized",
+            DriftExceedsBlowdown => "warning.cooling.drift_exceeds_blowdown",
+            PumpBelowMinimumFlow { .. } => "warning.cooling.pump_below_min_flow",
+            PumpTemperatureRiseHigh { .. } => "warning.cooling.pump_temp_rise_high",
+            PumpLiquidFlashing { .. } => "warning.cooling.pump_flashing",
+            OrificeBetaSmall { .. } => "warning.cooling.orifice_beta_small",
+            OrificeOutletFlashing { .. } => "warning.cooling.orifice_outlet_flashing",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        use CoolingWarning::*;
+        match *self {
+            BackPressureAboveTarget {
+                back_pressure_bar_abs,
+                target_bar_abs,
+            } => vec![
+                ("p", format!("{back_pressure_bar_abs:.3}")),
+                ("target", format!("{target_bar_abs:.3}")),
+            ],
+            NpshMarginLow { margin } => vec![
+                ("margin", format!("{margin:.2}")),
+                ("min", MIN_MARGIN_RATIO.to_string()),
+            ],
+            TransientNpshMarginLow {
+                margin,
+                min_submergence_m,
+            } => vec![
+                ("margin", format!("{margin:.2}")),
+                ("min", MIN_MARGIN_RATIO.to_string()),
+                ("submergence", format!("{min_submergence_m:.2}")),
+            ],
+            TightApproach { approach_c } => vec![("approach", format!("{approach_c:.1}"))],
+            RangeBelowTarget { range_c, target_c } => vec![
+                ("range", format!("{range_c:.1}")),
+                ("target", format!("{target_c:.1}")),
+            ],
+            ApproachAboveTarget {
+                approach_c,
+                target_c,
+            } => vec![
+                ("approach", format!("{approach_c:.1}")),
+                ("target", format!("{target_c:.1}")),
+            ],
+            SmallDesignItd { itd_k } => vec![("itd", format!("{itd_k:.1}"))],
+            AreaBelowRequired {
+                area_m2,
+                required_m2,
+                back_pressure_bar_abs,
+            } => vec![
+                ("area", format!("{area_m2:.0}")),
+                ("required", format!("{required_m2:.0}")),
+                ("p", format!("{back_pressure_bar_abs:.3}")),
+            ],
+            HotDayBackPressureLimit {
+                ambient_c,
+                back_pressure_bar_abs,
+                limit_bar_abs,
+            } => vec![
+                ("t", format!("{ambient_c:.0}")),
+                ("p", format!("{back_pressure_bar_abs:.3}")),
+                ("limit", format!("{limit_bar_abs:.3}")),
+            ],
+            HotDayBackPressureRise { ambient_c, ratio } => vec![
+                ("t", format!("{ambient_c:.0}")),
+                ("ratio", format!("{ratio:.1}")),
+            ],
+            DischargeLimitsRise {
+                max_discharge_c,
+                allowed_rise_k,
+            } => vec![
+                ("t_max", format!("{max_discharge_c:.1}")),
+                ("rise", format!("{allowed_rise_k:.1}")),
+            ],
+            RecirculatingSupplyWarmer { supply_c, source_c } => vec![
+                ("supply", format!("{supply_c:.1}")),
+                ("source", format!("{source_c:.1}")),
+            ],
+            PumpBelowMinimumFlow {
+                flow_m3_per_h,
+                min_flow_m3_per_h,
+            } => vec![
+                ("flow", format!("{flow_m3_per_h:.1}")),
+                ("min", format!("{min_flow_m3_per_h:.1}")),
+            ],
+            PumpTemperatureRiseHigh { rise_k, limit_k } => vec![
+                ("rise", format!("{rise_k:.1}")),
+                ("limit", format!("{limit_k:.1}")),
+            ],
+            PumpLiquidFlashing { rise_k, margin_k } => vec![
+                ("rise", format!("{rise_k:.1}")),
+                ("margin", format!("{margin_k:.1}")),
+            ],
+            OrificeBetaSmall { beta, stages } => vec![
+                ("beta", format!("{beta:.3}")),
+                ("stages", stages.to_string()),
+            ],
+            OrificeOutletFlashing {
+                return_pressure_bar_abs,
+                saturation_pressure_bar_abs,
+            } => vec![
+                ("p", format!("{return_pressure_bar_abs:.3}")),
+                ("p_sat", format!("{saturation_pressure_bar_abs:.3}")),
+            ],
+            CoolingWaterAboveSaturation
+            | UaHeatBalanceMismatch
+            | SaturatedSuctionLift
+            | NegativeApproach
+            | NonPositiveLmtd
+            | HeatBalanceImbalance
+            | TowerApproachOversized
+            | DriftExceedsBlowdown => Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for CoolingWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use CoolingWarning::*;
+        match *self {
+            CoolingWaterAboveSaturation => write!(
+                f,
+                "냉각수 출구/입구 온도가 포화온도 이상입니다. 역류 또는 센서 오류 가능"
+            ),
+            BackPressureAboveTarget {
+                back_pressure_bar_abs,
+                target_bar_abs,
+            } => write!(
+                f,
+                "배압 {back_pressure_bar_abs:.3} bar(abs)가 목표 {target_bar_abs:.3} bar(abs)보다 높습니다."
+            ),
+            UaHeatBalanceMismatch => {
+                write!(f, "UA 기반 열량과 냉각수 열수지 열량이 크게 다릅니다.")
+            }
+            NpshMarginLow { margin } => write!(
+                f,
+                "NPSH Margin {margin:.2} (<{MIN_MARGIN_RATIO}). 공동현상 위험."
+            ),
+            TransientNpshMarginLow {
+                margin,
+                min_submergence_m,
+            } => write!(
+                f,
+                "압력 감소 과도 상태 Margin {margin:.2} (<{MIN_MARGIN_RATIO}). 액면을 {min_submergence_m:.2} m 이상 확보하거나 흡입 배관을 줄이세요."
+            ),
+            SaturatedSuctionLift => write!(
+                f,
+                "액면이 펌프 중심보다 낮습니다. 포화수는 흡입 양정을 가질 수 없습니다."
+            ),
+            NegativeApproach => write!(
+                f,
+                "접근(Approach)이 음수입니다. 습구 온도보다 낮은 냉각은 불가능합니다."
+            ),
+            TightApproach { .. } => write!(
+                f,
+                "접근이 2°C 미만입니다. 실제 운전에서 달성하기 어려울 수 있습니다."
+            ),
+            RangeBelowTarget { range_c, target_c } => {
+                write!(f, "Range {range_c:.1}°C가 목표 {target_c:.1}°C보다 작습니다.")
+            }
+            ApproachAboveTarget {
+                approach_c,
+                target_c,
+            } => write!(
+                f,
+                "Approach {approach_c:.1}°C가 목표 {target_c:.1}°C보다 큽니다."
+            ),
+            NonPositiveLmtd => write!(
+                f,
+                "LMTD가 0 이하입니다. 온도 교차가 잘못되었을 수 있습니다."
+            ),
+            HeatBalanceImbalance => write!(f, "쉘/튜브 열수지 불균형이 5%를 초과합니다."),
+            SmallDesignItd { itd_k } => write!(
+                f,
+                "설계 ITD {itd_k:.1} K가 작아 면적과 팬 동력이 과대해집니다 (보통 25~40 K)."
+            ),
+            AreaBelowRequired {
+                area_m2,
+                required_m2,
+                back_pressure_bar_abs,
+            } => write!(
+                f,
+                "설치 면적 {area_m2:.0} m²가 설계 필요 면적 {required_m2:.0} m²보다 작아 배압이 {back_pressure_bar_abs:.3} bar(a)로 오릅니다."
+            ),
+            HotDayBackPressureLimit {
+                ambient_c,
+                back_pressure_bar_abs,
+                limit_bar_abs,
+            } => write!(
+                f,
+                "외기 {ambient_c:.0} °C에서 배압 {back_pressure_bar_abs:.3} bar(a)가 한계 {limit_bar_abs:.3} bar(a)를 넘습니다. 부하 제한, 팬 과속 운전 또는 흡입 공기 분무 냉각을 검토하세요."
+            ),
+            HotDayBackPressureRise { ambient_c, ratio } => write!(
+                f,
+                "외기 {ambient_c:.0} °C에서 배압이 설계의 {ratio:.1}배로 오릅니다. 열풍 재순환과 바람 영향이 더해지면 더 높아질 수 있습니다."
+            ),
+            DischargeLimitsRise {
+                max_discharge_c,
+                allowed_rise_k,
+            } => write!(
+                f,
+                "방류 온도 상한 {max_discharge_c:.1} °C 때문에 관류식 온도 상승이 {allowed_rise_k:.1} K로 제한됩니다."
+            ),
+            RecirculatingSupplyWarmer { supply_c, source_c } => write!(
+                f,
+                "순환식 공급 온도 {supply_c:.1} °C가 취수원 {source_c:.1} °C보다 높아 복수기 배압이 올라갑니다."
+            ),
+            TowerApproachOversized => {
+                write!(f, "냉각탑 접근 2.5 K 미만은 탑 크기가 과대해집니다.")
+            }
+            DriftExceedsBlowdown => write!(
+                f,
+                "비산 손실만으로 농축 배수가 유지되어 블로다운이 필요 없습니다. 비산률 입력을 확인하세요."
+            ),
+            PumpBelowMinimumFlow {
+                flow_m3_per_h,
+                min_flow_m3_per_h,
+            } => write!(
+                f,
+                "펌프 유량 {flow_m3_per_h:.1} m³/h가 최소 연속 유량 {min_flow_m3_per_h:.1} m³/h보다 작습니다. 재순환 라인을 여세요."
+            ),
+            PumpTemperatureRiseHigh { rise_k, limit_k } => write!(
+                f,
+                "펌프 온도 상승 {rise_k:.1} K가 허용값 {limit_k:.1} K를 넘습니다."
+            ),
+            PumpLiquidFlashing { rise_k, margin_k } => write!(
+                f,
+                "펌프 온도 상승 {rise_k:.1} K가 흡입 포화 여유 {margin_k:.1} K 이상입니다. 펌프 안에서 플래시가 생깁니다."
+            ),
+            OrificeBetaSmall { beta, stages } => write!(
+                f,
+                "재순환 오리피스 β {beta:.3}가 0.1보다 작습니다({stages}단). 단수를 늘리세요."
+            ),
+            OrificeOutletFlashing {
+                return_pressure_bar_abs,
+                saturation_pressure_bar_abs,
+            } => write!(
+                f,
+                "복귀처 압력 {return_pressure_bar_abs:.3} bar(a)가 재순환수 포화 압력 {saturation_pressure_bar_abs:.3} bar(a)보다 낮아 오리피스 하류에서 플래시가 생깁니다. 복귀 배관을 2상 유동으로 검토하세요."
+            ),
+        }
+    }
 }
 
 fn log_mean(delta1: f64, delta2: f64) -> Option<f64> {
@@ -111,19 +508,18 @@ pub fn compute_condenser(input: CondenserInput) -> Result<CondenserResult, Cooli
 
     let mut warnings = Vec::new();
     if d1 <= 0.0 || d2 <= 0.0 {
-        warnings
-            .push("냉각수 출구/입구 온도가 포화온도 이상입니다. 역류 또는 센서 오류 가능".into());
+        warnings.push(CoolingWarning::CoolingWaterAboveSaturation);
     }
     if let Some(target) = input.target_back_pressure_bar_abs {
         if psat_bar_abs > target {
-            warnings.push(format!(
-                "배압 {:.3} bar(abs)가 목표 {:.3} bar(abs)보다 높습니다.",
-                psat_bar_abs, target
-            ));
+            warnings.push(CoolingWarning::BackPressureAboveTarget {
+                back_pressure_bar_abs: psat_bar_abs,
+                target_bar_abs: target,
+            });
         }
     }
     if (q_kw - q_kw_from_water).abs() > 0.05 * q_kw_from_water && input.ua_kw_per_k.is_some() {
-        warnings.push("UA 기반 열량과 냉각수 열수지 열량이 크게 다릅니다.".into());
+        warnings.push(CoolingWarning::UaHeatBalanceMismatch);
     }
 
     Ok(CondenserResult {
@@ -134,3 +530,70 @@ pub fn compute_condenser(input: CondenserInput) -> Result<CondenserResult, Cooli
         warnings,
     })
 }
+
+/// 냉각수 유량 역산 결과.
+#[derive(Debug, Clone)]
+pub struct CwFlowForBackpressureResult {
+    /// 필요 냉각수 유량(m³/h)
+    pub cw_flow_m3_per_h: f64,
+    /// 냉각수 출구 온도(°C)
+    pub cw_outlet_temp_c: f64,
+    /// 응축 온도(°C)
+    pub condensing_temp_c: f64,
+    /// 응축 압력(bar abs)
+    pub condensing_pressure_bar_abs: f64,
+}
+
+/// 열부하·UA·냉각수 입구온도가 고정일 때 냉각수 유량에 따른 응축 온도(°C).
+/// ε-NTU(한쪽 상변화): Tsat = Tin + ΔT_cw / (1 - exp(-UA / (ṁ·cp)))
+pub fn condensing_temp_for_cw_flow(
+    heat_duty_kw: f64,
+    cw_inlet_temp_c: f64,
+    cw_flow_m3_per_h: f64,
+    ua_kw_per_k: f64,
+) -> f64 {
+    let m_cp = cw_flow_m3_per_h * (1000.0 / 3600.0) * 4.186; // kW/K
+    let rise = heat_duty_kw / m_cp;
+    let effectiveness = 1.0 - (-ua_kw_per_k / m_cp).exp();
+    cw_inlet_temp_c + rise / effectiveness
+}
+
+/// 목표 배압(bar abs)을 만족하는 냉각수 유량을 역산한다.
+/// 유량이 늘수록 응축 온도가 단조 감소하므로 (0, max_cw_flow_m3_per_h] 구간에서 Brent 탐색을 사용한다.
+pub fn cw_flow_for_backpressure(
+    heat_duty_kw: f64,
+    cw_inlet_temp_c: f64,
+    ua_kw_per_k: f64,
+    target_back_pressure_bar_abs: f64,
+    max_cw_flow_m3_per_h: f64,
+) -> Result<CwFlowForBackpressureResult, CoolingError> {
+    if heat_duty_kw <= 0.0 || ua_kw_per_k <= 0.0 || max_cw_flow_m3_per_h <= 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "열부하, UA, 최대 유량은 0보다 커야 합니다.",
+        ));
+    }
+    let target_tsat =
+        steam::if97::saturation_temp_c_from_pressure_bar_abs(target_back_pressure_bar_abs)
+            .map_err(|e| CoolingError::If97(e.to_string()))?;
+    // 유량 무한대에서의 하한: Tin + Q/UA
+    if target_tsat <= cw_inlet_temp_c + heat_duty_kw / ua_kw_per_k {
+        return Err(CoolingError::InvalidInput(
+            "목표 배압이 너무 낮아 UA와 냉각수 입구 온도로는 도달할 수 없습니다.",
+        ));
+    }
+    let res = goal_seek::goal_seek(
+        |flow| condensing_temp_for_cw_flow(heat_duty_kw, cw_inlet_temp_c, flow, ua_kw_per_k),
+        target_tsat,
+        max_cw_flow_m3_per_h * 1e-6,
+        max_cw_flow_m3_per_h,
+    )
+    .map_err(CoolingError::GoalSeek)?;
+    let flow = res.x;
+    let m_cp = flow * (1000.0 / 3600.0) * 4.186;
+    Ok(CwFlowForBackpressureResult {
+        cw_flow_m3_per_h: flow,
+        cw_outlet_temp_c: cw_inlet_temp_c + heat_duty_kw / m_cp,
+        condensing_temp_c: target_tsat,
+        condensing_pressure_bar_abs: target_back_pressure_bar_abs,
+    })
+}
@@ -0,0 +1,178 @@
+//! 관류식(once-through) 대 순환식(냉각탑) 냉각수 방식 비교.
+//! 같은 열부하에 대해 관류식은 허용 방류 온도 상승으로, 순환식은 냉각탑 Range로 순환 유량을 구하고,
+//! 순환식의 증발/비산/블로다운/보충수량과 양쪽 펌프 동력을 나란히 낸다 (타당성 검토용).
+
+use crate::cooling::condenser::{CoolingError, CoolingWarning};
+
+const WATER_DENSITY: f64 = 1000.0;
+const WATER_CP_KJ_PER_KGK: f64 = 4.186;
+const G: f64 = 9.80665;
+/// 증발 손실 계수 [1/K]: E = 0.00153·Range·순환량 (증발 잠열 분담 약 85% 가정)
+const EVAPORATION_FACTOR_PER_K: f64 = 0.001_53;
+
+/// 비교 입력.
+#[derive(Debug, Clone)]
+pub struct CoolingComparisonInput {
+    /// 방열량 [kW]
+    pub heat_duty_kw: f64,
+    /// 관류식 취수원(하천/해수) 온도 [°C]
+    pub source_water_temp_c: f64,
+    /// 관류식 허용 온도 상승 [K] (환경 규제 기준)
+    pub once_through_max_rise_k: f64,
+    /// 관류식 방류 온도 상한 [°C]
+    pub max_discharge_temp_c: Option<f64>,
+    /// 관류식 펌프 전양정 [m] (취수/방류 관로 포함)
+    pub once_through_head_m: f64,
+    /// 대기 습구온도 [°C]
+    pub wet_bulb_c: f64,
+    /// 냉각탑 접근(출구 − 습구) [K]
+    pub tower_approach_k: f64,
+    /// 냉각탑 Range [K]
+    pub tower_range_k: f64,
+    /// 농축 배수
+    pub cycles_of_concentration: f64,
+    /// 비산(drift) 손실률 (순환량 대비, 예: 0.0005)
+    pub drift_fraction: f64,
+    /// 순환식 펌프 전양정 [m] (탑 정수두 포함)
+    pub recirculating_head_m: f64,
+    /// 펌프 효율 (0~1)
+    pub pump_efficiency: f64,
+}
+
+/// 방식별 결과.
+#[derive(Debug, Clone)]
+pub struct CoolingOptionResult {
+    /// 순환(관류) 유량 [m³/h]
+    pub circulating_flow_m3_per_h: f64,
+    /// 공급(냉각수 입구) 온도 [°C]
+    pub supply_temp_c: f64,
+    /// 환수(방류) 온도 [°C]
+    pub return_temp_c: f64,
+    /// 취수량 [m³/h]. 관류식은 순환 유량, 순환식은 보충수량.
+    pub withdrawal_m3_per_h: f64,
+    pub evaporation_m3_per_h: f64,
+    pub drift_m3_per_h: f64,
+    pub blowdown_m3_per_h: f64,
+    pub pumping_power_kw: f64,
+}
+
+/// 비교 결과. `*_ratio`는 순환식 ÷ 관류식.
+#[derive(Debug, Clone)]
+pub struct CoolingComparisonResult {
+    pub once_through: CoolingOptionResult,
+    pub recirculating: CoolingOptionResult,
+    pub flow_ratio: f64,
+    pub pumping_power_ratio: f64,
+    pub withdrawal_ratio: f64,
+    pub warnings: Vec<CoolingWarning>,
+}
+
+fn flow_for_rise(heat_duty_kw: f64, rise_k: f64) -> f64 {
+    heat_duty_kw / (WATER_CP_KJ_PER_KGK * rise_k) / WATER_DENSITY * 3600.0
+}
+
+fn pump_power_kw(flow_m3_per_h: f64, head_m: f64, efficiency: f64) -> f64 {
+    WATER_DENSITY * G * flow_m3_per_h / 3600.0 * head_m / efficiency / 1000.0
+}
+
+/// 관류식과 순환식 냉각의 유량, 보충수, 펌프 동력을 비교한다.
+pub fn compare_cooling_options(
+    input: CoolingComparisonInput,
+) -> Result<CoolingComparisonResult, CoolingError> {
+    if input.heat_duty_kw <= 0.0 {
+        return Err(CoolingError::InvalidInput("열부하는 0보다 커야 합니다."));
+    }
+    if input.once_through_max_rise_k <= 0.0 || input.tower_range_k <= 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "관류식 온도 상승과 냉각탑 Range는 0보다 커야 합니다.",
+        ));
+    }
+    if input.tower_approach_k < 0.0 || input.drift_fraction < 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "접근과 비산 손실률은 0 이상이어야 합니다.",
+        ));
+    }
+    if input.cycles_of_concentration <= 1.0 {
+        return Err(CoolingError::InvalidInput("농축 배수는 1보다 커야 합니다."));
+    }
+    if input.pump_efficiency <= 0.0
+        || input.pump_efficiency > 1.0
+        || input.once_through_head_m < 0.0
+        || input.recirculating_head_m < 0.0
+    {
+        return Err(CoolingError::InvalidInput(
+            "펌프 효율은 0~1, 양정은 0 이상이어야 합니다.",
+        ));
+    }
+
+    let mut warnings = Vec::new();
+
+    let mut rise = input.once_through_max_rise_k;
+    if let Some(t_max) = input.max_discharge_temp_c {
+        let allowed = t_max - input.source_water_temp_c;
+        if allowed <= 0.0 {
+            return Err(CoolingError::NegativeDeltaT);
+        }
+        if allowed < rise {
+            warnings.push(CoolingWarning::DischargeLimitsRise {
+                max_discharge_c: t_max,
+                allowed_rise_k: allowed,
+            });
+            rise = allowed;
+        }
+    }
+    let ot_flow = flow_for_rise(input.heat_duty_kw, rise);
+    let once_through = CoolingOptionResult {
+        circulating_flow_m3_per_h: ot_flow,
+        supply_temp_c: input.source_water_temp_c,
+        return_temp_c: input.source_water_temp_c + rise,
+        withdrawal_m3_per_h: ot_flow,
+        evaporation_m3_per_h: 0.0,
+        drift_m3_per_h: 0.0,
+        blowdown_m3_per_h: 0.0,
+        pumping_power_kw: pump_power_kw(ot_flow, input.once_through_head_m, input.pump_efficiency),
+    };
+
+    let range = input.tower_range_k;
+    let rc_flow = flow_for_rise(input.heat_duty_kw, range);
+    let supply = input.wet_bulb_c + input.tower_approach_k;
+    let evaporation = EVAPORATION_FACTOR_PER_K * range * rc_flow;
+    let drift = input.drift_fraction * rc_flow;
+    let blowdown = (evaporation / (input.cycles_of_concentration - 1.0) - drift).max(0.0);
+    let recirculating = CoolingOptionResult {
+        circulating_flow_m3_per_h: rc_flow,
+        supply_temp_c: supply,
+        return_temp_c: supply + range,
+        withdrawal_m3_per_h: evaporation + drift + blowdown,
+        evaporation_m3_per_h: evaporation,
+        drift_m3_per_h: drift,
+        blowdown_m3_per_h: blowdown,
+        pumping_power_kw: pump_power_kw(rc_flow, input.recirculating_head_m, input.pump_efficiency),
+    };
+
+    if supply > input.source_water_temp_c + 3.0 {
+        warnings.push(CoolingWarning::RecirculatingSupplyWarmer {
+            supply_c: supply,
+            source_c: input.source_water_temp_c,
+        });
+    }
+    if input.tower_approach_k < 2.5 {
+        warnings.push(CoolingWarning::TowerApproachOversized);
+    }
+    if evaporation / (input.cycles_of_concentration - 1.0) < drift {
+        warnings.push(CoolingWarning::DriftExceedsBlowdown);
+    }
+
+    Ok(CoolingComparisonResult {
+        flow_ratio: rc_flow / ot_flow,
+        pumping_power_ratio: if once_through.pumping_power_kw > 0.0 {
+            recirculating.pumping_power_kw / once_through.pumping_power_kw
+        } else {
+            f64::INFINITY
+        },
+        withdrawal_ratio: recirculating.withdrawal_m3_per_h / ot_flow,
+        once_through,
+        recirculating,
+        warnings,
+    })
+}
@@ -1,3 +1,5 @@
+use crate::cooling::condenser::CoolingWarning;
+
 /// 냉각탑(접촉식) 범위/접근 계산을 위한 입력 값.
 #[derive(Debug, Clone)]
 pub struct CoolingTowerInput {
@@ -26,8 +28,8 @@ pub struct CoolingTowerResult {
     pub approach_c: f64,
     /// 냉각수 열량(kW) - 단순 cp*ΔT 계산
     pub heat_rejected_kw: f64,
-    /// 경고/주의 메시지
-    pub warnings: Vec<String>,
+    /// 경고/주의
+    pub warnings: Vec<CoolingWarning>,
 }
 
 /// 냉각탑 범위/접근을 계산한다. 복잡한 L/G 추정은 TODO로 남겨둔다.
@@ -41,25 +43,24 @@ pub fn compute_cooling_tower(input: CoolingTowerInput) -> CoolingTowerResult {
 
     let mut warnings = Vec::new();
     if approach_c < 0.0 {
-        warnings
-            .push("접근(Approach)이 음수입니다. 습구 온도보다 낮은 냉각은 불가능합니다.".into());
+        warnings.push(CoolingWarning::NegativeApproach);
     } else if approach_c < 2.0 {
-        warnings.push("접근이 2°C 미만입니다. 실제 운전에서 달성하기 어려울 수 있습니다.".into());
+        warnings.push(CoolingWarning::TightApproach { approach_c });
     }
     if let Some(t) = input.target_range_c {
         if range_c < t {
-            warnings.push(format!(
-                "Range {:.1}°C가 목표 {:.1}°C보다 작습니다.",
-                range_c, t
-            ));
+            warnings.push(CoolingWarning::RangeBelowTarget {
+                range_c,
+                target_c: t,
+            });
         }
     }
     if let Some(t) = input.target_approach_c {
         if approach_c > t {
-            warnings.push(format!(
-                "Approach {:.1}°C가 목표 {:.1}°C보다 큽니다.",
-                approach_c, t
-            ));
+            warnings.push(CoolingWarning::ApproachAboveTarget {
+                approach_c,
+                target_c: t,
+            });
         }
     }
 
@@ -1,3 +1,5 @@
+use crate::cooling::condenser::CoolingWarning;
+
 /// 드레인/재열기 등 2유체 열교환기 열수지 입력.
 #[derive(Debug, Clone)]
 pub struct DrainCoolerInput {
@@ -30,7 +32,7 @@ pub struct DrainCoolerResult {
     /// 불균형(kW)
     pub imbalance_kw: f64,
     /// 경고/주의
-    pub warnings: Vec<String>,
+    pub warnings: Vec<CoolingWarning>,
 }
 
 fn log_mean(delta1: f64, delta2: f64) -> Option<f64> {
@@ -59,11 +61,11 @@ pub fn compute_drain_cooler(input: DrainCoolerInput) -> DrainCoolerResult {
 
     let mut warnings = Vec::new();
     if lmtd <= 0.0 {
-        warnings.push("LMTD가 0 이하입니다. 온도 교차가 잘못되었을 수 있습니다.".into());
+        warnings.push(CoolingWarning::NonPositiveLmtd);
     }
     let imbalance = (shell_heat_kw - tube_heat_kw).abs();
     if imbalance > shell_heat_kw.abs().max(tube_heat_kw.abs()) * 0.05 {
-        warnings.push("쉘/튜브 열수지 불균형이 5%를 초과합니다.".into());
+        warnings.push(CoolingWarning::HeatBalanceImbalance);
     }
 
     // TODO: UA 기반 설계 검증, 핀 효율 등 상세 열전달 모델 추가
@@ -1,7 +1,10 @@
 //! 냉각·복수·순환수 관련 계산 모듈을 모아둔다.
-//! 콘덴서 열수지, 냉각탑 성능, 펌프 NPSH, 드레인/재열기 열수지 등으로 구성한다.
+//! 콘덴서 열수지, 공랭식 복수기, 냉각탑 성능, 관류식/순환식 비교, 펌프 NPSH와 최소 유량, 드레인/재열기 열수지 등으로 구성한다.
 
+pub mod air_cooled;
 pub mod condenser;
+pub mod cooling_comparison;
 pub mod cooling_tower;
 pub mod drain_cooler;
+pub mod pump_min_flow;
 pub mod pump_npsh;
@@ -0,0 +1,305 @@
+//! 펌프 저유량 온도 상승, 최소 연속 유량, 재순환 오리피스.
+//! 펌프 곡선은 정격(BEP) 점과 체절 양정으로 근사한다:
+//! H(Q) = H₀ − (H₀ − H_b)·(Q/Q_b)², η(Q) = η_b·(2x − x²) (x = Q/Q_b).
+//! 축동력 중 유체에 전달되지 않은 손실이 모두 액체를 데운다고 보면 ΔT = g·H·(1/η − 1)/c_p 이다.
+//! 최소 연속 유량은 온도 상승이 허용값이 되는 열적 최소 유량과 BEP 비율로 정한 안정 운전 최소 유량 중 큰 값이다.
+//! 재순환 라인은 최소 유량에서 토출 압력과 복귀처 압력의 차이를 제한 오리피스가 모두 먹는다고 보고
+//! `plant_piping::flow_meter`의 영구 손실 식으로 β를 구한다. 여러 단이면 차압을 똑같이 나눈다.
+
+use crate::cooling::condenser::{CoolingError, CoolingWarning};
+use crate::plant_piping::flow_meter::{self, FlowMeterError, MeterType};
+use crate::steam::if97;
+
+const G: f64 = 9.80665;
+/// 열적 최소 유량 역산(이분법) 반복 횟수
+const FLOW_ITERATIONS: usize = 60;
+
+/// 정격점과 체절 양정으로 근사한 펌프 곡선.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PumpCurve {
+    /// 최고 효율점 유량 [m³/h]
+    pub bep_flow_m3_per_h: f64,
+    /// 최고 효율점 양정 [m]
+    pub bep_head_m: f64,
+    /// 최고 효율 (0~1]
+    pub bep_efficiency: f64,
+    /// 체절(유량 0) 양정 [m]
+    pub shutoff_head_m: f64,
+}
+
+impl PumpCurve {
+    /// 유량에서의 양정 [m]
+    pub fn head_m(&self, flow_m3_per_h: f64) -> f64 {
+        let x = flow_m3_per_h / self.bep_flow_m3_per_h;
+        self.shutoff_head_m - (self.shutoff_head_m - self.bep_head_m) * x * x
+    }
+
+    /// 유량에서의 효율
+    pub fn efficiency(&self, flow_m3_per_h: f64) -> f64 {
+        let x = flow_m3_per_h / self.bep_flow_m3_per_h;
+        (self.bep_efficiency * (2.0 * x - x * x)).max(0.0)
+    }
+
+    fn validate(&self) -> Result<(), CoolingError> {
+        if self.bep_flow_m3_per_h <= 0.0 || self.bep_head_m <= 0.0 {
+            return Err(CoolingError::InvalidInput(
+                "BEP 유량과 양정은 0보다 커야 합니다.",
+            ));
+        }
+        if !(self.bep_efficiency > 0.0 && self.bep_efficiency <= 1.0) {
+            return Err(CoolingError::InvalidInput(
+                "BEP 효율은 0 초과 1 이하여야 합니다.",
+            ));
+        }
+        if self.shutoff_head_m < self.bep_head_m {
+            return Err(CoolingError::InvalidInput(
+                "체절 양정은 BEP 양정 이상이어야 합니다.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 재순환 라인 입력.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RecirculationInput {
+    /// 복귀처(탈기기/리시버) 압력 [bar abs]
+    pub return_pressure_bar_abs: f64,
+    /// 재순환 배관 내경 [m]
+    pub pipe_diameter_m: f64,
+    /// 오리피스 단수 (1 이상)
+    pub stages: u32,
+}
+
+/// 펌프 최소 유량 계산 입력.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PumpMinFlowInput {
+    pub curve: PumpCurve,
+    /// 흡입 압력 [bar abs]
+    pub suction_pressure_bar_abs: f64,
+    /// 흡입 액체 온도 [°C]
+    pub liquid_temp_c: f64,
+    /// 확인할 운전 유량 [m³/h]
+    pub flow_m3_per_h: f64,
+    /// 허용 온도 상승 [K]
+    pub max_temp_rise_k: f64,
+    /// 안정 운전 최소 유량 / BEP 유량 (보통 0.2~0.3)
+    pub stable_flow_fraction: f64,
+    pub recirculation: Option<RecirculationInput>,
+}
+
+/// 재순환 제한 오리피스.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecirculationOrifice {
+    /// 재순환 유량 (= 최소 연속 유량) [m³/h]
+    pub flow_m3_per_h: f64,
+    /// 최소 유량에서의 토출 압력 [bar abs]
+    pub discharge_pressure_bar_abs: f64,
+    /// 오리피스 전체 차압 [bar]
+    pub differential_bar: f64,
+    pub stages: u32,
+    /// 단당 영구 손실 [bar]
+    pub stage_loss_bar: f64,
+    /// 직경비 β = d/D
+    pub beta: f64,
+    /// 오리피스 구경 [mm]
+    pub bore_mm: f64,
+}
+
+/// 펌프 최소 유량 계산 결과.
+#[derive(Debug, Clone)]
+pub struct PumpMinFlowResult {
+    /// 운전 유량에서의 양정 [m]
+    pub head_m: f64,
+    /// 운전 유량에서의 효율
+    pub efficiency: f64,
+    /// 운전 유량에서의 온도 상승 [K]
+    pub temp_rise_k: f64,
+    /// 흡입 압력 포화 온도까지 남은 온도 [K]
+    pub saturation_margin_k: f64,
+    /// 온도 상승이 허용값이 되는 유량 [m³/h]
+    pub thermal_min_flow_m3_per_h: f64,
+    /// BEP 비율로 정한 안정 운전 최소 유량 [m³/h]
+    pub stable_min_flow_m3_per_h: f64,
+    /// 최소 연속 유량 [m³/h]
+    pub min_flow_m3_per_h: f64,
+    pub recirculation: Option<RecirculationOrifice>,
+    pub warnings: Vec<CoolingWarning>,
+}
+
+/// 양정과 효율에서 펌프 안 액체의 온도 상승 [K]. ΔT = g·H·(1/η − 1)/c_p (c_p: J/kg·K)
+pub fn temperature_rise_k(head_m: f64, efficiency: f64, cp_j_per_kgk: f64) -> f64 {
+    G * head_m * (1.0 / efficiency - 1.0) / cp_j_per_kgk
+}
+
+/// 온도 상승이 `max_temp_rise_k`가 되는 열적 최소 유량 [m³/h].
+/// BEP 아래에서는 유량이 줄수록 온도 상승이 커지므로 이분법으로 푼다.
+pub fn thermal_min_flow_m3_per_h(
+    curve: &PumpCurve,
+    cp_j_per_kgk: f64,
+    max_temp_rise_k: f64,
+) -> Result<f64, CoolingError> {
+    curve.validate()?;
+    if max_temp_rise_k <= 0.0 || cp_j_per_kgk <= 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "허용 온도 상승과 비열은 0보다 커야 합니다.",
+        ));
+    }
+    let rise_at = |q: f64| temperature_rise_k(curve.head_m(q), curve.efficiency(q), cp_j_per_kgk);
+    let (mut lo, mut hi) = (0.0, curve.bep_flow_m3_per_h);
+    if rise_at(hi) > max_temp_rise_k {
+        return Err(CoolingError::InvalidInput(
+            "허용 온도 상승이 BEP에서의 온도 상승보다 작습니다.",
+        ));
+    }
+    for _ in 0..FLOW_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if rise_at(mid) > max_temp_rise_k {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(0.5 * (lo + hi))
+}
+
+/// 최소 유량에서 토출 압력을 복귀처 압력까지 낮추는 재순환 제한 오리피스를 고른다.
+pub fn size_recirculation_orifice(
+    recirculation: &RecirculationInput,
+    flow_m3_per_h: f64,
+    discharge_pressure_bar_abs: f64,
+    density_kg_per_m3: f64,
+) -> Result<RecirculationOrifice, CoolingError> {
+    if recirculation.stages == 0 {
+        return Err(CoolingError::InvalidInput(
+            "오리피스 단수는 1 이상이어야 합니다.",
+        ));
+    }
+    let differential = discharge_pressure_bar_abs - recirculation.return_pressure_bar_abs;
+    if differential <= 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "복귀처 압력은 최소 유량에서의 토출 압력보다 낮아야 합니다.",
+        ));
+    }
+    let stage_loss = differential / recirculation.stages as f64;
+    let meter = MeterType::Orifice;
+    let beta = flow_meter::restriction_orifice_beta(
+        meter,
+        meter.typical_discharge_coefficient(),
+        recirculation.pipe_diameter_m,
+        flow_m3_per_h,
+        density_kg_per_m3,
+        stage_loss,
+    )
+    .map_err(|FlowMeterError::InvalidInput(msg)| CoolingError::InvalidInput(msg))?;
+    Ok(RecirculationOrifice {
+        flow_m3_per_h,
+        discharge_pressure_bar_abs,
+        differential_bar: differential,
+        stages: recirculation.stages,
+        stage_loss_bar: stage_loss,
+        beta,
+        bore_mm: beta * recirculation.pipe_diameter_m * 1000.0,
+    })
+}
+
+/// 운전 유량의 온도 상승, 최소 연속 유량, 재순환 오리피스를 계산한다.
+pub fn compute_pump_min_flow(input: &PumpMinFlowInput) -> Result<PumpMinFlowResult, CoolingError> {
+    input.curve.validate()?;
+    if input.flow_m3_per_h <= 0.0 || input.suction_pressure_bar_abs <= 0.0 {
+        return Err(CoolingError::InvalidInput(
+            "운전 유량과 흡입 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if !(0.0..1.0).contains(&input.stable_flow_fraction) {
+        return Err(CoolingError::InvalidInput(
+            "안정 운전 최소 유량 비율은 0 이상 1 미만이어야 합니다.",
+        ));
+    }
+    let if97_err = |e: &str| CoolingError::If97(e.into());
+    let p = input.suction_pressure_bar_abs;
+    let t = input.liquid_temp_c;
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p).map_err(if97_err)?;
+    if t <= 0.0 || t >= t_sat {
+        return Err(CoolingError::InvalidInput(
+            "흡입 액체 온도는 0 °C 초과, 흡입 압력의 포화 온도 미만이어야 합니다.",
+        ));
+    }
+    let (h, v, _) = if97::region_props(p, t).map_err(if97_err)?;
+    let dt = (t / 2.0).min(0.5);
+    let (h_lo, _, _) = if97::region_props(p, t - dt).map_err(if97_err)?;
+    let cp = (h - h_lo) / dt;
+    let rho = 1.0 / v;
+
+    let curve = &input.curve;
+    let q = input.flow_m3_per_h;
+    let head = curve.head_m(q);
+    let efficiency = curve.efficiency(q);
+    let rise = if efficiency > 0.0 {
+        temperature_rise_k(head, efficiency, cp)
+    } else {
+        f64::INFINITY
+    };
+    let margin = t_sat - t;
+
+    let thermal_min = thermal_min_flow_m3_per_h(curve, cp, input.max_temp_rise_k)?;
+    let stable_min = input.stable_flow_fraction * curve.bep_flow_m3_per_h;
+    let min_flow = thermal_min.max(stable_min);
+
+    let mut warnings = Vec::new();
+    if q < min_flow {
+        warnings.push(CoolingWarning::PumpBelowMinimumFlow {
+            flow_m3_per_h: q,
+            min_flow_m3_per_h: min_flow,
+        });
+    }
+    if rise >= margin {
+        warnings.push(CoolingWarning::PumpLiquidFlashing {
+            rise_k: rise,
+            margin_k: margin,
+        });
+    } else if rise > input.max_temp_rise_k {
+        warnings.push(CoolingWarning::PumpTemperatureRiseHigh {
+            rise_k: rise,
+            limit_k: input.max_temp_rise_k,
+        });
+    }
+
+    let recirculation = match &input.recirculation {
+        Some(recirc) => {
+            let discharge = p + rho * G * curve.head_m(min_flow) / 1e5;
+            let orifice = size_recirculation_orifice(recirc, min_flow, discharge, rho)?;
+            let (beta_min, _) = MeterType::Orifice.beta_range();
+            if orifice.beta < beta_min {
+                warnings.push(CoolingWarning::OrificeBetaSmall {
+                    beta: orifice.beta,
+                    stages: orifice.stages,
+                });
+            }
+            let t_out =
+                t + temperature_rise_k(curve.head_m(min_flow), curve.efficiency(min_flow), cp);
+            let p_sat_out =
+                if97::saturation_pressure_bar_abs_from_temp_c(t_out).map_err(if97_err)?;
+            if recirc.return_pressure_bar_abs < p_sat_out {
+                warnings.push(CoolingWarning::OrificeOutletFlashing {
+                    return_pressure_bar_abs: recirc.return_pressure_bar_abs,
+                    saturation_pressure_bar_abs: p_sat_out,
+                });
+            }
+            Some(orifice)
+        }
+        None => None,
+    };
+
+    Ok(PumpMinFlowResult {
+        head_m: head,
+        efficiency,
+        temp_rise_k: rise,
+        saturation_margin_k: margin,
+        thermal_min_flow_m3_per_h: thermal_min,
+        stable_min_flow_m3_per_h: stable_min,
+        min_flow_m3_per_h: min_flow,
+        recirculation,
+        warnings,
+    })
+}
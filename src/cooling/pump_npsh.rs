@@ -1,4 +1,9 @@
+use crate::cooling::condenser::{CoolingError, CoolingWarning};
+use crate::steam::if97;
+
 const G: f64 = 9.80665;
+/// 권장 최소 NPSH 여유비 (NPSHa / NPSHr)
+pub const MIN_MARGIN_RATIO: f64 = 1.1;
 
 /// 펌프 NPSH 계산 입력.
 #[derive(Debug, Clone)]
@@ -7,6 +12,8 @@ pub struct PumpNpshInput {
     pub suction_pressure_bar: f64,
     /// 압력 모드: true=절대, false=게이지
     pub suction_is_abs: bool,
+    /// 현장 대기압(bar abs) - 게이지 입력의 절대압 환산 기준 (해수면 1.01325)
+    pub atmospheric_pressure_bar_abs: f64,
     /// 유체 온도(°C) - 물로 가정하여 증기압을 계산
     pub liquid_temp_c: f64,
     /// 정수두(m) - 액면에서 펌프 중심까지의 높이(+상승, -낙차)
@@ -26,8 +33,8 @@ pub struct PumpNpshResult {
     pub npsha_m: f64,
     /// Margin = NPSHa / NPSHr
     pub margin_ratio: f64,
-    /// 경고/주의 메시지
-    pub warnings: Vec<String>,
+    /// 경고/주의
+    pub warnings: Vec<CoolingWarning>,
 }
 
 /// Antoine 식으로 대략적인 물의 포화증기압(bar abs)을 구한다.
@@ -45,7 +52,7 @@ pub fn compute_pump_npsh(input: PumpNpshInput) -> PumpNpshResult {
     let p_suction_abs_bar = if input.suction_is_abs {
         input.suction_pressure_bar
     } else {
-        input.suction_pressure_bar + 1.01325
+        input.suction_pressure_bar + input.atmospheric_pressure_bar_abs
     };
     let pv_bar = water_vapor_pressure_bar_abs(input.liquid_temp_c);
 
@@ -60,8 +67,8 @@ pub fn compute_pump_npsh(input: PumpNpshInput) -> PumpNpshResult {
         f64::INFINITY
     };
     let mut warnings = Vec::new();
-    if margin < 1.1 {
-        warnings.push(format!("NPSH Margin {:.2} (<1.1). 공동현상 위험.", margin));
+    if margin < MIN_MARGIN_RATIO {
+        warnings.push(CoolingWarning::NpshMarginLow { margin });
     }
     PumpNpshResult {
         npsha_m: npsha,
@@ -69,3 +76,107 @@ pub fn compute_pump_npsh(input: PumpNpshInput) -> PumpNpshResult {
         warnings,
     }
 }
+
+/// 포화수 흡입(복수기 핫웰/플래시 탱크) NPSH 입력.
+/// 용기 액체가 포화 상태이므로 용기 압력과 증기압이 상쇄되어 NPSHa는 수두와 손실만으로 정해진다.
+#[derive(Debug, Clone)]
+pub struct SaturatedSuctionInput {
+    /// 용기(핫웰/플래시 탱크) 압력 [bar abs]. 포화수 물성 계산에만 쓴다.
+    pub vessel_pressure_bar_abs: f64,
+    /// 액면에서 펌프 중심까지의 높이 [m] (액면이 위일 때 +)
+    pub submergence_m: f64,
+    /// 흡입 배관 마찰손실 [m 수두]
+    pub friction_loss_m: f64,
+    /// 요구 NPSH (제조사 값, m)
+    pub npshr_m: f64,
+    /// 용기에서 펌프 흡입구까지 흡입 배관 길이 [m]
+    pub suction_line_length_m: f64,
+    /// 흡입 배관 유속 [m/s]
+    pub suction_velocity_m_per_s: f64,
+    /// 부하 급감 시 용기 압력 감소율 [bar/s]
+    pub pressure_decay_bar_per_s: f64,
+}
+
+/// 포화수 흡입 NPSH 결과.
+#[derive(Debug, Clone)]
+pub struct SaturatedSuctionResult {
+    /// 용기 압력에서의 포화수 밀도 [kg/m³]
+    pub liquid_density_kg_per_m3: f64,
+    /// 정상 상태 NPSHa = 액면 높이 − 마찰손실 [m]
+    pub npsha_steady_m: f64,
+    pub margin_ratio_steady: f64,
+    /// 흡입 배관 체류 시간 [s]. 이 시간 동안 펌프 입구 액체는 이전 포화 온도를 유지한다.
+    pub transit_time_s: f64,
+    /// 압력 감소로 잃는 NPSH [m]
+    pub transient_loss_m: f64,
+    /// 과도 상태 최저 NPSHa [m]
+    pub npsha_transient_m: f64,
+    pub margin_ratio_transient: f64,
+    /// 과도 상태에서도 `MIN_MARGIN_RATIO`를 만족하는 최소 액면 높이 [m]
+    pub min_submergence_m: f64,
+    pub warnings: Vec<CoolingWarning>,
+}
+
+/// 포화수를 흡입하는 응축수 펌프의 정상/과도 NPSH를 계산한다.
+/// 과도 손실은 흡입 배관 체류 시간 동안의 용기 압력 감소량을 수두로 환산한 값
+/// (ΔH = dP/dt · L/v / (ρ·g))으로 보며, 배관 안 액체의 열용량에 의한 완화는 무시한다.
+pub fn compute_saturated_suction_npsh(
+    input: SaturatedSuctionInput,
+) -> Result<SaturatedSuctionResult, CoolingError> {
+    if input.vessel_pressure_bar_abs <= 0.0 {
+        return Err(CoolingError::InvalidInput("용기 압력은 0보다 커야 합니다."));
+    }
+    if input.suction_line_length_m < 0.0
+        || input.suction_velocity_m_per_s <= 0.0
+        || input.pressure_decay_bar_per_s < 0.0
+        || input.friction_loss_m < 0.0
+    {
+        return Err(CoolingError::InvalidInput(
+            "흡입 배관 길이/마찰손실/압력 감소율은 0 이상, 유속은 0보다 커야 합니다.",
+        ));
+    }
+    let (_, vf, _) = if97::props_from_px(input.vessel_pressure_bar_abs, 0.0)
+        .map_err(|e| CoolingError::If97(e.into()))?;
+    let rho = 1.0 / vf;
+
+    let npsha = input.submergence_m - input.friction_loss_m;
+    let transit_s = input.suction_line_length_m / input.suction_velocity_m_per_s;
+    let transient_loss = input.pressure_decay_bar_per_s * transit_s * 1.0e5 / (rho * G);
+    let npsha_transient = npsha - transient_loss;
+    let ratio = |h: f64| {
+        if input.npshr_m > 0.0 {
+            h / input.npshr_m
+        } else {
+            f64::INFINITY
+        }
+    };
+    let margin = ratio(npsha);
+    let margin_transient = ratio(npsha_transient);
+    let min_submergence =
+        MIN_MARGIN_RATIO * input.npshr_m.max(0.0) + input.friction_loss_m + transient_loss;
+
+    let mut warnings = Vec::new();
+    if margin < MIN_MARGIN_RATIO {
+        warnings.push(CoolingWarning::NpshMarginLow { margin });
+    } else if margin_transient < MIN_MARGIN_RATIO {
+        warnings.push(CoolingWarning::TransientNpshMarginLow {
+            margin: margin_transient,
+            min_submergence_m: min_submergence,
+        });
+    }
+    if input.submergence_m <= 0.0 {
+        warnings.push(CoolingWarning::SaturatedSuctionLift);
+    }
+
+    Ok(SaturatedSuctionResult {
+        liquid_density_kg_per_m3: rho,
+        npsha_steady_m: npsha,
+        margin_ratio_steady: margin,
+        transit_time_s: transit_s,
+        transient_loss_m: transient_loss,
+        npsha_transient_m: npsha_transient,
+        margin_ratio_transient: margin_transient,
+        min_submergence_m: min_submergence,
+        warnings,
+    })
+}
@@ -0,0 +1,293 @@
+//! 사용자 정의 단위 등록.
+//! 현장 고유 단위(kg/cm2g, MMBtu, therm 등)를 내장 단위 하나에 대한 배율/오프셋으로 정의해
+//! 실행 중에 등록한다. 등록된 단위는 `convert`를 거치는 모든 변환과 GUI 단위 콤보에 나타난다.
+//!
+//! 파일 형식 (`custom_units.toml`, config.toml과 같은 폴더):
+//! ```toml
+//! [[unit]]
+//! name = "kg/cm2g"
+//! kind = "pressure"   # number_format::quantity_key 와 같은 키
+//! base_unit = "bar"   # 내장 단위 코드
+//! scale = 0.980665    # 기준 단위 값 = 값 × scale + offset
+//! offset = 0.0
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::RwLock;
+
+use crate::conversion::{self, ConversionError};
+use crate::number_format::quantity_key;
+use crate::quantity::QuantityKind;
+
+/// 기본 사용자 단위 파일 이름
+pub const CUSTOM_UNITS_FILE: &str = "custom_units.toml";
+
+static REGISTRY: RwLock<Vec<CustomUnit>> = RwLock::new(Vec::new());
+
+/// 사용자 정의 단위 하나.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomUnit {
+    /// 단위 이름(콤보/입력에 쓰는 코드)
+    pub name: String,
+    /// 물리량 키 (예: "pressure", "energy")
+    pub kind: String,
+    /// 환산 기준이 되는 내장 단위 코드
+    pub base_unit: String,
+    /// 기준 단위 값 = 값 × scale + offset
+    pub scale: f64,
+    #[serde(default)]
+    pub offset: f64,
+}
+
+impl CustomUnit {
+    /// 이 단위의 값을 기준 단위 값으로 바꾼다.
+    pub fn to_base(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// 기준 단위 값을 이 단위의 값으로 바꾼다.
+    pub fn from_base(&self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CustomUnitFile {
+    #[serde(default)]
+    unit: Vec<CustomUnit>,
+}
+
+/// 사용자 단위 등록/로드 오류.
+#[derive(Debug)]
+pub enum CustomUnitError {
+    InvalidDefinition(String),
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+}
+
+impl std::fmt::Display for CustomUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CustomUnitError::InvalidDefinition(msg) => write!(f, "사용자 단위 정의 오류: {msg}"),
+            CustomUnitError::Io(e) => write!(f, "파일 입출력 오류: {e}"),
+            CustomUnitError::Parse(e) => write!(f, "사용자 단위 파일 파싱 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CustomUnitError {}
+
+impl From<std::io::Error> for CustomUnitError {
+    fn from(value: std::io::Error) -> Self {
+        CustomUnitError::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for CustomUnitError {
+    fn from(value: toml::de::Error) -> Self {
+        CustomUnitError::Parse(value)
+    }
+}
+
+const ALL_KINDS: [QuantityKind; 13] = [
+    QuantityKind::Temperature,
+    QuantityKind::TemperatureDifference,
+    QuantityKind::Pressure,
+    QuantityKind::Length,
+    QuantityKind::Area,
+    QuantityKind::Volume,
+    QuantityKind::Velocity,
+    QuantityKind::Mass,
+    QuantityKind::Viscosity,
+    QuantityKind::Energy,
+    QuantityKind::HeatTransferCoeff,
+    QuantityKind::ThermalConductivity,
+    QuantityKind::SpecificEnthalpy,
+];
+
+/// 물리량별 내장 단위 `(표시 라벨, 변환 코드)` 목록. GUI 단위 콤보와 CLI 단위 입력이 함께 쓴다.
+pub fn builtin_unit_options(kind: QuantityKind) -> &'static [(&'static str, &'static str)] {
+    match kind {
+        QuantityKind::Temperature => &[
+            ("Celsius (°C)", "C"),
+            ("Kelvin (K)", "K"),
+            ("Fahrenheit (°F)", "F"),
+            ("Rankine (R)", "R"),
+        ],
+        QuantityKind::TemperatureDifference => {
+            &[("Δ°C", "C"), ("ΔK", "K"), ("Δ°F", "F"), ("ΔR", "R")]
+        }
+        QuantityKind::Pressure => &[
+            ("bar(g)", "bar"),
+            ("bar(a)", "bara"),
+            ("kPa", "kPa"),
+            ("MPa", "MPa"),
+            ("psi", "psi"),
+            ("atm", "atm"),
+            ("Pa", "Pa"),
+            ("mmHg", "mmHg"),
+        ],
+        QuantityKind::Length => &[
+            ("m", "m"),
+            ("mm", "mm"),
+            ("cm", "cm"),
+            ("inch", "in"),
+            ("ft", "ft"),
+            ("yd", "yd"),
+            ("km", "km"),
+        ],
+        QuantityKind::Area => &[("m²", "m2"), ("ft²", "ft2")],
+        QuantityKind::Volume => &[("m³", "m3"), ("L", "l"), ("mL", "ml"), ("ft³", "ft3")],
+        QuantityKind::Velocity => &[("m/s", "m/s"), ("km/h", "km/h"), ("ft/s", "ft/s")],
+        QuantityKind::Mass => &[("kg", "kg"), ("g", "g"), ("lb", "lb")],
+        QuantityKind::Viscosity => &[("Pa·s", "Pa·s"), ("cP", "cps")],
+        QuantityKind::Energy => &[("J", "J"), ("kJ", "kJ"), ("kcal", "kcal"), ("Btu", "Btu")],
+        QuantityKind::HeatTransferCoeff => &[("W/m²·K", "W/m2K"), ("Btu/(h·ft²·F)", "Btu/h-ft2-F")],
+        QuantityKind::ThermalConductivity => &[("W/m·K", "W/mK"), ("Btu/(h·ft·F)", "Btu/h-ft-F")],
+        QuantityKind::SpecificEnthalpy => &[
+            ("kJ/kg", "kJ/kg"),
+            ("kcal/kg", "kcal/kg"),
+            ("Btu/lb", "Btu/lb"),
+        ],
+    }
+}
+
+/// 내장 단위 뒤에 등록된 사용자 단위를 붙인 `(라벨, 코드)` 목록.
+pub fn unit_choices(kind: QuantityKind) -> Vec<(String, String)> {
+    builtin_unit_options(kind)
+        .iter()
+        .map(|(label, code)| (label.to_string(), code.to_string()))
+        .chain(
+            units_for(kind)
+                .into_iter()
+                .map(|u| (u.name.clone(), u.name)),
+        )
+        .collect()
+}
+
+/// 물리량 키를 `QuantityKind`로 바꾼다.
+pub fn kind_from_key(key: &str) -> Option<QuantityKind> {
+    ALL_KINDS
+        .into_iter()
+        .find(|k| quantity_key(*k).eq_ignore_ascii_case(key.trim()))
+}
+
+/// 단위 정의가 등록 가능한지 검사한다.
+fn validate(unit: &CustomUnit) -> Result<(), CustomUnitError> {
+    if unit.name.trim().is_empty() || unit.base_unit.trim().is_empty() {
+        return Err(CustomUnitError::InvalidDefinition(
+            "단위 이름과 기준 단위는 비울 수 없습니다.".into(),
+        ));
+    }
+    if kind_from_key(&unit.kind).is_none() {
+        return Err(CustomUnitError::InvalidDefinition(format!(
+            "{}: 알 수 없는 물리량 '{}'",
+            unit.name, unit.kind
+        )));
+    }
+    if !unit.scale.is_finite() || unit.scale == 0.0 || !unit.offset.is_finite() {
+        return Err(CustomUnitError::InvalidDefinition(format!(
+            "{}: scale은 0이 아닌 유한값, offset은 유한값이어야 합니다.",
+            unit.name
+        )));
+    }
+    Ok(())
+}
+
+/// 같은 물리량·이름의 기존 단위를 지우고 새 단위를 넣는다.
+fn insert(reg: &mut Vec<CustomUnit>, unit: CustomUnit) {
+    reg.retain(|u| {
+        !(u.name.eq_ignore_ascii_case(&unit.name) && u.kind.eq_ignore_ascii_case(&unit.kind))
+    });
+    reg.push(unit);
+}
+
+/// 단위를 등록한다. 같은 물리량에 같은 이름이 있으면 교체한다.
+pub fn register(unit: CustomUnit) -> Result<(), CustomUnitError> {
+    validate(&unit)?;
+    let mut reg = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    insert(&mut reg, unit);
+    Ok(())
+}
+
+/// 등록된 사용자 단위를 모두 지운다.
+pub fn clear() {
+    REGISTRY.write().unwrap_or_else(|e| e.into_inner()).clear();
+}
+
+/// TOML 문자열의 `[[unit]]` 항목을 모두 등록하고 등록 개수를 돌려준다.
+/// 한 항목이라도 잘못되면 아무것도 등록하지 않는다.
+pub fn load_from_str(content: &str) -> Result<usize, CustomUnitError> {
+    let file: CustomUnitFile = toml::from_str(content)?;
+    file.unit.iter().try_for_each(validate)?;
+    let n = file.unit.len();
+    let mut reg = REGISTRY.write().unwrap_or_else(|e| e.into_inner());
+    for unit in file.unit {
+        insert(&mut reg, unit);
+    }
+    Ok(n)
+}
+
+/// 파일에서 사용자 단위를 읽어 등록한다.
+pub fn load_from_file(path: &Path) -> Result<usize, CustomUnitError> {
+    load_from_str(&std::fs::read_to_string(path)?)
+}
+
+/// 작업 폴더의 `custom_units.toml`이 있으면 읽는다. 없으면 0.
+pub fn load_default() -> Result<usize, CustomUnitError> {
+    let path = Path::new(CUSTOM_UNITS_FILE);
+    if path.exists() {
+        load_from_file(path)
+    } else {
+        Ok(0)
+    }
+}
+
+/// 물리량에 등록된 사용자 단위 목록.
+pub fn units_for(kind: QuantityKind) -> Vec<CustomUnit> {
+    let key = quantity_key(kind);
+    REGISTRY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .iter()
+        .filter(|u| u.kind.eq_ignore_ascii_case(key))
+        .cloned()
+        .collect()
+}
+
+/// 이름으로 사용자 단위를 찾는다.
+pub fn find(kind: QuantityKind, name: &str) -> Option<CustomUnit> {
+    units_for(kind)
+        .into_iter()
+        .find(|u| u.name.eq_ignore_ascii_case(name.trim()))
+}
+
+/// 사용자 단위면 기준 단위 값과 기준 단위 코드로, 아니면 그대로 돌려준다.
+pub fn to_base(kind: QuantityKind, value: f64, unit: &str) -> (f64, String) {
+    match find(kind, unit) {
+        Some(u) => (u.to_base(value), u.base_unit),
+        None => (value, unit.to_string()),
+    }
+}
+
+/// 사용자 단위를 포함해 단위를 변환한다. 내장 단위끼리는 `conversion::convert`와 같다.
+pub fn convert(
+    kind: QuantityKind,
+    value: f64,
+    from: &str,
+    to: &str,
+) -> Result<f64, ConversionError> {
+    let (v, from_base) = to_base(kind, value, from);
+    match find(kind, to) {
+        Some(target) => {
+            let base = if from_base.eq_ignore_ascii_case(&target.base_unit) {
+                v
+            } else {
+                conversion::convert(kind, v, &from_base, &target.base_unit)?
+            };
+            Ok(target.from_base(base))
+        }
+        None => conversion::convert(kind, v, &from_base, to),
+    }
+}
@@ -0,0 +1,370 @@
+//! 크레이트 공통 오류 타입.
+//! 모듈별 오류를 한 enum으로 감싸고 안정적인 오류 코드와 i18n 키를 붙여,
+//! GUI/CLI는 같은 방식으로 번역·보고하고 라이브러리 사용자는 실패 종류로 분기할 수 있다.
+//! 상세 메시지는 각 모듈 오류의 `Display`를 그대로 쓴다.
+
+use thiserror::Error;
+
+use crate::i18n::Translator;
+use crate::{
+    air, combustion, condensate_recovery, config, conversion, cooling, custom_units, flow_units,
+    gas, goal_seek, plant_piping, quantity_arg, steam, strapping_table, sweep, table_edit,
+    valve_db, vessel_geometry, water,
+};
+
+/// 크레이트 공통 오류.
+#[derive(Debug, Error)]
+pub enum ToolboxError {
+    #[error(transparent)]
+    SteamTable(#[from] steam::steam_tables::SteamTableError),
+    #[error(transparent)]
+    SteamPiping(#[from] steam::steam_piping::PipeCalcError),
+    #[error(transparent)]
+    Valve(#[from] steam::steam_valves::ValveCalcError),
+    #[error(transparent)]
+    CriticalFlow(#[from] steam::critical_flow::CriticalFlowError),
+    #[error(transparent)]
+    Relief(#[from] steam::relief::ReliefError),
+    #[error(transparent)]
+    Drum(#[from] steam::drum::DrumError),
+    #[error(transparent)]
+    Accumulator(#[from] steam::accumulator::AccumulatorError),
+    #[error(transparent)]
+    Ejector(#[from] steam::ejector::EjectorError),
+    #[error(transparent)]
+    Sparger(#[from] steam::sparger::SpargerError),
+    #[error(transparent)]
+    TankHeating(#[from] steam::tank_heating::TankHeatingError),
+    #[error(transparent)]
+    Leak(#[from] steam::steam_leak::LeakError),
+    #[error(transparent)]
+    Calorimeter(#[from] steam::steam_dryness::CalorimeterError),
+    #[error(transparent)]
+    SteamPurity(#[from] steam::steam_purity::SteamPurityError),
+    #[error(transparent)]
+    Cycle(#[from] steam::cycle::CycleError),
+    #[error(transparent)]
+    Hrsg(#[from] steam::hrsg::HrsgError),
+    #[error(transparent)]
+    TurbineBypass(#[from] steam::turbine_bypass::TurbineBypassError),
+    #[error(transparent)]
+    LineCase(#[from] steam::line_case::LineCaseError),
+    #[error(transparent)]
+    LineCondensation(#[from] steam::line_condensation::LineCondensationError),
+    #[error(transparent)]
+    Attemperator(#[from] steam::attemperator::AttemperatorError),
+    #[error(transparent)]
+    MeterCompensation(#[from] steam::meter_compensation::MeterCompensationError),
+    #[error(transparent)]
+    Totalizer(#[from] steam::totalizer::TotalizerError),
+    #[error(transparent)]
+    TableBooklet(#[from] steam::table_booklet::BookletError),
+    #[error(transparent)]
+    VentSilencer(#[from] steam::vent_silencer::VentSilencerError),
+    #[error(transparent)]
+    SteamBlow(#[from] steam::steam_blow::SteamBlowError),
+    #[error(transparent)]
+    ReliefScenario(#[from] steam::relief_scenario::ReliefScenarioError),
+    #[error(transparent)]
+    FireCase(#[from] steam::fire_case::FireCaseError),
+    #[error(transparent)]
+    SteamNetwork(#[from] steam::network::NetworkError),
+    #[error(transparent)]
+    ReturnLine(#[from] condensate_recovery::return_line::ReturnLineError),
+    #[error(transparent)]
+    Receiver(#[from] condensate_recovery::receiver::ReceiverError),
+    #[error(transparent)]
+    PipeNetwork(#[from] plant_piping::network::PipeNetworkError),
+    #[error(transparent)]
+    ExpansionLoop(#[from] plant_piping::expansion_loop::ExpansionLoopError),
+    #[error(transparent)]
+    FlowMeter(#[from] plant_piping::flow_meter::FlowMeterError),
+    #[error(transparent)]
+    WallThickness(#[from] plant_piping::wall_thickness::WallThicknessError),
+    #[error(transparent)]
+    PressureTest(#[from] plant_piping::pressure_test::PressureTestError),
+    #[error(transparent)]
+    LineFill(#[from] plant_piping::line_fill::LineFillError),
+    #[error(transparent)]
+    PipeClass(#[from] plant_piping::pipe_class::PipeClassError),
+    #[error(transparent)]
+    Cooling(#[from] cooling::condenser::CoolingError),
+    #[error(transparent)]
+    EvaporativeCooler(#[from] air::evaporative_cooler::EvaporativeCoolerError),
+    #[error(transparent)]
+    FreezeProtection(#[from] water::freeze_protection::FreezeProtectionError),
+    #[error(transparent)]
+    Blowdown(#[from] gas::blowdown::BlowdownError),
+    #[error(transparent)]
+    Aiv(#[from] gas::aiv::AivError),
+    #[error(transparent)]
+    GasProperties(#[from] gas::gas_properties::GasPropertiesError),
+    #[error(transparent)]
+    GasThrottling(#[from] gas::throttling::ThrottlingError),
+    #[error(transparent)]
+    Compressor(#[from] gas::compressor::CompressorError),
+    #[error(transparent)]
+    AirPreheater(#[from] combustion::air_preheater::AirPreheaterError),
+    #[error(transparent)]
+    DewPoint(#[from] combustion::dew_point::DewPointError),
+    #[error(transparent)]
+    Emissions(#[from] combustion::emissions::EmissionsError),
+    #[error(transparent)]
+    Config(#[from] config::ConfigError),
+    #[error(transparent)]
+    Conversion(#[from] conversion::ConversionError),
+    #[error(transparent)]
+    CustomUnit(#[from] custom_units::CustomUnitError),
+    #[error(transparent)]
+    FlowUnit(#[from] flow_units::FlowUnitError),
+    #[error(transparent)]
+    Sweep(#[from] sweep::SweepError),
+    #[error(transparent)]
+    GoalSeek(#[from] goal_seek::GoalSeekError),
+    #[error(transparent)]
+    QuantityArg(#[from] quantity_arg::QuantityArgError),
+    #[error(transparent)]
+    TablePaste(#[from] table_edit::TablePasteError),
+    #[error(transparent)]
+    ValveDb(#[from] valve_db::ValveDbError),
+    #[error(transparent)]
+    VesselGeometry(#[from] vessel_geometry::VesselGeometryError),
+    #[error(transparent)]
+    StrappingTable(#[from] strapping_table::StrappingError),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    /// 아직 구현되지 않은 기능 호출
+    #[error("아직 구현되지 않음: {0}")]
+    Unimplemented(&'static str),
+}
+
+impl ToolboxError {
+    /// (오류 코드, i18n 키, 영어 기본 요약)
+    fn meta(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            ToolboxError::SteamTable(_) => (
+                "E101",
+                "error.steam_table",
+                "Steam table calculation failed",
+            ),
+            ToolboxError::SteamPiping(_) => (
+                "E102",
+                "error.steam_piping",
+                "Steam piping calculation failed",
+            ),
+            ToolboxError::Valve(_) => ("E103", "error.valve", "Valve/orifice calculation failed"),
+            ToolboxError::CriticalFlow(_) => (
+                "E104",
+                "error.critical_flow",
+                "Critical flow calculation failed",
+            ),
+            ToolboxError::Relief(_) => ("E105", "error.relief", "Relief valve calculation failed"),
+            ToolboxError::Drum(_) => ("E106", "error.drum", "Drum calculation failed"),
+            ToolboxError::Accumulator(_) => (
+                "E107",
+                "error.accumulator",
+                "Steam accumulator calculation failed",
+            ),
+            ToolboxError::Ejector(_) => ("E108", "error.ejector", "Ejector calculation failed"),
+            ToolboxError::Sparger(_) => ("E109", "error.sparger", "Sparger calculation failed"),
+            ToolboxError::TankHeating(_) => (
+                "E110",
+                "error.tank_heating",
+                "Tank heating calculation failed",
+            ),
+            ToolboxError::Leak(_) => ("E111", "error.leak", "Steam leak analysis failed"),
+            ToolboxError::Calorimeter(_) => (
+                "E112",
+                "error.calorimeter",
+                "Dryness calorimeter calculation failed",
+            ),
+            ToolboxError::SteamPurity(_) => (
+                "E113",
+                "error.steam_purity",
+                "Steam purity calculation failed",
+            ),
+            ToolboxError::Cycle(_) => ("E114", "error.cycle", "Rankine cycle calculation failed"),
+            ToolboxError::Hrsg(_) => ("E115", "error.hrsg", "HRSG calculation failed"),
+            ToolboxError::SteamNetwork(_) => (
+                "E116",
+                "error.steam_network",
+                "Steam network calculation failed",
+            ),
+            ToolboxError::TurbineBypass(_) => (
+                "E117",
+                "error.turbine_bypass",
+                "Turbine bypass calculation failed",
+            ),
+            ToolboxError::LineCase(_) => ("E118", "error.line_case", "Line case check failed"),
+            ToolboxError::LineCondensation(_) => (
+                "E119",
+                "error.line_condensation",
+                "Line condensation calculation failed",
+            ),
+            ToolboxError::Attemperator(_) => (
+                "E120",
+                "error.attemperator",
+                "Attemperator calculation failed",
+            ),
+            ToolboxError::MeterCompensation(_) => (
+                "E121",
+                "error.meter_compensation",
+                "Meter compensation failed",
+            ),
+            ToolboxError::Totalizer(_) => {
+                ("E122", "error.totalizer", "Time-series totalizer failed")
+            }
+            ToolboxError::TableBooklet(_) => (
+                "E123",
+                "error.table_booklet",
+                "Steam table booklet generation failed",
+            ),
+            ToolboxError::VentSilencer(_) => (
+                "E124",
+                "error.vent_silencer",
+                "Steam vent silencer estimate failed",
+            ),
+            ToolboxError::SteamBlow(_) => {
+                ("E125", "error.steam_blow", "Steam blow calculation failed")
+            }
+            ToolboxError::ReliefScenario(_) => (
+                "E126",
+                "error.relief_scenario",
+                "Relief scenario evaluation failed",
+            ),
+            ToolboxError::FireCase(_) => {
+                ("E127", "error.fire_case", "Fire case calculation failed")
+            }
+            ToolboxError::ReturnLine(_) => (
+                "E201",
+                "error.return_line",
+                "Condensate return line calculation failed",
+            ),
+            ToolboxError::PipeNetwork(_) => (
+                "E202",
+                "error.pipe_network",
+                "Pipe network calculation failed",
+            ),
+            ToolboxError::ExpansionLoop(_) => (
+                "E203",
+                "error.expansion_loop",
+                "Expansion loop calculation failed",
+            ),
+            ToolboxError::FlowMeter(_) => (
+                "E204",
+                "error.flow_meter",
+                "Flow meter loss calculation failed",
+            ),
+            ToolboxError::WallThickness(_) => (
+                "E205",
+                "error.wall_thickness",
+                "Wall thickness calculation failed",
+            ),
+            ToolboxError::PressureTest(_) => (
+                "E206",
+                "error.pressure_test",
+                "Pressure test calculation failed",
+            ),
+            ToolboxError::LineFill(_) => {
+                ("E207", "error.line_fill", "Line fill calculation failed")
+            }
+            ToolboxError::PipeClass(_) => ("E208", "error.pipe_class", "Piping class check failed"),
+            ToolboxError::Receiver(_) => (
+                "E209",
+                "error.receiver",
+                "Condensate receiver sizing failed",
+            ),
+            ToolboxError::Cooling(_) => ("E301", "error.cooling", "Cooling calculation failed"),
+            ToolboxError::EvaporativeCooler(_) => (
+                "E302",
+                "error.evaporative_cooler",
+                "Evaporative cooler calculation failed",
+            ),
+            ToolboxError::FreezeProtection(_) => (
+                "E303",
+                "error.freeze_protection",
+                "Freeze protection calculation failed",
+            ),
+            ToolboxError::Blowdown(_) => {
+                ("E304", "error.blowdown", "Gas blowdown calculation failed")
+            }
+            ToolboxError::Aiv(_) => (
+                "E306",
+                "error.aiv",
+                "Acoustic-induced vibration screening failed",
+            ),
+            ToolboxError::GasProperties(_) => (
+                "E305",
+                "error.gas_properties",
+                "Gas property calculation failed",
+            ),
+            ToolboxError::GasThrottling(_) => (
+                "E307",
+                "error.gas_throttling",
+                "Gas throttling calculation failed",
+            ),
+            ToolboxError::Compressor(_) => {
+                ("E308", "error.compressor", "Compressor calculation failed")
+            }
+            ToolboxError::AirPreheater(_) => (
+                "E309",
+                "error.air_preheater",
+                "Air preheater calculation failed",
+            ),
+            ToolboxError::DewPoint(_) => (
+                "E310",
+                "error.dew_point",
+                "Flue gas dew point calculation failed",
+            ),
+            ToolboxError::Emissions(_) => {
+                ("E311", "error.emissions", "Emissions calculation failed")
+            }
+            ToolboxError::Config(_) => ("E401", "error.config", "Configuration error"),
+            ToolboxError::Conversion(_) => ("E402", "error.conversion", "Unit conversion failed"),
+            ToolboxError::CustomUnit(_) => {
+                ("E403", "error.custom_unit", "Custom unit definition error")
+            }
+            ToolboxError::FlowUnit(_) => (
+                "E404",
+                "error.flow_unit",
+                "Flow/power unit conversion failed",
+            ),
+            ToolboxError::Sweep(_) => ("E405", "error.sweep", "Parameter sweep failed"),
+            ToolboxError::GoalSeek(_) => ("E406", "error.goal_seek", "Goal seek failed"),
+            ToolboxError::QuantityArg(_) => (
+                "E407",
+                "error.quantity_arg",
+                "Invalid value or unit argument",
+            ),
+            ToolboxError::TablePaste(_) => ("E408", "error.table_paste", "Pasted table is invalid"),
+            ToolboxError::ValveDb(_) => ("E409", "error.valve_db", "Valve database error"),
+            ToolboxError::VesselGeometry(_) => {
+                ("E410", "error.vessel_geometry", "Vessel geometry error")
+            }
+            ToolboxError::StrappingTable(_) => (
+                "E411",
+                "error.strapping_table",
+                "Strapping table generation failed",
+            ),
+            ToolboxError::Io(_) => ("E501", "error.io", "File I/O error"),
+            ToolboxError::Unimplemented(_) => ("E900", "error.unimplemented", "Not implemented"),
+        }
+    }
+
+    /// 안정적인 오류 코드 (예: "E101"). 로그/지원 문의에서 언어와 무관하게 쓴다.
+    pub fn code(&self) -> &'static str {
+        self.meta().0
+    }
+
+    /// 오류 종류 요약 문구의 i18n 키 (예: "error.steam_table").
+    pub fn i18n_key(&self) -> &'static str {
+        self.meta().1
+    }
+
+    /// "[코드] 번역된 요약: 상세" 형식의 사용자 표시 문자열.
+    pub fn localized(&self, tr: &Translator) -> String {
+        let (code, key, fallback) = self.meta();
+        let summary = tr.lookup(key).unwrap_or_else(|| fallback.to_string());
+        format!("[{code}] {summary}: {self}")
+    }
+}
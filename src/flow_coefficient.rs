@@ -0,0 +1,98 @@
+//! 밸브 유량계수 환산 (Kv, Cv(US), Cv(UK), Av).
+//! 모두 "기준 차압에서 흐르는 기준 밀도 물의 유량"이므로 서로 상수배 관계다.
+//! - Kv: 1 bar 차압에서 m³/h
+//! - Cv(US): 1 psi 차압에서 US gpm
+//! - Cv(UK): 1 psi 차압에서 Imperial gpm
+//! - Av: Q[m³/s] = Av·√(ΔP[Pa]/ρ[kg/m³]) 인 SI 계수 [m²]
+//!
+//! 유량 ∝ √ΔP 이므로 Kv/Cv = (gal/min → m³/h) × √(1 bar / 1 psi) = 0.227 125 × 3.808 38.
+
+use serde::{Deserialize, Serialize};
+
+/// Cv(US) 1당 Kv
+pub const KV_PER_CV_US: f64 = 0.864_978;
+/// Cv(UK) 1당 Kv (Imperial gallon 4.546 09 L 기준)
+pub const KV_PER_CV_UK: f64 = 1.038_795;
+/// Kv 1당 Av [m²] = (1/3600)·√(1000 kg/m³ / 1e5 Pa)
+pub const AV_PER_KV: f64 = 2.777_778e-5;
+
+/// 유량계수 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowCoefficientKind {
+    Kv,
+    CvUs,
+    CvUk,
+    Av,
+}
+
+impl FlowCoefficientKind {
+    /// 콤보/목록 순서
+    pub const ALL: [FlowCoefficientKind; 4] = [
+        FlowCoefficientKind::CvUs,
+        FlowCoefficientKind::CvUk,
+        FlowCoefficientKind::Kv,
+        FlowCoefficientKind::Av,
+    ];
+
+    /// 표시 라벨
+    pub fn label(self) -> &'static str {
+        match self {
+            FlowCoefficientKind::Kv => "Kv",
+            FlowCoefficientKind::CvUs => "Cv(US)",
+            FlowCoefficientKind::CvUk => "Cv(UK)",
+            FlowCoefficientKind::Av => "Av [m²]",
+        }
+    }
+
+    /// 표 머리글 등에 쓰는 짧은 기호 (Kv/Cv/Av)
+    pub fn symbol(self) -> &'static str {
+        match self {
+            FlowCoefficientKind::Kv => "Kv",
+            FlowCoefficientKind::CvUs | FlowCoefficientKind::CvUk => "Cv",
+            FlowCoefficientKind::Av => "Av",
+        }
+    }
+
+    /// 코드 문자열에서 종류를 찾는다 (`kv`, `cv`/`cv(us)`/`cvus`, `cv(uk)`/`cvuk`, `av`).
+    /// 접미사 없는 `cv`는 US로 본다.
+    pub fn from_code(code: &str) -> Option<Self> {
+        let key: String = code
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '(' | ')' | '_' | '-'))
+            .flat_map(char::to_lowercase)
+            .collect();
+        match key.as_str() {
+            "kv" => Some(FlowCoefficientKind::Kv),
+            "cv" | "cvus" => Some(FlowCoefficientKind::CvUs),
+            "cvuk" => Some(FlowCoefficientKind::CvUk),
+            "av" | "av[m²]" | "av[m2]" => Some(FlowCoefficientKind::Av),
+            _ => None,
+        }
+    }
+
+    /// 이 종류 1당 Kv
+    fn kv_per_unit(self) -> f64 {
+        match self {
+            FlowCoefficientKind::Kv => 1.0,
+            FlowCoefficientKind::CvUs => KV_PER_CV_US,
+            FlowCoefficientKind::CvUk => KV_PER_CV_UK,
+            FlowCoefficientKind::Av => 1.0 / AV_PER_KV,
+        }
+    }
+
+    /// 이 종류의 값을 Kv로 바꾼다.
+    pub fn to_kv(self, value: f64) -> f64 {
+        value * self.kv_per_unit()
+    }
+
+    /// Kv를 이 종류의 값으로 바꾼다.
+    pub fn from_kv(self, kv: f64) -> f64 {
+        kv / self.kv_per_unit()
+    }
+}
+
+/// `from` 종류의 유량계수를 `to` 종류로 바꾼다.
+pub fn convert(value: f64, from: FlowCoefficientKind, to: FlowCoefficientKind) -> f64 {
+    to.from_kv(from.to_kv(value))
+}
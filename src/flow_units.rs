@@ -0,0 +1,183 @@
+//! 질량 유량/체적 유량/동력/밀도·비체적·비열 단위 변환.
+//! `QuantityKind`에 없는 유량·동력·물성 계열을 단위 코드 문자열로 변환한다.
+//! 내부 기준은 kg/s, m3/s, W, kg/m3, m3/kg, J/(kg·K)이며 단위 코드는 대소문자를 구분하지 않는다.
+//!
+//! 질량·체적 유량은 본래 `QuantityKind`와 `conversion::convert`에 들어가야 하지만, 두 정의가 있는
+//! `quantity`/`conversion` 모듈 소스가 이 저장소에 없어 여기서 따로 다룬다. 두 모듈을 되살리면
+//! `MassFlow`/`VolumetricFlow`를 `QuantityKind`로 옮기고 `FlowKind`에서는 뺀다.
+
+use serde::{Deserialize, Serialize};
+
+/// 유량·동력·물성 물리량 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FlowKind {
+    MassFlow,
+    VolumetricFlow,
+    Power,
+    Density,
+    SpecificVolume,
+    SpecificHeat,
+}
+
+/// 질량 유량 단위 (표시명, 코드)
+pub const MASS_FLOW_UNITS: &[(&str, &str)] = &[
+    ("kg/h", "kg/h"),
+    ("t/h", "t/h"),
+    ("kg/s", "kg/s"),
+    ("lb/h", "lb/h"),
+    ("klb/h", "klb/h"),
+];
+
+/// 체적 유량 단위 (표시명, 코드)
+pub const VOLUMETRIC_FLOW_UNITS: &[(&str, &str)] = &[
+    ("m³/h", "m3/h"),
+    ("L/min", "l/min"),
+    ("gpm (US)", "gpm"),
+    ("cfm", "cfm"),
+];
+
+/// 동력/열량률 단위 (표시명, 코드)
+pub const POWER_UNITS: &[(&str, &str)] = &[
+    ("kW", "kW"),
+    ("MW", "MW"),
+    ("Btu/h", "Btu/h"),
+    ("kcal/h", "kcal/h"),
+    ("TR", "TR"),
+    ("BHP (boiler)", "BHP"),
+];
+
+/// 밀도 단위 (표시명, 코드)
+pub const DENSITY_UNITS: &[(&str, &str)] = &[
+    ("kg/m³", "kg/m3"),
+    ("g/cm³", "g/cm3"),
+    ("lb/ft³", "lb/ft3"),
+    ("lb/gal (US)", "lb/gal"),
+];
+
+/// 비체적 단위 (표시명, 코드)
+pub const SPECIFIC_VOLUME_UNITS: &[(&str, &str)] = &[
+    ("m³/kg", "m3/kg"),
+    ("L/kg", "l/kg"),
+    ("ft³/lb", "ft3/lb"),
+];
+
+/// 비열 단위 (표시명, 코드)
+pub const SPECIFIC_HEAT_UNITS: &[(&str, &str)] = &[
+    ("kJ/kg·K", "kJ/kgK"),
+    ("J/kg·K", "J/kgK"),
+    ("kcal/kg·K", "kcal/kgK"),
+    ("Btu/lb·°F", "Btu/lb-F"),
+];
+
+const LB_KG: f64 = 0.453_592_37;
+const US_GALLON_M3: f64 = 3.785_411_784e-3;
+const CUBIC_FOOT_M3: f64 = 0.028_316_846_592;
+/// IT Btu [J]
+const BTU_J: f64 = 1_055.055_852_62;
+/// IT kcal [J]
+const KCAL_J: f64 = 4_186.8;
+/// 냉동톤 (12 000 Btu/h) [W]
+const TON_REFRIGERATION_W: f64 = 12_000.0 * BTU_J / 3600.0;
+/// 보일러 마력 (100 °C 상당 증발 34.5 lb/h, 33 475 Btu/h) [W]
+const BOILER_HP_W: f64 = 33_475.0 * BTU_J / 3600.0;
+
+/// 유량/동력 단위 변환 오류.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlowUnitError {
+    UnknownUnit(String),
+}
+
+impl std::fmt::Display for FlowUnitError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlowUnitError::UnknownUnit(u) => write!(f, "지원하지 않는 단위: {u}"),
+        }
+    }
+}
+
+impl std::error::Error for FlowUnitError {}
+
+/// 단위 목록 (표시명, 코드).
+pub fn unit_options(kind: FlowKind) -> &'static [(&'static str, &'static str)] {
+    match kind {
+        FlowKind::MassFlow => MASS_FLOW_UNITS,
+        FlowKind::VolumetricFlow => VOLUMETRIC_FLOW_UNITS,
+        FlowKind::Power => POWER_UNITS,
+        FlowKind::Density => DENSITY_UNITS,
+        FlowKind::SpecificVolume => SPECIFIC_VOLUME_UNITS,
+        FlowKind::SpecificHeat => SPECIFIC_HEAT_UNITS,
+    }
+}
+
+/// 1 단위가 기준 단위(kg/s, m3/s, W, kg/m3, m3/kg, J/(kg·K))로 얼마인지.
+fn to_base_factor(kind: FlowKind, unit: &str) -> Option<f64> {
+    let u = unit.trim().to_ascii_lowercase();
+    let factor = match kind {
+        FlowKind::MassFlow => match u.as_str() {
+            "kg/s" => 1.0,
+            "kg/h" => 1.0 / 3600.0,
+            "t/h" | "ton/h" | "tonne/h" => 1000.0 / 3600.0,
+            "lb/h" => LB_KG / 3600.0,
+            "klb/h" => 1000.0 * LB_KG / 3600.0,
+            "lb/s" => LB_KG,
+            _ => return None,
+        },
+        FlowKind::VolumetricFlow => match u.as_str() {
+            "m3/s" => 1.0,
+            "m3/h" => 1.0 / 3600.0,
+            "l/min" | "lpm" => 1.0e-3 / 60.0,
+            "l/s" => 1.0e-3,
+            "gpm" => US_GALLON_M3 / 60.0,
+            "cfm" => CUBIC_FOOT_M3 / 60.0,
+            _ => return None,
+        },
+        FlowKind::Power => match u.as_str() {
+            "w" => 1.0,
+            "kw" => 1.0e3,
+            "mw" => 1.0e6,
+            "btu/h" => BTU_J / 3600.0,
+            "mmbtu/h" => BTU_J * 1.0e6 / 3600.0,
+            "kcal/h" => KCAL_J / 3600.0,
+            "gcal/h" => KCAL_J * 1.0e6 / 3600.0,
+            "tr" | "rt" => TON_REFRIGERATION_W,
+            "bhp" | "boiler hp" => BOILER_HP_W,
+            _ => return None,
+        },
+        FlowKind::Density => match u.as_str() {
+            "kg/m3" => 1.0,
+            "g/cm3" | "kg/l" | "g/ml" => 1000.0,
+            "lb/ft3" => LB_KG / CUBIC_FOOT_M3,
+            "lb/gal" => LB_KG / US_GALLON_M3,
+            _ => return None,
+        },
+        FlowKind::SpecificVolume => match u.as_str() {
+            "m3/kg" => 1.0,
+            "l/kg" | "cm3/g" => 1.0e-3,
+            "ft3/lb" => CUBIC_FOOT_M3 / LB_KG,
+            _ => return None,
+        },
+        // IT Btu/(lb·°F)와 IT kcal/(kg·K)는 모두 4186.8 J/(kg·K)
+        FlowKind::SpecificHeat => match u.as_str() {
+            "j/kgk" | "j/kg-k" => 1.0,
+            "kj/kgk" | "kj/kg-k" => 1.0e3,
+            "kcal/kgk" | "kcal/kg-k" | "btu/lb-f" => KCAL_J,
+            _ => return None,
+        },
+    };
+    Some(factor)
+}
+
+/// 해당 종류에서 인식하는 단위 코드인지.
+pub fn is_unit(kind: FlowKind, unit: &str) -> bool {
+    to_base_factor(kind, unit).is_some()
+}
+
+/// `from` 단위의 값을 `to` 단위로 변환한다.
+pub fn convert(kind: FlowKind, value: f64, from: &str, to: &str) -> Result<f64, FlowUnitError> {
+    let f_from =
+        to_base_factor(kind, from).ok_or_else(|| FlowUnitError::UnknownUnit(from.to_string()))?;
+    let f_to =
+        to_base_factor(kind, to).ok_or_else(|| FlowUnitError::UnknownUnit(to.to_string()))?;
+    Ok(value * f_from / f_to)
+}
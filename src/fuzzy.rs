@@ -0,0 +1,70 @@
+//! 명령 팔레트 등에서 쓰는 퍼지 문자열 검색.
+//! 질의 글자가 후보 안에 순서대로 모두 나오면 일치로 보고,
+//! 단어 첫머리·연속 일치·단어 앞부분 통째 일치에 가점을 주어 순위를 매긴다.
+
+/// 단어 첫머리 글자 일치 가점
+const WORD_START_BONUS: u32 = 8;
+/// 바로 앞 글자에 이어지는 일치 가점
+const CONSECUTIVE_BONUS: u32 = 5;
+/// 질의가 후보의 어느 단어 첫머리부터 통째로 들어 있을 때의 가점
+const WORD_PREFIX_BONUS: u32 = 20;
+
+/// 질의와 후보의 일치 점수. 일치하지 않으면 `None`이고, 클수록 더 잘 맞는다.
+/// 대소문자와 질의의 공백은 무시한다. 빈 질의는 모든 후보와 0점으로 일치한다.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let query: Vec<char> = query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect();
+    if query.is_empty() {
+        return Some(0);
+    }
+    let text: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+
+    let mut score = 0;
+    let mut next = 0;
+    let mut last: Option<usize> = None;
+    for (i, &ch) in text.iter().enumerate() {
+        if next == query.len() {
+            break;
+        }
+        if ch != query[next] {
+            continue;
+        }
+        score += 1;
+        if i == 0 || !text[i - 1].is_alphanumeric() {
+            score += WORD_START_BONUS;
+        }
+        if last.is_some_and(|l| l + 1 == i) {
+            score += CONSECUTIVE_BONUS;
+        }
+        last = Some(i);
+        next += 1;
+    }
+    if next < query.len() {
+        return None;
+    }
+    let word_prefix = text
+        .windows(query.len())
+        .enumerate()
+        .any(|(i, w)| w == query.as_slice() && (i == 0 || !text[i - 1].is_alphanumeric()));
+    if word_prefix {
+        score += WORD_PREFIX_BONUS;
+    }
+    Some(score)
+}
+
+/// 일치하는 후보의 색인을 점수 높은 순으로 돌려준다. 같은 점수는 원래 순서를 지킨다.
+pub fn rank<'a, I>(query: &str, candidates: I) -> Vec<usize>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let mut hits: Vec<(usize, u32)> = candidates
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, c)| fuzzy_score(query, c).map(|s| (i, s)))
+        .collect();
+    hits.sort_by_key(|&(_, score)| std::cmp::Reverse(score));
+    hits.into_iter().map(|(i, _)| i).collect()
+}
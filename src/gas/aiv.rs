@@ -0,0 +1,201 @@
+//! 고차압 가스/증기 감압부(PRV, 제한 오리피스, blowdown 밸브)의 음향 유발 진동(AIV) 선별 검토.
+//! 음향 파워 레벨은 Carucci-Mueller 식
+//! PWL = 10·log10[((P1−P2)/P1)^3.6 · W² · (T1/Mw)^1.2] + 126.1 [dB] (W [kg/s], T1 [K])로 구하고,
+//! 하류 주관 D/t에 대한 허용선(Eisinger의 Carucci-Mueller 한계선) PWL_lim = 173.6 − 0.125·D/t와 비교한다.
+//! PWL이 155 dB 미만이면 Energy Institute 지침에 따라 추가 검토가 필요 없는 것으로 본다.
+//! 하류 배관의 PWL은 배관 직경당 0.06 dB씩 감쇠한다고 보고 검토가 필요한 구간 길이를 추정한다.
+//! 분기 연결부 형상과 응력집중은 다루지 않으므로 선별(screening)용이다.
+
+/// 이 PWL 미만이면 AIV 검토가 필요 없다 [dB].
+pub const SCREENING_PWL_DB: f64 = 155.0;
+/// 하류 배관 직경당 PWL 감쇠 [dB/D].
+pub const ATTENUATION_DB_PER_DIAMETER: f64 = 0.06;
+/// 증기 분자량 [kg/kmol]
+pub const STEAM_MOLAR_MASS: f64 = 18.015;
+/// 이보다 두꺼운 배관(D/t 미만)은 두께 보강안으로 보지 않는다.
+const MIN_PRACTICAL_D_OVER_T: f64 = 10.0;
+
+/// AIV 위험 등급.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AivRisk {
+    /// PWL < 155 dB
+    Low,
+    /// 155 dB 이상이지만 D/t 허용선 미만
+    Medium,
+    /// D/t 허용선 이상
+    High,
+}
+
+impl AivRisk {
+    pub fn label(self) -> &'static str {
+        match self {
+            AivRisk::Low => "Low",
+            AivRisk::Medium => "Medium",
+            AivRisk::High => "High",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            AivRisk::Low => "aiv_risk.low",
+            AivRisk::Medium => "aiv_risk.medium",
+            AivRisk::High => "aiv_risk.high",
+        }
+    }
+}
+
+/// AIV 선별 입력.
+#[derive(Debug, Clone)]
+pub struct AivInput {
+    /// 감압부 상류 압력 [bar abs]
+    pub upstream_pressure_bar_abs: f64,
+    /// 감압부 하류 압력 [bar abs]
+    pub downstream_pressure_bar_abs: f64,
+    /// 상류 온도 [°C]
+    pub upstream_temp_c: f64,
+    /// 질량유량 [kg/h]
+    pub mass_flow_kg_per_h: f64,
+    /// 분자량 [kg/kmol] (증기 18.015)
+    pub molar_mass_kg_per_kmol: f64,
+    /// 하류 주관 외경 [m]
+    pub outer_diameter_m: f64,
+    /// 하류 주관 두께 [m]
+    pub wall_thickness_m: f64,
+}
+
+/// AIV 선별 결과.
+#[derive(Debug, Clone)]
+pub struct AivResult {
+    /// 음향 파워 레벨 [dB]
+    pub sound_power_db: f64,
+    pub d_over_t: f64,
+    /// D/t 허용선 [dB]
+    pub limit_db: f64,
+    /// 허용선 − PWL [dB]. 음수면 초과.
+    pub margin_db: f64,
+    pub risk: AivRisk,
+    /// PWL이 155 dB 아래로 감쇠할 때까지의 하류 길이 [m]
+    pub screening_length_m: f64,
+    /// 허용선을 맞추는 최소 두께 [m]. 위험이 낮거나 두께로 해결할 수 없으면 `None`.
+    pub required_thickness_m: Option<f64>,
+    /// 권고 사항
+    pub recommendations: Vec<String>,
+}
+
+/// AIV 선별 오류.
+#[derive(Debug, Clone)]
+pub enum AivError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for AivError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AivError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AivError {}
+
+/// Carucci-Mueller 음향 파워 레벨 [dB].
+pub fn sound_power_level_db(
+    upstream_pressure_bar_abs: f64,
+    downstream_pressure_bar_abs: f64,
+    mass_flow_kg_per_s: f64,
+    upstream_temp_k: f64,
+    molar_mass_kg_per_kmol: f64,
+) -> f64 {
+    let dp_ratio =
+        (upstream_pressure_bar_abs - downstream_pressure_bar_abs) / upstream_pressure_bar_abs;
+    10.0 * (dp_ratio.powf(3.6)
+        * mass_flow_kg_per_s.powi(2)
+        * (upstream_temp_k / molar_mass_kg_per_kmol).powf(1.2))
+    .log10()
+        + 126.1
+}
+
+/// D/t에 대한 허용 PWL [dB].
+pub fn limit_sound_power_db(d_over_t: f64) -> f64 {
+    173.6 - 0.125 * d_over_t
+}
+
+/// 감압부 PWL을 계산하고 하류 배관 D/t로 AIV 위험을 선별한다.
+pub fn screen_aiv(input: &AivInput) -> Result<AivResult, AivError> {
+    let p1 = input.upstream_pressure_bar_abs;
+    let p2 = input.downstream_pressure_bar_abs;
+    if p1 <= 0.0 || p2 <= 0.0 || p2 >= p1 {
+        return Err(AivError::InvalidInput(
+            "하류 압력은 0보다 크고 상류 압력보다 낮아야 합니다.",
+        ));
+    }
+    if input.mass_flow_kg_per_h <= 0.0 || input.molar_mass_kg_per_kmol <= 0.0 {
+        return Err(AivError::InvalidInput("유량과 분자량은 0보다 커야 합니다."));
+    }
+    let d = input.outer_diameter_m;
+    let t = input.wall_thickness_m;
+    if d <= 0.0 || t <= 0.0 || 2.0 * t >= d {
+        return Err(AivError::InvalidInput(
+            "외경과 두께는 0보다 크고 두께는 외경의 절반 미만이어야 합니다.",
+        ));
+    }
+    let t1_k = input.upstream_temp_c + 273.15;
+    if t1_k <= 0.0 {
+        return Err(AivError::InvalidInput("온도가 절대영도 이하입니다."));
+    }
+
+    let pwl = sound_power_level_db(
+        p1,
+        p2,
+        input.mass_flow_kg_per_h / 3600.0,
+        t1_k,
+        input.molar_mass_kg_per_kmol,
+    );
+    let d_over_t = d / t;
+    let limit = limit_sound_power_db(d_over_t);
+    let margin = limit - pwl;
+    let risk = if pwl < SCREENING_PWL_DB {
+        AivRisk::Low
+    } else if margin > 0.0 {
+        AivRisk::Medium
+    } else {
+        AivRisk::High
+    };
+    let screening_length_m = ((pwl - SCREENING_PWL_DB) / ATTENUATION_DB_PER_DIAMETER).max(0.0) * d;
+
+    let mut recommendations = Vec::new();
+    let mut required_thickness_m = None;
+    match risk {
+        AivRisk::Low => {}
+        AivRisk::Medium => recommendations.push(format!(
+            "PWL {pwl:.1} dB가 선별 기준 {SCREENING_PWL_DB:.0} dB 이상입니다. 하류 {screening_length_m:.1} m 안의 소구경 분기와 용접 지지부는 보강형(sweepolet, 전둘레 패드)으로 하세요."
+        )),
+        AivRisk::High => {
+            // 173.6 − 0.125·D/t ≥ PWL → D/t ≤ (173.6 − PWL)/0.125
+            let max_d_over_t = (173.6 - pwl) / 0.125;
+            if max_d_over_t >= MIN_PRACTICAL_D_OVER_T {
+                let t_req = d / max_d_over_t;
+                required_thickness_m = Some(t_req);
+                recommendations.push(format!(
+                    "PWL {pwl:.1} dB가 D/t {d_over_t:.0} 허용선 {limit:.1} dB를 넘습니다. 하류 {screening_length_m:.1} m 구간의 두께를 {:.1} mm 이상(D/t ≤ {max_d_over_t:.0})으로 늘리세요.",
+                    t_req * 1000.0
+                ));
+            }
+            recommendations.push(format!(
+                "디퓨저(다공판)나 다단 감압으로 PWL을 {:.1} dB 이상 낮추세요.",
+                -margin
+            ));
+        }
+    }
+
+    Ok(AivResult {
+        sound_power_db: pwl,
+        d_over_t,
+        limit_db: limit,
+        margin_db: margin,
+        risk,
+        screening_length_m,
+        required_thickness_m,
+        recommendations,
+    })
+}
@@ -0,0 +1,307 @@
+//! 용기/헤더 감압(blowdown) 과도 계산.
+//! 이상기체(k, 분자량, Z) 용기가 오리피스 또는 밸브(Cv)를 통해 배압으로 방출될 때
+//! 초킹 → 아음속 전환을 포함한 압력-시간 곡선과 전체 감압 시간을 구한다.
+//! 단열(등엔트로피 팽창, 짧은 방출) 또는 등온(벽 열공급이 충분한 긴 방출) 과정을 선택한다.
+//! 시간은 t = ∫ dp / (−dp/dt)를 u = √(p − p_b)로 치환해 적분하므로 배압까지 감압해도 유한하다.
+
+use crate::flow_coefficient::FlowCoefficientKind;
+use crate::steam::critical_flow::ideal_gas_critical_pressure_ratio;
+
+/// 일반기체 상수 [J/(kmol·K)]
+const R_UNIVERSAL: f64 = 8_314.462_618;
+/// 액체 기준 Kv → 유효 면적 Cd·A [m²] 환산: Q = Cd·A·√(2ΔP/ρ), ΔP = 1 bar, ρ = 1000 kg/m³
+const KV_TO_CDA_M2: f64 = 1.0 / 50_911.7;
+/// 적분 구간 수 (짝수)
+const SEGMENTS: usize = 2000;
+/// 출력 곡선 점 수
+const PROFILE_POINTS: usize = 50;
+/// 이 온도 미만이면 저온 취성(MDMT) 검토 경고 [°C]
+const LOW_TEMP_WARNING_C: f64 = -29.0;
+
+/// 방출 기체 물성.
+#[derive(Debug, Clone, Copy)]
+pub struct BlowdownGas {
+    /// 비열비
+    pub k: f64,
+    /// 분자량 [kg/kmol]
+    pub molar_mass_kg_per_kmol: f64,
+    /// 압축계수
+    pub z: f64,
+}
+
+impl BlowdownGas {
+    pub fn air() -> Self {
+        BlowdownGas {
+            k: 1.4,
+            molar_mass_kg_per_kmol: 28.96,
+            z: 1.0,
+        }
+    }
+
+    /// 과열 증기 근사 (k = 1.3). 포화선 아래로 팽창하면 응축은 고려하지 않는다.
+    pub fn superheated_steam() -> Self {
+        BlowdownGas {
+            k: 1.3,
+            molar_mass_kg_per_kmol: 18.015,
+            z: 1.0,
+        }
+    }
+}
+
+/// 감압 중 용기 내 열역학 과정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlowdownProcess {
+    /// 단열: T = T0·(p/p0)^((k−1)/k)
+    Adiabatic,
+    /// 등온: T = T0
+    Isothermal,
+}
+
+/// 방출 제한 요소.
+#[derive(Debug, Clone, Copy)]
+pub enum BlowdownRestriction {
+    /// 오리피스 지름 [m]과 유량계수
+    Orifice {
+        diameter_m: f64,
+        discharge_coefficient: f64,
+    },
+    /// 밸브 Cv. 액체 기준 등가 면적으로 환산하며 밸브 xT 초킹 보정은 하지 않는다.
+    ValveCv { cv: f64 },
+}
+
+impl BlowdownRestriction {
+    /// 유효 면적 Cd·A [m²]
+    pub fn effective_area_m2(&self) -> f64 {
+        match *self {
+            BlowdownRestriction::Orifice {
+                diameter_m,
+                discharge_coefficient,
+            } => discharge_coefficient * std::f64::consts::PI * diameter_m.powi(2) / 4.0,
+            BlowdownRestriction::ValveCv { cv } => {
+                FlowCoefficientKind::CvUs.to_kv(cv) * KV_TO_CDA_M2
+            }
+        }
+    }
+}
+
+/// 감압 계산 입력.
+#[derive(Debug, Clone)]
+pub struct BlowdownInput {
+    /// 용기(+ 헤더) 내부 체적 [m³]
+    pub volume_m3: f64,
+    pub initial_pressure_bar_abs: f64,
+    pub initial_temp_c: f64,
+    /// 배압 [bar abs]. 대기 방출이면 1.01325.
+    pub back_pressure_bar_abs: f64,
+    /// 감압 종료 압력 [bar abs] (배압 이상). 배압과 같으면 완전 감압 시간.
+    pub end_pressure_bar_abs: f64,
+    pub gas: BlowdownGas,
+    pub process: BlowdownProcess,
+    pub restriction: BlowdownRestriction,
+}
+
+/// 압력-시간 곡선의 한 점.
+#[derive(Debug, Clone, Copy)]
+pub struct BlowdownPoint {
+    pub time_s: f64,
+    pub pressure_bar_abs: f64,
+    pub temperature_c: f64,
+    pub mass_flow_kg_per_s: f64,
+    pub choked: bool,
+}
+
+/// 감압 계산 결과.
+#[derive(Debug, Clone)]
+pub struct BlowdownResult {
+    pub initial_mass_kg: f64,
+    pub final_mass_kg: f64,
+    /// 초기(최대) 방출 유량 [kg/s]
+    pub initial_mass_flow_kg_per_s: f64,
+    /// 초킹이 풀리는 용기 압력 [bar abs] = 배압 / 임계 압력비
+    pub unchoke_pressure_bar_abs: f64,
+    /// 초킹 유지 시간 [s]
+    pub choked_duration_s: f64,
+    /// 종료 압력까지 전체 시간 [s]
+    pub total_duration_s: f64,
+    pub final_temperature_c: f64,
+    pub profile: Vec<BlowdownPoint>,
+    pub warnings: Vec<String>,
+}
+
+/// 감압 계산 오류.
+#[derive(Debug, Clone)]
+pub enum BlowdownError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for BlowdownError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BlowdownError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for BlowdownError {}
+
+/// 이상기체 오리피스 질량유속 [kg/m²·s]와 초킹 여부. 압력 [Pa], 온도 [K].
+fn gas_mass_flux(p_pa: f64, t_k: f64, pb_pa: f64, gas: &BlowdownGas, r: f64) -> (f64, bool) {
+    let k = gas.k;
+    let zrt = gas.z * r * t_k;
+    let ratio = pb_pa / p_pa;
+    if ratio <= ideal_gas_critical_pressure_ratio(k) {
+        let flux = p_pa * (k / zrt).sqrt() * (2.0 / (k + 1.0)).powf((k + 1.0) / (2.0 * (k - 1.0)));
+        (flux, true)
+    } else if ratio >= 1.0 {
+        (0.0, false)
+    } else {
+        let term = ratio.powf(2.0 / k) - ratio.powf((k + 1.0) / k);
+        (p_pa * (2.0 * k / ((k - 1.0) * zrt) * term).sqrt(), false)
+    }
+}
+
+/// 용기 감압 압력-시간 곡선과 감압 시간을 계산한다.
+pub fn compute_blowdown(input: BlowdownInput) -> Result<BlowdownResult, BlowdownError> {
+    let gas = input.gas;
+    if input.volume_m3 <= 0.0 {
+        return Err(BlowdownError::InvalidInput("체적은 0보다 커야 합니다."));
+    }
+    if gas.k <= 1.0 || gas.molar_mass_kg_per_kmol <= 0.0 || gas.z <= 0.0 {
+        return Err(BlowdownError::InvalidInput(
+            "k는 1보다, 분자량과 Z는 0보다 커야 합니다.",
+        ));
+    }
+    let t0_k = input.initial_temp_c + 273.15;
+    if t0_k <= 0.0 {
+        return Err(BlowdownError::InvalidInput(
+            "초기 온도는 절대영도보다 높아야 합니다.",
+        ));
+    }
+    let p0 = input.initial_pressure_bar_abs;
+    let pb = input.back_pressure_bar_abs;
+    let p_end = input.end_pressure_bar_abs;
+    if pb < 0.0 || p0 <= pb {
+        return Err(BlowdownError::InvalidInput(
+            "초기 압력은 배압보다 높아야 합니다.",
+        ));
+    }
+    if p_end < pb || p_end <= 0.0 || p_end >= p0 {
+        return Err(BlowdownError::InvalidInput(
+            "종료 압력은 0보다 크고 배압 이상, 초기 압력 미만이어야 합니다.",
+        ));
+    }
+    let cda = input.restriction.effective_area_m2();
+    if cda.is_nan() || cda <= 0.0 {
+        return Err(BlowdownError::InvalidInput(
+            "오리피스 지름/유량계수 또는 Cv는 0보다 커야 합니다.",
+        ));
+    }
+
+    let r = R_UNIVERSAL / gas.molar_mass_kg_per_kmol;
+    let k = gas.k;
+    let v = input.volume_m3;
+    let temp_k = |p: f64| match input.process {
+        BlowdownProcess::Adiabatic => t0_k * (p / p0).powf((k - 1.0) / k),
+        BlowdownProcess::Isothermal => t0_k,
+    };
+    let mass = |p: f64| p * 1.0e5 * v / (gas.z * r * temp_k(p));
+    // 용기 압력 p [bar abs]에서 (유량 [kg/s], −dp/dt [bar/s], 초킹)
+    let state = |p: f64| {
+        let (flux, choked) = gas_mass_flux(p * 1.0e5, temp_k(p), pb * 1.0e5, &gas, r);
+        let w = cda * flux;
+        let dm_dp = match input.process {
+            BlowdownProcess::Adiabatic => mass(p) / (k * p),
+            BlowdownProcess::Isothermal => mass(p) / p,
+        };
+        (w, w / dm_dp, choked)
+    };
+    // u = √(p − pb): dt = 2u du / (−dp/dt). u → 0에서 −dp/dt ∝ u 이므로 극한값을 쓴다.
+    let integrand = |u: f64| {
+        if u <= 0.0 {
+            let eps = 1.0e-6 * (p0 - pb);
+            let (_, rate, _) = state(pb + eps);
+            2.0 * eps.sqrt() / rate
+        } else {
+            let (_, rate, _) = state(pb + u * u);
+            2.0 * u / rate
+        }
+    };
+
+    let u0 = (p0 - pb).sqrt();
+    let u_end = (p_end - pb).sqrt();
+    let hu = (u0 - u_end) / SEGMENTS as f64;
+    // u0에서 u_end 방향으로 Simpson 구간을 누적한다.
+    let mut times = Vec::with_capacity(SEGMENTS / 2 + 1);
+    let mut t = 0.0;
+    times.push((p0, 0.0));
+    for i in (0..SEGMENTS).step_by(2) {
+        let ua = u0 - i as f64 * hu;
+        let um = ua - hu;
+        let ub = ua - 2.0 * hu;
+        t += hu / 3.0 * (integrand(ua) + 4.0 * integrand(um) + integrand(ub.max(0.0)));
+        times.push((pb + ub.max(0.0).powi(2), t));
+    }
+    let total = t;
+
+    let rc = ideal_gas_critical_pressure_ratio(k);
+    let p_unchoke = pb / rc;
+    let choked_duration = if p_unchoke >= p0 {
+        0.0
+    } else if p_unchoke <= p_end {
+        total
+    } else {
+        // 누적 시간 표에서 선형 보간
+        let idx = times
+            .iter()
+            .position(|&(p, _)| p <= p_unchoke)
+            .unwrap_or(times.len() - 1);
+        let (pa, ta) = times[idx.saturating_sub(1)];
+        let (pb_, tb) = times[idx];
+        if (pa - pb_).abs() < f64::EPSILON {
+            tb
+        } else {
+            ta + (tb - ta) * (pa - p_unchoke) / (pa - pb_)
+        }
+    };
+
+    let step = (times.len() - 1).div_ceil(PROFILE_POINTS).max(1);
+    let mut profile: Vec<BlowdownPoint> = times
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| i % step == 0 || *i == times.len() - 1)
+        .map(|(_, &(p, time_s))| {
+            let (w, _, choked) = state(p);
+            BlowdownPoint {
+                time_s,
+                pressure_bar_abs: p,
+                temperature_c: temp_k(p) - 273.15,
+                mass_flow_kg_per_s: w,
+                choked,
+            }
+        })
+        .collect();
+    profile.dedup_by(|a, b| a.time_s == b.time_s);
+
+    let final_temp_c = temp_k(p_end) - 273.15;
+    let mut warnings = Vec::new();
+    if input.process == BlowdownProcess::Adiabatic && final_temp_c < LOW_TEMP_WARNING_C {
+        warnings.push(format!(
+            "단열 팽창으로 용기 내 온도가 {final_temp_c:.0} °C까지 내려갑니다. 재질 최저 설계 금속 온도(MDMT)를 확인하세요."
+        ));
+    }
+    if gas.molar_mass_kg_per_kmol < 20.0 {
+        warnings.push("증기는 팽창 중 응축할 수 있어 이상기체 결과는 근사입니다.".into());
+    }
+
+    Ok(BlowdownResult {
+        initial_mass_kg: mass(p0),
+        final_mass_kg: mass(p_end),
+        initial_mass_flow_kg_per_s: state(p0).0,
+        unchoke_pressure_bar_abs: p_unchoke,
+        choked_duration_s: choked_duration,
+        total_duration_s: total,
+        final_temperature_c: final_temp_c,
+        profile,
+        warnings,
+    })
+}
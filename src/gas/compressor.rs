@@ -0,0 +1,347 @@
+//! 가스 압축기 동력과 토출 온도 추정 (공기, 연료가스 등).
+//! 단마다 흡입 상태의 실제기체 물성(`gas_properties`)으로 비열비 k와 평균 압축계수 Z를 잡고,
+//! 등엔트로피 또는 폴리트로픽 헤드 H = Z·R·T₁/M · m/(m−1) · (r^((m−1)/m) − 1)로 동력을 구한다.
+//! 다단 압축은 단마다 압력비를 같게 나누고, 단 사이 중간 냉각기에서 지정 온도까지 식힌다고 본다.
+
+use crate::gas::gas_properties::{self, Gas, GasPropertiesError};
+use crate::warning::{CalcWarning, Severity};
+
+/// 일반기체 상수 [J/(kmol·K)]
+const R_UNIVERSAL: f64 = 8_314.462_618;
+/// 자동 단수 선택의 최대 단수
+const MAX_STAGES: u32 = 8;
+/// 이보다 큰 단당 압력비는 경고한다 (왕복동/원심 한 단의 일반적 한계)
+const MAX_STAGE_RATIO: f64 = 4.5;
+
+/// 효율 기준.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CompressionModel {
+    /// 등엔트로피 효율 (0~1]
+    Isentropic { efficiency: f64 },
+    /// 폴리트로픽 효율 (0~1]
+    Polytropic { efficiency: f64 },
+}
+
+impl CompressionModel {
+    fn efficiency(self) -> f64 {
+        match self {
+            CompressionModel::Isentropic { efficiency }
+            | CompressionModel::Polytropic { efficiency } => efficiency,
+        }
+    }
+}
+
+/// 압축기 계산 입력.
+#[derive(Debug, Clone)]
+pub struct CompressorInput {
+    pub gas: Gas,
+    pub suction_pressure_bar_abs: f64,
+    pub suction_temp_c: f64,
+    pub discharge_pressure_bar_abs: f64,
+    pub mass_flow_kg_per_h: f64,
+    pub model: CompressionModel,
+    /// 단수. `None`이면 단 토출 온도가 `max_discharge_temp_c` 이하가 되는 최소 단수를 고른다.
+    pub stages: Option<u32>,
+    /// 중간 냉각기 출구(다음 단 흡입) 온도 [°C]
+    pub intercooler_outlet_temp_c: f64,
+    /// 단 토출 온도 한계 [°C] (자동 단수 선택과 경고에 쓴다)
+    pub max_discharge_temp_c: f64,
+    /// 기계/구동 효율 (축동력 = 가스 동력 / 효율)
+    pub mechanical_efficiency: f64,
+}
+
+/// 단 하나의 결과.
+#[derive(Debug, Clone)]
+pub struct CompressorStage {
+    pub suction_pressure_bar_abs: f64,
+    pub suction_temp_c: f64,
+    pub discharge_pressure_bar_abs: f64,
+    pub discharge_temp_c: f64,
+    pub pressure_ratio: f64,
+    /// 흡입 상태 비열비
+    pub k: f64,
+    /// 흡입/토출 평균 압축계수
+    pub z_avg: f64,
+    /// 폴리트로픽(또는 등엔트로피) 헤드 [kJ/kg]
+    pub head_kj_per_kg: f64,
+    /// 가스 동력 [kW]
+    pub gas_power_kw: f64,
+}
+
+/// 압축기 계산 결과.
+#[derive(Debug, Clone)]
+pub struct CompressorResult {
+    pub stages: Vec<CompressorStage>,
+    pub total_gas_power_kw: f64,
+    pub shaft_power_kw: f64,
+    /// 마지막 단 토출 온도 [°C]
+    pub discharge_temp_c: f64,
+    /// 중간 냉각기 열량 합계 [kW] (마지막 단 뒤 후냉각기는 제외)
+    pub intercooler_duty_kw: f64,
+    /// 흡입 체적 유량 [m³/min]
+    pub suction_volume_flow_m3_per_min: f64,
+    /// 흡입 체적 유량 기준 비동력 [kW/(m³/min)]
+    pub specific_power_kw_per_m3_per_min: f64,
+    pub warnings: Vec<CompressorWarning>,
+}
+
+/// 압축기 계산 경고. `stage`는 1부터 센다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CompressorWarning {
+    /// 단 토출 온도가 한계를 넘음
+    DischargeTempHigh {
+        stage: usize,
+        temp_c: f64,
+        max_c: f64,
+    },
+    /// 단 압력비가 일반적인 한계를 넘음
+    StageRatioHigh { stage: usize, ratio: f64 },
+}
+
+impl CalcWarning for CompressorWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            CompressorWarning::DischargeTempHigh { .. } => "warning.compressor.discharge_temp_high",
+            CompressorWarning::StageRatioHigh { .. } => "warning.compressor.stage_ratio_high",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            CompressorWarning::DischargeTempHigh {
+                stage,
+                temp_c,
+                max_c,
+            } => vec![
+                ("stage", stage.to_string()),
+                ("temp", format!("{temp_c:.0}")),
+                ("max", format!("{max_c:.0}")),
+            ],
+            CompressorWarning::StageRatioHigh { stage, ratio } => vec![
+                ("stage", stage.to_string()),
+                ("ratio", format!("{ratio:.1}")),
+                ("max", format!("{MAX_STAGE_RATIO}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for CompressorWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            CompressorWarning::DischargeTempHigh {
+                stage,
+                temp_c,
+                max_c,
+            } => write!(
+                f,
+                "{stage}단 토출 온도 {temp_c:.0} °C가 한계 {max_c:.0} °C를 넘습니다. 단수를 늘리거나 중간 냉각을 강화하세요."
+            ),
+            CompressorWarning::StageRatioHigh { stage, ratio } => write!(
+                f,
+                "{stage}단 압력비 {ratio:.1}이 일반적인 단당 한계 {MAX_STAGE_RATIO}보다 큽니다."
+            ),
+        }
+    }
+}
+
+/// 압축기 계산 오류.
+#[derive(Debug, Clone)]
+pub enum CompressorError {
+    InvalidInput(&'static str),
+    Properties(GasPropertiesError),
+    /// 최대 단수로도 토출 온도 한계를 지킬 수 없음
+    TooManyStages {
+        limit_c: f64,
+    },
+}
+
+impl std::fmt::Display for CompressorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompressorError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            CompressorError::Properties(e) => write!(f, "{e}"),
+            CompressorError::TooManyStages { limit_c } => write!(
+                f,
+                "{MAX_STAGES}단으로도 토출 온도를 {limit_c:.0} °C 이하로 맞출 수 없습니다."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CompressorError {}
+
+impl From<GasPropertiesError> for CompressorError {
+    fn from(e: GasPropertiesError) -> Self {
+        CompressorError::Properties(e)
+    }
+}
+
+/// 단 하나를 계산한다. 토출 Z는 토출 상태에서 다시 구해 흡입 Z와 평균한다.
+fn compute_stage(
+    gas: Gas,
+    p1: f64,
+    t1_c: f64,
+    p2: f64,
+    mass_flow_kg_per_s: f64,
+    model: CompressionModel,
+) -> Result<CompressorStage, CompressorError> {
+    let suction = gas_properties::gas_properties(gas, p1, t1_c)?;
+    let k = suction.k;
+    let r = p2 / p1;
+    let t1_k = t1_c + 273.15;
+    let eta = model.efficiency();
+    // m/(m−1): 등엔트로피는 k/(k−1), 폴리트로픽은 η_p·k/(k−1)
+    let exponent_factor = match model {
+        CompressionModel::Isentropic { .. } => k / (k - 1.0),
+        CompressionModel::Polytropic { efficiency } => efficiency * k / (k - 1.0),
+    };
+    let ratio_term = r.powf(1.0 / exponent_factor) - 1.0;
+    let t2_k = match model {
+        CompressionModel::Isentropic { efficiency } => t1_k * (1.0 + ratio_term / efficiency),
+        CompressionModel::Polytropic { .. } => t1_k * (1.0 + ratio_term),
+    };
+    let discharge = gas_properties::gas_properties(gas, p2, t2_k - 273.15)?;
+    let z_avg = (suction.z + discharge.z) / 2.0;
+    let head_j_per_kg =
+        z_avg * R_UNIVERSAL / suction.molar_mass_kg_per_kmol * t1_k * exponent_factor * ratio_term;
+    Ok(CompressorStage {
+        suction_pressure_bar_abs: p1,
+        suction_temp_c: t1_c,
+        discharge_pressure_bar_abs: p2,
+        discharge_temp_c: t2_k - 273.15,
+        pressure_ratio: r,
+        k,
+        z_avg,
+        head_kj_per_kg: head_j_per_kg / 1000.0,
+        gas_power_kw: mass_flow_kg_per_s * head_j_per_kg / eta / 1000.0,
+    })
+}
+
+/// 단수 `n`으로 전체를 계산한다 (단마다 같은 압력비).
+fn compute_with_stages(
+    input: &CompressorInput,
+    n: u32,
+) -> Result<(Vec<CompressorStage>, f64), CompressorError> {
+    let m = input.mass_flow_kg_per_h / 3600.0;
+    let r_stage =
+        (input.discharge_pressure_bar_abs / input.suction_pressure_bar_abs).powf(1.0 / n as f64);
+    let mut stages = Vec::with_capacity(n as usize);
+    let mut intercooler_duty_kw = 0.0;
+    let mut p = input.suction_pressure_bar_abs;
+    let mut t = input.suction_temp_c;
+    for i in 0..n {
+        let p2 = if i + 1 == n {
+            input.discharge_pressure_bar_abs
+        } else {
+            p * r_stage
+        };
+        let stage = compute_stage(input.gas, p, t, p2, m, input.model)?;
+        if i + 1 < n {
+            // 중간 냉각: 토출 온도 → 냉각기 출구 온도 (평균 온도의 cp)
+            let t_mean = (stage.discharge_temp_c + input.intercooler_outlet_temp_c) / 2.0;
+            let cp = gas_properties::gas_properties(input.gas, p2, t_mean)?.cp_kj_per_kgk;
+            intercooler_duty_kw +=
+                m * cp * (stage.discharge_temp_c - input.intercooler_outlet_temp_c).max(0.0);
+            t = input.intercooler_outlet_temp_c;
+        }
+        p = p2;
+        stages.push(stage);
+    }
+    Ok((stages, intercooler_duty_kw))
+}
+
+/// 압축 동력, 토출 온도, 중간 냉각 단수를 계산한다.
+pub fn compute_compressor(input: CompressorInput) -> Result<CompressorResult, CompressorError> {
+    if input.suction_pressure_bar_abs <= 0.0 || input.suction_temp_c + 273.15 <= 0.0 {
+        return Err(CompressorError::InvalidInput(
+            "흡입 압력과 절대온도는 0보다 커야 합니다.",
+        ));
+    }
+    if input.discharge_pressure_bar_abs <= input.suction_pressure_bar_abs {
+        return Err(CompressorError::InvalidInput(
+            "토출 압력은 흡입 압력보다 높아야 합니다.",
+        ));
+    }
+    if input.mass_flow_kg_per_h <= 0.0 {
+        return Err(CompressorError::InvalidInput("유량은 0보다 커야 합니다."));
+    }
+    let eta = input.model.efficiency();
+    if !(eta > 0.0
+        && eta <= 1.0
+        && input.mechanical_efficiency > 0.0
+        && input.mechanical_efficiency <= 1.0)
+    {
+        return Err(CompressorError::InvalidInput(
+            "효율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if input.stages == Some(0) {
+        return Err(CompressorError::InvalidInput("단수는 1 이상이어야 합니다."));
+    }
+
+    let (stages, intercooler_duty_kw) = match input.stages {
+        Some(n) => compute_with_stages(&input, n)?,
+        None => {
+            let mut found = None;
+            for n in 1..=MAX_STAGES {
+                let (stages, duty) = compute_with_stages(&input, n)?;
+                if stages
+                    .iter()
+                    .all(|s| s.discharge_temp_c <= input.max_discharge_temp_c)
+                {
+                    found = Some((stages, duty));
+                    break;
+                }
+            }
+            found.ok_or(CompressorError::TooManyStages {
+                limit_c: input.max_discharge_temp_c,
+            })?
+        }
+    };
+
+    let total_gas_power_kw: f64 = stages.iter().map(|s| s.gas_power_kw).sum();
+    let discharge_temp_c = stages
+        .last()
+        .map_or(input.suction_temp_c, |s| s.discharge_temp_c);
+    let suction = gas_properties::gas_properties(
+        input.gas,
+        input.suction_pressure_bar_abs,
+        input.suction_temp_c,
+    )?;
+    let suction_volume_flow_m3_per_min =
+        input.mass_flow_kg_per_h / suction.density_kg_per_m3 / 60.0;
+    let shaft_power_kw = total_gas_power_kw / input.mechanical_efficiency;
+
+    let mut warnings = Vec::new();
+    for (i, s) in stages.iter().enumerate() {
+        if s.discharge_temp_c > input.max_discharge_temp_c {
+            warnings.push(CompressorWarning::DischargeTempHigh {
+                stage: i + 1,
+                temp_c: s.discharge_temp_c,
+                max_c: input.max_discharge_temp_c,
+            });
+        }
+        if s.pressure_ratio > MAX_STAGE_RATIO {
+            warnings.push(CompressorWarning::StageRatioHigh {
+                stage: i + 1,
+                ratio: s.pressure_ratio,
+            });
+        }
+    }
+
+    Ok(CompressorResult {
+        total_gas_power_kw,
+        shaft_power_kw,
+        discharge_temp_c,
+        intercooler_duty_kw,
+        suction_volume_flow_m3_per_min,
+        specific_power_kw_per_m3_per_min: shaft_power_kw / suction_volume_flow_m3_per_min,
+        stages,
+        warnings,
+    })
+}
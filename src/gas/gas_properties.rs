@@ -0,0 +1,364 @@
+//! 주요 산업용 가스(N₂, O₂, CO₂, H₂, CH₄, Ar, 공기, 천연가스) 물성 계산.
+//! 압축계수 Z와 밀도는 Peng-Robinson 상태식(증기 근), 정압비열은 이상기체 다항식(Cengel, 273~1800 K)에
+//! PR 잔여 엔탈피의 온도 미분을 더해 구하고, cv와 음속은 PR의 편미분으로 구한다.
+//! 점도는 Sutherland 식(저압값)이며 압력 보정은 하지 않는다.
+
+use crate::gas::blowdown::BlowdownGas;
+
+/// 일반기체 상수 [J/(mol·K)]
+const R: f64 = 8.314_462_618;
+const SQRT2: f64 = std::f64::consts::SQRT_2;
+/// 이상기체 비열 다항식 유효 범위 [K]
+const CP_VALID_RANGE_K: (f64, f64) = (273.0, 1800.0);
+/// 이보다 높은 환산 압력에서는 저압 점도가 과소 평가된다.
+const VISCOSITY_PR_LIMIT: f64 = 0.5;
+/// 엔탈피 기준 온도 [K] (이상기체 H = 0)
+const H_REF_T_K: f64 = 298.15;
+
+/// 지원 가스.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gas {
+    Nitrogen,
+    Oxygen,
+    CarbonDioxide,
+    Hydrogen,
+    Methane,
+    Argon,
+    Air,
+    /// 파이프라인 천연가스 (CH₄ 90%, C₂H₆ 7%, N₂ 3%, 비중 약 0.6)
+    NaturalGas,
+}
+
+/// 가스 상수 데이터.
+#[derive(Debug, Clone, Copy)]
+struct GasData {
+    /// 분자량 [kg/kmol]
+    molar_mass: f64,
+    /// 임계 온도 [K], 임계 압력 [bar], 이심 인자
+    tc_k: f64,
+    pc_bar: f64,
+    omega: f64,
+    /// 이상기체 cp = a + bT + cT² + dT³ [J/mol·K]
+    cp: [f64; 4],
+    /// Sutherland 점도 (기준 온도 [K], 기준 점도 [Pa·s], S [K])
+    sutherland: (f64, f64, f64),
+}
+
+impl Gas {
+    pub const ALL: [Gas; 8] = [
+        Gas::Nitrogen,
+        Gas::Oxygen,
+        Gas::CarbonDioxide,
+        Gas::Hydrogen,
+        Gas::Methane,
+        Gas::Argon,
+        Gas::Air,
+        Gas::NaturalGas,
+    ];
+
+    /// 화학식(공기는 "Air", 천연가스는 "NG").
+    pub fn formula(self) -> &'static str {
+        match self {
+            Gas::Nitrogen => "N₂",
+            Gas::Oxygen => "O₂",
+            Gas::CarbonDioxide => "CO₂",
+            Gas::Hydrogen => "H₂",
+            Gas::Methane => "CH₄",
+            Gas::Argon => "Ar",
+            Gas::Air => "Air",
+            Gas::NaturalGas => "NG",
+        }
+    }
+
+    pub fn molar_mass_kg_per_kmol(self) -> f64 {
+        self.data().molar_mass
+    }
+
+    fn data(self) -> GasData {
+        match self {
+            Gas::Nitrogen => GasData {
+                molar_mass: 28.013,
+                tc_k: 126.2,
+                pc_bar: 33.98,
+                omega: 0.037,
+                cp: [28.90, -0.1571e-2, 0.8081e-5, -2.873e-9],
+                sutherland: (300.55, 17.81e-6, 111.0),
+            },
+            Gas::Oxygen => GasData {
+                molar_mass: 31.999,
+                tc_k: 154.58,
+                pc_bar: 50.43,
+                omega: 0.022,
+                cp: [25.48, 1.520e-2, -0.7155e-5, 1.312e-9],
+                sutherland: (292.25, 20.18e-6, 127.0),
+            },
+            Gas::CarbonDioxide => GasData {
+                molar_mass: 44.01,
+                tc_k: 304.13,
+                pc_bar: 73.77,
+                omega: 0.225,
+                cp: [22.26, 5.981e-2, -3.501e-5, 7.469e-9],
+                sutherland: (293.15, 14.8e-6, 240.0),
+            },
+            Gas::Hydrogen => GasData {
+                molar_mass: 2.016,
+                tc_k: 33.19,
+                pc_bar: 13.13,
+                omega: -0.216,
+                cp: [29.11, -0.1916e-2, 0.4003e-5, -0.8704e-9],
+                sutherland: (293.85, 8.76e-6, 72.0),
+            },
+            Gas::Methane => GasData {
+                molar_mass: 16.043,
+                tc_k: 190.56,
+                pc_bar: 45.99,
+                omega: 0.011,
+                cp: [19.89, 5.024e-2, 1.269e-5, -11.01e-9],
+                sutherland: (293.15, 11.0e-6, 198.0),
+            },
+            Gas::Argon => GasData {
+                molar_mass: 39.948,
+                tc_k: 150.69,
+                pc_bar: 48.63,
+                omega: -0.002,
+                cp: [20.786, 0.0, 0.0, 0.0],
+                sutherland: (273.15, 21.0e-6, 144.0),
+            },
+            // 공기는 의임계(pseudo-critical) 상수를 쓴다.
+            Gas::Air => GasData {
+                molar_mass: 28.96,
+                tc_k: 132.5,
+                pc_bar: 37.7,
+                omega: 0.035,
+                cp: [28.11, 0.1967e-2, 0.4802e-5, -1.966e-9],
+                sutherland: (291.15, 18.27e-6, 120.0),
+            },
+            // 천연가스는 Kay 규칙 의임계 상수와 몰분율 가중 비열식을 쓴다.
+            Gas::NaturalGas => GasData {
+                molar_mass: 17.38,
+                tc_k: 196.66,
+                pc_bar: 45.82,
+                omega: 0.018,
+                cp: [19.25, 5.726e-2, 0.718e-5, -9.485e-9],
+                sutherland: (293.15, 10.9e-6, 198.0),
+            },
+        }
+    }
+}
+
+/// 가스 물성 결과.
+#[derive(Debug, Clone)]
+pub struct GasProperties {
+    pub gas: Gas,
+    pub pressure_bar_abs: f64,
+    pub temperature_c: f64,
+    pub molar_mass_kg_per_kmol: f64,
+    /// 압축계수
+    pub z: f64,
+    pub density_kg_per_m3: f64,
+    /// 실제기체 정압/정적 비열 [kJ/kg·K]
+    pub cp_kj_per_kgk: f64,
+    pub cv_kj_per_kgk: f64,
+    /// 비열비 cp/cv
+    pub k: f64,
+    /// 등엔트로피 지수 −(v/p)(∂p/∂v)_s. 이상기체면 k와 같다.
+    pub isentropic_exponent: f64,
+    /// 동점성 계산용 점도 [Pa·s] (저압값)
+    pub viscosity_pa_s: f64,
+    pub speed_of_sound_m_per_s: f64,
+    /// 환산 온도/압력
+    pub reduced_temperature: f64,
+    pub reduced_pressure: f64,
+    pub warnings: Vec<String>,
+}
+
+impl GasProperties {
+    /// 감압/오리피스 계산용 (등엔트로피 지수, 분자량, Z).
+    pub fn blowdown_gas(&self) -> BlowdownGas {
+        BlowdownGas {
+            k: self.isentropic_exponent,
+            molar_mass_kg_per_kmol: self.molar_mass_kg_per_kmol,
+            z: self.z,
+        }
+    }
+}
+
+/// 가스 물성 계산 오류.
+#[derive(Debug, Clone)]
+pub enum GasPropertiesError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for GasPropertiesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GasPropertiesError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for GasPropertiesError {}
+
+/// PR 상수 (a(T) [Pa·m⁶/mol²], da/dT, b [m³/mol]).
+fn pr_parameters(data: &GasData, t_k: f64) -> (f64, f64, f64) {
+    let tc = data.tc_k;
+    let pc = data.pc_bar * 1e5;
+    let kappa = 0.374_64 + 1.542_26 * data.omega - 0.269_92 * data.omega.powi(2);
+    let a_c = 0.457_24 * (R * tc).powi(2) / pc;
+    let sqrt_alpha = 1.0 + kappa * (1.0 - (t_k / tc).sqrt());
+    let a = a_c * sqrt_alpha.powi(2);
+    let da_dt = -a_c * kappa * sqrt_alpha / (t_k * tc).sqrt();
+    let b = 0.077_80 * R * tc / pc;
+    (a, da_dt, b)
+}
+
+/// Z³ + c2·Z² + c1·Z + c0 = 0의 실근 중 가장 큰 값과 실근 개수.
+fn largest_cubic_root(c2: f64, c1: f64, c0: f64) -> (f64, usize) {
+    let q = (3.0 * c1 - c2 * c2) / 9.0;
+    let r = (9.0 * c2 * c1 - 27.0 * c0 - 2.0 * c2.powi(3)) / 54.0;
+    let disc = q.powi(3) + r * r;
+    if disc > 0.0 {
+        let s = (r + disc.sqrt()).cbrt();
+        let t = (r - disc.sqrt()).cbrt();
+        (s + t - c2 / 3.0, 1)
+    } else {
+        let theta = (r / (-q.powi(3)).sqrt()).clamp(-1.0, 1.0).acos();
+        let m = 2.0 * (-q).sqrt();
+        let roots = [0.0, 1.0, 2.0]
+            .map(|i| m * ((theta + 2.0 * std::f64::consts::PI * i) / 3.0).cos() - c2 / 3.0);
+        (roots.into_iter().fold(f64::MIN, f64::max), 3)
+    }
+}
+
+/// 증기 근 압축계수와 실근 개수.
+fn pr_z(data: &GasData, p_pa: f64, t_k: f64) -> (f64, usize) {
+    let (a, _, b) = pr_parameters(data, t_k);
+    let big_a = a * p_pa / (R * t_k).powi(2);
+    let big_b = b * p_pa / (R * t_k);
+    largest_cubic_root(
+        -(1.0 - big_b),
+        big_a - 3.0 * big_b * big_b - 2.0 * big_b,
+        -(big_a * big_b - big_b * big_b - big_b.powi(3)),
+    )
+}
+
+/// PR 잔여 엔탈피 [J/mol].
+fn residual_enthalpy(data: &GasData, p_pa: f64, t_k: f64) -> f64 {
+    let (a, da_dt, b) = pr_parameters(data, t_k);
+    let (z, _) = pr_z(data, p_pa, t_k);
+    let big_b = b * p_pa / (R * t_k);
+    let log_term = ((z + (1.0 + SQRT2) * big_b) / (z + (1.0 - SQRT2) * big_b)).ln();
+    R * t_k * (z - 1.0) + (t_k * da_dt - a) / (2.0 * SQRT2 * b) * log_term
+}
+
+fn ideal_cp_molar(data: &GasData, t_k: f64) -> f64 {
+    let [a, b, c, d] = data.cp;
+    a + b * t_k + c * t_k * t_k + d * t_k.powi(3)
+}
+
+/// 이상기체 엔탈피 ∫cp dT (기준 온도부터) [J/mol].
+fn ideal_enthalpy_molar(data: &GasData, t_k: f64) -> f64 {
+    let [a, b, c, d] = data.cp;
+    let h = |t: f64| a * t + b * t.powi(2) / 2.0 + c * t.powi(3) / 3.0 + d * t.powi(4) / 4.0;
+    h(t_k) - h(H_REF_T_K)
+}
+
+/// Wilson 식 포화압 근사 [bar abs]. 임계 온도 이상이면 `None`.
+fn wilson_saturation_pressure_bar(data: &GasData, t_k: f64) -> Option<f64> {
+    let tr = t_k / data.tc_k;
+    (tr < 1.0).then(|| data.pc_bar * (5.373 * (1.0 + data.omega) * (1.0 - 1.0 / tr)).exp())
+}
+
+fn sutherland_viscosity(data: &GasData, t_k: f64) -> f64 {
+    let (t0, mu0, s) = data.sutherland;
+    mu0 * (t_k / t0).powf(1.5) * (t0 + s) / (t_k + s)
+}
+
+/// 압력 [bar abs], 온도 [°C]에서 가스 물성을 계산한다.
+pub fn gas_properties(
+    gas: Gas,
+    pressure_bar_abs: f64,
+    temperature_c: f64,
+) -> Result<GasProperties, GasPropertiesError> {
+    let t_k = temperature_c + 273.15;
+    if pressure_bar_abs <= 0.0 || t_k <= 0.0 {
+        return Err(GasPropertiesError::InvalidInput(
+            "압력과 절대온도는 0보다 커야 합니다.",
+        ));
+    }
+    let data = gas.data();
+    let p_pa = pressure_bar_abs * 1e5;
+    let m_kg_per_mol = data.molar_mass / 1000.0;
+    let (z, root_count) = pr_z(&data, p_pa, t_k);
+    let v = z * R * t_k / p_pa;
+
+    // 실제기체 cp: 이상기체 + ∂H_R/∂T (등압, 중앙 차분)
+    let dt = 0.01;
+    let cp_residual = (residual_enthalpy(&data, p_pa, t_k + dt)
+        - residual_enthalpy(&data, p_pa, t_k - dt))
+        / (2.0 * dt);
+    let cp_molar = ideal_cp_molar(&data, t_k) + cp_residual;
+    // cp − cv = −T·(∂P/∂T)_v² / (∂P/∂v)_T
+    let (a, da_dt, b) = pr_parameters(&data, t_k);
+    let denom = v * v + 2.0 * b * v - b * b;
+    let dp_dt = R / (v - b) - da_dt / denom;
+    let dp_dv = -R * t_k / (v - b).powi(2) + a * (2.0 * v + 2.0 * b) / denom.powi(2);
+    let cv_molar = cp_molar + t_k * dp_dt.powi(2) / dp_dv;
+    let k = cp_molar / cv_molar;
+    let speed_of_sound = (-v * v * k * dp_dv / m_kg_per_mol).sqrt();
+
+    let tr = t_k / data.tc_k;
+    let pr = pressure_bar_abs / data.pc_bar;
+    let mut warnings = Vec::new();
+    if t_k < CP_VALID_RANGE_K.0 || t_k > CP_VALID_RANGE_K.1 {
+        warnings.push(format!(
+            "온도 {temperature_c:.0} °C가 이상기체 비열식 범위(0~1527 °C) 밖입니다."
+        ));
+    }
+    if let Some(p_sat) = wilson_saturation_pressure_bar(&data, t_k) {
+        if pressure_bar_abs >= p_sat || root_count == 3 && pressure_bar_abs > 0.9 * p_sat {
+            warnings.push(format!(
+                "포화압 약 {p_sat:.1} bar(a) 부근/이상이라 액체 또는 2상일 수 있습니다. 증기 근 물성입니다."
+            ));
+        }
+    } else if tr < 1.2 && pr > 0.8 {
+        warnings.push("임계점 부근이라 PR 상태식과 비열 오차가 큽니다.".into());
+    }
+    if pr > VISCOSITY_PR_LIMIT {
+        warnings.push(format!(
+            "환산 압력 {pr:.2}에서 점도는 저압값이라 과소 평가됩니다."
+        ));
+    }
+
+    Ok(GasProperties {
+        gas,
+        pressure_bar_abs,
+        temperature_c,
+        molar_mass_kg_per_kmol: data.molar_mass,
+        z,
+        density_kg_per_m3: m_kg_per_mol / v,
+        cp_kj_per_kgk: cp_molar / data.molar_mass,
+        cv_kj_per_kgk: cv_molar / data.molar_mass,
+        k,
+        isentropic_exponent: -k * v / p_pa * dp_dv,
+        viscosity_pa_s: sutherland_viscosity(&data, t_k),
+        speed_of_sound_m_per_s: speed_of_sound,
+        reduced_temperature: tr,
+        reduced_pressure: pr,
+        warnings,
+    })
+}
+
+/// 실제기체 비엔탈피 [kJ/kg] (298.15 K 이상기체 기준). 교축 등 엔탈피 수지에 쓴다.
+pub fn specific_enthalpy_kj_per_kg(gas: Gas, pressure_bar_abs: f64, temperature_c: f64) -> f64 {
+    let data = gas.data();
+    let t_k = temperature_c + 273.15;
+    let h_molar =
+        ideal_enthalpy_molar(&data, t_k) + residual_enthalpy(&data, pressure_bar_abs * 1e5, t_k);
+    h_molar / data.molar_mass
+}
+
+/// 온도 [°C]에서의 포화압 근사 [bar abs] (Wilson 식). 임계 온도 이상이면 `None`.
+pub fn saturation_pressure_estimate_bar_abs(gas: Gas, temperature_c: f64) -> Option<f64> {
+    wilson_saturation_pressure_bar(&gas.data(), temperature_c + 273.15)
+}
@@ -1,5 +1,11 @@
-//! 기타 가스 배관 계산 모듈. 현재는 stub 상태다.
+//! 기타 가스 배관 계산 모듈. 산업용 가스 실제기체 물성, 압축기 동력/토출 온도, 용기/헤더 감압(blowdown) 과도 계산,
+//! 감압부 음향 유발 진동(AIV) 선별, 감압 교축(Joule-Thomson) 온도 계산을 포함한다.
 
+pub mod aiv;
+pub mod blowdown;
+pub mod compressor;
 pub mod gas_piping;
+pub mod gas_properties;
+pub mod throttling;
 
 pub use gas_piping::*;
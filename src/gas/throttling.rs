@@ -0,0 +1,263 @@
+//! 가스 등엔탈피 교축(Joule-Thomson) 계산. 연료가스 감압 스테이션 등에서 감압 후 온도를 구한다.
+//! Peng-Robinson 상태식의 실제기체 엔탈피(`gas_properties`)로 h(p₁, T₁) = h(p₂, T₂)를 풀거나,
+//! 사용자가 준 평균 JT 계수로 T₂ = T₁ − μJT·(p₁ − p₂)를 계산한다.
+//! 천연가스는 Hammerschmidt 수화물 온도와, 모든 가스는 Wilson 포화압으로 응축 가능성을 경고한다.
+
+use crate::gas::gas_properties::{self, Gas, GasPropertiesError};
+use crate::warning::{CalcWarning, Severity};
+
+/// 출구 온도 반복 계산 허용 오차 [kJ/kg]
+const H_TOLERANCE_KJ_PER_KG: f64 = 1e-6;
+const MAX_ITERATIONS: usize = 50;
+/// 수화물 온도와 출구 온도 사이에 두는 여유 [K]
+const HYDRATE_MARGIN_K: f64 = 5.0;
+/// CO₂ 삼중점 압력 [bar abs], 이보다 낮은 압력에서 응축하면 드라이아이스가 된다
+const CO2_TRIPLE_POINT_BAR_ABS: f64 = 5.185;
+
+/// 출구 온도 계산 방법.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JouleThomsonMethod {
+    /// PR 상태식 엔탈피 수지
+    Eos,
+    /// 평균 JT 계수 [K/bar] (양수면 감압 시 냉각)
+    Coefficient { k_per_bar: f64 },
+}
+
+/// 교축 계산 입력.
+#[derive(Debug, Clone)]
+pub struct ThrottlingInput {
+    pub gas: Gas,
+    pub inlet_pressure_bar_abs: f64,
+    pub inlet_temp_c: f64,
+    pub outlet_pressure_bar_abs: f64,
+    pub method: JouleThomsonMethod,
+}
+
+/// 교축 계산 결과.
+#[derive(Debug, Clone)]
+pub struct ThrottlingResult {
+    pub outlet_temp_c: f64,
+    /// 입구 − 출구 온도 [K] (양수면 냉각)
+    pub temperature_drop_k: f64,
+    /// 평균 JT 계수 (T₁ − T₂)/(p₁ − p₂) [K/bar]
+    pub mean_jt_coefficient_k_per_bar: f64,
+    /// 입구 비엔탈피 [kJ/kg] (EOS 방법에서 출구와 같다)
+    pub enthalpy_kj_per_kg: f64,
+    /// 출구 압축계수와 밀도
+    pub outlet_z: f64,
+    pub outlet_density_kg_per_m3: f64,
+    /// 천연가스/메탄의 출구 압력 수화물 생성 온도 [°C] (물이 있을 때)
+    pub hydrate_temp_c: Option<f64>,
+    pub warnings: Vec<ThrottlingWarning>,
+}
+
+/// 교축 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ThrottlingWarning {
+    /// 출구 온도가 수화물 생성 온도 + 여유 이하
+    HydrateRisk {
+        outlet_temp_c: f64,
+        hydrate_temp_c: f64,
+    },
+    /// CO₂ 삼중점 아래 포화 영역 (드라이아이스)
+    DryIce,
+    /// 출구가 포화압 이상이라 응축 가능
+    Condensation { saturation_pressure_bar_abs: f64 },
+    /// 천연가스 탄화수소 이슬점은 계산하지 않음
+    HydrocarbonDewPointNotComputed,
+}
+
+impl CalcWarning for ThrottlingWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            ThrottlingWarning::HydrocarbonDewPointNotComputed => Severity::Info,
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ThrottlingWarning::HydrateRisk { .. } => "warning.throttling.hydrate_risk",
+            ThrottlingWarning::DryIce => "warning.throttling.dry_ice",
+            ThrottlingWarning::Condensation { .. } => "warning.throttling.condensation",
+            ThrottlingWarning::HydrocarbonDewPointNotComputed => {
+                "warning.throttling.hc_dew_point_not_computed"
+            }
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            ThrottlingWarning::HydrateRisk {
+                outlet_temp_c,
+                hydrate_temp_c,
+            } => vec![
+                ("t2", format!("{outlet_temp_c:.1}")),
+                ("t_hyd", format!("{hydrate_temp_c:.1}")),
+                ("margin", format!("{HYDRATE_MARGIN_K:.0}")),
+            ],
+            ThrottlingWarning::DryIce => vec![("p_triple", format!("{CO2_TRIPLE_POINT_BAR_ABS}"))],
+            ThrottlingWarning::Condensation {
+                saturation_pressure_bar_abs,
+            } => vec![("p_sat", format!("{saturation_pressure_bar_abs:.1}"))],
+            ThrottlingWarning::HydrocarbonDewPointNotComputed => Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for ThrottlingWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ThrottlingWarning::HydrateRisk {
+                outlet_temp_c,
+                hydrate_temp_c,
+            } => write!(
+                f,
+                "출구 {outlet_temp_c:.1} °C가 수화물 생성 온도 약 {hydrate_temp_c:.1} °C(+{HYDRATE_MARGIN_K:.0} K 여유) 이하입니다. 수분이 있으면 예열 또는 억제제 주입이 필요합니다."
+            ),
+            ThrottlingWarning::DryIce => write!(
+                f,
+                "출구가 CO₂ 삼중점 압력({CO2_TRIPLE_POINT_BAR_ABS} bar(a)) 아래 포화 영역이라 드라이아이스가 생길 수 있습니다."
+            ),
+            ThrottlingWarning::Condensation {
+                saturation_pressure_bar_abs,
+            } => write!(
+                f,
+                "출구 압력이 출구 온도의 포화압 약 {saturation_pressure_bar_abs:.1} bar(a) 이상이라 응축(액체 생성)될 수 있습니다. 증기 근 결과입니다."
+            ),
+            ThrottlingWarning::HydrocarbonDewPointNotComputed => f.write_str(
+                "천연가스 탄화수소 이슬점(C₃+ 성분)은 계산하지 않습니다. 가스 분석값으로 따로 확인하세요.",
+            ),
+        }
+    }
+}
+
+/// 교축 계산 오류.
+#[derive(Debug, Clone)]
+pub enum ThrottlingError {
+    InvalidInput(&'static str),
+    Properties(GasPropertiesError),
+    /// 출구 온도 반복 계산이 수렴하지 않음
+    NoConvergence,
+}
+
+impl std::fmt::Display for ThrottlingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThrottlingError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            ThrottlingError::Properties(e) => write!(f, "{e}"),
+            ThrottlingError::NoConvergence => {
+                write!(f, "출구 온도 계산이 수렴하지 않았습니다.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ThrottlingError {}
+
+impl From<GasPropertiesError> for ThrottlingError {
+    fn from(e: GasPropertiesError) -> Self {
+        ThrottlingError::Properties(e)
+    }
+}
+
+/// 천연가스(비중 약 0.6) 수화물 생성 온도 [°C] - Hammerschmidt: T[°F] = 8.9·p[psia]^0.285
+pub fn hydrate_temperature_c(pressure_bar_abs: f64) -> f64 {
+    let p_psia = pressure_bar_abs * 14.503_774;
+    let t_f = 8.9 * p_psia.powf(0.285);
+    (t_f - 32.0) / 1.8
+}
+
+/// h(p₂, T) = h₁이 되는 T [°C]를 Newton 법으로 찾는다. 기울기는 실제기체 cp다.
+fn solve_isenthalpic_temp(gas: Gas, p_bar_abs: f64, h_target: f64, t_guess_c: f64) -> Option<f64> {
+    let mut t = t_guess_c;
+    for _ in 0..MAX_ITERATIONS {
+        let residual = gas_properties::specific_enthalpy_kj_per_kg(gas, p_bar_abs, t) - h_target;
+        if residual.abs() < H_TOLERANCE_KJ_PER_KG {
+            return Some(t);
+        }
+        let cp = gas_properties::gas_properties(gas, p_bar_abs, t)
+            .ok()?
+            .cp_kj_per_kgk;
+        if !(cp.is_finite() && cp > 0.0) {
+            return None;
+        }
+        // 한 번에 50 K 넘게 움직이지 않게 제한한다
+        t -= (residual / cp).clamp(-50.0, 50.0);
+        if t + 273.15 <= 0.0 {
+            return None;
+        }
+    }
+    None
+}
+
+/// 감압 후 출구 온도와 수화물/응축 경고를 계산한다.
+pub fn throttle(input: ThrottlingInput) -> Result<ThrottlingResult, ThrottlingError> {
+    let p1 = input.inlet_pressure_bar_abs;
+    let p2 = input.outlet_pressure_bar_abs;
+    let t1 = input.inlet_temp_c;
+    if p1 <= 0.0 || p2 <= 0.0 || t1 + 273.15 <= 0.0 {
+        return Err(ThrottlingError::InvalidInput(
+            "압력과 절대온도는 0보다 커야 합니다.",
+        ));
+    }
+    if p2 > p1 {
+        return Err(ThrottlingError::InvalidInput(
+            "출구 압력이 입구 압력보다 높습니다.",
+        ));
+    }
+    let gas = input.gas;
+    let h1 = gas_properties::specific_enthalpy_kj_per_kg(gas, p1, t1);
+    let t2 = match input.method {
+        JouleThomsonMethod::Eos => {
+            solve_isenthalpic_temp(gas, p2, h1, t1).ok_or(ThrottlingError::NoConvergence)?
+        }
+        JouleThomsonMethod::Coefficient { k_per_bar } => {
+            let t2 = t1 - k_per_bar * (p1 - p2);
+            if t2 + 273.15 <= 0.0 {
+                return Err(ThrottlingError::InvalidInput(
+                    "JT 계수로 구한 출구 온도가 절대영도 이하입니다.",
+                ));
+            }
+            t2
+        }
+    };
+    let outlet = gas_properties::gas_properties(gas, p2, t2)?;
+
+    let mut warnings = Vec::new();
+    let hydrate_temp_c = matches!(gas, Gas::NaturalGas | Gas::Methane).then(|| {
+        let t_hyd = hydrate_temperature_c(p2);
+        if t2 < t_hyd + HYDRATE_MARGIN_K {
+            warnings.push(ThrottlingWarning::HydrateRisk {
+                outlet_temp_c: t2,
+                hydrate_temp_c: t_hyd,
+            });
+        }
+        t_hyd
+    });
+    if let Some(p_sat) = gas_properties::saturation_pressure_estimate_bar_abs(gas, t2) {
+        if p2 >= p_sat {
+            if gas == Gas::CarbonDioxide && p2 < CO2_TRIPLE_POINT_BAR_ABS {
+                warnings.push(ThrottlingWarning::DryIce);
+            } else {
+                warnings.push(ThrottlingWarning::Condensation {
+                    saturation_pressure_bar_abs: p_sat,
+                });
+            }
+        }
+    }
+    if gas == Gas::NaturalGas {
+        warnings.push(ThrottlingWarning::HydrocarbonDewPointNotComputed);
+    }
+
+    Ok(ThrottlingResult {
+        outlet_temp_c: t2,
+        temperature_drop_k: t1 - t2,
+        mean_jt_coefficient_k_per_bar: if p1 > p2 { (t1 - t2) / (p1 - p2) } else { 0.0 },
+        enthalpy_kj_per_kg: h1,
+        outlet_z: outlet.z,
+        outlet_density_kg_per_m3: outlet.density_kg_per_m3,
+        hydrate_temp_c,
+        warnings,
+    })
+}
@@ -0,0 +1,289 @@
+//! 목표값 탐색(Goal-seek) 유틸리티.
+//! 단조 구간에서 f(x) = target을 만족하는 x를 이분법 또는 Brent 방법으로 찾는다.
+//! 배관 내경, 밸브 Cv, 냉각수 유량 등의 역산 함수가 이 모듈을 사용한다.
+
+/// 탐색 허용 오차와 최대 반복 횟수.
+#[derive(Debug, Clone, Copy)]
+pub struct GoalSeekOptions {
+    /// x 방향 허용 오차(절대값)
+    pub x_tol: f64,
+    /// 잔차 |f(x) - target| 허용 오차
+    pub f_tol: f64,
+    pub max_iter: usize,
+}
+
+impl Default for GoalSeekOptions {
+    fn default() -> Self {
+        Self {
+            x_tol: 1e-9,
+            f_tol: 1e-10,
+            max_iter: 200,
+        }
+    }
+}
+
+/// 탐색 결과.
+#[derive(Debug, Clone, Copy)]
+pub struct GoalSeekResult {
+    pub x: f64,
+    /// f(x) - target
+    pub residual: f64,
+    pub iterations: usize,
+}
+
+/// 탐색 오류.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GoalSeekError {
+    /// 구간/옵션이 잘못된 경우
+    InvalidInput(&'static str),
+    /// 구간 양 끝에서 부호가 바뀌지 않아 해가 구간 안에 없음
+    NotBracketed { f_lo: f64, f_hi: f64 },
+    /// 계산 함수가 유한하지 않은 값을 반환함
+    NonFinite { x: f64 },
+    /// 최대 반복 내에 수렴하지 못함
+    NotConverged { x: f64, residual: f64 },
+}
+
+impl std::fmt::Display for GoalSeekError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoalSeekError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            GoalSeekError::NotBracketed { f_lo, f_hi } => write!(
+                f,
+                "탐색 구간 안에 해가 없습니다 (구간 양 끝 잔차 {f_lo:.4e}, {f_hi:.4e})"
+            ),
+            GoalSeekError::NonFinite { x } => {
+                write!(f, "x={x:.6e}에서 계산 결과가 유효하지 않습니다")
+            }
+            GoalSeekError::NotConverged { x, residual } => {
+                write!(f, "수렴하지 못했습니다 (x={x:.6e}, 잔차 {residual:.4e})")
+            }
+        }
+    }
+}
+
+impl std::error::Error for GoalSeekError {}
+
+fn eval<F>(f: &mut F, x: f64, target: f64) -> Result<f64, GoalSeekError>
+where
+    F: FnMut(f64) -> f64,
+{
+    let v = f(x) - target;
+    if v.is_finite() {
+        Ok(v)
+    } else {
+        Err(GoalSeekError::NonFinite { x })
+    }
+}
+
+fn check_bracket(lo: f64, hi: f64, opts: &GoalSeekOptions) -> Result<(), GoalSeekError> {
+    if !lo.is_finite() || !hi.is_finite() || lo >= hi {
+        return Err(GoalSeekError::InvalidInput(
+            "탐색 구간은 lo < hi인 유한한 값이어야 합니다.",
+        ));
+    }
+    if opts.x_tol <= 0.0 || opts.f_tol < 0.0 || opts.max_iter == 0 {
+        return Err(GoalSeekError::InvalidInput(
+            "허용 오차/반복 횟수가 잘못되었습니다.",
+        ));
+    }
+    Ok(())
+}
+
+/// 이분법으로 f(x) = target인 x를 [lo, hi]에서 찾는다. 느리지만 항상 구간 안에 머문다.
+pub fn bisection<F>(
+    mut f: F,
+    target: f64,
+    lo: f64,
+    hi: f64,
+    opts: GoalSeekOptions,
+) -> Result<GoalSeekResult, GoalSeekError>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_bracket(lo, hi, &opts)?;
+    let (mut a, mut b) = (lo, hi);
+    let mut fa = eval(&mut f, a, target)?;
+    let fb = eval(&mut f, b, target)?;
+    if fa.abs() <= opts.f_tol {
+        return Ok(GoalSeekResult {
+            x: a,
+            residual: fa,
+            iterations: 0,
+        });
+    }
+    if fb.abs() <= opts.f_tol {
+        return Ok(GoalSeekResult {
+            x: b,
+            residual: fb,
+            iterations: 0,
+        });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(GoalSeekError::NotBracketed { f_lo: fa, f_hi: fb });
+    }
+    let mut mid = 0.5 * (a + b);
+    let mut fm = fa;
+    for iter in 1..=opts.max_iter {
+        mid = 0.5 * (a + b);
+        fm = eval(&mut f, mid, target)?;
+        if fm.abs() <= opts.f_tol || 0.5 * (b - a) <= opts.x_tol {
+            return Ok(GoalSeekResult {
+                x: mid,
+                residual: fm,
+                iterations: iter,
+            });
+        }
+        if fm.signum() == fa.signum() {
+            a = mid;
+            fa = fm;
+        } else {
+            b = mid;
+        }
+    }
+    Err(GoalSeekError::NotConverged {
+        x: mid,
+        residual: fm,
+    })
+}
+
+/// Brent 방법(역2차 보간 + 할선 + 이분법)으로 f(x) = target인 x를 [lo, hi]에서 찾는다.
+/// 이분법의 안정성을 유지하면서 매끄러운 함수에서는 훨씬 빨리 수렴한다.
+pub fn brent<F>(
+    mut f: F,
+    target: f64,
+    lo: f64,
+    hi: f64,
+    opts: GoalSeekOptions,
+) -> Result<GoalSeekResult, GoalSeekError>
+where
+    F: FnMut(f64) -> f64,
+{
+    check_bracket(lo, hi, &opts)?;
+    let (mut a, mut b) = (lo, hi);
+    let mut fa = eval(&mut f, a, target)?;
+    let mut fb = eval(&mut f, b, target)?;
+    if fa.abs() <= opts.f_tol {
+        return Ok(GoalSeekResult {
+            x: a,
+            residual: fa,
+            iterations: 0,
+        });
+    }
+    if fb.abs() <= opts.f_tol {
+        return Ok(GoalSeekResult {
+            x: b,
+            residual: fb,
+            iterations: 0,
+        });
+    }
+    if fa.signum() == fb.signum() {
+        return Err(GoalSeekError::NotBracketed { f_lo: fa, f_hi: fb });
+    }
+
+    // b를 항상 더 좋은 추정값으로 유지한다.
+    if fa.abs() < fb.abs() {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+    let mut c = a;
+    let mut fc = fa;
+    let mut d = b - a;
+    let mut e = d;
+
+    for iter in 1..=opts.max_iter {
+        if fb.signum() == fc.signum() {
+            c = a;
+            fc = fa;
+            d = b - a;
+            e = d;
+        }
+        if fc.abs() < fb.abs() {
+            a = b;
+            b = c;
+            c = a;
+            fa = fb;
+            fb = fc;
+            fc = fa;
+        }
+        let tol = 2.0 * f64::EPSILON * b.abs() + 0.5 * opts.x_tol;
+        let m = 0.5 * (c - b);
+        if m.abs() <= tol || fb.abs() <= opts.f_tol {
+            return Ok(GoalSeekResult {
+                x: b,
+                residual: fb,
+                iterations: iter,
+            });
+        }
+
+        if e.abs() >= tol && fa.abs() > fb.abs() {
+            // 보간 단계
+            let s = fb / fa;
+            let (mut p, mut q) = if a == c {
+                (2.0 * m * s, 1.0 - s)
+            } else {
+                let q = fa / fc;
+                let r = fb / fc;
+                (
+                    s * (2.0 * m * q * (q - r) - (b - a) * (r - 1.0)),
+                    (q - 1.0) * (r - 1.0) * (s - 1.0),
+                )
+            };
+            if p > 0.0 {
+                q = -q;
+            } else {
+                p = -p;
+            }
+            if 2.0 * p < (3.0 * m * q - (tol * q).abs()).min((e * q).abs()) {
+                e = d;
+                d = p / q;
+            } else {
+                d = m;
+                e = m;
+            }
+        } else {
+            d = m;
+            e = m;
+        }
+
+        a = b;
+        fa = fb;
+        b += if d.abs() > tol { d } else { tol.copysign(m) };
+        fb = eval(&mut f, b, target)?;
+    }
+    Err(GoalSeekError::NotConverged { x: b, residual: fb })
+}
+
+/// 기본 옵션의 Brent 탐색.
+pub fn goal_seek<F>(f: F, target: f64, lo: f64, hi: f64) -> Result<GoalSeekResult, GoalSeekError>
+where
+    F: FnMut(f64) -> f64,
+{
+    brent(f, target, lo, hi, GoalSeekOptions::default())
+}
+
+/// 단조 증가 함수에 대해 f(hi) ≥ target이 될 때까지 상한을 두 배씩 늘려 탐색 구간을 만든다.
+pub fn expand_upper_bound<F>(
+    mut f: F,
+    target: f64,
+    start_hi: f64,
+    max_steps: usize,
+) -> Result<f64, GoalSeekError>
+where
+    F: FnMut(f64) -> f64,
+{
+    if !(start_hi.is_finite() && start_hi > 0.0) {
+        return Err(GoalSeekError::InvalidInput(
+            "초기 상한은 0보다 커야 합니다.",
+        ));
+    }
+    let mut hi = start_hi;
+    for _ in 0..=max_steps {
+        if eval(&mut f, hi, target)? >= 0.0 {
+            return Ok(hi);
+        }
+        hi *= 2.0;
+    }
+    let residual = eval(&mut f, hi, target)?;
+    Err(GoalSeekError::NotConverged { x: hi, residual })
+}
@@ -1,8 +1,11 @@
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
+use std::sync::OnceLock;
 use sys_locale::get_locale;
 
+use crate::number_format::NumberFormat;
+
 /// 문자열 키를 모아두는 네임스페이스.
 pub mod keys {
     pub const ERROR_PREFIX: &str = "general.error_prefix";
@@ -13,6 +16,7 @@ pub mod keys {
     pub const MAIN_MENU_STEAM_TABLES: &str = "main_menu.steam_tables";
     pub const MAIN_MENU_STEAM_PIPING: &str = "main_menu.steam_piping";
     pub const MAIN_MENU_STEAM_VALVES: &str = "main_menu.steam_valves";
+    pub const MAIN_MENU_STEAM_DRYNESS: &str = "main_menu.steam_dryness";
     pub const MAIN_MENU_SETTINGS: &str = "main_menu.settings";
     pub const MAIN_MENU_EXIT: &str = "main_menu.exit";
     pub const PROMPT_MENU_SELECT: &str = "prompt.menu_select";
@@ -21,6 +25,7 @@ pub mod keys {
     pub const UNIT_CONVERSION_HEADING: &str = "unit_conversion.heading";
     pub const UNIT_CONVERSION_OPTIONS_LINE1: &str = "unit_conversion.options_line1";
     pub const UNIT_CONVERSION_OPTIONS_LINE2: &str = "unit_conversion.options_line2";
+    pub const UNIT_CONVERSION_OPTIONS_LINE3: &str = "unit_conversion.options_line3";
     pub const UNIT_CONVERSION_NOTE_MMHG: &str = "unit_conversion.note_mmhg";
     pub const UNIT_CONVERSION_PROMPT_KIND: &str = "unit_conversion.prompt_kind";
     pub const UNIT_CONVERSION_PROMPT_VALUE: &str = "unit_conversion.prompt_value";
@@ -46,10 +51,14 @@ pub mod keys {
     pub const PROMPT_TARGET_VELOCITY: &str = "prompt.target_velocity";
     pub const RESULT_RECOMMENDED_ID: &str = "result.recommended_id";
     pub const RESULT_EXPECTED_VELOCITY: &str = "result.expected_velocity";
+    pub const RESULT_RECOMMENDED_VELOCITY: &str = "result.recommended_velocity";
+    pub const RESULT_VELOCITY_UTILIZATION: &str = "result.velocity_utilization";
+    pub const RESULT_VELOCITY_CHECK: &str = "result.velocity_check";
     pub const PROMPT_DENSITY_OPTIONAL: &str = "prompt.density_optional";
     pub const PROMPT_DIAMETER: &str = "prompt.diameter";
     pub const PROMPT_LENGTH: &str = "prompt.length";
     pub const PROMPT_EQ_LENGTH: &str = "prompt.eq_length";
+    pub const PROMPT_ELEVATION_RISE: &str = "prompt.elevation_rise";
     pub const PROMPT_FITTINGS_K: &str = "prompt.fittings_k";
     pub const PROMPT_ROUGHNESS: &str = "prompt.roughness";
     pub const PROMPT_VISCOSITY: &str = "prompt.viscosity";
@@ -68,6 +77,18 @@ pub mod keys {
     pub const PROMPT_UPSTREAM_PRESSURE: &str = "prompt.upstream_pressure";
     pub const RESULT_POSSIBLE_FLOW: &str = "result.possible_flow";
 
+    pub const STEAM_DRYNESS_HEADING: &str = "steam_dryness.heading";
+    pub const STEAM_DRYNESS_OPTIONS: &str = "steam_dryness.options";
+    pub const PROMPT_MAIN_PRESSURE_ABS: &str = "prompt.main_pressure_abs";
+    pub const PROMPT_CALORIMETER_PRESSURE: &str = "prompt.calorimeter_pressure";
+    pub const PROMPT_CALORIMETER_TEMPERATURE: &str = "prompt.calorimeter_temperature";
+    pub const PROMPT_SEPARATED_WATER: &str = "prompt.separated_water";
+    pub const PROMPT_CONDENSED_STEAM: &str = "prompt.condensed_steam";
+    pub const RESULT_DRYNESS: &str = "result.dryness";
+    pub const RESULT_THROTTLING_SUPERHEAT: &str = "result.throttling_superheat";
+    pub const RESULT_MIN_MEASURABLE_DRYNESS: &str = "result.min_measurable_dryness";
+    pub const STEAM_DRYNESS_WARNING: &str = "steam_dryness.warning";
+
     pub const SETTINGS_HEADING: &str = "settings.heading";
     pub const SETTINGS_CURRENT_UNIT_SYSTEM: &str = "settings.current_unit_system";
     pub const SETTINGS_OPTIONS: &str = "settings.options";
@@ -91,7 +112,16 @@ pub mod keys {
     pub const HELP_STEAM_PIPING_DROP: &str = "help.steam_piping_drop";
     pub const HELP_STEAM_VALVES_REQUIRED: &str = "help.steam_valves_required";
     pub const HELP_STEAM_VALVES_FLOW: &str = "help.steam_valves_flow";
+    pub const HELP_STEAM_DRYNESS: &str = "help.steam_dryness";
     pub const HELP_SETTINGS: &str = "help.settings";
+
+    pub const TRAP_SURVEY_HEADING: &str = "trap_survey.heading";
+    pub const TRAP_SURVEY_STEAM_COST: &str = "trap_survey.steam_cost";
+    pub const TRAP_SURVEY_COLUMNS: &str = "trap_survey.columns";
+    pub const TRAP_SURVEY_KIND_LEAK: &str = "trap_survey.kind_leak";
+    pub const TRAP_SURVEY_KIND_TRAP: &str = "trap_survey.kind_trap";
+    pub const TRAP_SURVEY_TOTAL: &str = "trap_survey.total";
+    pub const TRAP_SURVEY_WARNING: &str = "trap_survey.warning";
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -118,11 +148,39 @@ impl Language {
     }
 }
 
+/// 복수형 범주. 언어팩에서는 `키.one`, `키.other` 하위 키로 쓴다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PluralCategory {
+    One,
+    Other,
+}
+
+impl PluralCategory {
+    pub fn suffix(self) -> &'static str {
+        match self {
+            PluralCategory::One => "one",
+            PluralCategory::Other => "other",
+        }
+    }
+}
+
+/// 언어별 복수형 규칙. 영어/독일어는 1일 때만 one, 한국어는 단복수 구분이 없다.
+pub fn plural_category(lang_code: &str, count: u64) -> PluralCategory {
+    let c = lang_code.to_lowercase();
+    if (c.starts_with("en") || c.starts_with("de")) && count == 1 {
+        PluralCategory::One
+    } else {
+        PluralCategory::Other
+    }
+}
+
 /// 런타임 언어 번들을 제공한다.
 #[derive(Debug, Clone)]
 pub struct Translator {
     lang: Language,
+    code: String,
     overrides: Option<HashMap<String, String>>,
+    number_format: NumberFormat,
 }
 
 impl Translator {
@@ -130,7 +188,9 @@ impl Translator {
     pub fn new(lang_code: &str) -> Self {
         Self {
             lang: Language::from_code(lang_code),
+            code: lang_code.to_lowercase(),
             overrides: None,
+            number_format: NumberFormat::default(),
         }
     }
 
@@ -141,9 +201,12 @@ impl Translator {
             .and_then(|dir| load_overrides(dir, lang_code))
             .or_else(|| load_overrides("locales", lang_code))
             .or_else(|| built_in_pack(lang_code));
+        let number_format = locale_number_format(overrides.as_ref());
         Self {
             lang: Language::from_code(lang_code),
+            code: lang_code.to_lowercase(),
             overrides,
+            number_format,
         }
     }
 
@@ -162,6 +225,48 @@ impl Translator {
             .and_then(|m| m.get(key).cloned())
     }
 
+    /// 키를 언어팩에서 찾고, 없으면 같은 언어의 내장 언어팩에서 찾는다.
+    /// 사용자 언어팩이 새 키를 아직 모르거나 언어팩 없이 만든 번역기도 그 언어 문구를 쓴다.
+    pub fn lookup_or_bundled(&self, key: &str) -> Option<String> {
+        self.lookup(key)
+            .or_else(|| bundled_pack(&self.code)?.get(key).cloned())
+    }
+
+    /// 이 언어의 숫자 표시 형식 (언어팩 구분 기호 + 사용자 설정).
+    pub fn number_format(&self) -> &NumberFormat {
+        &self.number_format
+    }
+
+    /// 사용자 숫자 형식을 적용한다. 구분 기호를 기본값('.', 없음)으로 둔 경우 언어팩의 기호를 유지한다.
+    pub fn set_number_format(&mut self, user: &NumberFormat) {
+        let mut nf = user.clone();
+        if user.decimal_separator == '.' && user.thousands_separator.is_none() {
+            nf.decimal_separator = self.number_format.decimal_separator;
+            nf.thousands_separator = self.number_format.thousands_separator;
+        }
+        self.number_format = nf;
+    }
+
+    /// 키의 템플릿(없으면 `default`)에 인자를 채운다. 규칙은 [`fill_template`] 참고.
+    pub fn format(&self, key: &str, default: &str, args: &[(&str, String)]) -> String {
+        let template = self.lookup(key).unwrap_or_else(|| default.to_string());
+        fill_template(&template, args, &self.number_format)
+    }
+
+    /// 개수에 맞는 복수형 템플릿(`키.one`/`키.other`, 없으면 `키`, 그래도 없으면 `default`)에
+    /// `{n}`과 인자를 채운다.
+    pub fn plural(&self, key: &str, count: u64, default: &str, args: &[(&str, String)]) -> String {
+        let category = plural_category(&self.code, count);
+        let template = self
+            .lookup(&format!("{key}.{}", category.suffix()))
+            .or_else(|| self.lookup(&format!("{key}.other")))
+            .or_else(|| self.lookup(key))
+            .unwrap_or_else(|| default.to_string());
+        let mut all = vec![("n", count.to_string())];
+        all.extend(args.iter().cloned());
+        fill_template(&template, &all, &self.number_format)
+    }
+
     /// 번역을 가져온다. 영어 번역이 없으면 한국어 문자열을 폴백한다.
     pub fn t(&self, key: &str) -> &'static str {
         if let Some(ref map) = self.overrides {
@@ -176,6 +281,81 @@ impl Translator {
     }
 }
 
+/// 템플릿의 `{이름}`/`{이름:.N}` 자리를 인자로 채운다.
+/// 숫자 인자는 `nf`로 다시 표시하며, `.N`이 있으면 소수 N자리로 맞춘다.
+/// 인자에 없는 이름은 그대로 남긴다.
+pub fn fill_template(template: &str, args: &[(&str, String)], nf: &NumberFormat) -> String {
+    fill(template, args, nf, |value| nf.reformat(value))
+}
+
+/// [`fill_template`]과 같지만 `{이름}` 자리의 인자는 이미 `nf`로 표시한 값으로 보고 그대로 넣는다.
+/// 반올림한 문자열을 다시 읽지 않도록, 원래 값으로 표시한 인자를 넘길 때 쓴다.
+pub fn fill_template_formatted(
+    template: &str,
+    args: &[(&str, String)],
+    nf: &NumberFormat,
+) -> String {
+    fill(template, args, nf, str::to_string)
+}
+
+fn fill(
+    template: &str,
+    args: &[(&str, String)],
+    nf: &NumberFormat,
+    plain: impl Fn(&str) -> String,
+) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = &after[..end];
+        let (name, spec) = match inner.split_once(':') {
+            Some((n, s)) => (n, Some(s)),
+            None => (inner, None),
+        };
+        match args.iter().find(|(k, _)| *k == name) {
+            Some((_, value)) => {
+                let decimals = spec
+                    .and_then(|s| s.strip_prefix('.'))
+                    .and_then(|d| d.parse::<usize>().ok());
+                match (decimals, value.parse::<f64>()) {
+                    (Some(d), Ok(v)) => out.push_str(&nf.format(v, d)),
+                    _ => out.push_str(&plain(value)),
+                }
+            }
+            None => {
+                out.push('{');
+                out.push_str(inner);
+                out.push('}');
+            }
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 언어팩의 `number.decimal_separator`/`number.thousands_separator`로 기본 숫자 형식을 만든다.
+fn locale_number_format(pack: Option<&HashMap<String, String>>) -> NumberFormat {
+    let mut nf = NumberFormat::default();
+    let first_char = |key: &str| {
+        pack.and_then(|m| m.get(key))
+            .and_then(|v| v.chars().next())
+    };
+    if let Some(c) = first_char("number.decimal_separator") {
+        nf.decimal_separator = c;
+    }
+    if let Some(c) = first_char("number.thousands_separator") {
+        nf.thousands_separator = Some(c);
+    }
+    nf
+}
+
 /// CLI 플래그/설정/시스템 순으로 언어 코드를 결정한다.
 pub fn resolve_language(cli_arg: &str, config_lang: Option<&str>) -> String {
     normalize_lang(cli_arg)
@@ -291,6 +471,22 @@ fn parse_toml_to_map(src: &str) -> Option<HashMap<String, String>> {
     }
 }
 
+/// 내장 언어팩을 한 번만 읽어 둔다. `lookup_or_bundled`가 키마다 다시 파싱하지 않도록.
+fn bundled_pack(lang: &str) -> Option<&'static HashMap<String, String>> {
+    static EN_US: OnceLock<Option<HashMap<String, String>>> = OnceLock::new();
+    static EN_UK: OnceLock<Option<HashMap<String, String>>> = OnceLock::new();
+    static KO_KR: OnceLock<Option<HashMap<String, String>>> = OnceLock::new();
+    static DE_DE: OnceLock<Option<HashMap<String, String>>> = OnceLock::new();
+    let (cell, code) = match lang.to_lowercase().as_str() {
+        "en-us" | "en" => (&EN_US, "en-us"),
+        "en-uk" => (&EN_UK, "en-uk"),
+        "ko-kr" | "ko" => (&KO_KR, "ko-kr"),
+        "de-de" | "de" => (&DE_DE, "de-de"),
+        _ => return None,
+    };
+    cell.get_or_init(|| built_in_pack(code)).as_ref()
+}
+
 /// 내장 언어팩(파일이 없어도 동작하도록 빌드 시 포함).
 fn built_in_pack(lang: &str) -> Option<HashMap<String, String>> {
     match lang.to_lowercase().as_str() {
@@ -312,6 +508,7 @@ fn ko(key: &str) -> &'static str {
         MAIN_MENU_STEAM_TABLES => "2) Steam Tables",
         MAIN_MENU_STEAM_PIPING => "3) Steam Piping",
         MAIN_MENU_STEAM_VALVES => "4) Steam Valves & Orifices",
+        MAIN_MENU_STEAM_DRYNESS => "6) 증기 건도 열량계",
         MAIN_MENU_SETTINGS => "5) 설정",
         MAIN_MENU_EXIT => "0) 종료",
         PROMPT_MENU_SELECT => "메뉴 선택: ",
@@ -321,6 +518,7 @@ fn ko(key: &str) -> &'static str {
         UNIT_CONVERSION_OPTIONS_LINE2 => {
             "7) 속도  8) 질량  9) 점도 10) 에너지 11) 열전달율 12) 열전도율 13) 비엔탈피"
         }
+        UNIT_CONVERSION_OPTIONS_LINE3 => "14) 질량 유량 15) 체적 유량 16) 동력/열량률 17) 밀도 18) 비체적 19) 비열",
         UNIT_CONVERSION_NOTE_MMHG => {
             "참고: mmHg는 게이지 기준(0=대기, -760mmHg=완전진공)으로 처리됩니다."
         }
@@ -346,12 +544,16 @@ fn ko(key: &str) -> &'static str {
         PROMPT_TARGET_VELOCITY => "허용 유속 [m/s]: ",
         RESULT_RECOMMENDED_ID => "추천 내경:",
         RESULT_EXPECTED_VELOCITY => "예상 유속:",
+        RESULT_RECOMMENDED_VELOCITY => "권장 유속",
+        RESULT_VELOCITY_UTILIZATION => "유속 한계 대비",
+        RESULT_VELOCITY_CHECK => "유속 점검:",
         PROMPT_DENSITY_OPTIONAL => "증기 밀도 [kg/m3] (0 입력 시 IF97 기반 자동 계산): ",
         PROMPT_DIAMETER => "배관 내경 [mm] (in/\" 입력 가능): ",
         PROMPT_LENGTH => "배관 길이 [m]: ",
         PROMPT_EQ_LENGTH => "등가 길이 [m] (없으면 0): ",
-        PROMPT_FITTINGS_K => "피팅 K 합계 (없으면 0): ",
-        PROMPT_ROUGHNESS => "거칠기 ε [m] (탄소강 배관 약 0.000045): ",
+        PROMPT_ELEVATION_RISE => "고저차 출구-입구 [m] (수평이면 0, 내리막은 음수): ",
+        PROMPT_FITTINGS_K => "피팅: K 합계 또는 목록 (예: elbow90-lr:4, gate:2 / ? = 코드 목록, Enter = 없음): ",
+        PROMPT_ROUGHNESS => "거칠기: 재질 번호/이름 또는 ε [m] (Enter = 신품 탄소강): ",
         PROMPT_VISCOSITY => "동점도 [Pa·s] (증기 기본값 1.2e-5 추천): ",
         PROMPT_SOUND_SPEED => "음속 [m/s] (기본 450 정도): ",
         RESULT_PRESSURE_DROP => "압력손실 결과:",
@@ -366,14 +568,25 @@ fn ko(key: &str) -> &'static str {
         PROMPT_KV_CV_VALUE => "Kv/Cv 값: ",
         PROMPT_UPSTREAM_PRESSURE => "상류 압력 [bar(a)]: ",
         RESULT_POSSIBLE_FLOW => "가능한 유량:",
+        STEAM_DRYNESS_HEADING => "\n-- 증기 건도 열량계 --",
+        STEAM_DRYNESS_OPTIONS => "1) 교축  2) 분리  3) 분리 + 교축",
+        PROMPT_MAIN_PRESSURE_ABS => "주관 증기 압력 [bar(a)]: ",
+        PROMPT_CALORIMETER_PRESSURE => "교축 후 열량계 압력 [bar(a)]: ",
+        PROMPT_CALORIMETER_TEMPERATURE => "교축 후 열량계 온도 [°C]: ",
+        PROMPT_SEPARATED_WATER => "분리기에서 모은 물 [kg]: ",
+        PROMPT_CONDENSED_STEAM => "모은 증기 응축수 [kg]: ",
+        RESULT_DRYNESS => "건도:",
+        RESULT_THROTTLING_SUPERHEAT => "교축 후 과열도 [K]:",
+        RESULT_MIN_MEASURABLE_DRYNESS => "측정 가능한 최저 건도 (과열도 5 K):",
+        STEAM_DRYNESS_WARNING => "경고:",
         SETTINGS_HEADING => "\n-- 설정 --",
         SETTINGS_CURRENT_UNIT_SYSTEM => "현재 단위 시스템:",
         SETTINGS_OPTIONS => "1) SI(Bar)  2) SI  3) MKS  4) Imperial",
         SETTINGS_PROMPT_CHANGE => "변경할 번호(취소하려면 엔터): ",
         SETTINGS_INVALID => "잘못된 입력이므로 변경하지 않습니다.",
         SETTINGS_SAVED => "단위 시스템이 변경되었습니다:",
-        PRESSURE_UNIT_OPTIONS => "압력 단위: 1=bar 2=kPa 3=MPa 4=psi 5=atm",
-        TEMPERATURE_UNIT_OPTIONS => "온도 단위: 1=°C 2=K 3=°F 4=R",
+        PRESSURE_UNIT_OPTIONS => "압력 단위: 1=bar 2=kPa 3=MPa 4=psi 5=atm (번호 또는 단위 이름)",
+        TEMPERATURE_UNIT_OPTIONS => "온도 단위: 1=°C 2=K 3=°F 4=R (번호 또는 단위 이름)",
         ERROR_INVALID_NUMBER => "숫자를 입력하세요.",
         STATE_SATURATION_T => "포화 온도:",
         STATE_SATURATION_P => "포화 압력:",
@@ -385,7 +598,15 @@ fn ko(key: &str) -> &'static str {
         HELP_STEAM_PIPING_DROP => "도움말: 밀도 0 입력 시 IF97 자동. 내경/두께 mm 또는 in 입력 가능. K 합계/등가길이는 없으면 0.",
         HELP_STEAM_VALVES_REQUIRED => "도움말: 유량[m3/h], ΔP[bar], 밀도[kg/m3] 입력 → 필요 Kv/Cv 계산.",
         HELP_STEAM_VALVES_FLOW => "도움말: Kv 또는 Cv 값, ΔP[bar], 밀도[kg/m3], 상류압[bar(a)] 입력 → 가능한 유량 계산.",
+        HELP_STEAM_DRYNESS => "도움말: 교축 열량계는 교축 후 과열도 5 K 이상 필요, 분리 열량계는 상한값, 습증기는 두 방법을 조합하세요.",
         HELP_SETTINGS => "도움말: 단위 시스템 프리셋을 선택하면 기본 단위 세트가 바뀝니다 (SIBar/SI/MKS/Imperial).",
+        TRAP_SURVEY_HEADING => "\n-- 증기 누설/고장 트랩 절감 보고서 --",
+        TRAP_SURVEY_STEAM_COST => "증기 단가 [원/t]:",
+        TRAP_SURVEY_COLUMNS => "순위 | 태그 | 종류 | 압력 [bar(a)] | 구멍 [mm] | 증기 [kg/h] | 연간 [t] | 에너지 [GJ] | 비용 [원/년]",
+        TRAP_SURVEY_KIND_LEAK => "누설",
+        TRAP_SURVEY_KIND_TRAP => "고장 트랩",
+        TRAP_SURVEY_TOTAL => "합계:",
+        TRAP_SURVEY_WARNING => "경고:",
         _ => "[missing translation]",
     }
 }
@@ -400,6 +621,7 @@ fn en(key: &str) -> Option<&'static str> {
         MAIN_MENU_STEAM_TABLES => "2) Steam Tables",
         MAIN_MENU_STEAM_PIPING => "3) Steam Piping",
         MAIN_MENU_STEAM_VALVES => "4) Steam Valves & Orifices",
+        MAIN_MENU_STEAM_DRYNESS => "6) Steam Dryness Calorimeters",
         MAIN_MENU_SETTINGS => "5) Settings",
         MAIN_MENU_EXIT => "0) Exit",
         PROMPT_MENU_SELECT => "Select menu: ",
@@ -408,6 +630,7 @@ fn en(key: &str) -> Option<&'static str> {
         UNIT_CONVERSION_OPTIONS_LINE1 => "1) Temperature  2) ΔTemperature  3) Pressure  4) Length  5) Area  6) Volume",
         UNIT_CONVERSION_OPTIONS_LINE2 =>
             "7) Velocity  8) Mass  9) Viscosity 10) Energy 11) Heat Transfer 12) Conductivity 13) Specific Enthalpy",
+        UNIT_CONVERSION_OPTIONS_LINE3 => "14) Mass Flow 15) Volumetric Flow 16) Power/Heat Rate 17) Density 18) Specific Volume 19) Specific Heat",
         UNIT_CONVERSION_NOTE_MMHG => "Note: mmHg is treated as gauge (0=atm, -760mmHg=vacuum).",
         UNIT_CONVERSION_PROMPT_KIND => "Enter item number: ",
         UNIT_CONVERSION_PROMPT_VALUE => "Value: ",
@@ -431,12 +654,16 @@ fn en(key: &str) -> Option<&'static str> {
         PROMPT_TARGET_VELOCITY => "Target velocity [m/s]: ",
         RESULT_RECOMMENDED_ID => "Recommended ID:",
         RESULT_EXPECTED_VELOCITY => "Expected velocity:",
+        RESULT_RECOMMENDED_VELOCITY => "Recommended velocity",
+        RESULT_VELOCITY_UTILIZATION => "Velocity limit utilization",
+        RESULT_VELOCITY_CHECK => "Velocity check:",
         PROMPT_DENSITY_OPTIONAL => "Steam density [kg/m3] (0 = auto via IF97): ",
         PROMPT_DIAMETER => "Pipe inner diameter [mm] (in/\"): ",
         PROMPT_LENGTH => "Pipe length [m]: ",
         PROMPT_EQ_LENGTH => "Equivalent length [m] (0 if none): ",
-        PROMPT_FITTINGS_K => "Fittings K sum (0 if none): ",
-        PROMPT_ROUGHNESS => "Roughness ε [m] (carbon steel ~0.000045): ",
+        PROMPT_ELEVATION_RISE => "Elevation rise outlet − inlet [m] (0 if level, negative = downhill): ",
+        PROMPT_FITTINGS_K => "Fittings: K sum or list, e.g. elbow90-lr:4, gate:2 (? = codes, Enter = none): ",
+        PROMPT_ROUGHNESS => "Roughness: material number/name or ε [m] (Enter = new carbon steel): ",
         PROMPT_VISCOSITY => "Dynamic viscosity [Pa·s] (steam ~1.2e-5): ",
         PROMPT_SOUND_SPEED => "Speed of sound [m/s] (default ~450): ",
         RESULT_PRESSURE_DROP => "Pressure-drop result:",
@@ -451,14 +678,25 @@ fn en(key: &str) -> Option<&'static str> {
         PROMPT_KV_CV_VALUE => "Kv/Cv value: ",
         PROMPT_UPSTREAM_PRESSURE => "Upstream pressure [bar(a)]: ",
         RESULT_POSSIBLE_FLOW => "Possible flow:",
+        STEAM_DRYNESS_HEADING => "\n-- Steam Dryness Calorimeters --",
+        STEAM_DRYNESS_OPTIONS => "1) Throttling  2) Separating  3) Separating + throttling",
+        PROMPT_MAIN_PRESSURE_ABS => "Main steam pressure [bar(a)]: ",
+        PROMPT_CALORIMETER_PRESSURE => "Calorimeter pressure after throttling [bar(a)]: ",
+        PROMPT_CALORIMETER_TEMPERATURE => "Calorimeter temperature after throttling [°C]: ",
+        PROMPT_SEPARATED_WATER => "Water collected in separator [kg]: ",
+        PROMPT_CONDENSED_STEAM => "Steam condensate collected [kg]: ",
+        RESULT_DRYNESS => "Dryness fraction:",
+        RESULT_THROTTLING_SUPERHEAT => "Superheat after throttling [K]:",
+        RESULT_MIN_MEASURABLE_DRYNESS => "Minimum measurable dryness (5 K superheat):",
+        STEAM_DRYNESS_WARNING => "Warning:",
         SETTINGS_HEADING => "\n-- Settings --",
         SETTINGS_CURRENT_UNIT_SYSTEM => "Current unit system:",
         SETTINGS_OPTIONS => "1) SI(Bar)  2) SI  3) MKS  4) Imperial",
         SETTINGS_PROMPT_CHANGE => "Enter number to change (enter to cancel): ",
         SETTINGS_INVALID => "Invalid input; unit system unchanged.",
         SETTINGS_SAVED => "Unit system changed to:",
-        PRESSURE_UNIT_OPTIONS => "Pressure units: 1=bar 2=kPa 3=MPa 4=psi 5=atm",
-        TEMPERATURE_UNIT_OPTIONS => "Temperature units: 1=°C 2=K 3=°F 4=R",
+        PRESSURE_UNIT_OPTIONS => "Pressure units: 1=bar 2=kPa 3=MPa 4=psi 5=atm (number or unit name)",
+        TEMPERATURE_UNIT_OPTIONS => "Temperature units: 1=°C 2=K 3=°F 4=R (number or unit name)",
         ERROR_INVALID_NUMBER => "Please enter a number.",
         STATE_SATURATION_T => "Saturation temperature:",
         STATE_SATURATION_P => "Saturation pressure:",
@@ -470,7 +708,15 @@ fn en(key: &str) -> Option<&'static str> {
         HELP_STEAM_PIPING_DROP => "Help: density 0 => auto IF97. Diameter accepts mm or inch. K-sum/equivalent length can be 0 if none.",
         HELP_STEAM_VALVES_REQUIRED => "Help: flow [m3/h], ΔP [bar], density [kg/m3] → compute required Kv/Cv.",
         HELP_STEAM_VALVES_FLOW => "Help: Kv or Cv, ΔP [bar], density [kg/m3], upstream P [bar(a)] → compute flow.",
+        HELP_STEAM_DRYNESS => "Help: throttling needs ≥ 5 K superheat after the throttle; separating gives an upper bound; combine both for wet steam.",
         HELP_SETTINGS => "Help: unit-system preset changes default units (SIBar/SI/MKS/Imperial).",
+        TRAP_SURVEY_HEADING => "\n-- Steam Leak / Failed Trap Savings Report --",
+        TRAP_SURVEY_STEAM_COST => "Steam cost [per t]:",
+        TRAP_SURVEY_COLUMNS => "Rank | Tag | Kind | Pressure [bar(a)] | Orifice [mm] | Steam [kg/h] | Annual [t] | Energy [GJ] | Cost [per year]",
+        TRAP_SURVEY_KIND_LEAK => "Leak",
+        TRAP_SURVEY_KIND_TRAP => "Failed trap",
+        TRAP_SURVEY_TOTAL => "Total:",
+        TRAP_SURVEY_WARNING => "Warning:",
         _ => return None,
     })
 }
@@ -1,16 +1,47 @@
 //! 핵심 계산 로직을 라이브러리로 분리하여 CLI 뿐 아니라 추후 GUI 확장도 쉽게 한다.
 
 pub mod air;
+#[cfg(feature = "cli")]
 pub mod app;
+#[cfg(feature = "cli")]
+pub mod cli_commands;
+pub mod card_inputs;
+pub mod combustion;
 pub mod condensate_recovery;
 pub mod config;
 pub mod conversion;
 pub mod cooling;
+pub mod custom_units;
+pub mod error;
+pub mod flow_coefficient;
+pub mod flow_units;
+pub mod fuzzy;
 pub mod gas;
+pub mod goal_seek;
 pub mod i18n;
 pub mod material_db;
+pub mod number_format;
+pub mod parallel;
+pub mod plant_piping;
 pub mod quantity;
+pub mod quantity_arg;
+pub mod report;
+pub mod result_text;
+pub mod show_work;
 pub mod steam;
+pub mod strapping_table;
+pub mod stream_state;
+pub mod sweep;
+pub mod table_edit;
+pub mod typed_quantity;
+#[cfg(feature = "cli")]
 pub mod ui_cli;
+#[cfg(feature = "tui")]
+pub mod ui_tui;
 pub mod units;
+pub mod valve_db;
+pub mod vessel_geometry;
+pub mod warning;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 pub mod water;
@@ -1,6 +1,14 @@
 use clap::Parser;
+#[cfg(feature = "tui")]
+use std::io::IsTerminal;
+use steam_engineering_toolbox::cli_commands::{self, Command, OutputFormat};
+use steam_engineering_toolbox::error::ToolboxError;
 use steam_engineering_toolbox::i18n::keys;
-use steam_engineering_toolbox::{app, config, i18n};
+use steam_engineering_toolbox::steam::steam_cost::EnergyUnitCostInput;
+use steam_engineering_toolbox::steam::steam_leak::LeakEconomics;
+#[cfg(feature = "tui")]
+use steam_engineering_toolbox::ui_tui;
+use steam_engineering_toolbox::{app, config, custom_units, i18n};
 
 #[derive(Parser, Debug)]
 #[command(name = "steam_engineering_toolbox_cli")]
@@ -8,23 +16,103 @@ struct CliArgs {
     /// UI language (auto|en-us|en-uk|ko-kr|ko). auto uses config, then system locale, then en-us.
     #[arg(long = "lang", short = 'L', default_value = "auto")]
     lang: String,
+    /// Named config profile to apply (e.g. a plant), see [profiles.<name>] in config.toml
+    #[arg(long = "profile", short = 'P', value_name = "NAME")]
+    profile: Option<String>,
+    /// Trap-survey CSV (tag,kind,pressure_bar_g,orifice_mm,hours_per_year[,cd]); prints a ranked savings report and exits.
+    #[arg(long = "trap-survey", value_name = "CSV")]
+    trap_survey: Option<String>,
+    /// Fuel price per fuel unit (used with --trap-survey; default from config steam_cost)
+    #[arg(long = "fuel-price")]
+    fuel_price: Option<f64>,
+    /// Fuel lower heating value [kJ per fuel unit]
+    #[arg(long = "fuel-lhv-kj")]
+    fuel_lhv_kj: Option<f64>,
+    /// Boiler efficiency (0-1)
+    #[arg(long = "boiler-efficiency")]
+    boiler_efficiency: Option<f64>,
+    /// Make-up water temperature [°C]
+    #[arg(long = "makeup-temp")]
+    makeup_temp: Option<f64>,
+    /// Output format for calculation results (subcommands and --trap-survey)
+    #[arg(long = "format", global = true, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// Shorthand for --format json
+    #[arg(long = "json", global = true)]
+    json: bool,
+    /// Append the formulas with the actual numbers substituted (Re, f, Kv, ...) to the result
+    #[arg(long = "show-work", global = true)]
+    show_work: bool,
+    /// Run one calculation without prompts (e.g. `steam sat --p 10bar_a`); omit for the interactive menu
+    #[command(subcommand)]
+    command: Option<Command>,
+    /// Use the line-based interactive menu instead of the full-screen TUI
+    #[cfg(feature = "tui")]
+    #[arg(long = "plain")]
+    plain: bool,
 }
 
 /// 프로그램의 엔트리 포인트. 설정을 로드한 뒤 CLI 애플리케이션을 실행한다.
 fn main() {
-    let args = CliArgs::parse();
-    if let Err((lang_code, err)) = try_run(&args) {
+    let mut args = CliArgs::parse();
+    if let Err((lang_code, err)) = try_run(&mut args) {
         let tr = i18n::Translator::new(&lang_code);
-        eprintln!("{}: {err}", tr.t(keys::ERROR_PREFIX));
+        eprintln!("{}: {}", tr.t(keys::ERROR_PREFIX), err.localized(&tr));
     }
 }
 
-fn try_run(args: &CliArgs) -> Result<(), (String, Box<dyn std::error::Error>)> {
+/// 실패하면 오류 문구에 쓸 언어 코드와 오류를 돌려준다. `ToolboxError`는 커서 `Box`에 담는다.
+fn try_run(args: &mut CliArgs) -> Result<(), (String, Box<ToolboxError>)> {
     let lang_hint = i18n::resolve_language(&args.lang, None);
-    let mut cfg = config::load_or_default().map_err(|e| (lang_hint.clone(), Box::new(e) as _))?;
+    let mut cfg =
+        config::load_or_default().map_err(|e| (lang_hint.clone(), ToolboxError::from(e).into()))?;
+    if let Some(name) = &args.profile {
+        cfg.apply_profile(name)
+            .map_err(|e| (lang_hint.clone(), ToolboxError::from(e).into()))?;
+    }
     let lang_code = i18n::resolve_language(&args.lang, Some(cfg.language.as_str()));
     cfg.language = lang_code.clone();
-    let tr = i18n::Translator::new_with_pack(&lang_code, cfg.language_pack_dir.as_deref());
-    app::run(&mut cfg, &tr).map_err(|e| (lang_code, Box::new(e) as _))?;
+    let mut tr = i18n::Translator::new_with_pack(&lang_code, cfg.language_pack_dir.as_deref());
+    tr.set_number_format(&cfg.number_format);
+    if let Err(e) = custom_units::load_default() {
+        let e = ToolboxError::from(e);
+        eprintln!("{}: {}", tr.t(keys::ERROR_PREFIX), e.localized(&tr));
+    }
+    let format = if args.json {
+        OutputFormat::Json
+    } else {
+        args.format
+    };
+    // 책자 단위는 지정하지 않으면 설정(프로필 포함)의 단위 시스템을 따른다
+    if let Some(Command::Booklet(booklet)) = &mut args.command {
+        booklet.units.get_or_insert(cfg.unit_system.into());
+    }
+    if let Some(command) = &args.command {
+        cli_commands::run(command, cfg.site.atmosphere(), &tr, format, args.show_work)
+            .map_err(|e| (lang_code, ToolboxError::from(e).into()))?;
+        return Ok(());
+    }
+    if let Some(path) = &args.trap_survey {
+        let cost = &cfg.steam_cost;
+        let economics = LeakEconomics {
+            energy: EnergyUnitCostInput {
+                fuel_price_per_unit: args.fuel_price.unwrap_or(cost.fuel_price_per_unit),
+                fuel_lhv_kj_per_unit: args.fuel_lhv_kj.unwrap_or(cost.fuel_lhv_kj_per_unit),
+                boiler_efficiency: args.boiler_efficiency.unwrap_or(cost.boiler_efficiency),
+            },
+            loss_factor: 0.0,
+            makeup_water_temp_c: args.makeup_temp.unwrap_or(cost.makeup_water_temp_c),
+        };
+        app::run_trap_survey(path, &economics, format, &tr)
+            .map_err(|e| (lang_code, ToolboxError::from(e).into()))?;
+        return Ok(());
+    }
+    // 터미널에서 실행하면 전체 화면 TUI, 파이프/리다이렉트나 --plain이면 줄 단위 메뉴
+    #[cfg(feature = "tui")]
+    if !args.plain && std::io::stdout().is_terminal() {
+        ui_tui::run(&cfg, &tr).map_err(|e| (lang_code, ToolboxError::from(e).into()))?;
+        return Ok(());
+    }
+    app::run(&mut cfg, &tr).map_err(|e| (lang_code, ToolboxError::from(e).into()))?;
     Ok(())
 }
@@ -1,4 +1,4 @@
-/// 단순한 온도-허용응력/인장강도 테이블과 선형 보간을 제공한다.
+/// 단순한 온도-허용응력/인장강도, 종탄성계수, 열팽창계수, 열전도율 테이블과 선형 보간을 제공한다.
 /// 값은 참고용이며 설계 시 최신 코드(ASME 등)로 검증해야 한다.
 
 #[derive(Debug, Clone, Copy)]
@@ -20,6 +20,19 @@ pub struct MaterialData {
     pub notes: &'static str,
     pub allowable: &'static [TempPoint],
     pub uts: &'static [TempPoint],
+    /// 종탄성계수 [MPa]
+    pub modulus: &'static [TempPoint],
+    /// 20 °C 기준 평균 열팽창계수. `value_mpa` 자리에 µm/(m·K) 값을 담는다.
+    pub expansion: &'static [TempPoint],
+    /// 열전도율. `value_mpa` 자리에 W/(m·K) 값을 담는다.
+    pub conductivity: &'static [TempPoint],
+}
+
+impl MaterialData {
+    /// 오스테나이트계 스테인리스(A312 TP 등급) 여부. B31 y 계수 등 재질군 구분에 쓴다.
+    pub fn is_austenitic(&self) -> bool {
+        self.code.starts_with("TP")
+    }
 }
 
 #[derive(Debug)]
@@ -50,6 +63,72 @@ pub fn uts(code: &str, temp_c: f64) -> Option<MaterialValue> {
     interpolate(mat.uts, temp_c)
 }
 
+/// 종탄성계수 E(T) [MPa]
+pub fn elastic_modulus(code: &str, temp_c: f64) -> Option<MaterialValue> {
+    let mat = find_material(code)?;
+    interpolate(mat.modulus, temp_c)
+}
+
+/// 20 °C에서 `temp_c`까지의 평균 열팽창계수 [1/K]. 범위 밖이면 가장자리 값을 쓴다.
+pub fn mean_expansion_coefficient(code: &str, temp_c: f64) -> Option<f64> {
+    let mat = find_material(code)?;
+    interpolate(mat.expansion, temp_c).map(|v| v.value_mpa * 1e-6)
+}
+
+/// `from_c`에서 `to_c`까지의 열변형률 ΔL/L [-].
+/// 20 °C 기준 평균 α이므로 ε = α(T₂)·(T₂−20) − α(T₁)·(T₁−20)로 두 온도의 팽창량 차를 구한다.
+pub fn thermal_strain(code: &str, from_c: f64, to_c: f64) -> Option<f64> {
+    let strain = |t: f64| mean_expansion_coefficient(code, t).map(|a| a * (t - 20.0));
+    Some(strain(to_c)? - strain(from_c)?)
+}
+
+/// 열전도율 k(T) [W/(m·K)]. 범위 밖이면 가장자리 값을 쓴다.
+pub fn thermal_conductivity(code: &str, temp_c: f64) -> Option<f64> {
+    let mat = find_material(code)?;
+    interpolate(mat.conductivity, temp_c).map(|v| v.value_mpa)
+}
+
+/// 배관 내면 절대 거칠기 프리셋. 압력손실 입력에서 재질을 고르면 ε를 채운다.
+#[derive(Debug)]
+pub struct RoughnessPreset {
+    pub code: &'static str,
+    /// 비전문가도 알아볼 수 있는 표시 이름 (언어팩이 없을 때)
+    pub name: &'static str,
+    /// 표시 이름의 언어팩 키
+    pub key: &'static str,
+    /// 절대 거칠기 ε [mm]
+    pub roughness_mm: f64,
+    pub notes: &'static str,
+}
+
+impl RoughnessPreset {
+    /// 절대 거칠기 ε [m]
+    pub fn roughness_m(&self) -> f64 {
+        self.roughness_mm / 1000.0
+    }
+}
+
+pub fn roughness_presets() -> &'static [RoughnessPreset] {
+    ROUGHNESS_PRESETS
+}
+
+/// 코드 또는 이름으로 거칠기 프리셋을 찾는다. 대소문자, 공백, `-`/`_`/`/`는 무시한다.
+pub fn find_roughness(code: &str) -> Option<&'static RoughnessPreset> {
+    let norm = |s: &str| -> String {
+        s.chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_' | '/'))
+            .flat_map(char::to_lowercase)
+            .collect()
+    };
+    let key = norm(code);
+    if key.is_empty() {
+        return None;
+    }
+    ROUGHNESS_PRESETS
+        .iter()
+        .find(|r| norm(r.code) == key || norm(r.name) == key)
+}
+
 fn interpolate(points: &[TempPoint], temp_c: f64) -> Option<MaterialValue> {
     if points.is_empty() {
         return None;
@@ -124,6 +203,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 340.0),
             tp(700.0, 320.0),
         ],
+        modulus: CS_MODULUS,
+        expansion: CS_EXPANSION,
+        conductivity: CS_CONDUCTIVITY,
     },
     MaterialData {
         code: "A53B",
@@ -154,6 +236,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 340.0),
             tp(700.0, 320.0),
         ],
+        modulus: CS_MODULUS,
+        expansion: CS_EXPANSION,
+        conductivity: CS_CONDUCTIVITY,
     },
     MaterialData {
         code: "A335P11",
@@ -178,6 +263,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 440.0),
             tp(700.0, 420.0),
         ],
+        modulus: CRMO_MODULUS,
+        expansion: CRMO_EXPANSION,
+        conductivity: CRMO_CONDUCTIVITY,
     },
     MaterialData {
         code: "A335P12",
@@ -202,6 +290,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 370.0),
             tp(700.0, 350.0),
         ],
+        modulus: CRMO_MODULUS,
+        expansion: CRMO_EXPANSION,
+        conductivity: CRMO_CONDUCTIVITY,
     },
     MaterialData {
         code: "A335P91",
@@ -226,6 +317,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 530.0),
             tp(700.0, 500.0),
         ],
+        modulus: CR9_MODULUS,
+        expansion: CR9_EXPANSION,
+        conductivity: CR9_CONDUCTIVITY,
     },
     MaterialData {
         code: "A335P92",
@@ -250,6 +344,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 560.0),
             tp(700.0, 530.0),
         ],
+        modulus: CR9_MODULUS,
+        expansion: CR9_EXPANSION,
+        conductivity: CR9_CONDUCTIVITY,
     },
     MaterialData {
         code: "TP304",
@@ -274,6 +371,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 460.0),
             tp(700.0, 440.0),
         ],
+        modulus: SS_MODULUS,
+        expansion: SS_EXPANSION,
+        conductivity: SS_CONDUCTIVITY,
     },
     MaterialData {
         code: "TP304L",
@@ -298,6 +398,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 430.0),
             tp(700.0, 410.0),
         ],
+        modulus: SS_MODULUS,
+        expansion: SS_EXPANSION,
+        conductivity: SS_CONDUCTIVITY,
     },
     MaterialData {
         code: "TP316",
@@ -322,6 +425,9 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 460.0),
             tp(700.0, 440.0),
         ],
+        modulus: SS_MODULUS,
+        expansion: SS_EXPANSION,
+        conductivity: SS_CONDUCTIVITY,
     },
     MaterialData {
         code: "TP316L",
@@ -346,6 +452,179 @@ const MATERIALS: &[MaterialData] = &[
             tp(600.0, 430.0),
             tp(700.0, 410.0),
         ],
+        modulus: SS_MODULUS,
+        expansion: SS_EXPANSION,
+        conductivity: SS_CONDUCTIVITY,
+    },
+];
+
+// 종탄성계수/평균 열팽창계수 (ASME B31.1 Table C-1/C-2 근사, 재질군별 공통)
+const CS_MODULUS: &[TempPoint] = &[
+    tp(20.0, 203_000.0),
+    tp(100.0, 198_000.0),
+    tp(200.0, 191_000.0),
+    tp(300.0, 184_000.0),
+    tp(400.0, 175_000.0),
+    tp(500.0, 164_000.0),
+    tp(600.0, 150_000.0),
+    tp(700.0, 136_000.0),
+];
+const CS_EXPANSION: &[TempPoint] = &[
+    tp(20.0, 11.5),
+    tp(100.0, 11.9),
+    tp(200.0, 12.6),
+    tp(300.0, 13.2),
+    tp(400.0, 13.8),
+    tp(500.0, 14.3),
+    tp(600.0, 14.7),
+    tp(700.0, 15.0),
+];
+const CRMO_MODULUS: &[TempPoint] = &[
+    tp(20.0, 206_000.0),
+    tp(100.0, 201_000.0),
+    tp(200.0, 195_000.0),
+    tp(300.0, 188_000.0),
+    tp(400.0, 180_000.0),
+    tp(500.0, 171_000.0),
+    tp(600.0, 160_000.0),
+    tp(700.0, 147_000.0),
+];
+const CRMO_EXPANSION: &[TempPoint] = &[
+    tp(20.0, 11.2),
+    tp(100.0, 11.7),
+    tp(200.0, 12.3),
+    tp(300.0, 12.9),
+    tp(400.0, 13.4),
+    tp(500.0, 13.9),
+    tp(600.0, 14.3),
+    tp(700.0, 14.6),
+];
+const CR9_MODULUS: &[TempPoint] = &[
+    tp(20.0, 218_000.0),
+    tp(100.0, 213_000.0),
+    tp(200.0, 207_000.0),
+    tp(300.0, 199_000.0),
+    tp(400.0, 190_000.0),
+    tp(500.0, 181_000.0),
+    tp(600.0, 170_000.0),
+    tp(700.0, 158_000.0),
+];
+const CR9_EXPANSION: &[TempPoint] = &[
+    tp(20.0, 10.4),
+    tp(100.0, 10.8),
+    tp(200.0, 11.3),
+    tp(300.0, 11.7),
+    tp(400.0, 12.0),
+    tp(500.0, 12.3),
+    tp(600.0, 12.6),
+    tp(700.0, 12.8),
+];
+const SS_MODULUS: &[TempPoint] = &[
+    tp(20.0, 195_000.0),
+    tp(100.0, 189_000.0),
+    tp(200.0, 182_000.0),
+    tp(300.0, 176_000.0),
+    tp(400.0, 169_000.0),
+    tp(500.0, 162_000.0),
+    tp(600.0, 154_000.0),
+    tp(700.0, 146_000.0),
+];
+const SS_EXPANSION: &[TempPoint] = &[
+    tp(20.0, 15.3),
+    tp(100.0, 16.0),
+    tp(200.0, 16.7),
+    tp(300.0, 17.2),
+    tp(400.0, 17.6),
+    tp(500.0, 18.0),
+    tp(600.0, 18.3),
+    tp(700.0, 18.6),
+];
+
+// 열전도율 [W/(m·K)] (ASME Sec II-D Table TCD 근사, 재질군별 공통)
+const CS_CONDUCTIVITY: &[TempPoint] = &[
+    tp(20.0, 51.9),
+    tp(100.0, 51.1),
+    tp(200.0, 48.6),
+    tp(300.0, 44.8),
+    tp(400.0, 41.4),
+    tp(500.0, 37.8),
+    tp(600.0, 34.3),
+    tp(700.0, 30.5),
+];
+const CRMO_CONDUCTIVITY: &[TempPoint] = &[
+    tp(20.0, 41.0),
+    tp(100.0, 40.9),
+    tp(200.0, 39.7),
+    tp(300.0, 38.0),
+    tp(400.0, 36.0),
+    tp(500.0, 33.8),
+    tp(600.0, 31.4),
+    tp(700.0, 28.9),
+];
+const CR9_CONDUCTIVITY: &[TempPoint] = &[
+    tp(20.0, 26.0),
+    tp(100.0, 27.0),
+    tp(200.0, 28.0),
+    tp(300.0, 28.5),
+    tp(400.0, 28.7),
+    tp(500.0, 28.7),
+    tp(600.0, 28.5),
+    tp(700.0, 28.3),
+];
+const SS_CONDUCTIVITY: &[TempPoint] = &[
+    tp(20.0, 14.8),
+    tp(100.0, 16.2),
+    tp(200.0, 17.6),
+    tp(300.0, 19.0),
+    tp(400.0, 20.4),
+    tp(500.0, 21.6),
+    tp(600.0, 22.9),
+    tp(700.0, 24.2),
+];
+
+// 절대 거칠기 (Moody 선도/Crane TP-410 등 일반 문헌값). 실제 배관은 범위가 넓으니 대표값이다.
+const ROUGHNESS_PRESETS: &[RoughnessPreset] = &[
+    RoughnessPreset {
+        code: "steel-new",
+        name: "New carbon steel",
+        key: "material.roughness.steel_new",
+        roughness_mm: 0.045,
+        notes: "신품 상용 강관",
+    },
+    RoughnessPreset {
+        code: "steel-corroded",
+        name: "Old / corroded steel",
+        key: "material.roughness.steel_corroded",
+        roughness_mm: 0.5,
+        notes: "사용 중 녹·스케일 발생 강관, 0.15~1 mm 범위",
+    },
+    RoughnessPreset {
+        code: "stainless",
+        name: "Stainless steel",
+        key: "material.roughness.stainless",
+        roughness_mm: 0.015,
+        notes: "인발/냉간 가공 스테인리스 관",
+    },
+    RoughnessPreset {
+        code: "copper",
+        name: "Copper tube",
+        key: "material.roughness.copper",
+        roughness_mm: 0.0015,
+        notes: "인발 동관",
+    },
+    RoughnessPreset {
+        code: "pvc",
+        name: "PVC / plastic",
+        key: "material.roughness.pvc",
+        roughness_mm: 0.0015,
+        notes: "PVC, PE 등 플라스틱 관",
+    },
+    RoughnessPreset {
+        code: "concrete",
+        name: "Concrete",
+        key: "material.roughness.concrete",
+        roughness_mm: 1.0,
+        notes: "콘크리트 관, 0.3~3 mm 범위",
     },
 ];
 
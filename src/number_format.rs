@@ -0,0 +1,154 @@
+//! 결과 숫자 표시 형식(반올림/유효 숫자/소수점 기호/천 단위 구분/지수 표기).
+//! GUI와 CLI의 결과 출력은 고정 소수 자릿수 대신 이 설정을 거쳐 문자열로 만든다.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::quantity::QuantityKind;
+
+/// 숫자 표시 설정. `config.toml`의 `[number_format]` 섹션.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NumberFormat {
+    /// 유효 숫자 자릿수. `None`이면 호출부/물리량별 소수 자릿수를 쓴다.
+    pub significant_figures: Option<usize>,
+    /// 소수점 기호 ('.' 또는 ',')
+    pub decimal_separator: char,
+    /// 천 단위 구분 기호. `None`이면 묶지 않는다.
+    pub thousands_separator: Option<char>,
+    /// 절댓값이 이 이상이면 지수 표기
+    pub scientific_above: f64,
+    /// 0이 아닌 절댓값이 이 미만이면 지수 표기
+    pub scientific_below: f64,
+    /// 물리량별 소수 자릿수 덮어쓰기 (키: `quantity_key`)
+    pub quantity_decimals: BTreeMap<String, usize>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            significant_figures: None,
+            decimal_separator: '.',
+            thousands_separator: None,
+            scientific_above: 1.0e9,
+            scientific_below: 1.0e-4,
+            quantity_decimals: BTreeMap::new(),
+        }
+    }
+}
+
+/// 설정 파일에서 쓰는 물리량 키.
+pub fn quantity_key(kind: QuantityKind) -> &'static str {
+    match kind {
+        QuantityKind::Temperature => "temperature",
+        QuantityKind::TemperatureDifference => "temperature_diff",
+        QuantityKind::Pressure => "pressure",
+        QuantityKind::Length => "length",
+        QuantityKind::Area => "area",
+        QuantityKind::Volume => "volume",
+        QuantityKind::Velocity => "velocity",
+        QuantityKind::Mass => "mass",
+        QuantityKind::Viscosity => "viscosity",
+        QuantityKind::Energy => "energy",
+        QuantityKind::HeatTransferCoeff => "heat_transfer",
+        QuantityKind::ThermalConductivity => "conductivity",
+        QuantityKind::SpecificEnthalpy => "specific_enthalpy",
+    }
+}
+
+/// 물리량별 기본 소수 자릿수.
+pub fn default_decimals(kind: QuantityKind) -> usize {
+    match kind {
+        QuantityKind::Temperature | QuantityKind::TemperatureDifference => 1,
+        QuantityKind::Pressure => 3,
+        QuantityKind::Length | QuantityKind::Area | QuantityKind::Volume => 4,
+        QuantityKind::Velocity | QuantityKind::Mass => 2,
+        QuantityKind::Viscosity => 7,
+        QuantityKind::Energy | QuantityKind::SpecificEnthalpy => 1,
+        QuantityKind::HeatTransferCoeff => 1,
+        QuantityKind::ThermalConductivity => 3,
+    }
+}
+
+impl NumberFormat {
+    /// 물리량의 소수 자릿수 (덮어쓰기 → 기본값 순).
+    pub fn decimals_for(&self, kind: QuantityKind) -> usize {
+        self.quantity_decimals
+            .get(quantity_key(kind))
+            .copied()
+            .unwrap_or_else(|| default_decimals(kind))
+    }
+
+    /// 물리량 기본 자릿수로 표시한다.
+    pub fn format_quantity(&self, value: f64, kind: QuantityKind) -> String {
+        self.format(value, self.decimals_for(kind))
+    }
+
+    /// 소수 `decimals`자리(유효 숫자 설정이 있으면 그것)로 표시한다.
+    pub fn format(&self, value: f64, decimals: usize) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let abs = value.abs();
+        if abs != 0.0 && (abs >= self.scientific_above || abs < self.scientific_below) {
+            let mantissa_digits = self
+                .significant_figures
+                .map(|sf| sf.max(1) - 1)
+                .unwrap_or(decimals.clamp(1, 6));
+            let s = format!("{:.*e}", mantissa_digits, value);
+            return self.localize(&s, false);
+        }
+        let decimals = match self.significant_figures {
+            Some(sf) if abs != 0.0 => {
+                let magnitude = abs.log10().floor() as i32;
+                (sf.max(1) as i32 - 1 - magnitude).max(0) as usize
+            }
+            _ => decimals,
+        };
+        self.localize(&format!("{:.*}", decimals, value), true)
+    }
+
+    /// 이미 고정 소수로 만든 숫자 문자열("12.30", "-5")을 같은 자릿수로 다시 표시한다.
+    /// 숫자가 아닌 문자열은 그대로 돌려준다.
+    pub fn reformat(&self, text: &str) -> String {
+        let plain = !text.is_empty()
+            && text
+                .strip_prefix('-')
+                .unwrap_or(text)
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == '.');
+        match text.parse::<f64>() {
+            Ok(value) if plain => {
+                let decimals = text.split_once('.').map(|(_, f)| f.len()).unwrap_or(0);
+                self.format(value, decimals)
+            }
+            _ => text.to_string(),
+        }
+    }
+
+    /// '.' 소수점을 설정 기호로 바꾸고 정수부를 천 단위로 묶는다.
+    fn localize(&self, s: &str, group: bool) -> String {
+        let (sign, body) = match s.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", s),
+        };
+        let (int_part, rest) = match body.find(['.', 'e']) {
+            Some(i) => body.split_at(i),
+            None => (body, ""),
+        };
+        let mut out = String::from(sign);
+        match self.thousands_separator {
+            Some(sep) if group && int_part.len() > 3 => {
+                for (i, c) in int_part.chars().enumerate() {
+                    if i > 0 && (int_part.len() - i) % 3 == 0 {
+                        out.push(sep);
+                    }
+                    out.push(c);
+                }
+            }
+            _ => out.push_str(int_part),
+        }
+        out.push_str(&rest.replace('.', &self.decimal_separator.to_string()));
+        out
+    }
+}
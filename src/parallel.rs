@@ -0,0 +1,38 @@
+//! 스윕·배치·네트워크 요소 평가처럼 서로 독립인 계산을 반복하는 경로의 병렬 실행 도우미.
+//! `parallel` 기능이 켜져 있으면 rayon 스레드 풀에 나눠 계산하고, 꺼져 있으면(wasm 등) 순차로 계산한다.
+//! 어느 쪽이든 결과 순서는 입력 순서와 같다.
+
+/// 항목이 이보다 적으면 스레드 분배 비용이 더 커서 순차로 계산한다.
+pub const PARALLEL_MIN_ITEMS: usize = 16;
+
+/// 병렬 실행 경로가 빌드에 포함되었는지 여부.
+pub fn is_enabled() -> bool {
+    cfg!(feature = "parallel")
+}
+
+/// `items`의 각 항목에 `f`를 적용한 결과를 입력 순서대로 돌려준다.
+pub fn map<T, R, F>(items: &[T], f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync + Send,
+{
+    #[cfg(feature = "parallel")]
+    if items.len() >= PARALLEL_MIN_ITEMS {
+        use rayon::prelude::*;
+        return items.par_iter().map(f).collect();
+    }
+    items.iter().map(f).collect()
+}
+
+/// `map`과 같되 실패 가능한 계산용. 오류가 여러 개면 입력 순서상 첫 오류를 돌려준다
+/// (병렬 실행에서도 순차 실행과 같은 오류가 보고되도록).
+pub fn try_map<T, R, E, F>(items: &[T], f: F) -> Result<Vec<R>, E>
+where
+    T: Sync,
+    R: Send,
+    E: Send,
+    F: Fn(&T) -> Result<R, E> + Sync + Send,
+{
+    map(items, f).into_iter().collect()
+}
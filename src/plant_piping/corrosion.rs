@@ -0,0 +1,222 @@
+//! 두께 측정 기반 부식 여유와 잔여 수명 평가 (API 570 방식).
+//! 은퇴 두께는 B31 내압 필요 두께와 구조 최소 두께 중 큰 값으로 하고,
+//! 잔여 부식 여유 = 측정 두께 − 은퇴 두께, 잔여 수명 = 잔여 부식 여유 / 부식 속도로 구한다.
+//! 부식 속도를 주지 않으면 (공칭 − 측정) / 사용 연수의 장기 부식 속도를 쓴다.
+//! 다음 검사 시기는 잔여 수명의 절반으로 본다.
+
+use super::wall_thickness::{self, WallThicknessError, WallThicknessInput, WallThicknessWarning};
+use crate::warning::{CalcWarning, Severity};
+
+/// 부식 평가 입력.
+#[derive(Debug, Clone)]
+pub struct CorrosionInput {
+    /// 설계 조건. 부식 여유는 원래 설계값을 넣는다.
+    pub design: WallThicknessInput,
+    /// 공칭 두께 [m]
+    pub nominal_thickness_m: f64,
+    /// 측정 두께(최소 측정값) [m]
+    pub measured_thickness_m: f64,
+    /// 부식 속도 [mm/yr]. 없으면 사용 연수로 장기 부식 속도를 구한다.
+    pub corrosion_rate_mm_per_yr: Option<f64>,
+    /// 사용 연수 [yr]
+    pub years_in_service: Option<f64>,
+    /// 구조 최소 두께 [m] (API 574 표 등)
+    pub structural_minimum_m: Option<f64>,
+}
+
+/// 부식 평가 결과.
+#[derive(Debug, Clone)]
+pub struct CorrosionResult {
+    /// 내압 필요 두께 t [m] (부식 여유 제외)
+    pub pressure_thickness_m: f64,
+    /// 은퇴 두께 [m]
+    pub retirement_thickness_m: f64,
+    /// 잔여 부식 여유 [m]. 음수면 은퇴 두께 미만.
+    pub remaining_allowance_m: f64,
+    /// 적용 부식 속도 [mm/yr]
+    pub corrosion_rate_mm_per_yr: f64,
+    /// 잔여 수명 [yr]. 부식 속도가 0이면 `None`.
+    pub remaining_life_years: Option<f64>,
+    /// 다음 검사까지 [yr]
+    pub next_inspection_years: Option<f64>,
+    /// 측정 두께로 허용되는 최고 압력 [bar(g)]
+    pub mawp_bar_g: f64,
+    /// 측정 두께가 은퇴 두께 미만
+    pub below_retirement: bool,
+    pub warnings: Vec<CorrosionWarning>,
+}
+
+/// 부식 평가 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CorrosionWarning {
+    /// 설계 두께 계산 경고
+    Design(WallThicknessWarning),
+    /// 측정 두께가 은퇴 두께 미만
+    BelowRetirement {
+        measured_mm: f64,
+        retirement_mm: f64,
+        mawp_bar_g: f64,
+    },
+    /// 설계 부식 여유를 모두 소모
+    AllowanceConsumed { allowance_mm: f64, loss_mm: f64 },
+    /// 측정 두께가 공칭 두께보다 큼
+    MeasuredAboveNominal,
+    /// 잔여 수명이 짧음
+    ShortRemainingLife { years: f64 },
+}
+
+impl CalcWarning for CorrosionWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            CorrosionWarning::Design(w) => w.severity(),
+            CorrosionWarning::BelowRetirement { .. } => Severity::Critical,
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            CorrosionWarning::Design(w) => w.i18n_key(),
+            CorrosionWarning::BelowRetirement { .. } => "warning.corrosion.below_retirement",
+            CorrosionWarning::AllowanceConsumed { .. } => "warning.corrosion.allowance_consumed",
+            CorrosionWarning::MeasuredAboveNominal => "warning.corrosion.measured_above_nominal",
+            CorrosionWarning::ShortRemainingLife { .. } => "warning.corrosion.short_life",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            CorrosionWarning::Design(w) => w.args(),
+            CorrosionWarning::BelowRetirement {
+                measured_mm,
+                retirement_mm,
+                mawp_bar_g,
+            } => vec![
+                ("measured", format!("{measured_mm:.2}")),
+                ("retirement", format!("{retirement_mm:.2}")),
+                ("mawp", format!("{mawp_bar_g:.1}")),
+            ],
+            CorrosionWarning::AllowanceConsumed {
+                allowance_mm,
+                loss_mm,
+            } => vec![
+                ("allowance", format!("{allowance_mm:.2}")),
+                ("loss", format!("{loss_mm:.2}")),
+            ],
+            CorrosionWarning::MeasuredAboveNominal => Vec::new(),
+            CorrosionWarning::ShortRemainingLife { years } => {
+                vec![("years", format!("{years:.1}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for CorrosionWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CorrosionWarning::Design(w) => w.fmt(f),
+            CorrosionWarning::BelowRetirement {
+                measured_mm,
+                retirement_mm,
+                mawp_bar_g,
+            } => write!(
+                f,
+                "측정 두께 {measured_mm:.2} mm가 은퇴 두께 {retirement_mm:.2} mm 미만입니다. 교체하거나 설계 압력을 {mawp_bar_g:.1} bar(g) 이하로 낮추세요."
+            ),
+            CorrosionWarning::AllowanceConsumed {
+                allowance_mm,
+                loss_mm,
+            } => write!(
+                f,
+                "설계 부식 여유 {allowance_mm:.2} mm를 모두 소모했습니다 (감육 {loss_mm:.2} mm)."
+            ),
+            CorrosionWarning::MeasuredAboveNominal => f.write_str(
+                "측정 두께가 공칭 두께보다 큽니다. 측정 위치와 값을 확인하세요.",
+            ),
+            CorrosionWarning::ShortRemainingLife { years } => {
+                write!(f, "잔여 수명 {years:.1}년으로 짧습니다. 교체 계획을 세우세요.")
+            }
+        }
+    }
+}
+
+/// 측정 두께로 잔여 부식 여유, 잔여 수명, 은퇴 여부를 평가한다.
+pub fn assess_corrosion(input: &CorrosionInput) -> Result<CorrosionResult, WallThicknessError> {
+    let nominal = input.nominal_thickness_m;
+    let measured = input.measured_thickness_m;
+    if nominal <= 0.0 || measured <= 0.0 || 2.0 * measured >= input.design.outer_diameter_m {
+        return Err(WallThicknessError::InvalidInput(
+            "공칭/측정 두께는 0보다 크고 외경의 절반 미만이어야 합니다.",
+        ));
+    }
+    let rate = match (input.corrosion_rate_mm_per_yr, input.years_in_service) {
+        (Some(rate), _) if rate >= 0.0 => rate,
+        (Some(_), _) => {
+            return Err(WallThicknessError::InvalidInput(
+                "부식 속도는 음수일 수 없습니다.",
+            ))
+        }
+        (None, Some(years)) if years > 0.0 => ((nominal - measured) * 1000.0 / years).max(0.0),
+        (None, _) => {
+            return Err(WallThicknessError::InvalidInput(
+                "부식 속도나 0보다 큰 사용 연수가 필요합니다.",
+            ))
+        }
+    };
+
+    let design = wall_thickness::required_wall_thickness(&input.design)?;
+    let t_pressure = design.pressure_design_thickness_m;
+    let retirement = input
+        .structural_minimum_m
+        .map_or(t_pressure, |s| s.max(t_pressure));
+    let remaining = measured - retirement;
+    let below_retirement = remaining < 0.0;
+    let remaining_life = if rate > 0.0 {
+        Some((remaining * 1000.0 / rate).max(0.0))
+    } else {
+        None
+    };
+    let mawp = wall_thickness::pressure_for_thickness_bar_g(
+        measured,
+        input.design.outer_diameter_m,
+        design.allowable_stress_mpa,
+        input.design.weld_joint_efficiency * input.design.weld_strength_reduction,
+        design.y_coefficient,
+    );
+
+    let mut warnings: Vec<CorrosionWarning> = design
+        .warnings
+        .into_iter()
+        .map(CorrosionWarning::Design)
+        .collect();
+    if below_retirement {
+        warnings.push(CorrosionWarning::BelowRetirement {
+            measured_mm: measured * 1000.0,
+            retirement_mm: retirement * 1000.0,
+            mawp_bar_g: mawp,
+        });
+    } else if nominal - measured > input.design.corrosion_allowance_m {
+        warnings.push(CorrosionWarning::AllowanceConsumed {
+            allowance_mm: input.design.corrosion_allowance_m * 1000.0,
+            loss_mm: (nominal - measured) * 1000.0,
+        });
+    }
+    if measured > nominal {
+        warnings.push(CorrosionWarning::MeasuredAboveNominal);
+    }
+    if let Some(life) = remaining_life.filter(|&l| !below_retirement && l < 4.0) {
+        warnings.push(CorrosionWarning::ShortRemainingLife { years: life });
+    }
+
+    Ok(CorrosionResult {
+        pressure_thickness_m: t_pressure,
+        retirement_thickness_m: retirement,
+        remaining_allowance_m: remaining,
+        corrosion_rate_mm_per_yr: rate,
+        remaining_life_years: remaining_life,
+        next_inspection_years: remaining_life.map(|l| l / 2.0),
+        mawp_bar_g: mawp,
+        below_retirement,
+        warnings,
+    })
+}
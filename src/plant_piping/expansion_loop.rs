@@ -0,0 +1,185 @@
+//! 열팽창 루프/레그 사이징 (guided cantilever 방법).
+//! 팽창량 Δ를 흡수하는 수직 레그를 양단 회전이 구속된 외팔보로 보고
+//! 필요 레그 길이 L = √(3·E·D·Δ / S_A), 앵커 반력 F = 12·E·I·Δ / L³,
+//! 굽힘 응력 S = 3·E·D·Δ / L²을 계산한다.
+//! 팽창량은 재질 DB의 α(T)로 설치·운전 온도의 열변형률 차를 쓰고,
+//! 운전 중 반력은 B31.1처럼 냉간 반력에 E_h/E_c를 곱해 구한다.
+//! 허용 변위응력 범위 S_A는 ASME B31.1 식 f·(1.25·S_c + 0.25·S_h), f = 1.0으로 본다.
+//! 엘보 유연도와 응력강화계수는 무시하므로 예비 검토용이다.
+
+use crate::material_db;
+
+/// 팽창 흡수 배치 형식.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LoopLayout {
+    /// L형 굴곡: 긴 레그의 팽창을 짧은 레그가 흡수한다.
+    LBend { long_leg_m: f64 },
+    /// Z형 굴곡: 양쪽 평행 레그의 팽창을 가운데 오프셋 레그가 흡수한다.
+    ZBend { leg1_m: f64, leg2_m: f64 },
+    /// U 루프: 앵커 사이 직관의 팽창을 루프의 두 수직 레그가 절반씩 흡수한다.
+    ULoop { anchor_spacing_m: f64 },
+}
+
+/// 팽창 루프 사이징 입력.
+#[derive(Debug, Clone)]
+pub struct ExpansionLoopInput {
+    /// 배관 외경 [m]
+    pub outer_diameter_m: f64,
+    /// 배관 두께 [m]
+    pub wall_thickness_m: f64,
+    /// 재질 코드 (`material_db` 기준, 예: "A106B")
+    pub material: String,
+    /// 설치(냉간) 온도 [°C]
+    pub install_temp_c: f64,
+    /// 운전(설계) 온도 [°C]
+    pub design_temp_c: f64,
+    pub layout: LoopLayout,
+    /// 실제 확보 가능한 레그 길이 [m]. 지정하면 이 길이로 응력/반력을 계산한다.
+    pub available_leg_m: Option<f64>,
+}
+
+/// 팽창 루프 사이징 결과.
+#[derive(Debug, Clone)]
+pub struct ExpansionLoopResult {
+    /// 설치~운전 온도 구간의 평균 열팽창계수 [1/K]
+    pub expansion_coeff_per_k: f64,
+    /// 냉간 종탄성계수 [MPa]
+    pub modulus_cold_mpa: f64,
+    /// 운전 온도 종탄성계수 [MPa]
+    pub modulus_hot_mpa: f64,
+    /// 흡수해야 할 전체 팽창량 [mm]
+    pub total_expansion_mm: f64,
+    /// 레그 하나가 받는 변위 [mm]
+    pub leg_displacement_mm: f64,
+    /// 허용 변위응력 범위 S_A [MPa]
+    pub allowable_range_mpa: f64,
+    /// 필요 레그 길이 [m] (U 루프는 수직 레그 높이)
+    pub required_leg_m: f64,
+    /// U 루프 권장 폭 [m] (높이의 1/2). 그 외 형식은 `None`.
+    pub loop_width_m: Option<f64>,
+    /// 응력/반력 계산에 쓴 레그 길이 [m]
+    pub leg_m: f64,
+    /// 변위 굽힘 응력 [MPa]
+    pub stress_mpa: f64,
+    /// 앵커(가이드) 반력 [N] (냉간 E 기준)
+    pub anchor_force_n: f64,
+    /// 운전 중 앵커 반력 [N] = 반력 · E_h/E_c
+    pub hot_anchor_force_n: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 팽창 루프 계산 오류.
+#[derive(Debug, Clone)]
+pub enum ExpansionLoopError {
+    InvalidInput(&'static str),
+    UnknownMaterial(String),
+}
+
+impl std::fmt::Display for ExpansionLoopError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpansionLoopError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            ExpansionLoopError::UnknownMaterial(code) => write!(f, "알 수 없는 재질: {code}"),
+        }
+    }
+}
+
+impl std::error::Error for ExpansionLoopError {}
+
+/// 재질 DB의 E, α, 허용응력으로 팽창 흡수 레그 길이와 앵커 반력을 구한다.
+pub fn size_expansion_loop(
+    input: ExpansionLoopInput,
+) -> Result<ExpansionLoopResult, ExpansionLoopError> {
+    let d = input.outer_diameter_m;
+    let t = input.wall_thickness_m;
+    if d <= 0.0 || t <= 0.0 || 2.0 * t >= d {
+        return Err(ExpansionLoopError::InvalidInput(
+            "외경과 두께는 0보다 크고 두께는 외경의 절반 미만이어야 합니다.",
+        ));
+    }
+    let run_m = match input.layout {
+        LoopLayout::LBend { long_leg_m } => long_leg_m,
+        LoopLayout::ZBend { leg1_m, leg2_m } => {
+            if leg1_m < 0.0 || leg2_m < 0.0 {
+                -1.0
+            } else {
+                leg1_m + leg2_m
+            }
+        }
+        LoopLayout::ULoop { anchor_spacing_m } => anchor_spacing_m,
+    };
+    if run_m <= 0.0 {
+        return Err(ExpansionLoopError::InvalidInput(
+            "팽창 구간 길이는 0보다 커야 합니다.",
+        ));
+    }
+    if matches!(input.available_leg_m, Some(l) if l <= 0.0) {
+        return Err(ExpansionLoopError::InvalidInput(
+            "확보 레그 길이는 0보다 커야 합니다.",
+        ));
+    }
+
+    let unknown = || ExpansionLoopError::UnknownMaterial(input.material.clone());
+    let t_cold = input.install_temp_c.min(input.design_temp_c);
+    let t_hot = input.install_temp_c.max(input.design_temp_c);
+    let strain = material_db::thermal_strain(&input.material, t_cold, t_hot).ok_or_else(unknown)?;
+    let e_cold = material_db::elastic_modulus(&input.material, t_cold).ok_or_else(unknown)?;
+    let e_hot = material_db::elastic_modulus(&input.material, t_hot).ok_or_else(unknown)?;
+    let s_c = material_db::allowable_stress(&input.material, t_cold).ok_or_else(unknown)?;
+    let s_h = material_db::allowable_stress(&input.material, t_hot).ok_or_else(unknown)?;
+
+    if t_hot - t_cold <= 0.0 {
+        return Err(ExpansionLoopError::InvalidInput(
+            "설치 온도와 운전 온도가 같아 팽창량이 없습니다.",
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    if s_h.clamped && t_hot > s_h.source_temp_c {
+        warnings.push(format!(
+            "운전 온도 {t_hot:.0} °C가 재질 테이블 범위를 넘어 {:.0} °C 값을 사용했습니다.",
+            s_h.source_temp_c
+        ));
+    }
+
+    let total_m = strain * run_m;
+    let leg_delta_m = match input.layout {
+        LoopLayout::ULoop { .. } => total_m / 2.0,
+        _ => total_m,
+    };
+    let e = e_cold.value_mpa;
+    let s_a = 1.25 * s_c.value_mpa + 0.25 * s_h.value_mpa;
+    let required = (3.0 * e * d * leg_delta_m / s_a).sqrt();
+    let leg = input.available_leg_m.unwrap_or(required);
+
+    let stress = 3.0 * e * d * leg_delta_m / (leg * leg);
+    let inner = d - 2.0 * t;
+    let i_m4 = std::f64::consts::PI / 64.0 * (d.powi(4) - inner.powi(4));
+    // E [MPa] → [Pa]
+    let force = 12.0 * e * 1e6 * i_m4 * leg_delta_m / leg.powi(3);
+
+    if stress > s_a {
+        warnings.push(format!(
+            "레그 {leg:.2} m에서 변위응력 {stress:.0} MPa가 허용 범위 {s_a:.0} MPa를 넘습니다. 필요 길이는 {required:.2} m입니다."
+        ));
+    }
+
+    Ok(ExpansionLoopResult {
+        expansion_coeff_per_k: strain / (t_hot - t_cold),
+        modulus_cold_mpa: e,
+        modulus_hot_mpa: e_hot.value_mpa,
+        total_expansion_mm: total_m * 1000.0,
+        leg_displacement_mm: leg_delta_m * 1000.0,
+        allowable_range_mpa: s_a,
+        required_leg_m: required,
+        loop_width_m: match input.layout {
+            LoopLayout::ULoop { .. } => Some(required / 2.0),
+            _ => None,
+        },
+        leg_m: leg,
+        stress_mpa: stress,
+        anchor_force_n: force,
+        hot_anchor_force_n: force * e_hot.value_mpa / e,
+        warnings,
+    })
+}
@@ -0,0 +1,349 @@
+//! 차압식 유량계(오리피스/노즐/벤투리)의 영구 압력손실과 압력 회복.
+//! 오리피스와 ISA 노즐은 ISO 5167-2/-3 식
+//! Δϖ/Δp = (√(1−β⁴(1−C²)) − Cβ²) / (√(1−β⁴(1−C²)) + Cβ²)로 영구 손실 비율을 구하고,
+//! 벤투리는 ISO 5167-4의 5~20% 범위에서 7° 확산관 대표값 10%를 쓴다.
+//! 압력 회복 거리는 오리피스/노즐이 하류 약 6D, 벤투리는 확산관 길이로 본다.
+//! 영구 손실을 메우는 펌프(송풍기) 동력과 연간 에너지 비용을 함께 계산해 계량기 선정을 비교한다.
+//! 같은 식을 거꾸로 풀어 정해진 유량에서 원하는 영구 손실을 내는 제한 오리피스 β도 구한다.
+
+use crate::warning::{CalcWarning, Severity};
+
+/// 오리피스/노즐 하류 압력 회복 거리 [배관 내경 배수] (ISO 5167-2 Δϖ 측정 위치).
+pub const ORIFICE_RECOVERY_DIAMETERS: f64 = 6.0;
+/// 벤투리 영구 손실 비율 (7° 확산관 대표값).
+pub const VENTURI_LOSS_RATIO: f64 = 0.10;
+/// 벤투리 확산관 전체 각도 [°].
+pub const VENTURI_DIVERGENT_ANGLE_DEG: f64 = 7.0;
+/// 제한 오리피스 β 역산(이분법) 반복 횟수.
+const BETA_ITERATIONS: usize = 80;
+
+/// 차압식 유량계 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeterType {
+    Orifice,
+    Nozzle,
+    Venturi,
+}
+
+impl MeterType {
+    pub const ALL: [MeterType; 3] = [MeterType::Orifice, MeterType::Nozzle, MeterType::Venturi];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MeterType::Orifice => "Orifice (sharp)",
+            MeterType::Nozzle => "Nozzle (ISA)",
+            MeterType::Venturi => "Venturi",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            MeterType::Orifice => "meter_type.orifice",
+            MeterType::Nozzle => "meter_type.nozzle",
+            MeterType::Venturi => "meter_type.venturi",
+        }
+    }
+
+    /// 대표 유출계수 C.
+    pub fn typical_discharge_coefficient(self) -> f64 {
+        match self {
+            MeterType::Orifice => 0.61,
+            MeterType::Nozzle => 0.98,
+            MeterType::Venturi => 0.985,
+        }
+    }
+
+    /// ISO 5167 적용 β 범위.
+    pub fn beta_range(self) -> (f64, f64) {
+        match self {
+            MeterType::Orifice => (0.1, 0.75),
+            MeterType::Nozzle => (0.3, 0.8),
+            MeterType::Venturi => (0.3, 0.75),
+        }
+    }
+}
+
+/// 영구 압력손실 계산 입력.
+#[derive(Debug, Clone)]
+pub struct MeterLossInput {
+    pub meter: MeterType,
+    /// 직경비 β = d/D
+    pub beta: f64,
+    /// 배관 내경 D [m]
+    pub pipe_diameter_m: f64,
+    /// 계량 차압 Δp [bar]
+    pub differential_bar: f64,
+    /// 운전 체적유량 [m³/h]
+    pub volume_flow_m3_per_h: f64,
+    /// 유출계수 C. 없으면 계량기 대표값.
+    pub discharge_coefficient: Option<f64>,
+    /// 손실을 메우는 펌프/송풍기 효율 (0~1]
+    pub pump_efficiency: f64,
+    /// 연간 운전 시간 [h/yr]
+    pub hours_per_year: f64,
+    /// 전력 단가 [통화/kWh]
+    pub energy_price_per_kwh: f64,
+}
+
+/// 영구 압력손실 계산 결과.
+#[derive(Debug, Clone)]
+pub struct MeterLossResult {
+    pub meter: MeterType,
+    pub beta: f64,
+    pub discharge_coefficient: f64,
+    /// 계량 차압 Δp [bar]
+    pub differential_bar: f64,
+    /// 영구 손실 비율 Δϖ/Δp
+    pub loss_ratio: f64,
+    /// 영구 압력손실 Δϖ [bar]
+    pub permanent_loss_bar: f64,
+    /// 하류에서 회복되는 압력 [bar]
+    pub recovered_bar: f64,
+    /// 압력 회복 거리 [m] (계량기 하류)
+    pub recovery_distance_m: f64,
+    /// 영구 손실을 메우는 동력 [kW]
+    pub pumping_power_kw: f64,
+    /// 연간 에너지 [kWh/yr]
+    pub annual_energy_kwh: f64,
+    /// 연간 에너지 비용 [통화/yr]
+    pub annual_cost: f64,
+    pub warnings: Vec<FlowMeterWarning>,
+}
+
+/// 유량계 손실 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FlowMeterWarning {
+    /// β가 ISO 5167 적용 범위 밖
+    BetaOutOfRange { beta: f64, min: f64, max: f64 },
+}
+
+impl CalcWarning for FlowMeterWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            FlowMeterWarning::BetaOutOfRange { .. } => "warning.flow_meter.beta_out_of_range",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            FlowMeterWarning::BetaOutOfRange { beta, min, max } => vec![
+                ("beta", format!("{beta:.2}")),
+                ("min", format!("{min:.2}")),
+                ("max", format!("{max:.2}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for FlowMeterWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            FlowMeterWarning::BetaOutOfRange { beta, min, max } => write!(
+                f,
+                "β {beta:.2}가 ISO 5167 적용 범위 {min:.2}~{max:.2} 밖입니다."
+            ),
+        }
+    }
+}
+
+/// 유량계 손실 계산 오류.
+#[derive(Debug, Clone)]
+pub enum FlowMeterError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for FlowMeterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FlowMeterError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FlowMeterError {}
+
+/// β와 유출계수로 영구 손실 비율 Δϖ/Δp를 구한다.
+pub fn permanent_loss_ratio(meter: MeterType, beta: f64, discharge_coefficient: f64) -> f64 {
+    match meter {
+        MeterType::Orifice | MeterType::Nozzle => {
+            let c = discharge_coefficient;
+            let b2 = beta * beta;
+            let root = (1.0 - b2 * b2 * (1.0 - c * c)).sqrt();
+            (root - c * b2) / (root + c * b2)
+        }
+        MeterType::Venturi => VENTURI_LOSS_RATIO,
+    }
+}
+
+/// 계량기 하류에서 압력이 회복되는 거리 [m].
+pub fn recovery_distance_m(meter: MeterType, beta: f64, pipe_diameter_m: f64) -> f64 {
+    match meter {
+        MeterType::Orifice | MeterType::Nozzle => ORIFICE_RECOVERY_DIAMETERS * pipe_diameter_m,
+        MeterType::Venturi => {
+            let half_angle = (VENTURI_DIVERGENT_ANGLE_DEG / 2.0).to_radians();
+            pipe_diameter_m * (1.0 - beta) / (2.0 * half_angle.tan())
+        }
+    }
+}
+
+/// 계량 차압 Δp [bar]에서의 액체 체적유량 [m³/h].
+/// q = C/√(1−β⁴) · π/4·(βD)² · √(2Δp/ρ) (ISO 5167, 팽창 계수 1)
+pub fn liquid_flow_m3_per_h(
+    beta: f64,
+    discharge_coefficient: f64,
+    pipe_diameter_m: f64,
+    differential_bar: f64,
+    density_kg_per_m3: f64,
+) -> f64 {
+    let d = beta * pipe_diameter_m;
+    let area = std::f64::consts::PI * d * d / 4.0;
+    discharge_coefficient / (1.0 - beta.powi(4)).sqrt()
+        * area
+        * (2.0 * differential_bar * 1e5 / density_kg_per_m3).sqrt()
+        * 3600.0
+}
+
+/// 액체 유량에서 영구 압력손실이 `permanent_loss_bar`가 되는 제한 오리피스 β를 구한다.
+/// β가 커지면 같은 영구 손실에서 유량이 늘어나므로 이분법으로 푼다.
+pub fn restriction_orifice_beta(
+    meter: MeterType,
+    discharge_coefficient: f64,
+    pipe_diameter_m: f64,
+    volume_flow_m3_per_h: f64,
+    density_kg_per_m3: f64,
+    permanent_loss_bar: f64,
+) -> Result<f64, FlowMeterError> {
+    if pipe_diameter_m <= 0.0
+        || volume_flow_m3_per_h <= 0.0
+        || density_kg_per_m3 <= 0.0
+        || permanent_loss_bar <= 0.0
+    {
+        return Err(FlowMeterError::InvalidInput(
+            "배관 내경, 유량, 밀도, 영구 손실은 0보다 커야 합니다.",
+        ));
+    }
+    if !(discharge_coefficient > 0.0 && discharge_coefficient <= 1.0) {
+        return Err(FlowMeterError::InvalidInput(
+            "유출계수는 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    let flow_at = |beta: f64| {
+        let dp = permanent_loss_bar / permanent_loss_ratio(meter, beta, discharge_coefficient);
+        liquid_flow_m3_per_h(
+            beta,
+            discharge_coefficient,
+            pipe_diameter_m,
+            dp,
+            density_kg_per_m3,
+        )
+    };
+    let (mut lo, mut hi) = (1e-3, 0.95);
+    if flow_at(hi) < volume_flow_m3_per_h {
+        return Err(FlowMeterError::InvalidInput(
+            "배관이 작아 이 유량에서 요구 손실을 낼 오리피스가 없습니다.",
+        ));
+    }
+    if flow_at(lo) > volume_flow_m3_per_h {
+        return Err(FlowMeterError::InvalidInput(
+            "요구 손실이 너무 커 단일 오리피스로 낼 수 없습니다.",
+        ));
+    }
+    for _ in 0..BETA_ITERATIONS {
+        let mid = 0.5 * (lo + hi);
+        if flow_at(mid) < volume_flow_m3_per_h {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(0.5 * (lo + hi))
+}
+
+/// 영구 압력손실, 회복 거리, 펌핑 에너지 비용을 계산한다.
+pub fn permanent_loss(input: &MeterLossInput) -> Result<MeterLossResult, FlowMeterError> {
+    if !(input.beta > 0.0 && input.beta < 1.0) {
+        return Err(FlowMeterError::InvalidInput("β는 0과 1 사이여야 합니다."));
+    }
+    if input.pipe_diameter_m <= 0.0 || input.differential_bar <= 0.0 {
+        return Err(FlowMeterError::InvalidInput(
+            "배관 내경과 차압은 0보다 커야 합니다.",
+        ));
+    }
+    if input.volume_flow_m3_per_h < 0.0 || input.hours_per_year < 0.0 {
+        return Err(FlowMeterError::InvalidInput(
+            "유량과 운전 시간은 음수일 수 없습니다.",
+        ));
+    }
+    if !(input.pump_efficiency > 0.0 && input.pump_efficiency <= 1.0) {
+        return Err(FlowMeterError::InvalidInput(
+            "펌프 효율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    let c = input
+        .discharge_coefficient
+        .unwrap_or_else(|| input.meter.typical_discharge_coefficient());
+    if !(c > 0.0 && c <= 1.0) {
+        return Err(FlowMeterError::InvalidInput(
+            "유출계수는 0 초과 1 이하여야 합니다.",
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    let (beta_min, beta_max) = input.meter.beta_range();
+    if input.beta < beta_min || input.beta > beta_max {
+        warnings.push(FlowMeterWarning::BetaOutOfRange {
+            beta: input.beta,
+            min: beta_min,
+            max: beta_max,
+        });
+    }
+
+    let ratio = permanent_loss_ratio(input.meter, input.beta, c);
+    let loss_bar = ratio * input.differential_bar;
+    // Q [m³/s] · Δϖ [Pa] / η → W
+    let power_kw =
+        input.volume_flow_m3_per_h / 3600.0 * loss_bar * 1e5 / input.pump_efficiency / 1000.0;
+    let energy_kwh = power_kw * input.hours_per_year;
+
+    Ok(MeterLossResult {
+        meter: input.meter,
+        beta: input.beta,
+        discharge_coefficient: c,
+        differential_bar: input.differential_bar,
+        loss_ratio: ratio,
+        permanent_loss_bar: loss_bar,
+        recovered_bar: input.differential_bar - loss_bar,
+        recovery_distance_m: recovery_distance_m(input.meter, input.beta, input.pipe_diameter_m),
+        pumping_power_kw: power_kw,
+        annual_energy_kwh: energy_kwh,
+        annual_cost: energy_kwh * input.energy_price_per_kwh,
+        warnings,
+    })
+}
+
+/// 같은 유량과 β에서 세 계량기를 비교한다.
+/// 차압은 Δp ∝ 1/C²이므로 입력 계량기의 차압을 (C_입력/C)²로 환산하고, 나머지 계량기는 대표 유출계수를 쓴다.
+pub fn compare_meters(input: &MeterLossInput) -> Result<Vec<MeterLossResult>, FlowMeterError> {
+    let c_ref = input
+        .discharge_coefficient
+        .unwrap_or_else(|| input.meter.typical_discharge_coefficient());
+    MeterType::ALL
+        .iter()
+        .map(|&meter| {
+            if meter == input.meter {
+                return permanent_loss(input);
+            }
+            let c = meter.typical_discharge_coefficient();
+            permanent_loss(&MeterLossInput {
+                meter,
+                differential_bar: input.differential_bar * (c_ref / c).powi(2),
+                discharge_coefficient: Some(c),
+                ..input.clone()
+            })
+        })
+        .collect()
+}
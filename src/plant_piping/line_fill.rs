@@ -0,0 +1,341 @@
+//! 배관 충수량, 만수 배관 중량, 배수 시간.
+//! 관경별 길이 목록으로 수압 시험·충수에 필요한 물의 양과, 지지대 하중 검토용
+//! 만수 상태 단위 길이 중량(강관 + 물)을 구한다. 관경은 응축수 회수 배관과 같은
+//! DN/Sch40 표준 관경표를 쓴다.
+//!
+//! 배수 시간은 배수 밸브 Cv와 최고점~배수구 수두로 구한다. 초기 유량은
+//! Q₀ = Kv·√(ΔP/SG), ΔP = ρ·g·h이고, 수두가 남은 물의 양에 비례해 줄어든다고 보면
+//! dV/dt ∝ √V 이므로 배수 시간은 t = 2·V/Q₀ 이다 (초기 유량으로 흘린 시간의 2배).
+
+use crate::condensate_recovery::return_line::STANDARD_PIPE_SIZES;
+use crate::flow_coefficient::KV_PER_CV_US;
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// 중력 가속도 [m/s²]
+const G: f64 = 9.806_65;
+/// 충수 시 물의 압력 [bar abs] (대기압 개방 상태)
+const FILL_PRESSURE_BAR_ABS: f64 = 1.013_25;
+/// 탄소강 밀도 기본값 [kg/m³]
+pub const CARBON_STEEL_DENSITY: f64 = 7850.0;
+/// 이보다 배수 시간이 길면 배수구 추가를 권고한다 [h]
+const LONG_DRAIN_H: f64 = 8.0;
+
+/// 표준 관경 외경 (DN, 외경 mm). 내경은 `STANDARD_PIPE_SIZES`의 Sch40 값을 쓴다.
+const PIPE_OUTSIDE_DIAMETERS: [(u32, f64); 14] = [
+    (15, 21.3),
+    (20, 26.7),
+    (25, 33.4),
+    (32, 42.2),
+    (40, 48.3),
+    (50, 60.3),
+    (65, 73.0),
+    (80, 88.9),
+    (100, 114.3),
+    (125, 141.3),
+    (150, 168.3),
+    (200, 219.1),
+    (250, 273.0),
+    (300, 323.8),
+];
+
+/// 같은 관경의 배관 길이.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LineSegment {
+    /// 호칭 관경 DN
+    pub dn: u32,
+    /// 길이 [m]
+    pub length_m: f64,
+}
+
+/// 배수 밸브 조건.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DrainValve {
+    /// 배수 밸브 Cv (US)
+    pub cv: f64,
+    /// 최고점에서 배수구까지 수두 [m]
+    pub static_head_m: f64,
+}
+
+/// 충수량 계산 입력.
+#[derive(Debug, Clone)]
+pub struct LineFillInput {
+    pub segments: Vec<LineSegment>,
+    /// 충수 물 온도 [°C]
+    pub water_temp_c: f64,
+    /// 관재 밀도 [kg/m³] (탄소강 7850)
+    pub steel_density_kg_per_m3: f64,
+    /// 배수 시간 계산용 배수 밸브 (없으면 생략)
+    pub drain: Option<DrainValve>,
+}
+
+/// 관경별 충수량과 단위 길이 중량.
+#[derive(Debug, Clone)]
+pub struct SegmentFill {
+    pub dn: u32,
+    pub length_m: f64,
+    /// 내경 [mm]
+    pub inner_diameter_mm: f64,
+    /// 외경 [mm]
+    pub outer_diameter_mm: f64,
+    /// 충수량 [m³]
+    pub volume_m3: f64,
+    /// 물 중량 [kg/m]
+    pub water_kg_per_m: f64,
+    /// 강관 중량 [kg/m]
+    pub steel_kg_per_m: f64,
+    /// 만수 배관 중량 [kg/m]
+    pub filled_kg_per_m: f64,
+}
+
+/// 배수 시간 결과.
+#[derive(Debug, Clone)]
+pub struct DrainResult {
+    /// 배수 밸브 Kv
+    pub kv: f64,
+    /// 만수 상태 초기 배수 유량 [m³/h]
+    pub initial_flow_m3_per_h: f64,
+    /// 배수 시간 [h]
+    pub drain_time_h: f64,
+}
+
+/// 충수량 계산 결과.
+#[derive(Debug, Clone)]
+pub struct LineFillResult {
+    pub segments: Vec<SegmentFill>,
+    /// 물 밀도 [kg/m³]
+    pub water_density_kg_per_m3: f64,
+    /// 총 길이 [m]
+    pub total_length_m: f64,
+    /// 총 충수량 [m³]
+    pub volume_m3: f64,
+    /// 물 질량 [kg]
+    pub water_mass_kg: f64,
+    /// 강관 질량 [kg]
+    pub steel_mass_kg: f64,
+    /// 만수 배관 총 질량 [kg]
+    pub filled_mass_kg: f64,
+    /// 가장 무거운 구간의 만수 중량 [kg/m]
+    pub max_filled_kg_per_m: f64,
+    pub drain: Option<DrainResult>,
+    pub warnings: Vec<LineFillWarning>,
+}
+
+/// 충수량 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineFillWarning {
+    /// 충수 물 결빙 위험
+    FreezeRisk { water_temp_c: f64 },
+    /// 배수 시간이 김
+    LongDrain { drain_time_h: f64 },
+}
+
+impl CalcWarning for LineFillWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            LineFillWarning::FreezeRisk { .. } => "warning.line_fill.freeze_risk",
+            LineFillWarning::LongDrain { .. } => "warning.line_fill.long_drain",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            LineFillWarning::FreezeRisk { water_temp_c } => {
+                vec![("temp", format!("{water_temp_c:.0}"))]
+            }
+            LineFillWarning::LongDrain { drain_time_h } => {
+                vec![("hours", format!("{drain_time_h:.1}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for LineFillWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            LineFillWarning::FreezeRisk { water_temp_c } => write!(
+                f,
+                "물 온도 {water_temp_c:.0} °C는 결빙 위험이 있습니다. 충수 후 배수 계획과 보온을 확인하세요."
+            ),
+            LineFillWarning::LongDrain { drain_time_h } => write!(
+                f,
+                "배수에 {drain_time_h:.1} h가 걸립니다. 저점 배수구를 늘리거나 큰 배수 밸브를 검토하세요."
+            ),
+        }
+    }
+}
+
+/// 충수량 계산 오류.
+#[derive(Debug, Clone)]
+pub enum LineFillError {
+    InvalidInput(&'static str),
+    /// 표준 관경표에 없는 DN
+    UnknownSize(u32),
+    /// 관경 목록 문자열 오류 (항목, 사유)
+    Parse {
+        item: String,
+        message: &'static str,
+    },
+    If97(String),
+}
+
+impl std::fmt::Display for LineFillError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineFillError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            LineFillError::UnknownSize(dn) => {
+                write!(f, "표준 관경표에 없는 관경: DN{dn} (DN15~DN300)")
+            }
+            LineFillError::Parse { item, message } => write!(f, "관경 목록 '{item}': {message}"),
+            LineFillError::If97(msg) => write!(f, "물성 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for LineFillError {}
+
+/// DN의 (외경, Sch40 내경) [mm].
+pub fn pipe_dimensions_mm(dn: u32) -> Option<(f64, f64)> {
+    let od = PIPE_OUTSIDE_DIAMETERS.iter().find(|(d, _)| *d == dn)?.1;
+    let id = STANDARD_PIPE_SIZES.iter().find(|(d, _)| *d == dn)?.1;
+    Some((od, id))
+}
+
+/// `"DN100:120, 50:35"` 형식의 관경별 길이 목록을 읽는다.
+/// 항목은 쉼표, 세미콜론 또는 줄바꿈으로 나누고 `DN` 접두어는 생략할 수 있다.
+pub fn parse_segments(text: &str) -> Result<Vec<LineSegment>, LineFillError> {
+    let mut segments = Vec::new();
+    for item in text.split([',', ';', '\n']).map(str::trim) {
+        if item.is_empty() {
+            continue;
+        }
+        let err = |message| LineFillError::Parse {
+            item: item.to_string(),
+            message,
+        };
+        let (dn, length) = item
+            .split_once(':')
+            .ok_or_else(|| err("DN:길이 형식이 아닙니다."))?;
+        let dn = dn.trim();
+        let dn = dn
+            .strip_prefix("DN")
+            .or_else(|| dn.strip_prefix("dn"))
+            .unwrap_or(dn)
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| err("관경이 정수가 아닙니다."))?;
+        let length_m = length
+            .trim()
+            .trim_end_matches('m')
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| err("길이가 숫자가 아닙니다."))?;
+        segments.push(LineSegment { dn, length_m });
+    }
+    Ok(segments)
+}
+
+/// 충수량, 만수 중량, 배수 시간을 구한다.
+pub fn line_fill(input: &LineFillInput) -> Result<LineFillResult, LineFillError> {
+    if input.segments.is_empty() {
+        return Err(LineFillError::InvalidInput("배관 구간이 없습니다."));
+    }
+    if input.segments.iter().any(|s| s.length_m <= 0.0) {
+        return Err(LineFillError::InvalidInput(
+            "구간 길이는 0보다 커야 합니다.",
+        ));
+    }
+    if input.water_temp_c <= 0.0 || input.water_temp_c >= 100.0 {
+        return Err(LineFillError::InvalidInput(
+            "물 온도는 0~100 °C 사이여야 합니다.",
+        ));
+    }
+    if input.steel_density_kg_per_m3 <= 0.0 {
+        return Err(LineFillError::InvalidInput(
+            "관재 밀도는 0보다 커야 합니다.",
+        ));
+    }
+
+    let (_, v, _) = if97::region_props(FILL_PRESSURE_BAR_ABS, input.water_temp_c)
+        .map_err(|e| LineFillError::If97(e.to_string()))?;
+    let rho_w = 1.0 / v;
+    let area = |d_mm: f64| std::f64::consts::PI / 4.0 * (d_mm / 1000.0).powi(2);
+
+    let segments = input
+        .segments
+        .iter()
+        .map(|s| {
+            let (od, id) = pipe_dimensions_mm(s.dn).ok_or(LineFillError::UnknownSize(s.dn))?;
+            let water_kg_per_m = rho_w * area(id);
+            let steel_kg_per_m = input.steel_density_kg_per_m3 * (area(od) - area(id));
+            Ok(SegmentFill {
+                dn: s.dn,
+                length_m: s.length_m,
+                inner_diameter_mm: id,
+                outer_diameter_mm: od,
+                volume_m3: area(id) * s.length_m,
+                water_kg_per_m,
+                steel_kg_per_m,
+                filled_kg_per_m: water_kg_per_m + steel_kg_per_m,
+            })
+        })
+        .collect::<Result<Vec<_>, LineFillError>>()?;
+
+    let total_length_m = segments.iter().fold(0.0, |sum, s| sum + s.length_m);
+    let volume_m3 = segments.iter().fold(0.0, |sum, s| sum + s.volume_m3);
+    let steel_mass_kg = segments
+        .iter()
+        .fold(0.0, |sum, s| sum + s.steel_kg_per_m * s.length_m);
+    let water_mass_kg = rho_w * volume_m3;
+    let max_filled_kg_per_m = segments
+        .iter()
+        .map(|s| s.filled_kg_per_m)
+        .fold(0.0, f64::max);
+
+    let mut warnings = Vec::new();
+    if input.water_temp_c <= 5.0 {
+        warnings.push(LineFillWarning::FreezeRisk {
+            water_temp_c: input.water_temp_c,
+        });
+    }
+
+    let drain = match input.drain {
+        Some(valve) => {
+            if valve.cv <= 0.0 || valve.static_head_m <= 0.0 {
+                return Err(LineFillError::InvalidInput(
+                    "배수 밸브 Cv와 수두는 0보다 커야 합니다.",
+                ));
+            }
+            let kv = KV_PER_CV_US * valve.cv;
+            let dp_bar = rho_w * G * valve.static_head_m / 1e5;
+            let initial_flow_m3_per_h = kv * (dp_bar / (rho_w / 1000.0)).sqrt();
+            let drain_time_h = 2.0 * volume_m3 / initial_flow_m3_per_h;
+            if drain_time_h > LONG_DRAIN_H {
+                warnings.push(LineFillWarning::LongDrain { drain_time_h });
+            }
+            Some(DrainResult {
+                kv,
+                initial_flow_m3_per_h,
+                drain_time_h,
+            })
+        }
+        None => None,
+    };
+
+    Ok(LineFillResult {
+        segments,
+        water_density_kg_per_m3: rho_w,
+        total_length_m,
+        volume_m3,
+        water_mass_kg,
+        steel_mass_kg,
+        filled_mass_kg: water_mass_kg + steel_mass_kg,
+        max_filled_kg_per_m,
+        drain,
+        warnings,
+    })
+}
@@ -0,0 +1,11 @@
+//! 플랜트 배관 계산 모듈 모음. 배관망 수력 해석, 열팽창 루프 사이징, 차압식 유량계 손실,
+//! B31 내압 필요 두께, 부식 잔여 수명 평가, 내압 시험 압력, 충수량·만수 중량, 프로젝트 배관 등급 검토를 포함한다.
+
+pub mod corrosion;
+pub mod expansion_loop;
+pub mod flow_meter;
+pub mod line_fill;
+pub mod network;
+pub mod pipe_class;
+pub mod pressure_test;
+pub mod wall_thickness;
@@ -0,0 +1,528 @@
+//! 배관망(분기/루프) 수력 해석.
+//! 노드·배관·펌프·고정압력 경계로 구성된 비압축성 배관망을 전역 구배법(GGA, Todini-Pilati)으로 푼다.
+//! 배관 마찰은 `water_piping::darcy_friction_factor`를 재사용한다.
+//! 증기 배관에도 쓸 수 있지만 밀도를 상수로 보므로 압력강하가 작은 분배 헤더에만 적용한다.
+
+use crate::water::water_piping::darcy_friction_factor;
+
+const G: f64 = 9.80665;
+/// 유량 0 부근에서 Newton 기울기가 0이 되지 않도록 두는 최소 유량(m³/s)
+const Q_EPS: f64 = 1e-7;
+
+/// 노드 인덱스 (`PipeNetwork::add_node` 반환값)
+pub type NodeId = usize;
+
+/// 유체 물성(상수).
+#[derive(Debug, Clone, Copy)]
+pub struct FluidProps {
+    pub density_kg_per_m3: f64,
+    pub dynamic_viscosity_pa_s: f64,
+}
+
+/// 노드 종류.
+#[derive(Debug, Clone, Copy)]
+pub enum NodeKind {
+    /// 일반 분기점. 수요량(m³/h)이 양수면 유출, 음수면 유입(공급).
+    Junction { demand_m3_per_h: f64 },
+    /// 압력이 고정된 경계(탱크, 헤더 공급점 등). 압력은 bar(g).
+    FixedPressure { pressure_bar_g: f64 },
+}
+
+/// 배관망 노드.
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub name: String,
+    /// 기준면 대비 높이(m)
+    pub elevation_m: f64,
+    pub kind: NodeKind,
+}
+
+impl Node {
+    pub fn junction(name: impl Into<String>, elevation_m: f64, demand_m3_per_h: f64) -> Self {
+        Self {
+            name: name.into(),
+            elevation_m,
+            kind: NodeKind::Junction { demand_m3_per_h },
+        }
+    }
+
+    pub fn fixed(name: impl Into<String>, elevation_m: f64, pressure_bar_g: f64) -> Self {
+        Self {
+            name: name.into(),
+            elevation_m,
+            kind: NodeKind::FixedPressure { pressure_bar_g },
+        }
+    }
+}
+
+/// 노드 사이 연결 요소. 유량 부호는 from → to 방향이 양수다.
+#[derive(Debug, Clone)]
+pub enum Link {
+    Pipe {
+        name: String,
+        from: NodeId,
+        to: NodeId,
+        length_m: f64,
+        diameter_m: f64,
+        roughness_m: f64,
+        /// 부속 손실계수 합계
+        fittings_k_sum: f64,
+    },
+    /// 2차 곡선 펌프: H = H0 - c·Q², 정격점 (rated_flow, rated_head)로 c를 정한다.
+    Pump {
+        name: String,
+        from: NodeId,
+        to: NodeId,
+        shutoff_head_m: f64,
+        rated_flow_m3_per_h: f64,
+        rated_head_m: f64,
+    },
+}
+
+impl Link {
+    fn name(&self) -> &str {
+        match self {
+            Link::Pipe { name, .. } | Link::Pump { name, .. } => name,
+        }
+    }
+
+    fn ends(&self) -> (NodeId, NodeId) {
+        match self {
+            Link::Pipe { from, to, .. } | Link::Pump { from, to, .. } => (*from, *to),
+        }
+    }
+}
+
+/// 배관망 해석 오류.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PipeNetworkError {
+    InvalidInput(&'static str),
+    /// 연결 요소가 존재하지 않는 노드를 참조함
+    UnknownNode {
+        link: String,
+        node: NodeId,
+    },
+    /// 고정압력 노드가 없어 압력 기준이 정해지지 않음
+    NoFixedPressure,
+    /// 연립방정식이 특이함(고정압력 노드와 연결되지 않은 고립 구간 등)
+    Singular,
+    NotConverged {
+        iterations: usize,
+    },
+}
+
+impl std::fmt::Display for PipeNetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipeNetworkError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            PipeNetworkError::UnknownNode { link, node } => {
+                write!(f, "'{link}'이(가) 존재하지 않는 노드 {node}를 참조합니다.")
+            }
+            PipeNetworkError::NoFixedPressure => {
+                write!(f, "고정압력 노드가 최소 1개 필요합니다.")
+            }
+            PipeNetworkError::Singular => write!(
+                f,
+                "배관망을 풀 수 없습니다. 고정압력 노드와 연결되지 않은 구간이 있는지 확인하세요."
+            ),
+            PipeNetworkError::NotConverged { iterations } => {
+                write!(f, "{iterations}회 반복 내에 수렴하지 못했습니다.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PipeNetworkError {}
+
+/// 노드 결과.
+#[derive(Debug, Clone)]
+pub struct NodeResult {
+    pub name: String,
+    pub pressure_bar_g: f64,
+    /// 수두 기준 압력 + 높이(m)
+    pub hydraulic_head_m: f64,
+    /// 외부 유출량(m³/h). 분기점은 수요량, 고정압력 노드는 망으로 공급한 양의 음수.
+    pub external_outflow_m3_per_h: f64,
+}
+
+/// 연결 요소 결과.
+#[derive(Debug, Clone)]
+pub struct LinkResult {
+    pub name: String,
+    /// from → to 방향 유량(m³/h)
+    pub flow_m3_per_h: f64,
+    /// 배관 유속(m/s). 펌프는 0.
+    pub velocity_m_per_s: f64,
+    /// from 대비 to의 압력 감소(bar, 높이 차 포함). 펌프는 음수(승압).
+    pub pressure_drop_bar: f64,
+    /// 배관 Reynolds 수. 펌프는 0.
+    pub reynolds: f64,
+    /// 배관 Darcy 마찰계수. 펌프는 0.
+    pub friction_factor: f64,
+}
+
+/// 배관망 해석 결과.
+#[derive(Debug, Clone)]
+pub struct PipeNetworkResult {
+    pub nodes: Vec<NodeResult>,
+    pub links: Vec<LinkResult>,
+    pub iterations: usize,
+    pub warnings: Vec<String>,
+}
+
+/// 배관망 모델.
+#[derive(Debug, Clone)]
+pub struct PipeNetwork {
+    pub fluid: FluidProps,
+    pub nodes: Vec<Node>,
+    pub links: Vec<Link>,
+}
+
+struct LinkState {
+    /// 손실 수두(Pa 기준 압력 단위) h(Q) = P_from - P_to
+    loss: f64,
+    /// dh/dQ
+    slope: f64,
+    reynolds: f64,
+    friction_factor: f64,
+    velocity: f64,
+}
+
+impl PipeNetwork {
+    pub fn new(fluid: FluidProps) -> Self {
+        Self {
+            fluid,
+            nodes: Vec::new(),
+            links: Vec::new(),
+        }
+    }
+
+    pub fn add_node(&mut self, node: Node) -> NodeId {
+        self.nodes.push(node);
+        self.nodes.len() - 1
+    }
+
+    pub fn add_link(&mut self, link: Link) {
+        self.links.push(link);
+    }
+
+    fn validate(&self) -> Result<(), PipeNetworkError> {
+        if !(self.fluid.density_kg_per_m3 > 0.0 && self.fluid.dynamic_viscosity_pa_s > 0.0) {
+            return Err(PipeNetworkError::InvalidInput(
+                "밀도와 점도는 0보다 커야 합니다.",
+            ));
+        }
+        if self.links.is_empty() {
+            return Err(PipeNetworkError::InvalidInput(
+                "배관 또는 펌프가 최소 1개 필요합니다.",
+            ));
+        }
+        if !self
+            .nodes
+            .iter()
+            .any(|n| matches!(n.kind, NodeKind::FixedPressure { .. }))
+        {
+            return Err(PipeNetworkError::NoFixedPressure);
+        }
+        for link in &self.links {
+            let (from, to) = link.ends();
+            for node in [from, to] {
+                if node >= self.nodes.len() {
+                    return Err(PipeNetworkError::UnknownNode {
+                        link: link.name().to_string(),
+                        node,
+                    });
+                }
+            }
+            if from == to {
+                return Err(PipeNetworkError::InvalidInput(
+                    "연결 요소의 양 끝 노드가 같습니다.",
+                ));
+            }
+            match link {
+                Link::Pipe {
+                    length_m,
+                    diameter_m,
+                    roughness_m,
+                    fittings_k_sum,
+                    ..
+                } => {
+                    if *length_m < 0.0
+                        || *diameter_m <= 0.0
+                        || *roughness_m < 0.0
+                        || *fittings_k_sum < 0.0
+                    {
+                        return Err(PipeNetworkError::InvalidInput(
+                            "배관 길이/내경/조도/K값이 잘못되었습니다.",
+                        ));
+                    }
+                    if *length_m == 0.0 && *fittings_k_sum == 0.0 {
+                        return Err(PipeNetworkError::InvalidInput(
+                            "배관 길이와 K값이 모두 0이면 저항이 없습니다.",
+                        ));
+                    }
+                }
+                Link::Pump {
+                    shutoff_head_m,
+                    rated_flow_m3_per_h,
+                    rated_head_m,
+                    ..
+                } => {
+                    if !(*rated_flow_m3_per_h > 0.0 && *shutoff_head_m > *rated_head_m) {
+                        return Err(PipeNetworkError::InvalidInput(
+                            "펌프는 정격유량 > 0, 체절양정 > 정격양정이어야 합니다.",
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn link_state(&self, link: &Link, q: f64) -> LinkState {
+        let rho = self.fluid.density_kg_per_m3;
+        match link {
+            Link::Pipe {
+                length_m,
+                diameter_m,
+                roughness_m,
+                fittings_k_sum,
+                ..
+            } => {
+                let area = std::f64::consts::PI * diameter_m * diameter_m / 4.0;
+                let velocity = q.abs() / area;
+                let reynolds = rho * velocity * diameter_m / self.fluid.dynamic_viscosity_pa_s;
+                let friction_factor = darcy_friction_factor(reynolds, *roughness_m, *diameter_m);
+                let r = (friction_factor * length_m / diameter_m + fittings_k_sum) * rho
+                    / (2.0 * area * area);
+                LinkState {
+                    loss: r * q * q.abs(),
+                    slope: 2.0 * r * q.abs().max(Q_EPS),
+                    reynolds,
+                    friction_factor,
+                    velocity,
+                }
+            }
+            Link::Pump {
+                shutoff_head_m,
+                rated_flow_m3_per_h,
+                rated_head_m,
+                ..
+            } => {
+                let qr = rated_flow_m3_per_h / 3600.0;
+                let c = rho * G * (shutoff_head_m - rated_head_m) / (qr * qr);
+                LinkState {
+                    loss: -rho * G * shutoff_head_m + c * q * q.abs(),
+                    slope: 2.0 * c * q.abs().max(Q_EPS),
+                    reynolds: 0.0,
+                    friction_factor: 0.0,
+                    velocity: 0.0,
+                }
+            }
+        }
+    }
+
+    /// 기본 허용오차(유량 1e-9 m³/s), 최대 100회 반복으로 푼다.
+    pub fn solve(&self) -> Result<PipeNetworkResult, PipeNetworkError> {
+        self.solve_with(1e-9, 100)
+    }
+
+    /// 전역 구배법으로 유량과 노드 압력을 구한다.
+    /// 각 반복에서 (A21·D⁻¹·A12)·H = A21·Q − A21·D⁻¹·(h(Q) + A10·H0) − q 를 풀고 유량을 갱신한다.
+    pub fn solve_with(
+        &self,
+        flow_tol_m3_per_s: f64,
+        max_iter: usize,
+    ) -> Result<PipeNetworkResult, PipeNetworkError> {
+        self.validate()?;
+        let rho = self.fluid.density_kg_per_m3;
+
+        // 미지 노드 번호 매기기. 고정 노드는 압력 수두(Pa, 높이 포함)를 미리 계산한다.
+        let mut unknown_index = vec![None; self.nodes.len()];
+        let mut fixed_head = vec![0.0; self.nodes.len()];
+        let mut demand = Vec::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            match node.kind {
+                NodeKind::Junction { demand_m3_per_h } => {
+                    unknown_index[i] = Some(demand.len());
+                    demand.push(demand_m3_per_h / 3600.0);
+                }
+                NodeKind::FixedPressure { pressure_bar_g } => {
+                    fixed_head[i] = pressure_bar_g * 1e5 + rho * G * node.elevation_m;
+                }
+            }
+        }
+        let n = demand.len();
+
+        // 초기 유량: 배관 1 m/s, 펌프 정격 유량
+        let mut q: Vec<f64> = self
+            .links
+            .iter()
+            .map(|link| match link {
+                Link::Pipe { diameter_m, .. } => {
+                    std::f64::consts::PI * diameter_m * diameter_m / 4.0
+                }
+                Link::Pump {
+                    rated_flow_m3_per_h,
+                    ..
+                } => rated_flow_m3_per_h / 3600.0,
+            })
+            .collect();
+        let mut head = vec![0.0; n];
+
+        // 반복마다 새로 할당하지 않도록 작업 버퍼를 한 번만 만든다.
+        let mut states: Vec<LinkState> = Vec::with_capacity(self.links.len());
+        let mut m = vec![vec![0.0; n]; n];
+        let mut rhs = vec![0.0; n];
+
+        let mut converged_at = None;
+        for iter in 1..=max_iter {
+            states.clear();
+            states.extend(
+                self.links
+                    .iter()
+                    .zip(&q)
+                    .map(|(link, &qk)| self.link_state(link, qk)),
+            );
+
+            for row in &mut m {
+                row.fill(0.0);
+            }
+            for (r, d) in rhs.iter_mut().zip(&demand) {
+                *r = -d;
+            }
+            for (k, link) in self.links.iter().enumerate() {
+                let (from, to) = link.ends();
+                let inv = 1.0 / states[k].slope;
+                // h(Q) + A10·H0 : 고정 노드 항. from은 -1, to는 +1 계수.
+                let mut known = states[k].loss;
+                if unknown_index[from].is_none() {
+                    known -= fixed_head[from];
+                }
+                if unknown_index[to].is_none() {
+                    known += fixed_head[to];
+                }
+                // 절점 n에 대해 A21 계수는 from -1, to +1
+                for (node, sign) in [(from, -1.0), (to, 1.0)] {
+                    if let Some(i) = unknown_index[node] {
+                        rhs[i] += sign * (q[k] - inv * known);
+                        for (other, other_sign) in [(from, -1.0), (to, 1.0)] {
+                            if let Some(j) = unknown_index[other] {
+                                m[i][j] += sign * other_sign * inv;
+                            }
+                        }
+                    }
+                }
+            }
+            if !solve_linear_in_place(&mut m, &mut rhs, &mut head) {
+                return Err(PipeNetworkError::Singular);
+            }
+
+            let mut max_dq: f64 = 0.0;
+            for (k, link) in self.links.iter().enumerate() {
+                let (from, to) = link.ends();
+                let h_of = |node: NodeId| match unknown_index[node] {
+                    Some(i) => head[i],
+                    None => fixed_head[node],
+                };
+                let residual = states[k].loss - (h_of(from) - h_of(to));
+                let dq = -residual / states[k].slope;
+                q[k] += dq;
+                max_dq = max_dq.max(dq.abs());
+            }
+            if !max_dq.is_finite() {
+                return Err(PipeNetworkError::Singular);
+            }
+            if max_dq <= flow_tol_m3_per_s {
+                converged_at = Some(iter);
+                break;
+            }
+        }
+        let iterations = converged_at.ok_or(PipeNetworkError::NotConverged {
+            iterations: max_iter,
+        })?;
+
+        let node_head = |node: NodeId| match unknown_index[node] {
+            Some(i) => head[i],
+            None => fixed_head[node],
+        };
+        let mut outflow = vec![0.0; self.nodes.len()];
+        let mut links = Vec::with_capacity(self.links.len());
+        let mut warnings = Vec::new();
+        for (k, link) in self.links.iter().enumerate() {
+            let (from, to) = link.ends();
+            outflow[from] -= q[k];
+            outflow[to] += q[k];
+            let state = self.link_state(link, q[k]);
+            let dz = self.nodes[to].elevation_m - self.nodes[from].elevation_m;
+            let pressure_drop_pa = node_head(from) - node_head(to) - rho * G * dz;
+            if let Link::Pump { name, .. } = link {
+                if q[k] < 0.0 {
+                    warnings.push(format!("펌프 '{name}'에 역류가 발생합니다."));
+                }
+            }
+            links.push(LinkResult {
+                name: link.name().to_string(),
+                flow_m3_per_h: q[k] * 3600.0,
+                velocity_m_per_s: state.velocity,
+                pressure_drop_bar: pressure_drop_pa / 1e5,
+                reynolds: state.reynolds,
+                friction_factor: state.friction_factor,
+            });
+        }
+        let nodes = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| {
+                let h = node_head(i);
+                NodeResult {
+                    name: node.name.clone(),
+                    pressure_bar_g: (h - rho * G * node.elevation_m) / 1e5,
+                    hydraulic_head_m: h / (rho * G),
+                    // 연결 요소로 들어온 순유입 = 외부 유출
+                    external_outflow_m3_per_h: outflow[i] * 3600.0,
+                }
+            })
+            .collect();
+
+        Ok(PipeNetworkResult {
+            nodes,
+            links,
+            iterations,
+            warnings,
+        })
+    }
+}
+
+/// 부분 피벗 가우스 소거로 `a·x = b`를 풀어 `x`에 쓴다. `a`, `b`는 작업 공간으로 덮어쓴다. 특이 행렬이면 `false`.
+fn solve_linear_in_place(a: &mut [Vec<f64>], b: &mut [f64], x: &mut [f64]) -> bool {
+    let n = b.len();
+    for col in 0..n {
+        let Some(pivot) = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+        else {
+            return false;
+        };
+        if a[pivot][col].abs() < 1e-300 {
+            return false;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            if factor != 0.0 {
+                for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *x -= factor * p;
+                }
+                b[col + 1 + offset] -= factor * b[col];
+            }
+        }
+    }
+    for row in (0..n).rev() {
+        let s: f64 = (row + 1..n).map(|c| a[row][c] * x[c]).sum();
+        x[row] = (b[row] - s) / a[row][row];
+    }
+    true
+}
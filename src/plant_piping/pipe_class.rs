@@ -0,0 +1,438 @@
+//! 프로젝트 배관 등급(piping class) 카탈로그.
+//! 등급별 재질, 관경별 스케줄(또는 두께), 부식 여유, 최고 압력/온도를 TOML 파일에 정의하고
+//! 두께 계산과 관경 선정 결과가 그 등급에서 허용되는지 확인한다.
+//!
+//! 파일 형식 (`pipe_classes.toml`, config.toml과 같은 폴더):
+//! ```toml
+//! [[class]]
+//! name = "A1"
+//! description = "CS 150# RF"
+//! material = "A106B"            # material_db 재질 코드
+//! corrosion_allowance_mm = 1.5
+//! max_pressure_bar_g = 19.6
+//! max_temp_c = 425
+//! min_temp_c = -29               # 생략 가능
+//! mill_tolerance = 0.125         # 생략 시 0.125
+//! weld_joint_efficiency = 1.0    # 생략 시 1.0
+//! size = [
+//!   { dn = 50, schedule = "80" },
+//!   { dn = 100, schedule = "40" },
+//!   { dn = 150, wall_mm = 7.11 },  # 스케줄 대신 두께를 직접 줄 수 있다
+//! ]
+//! ```
+//! 스케줄 두께는 ASME B36.10M의 40/80/160, STD/XS를 DN15~DN300에 대해 내장한다.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::line_fill::pipe_dimensions_mm;
+use crate::material_db;
+use super::wall_thickness::{self, WallThicknessError, WallThicknessInput};
+
+/// 기본 배관 등급 파일 이름
+pub const PIPE_CLASS_FILE: &str = "pipe_classes.toml";
+/// 밀 공차가 없을 때 쓰는 값 (A106 12.5 %)
+pub const DEFAULT_MILL_TOLERANCE: f64 = 0.125;
+
+/// B36.10M 공칭 두께 (DN, Sch40, Sch80, Sch160) [mm]
+const SCHEDULE_WALLS: [(u32, f64, f64, f64); 14] = [
+    (15, 2.77, 3.73, 4.78),
+    (20, 2.87, 3.91, 5.56),
+    (25, 3.38, 4.55, 6.35),
+    (32, 3.56, 4.85, 6.35),
+    (40, 3.68, 5.08, 7.14),
+    (50, 3.91, 5.54, 8.74),
+    (65, 5.16, 7.01, 9.53),
+    (80, 5.49, 7.62, 11.13),
+    (100, 6.02, 8.56, 13.49),
+    (125, 6.55, 9.53, 15.88),
+    (150, 7.11, 10.97, 18.26),
+    (200, 8.18, 12.70, 23.01),
+    (250, 9.27, 15.09, 28.58),
+    (300, 10.31, 17.48, 33.32),
+];
+
+/// 스케줄 이름(`40`, `Sch80`, `STD`, `XS` 등)의 공칭 두께 [mm].
+/// STD/XS는 DN250 이상에서 Sch40/80과 다르다.
+pub fn schedule_wall_mm(dn: u32, schedule: &str) -> Option<f64> {
+    let &(_, s40, s80, s160) = SCHEDULE_WALLS.iter().find(|row| row.0 == dn)?;
+    let key = schedule.trim().to_ascii_uppercase();
+    let key = key.strip_prefix("SCH").unwrap_or(&key).trim();
+    match (key, dn) {
+        ("40", _) => Some(s40),
+        ("80", _) => Some(s80),
+        ("160", _) => Some(s160),
+        ("STD", 300) => Some(9.53),
+        ("STD", _) => Some(s40),
+        ("XS", 250) | ("XS", 300) => Some(12.70),
+        ("XS", _) => Some(s80),
+        _ => None,
+    }
+}
+
+/// 등급의 관경 한 줄. `schedule`이나 `wall_mm` 중 하나가 있어야 한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipeClassSize {
+    /// 호칭 관경 DN
+    pub dn: u32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schedule: Option<String>,
+    /// 공칭 두께 [mm]. 있으면 스케줄 표보다 우선한다.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wall_mm: Option<f64>,
+}
+
+fn default_mill_tolerance() -> f64 {
+    DEFAULT_MILL_TOLERANCE
+}
+
+fn default_joint_efficiency() -> f64 {
+    1.0
+}
+
+/// 배관 등급.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PipeClass {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// 재질 코드 (`material_db` 기준)
+    pub material: String,
+    #[serde(default)]
+    pub corrosion_allowance_mm: f64,
+    /// 등급 최고 압력 [bar(g)]
+    pub max_pressure_bar_g: f64,
+    /// 등급 최고 온도 [°C]
+    pub max_temp_c: f64,
+    /// 등급 최저 온도 [°C]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_temp_c: Option<f64>,
+    #[serde(default = "default_mill_tolerance")]
+    pub mill_tolerance: f64,
+    #[serde(default = "default_joint_efficiency")]
+    pub weld_joint_efficiency: f64,
+    #[serde(default, rename = "size")]
+    pub sizes: Vec<PipeClassSize>,
+}
+
+/// 등급에서 정한 관경의 치수.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClassPipe {
+    pub dn: u32,
+    /// 스케줄 이름 (두께를 직접 준 경우 `"7.11 mm"` 형식)
+    pub schedule: String,
+    pub outer_diameter_m: f64,
+    pub wall_m: f64,
+    pub inner_diameter_m: f64,
+}
+
+impl PipeClass {
+    /// 등급에 있는 관경의 치수. 없거나 스케줄을 모르면 오류.
+    pub fn pipe(&self, dn: u32) -> Result<ClassPipe, PipeClassError> {
+        let size = self.sizes.iter().find(|s| s.dn == dn).ok_or_else(|| {
+            PipeClassError::SizeNotInClass {
+                class: self.name.clone(),
+                dn,
+            }
+        })?;
+        let (od_mm, _) = pipe_dimensions_mm(dn).ok_or_else(|| {
+            PipeClassError::Invalid(format!("{}: 표준 관경표에 없는 DN{dn}", self.name))
+        })?;
+        let schedule = size.schedule.clone().unwrap_or_default();
+        let wall_mm = match size.wall_mm {
+            Some(wall) => wall,
+            None => schedule_wall_mm(dn, &schedule).ok_or_else(|| {
+                PipeClassError::Invalid(format!(
+                    "{}: DN{dn}의 스케줄 '{schedule}'을(를) 알 수 없습니다.",
+                    self.name
+                ))
+            })?,
+        };
+        let schedule = if schedule.is_empty() {
+            format!("{wall_mm:.2} mm")
+        } else {
+            schedule
+        };
+        Ok(ClassPipe {
+            dn,
+            schedule,
+            outer_diameter_m: od_mm / 1000.0,
+            wall_m: wall_mm / 1000.0,
+            inner_diameter_m: (od_mm - 2.0 * wall_mm) / 1000.0,
+        })
+    }
+
+    /// 등급의 모든 관경 치수 (DN 순).
+    pub fn pipes(&self) -> Result<Vec<ClassPipe>, PipeClassError> {
+        let mut pipes = self
+            .sizes
+            .iter()
+            .map(|s| self.pipe(s.dn))
+            .collect::<Result<Vec<_>, _>>()?;
+        pipes.sort_by_key(|p| p.dn);
+        Ok(pipes)
+    }
+
+    /// 등급 정의를 확인한다.
+    pub fn validate(&self) -> Result<(), PipeClassError> {
+        let invalid = |msg: &str| Err(PipeClassError::Invalid(format!("{}: {msg}", self.name)));
+        if self.name.trim().is_empty() {
+            return Err(PipeClassError::Invalid(
+                "배관 등급 이름은 비울 수 없습니다.".into(),
+            ));
+        }
+        if material_db::find_material(&self.material).is_none() {
+            return invalid(&format!("알 수 없는 재질 {}", self.material));
+        }
+        if self.corrosion_allowance_mm < 0.0 || !(0.0..0.5).contains(&self.mill_tolerance) {
+            return invalid("부식 여유는 0 이상, 밀 공차는 0~0.5 미만이어야 합니다.");
+        }
+        if !(self.weld_joint_efficiency > 0.0 && self.weld_joint_efficiency <= 1.0) {
+            return invalid("용접 이음 효율은 0 초과 1 이하여야 합니다.");
+        }
+        if self.max_pressure_bar_g <= 0.0 {
+            return invalid("최고 압력은 0보다 커야 합니다.");
+        }
+        if self.min_temp_c.is_some_and(|t| t >= self.max_temp_c) {
+            return invalid("최저 온도는 최고 온도보다 낮아야 합니다.");
+        }
+        if self.sizes.is_empty() {
+            return invalid("관경이 하나도 없습니다.");
+        }
+        for (i, size) in self.sizes.iter().enumerate() {
+            if self.sizes[..i].iter().any(|s| s.dn == size.dn) {
+                return invalid(&format!("DN{}이(가) 두 번 있습니다.", size.dn));
+            }
+        }
+        for pipe in self.pipes()? {
+            if pipe.wall_m <= 0.0 || pipe.inner_diameter_m <= 0.0 {
+                return invalid(&format!("DN{}의 두께가 잘못되었습니다.", pipe.dn));
+            }
+        }
+        Ok(())
+    }
+
+    /// 이 등급으로 만든 내압 두께 계산 입력.
+    pub fn wall_thickness_input(
+        &self,
+        pipe: &ClassPipe,
+        design_pressure_bar_g: f64,
+        design_temp_c: f64,
+    ) -> WallThicknessInput {
+        WallThicknessInput {
+            design_pressure_bar_g,
+            design_temp_c,
+            outer_diameter_m: pipe.outer_diameter_m,
+            material: self.material.clone(),
+            weld_joint_efficiency: self.weld_joint_efficiency,
+            weld_strength_reduction: 1.0,
+            corrosion_allowance_m: self.corrosion_allowance_mm / 1000.0,
+            mill_tolerance_frac: self.mill_tolerance,
+        }
+    }
+}
+
+/// 등급 관경 검토 결과.
+#[derive(Debug, Clone)]
+pub struct PipeClassCheck {
+    pub class: String,
+    pub pipe: ClassPipe,
+    /// 밀 공차를 감안한 필요 공칭 두께 [m]
+    pub required_wall_m: f64,
+    /// 등급 허용 범위를 벗어난 항목
+    pub violations: Vec<String>,
+}
+
+impl PipeClassCheck {
+    /// 등급에서 허용되는지.
+    pub fn acceptable(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// 배관 등급 오류.
+#[derive(Debug)]
+pub enum PipeClassError {
+    Invalid(String),
+    UnknownClass(String),
+    SizeNotInClass {
+        class: String,
+        dn: u32,
+    },
+    /// 필요 내경보다 큰 관경이 등급에 없다
+    NoSizeLargeEnough {
+        class: String,
+        inner_diameter_mm: f64,
+    },
+    WallThickness(WallThicknessError),
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+}
+
+impl std::fmt::Display for PipeClassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PipeClassError::Invalid(msg) => write!(f, "배관 등급 오류: {msg}"),
+            PipeClassError::UnknownClass(name) => write!(f, "알 수 없는 배관 등급: {name}"),
+            PipeClassError::SizeNotInClass { class, dn } => {
+                write!(f, "배관 등급 {class}에 DN{dn}이(가) 없습니다.")
+            }
+            PipeClassError::NoSizeLargeEnough {
+                class,
+                inner_diameter_mm,
+            } => write!(
+                f,
+                "배관 등급 {class}에 내경 {inner_diameter_mm:.1} mm 이상인 관경이 없습니다."
+            ),
+            PipeClassError::WallThickness(e) => write!(f, "{e}"),
+            PipeClassError::Io(e) => write!(f, "파일 입출력 오류: {e}"),
+            PipeClassError::Parse(e) => write!(f, "배관 등급 파일 파싱 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for PipeClassError {}
+
+impl From<WallThicknessError> for PipeClassError {
+    fn from(value: WallThicknessError) -> Self {
+        PipeClassError::WallThickness(value)
+    }
+}
+
+impl From<std::io::Error> for PipeClassError {
+    fn from(value: std::io::Error) -> Self {
+        PipeClassError::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for PipeClassError {
+    fn from(value: toml::de::Error) -> Self {
+        PipeClassError::Parse(value)
+    }
+}
+
+/// 등급 관경이 설계 압력/온도에서 허용되는지 검토한다.
+/// 등급 압력·온도 한계와, 등급 재질·부식 여유로 구한 필요 두께를 스케줄 두께와 비교한다.
+pub fn check_size(
+    class: &PipeClass,
+    dn: u32,
+    design_pressure_bar_g: f64,
+    design_temp_c: f64,
+) -> Result<PipeClassCheck, PipeClassError> {
+    let pipe = class.pipe(dn)?;
+    let required = wall_thickness::required_wall_thickness(&class.wall_thickness_input(
+        &pipe,
+        design_pressure_bar_g,
+        design_temp_c,
+    ))?;
+    let mut violations = Vec::new();
+    if design_pressure_bar_g > class.max_pressure_bar_g {
+        violations.push(format!(
+            "설계 압력 {design_pressure_bar_g:.1} bar(g)가 등급 {} 최고 압력 {:.1} bar(g)를 넘습니다.",
+            class.name, class.max_pressure_bar_g
+        ));
+    }
+    if design_temp_c > class.max_temp_c {
+        violations.push(format!(
+            "설계 온도 {design_temp_c:.0} °C가 등급 {} 최고 온도 {:.0} °C를 넘습니다.",
+            class.name, class.max_temp_c
+        ));
+    }
+    if let Some(min) = class.min_temp_c.filter(|&min| design_temp_c < min) {
+        violations.push(format!(
+            "설계 온도 {design_temp_c:.0} °C가 등급 {} 최저 온도 {min:.0} °C보다 낮습니다.",
+            class.name
+        ));
+    }
+    if pipe.wall_m < required.nominal_required_m {
+        violations.push(format!(
+            "DN{} {} 두께 {:.2} mm가 필요 두께 {:.2} mm보다 얇습니다.",
+            pipe.dn,
+            pipe.schedule,
+            pipe.wall_m * 1000.0,
+            required.nominal_required_m * 1000.0
+        ));
+    }
+    Ok(PipeClassCheck {
+        class: class.name.clone(),
+        pipe,
+        required_wall_m: required.nominal_required_m,
+        violations,
+    })
+}
+
+/// 관경 선정 결과 내경 이상인 가장 작은 등급 관경을 골라 검토한다.
+pub fn select_size(
+    class: &PipeClass,
+    min_inner_diameter_m: f64,
+    design_pressure_bar_g: f64,
+    design_temp_c: f64,
+) -> Result<PipeClassCheck, PipeClassError> {
+    let pipe = class
+        .pipes()?
+        .into_iter()
+        .find(|p| p.inner_diameter_m >= min_inner_diameter_m)
+        .ok_or_else(|| PipeClassError::NoSizeLargeEnough {
+            class: class.name.clone(),
+            inner_diameter_mm: min_inner_diameter_m * 1000.0,
+        })?;
+    check_size(class, pipe.dn, design_pressure_bar_g, design_temp_c)
+}
+
+/// 배관 등급 목록.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PipeClassCatalog {
+    #[serde(default, rename = "class")]
+    classes: Vec<PipeClass>,
+}
+
+impl PipeClassCatalog {
+    /// TOML 문자열에서 읽고 등급을 검사한다.
+    pub fn from_toml_str(content: &str) -> Result<Self, PipeClassError> {
+        let catalog: PipeClassCatalog = toml::from_str(content)?;
+        for (i, class) in catalog.classes.iter().enumerate() {
+            class.validate()?;
+            if catalog.classes[..i]
+                .iter()
+                .any(|c| c.name.eq_ignore_ascii_case(&class.name))
+            {
+                return Err(PipeClassError::Invalid(format!(
+                    "중복된 배관 등급: {}",
+                    class.name
+                )));
+            }
+        }
+        Ok(catalog)
+    }
+
+    /// 파일에서 읽는다. 파일이 없으면 빈 목록.
+    pub fn load(path: &Path) -> Result<Self, PipeClassError> {
+        if path.exists() {
+            Self::from_toml_str(&std::fs::read_to_string(path)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// 작업 폴더의 `pipe_classes.toml`을 읽는다.
+    pub fn load_default() -> Result<Self, PipeClassError> {
+        Self::load(Path::new(PIPE_CLASS_FILE))
+    }
+
+    pub fn classes(&self) -> &[PipeClass] {
+        &self.classes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// 이름으로 찾는다 (대소문자 무시).
+    pub fn find(&self, name: &str) -> Result<&PipeClass, PipeClassError> {
+        let name = name.trim();
+        self.classes
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| PipeClassError::UnknownClass(name.to_string()))
+    }
+}
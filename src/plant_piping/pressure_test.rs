@@ -0,0 +1,358 @@
+//! 배관 내압 시험 압력과 공압 시험 저장 에너지.
+//! 시험 압력은 B31.3 345.4/345.5와 B31.1 137.4/137.5를 따른다.
+//! - B31.3 수압: P_T = 1.5·P·(S_T/S), S_T/S ≤ 6.5 (시험/설계 온도 허용응력 비)
+//! - B31.3 공압: P_T = 1.1·P
+//! - B31.1 수압: P_T = 1.5·P, 공압: P_T = 1.2·P (온도 보정 없음)
+//!
+//! 시험 압력 유지 시간은 두 코드 모두 최소 10분이다.
+//! 공압 시험의 저장 에너지는 ASME PCC-2 Article 5.1의 단열 팽창 식
+//! E = P·V/(k−1)·[1 − (P_a/P)^((k−1)/k)] (절대압)으로 구하고,
+//! TNT 환산량 W와 환산 거리 R = 20·W^(1/3) [m]로 출입 제한 구역을 안내한다.
+
+use crate::material_db;
+use crate::warning::{CalcWarning, Severity};
+
+/// 시험 압력 유지 최소 시간 [min].
+pub const MIN_HOLD_MINUTES: f64 = 10.0;
+/// B31.3 수압 시험의 허용응력 비 상한.
+pub const MAX_STRESS_RATIO: f64 = 6.5;
+/// TNT 1 kg의 폭발 에너지 [J].
+pub const TNT_ENERGY_J_PER_KG: f64 = 4.184e6;
+/// PCC-2 출입 제한 환산 거리 [m/kg^(1/3)].
+pub const SCALED_EXCLUSION_DISTANCE: f64 = 20.0;
+/// 이 에너지를 넘으면 PCC-2에 따라 출입 제한 구역을 계산해 설정한다 [J].
+pub const STORED_ENERGY_THRESHOLD_J: f64 = 271_000.0;
+/// B31.3 공압 시험 첫 단계 압력 상한 [bar(g)] (170 kPa).
+const PNEUMATIC_FIRST_STEP_BAR: f64 = 1.7;
+/// B31.1 공압 시험 후 검사 압력 상한 [bar(g)] (100 psi).
+const B31_1_PNEUMATIC_EXAM_BAR: f64 = 6.9;
+
+/// 적용 배관 코드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestCode {
+    /// ASME B31.1 Power Piping
+    PowerPiping,
+    /// ASME B31.3 Process Piping
+    ProcessPiping,
+}
+
+impl TestCode {
+    pub const ALL: [TestCode; 2] = [TestCode::PowerPiping, TestCode::ProcessPiping];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TestCode::PowerPiping => "B31.1",
+            TestCode::ProcessPiping => "B31.3",
+        }
+    }
+}
+
+/// 시험 매체.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestMedium {
+    Hydrostatic,
+    Pneumatic,
+}
+
+impl TestMedium {
+    pub const ALL: [TestMedium; 2] = [TestMedium::Hydrostatic, TestMedium::Pneumatic];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TestMedium::Hydrostatic => "Hydrostatic",
+            TestMedium::Pneumatic => "Pneumatic",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            TestMedium::Hydrostatic => "test_medium.hydrostatic",
+            TestMedium::Pneumatic => "test_medium.pneumatic",
+        }
+    }
+}
+
+/// 시험 압력 계산 입력.
+#[derive(Debug, Clone)]
+pub struct TestPressureInput {
+    pub code: TestCode,
+    pub medium: TestMedium,
+    /// 설계 압력 [bar(g)]
+    pub design_pressure_bar_g: f64,
+    /// 설계 온도 [°C]
+    pub design_temp_c: f64,
+    /// 시험 온도 [°C]
+    pub test_temp_c: f64,
+    /// 재질 코드 (`material_db` 기준)
+    pub material: String,
+    /// 계획한 시험 압력 유지 시간 [min]
+    pub planned_hold_minutes: f64,
+}
+
+/// 시험 압력 계산 결과.
+#[derive(Debug, Clone)]
+pub struct TestPressureResult {
+    /// 설계 압력에 곱하는 코드 계수 (1.5, 1.2, 1.1)
+    pub factor: f64,
+    /// 적용한 허용응력 비 S_T/S (B31.3 수압 외에는 1)
+    pub stress_ratio: f64,
+    /// 시험 압력 [bar(g)]
+    pub test_pressure_bar_g: f64,
+    /// 최소 유지 시간 [min]
+    pub min_hold_minutes: f64,
+    /// 계획 유지 시간이 최소 유지 시간 이상인지
+    pub hold_ok: bool,
+    /// 누설 검사를 하는 압력 [bar(g)]
+    pub examination_pressure_bar_g: f64,
+    /// 공압 시험 승압 단계 [bar(g)]. 수압 시험은 비어 있다.
+    pub pressure_steps_bar_g: Vec<f64>,
+    pub warnings: Vec<PressureTestWarning>,
+}
+
+/// 압력 시험 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PressureTestWarning {
+    /// 허용응력 비 S_T/S를 상한으로 제한
+    StressRatioCapped { ratio: f64 },
+    /// 수압 시험 결빙 위험
+    HydroFreezeRisk { test_temp_c: f64 },
+    /// 공압 시험 취성 파괴/저장 에너지 위험
+    PneumaticHazard,
+    /// 계획 유지 시간이 최소보다 짧음
+    HoldTooShort { planned_minutes: f64 },
+}
+
+impl CalcWarning for PressureTestWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            PressureTestWarning::StressRatioCapped { .. } => {
+                "warning.pressure_test.stress_ratio_capped"
+            }
+            PressureTestWarning::HydroFreezeRisk { .. } => "warning.pressure_test.freeze_risk",
+            PressureTestWarning::PneumaticHazard => "warning.pressure_test.pneumatic_hazard",
+            PressureTestWarning::HoldTooShort { .. } => "warning.pressure_test.hold_too_short",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            PressureTestWarning::StressRatioCapped { ratio } => vec![
+                ("ratio", format!("{ratio:.2}")),
+                ("max", format!("{MAX_STRESS_RATIO}")),
+            ],
+            PressureTestWarning::HydroFreezeRisk { test_temp_c } => {
+                vec![("temp", format!("{test_temp_c:.0}"))]
+            }
+            PressureTestWarning::PneumaticHazard => Vec::new(),
+            PressureTestWarning::HoldTooShort { planned_minutes } => vec![
+                ("hold", format!("{planned_minutes:.0}")),
+                ("min", format!("{MIN_HOLD_MINUTES:.0}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for PressureTestWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PressureTestWarning::StressRatioCapped { ratio } => write!(
+                f,
+                "허용응력 비 {ratio:.2}가 {MAX_STRESS_RATIO}를 넘어 {MAX_STRESS_RATIO}로 제한했습니다."
+            ),
+            PressureTestWarning::HydroFreezeRisk { test_temp_c } => write!(
+                f,
+                "시험 온도 {test_temp_c:.0} °C는 결빙 위험이 있습니다. 시험수 온도를 높이거나 부동액 사용을 검토하세요."
+            ),
+            PressureTestWarning::PneumaticHazard => f.write_str(
+                "공압 시험은 취성 파괴와 저장 에너지 위험이 있습니다. 저장 에너지와 출입 제한 구역을 확인하세요.",
+            ),
+            PressureTestWarning::HoldTooShort { planned_minutes } => write!(
+                f,
+                "유지 시간 {planned_minutes:.0}분이 최소 {MIN_HOLD_MINUTES:.0}분보다 짧습니다."
+            ),
+        }
+    }
+}
+
+/// 공압 시험 저장 에너지 입력.
+#[derive(Debug, Clone)]
+pub struct StoredEnergyInput {
+    /// 시험 압력 [bar(g)]
+    pub test_pressure_bar_g: f64,
+    /// 시험 구간 내부 체적 [m³]
+    pub volume_m3: f64,
+    /// 시험 기체 비열비 k (공기/질소 1.4)
+    pub gamma: f64,
+    /// 대기압 [bar abs]
+    pub ambient_pressure_bar_abs: f64,
+}
+
+/// 공압 시험 저장 에너지 결과.
+#[derive(Debug, Clone)]
+pub struct StoredEnergyResult {
+    /// 저장 에너지 [J]
+    pub energy_j: f64,
+    /// TNT 환산량 [kg]
+    pub tnt_equivalent_kg: f64,
+    /// 출입 제한 거리 [m]
+    pub exclusion_radius_m: f64,
+    /// PCC-2 기준 에너지를 넘는지
+    pub exceeds_threshold: bool,
+    /// 안전 권고 사항
+    pub guidance: Vec<String>,
+}
+
+/// 압력 시험 계산 오류.
+#[derive(Debug, Clone)]
+pub enum PressureTestError {
+    InvalidInput(&'static str),
+    UnknownMaterial(String),
+}
+
+impl std::fmt::Display for PressureTestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PressureTestError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            PressureTestError::UnknownMaterial(code) => write!(f, "알 수 없는 재질: {code}"),
+        }
+    }
+}
+
+impl std::error::Error for PressureTestError {}
+
+/// 코드와 시험 매체에 따른 시험 압력, 유지 시간, 공압 승압 단계를 구한다.
+pub fn test_pressure(input: &TestPressureInput) -> Result<TestPressureResult, PressureTestError> {
+    let p = input.design_pressure_bar_g;
+    if p <= 0.0 {
+        return Err(PressureTestError::InvalidInput(
+            "설계 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input.planned_hold_minutes < 0.0 {
+        return Err(PressureTestError::InvalidInput(
+            "유지 시간은 음수일 수 없습니다.",
+        ));
+    }
+
+    let mut warnings = Vec::new();
+    let (factor, stress_ratio) = match (input.code, input.medium) {
+        (TestCode::ProcessPiping, TestMedium::Hydrostatic) => {
+            let stress = |t: f64| {
+                material_db::allowable_stress(&input.material, t)
+                    .ok_or_else(|| PressureTestError::UnknownMaterial(input.material.clone()))
+            };
+            let s_test = stress(input.test_temp_c)?.value_mpa;
+            let s_design = stress(input.design_temp_c)?.value_mpa;
+            let ratio = s_test / s_design;
+            if ratio > MAX_STRESS_RATIO {
+                warnings.push(PressureTestWarning::StressRatioCapped { ratio });
+            }
+            (1.5, ratio.min(MAX_STRESS_RATIO))
+        }
+        (TestCode::ProcessPiping, TestMedium::Pneumatic) => (1.1, 1.0),
+        (TestCode::PowerPiping, TestMedium::Hydrostatic) => (1.5, 1.0),
+        (TestCode::PowerPiping, TestMedium::Pneumatic) => (1.2, 1.0),
+    };
+    let pt = factor * stress_ratio * p;
+
+    let mut steps = Vec::new();
+    let examination = match input.medium {
+        TestMedium::Hydrostatic => {
+            if input.test_temp_c <= 5.0 {
+                warnings.push(PressureTestWarning::HydroFreezeRisk {
+                    test_temp_c: input.test_temp_c,
+                });
+            }
+            pt
+        }
+        TestMedium::Pneumatic => {
+            // B31.3 345.5.5: min(PT/2, 170 kPa)까지 올려 예비 검사 후 PT/10씩 승압
+            let first = (pt / 2.0).min(PNEUMATIC_FIRST_STEP_BAR);
+            steps.push(first);
+            let increment = pt / 10.0;
+            let mut next = first + increment;
+            while next < pt - 1e-9 {
+                steps.push(next);
+                next += increment;
+            }
+            steps.push(pt);
+            warnings.push(PressureTestWarning::PneumaticHazard);
+            match input.code {
+                TestCode::ProcessPiping => p,
+                TestCode::PowerPiping => p.min(B31_1_PNEUMATIC_EXAM_BAR),
+            }
+        }
+    };
+
+    let hold_ok = input.planned_hold_minutes >= MIN_HOLD_MINUTES;
+    if !hold_ok {
+        warnings.push(PressureTestWarning::HoldTooShort {
+            planned_minutes: input.planned_hold_minutes,
+        });
+    }
+
+    Ok(TestPressureResult {
+        factor,
+        stress_ratio,
+        test_pressure_bar_g: pt,
+        min_hold_minutes: MIN_HOLD_MINUTES,
+        hold_ok,
+        examination_pressure_bar_g: examination,
+        pressure_steps_bar_g: steps,
+        warnings,
+    })
+}
+
+/// 공압 시험 구간의 저장 에너지와 출입 제한 거리를 구한다.
+pub fn pneumatic_stored_energy(
+    input: &StoredEnergyInput,
+) -> Result<StoredEnergyResult, PressureTestError> {
+    if input.test_pressure_bar_g <= 0.0 || input.volume_m3 <= 0.0 {
+        return Err(PressureTestError::InvalidInput(
+            "시험 압력과 체적은 0보다 커야 합니다.",
+        ));
+    }
+    if input.gamma <= 1.0 || input.ambient_pressure_bar_abs <= 0.0 {
+        return Err(PressureTestError::InvalidInput(
+            "비열비는 1보다, 대기압은 0보다 커야 합니다.",
+        ));
+    }
+    let k = input.gamma;
+    let pa = input.ambient_pressure_bar_abs * 1e5;
+    let p_abs = input.test_pressure_bar_g * 1e5 + pa;
+    let energy = p_abs * input.volume_m3 / (k - 1.0) * (1.0 - (pa / p_abs).powf((k - 1.0) / k));
+    let tnt = energy / TNT_ENERGY_J_PER_KG;
+    let radius = SCALED_EXCLUSION_DISTANCE * tnt.cbrt();
+    let exceeds = energy > STORED_ENERGY_THRESHOLD_J;
+
+    let mut guidance = Vec::new();
+    if exceeds {
+        guidance.push(format!(
+            "저장 에너지 {:.0} kJ가 {:.0} kJ를 넘습니다. 반경 {radius:.0} m를 출입 제한 구역으로 설정하고, 승압 중에는 구역 밖에서 원격 감시하세요.",
+            energy / 1000.0,
+            STORED_ENERGY_THRESHOLD_J / 1000.0
+        ));
+        guidance.push(
+            "시험 구간을 나누거나 수압 시험으로 바꿔 저장 에너지를 줄일 수 있는지 검토하세요."
+                .into(),
+        );
+    } else {
+        guidance.push(format!(
+            "저장 에너지 {:.0} kJ는 {:.0} kJ 이하입니다. 시험 구간 주변 출입을 통제하세요.",
+            energy / 1000.0,
+            STORED_ENERGY_THRESHOLD_J / 1000.0
+        ));
+    }
+
+    Ok(StoredEnergyResult {
+        energy_j: energy,
+        tnt_equivalent_kg: tnt,
+        exclusion_radius_m: radius,
+        exceeds_threshold: exceeds,
+        guidance,
+    })
+}
@@ -0,0 +1,208 @@
+//! ASME B31.1/B31.3 직관 내압 필요 두께.
+//! t = P·D / (2·(S·E·W + P·y)), t_m = t + A(부식 여유), 주문 두께 = t_m / (1 − 밀 공차)로 구한다.
+//! S는 재질 DB의 설계 온도 허용응력, y는 B31 표의 재질군/온도별 계수를 쓴다.
+//! t ≥ D/6인 후육관은 코드상 별도 검토 대상이므로 경고만 낸다.
+
+use crate::material_db;
+use crate::warning::{CalcWarning, Severity};
+
+/// 배관 두께 계산 오류.
+#[derive(Debug, Clone)]
+pub enum WallThicknessError {
+    InvalidInput(&'static str),
+    UnknownMaterial(String),
+}
+
+impl std::fmt::Display for WallThicknessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WallThicknessError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            WallThicknessError::UnknownMaterial(code) => write!(f, "알 수 없는 재질: {code}"),
+        }
+    }
+}
+
+impl std::error::Error for WallThicknessError {}
+
+/// 내압 설계 조건.
+#[derive(Debug, Clone)]
+pub struct WallThicknessInput {
+    /// 설계 압력 [bar(g)]
+    pub design_pressure_bar_g: f64,
+    /// 설계 온도 [°C]
+    pub design_temp_c: f64,
+    /// 배관 외경 [m]
+    pub outer_diameter_m: f64,
+    /// 재질 코드 (`material_db` 기준)
+    pub material: String,
+    /// 용접 이음 효율 E (무계목 1.0)
+    pub weld_joint_efficiency: f64,
+    /// 용접 강도 감소 계수 W (크리프 영역 외 1.0)
+    pub weld_strength_reduction: f64,
+    /// 부식 여유 A [m]
+    pub corrosion_allowance_m: f64,
+    /// 밀 공차 비율 (A106 12.5% → 0.125)
+    pub mill_tolerance_frac: f64,
+}
+
+/// 내압 필요 두께 결과.
+#[derive(Debug, Clone)]
+pub struct WallThicknessResult {
+    /// 설계 온도 허용응력 S [MPa]
+    pub allowable_stress_mpa: f64,
+    pub y_coefficient: f64,
+    /// 내압 설계 두께 t [m]
+    pub pressure_design_thickness_m: f64,
+    /// 최소 필요 두께 t_m = t + A [m]
+    pub minimum_required_m: f64,
+    /// 밀 공차를 감안한 주문 두께 [m]
+    pub nominal_required_m: f64,
+    pub warnings: Vec<WallThicknessWarning>,
+}
+
+/// 배관 두께 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WallThicknessWarning {
+    /// 설계 온도가 재질 허용응력 표 범위를 넘어 표 끝 값을 사용
+    StressTableClamped {
+        design_temp_c: f64,
+        source_temp_c: f64,
+    },
+    /// t ≥ D/6 후육관
+    ThickWall { thickness_mm: f64 },
+}
+
+impl CalcWarning for WallThicknessWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            WallThicknessWarning::StressTableClamped { .. } => {
+                "warning.wall_thickness.stress_table_clamped"
+            }
+            WallThicknessWarning::ThickWall { .. } => "warning.wall_thickness.thick_wall",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            WallThicknessWarning::StressTableClamped {
+                design_temp_c,
+                source_temp_c,
+            } => vec![
+                ("design", format!("{design_temp_c:.0}")),
+                ("source", format!("{source_temp_c:.0}")),
+            ],
+            WallThicknessWarning::ThickWall { thickness_mm } => {
+                vec![("t", format!("{thickness_mm:.1}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for WallThicknessWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WallThicknessWarning::StressTableClamped {
+                design_temp_c,
+                source_temp_c,
+            } => write!(
+                f,
+                "설계 온도 {design_temp_c:.0} °C가 재질 테이블 범위를 넘어 {source_temp_c:.0} °C 허용응력을 사용했습니다."
+            ),
+            WallThicknessWarning::ThickWall { thickness_mm } => write!(
+                f,
+                "t = {thickness_mm:.1} mm ≥ D/6입니다. 후육관은 B31 304.1.2(b)에 따라 별도 검토하세요."
+            ),
+        }
+    }
+}
+
+/// B31.1 Table 104.1.2(A) / B31.3 Table 304.1.1의 y 계수. 표 사이 온도는 선형 보간한다.
+pub fn y_coefficient(austenitic: bool, temp_c: f64) -> f64 {
+    let (t1, t2, t3) = if austenitic {
+        (566.0, 593.0, 621.0)
+    } else {
+        (482.0, 510.0, 538.0)
+    };
+    if temp_c <= t1 {
+        0.4
+    } else if temp_c <= t2 {
+        0.4 + 0.1 * (temp_c - t1) / (t2 - t1)
+    } else if temp_c <= t3 {
+        0.5 + 0.2 * (temp_c - t2) / (t3 - t2)
+    } else {
+        0.7
+    }
+}
+
+/// 두께 `thickness_m`로 견딜 수 있는 내압 [bar(g)]. 두께 공식을 P에 대해 푼 값이다.
+pub fn pressure_for_thickness_bar_g(
+    thickness_m: f64,
+    outer_diameter_m: f64,
+    allowable_stress_mpa: f64,
+    joint_factor: f64,
+    y: f64,
+) -> f64 {
+    // P = 2·S·E·W·t / (D − 2·y·t) [MPa]
+    let p_mpa = 2.0 * allowable_stress_mpa * joint_factor * thickness_m
+        / (outer_diameter_m - 2.0 * y * thickness_m);
+    p_mpa * 10.0
+}
+
+/// 설계 조건에서 직관 필요 두께를 구한다.
+pub fn required_wall_thickness(
+    input: &WallThicknessInput,
+) -> Result<WallThicknessResult, WallThicknessError> {
+    if input.design_pressure_bar_g <= 0.0 || input.outer_diameter_m <= 0.0 {
+        return Err(WallThicknessError::InvalidInput(
+            "설계 압력과 외경은 0보다 커야 합니다.",
+        ));
+    }
+    let e = input.weld_joint_efficiency;
+    let w = input.weld_strength_reduction;
+    if !(e > 0.0 && e <= 1.0 && w > 0.0 && w <= 1.0) {
+        return Err(WallThicknessError::InvalidInput(
+            "용접 이음 효율과 강도 감소 계수는 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if input.corrosion_allowance_m < 0.0 || !(0.0..0.5).contains(&input.mill_tolerance_frac) {
+        return Err(WallThicknessError::InvalidInput(
+            "부식 여유는 0 이상, 밀 공차는 0~0.5 미만이어야 합니다.",
+        ));
+    }
+    let mat = material_db::find_material(&input.material)
+        .ok_or_else(|| WallThicknessError::UnknownMaterial(input.material.clone()))?;
+    let s = material_db::allowable_stress(mat.code, input.design_temp_c)
+        .ok_or_else(|| WallThicknessError::UnknownMaterial(input.material.clone()))?;
+    let y = y_coefficient(mat.is_austenitic(), input.design_temp_c);
+
+    let p_mpa = input.design_pressure_bar_g / 10.0;
+    let d = input.outer_diameter_m;
+    let t = p_mpa * d / (2.0 * (s.value_mpa * e * w + p_mpa * y));
+    let t_m = t + input.corrosion_allowance_m;
+
+    let mut warnings = Vec::new();
+    if s.clamped && input.design_temp_c > s.source_temp_c {
+        warnings.push(WallThicknessWarning::StressTableClamped {
+            design_temp_c: input.design_temp_c,
+            source_temp_c: s.source_temp_c,
+        });
+    }
+    if t >= d / 6.0 {
+        warnings.push(WallThicknessWarning::ThickWall {
+            thickness_mm: t * 1000.0,
+        });
+    }
+
+    Ok(WallThicknessResult {
+        allowable_stress_mpa: s.value_mpa,
+        y_coefficient: y,
+        pressure_design_thickness_m: t,
+        minimum_required_m: t_m,
+        nominal_required_m: t_m / (1.0 - input.mill_tolerance_frac),
+        warnings,
+    })
+}
@@ -0,0 +1,391 @@
+//! 명령줄 인자처럼 숫자와 단위가 붙은 문자열(`10bar_a`, `5t/h`, `200C`)을 강타입 물리량으로 읽는다.
+//! 단위를 생략하면 각 함수의 기본 단위를 쓰고, 압력은 `g`/`_g`/`(g)` 접미사가 있을 때만 게이지압으로 본다.
+
+use crate::custom_units;
+use crate::flow_units::{self, FlowKind};
+use crate::quantity::QuantityKind;
+use crate::typed_quantity::{
+    Atmosphere, Density, Length, MassFlow, Pressure, PressureDifference, PressureReference,
+    PressureValue, Temperature, Velocity, VolumeFlow, PRESSURE_DIFFERENCE_UNITS,
+};
+use crate::units::{PressureUnit, TemperatureUnit};
+
+/// 값/단위 인자 해석 오류.
+#[derive(Debug, Clone, PartialEq)]
+pub enum QuantityArgError {
+    /// 숫자 부분이 없거나 읽을 수 없음
+    InvalidNumber(String),
+    /// 해당 물리량에서 지원하지 않는 단위
+    UnknownUnit(String),
+    /// 지원하지 않는 단위지만 철자가 비슷한 후보가 있음 (`suggestion`은 후보의 표시 라벨)
+    UnitSuggestion { unit: String, suggestion: String },
+}
+
+impl std::fmt::Display for QuantityArgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            QuantityArgError::InvalidNumber(s) => write!(f, "숫자를 읽을 수 없음: {s}"),
+            QuantityArgError::UnknownUnit(s) => write!(f, "지원하지 않는 단위: {s}"),
+            QuantityArgError::UnitSuggestion { unit, suggestion } => {
+                write!(f, "지원하지 않는 단위: {unit} (혹시 '{suggestion}'?)")
+            }
+        }
+    }
+}
+
+impl std::error::Error for QuantityArgError {}
+
+/// `"10bar_a"` → `(10.0, "bar_a")`. 단위 앞의 공백과 `_`는 무시한다.
+pub fn split_value_unit(input: &str) -> Result<(f64, &str), QuantityArgError> {
+    let s = input.trim();
+    let bytes = s.as_bytes();
+    let mut end = 0;
+    if matches!(bytes.first(), Some(b'+' | b'-')) {
+        end = 1;
+    }
+    while end < bytes.len() {
+        match bytes[end] {
+            b'0'..=b'9' | b'.' => end += 1,
+            // 지수 표기(1e5, 2.5E-3)만 숫자로 보고 `e` 뒤에 숫자가 없으면 단위로 남긴다.
+            b'e' | b'E' => {
+                let digits_from = match bytes.get(end + 1) {
+                    Some(b'+' | b'-') => end + 2,
+                    _ => end + 1,
+                };
+                if !bytes.get(digits_from).is_some_and(u8::is_ascii_digit) {
+                    break;
+                }
+                end = digits_from;
+            }
+            _ => break,
+        }
+    }
+    let value = s[..end]
+        .parse::<f64>()
+        .map_err(|_| QuantityArgError::InvalidNumber(input.to_string()))?;
+    let unit = s[end..].trim_start().trim_start_matches('_');
+    Ok((value, unit))
+}
+
+/// 압력. 기본 단위 bar, 기본 기준은 절대압. `barg`/`bar_g`/`psig`는 게이지, `bara`/`bar(a)`는 절대,
+/// `mmhg_v`/`kpa(v)`는 진공도로 읽고 게이지/진공은 `atm` 기준으로 절대압으로 바꾼다.
+pub fn parse_pressure(input: &str, atm: Atmosphere) -> Result<Pressure, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    let (unit, reference) = pressure_unit(unit)?;
+    Ok(PressureValue::new(value, unit, reference).to_pressure(atm))
+}
+
+fn pressure_unit(raw: &str) -> Result<(PressureUnit, PressureReference), QuantityArgError> {
+    let u = normalize(raw);
+    if let Some(unit) = pressure_unit_name(&u) {
+        return Ok((unit, PressureReference::Absolute));
+    }
+    let suffixes = [
+        ("(a)", PressureReference::Absolute),
+        ("(g)", PressureReference::Gauge),
+        ("(v)", PressureReference::Vacuum),
+        ("abs", PressureReference::Absolute),
+        ("vac", PressureReference::Vacuum),
+        ("a", PressureReference::Absolute),
+        ("g", PressureReference::Gauge),
+        ("v", PressureReference::Vacuum),
+    ];
+    for (suffix, reference) in suffixes {
+        if let Some(base) = u.strip_suffix(suffix) {
+            let base = base.trim_end_matches('_');
+            if let Some(unit) = pressure_unit_name(base) {
+                return Ok((unit, reference));
+            }
+        }
+    }
+    Err(QuantityArgError::UnknownUnit(raw.to_string()))
+}
+
+fn pressure_unit_name(u: &str) -> Option<PressureUnit> {
+    Some(match u {
+        "" | "bar" => PressureUnit::Bar,
+        "mbar" => PressureUnit::MilliBar,
+        "pa" => PressureUnit::Pascal,
+        "kpa" => PressureUnit::KiloPascal,
+        "mpa" => PressureUnit::MegaPascal,
+        "psi" => PressureUnit::Psi,
+        "atm" => PressureUnit::Atm,
+        "kg/cm2" | "kgf/cm2" | "kg/cm²" => PressureUnit::KgPerCm2,
+        "mmhg" | "torr" => PressureUnit::MmHg,
+        _ => return None,
+    })
+}
+
+/// 압력 차(ΔP). 기본 단위 bar, 단위는 `PRESSURE_DIFFERENCE_UNITS`와 같다 (`mbar`, `kpa`, `psi`, `mmh2o` 등).
+/// 차이값이므로 `g`/`a` 접미사는 배율에 영향을 주지 않는다.
+pub fn parse_pressure_difference(input: &str) -> Result<PressureDifference, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    let code = if unit.is_empty() { "bar" } else { unit };
+    PressureDifference::from_unit(value, &normalize(code))
+        .ok_or_else(|| QuantityArgError::UnknownUnit(unit.to_string()))
+}
+
+/// 온도. 기본 단위 °C (`c`, `°c`, `degc`, `k`, `f`, `°f`, `r`).
+pub fn parse_temperature(input: &str) -> Result<Temperature, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    let norm = normalize(unit);
+    let unit_enum = match norm.trim_start_matches('°').trim_start_matches("deg") {
+        "" | "c" => TemperatureUnit::Celsius,
+        "k" => TemperatureUnit::Kelvin,
+        "f" => TemperatureUnit::Fahrenheit,
+        "r" => TemperatureUnit::Rankine,
+        _ => return Err(QuantityArgError::UnknownUnit(unit.to_string())),
+    };
+    Ok(Temperature::new(value, unit_enum))
+}
+
+/// 질량 유량. 기본 단위 kg/h, 단위 코드는 `flow_units`와 같다 (`t/h`, `kg/s`, `lb/h`, `klb/h` 등).
+pub fn parse_mass_flow(input: &str) -> Result<MassFlow, QuantityArgError> {
+    let kg_per_s = parse_flow(input, FlowKind::MassFlow, "kg/h", "kg/s")?;
+    Ok(MassFlow::from_kg_per_s(kg_per_s))
+}
+
+/// 체적 유량. 기본 단위 m3/h, 단위 코드는 `flow_units`와 같다 (`l/min`, `gpm`, `cfm` 등).
+pub fn parse_volume_flow(input: &str) -> Result<VolumeFlow, QuantityArgError> {
+    let m3_per_h = parse_flow(input, FlowKind::VolumetricFlow, "m3/h", "m3/h")?;
+    Ok(VolumeFlow::from_m3_per_h(m3_per_h))
+}
+
+fn parse_flow(
+    input: &str,
+    kind: FlowKind,
+    default_unit: &str,
+    base_unit: &str,
+) -> Result<f64, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    let norm = normalize(unit).replace('³', "3");
+    let from = if norm.is_empty() {
+        default_unit
+    } else {
+        norm.as_str()
+    };
+    flow_units::convert(kind, value, from, base_unit)
+        .map_err(|_| QuantityArgError::UnknownUnit(unit.to_string()))
+}
+
+/// 밀도. 기본 단위 kg/m3, 단위 코드는 `flow_units`와 같다 (`g/cm3`, `lb/ft3`, `lb/gal`).
+pub fn parse_density(input: &str) -> Result<Density, QuantityArgError> {
+    let kg_per_m3 = parse_flow(input, FlowKind::Density, "kg/m3", "kg/m3")?;
+    Ok(Density::from_kg_per_m3(kg_per_m3))
+}
+
+/// 유속. 기본 단위 m/s (`ft/s`, `km/h`).
+pub fn parse_velocity(input: &str) -> Result<Velocity, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    if unit.is_empty() {
+        return Ok(Velocity::from_m_per_s(value));
+    }
+    Velocity::new(value, unit).map_err(|_| QuantityArgError::UnknownUnit(unit.to_string()))
+}
+
+/// 길이. 단위가 없으면 `default_unit`(예: 배관 내경은 "mm", 배관 길이는 "m")을 쓴다.
+/// `"` 접미사는 inch로 읽는다.
+pub fn parse_length(input: &str, default_unit: &str) -> Result<Length, QuantityArgError> {
+    let (value, unit) = split_value_unit(input)?;
+    let unit = match unit {
+        "" => default_unit,
+        "\"" => "in",
+        u => u,
+    };
+    Length::new(value, unit).map_err(|_| QuantityArgError::UnknownUnit(unit.to_string()))
+}
+
+/// 숫자와 단위를 한 칸에 받는 입력(`"150 psig"`, `"4in"`)이 바꿔 넣을 작업 단위.
+/// GUI 카드의 단위 고정 입력 칸이 쓰며, 단위를 생략하면 작업 단위로 읽는다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldQuantity {
+    /// 절대압 [bar(a)]. 게이지/진공 접미사는 대기압 기준으로 환산한다.
+    PressureBarAbs,
+    /// 압력 차 [bar]
+    PressureDifferenceBar,
+    /// 온도 [°C]
+    TemperatureC,
+    /// 길이 [m]
+    LengthM,
+    /// 길이 [mm]
+    LengthMm,
+    /// 질량 유량 [kg/h]
+    MassFlowKgPerH,
+    /// 체적 유량 [m3/h]
+    VolumeFlowM3PerH,
+    /// 밀도 [kg/m3]
+    DensityKgPerM3,
+    /// 유속 [m/s]
+    VelocityMPerS,
+}
+
+/// 압력 단위 `(라벨, 철자)`. 게이지/절대 접미사를 붙여 퍼지 검색 후보를 만든다.
+const PRESSURE_UNIT_SPELLINGS: &[(&str, &str)] = &[
+    ("bar", "bar"),
+    ("mbar", "mbar"),
+    ("Pa", "pa"),
+    ("kPa", "kpa"),
+    ("MPa", "mpa"),
+    ("psi", "psi"),
+    ("atm", "atm"),
+    ("kg/cm²", "kg/cm2"),
+    ("mmHg", "mmhg"),
+];
+
+impl FieldQuantity {
+    /// 작업 단위 표시.
+    pub fn unit(self) -> &'static str {
+        match self {
+            FieldQuantity::PressureBarAbs => "bar(a)",
+            FieldQuantity::PressureDifferenceBar => "bar",
+            FieldQuantity::TemperatureC => "°C",
+            FieldQuantity::LengthM => "m",
+            FieldQuantity::LengthMm => "mm",
+            FieldQuantity::MassFlowKgPerH => "kg/h",
+            FieldQuantity::VolumeFlowM3PerH => "m3/h",
+            FieldQuantity::DensityKgPerM3 => "kg/m3",
+            FieldQuantity::VelocityMPerS => "m/s",
+        }
+    }
+
+    /// 입력을 작업 단위 값으로 읽는다. 단위 철자가 정확하지 않으면 [`parse_unit_fuzzy`]로
+    /// 후보를 찾아 다시 읽고(`"bar a"`, `"PSI-G"`), 비슷한 단위만 있으면 제안 오류를 돌려준다.
+    pub fn parse(self, input: &str, atm: Atmosphere) -> Result<f64, QuantityArgError> {
+        match self.parse_exact(input, atm) {
+            Err(QuantityArgError::UnknownUnit(_)) => {
+                let (value, unit) = split_value_unit(input)?;
+                let spellings = self.unit_spellings();
+                let spelling = parse_unit_fuzzy(unit, &spellings)?;
+                self.parse_exact(&format!("{value}{spelling}"), atm)
+            }
+            other => other,
+        }
+    }
+
+    fn parse_exact(self, input: &str, atm: Atmosphere) -> Result<f64, QuantityArgError> {
+        match self {
+            FieldQuantity::PressureBarAbs => parse_pressure(input, atm).map(Pressure::bar_abs),
+            FieldQuantity::PressureDifferenceBar => {
+                parse_pressure_difference(input).map(PressureDifference::bar)
+            }
+            FieldQuantity::TemperatureC => parse_temperature(input).map(Temperature::celsius),
+            FieldQuantity::LengthM => parse_length(input, "m").map(Length::m),
+            FieldQuantity::LengthMm => parse_length(input, "mm").map(Length::mm),
+            FieldQuantity::MassFlowKgPerH => parse_mass_flow(input).map(MassFlow::kg_per_h),
+            FieldQuantity::VolumeFlowM3PerH => parse_volume_flow(input).map(VolumeFlow::m3_per_h),
+            FieldQuantity::DensityKgPerM3 => parse_density(input).map(Density::kg_per_m3),
+            FieldQuantity::VelocityMPerS => parse_velocity(input).map(Velocity::m_per_s),
+        }
+    }
+
+    /// 퍼지 검색 후보 `(라벨, parse_exact가 읽는 철자)`.
+    fn unit_spellings(self) -> Vec<(String, String)> {
+        let owned = |list: &[(&str, &str)]| -> Vec<(String, String)> {
+            list.iter()
+                .map(|(label, code)| (label.to_string(), code.to_string()))
+                .collect()
+        };
+        match self {
+            FieldQuantity::PressureBarAbs => PRESSURE_UNIT_SPELLINGS
+                .iter()
+                .flat_map(|(label, code)| {
+                    [("", ""), ("(a)", "a"), ("(g)", "g")].map(|(shown, suffix)| {
+                        (format!("{label}{shown}"), format!("{code}{suffix}"))
+                    })
+                })
+                .collect(),
+            FieldQuantity::PressureDifferenceBar => owned(PRESSURE_DIFFERENCE_UNITS),
+            FieldQuantity::TemperatureC => owned(custom_units::builtin_unit_options(
+                QuantityKind::Temperature,
+            )),
+            FieldQuantity::LengthM | FieldQuantity::LengthMm => {
+                owned(custom_units::builtin_unit_options(QuantityKind::Length))
+            }
+            FieldQuantity::MassFlowKgPerH => owned(flow_units::unit_options(FlowKind::MassFlow)),
+            FieldQuantity::VolumeFlowM3PerH => {
+                owned(flow_units::unit_options(FlowKind::VolumetricFlow))
+            }
+            FieldQuantity::DensityKgPerM3 => owned(flow_units::unit_options(FlowKind::Density)),
+            FieldQuantity::VelocityMPerS => {
+                owned(custom_units::builtin_unit_options(QuantityKind::Velocity))
+            }
+        }
+    }
+}
+
+/// 자유 입력 단위를 `(표시 라벨, 변환 코드)` 후보 목록에서 찾아 코드를 돌려준다.
+/// 대소문자, 공백, `_`, 괄호, `°`, `·`, `-`와 `³`/`²` 표기 차이는 무시하고 라벨과 코드 모두와 비교하므로
+/// `"bar a"`는 `bar(a)`, `"barg"`는 `bar(g)`, `"KPA"`는 `kPa`로 읽힌다.
+/// 일치하는 후보가 없으면 한 단위만 가리키는 접두어(`"ps"` → psi)를 받아들이고,
+/// 그래도 없으면 편집 거리가 가까운 후보를 [`QuantityArgError::UnitSuggestion`]으로 알려 준다.
+pub fn parse_unit_fuzzy<'a, S: AsRef<str>>(
+    input: &str,
+    options: &'a [(S, S)],
+) -> Result<&'a str, QuantityArgError> {
+    let key = unit_key(input);
+    if key.is_empty() {
+        return Err(QuantityArgError::UnknownUnit(input.trim().to_string()));
+    }
+    let keyed = || {
+        options.iter().flat_map(|(label, code)| {
+            [label.as_ref(), code.as_ref()]
+                .map(|name| (unit_key(name), label.as_ref(), code.as_ref()))
+        })
+    };
+    if let Some((_, _, code)) = keyed().find(|(k, _, _)| *k == key) {
+        return Ok(code);
+    }
+    let mut prefixed = keyed()
+        .filter(|(k, _, _)| k.starts_with(&key))
+        .map(|(_, _, code)| code);
+    if let Some(first) = prefixed.next() {
+        if prefixed.all(|code| code == first) {
+            return Ok(first);
+        }
+    }
+    let nearest = keyed()
+        .map(|(k, label, _)| (edit_distance(&key, &k), label))
+        .min_by_key(|(d, _)| *d);
+    match nearest {
+        Some((d, label)) if d <= (key.chars().count() / 2).max(1) => {
+            Err(QuantityArgError::UnitSuggestion {
+                unit: input.trim().to_string(),
+                suggestion: label.to_string(),
+            })
+        }
+        _ => Err(QuantityArgError::UnknownUnit(input.trim().to_string())),
+    }
+}
+
+/// 단위 비교용 키: 소문자로 바꾸고 표기 차이만 나는 기호를 지운다.
+fn unit_key(unit: &str) -> String {
+    unit.chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '_' | '(' | ')' | '°' | '·' | '-'))
+        .map(|c| match c {
+            '³' => '3',
+            '²' => '2',
+            'µ' | 'μ' => 'u',
+            _ => c,
+        })
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// 두 문자열의 레벤슈타인 편집 거리.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitute = prev[j] + usize::from(ca != *cb);
+            cur[j + 1] = substitute.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+fn normalize(unit: &str) -> String {
+    unit.trim().to_lowercase().replace(' ', "")
+}
@@ -0,0 +1,201 @@
+//! 계산 결과를 구조화한 문서 형식.
+//! CLI `--format json|yaml`은 이 구조체를 그대로 직렬화하므로 필드 이름이 곧 외부 도구용 스키마다.
+//! 값은 단위를 함께 적은 숫자로만 담고, 번역 라벨과 표시 자릿수는 텍스트 출력에만 쓴다.
+//! 필드를 바꾸거나 지우면 `REPORT_SCHEMA_VERSION`을 올린다 (추가만 할 때는 유지).
+
+use serde::{Deserialize, Serialize};
+
+use crate::number_format::NumberFormat;
+use crate::show_work::{self, WorkStep};
+use crate::warning::Severity;
+
+/// 문서 스키마 버전.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+/// 한 번의 계산 결과 문서.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalcReport {
+    pub schema_version: u32,
+    /// 실행한 계산 (예: "pipe size")
+    pub command: String,
+    /// SI/공학 단위로 환산한 입력값
+    pub inputs: Vec<ReportValue>,
+    /// 결과에 쓰인 중간값 (물성, 환산 유량 등)
+    pub intermediates: Vec<ReportValue>,
+    pub results: Vec<ReportValue>,
+    pub warnings: Vec<ReportWarning>,
+    /// 항목별 표 (트랩 조사 목록 등). 없으면 직렬화하지 않는다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tables: Vec<ReportTable>,
+    /// 값을 대입한 계산 과정 (`--show-work`). 없으면 직렬화하지 않는다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub work: Vec<WorkStep>,
+}
+
+/// 이름/값/단위 한 항목. 무차원 값의 단위는 빈 문자열.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportValue {
+    /// 안정적인 snake_case 이름 (예: "inner_diameter")
+    pub name: String,
+    pub value: f64,
+    pub unit: String,
+    /// 텍스트 출력 라벨 (번역됨). 비어 있으면 `name`을 쓴다.
+    #[serde(skip)]
+    pub label: String,
+    /// 텍스트 출력 소수 자릿수
+    #[serde(skip)]
+    pub decimals: usize,
+}
+
+/// 계산 경고.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportWarning {
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// 열 이름/단위와 행으로 된 표.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportTable {
+    pub name: String,
+    pub columns: Vec<ReportColumn>,
+    /// 각 행은 `columns`와 같은 순서의 셀
+    pub rows: Vec<Vec<ReportCell>>,
+}
+
+/// 표 열 정의.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReportColumn {
+    pub name: String,
+    pub unit: String,
+}
+
+/// 표 셀: 숫자 또는 문자열.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ReportCell {
+    Number(f64),
+    Text(String),
+}
+
+impl ReportValue {
+    pub fn new(name: &str, value: f64, unit: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            value,
+            unit: unit.to_string(),
+            label: String::new(),
+            decimals: 3,
+        }
+    }
+
+    /// 텍스트 출력 라벨과 자릿수를 지정한다.
+    pub fn labeled(mut self, label: &str, decimals: usize) -> Self {
+        self.label = label.to_string();
+        self.decimals = decimals;
+        self
+    }
+}
+
+impl CalcReport {
+    pub fn new(command: &str) -> Self {
+        Self {
+            schema_version: REPORT_SCHEMA_VERSION,
+            command: command.to_string(),
+            inputs: Vec::new(),
+            intermediates: Vec::new(),
+            results: Vec::new(),
+            warnings: Vec::new(),
+            tables: Vec::new(),
+            work: Vec::new(),
+        }
+    }
+
+    pub fn input(mut self, value: ReportValue) -> Self {
+        self.inputs.push(value);
+        self
+    }
+
+    pub fn intermediate(mut self, value: ReportValue) -> Self {
+        self.intermediates.push(value);
+        self
+    }
+
+    pub fn result(mut self, value: ReportValue) -> Self {
+        self.results.push(value);
+        self
+    }
+
+    pub fn work(mut self, steps: Vec<WorkStep>) -> Self {
+        self.work.extend(steps);
+        self
+    }
+
+    pub fn warning(mut self, severity: Severity, message: impl Into<String>) -> Self {
+        self.warnings.push(ReportWarning {
+            severity,
+            message: message.into(),
+        });
+        self
+    }
+
+    /// 사람이 읽는 텍스트. 결과와 중간값을 한 줄씩, 경고는 심각도 기호를 붙여 쓴다.
+    /// 입력값은 명령줄에 이미 있으므로 생략한다. 계산 과정이 있으면 표 다음에 붙인다.
+    pub fn to_text(&self, nf: &NumberFormat) -> String {
+        let mut lines: Vec<String> = self
+            .intermediates
+            .iter()
+            .chain(&self.results)
+            .map(|v| value_line(v, nf))
+            .collect();
+        for table in &self.tables {
+            lines.push(
+                table
+                    .columns
+                    .iter()
+                    .map(|c| match c.unit.as_str() {
+                        "" => c.name.clone(),
+                        unit => format!("{} [{unit}]", c.name),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" | "),
+            );
+            for row in &table.rows {
+                lines.push(
+                    row.iter()
+                        .map(|cell| match cell {
+                            ReportCell::Number(n) => nf.format(*n, 2),
+                            ReportCell::Text(s) => s.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" | "),
+                );
+            }
+        }
+        if !self.work.is_empty() {
+            lines.push(show_work::to_text(&self.work, nf));
+        }
+        for w in &self.warnings {
+            lines.push(format!("{} {}", w.severity.marker(), w.message));
+        }
+        lines.join("\n")
+    }
+}
+
+fn value_line(v: &ReportValue, nf: &NumberFormat) -> String {
+    let label = if v.label.is_empty() {
+        v.name.as_str()
+    } else {
+        v.label.as_str()
+    };
+    let value = if v.value != 0.0 && v.value.abs() >= 1.0e6 {
+        format!("{:.3e}", v.value)
+    } else {
+        nf.format(v.value, v.decimals)
+    };
+    if v.unit.is_empty() {
+        format!("{label} {value}")
+    } else {
+        format!("{label} {value} {}", v.unit)
+    }
+}
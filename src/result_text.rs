@@ -0,0 +1,93 @@
+//! 계산 결과 문자열 가공 (클립보드 복사, 내보내기).
+//! 결과는 `이름=값 단위, 이름≈값 단위 (이름=값 ...)` 형태의 사람이 읽는 문장이라,
+//! 표 계산 프로그램에 붙여 넣을 수 있도록 항목 단위로 나눈다.
+
+use crate::warning::Severity;
+
+/// 결과 문자열을 `항목\t값\t단위` 줄로 바꾼다 (엑셀 붙여넣기용 TSV).
+///
+/// - 항목은 `, ` / `; ` / ` | ` / ` (` 로 나눈다. 소수점 쉼표(`0,50`)와 `bar(a)` 같은 단위는 나누지 않는다.
+/// - 이름과 값은 `=` 또는 `≈`, 없으면 `:`로 구분한다.
+/// - 값이 숫자가 아니거나 구분자가 없는 조각, 경고 줄은 첫 열에만 넣는다.
+pub fn result_to_tsv(text: &str) -> String {
+    let mut rows: Vec<String> = Vec::new();
+    for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+        if Severity::from_line(line).is_some() {
+            rows.push(clean_cell(line));
+            continue;
+        }
+        for segment in split_items(line) {
+            rows.push(item_row(&segment));
+        }
+    }
+    rows.join("\n")
+}
+
+fn split_items(line: &str) -> Vec<String> {
+    let mut normalized = line.to_string();
+    for sep in [" (", ", ", "; ", " | "] {
+        normalized = normalized.replace(sep, "\u{1f}");
+    }
+    normalized
+        .split('\u{1f}')
+        .map(|s| strip_unmatched_paren(s.trim()))
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 앞 조각에서 열린 괄호의 닫는 `)`를 떼어 낸다. `bar(a)`처럼 짝이 맞는 괄호는 둔다.
+fn strip_unmatched_paren(s: &str) -> String {
+    let mut out = s.to_string();
+    while out.ends_with(')') && out.matches(')').count() > out.matches('(').count() {
+        out.pop();
+        out = out.trim_end().to_string();
+    }
+    out
+}
+
+fn item_row(segment: &str) -> String {
+    let split = segment
+        .find(['=', '≈'])
+        .or_else(|| segment.find(':'))
+        .map(|i| (&segment[..i], &segment[i..]));
+    let Some((name, rest)) = split else {
+        return clean_cell(segment);
+    };
+    let rest = rest
+        .trim_start_matches(['=', '≈', ':'])
+        .trim_start_matches('~')
+        .trim();
+    let mut tokens = rest.split_whitespace().peekable();
+    let mut value = String::new();
+    while let Some(tok) = tokens.next_if(|t| is_numeric(t)) {
+        if !value.is_empty() {
+            value.push(' ');
+        }
+        value.push_str(tok);
+    }
+    if value.is_empty() {
+        return format!("{}\t{}", clean_cell(name.trim()), clean_cell(rest));
+    }
+    let unit = tokens.collect::<Vec<_>>().join(" ");
+    format!(
+        "{}\t{}\t{}",
+        clean_cell(name.trim()),
+        value,
+        clean_cell(&unit)
+    )
+}
+
+/// 숫자(부호, 소수점/천 단위 구분자, 지수 표기 포함)로만 된 토큰인지.
+fn is_numeric(token: &str) -> bool {
+    let body = token.trim_start_matches(['+', '-']);
+    body.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_digit() || c == '.')
+        && body
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | ',' | '\'' | 'e' | 'E' | '+' | '-'))
+}
+
+fn clean_cell(s: &str) -> String {
+    s.replace('\t', " ")
+}
@@ -0,0 +1,309 @@
+//! 결과의 계산 과정 ("계산 과정 보기").
+//! 공식, 사용자가 넣은 값을 대입한 식, 중간값(Re, f, LMTD 등)을 한 줄씩 만든다.
+//! GUI 결과 아래의 펼침 목록과 CLI `--show-work` 출력이 같은 줄을 쓰므로 검토 기록이 어디서나 같다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::cooling::condenser::{CondenserInput, CondenserResult};
+use crate::flow_coefficient::KV_PER_CV_US;
+use crate::number_format::NumberFormat;
+use crate::steam::steam_piping::{PressureLossInput, PressureLossResult};
+
+const G: f64 = 9.80665;
+/// 밸브 Kv 식의 기준 밀도 [kg/m³]
+const RHO_REF: f64 = 1000.0;
+/// 냉각수 비열 [kJ/kg·K] (`condenser`와 같은 값)
+const CP_WATER: f64 = 4.186;
+
+/// 계산 과정 한 단계: `기호 = 공식 = 대입식 = 값 단위`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkStep {
+    /// 안정적인 snake_case 이름 (예: "reynolds")
+    pub name: String,
+    /// 기호로 쓴 공식 (예: "Re = ρ·v·D/μ")
+    pub formula: String,
+    /// 값을 대입한 식 (표시 형식이 적용된 숫자)
+    pub substituted: String,
+    pub value: f64,
+    /// 무차원 값은 빈 문자열
+    pub unit: String,
+}
+
+impl WorkStep {
+    fn new(name: &str, formula: &str, substituted: String, value: f64, unit: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            formula: formula.to_string(),
+            substituted,
+            value,
+            unit: unit.to_string(),
+        }
+    }
+
+    /// `Re = ρ·v·D/μ = 5.150 × 32.80 × 0.1023 / 1.200e-5 = 1.433e6` 형태의 한 줄.
+    pub fn line(&self, nf: &NumberFormat) -> String {
+        let value = num(nf, self.value);
+        if self.unit.is_empty() {
+            format!("{} = {} = {value}", self.formula, self.substituted)
+        } else {
+            format!(
+                "{} = {} = {value} {}",
+                self.formula, self.substituted, self.unit
+            )
+        }
+    }
+}
+
+/// 여러 단계를 줄바꿈으로 이은 텍스트.
+pub fn to_text(steps: &[WorkStep], nf: &NumberFormat) -> String {
+    steps
+        .iter()
+        .map(|s| s.line(nf))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 대입식에 쓰는 숫자: 유효 숫자 4자리, 아주 크거나 작으면 지수 표기.
+fn num(nf: &NumberFormat, v: f64) -> String {
+    let abs = v.abs();
+    if abs == 0.0 || !v.is_finite() {
+        return nf.format(v, 0);
+    }
+    if abs < nf.scientific_below || abs >= nf.scientific_above || abs >= 1.0e6 {
+        return format_sci(nf, v);
+    }
+    let magnitude = abs.log10().floor() as i32;
+    nf.format(v, (3 - magnitude).clamp(0, 8) as usize)
+}
+
+fn format_sci(nf: &NumberFormat, v: f64) -> String {
+    format!("{v:.3e}").replace('.', &nf.decimal_separator.to_string())
+}
+
+/// Darcy-Weisbach 압력 손실의 계산 과정 (면적 → 유속 → Re → f → 등가 길이 → ΔP).
+/// `input.fittings_k_sum`은 피팅 목록의 K까지 더한 값을 넘긴다. 점도는 IF97로 바뀌었을 수 있으므로
+/// 결과의 Re에서 되짚어 적는다.
+pub fn pressure_loss_work(
+    input: &PressureLossInput,
+    result: &PressureLossResult,
+    nf: &NumberFormat,
+) -> Vec<WorkStep> {
+    let d = input.diameter_m;
+    let rho = result.density_kg_per_m3;
+    let v = result.velocity_m_per_s;
+    let re = result.reynolds_number;
+    let f = result.friction_factor;
+    let area = std::f64::consts::PI * d * d / 4.0;
+    let mdot = input.mass_flow_kg_per_h / 3600.0;
+    let mu = if re > 0.0 { rho * v * d / re } else { 0.0 };
+    let n = |x: f64| num(nf, x);
+
+    let mut steps = vec![
+        WorkStep::new(
+            "area",
+            "A = π·D²/4",
+            format!("π × {}² / 4", n(d)),
+            area,
+            "m²",
+        ),
+        WorkStep::new(
+            "velocity",
+            "v = ṁ/(ρ·A)",
+            format!("{} / ({} × {})", n(mdot), n(rho), n(area)),
+            v,
+            "m/s",
+        ),
+        WorkStep::new(
+            "reynolds",
+            "Re = ρ·v·D/μ",
+            format!("{} × {} × {} / {}", n(rho), n(v), n(d), n(mu)),
+            re,
+            "",
+        ),
+    ];
+    steps.push(if re < 2300.0 {
+        WorkStep::new(
+            "friction_factor",
+            "f = 64/Re",
+            format!("64 / {}", n(re)),
+            f,
+            "",
+        )
+    } else {
+        WorkStep::new(
+            "friction_factor",
+            "f = [−1.8·log₁₀((ε/D/3.7)^1.11 + 6.9/Re)]⁻²",
+            format!(
+                "[−1.8 × log₁₀(({} / {} / 3.7)^1.11 + 6.9 / {})]⁻²",
+                n(input.roughness_m),
+                n(d),
+                n(re)
+            ),
+            f,
+            "",
+        )
+    });
+    let eq_len_from_k = if f > 0.0 {
+        input.fittings_k_sum * d / f
+    } else {
+        0.0
+    };
+    if input.fittings_k_sum > 0.0 {
+        steps.push(WorkStep::new(
+            "fittings_equivalent_length",
+            "Leq,K = ΣK·D/f",
+            format!("{} × {} / {}", n(input.fittings_k_sum), n(d), n(f)),
+            eq_len_from_k,
+            "m",
+        ));
+    }
+    let total_length = input.length_m + input.equivalent_length_m + eq_len_from_k;
+    if total_length != input.length_m {
+        steps.push(WorkStep::new(
+            "total_length",
+            "Ltot = L + Leq + Leq,K",
+            format!(
+                "{} + {} + {}",
+                n(input.length_m),
+                n(input.equivalent_length_m),
+                n(eq_len_from_k)
+            ),
+            total_length,
+            "m",
+        ));
+    }
+    steps.push(WorkStep::new(
+        "pressure_drop",
+        "ΔP = f·(L/D)·ρ·v²/2",
+        format!(
+            "{} × ({} / {}) × {} × {}² / 2 / 10⁵",
+            n(f),
+            n(total_length),
+            n(d),
+            n(rho),
+            n(v)
+        ),
+        result.pressure_drop_bar,
+        "bar",
+    ));
+    let dz = input.outlet_elevation_m - input.inlet_elevation_m;
+    if dz != 0.0 {
+        steps.push(WorkStep::new(
+            "elevation_drop",
+            "ΔPz = ρ·g·Δz",
+            format!("{} × {} × {} / 10⁵", n(rho), n(G), n(dz)),
+            result.elevation_drop_bar,
+            "bar",
+        ));
+    }
+    if input.sound_speed_m_per_s > 0.0 {
+        steps.push(WorkStep::new(
+            "mach",
+            "Ma = v/c",
+            format!("{} / {}", n(v), n(input.sound_speed_m_per_s)),
+            result.mach,
+            "",
+        ));
+    }
+    steps
+}
+
+/// 비압축성 밸브 Kv/Cv의 계산 과정. 단위는 `steam_valves::required_kv`와 같다 (m³/h, bar, kg/m³).
+pub fn valve_kv_work(
+    volumetric_flow_m3_per_h: f64,
+    delta_p_bar: f64,
+    fluid_density_kg_m3: f64,
+    kv: f64,
+    nf: &NumberFormat,
+) -> Vec<WorkStep> {
+    let n = |x: f64| num(nf, x);
+    vec![
+        WorkStep::new(
+            "kv",
+            "Kv = Q·√(ρref/(ρ·ΔP))",
+            format!(
+                "{} × √({} / ({} × {}))",
+                n(volumetric_flow_m3_per_h),
+                n(RHO_REF),
+                n(fluid_density_kg_m3),
+                n(delta_p_bar)
+            ),
+            kv,
+            "",
+        ),
+        WorkStep::new(
+            "cv",
+            "Cv = Kv/0.865",
+            format!("{} / {}", n(kv), n(KV_PER_CV_US)),
+            kv / KV_PER_CV_US,
+            "",
+        ),
+    ]
+}
+
+/// 복수기 LMTD와 열량의 계산 과정. UA(또는 U·A)가 있으면 Q = UA·LMTD, 없으면 냉각수 열수지다.
+pub fn condenser_work(
+    input: &CondenserInput,
+    result: &CondenserResult,
+    nf: &NumberFormat,
+) -> Vec<WorkStep> {
+    let n = |x: f64| num(nf, x);
+    let tsat = result.condensing_temp_c;
+    let d1 = tsat - input.cw_outlet_temp_c;
+    let d2 = tsat - input.cw_inlet_temp_c;
+    let mut steps = vec![
+        WorkStep::new(
+            "delta_t_1",
+            "ΔT₁ = Tsat − Tcw,out",
+            format!("{} − {}", n(tsat), n(input.cw_outlet_temp_c)),
+            d1,
+            "K",
+        ),
+        WorkStep::new(
+            "delta_t_2",
+            "ΔT₂ = Tsat − Tcw,in",
+            format!("{} − {}", n(tsat), n(input.cw_inlet_temp_c)),
+            d2,
+            "K",
+        ),
+        WorkStep::new(
+            "lmtd",
+            "LMTD = (ΔT₁ − ΔT₂)/ln(ΔT₁/ΔT₂)",
+            format!("({} − {}) / ln({} / {})", n(d1), n(d2), n(d1), n(d2)),
+            result.lmtd_k,
+            "K",
+        ),
+    ];
+    let ua = input.ua_kw_per_k.or_else(|| {
+        input
+            .area_m2
+            .zip(input.overall_u_w_m2k)
+            .map(|(a, u)| a * u / 1000.0)
+    });
+    steps.push(match ua {
+        Some(ua) => WorkStep::new(
+            "heat_duty",
+            "Q = UA·LMTD",
+            format!("{} × {}", n(ua), n(result.lmtd_k)),
+            result.heat_duty_kw,
+            "kW",
+        ),
+        None => {
+            let m_cw = input.cw_flow_m3_per_h * (1000.0 / 3600.0);
+            WorkStep::new(
+                "heat_duty",
+                "Q = ṁcw·cp·(Tcw,out − Tcw,in)",
+                format!(
+                    "{} × {} × ({} − {})",
+                    n(m_cw),
+                    n(CP_WATER),
+                    n(input.cw_outlet_temp_c),
+                    n(input.cw_inlet_temp_c)
+                ),
+                result.heat_duty_kw,
+                "kW",
+            )
+        }
+    });
+    steps
+}
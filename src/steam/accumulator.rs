@@ -0,0 +1,351 @@
+//! 변압식(Ruths) 스팀 어큐뮬레이터 충전/방출 계산.
+//! 용기 안의 포화수·포화증기가 항상 평형이라고 보고 질량/에너지 수지를 시간 적분한다.
+//! 각 시점의 압력은 (전체 질량, 내부에너지, 용기 체적)을 만족하는 포화 압력을 찾아 구한다.
+
+use crate::goal_seek::{self, GoalSeekError, GoalSeekOptions};
+use crate::steam::if97;
+
+/// 압력 탐색 구간 (bar abs)
+const P_MIN_BAR: f64 = 0.05;
+const P_MAX_BAR: f64 = 200.0;
+
+/// 어큐뮬레이터 계산 오류.
+#[derive(Debug, Clone)]
+pub enum AccumulatorError {
+    InvalidInput(&'static str),
+    If97(String),
+    GoalSeek(GoalSeekError),
+    /// 증기를 다 빼내거나 물이 가득 차 포화 2상 상태를 벗어남
+    OutOfTwoPhase {
+        time_s: f64,
+    },
+    /// 목표 압력까지 방출하기 전에 용기 안 물이 모두 증발함
+    WaterExhausted {
+        pressure_bar_abs: f64,
+    },
+}
+
+impl std::fmt::Display for AccumulatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AccumulatorError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            AccumulatorError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            AccumulatorError::GoalSeek(e) => write!(f, "압력 계산 실패: {e}"),
+            AccumulatorError::OutOfTwoPhase { time_s } => write!(
+                f,
+                "{time_s:.0} s에서 용기가 포화 2상 범위를 벗어났습니다 (만수 또는 무수)."
+            ),
+            AccumulatorError::WaterExhausted { pressure_bar_abs } => write!(
+                f,
+                "방출 하한 압력에 닿기 전 {pressure_bar_abs:.2} bar(a)에서 용기 물이 모두 증발했습니다. 초기 수위를 높이세요."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for AccumulatorError {}
+
+/// 용기 상태.
+#[derive(Debug, Clone, Copy)]
+pub struct AccumulatorState {
+    pub pressure_bar_abs: f64,
+    pub water_mass_kg: f64,
+    pub steam_mass_kg: f64,
+    /// 물 체적 / 용기 체적
+    pub level_fraction: f64,
+}
+
+/// 충전/방출 구간. 구간 동안 유량은 일정하다.
+#[derive(Debug, Clone, Copy)]
+pub struct FlowStep {
+    pub duration_s: f64,
+    /// 충전 증기 유량 (kg/h)
+    pub charge_kg_per_h: f64,
+    /// 방출 증기 유량 (kg/h)
+    pub discharge_kg_per_h: f64,
+}
+
+/// 시뮬레이션 입력.
+#[derive(Debug, Clone)]
+pub struct AccumulatorSimInput {
+    pub volume_m3: f64,
+    pub initial_pressure_bar_abs: f64,
+    /// 초기 수위(물 체적 비율, 0~1)
+    pub initial_level_fraction: f64,
+    /// 충전 증기 엔탈피(kJ/kg). `None`이면 그 시점 압력의 건포화 증기로 본다.
+    pub charge_enthalpy_kj_per_kg: Option<f64>,
+    pub steps: Vec<FlowStep>,
+    /// 적분 시간 간격 (s)
+    pub time_step_s: f64,
+    /// 최저 사용 압력 (bar abs). 이하로 내려가면 경고.
+    pub min_pressure_bar_abs: Option<f64>,
+    /// 최고 사용 압력 (bar abs). 이상으로 올라가면 경고.
+    pub max_pressure_bar_abs: Option<f64>,
+}
+
+/// 시뮬레이션 결과 한 점.
+#[derive(Debug, Clone, Copy)]
+pub struct AccumulatorPoint {
+    pub time_s: f64,
+    pub state: AccumulatorState,
+}
+
+/// 시뮬레이션 결과.
+#[derive(Debug, Clone)]
+pub struct AccumulatorSimResult {
+    pub points: Vec<AccumulatorPoint>,
+    pub min_pressure_bar_abs: f64,
+    pub max_pressure_bar_abs: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 압력 p에서의 포화 물성 (vf, vg, uf, ug) - 비체적 m³/kg, 내부에너지 J/kg
+fn saturation_vu(p_bar_abs: f64) -> Result<(f64, f64, f64, f64), AccumulatorError> {
+    let (hf, vf, _) =
+        if97::props_from_px(p_bar_abs, 0.0).map_err(|e| AccumulatorError::If97(e.into()))?;
+    let (hg, vg, _) =
+        if97::props_from_px(p_bar_abs, 1.0).map_err(|e| AccumulatorError::If97(e.into()))?;
+    let p_pa = p_bar_abs * 1e5;
+    Ok((vf, vg, hf - p_pa * vf, hg - p_pa * vg))
+}
+
+/// 압력, 체적, 수위로 용기 상태와 내부에너지(J)를 만든다.
+fn state_from_level(
+    volume_m3: f64,
+    p_bar_abs: f64,
+    level_fraction: f64,
+) -> Result<(AccumulatorState, f64), AccumulatorError> {
+    let (vf, vg, uf, ug) = saturation_vu(p_bar_abs)?;
+    let water = volume_m3 * level_fraction / vf;
+    let steam = volume_m3 * (1.0 - level_fraction) / vg;
+    Ok((
+        AccumulatorState {
+            pressure_bar_abs: p_bar_abs,
+            water_mass_kg: water,
+            steam_mass_kg: steam,
+            level_fraction,
+        },
+        water * uf + steam * ug,
+    ))
+}
+
+/// 전체 질량(kg)·내부에너지(J)·체적(m³)을 만족하는 포화 상태를 찾는다.
+/// 체적에서 구한 건도와 에너지에서 구한 건도가 같아지는 압력을 Brent 탐색으로 구한다.
+fn state_from_mass_energy(
+    volume_m3: f64,
+    mass_kg: f64,
+    energy_j: f64,
+    hint_bar_abs: f64,
+) -> Result<AccumulatorState, AccumulatorError> {
+    let v = volume_m3 / mass_kg;
+    let u = energy_j / mass_kg;
+    let mismatch = |p: f64| match saturation_vu(p) {
+        Ok((vf, vg, uf, ug)) => (v - vf) / (vg - vf) - (u - uf) / (ug - uf),
+        Err(_) => f64::NAN,
+    };
+    let opts = GoalSeekOptions {
+        x_tol: 1e-9,
+        f_tol: 1e-12,
+        max_iter: 200,
+    };
+    // 직전 압력 근처에서 먼저 찾고, 구간이 맞지 않으면 전체 범위로 넓힌다.
+    let lo = (hint_bar_abs * 0.8).max(P_MIN_BAR);
+    let hi = (hint_bar_abs * 1.25).min(P_MAX_BAR);
+    let res = match goal_seek::brent(mismatch, 0.0, lo, hi, opts) {
+        Ok(r) => r,
+        Err(GoalSeekError::NotBracketed { .. }) => {
+            goal_seek::brent(mismatch, 0.0, P_MIN_BAR, P_MAX_BAR, opts)
+                .map_err(AccumulatorError::GoalSeek)?
+        }
+        Err(e) => return Err(AccumulatorError::GoalSeek(e)),
+    };
+    let p = res.x;
+    let (vf, vg, _, _) = saturation_vu(p)?;
+    let x = (v - vf) / (vg - vf);
+    let steam = mass_kg * x;
+    let water = mass_kg - steam;
+    Ok(AccumulatorState {
+        pressure_bar_abs: p,
+        water_mass_kg: water,
+        steam_mass_kg: steam,
+        level_fraction: water * vf / volume_m3,
+    })
+}
+
+fn validate_vessel(
+    volume_m3: f64,
+    p_bar_abs: f64,
+    level_fraction: f64,
+) -> Result<(), AccumulatorError> {
+    if volume_m3 <= 0.0 {
+        return Err(AccumulatorError::InvalidInput(
+            "용기 체적은 0보다 커야 합니다.",
+        ));
+    }
+    if !(P_MIN_BAR..P_MAX_BAR).contains(&p_bar_abs) {
+        return Err(AccumulatorError::InvalidInput(
+            "압력은 0.05~200 bar(abs) 범위여야 합니다.",
+        ));
+    }
+    if !(level_fraction > 0.0 && level_fraction < 1.0) {
+        return Err(AccumulatorError::InvalidInput(
+            "수위 비율은 0과 1 사이여야 합니다.",
+        ));
+    }
+    Ok(())
+}
+
+/// 충전/방출 유량 이력에 따른 압력·수위 변화를 계산한다 (명시적 오일러 적분).
+pub fn simulate(input: AccumulatorSimInput) -> Result<AccumulatorSimResult, AccumulatorError> {
+    validate_vessel(
+        input.volume_m3,
+        input.initial_pressure_bar_abs,
+        input.initial_level_fraction,
+    )?;
+    if input.time_step_s <= 0.0 {
+        return Err(AccumulatorError::InvalidInput(
+            "시간 간격은 0보다 커야 합니다.",
+        ));
+    }
+    if input
+        .steps
+        .iter()
+        .any(|s| s.duration_s < 0.0 || s.charge_kg_per_h < 0.0 || s.discharge_kg_per_h < 0.0)
+    {
+        return Err(AccumulatorError::InvalidInput(
+            "구간 시간과 유량은 0 이상이어야 합니다.",
+        ));
+    }
+
+    let (mut state, mut energy) = state_from_level(
+        input.volume_m3,
+        input.initial_pressure_bar_abs,
+        input.initial_level_fraction,
+    )?;
+    let mut mass = state.water_mass_kg + state.steam_mass_kg;
+    let mut time = 0.0;
+    let mut points = vec![AccumulatorPoint { time_s: 0.0, state }];
+
+    for step in &input.steps {
+        let mut remaining = step.duration_s;
+        while remaining > 1e-9 {
+            let dt = remaining.min(input.time_step_s);
+            let (_, hg) = if97::saturation_enthalpies(state.pressure_bar_abs)
+                .map_err(|e| AccumulatorError::If97(e.into()))?;
+            let h_in = input
+                .charge_enthalpy_kj_per_kg
+                .map(|h| h * 1000.0)
+                .unwrap_or(hg);
+            let m_in = step.charge_kg_per_h / 3600.0 * dt;
+            let m_out = step.discharge_kg_per_h / 3600.0 * dt;
+            mass += m_in - m_out;
+            energy += m_in * h_in - m_out * hg;
+            time += dt;
+            remaining -= dt;
+            if mass <= 0.0 {
+                return Err(AccumulatorError::OutOfTwoPhase { time_s: time });
+            }
+            state = state_from_mass_energy(input.volume_m3, mass, energy, state.pressure_bar_abs)?;
+            if !(state.level_fraction > 0.0 && state.level_fraction < 1.0) {
+                return Err(AccumulatorError::OutOfTwoPhase { time_s: time });
+            }
+            points.push(AccumulatorPoint {
+                time_s: time,
+                state,
+            });
+        }
+    }
+
+    let min_p = points
+        .iter()
+        .map(|p| p.state.pressure_bar_abs)
+        .fold(f64::INFINITY, f64::min);
+    let max_p = points
+        .iter()
+        .map(|p| p.state.pressure_bar_abs)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let mut warnings = Vec::new();
+    if let Some(limit) = input.min_pressure_bar_abs {
+        if min_p < limit {
+            warnings.push(format!(
+                "압력이 최저 사용 압력 {limit:.2} bar(abs) 아래로 내려갑니다 (최저 {min_p:.2})."
+            ));
+        }
+    }
+    if let Some(limit) = input.max_pressure_bar_abs {
+        if max_p > limit {
+            warnings.push(format!(
+                "압력이 최고 사용 압력 {limit:.2} bar(abs)를 넘습니다 (최고 {max_p:.2})."
+            ));
+        }
+    }
+    if let Some(last) = points.last() {
+        if last.state.level_fraction > 0.95 {
+            warnings.push("수위가 용기 체적의 95%를 넘습니다.".into());
+        }
+    }
+
+    Ok(AccumulatorSimResult {
+        points,
+        min_pressure_bar_abs: min_p,
+        max_pressure_bar_abs: max_p,
+        warnings,
+    })
+}
+
+/// 충전 없이 증기만 빼낼 때 압력이 p_high에서 p_low까지 떨어지는 동안 방출 가능한 증기량(kg).
+/// 용기 안의 상태는 체적에 비례하므로 결과는 체적에 선형이다.
+pub fn release_capacity_kg(
+    volume_m3: f64,
+    level_fraction_at_high: f64,
+    p_high_bar_abs: f64,
+    p_low_bar_abs: f64,
+) -> Result<f64, AccumulatorError> {
+    validate_vessel(volume_m3, p_high_bar_abs, level_fraction_at_high)?;
+    if !(p_low_bar_abs >= P_MIN_BAR && p_low_bar_abs < p_high_bar_abs) {
+        return Err(AccumulatorError::InvalidInput(
+            "방출 종료 압력은 시작 압력보다 낮아야 합니다.",
+        ));
+    }
+    let (mut state, mut energy) =
+        state_from_level(volume_m3, p_high_bar_abs, level_fraction_at_high)?;
+    let mut mass = state.water_mass_kg + state.steam_mass_kg;
+    // 압력 구간을 잘게 나누어 dU = h_g·dm 를 적분한다. 방출량 단위는 초기 물량 기준으로 잡는다.
+    let dm = state.water_mass_kg / 5000.0;
+    let mut released = 0.0;
+    loop {
+        let (_, hg) = if97::saturation_enthalpies(state.pressure_bar_abs)
+            .map_err(|e| AccumulatorError::If97(e.into()))?;
+        let prev_p = state.pressure_bar_abs;
+        mass -= dm;
+        energy -= dm * hg;
+        state = state_from_mass_energy(volume_m3, mass, energy, prev_p)?;
+        if state.pressure_bar_abs <= p_low_bar_abs {
+            // 마지막 구간은 압력에 대해 선형 보간
+            let frac = (prev_p - p_low_bar_abs) / (prev_p - state.pressure_bar_abs);
+            return Ok(released + dm * frac);
+        }
+        released += dm;
+        if state.level_fraction <= 0.0 {
+            return Err(AccumulatorError::WaterExhausted {
+                pressure_bar_abs: state.pressure_bar_abs,
+            });
+        }
+    }
+}
+
+/// 부하 변동(kg 증기)을 p_high → p_low 압력 강하로 감당하는 데 필요한 용기 체적(m³).
+pub fn required_volume_m3(
+    steam_swing_kg: f64,
+    level_fraction_at_high: f64,
+    p_high_bar_abs: f64,
+    p_low_bar_abs: f64,
+) -> Result<f64, AccumulatorError> {
+    if steam_swing_kg <= 0.0 {
+        return Err(AccumulatorError::InvalidInput(
+            "부하 변동량은 0보다 커야 합니다.",
+        ));
+    }
+    let per_m3 = release_capacity_kg(1.0, level_fraction_at_high, p_high_bar_abs, p_low_bar_abs)?;
+    Ok(steam_swing_kg / per_m3)
+}
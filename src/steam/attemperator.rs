@@ -0,0 +1,323 @@
+//! 과열기 감온기(attemperator) 스프레이 유량과 보일러 출력 보정.
+//! 1차 과열기 출구 증기에 절탄기 출구 급수를 분사해 설정 온도로 낮춘다고 보고,
+//! 혼합 열수지 ṁs·(h_in − h_out) = ṁw·(h_out − h_w)로 스프레이 유량을,
+//! 스프레이 밸브 최대 유량과 최소 과열도로 온도 제어 범위를 구한다.
+//! 스프레이는 급수 경계(절탄기 입구)를 지나 들어온 물이므로 보일러 출력은 주증기 전량 기준
+//! ṁms·(h_ms − h_fw)이고, 스프레이 분기 뒤 드럼 급수 유량으로 증기량을 잡으면 스프레이만큼 과소 보고된다
+//! (ASME PTC 4의 출력 정의).
+
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// 감온기 출구 최소 과열도 [K] (20 °F, 물방울 이월 방지)
+pub const MIN_OUTLET_SUPERHEAT_K: f64 = 11.0;
+/// 이보다 큰 스프레이 비율은 과열기 전열면 과잉/오염을 의심한다
+const HIGH_SPRAY_FRACTION: f64 = 0.08;
+
+/// 감온기 계산 입력.
+#[derive(Debug, Clone)]
+pub struct AttemperatorInput {
+    /// 주증기(과열기 최종 출구) 유량 [kg/h] (스프레이 포함)
+    pub main_steam_flow_kg_per_h: f64,
+    /// 감온기 위치 증기 압력 [bar abs]
+    pub attemperator_pressure_bar_abs: f64,
+    /// 감온기 입구(1차 과열기 출구) 증기 온도 [°C]
+    pub inlet_steam_temp_c: f64,
+    /// 감온기 출구 설정 온도 [°C]
+    pub outlet_setpoint_c: f64,
+    /// 스프레이수(절탄기 출구) 온도 [°C]
+    pub spray_water_temp_c: f64,
+    /// 스프레이 밸브 최대 유량 [kg/h]. 없으면 제어 하한을 최소 과열도로만 본다.
+    pub max_spray_flow_kg_per_h: Option<f64>,
+    /// 주증기 최종 압력 [bar abs] / 온도 [°C]
+    pub main_steam_pressure_bar_abs: f64,
+    pub main_steam_temp_c: f64,
+    /// 급수(절탄기 입구) 압력 [bar abs] / 온도 [°C]. 스프레이수 엔탈피도 이 압력으로 구한다.
+    pub feedwater_pressure_bar_abs: f64,
+    pub feedwater_temp_c: f64,
+    /// 연료 투입 열량 [kW]. 있으면 효율 영향을 계산한다.
+    pub fuel_heat_input_kw: Option<f64>,
+}
+
+/// 감온기 계산 결과.
+#[derive(Debug, Clone)]
+pub struct AttemperatorResult {
+    /// 설정 온도에 필요한 스프레이 유량 [kg/h]
+    pub spray_flow_kg_per_h: f64,
+    /// 스프레이 / 주증기 유량
+    pub spray_fraction: f64,
+    /// 1차 과열기 통과 증기 유량 [kg/h]
+    pub upstream_steam_flow_kg_per_h: f64,
+    /// 감온기 위치 포화 온도 [°C]
+    pub saturation_temp_c: f64,
+    /// 출구 설정 온도의 과열도 [K]
+    pub outlet_superheat_k: f64,
+    /// 제어 범위 하한 [°C]: 최대 스프레이와 최소 과열도 중 높은 쪽으로 낼 수 있는 최저 출구 온도
+    pub control_min_outlet_temp_c: f64,
+    /// 제어 범위 상한 [°C] (스프레이 0 = 입구 온도)
+    pub control_max_outlet_temp_c: f64,
+    /// 최소 과열도를 지키는 최대 스프레이 유량 [kg/h]
+    pub max_safe_spray_flow_kg_per_h: f64,
+    /// 주증기 전량 기준 보일러 출력 [kW]
+    pub boiler_output_kw: f64,
+    /// 스프레이를 뺀 유량으로 잡았을 때의 출력 [kW]
+    pub output_excluding_spray_kw: f64,
+    /// 스프레이 누락 시 출력 과소 보고율 [%]
+    pub output_understatement_pct: f64,
+    /// 연료 투입이 있을 때: (주증기 전량 효율, 스프레이 누락 효율)
+    pub efficiency: Option<(f64, f64)>,
+    pub warnings: Vec<AttemperatorWarning>,
+}
+
+/// 감온기 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AttemperatorWarning {
+    /// 설정 온도가 입구 온도보다 높음
+    SetpointAboveInlet { inlet_temp_c: f64 },
+    /// 필요 스프레이가 밸브 최대 유량을 넘음
+    SprayExceedsValve {
+        spray_kg_per_h: f64,
+        max_kg_per_h: f64,
+        min_outlet_temp_c: f64,
+    },
+    /// 출구 과열도가 [`MIN_OUTLET_SUPERHEAT_K`] 미만
+    LowOutletSuperheat { superheat_k: f64 },
+    /// 스프레이 비율이 큼
+    HighSprayFraction { fraction: f64 },
+}
+
+impl CalcWarning for AttemperatorWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            AttemperatorWarning::SetpointAboveInlet { .. } => {
+                "warning.attemperator.setpoint_above_inlet"
+            }
+            AttemperatorWarning::SprayExceedsValve { .. } => {
+                "warning.attemperator.spray_exceeds_valve"
+            }
+            AttemperatorWarning::LowOutletSuperheat { .. } => "warning.attemperator.low_superheat",
+            AttemperatorWarning::HighSprayFraction { .. } => "warning.attemperator.high_spray",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            AttemperatorWarning::SetpointAboveInlet { inlet_temp_c } => {
+                vec![("inlet", format!("{inlet_temp_c:.1}"))]
+            }
+            AttemperatorWarning::SprayExceedsValve {
+                spray_kg_per_h,
+                max_kg_per_h,
+                min_outlet_temp_c,
+            } => vec![
+                ("spray", format!("{spray_kg_per_h:.0}")),
+                ("max", format!("{max_kg_per_h:.0}")),
+                ("min_outlet", format!("{min_outlet_temp_c:.1}")),
+            ],
+            AttemperatorWarning::LowOutletSuperheat { superheat_k } => vec![
+                ("superheat", format!("{superheat_k:.1}")),
+                ("min", format!("{MIN_OUTLET_SUPERHEAT_K}")),
+            ],
+            AttemperatorWarning::HighSprayFraction { fraction } => {
+                vec![("pct", format!("{:.1}", fraction * 100.0))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for AttemperatorWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            AttemperatorWarning::SetpointAboveInlet { inlet_temp_c } => write!(
+                f,
+                "설정 온도가 입구 온도 {inlet_temp_c:.1} °C보다 높아 스프레이가 필요 없습니다. 과열기 흡열이 부족합니다."
+            ),
+            AttemperatorWarning::SprayExceedsValve {
+                spray_kg_per_h,
+                max_kg_per_h,
+                min_outlet_temp_c,
+            } => write!(
+                f,
+                "필요 스프레이 {spray_kg_per_h:.0} kg/h가 밸브 최대 {max_kg_per_h:.0} kg/h를 넘어 설정 온도를 맞출 수 없습니다 (최저 {min_outlet_temp_c:.1} °C)."
+            ),
+            AttemperatorWarning::LowOutletSuperheat { superheat_k } => write!(
+                f,
+                "출구 과열도 {superheat_k:.1} K가 {MIN_OUTLET_SUPERHEAT_K} K 미만이라 분사수가 다 증발하지 못하고 이월될 수 있습니다."
+            ),
+            AttemperatorWarning::HighSprayFraction { fraction } => write!(
+                f,
+                "스프레이가 주증기의 {:.1}%입니다. 과열기 흡열 과다(전열면 과잉, 노내 연소 위치 변화)를 점검하세요.",
+                fraction * 100.0
+            ),
+        }
+    }
+}
+
+/// 감온기 계산 오류.
+#[derive(Debug, Clone)]
+pub enum AttemperatorError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for AttemperatorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AttemperatorError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            AttemperatorError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for AttemperatorError {}
+
+fn if97_err(e: &str) -> AttemperatorError {
+    AttemperatorError::If97(e.to_string())
+}
+
+/// (p, h)의 온도. 습증기면 포화 온도가 나온다.
+fn temp_from_ph(p_bar_abs: f64, h_j_per_kg: f64) -> Result<f64, AttemperatorError> {
+    if97::temperature_from_ph(p_bar_abs, h_j_per_kg).map_err(if97_err)
+}
+
+/// 스프레이 유량, 온도 제어 범위와 보일러 출력/효율 영향을 계산한다.
+pub fn compute_attemperator(
+    input: AttemperatorInput,
+) -> Result<AttemperatorResult, AttemperatorError> {
+    if input.main_steam_flow_kg_per_h <= 0.0 {
+        return Err(AttemperatorError::InvalidInput(
+            "주증기 유량은 0보다 커야 합니다.",
+        ));
+    }
+    if input.attemperator_pressure_bar_abs <= 0.0
+        || input.main_steam_pressure_bar_abs <= 0.0
+        || input.feedwater_pressure_bar_abs <= 0.0
+    {
+        return Err(AttemperatorError::InvalidInput("압력은 0보다 커야 합니다."));
+    }
+    if input.feedwater_pressure_bar_abs <= input.attemperator_pressure_bar_abs {
+        return Err(AttemperatorError::InvalidInput(
+            "급수(스프레이수) 압력이 감온기 증기 압력보다 높아야 분사할 수 있습니다.",
+        ));
+    }
+    if input.max_spray_flow_kg_per_h.is_some_and(|m| m < 0.0) {
+        return Err(AttemperatorError::InvalidInput(
+            "스프레이 최대 유량은 0 이상이어야 합니다.",
+        ));
+    }
+
+    let p = input.attemperator_pressure_bar_abs;
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p).map_err(if97_err)?;
+    if input.inlet_steam_temp_c <= t_sat {
+        return Err(AttemperatorError::InvalidInput(
+            "감온기 입구 증기는 과열 상태여야 합니다.",
+        ));
+    }
+    if input.outlet_setpoint_c <= t_sat {
+        return Err(AttemperatorError::InvalidInput(
+            "출구 설정 온도가 포화 온도 이하입니다.",
+        ));
+    }
+    let (h_in, _, _) = if97::region_props(p, input.inlet_steam_temp_c).map_err(if97_err)?;
+    let (h_w, _, _) =
+        if97::region_props(input.feedwater_pressure_bar_abs, input.spray_water_temp_c)
+            .map_err(if97_err)?;
+    let t_w_sat = if97::saturation_temp_c_from_pressure_bar_abs(input.feedwater_pressure_bar_abs)
+        .map_err(if97_err)?;
+    if input.spray_water_temp_c >= t_w_sat {
+        return Err(AttemperatorError::InvalidInput(
+            "스프레이수는 급수 압력에서 과냉 액체여야 합니다.",
+        ));
+    }
+
+    // 설정 온도가 입구보다 높으면 스프레이 없이 입구 온도가 그대로 나간다
+    let setpoint = input.outlet_setpoint_c.min(input.inlet_steam_temp_c);
+    let (h_out, _, _) = if97::region_props(p, setpoint).map_err(if97_err)?;
+    // 주증기 = 상류 증기 + 스프레이: ṁw = ṁms·(h_in − h_out)/(h_in − h_w)
+    let m_ms = input.main_steam_flow_kg_per_h;
+    let spray = m_ms * (h_in - h_out) / (h_in - h_w);
+    let m_up = m_ms - spray;
+
+    // 최소 과열도를 지키는 최대 스프레이 (상류 증기 유량 고정)
+    let t_safe = t_sat + MIN_OUTLET_SUPERHEAT_K;
+    let (h_safe, _, _) = if97::region_props(p, t_safe).map_err(if97_err)?;
+    let max_safe_spray = if h_in > h_safe {
+        m_up * (h_in - h_safe) / (h_safe - h_w)
+    } else {
+        0.0
+    };
+    let control_min_outlet_temp_c = match input.max_spray_flow_kg_per_h {
+        Some(m_max) if m_max < max_safe_spray => {
+            let h_mix = (m_up * h_in + m_max * h_w) / (m_up + m_max);
+            temp_from_ph(p, h_mix)?
+        }
+        _ => t_safe.min(input.inlet_steam_temp_c),
+    };
+
+    let (h_ms, _, _) =
+        if97::region_props(input.main_steam_pressure_bar_abs, input.main_steam_temp_c)
+            .map_err(if97_err)?;
+    let (h_fw, _, _) = if97::region_props(input.feedwater_pressure_bar_abs, input.feedwater_temp_c)
+        .map_err(if97_err)?;
+    if h_ms <= h_fw {
+        return Err(AttemperatorError::InvalidInput(
+            "주증기 엔탈피가 급수 엔탈피보다 커야 합니다.",
+        ));
+    }
+    let boiler_output_kw = m_ms * (h_ms - h_fw) / 3.6e6;
+    let output_excluding_spray_kw = m_up * (h_ms - h_fw) / 3.6e6;
+    let output_understatement_pct =
+        (boiler_output_kw - output_excluding_spray_kw) / boiler_output_kw * 100.0;
+    let efficiency = input
+        .fuel_heat_input_kw
+        .filter(|&q| q > 0.0)
+        .map(|q| (boiler_output_kw / q, output_excluding_spray_kw / q));
+
+    let mut warnings = Vec::new();
+    if input.outlet_setpoint_c > input.inlet_steam_temp_c {
+        warnings.push(AttemperatorWarning::SetpointAboveInlet {
+            inlet_temp_c: input.inlet_steam_temp_c,
+        });
+    }
+    if let Some(m_max) = input.max_spray_flow_kg_per_h {
+        if spray > m_max {
+            warnings.push(AttemperatorWarning::SprayExceedsValve {
+                spray_kg_per_h: spray,
+                max_kg_per_h: m_max,
+                min_outlet_temp_c: control_min_outlet_temp_c,
+            });
+        }
+    }
+    let outlet_superheat_k = setpoint - t_sat;
+    if outlet_superheat_k < MIN_OUTLET_SUPERHEAT_K {
+        warnings.push(AttemperatorWarning::LowOutletSuperheat {
+            superheat_k: outlet_superheat_k,
+        });
+    }
+    let spray_fraction = spray / m_ms;
+    if spray_fraction > HIGH_SPRAY_FRACTION {
+        warnings.push(AttemperatorWarning::HighSprayFraction {
+            fraction: spray_fraction,
+        });
+    }
+
+    Ok(AttemperatorResult {
+        spray_flow_kg_per_h: spray,
+        spray_fraction,
+        upstream_steam_flow_kg_per_h: m_up,
+        saturation_temp_c: t_sat,
+        outlet_superheat_k,
+        control_min_outlet_temp_c,
+        control_max_outlet_temp_c: input.inlet_steam_temp_c,
+        max_safe_spray_flow_kg_per_h: max_safe_spray,
+        boiler_output_kw,
+        output_excluding_spray_kw,
+        output_understatement_pct,
+        efficiency,
+        warnings,
+    })
+}
@@ -1,3 +1,5 @@
+use crate::combustion::dew_point::{self, DewPointWarning, FlueGasDewPoints};
+
 /// 간단한 열수지 기반 보일러 효율 계산 입력.
 #[derive(Debug, Clone)]
 pub struct BoilerEfficiencyInput {
@@ -22,6 +24,8 @@ pub struct BoilerEfficiencyResult {
     pub fuel_heat_kw: f64,
     /// 증기 생성 유효 열량 [kW]
     pub useful_heat_kw: f64,
+    /// 연돌 노점 경고 (PTC 계산에 노점을 줄 때만)
+    pub warnings: Vec<DewPointWarning>,
 }
 
 /// 기본 열수지(증기엔탈피-급수엔탈피) 기반 보일러 효율을 계산한다.
@@ -38,6 +42,7 @@ pub fn boiler_efficiency(input: BoilerEfficiencyInput) -> BoilerEfficiencyResult
         efficiency,
         fuel_heat_kw: fuel_heat_kj_per_h / 3600.0,
         useful_heat_kw: useful_kj_per_h / 3600.0,
+        warnings: Vec::new(),
     }
 }
 
@@ -70,6 +75,8 @@ pub struct BoilerEfficiencyPtcInput {
     pub blowdown_rate_frac: f64,
     /// 블로다운 배출 엔탈피 [kJ/kg]
     pub blowdown_enthalpy_kj_per_kg: f64,
+    /// 배가스 노점. 있으면 연돌 온도가 노점에 가까울 때 경고한다.
+    pub flue_gas_dew_points: Option<FlueGasDewPoints>,
 }
 
 /// PTC 4.0에 준해 스택 손실, 복사 손실, 블로다운 손실을 고려한 효율을 계산한다.
@@ -102,9 +109,15 @@ pub fn boiler_efficiency_ptc(input: BoilerEfficiencyPtcInput) -> BoilerEfficienc
         0.0
     };
 
+    let warnings = input
+        .flue_gas_dew_points
+        .map(|dp| dew_point::stack_dew_point_warnings(input.stack_temp_c, &dp))
+        .unwrap_or_default();
+
     BoilerEfficiencyResult {
         efficiency,
         fuel_heat_kw: fuel_heat_kj_per_h / 3600.0,
         useful_heat_kw: useful_kj_per_h / 3600.0,
+        warnings,
     }
 }
@@ -0,0 +1,230 @@
+//! 임계(초킹) 유동 계산.
+//! 이상기체는 비열비 k로 임계 압력비와 질량유속을 닫힌 식으로 구하고,
+//! 증기는 IF97 등엔트로피 팽창 G(p) = √(2(h0 - h)) / v 의 최대점으로 실제기체 임계 조건을 찾는다.
+
+use crate::steam::if97;
+
+/// 일반기체 상수 [J/(kmol·K)]
+const R_UNIVERSAL: f64 = 8_314.462_618;
+/// IF97 임계 조건을 구하지 못할 때 이상기체 임계 압력비에 쓰는 과열 증기 비열비.
+pub const FALLBACK_GAMMA: f64 = 1.3;
+
+/// 노즐 상류 정체 상태.
+#[derive(Debug, Clone, Copy)]
+pub enum SteamStagnation {
+    /// 압력 + 온도(°C) (과열 증기)
+    Temperature(f64),
+    /// 압력 + 건도(0~1) (포화/습증기). 1.0이면 건포화 증기.
+    Quality(f64),
+}
+
+/// 임계 유동 결과.
+#[derive(Debug, Clone)]
+pub struct CriticalFlowResult {
+    /// 임계 압력비 p*/p0
+    pub critical_pressure_ratio: f64,
+    /// 임계(목) 압력 (bar abs)
+    pub critical_pressure_bar_abs: f64,
+    /// 임계 질량유속 (kg/m²·s)
+    pub mass_flux_kg_per_m2_s: f64,
+    /// 목 유속 (m/s)
+    pub throat_velocity_m_per_s: f64,
+}
+
+/// 노즐/오리피스 유량 결과.
+#[derive(Debug, Clone)]
+pub struct NozzleFlowResult {
+    /// 질량유량 (kg/h)
+    pub mass_flow_kg_per_h: f64,
+    /// 목 압력 (bar abs). 초킹 시 임계 압력, 아니면 배압.
+    pub throat_pressure_bar_abs: f64,
+    pub mass_flux_kg_per_m2_s: f64,
+    pub critical_pressure_ratio: f64,
+    pub choked: bool,
+}
+
+/// 임계 유동 계산 오류.
+#[derive(Debug, Clone)]
+pub enum CriticalFlowError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for CriticalFlowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CriticalFlowError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            CriticalFlowError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CriticalFlowError {}
+
+/// 이상기체 임계 압력비 (2/(k+1))^(k/(k-1)).
+pub fn ideal_gas_critical_pressure_ratio(k: f64) -> f64 {
+    (2.0 / (k + 1.0)).powf(k / (k - 1.0))
+}
+
+/// 이상기체 임계 질량유속(kg/m²·s). 상류 정체 압력(bar abs)·온도(°C), 분자량(kg/kmol), 압축계수 Z.
+pub fn ideal_gas_critical_mass_flux(
+    p0_bar_abs: f64,
+    t0_c: f64,
+    k: f64,
+    molar_mass_kg_per_kmol: f64,
+    z: f64,
+) -> Result<CriticalFlowResult, CriticalFlowError> {
+    let t0_k = t0_c + 273.15;
+    if p0_bar_abs <= 0.0 || t0_k <= 0.0 || k <= 1.0 || molar_mass_kg_per_kmol <= 0.0 || z <= 0.0 {
+        return Err(CriticalFlowError::InvalidInput(
+            "압력, 절대온도, 분자량, Z는 0보다 크고 k는 1보다 커야 합니다.",
+        ));
+    }
+    let r = R_UNIVERSAL / molar_mass_kg_per_kmol;
+    let ratio = ideal_gas_critical_pressure_ratio(k);
+    let p0_pa = p0_bar_abs * 1e5;
+    let flux =
+        p0_pa * (k / (z * r * t0_k)).sqrt() * (2.0 / (k + 1.0)).powf((k + 1.0) / (2.0 * (k - 1.0)));
+    // 목 온도 T* = 2·T0/(k+1), 유속 = 음속
+    let throat_velocity = (k * z * r * 2.0 * t0_k / (k + 1.0)).sqrt();
+    Ok(CriticalFlowResult {
+        critical_pressure_ratio: ratio,
+        critical_pressure_bar_abs: p0_bar_abs * ratio,
+        mass_flux_kg_per_m2_s: flux,
+        throat_velocity_m_per_s: throat_velocity,
+    })
+}
+
+/// 정체 상태의 (엔탈피, 엔트로피)
+pub(crate) fn stagnation(
+    p0_bar_abs: f64,
+    state: SteamStagnation,
+) -> Result<(f64, f64), CriticalFlowError> {
+    let (h, _, s) = match state {
+        SteamStagnation::Temperature(t) => if97::region_props(p0_bar_abs, t),
+        SteamStagnation::Quality(x) => {
+            if !(0.0..=1.0).contains(&x) {
+                return Err(CriticalFlowError::InvalidInput(
+                    "건도는 0~1 사이여야 합니다.",
+                ));
+            }
+            if97::props_from_px(p0_bar_abs, x)
+        }
+    }
+    .map_err(|e| CriticalFlowError::If97(e.into()))?;
+    Ok((h, s))
+}
+
+/// 등엔트로피 팽창으로 압력 p에 도달했을 때의 질량유속 (kg/m²·s)
+fn isentropic_mass_flux(p_bar_abs: f64, h0: f64, s0: f64) -> Result<f64, CriticalFlowError> {
+    let (h, v) =
+        if97::props_from_ps(p_bar_abs, s0).map_err(|e| CriticalFlowError::If97(e.into()))?;
+    Ok((2.0 * (h0 - h).max(0.0)).sqrt() / v)
+}
+
+/// IF97 등엔트로피 팽창으로 증기의 임계 압력비와 질량유속을 구한다.
+/// 질량유속 G(p)의 최대점을 황금분할 탐색으로 찾는다(균질 평형 모델, 습증기 포함).
+pub fn steam_critical_flow(
+    p0_bar_abs: f64,
+    state: SteamStagnation,
+) -> Result<CriticalFlowResult, CriticalFlowError> {
+    if p0_bar_abs <= 0.0 {
+        return Err(CriticalFlowError::InvalidInput(
+            "상류 압력은 0보다 커야 합니다.",
+        ));
+    }
+    let (h0, s0) = stagnation(p0_bar_abs, state)?;
+    let flux_at = |ratio: f64| isentropic_mass_flux(p0_bar_abs * ratio, h0, s0);
+
+    let inv_phi = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut a, mut b) = (0.05, 0.999);
+    let mut c = b - inv_phi * (b - a);
+    let mut d = a + inv_phi * (b - a);
+    let mut fc = flux_at(c)?;
+    let mut fd = flux_at(d)?;
+    while b - a > 1e-7 {
+        if fc > fd {
+            b = d;
+            d = c;
+            fd = fc;
+            c = b - inv_phi * (b - a);
+            fc = flux_at(c)?;
+        } else {
+            a = c;
+            c = d;
+            fc = fd;
+            d = a + inv_phi * (b - a);
+            fd = flux_at(d)?;
+        }
+    }
+    let ratio = 0.5 * (a + b);
+    let p_star = p0_bar_abs * ratio;
+    let (h, v) = if97::props_from_ps(p_star, s0).map_err(|e| CriticalFlowError::If97(e.into()))?;
+    let velocity = (2.0 * (h0 - h).max(0.0)).sqrt();
+    Ok(CriticalFlowResult {
+        critical_pressure_ratio: ratio,
+        critical_pressure_bar_abs: p_star,
+        mass_flux_kg_per_m2_s: velocity / v,
+        throat_velocity_m_per_s: velocity,
+    })
+}
+
+/// 상류 정체 상태의 IF97 임계 압력비. 구하지 못하면 `FALLBACK_GAMMA`의 이상기체 값을 쓴다.
+pub fn critical_pressure_ratio_or_fallback(p0_bar_abs: f64, state: SteamStagnation) -> f64 {
+    steam_critical_flow(p0_bar_abs, state)
+        .map(|c| c.critical_pressure_ratio)
+        .unwrap_or_else(|_| ideal_gas_critical_pressure_ratio(FALLBACK_GAMMA))
+}
+
+/// 증기 노즐/오리피스 유량(kg/h). 배압이 임계 압력보다 낮으면 임계 질량유속으로 제한한다.
+pub fn steam_nozzle_flow(
+    p0_bar_abs: f64,
+    state: SteamStagnation,
+    back_pressure_bar_abs: f64,
+    throat_area_m2: f64,
+    discharge_coefficient: f64,
+) -> Result<NozzleFlowResult, CriticalFlowError> {
+    if throat_area_m2 <= 0.0 || discharge_coefficient <= 0.0 || discharge_coefficient > 1.0 {
+        return Err(CriticalFlowError::InvalidInput(
+            "목 면적은 0보다 크고 유량계수는 0~1 사이여야 합니다.",
+        ));
+    }
+    if back_pressure_bar_abs < 0.0 || back_pressure_bar_abs >= p0_bar_abs {
+        return Err(CriticalFlowError::InvalidInput(
+            "배압은 0 이상이고 상류 압력보다 낮아야 합니다.",
+        ));
+    }
+    let crit = steam_critical_flow(p0_bar_abs, state)?;
+    let choked = back_pressure_bar_abs <= crit.critical_pressure_bar_abs;
+    let (throat_p, flux) = if choked {
+        (crit.critical_pressure_bar_abs, crit.mass_flux_kg_per_m2_s)
+    } else {
+        let (h0, s0) = stagnation(p0_bar_abs, state)?;
+        (
+            back_pressure_bar_abs,
+            isentropic_mass_flux(back_pressure_bar_abs, h0, s0)?,
+        )
+    };
+    Ok(NozzleFlowResult {
+        mass_flow_kg_per_h: discharge_coefficient * throat_area_m2 * flux * 3600.0,
+        throat_pressure_bar_abs: throat_p,
+        mass_flux_kg_per_m2_s: flux,
+        critical_pressure_ratio: crit.critical_pressure_ratio,
+        choked,
+    })
+}
+
+/// 밸브 Kv 식에 쓸 유효 차압(bar). 하류/상류 압력비가 임계비보다 낮으면 임계 차압으로 제한한다.
+/// 반환: (유효 차압, 초킹 여부)
+pub fn choked_limited_pressure_drop(
+    upstream_bar_abs: f64,
+    delta_p_bar: f64,
+    critical_pressure_ratio: f64,
+) -> (f64, bool) {
+    let dp_max = upstream_bar_abs * (1.0 - critical_pressure_ratio);
+    if upstream_bar_abs > 0.0 && delta_p_bar > dp_max {
+        (dp_max, true)
+    } else {
+        (delta_p_bar, false)
+    }
+}
@@ -0,0 +1,322 @@
+//! 랭킨 사이클 열소비율(heat rate)/효율 간이 계산.
+//! 주증기 조건, 복수기 압력, 터빈/펌프 효율로 1kg 주증기 기준 열수지를 세우고
+//! 재열 1단과 급수 가열기 1대(개방형 탈기기 또는 드레인을 복수기로 보내는 밀폐형)를 선택적으로 넣는다.
+//! 터빈 효율은 구간(고압: 주증기→재열 압력, 저압: 재열→복수기) 전체에 적용하며 추기점은 그 팽창선 위에 둔다.
+
+use crate::steam::if97;
+use serde::{Deserialize, Serialize};
+
+/// 배기 건도가 이보다 낮으면 말단 날개 침식이 우려된다.
+const MIN_EXHAUST_QUALITY: f64 = 0.88;
+/// 주증기 과열도가 이보다 작으면 경고한다 [K]
+const MIN_THROTTLE_SUPERHEAT_K: f64 = 20.0;
+
+/// 재열 조건.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ReheatInput {
+    /// 재열 압력(고압 터빈 배기) [bar abs]. 압력 손실은 무시한다.
+    pub pressure_bar_abs: f64,
+    /// 재열 증기 온도 [°C]
+    pub temp_c: f64,
+}
+
+/// 급수 가열기 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FeedwaterHeaterKind {
+    /// 개방형(탈기기): 추기와 복수가 섞여 추기 압력 포화수로 나간다.
+    Open,
+    /// 밀폐형: 급수 출구 = 추기 포화온도 − TTD, 드레인(포화수)은 복수기로 보낸다.
+    Closed { terminal_temp_difference_k: f64 },
+}
+
+/// 급수 가열기 조건.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeedwaterHeaterInput {
+    /// 추기 압력 [bar abs]
+    pub extraction_pressure_bar_abs: f64,
+    pub kind: FeedwaterHeaterKind,
+}
+
+/// 랭킨 사이클 입력.
+#[derive(Debug, Clone)]
+pub struct RankineCycleInput {
+    /// 주증기 압력 [bar abs]
+    pub throttle_pressure_bar_abs: f64,
+    /// 주증기 온도 [°C]
+    pub throttle_temp_c: f64,
+    /// 복수기 압력 [bar abs]
+    pub condenser_pressure_bar_abs: f64,
+    /// 터빈 등엔트로피 효율 (0~1)
+    pub turbine_efficiency: f64,
+    /// 펌프 효율 (0~1)
+    pub pump_efficiency: f64,
+    pub reheat: Option<ReheatInput>,
+    pub feedwater_heater: Option<FeedwaterHeaterInput>,
+    /// 주증기 유량 [kg/h]. 주면 출력 [kW]도 계산한다.
+    pub steam_flow_kg_per_h: Option<f64>,
+}
+
+/// 랭킨 사이클 결과. 비에너지는 주증기 1kg 기준 [kJ/kg].
+#[derive(Debug, Clone)]
+pub struct RankineCycleResult {
+    pub throttle_enthalpy_kj_per_kg: f64,
+    /// 고압 터빈 배기(재열 입구) 엔탈피. 재열이 없으면 `None`.
+    pub cold_reheat_enthalpy_kj_per_kg: Option<f64>,
+    pub hot_reheat_enthalpy_kj_per_kg: Option<f64>,
+    pub exhaust_enthalpy_kj_per_kg: f64,
+    pub exhaust_quality: f64,
+    /// 추기 엔탈피와 주증기 대비 추기 분율
+    pub extraction_enthalpy_kj_per_kg: Option<f64>,
+    pub extraction_fraction: f64,
+    /// 보일러 입구 급수 온도 [°C]와 엔탈피
+    pub feedwater_temp_c: f64,
+    pub feedwater_enthalpy_kj_per_kg: f64,
+    pub turbine_work_kj_per_kg: f64,
+    pub pump_work_kj_per_kg: f64,
+    pub net_work_kj_per_kg: f64,
+    /// 보일러 + 재열기 가열량
+    pub heat_input_kj_per_kg: f64,
+    pub thermal_efficiency: f64,
+    /// 열소비율 [kJ/kWh]
+    pub heat_rate_kj_per_kwh: f64,
+    /// 증기 소비율 [kg/kWh]
+    pub steam_rate_kg_per_kwh: f64,
+    /// 순출력 [kW] (`steam_flow_kg_per_h`를 준 경우)
+    pub net_power_kw: Option<f64>,
+    pub warnings: Vec<String>,
+}
+
+/// 사이클 계산 오류.
+#[derive(Debug, Clone)]
+pub enum CycleError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for CycleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CycleError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            CycleError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CycleError {}
+
+fn if97_err(e: &str) -> CycleError {
+    CycleError::If97(e.to_string())
+}
+
+/// 입구 (h, s)에서 출구 압력까지 효율 η로 팽창한 엔탈피 [J/kg].
+pub(crate) fn expand(h_in: f64, s_in: f64, p_out: f64, efficiency: f64) -> Result<f64, CycleError> {
+    let h_s = if97::enthalpy_from_ps(p_out, s_in).map_err(if97_err)?;
+    Ok(h_in - efficiency * (h_in - h_s))
+}
+
+/// 포화수를 p_in에서 p_out까지 올리는 펌프 일 [J/kg]. (v·Δp/η)
+pub(crate) fn pump_work(v_in: f64, p_in: f64, p_out: f64, efficiency: f64) -> f64 {
+    v_in * (p_out - p_in) * 1e5 / efficiency
+}
+
+/// 랭킨 사이클의 열소비율과 효율을 계산한다.
+pub fn compute_rankine_cycle(input: RankineCycleInput) -> Result<RankineCycleResult, CycleError> {
+    let p1 = input.throttle_pressure_bar_abs;
+    let p_c = input.condenser_pressure_bar_abs;
+    if p_c <= 0.0 || p1 <= p_c {
+        return Err(CycleError::InvalidInput(
+            "복수기 압력은 0보다 크고 주증기 압력보다 낮아야 합니다.",
+        ));
+    }
+    if !(input.turbine_efficiency > 0.0
+        && input.turbine_efficiency <= 1.0
+        && input.pump_efficiency > 0.0
+        && input.pump_efficiency <= 1.0)
+    {
+        return Err(CycleError::InvalidInput(
+            "터빈/펌프 효율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if matches!(input.steam_flow_kg_per_h, Some(m) if m <= 0.0) {
+        return Err(CycleError::InvalidInput("주증기 유량은 0보다 커야 합니다."));
+    }
+    if let Some(rh) = input.reheat {
+        if rh.pressure_bar_abs <= p_c || rh.pressure_bar_abs >= p1 {
+            return Err(CycleError::InvalidInput(
+                "재열 압력은 복수기 압력과 주증기 압력 사이여야 합니다.",
+            ));
+        }
+    }
+    if let Some(fwh) = input.feedwater_heater {
+        if fwh.extraction_pressure_bar_abs <= p_c || fwh.extraction_pressure_bar_abs >= p1 {
+            return Err(CycleError::InvalidInput(
+                "추기 압력은 복수기 압력과 주증기 압력 사이여야 합니다.",
+            ));
+        }
+        if matches!(fwh.kind, FeedwaterHeaterKind::Closed { terminal_temp_difference_k: ttd } if ttd < 0.0)
+        {
+            return Err(CycleError::InvalidInput("TTD는 0 이상이어야 합니다."));
+        }
+    }
+    let eta_t = input.turbine_efficiency;
+    let eta_p = input.pump_efficiency;
+    let mut warnings = Vec::new();
+
+    // 주증기
+    let t_sat1 = if97::saturation_temp_c_from_pressure_bar_abs(p1).map_err(if97_err)?;
+    if input.throttle_temp_c <= t_sat1 {
+        return Err(CycleError::InvalidInput(
+            "주증기 온도는 해당 압력의 포화온도보다 높아야 합니다.",
+        ));
+    }
+    if input.throttle_temp_c - t_sat1 < MIN_THROTTLE_SUPERHEAT_K {
+        warnings.push(format!(
+            "주증기 과열도가 {:.1} K로 작습니다.",
+            input.throttle_temp_c - t_sat1
+        ));
+    }
+    let (h1, _, s1) = if97::region_props(p1, input.throttle_temp_c).map_err(if97_err)?;
+
+    // 고압 구간 끝(재열 압력 또는 복수기)과 재열
+    let hp_end_p = input.reheat.map_or(p_c, |rh| rh.pressure_bar_abs);
+    let h_hp_end = expand(h1, s1, hp_end_p, eta_t)?;
+    let reheat_state = match input.reheat {
+        Some(rh) => {
+            let t_sat_rh = if97::saturation_temp_c_from_pressure_bar_abs(rh.pressure_bar_abs)
+                .map_err(if97_err)?;
+            if rh.temp_c <= t_sat_rh {
+                return Err(CycleError::InvalidInput(
+                    "재열 온도는 재열 압력의 포화온도보다 높아야 합니다.",
+                ));
+            }
+            let (h_rh, _, s_rh) =
+                if97::region_props(rh.pressure_bar_abs, rh.temp_c).map_err(if97_err)?;
+            if h_rh <= h_hp_end {
+                return Err(CycleError::InvalidInput(
+                    "재열 온도가 고압 터빈 배기 온도보다 낮습니다.",
+                ));
+            }
+            Some((h_rh, s_rh))
+        }
+        None => None,
+    };
+    let h_exh = match reheat_state {
+        Some((h_rh, s_rh)) => expand(h_rh, s_rh, p_c, eta_t)?,
+        None => h_hp_end,
+    };
+
+    // 복수기 포화수
+    let (hf_c, v_c, _) = if97::props_from_px(p_c, 0.0).map_err(if97_err)?;
+    let (_, hg_c) = if97::saturation_enthalpies(p_c).map_err(if97_err)?;
+    let exhaust_quality = (h_exh - hf_c) / (hg_c - hf_c);
+
+    // 추기와 급수 가열
+    let mut y = 0.0;
+    let mut h_ext = None;
+    let mut ext_in_hp = false;
+    let (h_fw, w_pump) = match input.feedwater_heater {
+        None => {
+            let w = pump_work(v_c, p_c, p1, eta_p);
+            (hf_c + w, w)
+        }
+        Some(fwh) => {
+            let p_e = fwh.extraction_pressure_bar_abs;
+            ext_in_hp = p_e >= hp_end_p;
+            let h_e = match reheat_state {
+                Some((h_rh, s_rh)) if !ext_in_hp => expand(h_rh, s_rh, p_e, eta_t)?,
+                _ => expand(h1, s1, p_e, eta_t)?,
+            };
+            h_ext = Some(h_e);
+            let (hf_e, v_e, _) = if97::props_from_px(p_e, 0.0).map_err(if97_err)?;
+            match fwh.kind {
+                FeedwaterHeaterKind::Open => {
+                    let w1 = pump_work(v_c, p_c, p_e, eta_p);
+                    let h_p1 = hf_c + w1;
+                    y = (hf_e - h_p1) / (h_e - h_p1);
+                    let w2 = pump_work(v_e, p_e, p1, eta_p);
+                    (hf_e + w2, (1.0 - y) * w1 + w2)
+                }
+                FeedwaterHeaterKind::Closed {
+                    terminal_temp_difference_k: ttd,
+                } => {
+                    let w = pump_work(v_c, p_c, p1, eta_p);
+                    let h_p = hf_c + w;
+                    let t_sat_e =
+                        if97::saturation_temp_c_from_pressure_bar_abs(p_e).map_err(if97_err)?;
+                    let (h_fw, _, _) = if97::region_props(p1, t_sat_e - ttd).map_err(if97_err)?;
+                    if h_fw <= h_p {
+                        return Err(CycleError::InvalidInput(
+                            "TTD가 너무 커서 급수가 가열되지 않습니다.",
+                        ));
+                    }
+                    y = (h_fw - h_p) / (h_e - hf_e);
+                    (h_fw, w)
+                }
+            }
+        }
+    };
+    if !(0.0..1.0).contains(&y) {
+        return Err(CycleError::InvalidInput(
+            "추기 분율이 0~1 범위를 벗어납니다. 추기 압력을 확인하세요.",
+        ));
+    }
+
+    // 터빈 일 (구간별 통과 유량 반영)
+    let hp_work = match h_ext {
+        Some(h_e) if ext_in_hp => (h1 - h_e) + (1.0 - y) * (h_e - h_hp_end),
+        _ => h1 - h_hp_end,
+    };
+    let m_rh = if ext_in_hp { 1.0 - y } else { 1.0 };
+    let (lp_work, reheat_heat) = match reheat_state {
+        Some((h_rh, _)) => {
+            let lp = match h_ext {
+                Some(h_e) if !ext_in_hp => (h_rh - h_e) + (1.0 - y) * (h_e - h_exh),
+                _ => m_rh * (h_rh - h_exh),
+            };
+            (lp, m_rh * (h_rh - h_hp_end))
+        }
+        None => (0.0, 0.0),
+    };
+    let turbine_work = hp_work + lp_work;
+    let net_work = turbine_work - w_pump;
+    let heat_input = (h1 - h_fw) + reheat_heat;
+    if net_work <= 0.0 {
+        return Err(CycleError::InvalidInput("순일이 0 이하입니다."));
+    }
+    let efficiency = net_work / heat_input;
+
+    if exhaust_quality < MIN_EXHAUST_QUALITY {
+        warnings.push(format!(
+            "배기 건도 {:.3}가 낮아 말단 날개 침식이 우려됩니다. 재열 또는 주증기 온도 상향을 검토하세요.",
+            exhaust_quality
+        ));
+    }
+    if exhaust_quality > 1.0 {
+        warnings.push("배기가 과열 상태입니다. 복수기 부하에 과열분이 더해집니다.".into());
+    }
+
+    let feedwater_temp_c = if97::temperature_from_ph(p1, h_fw).map_err(if97_err)?;
+    Ok(RankineCycleResult {
+        throttle_enthalpy_kj_per_kg: h1 / 1000.0,
+        cold_reheat_enthalpy_kj_per_kg: reheat_state.map(|_| h_hp_end / 1000.0),
+        hot_reheat_enthalpy_kj_per_kg: reheat_state.map(|(h_rh, _)| h_rh / 1000.0),
+        exhaust_enthalpy_kj_per_kg: h_exh / 1000.0,
+        exhaust_quality,
+        extraction_enthalpy_kj_per_kg: h_ext.map(|h| h / 1000.0),
+        extraction_fraction: y,
+        feedwater_temp_c,
+        feedwater_enthalpy_kj_per_kg: h_fw / 1000.0,
+        turbine_work_kj_per_kg: turbine_work / 1000.0,
+        pump_work_kj_per_kg: w_pump / 1000.0,
+        net_work_kj_per_kg: net_work / 1000.0,
+        heat_input_kj_per_kg: heat_input / 1000.0,
+        thermal_efficiency: efficiency,
+        heat_rate_kj_per_kwh: 3600.0 / efficiency,
+        steam_rate_kg_per_kwh: 3600.0 / (net_work / 1000.0),
+        net_power_kw: input
+            .steam_flow_kg_per_h
+            .map(|m| m / 3600.0 * net_work / 1000.0),
+        warnings,
+    })
+}
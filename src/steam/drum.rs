@@ -0,0 +1,261 @@
+//! 보일러 드럼 과도 거동 간이 계산.
+//! 부하 변화 시 상승관(riser) 기포율 변화로 생기는 수위 팽창(swell)/수축(shrink)과
+//! 연속 블로다운 배관의 플래싱(임계) 유량 검토를 다룬다.
+
+use crate::steam::critical_flow::{self, CriticalFlowError, SteamStagnation};
+use crate::steam::if97;
+
+/// 드럼 계산 오류.
+#[derive(Debug, Clone)]
+pub enum DrumError {
+    InvalidInput(&'static str),
+    If97(String),
+    CriticalFlow(CriticalFlowError),
+}
+
+impl std::fmt::Display for DrumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DrumError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            DrumError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            DrumError::CriticalFlow(e) => write!(f, "임계 유동 계산 실패: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for DrumError {}
+
+/// 운전점 (드럼 압력, 증발량)
+#[derive(Debug, Clone, Copy)]
+pub struct DrumOperatingPoint {
+    pub pressure_bar_abs: f64,
+    pub steam_flow_kg_per_h: f64,
+}
+
+/// 수위 팽창/수축 입력. 드럼은 수평 원통으로 본다.
+#[derive(Debug, Clone)]
+pub struct DrumSwellInput {
+    pub drum_inner_diameter_m: f64,
+    pub drum_length_m: f64,
+    /// 정상 수위(드럼 바닥 기준, m)
+    pub normal_water_level_m: f64,
+    /// 상승관 내부 체적 합계 (m³)
+    pub riser_volume_m3: f64,
+    /// 자연순환 유량 (kg/h). 부하와 무관하게 일정하다고 본다.
+    pub circulation_flow_kg_per_h: f64,
+    pub before: DrumOperatingPoint,
+    pub after: DrumOperatingPoint,
+}
+
+/// 수위 팽창/수축 결과.
+#[derive(Debug, Clone)]
+pub struct DrumSwellResult {
+    /// 상승관 평균 기포율 (변화 전/후)
+    pub mean_void_before: f64,
+    pub mean_void_after: f64,
+    /// 상승관 출구 건도 (변화 전/후)
+    pub exit_quality_before: f64,
+    pub exit_quality_after: f64,
+    /// 드럼으로 밀려 올라오는 물 체적 (m³). 양수 = 팽창, 음수 = 수축
+    pub displaced_volume_m3: f64,
+    /// 정상 수위에서의 수면 면적 (m²)
+    pub water_surface_area_m2: f64,
+    /// 수위 변화 (mm). 양수 = 상승
+    pub level_change_mm: f64,
+    pub warnings: Vec<String>,
+}
+
+/// Zivi 슬립비 S = (ρf/ρg)^(1/3)를 쓴 기포율 α(x)
+fn void_fraction(x: f64, rho_f: f64, rho_g: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let slip = (rho_f / rho_g).powf(1.0 / 3.0);
+    1.0 / (1.0 + (1.0 - x) / x * rho_g / rho_f * slip)
+}
+
+/// 상승관을 따라 건도가 0에서 출구 건도까지 선형 증가한다고 보고 평균 기포율을 구한다 (심프슨 적분).
+fn mean_riser_void(exit_quality: f64, rho_f: f64, rho_g: f64) -> f64 {
+    let n = 200;
+    let h = 1.0 / n as f64;
+    let sum: f64 = (0..=n)
+        .map(|i| {
+            let w = if i == 0 || i == n {
+                1.0
+            } else if i % 2 == 1 {
+                4.0
+            } else {
+                2.0
+            };
+            w * void_fraction(exit_quality * i as f64 * h, rho_f, rho_g)
+        })
+        .sum();
+    sum * h / 3.0
+}
+
+fn saturation_densities(p_bar_abs: f64) -> Result<(f64, f64), DrumError> {
+    let (_, vf, _) = if97::props_from_px(p_bar_abs, 0.0).map_err(|e| DrumError::If97(e.into()))?;
+    let (_, vg, _) = if97::props_from_px(p_bar_abs, 1.0).map_err(|e| DrumError::If97(e.into()))?;
+    Ok((1.0 / vf, 1.0 / vg))
+}
+
+/// 부하 변화 전후 상승관 기포량 차이로 드럼 수위 팽창/수축을 추정한다.
+/// 압력 강하(부하 급증)는 플래싱으로, 증발량 증가는 출구 건도 상승으로 기포율을 키운다.
+pub fn drum_swell(input: DrumSwellInput) -> Result<DrumSwellResult, DrumError> {
+    let d = input.drum_inner_diameter_m;
+    if d <= 0.0 || input.drum_length_m <= 0.0 || input.riser_volume_m3 <= 0.0 {
+        return Err(DrumError::InvalidInput(
+            "드럼 내경/길이와 상승관 체적은 0보다 커야 합니다.",
+        ));
+    }
+    if !(input.normal_water_level_m > 0.0 && input.normal_water_level_m < d) {
+        return Err(DrumError::InvalidInput(
+            "정상 수위는 0과 드럼 내경 사이여야 합니다.",
+        ));
+    }
+    if input.circulation_flow_kg_per_h <= 0.0 {
+        return Err(DrumError::InvalidInput("순환 유량은 0보다 커야 합니다."));
+    }
+    let mut voids = [0.0; 2];
+    let mut qualities = [0.0; 2];
+    for (i, point) in [input.before, input.after].iter().enumerate() {
+        if point.pressure_bar_abs <= 0.0 || point.steam_flow_kg_per_h < 0.0 {
+            return Err(DrumError::InvalidInput(
+                "드럼 압력은 0보다 크고 증발량은 0 이상이어야 합니다.",
+            ));
+        }
+        if point.steam_flow_kg_per_h >= input.circulation_flow_kg_per_h {
+            return Err(DrumError::InvalidInput(
+                "증발량이 순환 유량보다 작아야 합니다 (순환비 > 1).",
+            ));
+        }
+        let (rho_f, rho_g) = saturation_densities(point.pressure_bar_abs)?;
+        qualities[i] = point.steam_flow_kg_per_h / input.circulation_flow_kg_per_h;
+        voids[i] = mean_riser_void(qualities[i], rho_f, rho_g);
+    }
+
+    let displaced = input.riser_volume_m3 * (voids[1] - voids[0]);
+    let h = input.normal_water_level_m;
+    let area = 2.0 * (h * (d - h)).sqrt() * input.drum_length_m;
+    let level_change_mm = displaced / area * 1000.0;
+
+    let mut warnings = Vec::new();
+    let margin_mm = if level_change_mm >= 0.0 { d - h } else { h } * 1000.0;
+    if level_change_mm.abs() > 0.5 * margin_mm {
+        warnings.push(format!(
+            "수위 변화 {level_change_mm:.0} mm가 정상 수위에서 드럼 {}까지 거리의 절반을 넘습니다.",
+            if level_change_mm >= 0.0 {
+                "상단"
+            } else {
+                "하단"
+            }
+        ));
+    }
+    if input.after.pressure_bar_abs < input.before.pressure_bar_abs
+        && input.after.steam_flow_kg_per_h > input.before.steam_flow_kg_per_h
+    {
+        warnings.push(
+            "부하 증가와 압력 강하가 겹치면 순간 팽창 후 수축(역응답)이 나타납니다. 수위 제어는 3요소 제어를 권장합니다.".into(),
+        );
+    }
+
+    Ok(DrumSwellResult {
+        mean_void_before: voids[0],
+        mean_void_after: voids[1],
+        exit_quality_before: qualities[0],
+        exit_quality_after: qualities[1],
+        displaced_volume_m3: displaced,
+        water_surface_area_m2: area,
+        level_change_mm,
+        warnings,
+    })
+}
+
+/// 블로다운 배관 검토 입력.
+#[derive(Debug, Clone)]
+pub struct BlowdownLineInput {
+    pub drum_pressure_bar_abs: f64,
+    /// 블로다운 유량 (kg/h)
+    pub blowdown_flow_kg_per_h: f64,
+    /// 배관(또는 조절 오리피스) 내경 (m)
+    pub line_diameter_m: f64,
+    /// 플래시 탱크 압력 (bar abs)
+    pub flash_tank_pressure_bar_abs: f64,
+}
+
+/// 블로다운 배관 검토 결과.
+#[derive(Debug, Clone)]
+pub struct BlowdownLineResult {
+    /// 플래시 탱크 압력에서의 플래시 증기 분율
+    pub flash_fraction: f64,
+    pub flash_steam_kg_per_h: f64,
+    /// 포화수 균질 평형 임계 질량유속 (kg/m²·s)
+    pub critical_mass_flux_kg_per_m2_s: f64,
+    /// 배관 단면 기준 최대(초킹) 통과 유량 (kg/h)
+    pub choked_capacity_kg_per_h: f64,
+    /// 요구 유량 / 최대 유량 (%)
+    pub capacity_utilization_percent: f64,
+    /// 플래시 탱크 압력에서의 균질 2상 유속 (m/s)
+    pub outlet_two_phase_velocity_m_per_s: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 드럼 포화수 블로다운 배관의 플래싱 유량 한계를 검토한다.
+/// 통과 한계는 포화수(건도 0) 균질 평형 임계 질량유속으로 본다.
+pub fn blowdown_line_check(input: BlowdownLineInput) -> Result<BlowdownLineResult, DrumError> {
+    if input.blowdown_flow_kg_per_h <= 0.0 || input.line_diameter_m <= 0.0 {
+        return Err(DrumError::InvalidInput(
+            "블로다운 유량과 배관 내경은 0보다 커야 합니다.",
+        ));
+    }
+    if !(input.flash_tank_pressure_bar_abs > 0.0
+        && input.flash_tank_pressure_bar_abs < input.drum_pressure_bar_abs)
+    {
+        return Err(DrumError::InvalidInput(
+            "플래시 탱크 압력은 0보다 크고 드럼 압력보다 낮아야 합니다.",
+        ));
+    }
+    let (hf_drum, _) = if97::saturation_enthalpies(input.drum_pressure_bar_abs)
+        .map_err(|e| DrumError::If97(e.into()))?;
+    let (hf_tank, hg_tank) = if97::saturation_enthalpies(input.flash_tank_pressure_bar_abs)
+        .map_err(|e| DrumError::If97(e.into()))?;
+    let flash_fraction = ((hf_drum - hf_tank) / (hg_tank - hf_tank)).clamp(0.0, 1.0);
+
+    let crit = critical_flow::steam_critical_flow(
+        input.drum_pressure_bar_abs,
+        SteamStagnation::Quality(0.0),
+    )
+    .map_err(DrumError::CriticalFlow)?;
+    let area = std::f64::consts::PI * input.line_diameter_m * input.line_diameter_m / 4.0;
+    let capacity = crit.mass_flux_kg_per_m2_s * area * 3600.0;
+    let utilization = input.blowdown_flow_kg_per_h / capacity * 100.0;
+
+    let (_, vf, _) = if97::props_from_px(input.flash_tank_pressure_bar_abs, 0.0)
+        .map_err(|e| DrumError::If97(e.into()))?;
+    let (_, vg, _) = if97::props_from_px(input.flash_tank_pressure_bar_abs, 1.0)
+        .map_err(|e| DrumError::If97(e.into()))?;
+    let v_mix = vf + flash_fraction * (vg - vf);
+    let velocity = input.blowdown_flow_kg_per_h / 3600.0 * v_mix / area;
+
+    let mut warnings = Vec::new();
+    if utilization > 100.0 {
+        warnings.push(format!(
+            "요구 블로다운 유량이 배관 임계 유량의 {utilization:.0}%입니다. 배관을 키우거나 유량을 줄이세요."
+        ));
+    }
+    if velocity > 60.0 {
+        warnings.push(format!(
+            "플래시 탱크 입구 2상 유속 {velocity:.0} m/s로 높습니다. 침식/소음에 주의하세요."
+        ));
+    }
+
+    Ok(BlowdownLineResult {
+        flash_fraction,
+        flash_steam_kg_per_h: input.blowdown_flow_kg_per_h * flash_fraction,
+        critical_mass_flux_kg_per_m2_s: crit.mass_flux_kg_per_m2_s,
+        choked_capacity_kg_per_h: capacity,
+        capacity_utilization_percent: utilization,
+        outlet_two_phase_velocity_m_per_s: velocity,
+        warnings,
+    })
+}
@@ -0,0 +1,268 @@
+//! 증기 이젝터(스팀 제트) 진공 설비 사이징.
+//! 흡입 부하(공기 + 수증기)를 HEI 분자량 보정(수증기 0.81)으로 공기 환산 부하로 바꾸고,
+//! 구동 증기의 등엔트로피 팽창 엔탈피와 공기의 등엔트로피 압축 일의 비에
+//! 이젝터 효율을 곱해 구동 증기량을 추정한다 (에너지 비 방법).
+//! 2단 구성은 중간 응축기에서 수증기를 응축하고 남은 공기 + 포화 수증기를 2단이 흡입한다.
+
+use crate::steam::critical_flow::SteamStagnation;
+use crate::steam::if97;
+
+/// 기본 이젝터 효율. 10 bar(a) 포화 구동 증기, 130 mbar(a) → 대기압 단일 단에서
+/// 공기 1 kg당 구동 증기 약 2.5 kg(일반 제조사 곡선 수준)이 나오도록 맞춘 값이다.
+pub const DEFAULT_EJECTOR_EFFICIENCY: f64 = 0.15;
+/// HEI 분자량 보정: 수증기(M=18) 비말동반비 (공기 = 1.0)
+const VAPOR_ENTRAINMENT_RATIO: f64 = 0.81;
+/// 공기 정압비열 [J/(kg·K)]와 비열비
+const CP_AIR: f64 = 1_005.0;
+const K_AIR: f64 = 1.4;
+/// 수증기/공기 분자량 비
+const MW_RATIO_VAPOR_AIR: f64 = 18.015 / 28.966;
+/// 단일 단 안정 운전 권장 최대 압축비
+const MAX_STAGE_COMPRESSION_RATIO: f64 = 10.0;
+
+/// 이젝터 단 구성.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EjectorArrangement {
+    SingleStage,
+    /// 2단 + 중간 응축기
+    TwoStage,
+}
+
+/// 이젝터 사이징 입력.
+#[derive(Debug, Clone)]
+pub struct EjectorInput {
+    pub arrangement: EjectorArrangement,
+    /// 흡입 압력 [bar abs]
+    pub suction_pressure_bar_abs: f64,
+    /// 흡입 온도 [°C]
+    pub suction_temp_c: f64,
+    /// 최종 토출 압력 [bar abs] (대기 방출 또는 후부 응축기 압력)
+    pub discharge_pressure_bar_abs: f64,
+    /// 불응축 가스(공기) 부하 [kg/h]
+    pub air_load_kg_per_h: f64,
+    /// 수증기 부하 [kg/h]
+    pub vapor_load_kg_per_h: f64,
+    /// 구동 증기 압력 [bar abs]
+    pub motive_pressure_bar_abs: f64,
+    /// 구동 증기 상태 (온도 또는 건도)
+    pub motive_state: SteamStagnation,
+    /// 2단 중간 압력 [bar abs]. `None`이면 두 단 압축비가 같도록 기하평균을 쓴다.
+    pub interstage_pressure_bar_abs: Option<f64>,
+    /// 중간 응축기 출구(가스) 온도 [°C]. 보통 냉각수 입구 + 3~5 K.
+    pub intercondenser_outlet_temp_c: f64,
+    /// 이젝터 효율. `None`이면 `DEFAULT_EJECTOR_EFFICIENCY`.
+    pub efficiency: Option<f64>,
+}
+
+/// 단별 결과.
+#[derive(Debug, Clone)]
+pub struct EjectorStageResult {
+    pub suction_pressure_bar_abs: f64,
+    pub discharge_pressure_bar_abs: f64,
+    pub compression_ratio: f64,
+    /// 공기 환산 흡입 부하 [kg/h]
+    pub air_equivalent_kg_per_h: f64,
+    pub motive_steam_kg_per_h: f64,
+    /// 비말동반비 = 공기 환산 부하 / 구동 증기량
+    pub entrainment_ratio: f64,
+}
+
+/// 중간 응축기 결과.
+#[derive(Debug, Clone)]
+pub struct IntercondenserResult {
+    pub pressure_bar_abs: f64,
+    /// 1단 토출 혼합 가스 온도 [°C]
+    pub inlet_temp_c: f64,
+    pub outlet_temp_c: f64,
+    pub condensed_kg_per_h: f64,
+    /// 공기와 함께 2단으로 넘어가는 포화 수증기 [kg/h]
+    pub vapor_carryover_kg_per_h: f64,
+    pub duty_kw: f64,
+}
+
+/// 이젝터 사이징 결과.
+#[derive(Debug, Clone)]
+pub struct EjectorResult {
+    pub stages: Vec<EjectorStageResult>,
+    pub total_motive_steam_kg_per_h: f64,
+    /// 2단 구성에서만 `Some`
+    pub intercondenser: Option<IntercondenserResult>,
+    pub warnings: Vec<String>,
+}
+
+/// 이젝터 계산 오류.
+#[derive(Debug, Clone)]
+pub enum EjectorError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for EjectorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EjectorError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            EjectorError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for EjectorError {}
+
+fn if97_err(e: &str) -> EjectorError {
+    EjectorError::If97(e.into())
+}
+
+/// 1단/2단 스팀 제트 이젝터의 구동 증기량과 중간 응축기 부하를 구한다.
+pub fn size_ejector(input: EjectorInput) -> Result<EjectorResult, EjectorError> {
+    let p_s = input.suction_pressure_bar_abs;
+    let p_d = input.discharge_pressure_bar_abs;
+    if !(p_s > 0.0 && p_d > p_s) {
+        return Err(EjectorError::InvalidInput(
+            "흡입 압력은 0보다 크고 토출 압력보다 낮아야 합니다.",
+        ));
+    }
+    if input.motive_pressure_bar_abs <= p_d {
+        return Err(EjectorError::InvalidInput(
+            "구동 증기 압력은 토출 압력보다 높아야 합니다.",
+        ));
+    }
+    if input.air_load_kg_per_h < 0.0
+        || input.vapor_load_kg_per_h < 0.0
+        || input.air_load_kg_per_h + input.vapor_load_kg_per_h <= 0.0
+    {
+        return Err(EjectorError::InvalidInput(
+            "공기/수증기 부하는 0 이상이고 합이 0보다 커야 합니다.",
+        ));
+    }
+    let eta = input.efficiency.unwrap_or(DEFAULT_EJECTOR_EFFICIENCY);
+    if !(eta > 0.0 && eta <= 1.0) {
+        return Err(EjectorError::InvalidInput(
+            "이젝터 효율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+
+    let p_m = input.motive_pressure_bar_abs;
+    let motive = match input.motive_state {
+        SteamStagnation::Temperature(t_c) => {
+            let (h, _, s) = if97::region_props(p_m, t_c).map_err(if97_err)?;
+            (h, s)
+        }
+        SteamStagnation::Quality(x) => {
+            let (h, _, s) = if97::props_from_px(p_m, x.clamp(0.0, 1.0)).map_err(if97_err)?;
+            (h, s)
+        }
+    };
+
+    let mut warnings = Vec::new();
+    let mut stages = Vec::new();
+    let t_s = input.suction_temp_c;
+    let air = input.air_load_kg_per_h;
+    let vapor = input.vapor_load_kg_per_h;
+
+    let intercondenser = match input.arrangement {
+        EjectorArrangement::SingleStage => {
+            stages.push(stage(p_s, p_d, t_s, air, vapor, motive, eta)?);
+            if p_d / p_s > MAX_STAGE_COMPRESSION_RATIO {
+                warnings.push(format!(
+                    "단일 단 압축비 {:.1}가 {MAX_STAGE_COMPRESSION_RATIO:.0}을 넘습니다. 2단 구성을 검토하세요.",
+                    p_d / p_s
+                ));
+            }
+            None
+        }
+        EjectorArrangement::TwoStage => {
+            let p_i = input
+                .interstage_pressure_bar_abs
+                .unwrap_or_else(|| (p_s * p_d).sqrt());
+            if !(p_i > p_s && p_i < p_d) {
+                return Err(EjectorError::InvalidInput(
+                    "중간 압력은 흡입 압력과 토출 압력 사이여야 합니다.",
+                ));
+            }
+            let first = stage(p_s, p_i, t_s, air, vapor, motive, eta)?;
+
+            // 중간 응축기: 1단 토출 수증기(구동 + 흡입)를 출구 온도까지 냉각/응축
+            let t_o = input.intercondenser_outlet_temp_c;
+            // 흡입 수증기는 분압이 낮아 과열 상태이나 저압에서 엔탈피는 온도만의 함수로 본다.
+            let p_sat_s = if97::saturation_pressure_bar_abs_from_temp_c(t_s).map_err(if97_err)?;
+            let (h_v_in, _, _) = if97::props_from_px(p_sat_s, 1.0).map_err(if97_err)?;
+            let vapor_in = first.motive_steam_kg_per_h + vapor;
+            let h_mix = (first.motive_steam_kg_per_h * motive.0 + vapor * h_v_in) / vapor_in;
+            let t_mix = if97::temperature_from_ph(p_i, h_mix).map_err(if97_err)?;
+            let p_v = if97::saturation_pressure_bar_abs_from_temp_c(t_o).map_err(if97_err)?;
+            let vapor_out = if p_v < p_i {
+                (air * MW_RATIO_VAPOR_AIR * p_v / (p_i - p_v)).min(vapor_in)
+            } else {
+                warnings.push(format!(
+                    "중간 응축기 출구 {t_o:.1} °C의 포화압력 {p_v:.3} bar가 중간 압력 {p_i:.3} bar 이상이라 응축되지 않습니다. 중간 압력을 높이거나 냉각수 온도를 낮추세요."
+                ));
+                vapor_in
+            };
+            let condensed = vapor_in - vapor_out;
+            let (h_v_out, _, _) = if97::props_from_px(p_v, 1.0).map_err(if97_err)?;
+            let (h_f_out, _, _) = if97::props_from_px(p_v, 0.0).map_err(if97_err)?;
+            let duty_w = (vapor_in * h_mix - vapor_out * h_v_out - condensed * h_f_out
+                + air * CP_AIR * (t_mix - t_o))
+                / 3600.0;
+
+            let second = stage(p_i, p_d, t_o, air, vapor_out, motive, eta)?;
+            for st in [&first, &second] {
+                if st.compression_ratio > MAX_STAGE_COMPRESSION_RATIO {
+                    warnings.push(format!(
+                        "단 압축비 {:.1}가 {MAX_STAGE_COMPRESSION_RATIO:.0}을 넘습니다. 중간 압력을 조정하세요.",
+                        st.compression_ratio
+                    ));
+                }
+            }
+            stages.push(first);
+            stages.push(second);
+            Some(IntercondenserResult {
+                pressure_bar_abs: p_i,
+                inlet_temp_c: t_mix,
+                outlet_temp_c: t_o,
+                condensed_kg_per_h: condensed,
+                vapor_carryover_kg_per_h: vapor_out,
+                duty_kw: duty_w / 1000.0,
+            })
+        }
+    };
+
+    Ok(EjectorResult {
+        total_motive_steam_kg_per_h: stages.iter().map(|s| s.motive_steam_kg_per_h).sum(),
+        stages,
+        intercondenser,
+        warnings,
+    })
+}
+
+/// 한 단의 구동 증기량: 공기 환산 부하 × 압축 일 / (효율 × 구동 증기 팽창 엔탈피)
+fn stage(
+    p_in: f64,
+    p_out: f64,
+    t_in_c: f64,
+    air_kg_h: f64,
+    vapor_kg_h: f64,
+    (h_m, s_m): (f64, f64),
+    eta: f64,
+) -> Result<EjectorStageResult, EjectorError> {
+    let (h_exp, _) = if97::props_from_ps(p_in, s_m).map_err(if97_err)?;
+    let dh_motive = h_m - h_exp;
+    if dh_motive <= 0.0 {
+        return Err(EjectorError::InvalidInput(
+            "구동 증기 팽창 엔탈피가 0 이하입니다.",
+        ));
+    }
+    let ratio = p_out / p_in;
+    let t_k = t_in_c + 273.15;
+    let w_comp = CP_AIR * t_k * (ratio.powf((K_AIR - 1.0) / K_AIR) - 1.0);
+    let air_eq = air_kg_h + vapor_kg_h / VAPOR_ENTRAINMENT_RATIO;
+    let motive = air_eq * w_comp / (eta * dh_motive);
+    Ok(EjectorStageResult {
+        suction_pressure_bar_abs: p_in,
+        discharge_pressure_bar_abs: p_out,
+        compression_ratio: ratio,
+        air_equivalent_kg_per_h: air_eq,
+        motive_steam_kg_per_h: motive,
+        entrainment_ratio: air_eq / motive,
+    })
+}
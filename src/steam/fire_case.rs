@@ -0,0 +1,260 @@
+//! API 521 외부 화재(fire case) 입열량과 분출량.
+//! 젖음 면적은 `vessel_geometry`의 용기 형상과 액위에서 구하며, 지면에서 7.6 m(25 ft) 위까지만
+//! 화염에 노출된다고 본다.
+//! 입열량은 Q = C·F·A^0.82 [W] (배수·소화 설비 양호 C = 43 200, 불량 C = 70 900),
+//! 분출량은 W = Q / λ 이고 잠열 λ는 분출 압력의 IF97 값 또는 직접 입력값을 쓴다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::steam::if97;
+pub use crate::vessel_geometry::VesselOrientation;
+use crate::vessel_geometry::{HeadType, Vessel, VesselGeometryError};
+use crate::warning::{CalcWarning, Severity};
+
+/// 배수가 양호할 때 화재 입열 계수 [W/m^1.64]
+pub const FIRE_COEFFICIENT_ADEQUATE_DRAINAGE: f64 = 43_200.0;
+/// 배수가 불량할 때 화재 입열 계수 [W/m^1.64]
+pub const FIRE_COEFFICIENT_INADEQUATE_DRAINAGE: f64 = 70_900.0;
+/// 화염에 노출되는 지면 위 높이 한계 [m] (API 521, 25 ft)
+pub const FIRE_ZONE_HEIGHT_M: f64 = 7.6;
+/// 이 압력 이상이면 잠열이 작아져 잠열 방법 대신 기상 가열 검토가 필요하다 [bar abs].
+const NEAR_CRITICAL_BAR_ABS: f64 = 180.0;
+
+/// 화재에 노출되는 원통 용기.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FireVessel {
+    pub orientation: VesselOrientation,
+    /// 내경 [m]
+    pub inner_diameter_m: f64,
+    /// 동체 길이(접선 간 거리) [m]
+    pub length_m: f64,
+    #[serde(default)]
+    pub heads: HeadType,
+    /// 용기 바닥에서 잰 액위 [m]
+    pub liquid_level_m: f64,
+    /// 용기 바닥의 지면 위 높이 [m]
+    #[serde(default)]
+    pub elevation_m: f64,
+}
+
+impl FireVessel {
+    /// 용기 형상
+    pub fn vessel(&self) -> Vessel {
+        Vessel {
+            orientation: self.orientation,
+            inner_diameter_m: self.inner_diameter_m,
+            length_m: self.length_m,
+            heads: self.heads,
+        }
+    }
+
+    /// 지면에서 7.6 m 이하에 있는 젖음 면적 [m²]
+    pub fn wetted_area_m2(&self) -> f64 {
+        let h = self
+            .liquid_level_m
+            .min(FIRE_ZONE_HEIGHT_M - self.elevation_m);
+        self.vessel().wetted_area_m2(h)
+    }
+
+    fn validate(&self) -> Result<(), FireCaseError> {
+        let vessel = self.vessel();
+        vessel
+            .validate()
+            .map_err(|VesselGeometryError::InvalidInput(msg)| FireCaseError::InvalidInput(msg))?;
+        if self.liquid_level_m < 0.0 || self.liquid_level_m > vessel.height_m() {
+            return Err(FireCaseError::InvalidInput(
+                "액위는 0 이상, 용기 높이 이하여야 합니다.",
+            ));
+        }
+        if self.elevation_m < 0.0 {
+            return Err(FireCaseError::InvalidInput(
+                "설치 높이는 음수일 수 없습니다.",
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 젖음 면적 입력 방법.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WettedSurface {
+    /// 젖음 면적 직접 입력 [m²]
+    Area(f64),
+    /// 용기 형상과 액위에서 계산
+    Vessel(FireVessel),
+}
+
+/// 화재 분출량 계산 입력.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FireCaseInput {
+    pub surface: WettedSurface,
+    /// 환경 계수 F (단열이 없으면 1.0)
+    pub environment_factor: f64,
+    /// 배수·소화 설비가 양호한지
+    pub adequate_drainage: bool,
+    /// 분출 압력 [bar abs]
+    pub relieving_pressure_bar_abs: f64,
+    /// 증발 잠열 [kJ/kg]. 없으면 분출 압력의 IF97 값.
+    pub latent_heat_kj_per_kg: Option<f64>,
+}
+
+/// 화재 분출량 계산 결과.
+#[derive(Debug, Clone)]
+pub struct FireCaseResult {
+    /// 화염에 노출된 젖음 면적 [m²]
+    pub wetted_area_m2: f64,
+    /// 입열량 [kW]
+    pub heat_input_kw: f64,
+    /// 증발 잠열 [kJ/kg]
+    pub latent_heat_kj_per_kg: f64,
+    /// 필요 분출량 [kg/h]
+    pub relief_rate_kg_per_h: f64,
+    pub warnings: Vec<FireCaseWarning>,
+}
+
+/// 화재 분출량 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FireCaseWarning {
+    /// 젖은 부분이 화염 높이 밖이라 젖음 면적 0
+    NoWettedAreaInFireZone,
+    /// 분출 압력이 임계압에 가까워 잠열이 작음
+    NearCritical {
+        pressure_bar_abs: f64,
+        latent_kj_per_kg: f64,
+    },
+}
+
+impl CalcWarning for FireCaseWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            FireCaseWarning::NoWettedAreaInFireZone => "warning.fire_case.no_wetted_area",
+            FireCaseWarning::NearCritical { .. } => "warning.fire_case.near_critical",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            FireCaseWarning::NoWettedAreaInFireZone => {
+                vec![("height", format!("{FIRE_ZONE_HEIGHT_M}"))]
+            }
+            FireCaseWarning::NearCritical {
+                pressure_bar_abs,
+                latent_kj_per_kg,
+            } => vec![
+                ("p", format!("{pressure_bar_abs:.0}")),
+                ("latent", format!("{latent_kj_per_kg:.0}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for FireCaseWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            FireCaseWarning::NoWettedAreaInFireZone => write!(
+                f,
+                "젖은 부분이 지면 위 {FIRE_ZONE_HEIGHT_M} m 화염 높이 밖에 있어 젖음 면적이 0입니다. 기상부 가열(비젖음 용기)을 따로 검토하세요."
+            ),
+            FireCaseWarning::NearCritical {
+                pressure_bar_abs,
+                latent_kj_per_kg,
+            } => write!(
+                f,
+                "분출 압력 {pressure_bar_abs:.0} bar(a)가 임계압에 가까워 잠열({latent_kj_per_kg:.0} kJ/kg)이 작습니다. 잠열 방법 대신 초임계 유체 가열 방법을 검토하세요."
+            ),
+        }
+    }
+}
+
+/// 화재 분출량 계산 오류.
+#[derive(Debug, Clone)]
+pub enum FireCaseError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for FireCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FireCaseError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            FireCaseError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FireCaseError {}
+
+/// API 521 화재 입열량 [W]. Q = C·F·A^0.82 (A: 젖음 면적 m²)
+pub fn fire_heat_input_w(
+    wetted_area_m2: f64,
+    environment_factor: f64,
+    adequate_drainage: bool,
+) -> f64 {
+    let c = if adequate_drainage {
+        FIRE_COEFFICIENT_ADEQUATE_DRAINAGE
+    } else {
+        FIRE_COEFFICIENT_INADEQUATE_DRAINAGE
+    };
+    c * environment_factor * wetted_area_m2.powf(0.82)
+}
+
+/// 화재 입열량과 필요 분출량을 계산한다.
+pub fn fire_case(input: &FireCaseInput) -> Result<FireCaseResult, FireCaseError> {
+    if !(input.environment_factor > 0.0 && input.environment_factor <= 1.0) {
+        return Err(FireCaseError::InvalidInput(
+            "환경 계수는 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if input.relieving_pressure_bar_abs <= 0.0 {
+        return Err(FireCaseError::InvalidInput(
+            "분출 압력은 0보다 커야 합니다.",
+        ));
+    }
+    let mut warnings = Vec::new();
+    let area = match input.surface {
+        WettedSurface::Area(a) if a <= 0.0 => {
+            return Err(FireCaseError::InvalidInput(
+                "젖음 면적은 0보다 커야 합니다.",
+            ));
+        }
+        WettedSurface::Area(a) => a,
+        WettedSurface::Vessel(vessel) => {
+            vessel.validate()?;
+            let area = vessel.wetted_area_m2();
+            if area <= 0.0 {
+                warnings.push(FireCaseWarning::NoWettedAreaInFireZone);
+            }
+            area
+        }
+    };
+    let p = input.relieving_pressure_bar_abs;
+    let latent = match input.latent_heat_kj_per_kg {
+        Some(l) if l <= 0.0 => {
+            return Err(FireCaseError::InvalidInput("잠열은 0보다 커야 합니다."));
+        }
+        Some(l) => l,
+        None => {
+            let (hf, hg) =
+                if97::saturation_enthalpies(p).map_err(|e| FireCaseError::If97(e.into()))?;
+            if p >= NEAR_CRITICAL_BAR_ABS {
+                warnings.push(FireCaseWarning::NearCritical {
+                    pressure_bar_abs: p,
+                    latent_kj_per_kg: (hg - hf) / 1000.0,
+                });
+            }
+            (hg - hf) / 1000.0
+        }
+    };
+    let q_w = fire_heat_input_w(area, input.environment_factor, input.adequate_drainage);
+    Ok(FireCaseResult {
+        wetted_area_m2: area,
+        heat_input_kw: q_w / 1000.0,
+        latent_heat_kj_per_kg: latent,
+        relief_rate_kg_per_h: q_w / 1000.0 / latent * 3600.0,
+        warnings,
+    })
+}
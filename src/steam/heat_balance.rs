@@ -0,0 +1,805 @@
+//! 단순 사이클 열정산도(heat balance diagram) 빌더.
+//! 보일러·재열기·터빈 구간·복수기·펌프·급수 가열기를 노드로, 그 사이 흐름을 스트림으로 두고
+//! 주증기 1 kg 기준 질량·에너지 수지를 풀어 스트림마다 유량·압력·온도·엔탈피를 돌려준다.
+//! 터빈 팽창과 펌프 일은 `cycle` 모듈과 같은 모델(구간 효율 일정, v·Δp/η)을 쓴다.
+//! 급수 가열기 규칙:
+//! - 밀폐형: 급수 출구 = 추기 포화온도 − TTD, 드레인은 포화수로 한 단 낮은 가열기(또는 탈기기·복수기)로 보낸다
+//! - 개방형(탈기기)은 1대까지 두고, 그 뒤에 급수 펌프를 둔다
+//!
+//! 드레인이 위에서 내려오므로 가열기는 압력이 높은 것부터 풀고,
+//! 탈기기 아래 저압 가열기는 탈기기 수지에서 나온 복수 유량으로 푼다.
+
+use crate::steam::cycle::{
+    expand, pump_work, CycleError, FeedwaterHeaterInput, FeedwaterHeaterKind, ReheatInput,
+};
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+use serde::{Deserialize, Serialize};
+
+/// 배기 건도가 이보다 낮으면 말단 날개 침식이 우려된다.
+const MIN_EXHAUST_QUALITY: f64 = 0.88;
+
+/// 노드 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Boiler,
+    Reheater,
+    /// 고압 터빈 (재열이 없으면 터빈 전체)
+    HpTurbine,
+    /// 재열 후 중·저압 터빈
+    LpTurbine,
+    Condenser,
+    CondensatePump,
+    FeedPump,
+    /// 밀폐형 급수 가열기
+    ClosedHeater,
+    /// 개방형 급수 가열기 (탈기기)
+    Deaerator,
+}
+
+impl NodeKind {
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            NodeKind::Boiler => "heat_balance.node.boiler",
+            NodeKind::Reheater => "heat_balance.node.reheater",
+            NodeKind::HpTurbine => "heat_balance.node.hp_turbine",
+            NodeKind::LpTurbine => "heat_balance.node.lp_turbine",
+            NodeKind::Condenser => "heat_balance.node.condenser",
+            NodeKind::CondensatePump => "heat_balance.node.condensate_pump",
+            NodeKind::FeedPump => "heat_balance.node.feed_pump",
+            NodeKind::ClosedHeater => "heat_balance.node.closed_heater",
+            NodeKind::Deaerator => "heat_balance.node.deaerator",
+        }
+    }
+}
+
+/// 열정산도의 노드.
+#[derive(Debug, Clone)]
+pub struct Node {
+    pub kind: NodeKind,
+    /// 짧은 표시 이름 (HP, LP, FWH1, DA 등)
+    pub label: String,
+    /// 노드 압력 [bar abs] (터빈은 입구, 가열기는 추기 압력)
+    pub pressure_bar_abs: f64,
+    /// 보일러·재열기는 가열량, 터빈은 출력, 펌프는 소요 동력, 복수기는 방열량, 가열기는 급수 가열량 [kW]
+    pub duty_kw: f64,
+}
+
+/// 스트림 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamKind {
+    MainSteam,
+    Extraction,
+    ColdReheat,
+    HotReheat,
+    Exhaust,
+    /// 가열기 드레인
+    Drain,
+    /// 복수기 → 탈기기 (탈기기가 없으면 복수 펌프 출구까지)
+    Condensate,
+    /// 급수 (보일러 방향)
+    Feedwater,
+}
+
+impl StreamKind {
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            StreamKind::MainSteam => "heat_balance.stream.main_steam",
+            StreamKind::Extraction => "heat_balance.stream.extraction",
+            StreamKind::ColdReheat => "heat_balance.stream.cold_reheat",
+            StreamKind::HotReheat => "heat_balance.stream.hot_reheat",
+            StreamKind::Exhaust => "heat_balance.stream.exhaust",
+            StreamKind::Drain => "heat_balance.stream.drain",
+            StreamKind::Condensate => "heat_balance.stream.condensate",
+            StreamKind::Feedwater => "heat_balance.stream.feedwater",
+        }
+    }
+
+    /// 언어팩이 없을 때 쓰는 이름.
+    pub fn label(self) -> &'static str {
+        match self {
+            StreamKind::MainSteam => "Main steam",
+            StreamKind::Extraction => "Extraction",
+            StreamKind::ColdReheat => "Cold reheat",
+            StreamKind::HotReheat => "Hot reheat",
+            StreamKind::Exhaust => "Exhaust",
+            StreamKind::Drain => "Drain",
+            StreamKind::Condensate => "Condensate",
+            StreamKind::Feedwater => "Feedwater",
+        }
+    }
+}
+
+/// 두 노드를 잇는 스트림. `from`/`to`는 `HeatBalanceResult::nodes` 색인.
+#[derive(Debug, Clone, Copy)]
+pub struct Stream {
+    pub kind: StreamKind,
+    pub from: usize,
+    pub to: usize,
+    pub mass_flow_kg_per_h: f64,
+    pub pressure_bar_abs: f64,
+    pub enthalpy_kj_per_kg: f64,
+    pub temp_c: f64,
+    /// 습증기 건도. 과열 증기·압축수면 `None`.
+    pub quality: Option<f64>,
+}
+
+/// 열정산 입력.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HeatBalanceInput {
+    /// 주증기 압력 [bar abs]
+    pub throttle_pressure_bar_abs: f64,
+    /// 주증기 온도 [°C]
+    pub throttle_temp_c: f64,
+    /// 복수기 압력 [bar abs]
+    pub condenser_pressure_bar_abs: f64,
+    /// 터빈 등엔트로피 효율 (0~1)
+    pub turbine_efficiency: f64,
+    /// 펌프 효율 (0~1)
+    pub pump_efficiency: f64,
+    pub reheat: Option<ReheatInput>,
+    /// 급수 가열기 (순서 무관, 개방형은 1대까지)
+    pub heaters: Vec<FeedwaterHeaterInput>,
+    /// 주증기 유량 [kg/h]
+    pub steam_flow_kg_per_h: f64,
+}
+
+/// 미리 짜 둔 열정산도 구성.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeatBalanceTemplate {
+    /// 재열·가열기 없는 단순 랭킨
+    Simple,
+    /// 재열 1단 + 탈기기
+    SingleReheat,
+    /// 재열 1단 + 고압 가열기·탈기기·저압 가열기
+    SingleReheatThreeHeaters,
+}
+
+impl HeatBalanceTemplate {
+    pub const ALL: [HeatBalanceTemplate; 3] = [
+        HeatBalanceTemplate::Simple,
+        HeatBalanceTemplate::SingleReheat,
+        HeatBalanceTemplate::SingleReheatThreeHeaters,
+    ];
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            HeatBalanceTemplate::Simple => "heat_balance.template.simple",
+            HeatBalanceTemplate::SingleReheat => "heat_balance.template.single_reheat",
+            HeatBalanceTemplate::SingleReheatThreeHeaters => {
+                "heat_balance.template.single_reheat_3fwh"
+            }
+        }
+    }
+
+    /// 언어팩이 없을 때 쓰는 이름.
+    pub fn label(self) -> &'static str {
+        match self {
+            HeatBalanceTemplate::Simple => "Simple Rankine",
+            HeatBalanceTemplate::SingleReheat => "Single reheat + deaerator",
+            HeatBalanceTemplate::SingleReheatThreeHeaters => "Single reheat, 3 FWH",
+        }
+    }
+
+    /// 100 bar(a)/540 °C, 복수기 0.08 bar(a), 100 t/h 기준 입력.
+    pub fn input(self) -> HeatBalanceInput {
+        let reheat = Some(ReheatInput {
+            pressure_bar_abs: 20.0,
+            temp_c: 540.0,
+        });
+        let closed = FeedwaterHeaterKind::Closed {
+            terminal_temp_difference_k: 3.0,
+        };
+        let (reheat, heaters) = match self {
+            HeatBalanceTemplate::Simple => (None, Vec::new()),
+            HeatBalanceTemplate::SingleReheat => (
+                reheat,
+                vec![FeedwaterHeaterInput {
+                    extraction_pressure_bar_abs: 6.0,
+                    kind: FeedwaterHeaterKind::Open,
+                }],
+            ),
+            HeatBalanceTemplate::SingleReheatThreeHeaters => (
+                reheat,
+                vec![
+                    FeedwaterHeaterInput {
+                        extraction_pressure_bar_abs: 30.0,
+                        kind: closed,
+                    },
+                    FeedwaterHeaterInput {
+                        extraction_pressure_bar_abs: 6.0,
+                        kind: FeedwaterHeaterKind::Open,
+                    },
+                    FeedwaterHeaterInput {
+                        extraction_pressure_bar_abs: 1.0,
+                        kind: closed,
+                    },
+                ],
+            ),
+        };
+        HeatBalanceInput {
+            throttle_pressure_bar_abs: 100.0,
+            throttle_temp_c: 540.0,
+            condenser_pressure_bar_abs: 0.08,
+            turbine_efficiency: 0.85,
+            pump_efficiency: 0.8,
+            reheat,
+            heaters,
+            steam_flow_kg_per_h: 100_000.0,
+        }
+    }
+}
+
+/// 열정산 결과.
+#[derive(Debug, Clone)]
+pub struct HeatBalanceResult {
+    pub nodes: Vec<Node>,
+    pub streams: Vec<Stream>,
+    pub turbine_power_kw: f64,
+    pub pump_power_kw: f64,
+    pub net_power_kw: f64,
+    /// 보일러 + 재열기 가열량 [kW]
+    pub heat_input_kw: f64,
+    pub condenser_duty_kw: f64,
+    pub thermal_efficiency: f64,
+    /// 열소비율 [kJ/kWh]
+    pub heat_rate_kj_per_kwh: f64,
+    pub exhaust_quality: f64,
+    /// 보일러 입구 급수 온도 [°C]
+    pub feedwater_temp_c: f64,
+    /// 가열량 + 펌프 동력 − 터빈 출력 − 복수기 방열 [kW]. 수지가 닫히면 0에 가깝다.
+    pub energy_residual_kw: f64,
+    pub warnings: Vec<HeatBalanceWarning>,
+}
+
+/// 열정산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum HeatBalanceWarning {
+    /// 배기 건도가 낮아 말단 날개 침식 우려
+    LowExhaustQuality { quality: f64 },
+    /// 추기 엔탈피가 포화수보다 낮음
+    ExtractionBelowSaturation { pressure_bar_abs: f64 },
+}
+
+impl CalcWarning for HeatBalanceWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            HeatBalanceWarning::LowExhaustQuality { .. } => {
+                "warning.heat_balance.low_exhaust_quality"
+            }
+            HeatBalanceWarning::ExtractionBelowSaturation { .. } => {
+                "warning.heat_balance.extraction_below_saturation"
+            }
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            HeatBalanceWarning::LowExhaustQuality { quality } => {
+                vec![("x", format!("{quality:.3}"))]
+            }
+            HeatBalanceWarning::ExtractionBelowSaturation { pressure_bar_abs } => {
+                vec![("p", format!("{pressure_bar_abs:.2}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for HeatBalanceWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            HeatBalanceWarning::LowExhaustQuality { quality } => write!(
+                f,
+                "배기 건도 {quality:.3}가 낮아 말단 날개 침식이 우려됩니다."
+            ),
+            HeatBalanceWarning::ExtractionBelowSaturation { pressure_bar_abs } => write!(
+                f,
+                "{pressure_bar_abs:.2} bar(a) 추기가 포화수보다 엔탈피가 낮습니다. 추기 압력을 확인하세요."
+            ),
+        }
+    }
+}
+
+fn if97_err(e: &str) -> CycleError {
+    CycleError::If97(e.to_string())
+}
+
+/// 압력·엔탈피 [J/kg]로 온도와 (습증기면) 건도를 구한다.
+fn state(p_bar_abs: f64, h: f64) -> Result<(f64, Option<f64>), CycleError> {
+    if let Ok((hf, hg)) = if97::saturation_enthalpies(p_bar_abs) {
+        if h > hf && h < hg {
+            let t_sat =
+                if97::saturation_temp_c_from_pressure_bar_abs(p_bar_abs).map_err(if97_err)?;
+            return Ok((t_sat, Some((h - hf) / (hg - hf))));
+        }
+    }
+    Ok((
+        if97::temperature_from_ph(p_bar_abs, h).map_err(if97_err)?,
+        None,
+    ))
+}
+
+/// 풀이 중의 가열기 한 대 (압력 내림차순으로 정렬해 둔다).
+struct HeaterState {
+    pressure: f64,
+    kind: FeedwaterHeaterKind,
+    /// 추기 엔탈피 [J/kg]
+    h_ext: f64,
+    /// 추기 압력 포화수 엔탈피 (드레인·탈기기 출구) [J/kg]
+    hf: f64,
+    /// 급수 입구·출구 엔탈피 [J/kg]
+    h_in: f64,
+    h_out: f64,
+    /// 급수 배관 압력 [bar abs]
+    line_pressure: f64,
+    /// 주증기 1 kg당 추기량
+    y: f64,
+    /// 주증기 1 kg당 이 가열기를 지나는 급수량
+    m_fw: f64,
+    /// 주증기 1 kg당 이 가열기를 나가는 드레인량 (위에서 내려온 것 포함)
+    drain_out: f64,
+    node: usize,
+}
+
+/// 밀폐형 가열기 묶음(압력 내림차순)을 급수량 `m_fw`로 푼다. 반환: 맨 아래 드레인 (유량, 엔탈피).
+fn solve_closed_group(heaters: &mut [HeaterState], m_fw: f64) -> Result<(f64, f64), CycleError> {
+    let (mut drain, mut h_drain) = (0.0, 0.0);
+    for h in heaters.iter_mut() {
+        let y = (m_fw * (h.h_out - h.h_in) - drain * (h_drain - h.hf)) / (h.h_ext - h.hf);
+        if !(0.0..1.0).contains(&y) {
+            return Err(CycleError::InvalidInput(
+                "추기 분율이 0~1 범위를 벗어납니다. 가열기 압력과 TTD를 확인하세요.",
+            ));
+        }
+        h.y = y;
+        h.m_fw = m_fw;
+        drain += y;
+        h.drain_out = drain;
+        h_drain = h.hf;
+    }
+    Ok((drain, h_drain))
+}
+
+/// 템플릿 구성의 열정산을 풀고 노드·스트림 표를 만든다.
+pub fn solve_heat_balance(input: &HeatBalanceInput) -> Result<HeatBalanceResult, CycleError> {
+    let p1 = input.throttle_pressure_bar_abs;
+    let p_c = input.condenser_pressure_bar_abs;
+    if p_c <= 0.0 || p1 <= p_c {
+        return Err(CycleError::InvalidInput(
+            "복수기 압력은 0보다 크고 주증기 압력보다 낮아야 합니다.",
+        ));
+    }
+    let valid_efficiency = |eta: f64| eta > 0.0 && eta <= 1.0;
+    if !valid_efficiency(input.turbine_efficiency) || !valid_efficiency(input.pump_efficiency) {
+        return Err(CycleError::InvalidInput(
+            "터빈/펌프 효율은 0 초과 1 이하여야 합니다.",
+        ));
+    }
+    if input.steam_flow_kg_per_h <= 0.0 {
+        return Err(CycleError::InvalidInput("주증기 유량은 0보다 커야 합니다."));
+    }
+    if let Some(rh) = input.reheat {
+        if rh.pressure_bar_abs <= p_c || rh.pressure_bar_abs >= p1 {
+            return Err(CycleError::InvalidInput(
+                "재열 압력은 복수기 압력과 주증기 압력 사이여야 합니다.",
+            ));
+        }
+    }
+    let mut specs = input.heaters.clone();
+    specs.sort_by(|a, b| {
+        b.extraction_pressure_bar_abs
+            .total_cmp(&a.extraction_pressure_bar_abs)
+    });
+    for (i, fwh) in specs.iter().enumerate() {
+        let p = fwh.extraction_pressure_bar_abs;
+        if p <= p_c || p >= p1 {
+            return Err(CycleError::InvalidInput(
+                "추기 압력은 복수기 압력과 주증기 압력 사이여야 합니다.",
+            ));
+        }
+        if i > 0 && specs[i - 1].extraction_pressure_bar_abs == p {
+            return Err(CycleError::InvalidInput(
+                "급수 가열기 추기 압력이 겹칩니다.",
+            ));
+        }
+        if matches!(fwh.kind, FeedwaterHeaterKind::Closed { terminal_temp_difference_k: ttd } if ttd < 0.0)
+        {
+            return Err(CycleError::InvalidInput("TTD는 0 이상이어야 합니다."));
+        }
+    }
+    let da_pos = specs
+        .iter()
+        .position(|h| matches!(h.kind, FeedwaterHeaterKind::Open));
+    if specs
+        .iter()
+        .filter(|h| matches!(h.kind, FeedwaterHeaterKind::Open))
+        .count()
+        > 1
+    {
+        return Err(CycleError::InvalidInput(
+            "개방형 급수 가열기(탈기기)는 1대까지 둘 수 있습니다.",
+        ));
+    }
+    let eta_t = input.turbine_efficiency;
+    let eta_p = input.pump_efficiency;
+    let mut warnings = Vec::new();
+
+    // 터빈 팽창선
+    let t_sat1 = if97::saturation_temp_c_from_pressure_bar_abs(p1).map_err(if97_err)?;
+    if input.throttle_temp_c <= t_sat1 {
+        return Err(CycleError::InvalidInput(
+            "주증기 온도는 해당 압력의 포화온도보다 높아야 합니다.",
+        ));
+    }
+    let (h1, _, s1) = if97::region_props(p1, input.throttle_temp_c).map_err(if97_err)?;
+    let hp_end_p = input.reheat.map_or(p_c, |rh| rh.pressure_bar_abs);
+    let h_hp_end = expand(h1, s1, hp_end_p, eta_t)?;
+    let reheat_state = match input.reheat {
+        Some(rh) => {
+            let (h_rh, _, s_rh) =
+                if97::region_props(rh.pressure_bar_abs, rh.temp_c).map_err(if97_err)?;
+            if h_rh <= h_hp_end {
+                return Err(CycleError::InvalidInput(
+                    "재열 온도가 고압 터빈 배기 온도보다 낮습니다.",
+                ));
+            }
+            Some((h_rh, s_rh))
+        }
+        None => None,
+    };
+    let in_hp = |p: f64| reheat_state.is_none() || p >= hp_end_p;
+    let h_exh = match reheat_state {
+        Some((h_rh, s_rh)) => expand(h_rh, s_rh, p_c, eta_t)?,
+        None => h_hp_end,
+    };
+
+    // 복수기·펌프
+    let (hf_c, v_c, _) = if97::props_from_px(p_c, 0.0).map_err(if97_err)?;
+    let (_, hg_c) = if97::saturation_enthalpies(p_c).map_err(if97_err)?;
+    let exhaust_quality = (h_exh - hf_c) / (hg_c - hf_c);
+    let p_da = da_pos.map(|i| specs[i].extraction_pressure_bar_abs);
+    let w_cp = pump_work(v_c, p_c, p_da.unwrap_or(p1), eta_p);
+    let h_cp_out = hf_c + w_cp;
+
+    // 노드: 보일러, 터빈, (재열기, 저압 터빈), 복수기, 복수 펌프, (급수 펌프), 가열기(저압부터)
+    let mut nodes = vec![
+        Node {
+            kind: NodeKind::Boiler,
+            label: "Boiler".into(),
+            pressure_bar_abs: p1,
+            duty_kw: 0.0,
+        },
+        Node {
+            kind: NodeKind::HpTurbine,
+            label: if reheat_state.is_some() {
+                "HP"
+            } else {
+                "Turbine"
+            }
+            .into(),
+            pressure_bar_abs: p1,
+            duty_kw: 0.0,
+        },
+    ];
+    let (reheater, lp) = match input.reheat {
+        Some(rh) => {
+            nodes.push(Node {
+                kind: NodeKind::Reheater,
+                label: "Reheater".into(),
+                pressure_bar_abs: rh.pressure_bar_abs,
+                duty_kw: 0.0,
+            });
+            nodes.push(Node {
+                kind: NodeKind::LpTurbine,
+                label: "LP".into(),
+                pressure_bar_abs: rh.pressure_bar_abs,
+                duty_kw: 0.0,
+            });
+            (Some(2), 3)
+        }
+        None => (None, 1),
+    };
+    let (boiler, hp) = (0, 1);
+    let condenser = nodes.len();
+    nodes.push(Node {
+        kind: NodeKind::Condenser,
+        label: "Condenser".into(),
+        pressure_bar_abs: p_c,
+        duty_kw: 0.0,
+    });
+    let cond_pump = nodes.len();
+    nodes.push(Node {
+        kind: NodeKind::CondensatePump,
+        label: "CP".into(),
+        pressure_bar_abs: p_da.unwrap_or(p1),
+        duty_kw: 0.0,
+    });
+    let feed_pump = p_da.map(|_| {
+        nodes.push(Node {
+            kind: NodeKind::FeedPump,
+            label: "FP".into(),
+            pressure_bar_abs: p1,
+            duty_kw: 0.0,
+        });
+        nodes.len() - 1
+    });
+
+    // 가열기 상태 (압력 내림차순). 노드는 저압부터 FWH1, FWH2 … (탈기기는 DA)
+    let first_heater_node = nodes.len();
+    let n = specs.len();
+    let mut closed_no = specs
+        .iter()
+        .filter(|h| !matches!(h.kind, FeedwaterHeaterKind::Open))
+        .count();
+    let mut heaters = Vec::with_capacity(n);
+    for (i, fwh) in specs.iter().enumerate() {
+        let p = fwh.extraction_pressure_bar_abs;
+        let h_ext = match reheat_state {
+            Some((h_rh, s_rh)) if !in_hp(p) => expand(h_rh, s_rh, p, eta_t)?,
+            _ => expand(h1, s1, p, eta_t)?,
+        };
+        let (hf, _, _) = if97::props_from_px(p, 0.0).map_err(if97_err)?;
+        let line_pressure = match da_pos {
+            Some(d) if i >= d => p_da.unwrap_or(p1),
+            _ => p1,
+        };
+        let label = match fwh.kind {
+            FeedwaterHeaterKind::Open => "DA".to_string(),
+            FeedwaterHeaterKind::Closed { .. } => {
+                let label = format!("FWH{closed_no}");
+                closed_no -= 1;
+                label
+            }
+        };
+        heaters.push((
+            HeaterState {
+                pressure: p,
+                kind: fwh.kind,
+                h_ext,
+                hf,
+                h_in: 0.0,
+                h_out: 0.0,
+                line_pressure,
+                y: 0.0,
+                m_fw: 0.0,
+                drain_out: 0.0,
+                node: first_heater_node + (n - 1 - i),
+            },
+            label,
+        ));
+    }
+    // 저압부터 노드를 붙인다.
+    for (h, label) in heaters.iter().rev() {
+        nodes.push(Node {
+            kind: match h.kind {
+                FeedwaterHeaterKind::Open => NodeKind::Deaerator,
+                FeedwaterHeaterKind::Closed { .. } => NodeKind::ClosedHeater,
+            },
+            label: label.clone(),
+            pressure_bar_abs: h.pressure,
+            duty_kw: 0.0,
+        });
+    }
+    let mut heaters: Vec<HeaterState> = heaters.into_iter().map(|(h, _)| h).collect();
+
+    // 급수 경로 (저압 → 고압) 엔탈피
+    let h_fp_out = match (da_pos, p_da) {
+        (Some(d), Some(p_da)) => {
+            let (_, v_da, _) = if97::props_from_px(p_da, 0.0).map_err(if97_err)?;
+            Some(heaters[d].hf + pump_work(v_da, p_da, p1, eta_p))
+        }
+        _ => None,
+    };
+    let mut h_line = h_cp_out;
+    for i in (0..n).rev() {
+        if Some(i + 1) == da_pos {
+            h_line = h_fp_out.unwrap_or(h_line);
+        }
+        let h = &mut heaters[i];
+        h.h_in = h_line;
+        h.h_out = match h.kind {
+            FeedwaterHeaterKind::Open => h.hf,
+            FeedwaterHeaterKind::Closed {
+                terminal_temp_difference_k: ttd,
+            } => {
+                let t_sat =
+                    if97::saturation_temp_c_from_pressure_bar_abs(h.pressure).map_err(if97_err)?;
+                if97::region_props(h.line_pressure, t_sat - ttd)
+                    .map_err(if97_err)?
+                    .0
+            }
+        };
+        if h.h_out <= h.h_in {
+            return Err(CycleError::InvalidInput(
+                "급수가 가열되지 않는 가열기가 있습니다. 추기 압력 순서와 TTD를 확인하세요.",
+            ));
+        }
+        h_line = h.h_out;
+    }
+    // 탈기기가 맨 위면 급수 펌프 출구가 곧 보일러 입구다.
+    let h_fw = if da_pos == Some(0) {
+        h_fp_out.unwrap_or(h_line)
+    } else {
+        h_line
+    };
+
+    // 추기량: 탈기기 위 → 탈기기 → 탈기기 아래
+    let (m_cond, drain_to_condenser) = match da_pos {
+        Some(d) => {
+            let (d_hp, h_d_hp) = solve_closed_group(&mut heaters[..d], 1.0)?;
+            let h_c_in = heaters[d].h_in;
+            let da = &mut heaters[d];
+            let y = (da.hf - d_hp * h_d_hp - (1.0 - d_hp) * h_c_in) / (da.h_ext - h_c_in);
+            if !(0.0..1.0).contains(&y) {
+                return Err(CycleError::InvalidInput(
+                    "탈기기 추기 분율이 0~1 범위를 벗어납니다. 탈기기 압력을 확인하세요.",
+                ));
+            }
+            let m_c = 1.0 - d_hp - y;
+            da.y = y;
+            da.m_fw = m_c;
+            let lp_drain = solve_closed_group(&mut heaters[d + 1..], m_c)?;
+            (m_c, lp_drain)
+        }
+        None => (1.0, solve_closed_group(&mut heaters, 1.0)?),
+    };
+    let y_total: f64 = heaters.iter().map(|h| h.y).sum();
+    let exhaust = 1.0 - y_total;
+    if exhaust <= 0.0 {
+        return Err(CycleError::InvalidInput(
+            "추기 합이 주증기 유량 이상입니다.",
+        ));
+    }
+
+    // 터빈 구간 일 (추기점마다 통과 유량 감소)
+    let mut flow = 1.0;
+    let mut h_prev = h1;
+    let mut hp_work = 0.0;
+    for h in heaters.iter().filter(|h| in_hp(h.pressure)) {
+        hp_work += flow * (h_prev - h.h_ext);
+        flow -= h.y;
+        h_prev = h.h_ext;
+    }
+    hp_work += flow * (h_prev - h_hp_end);
+    let m_rh = flow;
+    let (mut lp_work, mut reheat_heat) = (0.0, 0.0);
+    if let Some((h_rh, _)) = reheat_state {
+        reheat_heat = m_rh * (h_rh - h_hp_end);
+        h_prev = h_rh;
+        for h in heaters.iter().filter(|h| !in_hp(h.pressure)) {
+            lp_work += flow * (h_prev - h.h_ext);
+            flow -= h.y;
+            h_prev = h.h_ext;
+        }
+        lp_work += flow * (h_prev - h_exh);
+    }
+    let w_fp = h_fp_out.zip(da_pos).map_or(0.0, |(h, d)| h - heaters[d].hf);
+    let pump_work_total = m_cond * w_cp + w_fp;
+    let boiler_heat = h1 - h_fw;
+    let heat_input = boiler_heat + reheat_heat;
+    let turbine_work = hp_work + lp_work;
+    let net_work = turbine_work - pump_work_total;
+    if net_work <= 0.0 {
+        return Err(CycleError::InvalidInput("순일이 0 이하입니다."));
+    }
+    let (d_cond, h_d_cond) = drain_to_condenser;
+    let condenser_heat = exhaust * h_exh + d_cond * h_d_cond - m_cond * hf_c;
+
+    // 유량 환산 [kg/h], 일·열 [J/kg] → [kW]
+    let m = input.steam_flow_kg_per_h;
+    let kw = |e: f64| m * e / 3.6e6;
+    nodes[boiler].duty_kw = kw(boiler_heat);
+    nodes[hp].duty_kw = kw(hp_work);
+    if let Some(r) = reheater {
+        nodes[r].duty_kw = kw(reheat_heat);
+        nodes[lp].duty_kw = kw(lp_work);
+    }
+    nodes[condenser].duty_kw = kw(condenser_heat);
+    nodes[cond_pump].duty_kw = kw(m_cond * w_cp);
+    if let Some(fp) = feed_pump {
+        nodes[fp].duty_kw = kw(w_fp);
+    }
+    for h in &heaters {
+        nodes[h.node].duty_kw = kw(h.m_fw * (h.h_out - h.h_in));
+    }
+
+    let mut streams = Vec::new();
+    let mut push = |kind, from, to, flow: f64, p: f64, h: f64| -> Result<(), CycleError> {
+        let (temp_c, quality) = state(p, h)?;
+        streams.push(Stream {
+            kind,
+            from,
+            to,
+            mass_flow_kg_per_h: flow * m,
+            pressure_bar_abs: p,
+            enthalpy_kj_per_kg: h / 1000.0,
+            temp_c,
+            quality,
+        });
+        Ok(())
+    };
+    push(StreamKind::MainSteam, boiler, hp, 1.0, p1, h1)?;
+    for h in &heaters {
+        let turbine = if in_hp(h.pressure) { hp } else { lp };
+        push(
+            StreamKind::Extraction,
+            turbine,
+            h.node,
+            h.y,
+            h.pressure,
+            h.h_ext,
+        )?;
+    }
+    if let (Some(r), Some((h_rh, _))) = (reheater, reheat_state) {
+        push(StreamKind::ColdReheat, hp, r, m_rh, hp_end_p, h_hp_end)?;
+        push(StreamKind::HotReheat, r, lp, m_rh, hp_end_p, h_rh)?;
+    }
+    push(StreamKind::Exhaust, lp, condenser, exhaust, p_c, h_exh)?;
+    for (i, h) in heaters.iter().enumerate() {
+        if matches!(h.kind, FeedwaterHeaterKind::Open) {
+            continue;
+        }
+        // 드레인은 한 단 낮은 가열기로, 맨 아래(또는 탈기기 아래 묶음의 맨 아래)는 복수기로 간다.
+        let to = heaters.get(i + 1).map_or(condenser, |next| next.node);
+        push(StreamKind::Drain, h.node, to, h.drain_out, h.pressure, h.hf)?;
+    }
+    push(
+        StreamKind::Condensate,
+        condenser,
+        cond_pump,
+        m_cond,
+        p_c,
+        hf_c,
+    )?;
+    // 급수 경로: 복수 펌프 → 저압 가열기 … → 탈기기 → 급수 펌프 → 고압 가열기 … → 보일러
+    let mut from = cond_pump;
+    let mut kind = StreamKind::Condensate;
+    let (mut flow, mut p_line, mut h_line) = (m_cond, p_da.unwrap_or(p1), h_cp_out);
+    for i in (0..n).rev() {
+        let h = &heaters[i];
+        push(kind, from, h.node, flow, p_line, h_line)?;
+        from = h.node;
+        (p_line, h_line) = (h.line_pressure, h.h_out);
+        if let (FeedwaterHeaterKind::Open, Some(fp)) = (h.kind, feed_pump) {
+            push(StreamKind::Feedwater, h.node, fp, 1.0, p_line, h_line)?;
+            from = fp;
+            (flow, p_line, h_line) = (1.0, p1, h_fp_out.unwrap_or(h_line));
+        }
+        kind = StreamKind::Feedwater;
+    }
+    push(StreamKind::Feedwater, from, boiler, 1.0, p1, h_fw)?;
+
+    if exhaust_quality < MIN_EXHAUST_QUALITY {
+        warnings.push(HeatBalanceWarning::LowExhaustQuality {
+            quality: exhaust_quality,
+        });
+    }
+    for h in heaters.iter().filter(|h| h.h_ext < h.hf) {
+        warnings.push(HeatBalanceWarning::ExtractionBelowSaturation {
+            pressure_bar_abs: h.pressure,
+        });
+    }
+
+    let efficiency = net_work / heat_input;
+    let feedwater_temp_c = if97::temperature_from_ph(p1, h_fw).map_err(if97_err)?;
+    Ok(HeatBalanceResult {
+        nodes,
+        streams,
+        turbine_power_kw: kw(turbine_work),
+        pump_power_kw: kw(pump_work_total),
+        net_power_kw: kw(net_work),
+        heat_input_kw: kw(heat_input),
+        condenser_duty_kw: kw(condenser_heat),
+        thermal_efficiency: efficiency,
+        heat_rate_kj_per_kwh: 3600.0 / efficiency,
+        exhaust_quality,
+        feedwater_temp_c,
+        energy_residual_kw: kw(heat_input + pump_work_total - turbine_work - condenser_heat),
+        warnings,
+    })
+}
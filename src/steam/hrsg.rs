@@ -0,0 +1,197 @@
+//! 단압(single-pressure) 배열회수보일러(HRSG) 핀치/어프로치 간이 계산.
+//! 가스터빈 배기(유량, 온도, 평균 cp)가 과열기 → 증발기 → 절탄기 순으로 흐른다고 보고,
+//! 증발기 출구 가스 온도 = 포화온도 + 핀치, 절탄기 출구 수온 = 포화온도 − 어프로치로 놓아
+//! 핀치 위쪽 열수지에서 증기 발생량을, 절탄기 열수지에서 연돌 온도를 구한다.
+//! 블로다운과 케이싱 열손실은 무시한다.
+
+use crate::combustion::dew_point::{self, FlueGasDewPoints};
+use crate::steam::if97;
+
+/// 열회수율 기준 대기 온도 [°C] (ISO 조건)
+const REFERENCE_AMBIENT_C: f64 = 15.0;
+/// 이보다 작은 핀치는 전열면적이 비현실적으로 커진다 [K]
+const MIN_PRACTICAL_PINCH_K: f64 = 5.0;
+/// 과열기 고온단 온도차(배기 입구 − 과열 증기)가 이보다 작으면 경고 [K]
+const MIN_SUPERHEATER_APPROACH_K: f64 = 20.0;
+/// 노점을 주지 않았을 때, 이보다 낮은 연돌 온도는 황 함유 연료에서 산노점 부식 우려 [°C]
+const MIN_STACK_TEMP_C: f64 = 90.0;
+
+/// HRSG 입력.
+#[derive(Debug, Clone)]
+pub struct HrsgInput {
+    /// 가스터빈 배기 유량 [kg/s]
+    pub gas_flow_kg_per_s: f64,
+    /// 배기 입구 온도 [°C]
+    pub gas_inlet_temp_c: f64,
+    /// 배기 평균 정압비열 [kJ/kgK] (천연가스 연소 배기 약 1.10)
+    pub gas_cp_kj_per_kgk: f64,
+    /// 드럼(증기) 압력 [bar abs]
+    pub steam_pressure_bar_abs: f64,
+    /// 과열기 출구 증기 온도 [°C]. `None`이면 포화 증기(과열기 없음).
+    pub steam_temp_c: Option<f64>,
+    /// 절탄기 입구 급수 온도 [°C]
+    pub feedwater_temp_c: f64,
+    /// 핀치: 증발기 출구 가스 온도 − 포화온도 [K]
+    pub pinch_k: f64,
+    /// 어프로치: 포화온도 − 절탄기 출구 수온 [K]
+    pub approach_k: f64,
+    /// 배기 노점. 있으면 연돌 온도를 수분/황산 노점과 비교한다.
+    pub flue_gas_dew_points: Option<FlueGasDewPoints>,
+}
+
+/// HRSG 결과.
+#[derive(Debug, Clone)]
+pub struct HrsgResult {
+    pub saturation_temp_c: f64,
+    /// 증기 발생량 [kg/h]
+    pub steam_flow_kg_per_h: f64,
+    /// 과열기/증발기/절탄기 열량 [kW]
+    pub superheater_duty_kw: f64,
+    pub evaporator_duty_kw: f64,
+    pub economizer_duty_kw: f64,
+    pub total_duty_kw: f64,
+    /// 과열기 출구(증발기 입구) 가스 온도 [°C]
+    pub gas_temp_after_superheater_c: f64,
+    /// 핀치점(증발기 출구) 가스 온도 [°C]
+    pub gas_temp_at_pinch_c: f64,
+    /// 절탄기 출구 수온 [°C]
+    pub economizer_outlet_temp_c: f64,
+    /// 연돌(절탄기 출구) 가스 온도 [°C]
+    pub stack_temp_c: f64,
+    /// 열회수율: 회수 열량 / 15 °C까지 냉각 시 배기 열량
+    pub heat_recovery_fraction: f64,
+    pub warnings: Vec<String>,
+}
+
+/// HRSG 계산 오류.
+#[derive(Debug, Clone)]
+pub enum HrsgError {
+    InvalidInput(&'static str),
+    /// 온도 교차: 연돌 가스 온도 [°C]가 급수 온도 이하
+    TemperatureCross(f64),
+    If97(String),
+}
+
+impl std::fmt::Display for HrsgError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HrsgError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            HrsgError::TemperatureCross(t) => write!(
+                f,
+                "절탄기 온도 교차: 연돌 가스 {t:.1} °C가 급수 온도 이하입니다. 핀치를 키우거나 급수 온도를 낮추세요."
+            ),
+            HrsgError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for HrsgError {}
+
+fn if97_err(e: &str) -> HrsgError {
+    HrsgError::If97(e.to_string())
+}
+
+/// 핀치/어프로치 조건에서 단압 HRSG의 증기 발생량과 연돌 온도를 계산한다.
+pub fn compute_hrsg(input: HrsgInput) -> Result<HrsgResult, HrsgError> {
+    if input.gas_flow_kg_per_s <= 0.0 || input.gas_cp_kj_per_kgk <= 0.0 {
+        return Err(HrsgError::InvalidInput(
+            "배기 유량과 비열은 0보다 커야 합니다.",
+        ));
+    }
+    if input.steam_pressure_bar_abs <= 0.0 {
+        return Err(HrsgError::InvalidInput("증기 압력은 0보다 커야 합니다."));
+    }
+    if input.pinch_k <= 0.0 || input.approach_k < 0.0 {
+        return Err(HrsgError::InvalidInput(
+            "핀치는 0보다 크고 어프로치는 0 이상이어야 합니다.",
+        ));
+    }
+    let p = input.steam_pressure_bar_abs;
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p).map_err(if97_err)?;
+    if input.feedwater_temp_c >= t_sat - input.approach_k {
+        return Err(HrsgError::InvalidInput(
+            "급수 온도는 절탄기 출구 수온(포화온도 − 어프로치)보다 낮아야 합니다.",
+        ));
+    }
+    let t_pinch_gas = t_sat + input.pinch_k;
+    if input.gas_inlet_temp_c <= t_pinch_gas {
+        return Err(HrsgError::InvalidInput(
+            "배기 입구 온도가 포화온도 + 핀치보다 높아야 증기를 만들 수 있습니다.",
+        ));
+    }
+    let (_, h_g) = if97::saturation_enthalpies(p).map_err(if97_err)?;
+    let h_steam = match input.steam_temp_c {
+        Some(t) if t > t_sat => if97::region_props(p, t).map_err(if97_err)?.0,
+        Some(_) => {
+            return Err(HrsgError::InvalidInput(
+                "과열 증기 온도는 포화온도보다 높아야 합니다.",
+            ))
+        }
+        None => h_g,
+    };
+    let t_econ_out = t_sat - input.approach_k;
+    let (h_econ_out, _, _) = if97::region1_props(p, t_econ_out).map_err(if97_err)?;
+    let (h_fw, _, _) = if97::region1_props(p, input.feedwater_temp_c).map_err(if97_err)?;
+
+    // kJ/kg 기준으로 환산해 열수지를 세운다.
+    let gas_capacity_kw_per_k = input.gas_flow_kg_per_s * input.gas_cp_kj_per_kgk;
+    let q_above_pinch = gas_capacity_kw_per_k * (input.gas_inlet_temp_c - t_pinch_gas);
+    let steam_kg_s = q_above_pinch / ((h_steam - h_econ_out) / 1000.0);
+    let q_sh = steam_kg_s * (h_steam - h_g) / 1000.0;
+    let q_evap = q_above_pinch - q_sh;
+    let q_econ = steam_kg_s * (h_econ_out - h_fw) / 1000.0;
+    let t_after_sh = input.gas_inlet_temp_c - q_sh / gas_capacity_kw_per_k;
+    let t_stack = t_pinch_gas - q_econ / gas_capacity_kw_per_k;
+    if t_stack <= input.feedwater_temp_c {
+        return Err(HrsgError::TemperatureCross(t_stack));
+    }
+    let total = q_above_pinch + q_econ;
+    let available = gas_capacity_kw_per_k * (input.gas_inlet_temp_c - REFERENCE_AMBIENT_C);
+
+    let mut warnings = Vec::new();
+    if input.pinch_k < MIN_PRACTICAL_PINCH_K {
+        warnings.push(format!(
+            "핀치 {:.1} K는 {MIN_PRACTICAL_PINCH_K} K 미만이라 증발기 면적이 과대해집니다.",
+            input.pinch_k
+        ));
+    }
+    if input.approach_k < MIN_PRACTICAL_PINCH_K {
+        warnings.push(
+            "어프로치가 작아 부분 부하에서 절탄기 내 증기 발생(스티밍) 우려가 있습니다.".into(),
+        );
+    }
+    if let Some(t) = input.steam_temp_c {
+        if input.gas_inlet_temp_c - t < MIN_SUPERHEATER_APPROACH_K {
+            warnings.push(format!(
+                "과열기 고온단 온도차 {:.1} K가 {MIN_SUPERHEATER_APPROACH_K} K 미만입니다.",
+                input.gas_inlet_temp_c - t
+            ));
+        }
+    }
+    match &input.flue_gas_dew_points {
+        Some(dp) => warnings.extend(
+            dew_point::stack_dew_point_warnings(t_stack, dp)
+                .iter()
+                .map(ToString::to_string),
+        ),
+        None if t_stack < MIN_STACK_TEMP_C => warnings.push(format!(
+            "연돌 온도 {t_stack:.0} °C: 황 함유 연료면 산노점 부식을 검토하세요."
+        )),
+        None => {}
+    }
+
+    Ok(HrsgResult {
+        saturation_temp_c: t_sat,
+        steam_flow_kg_per_h: steam_kg_s * 3600.0,
+        superheater_duty_kw: q_sh,
+        evaporator_duty_kw: q_evap,
+        economizer_duty_kw: q_econ,
+        total_duty_kw: total,
+        gas_temp_after_superheater_c: t_after_sh,
+        gas_temp_at_pinch_c: t_pinch_gas,
+        economizer_outlet_temp_c: t_econ_out,
+        stack_temp_c: t_stack,
+        heat_recovery_fraction: total / available,
+        warnings,
+    })
+}
@@ -2,18 +2,27 @@
 //! 입력: 압력(bar, 절대), 온도(°C)
 //! 출력: (엔탈피[J/kg], 비체적[m³/kg], 엔트로피[J/kg·K])
 
-use seuif97::{pt, OH, OS, OV};
+use crate::parallel;
+use seuif97::{ph, ps, pt, px, tv, OCP, ODV, OH, OP, OS, OT, OU, OV, OW};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::OnceLock;
 
 // ---------------- Region 4 (포화) ----------------
-const P4_STAR_MPA: f64 = 22.064;
+/// 임계 온도 [K]
 const T4_STAR_K: f64 = 647.096;
-const R4_N: [f64; 6] = [
-    -7.859_517_83,
-    1.844_082_59,
-    -11.786_649_7,
-    22.680_741_1,
-    -15.961_871_9,
-    1.801_225_02,
+/// IF97 포화선 식(식 30/31) 계수 n1~n10
+const R4_N: [f64; 10] = [
+    0.116_705_214_527_67e4,
+    -0.724_213_167_032_06e6,
+    -0.170_738_469_400_92e2,
+    0.120_208_247_024_70e5,
+    -0.323_255_503_223_33e7,
+    0.149_151_086_135_30e2,
+    -0.482_326_573_615_91e4,
+    0.405_113_405_420_57e6,
+    -0.238_555_575_678_49,
+    0.650_175_348_447_98e3,
 ];
 
 fn nan_err() -> Result<(f64, f64, f64), &'static str> {
@@ -69,7 +78,25 @@ pub fn region5_props(p_bar_abs: f64, t_c: f64) -> Result<(f64, f64, f64), &'stat
 }
 
 /// 온도(°C)·압력(bar abs)에 따라 자동 영역을 판정해 h/v/s를 반환한다.
+/// 같은 (p, T) 반복 호출은 스레드별 LRU 캐시에서 바로 돌려준다.
 pub fn region_props(p_bar_abs: f64, t_c: f64) -> Result<(f64, f64, f64), &'static str> {
+    let key = (p_bar_abs.to_bits(), t_c.to_bits());
+    if let Some(hit) = PROPS_CACHE.with(|c| c.borrow_mut().get(key)) {
+        return Ok(hit);
+    }
+    let props = region_props_uncached(p_bar_abs, t_c)?;
+    PROPS_CACHE.with(|c| c.borrow_mut().insert(key, props));
+    Ok(props)
+}
+
+/// (압력 bar abs, 온도 °C) 점 목록의 h/v/s를 입력 순서대로 한꺼번에 구한다.
+/// 전체 증기표 생성이나 몬테카를로 불확도 계산처럼 점이 많을 때 `parallel` 기능으로 나눠 계산한다.
+pub fn region_props_many(points: &[(f64, f64)]) -> Vec<Result<(f64, f64, f64), &'static str>> {
+    parallel::map(points, |&(p, t)| region_props(p, t))
+}
+
+/// 캐시를 거치지 않는 `region_props`. 기준값 검증/벤치마크용.
+pub fn region_props_uncached(p_bar_abs: f64, t_c: f64) -> Result<(f64, f64, f64), &'static str> {
     let p_mpa = p_bar_abs / 10.0;
     let h_kj = pt(p_mpa, t_c, OH);
     let v = pt(p_mpa, t_c, OV);
@@ -80,60 +107,743 @@ pub fn region_props(p_bar_abs: f64, t_c: f64) -> Result<(f64, f64, f64), &'stati
     Ok((h_kj * 1000.0, v, s_kj * 1000.0))
 }
 
-/// 포화압력(bar abs) - 입력 온도는 °C.
+/// 포화압력(bar abs) - 입력 온도는 °C. IF97 식 30 (닫힌 형태).
 pub fn saturation_pressure_bar_abs_from_temp_c(t_c: f64) -> Result<f64, &'static str> {
     let t_k = t_c + 273.15;
     if t_k <= 0.0 || t_k > T4_STAR_K {
         return Err("IF97 Region4 유효 범위를 벗어났습니다 (0~374°C)");
     }
-    let theta = 1.0 - t_k / T4_STAR_K;
-    let exp_term = (T4_STAR_K / t_k)
-        * (R4_N[0] * theta
-            + R4_N[1] * theta.powf(1.5)
-            + R4_N[2] * theta.powi(3)
-            + R4_N[3] * theta.powf(3.5)
-            + R4_N[4] * theta.powi(4)
-            + R4_N[5] * theta.powf(7.5));
-    let p_mpa = P4_STAR_MPA * exp_term.exp();
+    let n = &R4_N;
+    let theta = t_k + n[8] / (t_k - n[9]);
+    let a = theta * theta + n[0] * theta + n[1];
+    let b = n[2] * theta * theta + n[3] * theta + n[4];
+    let c = n[5] * theta * theta + n[6] * theta + n[7];
+    let p_mpa = (2.0 * c / (-b + (b * b - 4.0 * a * c).sqrt())).powi(4);
     Ok(p_mpa * 10.0)
 }
 
-/// 포화온도(°C) - 입력 압력은 bar abs.
+/// 포화온도(°C) - 입력 압력은 bar abs. IF97 식 31 (닫힌 형태).
 pub fn saturation_temp_c_from_pressure_bar_abs(p_bar_abs: f64) -> Result<f64, &'static str> {
     if p_bar_abs <= 0.0 {
         return Err("압력은 양수여야 합니다.");
     }
-    let mut t_k = 373.15_f64;
-    for _ in 0..30 {
-        let theta = 1.0 - t_k / T4_STAR_K;
-        let f = (T4_STAR_K / t_k)
-            * (R4_N[0] * theta
-                + R4_N[1] * theta.powf(1.5)
-                + R4_N[2] * theta.powi(3)
-                + R4_N[3] * theta.powf(3.5)
-                + R4_N[4] * theta.powi(4)
-                + R4_N[5] * theta.powf(7.5))
-            - (p_bar_abs / 10.0 / P4_STAR_MPA).ln();
-        let dtheta_dt = -1.0 / T4_STAR_K;
-        let dfdtheta = R4_N[0]
-            + 1.5 * R4_N[1] * theta.powf(0.5)
-            + 3.0 * R4_N[2] * theta.powi(2)
-            + 3.5 * R4_N[3] * theta.powf(2.5)
-            + 4.0 * R4_N[4] * theta.powi(3)
-            + 7.5 * R4_N[5] * theta.powf(6.5);
-        let dfd_t = (-(T4_STAR_K / t_k.powi(2))
-            * (R4_N[0] * theta
-                + R4_N[1] * theta.powf(1.5)
-                + R4_N[2] * theta.powi(3)
-                + R4_N[3] * theta.powf(3.5)
-                + R4_N[4] * theta.powi(4)
-                + R4_N[5] * theta.powf(7.5)))
-            + (T4_STAR_K / t_k) * dfdtheta * dtheta_dt;
-        let delta = f / dfd_t;
-        t_k -= delta;
-        if delta.abs() < 1e-8 {
-            break;
-        }
+    let n = &R4_N;
+    let beta = (p_bar_abs / 10.0).powf(0.25);
+    let e = beta * beta + n[2] * beta + n[5];
+    let f = n[0] * beta * beta + n[3] * beta + n[6];
+    let g = n[1] * beta * beta + n[4] * beta + n[7];
+    let d = 2.0 * g / (-f - (f * f - 4.0 * e * g).sqrt());
+    let t_k = (n[9] + d - ((n[9] + d).powi(2) - 4.0 * (n[8] + n[9] * d)).sqrt()) / 2.0;
+    if !t_k.is_finite() {
+        return Err("IF97 Region4 유효 범위를 벗어났습니다 (0~374°C)");
     }
     Ok(t_k - 273.15)
 }
+
+/// 압력(bar abs)과 엔트로피(J/kg·K)로 엔탈피(J/kg)를 구한다. 터빈 등엔트로피 팽창 계산용.
+pub fn enthalpy_from_ps(p_bar_abs: f64, s_j_per_kgk: f64) -> Result<f64, &'static str> {
+    let h_kj = ps(p_bar_abs / 10.0, s_j_per_kgk / 1000.0, OH);
+    if h_kj.is_nan() {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok(h_kj * 1000.0)
+}
+
+/// 압력(bar abs)과 엔탈피(J/kg)로 온도(°C)를 구한다.
+pub fn temperature_from_ph(p_bar_abs: f64, h_j_per_kg: f64) -> Result<f64, &'static str> {
+    let t_c = ph(p_bar_abs / 10.0, h_j_per_kg / 1000.0, OT);
+    if t_c.is_nan() {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok(t_c)
+}
+
+/// 포화 액/증기 엔탈피(J/kg) - 입력 압력은 bar abs. 반환: (hf, hg)
+pub fn saturation_enthalpies(p_bar_abs: f64) -> Result<(f64, f64), &'static str> {
+    let p_mpa = p_bar_abs / 10.0;
+    let hf = px(p_mpa, 0.0, OH);
+    let hg = px(p_mpa, 1.0, OH);
+    if hf.is_nan() || hg.is_nan() {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok((hf * 1000.0, hg * 1000.0))
+}
+
+/// 압력(bar abs)과 엔탈피(J/kg)로 유동 계산용 물성을 구한다. 반환: (비체적[m³/kg], 음속[m/s], 점도[Pa·s])
+/// 습증기 영역처럼 음속/점도가 정의되지 않으면 균질 평형 근사(k=1.135)와 포화증기 점도로 대신한다.
+pub fn flow_props_from_ph(
+    p_bar_abs: f64,
+    h_j_per_kg: f64,
+) -> Result<(f64, f64, f64), &'static str> {
+    let p_mpa = p_bar_abs / 10.0;
+    let h_kj = h_j_per_kg / 1000.0;
+    let v = ph(p_mpa, h_kj, OV);
+    if !(v.is_finite() && v > 0.0) {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    let mut w = ph(p_mpa, h_kj, OW);
+    if !(w.is_finite() && w > 0.0) {
+        w = (1.135 * p_bar_abs * 1e5 * v).sqrt();
+    }
+    let mut mu = ph(p_mpa, h_kj, ODV);
+    if !(mu.is_finite() && mu > 0.0) {
+        mu = px(p_mpa, 1.0, ODV);
+    }
+    Ok((v, w, mu))
+}
+
+/// 압력(bar abs)과 엔트로피(J/kg·K)로 (엔탈피[J/kg], 비체적[m³/kg])을 구한다. 등엔트로피 노즐 계산용.
+pub fn props_from_ps(p_bar_abs: f64, s_j_per_kgk: f64) -> Result<(f64, f64), &'static str> {
+    let p_mpa = p_bar_abs / 10.0;
+    let s_kj = s_j_per_kgk / 1000.0;
+    let h_kj = ps(p_mpa, s_kj, OH);
+    let v = ps(p_mpa, s_kj, OV);
+    if h_kj.is_nan() || !(v.is_finite() && v > 0.0) {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok((h_kj * 1000.0, v))
+}
+
+/// 포화 압력(bar abs)과 건도 x로 (엔탈피[J/kg], 비체적[m³/kg], 엔트로피[J/kg·K])를 구한다.
+pub fn props_from_px(p_bar_abs: f64, x: f64) -> Result<(f64, f64, f64), &'static str> {
+    let p_mpa = p_bar_abs / 10.0;
+    let h_kj = px(p_mpa, x, OH);
+    let v = px(p_mpa, x, OV);
+    let s_kj = px(p_mpa, x, OS);
+    if h_kj.is_nan() || v.is_nan() || s_kj.is_nan() {
+        return nan_err();
+    }
+    Ok((h_kj * 1000.0, v, s_kj * 1000.0))
+}
+
+/// 포화 액/증기 점도(Pa·s) - 입력 압력은 bar abs. 반환: (μf, μg)
+pub fn saturation_viscosities(p_bar_abs: f64) -> Result<(f64, f64), &'static str> {
+    let p_mpa = p_bar_abs / 10.0;
+    let mu_f = px(p_mpa, 0.0, ODV);
+    let mu_g = px(p_mpa, 1.0, ODV);
+    if !(mu_f.is_finite() && mu_f > 0.0 && mu_g.is_finite() && mu_g > 0.0) {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok((mu_f, mu_g))
+}
+
+// ---------------- region_props 캐시 ----------------
+
+/// 스레드별 캐시 한 세대의 최대 항목 수
+const PROPS_CACHE_CAPACITY: usize = 4096;
+
+thread_local! {
+    static PROPS_CACHE: RefCell<PropsCache> = RefCell::new(PropsCache::default());
+}
+
+/// 두 세대(current/previous)로 나눈 근사 LRU.
+/// current가 가득 차면 previous를 버리고 current를 previous로 내린다.
+/// previous에서 찾은 항목은 current로 올려 최근 사용분이 살아남게 한다.
+#[derive(Default)]
+struct PropsCache {
+    current: HashMap<(u64, u64), (f64, f64, f64)>,
+    previous: HashMap<(u64, u64), (f64, f64, f64)>,
+}
+
+impl PropsCache {
+    fn get(&mut self, key: (u64, u64)) -> Option<(f64, f64, f64)> {
+        if let Some(v) = self.current.get(&key) {
+            return Some(*v);
+        }
+        let v = self.previous.remove(&key)?;
+        self.insert(key, v);
+        Some(v)
+    }
+
+    fn insert(&mut self, key: (u64, u64), value: (f64, f64, f64)) {
+        if self.current.len() >= PROPS_CACHE_CAPACITY {
+            self.previous = std::mem::take(&mut self.current);
+        }
+        self.current.insert(key, value);
+    }
+}
+
+/// 현재 스레드의 `region_props` 캐시를 비운다.
+pub fn clear_property_cache() {
+    PROPS_CACHE.with(|c| *c.borrow_mut() = PropsCache::default());
+}
+
+// ---------------- 포화선 스플라인 ----------------
+
+/// 스플라인 격자 시작 온도 [°C]
+const SAT_LINE_T_MIN_C: f64 = 0.0;
+/// 스플라인 격자 끝 온도 [°C]. 350°C(Region 1/3 경계)부터 임계점까지는
+/// 포화 물성이 Region 3 식으로 넘어가며 꺾이므로 그 직전 격자점에서 끊고 직접 계산으로 넘긴다.
+const SAT_LINE_T_MAX_C: f64 = 349.5;
+/// 격자 간격 [K]
+const SAT_LINE_STEP_K: f64 = 0.5;
+
+/// 포화선 위 한 점의 물성.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaturationPoint {
+    /// 포화온도 [°C]
+    pub t_c: f64,
+    /// 포화압력 [bar abs]
+    pub p_bar_abs: f64,
+    /// 포화액 엔탈피 [J/kg]
+    pub hf: f64,
+    /// 포화증기 엔탈피 [J/kg]
+    pub hg: f64,
+    /// 포화액 비체적 [m³/kg]
+    pub vf: f64,
+    /// 포화증기 비체적 [m³/kg]
+    pub vg: f64,
+}
+
+/// 포화선을 일정 온도 간격으로 한 번 계산해 둔 3차 Hermite 스플라인.
+/// 압력은 ln p, 증기 비체적은 ln vg로 보간해 저압 구간의 지수적 변화를 따라간다.
+/// 격자 밖(350°C 초과 등)은 IF97 직접 계산으로 대신한다.
+pub struct SaturationLine {
+    t: Vec<f64>,
+    // 열 순서: ln p, hf, hg, vf, ln vg
+    y: [Vec<f64>; 5],
+    dy: [Vec<f64>; 5],
+}
+
+/// 프로세스 전체에서 공유하는 포화선 스플라인 (첫 호출 때 한 번 생성).
+pub fn saturation_line() -> &'static SaturationLine {
+    static LINE: OnceLock<SaturationLine> = OnceLock::new();
+    LINE.get_or_init(SaturationLine::build)
+}
+
+/// 포화온도(°C)에서 포화선 물성을 직접 계산한다.
+fn saturation_point_exact(t_c: f64) -> Result<SaturationPoint, &'static str> {
+    let p_bar_abs = saturation_pressure_bar_abs_from_temp_c(t_c)?;
+    let p_mpa = p_bar_abs / 10.0;
+    let hf = px(p_mpa, 0.0, OH);
+    let hg = px(p_mpa, 1.0, OH);
+    let vf = px(p_mpa, 0.0, OV);
+    let vg = px(p_mpa, 1.0, OV);
+    if [hf, hg, vf, vg].iter().any(|x| !x.is_finite()) {
+        return Err("IF97 계산 실패(유효 범위 밖이거나 수렴 실패)");
+    }
+    Ok(SaturationPoint {
+        t_c,
+        p_bar_abs,
+        hf: hf * 1000.0,
+        hg: hg * 1000.0,
+        vf,
+        vg,
+    })
+}
+
+impl SaturationLine {
+    fn build() -> Self {
+        let n = ((SAT_LINE_T_MAX_C - SAT_LINE_T_MIN_C) / SAT_LINE_STEP_K).round() as usize + 1;
+        let mut t = Vec::with_capacity(n);
+        let mut y: [Vec<f64>; 5] = Default::default();
+        for i in 0..n {
+            let t_c = (SAT_LINE_T_MIN_C + i as f64 * SAT_LINE_STEP_K).min(SAT_LINE_T_MAX_C);
+            let pt = saturation_point_exact(t_c).expect("포화선 격자는 IF97 유효 범위 안");
+            t.push(t_c);
+            for (col, val) in y
+                .iter_mut()
+                .zip([pt.p_bar_abs.ln(), pt.hf, pt.hg, pt.vf, pt.vg.ln()])
+            {
+                col.push(val);
+            }
+        }
+        // 기울기: 내부는 중앙차분, 양 끝은 2차 정확도 한쪽 차분
+        let h = SAT_LINE_STEP_K;
+        let dy = y.clone().map(|col| {
+            (0..n)
+                .map(|i| match i {
+                    0 => (-3.0 * col[0] + 4.0 * col[1] - col[2]) / (2.0 * h),
+                    _ if i == n - 1 => {
+                        (3.0 * col[i] - 4.0 * col[i - 1] + col[i - 2]) / (t[i] - t[i - 2])
+                    }
+                    _ => (col[i + 1] - col[i - 1]) / (t[i + 1] - t[i - 1]),
+                })
+                .collect()
+        });
+        Self { t, y, dy }
+    }
+
+    /// 스플라인이 다루는 온도 범위 [°C]
+    pub fn temperature_range(&self) -> (f64, f64) {
+        (self.t[0], self.t[self.t.len() - 1])
+    }
+
+    /// 스플라인이 다루는 압력 범위 [bar abs]
+    pub fn pressure_range(&self) -> (f64, f64) {
+        let ln_p = &self.y[0];
+        (ln_p[0].exp(), ln_p[ln_p.len() - 1].exp())
+    }
+
+    /// 구간 i에서 열 col의 Hermite 보간 값과 온도 미분
+    fn eval(&self, col: usize, i: usize, t_c: f64) -> (f64, f64) {
+        let h = self.t[i + 1] - self.t[i];
+        let s = (t_c - self.t[i]) / h;
+        let (y0, y1) = (self.y[col][i], self.y[col][i + 1]);
+        let (m0, m1) = (self.dy[col][i] * h, self.dy[col][i + 1] * h);
+        let s2 = s * s;
+        let s3 = s2 * s;
+        let value = (2.0 * s3 - 3.0 * s2 + 1.0) * y0
+            + (s3 - 2.0 * s2 + s) * m0
+            + (-2.0 * s3 + 3.0 * s2) * y1
+            + (s3 - s2) * m1;
+        let slope = ((6.0 * s2 - 6.0 * s) * y0
+            + (3.0 * s2 - 4.0 * s + 1.0) * m0
+            + (-6.0 * s2 + 6.0 * s) * y1
+            + (3.0 * s2 - 2.0 * s) * m1)
+            / h;
+        (value, slope)
+    }
+
+    fn point_in_cell(&self, i: usize, t_c: f64) -> SaturationPoint {
+        let v = |col| self.eval(col, i, t_c).0;
+        SaturationPoint {
+            t_c,
+            p_bar_abs: v(0).exp(),
+            hf: v(1),
+            hg: v(2),
+            vf: v(3),
+            vg: v(4).exp(),
+        }
+    }
+
+    fn cell_of_temperature(&self, t_c: f64) -> usize {
+        let i = ((t_c - self.t[0]) / SAT_LINE_STEP_K).floor() as usize;
+        i.min(self.t.len() - 2)
+    }
+
+    /// 포화온도(°C)에서의 포화선 물성.
+    pub fn at_temperature(&self, t_c: f64) -> Result<SaturationPoint, &'static str> {
+        let (t_min, t_max) = self.temperature_range();
+        if !(t_min..=t_max).contains(&t_c) {
+            return saturation_point_exact(t_c);
+        }
+        Ok(self.point_in_cell(self.cell_of_temperature(t_c), t_c))
+    }
+
+    /// 포화압력(bar abs)에서의 포화선 물성.
+    /// ln p 격자에서 구간을 이분 탐색한 뒤 구간 안에서 Newton 반복으로 온도를 구한다.
+    pub fn at_pressure(&self, p_bar_abs: f64) -> Result<SaturationPoint, &'static str> {
+        if p_bar_abs <= 0.0 {
+            return Err("압력은 양수여야 합니다.");
+        }
+        let (p_min, p_max) = self.pressure_range();
+        if !(p_min..=p_max).contains(&p_bar_abs) {
+            let t_c = saturation_temp_c_from_pressure_bar_abs(p_bar_abs)?;
+            return saturation_point_exact(t_c);
+        }
+        let ln_p = p_bar_abs.ln();
+        let i = self.y[0]
+            .partition_point(|&v| v <= ln_p)
+            .clamp(1, self.t.len() - 1)
+            - 1;
+        let (ln_p0, ln_p1) = (self.y[0][i], self.y[0][i + 1]);
+        let mut t_c = self.t[i] + (ln_p - ln_p0) / (ln_p1 - ln_p0) * SAT_LINE_STEP_K;
+        for _ in 0..8 {
+            let (f, df) = self.eval(0, i, t_c);
+            let delta = (f - ln_p) / df;
+            t_c = (t_c - delta).clamp(self.t[i], self.t[i + 1]);
+            if delta.abs() < 1e-10 {
+                break;
+            }
+        }
+        let mut point = self.point_in_cell(i, t_c);
+        point.p_bar_abs = p_bar_abs;
+        Ok(point)
+    }
+}
+
+/// 스플라인 기반 포화온도(°C) - 입력 압력은 bar abs. 표/스윕처럼 반복 호출하는 곳용.
+pub fn saturation_temp_c_fast(p_bar_abs: f64) -> Result<f64, &'static str> {
+    saturation_line().at_pressure(p_bar_abs).map(|s| s.t_c)
+}
+
+/// 스플라인 기반 포화압력(bar abs) - 입력 온도는 °C. 표/스윕처럼 반복 호출하는 곳용.
+pub fn saturation_pressure_bar_abs_fast(t_c: f64) -> Result<f64, &'static str> {
+    saturation_line().at_temperature(t_c).map(|s| s.p_bar_abs)
+}
+
+// ---------------- B23 (Region 2/3 경계선) ----------------
+const B23_N: [f64; 5] = [
+    0.348_051_856_289_69e3,
+    -0.116_718_598_799_75e1,
+    0.101_929_700_393_26e-2,
+    0.572_544_598_627_46e3,
+    0.139_188_397_788_70e2,
+];
+
+/// Region 2/3 경계 압력(bar abs) - 입력 온도는 °C (623.15~863.15 K).
+pub fn b23_pressure_bar_abs_from_temp_c(t_c: f64) -> f64 {
+    let t_k = t_c + 273.15;
+    (B23_N[0] + B23_N[1] * t_k + B23_N[2] * t_k * t_k) * 10.0
+}
+
+/// Region 2/3 경계 온도(°C) - 입력 압력은 bar abs (16.53~100 MPa).
+pub fn b23_temp_c_from_pressure_bar_abs(p_bar_abs: f64) -> f64 {
+    let pi = p_bar_abs / 10.0;
+    B23_N[3] + ((pi - B23_N[4]) / B23_N[2]).sqrt() - 273.15
+}
+
+// ---------------- IAPWS-IF97 검증표 ----------------
+
+/// (p, T) 입력 검증점. 기대값 순서: v[m³/kg], h[kJ/kg], u[kJ/kg], s[kJ/kg·K], cp[kJ/kg·K], w[m/s]
+struct PtReference {
+    case: &'static str,
+    region: i32,
+    p_mpa: f64,
+    t_k: f64,
+    expected: [f64; 6],
+}
+
+/// Region 3 (T, ρ) 입력 검증점. 기대값 순서: p[MPa], h, u, s, cp, w
+struct TrhoReference {
+    case: &'static str,
+    t_k: f64,
+    rho: f64,
+    expected: [f64; 6],
+}
+
+/// IAPWS-IF97 공식 문서 Table 5 / 15 / 42의 검증값
+const PT_REFERENCES: [PtReference; 9] = [
+    PtReference {
+        case: "T=300 K, p=3 MPa",
+        region: 1,
+        p_mpa: 3.0,
+        t_k: 300.0,
+        expected: [
+            0.100_215_168e-2,
+            0.115_331_273e3,
+            0.112_324_818e3,
+            0.392_294_792,
+            0.417_301_218e1,
+            0.150_773_921e4,
+        ],
+    },
+    PtReference {
+        case: "T=300 K, p=80 MPa",
+        region: 1,
+        p_mpa: 80.0,
+        t_k: 300.0,
+        expected: [
+            0.971_180_894e-3,
+            0.184_142_828e3,
+            0.106_448_356e3,
+            0.368_563_852,
+            0.401_008_987e1,
+            0.163_469_054e4,
+        ],
+    },
+    PtReference {
+        case: "T=500 K, p=3 MPa",
+        region: 1,
+        p_mpa: 3.0,
+        t_k: 500.0,
+        expected: [
+            0.120_241_800e-2,
+            0.975_542_239e3,
+            0.971_934_985e3,
+            0.258_041_912e1,
+            0.465_580_682e1,
+            0.124_071_337e4,
+        ],
+    },
+    PtReference {
+        case: "T=300 K, p=0.0035 MPa",
+        region: 2,
+        p_mpa: 0.0035,
+        t_k: 300.0,
+        expected: [
+            0.394_913_866e2,
+            0.254_991_145e4,
+            0.241_169_160e4,
+            0.852_238_967e1,
+            0.191_300_162e1,
+            0.427_920_172e3,
+        ],
+    },
+    PtReference {
+        case: "T=700 K, p=0.0035 MPa",
+        region: 2,
+        p_mpa: 0.0035,
+        t_k: 700.0,
+        expected: [
+            0.923_015_898e2,
+            0.333_568_375e4,
+            0.301_262_819e4,
+            0.101_749_996e2,
+            0.208_141_274e1,
+            0.644_289_068e3,
+        ],
+    },
+    PtReference {
+        case: "T=700 K, p=30 MPa",
+        region: 2,
+        p_mpa: 30.0,
+        t_k: 700.0,
+        expected: [
+            0.542_946_619e-2,
+            0.263_149_474e4,
+            0.246_861_076e4,
+            0.517_540_298e1,
+            0.103_505_092e2,
+            0.480_386_523e3,
+        ],
+    },
+    PtReference {
+        case: "T=1500 K, p=0.5 MPa",
+        region: 5,
+        p_mpa: 0.5,
+        t_k: 1500.0,
+        expected: [
+            0.138_455_090e1,
+            0.521_976_855e4,
+            0.452_749_310e4,
+            0.965_408_875e1,
+            0.261_609_445e1,
+            0.917_068_690e3,
+        ],
+    },
+    PtReference {
+        case: "T=1500 K, p=30 MPa",
+        region: 5,
+        p_mpa: 30.0,
+        t_k: 1500.0,
+        expected: [
+            0.230_761_299e-1,
+            0.516_723_514e4,
+            0.447_495_124e4,
+            0.772_970_133e1,
+            0.272_724_317e1,
+            0.928_548_002e3,
+        ],
+    },
+    PtReference {
+        case: "T=2000 K, p=30 MPa",
+        region: 5,
+        p_mpa: 30.0,
+        t_k: 2000.0,
+        expected: [
+            0.311_385_219e-1,
+            0.657_122_604e4,
+            0.563_707_038e4,
+            0.853_640_523e1,
+            0.288_569_882e1,
+            0.106_736_948e4,
+        ],
+    },
+];
+
+/// IAPWS-IF97 공식 문서 Table 33의 검증값
+const TRHO_REFERENCES: [TrhoReference; 3] = [
+    TrhoReference {
+        case: "T=650 K, rho=500 kg/m3",
+        t_k: 650.0,
+        rho: 500.0,
+        expected: [
+            0.255_837_018e2,
+            0.186_343_019e4,
+            0.181_226_279e4,
+            0.405_427_273e1,
+            0.138_935_717e2,
+            0.502_005_554e3,
+        ],
+    },
+    TrhoReference {
+        case: "T=650 K, rho=200 kg/m3",
+        t_k: 650.0,
+        rho: 200.0,
+        expected: [
+            0.222_930_643e2,
+            0.237_512_401e4,
+            0.226_365_868e4,
+            0.485_438_792e1,
+            0.446_579_342e2,
+            0.383_444_594e3,
+        ],
+    },
+    TrhoReference {
+        case: "T=750 K, rho=500 kg/m3",
+        t_k: 750.0,
+        rho: 500.0,
+        expected: [
+            0.783_095_639e2,
+            0.225_868_845e4,
+            0.210_206_932e4,
+            0.446_971_906e1,
+            0.634_165_359e1,
+            0.760_696_041e3,
+        ],
+    },
+];
+
+/// 검증표 값은 유효숫자 9자리로 실려 있으므로 반올림 오차를 넉넉히 덮는 허용 상대편차
+const VERIFY_REL_TOLERANCE: f64 = 1e-8;
+
+/// 검증 항목 하나 (공식 검증표 값과 이 모듈의 계산값 비교).
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationCheck {
+    /// 영역/경계선 이름 (예: "Region 1", "B23")
+    pub group: &'static str,
+    /// 검증점 설명
+    pub case: &'static str,
+    /// 물성 기호 (v, h, u, s, cp, w, p, T)
+    pub property: &'static str,
+    pub expected: f64,
+    /// 계산값. 계산에 실패하면 NaN
+    pub actual: f64,
+    /// |계산값 - 기대값| / |기대값|. 계산 실패 시 NaN
+    pub rel_deviation: f64,
+    pub tolerance: f64,
+}
+
+impl VerificationCheck {
+    fn new(
+        group: &'static str,
+        case: &'static str,
+        property: &'static str,
+        expected: f64,
+        actual: f64,
+    ) -> Self {
+        Self {
+            group,
+            case,
+            property,
+            expected,
+            actual,
+            rel_deviation: (actual - expected).abs() / expected.abs(),
+            tolerance: VERIFY_REL_TOLERANCE,
+        }
+    }
+
+    /// 허용 편차 안이면 true (계산 실패는 false)
+    pub fn passed(&self) -> bool {
+        self.rel_deviation <= self.tolerance
+    }
+}
+
+/// `verify()` 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerificationReport {
+    pub checks: Vec<VerificationCheck>,
+}
+
+impl VerificationReport {
+    /// 모든 항목이 허용 편차 안인지 여부
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(VerificationCheck::passed)
+    }
+
+    /// 영역/경계선별 최대 상대편차 (검증 순서대로). 계산 실패가 있으면 NaN
+    pub fn max_deviations(&self) -> Vec<(&'static str, f64)> {
+        let mut out: Vec<(&'static str, f64)> = Vec::new();
+        for c in &self.checks {
+            let dev = if c.rel_deviation.is_nan() {
+                f64::NAN
+            } else {
+                c.rel_deviation
+            };
+            match out.iter_mut().find(|(g, _)| *g == c.group) {
+                Some((_, max)) if max.is_nan() => {}
+                Some((_, max)) => *max = if dev.is_nan() { dev } else { max.max(dev) },
+                None => out.push((c.group, dev)),
+            }
+        }
+        out
+    }
+
+    /// 상대편차가 가장 큰 항목 (계산 실패 항목이 있으면 그 항목)
+    pub fn worst(&self) -> Option<&VerificationCheck> {
+        self.checks.iter().max_by(|a, b| {
+            let key = |c: &VerificationCheck| {
+                if c.rel_deviation.is_nan() {
+                    f64::INFINITY
+                } else {
+                    c.rel_deviation
+                }
+            };
+            key(a).total_cmp(&key(b))
+        })
+    }
+}
+
+/// 이 모듈의 IF97 계산을 IAPWS-IF97 공식 검증표(Region 1/2/3/5, 포화선, B23)와 비교한다.
+/// CLI의 숨은 `verify-if97` 명령으로도 실행할 수 있다.
+pub fn verify() -> VerificationReport {
+    const PROPS: [&str; 6] = ["v", "h", "u", "s", "cp", "w"];
+    let mut checks = Vec::new();
+
+    for r in &PT_REFERENCES {
+        let group = match r.region {
+            1 => "Region 1",
+            2 => "Region 2",
+            _ => "Region 5",
+        };
+        let t_c = r.t_k - 273.15;
+        for (i, o_id) in [OV, OH, OU, OS, OCP, OW].into_iter().enumerate() {
+            let actual = pt(r.p_mpa, t_c, (o_id, r.region));
+            checks.push(VerificationCheck::new(
+                group,
+                r.case,
+                PROPS[i],
+                r.expected[i],
+                actual,
+            ));
+        }
+    }
+
+    for r in &TRHO_REFERENCES {
+        let t_c = r.t_k - 273.15;
+        let v = 1.0 / r.rho;
+        for (i, o_id) in [OP, OH, OU, OS, OCP, OW].into_iter().enumerate() {
+            let actual = tv(t_c, v, (o_id, 3));
+            let property = if i == 0 { "p" } else { PROPS[i] };
+            checks.push(VerificationCheck::new(
+                "Region 3",
+                r.case,
+                property,
+                r.expected[i],
+                actual,
+            ));
+        }
+    }
+
+    // 포화선 (Table 35/36): psat(T)[MPa], Tsat(p)[K]
+    for (case, t_k, p_mpa) in [
+        ("T=300 K", 300.0, 0.353_658_941e-2),
+        ("T=500 K", 500.0, 0.263_889_776e1),
+        ("T=600 K", 600.0, 0.123_443_146e2),
+    ] {
+        let actual = saturation_pressure_bar_abs_from_temp_c(t_k - 273.15)
+            .map(|p| p / 10.0)
+            .unwrap_or(f64::NAN);
+        checks.push(VerificationCheck::new("Region 4", case, "p", p_mpa, actual));
+    }
+    for (case, p_mpa, t_k) in [
+        ("p=0.1 MPa", 0.1, 0.372_755_919e3),
+        ("p=1 MPa", 1.0, 0.453_035_632e3),
+        ("p=10 MPa", 10.0, 0.584_149_488e3),
+    ] {
+        let actual = saturation_temp_c_from_pressure_bar_abs(p_mpa * 10.0)
+            .map(|t| t + 273.15)
+            .unwrap_or(f64::NAN);
+        checks.push(VerificationCheck::new("Region 4", case, "T", t_k, actual));
+    }
+
+    // B23 (식 5/6 검증값): T=623.15 K ↔ p=16.529 164 3 MPa
+    checks.push(VerificationCheck::new(
+        "B23",
+        "T=623.15 K",
+        "p",
+        0.165_291_643e2,
+        b23_pressure_bar_abs_from_temp_c(350.0) / 10.0,
+    ));
+    checks.push(VerificationCheck::new(
+        "B23",
+        "p=16.5291643 MPa",
+        "T",
+        0.623_150_000e3,
+        b23_temp_c_from_pressure_bar_abs(165.291_643) + 273.15,
+    ));
+
+    VerificationReport { checks }
+}
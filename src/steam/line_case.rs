@@ -0,0 +1,388 @@
+//! 밸브와 앞뒤 배관을 한 "케이스"로 묶어 교차 점검한다.
+//! 카드마다 따로 채운 값이 서로 맞는지 본다.
+//! - 상류 배관·밸브·하류 배관 차압 배분의 합이 공급-수요 압력차와 맞는가
+//! - 배관 유속이 지침 유속·침식 한계 안인가
+//! - 밸브가 배분 차압에서 임계 유동이 아니고, Kv로 유량을 흘릴 수 있는가
+//!
+//! 밸브 감압은 등엔탈피로 보고, 각 지점 상태는 압력과 공급 엔탈피로 IF97에서 구한다.
+
+use crate::steam::critical_flow::{self, SteamStagnation};
+use crate::steam::if97;
+use crate::steam::steam_piping::velocity_check::{
+    self, PipeService, VelocityCheck, VelocityCheckInput,
+};
+use crate::steam::steam_valves;
+use crate::warning::{CalcWarning, Severity};
+
+/// 차압 배분이 맞다고 보는 상대 허용 오차 (가용 차압 대비)
+pub const DP_BALANCE_TOLERANCE: f64 = 0.02;
+/// 차압 배분 절대 허용 오차 [bar]
+const DP_BALANCE_MIN_BAR: f64 = 0.01;
+
+/// 케이스에 넣는 배관 (배관 ΔP 카드 결과).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CasePipe {
+    pub inner_diameter_m: f64,
+    /// 배관 차압 (마찰 + 정수두) [bar]
+    pub pressure_drop_bar: f64,
+}
+
+/// 케이스에 넣는 밸브 (밸브 카드 입력).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CaseValve {
+    pub kv: f64,
+    /// 밸브에 배분한 차압 [bar]
+    pub pressure_drop_bar: f64,
+}
+
+/// 공급 헤더 → 상류 배관 → 밸브 → 하류 배관 → 수요처로 이어지는 증기 라인 케이스.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LineCase {
+    /// 공급 압력 [bar abs]
+    pub source_pressure_bar_abs: f64,
+    /// 수요처 압력 [bar abs]
+    pub sink_pressure_bar_abs: f64,
+    /// 공급 증기 온도 [°C]
+    pub source_temp_c: f64,
+    pub mass_flow_kg_per_h: f64,
+    pub upstream_pipe: Option<CasePipe>,
+    pub valve: Option<CaseValve>,
+    pub downstream_pipe: Option<CasePipe>,
+}
+
+impl LineCase {
+    /// 밸브와 앞뒤 배관이 모두 정해졌는지.
+    pub fn is_complete(&self) -> bool {
+        self.upstream_pipe.is_some() && self.valve.is_some() && self.downstream_pipe.is_some()
+    }
+}
+
+/// 교차 점검에서 찾은 불일치.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineCaseFinding {
+    /// 배분 차압 합이 가용 차압을 넘음 (유량을 낼 수 없음)
+    DpShortfall {
+        allocated_bar: f64,
+        available_bar: f64,
+    },
+    /// 배분되지 않은 차압이 남음 (밸브가 더 닫혀 운전)
+    DpSurplus {
+        allocated_bar: f64,
+        available_bar: f64,
+    },
+    /// 밸브 차압비가 임계 압력비를 넘어 임계 유동
+    ValveChoked {
+        pressure_ratio: f64,
+        critical_ratio: f64,
+    },
+    /// 밸브 Kv가 배분 차압에서 필요한 Kv보다 작음
+    ValveUndersized { kv: f64, required_kv: f64 },
+}
+
+impl CalcWarning for LineCaseFinding {
+    fn severity(&self) -> Severity {
+        match self {
+            LineCaseFinding::DpShortfall { .. } | LineCaseFinding::ValveUndersized { .. } => {
+                Severity::Critical
+            }
+            LineCaseFinding::DpSurplus { .. } | LineCaseFinding::ValveChoked { .. } => {
+                Severity::Caution
+            }
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            LineCaseFinding::DpShortfall { .. } => "warning.line_case.dp_shortfall",
+            LineCaseFinding::DpSurplus { .. } => "warning.line_case.dp_surplus",
+            LineCaseFinding::ValveChoked { .. } => "warning.line_case.valve_choked",
+            LineCaseFinding::ValveUndersized { .. } => "warning.line_case.valve_undersized",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            LineCaseFinding::DpShortfall {
+                allocated_bar,
+                available_bar,
+            }
+            | LineCaseFinding::DpSurplus {
+                allocated_bar,
+                available_bar,
+            } => vec![
+                ("allocated", format!("{allocated_bar:.3}")),
+                ("available", format!("{available_bar:.3}")),
+                (
+                    "diff",
+                    format!("{:.3}", (allocated_bar - available_bar).abs()),
+                ),
+            ],
+            LineCaseFinding::ValveChoked {
+                pressure_ratio,
+                critical_ratio,
+            } => vec![
+                ("ratio", format!("{pressure_ratio:.3}")),
+                ("critical", format!("{critical_ratio:.3}")),
+            ],
+            LineCaseFinding::ValveUndersized { kv, required_kv } => vec![
+                ("kv", format!("{kv:.2}")),
+                ("required", format!("{required_kv:.2}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for LineCaseFinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            LineCaseFinding::DpShortfall {
+                allocated_bar,
+                available_bar,
+            } => write!(
+                f,
+                "배분 차압 합 {allocated_bar:.3} bar가 가용 차압 {available_bar:.3} bar보다 큽니다. 이 유량을 낼 수 없습니다."
+            ),
+            LineCaseFinding::DpSurplus {
+                allocated_bar,
+                available_bar,
+            } => write!(
+                f,
+                "배분 차압 합 {allocated_bar:.3} bar가 가용 차압 {available_bar:.3} bar보다 작습니다. 남는 차압은 밸브가 더 닫혀 소모합니다."
+            ),
+            LineCaseFinding::ValveChoked {
+                pressure_ratio,
+                critical_ratio,
+            } => write!(
+                f,
+                "밸브 출구/입구 압력비 {pressure_ratio:.3}가 임계 압력비 {critical_ratio:.3} 미만으로 임계 유동입니다. 하류 압력을 낮춰도 유량이 늘지 않습니다."
+            ),
+            LineCaseFinding::ValveUndersized { kv, required_kv } => write!(
+                f,
+                "밸브 Kv {kv:.2}가 배분 차압에서 필요한 Kv {required_kv:.2}보다 작습니다."
+            ),
+        }
+    }
+}
+
+/// 라인 한 지점의 상태.
+#[derive(Debug, Clone, Copy)]
+pub struct LinePoint {
+    pub pressure_bar_abs: f64,
+    pub temp_c: f64,
+    pub density_kg_per_m3: f64,
+}
+
+/// 교차 점검 결과.
+#[derive(Debug, Clone)]
+pub struct LineCaseReport {
+    /// 공급 − 수요 압력 [bar]
+    pub available_dp_bar: f64,
+    /// 상류 배관 + 밸브 + 하류 배관 차압 [bar]
+    pub allocated_dp_bar: f64,
+    pub valve_inlet: LinePoint,
+    pub valve_outlet: LinePoint,
+    /// 하류 배관 끝 (수요처 입구)
+    pub line_end: LinePoint,
+    pub upstream_velocity: VelocityCheck,
+    pub downstream_velocity: VelocityCheck,
+    pub critical_pressure_ratio: f64,
+    pub choked: bool,
+    /// 배분 차압(임계 제한)에서 필요한 Kv
+    pub required_kv: f64,
+    pub findings: Vec<LineCaseFinding>,
+}
+
+impl LineCaseReport {
+    /// 유속 판정과 불일치 항목을 모두 본 가장 높은 심각도.
+    pub fn max_severity(&self) -> Severity {
+        self.findings
+            .iter()
+            .map(|f| f.severity())
+            .chain([
+                self.upstream_velocity.severity(),
+                self.downstream_velocity.severity(),
+            ])
+            .max()
+            .unwrap_or(Severity::Info)
+    }
+}
+
+/// 케이스 점검 오류.
+#[derive(Debug)]
+pub enum LineCaseError {
+    InvalidInput(&'static str),
+    /// 밸브·상류 배관·하류 배관 중 빠진 것
+    Incomplete,
+    If97(String),
+    Pipe(crate::steam::steam_piping::PipeCalcError),
+    Valve(steam_valves::ValveCalcError),
+}
+
+impl std::fmt::Display for LineCaseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineCaseError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            LineCaseError::Incomplete => write!(
+                f,
+                "밸브, 상류 배관, 하류 배관을 모두 케이스에 넣어야 점검할 수 있습니다."
+            ),
+            LineCaseError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            LineCaseError::Pipe(e) => write!(f, "배관 유속 점검 오류: {e}"),
+            LineCaseError::Valve(e) => write!(f, "밸브 계산 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for LineCaseError {}
+
+fn if97_err(e: &str) -> LineCaseError {
+    LineCaseError::If97(e.into())
+}
+
+/// 압력과 엔탈피로 지점 상태와 배관 서비스를 구한다.
+fn line_point(p_bar_abs: f64, h_j_per_kg: f64) -> Result<(LinePoint, PipeService), LineCaseError> {
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_bar_abs).map_err(if97_err)?;
+    let (hf, hg) = if97::saturation_enthalpies(p_bar_abs).map_err(if97_err)?;
+    let (temp_c, v, service) = if h_j_per_kg < hg {
+        let x = ((h_j_per_kg - hf) / (hg - hf)).clamp(0.0, 1.0);
+        let (_, v, _) = if97::props_from_px(p_bar_abs, x).map_err(if97_err)?;
+        (t_sat, v, PipeService::WetSteam)
+    } else {
+        let t = if97::temperature_from_ph(p_bar_abs, h_j_per_kg).map_err(if97_err)?;
+        let (_, v, _) = if97::region_props(p_bar_abs, t).map_err(if97_err)?;
+        let service = PipeService::from_state(p_bar_abs, t).map_err(LineCaseError::Pipe)?;
+        (t, v, service)
+    };
+    Ok((
+        LinePoint {
+            pressure_bar_abs: p_bar_abs,
+            temp_c,
+            density_kg_per_m3: 1.0 / v,
+        },
+        service,
+    ))
+}
+
+fn pipe_velocity(
+    pipe: &CasePipe,
+    point: &LinePoint,
+    service: PipeService,
+    mass_flow_kg_per_h: f64,
+) -> Result<VelocityCheck, LineCaseError> {
+    let area = std::f64::consts::PI * pipe.inner_diameter_m.powi(2) / 4.0;
+    let velocity = mass_flow_kg_per_h / 3600.0 / point.density_kg_per_m3 / area;
+    velocity_check::check_velocity(&VelocityCheckInput {
+        service,
+        pressure_bar_abs: point.pressure_bar_abs,
+        temperature_c: Some(point.temp_c),
+        quality: None,
+        diameter_m: pipe.inner_diameter_m,
+        velocity_m_per_s: velocity,
+        density_kg_per_m3: Some(point.density_kg_per_m3),
+        c_factor: None,
+    })
+    .map_err(LineCaseError::Pipe)
+}
+
+/// 케이스의 차압 배분, 배관 유속, 밸브 임계 유동·용량을 함께 점검한다.
+pub fn check_line_case(case: &LineCase) -> Result<LineCaseReport, LineCaseError> {
+    let (Some(up_pipe), Some(valve), Some(down_pipe)) =
+        (case.upstream_pipe, case.valve, case.downstream_pipe)
+    else {
+        return Err(LineCaseError::Incomplete);
+    };
+    let p_source = case.source_pressure_bar_abs;
+    if p_source <= 0.0 || case.sink_pressure_bar_abs <= 0.0 {
+        return Err(LineCaseError::InvalidInput(
+            "공급·수요 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if case.mass_flow_kg_per_h <= 0.0 {
+        return Err(LineCaseError::InvalidInput("유량은 0보다 커야 합니다."));
+    }
+    if up_pipe.inner_diameter_m <= 0.0 || down_pipe.inner_diameter_m <= 0.0 {
+        return Err(LineCaseError::InvalidInput(
+            "배관 내경은 0보다 커야 합니다.",
+        ));
+    }
+    if up_pipe.pressure_drop_bar < 0.0
+        || down_pipe.pressure_drop_bar < 0.0
+        || valve.pressure_drop_bar <= 0.0
+    {
+        return Err(LineCaseError::InvalidInput(
+            "배관 차압은 0 이상, 밸브 차압은 0보다 커야 합니다.",
+        ));
+    }
+    let p_in = p_source - up_pipe.pressure_drop_bar;
+    let p_out = p_in - valve.pressure_drop_bar;
+    if p_out <= 0.0 {
+        return Err(LineCaseError::InvalidInput(
+            "배관·밸브 차압 합이 공급 압력보다 커서 밸브 출구 압력이 0 이하입니다.",
+        ));
+    }
+
+    let h = if97::region_props(p_source, case.source_temp_c)
+        .map_err(if97_err)?
+        .0;
+    // 유속은 각 배관 끝(압력이 낮아 밀도가 작은 쪽) 상태로 보수적으로 본다.
+    let (inlet, inlet_service) = line_point(p_in, h)?;
+    let (outlet, _) = line_point(p_out, h)?;
+    let p_end = p_out - down_pipe.pressure_drop_bar;
+    let (line_end, end_service) = line_point(if p_end > 0.0 { p_end } else { p_out }, h)?;
+    let m = case.mass_flow_kg_per_h;
+    let upstream_velocity = pipe_velocity(&up_pipe, &inlet, inlet_service, m)?;
+    let downstream_velocity = pipe_velocity(&down_pipe, &line_end, end_service, m)?;
+
+    let mut findings = Vec::new();
+    let available = p_source - case.sink_pressure_bar_abs;
+    let allocated =
+        up_pipe.pressure_drop_bar + valve.pressure_drop_bar + down_pipe.pressure_drop_bar;
+    let tolerance = (available.abs() * DP_BALANCE_TOLERANCE).max(DP_BALANCE_MIN_BAR);
+    if allocated > available + tolerance {
+        findings.push(LineCaseFinding::DpShortfall {
+            allocated_bar: allocated,
+            available_bar: available,
+        });
+    } else if allocated < available - tolerance {
+        findings.push(LineCaseFinding::DpSurplus {
+            allocated_bar: allocated,
+            available_bar: available,
+        });
+    }
+
+    let critical_ratio = critical_flow::critical_pressure_ratio_or_fallback(
+        p_in,
+        SteamStagnation::Temperature(inlet.temp_c),
+    );
+    let (dp_eff, choked) =
+        critical_flow::choked_limited_pressure_drop(p_in, valve.pressure_drop_bar, critical_ratio);
+    if choked {
+        findings.push(LineCaseFinding::ValveChoked {
+            pressure_ratio: p_out / p_in,
+            critical_ratio,
+        });
+    }
+    let rho_in = inlet.density_kg_per_m3;
+    let required_kv =
+        steam_valves::required_kv(m / rho_in, dp_eff, rho_in).map_err(LineCaseError::Valve)?;
+    if valve.kv < required_kv {
+        findings.push(LineCaseFinding::ValveUndersized {
+            kv: valve.kv,
+            required_kv,
+        });
+    }
+
+    Ok(LineCaseReport {
+        available_dp_bar: available,
+        allocated_dp_bar: allocated,
+        valve_inlet: inlet,
+        valve_outlet: outlet,
+        line_end,
+        upstream_velocity,
+        downstream_velocity,
+        critical_pressure_ratio: critical_ratio,
+        choked,
+        required_kv,
+        findings,
+    })
+}
@@ -0,0 +1,407 @@
+//! 보온 증기 배관을 따라 생기는 열손실과 응축(라인 응축) 계산.
+//! 배관을 같은 길이의 구간으로 나눠 입구부터 행진(marching)하며, 구간마다
+//! 보온 열통과율(`freeze_protection::insulated_pipe_ua_w_per_mk`)로 열손실을 구해 엔탈피에서 빼고,
+//! 그 구간 입구 상태의 균질 2상 압력손실(`recovery_piping::two_phase_pressure_loss`)로 압력을 낮춘다.
+//! 건도가 떨어지면 혼합 밀도와 유속이 바뀌므로 압력손실과 건도 변화가 구간마다 함께 계산된다.
+//! 내부 막/관벽 저항은 무시하므로 열손실은 약간 크게(보수적으로) 나온다.
+
+use crate::condensate_recovery::recovery_piping::{
+    two_phase_pressure_loss, TwoPhasePressureLossInput,
+};
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+use crate::water::freeze_protection::insulated_pipe_ua_w_per_mk;
+
+/// 구간 수 상한
+const MAX_SEGMENTS: usize = 1000;
+/// 이 건도보다 낮으면 사용처 앞 기수분리 경고
+const LOW_DRYNESS: f64 = 0.95;
+/// 응축이 생기는 증기 주관의 드립 레그 권장 간격 [m]
+const DRIP_LEG_SPACING_M: f64 = 50.0;
+
+/// 입구 증기 상태.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineInletState {
+    /// 과열(또는 포화) 증기 온도 [°C]. 포화 온도 미만이면 오류.
+    Superheated { temp_c: f64 },
+    /// 습증기 건도 (0~1]
+    Wet { dryness: f64 },
+}
+
+/// 라인 응축 계산 입력.
+#[derive(Debug, Clone)]
+pub struct LineCondensationInput {
+    pub mass_flow_kg_per_h: f64,
+    pub inlet_pressure_bar_abs: f64,
+    pub inlet: LineInletState,
+    /// 배관 내경 [m]
+    pub inner_diameter_m: f64,
+    /// 배관 외경 [m] (보온 안쪽 지름)
+    pub outer_diameter_m: f64,
+    pub length_m: f64,
+    pub roughness_m: f64,
+    pub inlet_elevation_m: f64,
+    pub outlet_elevation_m: f64,
+    /// 보온 두께 [m]. 0이면 나관.
+    pub insulation_thickness_m: f64,
+    /// 보온재 열전도율 [W/m·K]
+    pub insulation_conductivity_w_mk: f64,
+    pub ambient_temp_c: f64,
+    pub wind_speed_m_per_s: f64,
+    /// 계산 구간 수 (1 이상)
+    pub segments: usize,
+}
+
+/// 구간 하나의 결과 (구간 출구 상태).
+#[derive(Debug, Clone)]
+pub struct LineSegmentResult {
+    /// 구간 끝 위치 (입구 기준) [m]
+    pub distance_m: f64,
+    pub pressure_bar_abs: f64,
+    pub temp_c: f64,
+    /// 건도. 과열이면 `None`
+    pub dryness: Option<f64>,
+    /// 구간 열손실 [W] (음수면 열 획득)
+    pub heat_loss_w: f64,
+    /// 마찰 + 정수두 압력손실 [bar]
+    pub pressure_drop_bar: f64,
+    /// 구간 입구 상태의 균질 유속 [m/s]
+    pub velocity_m_per_s: f64,
+}
+
+/// 라인 응축 계산 결과.
+#[derive(Debug, Clone)]
+pub struct LineCondensationResult {
+    /// 보온 배관 단위길이 열통과율 [W/m·K]
+    pub ua_w_per_mk: f64,
+    /// 전체 열손실 [kW]
+    pub heat_loss_kw: f64,
+    /// 배관 안에서 생긴 응축수 [kg/h]
+    pub condensate_kg_per_h: f64,
+    pub outlet_pressure_bar_abs: f64,
+    pub outlet_temp_c: f64,
+    /// 출구 건도. 과열이면 `None`
+    pub outlet_dryness: Option<f64>,
+    /// 입구 − 출구 압력 [bar]
+    pub pressure_drop_bar: f64,
+    /// 과열 입구가 포화에 닿은 위치 [m]
+    pub superheat_lost_at_m: Option<f64>,
+    pub segments: Vec<LineSegmentResult>,
+    pub warnings: Vec<LineCondensationWarning>,
+}
+
+/// 배관 응축 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LineCondensationWarning {
+    /// 보온 없는 나관
+    BarePipe,
+    /// 입구 과열 증기가 배관 도중 포화에 닿음
+    SuperheatLost { at_m: f64 },
+    /// 출구 건도가 [`LOW_DRYNESS`] 미만
+    LowOutletDryness { dryness: f64 },
+    /// 응축수 발생, 드립 레그 권장 개수
+    DripLegs {
+        condensate_kg_per_h: f64,
+        drip_legs: f64,
+    },
+}
+
+impl CalcWarning for LineCondensationWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            LineCondensationWarning::BarePipe | LineCondensationWarning::DripLegs { .. } => {
+                Severity::Info
+            }
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            LineCondensationWarning::BarePipe => "warning.line_condensation.bare_pipe",
+            LineCondensationWarning::SuperheatLost { .. } => {
+                "warning.line_condensation.superheat_lost"
+            }
+            LineCondensationWarning::LowOutletDryness { .. } => {
+                "warning.line_condensation.low_dryness"
+            }
+            LineCondensationWarning::DripLegs { .. } => "warning.line_condensation.drip_legs",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            LineCondensationWarning::BarePipe => Vec::new(),
+            LineCondensationWarning::SuperheatLost { at_m } => vec![("at", format!("{at_m:.0}"))],
+            LineCondensationWarning::LowOutletDryness { dryness } => vec![
+                ("x", format!("{dryness:.3}")),
+                ("min", format!("{LOW_DRYNESS}")),
+            ],
+            LineCondensationWarning::DripLegs {
+                condensate_kg_per_h,
+                drip_legs,
+            } => vec![
+                ("condensate", format!("{condensate_kg_per_h:.1}")),
+                ("legs", format!("{drip_legs:.0}")),
+                ("spacing", format!("{DRIP_LEG_SPACING_M:.0}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for LineCondensationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            LineCondensationWarning::BarePipe => {
+                f.write_str("나관이라 열손실이 큽니다. 보온 두께를 입력해 비교하세요.")
+            }
+            LineCondensationWarning::SuperheatLost { at_m } => write!(
+                f,
+                "입구 과열 증기가 {at_m:.0} m 지점에서 포화에 닿아 그 뒤부터 응축이 생깁니다."
+            ),
+            LineCondensationWarning::LowOutletDryness { dryness } => write!(
+                f,
+                "출구 건도 {dryness:.3}이 {LOW_DRYNESS} 미만입니다. 사용처 앞에 기수분리기와 트랩을 두세요."
+            ),
+            LineCondensationWarning::DripLegs {
+                condensate_kg_per_h,
+                drip_legs,
+            } => write!(
+                f,
+                "응축수 {condensate_kg_per_h:.1} kg/h가 생깁니다. 약 {drip_legs:.0}곳(간격 {DRIP_LEG_SPACING_M:.0} m 이하)에 드립 레그와 트랩을 두세요."
+            ),
+        }
+    }
+}
+
+/// 라인 응축 계산 오류.
+#[derive(Debug, Clone)]
+pub enum LineCondensationError {
+    InvalidInput(&'static str),
+    /// IF97 물성 계산 실패
+    Properties(&'static str),
+    /// 배관 도중에 증기가 모두 응축됨
+    FullyCondensed {
+        distance_m: f64,
+    },
+    /// 배관 도중에 압력이 0 이하로 떨어짐
+    PressureExhausted {
+        distance_m: f64,
+    },
+}
+
+impl std::fmt::Display for LineCondensationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LineCondensationError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            LineCondensationError::Properties(msg) => write!(f, "물성 계산 오류: {msg}"),
+            LineCondensationError::FullyCondensed { distance_m } => write!(
+                f,
+                "입구에서 {distance_m:.0} m 지점에서 증기가 모두 응축됩니다."
+            ),
+            LineCondensationError::PressureExhausted { distance_m } => write!(
+                f,
+                "입구에서 {distance_m:.0} m 지점에서 압력이 모두 소진됩니다. 관경을 키우세요."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for LineCondensationError {}
+
+/// (p, h)의 증기 상태: (온도[°C], 건도(과열이면 None), 포화 액 엔탈피[J/kg])
+fn steam_state(
+    p_bar_abs: f64,
+    h_j_per_kg: f64,
+) -> Result<(f64, Option<f64>, f64), LineCondensationError> {
+    let (hf, hg) =
+        if97::saturation_enthalpies(p_bar_abs).map_err(LineCondensationError::Properties)?;
+    if h_j_per_kg >= hg {
+        let t = if97::temperature_from_ph(p_bar_abs, h_j_per_kg)
+            .map_err(LineCondensationError::Properties)?;
+        Ok((t, None, hf))
+    } else {
+        let t = if97::saturation_temp_c_from_pressure_bar_abs(p_bar_abs)
+            .map_err(LineCondensationError::Properties)?;
+        Ok((t, Some((h_j_per_kg - hf) / (hg - hf)), hf))
+    }
+}
+
+/// 배관을 따라 열손실, 응축량, 출구 건도/온도와 압력손실을 계산한다.
+pub fn compute_line_condensation(
+    input: LineCondensationInput,
+) -> Result<LineCondensationResult, LineCondensationError> {
+    if input.mass_flow_kg_per_h <= 0.0 || input.inlet_pressure_bar_abs <= 0.0 {
+        return Err(LineCondensationError::InvalidInput(
+            "유량과 입구 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input.inner_diameter_m <= 0.0 || input.outer_diameter_m <= input.inner_diameter_m {
+        return Err(LineCondensationError::InvalidInput(
+            "외경은 내경보다 커야 합니다.",
+        ));
+    }
+    if input.length_m <= 0.0 {
+        return Err(LineCondensationError::InvalidInput(
+            "배관 길이는 0보다 커야 합니다.",
+        ));
+    }
+    if input.insulation_thickness_m < 0.0
+        || (input.insulation_thickness_m > 0.0 && input.insulation_conductivity_w_mk <= 0.0)
+    {
+        return Err(LineCondensationError::InvalidInput(
+            "보온 두께와 열전도율을 확인하세요.",
+        ));
+    }
+    if input.segments == 0 || input.segments > MAX_SEGMENTS {
+        return Err(LineCondensationError::InvalidInput(
+            "구간 수는 1~1000이어야 합니다.",
+        ));
+    }
+
+    let m = input.mass_flow_kg_per_h / 3600.0;
+    let p_in = input.inlet_pressure_bar_abs;
+    let (hf_in, hg_in) =
+        if97::saturation_enthalpies(p_in).map_err(LineCondensationError::Properties)?;
+    let h_in = match input.inlet {
+        LineInletState::Superheated { temp_c } => {
+            let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_in)
+                .map_err(LineCondensationError::Properties)?;
+            if temp_c < t_sat - 0.01 {
+                return Err(LineCondensationError::InvalidInput(
+                    "입구 온도가 포화 온도보다 낮습니다. 습증기는 건도로 입력하세요.",
+                ));
+            }
+            if temp_c <= t_sat {
+                hg_in
+            } else {
+                let (h, _, _) =
+                    if97::region_props(p_in, temp_c).map_err(LineCondensationError::Properties)?;
+                h
+            }
+        }
+        LineInletState::Wet { dryness } => {
+            if !(dryness > 0.0 && dryness <= 1.0) {
+                return Err(LineCondensationError::InvalidInput(
+                    "입구 건도는 0 초과 1 이하여야 합니다.",
+                ));
+            }
+            hf_in + dryness * (hg_in - hf_in)
+        }
+    };
+    let (_, x_in, _) = steam_state(p_in, h_in)?;
+
+    let ua = insulated_pipe_ua_w_per_mk(
+        input.outer_diameter_m,
+        input.insulation_thickness_m,
+        input.insulation_conductivity_w_mk,
+        input.wind_speed_m_per_s,
+    );
+    let n = input.segments;
+    let dl = input.length_m / n as f64;
+    let dz = (input.outlet_elevation_m - input.inlet_elevation_m) / n as f64;
+
+    let mut p = p_in;
+    let mut h = h_in;
+    let mut x = x_in;
+    let mut superheat_lost_at_m = None;
+    let mut heat_loss_w = 0.0;
+    let mut segments = Vec::with_capacity(n);
+    for i in 0..n {
+        let (t, x_seg, _) = steam_state(p, h)?;
+        let q = ua * dl * (t - input.ambient_temp_c);
+
+        // 구간 입구 상태의 물성으로 압력손실을 구한다 (과열이면 x = 1의 단상 유동)
+        let (rho_f, rho_g, mu_f, mu_g, quality) = match x_seg {
+            Some(xs) => {
+                let (_, vf, _) =
+                    if97::props_from_px(p, 0.0).map_err(LineCondensationError::Properties)?;
+                let (_, vg, _) =
+                    if97::props_from_px(p, 1.0).map_err(LineCondensationError::Properties)?;
+                let (mu_f, mu_g) =
+                    if97::saturation_viscosities(p).map_err(LineCondensationError::Properties)?;
+                (1.0 / vf, 1.0 / vg, mu_f, mu_g, xs)
+            }
+            None => {
+                let (v, _, mu) =
+                    if97::flow_props_from_ph(p, h).map_err(LineCondensationError::Properties)?;
+                // 액상 물성은 x = 1이라 쓰이지 않지만 0 나눗셈을 피하려고 같은 값을 넣는다
+                (1.0 / v, 1.0 / v, mu, mu, 1.0)
+            }
+        };
+        let loss = two_phase_pressure_loss(TwoPhasePressureLossInput {
+            mass_flow_kg_per_h: input.mass_flow_kg_per_h,
+            quality,
+            liquid_density_kg_per_m3: rho_f,
+            vapor_density_kg_per_m3: rho_g,
+            liquid_viscosity_pa_s: mu_f,
+            vapor_viscosity_pa_s: mu_g,
+            diameter_m: input.inner_diameter_m,
+            length_m: dl,
+            roughness_m: input.roughness_m,
+            inlet_elevation_m: 0.0,
+            outlet_elevation_m: dz,
+        });
+        let distance_m = dl * (i + 1) as f64;
+        let dp = loss.total_drop_bar();
+        p -= dp;
+        if p <= 0.0 {
+            return Err(LineCondensationError::PressureExhausted { distance_m });
+        }
+        h -= q / m;
+        heat_loss_w += q;
+        let (t_out, x_out, hf) = steam_state(p, h)?;
+        if h <= hf {
+            return Err(LineCondensationError::FullyCondensed { distance_m });
+        }
+        if x.is_none() && x_out.is_some() && superheat_lost_at_m.is_none() {
+            superheat_lost_at_m = Some(distance_m);
+        }
+        x = x_out;
+        segments.push(LineSegmentResult {
+            distance_m,
+            pressure_bar_abs: p,
+            temp_c: t_out,
+            dryness: x_out,
+            heat_loss_w: q,
+            pressure_drop_bar: dp,
+            velocity_m_per_s: loss.velocity_m_per_s,
+        });
+    }
+
+    let outlet_temp_c = segments.last().map_or(0.0, |s| s.temp_c);
+    // 과열 상태는 건도 1로 보고 증기 질량 감소분을 응축수로 친다
+    let condensate_kg_per_h =
+        (input.mass_flow_kg_per_h * (x_in.unwrap_or(1.0) - x.unwrap_or(1.0))).max(0.0);
+
+    let mut warnings = Vec::new();
+    if input.insulation_thickness_m == 0.0 {
+        warnings.push(LineCondensationWarning::BarePipe);
+    }
+    if let Some(at) = superheat_lost_at_m {
+        warnings.push(LineCondensationWarning::SuperheatLost { at_m: at });
+    }
+    if let Some(x_out) = x.filter(|&v| v < LOW_DRYNESS) {
+        warnings.push(LineCondensationWarning::LowOutletDryness { dryness: x_out });
+    }
+    if condensate_kg_per_h > 0.0 {
+        let drip_legs = (input.length_m / DRIP_LEG_SPACING_M).ceil().max(1.0);
+        warnings.push(LineCondensationWarning::DripLegs {
+            condensate_kg_per_h,
+            drip_legs,
+        });
+    }
+
+    Ok(LineCondensationResult {
+        ua_w_per_mk: ua,
+        heat_loss_kw: heat_loss_w / 1000.0,
+        condensate_kg_per_h,
+        outlet_pressure_bar_abs: p,
+        outlet_temp_c,
+        outlet_dryness: x,
+        pressure_drop_bar: p_in - p,
+        superheat_lost_at_m,
+        segments,
+        warnings,
+    })
+}
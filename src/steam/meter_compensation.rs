@@ -0,0 +1,503 @@
+//! 증기 유량계 밀도 보정.
+//! 유량계는 설계(교정) 압력/온도의 밀도로 질량 유량을 표시하므로, 실제 상태가 다르면 IF97 밀도 비로 고친다.
+//! 차압식(오리피스/노즐/벤투리)은 ṁ ∝ √(ΔP·ρ)라서 ṁ = ṁ표시·√(ρ/ρ설계),
+//! 와류식은 체적 유량을 재므로 ṁ = ṁ표시·ρ/ρ설계이다. 차압식 팽창계수 변화는 무시한다.
+//! 온도가 없거나 포화 온도 이하이면 그 압력의 건포화 증기로 본다.
+//! 히스토리안 CSV(timestamp,flow_kg_h,pressure_bar_g[,temperature_c])를 행마다 보정하는 일괄 모드도 있다.
+
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+use serde::{Deserialize, Serialize};
+
+/// 과열도가 이보다 작으면 습증기 오차를 경고한다 [K]
+pub const LOW_SUPERHEAT_K: f64 = 5.0;
+/// 보정 계수가 1에서 이만큼 넘게 벗어나면 설계 조건 재검토를 권한다
+const LARGE_CORRECTION: f64 = 0.10;
+
+/// 유량계 측정 원리.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeterPrinciple {
+    /// 오리피스/노즐/벤투리/V-cone 차압식
+    DifferentialPressure,
+    /// 와류식 (체적 유량 측정)
+    Vortex,
+}
+
+impl MeterPrinciple {
+    pub const ALL: [MeterPrinciple; 2] =
+        [MeterPrinciple::DifferentialPressure, MeterPrinciple::Vortex];
+
+    /// i18n 키
+    pub fn key(self) -> &'static str {
+        match self {
+            MeterPrinciple::DifferentialPressure => "meter.dp",
+            MeterPrinciple::Vortex => "meter.vortex",
+        }
+    }
+
+    /// 영어 기본 이름
+    pub fn name(self) -> &'static str {
+        match self {
+            MeterPrinciple::DifferentialPressure => {
+                "Differential pressure (orifice/nozzle/venturi)"
+            }
+            MeterPrinciple::Vortex => "Vortex",
+        }
+    }
+
+    /// 밀도 비 ρ/ρ설계에서 질량 유량 보정 계수
+    pub fn correction_factor(self, density_ratio: f64) -> f64 {
+        match self {
+            MeterPrinciple::DifferentialPressure => density_ratio.sqrt(),
+            MeterPrinciple::Vortex => density_ratio,
+        }
+    }
+}
+
+/// 증기 상태. 온도가 `None`이면 건포화 증기.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteamCondition {
+    pub pressure_bar_abs: f64,
+    pub temperature_c: Option<f64>,
+}
+
+/// 밀도 계산 결과.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SteamDensity {
+    pub density_kg_per_m3: f64,
+    /// 계산에 쓴 온도 [°C] (포화로 본 경우 포화 온도)
+    pub temperature_c: f64,
+    /// 과열도 [K] (포화로 본 경우 0)
+    pub superheat_k: f64,
+    /// 입력 온도가 포화 온도 이하라 건포화로 바꿨는지
+    pub clamped_to_saturation: bool,
+}
+
+/// 유량계 한 점 보정 결과.
+#[derive(Debug, Clone)]
+pub struct MeterCompensationResult {
+    pub design: SteamDensity,
+    pub actual: SteamDensity,
+    /// ρ실제/ρ설계
+    pub density_ratio: f64,
+    /// 질량 유량 보정 계수 (보정 유량 / 표시 유량)
+    pub correction_factor: f64,
+    pub indicated_flow_kg_per_h: f64,
+    pub corrected_flow_kg_per_h: f64,
+    pub warnings: Vec<MeterCompensationWarning>,
+}
+
+/// 유량계 보정 경고. `*Rows`는 일괄 보정에서 해당 행 수를 센다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MeterCompensationWarning {
+    /// 실제 온도가 포화 온도 이하라 건포화로 계산
+    ClampedToSaturation {
+        temp_c: f64,
+    },
+    /// 과열도가 [`LOW_SUPERHEAT_K`] 미만
+    LowSuperheat {
+        superheat_k: f64,
+    },
+    /// 보정 계수가 1에서 많이 벗어남
+    LargeCorrection {
+        factor: f64,
+    },
+    SaturatedRows {
+        rows: usize,
+    },
+    LowSuperheatRows {
+        rows: usize,
+    },
+    LargeCorrectionRows {
+        rows: usize,
+    },
+}
+
+impl CalcWarning for MeterCompensationWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            MeterCompensationWarning::ClampedToSaturation { .. } => {
+                "warning.meter_comp.clamped_to_saturation"
+            }
+            MeterCompensationWarning::LowSuperheat { .. } => "warning.meter_comp.low_superheat",
+            MeterCompensationWarning::LargeCorrection { .. } => {
+                "warning.meter_comp.large_correction"
+            }
+            MeterCompensationWarning::SaturatedRows { .. } => "warning.meter_comp.saturated_rows",
+            MeterCompensationWarning::LowSuperheatRows { .. } => {
+                "warning.meter_comp.low_superheat_rows"
+            }
+            MeterCompensationWarning::LargeCorrectionRows { .. } => {
+                "warning.meter_comp.large_correction_rows"
+            }
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            MeterCompensationWarning::ClampedToSaturation { temp_c } => {
+                vec![("temp", format!("{temp_c:.1}"))]
+            }
+            MeterCompensationWarning::LowSuperheat { superheat_k } => {
+                vec![("superheat", format!("{superheat_k:.1}"))]
+            }
+            MeterCompensationWarning::LargeCorrection { factor } => {
+                vec![("factor", format!("{factor:.3}"))]
+            }
+            MeterCompensationWarning::SaturatedRows { rows } => {
+                vec![("rows", rows.to_string())]
+            }
+            MeterCompensationWarning::LowSuperheatRows { rows } => vec![
+                ("rows", rows.to_string()),
+                ("min", format!("{LOW_SUPERHEAT_K:.0}")),
+            ],
+            MeterCompensationWarning::LargeCorrectionRows { rows } => vec![
+                ("rows", rows.to_string()),
+                ("pct", format!("{:.0}", LARGE_CORRECTION * 100.0)),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for MeterCompensationWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            MeterCompensationWarning::ClampedToSaturation { temp_c } => write!(
+                f,
+                "실제 온도가 포화 온도 {temp_c:.1} °C 이하라 건포화 증기로 계산했습니다. 습증기이면 유량계가 실제보다 많이 표시합니다."
+            ),
+            MeterCompensationWarning::LowSuperheat { superheat_k } => write!(
+                f,
+                "과열도 {superheat_k:.1} K가 작아 배관 중 응축으로 습증기가 될 수 있습니다."
+            ),
+            MeterCompensationWarning::LargeCorrection { factor } => write!(
+                f,
+                "보정 계수 {factor:.3}이 1에서 많이 벗어나 유량계 설계 조건(차압 범위/교정) 재검토를 권합니다."
+            ),
+            MeterCompensationWarning::SaturatedRows { rows } => write!(
+                f,
+                "{rows}개 행의 온도가 포화 온도 이하라 건포화 증기로 계산했습니다."
+            ),
+            MeterCompensationWarning::LowSuperheatRows { rows } => write!(
+                f,
+                "{rows}개 행의 과열도가 {LOW_SUPERHEAT_K:.0} K 미만입니다."
+            ),
+            MeterCompensationWarning::LargeCorrectionRows { rows } => write!(
+                f,
+                "{rows}개 행의 보정 계수가 ±{:.0}%를 넘습니다. 유량계 설계 조건을 확인하세요.",
+                LARGE_CORRECTION * 100.0
+            ),
+        }
+    }
+}
+
+/// 히스토리안 CSV 한 행.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistorianRow {
+    /// CSV 줄 번호 (1부터)
+    pub line: usize,
+    pub timestamp: String,
+    pub indicated_flow_kg_per_h: f64,
+    pub actual: SteamCondition,
+}
+
+/// 보정된 히스토리안 한 행.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompensatedRow {
+    pub timestamp: String,
+    pub indicated_flow_kg_per_h: f64,
+    pub pressure_bar_abs: f64,
+    /// 계산에 쓴 온도 [°C]
+    pub temperature_c: f64,
+    pub density_kg_per_m3: f64,
+    pub correction_factor: f64,
+    pub corrected_flow_kg_per_h: f64,
+}
+
+/// 일괄 보정 결과.
+#[derive(Debug, Clone)]
+pub struct CompensatedSeries {
+    pub design: SteamDensity,
+    pub rows: Vec<CompensatedRow>,
+    /// 행 평균 표시/보정 유량 [kg/h]
+    pub mean_indicated_flow_kg_per_h: f64,
+    pub mean_corrected_flow_kg_per_h: f64,
+    pub min_correction_factor: f64,
+    pub max_correction_factor: f64,
+    pub warnings: Vec<MeterCompensationWarning>,
+}
+
+/// 유량계 보정 오류.
+#[derive(Debug, Clone)]
+pub enum MeterCompensationError {
+    InvalidInput(&'static str),
+    If97(String),
+    /// 히스토리안 CSV 행 오류 (1부터 세는 줄 번호)
+    Csv {
+        line: usize,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for MeterCompensationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeterCompensationError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            MeterCompensationError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            MeterCompensationError::Csv { line, message } => write!(f, "CSV {line}행: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for MeterCompensationError {}
+
+/// 증기 밀도. 온도가 없거나 포화 온도 이하이면 건포화 증기 밀도를 쓴다.
+pub fn steam_density(cond: SteamCondition) -> Result<SteamDensity, MeterCompensationError> {
+    let p = cond.pressure_bar_abs;
+    if !(p > 0.0 && p < 220.64) {
+        return Err(MeterCompensationError::InvalidInput(
+            "압력은 0보다 크고 임계압(220.64 bar abs)보다 낮아야 합니다.",
+        ));
+    }
+    let if97_err = |e: &str| MeterCompensationError::If97(e.into());
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p).map_err(if97_err)?;
+    match cond.temperature_c {
+        Some(t) if t > t_sat => {
+            let (_, v, _) = if97::region_props(p, t).map_err(if97_err)?;
+            Ok(SteamDensity {
+                density_kg_per_m3: 1.0 / v,
+                temperature_c: t,
+                superheat_k: t - t_sat,
+                clamped_to_saturation: false,
+            })
+        }
+        t => {
+            let (_, vg, _) = if97::props_from_px(p, 1.0).map_err(if97_err)?;
+            Ok(SteamDensity {
+                density_kg_per_m3: 1.0 / vg,
+                temperature_c: t_sat,
+                superheat_k: 0.0,
+                clamped_to_saturation: t.is_some(),
+            })
+        }
+    }
+}
+
+/// 설계 밀도를 이미 구한 상태에서 한 점을 보정한다.
+fn compensate_with_design(
+    principle: MeterPrinciple,
+    design: &SteamDensity,
+    actual: SteamCondition,
+    indicated_flow_kg_per_h: f64,
+) -> Result<(SteamDensity, f64, f64), MeterCompensationError> {
+    if indicated_flow_kg_per_h < 0.0 || indicated_flow_kg_per_h.is_nan() {
+        return Err(MeterCompensationError::InvalidInput(
+            "표시 유량은 0 이상이어야 합니다.",
+        ));
+    }
+    let actual = steam_density(actual)?;
+    let ratio = actual.density_kg_per_m3 / design.density_kg_per_m3;
+    Ok((actual, ratio, principle.correction_factor(ratio)))
+}
+
+/// 표시 유량을 실제 압력/온도의 밀도로 보정한다.
+pub fn compensate_flow(
+    principle: MeterPrinciple,
+    design: SteamCondition,
+    actual: SteamCondition,
+    indicated_flow_kg_per_h: f64,
+) -> Result<MeterCompensationResult, MeterCompensationError> {
+    let design = steam_density(design)?;
+    let (actual, density_ratio, correction_factor) =
+        compensate_with_design(principle, &design, actual, indicated_flow_kg_per_h)?;
+
+    let mut warnings = Vec::new();
+    if actual.clamped_to_saturation {
+        warnings.push(MeterCompensationWarning::ClampedToSaturation {
+            temp_c: actual.temperature_c,
+        });
+    } else if actual.superheat_k > 0.0 && actual.superheat_k < LOW_SUPERHEAT_K {
+        warnings.push(MeterCompensationWarning::LowSuperheat {
+            superheat_k: actual.superheat_k,
+        });
+    }
+    if (correction_factor - 1.0).abs() > LARGE_CORRECTION {
+        warnings.push(MeterCompensationWarning::LargeCorrection {
+            factor: correction_factor,
+        });
+    }
+
+    Ok(MeterCompensationResult {
+        design,
+        actual,
+        density_ratio,
+        correction_factor,
+        indicated_flow_kg_per_h,
+        corrected_flow_kg_per_h: indicated_flow_kg_per_h * correction_factor,
+        warnings,
+    })
+}
+
+/// 히스토리안 CSV를 읽는다. 열은 `timestamp,flow_kg_h,pressure_bar_g[,temperature_c]`이고
+/// 온도가 비어 있으면 포화 증기로 본다. 게이지 압력은 `atmosphere_bar_abs`를 더해 절대압으로 바꾼다.
+/// 첫 줄이 헤더(유량 열이 숫자가 아님)면 건너뛰고, 빈 줄과 `#` 주석 줄은 무시한다.
+pub fn parse_historian_csv(
+    text: &str,
+    atmosphere_bar_abs: f64,
+) -> Result<Vec<HistorianRow>, MeterCompensationError> {
+    let mut rows = Vec::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+        let csv_err = |message: String| MeterCompensationError::Csv {
+            line: line_no,
+            message,
+        };
+        if cols.len() < 3 {
+            return Err(csv_err(format!(
+                "열이 3개 이상 필요합니다 (현재 {}개).",
+                cols.len()
+            )));
+        }
+        if rows.is_empty() && cols[1].parse::<f64>().is_err() {
+            continue;
+        }
+        let num = |i: usize, name: &str| {
+            cols[i].parse::<f64>().map_err(|_| {
+                csv_err(format!(
+                    "{name} 값 '{}'을 숫자로 읽을 수 없습니다.",
+                    cols[i]
+                ))
+            })
+        };
+        let flow = num(1, "flow_kg_h")?;
+        if flow < 0.0 {
+            return Err(csv_err(format!("유량 {flow}는 0 이상이어야 합니다.")));
+        }
+        let temperature_c = match cols.get(3).filter(|c| !c.is_empty()) {
+            Some(_) => Some(num(3, "temperature_c")?),
+            None => None,
+        };
+        rows.push(HistorianRow {
+            line: line_no,
+            timestamp: cols[0].to_string(),
+            indicated_flow_kg_per_h: flow,
+            actual: SteamCondition {
+                pressure_bar_abs: num(2, "pressure_bar_g")? + atmosphere_bar_abs,
+                temperature_c,
+            },
+        });
+    }
+    Ok(rows)
+}
+
+/// 히스토리안 행을 모두 보정한다. 행 오류는 그 행의 CSV 줄 번호로 알린다.
+pub fn compensate_series(
+    principle: MeterPrinciple,
+    design: SteamCondition,
+    rows: &[HistorianRow],
+) -> Result<CompensatedSeries, MeterCompensationError> {
+    if rows.is_empty() {
+        return Err(MeterCompensationError::InvalidInput(
+            "보정할 행이 없습니다.",
+        ));
+    }
+    let design = steam_density(design)?;
+    let mut out = Vec::with_capacity(rows.len());
+    let mut saturated_rows = 0;
+    let mut low_superheat_rows = 0;
+    let mut large_rows = 0;
+    for row in rows {
+        let (actual, _, factor) =
+            compensate_with_design(principle, &design, row.actual, row.indicated_flow_kg_per_h)
+                .map_err(|e| MeterCompensationError::Csv {
+                    line: row.line,
+                    message: e.to_string(),
+                })?;
+        if actual.clamped_to_saturation {
+            saturated_rows += 1;
+        } else if actual.superheat_k > 0.0 && actual.superheat_k < LOW_SUPERHEAT_K {
+            low_superheat_rows += 1;
+        }
+        if (factor - 1.0).abs() > LARGE_CORRECTION {
+            large_rows += 1;
+        }
+        out.push(CompensatedRow {
+            timestamp: row.timestamp.clone(),
+            indicated_flow_kg_per_h: row.indicated_flow_kg_per_h,
+            pressure_bar_abs: row.actual.pressure_bar_abs,
+            temperature_c: actual.temperature_c,
+            density_kg_per_m3: actual.density_kg_per_m3,
+            correction_factor: factor,
+            corrected_flow_kg_per_h: row.indicated_flow_kg_per_h * factor,
+        });
+    }
+
+    let n = out.len() as f64;
+    let mean_indicated_flow_kg_per_h =
+        out.iter().map(|r| r.indicated_flow_kg_per_h).sum::<f64>() / n;
+    let mean_corrected_flow_kg_per_h =
+        out.iter().map(|r| r.corrected_flow_kg_per_h).sum::<f64>() / n;
+    let min_correction_factor = out
+        .iter()
+        .map(|r| r.correction_factor)
+        .fold(f64::INFINITY, f64::min);
+    let max_correction_factor = out
+        .iter()
+        .map(|r| r.correction_factor)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    let mut warnings = Vec::new();
+    if saturated_rows > 0 {
+        warnings.push(MeterCompensationWarning::SaturatedRows {
+            rows: saturated_rows,
+        });
+    }
+    if low_superheat_rows > 0 {
+        warnings.push(MeterCompensationWarning::LowSuperheatRows {
+            rows: low_superheat_rows,
+        });
+    }
+    if large_rows > 0 {
+        warnings.push(MeterCompensationWarning::LargeCorrectionRows { rows: large_rows });
+    }
+
+    Ok(CompensatedSeries {
+        design,
+        rows: out,
+        mean_indicated_flow_kg_per_h,
+        mean_corrected_flow_kg_per_h,
+        min_correction_factor,
+        max_correction_factor,
+        warnings,
+    })
+}
+
+/// 보정 결과를 CSV로 쓴다 (히스토리안 재입력용, 압력은 절대압).
+pub fn compensated_series_csv(series: &CompensatedSeries) -> String {
+    let mut out = String::from(
+        "timestamp,indicated_flow_kg_h,pressure_bar_a,temperature_c,density_kg_m3,correction_factor,corrected_flow_kg_h\n",
+    );
+    for r in &series.rows {
+        out.push_str(&format!(
+            "{},{:.3},{:.4},{:.2},{:.4},{:.5},{:.3}\n",
+            r.timestamp,
+            r.indicated_flow_kg_per_h,
+            r.pressure_bar_abs,
+            r.temperature_c,
+            r.density_kg_per_m3,
+            r.correction_factor,
+            r.corrected_flow_kg_per_h
+        ));
+    }
+    out
+}
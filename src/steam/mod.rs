@@ -1,13 +1,40 @@
 //! 증기 관련 계산 모듈 모음.
 
+pub mod accumulator;
+pub mod attemperator;
 pub mod boiler_efficiency;
 pub mod condensate_load;
+pub mod critical_flow;
+pub mod cycle;
+pub mod drum;
+pub mod ejector;
+pub mod fire_case;
+pub mod heat_balance;
+pub mod hrsg;
 pub mod if97;
+pub mod line_case;
+pub mod line_condensation;
+pub mod meter_compensation;
+pub mod network;
+pub mod reference_table;
+pub mod relief;
+pub mod relief_scenario;
+pub mod sparger;
+pub mod steam_blow;
 pub mod steam_cost;
 pub mod steam_dryness;
+pub mod steam_leak;
 pub mod steam_piping;
+pub mod steam_purity;
 pub mod steam_tables;
 pub mod steam_valves;
+pub mod table_booklet;
+pub mod totalizer;
+pub mod tank_heating;
+pub mod turbine_bypass;
+pub mod valve_actuator;
+pub mod valve_authority;
+pub mod vent_silencer;
 
 pub use steam_piping::*;
 pub use steam_tables::*;
@@ -0,0 +1,621 @@
+//! 플랜트 증기 헤더 수지(Steam balance) 계산.
+//! HP/MP/LP 헤더와 보일러, 감압·감온(Letdown) 스테이션, 터빈, 사용처, 벤트를 연결해
+//! 헤더별 질량/에너지 수지를 풀고 불균형(벤트 또는 메이크업 필요량)을 보고한다.
+//! 단위: 유량 kg/h, 압력 bar(abs), 온도 °C, 엔탈피 kJ/kg, 동력/열량 kW.
+
+use crate::parallel;
+use crate::steam::if97;
+
+/// 헤더 인덱스. `SteamNetwork::add_header`가 돌려준다.
+pub type HeaderId = usize;
+
+/// 질량 불균형 경고 기준(헤더 통과 유량 대비 비율).
+const IMBALANCE_WARN_FRACTION: f64 = 0.005;
+
+/// 증기 헤더.
+#[derive(Debug, Clone)]
+pub struct Header {
+    pub name: String,
+    pub pressure_bar_abs: f64,
+    /// 헤더 온도(°C). `None`이면 포화 증기로 본다.
+    pub temperature_c: Option<f64>,
+}
+
+impl Header {
+    pub fn new(name: &str, pressure_bar_abs: f64, temperature_c: Option<f64>) -> Self {
+        Self {
+            name: name.to_string(),
+            pressure_bar_abs,
+            temperature_c,
+        }
+    }
+}
+
+/// 감압 스테이션 유량 지정 방식.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LetdownFlow {
+    /// 상류 증기 유량 고정(kg/h)
+    Fixed(f64),
+    /// 하류 헤더 부족분을 메우도록 유량을 계산한다.
+    Balance,
+}
+
+/// 터빈 배기 조건.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TurbineExhaust {
+    /// 배압 터빈: 하류 헤더로 배기
+    Header(HeaderId),
+    /// 복수 터빈: 지정 압력(bar abs)의 복수기로 배기(네트워크 밖으로 나감)
+    Condensing { pressure_bar_abs: f64 },
+}
+
+/// 네트워크 구성 요소.
+#[derive(Debug, Clone)]
+pub enum Element {
+    /// 보일러(헤더로 증기 공급). 출구 온도가 없으면 헤더 조건으로 공급한다고 본다.
+    Boiler {
+        name: String,
+        header: HeaderId,
+        flow_kg_h: f64,
+        outlet_temp_c: Option<f64>,
+    },
+    /// 감압·감온 스테이션. 스프레이수로 하류 목표 온도를 맞춘다.
+    Letdown {
+        name: String,
+        from: HeaderId,
+        to: HeaderId,
+        flow: LetdownFlow,
+        spray_water_temp_c: f64,
+        /// 출구 목표 온도(°C). `None`이면 하류 헤더 조건에 맞춘다.
+        outlet_temp_c: Option<f64>,
+    },
+    /// 증기 터빈(상류 헤더에서 추기).
+    Turbine {
+        name: String,
+        from: HeaderId,
+        exhaust: TurbineExhaust,
+        flow_kg_h: f64,
+        isentropic_efficiency: f64,
+    },
+    /// 증기 사용처(공정 열교환기 등).
+    User {
+        name: String,
+        header: HeaderId,
+        flow_kg_h: f64,
+    },
+    /// 벤트(대기 방출).
+    Vent {
+        name: String,
+        header: HeaderId,
+        flow_kg_h: f64,
+    },
+}
+
+impl Element {
+    pub fn name(&self) -> &str {
+        match self {
+            Element::Boiler { name, .. }
+            | Element::Letdown { name, .. }
+            | Element::Turbine { name, .. }
+            | Element::User { name, .. }
+            | Element::Vent { name, .. } => name,
+        }
+    }
+
+    fn kind(&self) -> &'static str {
+        match self {
+            Element::Boiler { .. } => "boiler",
+            Element::Letdown { .. } => "letdown",
+            Element::Turbine { .. } => "turbine",
+            Element::User { .. } => "user",
+            Element::Vent { .. } => "vent",
+        }
+    }
+}
+
+/// 네트워크 계산 오류.
+#[derive(Debug)]
+pub enum NetworkError {
+    /// 입력값 오류
+    InvalidInput(&'static str),
+    /// 존재하지 않는 헤더를 참조함
+    UnknownHeader { element: String, header: HeaderId },
+    /// IF97 물성 계산 실패
+    If97 { element: String, msg: &'static str },
+}
+
+impl std::fmt::Display for NetworkError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NetworkError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            NetworkError::UnknownHeader { element, header } => {
+                write!(f, "{element}: 존재하지 않는 헤더 #{header}")
+            }
+            NetworkError::If97 { element, msg } => write!(f, "{element}: IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for NetworkError {}
+
+/// 헤더별 수지 결과.
+#[derive(Debug, Clone)]
+pub struct HeaderBalance {
+    pub name: String,
+    pub pressure_bar_abs: f64,
+    /// 헤더 조건의 증기 엔탈피(kJ/kg)
+    pub enthalpy_kj_per_kg: f64,
+    pub mass_in_kg_h: f64,
+    pub mass_out_kg_h: f64,
+    /// 유입 - 유출(kg/h). 양수면 벤트 필요, 음수면 메이크업(외부 공급) 필요.
+    pub imbalance_kg_h: f64,
+    /// 유입 증기 혼합 엔탈피(kJ/kg). 유입이 없으면 NaN.
+    pub mixed_inlet_enthalpy_kj_per_kg: f64,
+    /// Σ(유입 m·h) - Σ(유출 m)·h_header (kW)
+    pub energy_imbalance_kw: f64,
+}
+
+/// 요소별 계산 결과.
+#[derive(Debug, Clone)]
+pub struct ElementResult {
+    pub name: String,
+    pub kind: &'static str,
+    /// 상류(또는 공급) 증기 유량(kg/h)
+    pub steam_flow_kg_h: f64,
+    /// 하류로 나가는 유량(kg/h). 감압 스테이션은 스프레이수 포함.
+    pub outlet_flow_kg_h: f64,
+    /// 감압 스테이션 스프레이수(kg/h)
+    pub spray_water_kg_h: f64,
+    /// 터빈 축동력(kW)
+    pub power_kw: f64,
+    /// 출구 엔탈피(kJ/kg)
+    pub outlet_enthalpy_kj_per_kg: f64,
+}
+
+/// 네트워크 전체 결과.
+#[derive(Debug, Clone)]
+pub struct NetworkResult {
+    pub headers: Vec<HeaderBalance>,
+    pub elements: Vec<ElementResult>,
+    pub total_boiler_steam_kg_h: f64,
+    pub total_turbine_power_kw: f64,
+    pub total_spray_water_kg_h: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 헤더와 요소로 구성된 증기 네트워크.
+#[derive(Debug, Clone, Default)]
+pub struct SteamNetwork {
+    pub headers: Vec<Header>,
+    pub elements: Vec<Element>,
+}
+
+impl SteamNetwork {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_header(&mut self, header: Header) -> HeaderId {
+        self.headers.push(header);
+        self.headers.len() - 1
+    }
+
+    pub fn add(&mut self, element: Element) {
+        self.elements.push(element);
+    }
+
+    /// 질량/에너지 수지를 푼다.
+    /// 헤더를 압력이 낮은 순서로 처리하면서 `LetdownFlow::Balance` 스테이션의 유량을
+    /// 하류 부족분으로 정하고, 그 상류 추기량을 상위 헤더 유출에 반영한다.
+    pub fn solve(&self) -> Result<NetworkResult, NetworkError> {
+        self.validate()?;
+
+        let header_h = self
+            .headers
+            .iter()
+            .map(|h| header_enthalpy(h).map_err(|msg| if97_err(&h.name, msg)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // 요소별 결과 초기화(고정 유량 요소는 여기서 바로 계산, 요소끼리 독립이라 병렬 가능)
+        let mut results: Vec<ElementResult> =
+            parallel::try_map(&self.elements, |el| self.evaluate_fixed(el, &header_h))?;
+
+        let mut order: Vec<HeaderId> = (0..self.headers.len()).collect();
+        order.sort_by(|a, b| {
+            self.headers[*a]
+                .pressure_bar_abs
+                .total_cmp(&self.headers[*b].pressure_bar_abs)
+        });
+
+        let mut warnings = Vec::new();
+        for &hid in &order {
+            let (mass_in, mass_out) = self.header_flows(hid, &results);
+            let deficit = mass_out - mass_in;
+            let balancing: Vec<usize> = self
+                .elements
+                .iter()
+                .enumerate()
+                .filter(|(_, el)| {
+                    matches!(el, Element::Letdown { to, flow: LetdownFlow::Balance, .. } if *to == hid)
+                })
+                .map(|(i, _)| i)
+                .collect();
+            let Some((&first, rest)) = balancing.split_first() else {
+                continue;
+            };
+            if !rest.is_empty() {
+                warnings.push(format!(
+                    "{} 헤더에 유량 균형 감압 스테이션이 여러 개입니다. '{}'만 부족분을 담당합니다.",
+                    self.headers[hid].name,
+                    self.elements[first].name()
+                ));
+            }
+            let outlet = deficit.max(0.0);
+            results[first] = self.evaluate_letdown(&self.elements[first], outlet, &header_h)?;
+        }
+
+        let mut headers = Vec::with_capacity(self.headers.len());
+        for (hid, h) in self.headers.iter().enumerate() {
+            let (mass_in, mass_out) = self.header_flows(hid, &results);
+            let energy_in_kw = self.header_energy_in(hid, &results) / 3600.0;
+            let energy_out_kw = mass_out * header_h[hid] / 3600.0;
+            let imbalance = mass_in - mass_out;
+            let throughput = mass_in.max(mass_out);
+            if throughput > 0.0 && imbalance.abs() > IMBALANCE_WARN_FRACTION * throughput {
+                if imbalance > 0.0 {
+                    warnings.push(format!(
+                        "{} 헤더 증기 잉여 {:.1} kg/h → 벤트 또는 하위 헤더 감압이 필요합니다.",
+                        h.name, imbalance
+                    ));
+                } else {
+                    warnings.push(format!(
+                        "{} 헤더 증기 부족 {:.1} kg/h → 메이크업(외부 공급) 또는 상위 헤더 감압이 필요합니다.",
+                        h.name, -imbalance
+                    ));
+                }
+            }
+            headers.push(HeaderBalance {
+                name: h.name.clone(),
+                pressure_bar_abs: h.pressure_bar_abs,
+                enthalpy_kj_per_kg: header_h[hid],
+                mass_in_kg_h: mass_in,
+                mass_out_kg_h: mass_out,
+                imbalance_kg_h: imbalance,
+                mixed_inlet_enthalpy_kj_per_kg: if mass_in > 0.0 {
+                    energy_in_kw * 3600.0 / mass_in
+                } else {
+                    f64::NAN
+                },
+                energy_imbalance_kw: energy_in_kw - energy_out_kw,
+            });
+        }
+
+        for (el, r) in self.elements.iter().zip(&results) {
+            if let Element::Letdown {
+                outlet_temp_c: Some(t),
+                to,
+                ..
+            } = el
+            {
+                let sat = if97::saturation_temp_c_from_pressure_bar_abs(
+                    self.headers[*to].pressure_bar_abs,
+                )
+                .unwrap_or(f64::NAN);
+                if *t < sat + 5.0 {
+                    warnings.push(format!(
+                        "{}: 출구 목표 온도 {:.1}°C가 포화온도 {:.1}°C에 너무 가깝습니다(습증기 우려).",
+                        r.name, t, sat
+                    ));
+                }
+            }
+        }
+
+        Ok(NetworkResult {
+            total_boiler_steam_kg_h: results
+                .iter()
+                .filter(|r| r.kind == "boiler")
+                .map(|r| r.steam_flow_kg_h)
+                .sum(),
+            total_turbine_power_kw: results.iter().map(|r| r.power_kw).sum(),
+            total_spray_water_kg_h: results.iter().map(|r| r.spray_water_kg_h).sum(),
+            headers,
+            elements: results,
+            warnings,
+        })
+    }
+
+    fn validate(&self) -> Result<(), NetworkError> {
+        if self.headers.is_empty() {
+            return Err(NetworkError::InvalidInput("헤더가 하나 이상 필요합니다."));
+        }
+        if self.headers.iter().any(|h| h.pressure_bar_abs <= 0.0) {
+            return Err(NetworkError::InvalidInput("헤더 압력은 0보다 커야 합니다."));
+        }
+        let check = |el: &Element, hid: HeaderId| {
+            if hid < self.headers.len() {
+                Ok(())
+            } else {
+                Err(NetworkError::UnknownHeader {
+                    element: el.name().to_string(),
+                    header: hid,
+                })
+            }
+        };
+        for el in &self.elements {
+            match el {
+                Element::Boiler {
+                    header, flow_kg_h, ..
+                }
+                | Element::User {
+                    header, flow_kg_h, ..
+                }
+                | Element::Vent {
+                    header, flow_kg_h, ..
+                } => {
+                    check(el, *header)?;
+                    if *flow_kg_h < 0.0 {
+                        return Err(NetworkError::InvalidInput("유량은 0 이상이어야 합니다."));
+                    }
+                }
+                Element::Letdown { from, to, flow, .. } => {
+                    check(el, *from)?;
+                    check(el, *to)?;
+                    if self.headers[*from].pressure_bar_abs <= self.headers[*to].pressure_bar_abs {
+                        return Err(NetworkError::InvalidInput(
+                            "감압 스테이션의 상류 헤더 압력은 하류보다 높아야 합니다.",
+                        ));
+                    }
+                    if matches!(flow, LetdownFlow::Fixed(f) if *f < 0.0) {
+                        return Err(NetworkError::InvalidInput("유량은 0 이상이어야 합니다."));
+                    }
+                }
+                Element::Turbine {
+                    from,
+                    exhaust,
+                    flow_kg_h,
+                    isentropic_efficiency,
+                    ..
+                } => {
+                    check(el, *from)?;
+                    let p_out = match exhaust {
+                        TurbineExhaust::Header(to) => {
+                            check(el, *to)?;
+                            self.headers[*to].pressure_bar_abs
+                        }
+                        TurbineExhaust::Condensing { pressure_bar_abs } => *pressure_bar_abs,
+                    };
+                    if p_out <= 0.0 || p_out >= self.headers[*from].pressure_bar_abs {
+                        return Err(NetworkError::InvalidInput(
+                            "터빈 배기 압력은 0보다 크고 입구 헤더 압력보다 낮아야 합니다.",
+                        ));
+                    }
+                    if *flow_kg_h < 0.0 || !(0.0..=1.0).contains(isentropic_efficiency) {
+                        return Err(NetworkError::InvalidInput(
+                            "터빈 유량은 0 이상, 효율은 0~1 범위여야 합니다.",
+                        ));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// 유량이 고정된 요소를 계산한다. 균형 감압 스테이션은 유량 0으로 초기화한다.
+    fn evaluate_fixed(
+        &self,
+        el: &Element,
+        header_h: &[f64],
+    ) -> Result<ElementResult, NetworkError> {
+        let name = el.name().to_string();
+        let blank = |steam: f64, h_out: f64| ElementResult {
+            name: name.clone(),
+            kind: el.kind(),
+            steam_flow_kg_h: steam,
+            outlet_flow_kg_h: steam,
+            spray_water_kg_h: 0.0,
+            power_kw: 0.0,
+            outlet_enthalpy_kj_per_kg: h_out,
+        };
+        match el {
+            Element::Boiler {
+                header,
+                flow_kg_h,
+                outlet_temp_c,
+                ..
+            } => {
+                let h = match outlet_temp_c {
+                    Some(t) => {
+                        if97::region_props(self.headers[*header].pressure_bar_abs, *t)
+                            .map_err(|msg| if97_err(&name, msg))?
+                            .0
+                            / 1000.0
+                    }
+                    None => header_h[*header],
+                };
+                Ok(blank(*flow_kg_h, h))
+            }
+            Element::User {
+                header, flow_kg_h, ..
+            }
+            | Element::Vent {
+                header, flow_kg_h, ..
+            } => Ok(blank(*flow_kg_h, header_h[*header])),
+            Element::Letdown { flow, .. } => match flow {
+                LetdownFlow::Fixed(steam) => self.evaluate_letdown_steam(el, *steam, header_h),
+                LetdownFlow::Balance => self.evaluate_letdown(el, 0.0, header_h),
+            },
+            Element::Turbine {
+                from,
+                exhaust,
+                flow_kg_h,
+                isentropic_efficiency,
+                ..
+            } => {
+                let hdr = &self.headers[*from];
+                let t_in = match hdr.temperature_c {
+                    Some(t) => t,
+                    None => if97::saturation_temp_c_from_pressure_bar_abs(hdr.pressure_bar_abs)
+                        .map_err(|msg| if97_err(&name, msg))?,
+                };
+                let h_in = header_h[*from];
+                // 포화 헤더는 건포화 증기 엔트로피를 쓰기 위해 포화온도보다 약간 높은 점에서 구한다.
+                let t_eval = if hdr.temperature_c.is_some() {
+                    t_in
+                } else {
+                    t_in + 1e-6
+                };
+                let (_, _, s_in) = if97::region_props(hdr.pressure_bar_abs, t_eval)
+                    .map_err(|msg| if97_err(&name, msg))?;
+                let p_out = match exhaust {
+                    TurbineExhaust::Header(to) => self.headers[*to].pressure_bar_abs,
+                    TurbineExhaust::Condensing { pressure_bar_abs } => *pressure_bar_abs,
+                };
+                let h_is = if97::enthalpy_from_ps(p_out, s_in)
+                    .map_err(|msg| if97_err(&name, msg))?
+                    / 1000.0;
+                let h_out = h_in - isentropic_efficiency * (h_in - h_is);
+                let mut r = blank(*flow_kg_h, h_out);
+                r.power_kw = flow_kg_h * (h_in - h_out) / 3600.0;
+                Ok(r)
+            }
+        }
+    }
+
+    fn letdown_enthalpies(
+        &self,
+        el: &Element,
+        header_h: &[f64],
+    ) -> Result<(f64, f64, f64), NetworkError> {
+        let Element::Letdown {
+            name,
+            from,
+            to,
+            spray_water_temp_c,
+            outlet_temp_c,
+            ..
+        } = el
+        else {
+            unreachable!("letdown 요소만 전달된다");
+        };
+        let h_in = header_h[*from];
+        let p_to = self.headers[*to].pressure_bar_abs;
+        let h_target = match outlet_temp_c {
+            Some(t) => {
+                if97::region_props(p_to, *t)
+                    .map_err(|msg| if97_err(name, msg))?
+                    .0
+                    / 1000.0
+            }
+            None => header_h[*to],
+        };
+        // 스프레이수는 상류 압력의 압축수로 본다.
+        let h_w = if97::region1_props(self.headers[*from].pressure_bar_abs, *spray_water_temp_c)
+            .map_err(|msg| if97_err(name, msg))?
+            .0
+            / 1000.0;
+        // 감압만으로(등엔탈피) 목표보다 낮아지는 경우는 스프레이 없이 통과시킨다.
+        Ok((h_in, h_target.min(h_in), h_w))
+    }
+
+    /// 하류 출구 유량(스프레이 포함)을 기준으로 감압 스테이션을 계산한다.
+    fn evaluate_letdown(
+        &self,
+        el: &Element,
+        outlet_kg_h: f64,
+        header_h: &[f64],
+    ) -> Result<ElementResult, NetworkError> {
+        let (h_in, h_out, h_w) = self.letdown_enthalpies(el, header_h)?;
+        let steam = outlet_kg_h * (h_out - h_w) / (h_in - h_w);
+        Ok(ElementResult {
+            name: el.name().to_string(),
+            kind: el.kind(),
+            steam_flow_kg_h: steam,
+            outlet_flow_kg_h: outlet_kg_h,
+            spray_water_kg_h: outlet_kg_h - steam,
+            power_kw: 0.0,
+            outlet_enthalpy_kj_per_kg: h_out,
+        })
+    }
+
+    /// 상류 증기 유량을 기준으로 감압 스테이션을 계산한다.
+    fn evaluate_letdown_steam(
+        &self,
+        el: &Element,
+        steam_kg_h: f64,
+        header_h: &[f64],
+    ) -> Result<ElementResult, NetworkError> {
+        let (h_in, h_out, h_w) = self.letdown_enthalpies(el, header_h)?;
+        let outlet = steam_kg_h * (h_in - h_w) / (h_out - h_w);
+        Ok(ElementResult {
+            name: el.name().to_string(),
+            kind: el.kind(),
+            steam_flow_kg_h: steam_kg_h,
+            outlet_flow_kg_h: outlet,
+            spray_water_kg_h: outlet - steam_kg_h,
+            power_kw: 0.0,
+            outlet_enthalpy_kj_per_kg: h_out,
+        })
+    }
+
+    /// 헤더 유입/유출 질량(kg/h).
+    fn header_flows(&self, hid: HeaderId, results: &[ElementResult]) -> (f64, f64) {
+        let mut mass_in = 0.0;
+        let mut mass_out = 0.0;
+        for (el, r) in self.elements.iter().zip(results) {
+            match el {
+                Element::Boiler { header, .. } if *header == hid => mass_in += r.outlet_flow_kg_h,
+                Element::User { header, .. } | Element::Vent { header, .. } if *header == hid => {
+                    mass_out += r.steam_flow_kg_h
+                }
+                Element::Letdown { from, to, .. } => {
+                    if *from == hid {
+                        mass_out += r.steam_flow_kg_h;
+                    }
+                    if *to == hid {
+                        mass_in += r.outlet_flow_kg_h;
+                    }
+                }
+                Element::Turbine { from, exhaust, .. } => {
+                    if *from == hid {
+                        mass_out += r.steam_flow_kg_h;
+                    }
+                    if *exhaust == TurbineExhaust::Header(hid) {
+                        mass_in += r.outlet_flow_kg_h;
+                    }
+                }
+                _ => {}
+            }
+        }
+        (mass_in, mass_out)
+    }
+
+    /// 헤더 유입 에너지(kJ/h).
+    fn header_energy_in(&self, hid: HeaderId, results: &[ElementResult]) -> f64 {
+        self.elements
+            .iter()
+            .zip(results)
+            .filter(|(el, _)| match el {
+                Element::Boiler { header, .. } => *header == hid,
+                Element::Letdown { to, .. } => *to == hid,
+                Element::Turbine { exhaust, .. } => *exhaust == TurbineExhaust::Header(hid),
+                _ => false,
+            })
+            .map(|(_, r)| r.outlet_flow_kg_h * r.outlet_enthalpy_kj_per_kg)
+            .sum()
+    }
+}
+
+/// 헤더 조건의 증기 엔탈피(kJ/kg).
+fn header_enthalpy(h: &Header) -> Result<f64, &'static str> {
+    match h.temperature_c {
+        Some(t) => Ok(if97::region_props(h.pressure_bar_abs, t)?.0 / 1000.0),
+        None => Ok(if97::saturation_enthalpies(h.pressure_bar_abs)?.1 / 1000.0),
+    }
+}
+
+fn if97_err(element: &str, msg: &'static str) -> NetworkError {
+    NetworkError::If97 {
+        element: element.to_string(),
+        msg,
+    }
+}
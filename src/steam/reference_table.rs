@@ -0,0 +1,234 @@
+//! 증기표 참조값과 IF97 계산의 비교 (인수 시험 전 검증용).
+//! 참조값은 IAPWS-95 정식(NIST Chemistry WebBook, ASME 증기표와 같은 기준)을 유효 숫자 4~5자리로
+//! 옮긴 표이며 크레이트에 함께 들어 있다. 입력 상태에 가장 가까운 표 점에서 두 값을 구해
+//! 물성별 편차(%)를 돌려준다. 표 값 자체의 반올림 때문에 0.05% 안팎의 편차는 정상이다.
+
+use crate::steam::if97;
+use crate::steam::steam_tables::SteamState;
+
+/// 참조 데이터 출처 표기
+pub const REFERENCE_SOURCE: &str = "IAPWS-95 (NIST Chemistry WebBook / ASME steam tables)";
+
+/// 포화표 한 줄: 온도[°C], 압력[kPa], vf, vg[m³/kg], hf, hg[kJ/kg], sf, sg[kJ/kg·K]
+const SATURATION_TABLE: [[f64; 8]; 18] = [
+    [
+        20.0, 2.3392, 0.001002, 57.762, 83.915, 2537.4, 0.2965, 8.6652,
+    ],
+    [
+        40.0, 7.3851, 0.001008, 19.515, 167.53, 2573.5, 0.5724, 8.2557,
+    ],
+    [
+        60.0, 19.947, 0.001017, 7.6670, 251.18, 2608.8, 0.8313, 7.9082,
+    ],
+    [
+        80.0, 47.416, 0.001029, 3.4053, 335.02, 2642.9, 1.0756, 7.6105,
+    ],
+    [
+        100.0, 101.42, 0.001043, 1.6720, 419.17, 2675.6, 1.3072, 7.3541,
+    ],
+    [
+        120.0, 198.67, 0.001060, 0.89133, 503.81, 2706.0, 1.5279, 7.1292,
+    ],
+    [
+        140.0, 361.53, 0.001080, 0.50850, 589.16, 2733.5, 1.7392, 6.9294,
+    ],
+    [
+        160.0, 618.23, 0.001102, 0.30680, 675.47, 2757.0, 1.9426, 6.7488,
+    ],
+    [
+        180.0, 1002.8, 0.001127, 0.19384, 763.05, 2777.2, 2.1392, 6.5850,
+    ],
+    [
+        200.0, 1554.9, 0.001157, 0.12721, 852.26, 2792.0, 2.3305, 6.4302,
+    ],
+    [
+        220.0, 2319.6, 0.001190, 0.086094, 943.55, 2801.0, 2.5177, 6.2840,
+    ],
+    [
+        240.0, 3346.9, 0.001229, 0.059707, 1037.5, 2803.0, 2.7020, 6.1423,
+    ],
+    [
+        260.0, 4692.3, 0.001276, 0.042175, 1134.8, 2796.6, 2.8857, 6.0010,
+    ],
+    [
+        280.0, 6416.6, 0.001332, 0.030165, 1236.7, 2779.9, 3.0702, 5.8556,
+    ],
+    [
+        300.0, 8587.9, 0.001404, 0.021659, 1344.8, 2749.6, 3.2552, 5.7059,
+    ],
+    [
+        320.0, 11284.0, 0.001499, 0.015493, 1461.8, 2700.6, 3.4494, 5.5372,
+    ],
+    [
+        340.0, 14601.0, 0.001638, 0.010786, 1594.5, 2621.8, 3.6601, 5.3356,
+    ],
+    [
+        360.0, 18666.0, 0.001895, 0.006950, 1761.5, 2481.5, 3.9167, 5.0536,
+    ],
+];
+
+/// 과열표 한 점: 압력[MPa], 온도[°C], v[m³/kg], h[kJ/kg], s[kJ/kg·K]
+const SUPERHEATED_TABLE: [[f64; 5]; 16] = [
+    [0.1, 100.0, 1.6959, 2675.8, 7.3611],
+    [0.1, 200.0, 2.1724, 2875.5, 7.8356],
+    [0.1, 300.0, 2.6389, 3074.5, 8.2171],
+    [0.5, 200.0, 0.42503, 2855.8, 7.0610],
+    [0.5, 300.0, 0.52261, 3064.6, 7.4614],
+    [1.0, 200.0, 0.20602, 2828.3, 6.6955],
+    [1.0, 250.0, 0.23275, 2943.1, 6.9265],
+    [1.0, 300.0, 0.25799, 3051.6, 7.1246],
+    [1.0, 400.0, 0.30661, 3264.5, 7.4670],
+    [2.0, 300.0, 0.12551, 3024.2, 6.7684],
+    [2.0, 400.0, 0.15122, 3248.4, 7.1292],
+    [4.0, 400.0, 0.07343, 3214.5, 6.7714],
+    [4.0, 500.0, 0.08644, 3446.0, 7.0922],
+    [5.0, 300.0, 0.045347, 2925.7, 6.2110],
+    [10.0, 500.0, 0.032811, 3375.1, 6.5995],
+    [10.0, 600.0, 0.038378, 3625.8, 6.9045],
+];
+
+/// 과열표에서 가까운 점을 고를 때 ln(p) 차이 1과 같게 치는 온도 차이 [K]
+const SUPERHEAT_T_SCALE_K: f64 = 100.0;
+
+/// 비교에 쓴 참조 표 점의 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReferencePointKind {
+    Saturation,
+    Superheated,
+}
+
+/// 비교에 쓴 참조 표 점.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferencePoint {
+    pub kind: ReferencePointKind,
+    /// 압력 [bar abs]
+    pub p_bar_abs: f64,
+    /// 온도 [°C]
+    pub t_c: f64,
+}
+
+/// 물성 하나의 참조값/IF97 값/편차.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyDeviation {
+    /// 물성 기호 (p, v_f, v_g, h_f, h_g, s_f, s_g, v, h, s)
+    pub property: &'static str,
+    pub unit: &'static str,
+    pub reference: f64,
+    /// IF97 계산값. 계산에 실패하면 NaN
+    pub if97: f64,
+    /// (IF97 - 참조값) / 참조값 × 100. 계산 실패 시 NaN
+    pub deviation_pct: f64,
+}
+
+impl PropertyDeviation {
+    fn new(property: &'static str, unit: &'static str, reference: f64, if97: f64) -> Self {
+        Self {
+            property,
+            unit,
+            reference,
+            if97,
+            deviation_pct: (if97 - reference) / reference * 100.0,
+        }
+    }
+}
+
+/// 표 점 하나에서의 비교 결과.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReferenceComparison {
+    pub point: ReferencePoint,
+    pub properties: Vec<PropertyDeviation>,
+}
+
+impl ReferenceComparison {
+    /// 편차 절대값의 최대 [%]. 계산 실패가 있으면 NaN
+    pub fn max_abs_deviation_pct(&self) -> f64 {
+        self.properties.iter().fold(0.0, |max: f64, d| {
+            if max.is_nan() || d.deviation_pct.is_nan() {
+                f64::NAN
+            } else {
+                max.max(d.deviation_pct.abs())
+            }
+        })
+    }
+}
+
+fn saturation_row(row: &[f64; 8]) -> ReferenceComparison {
+    let t_c = row[0];
+    let p_if97 = if97::saturation_pressure_bar_abs_from_temp_c(t_c).unwrap_or(f64::NAN);
+    let (hf, vf, sf) = if97::props_from_px(p_if97, 0.0).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+    let (hg, vg, sg) = if97::props_from_px(p_if97, 1.0).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+    ReferenceComparison {
+        point: ReferencePoint {
+            kind: ReferencePointKind::Saturation,
+            p_bar_abs: row[1] / 100.0,
+            t_c,
+        },
+        properties: vec![
+            PropertyDeviation::new("p", "bar(a)", row[1] / 100.0, p_if97),
+            PropertyDeviation::new("v_f", "m3/kg", row[2], vf),
+            PropertyDeviation::new("v_g", "m3/kg", row[3], vg),
+            PropertyDeviation::new("h_f", "kJ/kg", row[4], hf / 1000.0),
+            PropertyDeviation::new("h_g", "kJ/kg", row[5], hg / 1000.0),
+            PropertyDeviation::new("s_f", "kJ/kgK", row[6], sf / 1000.0),
+            PropertyDeviation::new("s_g", "kJ/kgK", row[7], sg / 1000.0),
+        ],
+    }
+}
+
+fn superheated_row(row: &[f64; 5]) -> ReferenceComparison {
+    let p_bar_abs = row[0] * 10.0;
+    let t_c = row[1];
+    let (h, v, s) = if97::region_props(p_bar_abs, t_c).unwrap_or((f64::NAN, f64::NAN, f64::NAN));
+    ReferenceComparison {
+        point: ReferencePoint {
+            kind: ReferencePointKind::Superheated,
+            p_bar_abs,
+            t_c,
+        },
+        properties: vec![
+            PropertyDeviation::new("v", "m3/kg", row[2], v),
+            PropertyDeviation::new("h", "kJ/kg", row[3], h / 1000.0),
+            PropertyDeviation::new("s", "kJ/kgK", row[4], s / 1000.0),
+        ],
+    }
+}
+
+/// 포화 온도 `t_c`[°C]에 가장 가까운 포화표 점에서 IF97과 비교한다.
+pub fn compare_saturation(t_c: f64) -> ReferenceComparison {
+    let row = SATURATION_TABLE
+        .iter()
+        .min_by(|a, b| (a[0] - t_c).abs().total_cmp(&(b[0] - t_c).abs()))
+        .expect("saturation table is not empty");
+    saturation_row(row)
+}
+
+/// (p, T)에 가장 가까운 과열표 점에서 IF97과 비교한다.
+/// 거리는 ln(p) 차이와 온도 차이/100 K를 더해 잰다.
+pub fn compare_superheated(p_bar_abs: f64, t_c: f64) -> ReferenceComparison {
+    let distance = |row: &[f64; 5]| {
+        (p_bar_abs / (row[0] * 10.0)).ln().abs() + (row[1] - t_c).abs() / SUPERHEAT_T_SCALE_K
+    };
+    let row = SUPERHEATED_TABLE
+        .iter()
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .expect("superheated table is not empty");
+    superheated_row(row)
+}
+
+/// 증기표 계산 결과에 맞는 표 점에서 비교한다. 과열 상태면 과열표, 아니면 포화표를 쓴다.
+pub fn compare_state(state: &SteamState) -> ReferenceComparison {
+    if state.superheated_enthalpy_kj_per_kg.is_some() {
+        compare_superheated(state.pressure_bar, state.temperature_c)
+    } else {
+        compare_saturation(state.saturation_temperature_c)
+    }
+}
+
+/// 참조 표의 모든 점(포화표 → 과열표 순)에서 IF97과 비교한다.
+pub fn compare_all() -> Vec<ReferenceComparison> {
+    SATURATION_TABLE
+        .iter()
+        .map(saturation_row)
+        .chain(SUPERHEATED_TABLE.iter().map(superheated_row))
+        .collect()
+}
@@ -0,0 +1,275 @@
+//! 안전밸브(PSV) 방출 배관(tailpipe) 배압 계산.
+//! 방출 유량과 분출 조건에서 출구관 말단부터 밸브 출구까지 단열 압축성 유동(Fanno형)을 역으로 적분해
+//! 누적 배압(built-up backpressure)과 출구 Mach를 구한다. 물성은 IF97(p, h)로 계산한다.
+
+use crate::goal_seek::{self, GoalSeekError};
+use crate::steam::if97;
+use crate::water::water_piping::darcy_friction_factor;
+
+const ATM_BAR: f64 = 1.01325;
+/// 배관을 나누는 구간 수
+const SEGMENTS: usize = 100;
+
+/// 안전밸브 형식. 허용 누적 배압 기준이 다르다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReliefValveType {
+    /// 일반형: 누적 배압 ≤ 설정압의 10% (API 520, 과압 10% 기준)
+    Conventional,
+    /// 벨로즈 평형형: 누적 배압 ≤ 설정압의 30%로 본다 (제조사 곡선 확인 필요)
+    BalancedBellows,
+}
+
+impl ReliefValveType {
+    /// 허용 누적 배압(설정압 대비 %)
+    pub fn allowable_backpressure_percent(self) -> f64 {
+        match self {
+            ReliefValveType::Conventional => 10.0,
+            ReliefValveType::BalancedBellows => 30.0,
+        }
+    }
+}
+
+/// 방출 배관 배압 계산 입력.
+#[derive(Debug, Clone)]
+pub struct ReliefBackpressureInput {
+    /// 방출 유량(kg/h)
+    pub relief_flow_kg_per_h: f64,
+    /// 설정압(bar g)
+    pub set_pressure_bar_g: f64,
+    /// 과압(%) - 보통 10
+    pub overpressure_percent: f64,
+    /// 분출 온도(°C). `None`이면 분출 압력의 건포화 증기로 본다.
+    pub relieving_temp_c: Option<f64>,
+    /// 방출관 내경(m)
+    pub tailpipe_diameter_m: f64,
+    /// 방출관 길이(m)
+    pub tailpipe_length_m: f64,
+    /// 부속 손실계수 합계(엘보, 출구 등)
+    pub fittings_k_sum: f64,
+    pub roughness_m: f64,
+    /// 방출 말단 압력(bar abs). 대기 방출이면 1.01325.
+    pub exit_pressure_bar_abs: f64,
+    pub valve_type: ReliefValveType,
+}
+
+/// 방출 배관 배압 계산 결과.
+#[derive(Debug, Clone)]
+pub struct ReliefBackpressureResult {
+    /// 분출 압력(bar abs) = 설정압 × (1 + 과압) + 대기압
+    pub relieving_pressure_bar_abs: f64,
+    /// 밸브 출구 누적 배압(bar g)
+    pub built_up_backpressure_bar_g: f64,
+    /// 설정압 대비 누적 배압(%)
+    pub backpressure_percent_of_set: f64,
+    /// 허용 누적 배압(설정압 대비 %)
+    pub allowable_percent_of_set: f64,
+    /// 방출관 말단 압력(bar abs). 말단 초킹 시 대기압보다 높다.
+    pub exit_pressure_bar_abs: f64,
+    pub exit_velocity_m_per_s: f64,
+    pub exit_mach: f64,
+    pub outlet_velocity_m_per_s: f64,
+    /// 밸브 출구 플랜지 Mach
+    pub outlet_mach: f64,
+    /// 말단 초킹 여부
+    pub choked_at_exit: bool,
+    pub friction_factor: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 방출 배관 계산 오류.
+#[derive(Debug, Clone)]
+pub enum ReliefError {
+    InvalidInput(&'static str),
+    If97(String),
+    GoalSeek(GoalSeekError),
+    /// 방출관이 너무 작아 분출 압력으로도 유량을 흘릴 수 없음
+    TailpipeTooSmall {
+        required_bar_abs: f64,
+    },
+}
+
+impl std::fmt::Display for ReliefError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReliefError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            ReliefError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            ReliefError::GoalSeek(e) => write!(f, "배압 계산 실패: {e}"),
+            ReliefError::TailpipeTooSmall { required_bar_abs } => write!(
+                f,
+                "방출관이 너무 작습니다. 필요한 배압이 분출 압력 이상입니다 ({required_bar_abs:.3} bar abs)."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ReliefError {}
+
+/// 한 지점의 유동 상태
+#[derive(Debug, Clone, Copy)]
+struct FlowPoint {
+    specific_volume: f64,
+    velocity: f64,
+    mach: f64,
+    viscosity: f64,
+}
+
+/// 정체 엔탈피 h0와 질량유속 G가 주어질 때 압력 p에서의 상태. h = h0 - u²/2, u = G·v 를 반복해서 푼다.
+fn flow_point(p_bar_abs: f64, h0: f64, mass_flux: f64) -> Result<FlowPoint, ReliefError> {
+    let mut h = h0;
+    let mut props = (0.0, 0.0, 0.0);
+    for _ in 0..50 {
+        props = if97::flow_props_from_ph(p_bar_abs, h).map_err(|e| ReliefError::If97(e.into()))?;
+        let u = mass_flux * props.0;
+        let h_new = h0 - 0.5 * u * u;
+        if (h_new - h).abs() < 1e-6 {
+            break;
+        }
+        h = h_new;
+    }
+    let (v, w, mu) = props;
+    let velocity = mass_flux * v;
+    Ok(FlowPoint {
+        specific_volume: v,
+        velocity,
+        mach: velocity / w,
+        viscosity: mu,
+    })
+}
+
+/// 안전밸브 방출관의 누적 배압과 출구 Mach를 계산한다.
+/// 말단이 초킹되면(Mach 1) 말단 압력을 먼저 찾은 뒤, 구간별로 마찰 + 가속 압력차를 더해 밸브 출구까지 거슬러 올라간다.
+pub fn relief_backpressure(
+    input: ReliefBackpressureInput,
+) -> Result<ReliefBackpressureResult, ReliefError> {
+    if input.relief_flow_kg_per_h <= 0.0 || input.set_pressure_bar_g <= 0.0 {
+        return Err(ReliefError::InvalidInput(
+            "방출 유량과 설정압은 0보다 커야 합니다.",
+        ));
+    }
+    if input.tailpipe_diameter_m <= 0.0
+        || input.tailpipe_length_m < 0.0
+        || input.fittings_k_sum < 0.0
+        || input.roughness_m < 0.0
+    {
+        return Err(ReliefError::InvalidInput(
+            "방출관 내경/길이/K값/조도가 잘못되었습니다.",
+        ));
+    }
+    if input.overpressure_percent < 0.0 || input.exit_pressure_bar_abs <= 0.0 {
+        return Err(ReliefError::InvalidInput(
+            "과압과 말단 압력이 잘못되었습니다.",
+        ));
+    }
+
+    let p_rel = input.set_pressure_bar_g * (1.0 + input.overpressure_percent / 100.0) + ATM_BAR;
+    if p_rel <= input.exit_pressure_bar_abs {
+        return Err(ReliefError::InvalidInput(
+            "분출 압력이 말단 압력보다 높아야 합니다.",
+        ));
+    }
+    let h0 = match input.relieving_temp_c {
+        Some(t) => {
+            if97::region_props(p_rel, t)
+                .map_err(|e| ReliefError::If97(e.into()))?
+                .0
+        }
+        None => {
+            if97::saturation_enthalpies(p_rel)
+                .map_err(|e| ReliefError::If97(e.into()))?
+                .1
+        }
+    };
+
+    let d = input.tailpipe_diameter_m;
+    let area = std::f64::consts::PI * d * d / 4.0;
+    let g = input.relief_flow_kg_per_h / 3600.0 / area;
+
+    // 말단 압력: 대기압에서 아음속이면 대기압, 아니면 Mach = 1이 되는 압력
+    // 운동에너지가 정체 엔탈피를 넘어 물성을 구할 수 없는 압력은 초음속 영역으로 본다.
+    let mach_at = |p: f64| flow_point(p, h0, g).map(|s| s.mach).unwrap_or(10.0);
+    let (p_exit, choked) = if mach_at(input.exit_pressure_bar_abs) < 1.0 {
+        (input.exit_pressure_bar_abs, false)
+    } else {
+        if mach_at(p_rel) >= 1.0 {
+            return Err(ReliefError::TailpipeTooSmall {
+                required_bar_abs: p_rel,
+            });
+        }
+        let res = goal_seek::goal_seek(|p| -mach_at(p), -1.0, input.exit_pressure_bar_abs, p_rel)
+            .map_err(ReliefError::GoalSeek)?;
+        (res.x, true)
+    };
+
+    // 구간별 역적분: p_u - p_d = (f·ΔL/D + ΔK)·G²·v̄/2 + G²·(v_d - v_u)
+    let dl = input.tailpipe_length_m / SEGMENTS as f64;
+    let dk = input.fittings_k_sum / SEGMENTS as f64;
+    let exit = flow_point(p_exit, h0, g)?;
+    let mut p = p_exit;
+    let mut down = exit;
+    let mut friction_factor = 0.0;
+    for _ in 0..SEGMENTS {
+        let seg_residual = |p_u: f64| -> f64 {
+            let Ok(up) = flow_point(p_u, h0, g) else {
+                return f64::NAN;
+            };
+            let mu = 0.5 * (up.viscosity + down.viscosity);
+            let f = darcy_friction_factor(g * d / mu, input.roughness_m, d);
+            let v_avg = 0.5 * (up.specific_volume + down.specific_volume);
+            let dp_pa = (f * dl / d + dk) * g * g * v_avg / 2.0
+                + g * g * (down.specific_volume - up.specific_volume);
+            (p_u - p) * 1e5 - dp_pa
+        };
+        // 상한: 분출 압력을 조금 넘는 지점까지 본다
+        let hi = p_rel * 1.5;
+        if seg_residual(hi) < 0.0 {
+            return Err(ReliefError::TailpipeTooSmall {
+                required_bar_abs: hi,
+            });
+        }
+        let res = goal_seek::goal_seek(seg_residual, 0.0, p, hi).map_err(ReliefError::GoalSeek)?;
+        p = res.x;
+        down = flow_point(p, h0, g)?;
+        friction_factor = darcy_friction_factor(g * d / down.viscosity, input.roughness_m, d);
+    }
+    if p >= p_rel {
+        return Err(ReliefError::TailpipeTooSmall {
+            required_bar_abs: p,
+        });
+    }
+
+    let built_up = p - ATM_BAR;
+    let percent = built_up / input.set_pressure_bar_g * 100.0;
+    let allowable = input.valve_type.allowable_backpressure_percent();
+    let mut warnings = Vec::new();
+    if percent > allowable {
+        warnings.push(format!(
+            "누적 배압이 설정압의 {percent:.1}%로 허용치 {allowable:.0}%를 넘습니다. 방출관 확대 또는 밸브 형식 변경이 필요합니다."
+        ));
+    }
+    if choked {
+        warnings.push(format!(
+            "방출관 말단에서 초킹이 발생합니다 (말단 압력 {p_exit:.3} bar abs)."
+        ));
+    }
+    if down.mach > 0.7 {
+        warnings.push(format!(
+            "밸브 출구 Mach {:.2}로 높습니다. 소음/진동에 주의하세요.",
+            down.mach
+        ));
+    }
+
+    Ok(ReliefBackpressureResult {
+        relieving_pressure_bar_abs: p_rel,
+        built_up_backpressure_bar_g: built_up,
+        backpressure_percent_of_set: percent,
+        allowable_percent_of_set: allowable,
+        exit_pressure_bar_abs: p_exit,
+        exit_velocity_m_per_s: exit.velocity,
+        exit_mach: exit.mach,
+        outlet_velocity_m_per_s: down.velocity,
+        outlet_mach: down.mach,
+        choked_at_exit: choked,
+        friction_factor,
+        warnings,
+    })
+}
@@ -0,0 +1,622 @@
+//! 안전밸브(PSV) 분출 시나리오 집계.
+//! 보호 계통(protected system)마다 분출 부하를 여러 개 정의하고, 계통별로 PSV 크기를 정하는
+//! 지배(governing) 부하를 고른다. 부하 종류:
+//! - 출구 차단(blocked outlet): 공급원 최대 유량이 그대로 분출된다.
+//! - 화재(fire): `fire_case`의 API 521 젖음 면적식 입열량을 분출 압력의 증발 잠열로 나눈다.
+//!   젖음 면적은 직접 주거나 용기 형상과 액위로 준다.
+//! - 조절 밸브 고장 개방(control valve failure): 밸브 Kv와 분출 압력까지의 차압(임계 압력비로 제한)으로
+//!   유입량을 구하고, 계속 빠져나가는 정상 유출량을 공제한다.
+//!
+//! 분출 압력은 설정압 × (1 + 과압) + 대기압이며 과압은 기본 10 %, 화재는 21 %다.
+//! PSV 필요 면적은 대략 W/P1에 비례하므로 W/P1이 가장 큰 시나리오를 지배 부하로 본다.
+//!
+//! 파일 형식 (TOML):
+//! ```toml
+//! [[system]]
+//! name = "LP header"
+//! set_pressure_bar_g = 5.0
+//! overpressure_percent = 10        # 생략 시 10
+//! fire_overpressure_percent = 21   # 생략 시 21
+//!
+//! [[scenario]]
+//! system = "LP header"
+//! name = "PRV fail open"
+//! type = "control_valve_failure"
+//! kv = 40                          # 또는 cv
+//! upstream_pressure_bar_abs = 16
+//! upstream_temp_c = 250            # 생략하면 건포화 증기
+//! normal_outflow_kg_per_h = 2000   # 생략 시 0
+//!
+//! [[scenario]]
+//! system = "LP header"
+//! name = "Pool fire"
+//! type = "fire"
+//! wetted_area_m2 = 25              # 또는 vessel = { orientation = "horizontal", inner_diameter_m = 2.0,
+//!                                  #   length_m = 6.0, liquid_level_m = 1.2, elevation_m = 1.0 }
+//! adequate_drainage = true         # 생략 시 true
+//! environment_factor = 1.0         # 생략 시 1.0 (단열 시 API 521 표 값)
+//!
+//! [[scenario]]
+//! system = "LP header"
+//! name = "Header blocked"
+//! type = "blocked_outlet"
+//! source_flow_kg_per_h = 12000
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::i18n::Translator;
+use crate::steam::critical_flow::SteamStagnation;
+use crate::steam::fire_case::{
+    self, FireCaseError, FireCaseInput, FireCaseWarning, FireVessel, WettedSurface,
+};
+use crate::steam::if97;
+use crate::steam::steam_valves::{self, ValveCalcError};
+use crate::warning::{self, CalcWarning, Severity};
+
+/// 기본 과압(설정압 대비 %)
+pub const DEFAULT_OVERPRESSURE_PERCENT: f64 = 10.0;
+/// 화재 시 허용 과압(설정압 대비 %, API 521)
+pub const DEFAULT_FIRE_OVERPRESSURE_PERCENT: f64 = 21.0;
+
+fn default_overpressure() -> f64 {
+    DEFAULT_OVERPRESSURE_PERCENT
+}
+
+fn default_fire_overpressure() -> f64 {
+    DEFAULT_FIRE_OVERPRESSURE_PERCENT
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_environment_factor() -> f64 {
+    1.0
+}
+
+/// PSV로 보호하는 계통 (용기나 헤더).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProtectedSystem {
+    pub name: String,
+    /// PSV 설정압 [bar(g)]
+    pub set_pressure_bar_g: f64,
+    /// 과압 [%]
+    #[serde(default = "default_overpressure")]
+    pub overpressure_percent: f64,
+    /// 화재 시 과압 [%]
+    #[serde(default = "default_fire_overpressure")]
+    pub fire_overpressure_percent: f64,
+}
+
+impl ProtectedSystem {
+    /// 분출 압력 [bar abs] = 설정압 × (1 + 과압) + 대기압
+    pub fn relieving_pressure_bar_abs(&self, fire: bool, atmospheric_pressure_bar_abs: f64) -> f64 {
+        let overpressure = if fire {
+            self.fire_overpressure_percent
+        } else {
+            self.overpressure_percent
+        };
+        self.set_pressure_bar_g * (1.0 + overpressure / 100.0) + atmospheric_pressure_bar_abs
+    }
+}
+
+/// 분출 부하 정의.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ReliefLoad {
+    /// 출구 차단: 공급원 최대 유량 [kg/h]
+    BlockedOutlet { source_flow_kg_per_h: f64 },
+    /// 외부 화재 (API 521 젖음 면적식)
+    Fire {
+        /// 젖음 면적 [m²]. `vessel`과 둘 중 하나를 준다.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        wetted_area_m2: Option<f64>,
+        /// 젖음 면적을 계산할 용기 형상과 액위
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        vessel: Option<FireVessel>,
+        #[serde(default = "default_true")]
+        adequate_drainage: bool,
+        /// 환경 계수 F (단열이 없으면 1.0)
+        #[serde(default = "default_environment_factor")]
+        environment_factor: f64,
+        /// 증발 잠열 [kJ/kg]. 없으면 분출 압력의 IF97 값.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        latent_heat_kj_per_kg: Option<f64>,
+    },
+    /// 유입측 조절 밸브 고장 개방
+    ControlValveFailure {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        kv: Option<f64>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        cv: Option<f64>,
+        /// 밸브 상류 압력 [bar abs]
+        upstream_pressure_bar_abs: f64,
+        /// 밸브 상류 온도 [°C]. 없으면 건포화 증기.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        upstream_temp_c: Option<f64>,
+        /// 고장 중에도 계속 빠져나가는 정상 유출량 [kg/h] (공제분)
+        #[serde(default)]
+        normal_outflow_kg_per_h: f64,
+    },
+}
+
+impl ReliefLoad {
+    /// 부하 종류 이름 (파일의 `type` 값과 같다)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ReliefLoad::BlockedOutlet { .. } => "blocked_outlet",
+            ReliefLoad::Fire { .. } => "fire",
+            ReliefLoad::ControlValveFailure { .. } => "control_valve_failure",
+        }
+    }
+
+    fn is_fire(&self) -> bool {
+        matches!(self, ReliefLoad::Fire { .. })
+    }
+}
+
+/// 한 계통의 분출 시나리오.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReliefScenario {
+    /// 보호 계통 이름
+    pub system: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub load: ReliefLoad,
+}
+
+/// 시나리오별 분출 부하 계산 결과.
+#[derive(Debug, Clone)]
+pub struct ScenarioLoad {
+    pub scenario: String,
+    pub kind: &'static str,
+    /// 분출 압력 [bar abs]
+    pub relieving_pressure_bar_abs: f64,
+    /// 분출 부하 [kg/h]
+    pub load_kg_per_h: f64,
+    /// 지배 부하 비교 기준 W/P1 [kg/h/bar]
+    pub sizing_basis: f64,
+    /// 계산 근거 (입열량, 밸브 차압 등)
+    pub note: String,
+}
+
+/// 계통별 집계.
+#[derive(Debug, Clone)]
+pub struct SystemRelief {
+    pub system: String,
+    pub set_pressure_bar_g: f64,
+    pub loads: Vec<ScenarioLoad>,
+    /// `loads`에서 지배 부하의 위치. 부하가 모두 0이면 `None`.
+    pub governing: Option<usize>,
+}
+
+impl SystemRelief {
+    /// PSV 크기를 정하는 지배 부하.
+    pub fn governing_load(&self) -> Option<&ScenarioLoad> {
+        self.governing.map(|i| &self.loads[i])
+    }
+}
+
+/// 분출 시나리오 집계 결과.
+#[derive(Debug, Clone)]
+pub struct ReliefStudyResult {
+    pub systems: Vec<SystemRelief>,
+    pub warnings: Vec<ReliefWarning>,
+}
+
+/// 분출 시나리오 집계 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReliefWarning {
+    /// 화재 시나리오 계산 경고. 문구 앞에 계통/시나리오 이름을 붙인다.
+    Fire {
+        system: String,
+        scenario: String,
+        warning: FireCaseWarning,
+    },
+    /// 계통에 정의된 시나리오 없음
+    NoScenarios { system: String },
+    /// 시나리오 분출 부하가 0
+    ZeroLoad {
+        system: String,
+        scenario: String,
+        note: String,
+    },
+}
+
+impl CalcWarning for ReliefWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            ReliefWarning::Fire { warning, .. } => warning.severity(),
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ReliefWarning::Fire { warning, .. } => warning.i18n_key(),
+            ReliefWarning::NoScenarios { .. } => "warning.relief.no_scenarios",
+            ReliefWarning::ZeroLoad { .. } => "warning.relief.zero_load",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            ReliefWarning::Fire { warning, .. } => warning.args(),
+            ReliefWarning::NoScenarios { system } => vec![("system", system.clone())],
+            ReliefWarning::ZeroLoad {
+                system,
+                scenario,
+                note,
+            } => vec![
+                ("system", system.clone()),
+                ("scenario", scenario.clone()),
+                ("note", note.clone()),
+            ],
+        }
+    }
+
+    fn localized(&self, tr: &Translator) -> String {
+        match self {
+            ReliefWarning::Fire {
+                system,
+                scenario,
+                warning,
+            } => format!("{system} / {scenario}: {}", warning.localized(tr)),
+            _ => warning::localize(self, tr),
+        }
+    }
+}
+
+impl std::fmt::Display for ReliefWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReliefWarning::Fire {
+                system,
+                scenario,
+                warning,
+            } => write!(f, "{system} / {scenario}: {warning}"),
+            ReliefWarning::NoScenarios { system } => {
+                write!(f, "{system}: 정의된 분출 시나리오가 없습니다.")
+            }
+            ReliefWarning::ZeroLoad {
+                system,
+                scenario,
+                note,
+            } => write!(f, "{system} / {scenario}: 분출 부하가 0입니다 ({note})."),
+        }
+    }
+}
+
+/// 분출 시나리오 오류.
+#[derive(Debug)]
+pub enum ReliefScenarioError {
+    Invalid(String),
+    Valve(ValveCalcError),
+    FireCase(FireCaseError),
+    If97(String),
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+}
+
+impl std::fmt::Display for ReliefScenarioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReliefScenarioError::Invalid(msg) => write!(f, "분출 시나리오 오류: {msg}"),
+            ReliefScenarioError::Valve(e) => write!(f, "밸브 계산 오류: {e}"),
+            ReliefScenarioError::FireCase(e) => write!(f, "화재 분출량 계산 오류: {e}"),
+            ReliefScenarioError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            ReliefScenarioError::Io(e) => write!(f, "파일 입출력 오류: {e}"),
+            ReliefScenarioError::Parse(e) => write!(f, "분출 시나리오 파일 파싱 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ReliefScenarioError {}
+
+impl From<ValveCalcError> for ReliefScenarioError {
+    fn from(value: ValveCalcError) -> Self {
+        ReliefScenarioError::Valve(value)
+    }
+}
+
+impl From<FireCaseError> for ReliefScenarioError {
+    fn from(value: FireCaseError) -> Self {
+        ReliefScenarioError::FireCase(value)
+    }
+}
+
+impl From<std::io::Error> for ReliefScenarioError {
+    fn from(value: std::io::Error) -> Self {
+        ReliefScenarioError::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for ReliefScenarioError {
+    fn from(value: toml::de::Error) -> Self {
+        ReliefScenarioError::Parse(value)
+    }
+}
+
+fn if97_err(e: &str) -> ReliefScenarioError {
+    ReliefScenarioError::If97(e.to_string())
+}
+
+/// 분출 시나리오 파일 내용.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReliefStudy {
+    #[serde(default, rename = "system")]
+    pub systems: Vec<ProtectedSystem>,
+    #[serde(default, rename = "scenario")]
+    pub scenarios: Vec<ReliefScenario>,
+}
+
+impl ReliefStudy {
+    /// TOML 문자열에서 읽고 정의를 검사한다.
+    pub fn from_toml_str(content: &str) -> Result<Self, ReliefScenarioError> {
+        let study: ReliefStudy = toml::from_str(content)?;
+        study.validate()?;
+        Ok(study)
+    }
+
+    /// 파일에서 읽는다.
+    pub fn load(path: &Path) -> Result<Self, ReliefScenarioError> {
+        Self::from_toml_str(&std::fs::read_to_string(path)?)
+    }
+
+    /// 계통과 시나리오 정의를 확인한다.
+    pub fn validate(&self) -> Result<(), ReliefScenarioError> {
+        let invalid = |msg: String| Err(ReliefScenarioError::Invalid(msg));
+        if self.systems.is_empty() {
+            return invalid("보호 계통이 하나도 없습니다.".into());
+        }
+        for (i, system) in self.systems.iter().enumerate() {
+            if system.name.trim().is_empty() {
+                return invalid("계통 이름은 비울 수 없습니다.".into());
+            }
+            if self.systems[..i]
+                .iter()
+                .any(|s| s.name.eq_ignore_ascii_case(&system.name))
+            {
+                return invalid(format!("중복된 계통: {}", system.name));
+            }
+            if system.set_pressure_bar_g <= 0.0 {
+                return invalid(format!("{}: 설정압은 0보다 커야 합니다.", system.name));
+            }
+            if system.overpressure_percent < 0.0 || system.fire_overpressure_percent < 0.0 {
+                return invalid(format!("{}: 과압은 음수일 수 없습니다.", system.name));
+            }
+        }
+        for (i, scenario) in self.scenarios.iter().enumerate() {
+            if self.find_system(&scenario.system).is_none() {
+                return invalid(format!(
+                    "{}: 알 수 없는 계통 {}",
+                    scenario.name, scenario.system
+                ));
+            }
+            if self.scenarios[..i].iter().any(|s| {
+                s.system.eq_ignore_ascii_case(&scenario.system)
+                    && s.name.eq_ignore_ascii_case(&scenario.name)
+            }) {
+                return invalid(format!(
+                    "{}: 시나리오 {}이(가) 두 번 있습니다.",
+                    scenario.system, scenario.name
+                ));
+            }
+            let bad = |msg: &str| invalid(format!("{}: {msg}", scenario.name));
+            match &scenario.load {
+                ReliefLoad::BlockedOutlet {
+                    source_flow_kg_per_h,
+                } if *source_flow_kg_per_h <= 0.0 => {
+                    return bad("공급원 유량은 0보다 커야 합니다.");
+                }
+                ReliefLoad::Fire {
+                    wetted_area_m2,
+                    vessel,
+                    environment_factor,
+                    latent_heat_kj_per_kg,
+                    ..
+                } => {
+                    if wetted_area_m2.is_some() == vessel.is_some() {
+                        return bad("wetted_area_m2와 vessel 중 하나만 지정하세요.");
+                    }
+                    if wetted_area_m2.is_some_and(|a| a <= 0.0) {
+                        return bad("젖음 면적은 0보다 커야 합니다.");
+                    }
+                    if !(*environment_factor > 0.0 && *environment_factor <= 1.0) {
+                        return bad("환경 계수는 0 초과 1 이하여야 합니다.");
+                    }
+                    if latent_heat_kj_per_kg.is_some_and(|l| l <= 0.0) {
+                        return bad("잠열은 0보다 커야 합니다.");
+                    }
+                }
+                ReliefLoad::ControlValveFailure {
+                    kv,
+                    cv,
+                    upstream_pressure_bar_abs,
+                    normal_outflow_kg_per_h,
+                    ..
+                } => {
+                    if kv.is_some() == cv.is_some() {
+                        return bad("kv와 cv 중 하나만 지정하세요.");
+                    }
+                    if kv.or(*cv).is_some_and(|k| k <= 0.0) {
+                        return bad("Kv/Cv는 0보다 커야 합니다.");
+                    }
+                    if *upstream_pressure_bar_abs <= 0.0 || *normal_outflow_kg_per_h < 0.0 {
+                        return bad("상류 압력은 0보다 크고 정상 유출량은 0 이상이어야 합니다.");
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// 이름으로 계통을 찾는다 (대소문자 무시).
+    pub fn find_system(&self, name: &str) -> Option<&ProtectedSystem> {
+        let name = name.trim();
+        self.systems
+            .iter()
+            .find(|s| s.name.eq_ignore_ascii_case(name))
+    }
+}
+
+/// 조절 밸브 고장 개방 부하. 반환: (부하 kg/h, 근거)
+fn control_valve_load(
+    p_rel: f64,
+    kv: f64,
+    upstream_pressure_bar_abs: f64,
+    upstream_temp_c: Option<f64>,
+    normal_outflow_kg_per_h: f64,
+) -> Result<(f64, String), ReliefScenarioError> {
+    let p1 = upstream_pressure_bar_abs;
+    if p1 <= p_rel {
+        return Ok((
+            0.0,
+            format!("상류 {p1:.2} bar(a) ≤ 분출 압력 {p_rel:.2} bar(a), 유입 없음"),
+        ));
+    }
+    let stagnation = match upstream_temp_c {
+        Some(t) => SteamStagnation::Temperature(t),
+        None => SteamStagnation::Quality(1.0),
+    };
+    let (_, v, _) = match upstream_temp_c {
+        Some(t) => if97::region_props(p1, t),
+        None => if97::props_from_px(p1, 1.0),
+    }
+    .map_err(if97_err)?;
+    let rho = 1.0 / v;
+    let flow = steam_valves::steam_flow_from_kv(kv, p1 - p_rel, rho, p1, stagnation)?;
+    let (dp_eff, choked) = (flow.effective_delta_p_bar, flow.choked);
+    let inflow = flow.volumetric_flow_m3_per_h * rho;
+    let load = (inflow - normal_outflow_kg_per_h).max(0.0);
+    Ok((
+        load,
+        format!(
+            "Kv {kv:.1}, ΔP {dp_eff:.2} bar{}, 유입 {inflow:.0} kg/h − 정상 유출 {normal_outflow_kg_per_h:.0} kg/h",
+            if choked { " (choked)" } else { "" }
+        ),
+    ))
+}
+
+fn scenario_load(
+    system: &ProtectedSystem,
+    scenario: &ReliefScenario,
+    atmospheric_pressure_bar_abs: f64,
+    warnings: &mut Vec<ReliefWarning>,
+) -> Result<ScenarioLoad, ReliefScenarioError> {
+    let p_rel =
+        system.relieving_pressure_bar_abs(scenario.load.is_fire(), atmospheric_pressure_bar_abs);
+    let (load, note) = match &scenario.load {
+        ReliefLoad::BlockedOutlet {
+            source_flow_kg_per_h,
+        } => (*source_flow_kg_per_h, "공급원 최대 유량".to_string()),
+        ReliefLoad::Fire {
+            wetted_area_m2,
+            vessel,
+            adequate_drainage,
+            environment_factor,
+            latent_heat_kj_per_kg,
+        } => {
+            let surface = match (wetted_area_m2, vessel) {
+                (Some(a), _) => WettedSurface::Area(*a),
+                (None, Some(v)) => WettedSurface::Vessel(*v),
+                (None, None) => WettedSurface::Area(0.0),
+            };
+            let fire = fire_case::fire_case(&FireCaseInput {
+                surface,
+                environment_factor: *environment_factor,
+                adequate_drainage: *adequate_drainage,
+                relieving_pressure_bar_abs: p_rel,
+                latent_heat_kj_per_kg: *latent_heat_kj_per_kg,
+            })?;
+            warnings.extend(fire.warnings.iter().map(|w| ReliefWarning::Fire {
+                system: system.name.clone(),
+                scenario: scenario.name.clone(),
+                warning: w.clone(),
+            }));
+            (
+                fire.relief_rate_kg_per_h,
+                format!(
+                    "A = {:.2} m², Q = {:.0} kW, λ = {:.1} kJ/kg",
+                    fire.wetted_area_m2, fire.heat_input_kw, fire.latent_heat_kj_per_kg
+                ),
+            )
+        }
+        ReliefLoad::ControlValveFailure {
+            kv,
+            cv,
+            upstream_pressure_bar_abs,
+            upstream_temp_c,
+            normal_outflow_kg_per_h,
+        } => {
+            let kv = kv.unwrap_or_else(|| steam_valves::kv_from_cv(cv.unwrap_or_default()));
+            control_valve_load(
+                p_rel,
+                kv,
+                *upstream_pressure_bar_abs,
+                *upstream_temp_c,
+                *normal_outflow_kg_per_h,
+            )?
+        }
+    };
+    Ok(ScenarioLoad {
+        scenario: scenario.name.clone(),
+        kind: scenario.load.kind(),
+        relieving_pressure_bar_abs: p_rel,
+        load_kg_per_h: load,
+        sizing_basis: load / p_rel,
+        note,
+    })
+}
+
+/// 모든 시나리오의 분출 부하를 계산하고 계통별 지배 부하를 고른다.
+/// 계통 순서는 파일 순서를 따른다.
+pub fn evaluate(
+    study: &ReliefStudy,
+    atmospheric_pressure_bar_abs: f64,
+) -> Result<ReliefStudyResult, ReliefScenarioError> {
+    study.validate()?;
+    if atmospheric_pressure_bar_abs <= 0.0 {
+        return Err(ReliefScenarioError::Invalid(
+            "대기압은 0보다 커야 합니다.".into(),
+        ));
+    }
+    let mut warnings = Vec::new();
+    let mut systems = Vec::with_capacity(study.systems.len());
+    for system in &study.systems {
+        let loads = study
+            .scenarios
+            .iter()
+            .filter(|s| s.system.trim().eq_ignore_ascii_case(&system.name))
+            .map(|s| scenario_load(system, s, atmospheric_pressure_bar_abs, &mut warnings))
+            .collect::<Result<Vec<_>, _>>()?;
+        if loads.is_empty() {
+            warnings.push(ReliefWarning::NoScenarios {
+                system: system.name.clone(),
+            });
+        }
+        for load in loads.iter().filter(|l| l.load_kg_per_h <= 0.0) {
+            warnings.push(ReliefWarning::ZeroLoad {
+                system: system.name.clone(),
+                scenario: load.scenario.clone(),
+                note: load.note.clone(),
+            });
+        }
+        let governing = loads
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.load_kg_per_h > 0.0)
+            .max_by(|a, b| a.1.sizing_basis.total_cmp(&b.1.sizing_basis))
+            .map(|(i, _)| i);
+        systems.push(SystemRelief {
+            system: system.name.clone(),
+            set_pressure_bar_g: system.set_pressure_bar_g,
+            loads,
+            governing,
+        });
+    }
+    Ok(ReliefStudyResult { systems, warnings })
+}
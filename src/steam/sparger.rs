@@ -0,0 +1,183 @@
+//! 직접 증기 주입(스파저) 가열 계산.
+//! 물 흐름을 목표 온도로 올리는 증기량을 IF97 엔탈피 수지로 구하고,
+//! 주입 구멍의 증기 질량유속과 주입점 과냉도로 해머(chugging)/불완전 응축 위험을 검토한다.
+
+use crate::steam::critical_flow::{self, CriticalFlowError, SteamStagnation};
+use crate::steam::if97;
+
+const G: f64 = 9.80665;
+/// 불완전 응축(증기 관통)을 피하기 위한 주입점 최소 과냉도 [K]
+pub const MIN_SUBCOOLING_K: f64 = 10.0;
+/// 이 질량유속 미만에서 과냉도가 크면 증기 기포가 주기적으로 붕괴하는 chugging 영역으로 본다 [kg/m²s]
+pub const CHUGGING_MASS_FLUX: f64 = 150.0;
+/// chugging 판정 과냉도 [K]
+const CHUGGING_SUBCOOLING_K: f64 = 20.0;
+
+/// 스파저 가열 입력.
+#[derive(Debug, Clone)]
+pub struct SpargerInput {
+    /// 가열할 물 유량 [kg/h]
+    pub water_flow_kg_per_h: f64,
+    pub water_inlet_temp_c: f64,
+    pub target_temp_c: f64,
+    /// 액면(또는 배관) 압력 [bar abs]
+    pub liquid_pressure_bar_abs: f64,
+    /// 액면에서 스파저까지 깊이 [m]. 배관 인라인 주입이면 0.
+    pub submergence_m: f64,
+    /// 구멍 상류 증기 압력 [bar abs]
+    pub steam_pressure_bar_abs: f64,
+    pub steam_state: SteamStagnation,
+    /// 주입 구멍 지름 [m]과 개수
+    pub hole_diameter_m: f64,
+    pub hole_count: u32,
+    /// 구멍 유량계수 (예: 0.6~0.8)
+    pub discharge_coefficient: f64,
+}
+
+/// 스파저 가열 결과.
+#[derive(Debug, Clone)]
+pub struct SpargerResult {
+    pub steam_flow_kg_per_h: f64,
+    /// 가열 후 유량 (물 + 응축 증기) [kg/h]
+    pub outlet_flow_kg_per_h: f64,
+    /// 출구 유량 중 응축 증기 비율
+    pub dilution_fraction: f64,
+    /// 스파저 위치 압력 [bar abs]
+    pub local_pressure_bar_abs: f64,
+    pub local_saturation_temp_c: f64,
+    /// 주입점 과냉도 = 포화온도 − 목표 온도 [K]
+    pub subcooling_k: f64,
+    /// 필요 증기량 기준 구멍 질량유속 [kg/m²s]
+    pub hole_mass_flux_kg_per_m2_s: f64,
+    /// 구멍 총면적으로 낼 수 있는 최대 증기량 [kg/h]
+    pub hole_capacity_kg_per_h: f64,
+    pub choked: bool,
+    /// `MIN_SUBCOOLING_K`를 확보하는 최소 잠김 깊이 [m]
+    pub min_submergence_m: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 스파저 계산 오류.
+#[derive(Debug, Clone)]
+pub enum SpargerError {
+    InvalidInput(&'static str),
+    If97(String),
+    CriticalFlow(CriticalFlowError),
+}
+
+impl std::fmt::Display for SpargerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SpargerError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            SpargerError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            SpargerError::CriticalFlow(e) => write!(f, "구멍 유량 계산 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for SpargerError {}
+
+/// 물 흐름을 직접 증기 주입으로 가열하는 증기량과 해머 위험을 계산한다.
+pub fn compute_sparger(input: SpargerInput) -> Result<SpargerResult, SpargerError> {
+    if input.water_flow_kg_per_h <= 0.0 {
+        return Err(SpargerError::InvalidInput("물 유량은 0보다 커야 합니다."));
+    }
+    if input.target_temp_c <= input.water_inlet_temp_c {
+        return Err(SpargerError::InvalidInput(
+            "목표 온도는 입구 온도보다 높아야 합니다.",
+        ));
+    }
+    if input.liquid_pressure_bar_abs <= 0.0 || input.submergence_m < 0.0 {
+        return Err(SpargerError::InvalidInput(
+            "액면 압력은 0보다 크고 잠김 깊이는 0 이상이어야 합니다.",
+        ));
+    }
+    if input.hole_diameter_m <= 0.0 || input.hole_count == 0 {
+        return Err(SpargerError::InvalidInput(
+            "구멍 지름과 개수는 0보다 커야 합니다.",
+        ));
+    }
+    let if97_err = |e: &str| SpargerError::If97(e.into());
+
+    let p_surf = input.liquid_pressure_bar_abs;
+    let (h_in, v_in, _) = if97::region_props(p_surf, input.water_inlet_temp_c).map_err(if97_err)?;
+    let rho = 1.0 / v_in;
+    let p_local = p_surf + rho * G * input.submergence_m / 1.0e5;
+    if input.steam_pressure_bar_abs <= p_local {
+        return Err(SpargerError::InvalidInput(
+            "증기 압력은 스파저 위치 액체 압력보다 높아야 합니다.",
+        ));
+    }
+    let t_sat_local = if97::saturation_temp_c_from_pressure_bar_abs(p_local).map_err(if97_err)?;
+    if input.target_temp_c >= t_sat_local {
+        return Err(SpargerError::InvalidInput(
+            "목표 온도가 스파저 위치 포화온도 이상입니다. 잠김 깊이 또는 압력을 높이세요.",
+        ));
+    }
+    let (h_out, _, _) = if97::region_props(p_local, input.target_temp_c).map_err(if97_err)?;
+    let h_steam = match input.steam_state {
+        SteamStagnation::Temperature(t_c) => {
+            if97::region_props(input.steam_pressure_bar_abs, t_c)
+                .map_err(if97_err)?
+                .0
+        }
+        SteamStagnation::Quality(x) => {
+            if97::props_from_px(input.steam_pressure_bar_abs, x.clamp(0.0, 1.0))
+                .map_err(if97_err)?
+                .0
+        }
+    };
+
+    let m_w = input.water_flow_kg_per_h;
+    let m_s = m_w * (h_out - h_in) / (h_steam - h_out);
+    let outlet = m_w + m_s;
+
+    let hole_area =
+        input.hole_count as f64 * std::f64::consts::PI * input.hole_diameter_m.powi(2) / 4.0;
+    let nozzle = critical_flow::steam_nozzle_flow(
+        input.steam_pressure_bar_abs,
+        input.steam_state,
+        p_local,
+        hole_area,
+        input.discharge_coefficient,
+    )
+    .map_err(SpargerError::CriticalFlow)?;
+    let flux = m_s / 3600.0 / (input.discharge_coefficient * hole_area);
+    let subcooling = t_sat_local - input.target_temp_c;
+
+    let p_req =
+        if97::saturation_pressure_bar_abs_from_temp_c(input.target_temp_c + MIN_SUBCOOLING_K)
+            .map_err(if97_err)?;
+    let min_submergence = ((p_req - p_surf) * 1.0e5 / (rho * G)).max(0.0);
+
+    let mut warnings = Vec::new();
+    if m_s > nozzle.mass_flow_kg_per_h {
+        warnings.push(format!(
+            "필요 증기량 {m_s:.1} kg/h가 구멍 용량 {:.1} kg/h를 넘습니다. 구멍 수/지름이나 증기 압력을 늘리세요.",
+            nozzle.mass_flow_kg_per_h
+        ));
+    }
+    if subcooling < MIN_SUBCOOLING_K {
+        warnings.push(format!(
+            "주입점 과냉도 {subcooling:.1} K가 {MIN_SUBCOOLING_K:.0} K 미만이라 증기가 응축되지 않고 관통할 수 있습니다. 잠김 깊이를 {min_submergence:.2} m 이상으로 하세요."
+        ));
+    } else if flux < CHUGGING_MASS_FLUX && subcooling > CHUGGING_SUBCOOLING_K {
+        warnings.push(format!(
+            "구멍 질량유속 {flux:.0} kg/m²s가 낮고 과냉도 {subcooling:.0} K가 커서 chugging(해머) 위험이 있습니다. 구멍 면적을 줄이세요."
+        ));
+    }
+
+    Ok(SpargerResult {
+        steam_flow_kg_per_h: m_s,
+        outlet_flow_kg_per_h: outlet,
+        dilution_fraction: m_s / outlet,
+        local_pressure_bar_abs: p_local,
+        local_saturation_temp_c: t_sat_local,
+        subcooling_k: subcooling,
+        hole_mass_flux_kg_per_m2_s: flux,
+        hole_capacity_kg_per_h: nozzle.mass_flow_kg_per_h,
+        choked: nozzle.choked,
+        min_submergence_m: min_submergence,
+        warnings,
+    })
+}
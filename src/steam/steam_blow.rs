@@ -0,0 +1,542 @@
+//! 시운전 스팀 블로의 세정력비(CFR, disturbance factor) 계산.
+//! 같은 지점에서 블로 중 운동량 유속 G²·v를 설계(정상 운전) 운동량 유속으로 나눈 값이다.
+//! CFR = (W_b / A)²·v_b / ((W_d / A_d)²·v_d). A_d는 설계 기준 배관 단면적이므로 관경이 다른 구간도 비교된다.
+//!
+//! 블로 경로(영구 배관 + 가설 배관)는 `pipe_run`으로 따라가고 끝은 대기 방출로 본다.
+//! 방출구 음속 질량유속 ρ·c보다 유량이 크면 출구가 초킹되어 출구 압력이 대기압보다 높아진다.
+//! 보일러 출구 엔탈피를 경로 전체에 유지(등엔탈피 교축)한다. 블로 유량은 보일러 압력으로 경로가
+//! 흘릴 수 있는 유량과 블로 밸브 용량 중 작은 값이다. 밸브 용량이 정하면 남는 압력은 밸브가 교축한다.
+//! 목표 CFR에 필요한 최소 블로 유량과 그때의 경로 입구 압력을 함께 구해 권장값으로 낸다.
+
+use super::if97;
+use super::steam_piping::fittings::FittingMethod;
+use super::steam_piping::pipe_run::{self, PipeRunInput, PipeRunResult, PipeSegment};
+use super::steam_piping::PipeCalcError;
+use crate::warning::{CalcWarning, Severity};
+
+/// 권장 블로 압력 탐색 상한 [bar abs]
+const MAX_BLOW_PRESSURE_BAR_ABS: f64 = 250.0;
+/// 이분법 반복 횟수
+const BISECTION_STEPS: usize = 40;
+/// 가설 배관 유속 경고 기준 Mach. 이보다 빠르면 Darcy 계산이 맞지 않고 소음·진동이 커진다.
+pub const MACH_WARNING: f64 = 0.8;
+
+/// 스팀 블로 계산 입력.
+#[derive(Debug, Clone)]
+pub struct SteamBlowInput {
+    /// 설계(최대 연속 운전) 유량 [kg/h]
+    pub design_flow_kg_per_h: f64,
+    /// 설계 운전 압력 [bar abs]
+    pub design_pressure_bar_abs: f64,
+    pub design_temperature_c: f64,
+    /// 설계 운동량 유속 기준 배관 내경 [m] (세정 대상 영구 배관)
+    pub design_diameter_m: f64,
+    /// 블로 중 보일러(과열기 출구) 압력 [bar abs]
+    pub boiler_pressure_bar_abs: f64,
+    pub boiler_temperature_c: f64,
+    /// 블로 밸브 완전 개방 시 통과 가능 유량 [kg/h]
+    pub blow_valve_capacity_kg_per_h: f64,
+    /// 방출구 대기압 [bar abs]
+    pub atmospheric_pressure_bar_abs: f64,
+    /// 목표 CFR (보통 1.0 이상, 여유를 두면 1.2~1.4)
+    pub target_cfr: f64,
+    pub fitting_method: FittingMethod,
+    /// IF97 상태로 점도를 못 구할 때 쓰는 점도 [Pa·s]
+    pub dynamic_viscosity_pa_s: f64,
+    /// 보일러 출구부터 방출구까지 흐름 순서의 구간
+    pub segments: Vec<PipeSegment>,
+}
+
+/// 블로 경로 구간별 결과.
+#[derive(Debug, Clone)]
+pub struct BlowStation {
+    pub name: String,
+    pub inlet_pressure_bar_abs: f64,
+    pub outlet_pressure_bar_abs: f64,
+    /// 구간 출구 유속 [m/s]
+    pub outlet_velocity_m_per_s: f64,
+    /// 구간 출구 Mach (출구 상태 음속 기준)
+    pub mach: f64,
+    /// 구간 안의 최소 CFR (압력이 높은 입구 쪽)
+    pub cfr: f64,
+}
+
+/// 스팀 블로 계산 결과.
+#[derive(Debug, Clone)]
+pub struct SteamBlowResult {
+    /// 블로 유량 [kg/h]
+    pub blow_flow_kg_per_h: f64,
+    /// 블로 밸브 용량이 유량을 정하면 true (보일러 압력 여유는 밸브가 교축)
+    pub valve_limited: bool,
+    /// 블로 경로 입구 압력 [bar abs]
+    pub route_inlet_pressure_bar_abs: f64,
+    /// 방출구 압력 [bar abs]. 초킹되면 대기압보다 높다.
+    pub exit_pressure_bar_abs: f64,
+    pub exit_choked: bool,
+    pub stations: Vec<BlowStation>,
+    pub min_cfr: f64,
+    /// CFR이 가장 낮은 구간 위치
+    pub worst_station: usize,
+    /// 목표 CFR에 필요한 최소 블로 유량 [kg/h]. 탐색 압력 상한 안에서 못 찾으면 None.
+    pub recommended_flow_kg_per_h: Option<f64>,
+    /// 권장 유량을 흘릴 때의 경로 입구 압력 [bar abs]
+    pub recommended_inlet_pressure_bar_abs: Option<f64>,
+    pub warnings: Vec<SteamBlowWarning>,
+}
+
+/// 스팀 블로 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SteamBlowWarning {
+    /// 가장 낮은 구간 CFR이 목표 미만
+    CfrBelowTarget {
+        station: String,
+        min_cfr: f64,
+        target_cfr: f64,
+    },
+    /// 목표 CFR 유량이 블로 밸브 용량을 넘음
+    FlowExceedsValve {
+        flow_kg_per_h: f64,
+        capacity_kg_per_h: f64,
+    },
+    /// 목표 CFR 입구 압력이 보일러 블로 압력보다 높음
+    PressureExceedsBoiler {
+        required_bar_abs: f64,
+        boiler_bar_abs: f64,
+    },
+    /// 탐색 압력 상한 안에서 목표 CFR 불가
+    TargetUnreachable,
+    /// 방출구 초킹
+    ExitChoked { exit_pressure_bar_abs: f64 },
+    /// 블로 경로 Mach가 [`MACH_WARNING`]을 넘음
+    HighMach { mach: f64 },
+}
+
+impl CalcWarning for SteamBlowWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            SteamBlowWarning::CfrBelowTarget { .. } => "warning.steam_blow.cfr_below_target",
+            SteamBlowWarning::FlowExceedsValve { .. } => "warning.steam_blow.flow_exceeds_valve",
+            SteamBlowWarning::PressureExceedsBoiler { .. } => {
+                "warning.steam_blow.pressure_exceeds_boiler"
+            }
+            SteamBlowWarning::TargetUnreachable => "warning.steam_blow.target_unreachable",
+            SteamBlowWarning::ExitChoked { .. } => "warning.steam_blow.exit_choked",
+            SteamBlowWarning::HighMach { .. } => "warning.steam_blow.high_mach",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match self {
+            SteamBlowWarning::CfrBelowTarget {
+                station,
+                min_cfr,
+                target_cfr,
+            } => vec![
+                ("station", station.clone()),
+                ("cfr", format!("{min_cfr:.2}")),
+                ("target", format!("{target_cfr:.2}")),
+            ],
+            SteamBlowWarning::FlowExceedsValve {
+                flow_kg_per_h,
+                capacity_kg_per_h,
+            } => vec![
+                ("flow", format!("{flow_kg_per_h:.0}")),
+                ("capacity", format!("{capacity_kg_per_h:.0}")),
+            ],
+            SteamBlowWarning::PressureExceedsBoiler {
+                required_bar_abs,
+                boiler_bar_abs,
+            } => vec![
+                ("p", format!("{required_bar_abs:.1}")),
+                ("boiler", format!("{boiler_bar_abs:.1}")),
+            ],
+            SteamBlowWarning::TargetUnreachable => {
+                vec![("max", format!("{MAX_BLOW_PRESSURE_BAR_ABS:.0}"))]
+            }
+            SteamBlowWarning::ExitChoked {
+                exit_pressure_bar_abs,
+            } => vec![("p", format!("{exit_pressure_bar_abs:.2}"))],
+            SteamBlowWarning::HighMach { mach } => vec![
+                ("mach", format!("{mach:.2}")),
+                ("max", format!("{MACH_WARNING}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for SteamBlowWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SteamBlowWarning::CfrBelowTarget {
+                station,
+                min_cfr,
+                target_cfr,
+            } => write!(
+                f,
+                "'{station}' 구간의 CFR {min_cfr:.2}가 목표 {target_cfr:.2}보다 낮습니다."
+            ),
+            SteamBlowWarning::FlowExceedsValve {
+                flow_kg_per_h,
+                capacity_kg_per_h,
+            } => write!(
+                f,
+                "목표 CFR에 블로 유량 {flow_kg_per_h:.0} kg/h가 필요해 블로 밸브 용량 {capacity_kg_per_h:.0} kg/h를 넘습니다. 밸브를 키우거나 병렬 블로 밸브를 검토하세요."
+            ),
+            SteamBlowWarning::PressureExceedsBoiler {
+                required_bar_abs,
+                boiler_bar_abs,
+            } => write!(
+                f,
+                "목표 CFR에 경로 입구 압력 {required_bar_abs:.1} bar(a)가 필요해 보일러 블로 압력 {boiler_bar_abs:.1} bar(a)보다 높습니다."
+            ),
+            SteamBlowWarning::TargetUnreachable => write!(
+                f,
+                "{MAX_BLOW_PRESSURE_BAR_ABS:.0} bar(a) 안에서는 목표 CFR을 맞출 수 없습니다. 가설 배관 관경을 키우세요."
+            ),
+            SteamBlowWarning::ExitChoked {
+                exit_pressure_bar_abs,
+            } => write!(
+                f,
+                "방출구가 초킹되어 출구 압력이 {exit_pressure_bar_abs:.2} bar(a)입니다. 반력과 소음이 커지므로 방출구 관경과 고정을 검토하세요."
+            ),
+            SteamBlowWarning::HighMach { mach } => write!(
+                f,
+                "블로 경로 Mach {mach:.2}가 {MACH_WARNING}를 넘습니다. 압축성 손실이 커지므로 가설 배관 관경을 키우세요."
+            ),
+        }
+    }
+}
+
+impl SteamBlowResult {
+    /// 모든 구간이 목표 CFR을 넘는지
+    pub fn cfr_ok(&self, target_cfr: f64) -> bool {
+        self.min_cfr >= target_cfr
+    }
+}
+
+/// 스팀 블로 계산 오류.
+#[derive(Debug)]
+pub enum SteamBlowError {
+    InvalidInput(&'static str),
+    Pipe(PipeCalcError),
+    If97(String),
+}
+
+impl std::fmt::Display for SteamBlowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SteamBlowError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            SteamBlowError::Pipe(e) => write!(f, "블로 경로 계산 오류: {e}"),
+            SteamBlowError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SteamBlowError {}
+
+impl From<PipeCalcError> for SteamBlowError {
+    fn from(value: PipeCalcError) -> Self {
+        SteamBlowError::Pipe(value)
+    }
+}
+
+/// 압력 p와 엔탈피 h에서의 (비체적, 음속)
+fn volume_at(p_bar_abs: f64, h: f64) -> Result<(f64, f64), SteamBlowError> {
+    let (v, c, _) =
+        if97::flow_props_from_ph(p_bar_abs, h).map_err(|e| SteamBlowError::If97(e.into()))?;
+    Ok((v, c))
+}
+
+fn area(diameter_m: f64) -> f64 {
+    std::f64::consts::PI * diameter_m * diameter_m / 4.0
+}
+
+/// 블로 경로 계산에 고정되는 값.
+struct Route<'a> {
+    input: &'a SteamBlowInput,
+    h0: f64,
+    sound_speed: f64,
+    /// 설계 운동량 유속 (W_d/A_d)²·v_d
+    design_momentum: f64,
+}
+
+impl Route<'_> {
+    /// 입구 압력 p_in에서 유량 `flow`로 경로를 따라간 결과. 압력이 모자라 끝까지 못 가면 None.
+    fn run(&self, flow: f64, p_in: f64) -> Option<PipeRunResult> {
+        let t_in = if97::temperature_from_ph(p_in, self.h0).ok()?;
+        pipe_run::pipe_run(&PipeRunInput {
+            mass_flow_kg_per_h: flow,
+            inlet_pressure_bar_abs: p_in,
+            inlet_temperature_c: t_in,
+            fitting_method: self.input.fitting_method,
+            dynamic_viscosity_pa_s: self.input.dynamic_viscosity_pa_s,
+            sound_speed_m_per_s: self.sound_speed,
+            segments: self.input.segments.clone(),
+        })
+        .ok()
+    }
+
+    /// 방출구 압력. 마지막 구간 단면의 음속 질량유속 ρ·c가 유량보다 작으면
+    /// 출구가 초킹되어 ρ·c = G가 되는 압력까지 올라간다.
+    fn exit_pressure(&self, flow: f64) -> Result<f64, SteamBlowError> {
+        let last = self.input.segments.last().expect("segments checked");
+        let flux = flow / 3600.0 / area(last.diameter_m);
+        let sonic_flux = |p: f64| -> Result<f64, SteamBlowError> {
+            let (v, c) = volume_at(p, self.h0)?;
+            Ok(c / v)
+        };
+        let p_atm = self.input.atmospheric_pressure_bar_abs;
+        if sonic_flux(p_atm)? >= flux {
+            return Ok(p_atm);
+        }
+        let (mut lo, mut hi) = (p_atm, MAX_BLOW_PRESSURE_BAR_ABS);
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lo + hi);
+            if sonic_flux(mid)? < flux {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        Ok(hi)
+    }
+
+    /// 경로가 방출구 압력 이상으로 끝나는지. 중간 구간이 방출구 압력 아래로 떨어지면
+    /// 그 앞에서 이미 초킹된 것이므로(이후 Darcy 계산은 의미가 없다) 실패로 본다.
+    fn reaches_exit(&self, flow: f64, p_in: f64, p_exit: f64) -> bool {
+        self.run(flow, p_in).is_some_and(|r| {
+            r.segments
+                .iter()
+                .all(|s| s.outlet_pressure_bar_abs >= p_exit)
+        })
+    }
+
+    /// 유량 `flow`를 방출하는 데 필요한 입구 압력. `p_max`로도 모자라면 None.
+    fn inlet_pressure_for(&self, flow: f64, p_max: f64) -> Result<Option<f64>, SteamBlowError> {
+        let p_exit = self.exit_pressure(flow)?;
+        if p_exit >= p_max || !self.reaches_exit(flow, p_max, p_exit) {
+            return Ok(None);
+        }
+        let (mut lo, mut hi) = (p_exit, p_max);
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lo + hi);
+            if self.reaches_exit(flow, mid, p_exit) {
+                hi = mid;
+            } else {
+                lo = mid;
+            }
+        }
+        Ok(Some(hi))
+    }
+
+    /// 경로 결과에서 구간별 CFR을 구한다.
+    fn stations(&self, flow: f64, run: &PipeRunResult) -> Result<Vec<BlowStation>, SteamBlowError> {
+        let flow_kg_s = flow / 3600.0;
+        let mut stations = Vec::with_capacity(run.segments.len());
+        for (seg, res) in self.input.segments.iter().zip(&run.segments) {
+            let a = area(seg.diameter_m);
+            let (v_in, _) = volume_at(res.inlet_pressure_bar_abs, self.h0)?;
+            let (v_out, c_out) = volume_at(res.outlet_pressure_bar_abs, self.h0)?;
+            let velocity = flow_kg_s * v_out / a;
+            // 비체적은 하류로 갈수록 커지므로 입구 쪽 운동량 유속이 구간 최소다.
+            let momentum = (flow_kg_s / a).powi(2) * v_in.min(v_out);
+            stations.push(BlowStation {
+                name: res.name.clone(),
+                inlet_pressure_bar_abs: res.inlet_pressure_bar_abs,
+                outlet_pressure_bar_abs: res.outlet_pressure_bar_abs,
+                outlet_velocity_m_per_s: velocity,
+                mach: velocity / c_out,
+                cfr: momentum / self.design_momentum,
+            });
+        }
+        Ok(stations)
+    }
+
+    /// 유량 `flow`를 방출할 때의 (최소 CFR, 입구 압력). 압력 상한으로 못 흘리면 None.
+    fn min_cfr_at(&self, flow: f64) -> Result<Option<(f64, f64)>, SteamBlowError> {
+        let Some(p_in) = self.inlet_pressure_for(flow, MAX_BLOW_PRESSURE_BAR_ABS)? else {
+            return Ok(None);
+        };
+        let Some(run) = self.run(flow, p_in) else {
+            return Ok(None);
+        };
+        let min = self
+            .stations(flow, &run)?
+            .iter()
+            .map(|s| s.cfr)
+            .fold(f64::INFINITY, f64::min);
+        Ok(Some((min, p_in)))
+    }
+}
+
+/// 블로 유량, 구간별 CFR, 목표 CFR을 맞추는 권장 유량·압력을 구한다.
+pub fn steam_blow(input: &SteamBlowInput) -> Result<SteamBlowResult, SteamBlowError> {
+    if input.design_flow_kg_per_h <= 0.0
+        || input.design_pressure_bar_abs <= 0.0
+        || input.design_diameter_m <= 0.0
+    {
+        return Err(SteamBlowError::InvalidInput(
+            "설계 유량, 압력, 배관 내경은 0보다 커야 합니다.",
+        ));
+    }
+    if input.boiler_pressure_bar_abs <= input.atmospheric_pressure_bar_abs
+        || input.atmospheric_pressure_bar_abs <= 0.0
+    {
+        return Err(SteamBlowError::InvalidInput(
+            "보일러 블로 압력은 대기압보다 높아야 합니다.",
+        ));
+    }
+    if input.blow_valve_capacity_kg_per_h <= 0.0 || input.target_cfr <= 0.0 {
+        return Err(SteamBlowError::InvalidInput(
+            "블로 밸브 용량과 목표 CFR은 0보다 커야 합니다.",
+        ));
+    }
+    if input.segments.is_empty() {
+        return Err(SteamBlowError::InvalidInput(
+            "블로 경로 구간이 하나 이상 있어야 합니다.",
+        ));
+    }
+
+    let (h0, _, _) = if97::region_props(input.boiler_pressure_bar_abs, input.boiler_temperature_c)
+        .map_err(|e| SteamBlowError::If97(e.into()))?;
+    let (_, design_v, _) =
+        if97::region_props(input.design_pressure_bar_abs, input.design_temperature_c)
+            .map_err(|e| SteamBlowError::If97(e.into()))?;
+    let (_, sound_speed) = volume_at(input.atmospheric_pressure_bar_abs, h0)?;
+    let route = Route {
+        input,
+        h0,
+        sound_speed,
+        design_momentum: (input.design_flow_kg_per_h / 3600.0 / area(input.design_diameter_m))
+            .powi(2)
+            * design_v,
+    };
+
+    // 블로 밸브 용량을 보일러 압력으로 흘릴 수 있으면 밸브가 유량을 정한다.
+    let capacity = input.blow_valve_capacity_kg_per_h;
+    let p_boiler = input.boiler_pressure_bar_abs;
+    let (blow_flow, p_in, valve_limited) = match route.inlet_pressure_for(capacity, p_boiler)? {
+        Some(p_in) => (capacity, p_in, true),
+        None => {
+            let (mut lo, mut hi) = (0.0, capacity);
+            for _ in 0..BISECTION_STEPS {
+                let mid = 0.5 * (lo + hi);
+                let p_exit = route.exit_pressure(mid)?;
+                if p_exit < p_boiler && route.reaches_exit(mid, p_boiler, p_exit) {
+                    lo = mid;
+                } else {
+                    hi = mid;
+                }
+            }
+            if lo <= 0.0 {
+                return Err(SteamBlowError::InvalidInput(
+                    "보일러 압력으로 블로 경로를 흘릴 수 없습니다.",
+                ));
+            }
+            (lo, p_boiler, false)
+        }
+    };
+    let exit_pressure = route.exit_pressure(blow_flow)?;
+    let run = route
+        .run(blow_flow, p_in)
+        .ok_or(SteamBlowError::InvalidInput(
+            "보일러 압력으로 블로 경로를 흘릴 수 없습니다.",
+        ))?;
+    let stations = route.stations(blow_flow, &run)?;
+    let (worst_station, min_cfr) =
+        stations
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (i, s.cfr))
+            .fold(
+                (0, f64::INFINITY),
+                |acc, x| if x.1 < acc.1 { x } else { acc },
+            );
+
+    // 목표 CFR 권장 유량: CFR은 유량에 대해 단조 증가한다.
+    let mut hi = input.design_flow_kg_per_h;
+    let mut reachable = false;
+    for _ in 0..20 {
+        match route.min_cfr_at(hi)? {
+            Some((cfr, _)) if cfr >= input.target_cfr => {
+                reachable = true;
+                break;
+            }
+            Some(_) => hi *= 2.0,
+            None => break,
+        }
+    }
+    let (mut recommended_flow, mut recommended_p) = (None, None);
+    if reachable {
+        let mut lo = 0.0;
+        for _ in 0..BISECTION_STEPS {
+            let mid = 0.5 * (lo + hi);
+            match route.min_cfr_at(mid)? {
+                Some((cfr, _)) if cfr >= input.target_cfr => hi = mid,
+                _ => lo = mid,
+            }
+        }
+        recommended_flow = Some(hi);
+        recommended_p = route.min_cfr_at(hi)?.map(|(_, p)| p);
+    }
+
+    let mut warnings = Vec::new();
+    if min_cfr < input.target_cfr {
+        warnings.push(SteamBlowWarning::CfrBelowTarget {
+            station: stations[worst_station].name.clone(),
+            min_cfr,
+            target_cfr: input.target_cfr,
+        });
+    }
+    match (recommended_flow, recommended_p) {
+        (Some(flow), Some(p)) => {
+            if flow > capacity {
+                warnings.push(SteamBlowWarning::FlowExceedsValve {
+                    flow_kg_per_h: flow,
+                    capacity_kg_per_h: capacity,
+                });
+            }
+            if p > p_boiler {
+                warnings.push(SteamBlowWarning::PressureExceedsBoiler {
+                    required_bar_abs: p,
+                    boiler_bar_abs: p_boiler,
+                });
+            }
+        }
+        _ => warnings.push(SteamBlowWarning::TargetUnreachable),
+    }
+    let exit_choked = exit_pressure > input.atmospheric_pressure_bar_abs;
+    if exit_choked {
+        warnings.push(SteamBlowWarning::ExitChoked {
+            exit_pressure_bar_abs: exit_pressure,
+        });
+    }
+    // 초킹된 방출구는 Mach 1이므로 그 앞 구간만 본다.
+    let upstream = if exit_choked {
+        &stations[..stations.len() - 1]
+    } else {
+        &stations[..]
+    };
+    let max_mach = upstream.iter().map(|s| s.mach).fold(0.0, f64::max);
+    if max_mach > MACH_WARNING {
+        warnings.push(SteamBlowWarning::HighMach { mach: max_mach });
+    }
+
+    Ok(SteamBlowResult {
+        blow_flow_kg_per_h: blow_flow,
+        valve_limited,
+        route_inlet_pressure_bar_abs: p_in,
+        exit_pressure_bar_abs: exit_pressure,
+        exit_choked,
+        stations,
+        min_cfr,
+        worst_station,
+        recommended_flow_kg_per_h: recommended_flow,
+        recommended_inlet_pressure_bar_abs: recommended_p,
+        warnings,
+    })
+}
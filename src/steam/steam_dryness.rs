@@ -1,3 +1,7 @@
+//! 증기 건도 계산. 감압 후 건도, 분리기 효과, 교축/분리 열량계 측정값 평가를 포함한다.
+
+use crate::steam::if97;
+
 /// 감압 시 건도를 계산하기 위한 입력.
 #[derive(Debug, Clone)]
 pub struct PressureReductionInput {
@@ -67,3 +71,143 @@ pub fn mixed_steam_air_temperature(
         steam_temp_c
     }
 }
+
+/// 교축 열량계 출구에서 요구하는 최소 과열도 [K]. 이보다 작으면 출구 상태가 불확실하다.
+pub const MIN_THROTTLING_SUPERHEAT_K: f64 = 5.0;
+
+/// 교축 열량계 입력.
+#[derive(Debug, Clone)]
+pub struct ThrottlingCalorimeterInput {
+    /// 주관 증기 압력 [bar abs]
+    pub main_pressure_bar_abs: f64,
+    /// 열량계(교축 후) 압력 [bar abs]. 보통 대기압 부근.
+    pub calorimeter_pressure_bar_abs: f64,
+    /// 열량계(교축 후) 온도 [°C]
+    pub calorimeter_temp_c: f64,
+}
+
+/// 분리 열량계 입력 (같은 시간 동안 모은 질량).
+#[derive(Debug, Clone)]
+pub struct SeparatingCalorimeterInput {
+    /// 분리기에서 모은 물 [kg]
+    pub separated_water_kg: f64,
+    /// 분리기를 통과해 응축기에서 모은 증기 응축수 [kg]
+    pub condensed_steam_kg: f64,
+}
+
+/// 열량계 건도 결과.
+#[derive(Debug, Clone)]
+pub struct CalorimeterResult {
+    /// 주관 증기 건도
+    pub dryness: f64,
+    /// 교축 후 과열도 [K] (교축 열량계만)
+    pub superheat_after_throttling_k: Option<f64>,
+    /// 이 열량계 압력에서 최소 과열도를 확보할 수 있는 최저 건도 (교축 열량계만)
+    pub min_measurable_dryness: Option<f64>,
+    pub warnings: Vec<String>,
+}
+
+/// 열량계 계산 오류.
+#[derive(Debug, Clone)]
+pub enum CalorimeterError {
+    InvalidInput(&'static str),
+    /// 교축 후 과열되지 않아 엔탈피를 알 수 없음 (과열도 [K])
+    NotSuperheated(f64),
+    If97(String),
+}
+
+impl std::fmt::Display for CalorimeterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalorimeterError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            CalorimeterError::NotSuperheated(dt) => write!(
+                f,
+                "교축 후 과열도 {dt:.1} K: 출구가 습증기라 건도를 정할 수 없습니다. 분리-교축 열량계를 사용하세요."
+            ),
+            CalorimeterError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for CalorimeterError {}
+
+/// 교축 열량계: h(주관) = h(열량계 출구 과열 증기) 로부터 x = (h₂ − h_f1)/(h_g1 − h_f1).
+pub fn throttling_calorimeter(
+    input: ThrottlingCalorimeterInput,
+) -> Result<CalorimeterResult, CalorimeterError> {
+    let p1 = input.main_pressure_bar_abs;
+    let p2 = input.calorimeter_pressure_bar_abs;
+    if p2 <= 0.0 || p1 <= p2 {
+        return Err(CalorimeterError::InvalidInput(
+            "주관 압력은 열량계 압력보다 커야 하고 열량계 압력은 0보다 커야 합니다.",
+        ));
+    }
+    let if97_err = |e: &str| CalorimeterError::If97(e.to_string());
+    let t_sat2 = if97::saturation_temp_c_from_pressure_bar_abs(p2).map_err(if97_err)?;
+    let superheat = input.calorimeter_temp_c - t_sat2;
+    if superheat <= 0.0 {
+        return Err(CalorimeterError::NotSuperheated(superheat));
+    }
+    let (h2, _, _) = if97::region_props(p2, input.calorimeter_temp_c).map_err(if97_err)?;
+    let (hf1, hg1) = if97::saturation_enthalpies(p1).map_err(if97_err)?;
+    let dryness = (h2 - hf1) / (hg1 - hf1);
+    let (h2_min, _, _) =
+        if97::region_props(p2, t_sat2 + MIN_THROTTLING_SUPERHEAT_K).map_err(if97_err)?;
+    let min_dryness = ((h2_min - hf1) / (hg1 - hf1)).clamp(0.0, 1.0);
+
+    let mut warnings = Vec::new();
+    if superheat < MIN_THROTTLING_SUPERHEAT_K {
+        warnings.push(format!(
+            "교축 후 과열도 {superheat:.1} K가 최소 {MIN_THROTTLING_SUPERHEAT_K} K보다 작아 온도 측정 오차의 영향이 큽니다."
+        ));
+    }
+    if dryness > 1.0 {
+        warnings.push(
+            "건도가 1을 넘습니다: 주관 증기가 과열 상태이거나 열량계 열손실이 있습니다.".into(),
+        );
+    }
+    Ok(CalorimeterResult {
+        dryness: dryness.min(1.0),
+        superheat_after_throttling_k: Some(superheat),
+        min_measurable_dryness: Some(min_dryness),
+        warnings,
+    })
+}
+
+/// 분리 열량계: x = M/(M + m) (M: 통과 증기 응축수, m: 분리된 물).
+/// 분리 효율이 100 %가 아니므로 결과는 실제 건도의 상한이다.
+pub fn separating_calorimeter(
+    input: SeparatingCalorimeterInput,
+) -> Result<CalorimeterResult, CalorimeterError> {
+    let m = input.separated_water_kg;
+    let big_m = input.condensed_steam_kg;
+    if m < 0.0 || big_m <= 0.0 {
+        return Err(CalorimeterError::InvalidInput(
+            "응축수 질량은 0보다 크고 분리수 질량은 0 이상이어야 합니다.",
+        ));
+    }
+    Ok(CalorimeterResult {
+        dryness: big_m / (big_m + m),
+        superheat_after_throttling_k: None,
+        min_measurable_dryness: None,
+        warnings: vec![
+            "분리기가 모든 습분을 걸러내지 못하므로 실제 건도보다 높게 나옵니다.".into(),
+        ],
+    })
+}
+
+/// 분리-교축 조합 열량계: x = x_분리 · x_교축.
+/// 교축부 입력은 분리기를 지난 증기에 대한 값이다.
+pub fn separating_throttling_calorimeter(
+    separating: SeparatingCalorimeterInput,
+    throttling: ThrottlingCalorimeterInput,
+) -> Result<CalorimeterResult, CalorimeterError> {
+    let sep = separating_calorimeter(separating)?;
+    let thr = throttling_calorimeter(throttling)?;
+    Ok(CalorimeterResult {
+        dryness: sep.dryness * thr.dryness,
+        superheat_after_throttling_k: thr.superheat_after_throttling_k,
+        min_measurable_dryness: None,
+        warnings: thr.warnings,
+    })
+}
@@ -0,0 +1,300 @@
+//! 증기 누설/고장 트랩 에너지 절감 분석.
+//! 누설 구멍(또는 열림 고장 트랩 오리피스)의 증기 손실을 Napier/Grashof 식으로 추정하고,
+//! `steam_cost`의 에너지·증기 단가로 연간 에너지/비용 손실을 환산해 항목별 순위와 합계를 낸다.
+//! Napier/Grashof는 초킹(대기 배압 기준 약 1.85 bar(a) 이상) 포화 증기 식이므로,
+//! 그보다 낮은 압력은 IF97 노즐 유량(`critical_flow::steam_nozzle_flow`)으로 계산한다.
+
+use crate::parallel;
+use crate::steam::critical_flow::{self, SteamStagnation};
+use crate::steam::if97;
+use crate::steam::steam_cost::{
+    energy_unit_cost, steam_unit_cost, EnergyUnitCostInput, SteamUnitCostInput,
+};
+
+/// 대기압 [bar abs]
+const P_ATM_BAR: f64 = 1.01325;
+/// Napier: m[kg/s] = P[Pa]·A[m²] / 686.5 (원식 m[lb/s] = P[psia]·A[in²] / 70)
+const NAPIER_DIVISOR_SI: f64 = 686.5;
+/// Grashof: m[lb/s] = 0.0165·A[in²]·P[psia]^0.97
+const GRASHOF_COEFF: f64 = 0.0165;
+const LB_TO_KG: f64 = 0.453_592_37;
+const IN2_TO_M2: f64 = 6.4516e-4;
+const BAR_TO_PSI: f64 = 14.503_773_8;
+
+/// 누설 유량 식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakFormula {
+    Napier,
+    Grashof,
+}
+
+/// 손실 항목 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakKind {
+    /// 배관/플랜지/밸브 누설
+    Leak,
+    /// 열림 고장(blow-through) 트랩
+    FailedTrap,
+}
+
+/// 누설/고장 트랩 한 건.
+#[derive(Debug, Clone)]
+pub struct LeakItem {
+    /// 위치/태그
+    pub tag: String,
+    pub kind: LeakKind,
+    /// 라인 압력 [bar abs] (포화 증기)
+    pub pressure_bar_abs: f64,
+    /// 누설 구멍(트랩 오리피스) 지름 [m]
+    pub orifice_diameter_m: f64,
+    /// 유량계수. 날카로운 구멍 0.6~0.7, 고장 트랩은 부분 열림을 반영해 0.3~0.5.
+    pub discharge_coefficient: f64,
+    /// 연간 운전 시간 [h]
+    pub operating_hours_per_year: f64,
+}
+
+/// 비용 환산 기준.
+#[derive(Debug, Clone)]
+pub struct LeakEconomics {
+    /// 연료 단가/발열량/보일러 효율
+    pub energy: EnergyUnitCostInput,
+    /// 블로다운/배관 손실 계수 (`SteamUnitCostInput::loss_factor`)
+    pub loss_factor: f64,
+    /// 보충수 온도 [°C]. 잃은 증기를 이 온도의 물로 다시 만드는 에너지를 손실로 본다.
+    pub makeup_water_temp_c: f64,
+}
+
+/// 항목별 결과.
+#[derive(Debug, Clone)]
+pub struct LeakResult {
+    pub tag: String,
+    pub kind: LeakKind,
+    pub pressure_bar_abs: f64,
+    pub orifice_diameter_m: f64,
+    pub steam_loss_kg_per_h: f64,
+    /// 연간 증기 손실 [t]
+    pub annual_steam_t: f64,
+    /// 연간 에너지 손실 [GJ]
+    pub annual_energy_gj: f64,
+    /// 연간 비용 손실 [원]
+    pub annual_cost: f64,
+    /// 초킹이 아니어서 IF97 노즐 유량으로 계산했는지 여부
+    pub subcritical: bool,
+}
+
+/// 조사 전체 결과. `items`는 연간 비용 내림차순이다.
+#[derive(Debug, Clone)]
+pub struct LeakSurveyResult {
+    pub items: Vec<LeakResult>,
+    /// 증기 단가 [원/t]
+    pub steam_cost_per_t: f64,
+    pub total_steam_kg_per_h: f64,
+    pub total_annual_steam_t: f64,
+    pub total_annual_energy_gj: f64,
+    pub total_annual_cost: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 누설 분석 오류.
+#[derive(Debug, Clone)]
+pub enum LeakError {
+    InvalidInput(&'static str),
+    If97(String),
+    /// CSV 파싱 오류 (줄 번호, 설명)
+    Csv {
+        line: usize,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for LeakError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LeakError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            LeakError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            LeakError::Csv { line, message } => write!(f, "CSV {line}행: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for LeakError {}
+
+/// 포화 증기가 대기로 새는 유량 [kg/h].
+/// 반환: (유량, 초킹이 아니어서 IF97 노즐 유량을 썼는지 여부)
+pub fn steam_leak_flow_kg_per_h(
+    pressure_bar_abs: f64,
+    orifice_diameter_m: f64,
+    discharge_coefficient: f64,
+    formula: LeakFormula,
+) -> Result<(f64, bool), LeakError> {
+    if pressure_bar_abs <= P_ATM_BAR {
+        return Err(LeakError::InvalidInput(
+            "라인 압력은 대기압보다 높아야 합니다.",
+        ));
+    }
+    if orifice_diameter_m <= 0.0 || !(discharge_coefficient > 0.0 && discharge_coefficient <= 1.0) {
+        return Err(LeakError::InvalidInput(
+            "구멍 지름은 0보다 크고 유량계수는 0~1 사이여야 합니다.",
+        ));
+    }
+    let area = std::f64::consts::PI * orifice_diameter_m.powi(2) / 4.0;
+    let nozzle = critical_flow::steam_nozzle_flow(
+        pressure_bar_abs,
+        SteamStagnation::Quality(1.0),
+        P_ATM_BAR,
+        area,
+        discharge_coefficient,
+    )
+    .map_err(|e| LeakError::If97(e.to_string()))?;
+    if !nozzle.choked {
+        return Ok((nozzle.mass_flow_kg_per_h, true));
+    }
+    let kg_per_s = match formula {
+        LeakFormula::Napier => pressure_bar_abs * 1.0e5 * area / NAPIER_DIVISOR_SI,
+        LeakFormula::Grashof => {
+            GRASHOF_COEFF
+                * (area / IN2_TO_M2)
+                * (pressure_bar_abs * BAR_TO_PSI).powf(0.97)
+                * LB_TO_KG
+        }
+    };
+    Ok((discharge_coefficient * kg_per_s * 3600.0, false))
+}
+
+/// 누설/고장 트랩 목록의 증기·에너지·비용 손실을 계산하고 비용 순으로 정렬한다.
+pub fn analyze_leaks(
+    items: &[LeakItem],
+    economics: &LeakEconomics,
+    formula: LeakFormula,
+) -> Result<LeakSurveyResult, LeakError> {
+    if items.is_empty() {
+        return Err(LeakError::InvalidInput("분석할 항목이 없습니다."));
+    }
+    let if97_err = |e: &str| LeakError::If97(e.into());
+    let energy_cost = energy_unit_cost(economics.energy.clone());
+    if energy_cost.cost_per_kj <= 0.0 {
+        return Err(LeakError::InvalidInput(
+            "연료 단가/발열량/보일러 효율로 에너지 단가를 구할 수 없습니다.",
+        ));
+    }
+    let (h_makeup, _, _) =
+        if97::region_props(P_ATM_BAR, economics.makeup_water_temp_c).map_err(if97_err)?;
+
+    // 항목끼리는 독립이므로 병렬로 계산하고, 경고는 입력 순서대로 모은다.
+    let evaluated = parallel::try_map(items, |item| {
+        if item.operating_hours_per_year < 0.0 || item.operating_hours_per_year > 8784.0 {
+            return Err(LeakError::InvalidInput(
+                "연간 운전 시간은 0~8784 h 범위여야 합니다.",
+            ));
+        }
+        let (flow, subcritical) = steam_leak_flow_kg_per_h(
+            item.pressure_bar_abs,
+            item.orifice_diameter_m,
+            item.discharge_coefficient,
+            formula,
+        )?;
+        let (_, hg) = if97::saturation_enthalpies(item.pressure_bar_abs).map_err(if97_err)?;
+        let dh_kj = (hg - h_makeup) / 1000.0;
+        let steam_cost = steam_unit_cost(SteamUnitCostInput {
+            energy_cost_per_kj: energy_cost.cost_per_kj,
+            steam_latent_heat_kj_per_kg: dh_kj,
+            loss_factor: economics.loss_factor,
+        });
+        let annual_kg = flow * item.operating_hours_per_year;
+        let result = LeakResult {
+            tag: item.tag.clone(),
+            kind: item.kind,
+            pressure_bar_abs: item.pressure_bar_abs,
+            orifice_diameter_m: item.orifice_diameter_m,
+            steam_loss_kg_per_h: flow,
+            annual_steam_t: annual_kg / 1000.0,
+            annual_energy_gj: annual_kg * dh_kj * (1.0 + economics.loss_factor.max(0.0)) / 1.0e6,
+            annual_cost: annual_kg * steam_cost.cost_per_kg,
+            subcritical,
+        };
+        Ok((result, steam_cost.cost_per_ton))
+    })?;
+
+    let mut warnings = Vec::new();
+    let mut results = Vec::with_capacity(items.len());
+    let mut steam_cost_per_t = 0.0;
+    for (result, cost_per_t) in evaluated {
+        steam_cost_per_t = cost_per_t;
+        if result.subcritical {
+            warnings.push(format!(
+                "{}: 라인 압력이 낮아 초킹되지 않으므로 IF97 노즐 유량으로 계산했습니다.",
+                result.tag
+            ));
+        }
+        results.push(result);
+    }
+    results.sort_by(|a, b| b.annual_cost.total_cmp(&a.annual_cost));
+
+    Ok(LeakSurveyResult {
+        steam_cost_per_t,
+        total_steam_kg_per_h: results.iter().map(|r| r.steam_loss_kg_per_h).sum(),
+        total_annual_steam_t: results.iter().map(|r| r.annual_steam_t).sum(),
+        total_annual_energy_gj: results.iter().map(|r| r.annual_energy_gj).sum(),
+        total_annual_cost: results.iter().map(|r| r.annual_cost).sum(),
+        items: results,
+        warnings,
+    })
+}
+
+/// 트랩 조사 CSV를 읽는다.
+/// 열: `tag,kind,pressure_bar_g,orifice_mm,hours_per_year[,cd]`.
+/// kind는 `leak` 또는 `trap`, cd를 비우면 누설 0.7 / 고장 트랩 0.4를 쓴다.
+/// 첫 줄이 헤더(압력 열이 숫자가 아님)면 건너뛰고, 빈 줄과 `#` 주석 줄은 무시한다.
+pub fn parse_trap_survey_csv(text: &str) -> Result<Vec<LeakItem>, LeakError> {
+    let mut items = Vec::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+        let csv_err = |message: String| LeakError::Csv {
+            line: line_no,
+            message,
+        };
+        if cols.len() < 5 {
+            return Err(csv_err(format!(
+                "열이 5개 이상 필요합니다 (현재 {}개).",
+                cols.len()
+            )));
+        }
+        if items.is_empty() && cols[2].parse::<f64>().is_err() {
+            continue;
+        }
+        let num = |i: usize, name: &str| {
+            cols[i].parse::<f64>().map_err(|_| {
+                csv_err(format!(
+                    "{name} 값 '{}'을 숫자로 읽을 수 없습니다.",
+                    cols[i]
+                ))
+            })
+        };
+        let kind = match cols[1].to_ascii_lowercase().as_str() {
+            "leak" => LeakKind::Leak,
+            "trap" | "failed_trap" => LeakKind::FailedTrap,
+            other => return Err(csv_err(format!("알 수 없는 종류 '{other}' (leak/trap)."))),
+        };
+        let cd = match cols.get(5).filter(|c| !c.is_empty()) {
+            Some(_) => num(5, "cd")?,
+            None => match kind {
+                LeakKind::Leak => 0.7,
+                LeakKind::FailedTrap => 0.4,
+            },
+        };
+        items.push(LeakItem {
+            tag: cols[0].to_string(),
+            kind,
+            pressure_bar_abs: num(2, "pressure_bar_g")? + P_ATM_BAR,
+            orifice_diameter_m: num(3, "orifice_mm")? / 1000.0,
+            discharge_coefficient: cd,
+            operating_hours_per_year: num(4, "hours_per_year")?,
+        });
+    }
+    Ok(items)
+}
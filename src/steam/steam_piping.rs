@@ -1,16 +1,34 @@
+pub mod fittings;
+pub mod pipe_run;
+pub mod velocity_check;
+
+use crate::goal_seek::{self, GoalSeekError};
+use crate::typed_quantity::{Density, MassFlow, Pressure, Temperature, Velocity};
 use crate::units::{convert_pressure, convert_temperature, PressureUnit, TemperatureUnit};
+use fittings::{FittingList, FittingMethod};
+
+const G: f64 = 9.80665;
 
 /// 배관 계산 오류를 표현한다.
 #[derive(Debug)]
 pub enum PipeCalcError {
     /// 입력값이 잘못된 경우
     InvalidInput(&'static str),
+    /// 역산(goal-seek) 실패
+    GoalSeek(GoalSeekError),
+    /// 피팅 카탈로그에 없는 코드
+    UnknownFitting(String),
+    /// 배관 구간 CSV 형식 오류
+    Csv { line: usize, message: String },
 }
 
 impl std::fmt::Display for PipeCalcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PipeCalcError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            PipeCalcError::GoalSeek(e) => write!(f, "역산 실패: {e}"),
+            PipeCalcError::UnknownFitting(code) => write!(f, "알 수 없는 피팅: {code}"),
+            PipeCalcError::Csv { line, message } => write!(f, "CSV {line}행: {message}"),
         }
     }
 }
@@ -52,18 +70,34 @@ pub struct PressureLossInput {
     pub state_pressure_bar_abs: Option<f64>,
     /// 압력손실 계산용 상태 온도(°C). 제공 시 IF97로 밀도/점도 계산에 사용.
     pub state_temperature_c: Option<f64>,
+    /// 입구 높이 [m]
+    pub inlet_elevation_m: f64,
+    /// 출구 높이 [m]. 입구보다 높으면 정수두만큼 압력이 더 떨어진다.
+    pub outlet_elevation_m: f64,
 }
 
 /// 압력손실 계산 결과.
 #[derive(Debug, Clone)]
 pub struct PressureLossResult {
+    /// 계산에 쓴 밀도 [kg/m³] (IF97 상태가 있으면 그 값)
+    pub density_kg_per_m3: f64,
     pub velocity_m_per_s: f64,
+    /// 마찰·피팅 손실 [bar] (정수두 제외)
     pub pressure_drop_bar: f64,
+    /// 정수두 ρ·g·(z출구 - z입구) [bar]. 오르막이 +, 내리막이 -.
+    pub elevation_drop_bar: f64,
     pub reynolds_number: f64,
     pub friction_factor: f64,
     pub mach: f64,
 }
 
+impl PressureLossResult {
+    /// 마찰 손실과 정수두를 합한 입구-출구 압력 차 [bar]
+    pub fn total_drop_bar(&self) -> f64 {
+        self.pressure_drop_bar + self.elevation_drop_bar
+    }
+}
+
 /// 목표 유속을 만족하는 배관 내경을 계산한다.
 pub fn size_by_velocity(
     input: PipeSizingByVelocityInput,
@@ -96,10 +130,24 @@ pub fn size_by_velocity(
     })
 }
 
+/// 강타입 입력으로 목표 유속을 만족하는 배관 내경을 계산한다.
+pub fn size_by_velocity_typed(
+    mass_flow: MassFlow,
+    density: Density,
+    target_velocity: Velocity,
+) -> Result<PipeSizingByVelocityResult, PipeCalcError> {
+    size_by_velocity(PipeSizingByVelocityInput {
+        mass_flow_kg_per_h: mass_flow.kg_per_h(),
+        steam_density_kg_per_m3: density.kg_per_m3(),
+        target_velocity_m_per_s: target_velocity.m_per_s(),
+    })
+}
+
 /// Darcy-Weisbach 식을 사용해 압력손실을 추정한다.
 ///
 /// ΔP = f * (L/D) * ρ * v² / 2
 /// - f: 마찰계수(여기서는 단순 블라지우스/문수근사 혼합으로 계산)
+/// - 입구/출구 높이가 다르면 ρ·g·Δz를 `elevation_drop_bar`에 따로 낸다.
 pub fn pressure_loss(input: PressureLossInput) -> Result<PressureLossResult, PipeCalcError> {
     if input.mass_flow_kg_per_h <= 0.0 || input.diameter_m <= 0.0 || input.length_m <= 0.0 {
         return Err(PipeCalcError::InvalidInput(
@@ -138,6 +186,8 @@ pub fn pressure_loss(input: PressureLossInput) -> Result<PressureLossResult, Pip
         * velocity
         / 2.0;
     let delta_p_bar = delta_p_pa / 100_000.0;
+    let elevation_drop_pa =
+        steam_density_kg_per_m3 * G * (input.outlet_elevation_m - input.inlet_elevation_m);
     let mach = if input.sound_speed_m_per_s > 0.0 {
         velocity / input.sound_speed_m_per_s
     } else {
@@ -145,14 +195,74 @@ pub fn pressure_loss(input: PressureLossInput) -> Result<PressureLossResult, Pip
     };
 
     Ok(PressureLossResult {
+        density_kg_per_m3: steam_density_kg_per_m3,
         velocity_m_per_s: velocity,
         pressure_drop_bar: delta_p_bar,
+        elevation_drop_bar: elevation_drop_pa / 100_000.0,
         reynolds_number: reynolds,
         friction_factor,
         mach,
     })
 }
 
+/// 피팅 목록의 K를 `fittings_k_sum`에 더해 압력손실을 계산한다.
+/// 2-K 방식에 필요한 Re는 피팅과 무관하므로 피팅 없이 한 번 먼저 구한다.
+pub fn pressure_loss_with_fittings(
+    input: PressureLossInput,
+    fittings: &FittingList,
+    method: FittingMethod,
+) -> Result<PressureLossResult, PipeCalcError> {
+    if fittings.is_empty() {
+        return pressure_loss(input);
+    }
+    let base = pressure_loss(input.clone())?;
+    let k = fittings.k_sum(method, base.reynolds_number, input.diameter_m);
+    pressure_loss(PressureLossInput {
+        fittings_k_sum: input.fittings_k_sum + k,
+        ..input
+    })
+}
+
+/// 목표 압력손실(bar)을 만족하는 배관 내경(m)을 역산한다. `base.diameter_m`은 무시한다.
+/// ΔP는 내경에 대해 단조 감소하므로 [d_min_m, d_max_m] 구간에서 Brent 탐색을 사용한다.
+pub fn diameter_for_pressure_drop(
+    base: &PressureLossInput,
+    target_pressure_drop_bar: f64,
+    d_min_m: f64,
+    d_max_m: f64,
+) -> Result<f64, PipeCalcError> {
+    if target_pressure_drop_bar <= 0.0 {
+        return Err(PipeCalcError::InvalidInput(
+            "목표 압력손실은 0보다 커야 합니다.",
+        ));
+    }
+    if d_min_m <= 0.0 || d_max_m <= d_min_m {
+        return Err(PipeCalcError::InvalidInput(
+            "내경 탐색 구간은 0 < 최소 < 최대여야 합니다.",
+        ));
+    }
+    // 입력 오류는 탐색 전에 한 번 드러낸다.
+    pressure_loss(PressureLossInput {
+        diameter_m: d_max_m,
+        ..base.clone()
+    })?;
+    let res = goal_seek::goal_seek(
+        |d| {
+            pressure_loss(PressureLossInput {
+                diameter_m: d,
+                ..base.clone()
+            })
+            .map(|r| r.pressure_drop_bar)
+            .unwrap_or(f64::NAN)
+        },
+        target_pressure_drop_bar,
+        d_min_m,
+        d_max_m,
+    )
+    .map_err(PipeCalcError::GoalSeek)?;
+    Ok(res.x)
+}
+
 fn resolve_steam_props(input: &PressureLossInput) -> (f64, f64) {
     if let (Some(p_bar_abs), Some(t_c)) = (input.state_pressure_bar_abs, input.state_temperature_c)
     {
@@ -204,3 +314,9 @@ pub fn estimate_density(
     let r_specific = 461.5; // 증기 기체상수 [J/(kg·K)]
     p_pa / (r_specific * t_k)
 }
+
+/// 강타입 압력/온도로 이상기체 근사 증기 밀도를 계산한다.
+pub fn estimate_density_typed(pressure: Pressure, temperature: Temperature) -> Density {
+    let r_specific = 461.5; // 증기 기체상수 [J/(kg·K)]
+    Density::from_kg_per_m3(pressure.pa_abs() / (r_specific * temperature.kelvin()))
+}
@@ -0,0 +1,355 @@
+//! 배관 피팅 K 계수 카탈로그와 K/등가 길이 합산기.
+//! - 2-K (Hooper 1981): K = K₁/Re + K∞·(1 + 1/D[in])
+//! - Crane TP-410: K = (L/D)·f_T, f_T는 청정 상용 강관(ε 0.045 mm)의 완전 난류 마찰계수
+//!
+//! 입·출구와 리듀서처럼 L/D 자료가 없는 항목은 크기 항 없이 K₁/Re + K∞를 쓰고,
+//! Crane 방식에서는 K∞를 고정 K로 쓴다. 리듀서/확대관 K는 작은 쪽 관 기준이다.
+
+use super::PipeCalcError;
+use serde::{Deserialize, Serialize};
+
+/// Crane f_T 계산에 쓰는 청정 상용 강관 거칠기 [m]
+const CRANE_ROUGHNESS_M: f64 = 0.045e-3;
+
+/// 피팅 K 계산 방식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FittingMethod {
+    /// Hooper 2-K (Re와 관경 반영)
+    #[default]
+    TwoK,
+    /// Crane TP-410 L/D × f_T
+    Crane,
+}
+
+impl FittingMethod {
+    /// 콤보/목록 순서
+    pub const ALL: [FittingMethod; 2] = [FittingMethod::TwoK, FittingMethod::Crane];
+
+    /// 표시 라벨
+    pub fn label(self) -> &'static str {
+        match self {
+            FittingMethod::TwoK => "2-K (Hooper)",
+            FittingMethod::Crane => "Crane TP-410",
+        }
+    }
+
+    /// 코드 문자열에서 방식을 찾는다 (`2k`/`hooper`, `crane`).
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.trim().to_ascii_lowercase().as_str() {
+            "2k" | "2-k" | "hooper" => Some(FittingMethod::TwoK),
+            "crane" => Some(FittingMethod::Crane),
+            _ => None,
+        }
+    }
+}
+
+/// 피팅 한 종류의 자료.
+#[derive(Debug)]
+pub struct Fitting {
+    pub code: &'static str,
+    /// 표시 이름 (언어팩이 없을 때)
+    pub name: &'static str,
+    /// 표시 이름의 언어팩 키
+    pub key: &'static str,
+    /// 2-K 저 Re 항 K₁
+    pub k1: f64,
+    /// 2-K 고 Re 항 K∞ (L/D 자료가 없으면 Crane 방식의 고정 K)
+    pub k_inf: f64,
+    /// Crane 등가 길이비 L/D
+    pub l_over_d: Option<f64>,
+}
+
+impl Fitting {
+    /// 2-K 방식 K. Re가 0 이하이면 K₁ 항을 뺀다.
+    pub fn k_two_k(&self, reynolds: f64, diameter_m: f64) -> f64 {
+        let k1_term = if reynolds > 0.0 {
+            self.k1 / reynolds
+        } else {
+            0.0
+        };
+        match self.l_over_d {
+            Some(_) => k1_term + self.k_inf * (1.0 + 0.0254 / diameter_m),
+            None => k1_term + self.k_inf,
+        }
+    }
+
+    /// Crane 방식 K
+    pub fn k_crane(&self, diameter_m: f64) -> f64 {
+        match self.l_over_d {
+            Some(l_over_d) => l_over_d * crane_friction_factor(diameter_m),
+            None => self.k_inf,
+        }
+    }
+
+    /// 방식에 따른 K. Crane 방식은 `reynolds`를 쓰지 않는다.
+    pub fn k(&self, method: FittingMethod, reynolds: f64, diameter_m: f64) -> f64 {
+        match method {
+            FittingMethod::TwoK => self.k_two_k(reynolds, diameter_m),
+            FittingMethod::Crane => self.k_crane(diameter_m),
+        }
+    }
+}
+
+/// Crane f_T: 청정 상용 강관의 완전 난류 마찰계수 (Swamee-Jain에서 Re→∞)
+pub fn crane_friction_factor(diameter_m: f64) -> f64 {
+    0.25 / (CRANE_ROUGHNESS_M / (3.7 * diameter_m)).log10().powi(2)
+}
+
+pub fn catalog() -> &'static [Fitting] {
+    FITTINGS
+}
+
+/// 코드 또는 이름으로 피팅을 찾는다 (대소문자 무시).
+pub fn find_fitting(code: &str) -> Option<&'static Fitting> {
+    let code = code.trim();
+    FITTINGS
+        .iter()
+        .find(|f| f.code.eq_ignore_ascii_case(code) || f.name.eq_ignore_ascii_case(code))
+}
+
+/// 피팅 개수 목록. 같은 피팅을 다시 더하면 개수를 합친다.
+#[derive(Debug, Clone, Default)]
+pub struct FittingList {
+    items: Vec<(&'static Fitting, u32)>,
+}
+
+impl FittingList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(mut self, fitting: &'static Fitting, count: u32) -> Self {
+        if count == 0 {
+            return self;
+        }
+        match self.items.iter_mut().find(|(f, _)| f.code == fitting.code) {
+            Some((_, n)) => *n += count,
+            None => self.items.push((fitting, count)),
+        }
+        self
+    }
+
+    /// 카탈로그 코드로 피팅을 더한다.
+    pub fn add_code(self, code: &str, count: u32) -> Result<Self, PipeCalcError> {
+        let fitting =
+            find_fitting(code).ok_or_else(|| PipeCalcError::UnknownFitting(code.trim().into()))?;
+        Ok(self.add(fitting, count))
+    }
+
+    /// `elbow90-lr:4, gate, globe:2` 형식(개수 생략 시 1)을 읽는다. 빈 문자열은 빈 목록.
+    pub fn parse(text: &str) -> Result<Self, PipeCalcError> {
+        let mut list = Self::new();
+        for part in text
+            .split([',', ';'])
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let (code, count) = match part.split_once(['*', ':', '=']) {
+                Some((code, n)) => (
+                    code,
+                    n.trim().parse::<u32>().map_err(|_| {
+                        PipeCalcError::InvalidInput("피팅 개수는 0 이상의 정수여야 합니다.")
+                    })?,
+                ),
+                None => (part, 1),
+            };
+            list = list.add_code(code, count)?;
+        }
+        Ok(list)
+    }
+
+    pub fn items(&self) -> &[(&'static Fitting, u32)] {
+        &self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// 목록 전체의 K 합
+    pub fn k_sum(&self, method: FittingMethod, reynolds: f64, diameter_m: f64) -> f64 {
+        self.items
+            .iter()
+            .map(|(f, n)| f64::from(*n) * f.k(method, reynolds, diameter_m))
+            .sum()
+    }
+
+    /// K 합을 마찰계수 `friction_factor`의 직관 등가 길이 [m]로 바꾼다 (L = ΣK·D/f).
+    pub fn equivalent_length_m(
+        &self,
+        method: FittingMethod,
+        reynolds: f64,
+        diameter_m: f64,
+        friction_factor: f64,
+    ) -> f64 {
+        if friction_factor <= 0.0 {
+            return 0.0;
+        }
+        self.k_sum(method, reynolds, diameter_m) * diameter_m / friction_factor
+    }
+}
+
+// 2-K: Hooper, Chem. Eng. 1981. L/D: Crane TP-410. 리듀서/확대관은 β≈0.75, 30° 동심형(Crane 식 1, 3).
+const FITTINGS: &[Fitting] = &[
+    Fitting {
+        code: "elbow90-std",
+        name: "90° elbow, standard (R/D 1)",
+        key: "fitting.elbow90_std",
+        k1: 800.0,
+        k_inf: 0.40,
+        l_over_d: Some(30.0),
+    },
+    Fitting {
+        code: "elbow90-lr",
+        name: "90° elbow, long radius (R/D 1.5)",
+        key: "fitting.elbow90_lr",
+        k1: 800.0,
+        k_inf: 0.20,
+        l_over_d: Some(14.0),
+    },
+    Fitting {
+        code: "elbow90-mitre",
+        name: "90° mitre bend, 1 weld",
+        key: "fitting.elbow90_mitre",
+        k1: 1000.0,
+        k_inf: 1.15,
+        l_over_d: Some(60.0),
+    },
+    Fitting {
+        code: "elbow45-std",
+        name: "45° elbow, standard",
+        key: "fitting.elbow45_std",
+        k1: 500.0,
+        k_inf: 0.20,
+        l_over_d: Some(16.0),
+    },
+    Fitting {
+        code: "bend180",
+        name: "180° return bend",
+        key: "fitting.bend180",
+        k1: 1000.0,
+        k_inf: 0.35,
+        l_over_d: Some(50.0),
+    },
+    Fitting {
+        code: "tee-run",
+        name: "Tee, flow through run",
+        key: "fitting.tee_run",
+        k1: 150.0,
+        k_inf: 0.05,
+        l_over_d: Some(20.0),
+    },
+    Fitting {
+        code: "tee-branch",
+        name: "Tee, flow through branch",
+        key: "fitting.tee_branch",
+        k1: 800.0,
+        k_inf: 0.80,
+        l_over_d: Some(60.0),
+    },
+    Fitting {
+        code: "reducer",
+        name: "Concentric reducer (one size)",
+        key: "fitting.reducer",
+        k1: 0.0,
+        k_inf: 0.09,
+        l_over_d: None,
+    },
+    Fitting {
+        code: "expander",
+        name: "Concentric expander (one size)",
+        key: "fitting.expander",
+        k1: 0.0,
+        k_inf: 0.13,
+        l_over_d: None,
+    },
+    Fitting {
+        code: "gate",
+        name: "Gate valve, open",
+        key: "fitting.gate",
+        k1: 300.0,
+        k_inf: 0.10,
+        l_over_d: Some(8.0),
+    },
+    Fitting {
+        code: "ball",
+        name: "Ball valve, full bore",
+        key: "fitting.ball",
+        k1: 300.0,
+        k_inf: 0.10,
+        l_over_d: Some(3.0),
+    },
+    Fitting {
+        code: "globe",
+        name: "Globe valve, open",
+        key: "fitting.globe",
+        k1: 1500.0,
+        k_inf: 4.0,
+        l_over_d: Some(340.0),
+    },
+    Fitting {
+        code: "angle",
+        name: "Angle valve, open",
+        key: "fitting.angle",
+        k1: 1000.0,
+        k_inf: 2.0,
+        l_over_d: Some(150.0),
+    },
+    Fitting {
+        code: "butterfly",
+        name: "Butterfly valve, open",
+        key: "fitting.butterfly",
+        k1: 800.0,
+        k_inf: 0.25,
+        l_over_d: Some(45.0),
+    },
+    Fitting {
+        code: "check-swing",
+        name: "Swing check valve",
+        key: "fitting.check_swing",
+        k1: 1500.0,
+        k_inf: 1.5,
+        l_over_d: Some(100.0),
+    },
+    Fitting {
+        code: "check-lift",
+        name: "Lift check valve",
+        key: "fitting.check_lift",
+        k1: 2000.0,
+        k_inf: 10.0,
+        l_over_d: Some(600.0),
+    },
+    Fitting {
+        code: "entrance-sharp",
+        name: "Entrance, sharp-edged",
+        key: "fitting.entrance_sharp",
+        k1: 160.0,
+        k_inf: 0.5,
+        l_over_d: None,
+    },
+    Fitting {
+        code: "entrance-projecting",
+        name: "Entrance, inward projecting",
+        key: "fitting.entrance_projecting",
+        k1: 160.0,
+        k_inf: 1.0,
+        l_over_d: None,
+    },
+    Fitting {
+        code: "entrance-rounded",
+        name: "Entrance, well rounded",
+        key: "fitting.entrance_rounded",
+        k1: 160.0,
+        k_inf: 0.04,
+        l_over_d: None,
+    },
+    Fitting {
+        code: "exit",
+        name: "Exit to vessel",
+        key: "fitting.exit",
+        k1: 0.0,
+        k_inf: 1.0,
+        l_over_d: None,
+    },
+];
@@ -0,0 +1,274 @@
+//! 관경·길이·고저차·피팅이 서로 다른 여러 구간으로 이뤄진 배관 경로의 압력 계산.
+//! 구간마다 입구 상태에서 `pressure_loss`(Darcy)로 마찰 손실과 ρ·g·Δz 정수두를 구해
+//! 다음 구간 입구 압력으로 넘긴다. 유동은 등엔탈피(단열 교축)로 보고 온도는 (p, h)에서 다시 구한다.
+//! 한 구간의 손실이 입구 압력의 5%를 넘으면 밀도 변화를 따라가도록 구간을 잘게 나눠 계산한다.
+
+use super::fittings::{FittingList, FittingMethod};
+use super::{pressure_loss, PipeCalcError, PressureLossInput};
+use crate::material_db;
+use crate::steam::if97;
+
+/// 나눈 한 조각의 손실이 조각 입구 압력에서 차지할 최대 비율
+const MAX_STEP_DROP_FRACTION: f64 = 0.05;
+/// 한 구간을 나누는 최대 조각 수
+const MAX_SUBSTEPS: usize = 50;
+/// CSV에서 거칠기를 비웠을 때 쓰는 값 [mm] (신품 탄소강)
+const DEFAULT_ROUGHNESS_MM: f64 = 0.045;
+
+/// 배관 구간 하나.
+#[derive(Debug, Clone)]
+pub struct PipeSegment {
+    pub name: String,
+    pub diameter_m: f64,
+    pub length_m: f64,
+    /// 출구 높이 - 입구 높이 [m] (오르막이 +)
+    pub elevation_change_m: f64,
+    pub roughness_m: f64,
+    pub fittings: FittingList,
+    /// 카탈로그 밖의 추가 K 합
+    pub extra_k: f64,
+}
+
+/// 배관 경로 입력값.
+#[derive(Debug, Clone)]
+pub struct PipeRunInput {
+    pub mass_flow_kg_per_h: f64,
+    pub inlet_pressure_bar_abs: f64,
+    pub inlet_temperature_c: f64,
+    pub fitting_method: FittingMethod,
+    /// IF97 상태로 점도를 못 구할 때 쓰는 점도 [Pa·s]
+    pub dynamic_viscosity_pa_s: f64,
+    /// 음속 [m/s]. Mach 계산용.
+    pub sound_speed_m_per_s: f64,
+    pub segments: Vec<PipeSegment>,
+}
+
+/// 구간별 결과. 밀도·유속·Re·f는 구간 입구 값, Mach는 구간 안의 최댓값이다.
+#[derive(Debug, Clone)]
+pub struct SegmentResult {
+    pub name: String,
+    pub inlet_pressure_bar_abs: f64,
+    pub outlet_pressure_bar_abs: f64,
+    pub inlet_temperature_c: f64,
+    pub density_kg_per_m3: f64,
+    pub velocity_m_per_s: f64,
+    pub reynolds_number: f64,
+    pub friction_factor: f64,
+    /// 피팅 K 합 (추가 K 포함)
+    pub fittings_k: f64,
+    /// 마찰·피팅 손실 [bar]
+    pub friction_drop_bar: f64,
+    /// 정수두 ρ·g·Δz [bar] (오르막이 +)
+    pub elevation_drop_bar: f64,
+    pub mach: f64,
+    /// 계산에 쓴 조각 수
+    pub substeps: usize,
+}
+
+impl SegmentResult {
+    /// 구간 전체 압력 강하 [bar]
+    pub fn total_drop_bar(&self) -> f64 {
+        self.friction_drop_bar + self.elevation_drop_bar
+    }
+}
+
+/// 배관 경로 결과.
+#[derive(Debug, Clone)]
+pub struct PipeRunResult {
+    pub segments: Vec<SegmentResult>,
+    pub friction_drop_bar: f64,
+    pub elevation_drop_bar: f64,
+    pub total_drop_bar: f64,
+    pub outlet_pressure_bar_abs: f64,
+}
+
+/// 구간을 순서대로 따라가며 구간별/전체 압력 강하와 끝 압력을 계산한다.
+pub fn pipe_run(input: &PipeRunInput) -> Result<PipeRunResult, PipeCalcError> {
+    if input.mass_flow_kg_per_h <= 0.0 || input.inlet_pressure_bar_abs <= 0.0 {
+        return Err(PipeCalcError::InvalidInput(
+            "질량유량과 입구 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input.segments.is_empty() {
+        return Err(PipeCalcError::InvalidInput(
+            "배관 구간이 하나 이상 있어야 합니다.",
+        ));
+    }
+    let (h, _, _) = if97::region_props(input.inlet_pressure_bar_abs, input.inlet_temperature_c)
+        .map_err(|_| PipeCalcError::InvalidInput("입구 상태를 IF97로 계산할 수 없습니다."))?;
+
+    let mut segments = Vec::with_capacity(input.segments.len());
+    let mut p = input.inlet_pressure_bar_abs;
+    for segment in &input.segments {
+        let single = march(input, segment, p, h, 1)?;
+        let fraction = single.total_drop_bar().abs() / p;
+        let result = if fraction > MAX_STEP_DROP_FRACTION {
+            let n = ((fraction / MAX_STEP_DROP_FRACTION).ceil() as usize).min(MAX_SUBSTEPS);
+            march(input, segment, p, h, n)?
+        } else {
+            single
+        };
+        p = result.outlet_pressure_bar_abs;
+        if p <= 0.0 {
+            return Err(pressure_exhausted());
+        }
+        segments.push(result);
+    }
+
+    let friction_drop_bar = segments.iter().map(|s| s.friction_drop_bar).sum();
+    let elevation_drop_bar = segments.iter().map(|s| s.elevation_drop_bar).sum();
+    Ok(PipeRunResult {
+        segments,
+        friction_drop_bar,
+        elevation_drop_bar,
+        total_drop_bar: input.inlet_pressure_bar_abs - p,
+        outlet_pressure_bar_abs: p,
+    })
+}
+
+fn pressure_exhausted() -> PipeCalcError {
+    PipeCalcError::InvalidInput(
+        "배관 끝 압력이 0 이하로 떨어졌습니다. 관경을 키우거나 유량을 줄이세요.",
+    )
+}
+
+/// 구간을 `n`조각으로 나눠 조각마다 입구 상태로 Darcy 손실과 정수두를 구한다.
+/// 피팅 K와 고저차는 조각에 고르게 나눈다. 끝 압력은 음수일 수 있으며 호출 측에서 확인한다.
+fn march(
+    run: &PipeRunInput,
+    segment: &PipeSegment,
+    p_in: f64,
+    h: f64,
+    n: usize,
+) -> Result<SegmentResult, PipeCalcError> {
+    let share = 1.0 / n as f64;
+    let mut p = p_in;
+    let mut out: Option<SegmentResult> = None;
+    for _ in 0..n {
+        if p <= 0.0 {
+            return Err(pressure_exhausted());
+        }
+        let t_c = if97::temperature_from_ph(p, h)
+            .map_err(|_| PipeCalcError::InvalidInput("구간 상태를 IF97로 계산할 수 없습니다."))?;
+        let (_, v, _) = if97::region_props(p, t_c)
+            .map_err(|_| PipeCalcError::InvalidInput("구간 상태를 IF97로 계산할 수 없습니다."))?;
+        let step = PressureLossInput {
+            mass_flow_kg_per_h: run.mass_flow_kg_per_h,
+            steam_density_kg_per_m3: 1.0 / v,
+            diameter_m: segment.diameter_m,
+            length_m: segment.length_m * share,
+            fittings_k_sum: 0.0,
+            equivalent_length_m: 0.0,
+            roughness_m: segment.roughness_m,
+            dynamic_viscosity_pa_s: run.dynamic_viscosity_pa_s,
+            sound_speed_m_per_s: run.sound_speed_m_per_s,
+            state_pressure_bar_abs: Some(p),
+            state_temperature_c: Some(t_c),
+            inlet_elevation_m: 0.0,
+            outlet_elevation_m: segment.elevation_change_m * share,
+        };
+        // 2-K 피팅 K는 Re에 따라 달라지므로 피팅 없이 먼저 Re를 구한다.
+        let bare = pressure_loss(step.clone())?;
+        let k = segment.extra_k
+            + segment
+                .fittings
+                .k_sum(run.fitting_method, bare.reynolds_number, segment.diameter_m);
+        let r = pressure_loss(PressureLossInput {
+            fittings_k_sum: k * share,
+            ..step
+        })?;
+        let elevation = r.elevation_drop_bar;
+        p -= r.pressure_drop_bar + elevation;
+        match out.as_mut() {
+            Some(acc) => {
+                acc.friction_drop_bar += r.pressure_drop_bar;
+                acc.elevation_drop_bar += elevation;
+                acc.mach = acc.mach.max(r.mach);
+            }
+            None => {
+                out = Some(SegmentResult {
+                    name: segment.name.clone(),
+                    inlet_pressure_bar_abs: p_in,
+                    outlet_pressure_bar_abs: p,
+                    inlet_temperature_c: t_c,
+                    density_kg_per_m3: r.density_kg_per_m3,
+                    velocity_m_per_s: r.velocity_m_per_s,
+                    reynolds_number: r.reynolds_number,
+                    friction_factor: r.friction_factor,
+                    fittings_k: k,
+                    friction_drop_bar: r.pressure_drop_bar,
+                    elevation_drop_bar: elevation,
+                    mach: r.mach,
+                    substeps: n,
+                })
+            }
+        }
+    }
+    let mut result = out.expect("n >= 1");
+    result.outlet_pressure_bar_abs = p;
+    Ok(result)
+}
+
+/// 배관 구간 CSV를 읽는다.
+/// 열: `name,diameter_mm,length_m,rise_m[,roughness_mm|재질][,extra_k][,fittings]`.
+/// 거칠기는 mm 값이나 `material_db` 거칠기 프리셋 이름(pvc, stainless 등)이며 비우면 0.045 mm,
+/// fittings는 `elbow90-lr:4; gate:2` 형식이다(쉼표로 나눠 써도 마지막 열부터 끝까지 합친다).
+/// 첫 줄이 헤더(관경 열이 숫자가 아님)면 건너뛰고, 빈 줄과 `#` 주석 줄은 무시한다.
+pub fn parse_pipe_run_csv(text: &str) -> Result<Vec<PipeSegment>, PipeCalcError> {
+    let mut segments = Vec::new();
+    for (idx, raw) in text.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let cols: Vec<&str> = line.split(',').map(|c| c.trim()).collect();
+        let csv_err = |message: String| PipeCalcError::Csv {
+            line: line_no,
+            message,
+        };
+        if cols.len() < 4 {
+            return Err(csv_err(format!(
+                "열이 4개 이상 필요합니다 (현재 {}개).",
+                cols.len()
+            )));
+        }
+        if segments.is_empty() && cols[1].parse::<f64>().is_err() {
+            continue;
+        }
+        let num = |i: usize, name: &str| {
+            cols[i].parse::<f64>().map_err(|_| {
+                csv_err(format!(
+                    "{name} 값 '{}'을 숫자로 읽을 수 없습니다.",
+                    cols[i]
+                ))
+            })
+        };
+        let roughness_m = match cols.get(4).filter(|c| !c.is_empty()) {
+            None => DEFAULT_ROUGHNESS_MM / 1000.0,
+            Some(c) => match material_db::find_roughness(c) {
+                Some(preset) => preset.roughness_m(),
+                None => num(4, "roughness_mm")? / 1000.0,
+            },
+        };
+        let extra_k = match cols.get(5).filter(|c| !c.is_empty()) {
+            Some(_) => num(5, "extra_k")?,
+            None => 0.0,
+        };
+        let fittings = match cols.get(6..) {
+            Some(rest) if !rest.is_empty() => FittingList::parse(&rest.join(","))
+                .map_err(|e| csv_err(format!("fittings: {e}")))?,
+            _ => FittingList::new(),
+        };
+        segments.push(PipeSegment {
+            name: cols[0].to_string(),
+            diameter_m: num(1, "diameter_mm")? / 1000.0,
+            length_m: num(2, "length_m")?,
+            elevation_change_m: num(3, "rise_m")?,
+            roughness_m,
+            fittings,
+            extra_k,
+        });
+    }
+    Ok(segments)
+}
@@ -0,0 +1,340 @@
+//! 배관 유속 점검: 유체별 지침 유속(Spirax Sarco / CIBSE Guide C)과 API RP 14E 침식 한계 유속.
+//! 지침 유속은 권장값 이하면 통과, 권장값~상한이면 주의, 상한을 넘으면 실패로 본다.
+//! 침식 한계 Ve = 1.22·C/√ρ [m/s] (C: 14E C-계수, 연속 운전 100)를 넘어도 실패다.
+
+use super::{PipeCalcError, PipeSizingByVelocityResult};
+use crate::steam::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// API RP 14E 연속 운전 C-계수 (고형물 없는 유체)
+pub const DEFAULT_C_FACTOR: f64 = 100.0;
+/// 습증기 건도를 주지 않았을 때 쓰는 값
+pub const DEFAULT_WET_QUALITY: f64 = 0.95;
+/// 14E 식 Ve[ft/s] = C/√ρ[lb/ft³]를 SI로 바꾸는 계수 (0.3048/√0.062428)
+const EROSIONAL_SI_FACTOR: f64 = 1.2199;
+/// 소구경으로 보는 내경 상한 [m] (DN50 Sch40)
+const SMALL_BORE_M: f64 = 0.0525;
+/// 중구경으로 보는 내경 상한 [m] (DN150 Sch40)
+const MEDIUM_BORE_M: f64 = 0.1541;
+/// 포화 상태로 보는 온도 폭 [K]
+const SATURATION_BAND_K: f64 = 0.5;
+
+/// 배관 유체 종류.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PipeService {
+    SaturatedSteam,
+    SuperheatedSteam,
+    /// 습증기·배기 증기·플래시 증기
+    WetSteam,
+    /// 펌프 압송 응축수 (포화수)
+    Condensate,
+    Water,
+}
+
+impl PipeService {
+    pub const ALL: [PipeService; 5] = [
+        PipeService::SaturatedSteam,
+        PipeService::SuperheatedSteam,
+        PipeService::WetSteam,
+        PipeService::Condensate,
+        PipeService::Water,
+    ];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            PipeService::SaturatedSteam => "saturated",
+            PipeService::SuperheatedSteam => "superheated",
+            PipeService::WetSteam => "wet",
+            PipeService::Condensate => "condensate",
+            PipeService::Water => "water",
+        }
+    }
+
+    /// 언어팩이 없을 때 쓰는 이름.
+    pub fn label(self) -> &'static str {
+        match self {
+            PipeService::SaturatedSteam => "Saturated steam",
+            PipeService::SuperheatedSteam => "Superheated steam",
+            PipeService::WetSteam => "Wet / exhaust steam",
+            PipeService::Condensate => "Pumped condensate",
+            PipeService::Water => "Water",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            PipeService::SaturatedSteam => "pipe_service.saturated",
+            PipeService::SuperheatedSteam => "pipe_service.superheated",
+            PipeService::WetSteam => "pipe_service.wet",
+            PipeService::Condensate => "pipe_service.condensate",
+            PipeService::Water => "pipe_service.water",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_ascii_lowercase();
+        Self::ALL.into_iter().find(|s| s.code() == code)
+    }
+
+    /// 압력·온도로 서비스를 고른다. 포화온도 ±0.5 K 안은 포화 증기, 위는 과열 증기, 아래는 물.
+    pub fn from_state(pressure_bar_abs: f64, temperature_c: f64) -> Result<Self, PipeCalcError> {
+        let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(pressure_bar_abs)
+            .map_err(|_| PipeCalcError::InvalidInput("포화온도를 IF97로 계산할 수 없습니다."))?;
+        Ok(if temperature_c > t_sat + SATURATION_BAND_K {
+            PipeService::SuperheatedSteam
+        } else if temperature_c < t_sat - SATURATION_BAND_K {
+            PipeService::Water
+        } else {
+            PipeService::SaturatedSteam
+        })
+    }
+}
+
+/// 지침 유속 범위 [m/s].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VelocityGuidance {
+    pub recommended_m_per_s: f64,
+    pub max_m_per_s: f64,
+    /// 근거 지침
+    pub source: &'static str,
+}
+
+/// 서비스와 내경에 맞는 지침 유속. 증기 소구경(≤DN50)은 소음·압력손실 때문에 낮춰 잡는다.
+pub fn velocity_guidance(service: PipeService, diameter_m: f64) -> VelocityGuidance {
+    let small = diameter_m <= SMALL_BORE_M;
+    let (recommended_m_per_s, max_m_per_s, source) = match service {
+        PipeService::SaturatedSteam if small => (15.0, 25.0, "CIBSE Guide C"),
+        PipeService::SaturatedSteam => (25.0, 40.0, "Spirax Sarco"),
+        PipeService::SuperheatedSteam if small => (35.0, 50.0, "CIBSE Guide C"),
+        PipeService::SuperheatedSteam => (50.0, 70.0, "Spirax Sarco"),
+        PipeService::WetSteam => (15.0, 20.0, "Spirax Sarco"),
+        PipeService::Condensate => (1.5, 2.0, "Spirax Sarco"),
+        PipeService::Water if small => (1.5, 2.0, "CIBSE Guide C"),
+        PipeService::Water if diameter_m <= MEDIUM_BORE_M => (2.0, 3.0, "CIBSE Guide C"),
+        PipeService::Water => (2.5, 3.5, "CIBSE Guide C"),
+    };
+    VelocityGuidance {
+        recommended_m_per_s,
+        max_m_per_s,
+        source,
+    }
+}
+
+/// API RP 14E 침식 한계 유속 Ve = 1.22·C/√ρ [m/s].
+pub fn erosional_velocity_m_per_s(density_kg_per_m3: f64, c_factor: f64) -> f64 {
+    EROSIONAL_SI_FACTOR * c_factor / density_kg_per_m3.sqrt()
+}
+
+/// 유속 판정.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VelocityVerdict {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl VelocityVerdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            VelocityVerdict::Pass => "PASS",
+            VelocityVerdict::Warn => "WARN",
+            VelocityVerdict::Fail => "FAIL",
+        }
+    }
+}
+
+/// 판정을 좌우한 기준.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitingCriterion {
+    /// 지침 유속 상한
+    Guidance,
+    /// API RP 14E 침식 한계
+    Erosional,
+}
+
+/// 유속 점검 입력값.
+#[derive(Debug, Clone)]
+pub struct VelocityCheckInput {
+    pub service: PipeService,
+    pub pressure_bar_abs: f64,
+    /// 과열 증기는 필수, 물은 없으면 20 °C
+    pub temperature_c: Option<f64>,
+    /// 습증기 건도 (없으면 0.95)
+    pub quality: Option<f64>,
+    pub diameter_m: f64,
+    pub velocity_m_per_s: f64,
+    /// 밀도 [kg/m³]. 없으면 서비스와 압력으로 IF97에서 구한다.
+    pub density_kg_per_m3: Option<f64>,
+    /// API RP 14E C-계수 (없으면 100)
+    pub c_factor: Option<f64>,
+}
+
+/// 유속 점검 결과.
+#[derive(Debug, Clone)]
+pub struct VelocityCheck {
+    pub service: PipeService,
+    pub verdict: VelocityVerdict,
+    pub limiting: LimitingCriterion,
+    pub velocity_m_per_s: f64,
+    /// 판정을 좌우한 기준의 한계 유속 [m/s] (지침 상한 또는 침식 한계)
+    pub limit_m_per_s: f64,
+    pub guidance: VelocityGuidance,
+    pub erosional_m_per_s: f64,
+    pub density_kg_per_m3: f64,
+}
+
+impl VelocityCheck {
+    /// 한계 유속 대비 비율 (1 초과면 한계 초과)
+    pub fn utilization(&self) -> f64 {
+        self.velocity_m_per_s / self.limit_m_per_s
+    }
+}
+
+/// 유속을 지침 유속과 침식 한계로 점검한다. 지침 상한과 침식 한계 중 낮은 쪽이 판정 기준이 된다.
+pub fn check_velocity(input: &VelocityCheckInput) -> Result<VelocityCheck, PipeCalcError> {
+    if input.pressure_bar_abs <= 0.0 || input.diameter_m <= 0.0 || input.velocity_m_per_s < 0.0 {
+        return Err(PipeCalcError::InvalidInput(
+            "압력과 내경은 0보다 크고 유속은 0 이상이어야 합니다.",
+        ));
+    }
+    let c_factor = input.c_factor.unwrap_or(DEFAULT_C_FACTOR);
+    if c_factor <= 0.0 {
+        return Err(PipeCalcError::InvalidInput("C-계수는 0보다 커야 합니다."));
+    }
+    let density = match input.density_kg_per_m3 {
+        Some(rho) if rho > 0.0 => rho,
+        Some(_) => return Err(PipeCalcError::InvalidInput("밀도는 0보다 커야 합니다.")),
+        None => service_density(input)?,
+    };
+
+    let guidance = velocity_guidance(input.service, input.diameter_m);
+    let erosional = erosional_velocity_m_per_s(density, c_factor);
+    let v = input.velocity_m_per_s;
+    let (limiting, limit) = if erosional < guidance.max_m_per_s {
+        (LimitingCriterion::Erosional, erosional)
+    } else {
+        (LimitingCriterion::Guidance, guidance.max_m_per_s)
+    };
+    let verdict = if v > guidance.max_m_per_s || v > erosional {
+        VelocityVerdict::Fail
+    } else if v > guidance.recommended_m_per_s {
+        VelocityVerdict::Warn
+    } else {
+        VelocityVerdict::Pass
+    };
+    Ok(VelocityCheck {
+        service: input.service,
+        verdict,
+        limiting,
+        velocity_m_per_s: v,
+        limit_m_per_s: limit,
+        guidance,
+        erosional_m_per_s: erosional,
+        density_kg_per_m3: density,
+    })
+}
+
+/// 속도 기준 사이징 결과의 내경과 유속을 점검한다. `service`가 없으면 압력·온도로 고른다.
+pub fn check_sized_pipe(
+    sizing: &PipeSizingByVelocityResult,
+    service: Option<PipeService>,
+    pressure_bar_abs: f64,
+    temperature_c: f64,
+    density_kg_per_m3: f64,
+) -> Result<VelocityCheck, PipeCalcError> {
+    let service = match service {
+        Some(service) => service,
+        None => PipeService::from_state(pressure_bar_abs, temperature_c)?,
+    };
+    check_velocity(&VelocityCheckInput {
+        service,
+        pressure_bar_abs,
+        temperature_c: Some(temperature_c),
+        quality: None,
+        diameter_m: sizing.inner_diameter_m,
+        velocity_m_per_s: sizing.velocity_m_per_s,
+        density_kg_per_m3: Some(density_kg_per_m3),
+        c_factor: None,
+    })
+}
+
+fn service_density(input: &VelocityCheckInput) -> Result<f64, PipeCalcError> {
+    let p = input.pressure_bar_abs;
+    let props = match input.service {
+        PipeService::SaturatedSteam => if97::props_from_px(p, 1.0),
+        PipeService::WetSteam => if97::props_from_px(
+            p,
+            input.quality.unwrap_or(DEFAULT_WET_QUALITY).clamp(0.0, 1.0),
+        ),
+        PipeService::Condensate => if97::props_from_px(p, 0.0),
+        PipeService::SuperheatedSteam => {
+            let t_c = input.temperature_c.ok_or(PipeCalcError::InvalidInput(
+                "과열 증기는 온도가 필요합니다.",
+            ))?;
+            if97::region_props(p, t_c)
+        }
+        PipeService::Water => if97::region_props(p, input.temperature_c.unwrap_or(20.0)),
+    };
+    let (_, v, _) =
+        props.map_err(|_| PipeCalcError::InvalidInput("밀도를 IF97로 계산할 수 없습니다."))?;
+    Ok(1.0 / v)
+}
+
+impl CalcWarning for VelocityCheck {
+    fn severity(&self) -> Severity {
+        match self.verdict {
+            VelocityVerdict::Pass => Severity::Info,
+            VelocityVerdict::Warn => Severity::Caution,
+            VelocityVerdict::Fail => Severity::Critical,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match (self.verdict, self.limiting) {
+            (VelocityVerdict::Pass, _) => "warning.velocity.pass",
+            (VelocityVerdict::Warn, _) => "warning.velocity.above_recommended",
+            (VelocityVerdict::Fail, LimitingCriterion::Guidance) => "warning.velocity.above_max",
+            (VelocityVerdict::Fail, LimitingCriterion::Erosional) => "warning.velocity.erosional",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("v", format!("{:.1}", self.velocity_m_per_s)),
+            ("rec", format!("{:.1}", self.guidance.recommended_m_per_s)),
+            ("max", format!("{:.1}", self.guidance.max_m_per_s)),
+            ("ve", format!("{:.1}", self.erosional_m_per_s)),
+            ("util", format!("{:.0}", self.utilization() * 100.0)),
+            ("source", self.guidance.source.to_string()),
+        ]
+    }
+}
+
+impl std::fmt::Display for VelocityCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let v = self.velocity_m_per_s;
+        let rec = self.guidance.recommended_m_per_s;
+        let max = self.guidance.max_m_per_s;
+        let ve = self.erosional_m_per_s;
+        let source = self.guidance.source;
+        match (self.verdict, self.limiting) {
+            (VelocityVerdict::Pass, _) => write!(
+                f,
+                "유속 {v:.1} m/s 통과: 권장 {rec:.1} m/s 이하 ({source}), 침식 한계 {ve:.1} m/s, 한계 대비 {:.0}%.",
+                self.utilization() * 100.0
+            ),
+            (VelocityVerdict::Warn, _) => write!(
+                f,
+                "유속 {v:.1} m/s가 권장 {rec:.1} m/s를 넘습니다 (상한 {max:.1} m/s, {source}). 소음과 압력손실을 검토하세요."
+            ),
+            (VelocityVerdict::Fail, LimitingCriterion::Guidance) => write!(
+                f,
+                "유속 {v:.1} m/s가 지침 상한 {max:.1} m/s를 넘습니다 ({source}). 관경을 키우세요."
+            ),
+            (VelocityVerdict::Fail, LimitingCriterion::Erosional) => write!(
+                f,
+                "유속 {v:.1} m/s가 API RP 14E 침식 한계 {ve:.1} m/s를 넘습니다. 관경을 키우세요."
+            ),
+        }
+    }
+}
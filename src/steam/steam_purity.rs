@@ -0,0 +1,197 @@
+//! 증기 순도(캐리오버) 추정.
+//! 보일러수 TDS/실리카가 증기로 넘어가는 양을 기계적 캐리오버(비말 동반, 보일러수 그대로)와
+//! 증기상 용해에 의한 휘발성 캐리오버(분배비 Kd = (ρ_v/ρ_l)^n, Martynova 상관식)로 나눠 구하고,
+//! 터빈 증기 순도 기준과 ASME 보일러수 관리 기준에 대해 경고한다.
+
+use crate::steam::if97;
+
+/// 실리카(규산) 분배비 지수 n
+const SILICA_VOLATILITY_EXPONENT: f64 = 2.3;
+/// NaCl 계열 염 분배비 지수 n
+const SALT_VOLATILITY_EXPONENT: f64 = 4.0;
+/// 터빈 입구 증기 실리카 기준 [mg/kg]
+pub const TURBINE_SILICA_LIMIT_MG_PER_KG: f64 = 0.02;
+/// 터빈 입구 증기 총 고형물 기준 [mg/kg]
+pub const TURBINE_TDS_LIMIT_MG_PER_KG: f64 = 0.05;
+const HOURS_PER_YEAR: f64 = 8760.0;
+
+/// ASME 드럼 보일러수 관리 기준 (상한 압력 [bar g], TDS [mg/kg], 실리카 [mg/kg]).
+const ASME_BOILER_WATER_LIMITS: [(f64, f64, f64); 8] = [
+    (20.7, 3500.0, 150.0),
+    (31.0, 3000.0, 90.0),
+    (41.4, 2500.0, 40.0),
+    (51.7, 1000.0, 30.0),
+    (62.1, 750.0, 20.0),
+    (69.0, 625.0, 8.0),
+    (103.4, 100.0, 2.0),
+    (137.9, 50.0, 1.0),
+];
+
+/// 증기 순도 입력.
+#[derive(Debug, Clone)]
+pub struct SteamPurityInput {
+    /// 드럼 압력 [bar abs]
+    pub drum_pressure_bar_abs: f64,
+    /// 보일러수 TDS [mg/kg]
+    pub boiler_water_tds_mg_per_kg: f64,
+    /// 보일러수 실리카(SiO₂) [mg/kg]
+    pub boiler_water_silica_mg_per_kg: f64,
+    /// 기계적 캐리오버(증기 중 비말 보일러수) [%]. 기수분리기가 정상이면 0.01~0.1 %.
+    pub mechanical_carryover_percent: f64,
+    /// 증기 유량 [kg/h]. 주면 터빈으로 넘어가는 고형물 양을 계산한다.
+    pub steam_flow_kg_per_h: Option<f64>,
+}
+
+/// 증기 순도 결과.
+#[derive(Debug, Clone)]
+pub struct SteamPurityResult {
+    /// 포화 증기/물 밀도비 ρ_v/ρ_l
+    pub density_ratio: f64,
+    /// 실리카/염 휘발 분배비 (증기 농도 ÷ 보일러수 농도)
+    pub silica_distribution_ratio: f64,
+    pub salt_distribution_ratio: f64,
+    /// 증기 중 TDS [mg/kg] (기계적 + 휘발성)
+    pub steam_tds_mg_per_kg: f64,
+    /// 증기 중 실리카 [mg/kg] (기계적 + 휘발성)
+    pub steam_silica_mg_per_kg: f64,
+    /// 증기 중 실리카 중 휘발성 분량 [mg/kg]
+    pub vaporous_silica_mg_per_kg: f64,
+    /// 터빈 기준을 맞추기 위한 보일러수 최대 실리카/TDS [mg/kg]
+    pub max_boiler_water_silica_mg_per_kg: f64,
+    pub max_boiler_water_tds_mg_per_kg: f64,
+    /// 해당 압력의 ASME 보일러수 기준 (TDS, 실리카) [mg/kg]. 범위 밖이면 `None`.
+    pub asme_limits_mg_per_kg: Option<(f64, f64)>,
+    /// 연간 터빈 유입 고형물 [kg/yr] (TDS, 실리카)
+    pub annual_solids_to_turbine_kg: Option<(f64, f64)>,
+    pub warnings: Vec<String>,
+}
+
+/// 증기 순도 계산 오류.
+#[derive(Debug, Clone)]
+pub enum SteamPurityError {
+    InvalidInput(&'static str),
+    If97(String),
+}
+
+impl std::fmt::Display for SteamPurityError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SteamPurityError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            SteamPurityError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SteamPurityError {}
+
+/// 정상 상태 보일러수 농도 [mg/kg]. 고형물이 블로다운으로만 빠진다고 보면
+/// C_bw = C_fw·(1 + b)/b (b = 블로다운/증기 유량비, `boiler_efficiency`의 `blowdown_rate_frac`과 같은 정의).
+pub fn boiler_water_concentration(feedwater_mg_per_kg: f64, blowdown_rate_frac: f64) -> f64 {
+    if blowdown_rate_frac <= 0.0 {
+        return f64::INFINITY;
+    }
+    feedwater_mg_per_kg * (1.0 + blowdown_rate_frac) / blowdown_rate_frac
+}
+
+/// 드럼 압력 [bar abs]에서의 ASME 보일러수 기준 (TDS, 실리카) [mg/kg].
+pub fn asme_boiler_water_limits(drum_pressure_bar_abs: f64) -> Option<(f64, f64)> {
+    let p_gauge = drum_pressure_bar_abs - 1.013_25;
+    ASME_BOILER_WATER_LIMITS
+        .iter()
+        .find(|(p_max, _, _)| p_gauge <= *p_max)
+        .map(|&(_, tds, silica)| (tds, silica))
+}
+
+/// 드럼 보일러의 증기 중 TDS/실리카를 추정한다.
+pub fn estimate_steam_purity(
+    input: SteamPurityInput,
+) -> Result<SteamPurityResult, SteamPurityError> {
+    if input.drum_pressure_bar_abs <= 0.0 {
+        return Err(SteamPurityError::InvalidInput(
+            "드럼 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input.boiler_water_tds_mg_per_kg < 0.0 || input.boiler_water_silica_mg_per_kg < 0.0 {
+        return Err(SteamPurityError::InvalidInput(
+            "보일러수 TDS와 실리카는 0 이상이어야 합니다.",
+        ));
+    }
+    if !(0.0..100.0).contains(&input.mechanical_carryover_percent) {
+        return Err(SteamPurityError::InvalidInput(
+            "기계적 캐리오버는 0~100 % 사이여야 합니다.",
+        ));
+    }
+    if matches!(input.steam_flow_kg_per_h, Some(m) if m <= 0.0) {
+        return Err(SteamPurityError::InvalidInput(
+            "증기 유량은 0보다 커야 합니다.",
+        ));
+    }
+    let p = input.drum_pressure_bar_abs;
+    let if97_err = |e: &str| SteamPurityError::If97(e.to_string());
+    let (_, v_l, _) = if97::props_from_px(p, 0.0).map_err(if97_err)?;
+    let (_, v_v, _) = if97::props_from_px(p, 1.0).map_err(if97_err)?;
+    let density_ratio = v_l / v_v;
+
+    let mech = input.mechanical_carryover_percent / 100.0;
+    let kd_silica = density_ratio.powf(SILICA_VOLATILITY_EXPONENT);
+    let kd_salt = density_ratio.powf(SALT_VOLATILITY_EXPONENT);
+    let vaporous_silica = kd_silica * input.boiler_water_silica_mg_per_kg;
+    let steam_silica = mech * input.boiler_water_silica_mg_per_kg + vaporous_silica;
+    let steam_tds = (mech + kd_salt) * input.boiler_water_tds_mg_per_kg;
+    let max_bw_silica = TURBINE_SILICA_LIMIT_MG_PER_KG / (mech + kd_silica);
+    let max_bw_tds = TURBINE_TDS_LIMIT_MG_PER_KG / (mech + kd_salt);
+    let asme = asme_boiler_water_limits(p);
+
+    let mut warnings = Vec::new();
+    if steam_silica > TURBINE_SILICA_LIMIT_MG_PER_KG {
+        warnings.push(format!(
+            "증기 실리카 {steam_silica:.3} mg/kg가 터빈 기준 {TURBINE_SILICA_LIMIT_MG_PER_KG} mg/kg를 넘어 날개 퇴적이 우려됩니다. 보일러수 실리카를 {max_bw_silica:.2} mg/kg 이하로 낮추세요."
+        ));
+    }
+    if steam_tds > TURBINE_TDS_LIMIT_MG_PER_KG {
+        warnings.push(format!(
+            "증기 TDS {steam_tds:.3} mg/kg가 터빈 기준 {TURBINE_TDS_LIMIT_MG_PER_KG} mg/kg를 넘습니다. 블로다운 증가 또는 기수분리기 점검이 필요합니다."
+        ));
+    }
+    if vaporous_silica > mech * input.boiler_water_silica_mg_per_kg && vaporous_silica > 0.0 {
+        warnings.push(
+            "실리카는 휘발성 캐리오버가 지배적이므로 기수분리기 개선으로는 줄지 않습니다.".into(),
+        );
+    }
+    if let Some((tds_limit, silica_limit)) = asme {
+        if input.boiler_water_tds_mg_per_kg > tds_limit {
+            warnings.push(format!(
+                "보일러수 TDS가 ASME 기준 {tds_limit:.0} mg/kg를 넘습니다."
+            ));
+        }
+        if input.boiler_water_silica_mg_per_kg > silica_limit {
+            warnings.push(format!(
+                "보일러수 실리카가 ASME 기준 {silica_limit} mg/kg를 넘습니다."
+            ));
+        }
+    } else {
+        warnings.push("ASME 드럼 보일러수 기준 범위(138 bar g) 밖의 압력입니다.".into());
+    }
+
+    let annual = input.steam_flow_kg_per_h.map(|m| {
+        let kg_steam_per_year = m * HOURS_PER_YEAR;
+        (
+            steam_tds * kg_steam_per_year / 1e6,
+            steam_silica * kg_steam_per_year / 1e6,
+        )
+    });
+
+    Ok(SteamPurityResult {
+        density_ratio,
+        silica_distribution_ratio: kd_silica,
+        salt_distribution_ratio: kd_salt,
+        steam_tds_mg_per_kg: steam_tds,
+        steam_silica_mg_per_kg: steam_silica,
+        vaporous_silica_mg_per_kg: vaporous_silica,
+        max_boiler_water_silica_mg_per_kg: max_bw_silica,
+        max_boiler_water_tds_mg_per_kg: max_bw_tds,
+        asme_limits_mg_per_kg: asme,
+        annual_solids_to_turbine_kg: annual,
+        warnings,
+    })
+}
@@ -1,5 +1,6 @@
 use super::if97;
 use crate::conversion::PressureMode;
+use crate::typed_quantity::{Pressure, Temperature};
 use crate::units::{convert_temperature, PressureUnit, TemperatureUnit};
 
 /// 단순 선형 보간 기반 포화/과열 증기 특성을 제공한다.
@@ -301,6 +302,86 @@ pub fn superheated_at_mode(
     Ok(state)
 }
 
+/// 강타입 압력으로 포화 증기 특성을 계산한다.
+pub fn saturation_at(pressure: Pressure) -> Result<SteamState, SteamTableError> {
+    saturation_by_pressure_mode(
+        pressure.bar_abs(),
+        PressureUnit::Bar,
+        PressureMode::Absolute,
+    )
+}
+
+/// 강타입 온도로 포화 증기 특성을 계산한다.
+pub fn saturation_at_temperature(temperature: Temperature) -> Result<SteamState, SteamTableError> {
+    saturation_by_temperature(temperature.celsius(), TemperatureUnit::Celsius)
+}
+
+/// 강타입 압력/온도로 과열 증기 특성을 계산한다.
+pub fn superheated_at_state(
+    pressure: Pressure,
+    temperature: Temperature,
+) -> Result<SteamState, SteamTableError> {
+    superheated_at_mode(
+        pressure.bar_abs(),
+        PressureUnit::Bar,
+        PressureMode::Absolute,
+        temperature.celsius(),
+        TemperatureUnit::Celsius,
+    )
+}
+
+/// 진공 포화표 기본 행 (mmHg 게이지, 0=대기, -760=진공).
+/// 대기압 근처는 100 단위, 깊은 진공으로 갈수록 20→10→5 단위로 촘촘하다.
+pub const VACUUM_TABLE_MMHG_GAUGE: [f64; 36] = [
+    0.0, -100.0, -200.0, -300.0, -400.0, // 100단계
+    -420.0, -440.0, -460.0, -480.0, -500.0, -520.0, -540.0, -560.0, -580.0,
+    -600.0, // 20단계
+    -610.0, -620.0, -630.0, -640.0, -650.0, -660.0, -670.0, -680.0, // 10단계
+    -685.0, -690.0, -695.0, -700.0, -705.0, -710.0, -715.0, -720.0, -725.0, -730.0, -735.0,
+    -740.0, // 5단계
+    -760.0, // -740~-760은 20 단위(끝값만 표시)
+];
+
+/// 1 mmHg당 bar (760 mmHg = 1 atm)
+const BAR_PER_MMHG: f64 = 1.01325 / 760.0;
+/// 물 삼중점 압력 [bar abs]. 이보다 낮으면 포화 액체가 없다.
+const TRIPLE_POINT_BAR_ABS: f64 = 0.006_116_57;
+
+/// 진공 포화표 한 행.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VacuumTableRow {
+    /// 게이지 압력 [mmHg(g)]
+    pub mmhg_gauge: f64,
+    /// 절대압력 [bar abs]
+    pub pressure_bar_abs: f64,
+    /// 포화온도 [°C]. 절대압력이 삼중점보다 낮거나 IF97 범위 밖이면 None
+    pub saturation_temperature_c: Option<f64>,
+}
+
+/// mmHg 게이지 행들을 대기압 기준 절대압력과 IF97 포화온도로 표를 만든다.
+/// 포화온도는 포화선 스플라인으로 구하므로 수백 행도 가볍다.
+pub fn vacuum_saturation_table(
+    rows_mmhg_gauge: &[f64],
+    atmosphere_bar_abs: f64,
+) -> Vec<VacuumTableRow> {
+    rows_mmhg_gauge
+        .iter()
+        .map(|&mmhg_gauge| {
+            let pressure_bar_abs = atmosphere_bar_abs + mmhg_gauge * BAR_PER_MMHG;
+            let saturation_temperature_c = if pressure_bar_abs >= TRIPLE_POINT_BAR_ABS {
+                if97::saturation_temp_c_fast(pressure_bar_abs).ok()
+            } else {
+                None
+            };
+            VacuumTableRow {
+                mmhg_gauge,
+                pressure_bar_abs,
+                saturation_temperature_c,
+            }
+        })
+        .collect()
+}
+
 fn bracket_by_pressure(p_bar: f64) -> Result<(SteamTableRow, SteamTableRow), SteamTableError> {
     if p_bar < SAT_TABLE.first().unwrap().pressure_bar
         || p_bar > SAT_TABLE.last().unwrap().pressure_bar
@@ -1,3 +1,8 @@
+use crate::flow_coefficient::FlowCoefficientKind;
+use crate::steam::critical_flow::{self, SteamStagnation};
+use crate::typed_quantity::{Density, MassFlow, PressureDifference, VolumeFlow};
+use crate::warning::{CalcWarning, Severity};
+
 /// Cv/Kv 계산 및 밸브 유량 추정을 위한 모듈.
 #[derive(Debug)]
 pub enum ValveCalcError {
@@ -18,14 +23,14 @@ impl std::fmt::Display for ValveCalcError {
 
 impl std::error::Error for ValveCalcError {}
 
-/// Cv→Kv 변환 (Cv는 미국식, Kv는 SI 기반)
+/// Cv→Kv 변환 (Cv는 미국식, Kv는 SI 기반). 다른 계수는 `flow_coefficient` 참고.
 pub fn kv_from_cv(cv: f64) -> f64 {
-    cv * 0.865
+    FlowCoefficientKind::CvUs.to_kv(cv)
 }
 
 /// Kv→Cv 변환
 pub fn cv_from_kv(kv: f64) -> f64 {
-    kv / 0.865
+    FlowCoefficientKind::CvUs.from_kv(kv)
 }
 
 /// 요구 Kv 값을 계산한다. 비압축성 근사식: Kv = Q * sqrt(ρ_ref / (ρ * ΔP))
@@ -56,38 +61,131 @@ pub fn required_cv(
 }
 
 /// 주어진 Kv로 통과 가능한 유량을 계산한다. 비압축성 근사.
+/// 임계 유동은 판정하지 않으므로 증기는 `steam_flow_from_kv`를 쓴다.
 pub fn flow_from_kv(
     kv: f64,
     delta_p_bar: f64,
     fluid_density_kg_m3: f64,
-    upstream_bar_abs: Option<f64>,
 ) -> Result<f64, ValveCalcError> {
     if kv <= 0.0 || delta_p_bar <= 0.0 || fluid_density_kg_m3 <= 0.0 {
         return Err(ValveCalcError::InvalidInput(
             "Kv, 차압, 밀도는 0보다 커야 합니다.",
         ));
     }
-    // 간략한 임계 유동 판정: 증기 가정 시 임계비 약 0.55 (gamma ~1.3)
-    if let Some(p_up_abs) = upstream_bar_abs {
-        let p_down_abs = (p_up_abs - delta_p_bar).max(0.0);
-        if p_up_abs > 0.0 && p_down_abs / p_up_abs < 0.55 {
-            return Err(ValveCalcError::ChokedFlow(
-                "임계(음속) 영역 가능성이 높아 단순 Kv 식이 부정확할 수 있습니다.",
-            ));
-        }
-    }
     let rho_ref = 1000.0;
     let flow = kv * (delta_p_bar * fluid_density_kg_m3 / rho_ref).sqrt();
     Ok(flow)
 }
 
+/// 증기 밸브 통과 유량 결과.
+#[derive(Debug, Clone, Copy)]
+pub struct SteamValveFlow {
+    /// 통과 유량 [m³/h] (상류 밀도 기준)
+    pub volumetric_flow_m3_per_h: f64,
+    /// Kv 식에 쓴 차압 [bar]. 임계 유동이면 임계 차압.
+    pub effective_delta_p_bar: f64,
+    /// 입력 차압 기준 하류/상류 압력비
+    pub pressure_ratio: f64,
+    /// 상류 상태의 IF97 임계 압력비
+    pub critical_pressure_ratio: f64,
+    pub choked: bool,
+}
+
+impl SteamValveFlow {
+    /// 임계 유동이면 경고를 돌려준다.
+    pub fn warning(&self) -> Option<ValveWarning> {
+        self.choked.then_some(ValveWarning::Choked {
+            pressure_ratio: self.pressure_ratio,
+            critical_ratio: self.critical_pressure_ratio,
+            effective_delta_p_bar: self.effective_delta_p_bar,
+        })
+    }
+}
+
+/// 밸브 계산 경고.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ValveWarning {
+    /// 하류/상류 압력비가 임계 압력비보다 낮아 유량이 임계 차압에서 제한됨
+    Choked {
+        pressure_ratio: f64,
+        critical_ratio: f64,
+        effective_delta_p_bar: f64,
+    },
+}
+
+impl CalcWarning for ValveWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ValveWarning::Choked { .. } => "warning.valve.choked",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            ValveWarning::Choked {
+                pressure_ratio,
+                critical_ratio,
+                effective_delta_p_bar,
+            } => vec![
+                ("ratio", format!("{pressure_ratio:.3}")),
+                ("critical", format!("{critical_ratio:.3}")),
+                ("dp", format!("{effective_delta_p_bar:.3}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for ValveWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ValveWarning::Choked {
+                pressure_ratio,
+                critical_ratio,
+                effective_delta_p_bar,
+            } => write!(
+                f,
+                "밸브 출구/입구 압력비 {pressure_ratio:.3}가 임계 압력비 {critical_ratio:.3} 미만으로 임계 유동입니다. 유량은 임계 차압 {effective_delta_p_bar:.3} bar로 계산했습니다."
+            ),
+        }
+    }
+}
+
+/// 증기 밸브 통과 유량. 차압을 상류 상태의 IF97 임계 압력비로 제한한 뒤 Kv 식을 쓴다.
+pub fn steam_flow_from_kv(
+    kv: f64,
+    delta_p_bar: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: f64,
+    state: SteamStagnation,
+) -> Result<SteamValveFlow, ValveCalcError> {
+    if upstream_bar_abs <= 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "상류 압력은 0보다 커야 합니다.",
+        ));
+    }
+    let crit_ratio = critical_flow::critical_pressure_ratio_or_fallback(upstream_bar_abs, state);
+    let (dp_eff, choked) =
+        critical_flow::choked_limited_pressure_drop(upstream_bar_abs, delta_p_bar, crit_ratio);
+    Ok(SteamValveFlow {
+        volumetric_flow_m3_per_h: flow_from_kv(kv, dp_eff, fluid_density_kg_m3)?,
+        effective_delta_p_bar: dp_eff,
+        pressure_ratio: (upstream_bar_abs - delta_p_bar).max(0.0) / upstream_bar_abs,
+        critical_pressure_ratio: crit_ratio,
+        choked,
+    })
+}
+
 /// Cv 값을 기반으로 SI 기준 유량(m³/h)을 계산한다.
 pub fn flow_from_cv(
     cv: f64,
     delta_p_bar: f64,
     fluid_density_kg_m3: f64,
 ) -> Result<f64, ValveCalcError> {
-    flow_from_kv(kv_from_cv(cv), delta_p_bar, fluid_density_kg_m3, None)
+    flow_from_kv(kv_from_cv(cv), delta_p_bar, fluid_density_kg_m3)
 }
 
 /// Kv와 밀도를 사용해 질량유량(kg/h)을 반환한다.
@@ -96,6 +194,114 @@ pub fn mass_flow_from_kv(
     delta_p_bar: f64,
     fluid_density_kg_m3: f64,
 ) -> Result<f64, ValveCalcError> {
-    let q_m3_h = flow_from_kv(kv, delta_p_bar, fluid_density_kg_m3, None)?;
+    let q_m3_h = flow_from_kv(kv, delta_p_bar, fluid_density_kg_m3)?;
     Ok(q_m3_h * fluid_density_kg_m3)
 }
+
+/// 목표 하류 압력(bar abs)을 만들면서 주어진 유량(m³/h)을 통과시키는 Kv를 구한다.
+/// Kv 식이 Kv에 선형이므로 차압 = 상류 - 목표 하류로 `required_kv`를 그대로 쓴다. 임계 유동은 판정하지 않는다.
+pub fn kv_for_downstream_pressure(
+    volumetric_flow_m3_per_h: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: f64,
+    target_downstream_bar_abs: f64,
+) -> Result<f64, ValveCalcError> {
+    if volumetric_flow_m3_per_h <= 0.0 || fluid_density_kg_m3 <= 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "유량과 밀도는 0보다 커야 합니다.",
+        ));
+    }
+    if target_downstream_bar_abs < 0.0 || target_downstream_bar_abs >= upstream_bar_abs {
+        return Err(ValveCalcError::InvalidInput(
+            "목표 하류 압력은 0 이상, 상류 압력 미만이어야 합니다.",
+        ));
+    }
+    required_kv(
+        volumetric_flow_m3_per_h,
+        upstream_bar_abs - target_downstream_bar_abs,
+        fluid_density_kg_m3,
+    )
+}
+
+/// 증기 밸브의 목표 하류 압력 Kv. 목표가 IF97 임계 압력보다 낮으면 Kv로 하류 압력을 만들 수 없어
+/// `ChokedFlow` 오류를 돌려준다.
+pub fn steam_kv_for_downstream_pressure(
+    volumetric_flow_m3_per_h: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: f64,
+    target_downstream_bar_abs: f64,
+    state: SteamStagnation,
+) -> Result<f64, ValveCalcError> {
+    let kv = kv_for_downstream_pressure(
+        volumetric_flow_m3_per_h,
+        fluid_density_kg_m3,
+        upstream_bar_abs,
+        target_downstream_bar_abs,
+    )?;
+    let crit_ratio = critical_flow::critical_pressure_ratio_or_fallback(upstream_bar_abs, state);
+    let (_, choked) = critical_flow::choked_limited_pressure_drop(
+        upstream_bar_abs,
+        upstream_bar_abs - target_downstream_bar_abs,
+        crit_ratio,
+    );
+    if choked {
+        return Err(ValveCalcError::ChokedFlow(
+            "목표 하류 압력이 임계 압력보다 낮아 밸브 출구가 임계(음속) 유동이 됩니다.",
+        ));
+    }
+    Ok(kv)
+}
+
+/// 목표 하류 압력에 대한 Cv 역산. 단위는 `kv_for_downstream_pressure`와 같다.
+pub fn cv_for_downstream_pressure(
+    volumetric_flow_m3_per_h: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: f64,
+    target_downstream_bar_abs: f64,
+) -> Result<f64, ValveCalcError> {
+    let kv = kv_for_downstream_pressure(
+        volumetric_flow_m3_per_h,
+        fluid_density_kg_m3,
+        upstream_bar_abs,
+        target_downstream_bar_abs,
+    )?;
+    Ok(cv_from_kv(kv))
+}
+
+/// 강타입 입력으로 요구 Kv를 계산한다.
+pub fn required_kv_typed(
+    flow: VolumeFlow,
+    delta_p: PressureDifference,
+    density: Density,
+) -> Result<f64, ValveCalcError> {
+    required_kv(flow.m3_per_h(), delta_p.bar(), density.kg_per_m3())
+}
+
+/// 강타입 입력으로 요구 Cv를 계산한다.
+pub fn required_cv_typed(
+    flow: VolumeFlow,
+    delta_p: PressureDifference,
+    density: Density,
+) -> Result<f64, ValveCalcError> {
+    required_cv(flow.m3_per_h(), delta_p.bar(), density.kg_per_m3())
+}
+
+/// 강타입 입력으로 Kv 통과 유량을 계산한다.
+pub fn flow_from_kv_typed(
+    kv: f64,
+    delta_p: PressureDifference,
+    density: Density,
+) -> Result<VolumeFlow, ValveCalcError> {
+    let q_m3_h = flow_from_kv(kv, delta_p.bar(), density.kg_per_m3())?;
+    Ok(VolumeFlow::from_m3_per_h(q_m3_h))
+}
+
+/// 강타입 입력으로 Kv 통과 질량유량을 계산한다.
+pub fn mass_flow_from_kv_typed(
+    kv: f64,
+    delta_p: PressureDifference,
+    density: Density,
+) -> Result<MassFlow, ValveCalcError> {
+    let kg_h = mass_flow_from_kv(kv, delta_p.bar(), density.kg_per_m3())?;
+    Ok(MassFlow::from_kg_per_h(kg_h))
+}
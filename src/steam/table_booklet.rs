@@ -0,0 +1,596 @@
+//! 인쇄용 증기표 책자.
+//! 온도 기준·압력 기준 포화표와 과열 증기표를 사용자 단위계로 만들어, 페이지마다 나눈
+//! 인쇄용 HTML 문서로 낸다. 브라우저의 "PDF로 인쇄"로 현장 비치용 PDF를 만들 수 있다.
+//!
+//! 행 값(온도, 압력)은 표시 단위의 반올림한 값으로 잡고 계산할 때만 기준 단위로 바꾼다.
+//! 과열표 점은 `if97::region_props_many`로 한꺼번에 계산한다.
+
+use crate::config::UnitSystem;
+use crate::number_format::NumberFormat;
+use crate::steam::if97;
+
+/// 삼중점 온도 [°C]
+const TRIPLE_POINT_TEMP_C: f64 = 0.01;
+/// 임계 온도 [°C]
+const CRITICAL_TEMP_C: f64 = 373.946;
+/// 삼중점 압력 [bar abs]
+const TRIPLE_POINT_BAR_ABS: f64 = 0.006_116_57;
+/// 임계 압력 [bar abs]
+const CRITICAL_PRESSURE_BAR_ABS: f64 = 220.64;
+/// 과열표 상한 온도 [°C] (IF97 영역 2)
+const MAX_SUPERHEAT_TEMP_C: f64 = 800.0;
+/// 포화표 한 페이지의 행 수 (A4 세로, 9 pt 기준)
+pub const ROWS_PER_PAGE: usize = 45;
+/// 과열표 한 페이지에 나란히 놓는 압력 수
+pub const PRESSURES_PER_PAGE: usize = 3;
+
+/// 표에 쓰는 단위. 표시값 = 기준값 × `scale` + `offset`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayUnit {
+    pub label: &'static str,
+    pub scale: f64,
+    pub offset: f64,
+}
+
+impl DisplayUnit {
+    const fn linear(label: &'static str, scale: f64) -> Self {
+        Self {
+            label,
+            scale,
+            offset: 0.0,
+        }
+    }
+
+    /// 기준 단위 값을 표시 단위로 바꾼다.
+    pub fn from_base(&self, value: f64) -> f64 {
+        value * self.scale + self.offset
+    }
+
+    /// 표시 단위 값을 기준 단위로 바꾼다.
+    pub fn to_base(&self, value: f64) -> f64 {
+        (value - self.offset) / self.scale
+    }
+}
+
+/// 책자 단위 묶음. 기준 단위는 bar abs, °C, m³/kg, J/kg, J/(kg·K) (IF97 함수와 같다).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookletUnits {
+    /// 절대압력
+    pub pressure: DisplayUnit,
+    pub temperature: DisplayUnit,
+    pub specific_volume: DisplayUnit,
+    pub enthalpy: DisplayUnit,
+    pub entropy: DisplayUnit,
+}
+
+impl BookletUnits {
+    /// 단위 시스템 설정에 맞는 책자 단위.
+    pub fn for_system(system: UnitSystem) -> Self {
+        const CELSIUS: DisplayUnit = DisplayUnit::linear("°C", 1.0);
+        const M3_PER_KG: DisplayUnit = DisplayUnit::linear("m³/kg", 1.0);
+        const KJ_PER_KG: DisplayUnit = DisplayUnit::linear("kJ/kg", 1.0e-3);
+        const KJ_PER_KGK: DisplayUnit = DisplayUnit::linear("kJ/(kg·K)", 1.0e-3);
+        match system {
+            UnitSystem::SIBar => Self {
+                pressure: DisplayUnit::linear("bar(a)", 1.0),
+                temperature: CELSIUS,
+                specific_volume: M3_PER_KG,
+                enthalpy: KJ_PER_KG,
+                entropy: KJ_PER_KGK,
+            },
+            UnitSystem::SI => Self {
+                pressure: DisplayUnit::linear("kPa(a)", 100.0),
+                temperature: CELSIUS,
+                specific_volume: M3_PER_KG,
+                enthalpy: KJ_PER_KG,
+                entropy: KJ_PER_KGK,
+            },
+            UnitSystem::MKS => Self {
+                pressure: DisplayUnit::linear("kgf/cm²(a)", 1.0 / 0.980_665),
+                temperature: CELSIUS,
+                specific_volume: M3_PER_KG,
+                enthalpy: DisplayUnit::linear("kcal/kg", 1.0 / 4186.8),
+                entropy: DisplayUnit::linear("kcal/(kg·K)", 1.0 / 4186.8),
+            },
+            UnitSystem::Imperial => Self {
+                pressure: DisplayUnit::linear("psia", 1.0 / 0.068_947_57),
+                temperature: DisplayUnit {
+                    label: "°F",
+                    scale: 1.8,
+                    offset: 32.0,
+                },
+                specific_volume: DisplayUnit::linear("ft³/lb", 16.018_46),
+                enthalpy: DisplayUnit::linear("Btu/lb", 1.0 / 2326.0),
+                entropy: DisplayUnit::linear("Btu/(lb·°F)", 1.0 / 4186.8),
+            },
+        }
+    }
+}
+
+/// 책자 구성. 행 값은 모두 표시 단위다 (압력은 절대압).
+#[derive(Debug, Clone)]
+pub struct BookletOptions {
+    /// 모든 페이지 머리에 찍는 제목
+    pub title: String,
+    pub units: BookletUnits,
+    /// 온도 기준 포화표 행
+    pub saturation_temperatures: Vec<f64>,
+    /// 압력 기준 포화표 행
+    pub saturation_pressures: Vec<f64>,
+    /// 과열표 압력 (열 묶음)
+    pub superheat_pressures: Vec<f64>,
+    /// 과열표 온도 (행)
+    pub superheat_temperatures: Vec<f64>,
+}
+
+impl BookletOptions {
+    /// 단위 시스템별 표준 책자. 온도는 5 °C(영국식 10 °F) 간격,
+    /// 압력은 1-1.5-2-2.5-3-4-5-6-7-8 계열(과열표는 1-2-3-5-7 계열)로 잡는다.
+    pub fn standard(system: UnitSystem, title: &str) -> Self {
+        let units = BookletUnits::for_system(system);
+        let (saturation_temperatures, superheat_temperatures) = match system {
+            UnitSystem::Imperial => (
+                std::iter::once(32.018)
+                    .chain(stepped(40.0, 700.0, 10.0))
+                    .collect(),
+                stepped(200.0, 1450.0, 50.0),
+            ),
+            _ => (
+                std::iter::once(TRIPLE_POINT_TEMP_C)
+                    .chain(stepped(5.0, 370.0, 5.0))
+                    .collect(),
+                stepped(100.0, 800.0, 25.0),
+            ),
+        };
+        // 표시 압력 단위로 본 (포화표 최소, 과열표 최소, 최대)
+        let (sat_min, sh_min, max) = match system {
+            UnitSystem::SIBar | UnitSystem::MKS => (0.01, 0.1, 200.0),
+            UnitSystem::SI => (1.0, 10.0, 20_000.0),
+            UnitSystem::Imperial => (0.2, 1.0, 3000.0),
+        };
+        Self {
+            title: title.to_string(),
+            units,
+            saturation_temperatures,
+            saturation_pressures: preferred_series(
+                sat_min,
+                max,
+                &[1.0, 1.5, 2.0, 2.5, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+            ),
+            superheat_pressures: preferred_series(sh_min, max, &[1.0, 2.0, 3.0, 5.0, 7.0]),
+            superheat_temperatures,
+        }
+    }
+}
+
+/// 포화표 한 행 (기준 단위).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SaturationRow {
+    pub pressure_bar_abs: f64,
+    pub temperature_c: f64,
+    pub vf_m3_per_kg: f64,
+    pub vg_m3_per_kg: f64,
+    pub hf_j_per_kg: f64,
+    pub hg_j_per_kg: f64,
+    pub sf_j_per_kgk: f64,
+    pub sg_j_per_kgk: f64,
+}
+
+/// 과열 증기 물성 (기준 단위).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SuperheatPoint {
+    pub v_m3_per_kg: f64,
+    pub h_j_per_kg: f64,
+    pub s_j_per_kgk: f64,
+}
+
+/// 한 압력의 과열표 열 묶음.
+#[derive(Debug, Clone)]
+pub struct SuperheatColumn {
+    pub pressure_bar_abs: f64,
+    /// 포화 증기 (열 머리의 Sat. 행)
+    pub saturation: SaturationRow,
+    /// `BookletOptions::superheat_temperatures` 순서. 포화온도 이하는 None
+    pub points: Vec<Option<SuperheatPoint>>,
+}
+
+/// 계산된 책자.
+#[derive(Debug, Clone)]
+pub struct SteamTableBooklet {
+    pub options: BookletOptions,
+    pub saturation_by_temperature: Vec<SaturationRow>,
+    pub saturation_by_pressure: Vec<SaturationRow>,
+    pub superheat: Vec<SuperheatColumn>,
+}
+
+/// 책자 생성 오류.
+#[derive(Debug, Clone)]
+pub enum BookletError {
+    InvalidInput(&'static str),
+    /// 포화선 범위 밖 행 (표시 단위 값, 단위)
+    OutOfRange {
+        value: f64,
+        unit: &'static str,
+    },
+    If97(String),
+}
+
+impl std::fmt::Display for BookletError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BookletError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            BookletError::OutOfRange { value, unit } => {
+                write!(f, "포화표 범위(삼중점~임계점) 밖의 행: {value} {unit}")
+            }
+            BookletError::If97(msg) => write!(f, "물성 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for BookletError {}
+
+fn if97_err(e: &str) -> BookletError {
+    BookletError::If97(e.to_string())
+}
+
+/// `start`부터 `end`까지(포함) `step` 간격의 값.
+fn stepped(start: f64, end: f64, step: f64) -> Vec<f64> {
+    let n = ((end - start) / step + 1e-9).floor() as usize;
+    (0..=n).map(|i| start + i as f64 * step).collect()
+}
+
+/// `min`~`max`(포함) 범위의 10진 선호수 계열 (가수 × 10ⁿ).
+fn preferred_series(min: f64, max: f64, mantissas: &[f64]) -> Vec<f64> {
+    let first = min.log10().floor() as i32;
+    let last = max.log10().ceil() as i32;
+    (first..=last)
+        .flat_map(|exp| {
+            mantissas.iter().map(move |m| {
+                // 0.1×3 같은 부동소수 꼬리를 없앤다
+                let value = m * 10f64.powi(exp);
+                let digits = 10f64.powi(6 - exp.min(6));
+                (value * digits).round() / digits
+            })
+        })
+        .filter(|v| *v >= min * (1.0 - 1e-9) && *v <= max * (1.0 + 1e-9))
+        .collect()
+}
+
+fn saturation_row(
+    pressure_bar_abs: f64,
+    temperature_c: f64,
+) -> Result<SaturationRow, BookletError> {
+    let (hf, vf, sf) = if97::props_from_px(pressure_bar_abs, 0.0).map_err(if97_err)?;
+    let (hg, vg, sg) = if97::props_from_px(pressure_bar_abs, 1.0).map_err(if97_err)?;
+    Ok(SaturationRow {
+        pressure_bar_abs,
+        temperature_c,
+        vf_m3_per_kg: vf,
+        vg_m3_per_kg: vg,
+        hf_j_per_kg: hf,
+        hg_j_per_kg: hg,
+        sf_j_per_kgk: sf,
+        sg_j_per_kgk: sg,
+    })
+}
+
+/// 포화표와 과열표를 계산한다.
+pub fn generate(options: &BookletOptions) -> Result<SteamTableBooklet, BookletError> {
+    let units = &options.units;
+    if options.saturation_temperatures.is_empty()
+        && options.saturation_pressures.is_empty()
+        && options.superheat_pressures.is_empty()
+    {
+        return Err(BookletError::InvalidInput("표 행이 하나도 없습니다."));
+    }
+    if !options.superheat_pressures.is_empty() && options.superheat_temperatures.is_empty() {
+        return Err(BookletError::InvalidInput("과열표 온도 행이 없습니다."));
+    }
+
+    let saturation_by_temperature = options
+        .saturation_temperatures
+        .iter()
+        .map(|&t| {
+            let t_c = units.temperature.to_base(t);
+            if !(TRIPLE_POINT_TEMP_C - 1e-3..CRITICAL_TEMP_C).contains(&t_c) {
+                return Err(BookletError::OutOfRange {
+                    value: t,
+                    unit: units.temperature.label,
+                });
+            }
+            let p = if97::saturation_pressure_bar_abs_from_temp_c(t_c.max(TRIPLE_POINT_TEMP_C))
+                .map_err(if97_err)?;
+            saturation_row(p, t_c)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let saturation_at_pressure = |p: f64| {
+        let p_bar = units.pressure.to_base(p);
+        if !(TRIPLE_POINT_BAR_ABS..CRITICAL_PRESSURE_BAR_ABS).contains(&p_bar) {
+            return Err(BookletError::OutOfRange {
+                value: p,
+                unit: units.pressure.label,
+            });
+        }
+        let t = if97::saturation_temp_c_from_pressure_bar_abs(p_bar).map_err(if97_err)?;
+        saturation_row(p_bar, t)
+    };
+    let saturation_by_pressure = options
+        .saturation_pressures
+        .iter()
+        .map(|&p| saturation_at_pressure(p))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let superheat_temps_c: Vec<f64> = options
+        .superheat_temperatures
+        .iter()
+        .map(|&t| units.temperature.to_base(t))
+        .collect();
+    if superheat_temps_c
+        .iter()
+        .any(|&t| t > MAX_SUPERHEAT_TEMP_C + 1e-6)
+    {
+        return Err(BookletError::InvalidInput(
+            "과열표 온도는 800 °C 이하여야 합니다 (IF97 영역 2).",
+        ));
+    }
+    let saturations = options
+        .superheat_pressures
+        .iter()
+        .map(|&p| saturation_at_pressure(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    let points: Vec<(f64, f64)> = saturations
+        .iter()
+        .flat_map(|sat| {
+            superheat_temps_c
+                .iter()
+                .filter(move |&&t| t > sat.temperature_c)
+                .map(move |&t| (sat.pressure_bar_abs, t))
+        })
+        .collect();
+    let mut props = if97::region_props_many(&points).into_iter();
+    let mut superheat = Vec::with_capacity(saturations.len());
+    for saturation in saturations {
+        let mut column = Vec::with_capacity(superheat_temps_c.len());
+        for &t in &superheat_temps_c {
+            if t <= saturation.temperature_c {
+                column.push(None);
+                continue;
+            }
+            let (h, v, s) = props
+                .next()
+                .expect("점 목록과 같은 순서")
+                .map_err(if97_err)?;
+            column.push(Some(SuperheatPoint {
+                v_m3_per_kg: v,
+                h_j_per_kg: h,
+                s_j_per_kgk: s,
+            }));
+        }
+        superheat.push(SuperheatColumn {
+            pressure_bar_abs: saturation.pressure_bar_abs,
+            saturation,
+            points: column,
+        });
+    }
+
+    Ok(SteamTableBooklet {
+        options: options.clone(),
+        saturation_by_temperature,
+        saturation_by_pressure,
+        superheat,
+    })
+}
+
+impl SteamTableBooklet {
+    /// 페이지 제목 목록 (인쇄 순서).
+    pub fn page_titles(&self) -> Vec<String> {
+        let units = &self.options.units;
+        let pages = |rows: usize| rows.div_ceil(ROWS_PER_PAGE);
+        let mut titles = Vec::new();
+        for i in 0..pages(self.saturation_by_temperature.len()) {
+            titles.push(format!(
+                "Saturated steam: temperature table ({}/{})",
+                i + 1,
+                pages(self.saturation_by_temperature.len())
+            ));
+        }
+        for i in 0..pages(self.saturation_by_pressure.len()) {
+            titles.push(format!(
+                "Saturated steam: pressure table ({}/{})",
+                i + 1,
+                pages(self.saturation_by_pressure.len())
+            ));
+        }
+        for chunk in self.options.superheat_pressures.chunks(PRESSURES_PER_PAGE) {
+            let range = match chunk {
+                [only] => only.to_string(),
+                [first, .., last] => format!("{first}–{last}"),
+                [] => unreachable!("chunks는 빈 조각을 내지 않는다"),
+            };
+            titles.push(format!(
+                "Superheated steam: {range} {}",
+                units.pressure.label
+            ));
+        }
+        titles
+    }
+
+    /// 인쇄용 HTML 문서. 표 한 덩어리가 A4 한 페이지가 되도록 페이지를 나눈다.
+    pub fn to_html(&self, nf: &NumberFormat) -> String {
+        let units = &self.options.units;
+        let titles = self.page_titles();
+        let total = titles.len();
+        let mut titles = titles.into_iter().enumerate();
+        let mut html = String::new();
+        html.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        html.push_str(&format!("<title>{}</title>\n", escape(&self.options.title)));
+        html.push_str(STYLE);
+        html.push_str("</head>\n<body>\n");
+
+        let mut page = |html: &mut String, body: String| {
+            let (index, title) = titles.next().expect("페이지 제목 수와 같다");
+            html.push_str(&format!(
+                "<section class=\"page\">\n<header><span>{}</span><span>IAPWS-IF97</span></header>\n<h2>{}</h2>\n{body}<footer>{} / {total}</footer>\n</section>\n",
+                escape(&self.options.title),
+                escape(&title),
+                index + 1,
+            ));
+        };
+
+        for (by_temperature, rows) in [
+            (true, &self.saturation_by_temperature),
+            (false, &self.saturation_by_pressure),
+        ] {
+            for chunk in rows.chunks(ROWS_PER_PAGE) {
+                let mut body = String::from("<table>\n<thead>\n<tr>");
+                let (first, second) = if by_temperature {
+                    (("T", units.temperature), ("p", units.pressure))
+                } else {
+                    (("p", units.pressure), ("T", units.temperature))
+                };
+                for (symbol, unit) in [
+                    first,
+                    second,
+                    ("v<sub>f</sub>", units.specific_volume),
+                    ("v<sub>g</sub>", units.specific_volume),
+                    ("h<sub>f</sub>", units.enthalpy),
+                    ("h<sub>fg</sub>", units.enthalpy),
+                    ("h<sub>g</sub>", units.enthalpy),
+                    ("s<sub>f</sub>", units.entropy),
+                    ("s<sub>g</sub>", units.entropy),
+                ] {
+                    body.push_str(&format!("<th>{symbol}<br>{}</th>", unit.label));
+                }
+                body.push_str("</tr>\n</thead>\n<tbody>\n");
+                for row in chunk {
+                    let t = cell(nf, units.temperature.from_base(row.temperature_c), 2);
+                    let p = significant(nf, units.pressure.from_base(row.pressure_bar_abs), 5);
+                    let (first, second) = if by_temperature { (t, p) } else { (p, t) };
+                    body.push_str(&format!("<tr><th>{first}</th><td>{second}</td>"));
+                    for value in [
+                        significant(nf, units.specific_volume.from_base(row.vf_m3_per_kg), 5),
+                        significant(nf, units.specific_volume.from_base(row.vg_m3_per_kg), 5),
+                        cell(nf, units.enthalpy.from_base(row.hf_j_per_kg), 1),
+                        cell(
+                            nf,
+                            units.enthalpy.from_base(row.hg_j_per_kg)
+                                - units.enthalpy.from_base(row.hf_j_per_kg),
+                            1,
+                        ),
+                        cell(nf, units.enthalpy.from_base(row.hg_j_per_kg), 1),
+                        cell(nf, units.entropy.from_base(row.sf_j_per_kgk), 4),
+                        cell(nf, units.entropy.from_base(row.sg_j_per_kgk), 4),
+                    ] {
+                        body.push_str(&format!("<td>{value}</td>"));
+                    }
+                    body.push_str("</tr>\n");
+                }
+                body.push_str("</tbody>\n</table>\n");
+                page(&mut html, body);
+            }
+        }
+
+        for chunk in self.superheat.chunks(PRESSURES_PER_PAGE) {
+            let mut body =
+                String::from("<table class=\"superheat\">\n<thead>\n<tr><th rowspan=\"2\">T<br>");
+            body.push_str(units.temperature.label);
+            body.push_str("</th>");
+            for column in chunk {
+                body.push_str(&format!(
+                    "<th colspan=\"3\" class=\"group\">p = {} {} (T<sub>sat</sub> {} {})</th>",
+                    significant(nf, units.pressure.from_base(column.pressure_bar_abs), 5),
+                    units.pressure.label,
+                    cell(
+                        nf,
+                        units.temperature.from_base(column.saturation.temperature_c),
+                        2
+                    ),
+                    units.temperature.label,
+                ));
+            }
+            body.push_str("</tr>\n<tr>");
+            for _ in chunk {
+                body.push_str(&format!(
+                    "<th class=\"group\">v<br>{}</th><th>h<br>{}</th><th>s<br>{}</th>",
+                    units.specific_volume.label, units.enthalpy.label, units.entropy.label
+                ));
+            }
+            body.push_str("</tr>\n</thead>\n<tbody>\n<tr class=\"sat\"><th>Sat.</th>");
+            for column in chunk {
+                let sat = &column.saturation;
+                body.push_str(&format!(
+                    "<td class=\"group\">{}</td><td>{}</td><td>{}</td>",
+                    significant(nf, units.specific_volume.from_base(sat.vg_m3_per_kg), 5),
+                    cell(nf, units.enthalpy.from_base(sat.hg_j_per_kg), 1),
+                    cell(nf, units.entropy.from_base(sat.sg_j_per_kgk), 4),
+                ));
+            }
+            body.push_str("</tr>\n");
+            for (i, &t) in self.options.superheat_temperatures.iter().enumerate() {
+                body.push_str(&format!("<tr><th>{}</th>", cell(nf, t, 0)));
+                for column in chunk {
+                    match column.points[i] {
+                        Some(point) => body.push_str(&format!(
+                            "<td class=\"group\">{}</td><td>{}</td><td>{}</td>",
+                            significant(nf, units.specific_volume.from_base(point.v_m3_per_kg), 5),
+                            cell(nf, units.enthalpy.from_base(point.h_j_per_kg), 1),
+                            cell(nf, units.entropy.from_base(point.s_j_per_kgk), 4),
+                        )),
+                        None => body.push_str("<td class=\"group\">—</td><td>—</td><td>—</td>"),
+                    }
+                }
+                body.push_str("</tr>\n");
+            }
+            body.push_str("</tbody>\n</table>\n");
+            page(&mut html, body);
+        }
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+}
+
+/// A4 세로, 페이지마다 표 하나. 화면에서는 페이지 사이를 띄워 보여 준다.
+const STYLE: &str = "<style>
+@page { size: A4 portrait; margin: 12mm 10mm; }
+body { font-family: \"DejaVu Sans\", Arial, sans-serif; font-size: 9pt; color: #000; }
+section.page { break-after: page; page-break-after: always; }
+section.page:last-child { break-after: auto; page-break-after: auto; }
+header { display: flex; justify-content: space-between; border-bottom: 1px solid #000; font-size: 8pt; }
+h2 { font-size: 11pt; margin: 4mm 0 2mm; }
+table { border-collapse: collapse; width: 100%; }
+th, td { border: 1px solid #999; padding: 1px 3px; text-align: right; white-space: nowrap; }
+thead th { background: #eee; text-align: center; }
+tbody tr:nth-child(5n) td, tbody tr:nth-child(5n) th { border-bottom: 1px solid #000; }
+.group { border-left: 2px solid #000; }
+tr.sat { font-style: italic; }
+footer { text-align: center; font-size: 8pt; margin-top: 2mm; }
+@media screen { section.page { max-width: 190mm; margin: 0 auto 12mm; } }
+</style>
+";
+
+fn cell(nf: &NumberFormat, value: f64, decimals: usize) -> String {
+    // 삼중점의 hf, sf 같은 0 근처 잔차를 지수 표기나 -0.0으로 찍지 않는다
+    if value.abs() < 0.5 * 10f64.powi(-(decimals as i32)) {
+        return nf.format(0.0, decimals);
+    }
+    nf.format(value, decimals)
+}
+
+/// 유효 숫자 `digits`자리로 표시한다 (비체적처럼 자릿수 폭이 큰 값).
+fn significant(nf: &NumberFormat, value: f64, digits: usize) -> String {
+    if value == 0.0 || !value.is_finite() {
+        return nf.format(value, 0);
+    }
+    let magnitude = value.abs().log10().floor() as i32;
+    let decimals = (digits as i32 - 1 - magnitude).clamp(0, 8) as usize;
+    nf.format(value, decimals)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,308 @@
+//! 탱크/용기 가열 시간, 증기 소비량, 스팀 코일 면적 및 벽면 열손실 계산.
+//! 코일 가열은 일정 U로 보고 M·cp·dT/dt = UA·(Ts − T) − UA_loss·(T − Ta)의 해석해를 쓴다.
+//! 직접 주입은 주입 증기가 액체와 섞이며 액체 질량이 늘어나는 것을 온도 기준 RK4로 적분한다.
+//! 액체 엔탈피는 cp·T(0 °C 기준)로 보므로 물과 비슷한 액체에 적합하다.
+
+use crate::goal_seek::{self, GoalSeekError};
+use crate::steam::if97;
+
+/// 직접 주입 적분 구간 수
+const INJECTION_STEPS: usize = 200;
+
+/// 가열 방식.
+#[derive(Debug, Clone, Copy)]
+pub enum HeatingMethod {
+    /// 스팀 코일(간접). `area_m2`가 있으면 가열 시간을, 없으면 목표 시간에 필요한 면적을 구한다.
+    Coil { u_w_m2k: f64, area_m2: Option<f64> },
+    /// 직접 증기 주입. `steam_flow_kg_per_h`가 있으면 가열 시간을, 없으면 필요한 주입량을 구한다.
+    DirectInjection { steam_flow_kg_per_h: Option<f64> },
+}
+
+/// 탱크 가열 입력.
+#[derive(Debug, Clone)]
+pub struct TankHeatingInput {
+    /// 초기 액체 질량 [kg]
+    pub liquid_mass_kg: f64,
+    /// 액체 비열 [kJ/kgK]
+    pub specific_heat_kj_per_kgk: f64,
+    pub initial_temp_c: f64,
+    pub target_temp_c: f64,
+    /// 목표 가열 시간 [h]. 코일 면적/주입량을 주지 않을 때 필요하다.
+    pub heat_up_time_h: Option<f64>,
+    /// 공급 증기 압력 [bar abs] (포화 증기)
+    pub steam_pressure_bar_abs: f64,
+    pub method: HeatingMethod,
+    /// 열손실 벽면적 [m²] (측벽 + 지붕 + 액면)
+    pub wall_area_m2: f64,
+    /// 벽면 열손실 계수 [W/m²K] (보온 0.5~1, 나관 8~12)
+    pub wall_loss_u_w_m2k: f64,
+    pub ambient_temp_c: f64,
+}
+
+/// 탱크 가열 결과.
+#[derive(Debug, Clone)]
+pub struct TankHeatingResult {
+    pub steam_saturation_temp_c: f64,
+    /// 가열 시간 [h]
+    pub heat_up_time_h: f64,
+    /// 액체 승온에 필요한 열량 [kWh] (손실 제외)
+    pub heat_up_energy_kwh: f64,
+    /// 가열 중 평균 벽면 열손실 [kW]
+    pub mean_heat_loss_kw: f64,
+    /// 목표 온도 유지 시 열손실 [kW]
+    pub holding_loss_kw: f64,
+    /// 가열 중 총 증기 소비량 [kg]
+    pub total_steam_kg: f64,
+    pub mean_steam_kg_per_h: f64,
+    /// 가열 시작 시점 최대 증기량 [kg/h]
+    pub peak_steam_kg_per_h: f64,
+    /// 목표 온도 유지 증기량 [kg/h]
+    pub holding_steam_kg_per_h: f64,
+    /// 코일 UA [kW/K] (코일 가열만)
+    pub coil_ua_kw_per_k: Option<f64>,
+    /// 코일 면적 [m²] (코일 가열만)
+    pub coil_area_m2: Option<f64>,
+    /// 가열 전체 기간의 유효 LMTD [K] (코일 가열만)
+    pub effective_lmtd_k: Option<f64>,
+    /// 가열 후 액체 질량 [kg] (직접 주입은 응축수만큼 늘어난다)
+    pub final_liquid_mass_kg: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 탱크 가열 계산 오류.
+#[derive(Debug, Clone)]
+pub enum TankHeatingError {
+    InvalidInput(&'static str),
+    If97(String),
+    /// 손실 때문에 목표 온도에 도달할 수 없음
+    Unreachable,
+    GoalSeek(GoalSeekError),
+}
+
+impl std::fmt::Display for TankHeatingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TankHeatingError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            TankHeatingError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            TankHeatingError::Unreachable => write!(
+                f,
+                "열손실 때문에 목표 온도에 도달할 수 없습니다. 가열 용량을 늘리거나 보온을 보강하세요."
+            ),
+            TankHeatingError::GoalSeek(e) => write!(f, "역산 실패: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TankHeatingError {}
+
+/// 탱크 가열 시간/증기량/코일 면적과 열손실을 계산한다.
+pub fn compute_tank_heating(
+    input: TankHeatingInput,
+) -> Result<TankHeatingResult, TankHeatingError> {
+    if input.liquid_mass_kg <= 0.0 || input.specific_heat_kj_per_kgk <= 0.0 {
+        return Err(TankHeatingError::InvalidInput(
+            "액체 질량과 비열은 0보다 커야 합니다.",
+        ));
+    }
+    if input.target_temp_c <= input.initial_temp_c {
+        return Err(TankHeatingError::InvalidInput(
+            "목표 온도는 초기 온도보다 높아야 합니다.",
+        ));
+    }
+    if input.wall_area_m2 < 0.0 || input.wall_loss_u_w_m2k < 0.0 {
+        return Err(TankHeatingError::InvalidInput(
+            "벽면적과 열손실 계수는 0 이상이어야 합니다.",
+        ));
+    }
+    if matches!(input.heat_up_time_h, Some(t) if t <= 0.0) {
+        return Err(TankHeatingError::InvalidInput(
+            "목표 가열 시간은 0보다 커야 합니다.",
+        ));
+    }
+    let if97_err = |e: &str| TankHeatingError::If97(e.into());
+    let p = input.steam_pressure_bar_abs;
+    let t_s = if97::saturation_temp_c_from_pressure_bar_abs(p).map_err(if97_err)?;
+    let (hf, hg) = if97::saturation_enthalpies(p).map_err(if97_err)?;
+    let (hf, hg) = (hf / 1000.0, hg / 1000.0);
+    if input.target_temp_c >= t_s {
+        return Err(TankHeatingError::InvalidInput(
+            "목표 온도는 공급 증기 포화온도보다 낮아야 합니다.",
+        ));
+    }
+
+    let m0 = input.liquid_mass_kg;
+    let cp = input.specific_heat_kj_per_kgk;
+    let (t0, t1, ta) = (
+        input.initial_temp_c,
+        input.target_temp_c,
+        input.ambient_temp_c,
+    );
+    // kW/K
+    let ua_loss = input.wall_area_m2 * input.wall_loss_u_w_m2k / 1000.0;
+    let heat_up_kj = m0 * cp * (t1 - t0);
+    let holding_loss_kw = ua_loss * (t1 - ta);
+    let mut warnings = Vec::new();
+    if t0 < ta {
+        warnings.push("초기 온도가 주위 온도보다 낮아 초기에는 주위에서 열을 얻습니다.".into());
+    }
+
+    match input.method {
+        HeatingMethod::Coil { u_w_m2k, area_m2 } => {
+            if u_w_m2k <= 0.0 || matches!(area_m2, Some(a) if a <= 0.0) {
+                return Err(TankHeatingError::InvalidInput(
+                    "코일 U와 면적은 0보다 커야 합니다.",
+                ));
+            }
+            let latent = hg - hf;
+            // 코일 UA가 이 값 이하면 목표 온도에서 손실을 이기지 못한다.
+            let ua_min = ua_loss * (t1 - ta) / (t_s - t1);
+            let time_s = |ua: f64| coil_time_s(ua, ua_loss, m0 * cp, t_s, ta, t0, t1);
+            let ua = match (area_m2, input.heat_up_time_h) {
+                (Some(a), _) => a * u_w_m2k / 1000.0,
+                (None, Some(h)) => {
+                    let lo = (ua_min * (1.0 + 1e-6)).max(1e-9);
+                    let start = (heat_up_kj / (h * 3600.0) / (t_s - 0.5 * (t0 + t1))).max(lo * 2.0);
+                    let target = -h * 3600.0;
+                    let hi = goal_seek::expand_upper_bound(|ua| -time_s(ua), target, start, 60)
+                        .map_err(TankHeatingError::GoalSeek)?;
+                    goal_seek::goal_seek(|ua| -time_s(ua), target, lo, hi)
+                        .map_err(TankHeatingError::GoalSeek)?
+                        .x
+                }
+                (None, None) => {
+                    return Err(TankHeatingError::InvalidInput(
+                        "코일 면적 또는 목표 가열 시간 중 하나는 있어야 합니다.",
+                    ))
+                }
+            };
+            if ua <= ua_min {
+                return Err(TankHeatingError::Unreachable);
+            }
+            let t_sec = time_s(ua);
+            // ∫(T − Ta)dt: T(t) = T∞ − (T∞ − T0)·e^(−k·t)
+            let k = (ua + ua_loss) / (m0 * cp);
+            let t_inf = (ua * t_s + ua_loss * ta) / (ua + ua_loss);
+            let decay = 1.0 - (-k * t_sec).exp();
+            let loss_kj = ua_loss * ((t_inf - ta) * t_sec - (t_inf - t0) * decay / k);
+            let coil_kj = heat_up_kj + loss_kj;
+            let hours = t_sec / 3600.0;
+            let total_steam = coil_kj / latent;
+            Ok(TankHeatingResult {
+                steam_saturation_temp_c: t_s,
+                heat_up_time_h: hours,
+                heat_up_energy_kwh: heat_up_kj / 3600.0,
+                mean_heat_loss_kw: loss_kj / t_sec,
+                holding_loss_kw,
+                total_steam_kg: total_steam,
+                mean_steam_kg_per_h: total_steam / hours,
+                peak_steam_kg_per_h: ua * (t_s - t0) / latent * 3600.0,
+                holding_steam_kg_per_h: holding_loss_kw / latent * 3600.0,
+                coil_ua_kw_per_k: Some(ua),
+                coil_area_m2: Some(ua * 1000.0 / u_w_m2k),
+                effective_lmtd_k: Some(coil_kj / (ua * t_sec)),
+                final_liquid_mass_kg: m0,
+                warnings,
+            })
+        }
+        HeatingMethod::DirectInjection {
+            steam_flow_kg_per_h,
+        } => {
+            if matches!(steam_flow_kg_per_h, Some(f) if f <= 0.0) {
+                return Err(TankHeatingError::InvalidInput(
+                    "주입 증기량은 0보다 커야 합니다.",
+                ));
+            }
+            // kg/s 기준으로 (가열 시간 s, 최종 질량 kg)
+            let run = |ms: f64| injection_run(ms, m0, cp, hg, ua_loss, ta, t0, t1);
+            let ms_min = ua_loss * (t1 - ta) / (hg - cp * t1);
+            let ms = match (steam_flow_kg_per_h, input.heat_up_time_h) {
+                (Some(f), _) => f / 3600.0,
+                (None, Some(h)) => {
+                    let lo = (ms_min * (1.0 + 1e-6)).max(1e-12);
+                    let start = (heat_up_kj / (h * 3600.0) / (hg - cp * t1)).max(lo * 2.0);
+                    let target = -h * 3600.0;
+                    let time = |ms: f64| run(ms).map_or(f64::INFINITY, |(t, _)| t);
+                    let hi = goal_seek::expand_upper_bound(|ms| -time(ms), target, start, 60)
+                        .map_err(TankHeatingError::GoalSeek)?;
+                    goal_seek::goal_seek(|ms| -time(ms), target, lo, hi)
+                        .map_err(TankHeatingError::GoalSeek)?
+                        .x
+                }
+                (None, None) => {
+                    return Err(TankHeatingError::InvalidInput(
+                        "주입 증기량 또는 목표 가열 시간 중 하나는 있어야 합니다.",
+                    ))
+                }
+            };
+            let (t_sec, m1) = run(ms).ok_or(TankHeatingError::Unreachable)?;
+            let total_steam = m1 - m0;
+            // 에너지 보존: 증기 엔탈피 − 액체 승온(질량 증가 포함) = 손실
+            let loss_kj = total_steam * hg - (m1 * cp * t1 - m0 * cp * t0);
+            let hours = t_sec / 3600.0;
+            if ms * 3600.0 > 0.05 * m0 {
+                warnings.push(
+                    "시간당 주입량이 액체 질량의 5 %를 넘습니다. 주입 노즐 소음/해머를 검토하세요."
+                        .into(),
+                );
+            }
+            Ok(TankHeatingResult {
+                steam_saturation_temp_c: t_s,
+                heat_up_time_h: hours,
+                heat_up_energy_kwh: heat_up_kj / 3600.0,
+                mean_heat_loss_kw: loss_kj / t_sec,
+                holding_loss_kw,
+                total_steam_kg: total_steam,
+                mean_steam_kg_per_h: total_steam / hours,
+                peak_steam_kg_per_h: ms * 3600.0,
+                holding_steam_kg_per_h: holding_loss_kw / (hg - cp * t1) * 3600.0,
+                coil_ua_kw_per_k: None,
+                coil_area_m2: None,
+                effective_lmtd_k: None,
+                final_liquid_mass_kg: m1,
+                warnings,
+            })
+        }
+    }
+}
+
+/// 코일 가열 시간 [s]. 목표 온도가 평형 온도 이상이면 무한대.
+fn coil_time_s(ua: f64, ua_loss: f64, mcp: f64, t_s: f64, ta: f64, t0: f64, t1: f64) -> f64 {
+    let t_inf = (ua * t_s + ua_loss * ta) / (ua + ua_loss);
+    if t_inf <= t1 {
+        return f64::INFINITY;
+    }
+    let k = (ua + ua_loss) / mcp;
+    ((t_inf - t0) / (t_inf - t1)).ln() / k
+}
+
+/// 직접 주입: 온도를 독립변수로 (시간, 질량)을 RK4 적분한다. 손실을 이기지 못하면 `None`.
+#[allow(clippy::too_many_arguments)]
+fn injection_run(
+    ms: f64,
+    m0: f64,
+    cp: f64,
+    hg: f64,
+    ua_loss: f64,
+    ta: f64,
+    t0: f64,
+    t1: f64,
+) -> Option<(f64, f64)> {
+    // dt/dT = M·cp / (ms·(hg − cp·T) − UA_loss·(T − Ta)), dM/dT = ms·dt/dT
+    let dt_dtemp = |temp: f64, m: f64| {
+        let net = ms * (hg - cp * temp) - ua_loss * (temp - ta);
+        (net > 0.0).then(|| m * cp / net)
+    };
+    let h = (t1 - t0) / INJECTION_STEPS as f64;
+    let (mut time, mut m, mut temp) = (0.0, m0, t0);
+    for _ in 0..INJECTION_STEPS {
+        let k1 = dt_dtemp(temp, m)?;
+        let k2 = dt_dtemp(temp + 0.5 * h, m + 0.5 * h * ms * k1)?;
+        let k3 = dt_dtemp(temp + 0.5 * h, m + 0.5 * h * ms * k2)?;
+        let k4 = dt_dtemp(temp + h, m + h * ms * k3)?;
+        let dt = h * (k1 + 2.0 * k2 + 2.0 * k3 + k4) / 6.0;
+        time += dt;
+        m += ms * dt;
+        temp += h;
+    }
+    Some((time, m))
+}
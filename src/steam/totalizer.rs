@@ -0,0 +1,384 @@
+//! 히스토리안 시계열(압력/온도/유량) 적산.
+//! `meter_compensation`과 같은 CSV(timestamp,flow_kg_h,pressure_bar_g[,temperature_c])를 읽어
+//! 행마다 IF97 엔탈피/밀도를 구하고, 이웃한 두 행 사이를 사다리꼴로 적분해 증기량 [t]과 에너지 [MWh]를 낸다.
+//! 에너지는 기준 급수 온도의 포화수 엔탈피를 뺀 값이고, 기준이 없으면 IF97 기준(0.01 °C 포화수)이다.
+//! 압력/온도/유량 평균은 시간 가중, 평균 엔탈피는 질량 가중이다.
+//! 타임스탬프는 `YYYY-MM-DD HH:MM[:SS]`(`T` 구분, `/` 날짜, 끝의 `Z` 허용) 또는 초 단위 숫자이다.
+
+use crate::steam::if97;
+use crate::steam::meter_compensation::{self, HistorianRow, MeterCompensationError};
+use crate::warning::{CalcWarning, Severity};
+
+/// 중앙 간격의 이 배수를 넘는 구간은 데이터 결측으로 보고 경고한다
+const GAP_FACTOR: f64 = 10.0;
+
+/// 적산 입력.
+#[derive(Debug, Clone)]
+pub struct TotalizerInput {
+    pub rows: Vec<HistorianRow>,
+    /// 기준 급수 온도 [°C]. 있으면 에너지 = ṁ·(h − hf(T기준)).
+    pub reference_temp_c: Option<f64>,
+    /// 보일러 최대 연속 증발량 MCR [kg/h]. 있으면 부하율과 초과 시간을 낸다.
+    pub mcr_kg_per_h: Option<f64>,
+}
+
+/// 행별 물성.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TotalizerRow {
+    pub timestamp: String,
+    /// 첫 행 기준 경과 시간 [h]
+    pub elapsed_h: f64,
+    pub flow_kg_per_h: f64,
+    pub pressure_bar_abs: f64,
+    /// 계산에 쓴 온도 [°C] (포화로 본 경우 포화 온도)
+    pub temperature_c: f64,
+    pub enthalpy_kj_per_kg: f64,
+    pub density_kg_per_m3: f64,
+    /// 기준 대비 열량 흐름 [kW]
+    pub heat_flow_kw: f64,
+}
+
+/// 최소/평균/최대.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SeriesStats {
+    pub min: f64,
+    /// 시간 가중 평균
+    pub mean: f64,
+    pub max: f64,
+}
+
+/// MCR 대비 부하.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct McrSummary {
+    pub mcr_kg_per_h: f64,
+    /// 최대 / 평균 유량의 MCR 비 (0~1)
+    pub peak_load_frac: f64,
+    pub average_load_frac: f64,
+    /// 구간 평균 유량이 MCR을 넘은 시간 [h]
+    pub hours_above_mcr: f64,
+}
+
+/// 적산 결과.
+#[derive(Debug, Clone)]
+pub struct TotalizerResult {
+    pub rows: Vec<TotalizerRow>,
+    pub duration_h: f64,
+    /// 적산 증기량 [t]
+    pub total_mass_t: f64,
+    /// 적산 에너지 [MWh]
+    pub total_energy_mwh: f64,
+    /// 기준 엔탈피 [kJ/kg]
+    pub reference_enthalpy_kj_per_kg: f64,
+    /// 질량 가중 평균 엔탈피 [kJ/kg]
+    pub average_enthalpy_kj_per_kg: f64,
+    pub flow_kg_per_h: SeriesStats,
+    pub pressure_bar_abs: SeriesStats,
+    pub temperature_c: SeriesStats,
+    pub mcr: Option<McrSummary>,
+    pub warnings: Vec<TotalizerWarning>,
+}
+
+/// 적산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TotalizerWarning {
+    /// 중앙 간격의 [`GAP_FACTOR`]배를 넘는 결측 구간 수
+    Gaps { gaps: usize },
+    /// 포화 온도 이하라 건포화로 계산한 행 수
+    SaturatedRows { rows: usize },
+    /// 최대 유량이 MCR을 넘음
+    AboveMcr { peak_load_frac: f64, hours: f64 },
+}
+
+impl CalcWarning for TotalizerWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            TotalizerWarning::Gaps { .. } => "warning.totalizer.gaps",
+            // 유량계 일괄 보정과 같은 문구
+            TotalizerWarning::SaturatedRows { .. } => "warning.meter_comp.saturated_rows",
+            TotalizerWarning::AboveMcr { .. } => "warning.totalizer.above_mcr",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            TotalizerWarning::Gaps { gaps } => vec![
+                ("gaps", gaps.to_string()),
+                ("factor", format!("{GAP_FACTOR:.0}")),
+            ],
+            TotalizerWarning::SaturatedRows { rows } => vec![("rows", rows.to_string())],
+            TotalizerWarning::AboveMcr {
+                peak_load_frac,
+                hours,
+            } => vec![
+                ("peak", format!("{:.0}", peak_load_frac * 100.0)),
+                ("hours", format!("{hours:.2}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for TotalizerWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            TotalizerWarning::Gaps { gaps } => write!(
+                f,
+                "{gaps}개 구간이 중앙 간격의 {GAP_FACTOR:.0}배를 넘습니다. 결측 구간을 직선 보간해 적산했습니다."
+            ),
+            TotalizerWarning::SaturatedRows { rows } => write!(
+                f,
+                "{rows}개 행의 온도가 포화 온도 이하라 건포화 증기로 계산했습니다."
+            ),
+            TotalizerWarning::AboveMcr {
+                peak_load_frac,
+                hours,
+            } => write!(
+                f,
+                "최대 유량이 MCR의 {:.0}%이고, MCR 초과 시간은 {hours:.2} h입니다.",
+                peak_load_frac * 100.0
+            ),
+        }
+    }
+}
+
+/// 적산 오류.
+#[derive(Debug, Clone)]
+pub enum TotalizerError {
+    InvalidInput(&'static str),
+    If97(String),
+    /// CSV 행 오류 (1부터 세는 줄 번호)
+    Csv {
+        line: usize,
+        message: String,
+    },
+}
+
+impl std::fmt::Display for TotalizerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TotalizerError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            TotalizerError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            TotalizerError::Csv { line, message } => write!(f, "CSV {line}행: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TotalizerError {}
+
+impl From<MeterCompensationError> for TotalizerError {
+    fn from(e: MeterCompensationError) -> Self {
+        match e {
+            MeterCompensationError::InvalidInput(msg) => TotalizerError::InvalidInput(msg),
+            MeterCompensationError::If97(msg) => TotalizerError::If97(msg),
+            MeterCompensationError::Csv { line, message } => TotalizerError::Csv { line, message },
+        }
+    }
+}
+
+/// 히스토리안 CSV를 읽는다 (`meter_compensation::parse_historian_csv`와 같은 형식).
+pub fn parse_totalizer_csv(
+    text: &str,
+    atmosphere_bar_abs: f64,
+) -> Result<Vec<HistorianRow>, TotalizerError> {
+    Ok(meter_compensation::parse_historian_csv(
+        text,
+        atmosphere_bar_abs,
+    )?)
+}
+
+/// 1970-01-01부터의 날짜 수 (그레고리력, H. Hinnant 알고리즘)
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// 타임스탬프를 초로 읽는다. 날짜/시각 형식은 1970-01-01 00:00 기준 초, 숫자는 그대로.
+pub fn parse_timestamp_s(text: &str) -> Option<f64> {
+    let s = text.trim().trim_end_matches('Z');
+    if let Ok(v) = s.parse::<f64>() {
+        return v.is_finite().then_some(v);
+    }
+    let (date, time) = s.split_once([' ', 'T']).unwrap_or((s, "0:0"));
+    let mut d = date.split(['-', '/']);
+    let year: i64 = d.next()?.parse().ok()?;
+    let month: i64 = d.next()?.parse().ok()?;
+    let day: i64 = d.next()?.parse().ok()?;
+    if d.next().is_some() || !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let mut t = time.trim().split(':');
+    let hour: f64 = t.next()?.parse().ok()?;
+    let minute: f64 = t.next()?.parse().ok()?;
+    let second: f64 = match t.next() {
+        Some(sec) => sec.parse().ok()?,
+        None => 0.0,
+    };
+    if t.next().is_some()
+        || !(0.0..24.0).contains(&hour)
+        || !(0.0..60.0).contains(&minute)
+        || !(0.0..61.0).contains(&second)
+    {
+        return None;
+    }
+    Some(
+        days_from_civil(year, month, day) as f64 * 86_400.0
+            + hour * 3600.0
+            + minute * 60.0
+            + second,
+    )
+}
+
+/// 시간 가중 평균과 최소/최대
+fn stats(times_h: &[f64], values: &[f64]) -> SeriesStats {
+    let duration = times_h[times_h.len() - 1] - times_h[0];
+    let integral: f64 = times_h
+        .windows(2)
+        .zip(values.windows(2))
+        .map(|(t, v)| (v[0] + v[1]) / 2.0 * (t[1] - t[0]))
+        .sum();
+    SeriesStats {
+        min: values.iter().copied().fold(f64::INFINITY, f64::min),
+        mean: integral / duration,
+        max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+    }
+}
+
+/// 시계열을 적산해 증기량, 에너지, 평균/최소/최대 조건을 구한다.
+pub fn totalize(input: &TotalizerInput) -> Result<TotalizerResult, TotalizerError> {
+    if input.rows.len() < 2 {
+        return Err(TotalizerError::InvalidInput(
+            "적산하려면 2행 이상이 필요합니다.",
+        ));
+    }
+    if input.mcr_kg_per_h.is_some_and(|m| m <= 0.0) {
+        return Err(TotalizerError::InvalidInput("MCR은 0보다 커야 합니다."));
+    }
+    let if97_err = |e: &str| TotalizerError::If97(e.into());
+    let reference_enthalpy_kj_per_kg = match input.reference_temp_c {
+        Some(t) => {
+            if !(0.01..=350.0).contains(&t) {
+                return Err(TotalizerError::InvalidInput(
+                    "기준 급수 온도는 0.01~350 °C여야 합니다.",
+                ));
+            }
+            let p = if97::saturation_pressure_bar_abs_from_temp_c(t).map_err(if97_err)?;
+            if97::saturation_enthalpies(p).map_err(if97_err)?.0 / 1000.0
+        }
+        None => 0.0,
+    };
+
+    let mut rows = Vec::with_capacity(input.rows.len());
+    let mut t0 = None;
+    let mut saturated_rows = 0;
+    for row in &input.rows {
+        let csv_err = |message: String| TotalizerError::Csv {
+            line: row.line,
+            message,
+        };
+        let t_s = parse_timestamp_s(&row.timestamp).ok_or_else(|| {
+            csv_err(format!(
+                "타임스탬프 '{}'를 읽을 수 없습니다.",
+                row.timestamp
+            ))
+        })?;
+        let elapsed_h = (t_s - *t0.get_or_insert(t_s)) / 3600.0;
+        if let Some(prev) = rows.last().map(|r: &TotalizerRow| r.elapsed_h) {
+            if elapsed_h <= prev {
+                return Err(csv_err("타임스탬프가 앞 행보다 늦어야 합니다.".to_string()));
+            }
+        }
+        let density =
+            meter_compensation::steam_density(row.actual).map_err(|e| csv_err(e.to_string()))?;
+        let p = row.actual.pressure_bar_abs;
+        let (h, _, _) = if density.superheat_k > 0.0 {
+            if97::region_props(p, density.temperature_c)
+        } else {
+            if97::props_from_px(p, 1.0)
+        }
+        .map_err(|e| csv_err(e.to_string()))?;
+        if density.clamped_to_saturation {
+            saturated_rows += 1;
+        }
+        let enthalpy_kj_per_kg = h / 1000.0;
+        rows.push(TotalizerRow {
+            timestamp: row.timestamp.clone(),
+            elapsed_h,
+            flow_kg_per_h: row.indicated_flow_kg_per_h,
+            pressure_bar_abs: p,
+            temperature_c: density.temperature_c,
+            enthalpy_kj_per_kg,
+            density_kg_per_m3: density.density_kg_per_m3,
+            heat_flow_kw: row.indicated_flow_kg_per_h / 3600.0
+                * (enthalpy_kj_per_kg - reference_enthalpy_kj_per_kg),
+        });
+    }
+
+    let times: Vec<f64> = rows.iter().map(|r| r.elapsed_h).collect();
+    let column = |f: fn(&TotalizerRow) -> f64| rows.iter().map(f).collect::<Vec<f64>>();
+    let flows = column(|r| r.flow_kg_per_h);
+    let heat = column(|r| r.heat_flow_kw);
+    let duration_h = times[times.len() - 1];
+    let flow_stats = stats(&times, &flows);
+    let total_mass_t = flow_stats.mean * duration_h / 1000.0;
+    let total_energy_mwh = stats(&times, &heat).mean * duration_h / 1000.0;
+    let average_enthalpy_kj_per_kg = if total_mass_t > 0.0 {
+        total_energy_mwh * 3600.0 / total_mass_t + reference_enthalpy_kj_per_kg
+    } else {
+        f64::NAN
+    };
+
+    let mut intervals: Vec<f64> = times.windows(2).map(|t| t[1] - t[0]).collect();
+    let mcr = input.mcr_kg_per_h.map(|mcr| McrSummary {
+        mcr_kg_per_h: mcr,
+        peak_load_frac: flow_stats.max / mcr,
+        average_load_frac: flow_stats.mean / mcr,
+        hours_above_mcr: flows
+            .windows(2)
+            .zip(&intervals)
+            .filter(|(m, _)| (m[0] + m[1]) / 2.0 > mcr)
+            .fold(0.0, |hours, (_, dt)| hours + dt),
+    });
+    intervals.sort_by(f64::total_cmp);
+    let median = intervals[intervals.len() / 2];
+    let gaps = intervals
+        .iter()
+        .filter(|&&dt| dt > GAP_FACTOR * median)
+        .count();
+
+    let mut warnings = Vec::new();
+    if gaps > 0 {
+        warnings.push(TotalizerWarning::Gaps { gaps });
+    }
+    if saturated_rows > 0 {
+        warnings.push(TotalizerWarning::SaturatedRows {
+            rows: saturated_rows,
+        });
+    }
+    if let Some(m) = mcr.filter(|m| m.peak_load_frac > 1.0) {
+        warnings.push(TotalizerWarning::AboveMcr {
+            peak_load_frac: m.peak_load_frac,
+            hours: m.hours_above_mcr,
+        });
+    }
+
+    Ok(TotalizerResult {
+        duration_h,
+        total_mass_t,
+        total_energy_mwh,
+        reference_enthalpy_kj_per_kg,
+        average_enthalpy_kj_per_kg,
+        flow_kg_per_h: flow_stats,
+        pressure_bar_abs: stats(&times, &column(|r| r.pressure_bar_abs)),
+        temperature_c: stats(&times, &column(|r| r.temperature_c)),
+        mcr,
+        warnings,
+        rows,
+    })
+}
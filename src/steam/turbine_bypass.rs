@@ -0,0 +1,490 @@
+//! 증기터빈 바이패스 밸브의 감압·스프레이 후 하류 상태.
+//! 밸브 통과 유량은 Kv와 (임계 압력비로 제한한) 차압으로 구하고, 감압은 등엔탈피로 본다.
+//! 스프레이 혼합은 엔탈피 수지 h_mix = (ṁ_s·h_s + ṁ_w·h_w) / (ṁ_s + ṁ_w)로 계산하며,
+//! 하류 압력과 h_mix로 IF97 온도·과열도를 구한다.
+//! 목표 하류 온도에 대한 스프레이량은 하류 온도 잔차 T(p₂, h_mix(ṁ_w)) − T_목표를 Illinois 할선법으로 풀어
+//! 역산하고, 반복 횟수·잔차·수렴 여부를 함께 돌려준다.
+//! 혼합 상태가 포화선에 가까우면 미증발 물방울이 하류 배관을 침식하므로 경고한다.
+
+use crate::steam::critical_flow::SteamStagnation;
+use crate::steam::if97;
+use crate::steam::steam_valves::{self, ValveCalcError};
+use crate::warning::{CalcWarning, Severity};
+
+/// 이 과열도 미만이면 스프레이 물이 다 증발하지 않을 수 있다 [K].
+pub const MIN_SUPERHEAT_K: f64 = 10.0;
+/// 스프레이 역산 수렴 판정 온도 잔차 [K].
+pub const SPRAY_TOLERANCE_K: f64 = 0.01;
+/// 스프레이 역산 최대 반복 횟수.
+pub const SPRAY_MAX_ITERATIONS: usize = 60;
+
+/// 바이패스 계산 입력.
+#[derive(Debug, Clone)]
+pub struct BypassInput {
+    /// 밸브 상류 압력 [bar abs]
+    pub upstream_pressure_bar_abs: f64,
+    /// 밸브 상류 온도 [°C]
+    pub upstream_temp_c: f64,
+    /// 증기 엔탈피 직접 입력 [J/kg]. 없으면 IF97.
+    pub steam_enthalpy_j_per_kg: Option<f64>,
+    /// 하류 압력 [bar abs]
+    pub downstream_pressure_bar_abs: f64,
+    /// 밸브 Kv (행정 반영)
+    pub kv: f64,
+    /// 스프레이 물 유량 [kg/h]
+    pub spray_flow_kg_per_h: f64,
+    /// 스프레이 물 온도 [°C]
+    pub spray_temp_c: f64,
+    /// 스프레이 물 엔탈피 직접 입력 [J/kg]. 없으면 IF97.
+    pub spray_enthalpy_j_per_kg: Option<f64>,
+}
+
+/// 스프레이 역산용 입구 증기 (감압 밸브나 감온기 상류).
+#[derive(Debug, Clone, Copy)]
+pub struct SteamInlet {
+    pub flow_kg_per_h: f64,
+    pub pressure_bar_abs: f64,
+    pub temp_c: f64,
+    /// 엔탈피 직접 입력 [J/kg]. 없으면 IF97.
+    pub enthalpy_j_per_kg: Option<f64>,
+}
+
+/// 스프레이 물.
+#[derive(Debug, Clone, Copy)]
+pub struct SprayWater {
+    pub temp_c: f64,
+    /// 엔탈피 직접 입력 [J/kg]. 없으면 하류 압력 기준 압축수로 IF97.
+    pub enthalpy_j_per_kg: Option<f64>,
+}
+
+/// 감압·혼합 경로의 위치.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BypassStage {
+    /// 밸브 상류
+    Upstream,
+    /// 감압 직후 (스프레이 전)
+    AfterLetdown,
+    /// 스프레이 혼합 후
+    AfterSpray,
+}
+
+impl BypassStage {
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            BypassStage::Upstream => "bypass_stage.upstream",
+            BypassStage::AfterLetdown => "bypass_stage.after_letdown",
+            BypassStage::AfterSpray => "bypass_stage.after_spray",
+        }
+    }
+}
+
+/// 경로상 한 지점의 상태.
+#[derive(Debug, Clone, Copy)]
+pub struct StagePoint {
+    pub stage: BypassStage,
+    pub pressure_bar_abs: f64,
+    /// [J/kg]
+    pub enthalpy_j_per_kg: f64,
+    pub temp_c: f64,
+    pub saturation_temp_c: f64,
+    /// 과열도 [K]. 습증기면 0.
+    pub superheat_k: f64,
+    /// 건도. 과열 증기면 `None`.
+    pub quality: Option<f64>,
+}
+
+/// 바이패스 계산 결과.
+#[derive(Debug, Clone)]
+pub struct BypassResult {
+    /// 증기 체적유량 (상류 밀도 기준) [m³/h]
+    pub steam_volume_flow_m3_per_h: f64,
+    pub steam_flow_kg_per_h: f64,
+    pub choked: bool,
+    /// 스프레이 물 엔탈피 [J/kg]
+    pub spray_enthalpy_j_per_kg: f64,
+    pub mixed_flow_kg_per_h: f64,
+    /// 상류 → 감압 후 → 혼합 후
+    pub profile: Vec<StagePoint>,
+    /// 하류로 보내는 열량 ṁ·h [kW]
+    pub total_heat_kw: f64,
+    /// 스프레이가 흡수한 열량 ṁ_s·(h_s − h_mix) [kW]
+    pub spray_heat_absorbed_kw: f64,
+    pub warnings: Vec<BypassWarning>,
+}
+
+/// 목표 하류 온도에 대한 스프레이 역산 결과.
+#[derive(Debug, Clone)]
+pub struct SprayRequirement {
+    pub steam_flow_kg_per_h: f64,
+    pub spray_flow_kg_per_h: f64,
+    /// 스프레이/증기 질량비
+    pub spray_ratio: f64,
+    /// 목표 온도의 엔탈피 [J/kg]
+    pub target_enthalpy_j_per_kg: f64,
+    /// 목표 온도의 과열도 [K]
+    pub target_superheat_k: f64,
+    /// 역산한 스프레이량으로 다시 계산한 혼합 온도 [°C]
+    pub mixed_temp_c: f64,
+    /// 반복 횟수
+    pub iterations: usize,
+    /// 최종 온도 잔차 |T_mix − T_목표| [K]
+    pub residual_k: f64,
+    pub converged: bool,
+    pub warnings: Vec<BypassWarning>,
+}
+
+/// 바이패스 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BypassWarning {
+    /// 바이패스 밸브 임계 유동
+    Choked,
+    /// 혼합 후 습증기
+    WetAfterSpray { quality: f64 },
+    /// 혼합 후 과열도가 [`MIN_SUPERHEAT_K`] 미만
+    LowSuperheatAfterSpray { superheat_k: f64 },
+    /// 스프레이 역산 미수렴
+    SprayNotConverged { iterations: usize, residual_k: f64 },
+    /// 목표 과열도가 [`MIN_SUPERHEAT_K`] 미만
+    LowTargetSuperheat { superheat_k: f64 },
+}
+
+impl CalcWarning for BypassWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            BypassWarning::Choked => Severity::Info,
+            BypassWarning::WetAfterSpray { .. } => Severity::Critical,
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            BypassWarning::Choked => "warning.bypass.choked",
+            BypassWarning::WetAfterSpray { .. } => "warning.bypass.wet_after_spray",
+            BypassWarning::LowSuperheatAfterSpray { .. } => "warning.bypass.low_superheat",
+            BypassWarning::SprayNotConverged { .. } => "warning.bypass.spray_not_converged",
+            BypassWarning::LowTargetSuperheat { .. } => "warning.bypass.low_target_superheat",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            BypassWarning::Choked => Vec::new(),
+            BypassWarning::WetAfterSpray { quality } => vec![("x", format!("{quality:.3}"))],
+            BypassWarning::LowSuperheatAfterSpray { superheat_k }
+            | BypassWarning::LowTargetSuperheat { superheat_k } => vec![
+                ("superheat", format!("{superheat_k:.1}")),
+                ("min", format!("{MIN_SUPERHEAT_K:.0}")),
+            ],
+            BypassWarning::SprayNotConverged {
+                iterations,
+                residual_k,
+            } => vec![
+                ("iterations", iterations.to_string()),
+                ("residual", format!("{residual_k:.3}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for BypassWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            BypassWarning::Choked => f.write_str(
+                "임계 유동입니다. 차압을 임계 압력비로 제한해 유량을 계산했습니다.",
+            ),
+            BypassWarning::WetAfterSpray { quality } => write!(
+                f,
+                "혼합 후 습증기(건도 {quality:.3})입니다. 스프레이를 줄이세요. 미증발 물이 하류 배관과 복수기를 침식합니다."
+            ),
+            BypassWarning::LowSuperheatAfterSpray { superheat_k } => write!(
+                f,
+                "혼합 후 과열도 {superheat_k:.1} K가 {MIN_SUPERHEAT_K:.0} K 미만으로 포화선에 가깝습니다. 스프레이가 완전히 증발하지 않을 수 있습니다."
+            ),
+            BypassWarning::SprayNotConverged {
+                iterations,
+                residual_k,
+            } => write!(
+                f,
+                "스프레이 역산이 {iterations}회 안에 수렴하지 않았습니다 (잔차 {residual_k:.3} K)."
+            ),
+            BypassWarning::LowTargetSuperheat { superheat_k } => write!(
+                f,
+                "목표 과열도 {superheat_k:.1} K가 {MIN_SUPERHEAT_K:.0} K 미만입니다. 목표 온도를 올리세요."
+            ),
+        }
+    }
+}
+
+/// 바이패스 계산 오류.
+#[derive(Debug)]
+pub enum TurbineBypassError {
+    InvalidInput(&'static str),
+    If97(String),
+    Valve(ValveCalcError),
+}
+
+impl std::fmt::Display for TurbineBypassError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TurbineBypassError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            TurbineBypassError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+            TurbineBypassError::Valve(e) => write!(f, "밸브 유량 계산 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for TurbineBypassError {}
+
+fn if97_err(e: &str) -> TurbineBypassError {
+    TurbineBypassError::If97(e.into())
+}
+
+/// 압력과 엔탈피로 경로 지점 상태를 만든다.
+fn stage_point(
+    stage: BypassStage,
+    p_bar_abs: f64,
+    h_j_per_kg: f64,
+) -> Result<StagePoint, TurbineBypassError> {
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_bar_abs).map_err(if97_err)?;
+    let (hf, hg) = if97::saturation_enthalpies(p_bar_abs).map_err(if97_err)?;
+    let (temp_c, quality) = if h_j_per_kg < hg {
+        (t_sat, Some(((h_j_per_kg - hf) / (hg - hf)).clamp(0.0, 1.0)))
+    } else {
+        (
+            if97::temperature_from_ph(p_bar_abs, h_j_per_kg).map_err(if97_err)?,
+            None,
+        )
+    };
+    Ok(StagePoint {
+        stage,
+        pressure_bar_abs: p_bar_abs,
+        enthalpy_j_per_kg: h_j_per_kg,
+        temp_c,
+        saturation_temp_c: t_sat,
+        superheat_k: (temp_c - t_sat).max(0.0),
+        quality,
+    })
+}
+
+/// 스프레이 물 엔탈피 [J/kg]. 물이 하류 압력에서 끓지 않도록 포화압보다 약간 높은 압력의 압축수로 본다.
+fn water_enthalpy(water: &SprayWater, p_down_bar_abs: f64) -> Result<f64, TurbineBypassError> {
+    if let Some(h) = water.enthalpy_j_per_kg {
+        return Ok(h);
+    }
+    let p_sat = if97::saturation_pressure_bar_abs_from_temp_c(water.temp_c).map_err(if97_err)?;
+    let p = p_down_bar_abs.max(p_sat * 1.01);
+    Ok(if97::region_props(p, water.temp_c).map_err(if97_err)?.0)
+}
+
+fn spray_water(input: &BypassInput) -> SprayWater {
+    SprayWater {
+        temp_c: input.spray_temp_c,
+        enthalpy_j_per_kg: input.spray_enthalpy_j_per_kg,
+    }
+}
+
+/// 상류 증기 엔탈피와 밸브 통과 유량을 구한다. 반환: (h_s [J/kg], Q [m³/h], ṁ [kg/h], choked)
+fn steam_flow(input: &BypassInput) -> Result<(f64, f64, f64, bool), TurbineBypassError> {
+    let p1 = input.upstream_pressure_bar_abs;
+    let p2 = input.downstream_pressure_bar_abs;
+    if p1 <= 0.0 || p2 <= 0.0 || p2 >= p1 {
+        return Err(TurbineBypassError::InvalidInput(
+            "하류 압력은 0보다 크고 상류 압력보다 낮아야 합니다.",
+        ));
+    }
+    if input.kv <= 0.0 {
+        return Err(TurbineBypassError::InvalidInput("Kv는 0보다 커야 합니다."));
+    }
+    let (h_if97, v, _) = if97::region_props(p1, input.upstream_temp_c).map_err(if97_err)?;
+    let rho = 1.0 / v;
+    let h_steam = input.steam_enthalpy_j_per_kg.unwrap_or(h_if97);
+    let flow = steam_valves::steam_flow_from_kv(
+        input.kv,
+        p1 - p2,
+        rho,
+        p1,
+        SteamStagnation::Temperature(input.upstream_temp_c),
+    )
+    .map_err(TurbineBypassError::Valve)?;
+    let q = flow.volumetric_flow_m3_per_h;
+    Ok((h_steam, q, q * rho, flow.choked))
+}
+
+fn saturation_warning(point: &StagePoint, warnings: &mut Vec<BypassWarning>) {
+    if let Some(quality) = point.quality {
+        warnings.push(BypassWarning::WetAfterSpray { quality });
+    } else if point.superheat_k < MIN_SUPERHEAT_K {
+        warnings.push(BypassWarning::LowSuperheatAfterSpray {
+            superheat_k: point.superheat_k,
+        });
+    }
+}
+
+/// 바이패스 감압과 스프레이 혼합 후 하류 상태를 계산한다.
+pub fn compute_bypass(input: &BypassInput) -> Result<BypassResult, TurbineBypassError> {
+    if input.spray_flow_kg_per_h < 0.0 {
+        return Err(TurbineBypassError::InvalidInput(
+            "스프레이 유량은 음수일 수 없습니다.",
+        ));
+    }
+    let (h_steam, q, m_steam, choked) = steam_flow(input)?;
+    let h_spray = water_enthalpy(&spray_water(input), input.downstream_pressure_bar_abs)?;
+    let m_spray = input.spray_flow_kg_per_h;
+    let m_total = m_steam + m_spray;
+    let h_mix = (m_steam * h_steam + m_spray * h_spray) / m_total;
+
+    let p1 = input.upstream_pressure_bar_abs;
+    let p2 = input.downstream_pressure_bar_abs;
+    let profile = vec![
+        stage_point(BypassStage::Upstream, p1, h_steam)?,
+        stage_point(BypassStage::AfterLetdown, p2, h_steam)?,
+        stage_point(BypassStage::AfterSpray, p2, h_mix)?,
+    ];
+
+    let mut warnings = Vec::new();
+    if choked {
+        warnings.push(BypassWarning::Choked);
+    }
+    if m_spray > 0.0 {
+        saturation_warning(&profile[2], &mut warnings);
+    }
+
+    Ok(BypassResult {
+        steam_volume_flow_m3_per_h: q,
+        steam_flow_kg_per_h: m_steam,
+        choked,
+        spray_enthalpy_j_per_kg: h_spray,
+        mixed_flow_kg_per_h: m_total,
+        total_heat_kw: m_total * h_mix / 3.6e6,
+        spray_heat_absorbed_kw: m_steam * (h_steam - h_mix) / 3.6e6,
+        profile,
+        warnings,
+    })
+}
+
+/// 하류 온도를 `target_temp_c`로 맞추는 스프레이 유량을 역산한다. `input.spray_flow_kg_per_h`는 쓰지 않는다.
+pub fn spray_for_target_temperature(
+    input: &BypassInput,
+    target_temp_c: f64,
+) -> Result<SprayRequirement, TurbineBypassError> {
+    let (h_steam, _, m_steam, _) = steam_flow(input)?;
+    let steam_in = SteamInlet {
+        flow_kg_per_h: m_steam,
+        pressure_bar_abs: input.upstream_pressure_bar_abs,
+        temp_c: input.upstream_temp_c,
+        enthalpy_j_per_kg: Some(h_steam),
+    };
+    required_spray_flow(
+        &steam_in,
+        input.downstream_pressure_bar_abs,
+        target_temp_c,
+        &spray_water(input),
+    )
+}
+
+/// 입구 증기를 하류 압력 `p_down_bar_abs`에서 `t_down_target_c`로 낮추는 스프레이 물 유량을 구한다.
+/// 스프레이량 0(감압만 한 온도)과 혼합이 포화 증기가 되는 스프레이량 사이에서 Illinois 할선법으로 푼다.
+pub fn required_spray_flow(
+    steam_in: &SteamInlet,
+    p_down_bar_abs: f64,
+    t_down_target_c: f64,
+    water_in: &SprayWater,
+) -> Result<SprayRequirement, TurbineBypassError> {
+    let m_steam = steam_in.flow_kg_per_h;
+    if m_steam <= 0.0 {
+        return Err(TurbineBypassError::InvalidInput(
+            "증기 유량은 0보다 커야 합니다.",
+        ));
+    }
+    if p_down_bar_abs <= 0.0 || p_down_bar_abs > steam_in.pressure_bar_abs {
+        return Err(TurbineBypassError::InvalidInput(
+            "하류 압력은 0보다 크고 입구 압력 이하여야 합니다.",
+        ));
+    }
+    let h_steam = match steam_in.enthalpy_j_per_kg {
+        Some(h) => h,
+        None => {
+            if97::region_props(steam_in.pressure_bar_abs, steam_in.temp_c)
+                .map_err(if97_err)?
+                .0
+        }
+    };
+    let h_water = water_enthalpy(water_in, p_down_bar_abs)?;
+    let t_sat = if97::saturation_temp_c_from_pressure_bar_abs(p_down_bar_abs).map_err(if97_err)?;
+    if t_down_target_c <= t_sat {
+        return Err(TurbineBypassError::InvalidInput(
+            "목표 온도는 하류 포화온도보다 높아야 합니다.",
+        ));
+    }
+    let h_target = if97::region_props(p_down_bar_abs, t_down_target_c)
+        .map_err(if97_err)?
+        .0;
+    if h_target >= h_steam {
+        return Err(TurbineBypassError::InvalidInput(
+            "목표 온도가 감압 후 온도 이상입니다. 스프레이가 필요 없습니다.",
+        ));
+    }
+    if h_target <= h_water {
+        return Err(TurbineBypassError::InvalidInput(
+            "스프레이 물 엔탈피가 목표 엔탈피보다 높습니다.",
+        ));
+    }
+    let (_, hg) = if97::saturation_enthalpies(p_down_bar_abs).map_err(if97_err)?;
+    let residual = |m_water: f64| -> Result<f64, TurbineBypassError> {
+        let h_mix = (m_steam * h_steam + m_water * h_water) / (m_steam + m_water);
+        let t_mix = if h_mix <= hg {
+            t_sat
+        } else {
+            if97::temperature_from_ph(p_down_bar_abs, h_mix).map_err(if97_err)?
+        };
+        Ok(t_mix - t_down_target_c)
+    };
+
+    // 구간 [0, 포화 증기가 되는 스프레이량]에서 잔차 부호가 바뀐다.
+    let (mut a, mut fa) = (0.0, residual(0.0)?);
+    let mut b = m_steam * (h_steam - hg) / (hg - h_water);
+    let mut fb = t_sat - t_down_target_c;
+    let mut iterations = 0;
+    while iterations < SPRAY_MAX_ITERATIONS && fb.abs() > SPRAY_TOLERANCE_K {
+        iterations += 1;
+        let c = b - fb * (b - a) / (fb - fa);
+        let fc = residual(c)?;
+        if fc * fb < 0.0 {
+            a = b;
+            fa = fb;
+        } else {
+            fa /= 2.0;
+        }
+        b = c;
+        fb = fc;
+    }
+    let converged = fb.abs() <= SPRAY_TOLERANCE_K;
+    let m_spray = b;
+
+    let superheat = t_down_target_c - t_sat;
+    let mut warnings = Vec::new();
+    if !converged {
+        warnings.push(BypassWarning::SprayNotConverged {
+            iterations,
+            residual_k: fb.abs(),
+        });
+    }
+    if superheat < MIN_SUPERHEAT_K {
+        warnings.push(BypassWarning::LowTargetSuperheat {
+            superheat_k: superheat,
+        });
+    }
+    Ok(SprayRequirement {
+        steam_flow_kg_per_h: m_steam,
+        spray_flow_kg_per_h: m_spray,
+        spray_ratio: m_spray / m_steam,
+        target_enthalpy_j_per_kg: h_target,
+        target_superheat_k: superheat,
+        mixed_temp_c: t_down_target_c + fb,
+        iterations,
+        residual_k: fb.abs(),
+        converged,
+        warnings,
+    })
+}
@@ -0,0 +1,390 @@
+//! 제어 밸브 구동기 사이징 (추력/토크와 스프링 범위).
+//! 직동형(글로브) 밸브의 필요 추력은 차단 차압의 불평형력, 누설 등급별 시트 하중, 패킹 마찰의 합이다.
+//! - 불평형력 F_ub = ΔP_차단 · π/4 · |d_포트² − d_밸런스씰²| (불평형 플러그는 씰 직경 0)
+//! - 시트 하중 F_seat = 누설 등급별 단위 하중 [N/m] · π · d_포트
+//! - 패킹 마찰은 스템 직경에 비례 (PTFE ≈ 100 lbf/in, 그래파이트 ≈ 330 lbf/in)
+//!
+//! 스프링 다이어프램 구동기는 표준 유효 면적과 벤치 스프링 범위 중 여유를 만족하는 가장 작은 조합을,
+//! 피스톤 구동기는 공급 공기압으로 추력을 내는 가장 작은 보어를 고른다.
+//! 회전형(버터플라이/볼) 밸브는 시트 토크 + 베어링 마찰 토크 + 패킹 토크로 기동 토크를 구한다.
+
+use super::steam_valves::ValveCalcError;
+use crate::warning::{CalcWarning, Severity};
+
+/// 1 lbf/in [N/m]
+const N_PER_M_PER_LBF_PER_IN: f64 = 175.127;
+/// 필요 추력 대비 기본 설계 여유.
+pub const DEFAULT_MARGIN: f64 = 1.25;
+/// 스프링 상한 압력 위로 남겨야 하는 공급 압력 [bar] (전 행정 확보).
+pub const SUPPLY_HEADROOM_BAR: f64 = 0.2;
+/// 표준 다이어프램 유효 면적 [cm²].
+pub const DIAPHRAGM_AREAS_CM2: [f64; 7] = [120.0, 175.0, 240.0, 350.0, 700.0, 1400.0, 2800.0];
+/// 표준 벤치 스프링 범위 [bar].
+pub const SPRING_RANGES_BAR: [(f64, f64); 6] = [
+    (0.2, 1.0),
+    (0.4, 1.2),
+    (0.4, 2.0),
+    (0.6, 3.0),
+    (1.4, 2.3),
+    (2.1, 3.3),
+];
+/// 표준 피스톤 보어 [mm].
+pub const PISTON_BORES_MM: [f64; 7] = [80.0, 100.0, 125.0, 160.0, 200.0, 250.0, 320.0];
+/// 피스톤 구동기 기계 효율 (씰 마찰).
+const PISTON_EFFICIENCY: f64 = 0.9;
+
+/// ANSI/FCI 70-2 누설 등급.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeakageClass {
+    II,
+    III,
+    IV,
+    V,
+    VI,
+}
+
+impl LeakageClass {
+    pub const ALL: [LeakageClass; 5] = [
+        LeakageClass::II,
+        LeakageClass::III,
+        LeakageClass::IV,
+        LeakageClass::V,
+        LeakageClass::VI,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            LeakageClass::II => "Class II",
+            LeakageClass::III => "Class III",
+            LeakageClass::IV => "Class IV",
+            LeakageClass::V => "Class V",
+            LeakageClass::VI => "Class VI",
+        }
+    }
+
+    /// 포트 둘레당 권장 시트 하중 [N/m] (금속 시트 기준).
+    pub fn seat_load_n_per_m(self) -> f64 {
+        let lbf_per_in = match self {
+            LeakageClass::II => 20.0,
+            LeakageClass::III => 40.0,
+            LeakageClass::IV => 80.0,
+            LeakageClass::V | LeakageClass::VI => 300.0,
+        };
+        lbf_per_in * N_PER_M_PER_LBF_PER_IN
+    }
+}
+
+/// 스템 패킹 재질.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackingType {
+    Ptfe,
+    Graphite,
+}
+
+impl PackingType {
+    pub const ALL: [PackingType; 2] = [PackingType::Ptfe, PackingType::Graphite];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PackingType::Ptfe => "PTFE",
+            PackingType::Graphite => "Graphite",
+        }
+    }
+
+    /// 스템 직경당 마찰력 [N/m].
+    pub fn friction_n_per_m(self) -> f64 {
+        let lbf_per_in = match self {
+            PackingType::Ptfe => 100.0,
+            PackingType::Graphite => 330.0,
+        };
+        lbf_per_in * N_PER_M_PER_LBF_PER_IN
+    }
+}
+
+/// 구동기 형식.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActuatorKind {
+    /// 스프링 다이어프램, 공기 상실 시 닫힘 (air-to-open)
+    DiaphragmFailClose,
+    /// 스프링 다이어프램, 공기 상실 시 열림 (air-to-close)
+    DiaphragmFailOpen,
+    /// 복동 피스톤
+    Piston,
+}
+
+impl ActuatorKind {
+    pub const ALL: [ActuatorKind; 3] = [
+        ActuatorKind::DiaphragmFailClose,
+        ActuatorKind::DiaphragmFailOpen,
+        ActuatorKind::Piston,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ActuatorKind::DiaphragmFailClose => "Diaphragm, fail-close",
+            ActuatorKind::DiaphragmFailOpen => "Diaphragm, fail-open",
+            ActuatorKind::Piston => "Piston",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            ActuatorKind::DiaphragmFailClose => "actuator_kind.diaphragm_fail_close",
+            ActuatorKind::DiaphragmFailOpen => "actuator_kind.diaphragm_fail_open",
+            ActuatorKind::Piston => "actuator_kind.piston",
+        }
+    }
+}
+
+/// 직동형 밸브 추력 입력.
+#[derive(Debug, Clone)]
+pub struct ThrustInput {
+    /// 차단 차압 [bar]
+    pub shutoff_dp_bar: f64,
+    /// 포트(시트) 직경 [m]
+    pub port_diameter_m: f64,
+    /// 밸런스 씰 직경 [m]. 불평형 플러그면 `None`.
+    pub balance_seal_diameter_m: Option<f64>,
+    /// 스템 직경 [m]
+    pub stem_diameter_m: f64,
+    pub leakage_class: LeakageClass,
+    pub packing: PackingType,
+}
+
+/// 필요 추력 결과 [N].
+#[derive(Debug, Clone, Copy)]
+pub struct ThrustResult {
+    pub unbalance_force_n: f64,
+    pub seat_load_n: f64,
+    pub packing_friction_n: f64,
+    /// 위 세 항의 합
+    pub required_thrust_n: f64,
+}
+
+/// 구동기 선정 입력.
+#[derive(Debug, Clone)]
+pub struct ActuatorSelectionInput {
+    pub kind: ActuatorKind,
+    /// 필요 추력 [N]
+    pub required_thrust_n: f64,
+    /// 공급 공기압 [bar(g)]
+    pub supply_pressure_bar_g: f64,
+    /// 요구 여유 (가용/필요, 보통 1.25)
+    pub margin: f64,
+}
+
+/// 선정된 구동기.
+#[derive(Debug, Clone)]
+pub struct ActuatorSelection {
+    pub kind: ActuatorKind,
+    /// 유효 면적 [m²]
+    pub effective_area_m2: f64,
+    /// 피스톤 보어 [mm] (피스톤만)
+    pub piston_bore_mm: Option<f64>,
+    /// 벤치 스프링 범위 [bar] (다이어프램만)
+    pub spring_range_bar: Option<(f64, f64)>,
+    /// 시트에 걸 수 있는 추력 [N]
+    pub available_thrust_n: f64,
+    /// 가용/필요 추력
+    pub margin: f64,
+    pub warnings: Vec<ActuatorWarning>,
+}
+
+/// 구동기 선정 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ActuatorWarning {
+    /// 공기 상실 시 닫힘: 스프링 초기 하중으로만 차단
+    FailCloseBenchSet { spring_low_bar: f64 },
+    /// 토크 여유가 [`DEFAULT_MARGIN`] 미만
+    LowTorqueMargin { margin: f64 },
+}
+
+impl CalcWarning for ActuatorWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            ActuatorWarning::FailCloseBenchSet { .. } => Severity::Info,
+            ActuatorWarning::LowTorqueMargin { .. } => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ActuatorWarning::FailCloseBenchSet { .. } => "warning.actuator.fail_close_bench_set",
+            ActuatorWarning::LowTorqueMargin { .. } => "warning.actuator.low_torque_margin",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            ActuatorWarning::FailCloseBenchSet { spring_low_bar } => {
+                vec![("lo", format!("{spring_low_bar:.1}"))]
+            }
+            ActuatorWarning::LowTorqueMargin { margin } => vec![
+                ("margin", format!("{margin:.2}")),
+                ("min", format!("{DEFAULT_MARGIN}")),
+            ],
+        }
+    }
+}
+
+impl std::fmt::Display for ActuatorWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            ActuatorWarning::FailCloseBenchSet { spring_low_bar } => write!(
+                f,
+                "공기 상실 시 닫힘: 차단 추력은 스프링 초기 하중만으로 냅니다. 벤치 세팅 하한 {spring_low_bar:.1} bar를 현장에서 확인하세요."
+            ),
+            ActuatorWarning::LowTorqueMargin { margin } => write!(
+                f,
+                "구동기 토크 여유 {margin:.2}가 {DEFAULT_MARGIN} 미만입니다. 한 단계 큰 구동기를 검토하세요."
+            ),
+        }
+    }
+}
+
+/// 회전형 밸브 토크 입력.
+#[derive(Debug, Clone)]
+pub struct TorqueInput {
+    /// 차단 차압 [bar]
+    pub shutoff_dp_bar: f64,
+    /// 디스크(볼) 직경 [m]
+    pub disc_diameter_m: f64,
+    /// 샤프트 직경 [m]
+    pub shaft_diameter_m: f64,
+    /// 베어링 마찰 계수
+    pub bearing_friction: f64,
+    /// 제조사 시트 토크 [N·m]
+    pub seat_torque_nm: f64,
+    pub packing: PackingType,
+    /// 구동기 정격 토크 [N·m]
+    pub actuator_torque_nm: f64,
+}
+
+/// 회전형 밸브 토크 결과.
+#[derive(Debug, Clone)]
+pub struct TorqueResult {
+    pub bearing_torque_nm: f64,
+    pub packing_torque_nm: f64,
+    /// 기동 토크 [N·m]
+    pub breakout_torque_nm: f64,
+    /// 구동기 정격/기동 토크
+    pub margin: f64,
+    pub warnings: Vec<ActuatorWarning>,
+}
+
+/// 직동형 밸브 차단에 필요한 추력을 구한다.
+pub fn required_thrust(input: &ThrustInput) -> Result<ThrustResult, ValveCalcError> {
+    if input.shutoff_dp_bar < 0.0 || input.port_diameter_m <= 0.0 || input.stem_diameter_m <= 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "차단 차압은 0 이상, 포트와 스템 직경은 0보다 커야 합니다.",
+        ));
+    }
+    let seal = input.balance_seal_diameter_m.unwrap_or(0.0);
+    if seal < 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "밸런스 씰 직경은 음수일 수 없습니다.",
+        ));
+    }
+    let area = std::f64::consts::FRAC_PI_4 * (input.port_diameter_m.powi(2) - seal.powi(2)).abs();
+    let unbalance = input.shutoff_dp_bar * 1e5 * area;
+    let seat =
+        input.leakage_class.seat_load_n_per_m() * std::f64::consts::PI * input.port_diameter_m;
+    let packing = input.packing.friction_n_per_m() * input.stem_diameter_m;
+    Ok(ThrustResult {
+        unbalance_force_n: unbalance,
+        seat_load_n: seat,
+        packing_friction_n: packing,
+        required_thrust_n: unbalance + seat + packing,
+    })
+}
+
+/// 필요 추력과 공급 압력으로 구동기 크기와 스프링 범위를 고른다.
+pub fn select_actuator(
+    input: &ActuatorSelectionInput,
+) -> Result<ActuatorSelection, ValveCalcError> {
+    if input.required_thrust_n <= 0.0 || input.supply_pressure_bar_g <= 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "필요 추력과 공급 압력은 0보다 커야 합니다.",
+        ));
+    }
+    if input.margin < 1.0 {
+        return Err(ValveCalcError::InvalidInput("여유는 1 이상이어야 합니다."));
+    }
+    let needed = input.required_thrust_n * input.margin;
+    let supply_pa = input.supply_pressure_bar_g * 1e5;
+
+    let selection = match input.kind {
+        ActuatorKind::Piston => PISTON_BORES_MM.iter().find_map(|&bore| {
+            let area = std::f64::consts::FRAC_PI_4 * (bore / 1000.0).powi(2);
+            let thrust = area * supply_pa * PISTON_EFFICIENCY;
+            (thrust >= needed).then_some((area, Some(bore), None, thrust))
+        }),
+        kind => DIAPHRAGM_AREAS_CM2.iter().find_map(|&cm2| {
+            let area = cm2 * 1e-4;
+            SPRING_RANGES_BAR.iter().find_map(|&(lo, hi)| {
+                if hi + SUPPLY_HEADROOM_BAR > input.supply_pressure_bar_g {
+                    return None;
+                }
+                // 공기 상실 시 닫힘: 스프링 초기 압축(하한)이 시트를 누른다.
+                // 공기 상실 시 열림: 공급 압력에서 스프링 최대 압축(상한)을 뺀 만큼이 시트를 누른다.
+                let thrust = match kind {
+                    ActuatorKind::DiaphragmFailClose => area * lo * 1e5,
+                    _ => area * (supply_pa - hi * 1e5),
+                };
+                (thrust >= needed).then_some((area, None, Some((lo, hi)), thrust))
+            })
+        }),
+    };
+
+    let (area, bore, spring, thrust) = selection.ok_or(ValveCalcError::InvalidInput(
+        "표준 크기 안에서 필요 추력을 낼 수 있는 구동기가 없습니다. 공급 압력을 높이거나 밸런스형 플러그를 검토하세요.",
+    ))?;
+    let mut warnings = Vec::new();
+    if matches!(input.kind, ActuatorKind::DiaphragmFailClose) {
+        warnings.push(ActuatorWarning::FailCloseBenchSet {
+            spring_low_bar: spring.map_or(0.0, |(lo, _)| lo),
+        });
+    }
+    Ok(ActuatorSelection {
+        kind: input.kind,
+        effective_area_m2: area,
+        piston_bore_mm: bore,
+        spring_range_bar: spring,
+        available_thrust_n: thrust,
+        margin: thrust / input.required_thrust_n,
+        warnings,
+    })
+}
+
+/// 회전형 밸브 기동 토크와 구동기 여유를 구한다.
+pub fn breakout_torque(input: &TorqueInput) -> Result<TorqueResult, ValveCalcError> {
+    if input.shutoff_dp_bar < 0.0
+        || input.disc_diameter_m <= 0.0
+        || input.shaft_diameter_m <= 0.0
+        || input.bearing_friction < 0.0
+        || input.seat_torque_nm < 0.0
+        || input.actuator_torque_nm <= 0.0
+    {
+        return Err(ValveCalcError::InvalidInput(
+            "직경과 구동기 토크는 0보다 크고, 차압·마찰 계수·시트 토크는 음수일 수 없습니다.",
+        ));
+    }
+    let disc_force =
+        input.shutoff_dp_bar * 1e5 * std::f64::consts::FRAC_PI_4 * input.disc_diameter_m.powi(2);
+    let bearing = disc_force * input.bearing_friction * input.shaft_diameter_m / 2.0;
+    let packing =
+        input.packing.friction_n_per_m() * input.shaft_diameter_m * input.shaft_diameter_m / 2.0;
+    let breakout = input.seat_torque_nm + bearing + packing;
+    let margin = input.actuator_torque_nm / breakout;
+    let mut warnings = Vec::new();
+    if margin < DEFAULT_MARGIN {
+        warnings.push(ActuatorWarning::LowTorqueMargin { margin });
+    }
+    Ok(TorqueResult {
+        bearing_torque_nm: bearing,
+        packing_torque_nm: packing,
+        breakout_torque_nm: breakout,
+        margin,
+        warnings,
+    })
+}
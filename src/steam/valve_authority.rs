@@ -0,0 +1,232 @@
+//! 밸브 권한(authority)과 설치 특성.
+//! 고유 특성 f(x)(선형, 등비율 R^(x−1), 급개 √x)에 배관 회로의 차압 분배를 더해
+//! 전체 차압이 일정할 때의 설치 유량 q = f / √(N + (1−N)·f²)를 구한다.
+//! 권한 N = ΔP_밸브(전개) / (ΔP_밸브(전개) + ΔP_회로)이며, 0.25 미만이면 제어성이 나쁜 것으로 본다.
+//! 설치 이득 dq/dx는 행정 구간별 차분으로 구한다.
+
+use serde::{Deserialize, Serialize};
+
+use super::steam_valves::ValveCalcError;
+use crate::warning::{CalcWarning, Severity};
+
+/// 이 권한 미만이면 제어성 불량으로 표시한다.
+pub const POOR_AUTHORITY: f64 = 0.25;
+/// 설치 이득의 최대/최소 비가 이 값을 넘으면 루프 튜닝이 어렵다고 본다.
+pub const GAIN_RATIO_LIMIT: f64 = 3.0;
+
+/// 밸브 고유 특성.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InherentCharacteristic {
+    Linear,
+    EqualPercentage,
+    QuickOpening,
+}
+
+impl InherentCharacteristic {
+    pub const ALL: [InherentCharacteristic; 3] = [
+        InherentCharacteristic::Linear,
+        InherentCharacteristic::EqualPercentage,
+        InherentCharacteristic::QuickOpening,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            InherentCharacteristic::Linear => "Linear",
+            InherentCharacteristic::EqualPercentage => "Equal percentage",
+            InherentCharacteristic::QuickOpening => "Quick opening",
+        }
+    }
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            InherentCharacteristic::Linear => "valve_characteristic.linear",
+            InherentCharacteristic::EqualPercentage => "valve_characteristic.equal_percentage",
+            InherentCharacteristic::QuickOpening => "valve_characteristic.quick_opening",
+        }
+    }
+
+    /// 행정 x(0~1)에서 전개 대비 Cv 비율. 등비율은 레인지어빌리티 R을 쓴다.
+    pub fn relative_cv(self, stroke: f64, rangeability: f64) -> f64 {
+        let x = stroke.clamp(0.0, 1.0);
+        match self {
+            InherentCharacteristic::Linear => x,
+            InherentCharacteristic::EqualPercentage => rangeability.powf(x - 1.0),
+            InherentCharacteristic::QuickOpening => x.sqrt(),
+        }
+    }
+}
+
+/// 권한 해석 입력.
+#[derive(Debug, Clone)]
+pub struct AuthorityInput {
+    pub characteristic: InherentCharacteristic,
+    /// 등비율 특성의 레인지어빌리티 R (보통 30~50)
+    pub rangeability: f64,
+    /// 설계 유량에서 전개 밸브 차압 [bar]
+    pub valve_dp_bar: f64,
+    /// 설계 유량에서 밸브를 뺀 회로 차압(배관, 열교환기 등) [bar]
+    pub circuit_dp_bar: f64,
+    /// 행정 분할 수
+    pub steps: usize,
+}
+
+/// 행정별 특성 점.
+#[derive(Debug, Clone, Copy)]
+pub struct CharacteristicPoint {
+    /// 행정 [0~1]
+    pub stroke: f64,
+    /// 고유 특성 유량 비 [0~1]
+    pub inherent_flow: f64,
+    /// 설치 특성 유량 비 [0~1]
+    pub installed_flow: f64,
+    /// 설치 이득 dq/dx
+    pub installed_gain: f64,
+}
+
+/// 권한 해석 결과.
+#[derive(Debug, Clone)]
+pub struct AuthorityResult {
+    /// 밸브 권한 N
+    pub authority: f64,
+    pub points: Vec<CharacteristicPoint>,
+    /// 행정 10~90% 구간 설치 이득의 최대/최소 비
+    pub gain_ratio: f64,
+    /// 권한 < 0.25
+    pub poor_authority: bool,
+    pub warnings: Vec<AuthorityWarning>,
+}
+
+/// 권한 해석 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthorityWarning {
+    /// 권한 N이 [`POOR_AUTHORITY`] 미만
+    PoorAuthority { authority: f64 },
+    /// 설치 이득 변화가 [`GAIN_RATIO_LIMIT`]를 넘음
+    GainVariation { gain_ratio: f64 },
+}
+
+impl CalcWarning for AuthorityWarning {
+    fn severity(&self) -> Severity {
+        Severity::Caution
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            AuthorityWarning::PoorAuthority { .. } => "warning.valve_authority.poor_authority",
+            AuthorityWarning::GainVariation { .. } => "warning.valve_authority.gain_variation",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            AuthorityWarning::PoorAuthority { authority } => vec![
+                ("n", format!("{authority:.2}")),
+                ("min", format!("{POOR_AUTHORITY}")),
+            ],
+            AuthorityWarning::GainVariation { gain_ratio } => {
+                vec![("ratio", format!("{gain_ratio:.1}"))]
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for AuthorityWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            AuthorityWarning::PoorAuthority { authority } => write!(
+                f,
+                "밸브 권한 {authority:.2}가 {POOR_AUTHORITY} 미만입니다. 설치 특성이 급개형으로 변해 저개도 제어가 불안정합니다. 밸브 차압 배분을 늘리거나 등비율 특성을 쓰세요."
+            ),
+            AuthorityWarning::GainVariation { gain_ratio } => write!(
+                f,
+                "행정 10~90% 구간 설치 이득이 {gain_ratio:.1}배 변합니다. 한 가지 튜닝으로 전 구간을 맞추기 어렵습니다."
+            ),
+        }
+    }
+}
+
+/// 권한 N = ΔP_밸브 / (ΔP_밸브 + ΔP_회로).
+pub fn valve_authority(valve_dp_bar: f64, circuit_dp_bar: f64) -> f64 {
+    valve_dp_bar / (valve_dp_bar + circuit_dp_bar)
+}
+
+/// 권한 N에서 고유 유량 비 f가 설치 유량 비로 바뀐 값.
+pub fn installed_flow(relative_cv: f64, authority: f64) -> f64 {
+    let f = relative_cv;
+    if f <= 0.0 {
+        return 0.0;
+    }
+    f / (authority + (1.0 - authority) * f * f).sqrt()
+}
+
+/// 고유 특성과 회로 차압 분배로 권한, 설치 특성, 설치 이득을 구한다.
+pub fn analyze_authority(input: &AuthorityInput) -> Result<AuthorityResult, ValveCalcError> {
+    if input.valve_dp_bar <= 0.0 || input.circuit_dp_bar < 0.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "밸브 차압은 0보다 크고 회로 차압은 음수일 수 없습니다.",
+        ));
+    }
+    if input.rangeability <= 1.0 {
+        return Err(ValveCalcError::InvalidInput(
+            "레인지어빌리티는 1보다 커야 합니다.",
+        ));
+    }
+    if input.steps < 2 {
+        return Err(ValveCalcError::InvalidInput(
+            "행정 분할 수는 2 이상이어야 합니다.",
+        ));
+    }
+
+    let n = valve_authority(input.valve_dp_bar, input.circuit_dp_bar);
+    let dx = 1.0 / input.steps as f64;
+    let flow_at = |x: f64| {
+        let f = input.characteristic.relative_cv(x, input.rangeability);
+        (f, installed_flow(f, n))
+    };
+    let points: Vec<CharacteristicPoint> = (0..=input.steps)
+        .map(|i| {
+            let x = i as f64 * dx;
+            let (inherent, installed) = flow_at(x);
+            // 중앙 차분 (양 끝은 한쪽 차분)
+            let lo = (x - dx).max(0.0);
+            let hi = (x + dx).min(1.0);
+            let gain = (flow_at(hi).1 - flow_at(lo).1) / (hi - lo);
+            CharacteristicPoint {
+                stroke: x,
+                inherent_flow: inherent,
+                installed_flow: installed,
+                installed_gain: gain,
+            }
+        })
+        .collect();
+
+    let (g_min, g_max) = points
+        .iter()
+        .filter(|p| (0.1 - 1e-9..=0.9 + 1e-9).contains(&p.stroke))
+        .fold((f64::INFINITY, 0.0f64), |(lo, hi), p| {
+            (lo.min(p.installed_gain), hi.max(p.installed_gain))
+        });
+    let gain_ratio = if g_min > 0.0 {
+        g_max / g_min
+    } else {
+        f64::INFINITY
+    };
+
+    let poor_authority = n < POOR_AUTHORITY;
+    let mut warnings = Vec::new();
+    if poor_authority {
+        warnings.push(AuthorityWarning::PoorAuthority { authority: n });
+    }
+    if gain_ratio > GAIN_RATIO_LIMIT {
+        warnings.push(AuthorityWarning::GainVariation { gain_ratio });
+    }
+
+    Ok(AuthorityResult {
+        authority: n,
+        points,
+        gain_ratio,
+        poor_authority,
+        warnings,
+    })
+}
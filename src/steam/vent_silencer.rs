@@ -0,0 +1,320 @@
+//! 대기 방출 증기 벤트(시운전 스팀 블로, 기동 벤트)의 출구 유속, 소음, 소음기 필요 감쇠량 추정.
+//! 벤트 밸브 유량은 IF97 등엔트로피 노즐의 임계(초킹) 유동으로 구하고, 밸브 뒤는 단열(정체 엔탈피 일정)로 본다.
+//! 벤트 배관 출구는 대기압에서 아음속이면 그대로, 음속에 닿으면 출구 압력을 대기압보다 높여 M = 1로 맞춘다.
+//!
+//! 분출 소음은 Lighthill 상관식으로 어림한다.
+//! 음향 효율 η = 1e-4·Mj⁵ (Mj: 완전 팽창 분출 마하수, 상한 1e-2), 기계적 파워 W = ½·ṁ·vj²,
+//! PWL = 10·log10(η·W / 1e-12) [dB]. 피크 주파수는 Strouhal 수 0.2로 f = 0.2·v/D이고
+//! 그 주파수의 A 보정을 더해 dB(A)로 본다. 거리 r의 음압은 반구 확산 SPL = PWL − 10·log10(2π·r²)이다.
+//! 지향성, 대기 흡음, 배경 소음은 다루지 않으므로 소음기 사양 검토 전 어림용이다.
+
+use super::critical_flow::{self, CriticalFlowError, SteamStagnation};
+use super::if97;
+use crate::warning::{CalcWarning, Severity};
+
+/// 출구 분출 피크 주파수 Strouhal 수
+const PEAK_STROUHAL: f64 = 0.2;
+/// Lighthill 음향 효율 계수 (Mj = 1에서 η)
+const ACOUSTIC_EFFICIENCY_AT_MACH1: f64 = 1.0e-4;
+/// 음향 효율 상한
+const MAX_ACOUSTIC_EFFICIENCY: f64 = 1.0e-2;
+/// 완전 팽창 마하수 계산용 증기 비열비
+const STEAM_K: f64 = 1.3;
+/// 기준 음향 파워 [W]
+const REFERENCE_POWER_W: f64 = 1.0e-12;
+/// 소음기 한 단으로 보통 얻는 감쇠량 [dB]
+pub const SINGLE_STAGE_ATTENUATION_DB: f64 = 35.0;
+
+/// 벤트 소음 계산 입력.
+#[derive(Debug, Clone)]
+pub struct VentInput {
+    /// 벤트 밸브 상류 압력 [bar abs]
+    pub upstream_pressure_bar_abs: f64,
+    pub stagnation: SteamStagnation,
+    /// 벤트 밸브 목(시트) 지름 [m]
+    pub valve_throat_diameter_m: f64,
+    /// 밸브 유량계수 Cd
+    pub discharge_coefficient: f64,
+    /// 벤트 배관(방출구) 내경 [m]
+    pub vent_pipe_id_m: f64,
+    /// 대기압 [bar abs]
+    pub atmospheric_pressure_bar_abs: f64,
+    /// 소음 평가 지점까지 거리 [m]
+    pub distance_m: f64,
+    /// 평가 지점 허용 소음 [dB(A)]
+    pub noise_limit_dba: f64,
+}
+
+/// 벤트 소음 계산 결과.
+#[derive(Debug, Clone)]
+pub struct VentResult {
+    /// 벤트 밸브 유량 [kg/h]
+    pub mass_flow_kg_per_h: f64,
+    pub valve_choked: bool,
+    /// 방출구 압력 [bar abs]. 출구가 초킹되면 대기압보다 높다.
+    pub exit_pressure_bar_abs: f64,
+    pub exit_velocity_m_per_s: f64,
+    pub exit_mach: f64,
+    pub exit_choked: bool,
+    /// 완전 팽창 분출 마하수
+    pub jet_mach: f64,
+    /// 음향 파워 레벨 [dB]
+    pub sound_power_db: f64,
+    /// 피크 주파수 [Hz]
+    pub peak_frequency_hz: f64,
+    /// 평가 거리의 소음 [dB(A)] (소음기 없음)
+    pub sound_pressure_dba: f64,
+    /// 소음기 필요 감쇠량 [dB]. 허용치 이하면 0.
+    pub required_attenuation_db: f64,
+    pub warnings: Vec<VentWarning>,
+}
+
+/// 벤트 소음 계산 경고.
+#[derive(Debug, Clone, PartialEq)]
+pub enum VentWarning {
+    /// 방출구 초킹
+    ExitChoked { exit_pressure_bar_abs: f64 },
+    /// 필요 감쇠량이 소음기 한 단을 넘음
+    AttenuationAboveOneStage { required_db: f64 },
+    /// 벤트 밸브가 초킹되지 않음
+    ValveNotChoked,
+}
+
+impl CalcWarning for VentWarning {
+    fn severity(&self) -> Severity {
+        match self {
+            VentWarning::ValveNotChoked => Severity::Info,
+            _ => Severity::Caution,
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            VentWarning::ExitChoked { .. } => "warning.vent.exit_choked",
+            VentWarning::AttenuationAboveOneStage { .. } => {
+                "warning.vent.attenuation_above_one_stage"
+            }
+            VentWarning::ValveNotChoked => "warning.vent.valve_not_choked",
+        }
+    }
+
+    fn args(&self) -> Vec<(&'static str, String)> {
+        match *self {
+            VentWarning::ExitChoked {
+                exit_pressure_bar_abs,
+            } => vec![("p", format!("{exit_pressure_bar_abs:.2}"))],
+            VentWarning::AttenuationAboveOneStage { required_db } => vec![
+                ("required", format!("{required_db:.0}")),
+                ("stage", format!("{SINGLE_STAGE_ATTENUATION_DB:.0}")),
+            ],
+            VentWarning::ValveNotChoked => Vec::new(),
+        }
+    }
+}
+
+impl std::fmt::Display for VentWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            VentWarning::ExitChoked {
+                exit_pressure_bar_abs,
+            } => write!(
+                f,
+                "방출구가 초킹되어 출구 압력이 {exit_pressure_bar_abs:.2} bar(a)입니다. 벤트 배관을 키우거나 확관 방출구를 검토하세요."
+            ),
+            VentWarning::AttenuationAboveOneStage { required_db } => write!(
+                f,
+                "필요 감쇠량 {required_db:.0} dB는 소음기 한 단(약 {SINGLE_STAGE_ATTENUATION_DB:.0} dB)을 넘습니다. 2단 소음기나 거리 확보를 검토하세요."
+            ),
+            VentWarning::ValveNotChoked => f.write_str(
+                "벤트 밸브가 초킹되지 않았습니다. 유량이 하류 배관 저항에 따라 달라질 수 있습니다.",
+            ),
+        }
+    }
+}
+
+/// 벤트 소음 계산 오류.
+#[derive(Debug, Clone)]
+pub enum VentSilencerError {
+    InvalidInput(&'static str),
+    Flow(CriticalFlowError),
+    If97(String),
+}
+
+impl std::fmt::Display for VentSilencerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VentSilencerError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            VentSilencerError::Flow(e) => write!(f, "벤트 밸브 유량 계산 오류: {e}"),
+            VentSilencerError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for VentSilencerError {}
+
+impl From<CriticalFlowError> for VentSilencerError {
+    fn from(value: CriticalFlowError) -> Self {
+        VentSilencerError::Flow(value)
+    }
+}
+
+/// 압력 p와 유속 v에서의 (밀도, 음속). 정체 엔탈피 h0에서 운동 에너지를 뺀 엔탈피를 쓴다.
+fn state_at(p_bar_abs: f64, h0: f64, v: f64) -> Result<(f64, f64), VentSilencerError> {
+    let (spec_v, c, _) = if97::flow_props_from_ph(p_bar_abs, h0 - 0.5 * v * v)
+        .map_err(|e| VentSilencerError::If97(e.into()))?;
+    Ok((1.0 / spec_v, c))
+}
+
+/// 압력 p에서 M = 1인 (유속, 질량유속). 음속과 엔탈피가 서로 맞을 때까지 반복한다.
+fn sonic_state(p_bar_abs: f64, h0: f64) -> Result<(f64, f64), VentSilencerError> {
+    let mut v = 0.0;
+    let mut rho = 0.0;
+    for _ in 0..50 {
+        let (r, c) = state_at(p_bar_abs, h0, v)?;
+        rho = r;
+        if (c - v).abs() < 1e-6 * c {
+            break;
+        }
+        v = c;
+    }
+    Ok((v, rho * v))
+}
+
+/// 압력 p에서 질량유속 `flux`를 흘리는 아음속 유속 [m/s] (이분법).
+fn subsonic_velocity(
+    p_bar_abs: f64,
+    h0: f64,
+    flux: f64,
+    sonic_v: f64,
+) -> Result<f64, VentSilencerError> {
+    let (mut lo, mut hi) = (0.0, sonic_v);
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        let (rho, _) = state_at(p_bar_abs, h0, mid)?;
+        if rho * mid < flux {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(0.5 * (lo + hi))
+}
+
+/// A 보정 [dB] (IEC 61672).
+pub fn a_weighting_db(frequency_hz: f64) -> f64 {
+    let f2 = frequency_hz * frequency_hz;
+    let ra = 12_194.0_f64.powi(2) * f2 * f2
+        / ((f2 + 20.6_f64.powi(2))
+            * ((f2 + 107.7_f64.powi(2)) * (f2 + 737.9_f64.powi(2))).sqrt()
+            * (f2 + 12_194.0_f64.powi(2)));
+    20.0 * ra.log10() + 2.0
+}
+
+/// 반구 확산으로 거리 r [m]의 음압 레벨 [dB].
+pub fn sound_pressure_at_distance_db(sound_power_db: f64, distance_m: f64) -> f64 {
+    sound_power_db - 10.0 * (2.0 * std::f64::consts::PI * distance_m * distance_m).log10()
+}
+
+/// 벤트 밸브 유량, 방출구 유속, 분출 소음과 소음기 필요 감쇠량을 구한다.
+pub fn vent_silencer(input: &VentInput) -> Result<VentResult, VentSilencerError> {
+    let p0 = input.upstream_pressure_bar_abs;
+    let p_atm = input.atmospheric_pressure_bar_abs;
+    if p_atm <= 0.0 || p0 <= p_atm {
+        return Err(VentSilencerError::InvalidInput(
+            "상류 압력은 대기압보다 높아야 합니다.",
+        ));
+    }
+    if input.valve_throat_diameter_m <= 0.0 || input.vent_pipe_id_m <= 0.0 {
+        return Err(VentSilencerError::InvalidInput(
+            "밸브 목 지름과 벤트 배관 내경은 0보다 커야 합니다.",
+        ));
+    }
+    if input.distance_m <= 0.0 {
+        return Err(VentSilencerError::InvalidInput(
+            "평가 거리는 0보다 커야 합니다.",
+        ));
+    }
+
+    let throat_area =
+        std::f64::consts::PI * input.valve_throat_diameter_m * input.valve_throat_diameter_m / 4.0;
+    let nozzle = critical_flow::steam_nozzle_flow(
+        p0,
+        input.stagnation,
+        p_atm,
+        throat_area,
+        input.discharge_coefficient,
+    )?;
+    let mass_flow_kg_s = nozzle.mass_flow_kg_per_h / 3600.0;
+    let (h0, _) = critical_flow::stagnation(p0, input.stagnation)?;
+
+    let pipe_area = std::f64::consts::PI * input.vent_pipe_id_m * input.vent_pipe_id_m / 4.0;
+    let flux = mass_flow_kg_s / pipe_area;
+    let (sonic_v_atm, max_flux_atm) = sonic_state(p_atm, h0)?;
+    let (exit_p, exit_v, exit_c) = if flux < max_flux_atm {
+        let v = subsonic_velocity(p_atm, h0, flux, sonic_v_atm)?;
+        let (_, c) = state_at(p_atm, h0, v)?;
+        (p_atm, v, c)
+    } else {
+        // 출구 초킹: 음속 질량유속이 flux가 되는 출구 압력을 찾는다 (압력에 단조 증가)
+        let (mut lo, mut hi) = (p_atm, p0);
+        for _ in 0..60 {
+            let mid = 0.5 * (lo + hi);
+            if sonic_state(mid, h0)?.1 < flux {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        let (v, _) = sonic_state(hi, h0)?;
+        (hi, v, v)
+    };
+    let exit_mach = exit_v / exit_c;
+    let exit_choked = exit_p > p_atm;
+
+    // 완전 팽창 분출 마하수: 초킹 출구는 출구/대기 압력비로 등엔트로피 팽창시킨다
+    let k = STEAM_K;
+    let jet_mach = if exit_choked {
+        let total_ratio = (exit_p / p_atm).powf((k - 1.0) / k) * (1.0 + 0.5 * (k - 1.0));
+        (2.0 / (k - 1.0) * (total_ratio - 1.0)).sqrt()
+    } else {
+        exit_mach
+    };
+    let jet_velocity = jet_mach * exit_c;
+    let efficiency = (ACOUSTIC_EFFICIENCY_AT_MACH1 * jet_mach.powi(5)).min(MAX_ACOUSTIC_EFFICIENCY);
+    let acoustic_power_w = efficiency * 0.5 * mass_flow_kg_s * jet_velocity * jet_velocity;
+    let sound_power_db = 10.0 * (acoustic_power_w / REFERENCE_POWER_W).log10();
+    let peak_frequency_hz = PEAK_STROUHAL * jet_velocity / input.vent_pipe_id_m;
+    let sound_pressure_dba = sound_pressure_at_distance_db(sound_power_db, input.distance_m)
+        + a_weighting_db(peak_frequency_hz);
+    let required_attenuation_db = (sound_pressure_dba - input.noise_limit_dba).max(0.0);
+
+    let mut warnings = Vec::new();
+    if exit_choked {
+        warnings.push(VentWarning::ExitChoked {
+            exit_pressure_bar_abs: exit_p,
+        });
+    }
+    if required_attenuation_db > SINGLE_STAGE_ATTENUATION_DB {
+        warnings.push(VentWarning::AttenuationAboveOneStage {
+            required_db: required_attenuation_db,
+        });
+    }
+    if !nozzle.choked {
+        warnings.push(VentWarning::ValveNotChoked);
+    }
+
+    Ok(VentResult {
+        mass_flow_kg_per_h: nozzle.mass_flow_kg_per_h,
+        valve_choked: nozzle.choked,
+        exit_pressure_bar_abs: exit_p,
+        exit_velocity_m_per_s: exit_v,
+        exit_mach,
+        exit_choked,
+        jet_mach,
+        sound_power_db,
+        peak_frequency_hz,
+        sound_pressure_dba,
+        required_attenuation_db,
+        warnings,
+    })
+}
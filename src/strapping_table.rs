@@ -0,0 +1,182 @@
+//! 탱크/용기 검량표(strapping table): 액위별 용적표와 CSV 출력.
+//! 용적은 `vessel_geometry`에서 구하고, 측정 온도의 액체 용적을 기준 온도(보통 15 °C) 용적으로
+//! 환산한 값을 함께 낸다. 용기 벽의 열팽창은 무시한다. 용적 보정 계수 VCF = V_기준 / V_측정:
+//! - 물: IF97 밀도비 ρ(T)/ρ(T_기준)
+//! - 그 밖의 액체: 체적 팽창 계수 β로 1 / (1 + β·(T − T_기준))
+
+use crate::steam::if97;
+use crate::vessel_geometry::{Vessel, VesselGeometryError};
+
+/// 표 한 장의 최대 행 수
+pub const MAX_ROWS: usize = 100_000;
+
+/// 액체 열팽창 보정 방법.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LiquidExpansion {
+    /// 물 (IF97 밀도, 압력 bar abs)
+    Water { pressure_bar_abs: f64 },
+    /// 체적 팽창 계수 β [1/K]
+    Coefficient { beta_per_k: f64 },
+}
+
+/// 검량표 입력.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrappingInput {
+    pub vessel: Vessel,
+    /// 액위 간격 [m]
+    pub increment_m: f64,
+    /// 액체 온도 [°C]
+    pub liquid_temp_c: f64,
+    /// 기준 온도 [°C]
+    pub reference_temp_c: f64,
+    pub expansion: LiquidExpansion,
+}
+
+/// 검량표 한 행.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StrappingRow {
+    pub level_m: f64,
+    /// 측정 온도의 용적 [m³]
+    pub volume_m3: f64,
+    /// 기준 온도로 환산한 용적 [m³]
+    pub corrected_volume_m3: f64,
+    /// 전체 용적 대비 [%]
+    pub percent_full: f64,
+}
+
+/// 검량표.
+#[derive(Debug, Clone)]
+pub struct StrappingTable {
+    pub rows: Vec<StrappingRow>,
+    /// 전체 내용적 [m³]
+    pub total_volume_m3: f64,
+    /// 용적 보정 계수 V_기준 / V_측정
+    pub volume_correction_factor: f64,
+    pub liquid_temp_c: f64,
+    pub reference_temp_c: f64,
+}
+
+/// 검량표 오류.
+#[derive(Debug, Clone)]
+pub enum StrappingError {
+    InvalidInput(&'static str),
+    Geometry(VesselGeometryError),
+    If97(String),
+}
+
+impl std::fmt::Display for StrappingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrappingError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+            StrappingError::Geometry(e) => write!(f, "용기 형상 오류: {e}"),
+            StrappingError::If97(msg) => write!(f, "IF97 계산 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for StrappingError {}
+
+impl From<VesselGeometryError> for StrappingError {
+    fn from(value: VesselGeometryError) -> Self {
+        StrappingError::Geometry(value)
+    }
+}
+
+/// 측정 온도 용적을 기준 온도 용적으로 바꾸는 계수 V_기준 / V_측정.
+pub fn volume_correction_factor(
+    expansion: LiquidExpansion,
+    liquid_temp_c: f64,
+    reference_temp_c: f64,
+) -> Result<f64, StrappingError> {
+    match expansion {
+        LiquidExpansion::Water { pressure_bar_abs } => {
+            let v = |t: f64| {
+                if97::region_props(pressure_bar_abs, t)
+                    .map(|(_, v, _)| v)
+                    .map_err(|e| StrappingError::If97(e.into()))
+            };
+            let p_sat =
+                if97::saturation_pressure_bar_abs_from_temp_c(liquid_temp_c.max(reference_temp_c))
+                    .map_err(|e| StrappingError::If97(e.into()))?;
+            if pressure_bar_abs <= p_sat {
+                return Err(StrappingError::InvalidInput(
+                    "물이 액체로 있으려면 압력이 온도의 포화 압력보다 높아야 합니다.",
+                ));
+            }
+            // ρ(T)/ρ(T_기준) = v(T_기준)/v(T)
+            Ok(v(reference_temp_c)? / v(liquid_temp_c)?)
+        }
+        LiquidExpansion::Coefficient { beta_per_k } => {
+            if !(0.0..0.01).contains(&beta_per_k) {
+                return Err(StrappingError::InvalidInput(
+                    "체적 팽창 계수는 0 이상 0.01 1/K 미만이어야 합니다.",
+                ));
+            }
+            let denom = 1.0 + beta_per_k * (liquid_temp_c - reference_temp_c);
+            if denom <= 0.0 {
+                return Err(StrappingError::InvalidInput(
+                    "온도 차가 너무 커서 팽창 보정을 할 수 없습니다.",
+                ));
+            }
+            Ok(1.0 / denom)
+        }
+    }
+}
+
+/// 바닥부터 꼭대기까지 `increment_m` 간격의 검량표를 만든다. 마지막 행은 항상 꼭대기다.
+pub fn strapping_table(input: &StrappingInput) -> Result<StrappingTable, StrappingError> {
+    input.vessel.validate()?;
+    if input.increment_m <= 0.0 {
+        return Err(StrappingError::InvalidInput(
+            "액위 간격은 0보다 커야 합니다.",
+        ));
+    }
+    let height = input.vessel.height_m();
+    let steps = (height / input.increment_m - 1e-9).ceil() as usize;
+    if steps + 1 > MAX_ROWS {
+        return Err(StrappingError::InvalidInput(
+            "행 수가 너무 많습니다. 액위 간격을 늘리세요.",
+        ));
+    }
+    let vcf =
+        volume_correction_factor(input.expansion, input.liquid_temp_c, input.reference_temp_c)?;
+    let total = input.vessel.volume_m3();
+    let rows = (0..=steps)
+        .map(|i| {
+            let level_m = (i as f64 * input.increment_m).min(height);
+            let volume_m3 = input.vessel.volume_at_level_m3(level_m);
+            StrappingRow {
+                level_m,
+                volume_m3,
+                corrected_volume_m3: volume_m3 * vcf,
+                percent_full: volume_m3 / total * 100.0,
+            }
+        })
+        .collect();
+    Ok(StrappingTable {
+        rows,
+        total_volume_m3: total,
+        volume_correction_factor: vcf,
+        liquid_temp_c: input.liquid_temp_c,
+        reference_temp_c: input.reference_temp_c,
+    })
+}
+
+/// 검량표를 CSV로 쓴다. 액위는 mm, 용적은 m³.
+pub fn strapping_table_csv(table: &StrappingTable) -> String {
+    let mut out = format!(
+        "level_mm,volume_m3_at_{t:.1}c,volume_m3_at_{r:.1}c,percent_full\n",
+        t = table.liquid_temp_c,
+        r = table.reference_temp_c
+    );
+    for row in &table.rows {
+        out.push_str(&format!(
+            "{:.1},{:.4},{:.4},{:.2}\n",
+            row.level_m * 1000.0,
+            row.volume_m3,
+            row.corrected_volume_m3,
+            row.percent_full
+        ));
+    }
+    out
+}
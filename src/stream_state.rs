@@ -0,0 +1,204 @@
+//! 계산 카드 사이에서 주고받는 흐름 상태 ("결과 보내기…").
+//! 한 카드의 결과(압력·온도·밀도·엔탈피·유량)를 담아 두었다가 다른 카드의 입력으로 옮긴다.
+//! 대상 카드는 받을 수 있는 항목만 가져가며, 빠진 밀도·엔탈피는 IF97로 채울 수 있다.
+
+use crate::steam::if97;
+use crate::steam::steam_tables::SteamState;
+
+/// 흐름 상태의 항목.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamField {
+    Pressure,
+    Temperature,
+    Density,
+    Enthalpy,
+    MassFlow,
+    /// 질량 유량과 밀도로 구한 체적 유량
+    VolumeFlow,
+}
+
+/// 흐름 상태를 받을 수 있는 카드.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamTarget {
+    /// 유속 기준 배관 구경 선정
+    PipeSizing,
+    /// 배관 압력 손실 (Darcy-Weisbach)
+    PipeLoss,
+    /// 밸브 Cv/Kv 산정
+    ValveSizing,
+    /// 라인 케이스 교차 점검의 공급 조건
+    LineCase,
+}
+
+impl StreamTarget {
+    pub const ALL: [StreamTarget; 4] = [
+        StreamTarget::PipeSizing,
+        StreamTarget::PipeLoss,
+        StreamTarget::ValveSizing,
+        StreamTarget::LineCase,
+    ];
+
+    pub fn i18n_key(self) -> &'static str {
+        match self {
+            StreamTarget::PipeSizing => "stream_target.pipe_sizing",
+            StreamTarget::PipeLoss => "stream_target.pipe_loss",
+            StreamTarget::ValveSizing => "stream_target.valve_sizing",
+            StreamTarget::LineCase => "stream_target.line_case",
+        }
+    }
+
+    /// 언어팩이 없을 때 쓰는 이름.
+    pub fn label(self) -> &'static str {
+        match self {
+            StreamTarget::PipeSizing => "Pipe sizing",
+            StreamTarget::PipeLoss => "Pipe pressure loss",
+            StreamTarget::ValveSizing => "Valve Cv/Kv sizing",
+            StreamTarget::LineCase => "Line case (source)",
+        }
+    }
+
+    /// 카드가 입력으로 받는 항목.
+    pub fn fields(self) -> &'static [StreamField] {
+        match self {
+            StreamTarget::PipeSizing => &[
+                StreamField::MassFlow,
+                StreamField::Pressure,
+                StreamField::Temperature,
+            ],
+            StreamTarget::PipeLoss => &[
+                StreamField::MassFlow,
+                StreamField::Pressure,
+                StreamField::Temperature,
+                StreamField::Density,
+            ],
+            StreamTarget::ValveSizing => &[
+                StreamField::VolumeFlow,
+                StreamField::Pressure,
+                StreamField::Density,
+            ],
+            StreamTarget::LineCase => &[
+                StreamField::MassFlow,
+                StreamField::Pressure,
+                StreamField::Temperature,
+            ],
+        }
+    }
+}
+
+/// 카드 사이에서 옮기는 흐름 상태. 모르는 항목은 `None`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StreamState {
+    /// 보낸 카드 이름의 i18n 키
+    pub source_key: &'static str,
+    /// 압력 [bar abs]
+    pub pressure_bar_abs: Option<f64>,
+    /// 온도 [°C]
+    pub temp_c: Option<f64>,
+    /// 밀도 [kg/m³]
+    pub density_kg_per_m3: Option<f64>,
+    /// 비엔탈피 [kJ/kg]
+    pub enthalpy_kj_per_kg: Option<f64>,
+    /// 질량 유량 [kg/h]
+    pub mass_flow_kg_per_h: Option<f64>,
+    /// 습증기 건도 (포화 상태일 때)
+    pub quality: Option<f64>,
+}
+
+impl StreamState {
+    pub fn new(source_key: &'static str) -> Self {
+        Self {
+            source_key,
+            ..Self::default()
+        }
+    }
+
+    /// 증기표 결과에서 만든다. 과열 엔탈피가 없으면 포화 증기(x = 1)로 본다.
+    pub fn from_steam_state(source_key: &'static str, s: &SteamState) -> Self {
+        match s.superheated_enthalpy_kj_per_kg {
+            Some(h) => Self {
+                pressure_bar_abs: Some(s.pressure_bar),
+                temp_c: Some(s.temperature_c),
+                enthalpy_kj_per_kg: Some(h),
+                ..Self::new(source_key)
+            }
+            .complete_from_if97(),
+            None => Self {
+                pressure_bar_abs: Some(s.pressure_bar),
+                temp_c: Some(s.saturation_temperature_c),
+                density_kg_per_m3: (s.saturation_specific_volume > 0.0)
+                    .then(|| 1.0 / s.saturation_specific_volume),
+                enthalpy_kj_per_kg: Some(s.saturation_enthalpy_kj_per_kg),
+                quality: Some(1.0),
+                ..Self::new(source_key)
+            },
+        }
+    }
+
+    pub fn with_mass_flow(mut self, mass_flow_kg_per_h: f64) -> Self {
+        self.mass_flow_kg_per_h = Some(mass_flow_kg_per_h);
+        self
+    }
+
+    /// 단상(건도 없음)이고 압력·온도를 알면 빠진 밀도·엔탈피를 IF97로 채운다.
+    /// 범위를 벗어나면 그대로 둔다.
+    pub fn complete_from_if97(mut self) -> Self {
+        if let (Some(p), Some(t), None) = (self.pressure_bar_abs, self.temp_c, self.quality) {
+            if let Ok((h, v, _)) = if97::region_props(p, t) {
+                self.enthalpy_kj_per_kg.get_or_insert(h / 1000.0);
+                if v > 0.0 {
+                    self.density_kg_per_m3.get_or_insert(1.0 / v);
+                }
+            }
+        }
+        self
+    }
+
+    /// 체적 유량 [m³/h]
+    pub fn volume_flow_m3_per_h(&self) -> Option<f64> {
+        match (self.mass_flow_kg_per_h, self.density_kg_per_m3) {
+            (Some(m), Some(rho)) if rho > 0.0 => Some(m / rho),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, field: StreamField) -> Option<f64> {
+        match field {
+            StreamField::Pressure => self.pressure_bar_abs,
+            StreamField::Temperature => self.temp_c,
+            StreamField::Density => self.density_kg_per_m3,
+            StreamField::Enthalpy => self.enthalpy_kj_per_kg,
+            StreamField::MassFlow => self.mass_flow_kg_per_h,
+            StreamField::VolumeFlow => self.volume_flow_m3_per_h(),
+        }
+    }
+
+    /// 대상 카드로 옮겨질 항목과 값.
+    pub fn transfer(&self, target: StreamTarget) -> Vec<(StreamField, f64)> {
+        target
+            .fields()
+            .iter()
+            .filter_map(|&f| self.get(f).map(|v| (f, v)))
+            .collect()
+    }
+}
+
+impl std::fmt::Display for StreamState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts: Vec<String> = [
+            self.pressure_bar_abs.map(|p| format!("P={p:.3} bar(a)")),
+            self.temp_c.map(|t| format!("T={t:.1} °C")),
+            self.density_kg_per_m3.map(|r| format!("ρ={r:.3} kg/m³")),
+            self.enthalpy_kj_per_kg.map(|h| format!("h={h:.1} kJ/kg")),
+            self.quality.map(|x| format!("x={x:.3}")),
+            self.mass_flow_kg_per_h.map(|m| format!("ṁ={m:.1} kg/h")),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        if parts.is_empty() {
+            write!(f, "(비어 있음)")
+        } else {
+            write!(f, "{}", parts.join(", "))
+        }
+    }
+}
@@ -0,0 +1,280 @@
+//! 민감도(What-if) 스윕 엔진.
+//! 임의의 계산 입력 구조체에서 파라미터 하나를 범위/간격으로 바꿔 가며 계산하고,
+//! 결과를 표(행=파라미터 값, 열=출력 항목)로 돌려준다. GUI 카드의 Sweep 버튼이 이 모듈을 사용한다.
+
+use crate::cooling::condenser::{self, CondenserInput, CondenserResult};
+use crate::parallel;
+use crate::steam::critical_flow::SteamStagnation;
+use crate::steam::steam_piping::{self, PressureLossInput, PressureLossResult};
+use crate::steam::steam_valves;
+
+/// 한 번의 스윕에서 허용하는 최대 계산 점 수.
+pub const MAX_SWEEP_POINTS: usize = 1000;
+
+/// 스윕 설정 오류.
+#[derive(Debug)]
+pub enum SweepError {
+    /// 범위/간격이 잘못된 경우
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for SweepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SweepError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SweepError {}
+
+/// 스윕 범위. `start`에서 `end`까지 `step` 간격으로 값을 만든다(끝 값 포함).
+#[derive(Debug, Clone, Copy)]
+pub struct SweepRange {
+    pub start: f64,
+    pub end: f64,
+    pub step: f64,
+}
+
+impl SweepRange {
+    pub fn new(start: f64, end: f64, step: f64) -> Self {
+        Self { start, end, step }
+    }
+
+    /// 계산할 파라미터 값 목록. `end < start`이면 내림차순으로 만든다.
+    pub fn points(&self) -> Result<Vec<f64>, SweepError> {
+        if !self.start.is_finite() || !self.end.is_finite() || !self.step.is_finite() {
+            return Err(SweepError::InvalidInput(
+                "범위와 간격은 유한한 값이어야 합니다.",
+            ));
+        }
+        if self.step <= 0.0 {
+            return Err(SweepError::InvalidInput("간격은 0보다 커야 합니다."));
+        }
+        let span = (self.end - self.start).abs();
+        // 부동소수 누적 오차로 끝 값이 빠지지 않도록 약간의 여유를 둔다.
+        let n = (span / self.step + 1e-9).floor() as usize + 1;
+        if n > MAX_SWEEP_POINTS {
+            return Err(SweepError::InvalidInput(
+                "계산 점이 너무 많습니다. 간격을 늘려 주세요.",
+            ));
+        }
+        let dir = if self.end >= self.start { 1.0 } else { -1.0 };
+        let mut pts: Vec<f64> = (0..n)
+            .map(|i| self.start + dir * self.step * i as f64)
+            .collect();
+        if let Some(last) = pts.last() {
+            if (self.end - last).abs() > 1e-9 * self.step.max(1.0) && span > 0.0 {
+                pts.push(self.end);
+            }
+        }
+        Ok(pts)
+    }
+}
+
+/// 결과 구조체에서 표의 한 열을 뽑아내는 정의.
+pub struct SweepColumn<R> {
+    pub label: &'static str,
+    pub value: fn(&R) -> f64,
+}
+
+impl<R> SweepColumn<R> {
+    pub const fn new(label: &'static str, value: fn(&R) -> f64) -> Self {
+        Self { label, value }
+    }
+}
+
+/// 스윕 표의 한 행. 계산 실패 시 `values`는 `Err(메시지)`가 된다.
+#[derive(Debug, Clone)]
+pub struct SweepRow {
+    pub parameter: f64,
+    pub values: Result<Vec<f64>, String>,
+}
+
+/// 스윕 결과 표.
+#[derive(Debug, Clone)]
+pub struct SweepTable {
+    /// 변화시킨 파라미터 이름
+    pub parameter: String,
+    /// 출력 열 이름
+    pub columns: Vec<String>,
+    pub rows: Vec<SweepRow>,
+}
+
+impl SweepTable {
+    /// 지정 열의 (파라미터, 값) 점 목록. 실패한 행과 NaN은 제외한다.
+    pub fn series(&self, column: usize) -> Vec<[f64; 2]> {
+        self.rows
+            .iter()
+            .filter_map(|row| {
+                let v = *row.values.as_ref().ok()?.get(column)?;
+                v.is_finite().then_some([row.parameter, v])
+            })
+            .collect()
+    }
+
+    /// 계산에 실패한 행 수.
+    pub fn failed_count(&self) -> usize {
+        self.rows.iter().filter(|r| r.values.is_err()).count()
+    }
+}
+
+/// 기준 입력 `base`를 복제해 `apply`로 파라미터를 바꾼 뒤 `calc`를 반복 실행한다.
+/// 개별 점의 계산 오류는 표의 해당 행에 기록하고 스윕은 계속한다.
+/// 점들은 서로 독립이므로 `parallel` 기능이 켜져 있으면 나눠 계산한다.
+pub fn run_sweep<I, R, E>(
+    base: &I,
+    parameter: &str,
+    range: SweepRange,
+    apply: impl Fn(&mut I, f64) + Sync + Send,
+    calc: impl Fn(I) -> Result<R, E> + Sync + Send,
+    columns: &[SweepColumn<R>],
+) -> Result<SweepTable, SweepError>
+where
+    I: Clone + Sync,
+    E: std::fmt::Display,
+{
+    if columns.is_empty() {
+        return Err(SweepError::InvalidInput("출력 열이 하나 이상 필요합니다."));
+    }
+    let points = range.points()?;
+    let rows = parallel::map(&points, |&x| {
+        let mut input = base.clone();
+        apply(&mut input, x);
+        let values = calc(input)
+            .map(|r| columns.iter().map(|c| (c.value)(&r)).collect())
+            .map_err(|e| e.to_string());
+        SweepRow {
+            parameter: x,
+            values,
+        }
+    });
+    Ok(SweepTable {
+        parameter: parameter.to_string(),
+        columns: columns.iter().map(|c| c.label.to_string()).collect(),
+        rows,
+    })
+}
+
+/// 압력손실 스윕의 기본 출력 열.
+pub const PRESSURE_LOSS_COLUMNS: &[SweepColumn<PressureLossResult>] = &[
+    SweepColumn::new("ΔP [bar]", |r| r.pressure_drop_bar),
+    SweepColumn::new("v [m/s]", |r| r.velocity_m_per_s),
+    SweepColumn::new("Re", |r| r.reynolds_number),
+    SweepColumn::new("Mach", |r| r.mach),
+];
+
+/// 배관 내경에 따른 압력손실(ΔP vs D).
+pub fn pressure_loss_vs_diameter(
+    base: &PressureLossInput,
+    range: SweepRange,
+) -> Result<SweepTable, SweepError> {
+    run_sweep(
+        base,
+        "D [m]",
+        range,
+        |i, x| i.diameter_m = x,
+        steam_piping::pressure_loss,
+        PRESSURE_LOSS_COLUMNS,
+    )
+}
+
+/// 질량 유량에 따른 압력손실(ΔP vs ṁ).
+pub fn pressure_loss_vs_mass_flow(
+    base: &PressureLossInput,
+    range: SweepRange,
+) -> Result<SweepTable, SweepError> {
+    run_sweep(
+        base,
+        "ṁ [kg/h]",
+        range,
+        |i, x| i.mass_flow_kg_per_h = x,
+        steam_piping::pressure_loss,
+        PRESSURE_LOSS_COLUMNS,
+    )
+}
+
+/// 요구 Kv 스윕의 출력 열(Kv, Cv).
+pub const VALVE_KV_COLUMNS: &[SweepColumn<f64>] = &[
+    SweepColumn::new("Kv", |kv| *kv),
+    SweepColumn::new("Cv", |kv| steam_valves::cv_from_kv(*kv)),
+];
+
+/// 차압에 따른 요구 Kv/Cv (유량 m³/h, 밀도 kg/m³ 고정).
+pub fn required_cv_vs_dp(
+    volumetric_flow_m3_per_h: f64,
+    fluid_density_kg_m3: f64,
+    range: SweepRange,
+) -> Result<SweepTable, SweepError> {
+    run_sweep(
+        &(volumetric_flow_m3_per_h, 0.0, fluid_density_kg_m3),
+        "ΔP [bar]",
+        range,
+        |i, x| i.1 = x,
+        |(q, dp, rho)| steam_valves::required_kv(q, dp, rho),
+        VALVE_KV_COLUMNS,
+    )
+}
+
+/// 차압에 따른 통과 유량(Kv 고정). `upstream_bar_abs`가 있으면 건포화 증기로 보고
+/// IF97 임계 차압에서 유량을 제한한다.
+pub fn flow_from_kv_vs_dp(
+    kv: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: Option<f64>,
+    range: SweepRange,
+) -> Result<SweepTable, SweepError> {
+    run_sweep(
+        &(kv, 0.0, fluid_density_kg_m3, upstream_bar_abs),
+        "ΔP [bar]",
+        range,
+        |i, x| i.1 = x,
+        |(kv, dp, rho, up)| {
+            let q = match up {
+                Some(p1) => {
+                    steam_valves::steam_flow_from_kv(
+                        kv,
+                        dp,
+                        rho,
+                        p1,
+                        SteamStagnation::Quality(1.0),
+                    )?
+                    .volumetric_flow_m3_per_h
+                }
+                None => steam_valves::flow_from_kv(kv, dp, rho)?,
+            };
+            Ok::<_, steam_valves::ValveCalcError>((q, q * rho))
+        },
+        &[
+            SweepColumn::new("Q [m³/h]", |r: &(f64, f64)| r.0),
+            SweepColumn::new("ṁ [kg/h]", |r: &(f64, f64)| r.1),
+        ],
+    )
+}
+
+/// 콘덴서 스윕의 기본 출력 열.
+pub const CONDENSER_COLUMNS: &[SweepColumn<CondenserResult>] = &[
+    SweepColumn::new("Tsat [°C]", |r| r.condensing_temp_c),
+    SweepColumn::new("Psat [bar(a)]", |r| r.condensing_pressure_bar_abs),
+    SweepColumn::new("LMTD [K]", |r| r.lmtd_k),
+    SweepColumn::new("Q [kW]", |r| r.heat_duty_kw),
+];
+
+/// 냉각수 입구 온도에 따른 콘덴서 상태. 냉각수 온도 상승폭(출구-입구)은 기준 입력 값을 유지한다.
+pub fn condenser_vs_cw_inlet(
+    base: &CondenserInput,
+    range: SweepRange,
+) -> Result<SweepTable, SweepError> {
+    let rise = base.cw_outlet_temp_c - base.cw_inlet_temp_c;
+    run_sweep(
+        base,
+        "CW in [°C]",
+        range,
+        move |i, x| {
+            i.cw_inlet_temp_c = x;
+            i.cw_outlet_temp_c = x + rise;
+        },
+        condenser::compute_condenser,
+        CONDENSER_COLUMNS,
+    )
+}
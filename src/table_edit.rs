@@ -0,0 +1,117 @@
+//! 숫자 표 입력(스트로크-Cv 표 등)의 붙여넣기와 복사.
+//! 엑셀이나 CSV에서 복사한 여러 열 표를 열 단위 숫자 목록으로 읽고,
+//! 입력 표를 다시 엑셀에 붙여 넣을 수 있는 TSV로 낸다.
+//!
+//! - 열 구분자는 줄마다 탭 → 세미콜론 → 쉼표 → 공백 순으로 찾는다.
+//!   탭/세미콜론 구분 표는 소수점 쉼표(`1,5`)도 읽는다.
+//! - 첫 줄이 숫자가 아니면 머리글로 보고 건너뛴다. 빈 줄은 무시한다.
+//! - 필요한 열보다 많은 열은 뒤쪽을 버린다. 칸 끝의 `%`와 따옴표는 떼어 낸다.
+
+/// 붙여넣기 표 오류. 줄 번호는 1부터 센다.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TablePasteError {
+    /// 숫자 행이 없다
+    Empty,
+    /// 열이 모자란 행
+    MissingColumns {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// 숫자가 아닌 칸
+    NotNumber { line: usize, cell: String },
+}
+
+impl std::fmt::Display for TablePasteError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TablePasteError::Empty => write!(f, "붙여 넣은 내용에 숫자 행이 없습니다."),
+            TablePasteError::MissingColumns {
+                line,
+                expected,
+                found,
+            } => write!(f, "{line}번째 줄: {expected}열이 필요한데 {found}열입니다."),
+            TablePasteError::NotNumber { line, cell } => {
+                write!(f, "{line}번째 줄: '{cell}'은(는) 숫자가 아닙니다.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TablePasteError {}
+
+/// 한 줄을 칸으로 나눈다. 두 번째 값은 소수점 쉼표를 허용하는지 여부.
+fn split_cells(line: &str) -> (Vec<&str>, bool) {
+    if line.contains('\t') {
+        (line.split('\t').collect(), true)
+    } else if line.contains(';') {
+        (line.split(';').collect(), true)
+    } else if line.contains(',') {
+        (line.split(',').collect(), false)
+    } else {
+        (line.split_whitespace().collect(), false)
+    }
+}
+
+fn parse_cell(cell: &str, decimal_comma: bool) -> Option<f64> {
+    let cell = cell.trim().trim_matches('"').trim();
+    let cell = cell.strip_suffix('%').unwrap_or(cell).trim();
+    if decimal_comma {
+        cell.replace(',', ".").parse().ok()
+    } else {
+        cell.parse().ok()
+    }
+}
+
+/// 붙여 넣은 텍스트를 `columns`개 열의 숫자 목록으로 읽는다 (열 순서대로).
+pub fn parse_table(text: &str, columns: usize) -> Result<Vec<Vec<f64>>, TablePasteError> {
+    let mut table = vec![Vec::new(); columns];
+    let mut first = true;
+    for (index, line) in text.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let line_no = index + 1;
+        let (cells, decimal_comma) = split_cells(line);
+        let cells: Vec<&str> = cells.into_iter().take(columns).collect();
+        let values: Vec<Option<f64>> = cells.iter().map(|c| parse_cell(c, decimal_comma)).collect();
+        let header = first && values.iter().all(Option::is_none);
+        first = false;
+        if header {
+            continue;
+        }
+        if cells.len() < columns {
+            return Err(TablePasteError::MissingColumns {
+                line: line_no,
+                expected: columns,
+                found: cells.len(),
+            });
+        }
+        for ((column, value), cell) in table.iter_mut().zip(values).zip(&cells) {
+            column.push(value.ok_or_else(|| TablePasteError::NotNumber {
+                line: line_no,
+                cell: cell.trim().to_string(),
+            })?);
+        }
+    }
+    if table.iter().all(Vec::is_empty) {
+        return Err(TablePasteError::Empty);
+    }
+    Ok(table)
+}
+
+/// 열 목록을 머리글 한 줄과 함께 TSV로 낸다. 열 길이가 다르면 짧은 열 기준으로 자른다.
+pub fn table_to_tsv(headers: &[&str], columns: &[&[f64]]) -> String {
+    let rows = columns.iter().map(|c| c.len()).min().unwrap_or(0);
+    let mut lines = vec![headers.join("\t")];
+    for i in 0..rows {
+        lines.push(
+            columns
+                .iter()
+                .map(|c| c[i].to_string())
+                .collect::<Vec<_>>()
+                .join("\t"),
+        );
+    }
+    lines.join("\n")
+}
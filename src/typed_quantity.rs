@@ -0,0 +1,500 @@
+//! 단위 실수를 컴파일 시점에 잡기 위한 강타입 물리량 래퍼.
+//! 내부 값은 SI(Pa abs, K, kg/s, m³/s, kg/m³, m, m/s)로 보관하고,
+//! 생성자는 지원 단위에서 변환하며 접근자는 SI 또는 공학 단위로 돌려준다.
+//!
+//! 이 타입들은 `quantity` 모듈에 함께 두는 것이 맞지만, `lib.rs`가 선언한 `quantity.rs`가 이 저장소에
+//! 없어 별도 모듈로 둔다. `quantity.rs`를 되살리면 그쪽으로 옮기고 이 모듈은 재노출만 남긴다.
+
+use crate::conversion::{self, ConversionError, PressureMode};
+use crate::quantity::QuantityKind;
+use crate::units::{self, PressureUnit, TemperatureUnit};
+
+const ATM_PA: f64 = 101_325.0;
+
+/// 절대 압력. 내부 값은 Pa(abs).
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Pressure(f64);
+
+impl Pressure {
+    /// 임의 단위/모드(게이지·절대)에서 생성한다.
+    pub fn new(value: f64, unit: PressureUnit, mode: PressureMode) -> Self {
+        let bar_abs = conversion::convert_pressure_mode(
+            value,
+            unit,
+            mode,
+            PressureUnit::Bar,
+            PressureMode::Absolute,
+        );
+        Self(bar_abs * 100_000.0)
+    }
+
+    pub fn from_pa_abs(pa: f64) -> Self {
+        Self(pa)
+    }
+
+    pub fn from_bar_abs(bar: f64) -> Self {
+        Self(bar * 100_000.0)
+    }
+
+    pub fn from_bar_gauge(bar: f64) -> Self {
+        Self(bar * 100_000.0 + ATM_PA)
+    }
+
+    pub fn from_kpa_abs(kpa: f64) -> Self {
+        Self(kpa * 1000.0)
+    }
+
+    pub fn from_mpa_abs(mpa: f64) -> Self {
+        Self(mpa * 1.0e6)
+    }
+
+    pub fn pa_abs(self) -> f64 {
+        self.0
+    }
+
+    pub fn bar_abs(self) -> f64 {
+        self.0 / 100_000.0
+    }
+
+    pub fn bar_gauge(self) -> f64 {
+        (self.0 - ATM_PA) / 100_000.0
+    }
+
+    pub fn mpa_abs(self) -> f64 {
+        self.0 / 1.0e6
+    }
+
+    /// 지정 단위/모드 값으로 변환한다.
+    pub fn to(self, unit: PressureUnit, mode: PressureMode) -> f64 {
+        conversion::convert_pressure_mode(
+            self.bar_abs(),
+            PressureUnit::Bar,
+            PressureMode::Absolute,
+            unit,
+            mode,
+        )
+    }
+
+    /// 두 압력의 차이(self - other).
+    pub fn minus(self, other: Pressure) -> PressureDifference {
+        PressureDifference(self.0 - other.0)
+    }
+
+    /// 현장 대기압 기준 게이지 압력 [bar]에서 생성한다.
+    pub fn from_bar_gauge_at(bar: f64, atm: Atmosphere) -> Self {
+        Self(bar * 100_000.0 + atm.pa())
+    }
+
+    /// 현장 대기압 기준 게이지 압력 [bar].
+    pub fn bar_gauge_at(self, atm: Atmosphere) -> f64 {
+        (self.0 - atm.pa()) / 100_000.0
+    }
+}
+
+/// 압력 값의 기준.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressureReference {
+    /// 절대압
+    Absolute,
+    /// 게이지압 (대기압 기준, 음수면 진공)
+    Gauge,
+    /// 진공도 (대기압 − 절대압, 양수)
+    Vacuum,
+}
+
+/// 현장 대기압. 게이지/진공 ↔ 절대 환산의 기준이 된다.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Atmosphere(f64);
+
+impl Atmosphere {
+    /// 표준 대기압 101.325 kPa (해수면)
+    pub const STANDARD: Atmosphere = Atmosphere(ATM_PA);
+
+    pub fn from_pa(pa: f64) -> Self {
+        Self(pa)
+    }
+
+    pub fn from_bar_abs(bar: f64) -> Self {
+        Self(bar * 100_000.0)
+    }
+
+    /// ISA 표준대기(대류권) 식으로 해발 고도 [m]의 대기압을 구한다.
+    pub fn from_elevation_m(elevation_m: f64) -> Self {
+        Self(ATM_PA * (1.0 - 2.255_77e-5 * elevation_m).powf(5.255_88))
+    }
+
+    pub fn pa(self) -> f64 {
+        self.0
+    }
+
+    pub fn bar_abs(self) -> f64 {
+        self.0 / 100_000.0
+    }
+}
+
+impl Default for Atmosphere {
+    fn default() -> Self {
+        Self::STANDARD
+    }
+}
+
+/// 단위와 기준(게이지/절대/진공)을 함께 가진 압력 입력/출력 값.
+/// mmHg 같은 단위도 기준을 명시하므로 단위별 게이지 특례가 필요 없다.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PressureValue {
+    pub value: f64,
+    pub unit: PressureUnit,
+    pub reference: PressureReference,
+}
+
+impl PressureValue {
+    pub fn new(value: f64, unit: PressureUnit, reference: PressureReference) -> Self {
+        Self {
+            value,
+            unit,
+            reference,
+        }
+    }
+
+    /// 대기압 `atm`을 기준으로 절대 압력으로 바꾼다.
+    pub fn to_pressure(self, atm: Atmosphere) -> Pressure {
+        let magnitude_pa = scale_to_pa(self.value, self.unit);
+        Pressure(match self.reference {
+            PressureReference::Absolute => magnitude_pa,
+            PressureReference::Gauge => atm.pa() + magnitude_pa,
+            PressureReference::Vacuum => atm.pa() - magnitude_pa,
+        })
+    }
+
+    /// 절대 압력을 지정 단위/기준 값으로 나타낸다.
+    pub fn from_pressure(
+        p: Pressure,
+        unit: PressureUnit,
+        reference: PressureReference,
+        atm: Atmosphere,
+    ) -> Self {
+        let magnitude_pa = match reference {
+            PressureReference::Absolute => p.pa_abs(),
+            PressureReference::Gauge => p.pa_abs() - atm.pa(),
+            PressureReference::Vacuum => atm.pa() - p.pa_abs(),
+        };
+        Self::new(magnitude_pa / scale_to_pa(1.0, unit), unit, reference)
+    }
+
+    /// 다른 단위/기준으로 변환한다.
+    pub fn convert(
+        self,
+        unit: PressureUnit,
+        reference: PressureReference,
+        atm: Atmosphere,
+    ) -> Self {
+        Self::from_pressure(self.to_pressure(atm), unit, reference, atm)
+    }
+}
+
+/// 단위 배율만 적용해 Pa로 바꾼다 (기준 환산 없음).
+fn scale_to_pa(value: f64, unit: PressureUnit) -> f64 {
+    conversion::convert_pressure_mode(
+        value,
+        unit,
+        PressureMode::Absolute,
+        PressureUnit::Bar,
+        PressureMode::Absolute,
+    ) * 100_000.0
+}
+
+/// 압력 차이(ΔP). 게이지/절대 구분이 없으며 내부 값은 Pa.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct PressureDifference(f64);
+
+/// ΔP 단위 `(표시 라벨, 코드)`. 차이값이라 게이지/절대 기준이 없고 배율만 다르다.
+pub const PRESSURE_DIFFERENCE_UNITS: &[(&str, &str)] = &[
+    ("bar", "bar"),
+    ("mbar", "mbar"),
+    ("kPa", "kPa"),
+    ("MPa", "MPa"),
+    ("Pa", "Pa"),
+    ("psi", "psi"),
+    ("kg/cm²", "kg/cm2"),
+    ("mmHg", "mmHg"),
+    ("mmH₂O", "mmH2O"),
+    ("inH₂O", "inH2O"),
+];
+
+/// ΔP 단위 1당 Pa. 압력 콤보에서 넘어온 `bara`/`barg`, `psia`/`psig`도 같은 배율로 본다.
+fn pa_per_difference_unit(unit: &str) -> Option<f64> {
+    Some(match unit.trim().to_lowercase().as_str() {
+        "pa" => 1.0,
+        "kpa" => 1.0e3,
+        "mpa" => 1.0e6,
+        "mbar" => 100.0,
+        "bar" | "bara" | "barg" | "bar(a)" | "bar(g)" => 1.0e5,
+        "psi" | "psia" | "psig" => 6_894.757,
+        "atm" => ATM_PA,
+        "kg/cm2" | "kgf/cm2" | "kg/cm²" => 98_066.5,
+        "mmhg" | "torr" => 133.322_387,
+        "mmh2o" | "mmh₂o" | "mmwc" => 9.806_65,
+        "inh2o" | "inh₂o" | "inwc" => 249.088_9,
+        _ => return None,
+    })
+}
+
+impl PressureDifference {
+    /// 단위 문자열(bar, kPa, psi, mmH2O 등)에서 생성한다. 모르는 단위면 `None`.
+    pub fn from_unit(value: f64, unit: &str) -> Option<Self> {
+        pa_per_difference_unit(unit).map(|f| Self(value * f))
+    }
+
+    /// 지정 단위 값으로 돌려준다. 모르는 단위면 `None`.
+    pub fn in_unit(self, unit: &str) -> Option<f64> {
+        pa_per_difference_unit(unit).map(|f| self.0 / f)
+    }
+
+    pub fn from_pa(pa: f64) -> Self {
+        Self(pa)
+    }
+
+    pub fn from_bar(bar: f64) -> Self {
+        Self(bar * 100_000.0)
+    }
+
+    pub fn from_kpa(kpa: f64) -> Self {
+        Self(kpa * 1000.0)
+    }
+
+    pub fn from_psi(psi: f64) -> Self {
+        Self(psi * 6_894.757)
+    }
+
+    pub fn pa(self) -> f64 {
+        self.0
+    }
+
+    pub fn bar(self) -> f64 {
+        self.0 / 100_000.0
+    }
+
+    pub fn kpa(self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+/// 온도. 내부 값은 K.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Temperature(f64);
+
+impl Temperature {
+    pub fn new(value: f64, unit: TemperatureUnit) -> Self {
+        Self(units::convert_temperature(
+            value,
+            unit,
+            TemperatureUnit::Kelvin,
+        ))
+    }
+
+    pub fn from_kelvin(k: f64) -> Self {
+        Self(k)
+    }
+
+    pub fn from_celsius(c: f64) -> Self {
+        Self(c + 273.15)
+    }
+
+    pub fn from_fahrenheit(f: f64) -> Self {
+        Self((f - 32.0) / 1.8 + 273.15)
+    }
+
+    pub fn kelvin(self) -> f64 {
+        self.0
+    }
+
+    pub fn celsius(self) -> f64 {
+        self.0 - 273.15
+    }
+
+    pub fn to(self, unit: TemperatureUnit) -> f64 {
+        units::convert_temperature(self.0, TemperatureUnit::Kelvin, unit)
+    }
+}
+
+/// 질량 유량 단위.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MassFlowUnit {
+    KgPerSecond,
+    KgPerHour,
+    TonnePerHour,
+    PoundPerHour,
+}
+
+impl MassFlowUnit {
+    fn to_kg_per_s(self) -> f64 {
+        match self {
+            MassFlowUnit::KgPerSecond => 1.0,
+            MassFlowUnit::KgPerHour => 1.0 / 3600.0,
+            MassFlowUnit::TonnePerHour => 1000.0 / 3600.0,
+            MassFlowUnit::PoundPerHour => 0.453_592_37 / 3600.0,
+        }
+    }
+}
+
+/// 질량 유량. 내부 값은 kg/s.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct MassFlow(f64);
+
+impl MassFlow {
+    pub fn new(value: f64, unit: MassFlowUnit) -> Self {
+        Self(value * unit.to_kg_per_s())
+    }
+
+    pub fn from_kg_per_s(v: f64) -> Self {
+        Self(v)
+    }
+
+    pub fn from_kg_per_h(v: f64) -> Self {
+        Self::new(v, MassFlowUnit::KgPerHour)
+    }
+
+    pub fn kg_per_s(self) -> f64 {
+        self.0
+    }
+
+    pub fn kg_per_h(self) -> f64 {
+        self.0 * 3600.0
+    }
+
+    pub fn to(self, unit: MassFlowUnit) -> f64 {
+        self.0 / unit.to_kg_per_s()
+    }
+}
+
+/// 체적 유량 단위.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeFlowUnit {
+    CubicMeterPerSecond,
+    CubicMeterPerHour,
+    LiterPerMinute,
+    UsGallonPerMinute,
+}
+
+impl VolumeFlowUnit {
+    fn to_m3_per_s(self) -> f64 {
+        match self {
+            VolumeFlowUnit::CubicMeterPerSecond => 1.0,
+            VolumeFlowUnit::CubicMeterPerHour => 1.0 / 3600.0,
+            VolumeFlowUnit::LiterPerMinute => 1.0 / 60_000.0,
+            VolumeFlowUnit::UsGallonPerMinute => 0.227_124_7 / 3600.0,
+        }
+    }
+}
+
+/// 체적 유량. 내부 값은 m³/s.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct VolumeFlow(f64);
+
+impl VolumeFlow {
+    pub fn new(value: f64, unit: VolumeFlowUnit) -> Self {
+        Self(value * unit.to_m3_per_s())
+    }
+
+    pub fn from_m3_per_h(v: f64) -> Self {
+        Self::new(v, VolumeFlowUnit::CubicMeterPerHour)
+    }
+
+    pub fn m3_per_s(self) -> f64 {
+        self.0
+    }
+
+    pub fn m3_per_h(self) -> f64 {
+        self.0 * 3600.0
+    }
+
+    pub fn to(self, unit: VolumeFlowUnit) -> f64 {
+        self.0 / unit.to_m3_per_s()
+    }
+}
+
+/// 밀도. 내부 값은 kg/m³.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Density(f64);
+
+impl Density {
+    pub fn from_kg_per_m3(v: f64) -> Self {
+        Self(v)
+    }
+
+    pub fn from_lb_per_ft3(v: f64) -> Self {
+        Self(v * 16.018_46)
+    }
+
+    pub fn kg_per_m3(self) -> f64 {
+        self.0
+    }
+
+    pub fn lb_per_ft3(self) -> f64 {
+        self.0 / 16.018_46
+    }
+}
+
+/// 길이. 내부 값은 m.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Length(f64);
+
+impl Length {
+    /// 단위 문자열(m, mm, in, ft 등)에서 생성한다.
+    pub fn new(value: f64, unit: &str) -> Result<Self, ConversionError> {
+        Ok(Self(conversion::convert(
+            QuantityKind::Length,
+            value,
+            unit,
+            "m",
+        )?))
+    }
+
+    pub fn from_m(v: f64) -> Self {
+        Self(v)
+    }
+
+    pub fn from_mm(v: f64) -> Self {
+        Self(v / 1000.0)
+    }
+
+    pub fn from_inch(v: f64) -> Self {
+        Self(v * 0.0254)
+    }
+
+    pub fn m(self) -> f64 {
+        self.0
+    }
+
+    pub fn mm(self) -> f64 {
+        self.0 * 1000.0
+    }
+}
+
+/// 속도. 내부 값은 m/s.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Velocity(f64);
+
+impl Velocity {
+    /// 단위 문자열(m/s, ft/s, km/h)에서 생성한다.
+    pub fn new(value: f64, unit: &str) -> Result<Self, ConversionError> {
+        Ok(Self(conversion::convert(
+            QuantityKind::Velocity,
+            value,
+            unit,
+            "m/s",
+        )?))
+    }
+
+    pub fn from_m_per_s(v: f64) -> Self {
+        Self(v)
+    }
+
+    pub fn from_ft_per_s(v: f64) -> Self {
+        Self(v * 0.3048)
+    }
+
+    pub fn m_per_s(self) -> f64 {
+        self.0
+    }
+}
@@ -2,13 +2,26 @@ use std::io::{self, Write};
 
 use crate::app::AppError;
 use crate::config::{Config, UnitSystem};
-use crate::conversion;
+use crate::custom_units;
+use crate::flow_units::{self, FlowKind};
 use crate::i18n::{self, Translator};
+use crate::material_db;
+use crate::number_format::NumberFormat;
 use crate::quantity::QuantityKind;
+use crate::quantity_arg::{self, QuantityArgError};
 use crate::steam::{
-    self, steam_piping::PipeSizingByVelocityInput, steam_piping::PressureLossInput,
+    self,
+    critical_flow::SteamStagnation,
+    steam_dryness::{
+        self, CalorimeterResult, SeparatingCalorimeterInput, ThrottlingCalorimeterInput,
+    },
+    steam_piping::fittings::{self, FittingList, FittingMethod},
+    steam_piping::velocity_check,
+    steam_piping::PipeSizingByVelocityInput,
+    steam_piping::PressureLossInput,
 };
 use crate::units::{self, PressureUnit, TemperatureUnit};
+use crate::warning::CalcWarning;
 
 /// 메인 메뉴 선택지를 표현한다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,6 +30,7 @@ pub enum MenuChoice {
     SteamTables,
     SteamPiping,
     SteamValves,
+    SteamDryness,
     Settings,
     Exit,
 }
@@ -28,6 +42,7 @@ pub fn main_menu(tr: &Translator) -> Result<MenuChoice, AppError> {
     println!("{}", tr.t(i18n::keys::MAIN_MENU_STEAM_TABLES));
     println!("{}", tr.t(i18n::keys::MAIN_MENU_STEAM_PIPING));
     println!("{}", tr.t(i18n::keys::MAIN_MENU_STEAM_VALVES));
+    println!("{}", tr.t(i18n::keys::MAIN_MENU_STEAM_DRYNESS));
     println!("{}", tr.t(i18n::keys::MAIN_MENU_SETTINGS));
     println!("{}", tr.t(i18n::keys::MAIN_MENU_EXIT));
     loop {
@@ -38,6 +53,7 @@ pub fn main_menu(tr: &Translator) -> Result<MenuChoice, AppError> {
             "3" => return Ok(MenuChoice::SteamPiping),
             "4" => return Ok(MenuChoice::SteamValves),
             "5" => return Ok(MenuChoice::Settings),
+            "6" => return Ok(MenuChoice::SteamDryness),
             "0" => return Ok(MenuChoice::Exit),
             _ => println!("{}", tr.t(i18n::keys::INVALID_SELECTION_RETRY)),
         }
@@ -45,34 +61,90 @@ pub fn main_menu(tr: &Translator) -> Result<MenuChoice, AppError> {
 }
 
 /// 단위 변환 메뉴를 처리한다.
-pub fn handle_unit_conversion(tr: &Translator, _cfg: &Config) -> Result<(), AppError> {
+pub fn handle_unit_conversion(tr: &Translator) -> Result<(), AppError> {
     println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_HEADING));
     println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_OPTIONS_LINE1));
     println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_OPTIONS_LINE2));
+    println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_OPTIONS_LINE3));
     println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_NOTE_MMHG));
     println!("{}", tr.t(i18n::keys::HELP_UNIT_CONVERSION));
-    let kind = loop {
+    let target = loop {
         let sel = read_line(tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_KIND))?;
         if let Ok(n) = sel.trim().parse::<u32>() {
             if let Some(kind) = map_quantity(n) {
-                break kind;
+                break ConversionTarget::Quantity(kind);
+            }
+            if let Some(kind) = map_flow_kind(n) {
+                break ConversionTarget::Flow(kind);
             }
         }
         println!("{}", tr.t(i18n::keys::UNIT_CONVERSION_UNSUPPORTED));
     };
     let value = read_f64(tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_VALUE), tr)?;
-    let from_unit = read_line(tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_FROM_UNIT))?;
-    let to_unit = read_line(tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_TO_UNIT))?;
-    let result = conversion::convert(kind, value, from_unit.trim(), to_unit.trim())?;
+    let options: Vec<(String, String)> = match target {
+        ConversionTarget::Quantity(kind) => custom_units::unit_choices(kind),
+        ConversionTarget::Flow(kind) => flow_units::unit_options(kind)
+            .iter()
+            .map(|(label, code)| (label.to_string(), code.to_string()))
+            .collect(),
+    };
+    // 목록에 없는 별칭(lpm, ton/h 등)도 변환기가 아는 단위면 그대로 쓴다.
+    let known = |unit: &str| match target {
+        ConversionTarget::Quantity(kind) => {
+            let base = custom_units::builtin_unit_options(kind)[0].1;
+            custom_units::convert(kind, 1.0, unit, base).is_ok()
+        }
+        ConversionTarget::Flow(kind) => flow_units::is_unit(kind, unit),
+    };
+    let from_unit = read_unit(
+        tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_FROM_UNIT),
+        &options,
+        known,
+        tr,
+    )?;
+    let to_unit = read_unit(
+        tr.t(i18n::keys::UNIT_CONVERSION_PROMPT_TO_UNIT),
+        &options,
+        known,
+        tr,
+    )?;
+    let formatted = match target {
+        ConversionTarget::Quantity(kind) => {
+            let result = custom_units::convert(kind, value, &from_unit, &to_unit)?;
+            tr.number_format().format_quantity(result, kind)
+        }
+        ConversionTarget::Flow(kind) => {
+            let result = flow_units::convert(kind, value, &from_unit, &to_unit)?;
+            tr.number_format().format(result, 3)
+        }
+    };
     println!(
         "{} {} {}",
         tr.t(i18n::keys::UNIT_CONVERSION_RESULT),
-        result,
-        to_unit.trim()
+        formatted,
+        to_unit
     );
     Ok(())
 }
 
+/// 변환 메뉴 선택: `QuantityKind` 또는 유량/동력/물성 계열
+enum ConversionTarget {
+    Quantity(QuantityKind),
+    Flow(FlowKind),
+}
+
+fn map_flow_kind(n: u32) -> Option<FlowKind> {
+    match n {
+        14 => Some(FlowKind::MassFlow),
+        15 => Some(FlowKind::VolumetricFlow),
+        16 => Some(FlowKind::Power),
+        17 => Some(FlowKind::Density),
+        18 => Some(FlowKind::SpecificVolume),
+        19 => Some(FlowKind::SpecificHeat),
+        _ => None,
+    }
+}
+
 fn map_quantity(n: u32) -> Option<QuantityKind> {
     match n {
         1 => Some(QuantityKind::Temperature),
@@ -93,7 +165,7 @@ fn map_quantity(n: u32) -> Option<QuantityKind> {
 }
 
 /// Steam Tables 메뉴를 처리한다.
-pub fn handle_steam_tables(tr: &Translator, _cfg: &Config) -> Result<(), AppError> {
+pub fn handle_steam_tables(tr: &Translator) -> Result<(), AppError> {
     println!("{}", tr.t(i18n::keys::STEAM_TABLES_HEADING));
     println!("{}", tr.t(i18n::keys::STEAM_TABLES_NOTE));
     println!("{}", tr.t(i18n::keys::STEAM_TABLES_OPTIONS));
@@ -104,13 +176,13 @@ pub fn handle_steam_tables(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             let p = read_f64(tr.t(i18n::keys::PROMPT_PRESSURE_VALUE), tr)?;
             let unit = read_pressure_unit(tr)?;
             let state = steam::saturation_by_pressure(p, unit)?;
-            print_state(&state, tr);
+            print_state(&state, tr.number_format(), tr);
         }
         "2" => {
             let t = read_f64(tr.t(i18n::keys::PROMPT_TEMPERATURE_VALUE), tr)?;
             let unit = read_temperature_unit(tr)?;
             let state = steam::saturation_by_temperature(t, unit)?;
-            print_state(&state, tr);
+            print_state(&state, tr.number_format(), tr);
         }
         "3" => {
             let p = read_f64(tr.t(i18n::keys::PROMPT_PRESSURE_VALUE), tr)?;
@@ -118,7 +190,7 @@ pub fn handle_steam_tables(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             let t = read_f64(tr.t(i18n::keys::PROMPT_TEMPERATURE_VALUE), tr)?;
             let t_unit = read_temperature_unit(tr)?;
             let state = steam::superheated_at(p, p_unit, t, t_unit)?;
-            print_state(&state, tr);
+            print_state(&state, tr.number_format(), tr);
         }
         _ => println!("{}", tr.t(i18n::keys::INVALID_SELECTION_RETRY)),
     }
@@ -126,7 +198,8 @@ pub fn handle_steam_tables(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
 }
 
 /// Steam Piping 메뉴를 처리한다.
-pub fn handle_steam_piping(tr: &Translator, _cfg: &Config) -> Result<(), AppError> {
+pub fn handle_steam_piping(tr: &Translator) -> Result<(), AppError> {
+    let nf = tr.number_format();
     println!("{}", tr.t(i18n::keys::STEAM_PIPING_HEADING));
     println!("{}", tr.t(i18n::keys::STEAM_PIPING_OPTION_SIZING));
     println!("{}", tr.t(i18n::keys::STEAM_PIPING_OPTION_PRESSURE_DROP));
@@ -148,17 +221,34 @@ pub fn handle_steam_piping(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             };
             let result = steam::size_by_velocity(input)?;
             println!(
-                "{} {:.1} mm ({:.3} in)",
+                "{} {} mm ({} in)",
                 tr.t(i18n::keys::RESULT_RECOMMENDED_ID),
-                result.inner_diameter_m * 1000.0,
-                result.inner_diameter_m / 0.0254
+                nf.format(result.inner_diameter_m * 1000.0, 1),
+                nf.format(result.inner_diameter_m / 0.0254, 3)
             );
             println!(
-                "{} {:.2} m/s, Re={:.2e}",
+                "{} {} m/s, Re={:.2e}",
                 tr.t(i18n::keys::RESULT_EXPECTED_VELOCITY),
-                result.velocity_m_per_s,
+                nf.format(result.velocity_m_per_s, 2),
                 result.reynolds_number
             );
+            let state_p_bar_abs =
+                units::convert_pressure(pressure, p_unit, units::PressureUnit::BarA).max(0.0);
+            let state_t_c =
+                units::convert_temperature(temp, t_unit, units::TemperatureUnit::Celsius);
+            if let Ok(check) =
+                velocity_check::check_sized_pipe(&result, None, state_p_bar_abs, state_t_c, density)
+            {
+                let service = tr
+                    .lookup(check.service.i18n_key())
+                    .unwrap_or_else(|| check.service.label().to_string());
+                println!(
+                    "{} [{}] {}",
+                    tr.t(i18n::keys::RESULT_VELOCITY_CHECK),
+                    service,
+                    check.localized(tr)
+                );
+            }
         }
         "2" => {
             println!("{}", tr.t(i18n::keys::HELP_STEAM_PIPING_DROP));
@@ -183,8 +273,9 @@ pub fn handle_steam_piping(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             let diameter = read_diameter_m(tr.t(i18n::keys::PROMPT_DIAMETER), tr)?;
             let length = read_f64(tr.t(i18n::keys::PROMPT_LENGTH), tr)?;
             let eq_len = read_f64(tr.t(i18n::keys::PROMPT_EQ_LENGTH), tr)?;
-            let k_sum = read_f64(tr.t(i18n::keys::PROMPT_FITTINGS_K), tr)?;
-            let roughness = read_f64(tr.t(i18n::keys::PROMPT_ROUGHNESS), tr)?;
+            let rise = read_f64(tr.t(i18n::keys::PROMPT_ELEVATION_RISE), tr)?;
+            let (k_sum, fittings) = read_fittings(tr)?;
+            let roughness = read_roughness_m(tr)?;
             let visc = read_f64(tr.t(i18n::keys::PROMPT_VISCOSITY), tr)?;
             let sound_speed = read_f64(tr.t(i18n::keys::PROMPT_SOUND_SPEED), tr)?;
             let input = PressureLossInput {
@@ -199,16 +290,19 @@ pub fn handle_steam_piping(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
                 sound_speed_m_per_s: sound_speed,
                 state_pressure_bar_abs: Some(state_p_bar_abs),
                 state_temperature_c: Some(state_t_c),
+                inlet_elevation_m: 0.0,
+                outlet_elevation_m: rise,
             };
-            let result = steam::pressure_loss(input)?;
+            let result = steam::pressure_loss_with_fittings(input, &fittings, FittingMethod::TwoK)?;
             println!(
-                "{} {:.2} m/s, ΔP: {:.4} bar, f={:.4}, Re={:.2e}, Mach={:.3}",
+                "{} {} m/s, ΔP: {} bar, ρgΔz: {} bar, f={}, Re={:.2e}, Mach={}",
                 tr.t(i18n::keys::RESULT_PRESSURE_DROP),
-                result.velocity_m_per_s,
-                result.pressure_drop_bar,
-                result.friction_factor,
+                nf.format(result.velocity_m_per_s, 2),
+                nf.format(result.pressure_drop_bar, 4),
+                nf.format(result.elevation_drop_bar, 4),
+                nf.format(result.friction_factor, 4),
                 result.reynolds_number,
-                result.mach
+                nf.format(result.mach, 3)
             );
         }
         _ => println!("{}", tr.t(i18n::keys::INVALID_SELECTION_RETRY)),
@@ -217,7 +311,8 @@ pub fn handle_steam_piping(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
 }
 
 /// Steam Valves 메뉴를 처리한다.
-pub fn handle_steam_valves(tr: &Translator, _cfg: &Config) -> Result<(), AppError> {
+pub fn handle_steam_valves(tr: &Translator) -> Result<(), AppError> {
+    let nf = tr.number_format();
     println!("{}", tr.t(i18n::keys::STEAM_VALVES_HEADING));
     println!("{}", tr.t(i18n::keys::STEAM_VALVES_OPTION_REQUIRED));
     println!("{}", tr.t(i18n::keys::STEAM_VALVES_OPTION_FLOW));
@@ -231,10 +326,10 @@ pub fn handle_steam_valves(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             let kv = steam::required_kv(flow, dp, rho)?;
             let cv = steam::cv_from_kv(kv);
             println!(
-                "{} Kv={:.3}, Cv={:.3}",
+                "{} Kv={}, Cv={}",
                 tr.t(i18n::keys::RESULT_REQUIRED_KV_CV),
-                kv,
-                cv
+                nf.format(kv, 3),
+                nf.format(cv, 3)
             );
         }
         "2" => {
@@ -244,23 +339,92 @@ pub fn handle_steam_valves(tr: &Translator, _cfg: &Config) -> Result<(), AppErro
             let dp = read_f64(tr.t(i18n::keys::PROMPT_DELTA_P), tr)?;
             let rho = read_f64(tr.t(i18n::keys::PROMPT_DENSITY_GENERIC), tr)?;
             let p_up = read_f64(tr.t(i18n::keys::PROMPT_UPSTREAM_PRESSURE), tr)?;
-            let flow = if mode.trim() == "2" {
-                steam::flow_from_cv(value, dp, rho)?
+            let kv = if mode.trim() == "2" {
+                steam::kv_from_cv(value)
             } else {
-                steam::flow_from_kv(value, dp, rho, Some(p_up))?
+                value
             };
+            let flow = steam::steam_flow_from_kv(kv, dp, rho, p_up, SteamStagnation::Quality(1.0))?;
+            let q = flow.volumetric_flow_m3_per_h;
             println!(
-                "{} {:.3} m3/h ({:.3} kg/h)",
+                "{} {} m3/h ({} kg/h)",
                 tr.t(i18n::keys::RESULT_POSSIBLE_FLOW),
-                flow,
-                flow * rho
+                nf.format(q, 3),
+                nf.format(q * rho, 3)
             );
+            if let Some(w) = flow.warning() {
+                println!("{} {}", w.severity().marker(), w.localized(tr));
+            }
         }
         _ => println!("{}", tr.t(i18n::keys::INVALID_SELECTION_RETRY)),
     }
     Ok(())
 }
 
+/// 증기 건도 열량계 메뉴를 처리한다.
+pub fn handle_steam_dryness(tr: &Translator) -> Result<(), AppError> {
+    println!("{}", tr.t(i18n::keys::STEAM_DRYNESS_HEADING));
+    println!("{}", tr.t(i18n::keys::STEAM_DRYNESS_OPTIONS));
+    println!("{}", tr.t(i18n::keys::HELP_STEAM_DRYNESS));
+    let sel = read_line(tr.t(i18n::keys::PROMPT_SELECT))?;
+    let result = match sel.trim() {
+        "1" => steam_dryness::throttling_calorimeter(read_throttling_input(tr)?)?,
+        "2" => steam_dryness::separating_calorimeter(read_separating_input(tr)?)?,
+        "3" => {
+            let separating = read_separating_input(tr)?;
+            let throttling = read_throttling_input(tr)?;
+            steam_dryness::separating_throttling_calorimeter(separating, throttling)?
+        }
+        _ => {
+            println!("{}", tr.t(i18n::keys::INVALID_SELECTION_RETRY));
+            return Ok(());
+        }
+    };
+    print_calorimeter_result(&result, tr.number_format(), tr);
+    Ok(())
+}
+
+fn read_throttling_input(tr: &Translator) -> Result<ThrottlingCalorimeterInput, AppError> {
+    Ok(ThrottlingCalorimeterInput {
+        main_pressure_bar_abs: read_f64(tr.t(i18n::keys::PROMPT_MAIN_PRESSURE_ABS), tr)?,
+        calorimeter_pressure_bar_abs: read_f64(tr.t(i18n::keys::PROMPT_CALORIMETER_PRESSURE), tr)?,
+        calorimeter_temp_c: read_f64(tr.t(i18n::keys::PROMPT_CALORIMETER_TEMPERATURE), tr)?,
+    })
+}
+
+fn read_separating_input(tr: &Translator) -> Result<SeparatingCalorimeterInput, AppError> {
+    Ok(SeparatingCalorimeterInput {
+        separated_water_kg: read_f64(tr.t(i18n::keys::PROMPT_SEPARATED_WATER), tr)?,
+        condensed_steam_kg: read_f64(tr.t(i18n::keys::PROMPT_CONDENSED_STEAM), tr)?,
+    })
+}
+
+fn print_calorimeter_result(result: &CalorimeterResult, nf: &NumberFormat, tr: &Translator) {
+    println!(
+        "{} {} ({} %)",
+        tr.t(i18n::keys::RESULT_DRYNESS),
+        nf.format(result.dryness, 4),
+        nf.format(result.dryness * 100.0, 2)
+    );
+    if let Some(dt) = result.superheat_after_throttling_k {
+        println!(
+            "{} {}",
+            tr.t(i18n::keys::RESULT_THROTTLING_SUPERHEAT),
+            nf.format(dt, 1)
+        );
+    }
+    if let Some(x_min) = result.min_measurable_dryness {
+        println!(
+            "{} {}",
+            tr.t(i18n::keys::RESULT_MIN_MEASURABLE_DRYNESS),
+            nf.format(x_min, 4)
+        );
+    }
+    for w in &result.warnings {
+        println!("{} {w}", tr.t(i18n::keys::STEAM_DRYNESS_WARNING));
+    }
+}
+
 /// 설정 메뉴를 처리한다.
 pub fn handle_settings(tr: &Translator, cfg: &mut Config) -> Result<(), AppError> {
     println!("{}", tr.t(i18n::keys::SETTINGS_HEADING));
@@ -319,6 +483,60 @@ fn read_diameter_m(prompt: &str, tr: &Translator) -> Result<f64, AppError> {
     }
 }
 
+/// 피팅 입력을 읽는다. 숫자면 K 합계, 아니면 `elbow90-lr:4, gate:2` 형식의 카탈로그 목록.
+/// `?`를 입력하면 카탈로그 코드를 보여 준다.
+fn read_fittings(tr: &Translator) -> Result<(f64, FittingList), AppError> {
+    loop {
+        let raw = read_line(tr.t(i18n::keys::PROMPT_FITTINGS_K))?;
+        let text = raw.trim();
+        if text.is_empty() {
+            return Ok((0.0, FittingList::new()));
+        }
+        if text == "?" {
+            for f in fittings::catalog() {
+                let name = tr.lookup(f.key).unwrap_or_else(|| f.name.to_string());
+                println!("  {:<20} {}", f.code, name);
+            }
+            continue;
+        }
+        if let Ok(k) = text.parse::<f64>() {
+            return Ok((k, FittingList::new()));
+        }
+        match FittingList::parse(text) {
+            Ok(list) => return Ok((0.0, list)),
+            Err(e) => println!("{e}"),
+        }
+    }
+}
+
+/// 거칠기 프리셋 목록을 보여 주고 번호, 재질 이름 또는 ε [m] 값을 읽는다. 빈 입력은 첫 번째 프리셋.
+fn read_roughness_m(tr: &Translator) -> Result<f64, AppError> {
+    let presets = material_db::roughness_presets();
+    for (i, r) in presets.iter().enumerate() {
+        let name = tr.lookup(r.key).unwrap_or_else(|| r.name.to_string());
+        println!("{}) {} (ε {} mm)", i + 1, name, r.roughness_mm);
+    }
+    loop {
+        let raw = read_line(tr.t(i18n::keys::PROMPT_ROUGHNESS))?;
+        let sel = raw.trim();
+        if sel.is_empty() {
+            return Ok(presets[0].roughness_m());
+        }
+        if let Ok(n) = sel.parse::<usize>() {
+            if let Some(r) = n.checked_sub(1).and_then(|i| presets.get(i)) {
+                return Ok(r.roughness_m());
+            }
+        }
+        if let Some(r) = material_db::find_roughness(sel) {
+            return Ok(r.roughness_m());
+        }
+        match sel.parse::<f64>() {
+            Ok(v) if v >= 0.0 => return Ok(v),
+            _ => println!("{}", tr.t(i18n::keys::ERROR_INVALID_NUMBER)),
+        }
+    }
+}
+
 fn parse_diameter_to_m(input: &str) -> Option<f64> {
     let trimmed = input.trim();
     if trimmed.is_empty() {
@@ -345,55 +563,130 @@ fn parse_diameter_to_m(input: &str) -> Option<f64> {
     }
 }
 
+/// 단위 이름을 읽는다. `known`이 받아들이는 단위는 그대로, 그 밖에는 철자가 조금 달라도
+/// (`barg`, `bar a`, `KPA`) 목록에서 찾고, 못 찾으면 가장 가까운 단위를 알려 준 뒤 다시 묻는다.
+fn read_unit<S: AsRef<str>>(
+    prompt: &str,
+    options: &[(S, S)],
+    known: impl Fn(&str) -> bool,
+    tr: &Translator,
+) -> Result<String, AppError> {
+    loop {
+        let raw = read_line(prompt)?;
+        if known(raw.trim()) {
+            return Ok(raw.trim().to_string());
+        }
+        match quantity_arg::parse_unit_fuzzy(&raw, options) {
+            Ok(code) => return Ok(code.to_string()),
+            Err(e) => println!("{}", unit_miss_text(&e, options, tr)),
+        }
+    }
+}
+
+/// 메뉴 번호 또는 단위 이름으로 단위를 고른다. 빈 입력은 첫 번째 항목.
+fn read_unit_choice<T: Copy>(
+    options_key: &str,
+    choices: &[(&str, &str, T)],
+    tr: &Translator,
+) -> Result<T, AppError> {
+    let options: Vec<(&str, &str)> = choices.iter().map(|(l, c, _)| (*l, *c)).collect();
+    println!("{}", tr.t(options_key));
+    loop {
+        let sel = read_line(tr.t(i18n::keys::PROMPT_SELECT))?;
+        let sel = sel.trim();
+        if sel.is_empty() {
+            return Ok(choices[0].2);
+        }
+        if let Ok(n) = sel.parse::<usize>() {
+            if let Some((_, _, unit)) = n.checked_sub(1).and_then(|i| choices.get(i)) {
+                return Ok(*unit);
+            }
+        }
+        match quantity_arg::parse_unit_fuzzy(sel, &options) {
+            Ok(code) => {
+                if let Some((_, _, unit)) = choices.iter().find(|(_, c, _)| *c == code) {
+                    return Ok(*unit);
+                }
+            }
+            Err(e) => println!("{}", unit_miss_text(&e, &options, tr)),
+        }
+    }
+}
+
+fn unit_miss_text<S: AsRef<str>>(
+    err: &QuantityArgError,
+    options: &[(S, S)],
+    tr: &Translator,
+) -> String {
+    match err {
+        QuantityArgError::UnitSuggestion { unit, suggestion } => tr.format(
+            "unit.did_you_mean",
+            "Unknown unit '{unit}'. Did you mean '{suggestion}'?",
+            &[("unit", unit.clone()), ("suggestion", suggestion.clone())],
+        ),
+        QuantityArgError::UnknownUnit(unit) | QuantityArgError::InvalidNumber(unit) => {
+            let units: Vec<&str> = options.iter().map(|(label, _)| label.as_ref()).collect();
+            tr.format(
+                "unit.unknown",
+                "Unknown unit '{unit}'. Available: {units}",
+                &[("unit", unit.clone()), ("units", units.join(", "))],
+            )
+        }
+    }
+}
+
 fn read_pressure_unit(tr: &Translator) -> Result<PressureUnit, AppError> {
-    println!("{}", tr.t(i18n::keys::PRESSURE_UNIT_OPTIONS));
-    let sel = read_line(tr.t(i18n::keys::PROMPT_SELECT))?;
-    let unit = match sel.trim() {
-        "1" => PressureUnit::Bar,
-        "2" => PressureUnit::KiloPascal,
-        "3" => PressureUnit::MegaPascal,
-        "4" => PressureUnit::Psi,
-        "5" => PressureUnit::Atm,
-        _ => PressureUnit::Bar,
-    };
-    Ok(unit)
+    read_unit_choice(
+        i18n::keys::PRESSURE_UNIT_OPTIONS,
+        &[
+            ("bar(g)", "bar", PressureUnit::Bar),
+            ("kPa", "kPa", PressureUnit::KiloPascal),
+            ("MPa", "MPa", PressureUnit::MegaPascal),
+            ("psi", "psi", PressureUnit::Psi),
+            ("atm", "atm", PressureUnit::Atm),
+        ],
+        tr,
+    )
 }
 
 fn read_temperature_unit(tr: &Translator) -> Result<TemperatureUnit, AppError> {
-    println!("{}", tr.t(i18n::keys::TEMPERATURE_UNIT_OPTIONS));
-    let sel = read_line(tr.t(i18n::keys::PROMPT_SELECT))?;
-    let unit = match sel.trim() {
-        "1" => TemperatureUnit::Celsius,
-        "2" => TemperatureUnit::Kelvin,
-        "3" => TemperatureUnit::Fahrenheit,
-        "4" => TemperatureUnit::Rankine,
-        _ => TemperatureUnit::Celsius,
-    };
-    Ok(unit)
+    read_unit_choice(
+        i18n::keys::TEMPERATURE_UNIT_OPTIONS,
+        &[
+            ("°C", "C", TemperatureUnit::Celsius),
+            ("K", "K", TemperatureUnit::Kelvin),
+            ("°F", "F", TemperatureUnit::Fahrenheit),
+            ("R", "R", TemperatureUnit::Rankine),
+        ],
+        tr,
+    )
 }
 
-fn print_state(state: &steam::SteamState, tr: &Translator) {
+fn print_state(state: &steam::SteamState, nf: &NumberFormat, tr: &Translator) {
     println!(
-        "{} {:.2} °C",
+        "{} {} °C",
         tr.t(i18n::keys::STATE_SATURATION_T),
-        state.saturation_temperature_c
+        nf.format(state.saturation_temperature_c, 2)
     );
     println!(
-        "{} {:.3} bar",
+        "{} {} bar",
         tr.t(i18n::keys::STATE_SATURATION_P),
-        state.pressure_bar
+        nf.format_quantity(state.pressure_bar, QuantityKind::Pressure)
     );
     println!(
-        "{} {:.1} kJ/kg, {:.3} m3/kg",
+        "{} {} kJ/kg, {} m3/kg",
         tr.t(i18n::keys::STATE_ENTHALPY_VOLUME),
-        state.saturation_enthalpy_kj_per_kg,
-        state.saturation_specific_volume
+        nf.format_quantity(
+            state.saturation_enthalpy_kj_per_kg,
+            QuantityKind::SpecificEnthalpy
+        ),
+        nf.format(state.saturation_specific_volume, 3)
     );
     if let Some(h) = state.superheated_enthalpy_kj_per_kg {
         println!(
-            "{} {:.1} kJ/kg",
+            "{} {} kJ/kg",
             tr.t(i18n::keys::STATE_SUPERHEATED_ENTHALPY),
-            h
+            nf.format_quantity(h, QuantityKind::SpecificEnthalpy)
         );
     }
 }
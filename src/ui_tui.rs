@@ -0,0 +1,662 @@
+//! ratatui 기반 대화형 터미널 UI (`tui` 기능).
+//! GUI처럼 왼쪽 계산기 메뉴, 입력 폼(값 + 단위 선택), 결과 창, 계산 이력을 한 화면에 둔다.
+//! 계산은 CLI 서브커맨드와 같은 `cli_commands::report` 경로를 쓰므로 결과/경고 형식이 같다.
+
+use std::collections::VecDeque;
+use std::io::{self, Stdout};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use ratatui::{Frame, Terminal};
+
+use crate::app::AppError;
+use crate::cli_commands::{
+    self, Command, DrynessCommand, FittingMethodArg, PipeCommand, SteamCommand, ThrottlingArgs,
+    ValveCommand,
+};
+use crate::config::Config;
+use crate::i18n::Translator;
+use crate::material_db;
+use crate::quantity_arg::{self, QuantityArgError};
+use crate::warning::Severity;
+
+const PRESSURE_UNITS: &[&str] = &["bar_a", "barg", "kPa_a", "kPa_g", "MPa_a", "psia", "psig"];
+const TEMPERATURE_UNITS: &[&str] = &["C", "K", "F"];
+const MASS_FLOW_UNITS: &[&str] = &["t/h", "kg/h", "kg/s", "lb/h", "klb/h"];
+const VOLUME_FLOW_UNITS: &[&str] = &["m3/h", "l/min", "gpm", "cfm"];
+const VELOCITY_UNITS: &[&str] = &["m/s", "ft/s"];
+const DIAMETER_UNITS: &[&str] = &["mm", "in"];
+const LENGTH_UNITS: &[&str] = &["m", "ft"];
+const ROUGHNESS_UNITS: &[&str] = &["mm"];
+const DP_UNITS: &[&str] = &["bar", "kPa", "psi", "mbar"];
+const DENSITY_UNITS: &[&str] = &["kg/m3", "g/cm3", "lb/ft3", "lb/gal"];
+const NO_UNIT: &[&str] = &[""];
+/// 피팅 칸의 ◂ ▸ 선택지는 단위가 아니라 K 계산 방법이다.
+const FITTING_METHODS: &[&str] = &["2k", "crane"];
+
+/// 계산 이력 최대 개수
+const HISTORY_LEN: usize = 50;
+
+/// 입력 필드: 숫자 문자열 + 단위 목록 중 선택.
+struct Field {
+    key: &'static str,
+    default_label: &'static str,
+    value: String,
+    units: &'static [&'static str],
+    unit: usize,
+    /// 숫자 외의 문자도 받는 칸 (피팅 목록 등)
+    text: bool,
+}
+
+impl Field {
+    fn new(
+        key: &'static str,
+        default_label: &'static str,
+        value: &str,
+        units: &'static [&'static str],
+    ) -> Self {
+        Self {
+            key,
+            default_label,
+            value: value.to_string(),
+            units,
+            unit: 0,
+            text: false,
+        }
+    }
+
+    fn text(mut self) -> Self {
+        self.text = true;
+        self
+    }
+
+    /// `quantity_arg` 형식 인자 (`"10bar_a"`).
+    fn arg(&self) -> String {
+        format!("{}{}", self.value.trim(), self.units[self.unit])
+    }
+
+    /// 값이 비어 있으면 `None`인 선택 인자.
+    fn opt_arg(&self) -> Option<String> {
+        (!self.value.trim().is_empty()).then(|| self.arg())
+    }
+
+    fn number(&self) -> Result<f64, QuantityArgError> {
+        Ok(quantity_arg::split_value_unit(&self.value)?.0)
+    }
+}
+
+/// 계산기 하나의 입력 폼.
+struct Form {
+    key: &'static str,
+    default_title: &'static str,
+    fields: Vec<Field>,
+    build: fn(&[Field]) -> Result<Command, QuantityArgError>,
+    selected: usize,
+}
+
+fn pressure(value: &str) -> Field {
+    Field::new("tui.field.pressure", "Pressure", value, PRESSURE_UNITS)
+}
+
+fn temperature(value: &str) -> Field {
+    Field::new(
+        "tui.field.temperature",
+        "Temperature",
+        value,
+        TEMPERATURE_UNITS,
+    )
+}
+
+fn mass_flow(value: &str) -> Field {
+    Field::new("tui.field.mass_flow", "Mass flow", value, MASS_FLOW_UNITS)
+}
+
+fn delta_p(value: &str) -> Field {
+    Field::new("tui.field.delta_p", "Pressure drop ΔP", value, DP_UNITS)
+}
+
+fn density(value: &str) -> Field {
+    Field::new("tui.field.density", "Density", value, DENSITY_UNITS)
+}
+
+fn form(
+    key: &'static str,
+    default_title: &'static str,
+    fields: Vec<Field>,
+    build: fn(&[Field]) -> Result<Command, QuantityArgError>,
+) -> Form {
+    Form {
+        key,
+        default_title,
+        fields,
+        build,
+        selected: 0,
+    }
+}
+
+fn forms() -> Vec<Form> {
+    vec![
+        form(
+            "tui.calc.steam_sat_p",
+            "Saturation by pressure",
+            vec![pressure("10")],
+            |f| {
+                Ok(Command::Steam(SteamCommand::Sat {
+                    p: Some(f[0].arg()),
+                    t: None,
+                    compare: false,
+                }))
+            },
+        ),
+        form(
+            "tui.calc.steam_sat_t",
+            "Saturation by temperature",
+            vec![temperature("180")],
+            |f| {
+                Ok(Command::Steam(SteamCommand::Sat {
+                    p: None,
+                    t: Some(f[0].arg()),
+                    compare: false,
+                }))
+            },
+        ),
+        form(
+            "tui.calc.steam_superheat",
+            "Superheated steam",
+            vec![pressure("10"), temperature("250")],
+            |f| {
+                Ok(Command::Steam(SteamCommand::Superheat {
+                    p: f[0].arg(),
+                    t: f[1].arg(),
+                    compare: false,
+                }))
+            },
+        ),
+        form(
+            "tui.calc.pipe_size",
+            "Pipe sizing by velocity",
+            vec![
+                mass_flow("5"),
+                pressure("10"),
+                temperature("200"),
+                Field::new(
+                    "tui.field.velocity",
+                    "Target velocity",
+                    "25",
+                    VELOCITY_UNITS,
+                ),
+            ],
+            |f| {
+                Ok(Command::Pipe(PipeCommand::Size {
+                    mdot: f[0].arg(),
+                    p: f[1].arg(),
+                    t: f[2].arg(),
+                    v: f[3].arg(),
+                    rho: None,
+                    service: None,
+                    class: None,
+                }))
+            },
+        ),
+        form(
+            "tui.calc.pipe_dp",
+            "Pipe pressure drop",
+            vec![
+                mass_flow("5"),
+                pressure("10"),
+                temperature("200"),
+                Field::new(
+                    "tui.field.diameter",
+                    "Inner diameter",
+                    "102.3",
+                    DIAMETER_UNITS,
+                ),
+                Field::new("tui.field.length", "Length", "100", LENGTH_UNITS),
+                Field::new(
+                    "tui.field.eq_length",
+                    "Equivalent length",
+                    "0",
+                    LENGTH_UNITS,
+                ),
+                Field::new("tui.field.k_sum", "Additional ΣK", "0", NO_UNIT),
+                Field::new("tui.field.roughness", "Roughness", "0.045", ROUGHNESS_UNITS),
+                Field::new("tui.field.viscosity", "Viscosity [Pa·s]", "1.5e-5", NO_UNIT),
+                Field::new(
+                    "tui.field.sound_speed",
+                    "Speed of sound",
+                    "450",
+                    VELOCITY_UNITS,
+                ),
+                Field::new(
+                    "tui.field.fittings",
+                    "Fittings (code:count, …)",
+                    "",
+                    FITTING_METHODS,
+                )
+                .text(),
+                Field::new(
+                    "tui.field.inlet_elevation",
+                    "Inlet elevation",
+                    "0",
+                    LENGTH_UNITS,
+                ),
+                Field::new(
+                    "tui.field.outlet_elevation",
+                    "Outlet elevation",
+                    "0",
+                    LENGTH_UNITS,
+                ),
+            ],
+            |f| {
+                Ok(Command::Pipe(PipeCommand::Dp {
+                    mdot: f[0].arg(),
+                    p: f[1].arg(),
+                    t: f[2].arg(),
+                    d: f[3].arg(),
+                    l: f[4].arg(),
+                    eq_len: f[5].arg(),
+                    z_in: f[11].arg(),
+                    z_out: f[12].arg(),
+                    k: f[6].number()?,
+                    fittings: f[10].value.trim().to_string(),
+                    fitting_method: match f[10].units[f[10].unit] {
+                        "crane" => FittingMethodArg::Crane,
+                        _ => FittingMethodArg::TwoK,
+                    },
+                    // 재질 이름(pvc, stainless 등)은 단위를 붙이지 않고 그대로 넘긴다
+                    roughness: material_db::find_roughness(&f[7].value)
+                        .map_or_else(|| f[7].arg(), |r| r.code.to_string()),
+                    visc: f[8].number()?,
+                    sound_speed: f[9].arg(),
+                    rho: None,
+                }))
+            },
+        ),
+        form(
+            "tui.calc.valve_kv",
+            "Valve required Kv/Cv",
+            vec![
+                Field::new(
+                    "tui.field.volumetric_flow",
+                    "Volumetric flow",
+                    "12",
+                    VOLUME_FLOW_UNITS,
+                ),
+                delta_p("1.5"),
+                density("5.2"),
+            ],
+            |f| {
+                Ok(Command::Valve(ValveCommand::Kv {
+                    q: f[0].arg(),
+                    dp: f[1].arg(),
+                    rho: f[2].arg(),
+                }))
+            },
+        ),
+        form(
+            "tui.calc.valve_flow",
+            "Valve flow from Kv",
+            vec![
+                Field::new("tui.field.kv", "Kv", "20", NO_UNIT),
+                delta_p("1"),
+                density("5.2"),
+                Field::new(
+                    "tui.field.upstream_pressure",
+                    "Upstream pressure (optional)",
+                    "",
+                    PRESSURE_UNITS,
+                ),
+            ],
+            |f| {
+                Ok(Command::Valve(ValveCommand::Flow {
+                    kv: Some(f[0].number()?),
+                    cv: None,
+                    dp: f[1].arg(),
+                    rho: f[2].arg(),
+                    p1: f[3].opt_arg(),
+                }))
+            },
+        ),
+        form(
+            "tui.calc.dryness",
+            "Throttling calorimeter",
+            vec![
+                Field::new(
+                    "tui.field.main_pressure",
+                    "Main steam pressure",
+                    "10",
+                    PRESSURE_UNITS,
+                ),
+                Field::new(
+                    "tui.field.calorimeter_pressure",
+                    "Calorimeter pressure",
+                    "1.013",
+                    PRESSURE_UNITS,
+                ),
+                Field::new(
+                    "tui.field.calorimeter_temperature",
+                    "Calorimeter temperature",
+                    "120",
+                    TEMPERATURE_UNITS,
+                ),
+            ],
+            |f| {
+                Ok(Command::Dryness(DrynessCommand::Throttling(
+                    ThrottlingArgs {
+                        p_main: f[0].arg(),
+                        p_cal: f[1].arg(),
+                        t_cal: f[2].arg(),
+                    },
+                )))
+            },
+        ),
+    ]
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Focus {
+    Menu,
+    Form,
+}
+
+/// 결과 창의 한 줄 (경고는 심각도별 색).
+struct OutputLine {
+    text: String,
+    severity: Option<Severity>,
+}
+
+struct TuiApp<'a> {
+    tr: &'a Translator,
+    config: &'a Config,
+    forms: Vec<Form>,
+    menu: ListState,
+    focus: Focus,
+    output: Vec<OutputLine>,
+    history: VecDeque<String>,
+}
+
+impl<'a> TuiApp<'a> {
+    fn new(config: &'a Config, tr: &'a Translator) -> Self {
+        let mut menu = ListState::default();
+        menu.select(Some(0));
+        Self {
+            tr,
+            config,
+            forms: forms(),
+            menu,
+            focus: Focus::Menu,
+            output: Vec::new(),
+            history: VecDeque::new(),
+        }
+    }
+
+    fn txt(&self, key: &str, default: &str) -> String {
+        self.tr.lookup(key).unwrap_or_else(|| default.to_string())
+    }
+
+    fn current(&self) -> usize {
+        self.menu.selected().unwrap_or(0)
+    }
+
+    fn select_form(&mut self, index: usize) {
+        self.menu.select(Some(index));
+        self.output.clear();
+    }
+
+    /// 현재 폼으로 계산하고 결과 창과 이력을 갱신한다.
+    fn calculate(&mut self) {
+        let form = &self.forms[self.current()];
+        let title = self.txt(form.key, form.default_title);
+        let report = (form.build)(&form.fields)
+            .map_err(AppError::from)
+            .and_then(|cmd| cli_commands::report(&cmd, self.config.site.atmosphere(), self.tr));
+        self.output.clear();
+        match report {
+            Ok(report) => {
+                let nf = self.tr.number_format();
+                let text = report.to_text(nf);
+                for line in text.lines() {
+                    self.output.push(OutputLine {
+                        text: line.to_string(),
+                        severity: Severity::from_line(line),
+                    });
+                }
+                let summary = text
+                    .lines()
+                    .find(|l| Severity::from_line(l).is_none())
+                    .unwrap_or_default();
+                self.history.push_front(format!("{title}: {summary}"));
+                self.history.truncate(HISTORY_LEN);
+            }
+            Err(e) => {
+                let e = crate::error::ToolboxError::from(e);
+                self.output.push(OutputLine {
+                    text: e.localized(self.tr),
+                    severity: Some(Severity::Critical),
+                });
+            }
+        }
+    }
+
+    /// 키 입력을 처리한다. 종료 요청이면 `false`.
+    fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> bool {
+        if code == KeyCode::Esc
+            || (code == KeyCode::Char('c') && modifiers.contains(KeyModifiers::CONTROL))
+        {
+            return false;
+        }
+        if matches!(code, KeyCode::Tab | KeyCode::BackTab) {
+            self.focus = match self.focus {
+                Focus::Menu => Focus::Form,
+                Focus::Form => Focus::Menu,
+            };
+            return true;
+        }
+        let count = self.forms.len();
+        let current = self.current();
+        match self.focus {
+            Focus::Menu => match code {
+                KeyCode::Char('q') => return false,
+                KeyCode::Up => self.select_form((current + count - 1) % count),
+                KeyCode::Down => self.select_form((current + 1) % count),
+                KeyCode::Enter | KeyCode::Right => self.focus = Focus::Form,
+                _ => {}
+            },
+            Focus::Form if code == KeyCode::Enter => self.calculate(),
+            Focus::Form => {
+                let form = &mut self.forms[current];
+                let field_count = form.fields.len();
+                let field = &mut form.fields[form.selected];
+                let unit_count = field.units.len();
+                match code {
+                    KeyCode::Up => form.selected = (form.selected + field_count - 1) % field_count,
+                    KeyCode::Down => form.selected = (form.selected + 1) % field_count,
+                    KeyCode::Left => field.unit = (field.unit + unit_count - 1) % unit_count,
+                    KeyCode::Right => field.unit = (field.unit + 1) % unit_count,
+                    KeyCode::Backspace => {
+                        field.value.pop();
+                    }
+                    KeyCode::Char(c) if c.is_ascii_digit() || ".-+eE".contains(c) => {
+                        field.value.push(c)
+                    }
+                    KeyCode::Char(c) if field.text && !c.is_control() => field.value.push(c),
+                    _ => {}
+                }
+            }
+        }
+        true
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(10), Constraint::Length(1)])
+            .split(frame.size());
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Length(34), Constraint::Min(40)])
+            .split(rows[0]);
+        let form = &self.forms[self.current()];
+        let right = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(form.fields.len() as u16 + 2),
+                Constraint::Min(6),
+                Constraint::Length(8),
+            ])
+            .split(columns[1]);
+
+        let focused = |f: Focus| {
+            if self.focus == f {
+                Style::default().fg(Color::Cyan)
+            } else {
+                Style::default()
+            }
+        };
+
+        let items: Vec<ListItem> = self
+            .forms
+            .iter()
+            .map(|f| ListItem::new(self.txt(f.key, f.default_title)))
+            .collect();
+        let menu = List::new(items)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(focused(Focus::Menu))
+                    .title(self.txt("tui.menu", "Calculators")),
+            )
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .highlight_symbol("› ");
+        frame.render_stateful_widget(menu, columns[0], &mut self.menu);
+
+        let label_width = form
+            .fields
+            .iter()
+            .map(|f| self.txt(f.key, f.default_label).chars().count())
+            .max()
+            .unwrap_or(0);
+        let lines: Vec<Line> = form
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let active = self.focus == Focus::Form && i == form.selected;
+                let style = if active {
+                    Style::default().add_modifier(Modifier::REVERSED)
+                } else {
+                    Style::default()
+                };
+                let unit = if f.units.len() > 1 {
+                    format!("◂ {} ▸", f.units[f.unit])
+                } else {
+                    f.units[f.unit].to_string()
+                };
+                Line::from(vec![
+                    Span::raw(format!(
+                        "{:<label_width$}  ",
+                        self.txt(f.key, f.default_label)
+                    )),
+                    Span::styled(format!("{:>12}", f.value), style),
+                    Span::raw(format!("  {unit}")),
+                ])
+            })
+            .collect();
+        let title = self.txt(form.key, form.default_title);
+        frame.render_widget(
+            Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(focused(Focus::Form))
+                    .title(title),
+            ),
+            right[0],
+        );
+
+        let output: Vec<Line> = self
+            .output
+            .iter()
+            .map(|l| {
+                let color = match l.severity {
+                    Some(Severity::Critical) => Color::Red,
+                    Some(Severity::Caution) => Color::Yellow,
+                    Some(Severity::Info) => Color::Blue,
+                    None => Color::Reset,
+                };
+                Line::styled(l.text.clone(), Style::default().fg(color))
+            })
+            .collect();
+        frame.render_widget(
+            Paragraph::new(output).wrap(Wrap { trim: false }).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.txt("tui.result", "Result")),
+            ),
+            right[1],
+        );
+
+        let history: Vec<ListItem> = self
+            .history
+            .iter()
+            .map(|h| ListItem::new(h.as_str()))
+            .collect();
+        frame.render_widget(
+            List::new(history).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(self.txt("tui.history", "History")),
+            ),
+            right[2],
+        );
+
+        frame.render_widget(
+            Paragraph::new(self.txt(
+                "tui.help",
+                "Tab: menu/inputs  ↑↓: select  ←→: unit  Enter: calculate  Esc: quit",
+            ))
+            .style(Style::default().fg(Color::DarkGray)),
+            rows[1],
+        );
+    }
+}
+
+/// 터미널을 원래 상태로 돌려놓는다 (오류/패닉 경로 포함).
+struct TerminalGuard;
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(io::stdout(), LeaveAlternateScreen);
+    }
+}
+
+/// TUI를 실행한다. Esc/Ctrl+C(메뉴에서는 q)로 종료한다.
+pub fn run(config: &Config, tr: &Translator) -> Result<(), AppError> {
+    enable_raw_mode()?;
+    let _guard = TerminalGuard;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    let mut terminal: Terminal<CrosstermBackend<Stdout>> =
+        Terminal::new(CrosstermBackend::new(io::stdout()))?;
+    let mut app = TuiApp::new(config, tr);
+    loop {
+        terminal.draw(|frame| app.draw(frame))?;
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !app.handle_key(key.code, key.modifiers) {
+                break;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,267 @@
+//! 밸브 트림 데이터베이스.
+//! 밸브 태그별 정격 Cv, 고유 특성, 스트로크-Cv 점, FL/xT를 TOML 파일에 저장해
+//! 제조사 자료를 매번 다시 입력하지 않게 한다. 목록 조회/추가·수정/삭제는 `ValveDb`로 한다.
+//!
+//! 파일 형식 (`valve_db.toml`, config.toml과 같은 폴더):
+//! ```toml
+//! [[valve]]
+//! tag = "PCV-101"
+//! description = "HP bypass, 4in globe"
+//! rated_cv = 120.0                  # 전개 Cv(US)
+//! characteristic = "equal_percentage"  # linear | equal_percentage | quick_opening
+//! rangeability = 50.0
+//! stroke_cv = [[0, 0], [50, 24], [100, 120]]  # [스트로크 %, Cv(US)]
+//! fl = 0.9                          # 액체 압력 회복 계수
+//! xt = 0.72                         # 기체 차압비 계수
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use crate::flow_coefficient::KV_PER_CV_US;
+use crate::steam::valve_authority::InherentCharacteristic;
+
+/// 기본 밸브 데이터베이스 파일 이름
+pub const VALVE_DB_FILE: &str = "valve_db.toml";
+/// 레인지어빌리티가 없을 때 등비율 특성 표에 쓰는 값
+pub const DEFAULT_RANGEABILITY: f64 = 50.0;
+/// 스트로크 표가 없을 때 고유 특성으로 만드는 점 [%]
+const GENERATED_STROKES: [f64; 5] = [0.0, 25.0, 50.0, 75.0, 100.0];
+
+/// 밸브 하나의 트림 자료.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValveRecord {
+    pub tag: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    /// 전개 Cv(US)
+    pub rated_cv: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub characteristic: Option<InherentCharacteristic>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rangeability: Option<f64>,
+    /// [스트로크 %, Cv(US)] 점
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stroke_cv: Vec<[f64; 2]>,
+    /// 액체 압력 회복 계수 FL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fl: Option<f64>,
+    /// 기체 차압비 계수 xT
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub xt: Option<f64>,
+}
+
+impl ValveRecord {
+    /// 정격 Cv만 있는 새 자료.
+    pub fn new(tag: &str, rated_cv: f64) -> Self {
+        Self {
+            tag: tag.trim().to_string(),
+            description: String::new(),
+            rated_cv,
+            characteristic: None,
+            rangeability: None,
+            stroke_cv: Vec::new(),
+            fl: None,
+            xt: None,
+        }
+    }
+
+    /// 전개 Kv.
+    pub fn rated_kv(&self) -> f64 {
+        self.rated_cv * KV_PER_CV_US
+    }
+
+    /// 스트로크-Cv(US) 점 (스트로크 순). 표가 없으면 고유 특성(없으면 선형)과
+    /// 정격 Cv로 0/25/50/75/100 % 점을 만든다.
+    pub fn stroke_cv_points(&self) -> Vec<(f64, f64)> {
+        if !self.stroke_cv.is_empty() {
+            let mut points: Vec<(f64, f64)> = self.stroke_cv.iter().map(|p| (p[0], p[1])).collect();
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+            return points;
+        }
+        let characteristic = self
+            .characteristic
+            .unwrap_or(InherentCharacteristic::Linear);
+        let rangeability = self.rangeability.unwrap_or(DEFAULT_RANGEABILITY);
+        GENERATED_STROKES
+            .iter()
+            .map(|&s| {
+                // 등비율 곡선은 0 %에서 1/R이지만 표에서는 닫힘을 0으로 둔다
+                let relative = if s <= 0.0 {
+                    0.0
+                } else {
+                    characteristic.relative_cv(s / 100.0, rangeability)
+                };
+                (s, self.rated_cv * relative)
+            })
+            .collect()
+    }
+
+    /// 값 범위를 확인한다.
+    pub fn validate(&self) -> Result<(), ValveDbError> {
+        let invalid = |msg: &str| Err(ValveDbError::Invalid(format!("{}: {msg}", self.tag)));
+        if self.tag.trim().is_empty() {
+            return Err(ValveDbError::Invalid(
+                "밸브 태그는 비울 수 없습니다.".into(),
+            ));
+        }
+        if !self.rated_cv.is_finite() || self.rated_cv <= 0.0 {
+            return invalid("정격 Cv는 0보다 커야 합니다.");
+        }
+        if self
+            .rangeability
+            .is_some_and(|r| !r.is_finite() || r <= 1.0)
+        {
+            return invalid("레인지어빌리티는 1보다 커야 합니다.");
+        }
+        if self.stroke_cv.iter().any(|p| {
+            !p[0].is_finite() || !p[1].is_finite() || p[0] < 0.0 || p[0] > 100.0 || p[1] < 0.0
+        }) {
+            return invalid("스트로크는 0~100 %, Cv는 0 이상이어야 합니다.");
+        }
+        for (name, value) in [("FL", self.fl), ("xT", self.xt)] {
+            if value.is_some_and(|v| !v.is_finite() || v <= 0.0 || v > 1.0) {
+                return Err(ValveDbError::Invalid(format!(
+                    "{}: {name}는 0~1 사이여야 합니다.",
+                    self.tag
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 밸브 데이터베이스 오류.
+#[derive(Debug)]
+pub enum ValveDbError {
+    /// 값 범위 오류
+    Invalid(String),
+    /// 같은 태그가 두 번 있다
+    DuplicateTag(String),
+    Io(std::io::Error),
+    Parse(toml::de::Error),
+    Serialize(toml::ser::Error),
+}
+
+impl std::fmt::Display for ValveDbError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValveDbError::Invalid(msg) => write!(f, "밸브 자료 오류: {msg}"),
+            ValveDbError::DuplicateTag(tag) => write!(f, "중복된 밸브 태그: {tag}"),
+            ValveDbError::Io(e) => write!(f, "파일 입출력 오류: {e}"),
+            ValveDbError::Parse(e) => write!(f, "밸브 데이터베이스 파싱 오류: {e}"),
+            ValveDbError::Serialize(e) => write!(f, "밸브 데이터베이스 직렬화 오류: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ValveDbError {}
+
+impl From<std::io::Error> for ValveDbError {
+    fn from(value: std::io::Error) -> Self {
+        ValveDbError::Io(value)
+    }
+}
+
+impl From<toml::de::Error> for ValveDbError {
+    fn from(value: toml::de::Error) -> Self {
+        ValveDbError::Parse(value)
+    }
+}
+
+impl From<toml::ser::Error> for ValveDbError {
+    fn from(value: toml::ser::Error) -> Self {
+        ValveDbError::Serialize(value)
+    }
+}
+
+/// 밸브 자료 목록. 태그는 대소문자를 구분하지 않고 하나씩만 둔다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValveDb {
+    #[serde(default, rename = "valve")]
+    valves: Vec<ValveRecord>,
+}
+
+impl ValveDb {
+    /// TOML 문자열에서 읽고 항목을 검사한다.
+    pub fn from_toml_str(content: &str) -> Result<Self, ValveDbError> {
+        let db: ValveDb = toml::from_str(content)?;
+        for (i, record) in db.valves.iter().enumerate() {
+            record.validate()?;
+            if db.valves[..i]
+                .iter()
+                .any(|r| r.tag.eq_ignore_ascii_case(&record.tag))
+            {
+                return Err(ValveDbError::DuplicateTag(record.tag.clone()));
+            }
+        }
+        Ok(db)
+    }
+
+    /// TOML 문자열로 직렬화한다.
+    pub fn to_toml_string(&self) -> Result<String, ValveDbError> {
+        Ok(toml::to_string_pretty(self)?)
+    }
+
+    /// 파일에서 읽는다. 파일이 없으면 빈 목록.
+    pub fn load(path: &Path) -> Result<Self, ValveDbError> {
+        if path.exists() {
+            Self::from_toml_str(&std::fs::read_to_string(path)?)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// 작업 폴더의 `valve_db.toml`을 읽는다.
+    pub fn load_default() -> Result<Self, ValveDbError> {
+        Self::load(Path::new(VALVE_DB_FILE))
+    }
+
+    /// 파일에 저장한다.
+    pub fn save(&self, path: &Path) -> Result<(), ValveDbError> {
+        std::fs::write(path, self.to_toml_string()?)?;
+        Ok(())
+    }
+
+    /// 작업 폴더의 `valve_db.toml`에 저장한다.
+    pub fn save_default(&self) -> Result<(), ValveDbError> {
+        self.save(Path::new(VALVE_DB_FILE))
+    }
+
+    /// 전체 목록 (태그 순).
+    pub fn valves(&self) -> &[ValveRecord] {
+        &self.valves
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.valves.is_empty()
+    }
+
+    /// 태그로 찾는다.
+    pub fn get(&self, tag: &str) -> Option<&ValveRecord> {
+        let tag = tag.trim();
+        self.valves.iter().find(|r| r.tag.eq_ignore_ascii_case(tag))
+    }
+
+    /// 자료를 추가하거나 같은 태그의 자료를 바꾼다. 바꿨으면 true.
+    pub fn upsert(&mut self, mut record: ValveRecord) -> Result<bool, ValveDbError> {
+        record.tag = record.tag.trim().to_string();
+        record.validate()?;
+        let replaced = self.remove(&record.tag).is_some();
+        let at = self
+            .valves
+            .partition_point(|r| r.tag.to_lowercase() < record.tag.to_lowercase());
+        self.valves.insert(at, record);
+        Ok(replaced)
+    }
+
+    /// 태그의 자료를 지우고 돌려준다.
+    pub fn remove(&mut self, tag: &str) -> Option<ValveRecord> {
+        let tag = tag.trim();
+        let index = self
+            .valves
+            .iter()
+            .position(|r| r.tag.eq_ignore_ascii_case(tag))?;
+        Some(self.valves.remove(index))
+    }
+}
@@ -0,0 +1,287 @@
+//! 압력 용기 형상: 용적, 액위↔용적 곡선, 젖음 면적.
+//! 수직/수평 원통에 평판, 2:1 반타원형, 반구형 헤드를 붙인 용기를 다룬다.
+//! 액위는 용기 가장 낮은 점(수직형은 아래 헤드 끝, 수평형은 동체 바닥)에서 잰다.
+//!
+//! 헤드는 축 방향 반지름 a(평판 0, 2:1 반타원 D/4, 반구 D/2)인 회전 타원체로 보고
+//! - 수직형 헤드 용적은 회전체 단면 적분 V(z) = πR²(a·z² − z³/3)/a²,
+//! - 수평형 헤드 용적은 반구 용적을 a/R로 늘인 값 (a/R)·πh²(3R − h)/6,
+//! - 수직형 헤드 젖음 면적은 회전면 적분(심프슨),
+//! - 수평형 반타원 헤드 젖음 면적은 반구처럼 액위에 비례한다고 근사한다.
+
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// 수직형 헤드 젖음 면적 적분 구간 수 (짝수)
+const HEAD_AREA_STEPS: usize = 200;
+/// 액위 역산(이분법) 반복 횟수
+const LEVEL_ITERATIONS: usize = 60;
+
+/// 용기 설치 방향.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VesselOrientation {
+    Vertical,
+    Horizontal,
+}
+
+/// 헤드 형식.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HeadType {
+    #[default]
+    Flat,
+    /// 2:1 반타원형 (깊이 D/4)
+    Ellipsoidal,
+    /// 반구형 (깊이 D/2)
+    Hemispherical,
+}
+
+impl HeadType {
+    /// 헤드 깊이 [m]
+    pub fn depth_m(self, inner_diameter_m: f64) -> f64 {
+        match self {
+            HeadType::Flat => 0.0,
+            HeadType::Ellipsoidal => inner_diameter_m / 4.0,
+            HeadType::Hemispherical => inner_diameter_m / 2.0,
+        }
+    }
+}
+
+/// 용기 형상 오류.
+#[derive(Debug, Clone)]
+pub enum VesselGeometryError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for VesselGeometryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VesselGeometryError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for VesselGeometryError {}
+
+/// 원통 용기 (양쪽 헤드 같은 형식).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Vessel {
+    pub orientation: VesselOrientation,
+    /// 내경 [m]
+    pub inner_diameter_m: f64,
+    /// 동체 길이(접선 간 거리) [m]
+    pub length_m: f64,
+    #[serde(default)]
+    pub heads: HeadType,
+}
+
+/// 액위↔용적 곡선의 한 점.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LevelVolume {
+    pub level_m: f64,
+    pub volume_m3: f64,
+}
+
+impl Vessel {
+    /// 치수를 확인한다.
+    pub fn validate(&self) -> Result<(), VesselGeometryError> {
+        if self.inner_diameter_m <= 0.0 || self.length_m < 0.0 {
+            return Err(VesselGeometryError::InvalidInput(
+                "용기 내경은 0보다 크고 동체 길이는 0 이상이어야 합니다.",
+            ));
+        }
+        if self.heads == HeadType::Flat && self.length_m <= 0.0 {
+            return Err(VesselGeometryError::InvalidInput(
+                "평판 헤드 용기의 동체 길이는 0보다 커야 합니다.",
+            ));
+        }
+        Ok(())
+    }
+
+    fn radius(&self) -> f64 {
+        self.inner_diameter_m / 2.0
+    }
+
+    /// 헤드 깊이 [m]
+    pub fn head_depth_m(&self) -> f64 {
+        self.heads.depth_m(self.inner_diameter_m)
+    }
+
+    /// 액위 범위(바닥~꼭대기) [m]
+    pub fn height_m(&self) -> f64 {
+        match self.orientation {
+            VesselOrientation::Vertical => self.length_m + 2.0 * self.head_depth_m(),
+            VesselOrientation::Horizontal => self.inner_diameter_m,
+        }
+    }
+
+    /// 전체 내용적 [m³]
+    pub fn volume_m3(&self) -> f64 {
+        let r = self.radius();
+        PI * r * r * self.length_m + 4.0 / 3.0 * PI * r * r * self.head_depth_m()
+    }
+
+    /// 전체 내부 표면적 [m²]
+    pub fn surface_area_m2(&self) -> f64 {
+        let r = self.radius();
+        2.0 * PI * r * self.length_m + 2.0 * self.head_area_m2()
+    }
+
+    /// 헤드 하나의 내부 표면적 [m²]
+    pub fn head_area_m2(&self) -> f64 {
+        let r = self.radius();
+        let a = self.head_depth_m();
+        match self.heads {
+            HeadType::Flat => PI * r * r,
+            HeadType::Hemispherical => 2.0 * PI * r * r,
+            // 편평 회전 타원체 절반: πR²(1 + (1 − e²)/e · atanh e)
+            HeadType::Ellipsoidal => {
+                let e = (1.0 - (a / r).powi(2)).sqrt();
+                PI * r * r * (1.0 + (1.0 - e * e) / e * e.atanh())
+            }
+        }
+    }
+
+    /// 수직형 헤드 끝에서 높이 z까지의 헤드 용적 [m³]
+    fn vertical_head_volume(&self, z: f64) -> f64 {
+        let a = self.head_depth_m();
+        if a <= 0.0 {
+            return 0.0;
+        }
+        let r = self.radius();
+        let z = z.clamp(0.0, a);
+        PI * r * r * (a * z * z - z * z * z / 3.0) / (a * a)
+    }
+
+    /// 수직형 헤드 끝에서 높이 z까지의 헤드 표면적 [m²]. 회전면 2π·√(r² + (r·r')²) 적분.
+    fn vertical_head_area(&self, z: f64) -> f64 {
+        let r = self.radius();
+        let a = self.head_depth_m();
+        if a <= 0.0 {
+            return if z > 0.0 { PI * r * r } else { 0.0 };
+        }
+        let z = z.clamp(0.0, a);
+        let integrand = |y: f64| {
+            let u = (a - y) / a;
+            let rr = r * r * (1.0 - u * u).max(0.0);
+            let rdr = r * r * (a - y) / (a * a);
+            2.0 * PI * (rr + rdr * rdr).sqrt()
+        };
+        let n = HEAD_AREA_STEPS;
+        let dy = z / n as f64;
+        let sum: f64 = (0..=n)
+            .map(|i| {
+                let w = if i == 0 || i == n {
+                    1.0
+                } else if i % 2 == 1 {
+                    4.0
+                } else {
+                    2.0
+                };
+                w * integrand(i as f64 * dy)
+            })
+            .sum();
+        sum * dy / 3.0
+    }
+
+    /// 수평형 원 단면에서 액위 h 아래 활꼴 면적 [m²]
+    fn segment_area(&self, h: f64) -> f64 {
+        let r = self.radius();
+        let h = h.clamp(0.0, 2.0 * r);
+        r * r * ((r - h) / r).clamp(-1.0, 1.0).acos()
+            - (r - h) * (2.0 * r * h - h * h).max(0.0).sqrt()
+    }
+
+    /// 액위까지의 액체 용적 [m³]. 액위는 용기 범위로 제한한다.
+    pub fn volume_at_level_m3(&self, level_m: f64) -> f64 {
+        let h = level_m.clamp(0.0, self.height_m());
+        let r = self.radius();
+        let a = self.head_depth_m();
+        match self.orientation {
+            VesselOrientation::Vertical => {
+                let top = a + self.length_m;
+                let bottom = self.vertical_head_volume(h);
+                let shell = PI * r * r * (h - a).clamp(0.0, self.length_m);
+                let upper = if h > top {
+                    self.vertical_head_volume(a) - self.vertical_head_volume(a - (h - top))
+                } else {
+                    0.0
+                };
+                bottom + shell + upper
+            }
+            VesselOrientation::Horizontal => {
+                self.segment_area(h) * self.length_m + (a / r) * PI * h * h * (3.0 * r - h) / 3.0
+            }
+        }
+    }
+
+    /// 액체 용적에 해당하는 액위 [m] (이분법). 용적은 전체 용적으로 제한한다.
+    pub fn level_at_volume_m(&self, volume_m3: f64) -> f64 {
+        let target = volume_m3.clamp(0.0, self.volume_m3());
+        let (mut lo, mut hi) = (0.0, self.height_m());
+        for _ in 0..LEVEL_ITERATIONS {
+            let mid = 0.5 * (lo + hi);
+            if self.volume_at_level_m3(mid) < target {
+                lo = mid;
+            } else {
+                hi = mid;
+            }
+        }
+        0.5 * (lo + hi)
+    }
+
+    /// 액위까지 액체에 젖는 내부 표면적 [m²]
+    pub fn wetted_area_m2(&self, level_m: f64) -> f64 {
+        let h = level_m.clamp(0.0, self.height_m());
+        if h <= 0.0 {
+            return 0.0;
+        }
+        let r = self.radius();
+        let a = self.head_depth_m();
+        match self.orientation {
+            VesselOrientation::Vertical => {
+                let top = a + self.length_m;
+                let bottom = self.vertical_head_area(h);
+                let shell = 2.0 * PI * r * (h - a).clamp(0.0, self.length_m);
+                let upper = if self.heads == HeadType::Flat {
+                    if h >= top {
+                        PI * r * r
+                    } else {
+                        0.0
+                    }
+                } else if h > top {
+                    self.vertical_head_area(a) - self.vertical_head_area(a - (h - top))
+                } else {
+                    0.0
+                };
+                bottom + shell + upper
+            }
+            VesselOrientation::Horizontal => {
+                let theta = 2.0 * (1.0 - h / r).clamp(-1.0, 1.0).acos();
+                let heads = match self.heads {
+                    HeadType::Flat => 2.0 * self.segment_area(h),
+                    // 구면 띠 면적은 높이에 비례한다 (반타원은 근사)
+                    HeadType::Hemispherical | HeadType::Ellipsoidal => {
+                        2.0 * self.head_area_m2() * h / (2.0 * r)
+                    }
+                };
+                r * theta * self.length_m + heads
+            }
+        }
+    }
+
+    /// 바닥부터 꼭대기까지 `points`개(양 끝 포함) 등간격 액위의 용적 곡선.
+    pub fn level_volume_curve(&self, points: usize) -> Vec<LevelVolume> {
+        let points = points.max(2);
+        let height = self.height_m();
+        (0..points)
+            .map(|i| {
+                let level_m = height * i as f64 / (points - 1) as f64;
+                LevelVolume {
+                    level_m,
+                    volume_m3: self.volume_at_level_m3(level_m),
+                }
+            })
+            .collect()
+    }
+}
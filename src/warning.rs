@@ -0,0 +1,75 @@
+//! 계산 결과 경고의 공통 형식.
+//! 모듈은 번역된 문자열 대신 경고 enum을 돌려주고, 화면 문구는 i18n 키와 인자로 만든다.
+//! 언어팩에 키가 없으면 같은 언어의 내장 언어팩 문구를 쓰고,
+//! `Display`는 내장 언어팩도 없는 언어에서만 쓰는 기본(한국어) 문구다.
+
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::i18n::Translator;
+
+/// 경고 심각도. 순서는 Info < Caution < Critical.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    /// 참고 정보 (계산 가정/제한 안내)
+    Info,
+    /// 설계 여유 부족 등 검토가 필요한 상태
+    Caution,
+    /// 운전 불가/손상 위험이 있는 상태
+    Critical,
+}
+
+impl Severity {
+    /// 결과 문자열에서 경고 줄 앞에 붙이는 표시 기호.
+    pub fn marker(self) -> &'static str {
+        match self {
+            Severity::Info => "ℹ",
+            Severity::Caution => "⚠",
+            Severity::Critical => "⛔",
+        }
+    }
+
+    /// 표시 기호로 시작하는 줄의 심각도.
+    pub fn from_line(line: &str) -> Option<Severity> {
+        let line = line.trim_start();
+        [Severity::Critical, Severity::Caution, Severity::Info]
+            .into_iter()
+            .find(|s| line.starts_with(s.marker()))
+    }
+}
+
+/// 계산 경고 공통 인터페이스.
+pub trait CalcWarning: Display {
+    fn severity(&self) -> Severity;
+
+    /// 문구 템플릿의 i18n 키 (예: "warning.cooling.npsh_margin_low").
+    fn i18n_key(&self) -> &'static str;
+
+    /// 템플릿 `{이름}` 자리에 넣을 값 (이미 표시 자릿수로 포맷됨).
+    fn args(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+
+    /// 언어팩 템플릿으로 문구를 만든다. 언어팩과 내장 언어팩 모두 키가 없으면 `Display` 문구.
+    fn localized(&self, tr: &Translator) -> String {
+        localize(self, tr)
+    }
+}
+
+/// `CalcWarning::localized`의 기본 구현. 재정의한 구현이 일부 경우에 그대로 쓴다.
+pub fn localize<W: CalcWarning + ?Sized>(warning: &W, tr: &Translator) -> String {
+    let mut text = tr
+        .lookup_or_bundled(warning.i18n_key())
+        .unwrap_or_else(|| warning.to_string());
+    for (k, v) in warning.args() {
+        text = text.replace(&format!("{{{k}}}"), &v);
+    }
+    text
+}
+
+/// 경고 목록 중 가장 높은 심각도. 비어 있으면 `None`.
+pub fn max_severity<W: CalcWarning>(warnings: &[W]) -> Option<Severity> {
+    warnings.iter().map(|w| w.severity()).max()
+}
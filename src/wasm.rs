@@ -0,0 +1,275 @@
+//! 브라우저에서 계산 코어를 호출하기 위한 wasm-bindgen 래퍼.
+//! 증기표, 단위 변환, 밸브 Cv/Kv 계산만 노출하며 설정/파일 입출력은 포함하지 않는다.
+//! 빌드: `cargo build --lib --no-default-features --features wasm --target wasm32-unknown-unknown`
+
+use wasm_bindgen::prelude::*;
+
+use crate::conversion::{self, PressureMode};
+use crate::flow_coefficient::{self, FlowCoefficientKind};
+use crate::quantity::QuantityKind;
+use crate::steam::critical_flow::SteamStagnation;
+use crate::steam::{steam_tables, steam_valves};
+use crate::units::{PressureUnit, TemperatureUnit};
+
+/// JS로 반환하는 증기 상태값. 압력은 bar(abs), 온도는 °C, 엔탈피는 kJ/kg, 엔트로피는 kJ/kgK 기준이다.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy)]
+pub struct WasmSteamState {
+    pub pressure_bar_abs: f64,
+    pub temperature_c: f64,
+    pub saturation_temperature_c: f64,
+    pub saturation_enthalpy_kj_per_kg: f64,
+    pub saturation_specific_volume: f64,
+    pub saturation_entropy_kj_per_kgk: f64,
+    pub sat_liquid_enthalpy_kj_per_kg: f64,
+    pub sat_liquid_specific_volume: f64,
+    pub sat_liquid_entropy_kj_per_kgk: f64,
+    /// 과열 비엔탈피(kJ/kg). 포화 계산이면 NaN.
+    pub superheated_enthalpy_kj_per_kg: f64,
+}
+
+impl From<steam_tables::SteamState> for WasmSteamState {
+    /// `SteamState`의 엔탈피·엔트로피는 IF97 그대로 J/kg, J/kgK라서 1000으로 나눈다.
+    fn from(s: steam_tables::SteamState) -> Self {
+        Self {
+            pressure_bar_abs: s.pressure_bar,
+            temperature_c: s.temperature_c,
+            saturation_temperature_c: s.saturation_temperature_c,
+            saturation_enthalpy_kj_per_kg: s.saturation_enthalpy_kj_per_kg / 1000.0,
+            saturation_specific_volume: s.saturation_specific_volume,
+            saturation_entropy_kj_per_kgk: s.saturation_entropy_kj_per_kgk / 1000.0,
+            sat_liquid_enthalpy_kj_per_kg: s.sat_liquid_enthalpy_kj_per_kg / 1000.0,
+            sat_liquid_specific_volume: s.sat_liquid_specific_volume,
+            sat_liquid_entropy_kj_per_kgk: s.sat_liquid_entropy_kj_per_kgk / 1000.0,
+            superheated_enthalpy_kj_per_kg: s
+                .superheated_enthalpy_kj_per_kg
+                .map_or(f64::NAN, |h| h / 1000.0),
+        }
+    }
+}
+
+/// 물리량 이름과 단위 문자열로 값을 변환한다. (예: "pressure", 1.0, "bar", "psi")
+#[wasm_bindgen(js_name = convertUnit)]
+pub fn convert_unit(kind: &str, value: f64, from: &str, to: &str) -> Result<f64, JsError> {
+    let kind = parse_kind(kind).ok_or_else(|| JsError::new("unknown quantity kind"))?;
+    Ok(conversion::convert(kind, value, from, to)?)
+}
+
+/// 게이지/절대 모드를 포함한 압력 변환.
+#[wasm_bindgen(js_name = convertPressure)]
+pub fn convert_pressure(
+    value: f64,
+    from_unit: &str,
+    from_absolute: bool,
+    to_unit: &str,
+    to_absolute: bool,
+) -> Result<f64, JsError> {
+    Ok(conversion::convert_pressure_mode(
+        value,
+        parse_pressure_unit(from_unit)?,
+        pressure_mode(from_absolute),
+        parse_pressure_unit(to_unit)?,
+        pressure_mode(to_absolute),
+    ))
+}
+
+/// 압력 기준 포화 증기 특성.
+#[wasm_bindgen(js_name = saturationByPressure)]
+pub fn saturation_by_pressure(
+    value: f64,
+    unit: &str,
+    absolute: bool,
+) -> Result<WasmSteamState, JsError> {
+    let state = steam_tables::saturation_by_pressure_mode(
+        value,
+        parse_pressure_unit(unit)?,
+        pressure_mode(absolute),
+    )?;
+    Ok(state.into())
+}
+
+/// 온도 기준 포화 증기 특성.
+#[wasm_bindgen(js_name = saturationByTemperature)]
+pub fn saturation_by_temperature(value: f64, unit: &str) -> Result<WasmSteamState, JsError> {
+    let state = steam_tables::saturation_by_temperature(value, parse_temperature_unit(unit)?)?;
+    Ok(state.into())
+}
+
+/// 압력+온도 기준 과열 증기 특성.
+#[wasm_bindgen(js_name = superheated)]
+pub fn superheated(
+    pressure: f64,
+    pressure_unit: &str,
+    absolute: bool,
+    temperature: f64,
+    temperature_unit: &str,
+) -> Result<WasmSteamState, JsError> {
+    let state = steam_tables::superheated_at_mode(
+        pressure,
+        parse_pressure_unit(pressure_unit)?,
+        pressure_mode(absolute),
+        temperature,
+        parse_temperature_unit(temperature_unit)?,
+    )?;
+    Ok(state.into())
+}
+
+/// 요구 Kv (유량 m³/h, ΔP bar, 밀도 kg/m³).
+#[wasm_bindgen(js_name = requiredKv)]
+pub fn required_kv(
+    volumetric_flow_m3_per_h: f64,
+    delta_p_bar: f64,
+    fluid_density_kg_m3: f64,
+) -> Result<f64, JsError> {
+    Ok(steam_valves::required_kv(
+        volumetric_flow_m3_per_h,
+        delta_p_bar,
+        fluid_density_kg_m3,
+    )?)
+}
+
+/// 요구 Cv (유량 m³/h, ΔP bar, 밀도 kg/m³).
+#[wasm_bindgen(js_name = requiredCv)]
+pub fn required_cv(
+    volumetric_flow_m3_per_h: f64,
+    delta_p_bar: f64,
+    fluid_density_kg_m3: f64,
+) -> Result<f64, JsError> {
+    Ok(steam_valves::required_cv(
+        volumetric_flow_m3_per_h,
+        delta_p_bar,
+        fluid_density_kg_m3,
+    )?)
+}
+
+/// Kv로 통과 가능한 유량(m³/h). 상류 압력(bar abs)이 0보다 크면 건포화 증기로 보고
+/// IF97 임계 차압에서 유량을 제한하고, 0 이하면 임계 판정을 생략한다.
+#[wasm_bindgen(js_name = flowFromKv)]
+pub fn flow_from_kv(
+    kv: f64,
+    delta_p_bar: f64,
+    fluid_density_kg_m3: f64,
+    upstream_bar_abs: f64,
+) -> Result<f64, JsError> {
+    if upstream_bar_abs > 0.0 {
+        Ok(steam_valves::steam_flow_from_kv(
+            kv,
+            delta_p_bar,
+            fluid_density_kg_m3,
+            upstream_bar_abs,
+            SteamStagnation::Quality(1.0),
+        )?
+        .volumetric_flow_m3_per_h)
+    } else {
+        Ok(steam_valves::flow_from_kv(
+            kv,
+            delta_p_bar,
+            fluid_density_kg_m3,
+        )?)
+    }
+}
+
+/// Cv로 통과 가능한 유량(m³/h).
+#[wasm_bindgen(js_name = flowFromCv)]
+pub fn flow_from_cv(cv: f64, delta_p_bar: f64, fluid_density_kg_m3: f64) -> Result<f64, JsError> {
+    Ok(steam_valves::flow_from_cv(
+        cv,
+        delta_p_bar,
+        fluid_density_kg_m3,
+    )?)
+}
+
+/// Cv→Kv 변환.
+#[wasm_bindgen(js_name = kvFromCv)]
+pub fn kv_from_cv(cv: f64) -> f64 {
+    steam_valves::kv_from_cv(cv)
+}
+
+/// Kv→Cv 변환.
+#[wasm_bindgen(js_name = cvFromKv)]
+pub fn cv_from_kv(kv: f64) -> f64 {
+    steam_valves::cv_from_kv(kv)
+}
+
+/// 유량계수 환산. 종류 코드는 `kv`, `cv`(=`cv(us)`), `cv(uk)`, `av`.
+#[wasm_bindgen(js_name = convertFlowCoefficient)]
+pub fn convert_flow_coefficient(value: f64, from: &str, to: &str) -> Result<f64, JsError> {
+    let kind = |code: &str| {
+        FlowCoefficientKind::from_code(code)
+            .ok_or_else(|| JsError::new("unknown flow coefficient kind"))
+    };
+    Ok(flow_coefficient::convert(value, kind(from)?, kind(to)?))
+}
+
+fn pressure_mode(absolute: bool) -> PressureMode {
+    if absolute {
+        PressureMode::Absolute
+    } else {
+        PressureMode::Gauge
+    }
+}
+
+fn parse_kind(s: &str) -> Option<QuantityKind> {
+    match s.to_ascii_lowercase().replace(['-', ' '], "_").as_str() {
+        "temperature" => Some(QuantityKind::Temperature),
+        "temperature_difference" | "temperature_diff" => Some(QuantityKind::TemperatureDifference),
+        "pressure" => Some(QuantityKind::Pressure),
+        "length" => Some(QuantityKind::Length),
+        "area" => Some(QuantityKind::Area),
+        "volume" => Some(QuantityKind::Volume),
+        "velocity" => Some(QuantityKind::Velocity),
+        "mass" => Some(QuantityKind::Mass),
+        "viscosity" => Some(QuantityKind::Viscosity),
+        "energy" => Some(QuantityKind::Energy),
+        "heat_transfer" | "heat_transfer_coeff" => Some(QuantityKind::HeatTransferCoeff),
+        "conductivity" | "thermal_conductivity" => Some(QuantityKind::ThermalConductivity),
+        "specific_enthalpy" => Some(QuantityKind::SpecificEnthalpy),
+        _ => None,
+    }
+}
+
+/// JS에서 받은 단위 문자열 오류. `?`로 `JsError`가 된다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnitParseError {
+    Pressure(String),
+    Temperature(String),
+}
+
+impl std::fmt::Display for UnitParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            UnitParseError::Pressure(unit) => write!(f, "unknown pressure unit '{unit}'"),
+            UnitParseError::Temperature(unit) => write!(f, "unknown temperature unit '{unit}'"),
+        }
+    }
+}
+
+impl std::error::Error for UnitParseError {}
+
+/// 압력 단위 문자열. 모르는 단위를 bar로 바꿔 계산하지 않고 오류로 돌려준다.
+pub fn parse_pressure_unit(s: &str) -> Result<PressureUnit, UnitParseError> {
+    Ok(match s.to_lowercase().as_str() {
+        "bar" => PressureUnit::Bar,
+        "bara" | "bar(a)" => PressureUnit::BarA,
+        "mbar" => PressureUnit::MilliBar,
+        "kpa" => PressureUnit::KiloPascal,
+        "mpa" => PressureUnit::MegaPascal,
+        "psi" => PressureUnit::Psi,
+        "atm" => PressureUnit::Atm,
+        "pa" => PressureUnit::Pascal,
+        "kg/cm2" => PressureUnit::KgPerCm2,
+        "mmhg" => PressureUnit::MmHg,
+        _ => return Err(UnitParseError::Pressure(s.to_string())),
+    })
+}
+
+/// 온도 단위 문자열 (`c`/`°c`, `k`, `f`, `r`).
+pub fn parse_temperature_unit(s: &str) -> Result<TemperatureUnit, UnitParseError> {
+    Ok(match s.to_lowercase().as_str() {
+        "c" | "°c" => TemperatureUnit::Celsius,
+        "k" => TemperatureUnit::Kelvin,
+        "f" => TemperatureUnit::Fahrenheit,
+        "r" => TemperatureUnit::Rankine,
+        _ => return Err(UnitParseError::Temperature(s.to_string())),
+    })
+}
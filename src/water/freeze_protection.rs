@@ -0,0 +1,218 @@
+//! 정체된 물 배관의 동결 보호(winterization) 계산.
+//! 보온 배관의 단위길이 열저항(보온 전도 + 외부 대류/복사)과 물·관벽 열용량으로
+//! 0 °C까지의 냉각 시간(집중 열용량 지수 냉각)과 전량 동결 시간(잠열 ÷ 0 °C 열손실)을 구한다.
+//! 배관 재질을 지정하면 재질 DB의 k(T)로 관벽 전도 저항을 더한다.
+//! 내부 막 저항과 얼음층 저항은 무시하므로 시간은 보수적(짧게)으로 나온다.
+
+use crate::material_db;
+
+/// 물의 융해 잠열 [kJ/kg]
+const WATER_LATENT_FUSION_KJ_PER_KG: f64 = 333.55;
+const WATER_DENSITY_KG_PER_M3: f64 = 1000.0;
+const WATER_CP_KJ_PER_KGK: f64 = 4.19;
+const STEEL_DENSITY_KG_PER_M3: f64 = 7850.0;
+const STEEL_CP_KJ_PER_KGK: f64 = 0.49;
+/// 히트 트레이싱 유지 온도 [°C]
+pub const HEAT_TRACE_MAINTAIN_TEMP_C: f64 = 5.0;
+/// 이보다 짧으면 야간 정지 중 동결 가능성이 크다고 본다 [h]
+const SHORT_FREEZE_TIME_H: f64 = 8.0;
+
+/// 동결 보호 계산 입력.
+#[derive(Debug, Clone)]
+pub struct FreezeProtectionInput {
+    /// 배관 외경 [m]
+    pub outer_diameter_m: f64,
+    /// 배관 두께 [m]
+    pub wall_thickness_m: f64,
+    /// 보온 두께 [m]. 0이면 나관.
+    pub insulation_thickness_m: f64,
+    /// 보온재 열전도율 [W/m·K] (미네랄울 약 0.04)
+    pub insulation_conductivity_w_mk: f64,
+    /// 풍속 [m/s]
+    pub wind_speed_m_per_s: f64,
+    /// 외기 온도 [°C] (0 °C 미만)
+    pub ambient_temp_c: f64,
+    /// 정체 시작 시 물 온도 [°C]
+    pub initial_temp_c: f64,
+    /// 배관 재질 코드 (`material_db` 기준). 없으면 관벽 저항을 무시한다.
+    pub pipe_material: Option<String>,
+}
+
+/// 동결 보호 계산 결과.
+#[derive(Debug, Clone)]
+pub struct FreezeProtectionResult {
+    /// 외부 막 계수 [W/m²·K]
+    pub outside_film_w_m2k: f64,
+    /// 관벽 열전도율 [W/m·K] (재질 지정 시)
+    pub wall_conductivity_w_mk: Option<f64>,
+    /// 단위길이 열통과율 [W/m·K]
+    pub ua_w_per_mk: f64,
+    /// 정체 시작 시 열손실 [W/m]
+    pub initial_heat_loss_w_per_m: f64,
+    /// 배관 내 물 질량 [kg/m]
+    pub water_mass_kg_per_m: f64,
+    /// 물 + 관벽 열용량 [kJ/m·K]
+    pub heat_capacity_kj_per_mk: f64,
+    /// 냉각 시정수 [h]
+    pub time_constant_h: f64,
+    /// 0 °C 도달 시간 [h]
+    pub time_to_zero_h: f64,
+    /// 0 °C 도달 후 전량 동결까지 [h]
+    pub freezing_duration_h: f64,
+    /// 정체 시작부터 전량 동결까지 [h]
+    pub time_to_complete_freeze_h: f64,
+    /// `HEAT_TRACE_MAINTAIN_TEMP_C` 유지에 필요한 히트 트레이싱 [W/m]
+    pub heat_trace_w_per_m: f64,
+    pub warnings: Vec<String>,
+}
+
+/// 동결 보호 계산 오류.
+#[derive(Debug, Clone)]
+pub enum FreezeProtectionError {
+    InvalidInput(&'static str),
+}
+
+impl std::fmt::Display for FreezeProtectionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FreezeProtectionError::InvalidInput(msg) => write!(f, "입력 오류: {msg}"),
+        }
+    }
+}
+
+impl std::error::Error for FreezeProtectionError {}
+
+/// 외기 막 계수(대류+복사) [W/m²·K]. h = 10.45 − v + 10√v (무풍 시 약 10 W/m²·K).
+pub fn outside_film_coefficient(wind_speed_m_per_s: f64) -> f64 {
+    let v = wind_speed_m_per_s.clamp(0.0, 20.0);
+    10.45 - v + 10.0 * v.sqrt()
+}
+
+/// 보온 배관의 단위길이 열통과율 [W/m·K].
+/// R' = ln(r_ins/r_o)/(2πk) + 1/(h·2πr_ins), 관벽/내부 막 저항은 무시한다.
+pub fn insulated_pipe_ua_w_per_mk(
+    outer_diameter_m: f64,
+    insulation_thickness_m: f64,
+    insulation_conductivity_w_mk: f64,
+    wind_speed_m_per_s: f64,
+) -> f64 {
+    let r_o = outer_diameter_m / 2.0;
+    let r_ins = r_o + insulation_thickness_m.max(0.0);
+    let r_cond = if insulation_thickness_m > 0.0 {
+        (r_ins / r_o).ln() / (2.0 * std::f64::consts::PI * insulation_conductivity_w_mk)
+    } else {
+        0.0
+    };
+    let r_film =
+        1.0 / (outside_film_coefficient(wind_speed_m_per_s) * 2.0 * std::f64::consts::PI * r_ins);
+    1.0 / (r_cond + r_film)
+}
+
+/// 관벽 단위길이 전도 저항 [m·K/W] = ln(r_o/r_i)/(2πk).
+pub fn pipe_wall_resistance_mk_per_w(
+    outer_diameter_m: f64,
+    wall_thickness_m: f64,
+    conductivity_w_mk: f64,
+) -> f64 {
+    let r_o = outer_diameter_m / 2.0;
+    let r_i = r_o - wall_thickness_m;
+    (r_o / r_i).ln() / (2.0 * std::f64::consts::PI * conductivity_w_mk)
+}
+
+/// 정체 물 배관의 0 °C 도달 시간과 전량 동결 시간을 계산한다.
+pub fn compute_freeze_protection(
+    input: FreezeProtectionInput,
+) -> Result<FreezeProtectionResult, FreezeProtectionError> {
+    if input.outer_diameter_m <= 0.0
+        || input.wall_thickness_m <= 0.0
+        || input.wall_thickness_m * 2.0 >= input.outer_diameter_m
+    {
+        return Err(FreezeProtectionError::InvalidInput(
+            "외경과 두께는 0보다 크고 두께는 외경의 절반 미만이어야 합니다.",
+        ));
+    }
+    if input.insulation_thickness_m < 0.0
+        || (input.insulation_thickness_m > 0.0 && input.insulation_conductivity_w_mk <= 0.0)
+    {
+        return Err(FreezeProtectionError::InvalidInput(
+            "보온 두께는 0 이상, 보온재 열전도율은 0보다 커야 합니다.",
+        ));
+    }
+    if input.wind_speed_m_per_s < 0.0 {
+        return Err(FreezeProtectionError::InvalidInput(
+            "풍속은 0 이상이어야 합니다.",
+        ));
+    }
+    if input.ambient_temp_c >= 0.0 {
+        return Err(FreezeProtectionError::InvalidInput(
+            "외기 온도가 0 °C 이상이면 동결되지 않습니다.",
+        ));
+    }
+    if input.initial_temp_c <= 0.0 {
+        return Err(FreezeProtectionError::InvalidInput(
+            "초기 물 온도는 0 °C보다 높아야 합니다.",
+        ));
+    }
+
+    let pi = std::f64::consts::PI;
+    let d_i = input.outer_diameter_m - 2.0 * input.wall_thickness_m;
+    let water_mass = WATER_DENSITY_KG_PER_M3 * pi * d_i.powi(2) / 4.0;
+    let steel_mass =
+        STEEL_DENSITY_KG_PER_M3 * pi * (input.outer_diameter_m.powi(2) - d_i.powi(2)) / 4.0;
+    let heat_capacity = water_mass * WATER_CP_KJ_PER_KGK + steel_mass * STEEL_CP_KJ_PER_KGK;
+
+    // 관벽 k는 물 쪽 온도(정체 시작)에서 본다.
+    let wall_k = match &input.pipe_material {
+        Some(code) => Some(
+            material_db::thermal_conductivity(code, input.initial_temp_c).ok_or(
+                FreezeProtectionError::InvalidInput("재질 DB에 없는 배관 재질입니다."),
+            )?,
+        ),
+        None => None,
+    };
+    let ua_outside = insulated_pipe_ua_w_per_mk(
+        input.outer_diameter_m,
+        input.insulation_thickness_m,
+        input.insulation_conductivity_w_mk,
+        input.wind_speed_m_per_s,
+    );
+    let ua = match wall_k {
+        Some(k) => {
+            let r_wall =
+                pipe_wall_resistance_mk_per_w(input.outer_diameter_m, input.wall_thickness_m, k);
+            1.0 / (1.0 / ua_outside + r_wall)
+        }
+        None => ua_outside,
+    };
+    let ta = input.ambient_temp_c;
+    let tau_s = heat_capacity * 1000.0 / ua;
+    let t_zero_s = tau_s * ((input.initial_temp_c - ta) / (0.0 - ta)).ln();
+    let t_freeze_s = water_mass * WATER_LATENT_FUSION_KJ_PER_KG * 1000.0 / (ua * (0.0 - ta));
+    let total_h = (t_zero_s + t_freeze_s) / 3600.0;
+
+    let mut warnings = Vec::new();
+    if t_zero_s / 3600.0 < SHORT_FREEZE_TIME_H {
+        warnings.push(format!(
+            "{:.1} h 만에 0 °C에 도달합니다. 야간/주말 정지 시 배수, 연속 유동 또는 히트 트레이싱이 필요합니다.",
+            t_zero_s / 3600.0
+        ));
+    }
+    if input.insulation_thickness_m == 0.0 {
+        warnings.push("나관입니다. 보온만으로도 동결 시간을 크게 늘릴 수 있습니다.".into());
+    }
+
+    Ok(FreezeProtectionResult {
+        outside_film_w_m2k: outside_film_coefficient(input.wind_speed_m_per_s),
+        wall_conductivity_w_mk: wall_k,
+        ua_w_per_mk: ua,
+        initial_heat_loss_w_per_m: ua * (input.initial_temp_c - ta),
+        water_mass_kg_per_m: water_mass,
+        heat_capacity_kj_per_mk: heat_capacity,
+        time_constant_h: tau_s / 3600.0,
+        time_to_zero_h: t_zero_s / 3600.0,
+        freezing_duration_h: t_freeze_s / 3600.0,
+        time_to_complete_freeze_h: total_h,
+        heat_trace_w_per_m: ua * (HEAT_TRACE_MAINTAIN_TEMP_C - ta),
+        warnings,
+    })
+}
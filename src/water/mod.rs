@@ -1,5 +1,6 @@
-//! 물 배관/밸브 계산 모듈 모음. 현재는 stub 상태다.
+//! 물 배관/밸브 계산 모듈 모음. 정체 배관 동결 보호 계산을 포함한다.
 
+pub mod freeze_protection;
 pub mod water_piping;
 
 pub use water_piping::*;
@@ -1,3 +1,5 @@
+const G: f64 = 9.80665;
+
 /// Darcy-Weisbach 기반 물 배관 압력손실 계산 입력.
 #[derive(Debug, Clone)]
 pub struct WaterPressureLossInput {
@@ -13,6 +15,10 @@ pub struct WaterPressureLossInput {
     pub length_m: f64,
     /// 거칠기 [m]
     pub roughness_m: f64,
+    /// 입구 높이 [m]
+    pub inlet_elevation_m: f64,
+    /// 출구 높이 [m]
+    pub outlet_elevation_m: f64,
 }
 
 /// 압력손실 결과.
@@ -20,15 +26,36 @@ pub struct WaterPressureLossInput {
 pub struct WaterPressureLossResult {
     /// 유속 [m/s]
     pub velocity_m_per_s: f64,
-    /// 압력강하 [bar]
+    /// 마찰 압력강하 [bar] (정수두 제외)
     pub pressure_drop_bar: f64,
+    /// 정수두 ρ·g·(z출구 - z입구) [bar]. 오르막이 +.
+    pub elevation_drop_bar: f64,
     /// 레이놀즈수
     pub reynolds: f64,
     /// 마찰계수
     pub friction_factor: f64,
 }
 
-/// Darcy-Weisbach 식으로 물 배관 압력손실을 계산한다.
+impl WaterPressureLossResult {
+    /// 마찰 손실과 정수두를 합한 입구-출구 압력 차 [bar]
+    pub fn total_drop_bar(&self) -> f64 {
+        self.pressure_drop_bar + self.elevation_drop_bar
+    }
+}
+
+/// Darcy 마찰계수. 층류(Re<2300)는 64/Re, 난류는 Haaland 근사식을 쓴다.
+pub fn darcy_friction_factor(reynolds: f64, roughness_m: f64, diameter_m: f64) -> f64 {
+    if reynolds < 2300.0 {
+        64.0 / reynolds.max(1.0)
+    } else {
+        let roughness_ratio = roughness_m / diameter_m;
+        let log_term = (roughness_ratio / 3.7).powf(1.11) + 6.9 / reynolds;
+        let inv_sqrt_f = -1.8 * log_term.log10();
+        1.0 / inv_sqrt_f.powi(2)
+    }
+}
+
+/// Darcy-Weisbach 식으로 물 배관 압력손실을 계산한다. 높이 차에 의한 정수두는 따로 낸다.
 pub fn water_pressure_loss(input: WaterPressureLossInput) -> WaterPressureLossResult {
     let flow_m3_s = input.flow_m3_per_h / 3600.0;
     let area = std::f64::consts::PI * input.diameter_m * input.diameter_m / 4.0;
@@ -36,14 +63,7 @@ pub fn water_pressure_loss(input: WaterPressureLossInput) -> WaterPressureLossRe
     let reynolds =
         input.density_kg_per_m3 * velocity * input.diameter_m / input.dynamic_viscosity_pa_s;
 
-    let friction_factor = if reynolds < 2300.0 {
-        64.0 / reynolds.max(1.0)
-    } else {
-        let roughness_ratio = input.roughness_m / input.diameter_m;
-        let log_term = (roughness_ratio / 3.7).powf(1.11) + 6.9 / reynolds;
-        let inv_sqrt_f = -1.8 * log_term.log10();
-        1.0 / inv_sqrt_f.powi(2)
-    };
+    let friction_factor = darcy_friction_factor(reynolds, input.roughness_m, input.diameter_m);
 
     let delta_p_pa = friction_factor
         * (input.length_m / input.diameter_m)
@@ -51,10 +71,13 @@ pub fn water_pressure_loss(input: WaterPressureLossInput) -> WaterPressureLossRe
         * velocity
         * velocity
         / 2.0;
+    let elevation_drop_pa =
+        input.density_kg_per_m3 * G * (input.outlet_elevation_m - input.inlet_elevation_m);
 
     WaterPressureLossResult {
         velocity_m_per_s: velocity,
         pressure_drop_bar: delta_p_pa / 100_000.0,
+        elevation_drop_bar: elevation_drop_pa / 100_000.0,
         reynolds,
         friction_factor,
     }
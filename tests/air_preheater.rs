@@ -0,0 +1,79 @@
+use steam_engineering_toolbox::combustion::air_preheater::{
+    compute_air_preheater, AirPreheaterError, AirPreheaterInput, AirPreheaterWarning,
+};
+use steam_engineering_toolbox::combustion::fuel::{self, FuelType};
+
+fn oil_fired(fuel_type: FuelType) -> AirPreheaterInput {
+    AirPreheaterInput {
+        fuel: fuel_type.analysis(),
+        fuel_flow_kg_per_h: 5_000.0,
+        fuel_lhv_kj_per_kg: fuel_type.lhv_kj_per_kg(),
+        excess_air_frac: 0.15,
+        air_humidity_ratio: 0.01,
+        gas_inlet_temp_c: 350.0,
+        air_inlet_temp_c: 30.0,
+        air_outlet_temp_c: 280.0,
+        air_leakage_frac: 0.0,
+    }
+}
+
+#[test]
+fn gas_and_air_sides_balance() {
+    let r = compute_air_preheater(oil_fired(FuelType::FuelOil6)).unwrap();
+    let gas = fuel::flue_gas(&FuelType::FuelOil6.analysis(), 0.15, 0.01);
+    let cp_g = gas.cp_kj_per_kgk((350.0 + r.gas_outlet_temp_c) / 2.0);
+    let q_gas = r.flue_gas_flow_kg_per_h * cp_g * (350.0 - r.gas_outlet_temp_c) / 3600.0;
+    assert!((q_gas - r.heat_recovered_kw).abs() < 1e-6 * r.heat_recovered_kw);
+    // 누설이 없으면 두 출구 온도가 같고, X-비 = 가스 강하 / 공기 상승
+    assert!((r.gas_outlet_temp_c - r.gas_outlet_temp_no_leakage_c).abs() < 1e-9);
+    assert!((r.x_ratio - (350.0 - r.gas_outlet_temp_c) / 250.0).abs() < 1e-12);
+    assert!((0.7..1.0).contains(&r.x_ratio), "{}", r.x_ratio);
+    assert!(
+        ((r.stack_loss_before_frac - r.stack_loss_after_frac) - r.efficiency_gain).abs()
+            < 0.02 * r.efficiency_gain
+    );
+    // 연돌 20 K 저하당 약 1% 효율
+    assert!(
+        (0.007..0.013).contains(&r.efficiency_gain_per_step),
+        "{}",
+        r.efficiency_gain_per_step
+    );
+}
+
+#[test]
+fn leakage_cools_the_gas_outlet_and_sulfur_sets_the_acid_dew_point() {
+    let tight = compute_air_preheater(oil_fired(FuelType::FuelOil6)).unwrap();
+    let leaky = compute_air_preheater(AirPreheaterInput {
+        air_leakage_frac: 0.08,
+        ..oil_fired(FuelType::FuelOil6)
+    })
+    .unwrap();
+    assert!(leaky.gas_outlet_temp_c < tight.gas_outlet_temp_c);
+    assert_eq!(
+        leaky.gas_outlet_temp_no_leakage_c,
+        tight.gas_outlet_temp_no_leakage_c
+    );
+
+    // 중유 2.5% S: 산노점 130~160 °C, 저온부 평균이 그보다 낮아 경고
+    let adp = tight.acid_dew_point_c.unwrap();
+    assert!((130.0..160.0).contains(&adp), "{adp}");
+    assert!(tight.cold_end_average_temp_c < adp);
+    assert!(tight
+        .warnings
+        .iter()
+        .any(|w| matches!(w, AirPreheaterWarning::ColdEndBelowAcidDewPoint { .. })));
+
+    let ng = compute_air_preheater(oil_fired(FuelType::NaturalGas)).unwrap();
+    assert!(ng.acid_dew_point_c.is_none());
+    assert!(ng.warnings.is_empty(), "{:?}", ng.warnings);
+}
+
+#[test]
+fn rejects_air_outlet_above_gas_inlet() {
+    let err = compute_air_preheater(AirPreheaterInput {
+        air_outlet_temp_c: 360.0,
+        ..oil_fired(FuelType::FuelOil2)
+    })
+    .unwrap_err();
+    assert!(matches!(err, AirPreheaterError::InvalidInput(_)));
+}
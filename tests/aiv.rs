@@ -0,0 +1,61 @@
+use steam_engineering_toolbox::gas::aiv::{
+    limit_sound_power_db, screen_aiv, AivError, AivInput, AivRisk, STEAM_MOLAR_MASS,
+};
+
+fn steam_letdown(mass_flow_kg_per_h: f64) -> AivInput {
+    // 40 → 5 bar(a), 250 °C 증기, 하류 12" Sch.STD (323.9 × 9.53 mm)
+    AivInput {
+        upstream_pressure_bar_abs: 40.0,
+        downstream_pressure_bar_abs: 5.0,
+        upstream_temp_c: 250.0,
+        mass_flow_kg_per_h,
+        molar_mass_kg_per_kmol: STEAM_MOLAR_MASS,
+        outer_diameter_m: 0.3239,
+        wall_thickness_m: 0.00953,
+    }
+}
+
+#[test]
+fn carucci_mueller_level_ranks_letdown_risk() {
+    let small = screen_aiv(&steam_letdown(5_000.0)).unwrap();
+    assert_eq!(small.risk, AivRisk::Low);
+    assert!(small.recommendations.is_empty());
+    assert_eq!(small.screening_length_m, 0.0);
+
+    // 0.875^3.6 · 5.556² · (523.15/18.015)^1.2 → PWL ≈ 156.5 dB, D/t 34 허용선 ≈ 169.4 dB
+    let medium = screen_aiv(&steam_letdown(20_000.0)).unwrap();
+    assert!(
+        (medium.sound_power_db - 156.5).abs() < 0.1,
+        "{}",
+        medium.sound_power_db
+    );
+    assert!((medium.limit_db - limit_sound_power_db(0.3239 / 0.00953)).abs() < 1e-12);
+    assert_eq!(medium.risk, AivRisk::Medium);
+    assert_eq!(medium.required_thickness_m, None);
+    assert_eq!(medium.recommendations.len(), 1);
+
+    // 유량 5배 → +14 dB
+    let high = screen_aiv(&steam_letdown(100_000.0)).unwrap();
+    assert!((high.sound_power_db - medium.sound_power_db - 13.98).abs() < 0.01);
+    assert_eq!(high.risk, AivRisk::High);
+    assert!(high.margin_db < 0.0);
+    let t_req = high.required_thickness_m.unwrap();
+    assert!(t_req > 0.00953 && t_req < 0.015, "{t_req}");
+    assert!(limit_sound_power_db(0.3239 / t_req) >= high.sound_power_db - 1e-9);
+    assert_eq!(high.recommendations.len(), 2);
+    assert!(high.screening_length_m > 50.0);
+}
+
+#[test]
+fn thickness_is_not_offered_above_practical_limit_and_bad_input_fails() {
+    let mut input = steam_letdown(400_000.0);
+    input.upstream_pressure_bar_abs = 100.0;
+    input.downstream_pressure_bar_abs = 2.0;
+    let r = screen_aiv(&input).unwrap();
+    assert_eq!(r.risk, AivRisk::High);
+    assert_eq!(r.required_thickness_m, None);
+    assert_eq!(r.recommendations.len(), 1);
+
+    input.downstream_pressure_bar_abs = 120.0;
+    assert!(matches!(screen_aiv(&input), Err(AivError::InvalidInput(_))));
+}
@@ -0,0 +1,87 @@
+use steam_engineering_toolbox::steam::attemperator::{
+    compute_attemperator, AttemperatorError, AttemperatorInput, AttemperatorWarning,
+    MIN_OUTLET_SUPERHEAT_K,
+};
+use steam_engineering_toolbox::steam::if97;
+
+fn drum_boiler() -> AttemperatorInput {
+    AttemperatorInput {
+        main_steam_flow_kg_per_h: 100_000.0,
+        attemperator_pressure_bar_abs: 100.0,
+        inlet_steam_temp_c: 480.0,
+        outlet_setpoint_c: 450.0,
+        spray_water_temp_c: 230.0,
+        max_spray_flow_kg_per_h: Some(15_000.0),
+        main_steam_pressure_bar_abs: 95.0,
+        main_steam_temp_c: 540.0,
+        feedwater_pressure_bar_abs: 120.0,
+        feedwater_temp_c: 220.0,
+        fuel_heat_input_kw: Some(80_000.0),
+    }
+}
+
+#[test]
+fn spray_flow_closes_the_mixing_heat_balance() {
+    let r = compute_attemperator(drum_boiler()).unwrap();
+    let (h_in, _, _) = if97::region_props(100.0, 480.0).unwrap();
+    let (h_out, _, _) = if97::region_props(100.0, 450.0).unwrap();
+    let (h_w, _, _) = if97::region_props(120.0, 230.0).unwrap();
+    let mixed = r.upstream_steam_flow_kg_per_h * h_in + r.spray_flow_kg_per_h * h_w;
+    assert!((mixed / 100_000.0 - h_out).abs() < 1e-6 * h_out);
+    // 30 K 감온에 약 3% 스프레이
+    assert!(
+        (0.02..0.05).contains(&r.spray_fraction),
+        "{}",
+        r.spray_fraction
+    );
+    assert!(r.warnings.is_empty(), "{:?}", r.warnings);
+
+    // 제어 범위: 밸브 한계가 없으면 하한은 최소 과열도
+    assert_eq!(r.control_max_outlet_temp_c, 480.0);
+    assert!(r.max_safe_spray_flow_kg_per_h > 15_000.0);
+    assert!(r.control_min_outlet_temp_c > r.saturation_temp_c + MIN_OUTLET_SUPERHEAT_K);
+    let unlimited = compute_attemperator(AttemperatorInput {
+        max_spray_flow_kg_per_h: None,
+        ..drum_boiler()
+    })
+    .unwrap();
+    assert!(
+        (unlimited.control_min_outlet_temp_c - (r.saturation_temp_c + MIN_OUTLET_SUPERHEAT_K))
+            .abs()
+            < 1e-9
+    );
+}
+
+#[test]
+fn missing_spray_understates_output_and_efficiency() {
+    let r = compute_attemperator(drum_boiler()).unwrap();
+    let ratio = r.output_excluding_spray_kw / r.boiler_output_kw;
+    assert!((ratio - (1.0 - r.spray_fraction)).abs() < 1e-12);
+    assert!((r.output_understatement_pct - r.spray_fraction * 100.0).abs() < 1e-9);
+    let (eta, eta_reported) = r.efficiency.unwrap();
+    assert!(eta > eta_reported);
+    assert!((eta - r.boiler_output_kw / 80_000.0).abs() < 1e-12);
+}
+
+#[test]
+fn small_spray_valve_limits_the_control_range() {
+    let r = compute_attemperator(AttemperatorInput {
+        outlet_setpoint_c: 420.0,
+        max_spray_flow_kg_per_h: Some(3_000.0),
+        ..drum_boiler()
+    })
+    .unwrap();
+    assert!(r.spray_flow_kg_per_h > 3_000.0);
+    assert!(r.control_min_outlet_temp_c > 420.0 && r.control_min_outlet_temp_c < 480.0);
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, AttemperatorWarning::SprayExceedsValve { .. })));
+
+    let err = compute_attemperator(AttemperatorInput {
+        feedwater_pressure_bar_abs: 90.0,
+        ..drum_boiler()
+    })
+    .unwrap_err();
+    assert!(matches!(err, AttemperatorError::InvalidInput(_)));
+}
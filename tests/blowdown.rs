@@ -0,0 +1,65 @@
+use steam_engineering_toolbox::gas::blowdown::{
+    compute_blowdown, BlowdownError, BlowdownGas, BlowdownInput, BlowdownProcess,
+    BlowdownRestriction,
+};
+
+fn air_vessel(process: BlowdownProcess) -> BlowdownInput {
+    BlowdownInput {
+        volume_m3: 1.0,
+        initial_pressure_bar_abs: 10.0,
+        initial_temp_c: 20.0,
+        back_pressure_bar_abs: 1.01325,
+        end_pressure_bar_abs: 1.01325,
+        gas: BlowdownGas::air(),
+        process,
+        restriction: BlowdownRestriction::Orifice {
+            diameter_m: 0.01,
+            discharge_coefficient: 1.0,
+        },
+    }
+}
+
+#[test]
+fn isothermal_choked_phase_matches_exponential_decay() {
+    let r = compute_blowdown(air_vessel(BlowdownProcess::Isothermal)).unwrap();
+    // 초킹 구간: p = p0·exp(−t/τ), τ = V / (Cd·A·√(ZRT)·C*) ≈ 64 s
+    let k: f64 = 1.4;
+    let c_star = k.sqrt() * (2.0 / (k + 1.0)).powf((k + 1.0) / (2.0 * (k - 1.0)));
+    let zrt: f64 = 8314.462618 / 28.96 * 293.15;
+    let area = std::f64::consts::PI * 0.01f64.powi(2) / 4.0;
+    let tau = 1.0 / (area * zrt.sqrt() * c_star);
+    let expected = tau * (10.0 / r.unchoke_pressure_bar_abs).ln();
+    assert!(
+        (r.choked_duration_s / expected - 1.0).abs() < 0.01,
+        "{} vs {expected}",
+        r.choked_duration_s
+    );
+    assert!(r.total_duration_s > r.choked_duration_s && r.total_duration_s.is_finite());
+    assert!(r
+        .profile
+        .windows(2)
+        .all(|w| w[1].pressure_bar_abs < w[0].pressure_bar_abs && w[1].time_s > w[0].time_s));
+    assert!(r.profile.first().unwrap().choked && !r.profile.last().unwrap().choked);
+}
+
+#[test]
+fn adiabatic_cools_and_valve_cv_is_accepted() {
+    let iso = compute_blowdown(air_vessel(BlowdownProcess::Isothermal)).unwrap();
+    let mut input = air_vessel(BlowdownProcess::Adiabatic);
+    input.end_pressure_bar_abs = 2.0;
+    let adi = compute_blowdown(input).unwrap();
+    assert!(adi.final_temperature_c < -50.0);
+    assert!(!adi.warnings.is_empty());
+
+    let mut valve = air_vessel(BlowdownProcess::Isothermal);
+    valve.restriction = BlowdownRestriction::ValveCv { cv: 5.0 };
+    let v = compute_blowdown(valve).unwrap();
+    assert!(v.total_duration_s < iso.total_duration_s);
+
+    let mut bad = air_vessel(BlowdownProcess::Isothermal);
+    bad.end_pressure_bar_abs = 0.5;
+    assert!(matches!(
+        compute_blowdown(bad),
+        Err(BlowdownError::InvalidInput(_))
+    ));
+}
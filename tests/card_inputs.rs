@@ -0,0 +1,130 @@
+use steam_engineering_toolbox::card_inputs::{CardId, GuiInputs, SteamMode, ValveMode};
+use steam_engineering_toolbox::combustion::fuel::FuelType;
+use steam_engineering_toolbox::condensate_recovery::ReturnLineKind;
+use steam_engineering_toolbox::config::{FluidDefaults, SiteConditions};
+use steam_engineering_toolbox::conversion::PressureMode;
+use steam_engineering_toolbox::quantity::QuantityKind;
+use steam_engineering_toolbox::steam::heat_balance::HeatBalanceTemplate;
+
+#[test]
+fn inputs_round_trip_through_toml() {
+    let mut inputs = GuiInputs::default();
+    inputs.steam_tables.properties.mode = SteamMode::Superheated;
+    inputs.steam_tables.properties.p_mode = PressureMode::Absolute;
+    inputs.piping.sizing.mass_flow = 1234.5;
+    inputs.piping.loss.material = "stainless".into();
+    inputs.valves.sizing.mode = ValveMode::FlowFromCvKv;
+    inputs.boiler.cycle.reheat = true;
+
+    let text = inputs.to_toml_string().unwrap();
+    assert!(text.contains("p_mode = \"absolute\""), "{text}");
+    assert!(text.contains("mode = \"flow_from_cv_kv\""), "{text}");
+    assert_eq!(GuiInputs::from_toml_str(&text).unwrap(), inputs);
+}
+
+#[test]
+fn partial_file_keeps_defaults_for_missing_cards() {
+    let inputs = GuiInputs::from_toml_str(
+        "[piping.sizing]\nmass_flow = 800.0\nunknown_field = 1\n\n[boiler.cycle]\nfwh_mode = 2\n",
+    )
+    .unwrap();
+    assert_eq!(inputs.piping.sizing.mass_flow, 800.0);
+    assert_eq!(inputs.piping.sizing.pressure_unit, "bar");
+    assert_eq!(inputs.boiler.cycle.fwh_mode, 2);
+    assert_eq!(inputs.boiler.cycle.throttle_p_bar_abs, 100.0);
+    assert_eq!(inputs.steam_tables, Default::default());
+    assert!(GuiInputs::from_toml_str("[steam_tables.properties]\np_mode = \"vacuum\"\n").is_err());
+
+    let fluid = FluidDefaults {
+        gas_density_kg_per_m3: 3.3,
+        ..FluidDefaults::default()
+    };
+    let seeded = GuiInputs::for_fluid(&fluid);
+    assert_eq!(seeded.valves.sizing.rho, 3.3);
+    assert_eq!(seeded.piping, GuiInputs::default().piping);
+}
+
+#[test]
+fn calculator_tabs_round_trip_through_toml() {
+    let mut inputs = GuiInputs::default();
+    inputs.unit_conv.quantity.kind = QuantityKind::SpecificEnthalpy;
+    inputs.boiler.hrsg.fuel = None;
+    inputs.boiler.efficiency.dew_fuel = Some(FuelType::FuelOil6);
+    inputs.boiler.heat_balance.template = HeatBalanceTemplate::Simple;
+    inputs.boiler.heat_balance.input = HeatBalanceTemplate::Simple.input();
+    inputs.cooling.condenser.pressure_mode = PressureMode::Absolute;
+    inputs.plant_piping.line.material = "P22".into();
+    inputs.plant_piping.corrosion.class_choice = Some("A1".into());
+    inputs.condensate.return_line.kind = ReturnLineKind::Pumped;
+
+    let text = inputs.to_toml_string().unwrap();
+    assert!(text.contains("kind = \"specific_enthalpy\""), "{text}");
+    assert!(text.contains("fuel = \"none\""), "{text}");
+    let back = GuiInputs::from_toml_str(&text).unwrap();
+    assert_eq!(back, inputs);
+    assert_eq!(back.boiler.hrsg.fuel, None);
+
+    assert!(GuiInputs::from_toml_str("[unit_conv.quantity]\nkind = \"luminance\"\n").is_err());
+    assert!(GuiInputs::from_toml_str("[boiler.hrsg]\nfuel = \"peat\"\n").is_err());
+}
+
+#[test]
+fn site_and_fluid_defaults_seed_cooling_inputs() {
+    let fluid = FluidDefaults {
+        liquid_density_kg_per_m3: 1010.0,
+        ..FluidDefaults::default()
+    };
+    let site = SiteConditions {
+        design_dry_bulb_c: 38.0,
+        design_wet_bulb_c: 26.0,
+        ..SiteConditions::default()
+    };
+    let seeded = GuiInputs::for_fluid(&fluid).with_site(&site);
+    assert_eq!(seeded.cooling.npsh.rho, 1010.0);
+    assert_eq!(seeded.cooling.tower.db, 38.0);
+    assert_eq!(seeded.cooling.tower.wb, 26.0);
+    assert_eq!(seeded.cooling.evaporative.wb_c, 26.0);
+    assert_eq!(
+        seeded.cooling.tower.water_in,
+        GuiInputs::default().cooling.tower.water_in
+    );
+}
+
+#[test]
+fn dashboard_pins_keep_order_and_persist() {
+    let mut inputs = GuiInputs::default();
+    inputs.dashboard.toggle(CardId::ValveSizing);
+    inputs.dashboard.toggle(CardId::SteamProperties);
+    inputs.dashboard.toggle(CardId::PipeSizing);
+    inputs.dashboard.toggle(CardId::ValveSizing);
+    assert_eq!(
+        inputs.dashboard.pinned,
+        vec![CardId::SteamProperties, CardId::PipeSizing]
+    );
+    assert!(!inputs.dashboard.is_pinned(CardId::ValveSizing));
+
+    let text = inputs.to_toml_string().unwrap();
+    assert!(text.starts_with("[dashboard]"), "{text}");
+    assert_eq!(
+        GuiInputs::from_toml_str(&text).unwrap().dashboard,
+        inputs.dashboard
+    );
+}
+
+#[test]
+fn every_card_has_its_own_key_and_pins_round_trip() {
+    let mut keys: Vec<_> = CardId::ALL.iter().map(|card| card.i18n_key()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    assert_eq!(keys.len(), CardId::ALL.len());
+
+    let mut inputs = GuiInputs::default();
+    for card in CardId::ALL {
+        inputs.dashboard.toggle(card);
+    }
+    let text = inputs.to_toml_string().unwrap();
+    assert_eq!(
+        GuiInputs::from_toml_str(&text).unwrap().dashboard.pinned,
+        CardId::ALL.to_vec()
+    );
+}
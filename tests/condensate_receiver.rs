@@ -0,0 +1,93 @@
+use steam_engineering_toolbox::condensate_recovery::{
+    size_receiver, CondensateStream, ReceiverError, ReceiverInput, ReceiverWarning,
+};
+
+fn stream(name: &str, flow_kg_per_h: f64, pressure_bar_abs: f64) -> CondensateStream {
+    CondensateStream {
+        name: name.into(),
+        flow_kg_per_h,
+        pressure_bar_abs,
+    }
+}
+
+fn input() -> ReceiverInput {
+    ReceiverInput {
+        streams: vec![
+            stream("HP heater", 2_000.0, 11.0),
+            stream("tracing", 500.0, 4.0),
+            stream("drain", 300.0, 1.01325),
+        ],
+        receiver_pressure_bar_abs: 1.01325,
+        vent_velocity_limit_m_per_s: None,
+        surge_time_min: 10.0,
+        working_fraction: 0.5,
+        pump_capacity_factor: 2.0,
+        pump_npshr_m: None,
+    }
+}
+
+#[test]
+fn flash_from_each_stream_goes_to_vent() {
+    let r = size_receiver(&input()).unwrap();
+    // 11 bar(a) → 대기압 플래시 약 16% ((781 - 419) / 2257)
+    assert!((r.streams[0].flash_fraction - 0.160).abs() < 0.005);
+    assert!(r.streams[1].flash_fraction < r.streams[0].flash_fraction);
+    assert!(r.streams[2].flash_fraction.abs() < 1e-9);
+    let flash: f64 = r.streams.iter().map(|s| s.flash_steam_kg_per_h).sum();
+    assert!((r.flash_steam_kg_per_h - flash).abs() < 1e-9);
+    assert!((r.liquid_kg_per_h + r.flash_steam_kg_per_h - 2_800.0).abs() < 1e-9);
+    assert!((r.liquid_temp_c - 100.0).abs() < 0.1);
+
+    // 대기압 증기 비체적 약 1.67 m³/kg
+    assert!((r.vent_volumetric_flow_m3_per_h / r.flash_steam_kg_per_h - 1.673).abs() < 0.01);
+    assert!(r.vent_velocity_m_per_s <= r.vent_velocity_limit_m_per_s);
+    assert!(r.vent_inner_diameter_m >= r.vent_min_inner_diameter_m);
+    assert_eq!(r.vent_dn, Some(125));
+    // 플래시가 10%를 넘으면 회수 검토를 권한다
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ReceiverWarning::HighFlash { .. })));
+}
+
+#[test]
+fn receiver_volume_and_pump_follow_liquid_flow() {
+    let r = size_receiver(&input()).unwrap();
+    let liquid_m3_h = r.liquid_kg_per_h / 958.4;
+    assert!((r.surge_volume_m3 - liquid_m3_h / 6.0).abs() < 1e-3);
+    assert!((r.receiver_volume_m3 - 2.0 * r.surge_volume_m3).abs() < 1e-12);
+    assert!((r.pump_flow_m3_per_h - 2.0 * liquid_m3_h).abs() < 1e-3);
+    assert!(r.min_static_head_m.is_none());
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ReceiverWarning::NpshReminder { .. })));
+
+    let mut with_npshr = input();
+    with_npshr.pump_npshr_m = Some(2.0);
+    let r = size_receiver(&with_npshr).unwrap();
+    assert!((r.min_static_head_m.unwrap() - 2.2).abs() < 1e-12);
+    assert!(!r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ReceiverWarning::NpshReminder { .. })));
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    let mut below = input();
+    below.streams.push(stream("vacuum", 100.0, 0.5));
+    assert!(matches!(
+        size_receiver(&below),
+        Err(ReceiverError::InvalidInput(_))
+    ));
+    let mut empty = input();
+    empty.streams.clear();
+    assert!(size_receiver(&empty).is_err());
+    let mut small_pump = input();
+    small_pump.pump_capacity_factor = 0.8;
+    assert!(size_receiver(&small_pump).is_err());
+    let mut fraction = input();
+    fraction.working_fraction = 0.0;
+    assert!(size_receiver(&fraction).is_err());
+}
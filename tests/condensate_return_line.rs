@@ -0,0 +1,59 @@
+use steam_engineering_toolbox::condensate_recovery::{
+    size_return_line, ReturnLineInput, ReturnLineKind,
+};
+
+fn input(kind: ReturnLineKind) -> ReturnLineInput {
+    ReturnLineInput {
+        kind,
+        condensate_flow_kg_per_h: 5_000.0,
+        source_pressure_bar_abs: 11.0,
+        receiver_pressure_bar_abs: 1.2,
+        length_m: 100.0,
+        roughness_m: 4.5e-5,
+        lift_m: 0.0,
+        max_velocity_m_per_s: None,
+    }
+}
+
+#[test]
+fn flashing_trap_discharge_needs_larger_line_than_pumped_return() {
+    let trap = size_return_line(input(ReturnLineKind::TrapDischarge)).unwrap();
+    let pumped = size_return_line(input(ReturnLineKind::Pumped)).unwrap();
+    // 11 → 1.2 bar(a) 플래시 약 15% ((781 - 439) / 2244)
+    assert!((trap.flash_fraction - 0.152).abs() < 0.005);
+    assert!((trap.flash_steam_kg_per_h - 5_000.0 * trap.flash_fraction).abs() < 1e-9);
+    assert!(trap.volumetric_flow_m3_per_h > 50.0 * pumped.volumetric_flow_m3_per_h);
+    assert!(trap.recommended_dn.unwrap() > pumped.recommended_dn.unwrap());
+    assert!(trap.velocity_m_per_s <= trap.velocity_limit_m_per_s);
+    assert!(pumped.velocity_m_per_s <= 2.0);
+    assert!(trap.recommended_inner_diameter_m >= trap.min_inner_diameter_m);
+}
+
+#[test]
+fn rejects_receiver_above_source_and_warns_for_gravity_line() {
+    let mut bad = input(ReturnLineKind::Gravity);
+    bad.receiver_pressure_bar_abs = 12.0;
+    assert!(size_return_line(bad).is_err());
+
+    let gravity = size_return_line(input(ReturnLineKind::Gravity)).unwrap();
+    assert!((gravity.velocity_limit_m_per_s - 1.0).abs() < 1e-12);
+    assert!(!gravity.warnings.is_empty());
+}
+
+#[test]
+fn lift_after_trap_adds_static_head() {
+    let flat = size_return_line(input(ReturnLineKind::TrapDischarge)).unwrap();
+    let mut lifted = input(ReturnLineKind::TrapDischarge);
+    lifted.lift_m = 8.0;
+    let lifted = size_return_line(lifted).unwrap();
+    assert_eq!(flat.elevation_drop_bar, 0.0);
+    assert_eq!(lifted.pressure_drop_bar, flat.pressure_drop_bar);
+    // 균질 혼합 밀도는 포화수보다 훨씬 가벼우므로 정수두는 8 m 물기둥(≈0.75 bar)보다 작다.
+    assert!(lifted.elevation_drop_bar > 0.0 && lifted.elevation_drop_bar < 0.1);
+
+    let mut gravity = input(ReturnLineKind::Gravity);
+    gravity.lift_m = 2.0;
+    let gravity = size_return_line(gravity).unwrap();
+    assert!(gravity.elevation_drop_bar > 0.15);
+    assert!(gravity.warnings.iter().any(|w| w.contains("리프트")));
+}
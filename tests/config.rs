@@ -0,0 +1,64 @@
+use steam_engineering_toolbox::config::{self, Config, DetachedWindow, ThemeMode};
+
+#[test]
+fn site_conditions_drive_atmosphere() {
+    let cfg = Config::from_toml_str("[site]\nelevation_m = 1500.0\n").unwrap();
+    let atm = cfg.site.atmosphere().bar_abs();
+    assert!((atm - 0.8456).abs() < 1e-3);
+    assert_eq!(cfg.site.design_wet_bulb_c, 28.0);
+
+    let measured =
+        Config::from_toml_str("[site]\nelevation_m = 1500.0\nbarometric_pressure_bar_abs = 0.86\n")
+            .unwrap();
+    assert!((measured.site.atmosphere().bar_abs() - 0.86).abs() < 1e-12);
+    assert!((Config::default().site.atmosphere().bar_abs() - 1.01325).abs() < 1e-12);
+}
+
+#[test]
+fn profiles_round_trip_and_apply() {
+    let mut cfg = Config::default();
+    cfg.site.elevation_m = 800.0;
+    cfg.steam_cost.fuel_price_per_unit = 1.2;
+    cfg.save_profile("plant_b");
+    cfg.site.elevation_m = 0.0;
+
+    let mut reloaded = Config::from_toml_str(&cfg.to_toml_string().unwrap()).unwrap();
+    assert_eq!(reloaded.profile_names(), vec!["plant_b".to_string()]);
+    reloaded.apply_profile("plant_b").unwrap();
+    assert_eq!(reloaded.site.elevation_m, 800.0);
+    assert_eq!(reloaded.steam_cost.fuel_price_per_unit, 1.2);
+    assert_eq!(reloaded.active_profile.as_deref(), Some("plant_b"));
+    assert!(reloaded.apply_profile("missing").is_err());
+}
+
+#[test]
+fn theme_settings_persist_with_hex_colors() {
+    assert_eq!(Config::default().theme.mode, ThemeMode::SoftBlue);
+
+    let cfg = Config::from_toml_str(
+        "[theme]\nmode = \"dark\"\naccent = \"#1E90ff\"\nbackground = \"zz0000\"\nhigh_contrast = true\n",
+    )
+    .unwrap();
+    assert_eq!(cfg.theme.mode, ThemeMode::Dark);
+    assert_eq!(cfg.theme.accent_rgb(), Some([0x1E, 0x90, 0xFF]));
+    assert_eq!(cfg.theme.background_rgb(), None);
+    assert!(cfg.theme.high_contrast);
+
+    let reloaded = Config::from_toml_str(&cfg.to_toml_string().unwrap()).unwrap();
+    assert_eq!(reloaded.theme, cfg.theme);
+    assert_eq!(config::format_hex_color([0x1E, 0x90, 0xFF]), "#1E90FF");
+}
+
+#[test]
+fn detached_window_layout_round_trips() {
+    let cfg = Config::from_toml_str("[[detached_windows]]\npanel = \"cooling\"\n").unwrap();
+    assert_eq!(cfg.detached_windows, vec![DetachedWindow::new("cooling")]);
+
+    let mut cfg = Config::default();
+    let mut win = DetachedWindow::new("steam_tables");
+    win.position = Some([100.0, 80.0]);
+    win.size = [500.0, 600.0];
+    cfg.detached_windows = vec![win, DetachedWindow::new("vacuum_table")];
+    let reloaded = Config::from_toml_str(&cfg.to_toml_string().unwrap()).unwrap();
+    assert_eq!(reloaded.detached_windows, cfg.detached_windows);
+}
@@ -1,7 +1,8 @@
 use steam_engineering_toolbox::{
     conversion::PressureMode,
-    cooling::{condenser, cooling_tower, pump_npsh},
+    cooling::{air_cooled, condenser, cooling_comparison, cooling_tower, pump_npsh},
     units::PressureUnit,
+    warning::{self, CalcWarning, Severity},
 };
 
 #[test]
@@ -50,6 +51,7 @@ fn pump_npsh_margin_above_one() {
     let res = pump_npsh::compute_pump_npsh(pump_npsh::PumpNpshInput {
         suction_pressure_bar: 0.5,
         suction_is_abs: false,
+        atmospheric_pressure_bar_abs: 1.01325,
         liquid_temp_c: 25.0,
         static_head_m: 3.0,
         friction_loss_m: 1.0,
@@ -58,3 +60,124 @@ fn pump_npsh_margin_above_one() {
     });
     assert!(res.margin_ratio > 1.1);
 }
+
+#[test]
+fn saturated_suction_transient_loss_and_min_submergence() {
+    let base = pump_npsh::SaturatedSuctionInput {
+        vessel_pressure_bar_abs: 0.1,
+        submergence_m: 3.0,
+        friction_loss_m: 0.3,
+        npshr_m: 1.5,
+        suction_line_length_m: 10.0,
+        suction_velocity_m_per_s: 1.5,
+        pressure_decay_bar_per_s: 0.005,
+    };
+    let res = pump_npsh::compute_saturated_suction_npsh(base.clone()).unwrap();
+    assert!((res.npsha_steady_m - 2.7).abs() < 1e-12);
+    // 6.67 s × 0.005 bar/s ≈ 0.033 bar ≈ 0.34 m
+    assert!(
+        (res.transient_loss_m - 0.343).abs() < 0.01,
+        "{}",
+        res.transient_loss_m
+    );
+    assert!(res.warnings.is_empty());
+
+    let at_min = pump_npsh::compute_saturated_suction_npsh(pump_npsh::SaturatedSuctionInput {
+        submergence_m: res.min_submergence_m,
+        ..base.clone()
+    })
+    .unwrap();
+    assert!((at_min.margin_ratio_transient - pump_npsh::MIN_MARGIN_RATIO).abs() < 1e-9);
+
+    let fast = pump_npsh::compute_saturated_suction_npsh(pump_npsh::SaturatedSuctionInput {
+        pressure_decay_bar_per_s: 0.05,
+        ..base
+    })
+    .unwrap();
+    assert!(fast.margin_ratio_steady >= pump_npsh::MIN_MARGIN_RATIO);
+    assert!(fast.margin_ratio_transient < pump_npsh::MIN_MARGIN_RATIO);
+    assert_eq!(fast.warnings.len(), 1);
+    assert!(matches!(
+        fast.warnings[0],
+        condenser::CoolingWarning::TransientNpshMarginLow { .. }
+    ));
+    assert_eq!(fast.warnings[0].severity(), Severity::Caution);
+    assert_eq!(
+        fast.warnings[0].i18n_key(),
+        "warning.cooling.npsh_transient_margin_low"
+    );
+}
+
+#[test]
+fn air_cooled_condenser_design_area_reproduces_back_pressure_and_hot_day_rises() {
+    let input = air_cooled::AirCooledCondenserInput {
+        steam_flow_kg_per_h: 100_000.0,
+        exhaust_quality: 0.92,
+        design_back_pressure_bar_abs: 0.15,
+        design_ambient_c: 20.0,
+        air_temp_rise_k: 18.0,
+        condensing_h_w_m2k: 10_000.0,
+        air_side_h_w_m2k: 45.0,
+        fin_area_ratio: 20.0,
+        fin_efficiency: 0.85,
+        bare_tube_area_m2: None,
+        hot_day_ambient_c: Some(40.0),
+        max_back_pressure_bar_abs: Some(0.3),
+    };
+    let res = air_cooled::compute_air_cooled_condenser(input.clone()).expect("acc calc");
+    assert!((res.back_pressure_bar_abs - 0.15).abs() < 1e-6);
+    assert!((res.approach_to_dry_bulb_k - res.design_itd_k).abs() < 1e-6);
+    // 100 t/h × 0.92 × hfg(0.15 bar) ≈ 60 MW
+    assert!(res.heat_duty_kw > 58_000.0 && res.heat_duty_kw < 62_000.0);
+    let hot = res.hot_day.as_ref().expect("hot day");
+    assert!(
+        hot.back_pressure_bar_abs > 0.3 && hot.air_mass_flow_kg_per_s < res.air_mass_flow_kg_per_s
+    );
+    assert_eq!(
+        warning::max_severity(&res.warnings),
+        Some(Severity::Critical)
+    );
+
+    let mut small = input;
+    small.design_ambient_c = 45.0;
+    assert!(matches!(
+        air_cooled::compute_air_cooled_condenser(small),
+        Err(condenser::CoolingError::NegativeDeltaT)
+    ));
+}
+
+#[test]
+fn once_through_vs_recirculating_flows_makeup_and_pumping() {
+    let res =
+        cooling_comparison::compare_cooling_options(cooling_comparison::CoolingComparisonInput {
+            heat_duty_kw: 100_000.0,
+            source_water_temp_c: 20.0,
+            once_through_max_rise_k: 8.0,
+            max_discharge_temp_c: Some(32.0),
+            once_through_head_m: 15.0,
+            wet_bulb_c: 24.0,
+            tower_approach_k: 5.0,
+            tower_range_k: 10.0,
+            cycles_of_concentration: 4.0,
+            drift_fraction: 0.0005,
+            recirculating_head_m: 25.0,
+            pump_efficiency: 0.85,
+        })
+        .expect("comparison");
+    let ot = &res.once_through;
+    let rc = &res.recirculating;
+    // Q = ṁ·cp·ΔT → 100 MW / (4.186 × 8 K) ≈ 10 750 m³/h
+    assert!((ot.circulating_flow_m3_per_h - 10_750.0).abs() < 10.0);
+    assert!((res.flow_ratio - 0.8).abs() < 1e-9);
+    // 증발 ≈ 0.153%/K × 10 K ≈ 1.5% of 순환량, 보충수 = 증발 + 비산 + 블로다운
+    assert!((rc.evaporation_m3_per_h / rc.circulating_flow_m3_per_h - 0.0153).abs() < 1e-9);
+    assert!(
+        (rc.withdrawal_m3_per_h
+            - (rc.evaporation_m3_per_h + rc.drift_m3_per_h + rc.blowdown_m3_per_h))
+            .abs()
+            < 1e-9
+    );
+    assert!(res.withdrawal_ratio < 0.03);
+    assert!((res.pumping_power_ratio - 0.8 * 25.0 / 15.0).abs() < 1e-9);
+    assert!(!res.warnings.is_empty());
+}
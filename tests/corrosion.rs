@@ -0,0 +1,59 @@
+use steam_engineering_toolbox::plant_piping::corrosion::{assess_corrosion, CorrosionInput};
+use steam_engineering_toolbox::plant_piping::wall_thickness::{
+    WallThicknessError, WallThicknessInput,
+};
+
+fn inspected_line(measured_mm: f64) -> CorrosionInput {
+    CorrosionInput {
+        design: WallThicknessInput {
+            design_pressure_bar_g: 40.0,
+            design_temp_c: 200.0,
+            outer_diameter_m: 0.1143,
+            material: "A106B".into(),
+            weld_joint_efficiency: 1.0,
+            weld_strength_reduction: 1.0,
+            corrosion_allowance_m: 0.001,
+            mill_tolerance_frac: 0.125,
+        },
+        nominal_thickness_m: 6.02e-3,
+        measured_thickness_m: measured_mm / 1000.0,
+        corrosion_rate_mm_per_yr: None,
+        years_in_service: Some(10.0),
+        structural_minimum_m: Some(2.3e-3),
+    }
+}
+
+#[test]
+fn long_term_rate_gives_remaining_life_against_structural_minimum() {
+    let r = assess_corrosion(&inspected_line(4.02)).unwrap();
+    // (6.02 − 4.02) mm / 10 yr
+    assert!((r.corrosion_rate_mm_per_yr - 0.2).abs() < 1e-9);
+    // 구조 최소 2.3 mm가 내압 두께 1.82 mm보다 커서 은퇴 두께가 된다.
+    assert!((r.retirement_thickness_m - 2.3e-3).abs() < 1e-12);
+    assert!((r.remaining_allowance_m - 1.72e-3).abs() < 1e-9);
+    assert!((r.remaining_life_years.unwrap() - 8.6).abs() < 1e-6);
+    assert!((r.next_inspection_years.unwrap() - 4.3).abs() < 1e-6);
+    // 2·124·4.02 / (114.3 − 0.8·4.02) MPa ≈ 89.7 bar(g)
+    assert!((r.mawp_bar_g - 89.75).abs() < 0.05, "{}", r.mawp_bar_g);
+    assert!(!r.below_retirement);
+    // 감육 2 mm > 설계 부식 여유 1 mm
+    assert_eq!(r.warnings.len(), 1);
+}
+
+#[test]
+fn thin_reading_is_flagged_for_retirement() {
+    let mut input = inspected_line(2.0);
+    input.corrosion_rate_mm_per_yr = Some(0.3);
+    let r = assess_corrosion(&input).unwrap();
+    assert!(r.below_retirement);
+    assert_eq!(r.remaining_life_years, Some(0.0));
+    // 내압으로는 아직 견디지만(MAWP ≈ 44 bar(g)) 구조 최소 두께에 걸린다.
+    assert!(r.mawp_bar_g > 40.0);
+
+    input.corrosion_rate_mm_per_yr = None;
+    input.years_in_service = None;
+    assert!(matches!(
+        assess_corrosion(&input),
+        Err(WallThicknessError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,53 @@
+use steam_engineering_toolbox::steam::critical_flow::{
+    ideal_gas_critical_mass_flux, ideal_gas_critical_pressure_ratio, steam_critical_flow,
+    steam_nozzle_flow, SteamStagnation,
+};
+use steam_engineering_toolbox::steam::steam_valves;
+use steam_engineering_toolbox::warning::CalcWarning;
+
+#[test]
+fn ideal_gas_air_matches_textbook() {
+    assert!((ideal_gas_critical_pressure_ratio(1.4) - 0.5283).abs() < 1e-4);
+    // 공기 10 bar(a), 20°C: G* ≈ 0.0404·p0/√T0 (SI) ≈ 2360 kg/m²s
+    let air = ideal_gas_critical_mass_flux(10.0, 20.0, 1.4, 28.964, 1.0).unwrap();
+    assert!((air.mass_flux_kg_per_m2_s - 2360.0).abs() < 10.0);
+}
+
+#[test]
+fn steam_critical_ratio_from_if97() {
+    let sh = steam_critical_flow(10.0, SteamStagnation::Temperature(300.0)).unwrap();
+    assert!((sh.critical_pressure_ratio - 0.546).abs() < 0.01);
+    let sat = steam_critical_flow(10.0, SteamStagnation::Quality(1.0)).unwrap();
+    assert!(sat.critical_pressure_ratio > sh.critical_pressure_ratio);
+    // Napier 식(W[lb/s] = p[psia]·A[in²]/70) 기준 10 bar(a) 건포화 증기 ≈ 1460 kg/m²s
+    assert!(sat.mass_flux_kg_per_m2_s > 1400.0 && sat.mass_flux_kg_per_m2_s < 1600.0);
+
+    let area = 1e-4;
+    let choked = steam_nozzle_flow(10.0, SteamStagnation::Quality(1.0), 1.0, area, 1.0).unwrap();
+    let sub = steam_nozzle_flow(10.0, SteamStagnation::Quality(1.0), 8.0, area, 1.0).unwrap();
+    assert!(choked.choked && !sub.choked);
+    assert!(sub.mass_flow_kg_per_h < choked.mass_flow_kg_per_h);
+    assert!((choked.mass_flow_kg_per_h - sat.mass_flux_kg_per_m2_s * area * 3600.0).abs() < 1e-6);
+}
+
+#[test]
+fn valve_flow_caps_only_steam_at_the_critical_drop() {
+    // 급수 스프레이: 출구가 입구의 30%여도 액체는 임계 판정 없이 Kv 식 그대로
+    let water = steam_valves::flow_from_kv(2.0, 70.0, 950.0).unwrap();
+    assert!((water - 2.0 * (70.0f64 * 0.95).sqrt()).abs() < 1e-9);
+
+    let dry = SteamStagnation::Quality(1.0);
+    let sub = steam_valves::steam_flow_from_kv(50.0, 2.0, 5.0, 10.0, dry).unwrap();
+    assert!(!sub.choked && sub.warning().is_none());
+    assert_eq!(sub.effective_delta_p_bar, 2.0);
+
+    let choked = steam_valves::steam_flow_from_kv(50.0, 7.0, 5.0, 10.0, dry).unwrap();
+    let ratio = steam_critical_flow(10.0, dry)
+        .unwrap()
+        .critical_pressure_ratio;
+    assert!(choked.choked);
+    assert!((choked.effective_delta_p_bar - 10.0 * (1.0 - ratio)).abs() < 1e-12);
+    let at_critical = steam_valves::flow_from_kv(50.0, choked.effective_delta_p_bar, 5.0).unwrap();
+    assert_eq!(choked.volumetric_flow_m3_per_h, at_critical);
+    assert_eq!(choked.warning().unwrap().i18n_key(), "warning.valve.choked");
+}
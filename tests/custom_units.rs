@@ -0,0 +1,82 @@
+use steam_engineering_toolbox::custom_units::{self, CustomUnit};
+use steam_engineering_toolbox::quantity::QuantityKind;
+
+#[test]
+fn custom_units_share_base_unit() {
+    let n = custom_units::load_from_str(
+        r#"
+[[unit]]
+name = "MMBtu"
+kind = "energy"
+base_unit = "kJ"
+scale = 1055056.0
+
+[[unit]]
+name = "therm"
+kind = "energy"
+base_unit = "kJ"
+scale = 105505.6
+"#,
+    )
+    .unwrap();
+    assert_eq!(n, 2);
+    let therms = custom_units::convert(QuantityKind::Energy, 1.0, "mmbtu", "therm").unwrap();
+    assert!((therms - 10.0).abs() < 1e-9);
+    let names: Vec<String> = custom_units::units_for(QuantityKind::Energy)
+        .into_iter()
+        .map(|u| u.name)
+        .collect();
+    assert!(names.contains(&"MMBtu".to_string()));
+    assert!(custom_units::units_for(QuantityKind::Pressure)
+        .iter()
+        .all(|u| u.name != "therm"));
+}
+
+#[test]
+fn rejects_invalid_definitions() {
+    let bad_kind = CustomUnit {
+        name: "foo".into(),
+        kind: "flux".into(),
+        base_unit: "kJ".into(),
+        scale: 1.0,
+        offset: 0.0,
+    };
+    assert!(custom_units::register(bad_kind).is_err());
+    let zero_scale = CustomUnit {
+        name: "bar2".into(),
+        kind: "pressure".into(),
+        base_unit: "bar".into(),
+        scale: 0.0,
+        offset: 0.0,
+    };
+    assert!(custom_units::register(zero_scale).is_err());
+    assert!(custom_units::find(QuantityKind::Pressure, "bar2").is_none());
+}
+
+#[test]
+fn invalid_entry_leaves_file_unregistered() {
+    let err = custom_units::load_from_str(
+        r#"
+[[unit]]
+name = "ksc"
+kind = "pressure"
+base_unit = "bar"
+scale = 0.980665
+
+[[unit]]
+name = "Mcal"
+kind = "energy"
+base_unit = "kJ"
+scale = 4186.8
+
+[[unit]]
+name = "broken"
+kind = "pressure"
+base_unit = "bar"
+scale = 0.0
+"#,
+    );
+    assert!(err.is_err());
+    assert!(custom_units::find(QuantityKind::Pressure, "ksc").is_none());
+    assert!(custom_units::find(QuantityKind::Energy, "Mcal").is_none());
+}
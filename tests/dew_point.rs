@@ -0,0 +1,92 @@
+use steam_engineering_toolbox::combustion::dew_point::{
+    self, acid_dew_point_c, flue_gas_dew_points, water_dew_point_c, DewPointWarning,
+    FlueGasDewPoints, ATMOSPHERIC_PRESSURE_BAR_ABS, DEFAULT_SO3_CONVERSION,
+    STANDARD_AIR_HUMIDITY_RATIO,
+};
+use steam_engineering_toolbox::combustion::fuel::{flue_gas, FuelType};
+use steam_engineering_toolbox::steam::boiler_efficiency::{
+    boiler_efficiency_ptc, BoilerEfficiencyPtcInput,
+};
+use steam_engineering_toolbox::steam::hrsg::{compute_hrsg, HrsgInput};
+
+fn dew_points(fuel: FuelType, excess_air: f64) -> FlueGasDewPoints {
+    let gas = flue_gas(&fuel.analysis(), excess_air, STANDARD_AIR_HUMIDITY_RATIO);
+    flue_gas_dew_points(&gas, DEFAULT_SO3_CONVERSION, ATMOSPHERIC_PRESSURE_BAR_ABS).unwrap()
+}
+
+#[test]
+fn natural_gas_has_only_a_water_dew_point() {
+    let dp = dew_points(FuelType::NaturalGas, 0.15);
+    // 천연가스 15% 과잉공기: H₂O 약 18 vol% → 수분 노점 약 58 °C
+    assert!((55.0..62.0).contains(&dp.water_dew_point_c), "{dp:?}");
+    assert!(dp.acid_dew_point_c.is_none());
+    assert_eq!(dp.so3_ppmv, 0.0);
+    // 10 vol% H₂O, 대기압: 분압 0.101 bar → 약 46 °C
+    let t = water_dew_point_c(0.10, 1.01325).unwrap();
+    assert!((t - 46.1).abs() < 0.5, "{t}");
+    assert!(water_dew_point_c(0.0, 1.01325).is_err());
+}
+
+#[test]
+fn acid_dew_point_rises_with_so3() {
+    let dp = dew_points(FuelType::FuelOil6, 0.15);
+    let adp = dp.acid_dew_point_c.unwrap();
+    assert!((130.0..160.0).contains(&adp), "{adp}");
+    assert!(adp > dp.water_dew_point_c + 50.0);
+    // Verhoff-Banchero: 10% H₂O, 10 ppmv SO₃ → 약 139 °C
+    let t = acid_dew_point_c(0.10, 10e-6, 1.01325).unwrap();
+    assert!((t - 139.0).abs() < 3.0, "{t}");
+    assert!(acid_dew_point_c(0.10, 20e-6, 1.01325).unwrap() > t);
+
+    let warnings = dew_point::stack_dew_point_warnings(adp + 5.0, &dp);
+    assert!(matches!(
+        warnings[..],
+        [DewPointWarning::NearAcidDewPoint { .. }]
+    ));
+    assert!(dew_point::stack_dew_point_warnings(adp + 30.0, &dp).is_empty());
+}
+
+#[test]
+fn boiler_and_hrsg_stack_warnings_use_the_dew_points() {
+    let oil = dew_points(FuelType::FuelOil6, 0.15);
+    let ptc = |stack_temp_c: f64| BoilerEfficiencyPtcInput {
+        fuel_flow_per_h: 1_000.0,
+        fuel_lhv_kj_per_unit: 40_500.0,
+        steam_flow_kg_per_h: 14_000.0,
+        steam_enthalpy_kj_per_kg: 2_780.0,
+        feedwater_enthalpy_kj_per_kg: 440.0,
+        flue_gas_flow_kg_per_h: 16_000.0,
+        flue_gas_cp_kj_per_kgk: 1.05,
+        stack_temp_c,
+        ambient_temp_c: 25.0,
+        excess_air_frac: 0.15,
+        radiation_loss_frac: 0.01,
+        blowdown_rate_frac: 0.03,
+        blowdown_enthalpy_kj_per_kg: 800.0,
+        flue_gas_dew_points: Some(oil),
+    };
+    assert!(boiler_efficiency_ptc(ptc(200.0)).warnings.is_empty());
+    let cold = boiler_efficiency_ptc(ptc(120.0));
+    assert!(cold
+        .warnings
+        .iter()
+        .any(|w| matches!(w, DewPointWarning::BelowAcidDewPoint { .. })));
+
+    let hrsg = |dew_points| HrsgInput {
+        gas_flow_kg_per_s: 100.0,
+        gas_inlet_temp_c: 550.0,
+        gas_cp_kj_per_kgk: 1.1,
+        steam_pressure_bar_abs: 10.0,
+        steam_temp_c: None,
+        feedwater_temp_c: 40.0,
+        pinch_k: 8.0,
+        approach_k: 5.0,
+        flue_gas_dew_points: dew_points,
+    };
+    let gas_turbine = dew_points(FuelType::NaturalGas, 2.5);
+    let res = compute_hrsg(hrsg(Some(gas_turbine))).unwrap();
+    assert!(res.stack_temp_c > gas_turbine.water_dew_point_c);
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+    let generic = compute_hrsg(hrsg(None)).unwrap();
+    assert!(generic.warnings.iter().any(|w| w.contains("산노점")));
+}
@@ -0,0 +1,59 @@
+use steam_engineering_toolbox::steam::drum::{
+    blowdown_line_check, drum_swell, BlowdownLineInput, DrumOperatingPoint, DrumSwellInput,
+};
+
+#[test]
+fn load_increase_with_pressure_dip_swells() {
+    let base = DrumSwellInput {
+        drum_inner_diameter_m: 1.5,
+        drum_length_m: 8.0,
+        normal_water_level_m: 0.75,
+        riser_volume_m3: 6.0,
+        circulation_flow_kg_per_h: 500_000.0,
+        before: DrumOperatingPoint {
+            pressure_bar_abs: 60.0,
+            steam_flow_kg_per_h: 40_000.0,
+        },
+        after: DrumOperatingPoint {
+            pressure_bar_abs: 55.0,
+            steam_flow_kg_per_h: 60_000.0,
+        },
+    };
+    let swell = drum_swell(base.clone()).unwrap();
+    assert!(swell.mean_void_after > swell.mean_void_before);
+    assert!(swell.level_change_mm > 0.0);
+    assert!((swell.water_surface_area_m2 - 1.5 * 8.0).abs() < 1e-9);
+
+    let shrink = drum_swell(DrumSwellInput {
+        before: base.after,
+        after: base.before,
+        ..base
+    })
+    .unwrap();
+    assert!((shrink.level_change_mm + swell.level_change_mm).abs() < 1e-9);
+}
+
+#[test]
+fn blowdown_line_capacity_and_flash() {
+    let res = blowdown_line_check(BlowdownLineInput {
+        drum_pressure_bar_abs: 41.0,
+        blowdown_flow_kg_per_h: 2_000.0,
+        line_diameter_m: 0.025,
+        flash_tank_pressure_bar_abs: 1.5,
+    })
+    .unwrap();
+    // 41 bar(a) 포화수 → 1.5 bar(a) 플래시 분율 약 0.28 ((1090 - 467) / 2226)
+    assert!((res.flash_fraction - 0.28).abs() < 0.01);
+    assert!(res.choked_capacity_kg_per_h > 2_000.0);
+    assert!(res.capacity_utilization_percent < 100.0);
+
+    let small = blowdown_line_check(BlowdownLineInput {
+        drum_pressure_bar_abs: 41.0,
+        blowdown_flow_kg_per_h: 2_000.0,
+        line_diameter_m: 0.005,
+        flash_tank_pressure_bar_abs: 1.5,
+    })
+    .unwrap();
+    assert!(small.capacity_utilization_percent > 100.0);
+    assert!(!small.warnings.is_empty());
+}
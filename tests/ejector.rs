@@ -0,0 +1,62 @@
+use steam_engineering_toolbox::steam::critical_flow::SteamStagnation;
+use steam_engineering_toolbox::steam::ejector::{
+    size_ejector, EjectorArrangement, EjectorError, EjectorInput,
+};
+
+fn base(arrangement: EjectorArrangement, suction_bar_abs: f64) -> EjectorInput {
+    EjectorInput {
+        arrangement,
+        suction_pressure_bar_abs: suction_bar_abs,
+        suction_temp_c: 20.0,
+        discharge_pressure_bar_abs: 1.03,
+        air_load_kg_per_h: 10.0,
+        vapor_load_kg_per_h: 0.0,
+        motive_pressure_bar_abs: 10.0,
+        motive_state: SteamStagnation::Quality(1.0),
+        interstage_pressure_bar_abs: None,
+        intercondenser_outlet_temp_c: 35.0,
+        efficiency: None,
+    }
+}
+
+#[test]
+fn single_stage_air_ratio_matches_default_calibration() {
+    let res = size_ejector(base(EjectorArrangement::SingleStage, 0.13)).unwrap();
+    assert_eq!(res.stages.len(), 1);
+    let ratio = res.total_motive_steam_kg_per_h / 10.0;
+    assert!(ratio > 2.0 && ratio < 3.0, "{ratio}");
+    assert!(res.intercondenser.is_none());
+
+    // 수증기 부하는 HEI 보정으로 공기보다 무겁게 계산된다.
+    let mut wet = base(EjectorArrangement::SingleStage, 0.13);
+    wet.air_load_kg_per_h = 0.0;
+    wet.vapor_load_kg_per_h = 10.0;
+    let wet = size_ejector(wet).unwrap();
+    assert!((wet.stages[0].air_equivalent_kg_per_h - 10.0 / 0.81).abs() < 1e-9);
+}
+
+#[test]
+fn two_stage_splits_compression_and_condenses_motive() {
+    let mut input = base(EjectorArrangement::TwoStage, 0.05);
+    input.vapor_load_kg_per_h = 20.0;
+    let res = size_ejector(input.clone()).unwrap();
+    assert_eq!(res.stages.len(), 2);
+    assert!((res.stages[0].compression_ratio - res.stages[1].compression_ratio).abs() < 1e-9);
+    let ic = res.intercondenser.as_ref().unwrap();
+    assert!(ic.condensed_kg_per_h > res.stages[0].motive_steam_kg_per_h);
+    assert!(ic.duty_kw > 0.0);
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+
+    let single = size_ejector(EjectorInput {
+        arrangement: EjectorArrangement::SingleStage,
+        ..input.clone()
+    })
+    .unwrap();
+    assert!(!single.warnings.is_empty());
+
+    input.interstage_pressure_bar_abs = Some(2.0);
+    assert!(matches!(
+        size_ejector(input),
+        Err(EjectorError::InvalidInput(_))
+    ));
+}
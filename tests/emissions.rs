@@ -0,0 +1,79 @@
+use steam_engineering_toolbox::combustion::emissions::{
+    ap42_nox_factor_kg_per_gj, compute_emissions, BurnerType, EmissionsError, EmissionsInput,
+};
+use steam_engineering_toolbox::combustion::fuel::FuelType;
+
+fn boiler(fuel_type: FuelType) -> EmissionsInput {
+    EmissionsInput {
+        fuel_type,
+        fuel: fuel_type.analysis(),
+        fuel_flow_kg_per_h: 1_000.0,
+        fuel_lhv_kj_per_kg: fuel_type.lhv_kj_per_kg(),
+        burner: BurnerType::Conventional,
+        operating_hours_per_year: 8_000.0,
+        nox_factor_kg_per_gj: None,
+        useful_output_kw: Some(11_500.0),
+    }
+}
+
+#[test]
+fn carbon_and_sulfur_balances_give_co2_and_so2() {
+    let ng = compute_emissions(boiler(FuelType::NaturalGas)).unwrap();
+    // 탄소 73%: 1000 × 0.73 × 44.01/12.011 ≈ 2675 kg/h
+    assert!(
+        (ng.co2.kg_per_h - 2674.8).abs() < 1.0,
+        "{}",
+        ng.co2.kg_per_h
+    );
+    assert!((ng.co2.tonnes_per_year - ng.co2.kg_per_h * 8.0).abs() < 1e-9);
+    // 천연가스 CO₂ 원단위 약 50 kg/GJ(고위) (EPA 53.06 kg/MMBtu)
+    assert!(
+        (49.0..53.0).contains(&ng.co2.kg_per_gj),
+        "{}",
+        ng.co2.kg_per_gj
+    );
+    assert_eq!(ng.so2.kg_per_h, 0.0);
+    assert!(ng.heat_input_hhv_kw > ng.heat_input_lhv_kw * 1.1);
+    let per_mwh = ng.co2_per_useful_mwh_kg.unwrap();
+    assert!((per_mwh - ng.co2.kg_per_h / 11.5).abs() < 1e-9);
+
+    let oil = compute_emissions(boiler(FuelType::FuelOil6)).unwrap();
+    // 황 2.5%: 1000 × 0.025 × 64.066/32.06 ≈ 50 kg/h
+    assert!(
+        (oil.so2.kg_per_h - 49.96).abs() < 0.1,
+        "{}",
+        oil.so2.kg_per_h
+    );
+}
+
+#[test]
+fn nox_follows_ap42_burner_and_size_classes() {
+    let small = compute_emissions(boiler(FuelType::NaturalGas)).unwrap();
+    assert!(small.nox_from_ap42);
+    // 100 lb/10⁶ scf ÷ 1020 Btu/scf ≈ 0.098 lb/MMBtu ≈ 42 g/GJ
+    assert!((small.nox_factor_kg_per_gj - 0.0421).abs() < 0.001);
+    assert!(
+        (small.nox.kg_per_h - small.nox.kg_per_gj * small.heat_input_hhv_kw * 3.6e-3).abs() < 1e-9
+    );
+    let lnb = ap42_nox_factor_kg_per_gj(FuelType::NaturalGas, BurnerType::LowNox, 10_000.0);
+    let large = ap42_nox_factor_kg_per_gj(FuelType::NaturalGas, BurnerType::LowNox, 50_000.0);
+    assert!(lnb < small.nox_factor_kg_per_gj && large > lnb);
+
+    let measured = compute_emissions(EmissionsInput {
+        nox_factor_kg_per_gj: Some(0.03),
+        ..boiler(FuelType::NaturalGas)
+    })
+    .unwrap();
+    assert!(!measured.nox_from_ap42);
+    assert!((measured.nox.kg_per_gj - 0.03).abs() < 1e-12);
+}
+
+#[test]
+fn rejects_operating_hours_beyond_a_year() {
+    let err = compute_emissions(EmissionsInput {
+        operating_hours_per_year: 9_000.0,
+        ..boiler(FuelType::FuelOil2)
+    })
+    .unwrap_err();
+    assert!(matches!(err, EmissionsError::InvalidInput(_)));
+}
@@ -0,0 +1,30 @@
+use steam_engineering_toolbox::config::ConfigError;
+use steam_engineering_toolbox::error::ToolboxError;
+use steam_engineering_toolbox::flow_units::FlowUnitError;
+use steam_engineering_toolbox::i18n::Translator;
+use steam_engineering_toolbox::steam::steam_tables::SteamTableError;
+
+#[test]
+fn module_errors_convert_with_stable_codes() {
+    let e: ToolboxError = SteamTableError::OutOfRange("압력").into();
+    assert!(matches!(e, ToolboxError::SteamTable(_)));
+    assert_eq!(e.code(), "E101");
+    assert_eq!(e.i18n_key(), "error.steam_table");
+
+    let e = ToolboxError::from(FlowUnitError::UnknownUnit("furlong/h".into()));
+    assert_eq!(e.code(), "E404");
+    assert!(e.to_string().contains("furlong/h"));
+
+    let e = ToolboxError::from(ConfigError::UnknownProfile("plant-b".into()));
+    assert_eq!(e.code(), "E401");
+    assert_eq!(ToolboxError::Unimplemented("x").code(), "E900");
+}
+
+#[test]
+fn localized_message_has_code_summary_and_detail() {
+    let tr = Translator::new("en-us");
+    let e = ToolboxError::from(FlowUnitError::UnknownUnit("furlong/h".into()));
+    let text = e.localized(&tr);
+    assert!(text.starts_with("[E404] "));
+    assert!(text.ends_with("furlong/h"));
+}
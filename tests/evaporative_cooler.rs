@@ -0,0 +1,54 @@
+use steam_engineering_toolbox::air::evaporative_cooler::{
+    compute_evaporative_cooler, EvaporativeCoolerError, EvaporativeCoolerInput, InletHumidity,
+};
+use steam_engineering_toolbox::air::humid_air::{humidity_ratio_from_rh, wet_bulb_from_rh};
+
+fn cooler(inlet_humidity: InletHumidity) -> EvaporativeCoolerInput {
+    EvaporativeCoolerInput {
+        dry_bulb_c: 35.0,
+        inlet_humidity,
+        effectiveness: 0.85,
+        air_flow_m3_per_h: 10_000.0,
+        total_pressure_kpa: 101.325,
+        cycles_of_concentration: 3.0,
+    }
+}
+
+#[test]
+fn psychrometric_wet_bulb_matches_chart_and_round_trips() {
+    // 30 °C, 50 %RH → 습구 약 22.0 °C (ASHRAE 선도)
+    let wb = wet_bulb_from_rh(30.0, 50.0, 101.325).unwrap();
+    assert!((wb - 22.0).abs() < 0.3, "{wb}");
+
+    let r = compute_evaporative_cooler(cooler(InletHumidity::RelativeHumidity(50.0))).unwrap();
+    let w = humidity_ratio_from_rh(35.0, 50.0, 101.325).humidity_ratio;
+    assert!((r.inlet_humidity_ratio - w).abs() < 1e-12);
+    assert!((r.inlet_rh_pct - 50.0).abs() < 1e-6);
+}
+
+#[test]
+fn outlet_follows_effectiveness_and_enthalpy_is_conserved() {
+    let r = compute_evaporative_cooler(cooler(InletHumidity::WetBulb(20.0))).unwrap();
+    assert!((r.outlet_dry_bulb_c - 22.25).abs() < 1e-9);
+    assert!(r.outlet_humidity_ratio > r.inlet_humidity_ratio);
+    assert!(
+        (r.outlet_enthalpy_kj_per_kg - r.inlet_enthalpy_kj_per_kg).abs() < 0.5,
+        "{} vs {}",
+        r.outlet_enthalpy_kj_per_kg,
+        r.inlet_enthalpy_kj_per_kg
+    );
+    // 10 000 m³/h, ΔW ≈ 0.005 → 약 60 kg/h 증발, 배수 3 → 블로다운 절반
+    assert!(
+        r.evaporation_kg_per_h > 45.0 && r.evaporation_kg_per_h < 75.0,
+        "{}",
+        r.evaporation_kg_per_h
+    );
+    assert!((r.bleed_kg_per_h - r.evaporation_kg_per_h / 2.0).abs() < 1e-9);
+    // 현열 냉각량 ≈ 증발 잠열
+    assert!((r.sensible_cooling_kw - r.evaporation_kg_per_h * 2450.0 / 3600.0).abs() < 5.0);
+
+    assert!(matches!(
+        compute_evaporative_cooler(cooler(InletHumidity::WetBulb(40.0))),
+        Err(EvaporativeCoolerError::InvalidInput(_))
+    ));
+}
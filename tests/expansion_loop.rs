@@ -0,0 +1,78 @@
+use steam_engineering_toolbox::plant_piping::expansion_loop::{
+    size_expansion_loop, ExpansionLoopError, ExpansionLoopInput, LoopLayout,
+};
+
+fn dn200(layout: LoopLayout, available_leg_m: Option<f64>) -> ExpansionLoopInput {
+    ExpansionLoopInput {
+        outer_diameter_m: 0.2191,
+        wall_thickness_m: 0.00818,
+        material: "A106B".into(),
+        install_temp_c: 20.0,
+        design_temp_c: 300.0,
+        layout,
+        available_leg_m,
+    }
+}
+
+#[test]
+fn l_bend_leg_meets_allowable_and_u_loop_splits_displacement() {
+    let l = size_expansion_loop(dn200(LoopLayout::LBend { long_leg_m: 50.0 }, None)).unwrap();
+    // α ≈ 13.2e-6 /K → 50 m × 280 K ≈ 185 mm
+    assert!(
+        (l.total_expansion_mm - 184.8).abs() < 1.0,
+        "{}",
+        l.total_expansion_mm
+    );
+    assert!((l.stress_mpa - l.allowable_range_mpa).abs() < 1e-6);
+    assert!(
+        l.required_leg_m > 10.0 && l.required_leg_m < 12.0,
+        "{}",
+        l.required_leg_m
+    );
+    assert!(l.anchor_force_n > 0.0 && l.warnings.is_empty());
+    // 운전 반력 = 냉간 반력 · E_h/E_c (300 °C 184 / 20 °C 203 GPa)
+    assert!((l.hot_anchor_force_n / l.anchor_force_n - 184.0 / 203.0).abs() < 1e-9);
+
+    // 설치 온도가 20 °C가 아니면 열변형률 차로 팽창량을 구한다.
+    let mut warm = dn200(LoopLayout::LBend { long_leg_m: 50.0 }, None);
+    warm.install_temp_c = 100.0;
+    let warm = size_expansion_loop(warm).unwrap();
+    let expected = (13.2e-6 * 280.0 - 11.9e-6 * 80.0) * 50.0 * 1000.0;
+    assert!(
+        (warm.total_expansion_mm - expected).abs() < 1e-6,
+        "{}",
+        warm.total_expansion_mm
+    );
+
+    let u = size_expansion_loop(dn200(
+        LoopLayout::ULoop {
+            anchor_spacing_m: 50.0,
+        },
+        None,
+    ))
+    .unwrap();
+    assert!((u.leg_displacement_mm - l.total_expansion_mm / 2.0).abs() < 1e-9);
+    assert!((u.required_leg_m - l.required_leg_m / 2f64.sqrt()).abs() < 1e-9);
+    assert_eq!(u.loop_width_m, Some(u.required_leg_m / 2.0));
+}
+
+#[test]
+fn short_available_leg_warns_and_unknown_material_fails() {
+    let z = size_expansion_loop(dn200(
+        LoopLayout::ZBend {
+            leg1_m: 20.0,
+            leg2_m: 30.0,
+        },
+        Some(5.0),
+    ))
+    .unwrap();
+    assert!(z.stress_mpa > z.allowable_range_mpa);
+    assert!(!z.warnings.is_empty());
+
+    let mut input = dn200(LoopLayout::LBend { long_leg_m: 10.0 }, None);
+    input.material = "UNOBTANIUM".into();
+    assert!(matches!(
+        size_expansion_loop(input),
+        Err(ExpansionLoopError::UnknownMaterial(_))
+    ));
+}
@@ -0,0 +1,94 @@
+use std::f64::consts::PI;
+
+use steam_engineering_toolbox::steam::fire_case::{
+    fire_case, fire_heat_input_w, FireCaseError, FireCaseInput, FireVessel, VesselOrientation,
+    WettedSurface,
+};
+use steam_engineering_toolbox::vessel_geometry::HeadType;
+
+fn vessel(
+    orientation: VesselOrientation,
+    length_m: f64,
+    liquid_level_m: f64,
+    elevation_m: f64,
+) -> FireVessel {
+    FireVessel {
+        orientation,
+        inner_diameter_m: 2.0,
+        length_m,
+        heads: HeadType::Flat,
+        liquid_level_m,
+        elevation_m,
+    }
+}
+
+fn input(surface: WettedSurface) -> FireCaseInput {
+    FireCaseInput {
+        surface,
+        environment_factor: 1.0,
+        adequate_drainage: true,
+        relieving_pressure_bar_abs: 10.0,
+        latent_heat_kj_per_kg: None,
+    }
+}
+
+#[test]
+fn wetted_area_follows_level_and_fire_zone() {
+    // 반만 찬 수평 용기: 동체 절반 + 반원 헤드 2개
+    let half = vessel(VesselOrientation::Horizontal, 6.0, 1.0, 0.0);
+    assert!((half.wetted_area_m2() - 7.0 * PI).abs() < 1e-9);
+    let full = vessel(VesselOrientation::Horizontal, 6.0, 2.0, 0.0);
+    assert!((full.wetted_area_m2() - (2.0 * PI * 6.0 + 2.0 * PI)).abs() < 1e-9);
+
+    // 수직 용기는 지면 위 7.6 m까지만 젖음 면적으로 본다 (바닥 헤드 포함)
+    let tall = vessel(VesselOrientation::Vertical, 5.0, 3.0, 6.0);
+    assert!((tall.wetted_area_m2() - (PI * 2.0 * 1.6 + PI)).abs() < 1e-9);
+    let high = vessel(VesselOrientation::Vertical, 5.0, 3.0, 8.0);
+    assert_eq!(high.wetted_area_m2(), 0.0);
+    let res = fire_case(&input(WettedSurface::Vessel(high))).unwrap();
+    assert_eq!(res.relief_rate_kg_per_h, 0.0);
+    assert_eq!(res.warnings.len(), 1);
+}
+
+#[test]
+fn relief_rate_uses_if97_latent_heat_and_drainage() {
+    let res = fire_case(&input(WettedSurface::Area(20.0))).unwrap();
+    assert!((res.heat_input_kw - 43.2 * 20f64.powf(0.82)).abs() < 1e-9);
+    // 10 bar(a) 포화 잠열 약 2014 kJ/kg
+    assert!((res.latent_heat_kj_per_kg - 2014.0).abs() < 2.0);
+    assert!(
+        (res.relief_rate_kg_per_h - res.heat_input_kw / res.latent_heat_kj_per_kg * 3600.0).abs()
+            < 1e-9
+    );
+    assert!(res.warnings.is_empty());
+
+    let mut poor = input(WettedSurface::Area(20.0));
+    poor.adequate_drainage = false;
+    poor.latent_heat_kj_per_kg = Some(300.0);
+    let res = fire_case(&poor).unwrap();
+    assert!((res.heat_input_kw * 1000.0 - fire_heat_input_w(20.0, 1.0, false)).abs() < 1e-6);
+    assert_eq!(res.latent_heat_kj_per_kg, 300.0);
+
+    let mut near_critical = input(WettedSurface::Area(20.0));
+    near_critical.relieving_pressure_bar_abs = 200.0;
+    assert_eq!(fire_case(&near_critical).unwrap().warnings.len(), 1);
+}
+
+#[test]
+fn invalid_input_is_rejected() {
+    let overfull = vessel(VesselOrientation::Horizontal, 6.0, 2.5, 0.0);
+    assert!(matches!(
+        fire_case(&input(WettedSurface::Vessel(overfull))),
+        Err(FireCaseError::InvalidInput(_))
+    ));
+    let mut bad_f = input(WettedSurface::Area(20.0));
+    bad_f.environment_factor = 0.0;
+    assert!(matches!(
+        fire_case(&bad_f),
+        Err(FireCaseError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        fire_case(&input(WettedSurface::Area(-1.0))),
+        Err(FireCaseError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,43 @@
+use steam_engineering_toolbox::flow_coefficient::{self, FlowCoefficientKind};
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * b.abs().max(1e-12)
+}
+
+#[test]
+fn coefficient_conversions_follow_definitions() {
+    // Cv(US) 1 = 1 US gpm @ 1 psi → 0.2271 m³/h × √(1 bar / 1 psi)
+    let kv = FlowCoefficientKind::CvUs.to_kv(100.0);
+    assert!(close(kv, 86.4978, 1e-5));
+    // Imperial gallon / US gallon = 1.20095
+    let cv_us =
+        flow_coefficient::convert(10.0, FlowCoefficientKind::CvUk, FlowCoefficientKind::CvUs);
+    assert!(close(cv_us, 12.0095, 1e-4));
+    // Av = 2.78e-5 Kv = 2.40e-5 Cv
+    let av = flow_coefficient::convert(1.0, FlowCoefficientKind::CvUs, FlowCoefficientKind::Av);
+    assert!(close(av, 2.4027e-5, 1e-3));
+    for kind in FlowCoefficientKind::ALL {
+        assert!(close(kind.from_kv(kind.to_kv(42.0)), 42.0, 1e-12));
+    }
+}
+
+#[test]
+fn kind_codes() {
+    assert_eq!(
+        FlowCoefficientKind::from_code("Cv (UK)"),
+        Some(FlowCoefficientKind::CvUk)
+    );
+    assert_eq!(
+        FlowCoefficientKind::from_code("cv"),
+        Some(FlowCoefficientKind::CvUs)
+    );
+    assert_eq!(
+        FlowCoefficientKind::from_code("KV"),
+        Some(FlowCoefficientKind::Kv)
+    );
+    assert_eq!(
+        FlowCoefficientKind::from_code("Av"),
+        Some(FlowCoefficientKind::Av)
+    );
+    assert_eq!(FlowCoefficientKind::from_code("Cd"), None);
+}
@@ -0,0 +1,59 @@
+use steam_engineering_toolbox::plant_piping::flow_meter::{
+    compare_meters, permanent_loss, permanent_loss_ratio, FlowMeterError, MeterLossInput, MeterType,
+};
+
+fn dn200_water(meter: MeterType) -> MeterLossInput {
+    MeterLossInput {
+        meter,
+        beta: 0.5,
+        pipe_diameter_m: 0.2,
+        differential_bar: 0.5,
+        volume_flow_m3_per_h: 100.0,
+        discharge_coefficient: None,
+        pump_efficiency: 0.75,
+        hours_per_year: 8000.0,
+        energy_price_per_kwh: 0.1,
+    }
+}
+
+#[test]
+fn orifice_loss_follows_iso_ratio_and_costs_pumping_energy() {
+    let r = permanent_loss(&dn200_water(MeterType::Orifice)).unwrap();
+    // β=0.5, C=0.61 → Δϖ/Δp ≈ 0.731 (고전 근사 1−β^1.9 ≈ 0.732)
+    assert!((r.loss_ratio - 0.7307).abs() < 1e-3, "{}", r.loss_ratio);
+    assert!((r.permanent_loss_bar + r.recovered_bar - 0.5).abs() < 1e-12);
+    assert!((r.recovery_distance_m - 1.2).abs() < 1e-12);
+    // 100 m³/h × 0.365 bar / 0.75 ≈ 1.35 kW
+    assert!(
+        (r.pumping_power_kw - 1.353).abs() < 0.01,
+        "{}",
+        r.pumping_power_kw
+    );
+    assert!((r.annual_cost - r.pumping_power_kw * 800.0).abs() < 1e-9);
+    assert!(r.warnings.is_empty());
+
+    // β가 커질수록 회복이 커져 손실 비율이 줄어든다.
+    assert!(permanent_loss_ratio(MeterType::Orifice, 0.7, 0.61) < r.loss_ratio);
+}
+
+#[test]
+fn venturi_is_cheapest_at_same_flow_and_beta() {
+    let all = compare_meters(&dn200_water(MeterType::Orifice)).unwrap();
+    assert_eq!(all.len(), 3);
+    let venturi = &all[2];
+    assert_eq!(venturi.meter, MeterType::Venturi);
+    // 같은 유량에서 차압은 (0.61/0.985)² 배로 작아진다.
+    assert!((venturi.differential_bar - 0.5 * (0.61f64 / 0.985).powi(2)).abs() < 1e-12);
+    // 7° 확산관 길이 D(1−β)/(2·tan 3.5°) ≈ 0.82 m
+    assert!((venturi.recovery_distance_m - 0.8175).abs() < 1e-3);
+    assert!(venturi.annual_cost < all[1].annual_cost && all[1].annual_cost < all[0].annual_cost);
+
+    let mut bad = dn200_water(MeterType::Nozzle);
+    bad.beta = 1.0;
+    assert!(matches!(
+        permanent_loss(&bad),
+        Err(FlowMeterError::InvalidInput(_))
+    ));
+    bad.beta = 0.2;
+    assert_eq!(permanent_loss(&bad).unwrap().warnings.len(), 1);
+}
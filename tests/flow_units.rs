@@ -0,0 +1,52 @@
+use steam_engineering_toolbox::flow_units::{self, FlowKind};
+
+fn close(a: f64, b: f64, tol: f64) -> bool {
+    (a - b).abs() <= tol * b.abs().max(1.0)
+}
+
+#[test]
+fn mass_and_volumetric_flow_conversions() {
+    let kg_h = flow_units::convert(FlowKind::MassFlow, 10.0, "klb/h", "kg/h").unwrap();
+    assert!(close(kg_h, 4_535.923_7, 1e-12));
+    let t_h = flow_units::convert(FlowKind::MassFlow, 1.0, "kg/s", "t/h").unwrap();
+    assert!(close(t_h, 3.6, 1e-12));
+    let m3_h = flow_units::convert(FlowKind::VolumetricFlow, 1.0, "gpm", "m3/h").unwrap();
+    assert!(close(m3_h, 0.227_124_7, 1e-6));
+    let cfm = flow_units::convert(FlowKind::VolumetricFlow, 1.0, "m3/h", "cfm").unwrap();
+    assert!(close(cfm, 0.588_577_8, 1e-6));
+    assert!(flow_units::convert(FlowKind::MassFlow, 1.0, "m3/h", "kg/h").is_err());
+}
+
+#[test]
+fn power_conversions() {
+    let btu_h = flow_units::convert(FlowKind::Power, 1.0, "kW", "Btu/h").unwrap();
+    assert!(close(btu_h, 3_412.14, 1e-5));
+    let kw = flow_units::convert(FlowKind::Power, 860.0, "kcal/h", "kW").unwrap();
+    assert!(close(kw, 1.000_187, 1e-5));
+    let tr = flow_units::convert(FlowKind::Power, 1.0, "MW", "TR").unwrap();
+    assert!(close(tr, 284.345, 1e-5));
+    let bhp_kw = flow_units::convert(FlowKind::Power, 1.0, "BHP", "kW").unwrap();
+    assert!(close(bhp_kw, 9.810_55, 1e-5));
+    for (_, code) in flow_units::POWER_UNITS {
+        assert!(flow_units::is_unit(FlowKind::Power, code));
+    }
+}
+
+#[test]
+fn density_specific_volume_and_specific_heat_conversions() {
+    let kg_m3 = flow_units::convert(FlowKind::Density, 1.0, "lb/ft3", "kg/m3").unwrap();
+    assert!(close(kg_m3, 16.018_463, 1e-7));
+    let lb_gal = flow_units::convert(FlowKind::Density, 1.0, "g/cm3", "lb/gal").unwrap();
+    assert!(close(lb_gal, 8.345_404, 1e-6));
+    // 비체적은 밀도의 역수
+    let ft3_lb = flow_units::convert(FlowKind::SpecificVolume, 0.194_4, "m3/kg", "ft3/lb").unwrap();
+    assert!(close(ft3_lb, 3.113_99, 1e-5));
+    let cp = flow_units::convert(FlowKind::SpecificHeat, 1.0, "Btu/lb-F", "kJ/kgK").unwrap();
+    assert!(close(cp, 4.1868, 1e-12));
+    for kind in [FlowKind::Density, FlowKind::SpecificVolume, FlowKind::SpecificHeat] {
+        for (_, code) in flow_units::unit_options(kind) {
+            assert!(flow_units::is_unit(kind, code), "{code}");
+        }
+    }
+    assert!(flow_units::convert(FlowKind::Density, 1.0, "m3/kg", "kg/m3").is_err());
+}
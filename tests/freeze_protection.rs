@@ -0,0 +1,60 @@
+use steam_engineering_toolbox::water::freeze_protection::{
+    compute_freeze_protection, FreezeProtectionError, FreezeProtectionInput,
+};
+
+fn dn50(insulation_thickness_m: f64) -> FreezeProtectionInput {
+    FreezeProtectionInput {
+        outer_diameter_m: 0.0603,
+        wall_thickness_m: 0.00391,
+        insulation_thickness_m,
+        insulation_conductivity_w_mk: 0.04,
+        wind_speed_m_per_s: 5.0,
+        ambient_temp_c: -20.0,
+        initial_temp_c: 20.0,
+        pipe_material: None,
+    }
+}
+
+#[test]
+fn insulated_dn50_cools_then_freezes() {
+    let r = compute_freeze_protection(dn50(0.025)).unwrap();
+    // UA ≈ 0.40 W/m·K, τ ≈ 8.2 h → 0 °C까지 τ·ln2 ≈ 5.7 h, 동결 ≈ 25 h
+    assert!((r.ua_w_per_mk - 0.399).abs() < 0.005, "{}", r.ua_w_per_mk);
+    assert!(
+        (r.time_to_zero_h - 5.66).abs() < 0.1,
+        "{}",
+        r.time_to_zero_h
+    );
+    assert!(
+        (r.freezing_duration_h - 25.1).abs() < 0.3,
+        "{}",
+        r.freezing_duration_h
+    );
+    assert!((r.heat_trace_w_per_m - r.ua_w_per_mk * 25.0).abs() < 1e-9);
+    assert!(!r.warnings.is_empty());
+}
+
+#[test]
+fn bare_pipe_freezes_faster_and_warm_ambient_is_rejected() {
+    let insulated = compute_freeze_protection(dn50(0.05)).unwrap();
+    let bare = compute_freeze_protection(dn50(0.0)).unwrap();
+    assert!(bare.time_to_complete_freeze_h < insulated.time_to_complete_freeze_h / 5.0);
+
+    // 관벽 저항은 보온에 비해 매우 작다.
+    let mut steel = dn50(0.05);
+    steel.pipe_material = Some("A106B".into());
+    let steel = compute_freeze_protection(steel).unwrap();
+    assert!(steel.wall_conductivity_w_mk.unwrap() > 50.0);
+    assert!(steel.ua_w_per_mk < insulated.ua_w_per_mk);
+    assert!(steel.ua_w_per_mk > insulated.ua_w_per_mk * 0.999);
+
+    let mut input = dn50(0.025);
+    input.pipe_material = Some("UNOBTANIUM".into());
+    assert!(compute_freeze_protection(input.clone()).is_err());
+    input.pipe_material = None;
+    input.ambient_temp_c = 2.0;
+    assert!(matches!(
+        compute_freeze_protection(input),
+        Err(FreezeProtectionError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,26 @@
+use steam_engineering_toolbox::fuzzy::{fuzzy_score, rank};
+
+#[test]
+fn score_matches_subsequences_case_insensitively() {
+    assert!(fuzzy_score("npsh", "Circulating Pump NPSH / Margin").is_some());
+    assert!(fuzzy_score("orf", "Orifice / Nozzle flow check").is_some());
+    assert!(fuzzy_score("배관", "배관 압력 손실").is_some());
+    assert_eq!(fuzzy_score("  ", "anything"), Some(0));
+    assert_eq!(fuzzy_score("xyz", "Orifice / Nozzle flow check"), None);
+    assert_eq!(fuzzy_score("ecif", "Orifice"), None);
+}
+
+#[test]
+fn rank_prefers_word_starts() {
+    let names = [
+        "Sample cooler",
+        "Pipe loss",
+        "Hotwell / Flash Tank Pump NPSH",
+        "Circulating Pump NPSH / Margin",
+        "Expansion loop sizing",
+    ];
+    assert_eq!(rank("pl", names)[0], 1);
+    assert_eq!(rank("npsh", names), vec![2, 3]);
+    assert_eq!(rank("loop", names)[0], 4);
+    assert_eq!(rank("", names), vec![0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,65 @@
+use steam_engineering_toolbox::gas::compressor::{
+    compute_compressor, CompressionModel, CompressorError, CompressorInput,
+};
+use steam_engineering_toolbox::gas::gas_properties::Gas;
+
+fn air_to_8_bar(stages: Option<u32>) -> CompressorInput {
+    CompressorInput {
+        gas: Gas::Air,
+        suction_pressure_bar_abs: 1.013,
+        suction_temp_c: 20.0,
+        discharge_pressure_bar_abs: 8.0,
+        mass_flow_kg_per_h: 3600.0,
+        model: CompressionModel::Isentropic { efficiency: 0.8 },
+        stages,
+        intercooler_outlet_temp_c: 30.0,
+        max_discharge_temp_c: 200.0,
+        mechanical_efficiency: 1.0,
+    }
+}
+
+#[test]
+fn single_stage_air_matches_ideal_gas_formula() {
+    let res = compute_compressor(air_to_8_bar(Some(1))).unwrap();
+    // 이상기체 k = 1.4: T₂ ≈ 315 °C, H_is ≈ 237 kJ/kg → 1 kg/s에서 약 296 kW
+    assert!((res.discharge_temp_c - 315.0).abs() < 5.0, "{}", res.discharge_temp_c);
+    assert!((res.total_gas_power_kw - 296.0).abs() < 6.0, "{}", res.total_gas_power_kw);
+    assert_eq!(res.intercooler_duty_kw, 0.0);
+    // 흡입 약 50 m³/min, 비동력 약 6 kW/(m³/min)
+    assert!((res.specific_power_kw_per_m3_per_min - 5.9).abs() < 0.3);
+    assert!(!res.warnings.is_empty());
+}
+
+#[test]
+fn intercooling_lowers_power_and_auto_picks_stages() {
+    let one = compute_compressor(air_to_8_bar(Some(1))).unwrap();
+    let auto = compute_compressor(air_to_8_bar(None)).unwrap();
+    assert_eq!(auto.stages.len(), 2);
+    assert!(auto.stages.iter().all(|s| s.discharge_temp_c <= 200.0));
+    assert!(auto.total_gas_power_kw < 0.9 * one.total_gas_power_kw);
+    assert!(auto.intercooler_duty_kw > 0.0);
+    assert!((auto.stages[1].suction_temp_c - 30.0).abs() < 1e-12);
+    assert!((auto.stages[1].discharge_pressure_bar_abs - 8.0).abs() < 1e-12);
+
+    let mut poly = air_to_8_bar(Some(2));
+    poly.model = CompressionModel::Polytropic { efficiency: 0.8 };
+    let poly = compute_compressor(poly).unwrap();
+    // 같은 효율값이면 폴리트로픽 기준이 등엔트로피 기준보다 동력이 조금 크다
+    assert!(poly.total_gas_power_kw > auto.total_gas_power_kw);
+}
+
+#[test]
+fn invalid_inputs_and_unreachable_limits() {
+    let mut input = air_to_8_bar(None);
+    input.discharge_pressure_bar_abs = 1.0;
+    assert!(matches!(
+        compute_compressor(input),
+        Err(CompressorError::InvalidInput(_))
+    ));
+    let mut input = air_to_8_bar(None);
+    input.max_discharge_temp_c = 25.0;
+    assert!(matches!(
+        compute_compressor(input),
+        Err(CompressorError::TooManyStages { .. })
+    ));
+}
@@ -0,0 +1,35 @@
+use steam_engineering_toolbox::gas::gas_properties::{gas_properties, Gas};
+
+#[test]
+fn ambient_gases_are_nearly_ideal() {
+    let n2 = gas_properties(Gas::Nitrogen, 1.01325, 20.0).unwrap();
+    // 1 atm, 20 °C 질소: ρ ≈ 1.165 kg/m³, k ≈ 1.40, μ ≈ 17.6 µPa·s
+    assert!((n2.density_kg_per_m3 - 1.165).abs() < 0.005);
+    assert!((n2.k - 1.40).abs() < 0.01);
+    assert!((n2.viscosity_pa_s - 17.6e-6).abs() < 0.3e-6);
+    assert!((n2.z - 1.0).abs() < 0.002);
+
+    let air = gas_properties(Gas::Air, 1.01325, 20.0)
+        .unwrap()
+        .blowdown_gas();
+    assert!((air.k - 1.40).abs() < 0.01);
+    assert!((air.molar_mass_kg_per_kmol - 28.96).abs() < 1e-9);
+}
+
+#[test]
+fn real_gas_effects_at_pressure() {
+    // CO₂ 50 bar(a), 50 °C: Z ≈ 0.75, 밀도 100~120 kg/m³
+    let co2 = gas_properties(Gas::CarbonDioxide, 50.0, 50.0).unwrap();
+    assert!(co2.z > 0.70 && co2.z < 0.80);
+    assert!(co2.density_kg_per_m3 > 100.0 && co2.density_kg_per_m3 < 120.0);
+    assert!(co2.cp_kj_per_kgk > 1.0);
+
+    // 고압 수소는 Z > 1
+    let h2 = gas_properties(Gas::Hydrogen, 300.0, 25.0).unwrap();
+    assert!(h2.z > 1.1);
+
+    // 20 °C 액화 CO₂ 압력 이상이면 경고
+    let liquid = gas_properties(Gas::CarbonDioxide, 60.0, 20.0).unwrap();
+    assert!(!liquid.warnings.is_empty());
+    assert!(gas_properties(Gas::Oxygen, 0.0, 20.0).is_err());
+}
@@ -0,0 +1,67 @@
+use steam_engineering_toolbox::gas::gas_properties::Gas;
+use steam_engineering_toolbox::gas::throttling::{
+    hydrate_temperature_c, throttle, JouleThomsonMethod, ThrottlingInput, ThrottlingWarning,
+};
+
+fn input(gas: Gas, p1: f64, t1: f64, p2: f64) -> ThrottlingInput {
+    ThrottlingInput {
+        gas,
+        inlet_pressure_bar_abs: p1,
+        inlet_temp_c: t1,
+        outlet_pressure_bar_abs: p2,
+        method: JouleThomsonMethod::Eos,
+    }
+}
+
+#[test]
+fn eos_letdown_cools_real_gases() {
+    // 공기 상온 JT 계수 약 0.2~0.27 K/bar
+    let air = throttle(input(Gas::Air, 11.0, 25.0, 1.0)).unwrap();
+    assert!(
+        air.mean_jt_coefficient_k_per_bar > 0.18 && air.mean_jt_coefficient_k_per_bar < 0.30,
+        "{}",
+        air.mean_jt_coefficient_k_per_bar
+    );
+    assert!(air.warnings.is_empty());
+    assert!(air.hydrate_temp_c.is_none());
+
+    // CO₂는 약 1 K/bar로 크게 냉각된다
+    let co2 = throttle(input(Gas::CarbonDioxide, 30.0, 40.0, 5.0)).unwrap();
+    assert!(co2.temperature_drop_k > 18.0 && co2.temperature_drop_k < 35.0);
+
+    // 상온 수소는 역전 온도 근처라 온도 변화가 거의 없다
+    let h2 = throttle(input(Gas::Hydrogen, 100.0, 25.0, 10.0)).unwrap();
+    assert!(h2.temperature_drop_k.abs() < 2.0);
+}
+
+#[test]
+fn natural_gas_station_warns_about_hydrates() {
+    // 1000 psia 수화물 온도 약 17 °C (Katz 도표 비중 0.6)
+    assert!((hydrate_temperature_c(1000.0 / 14.503_774) - 17.6).abs() < 0.5);
+
+    let ng = throttle(input(Gas::NaturalGas, 70.0, 15.0, 20.0)).unwrap();
+    assert!(ng.mean_jt_coefficient_k_per_bar > 0.35 && ng.mean_jt_coefficient_k_per_bar < 0.65);
+    assert!(ng.hydrate_temp_c.unwrap() > 5.0);
+    assert!(ng
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ThrottlingWarning::HydrateRisk { .. })));
+
+    let warm = throttle(input(Gas::NaturalGas, 70.0, 60.0, 20.0)).unwrap();
+    assert!(!warm
+        .warnings
+        .iter()
+        .any(|w| matches!(w, ThrottlingWarning::HydrateRisk { .. })));
+}
+
+#[test]
+fn coefficient_method_and_invalid_inputs() {
+    let mut inp = input(Gas::NaturalGas, 50.0, 20.0, 10.0);
+    inp.method = JouleThomsonMethod::Coefficient { k_per_bar: 0.5 };
+    let res = throttle(inp).unwrap();
+    assert!((res.outlet_temp_c - 0.0).abs() < 1e-12);
+    assert!((res.mean_jt_coefficient_k_per_bar - 0.5).abs() < 1e-12);
+
+    assert!(throttle(input(Gas::Air, 5.0, 20.0, 10.0)).is_err());
+    assert!(throttle(input(Gas::Air, 0.0, 20.0, 0.0)).is_err());
+}
@@ -0,0 +1,66 @@
+use steam_engineering_toolbox::cooling::condenser;
+use steam_engineering_toolbox::goal_seek::{self, GoalSeekError, GoalSeekOptions};
+use steam_engineering_toolbox::steam::critical_flow::SteamStagnation;
+use steam_engineering_toolbox::steam::steam_piping::{self, PressureLossInput};
+use steam_engineering_toolbox::steam::steam_valves;
+
+#[test]
+fn brent_and_bisection_agree() {
+    let f = |x: f64| x * x * x - 2.0 * x;
+    let b = goal_seek::brent(f, 5.0, 0.0, 3.0, GoalSeekOptions::default()).unwrap();
+    let s = goal_seek::bisection(f, 5.0, 0.0, 3.0, GoalSeekOptions::default()).unwrap();
+    assert!((b.x - s.x).abs() < 1e-7);
+    assert!(b.iterations < s.iterations);
+    assert!(matches!(
+        goal_seek::goal_seek(f, 100.0, 0.0, 3.0),
+        Err(GoalSeekError::NotBracketed { .. })
+    ));
+}
+
+#[test]
+fn diameter_for_pressure_drop_roundtrip() {
+    let base = PressureLossInput {
+        mass_flow_kg_per_h: 8000.0,
+        steam_density_kg_per_m3: 6.0,
+        diameter_m: 0.0,
+        length_m: 120.0,
+        fittings_k_sum: 4.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 4.5e-5,
+        dynamic_viscosity_pa_s: 1.5e-5,
+        sound_speed_m_per_s: 450.0,
+        state_pressure_bar_abs: None,
+        state_temperature_c: None,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    };
+    let d = steam_piping::diameter_for_pressure_drop(&base, 0.2, 0.02, 0.5).unwrap();
+    let check = steam_piping::pressure_loss(PressureLossInput {
+        diameter_m: d,
+        ..base
+    })
+    .unwrap();
+    assert!((check.pressure_drop_bar - 0.2).abs() < 1e-6);
+}
+
+#[test]
+fn valve_kv_for_downstream_pressure_roundtrip() {
+    let kv = steam_valves::kv_for_downstream_pressure(20.0, 950.0, 10.0, 8.5).unwrap();
+    let q = steam_valves::flow_from_kv(kv, 1.5, 950.0).unwrap();
+    assert!((q - 20.0).abs() < 1e-6);
+    // 증기는 임계 압력(약 0.58·p₁) 아래 목표를 Kv로 만들 수 없다
+    let dry = SteamStagnation::Quality(1.0);
+    assert!(steam_valves::steam_kv_for_downstream_pressure(20.0, 5.0, 10.0, 3.0, dry).is_err());
+    let kv_steam =
+        steam_valves::steam_kv_for_downstream_pressure(20.0, 5.0, 10.0, 8.0, dry).unwrap();
+    assert_eq!(kv_steam, steam_valves::required_kv(20.0, 2.0, 5.0).unwrap());
+}
+
+#[test]
+fn cw_flow_for_backpressure_reaches_target() {
+    let res = condenser::cw_flow_for_backpressure(50_000.0, 25.0, 5_000.0, 0.08, 50_000.0).unwrap();
+    let tsat =
+        condenser::condensing_temp_for_cw_flow(50_000.0, 25.0, res.cw_flow_m3_per_h, 5_000.0);
+    assert!((tsat - res.condensing_temp_c).abs() < 1e-6);
+    assert!(res.cw_outlet_temp_c > 25.0 && res.cw_outlet_temp_c < tsat);
+}
@@ -0,0 +1,101 @@
+use steam_engineering_toolbox::steam::cycle::{
+    compute_rankine_cycle, FeedwaterHeaterInput, FeedwaterHeaterKind, RankineCycleInput,
+    ReheatInput,
+};
+use steam_engineering_toolbox::steam::heat_balance::{
+    solve_heat_balance, HeatBalanceTemplate, NodeKind, StreamKind,
+};
+
+#[test]
+fn single_heater_flowsheet_matches_rankine_cycle() {
+    let closed = FeedwaterHeaterInput {
+        extraction_pressure_bar_abs: 12.0,
+        kind: FeedwaterHeaterKind::Closed {
+            terminal_temp_difference_k: 3.0,
+        },
+    };
+    let open = FeedwaterHeaterInput {
+        extraction_pressure_bar_abs: 12.0,
+        kind: FeedwaterHeaterKind::Open,
+    };
+    for heater in [None, Some(open), Some(closed)] {
+        let mut input = HeatBalanceTemplate::Simple.input();
+        input.reheat = Some(ReheatInput {
+            pressure_bar_abs: 40.0,
+            temp_c: 540.0,
+        });
+        input.heaters = heater.into_iter().collect();
+        let hb = solve_heat_balance(&input).unwrap();
+        let cycle = compute_rankine_cycle(RankineCycleInput {
+            throttle_pressure_bar_abs: input.throttle_pressure_bar_abs,
+            throttle_temp_c: input.throttle_temp_c,
+            condenser_pressure_bar_abs: input.condenser_pressure_bar_abs,
+            turbine_efficiency: input.turbine_efficiency,
+            pump_efficiency: input.pump_efficiency,
+            reheat: input.reheat,
+            feedwater_heater: heater,
+            steam_flow_kg_per_h: Some(input.steam_flow_kg_per_h),
+        })
+        .unwrap();
+        assert!((hb.thermal_efficiency - cycle.thermal_efficiency).abs() < 1e-9);
+        assert!((hb.net_power_kw - cycle.net_power_kw.unwrap()).abs() < 1e-6);
+        assert!((hb.feedwater_temp_c - cycle.feedwater_temp_c).abs() < 1e-9);
+        assert!(
+            hb.energy_residual_kw.abs() < 1e-6,
+            "{}",
+            hb.energy_residual_kw
+        );
+    }
+}
+
+#[test]
+fn three_heater_template_closes_mass_and_energy_balance() {
+    let simple = solve_heat_balance(&HeatBalanceTemplate::Simple.input()).unwrap();
+    let input = HeatBalanceTemplate::SingleReheatThreeHeaters.input();
+    let res = solve_heat_balance(&input).unwrap();
+    assert!(res.thermal_efficiency > simple.thermal_efficiency + 0.03);
+    assert!(
+        res.energy_residual_kw.abs() < 1e-6,
+        "{}",
+        res.energy_residual_kw
+    );
+    assert!((res.net_power_kw - (res.turbine_power_kw - res.pump_power_kw)).abs() < 1e-9);
+
+    // 노드마다 들어온 질량 = 나간 질량 (보일러는 급수 = 주증기)
+    for (i, node) in res.nodes.iter().enumerate() {
+        let inflow: f64 = res
+            .streams
+            .iter()
+            .filter(|s| s.to == i)
+            .map(|s| s.mass_flow_kg_per_h)
+            .sum();
+        let outflow: f64 = res
+            .streams
+            .iter()
+            .filter(|s| s.from == i)
+            .map(|s| s.mass_flow_kg_per_h)
+            .sum();
+        if node.kind == NodeKind::Reheater {
+            assert!(inflow < input.steam_flow_kg_per_h);
+        }
+        assert!(
+            (inflow - outflow).abs() < 1e-6,
+            "{} {inflow} {outflow}",
+            node.label
+        );
+    }
+    let fw = res
+        .streams
+        .iter()
+        .find(|s| s.kind == StreamKind::Feedwater && s.to == 0)
+        .unwrap();
+    assert!((fw.temp_c - res.feedwater_temp_c).abs() < 1e-9);
+    // 30 bar(a) 포화 233.9 °C − TTD 3 K
+    assert!((fw.temp_c - 230.9).abs() < 0.2, "{}", fw.temp_c);
+    let labels: Vec<_> = res.nodes.iter().map(|n| n.label.as_str()).collect();
+    assert!(labels.ends_with(&["FWH1", "DA", "FWH2"]), "{labels:?}");
+
+    let mut two_open = input.clone();
+    two_open.heaters[0].kind = FeedwaterHeaterKind::Open;
+    assert!(solve_heat_balance(&two_open).is_err());
+}
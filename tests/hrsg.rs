@@ -0,0 +1,61 @@
+use steam_engineering_toolbox::steam::hrsg::{compute_hrsg, HrsgError, HrsgInput};
+
+fn base() -> HrsgInput {
+    HrsgInput {
+        gas_flow_kg_per_s: 100.0,
+        gas_inlet_temp_c: 550.0,
+        gas_cp_kj_per_kgk: 1.1,
+        steam_pressure_bar_abs: 40.0,
+        steam_temp_c: Some(480.0),
+        feedwater_temp_c: 105.0,
+        pinch_k: 10.0,
+        approach_k: 5.0,
+        flue_gas_dew_points: None,
+    }
+}
+
+#[test]
+fn pinch_sets_steam_flow_and_stack_temperature() {
+    let res = compute_hrsg(base()).unwrap();
+    // 40 bar(a): Tsat ≈ 250.4 °C, 핀치 위 열량 110·(550 − 260.4) ≈ 31.9 MW → 약 13.7 kg/s
+    assert!((res.saturation_temp_c - 250.35).abs() < 0.1);
+    assert!((res.steam_flow_kg_per_h - 49_300.0).abs() < 500.0);
+    assert!(res.stack_temp_c > 175.0 && res.stack_temp_c < 190.0);
+    // 가스 측과 수증기 측 열수지가 맞아야 한다.
+    let gas_side = 100.0 * 1.1 * (550.0 - res.stack_temp_c);
+    assert!((res.total_duty_kw - gas_side).abs() < 1e-6 * gas_side);
+    assert!(
+        (res.superheater_duty_kw + res.evaporator_duty_kw + res.economizer_duty_kw
+            - res.total_duty_kw)
+            .abs()
+            < 1e-6
+    );
+    assert!(res.gas_temp_after_superheater_c < 550.0);
+    assert!(res.gas_temp_after_superheater_c > res.gas_temp_at_pinch_c);
+
+    // 핀치를 줄이면 증기가 늘고 연돌 온도가 내려간다.
+    let tight = compute_hrsg(HrsgInput {
+        pinch_k: 5.0,
+        ..base()
+    })
+    .unwrap();
+    assert!(tight.steam_flow_kg_per_h > res.steam_flow_kg_per_h);
+    assert!(tight.stack_temp_c < res.stack_temp_c);
+}
+
+#[test]
+fn infeasible_conditions_are_rejected() {
+    // 배기 온도가 포화온도 + 핀치 이하이면 증기를 만들 수 없다.
+    assert!(matches!(
+        compute_hrsg(HrsgInput {
+            gas_inlet_temp_c: 255.0,
+            ..base()
+        }),
+        Err(HrsgError::InvalidInput(_))
+    ));
+    assert!(compute_hrsg(HrsgInput {
+        steam_temp_c: Some(200.0),
+        ..base()
+    })
+    .is_err());
+}
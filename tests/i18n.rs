@@ -0,0 +1,68 @@
+use steam_engineering_toolbox::i18n::{self, PluralCategory, Translator};
+use steam_engineering_toolbox::number_format::NumberFormat;
+
+#[test]
+fn fill_template_applies_precision_and_keeps_unknown_placeholders() {
+    let nf = NumberFormat::default();
+    let out = i18n::fill_template(
+        "Q={q:.1} kW, Kv={kv}, unit {u}, {missing}",
+        &[
+            ("q", "12.345".to_string()),
+            ("kv", "3.50".to_string()),
+            ("u", "m3/h".to_string()),
+        ],
+        &nf,
+    );
+    assert_eq!(out, "Q=12.3 kW, Kv=3.50, unit m3/h, {missing}");
+}
+
+#[test]
+fn fill_template_formatted_keeps_digits_formatted_from_raw_values() {
+    let nf = NumberFormat {
+        significant_figures: Some(3),
+        ..NumberFormat::default()
+    };
+    let out = i18n::fill_template_formatted(
+        "x={x}, y={y:.1}",
+        &[
+            ("x", nf.format(0.00012, 3)),
+            ("y", "12.345".to_string()),
+        ],
+        &nf,
+    );
+    assert_eq!(out, "x=0.000120, y=12.3");
+}
+
+#[test]
+fn plural_forms_follow_language_rules() {
+    assert_eq!(i18n::plural_category("en-us", 1), PluralCategory::One);
+    assert_eq!(i18n::plural_category("en-us", 2), PluralCategory::Other);
+    assert_eq!(i18n::plural_category("ko-kr", 1), PluralCategory::Other);
+
+    let en = Translator::new_with_pack("en-us", None);
+    assert_eq!(en.plural("gui.sweep.failed", 1, "", &[]), "1 point failed");
+    assert_eq!(en.plural("gui.sweep.failed", 3, "", &[]), "3 points failed");
+    let ko = Translator::new_with_pack("ko-kr", None);
+    assert_eq!(
+        ko.plural("gui.sweep.failed", 1, "", &[]),
+        "1개 점 계산 실패"
+    );
+}
+
+#[test]
+fn language_pack_sets_number_separators_unless_user_overrides() {
+    let mut de = Translator::new_with_pack("de-de", None);
+    assert_eq!(de.number_format().format(1234.5, 1), "1.234,5");
+    assert_eq!(
+        de.format("missing.key", "Q={q:.2} kW", &[("q", "0.5".into())]),
+        "Q=0,50 kW"
+    );
+
+    let user = NumberFormat {
+        decimal_separator: '.',
+        thousands_separator: Some(' '),
+        ..NumberFormat::default()
+    };
+    de.set_number_format(&user);
+    assert_eq!(de.number_format().format(1234.5, 1), "1 234.5");
+}
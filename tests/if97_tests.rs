@@ -1,6 +1,8 @@
 //! IF97 기준점 회귀 테스트. IAPWS-IF97 공식 문서의 검증 예제 값을 활용한다.
 use steam_engineering_toolbox::steam::if97::{
-    region1_props, region2_props, region3_props, region5_props, region_props,
+    props_from_px, region1_props, region2_props, region3_props, region5_props, region_props,
+    region_props_uncached, saturation_line, saturation_pressure_bar_abs_from_temp_c,
+    saturation_temp_c_from_pressure_bar_abs, temperature_from_ph, verify,
 };
 
 fn assert_close(label: &str, actual: f64, expected: f64, rel_tol: f64) {
@@ -86,3 +88,115 @@ fn region_dispatch_matches_reference_regions() {
     assert_close("v5", v5, 1.384_550_898_781_53, 1e-6);
     assert_close("s5", s5, 9_654.088_753_312_948, 1e-6);
 }
+
+#[test]
+fn cached_region_props_match_direct_evaluation() {
+    for &(p, t) in &[
+        (30.0, 26.85),
+        (10.0, 250.0),
+        (0.035, 426.85),
+        (250.0, 376.85),
+    ] {
+        let direct = region_props_uncached(p, t).expect("direct");
+        assert_eq!(region_props(p, t).expect("first"), direct);
+        assert_eq!(region_props(p, t).expect("cached"), direct);
+    }
+}
+
+#[test]
+fn saturation_spline_tracks_if97() {
+    let line = saturation_line();
+    for &t in &[
+        0.01, 1.3, 45.0, 99.63, 151.8, 212.4, 300.25, 349.2, 355.0, 369.9,
+    ] {
+        let sat = line.at_temperature(t).expect("spline T");
+        let p = saturation_pressure_bar_abs_from_temp_c(t).expect("exact p");
+        assert_close("p(T)", sat.p_bar_abs, p, 1e-6);
+        let (hf, vf, _) = props_from_px(p, 0.0).expect("liquid");
+        let (hg, vg, _) = props_from_px(p, 1.0).expect("vapour");
+        assert_close("hf kJ", sat.hf / 1000.0, hf / 1000.0, 1e-5);
+        assert_close("hg kJ", sat.hg / 1000.0, hg / 1000.0, 1e-5);
+        assert_close("vf", sat.vf / vf, 1.0, 1e-5);
+        assert_close("vg", sat.vg / vg, 1.0, 1e-5);
+    }
+    for &p in &[0.01, 0.2, 1.01325, 10.0, 42.0, 160.0, 180.0] {
+        let sat = line.at_pressure(p).expect("spline p");
+        let t = saturation_temp_c_from_pressure_bar_abs(p).expect("exact T");
+        assert_close("T(p)", sat.t_c, t, 1e-6);
+    }
+    // 격자 밖은 직접 계산으로 넘어간다
+    let near_critical = line.at_temperature(373.0).expect("fallback");
+    assert_close(
+        "p(373)",
+        near_critical.p_bar_abs,
+        saturation_pressure_bar_abs_from_temp_c(373.0).unwrap(),
+        1e-12,
+    );
+}
+
+#[test]
+fn official_verification_tables_pass() {
+    let report = verify();
+    // Region 1/2/3/5 각 물성 6개, 포화선 6점, B23 2점
+    assert_eq!(report.checks.len(), 9 * 6 + 3 * 6 + 6 + 2);
+    for c in &report.checks {
+        assert!(
+            c.passed(),
+            "{} {} {}: expected {} got {} (rel {:e})",
+            c.group,
+            c.case,
+            c.property,
+            c.expected,
+            c.actual,
+            c.rel_deviation
+        );
+    }
+    let groups: Vec<_> = report.max_deviations().iter().map(|(g, _)| *g).collect();
+    assert_eq!(
+        groups,
+        ["Region 1", "Region 2", "Region 5", "Region 3", "Region 4", "B23"]
+    );
+    assert!(report.worst().unwrap().rel_deviation < 1e-8);
+}
+
+#[test]
+fn saturation_line_round_trips_and_matches_seuif97() {
+    let mut t = 1.0;
+    while t < 370.0 {
+        let p = saturation_pressure_bar_abs_from_temp_c(t).unwrap();
+        let back = saturation_temp_c_from_pressure_bar_abs(p).unwrap();
+        assert!((back - t).abs() < 1e-6, "Tsat(psat({t})) = {back}");
+        t += 7.3;
+    }
+}
+
+#[test]
+fn enthalpy_is_continuous_across_saturation() {
+    // 포화온도 바로 위 과열 증기 h는 hg, 바로 아래 압축수 h는 hf에 수렴해야 한다
+    for &p in &[0.05, 1.0, 10.0, 50.0, 150.0] {
+        let tsat = saturation_temp_c_from_pressure_bar_abs(p).unwrap();
+        let (hf, _, _) = props_from_px(p, 0.0).unwrap();
+        let (hg, _, _) = props_from_px(p, 1.0).unwrap();
+        let (h_vap, _, _) = region_props(p, tsat + 1e-3).unwrap();
+        let (h_liq, _, _) = region_props(p, tsat - 1e-3).unwrap();
+        assert_close("h(Tsat+)", h_vap / 1000.0, hg / 1000.0, 1e-4);
+        assert_close("h(Tsat-)", h_liq / 1000.0, hf / 1000.0, 1e-4);
+    }
+}
+
+#[test]
+fn temperature_round_trips_through_enthalpy() {
+    // T(p, h(p, T)) = T : Region 1, 2, 5 전반에서 역함수 일관성 확인
+    // (IF97 역방향 식 T(p,h)의 허용 불일치는 Region 1에서 ±25 mK)
+    for &p in &[0.1, 1.0, 10.0, 40.0, 100.0, 250.0] {
+        let tsat = saturation_temp_c_from_pressure_bar_abs(p).unwrap_or(f64::INFINITY);
+        for &t in &[20.0, 80.0, 150.0, 250.0, 320.0, 400.0, 550.0, 800.0, 1200.0] {
+            if (t - tsat).abs() < 1.0 {
+                continue;
+            }
+            let (h, _, _) = region_props(p, t).unwrap();
+            let back = temperature_from_ph(p, h).unwrap();
+            assert!((back - t).abs() < 0.03, "p={p} T={t}: T(p,h) = {back}");
+        }
+    }
+}
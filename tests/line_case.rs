@@ -0,0 +1,100 @@
+use steam_engineering_toolbox::steam::line_case::{
+    check_line_case, CasePipe, CaseValve, LineCase, LineCaseError, LineCaseFinding,
+};
+use steam_engineering_toolbox::steam::steam_piping::velocity_check::VelocityVerdict;
+use steam_engineering_toolbox::warning::Severity;
+
+/// 11 bar(a), 250 °C 증기 3 t/h를 DN80 → 밸브 → DN100으로 6.5 bar(a) 수요처에 보낸다.
+fn consistent_case() -> LineCase {
+    LineCase {
+        source_pressure_bar_abs: 11.0,
+        sink_pressure_bar_abs: 6.5,
+        source_temp_c: 250.0,
+        mass_flow_kg_per_h: 3000.0,
+        upstream_pipe: Some(CasePipe {
+            inner_diameter_m: 0.0779,
+            pressure_drop_bar: 0.2,
+        }),
+        valve: Some(CaseValve {
+            kv: 6000.0,
+            pressure_drop_bar: 4.0,
+        }),
+        downstream_pipe: Some(CasePipe {
+            inner_diameter_m: 0.1023,
+            pressure_drop_bar: 0.3,
+        }),
+    }
+}
+
+#[test]
+fn consistent_line_passes_every_cross_check() {
+    let case = consistent_case();
+    assert!(case.is_complete());
+    let report = check_line_case(&case).unwrap();
+    assert!((report.available_dp_bar - 4.5).abs() < 1e-12);
+    assert!((report.allocated_dp_bar - 4.5).abs() < 1e-12);
+    assert!((report.valve_inlet.pressure_bar_abs - 10.8).abs() < 1e-12);
+    assert!((report.valve_outlet.pressure_bar_abs - 6.8).abs() < 1e-12);
+    // 등엔탈피 감압이라 출구 온도가 조금 내려간다.
+    assert!(report.valve_outlet.temp_c < report.valve_inlet.temp_c);
+    assert!(!report.choked);
+    assert!(
+        report.required_kv > 4000.0 && report.required_kv < 5500.0,
+        "{}",
+        report.required_kv
+    );
+    assert_eq!(report.upstream_velocity.verdict, VelocityVerdict::Pass);
+    assert_eq!(report.downstream_velocity.verdict, VelocityVerdict::Pass);
+    assert!(report.findings.is_empty(), "{:?}", report.findings);
+    assert_eq!(report.max_severity(), Severity::Info);
+}
+
+#[test]
+fn independently_filled_cards_that_disagree_are_flagged() {
+    let mut case = consistent_case();
+    // 수요처 압력을 낮추고 밸브 차압을 키웠지만 배관 ΔP와 Kv는 그대로 둔 경우.
+    case.sink_pressure_bar_abs = 2.0;
+    case.valve = Some(CaseValve {
+        kv: 1000.0,
+        pressure_drop_bar: 7.0,
+    });
+    let report = check_line_case(&case).unwrap();
+    assert!(report.choked);
+    assert!(report
+        .findings
+        .iter()
+        .any(|f| matches!(f, LineCaseFinding::DpSurplus { .. })));
+    assert!(report
+        .findings
+        .iter()
+        .any(|f| matches!(f, LineCaseFinding::ValveChoked { .. })));
+    assert!(report
+        .findings
+        .iter()
+        .any(|f| matches!(f, LineCaseFinding::ValveUndersized { .. })));
+    // 하류 3.5 bar(a) 근처에서 DN100 유속이 올라간다.
+    assert!(
+        report.downstream_velocity.velocity_m_per_s > report.upstream_velocity.velocity_m_per_s
+    );
+    assert_eq!(report.max_severity(), Severity::Critical);
+
+    case.sink_pressure_bar_abs = 6.5;
+    case.valve = Some(CaseValve {
+        kv: 6000.0,
+        pressure_drop_bar: 4.6,
+    });
+    let report = check_line_case(&case).unwrap();
+    assert_eq!(
+        report.findings,
+        vec![LineCaseFinding::DpShortfall {
+            allocated_bar: 5.1,
+            available_bar: 4.5,
+        }]
+    );
+
+    case.downstream_pipe = None;
+    assert!(matches!(
+        check_line_case(&case),
+        Err(LineCaseError::Incomplete)
+    ));
+}
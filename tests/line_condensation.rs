@@ -0,0 +1,102 @@
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::line_condensation::{
+    compute_line_condensation, LineCondensationError, LineCondensationInput,
+    LineCondensationWarning, LineInletState,
+};
+
+fn dn100_main(inlet: LineInletState) -> LineCondensationInput {
+    LineCondensationInput {
+        mass_flow_kg_per_h: 2000.0,
+        inlet_pressure_bar_abs: 10.0,
+        inlet,
+        inner_diameter_m: 0.1023,
+        outer_diameter_m: 0.1143,
+        length_m: 200.0,
+        roughness_m: 4.5e-5,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+        insulation_thickness_m: 0.05,
+        insulation_conductivity_w_mk: 0.045,
+        ambient_temp_c: 0.0,
+        wind_speed_m_per_s: 3.0,
+        segments: 20,
+    }
+}
+
+#[test]
+fn saturated_line_condensate_matches_heat_loss_over_latent_heat() {
+    let r = compute_line_condensation(dn100_main(LineInletState::Wet { dryness: 1.0 })).unwrap();
+    let (hf, hg) = if97::saturation_enthalpies(10.0).unwrap();
+    let hfg_kj = (hg - hf) / 1000.0;
+    let expected = r.heat_loss_kw * 3600.0 / hfg_kj;
+    // 압력 강하에 따른 재증발분만큼 Q/hfg보다 조금 적다
+    assert!(
+        (r.condensate_kg_per_h - expected).abs() / expected < 0.04,
+        "{} vs {expected}",
+        r.condensate_kg_per_h
+    );
+    // 50 mm 보온 DN100 주관은 100 W/m 안팎이다
+    let w_per_m = r.heat_loss_kw * 1000.0 / 200.0;
+    assert!((60.0..140.0).contains(&w_per_m), "{w_per_m}");
+    let x_out = r.outlet_dryness.unwrap();
+    assert!(x_out < 1.0 && x_out > 0.98, "{x_out}");
+    assert!(r.pressure_drop_bar > 0.0);
+    assert!((r.outlet_pressure_bar_abs - (10.0 - r.pressure_drop_bar)).abs() < 1e-12);
+    assert_eq!(r.segments.len(), 20);
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, LineCondensationWarning::DripLegs { .. })));
+
+    let bare = compute_line_condensation(LineCondensationInput {
+        insulation_thickness_m: 0.0,
+        ..dn100_main(LineInletState::Wet { dryness: 1.0 })
+    })
+    .unwrap();
+    assert!(bare.condensate_kg_per_h > 5.0 * r.condensate_kg_per_h);
+}
+
+#[test]
+fn superheated_inlet_cools_then_starts_condensing_on_bare_pipe() {
+    let insulated =
+        compute_line_condensation(dn100_main(LineInletState::Superheated { temp_c: 250.0 }))
+            .unwrap();
+    assert!(insulated.outlet_dryness.is_none());
+    assert_eq!(insulated.condensate_kg_per_h, 0.0);
+    assert!(insulated.outlet_temp_c < 250.0 && insulated.outlet_temp_c > 230.0);
+
+    let bare = compute_line_condensation(LineCondensationInput {
+        insulation_thickness_m: 0.0,
+        ..dn100_main(LineInletState::Superheated { temp_c: 200.0 })
+    })
+    .unwrap();
+    let at = bare.superheat_lost_at_m.unwrap();
+    assert!(at > 0.0 && at < 200.0, "{at}");
+    assert!(bare.outlet_dryness.is_some());
+    assert!(bare.condensate_kg_per_h > 0.0);
+    let t_sat =
+        if97::saturation_temp_c_from_pressure_bar_abs(bare.outlet_pressure_bar_abs).unwrap();
+    assert!((bare.outlet_temp_c - t_sat).abs() < 1e-6);
+}
+
+#[test]
+fn rejects_bad_geometry_and_reports_full_condensation() {
+    let err = compute_line_condensation(LineCondensationInput {
+        outer_diameter_m: 0.1,
+        ..dn100_main(LineInletState::Wet { dryness: 1.0 })
+    })
+    .unwrap_err();
+    assert!(matches!(err, LineCondensationError::InvalidInput(_)));
+
+    let err = compute_line_condensation(LineCondensationInput {
+        mass_flow_kg_per_h: 20.0,
+        insulation_thickness_m: 0.0,
+        length_m: 1000.0,
+        ..dn100_main(LineInletState::Wet { dryness: 0.9 })
+    })
+    .unwrap_err();
+    assert!(matches!(
+        err,
+        LineCondensationError::FullyCondensed { distance_m } if distance_m < 1000.0
+    ));
+}
@@ -0,0 +1,119 @@
+use steam_engineering_toolbox::plant_piping::line_fill::{
+    line_fill, parse_segments, pipe_dimensions_mm, DrainValve, LineFillError, LineFillInput,
+    LineFillWarning, LineSegment, CARBON_STEEL_DENSITY,
+};
+
+fn input(segments: Vec<LineSegment>, drain: Option<DrainValve>) -> LineFillInput {
+    LineFillInput {
+        segments,
+        water_temp_c: 15.0,
+        steel_density_kg_per_m3: CARBON_STEEL_DENSITY,
+        drain,
+    }
+}
+
+#[test]
+fn segment_list_parses_with_or_without_dn_prefix() {
+    let segments = parse_segments("DN100:120, 50:35m;\n dn25 : 8.5").unwrap();
+    assert_eq!(
+        segments,
+        vec![
+            LineSegment {
+                dn: 100,
+                length_m: 120.0
+            },
+            LineSegment {
+                dn: 50,
+                length_m: 35.0
+            },
+            LineSegment {
+                dn: 25,
+                length_m: 8.5
+            },
+        ]
+    );
+    assert!(matches!(
+        parse_segments("100-120"),
+        Err(LineFillError::Parse { .. })
+    ));
+    assert!(matches!(
+        parse_segments("DN4in:10"),
+        Err(LineFillError::Parse { .. })
+    ));
+}
+
+#[test]
+fn fill_volume_and_filled_weight_use_sch40_dimensions() {
+    let r = line_fill(&input(
+        vec![
+            LineSegment {
+                dn: 100,
+                length_m: 120.0,
+            },
+            LineSegment {
+                dn: 50,
+                length_m: 35.0,
+            },
+        ],
+        None,
+    ))
+    .unwrap();
+    let area = |d_mm: f64| std::f64::consts::PI / 4.0 * (d_mm / 1000.0).powi(2);
+    let expected_v = area(102.3) * 120.0 + area(52.5) * 35.0;
+    assert!((r.volume_m3 - expected_v).abs() < 1e-12);
+    assert!((r.water_density_kg_per_m3 - 999.1).abs() < 0.1);
+    assert!((r.total_length_m - 155.0).abs() < 1e-12);
+
+    // DN100 Sch40 강관 약 16 kg/m, 만수 시 약 24.2 kg/m
+    let dn100 = &r.segments[0];
+    assert_eq!(pipe_dimensions_mm(100), Some((114.3, 102.3)));
+    assert!((dn100.steel_kg_per_m - 16.0).abs() < 0.1);
+    assert!((dn100.filled_kg_per_m - 24.2).abs() < 0.1);
+    assert_eq!(r.max_filled_kg_per_m, dn100.filled_kg_per_m);
+    assert!((r.filled_mass_kg - r.water_mass_kg - r.steel_mass_kg).abs() < 1e-9);
+    assert!(r.drain.is_none());
+
+    let err = line_fill(&input(
+        vec![LineSegment {
+            dn: 350,
+            length_m: 1.0,
+        }],
+        None,
+    ))
+    .unwrap_err();
+    assert!(matches!(err, LineFillError::UnknownSize(350)));
+}
+
+#[test]
+fn drain_time_is_twice_the_initial_flow_time() {
+    let segments = vec![LineSegment {
+        dn: 200,
+        length_m: 1000.0,
+    }];
+    let r = line_fill(&input(
+        segments.clone(),
+        Some(DrainValve {
+            cv: 10.0,
+            static_head_m: 5.0,
+        }),
+    ))
+    .unwrap();
+    let drain = r.drain.as_ref().unwrap();
+    // Q₀ = Kv·√(ρgh/1e5 / SG) = Kv·√(g·h/100), 밀도와 무관
+    let expected_q = drain.kv * (9.806_65 * 5.0 / 100.0_f64).sqrt();
+    assert!((drain.initial_flow_m3_per_h - expected_q).abs() < 1e-9);
+    assert!((drain.drain_time_h - 2.0 * r.volume_m3 / expected_q).abs() < 1e-9);
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, LineFillWarning::LongDrain { .. })));
+
+    let bad = line_fill(&input(
+        segments,
+        Some(DrainValve {
+            cv: 0.0,
+            static_head_m: 5.0,
+        }),
+    ));
+    assert!(matches!(bad, Err(LineFillError::InvalidInput(_))));
+}
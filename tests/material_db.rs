@@ -0,0 +1,56 @@
+use steam_engineering_toolbox::material_db::{
+    elastic_modulus, find_roughness, mean_expansion_coefficient, roughness_presets,
+    thermal_conductivity, thermal_strain,
+};
+
+#[test]
+fn roughness_presets_resolve_by_code_or_friendly_name() {
+    let new_steel = find_roughness("steel-new").expect("new carbon steel preset");
+    assert!((new_steel.roughness_m() - 0.000045).abs() < 1e-12);
+    assert_eq!(
+        find_roughness("New Carbon Steel").map(|r| r.code),
+        Some("steel-new")
+    );
+    assert_eq!(
+        find_roughness("STEEL_CORRODED").map(|r| r.code),
+        Some("steel-corroded")
+    );
+    assert_eq!(find_roughness("pvc / plastic").map(|r| r.code), Some("pvc"));
+    assert!(find_roughness("0.045mm").is_none());
+    assert!(find_roughness("").is_none());
+}
+
+#[test]
+fn roughness_table_is_ordered_and_physical() {
+    let presets = roughness_presets();
+    assert_eq!(
+        presets[0].code, "steel-new",
+        "first preset is the CLI/GUI default"
+    );
+    for r in presets {
+        assert!(r.roughness_mm > 0.0 && r.roughness_mm < 5.0, "{}", r.code);
+        assert!(r.key.starts_with("material.roughness."), "{}", r.code);
+    }
+    let corroded = find_roughness("steel-corroded").unwrap();
+    assert!(corroded.roughness_mm > find_roughness("steel-new").unwrap().roughness_mm);
+}
+
+#[test]
+fn thermal_curves_interpolate_and_strain_is_path_independent() {
+    // 탄소강 k: 300 °C 44.8, 400 °C 41.4 → 350 °C 43.1
+    assert!((thermal_conductivity("A106B", 350.0).unwrap() - 43.1).abs() < 1e-9);
+    // 오스테나이트계는 온도가 오를수록 k가 커진다.
+    assert!(
+        thermal_conductivity("TP316", 500.0).unwrap()
+            > thermal_conductivity("TP316", 20.0).unwrap()
+    );
+    assert!(thermal_conductivity("UNOBTANIUM", 100.0).is_none());
+    assert!(elastic_modulus("A335P91", 550.0).unwrap().value_mpa < 181_000.0);
+
+    let from_20 = thermal_strain("A106B", 20.0, 300.0).unwrap();
+    assert!((from_20 - mean_expansion_coefficient("A106B", 300.0).unwrap() * 280.0).abs() < 1e-15);
+    let split = thermal_strain("A106B", 20.0, 150.0).unwrap()
+        + thermal_strain("A106B", 150.0, 300.0).unwrap();
+    assert!((split - from_20).abs() < 1e-15);
+    assert!(thermal_strain("A106B", 300.0, 20.0).unwrap() < 0.0);
+}
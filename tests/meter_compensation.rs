@@ -0,0 +1,94 @@
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::meter_compensation::{
+    compensate_flow, compensate_series, compensated_series_csv, parse_historian_csv,
+    MeterCompensationError, MeterCompensationWarning, MeterPrinciple, SteamCondition,
+};
+
+const DESIGN: SteamCondition = SteamCondition {
+    pressure_bar_abs: 11.0,
+    temperature_c: None,
+};
+
+#[test]
+fn dp_meter_uses_square_root_and_vortex_the_full_density_ratio() {
+    let actual = SteamCondition {
+        pressure_bar_abs: 8.0,
+        temperature_c: None,
+    };
+    let dp = compensate_flow(MeterPrinciple::DifferentialPressure, DESIGN, actual, 5000.0).unwrap();
+    let vortex = compensate_flow(MeterPrinciple::Vortex, DESIGN, actual, 5000.0).unwrap();
+
+    let (_, vg_design, _) = if97::props_from_px(11.0, 1.0).unwrap();
+    let (_, vg_actual, _) = if97::props_from_px(8.0, 1.0).unwrap();
+    let ratio = vg_design / vg_actual;
+    assert!((dp.density_ratio - ratio).abs() < 1e-12);
+    assert!((dp.correction_factor - ratio.sqrt()).abs() < 1e-12);
+    assert!((vortex.correction_factor - ratio).abs() < 1e-12);
+    // 압력이 낮으면 밀도가 작아 두 계기 모두 많이 표시한다
+    assert!(vortex.corrected_flow_kg_per_h < dp.corrected_flow_kg_per_h);
+    assert!(dp.corrected_flow_kg_per_h < 5000.0);
+
+    // 설계 상태 그대로면 보정 없음
+    let same = compensate_flow(MeterPrinciple::Vortex, DESIGN, DESIGN, 5000.0).unwrap();
+    assert!((same.corrected_flow_kg_per_h - 5000.0).abs() < 1e-9);
+    assert!(same.warnings.is_empty(), "{:?}", same.warnings);
+}
+
+#[test]
+fn temperature_below_saturation_falls_back_to_dry_saturated_steam() {
+    let superheated = SteamCondition {
+        pressure_bar_abs: 11.0,
+        temperature_c: Some(250.0),
+    };
+    let wet = SteamCondition {
+        pressure_bar_abs: 11.0,
+        temperature_c: Some(150.0),
+    };
+    let sh = compensate_flow(
+        MeterPrinciple::DifferentialPressure,
+        DESIGN,
+        superheated,
+        1000.0,
+    )
+    .unwrap();
+    assert!(sh.actual.superheat_k > 60.0);
+    assert!(sh.correction_factor < 1.0);
+
+    let r = compensate_flow(MeterPrinciple::DifferentialPressure, DESIGN, wet, 1000.0).unwrap();
+    assert!(r.actual.clamped_to_saturation);
+    assert!((r.correction_factor - 1.0).abs() < 1e-12);
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, MeterCompensationWarning::ClampedToSaturation { .. })));
+}
+
+#[test]
+fn historian_csv_is_corrected_row_by_row() {
+    let text = "timestamp,flow_kg_h,pressure_bar_g,temperature_c\n\
+                # 정지 구간 포함\n\
+                2024-05-01 00:00,5000,10,\n\
+                2024-05-01 00:15,4800,7,\n\
+                2024-05-01 00:30,0,9.5,200\n";
+    let rows = parse_historian_csv(text, 1.0).unwrap();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1].line, 4);
+    assert_eq!(rows[1].actual.pressure_bar_abs, 8.0);
+    assert_eq!(rows[2].actual.temperature_c, Some(200.0));
+
+    let series = compensate_series(MeterPrinciple::Vortex, DESIGN, &rows).unwrap();
+    assert!((series.rows[0].correction_factor - 1.0).abs() < 1e-12);
+    assert!(series.rows[1].corrected_flow_kg_per_h < 4800.0);
+    assert_eq!(series.rows[2].corrected_flow_kg_per_h, 0.0);
+    assert!(series.min_correction_factor < series.max_correction_factor);
+    let csv = compensated_series_csv(&series);
+    assert_eq!(csv.lines().count(), 4);
+    assert!(csv
+        .lines()
+        .nth(2)
+        .unwrap()
+        .starts_with("2024-05-01 00:15,4800.000,8.0000,"));
+
+    let err = parse_historian_csv("t,flow,p\n00:00,10,5\n00:15,abc,5\n", 1.0).unwrap_err();
+    assert!(matches!(err, MeterCompensationError::Csv { line: 3, .. }));
+}
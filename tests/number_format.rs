@@ -0,0 +1,39 @@
+use steam_engineering_toolbox::number_format::NumberFormat;
+use steam_engineering_toolbox::quantity::QuantityKind;
+
+#[test]
+fn default_format_matches_fixed_decimals() {
+    let nf = NumberFormat::default();
+    assert_eq!(nf.format(12.3456, 2), "12.35");
+    assert_eq!(nf.format(-0.5, 3), "-0.500");
+    assert_eq!(nf.format(0.0, 1), "0.0");
+    assert_eq!(
+        nf.format_quantity(179.884, QuantityKind::Temperature),
+        "179.9"
+    );
+    assert_eq!(nf.reformat("1234.50"), "1234.50");
+    assert_eq!(nf.reformat("n/a"), "n/a");
+}
+
+#[test]
+fn significant_figures_separators_and_scientific() {
+    let nf = NumberFormat {
+        significant_figures: Some(3),
+        decimal_separator: ',',
+        thousands_separator: Some('.'),
+        ..NumberFormat::default()
+    };
+    assert_eq!(nf.format(12_345.678, 2), "12.346");
+    assert_eq!(nf.format(0.012_345, 1), "0,0123");
+    assert_eq!(nf.format(-1.5, 0), "-1,50");
+    assert_eq!(nf.reformat("2716.60"), "2.717");
+    assert_eq!(nf.format(3.2e-6, 2), "3,20e-6");
+    assert_eq!(nf.format(4.5e12, 2), "4,50e12");
+
+    let mut per_kind = NumberFormat::default();
+    per_kind.quantity_decimals.insert("pressure".into(), 1);
+    assert_eq!(
+        per_kind.format_quantity(10.123, QuantityKind::Pressure),
+        "10.1"
+    );
+}
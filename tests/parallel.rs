@@ -0,0 +1,25 @@
+//! 병렬 실행 도우미 회귀 테스트. `parallel` 기능 여부와 관계없이 결과가 같아야 한다.
+use steam_engineering_toolbox::parallel;
+use steam_engineering_toolbox::steam::if97::{region_props, region_props_many};
+
+#[test]
+fn map_keeps_input_order_and_first_error() {
+    let items: Vec<u32> = (0..500).collect();
+    let doubled = parallel::map(&items, |x| x * 2);
+    assert_eq!(doubled, items.iter().map(|x| x * 2).collect::<Vec<_>>());
+
+    let res = parallel::try_map(&items, |&x| if x % 97 == 96 { Err(x) } else { Ok(x) });
+    assert_eq!(res, Err(96));
+}
+
+#[test]
+fn batch_region_props_match_single_calls() {
+    let points: Vec<(f64, f64)> = (0..200)
+        .map(|i| (1.0 + i as f64 * 0.5, 120.0 + i as f64 * 1.5))
+        .collect();
+    let batch = region_props_many(&points);
+    assert_eq!(batch.len(), points.len());
+    for (&(p, t), got) in points.iter().zip(&batch) {
+        assert_eq!(*got, region_props(p, t));
+    }
+}
@@ -0,0 +1,76 @@
+use steam_engineering_toolbox::plant_piping::pipe_class::{
+    check_size, schedule_wall_mm, select_size, PipeClassCatalog, PipeClassError,
+};
+
+const CLASSES: &str = r#"
+[[class]]
+name = "A1"
+description = "CS 150# RF"
+material = "A106B"
+corrosion_allowance_mm = 1.5
+max_pressure_bar_g = 19.6
+max_temp_c = 425
+min_temp_c = -29
+size = [
+  { dn = 50, schedule = "80" },
+  { dn = 100, schedule = "Sch40" },
+  { dn = 150, wall_mm = 7.11 },
+  { dn = 300, schedule = "STD" },
+]
+"#;
+
+#[test]
+fn class_sizes_resolve_schedule_walls() {
+    assert_eq!(schedule_wall_mm(100, "40"), Some(6.02));
+    assert_eq!(schedule_wall_mm(300, "std"), Some(9.53));
+    assert_eq!(schedule_wall_mm(250, "XS"), Some(12.70));
+    assert_eq!(schedule_wall_mm(100, "XXS"), None);
+
+    let catalog = PipeClassCatalog::from_toml_str(CLASSES).unwrap();
+    let class = catalog.find("a1").unwrap();
+    assert_eq!(class.mill_tolerance, 0.125);
+    let pipes = class.pipes().unwrap();
+    assert_eq!(pipes.iter().map(|p| p.dn).collect::<Vec<_>>(), [50, 100, 150, 300]);
+    assert!((pipes[1].inner_diameter_m - 0.10226).abs() < 1e-6);
+    assert_eq!(pipes[2].schedule, "7.11 mm");
+    assert!(matches!(
+        catalog.find("B2"),
+        Err(PipeClassError::UnknownClass(_))
+    ));
+}
+
+#[test]
+fn check_flags_class_limits_and_thin_wall() {
+    let catalog = PipeClassCatalog::from_toml_str(CLASSES).unwrap();
+    let class = catalog.find("A1").unwrap();
+    let ok = check_size(class, 100, 10.0, 200.0).unwrap();
+    assert!(ok.acceptable(), "{:?}", ok.violations);
+    assert!(ok.required_wall_m < ok.pipe.wall_m);
+
+    let hot = check_size(class, 100, 25.0, 450.0).unwrap();
+    assert_eq!(hot.violations.len(), 2);
+    // 큰 관경은 같은 압력에서 필요 두께가 커진다
+    let large = check_size(class, 300, 19.0, 400.0).unwrap();
+    assert!(large.required_wall_m > ok.required_wall_m);
+    assert!(matches!(
+        check_size(class, 80, 10.0, 200.0),
+        Err(PipeClassError::SizeNotInClass { dn: 80, .. })
+    ));
+}
+
+#[test]
+fn line_sizing_picks_smallest_fitting_class_size() {
+    let catalog = PipeClassCatalog::from_toml_str(CLASSES).unwrap();
+    let class = catalog.find("A1").unwrap();
+    assert_eq!(select_size(class, 0.045, 10.0, 200.0).unwrap().pipe.dn, 50);
+    assert_eq!(select_size(class, 0.060, 10.0, 200.0).unwrap().pipe.dn, 100);
+    assert!(matches!(
+        select_size(class, 0.40, 10.0, 200.0),
+        Err(PipeClassError::NoSizeLargeEnough { .. })
+    ));
+    let duplicate = CLASSES.replace("{ dn = 150, wall_mm = 7.11 }", "{ dn = 100, wall_mm = 7.11 }");
+    assert!(matches!(
+        PipeClassCatalog::from_toml_str(&duplicate),
+        Err(PipeClassError::Invalid(_))
+    ));
+}
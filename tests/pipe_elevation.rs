@@ -0,0 +1,51 @@
+use steam_engineering_toolbox::steam::steam_piping::{pressure_loss, PressureLossInput};
+use steam_engineering_toolbox::water::water_piping::{water_pressure_loss, WaterPressureLossInput};
+
+fn steam_riser(inlet_elevation_m: f64, outlet_elevation_m: f64) -> PressureLossInput {
+    PressureLossInput {
+        mass_flow_kg_per_h: 5_000.0,
+        steam_density_kg_per_m3: 1.0,
+        diameter_m: 0.1023,
+        length_m: 60.0,
+        fittings_k_sum: 0.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 4.5e-5,
+        dynamic_viscosity_pa_s: 1.5e-5,
+        sound_speed_m_per_s: 450.0,
+        state_pressure_bar_abs: Some(10.0),
+        state_temperature_c: Some(200.0),
+        inlet_elevation_m,
+        outlet_elevation_m,
+    }
+}
+
+#[test]
+fn steam_riser_adds_static_head_separately_from_friction() {
+    let flat = pressure_loss(steam_riser(5.0, 5.0)).unwrap();
+    let riser = pressure_loss(steam_riser(5.0, 25.0)).unwrap();
+    assert_eq!(flat.elevation_drop_bar, 0.0);
+    assert_eq!(riser.pressure_drop_bar, flat.pressure_drop_bar);
+    // IF97 밀도(10 bar(a), 200 °C ≈ 4.85 kg/m³) × g × 20 m
+    let expected = riser.density_kg_per_m3 * 9.80665 * 20.0 / 1e5;
+    assert!((riser.elevation_drop_bar - expected).abs() < 1e-12);
+    assert!((riser.density_kg_per_m3 - 4.85).abs() < 0.05);
+    assert!((riser.total_drop_bar() - (riser.pressure_drop_bar + expected)).abs() < 1e-12);
+}
+
+#[test]
+fn water_downcomer_gains_pressure() {
+    let res = water_pressure_loss(WaterPressureLossInput {
+        flow_m3_per_h: 40.0,
+        density_kg_per_m3: 998.0,
+        dynamic_viscosity_pa_s: 1.0e-3,
+        diameter_m: 0.1,
+        length_m: 30.0,
+        roughness_m: 4.5e-5,
+        inlet_elevation_m: 30.0,
+        outlet_elevation_m: 0.0,
+    });
+    // 998 × 9.80665 × (-30) Pa ≈ -2.936 bar
+    assert!((res.elevation_drop_bar + 2.936).abs() < 1e-3);
+    assert!(res.pressure_drop_bar > 0.0);
+    assert!(res.total_drop_bar() < 0.0);
+}
@@ -0,0 +1,92 @@
+use steam_engineering_toolbox::steam::steam_piping::fittings::{
+    self, crane_friction_factor, FittingList, FittingMethod,
+};
+use steam_engineering_toolbox::steam::steam_piping::{
+    pressure_loss, pressure_loss_with_fittings, PipeCalcError, PressureLossInput,
+};
+
+fn water_line() -> PressureLossInput {
+    PressureLossInput {
+        mass_flow_kg_per_h: 36_000.0,
+        steam_density_kg_per_m3: 998.0,
+        diameter_m: 0.1023,
+        length_m: 50.0,
+        fittings_k_sum: 0.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 0.045e-3,
+        dynamic_viscosity_pa_s: 1.0e-3,
+        sound_speed_m_per_s: 1480.0,
+        state_pressure_bar_abs: None,
+        state_temperature_c: None,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    }
+}
+
+#[test]
+fn catalog_values_follow_two_k_and_crane_definitions() {
+    let elbow = fittings::find_fitting("elbow90-std").unwrap();
+    // 4" (102.3 mm), Re=1e5: 800/1e5 + 0.40·(1 + 1/4.03)
+    let k = elbow.k_two_k(1.0e5, 0.1023);
+    assert!((k - (0.008 + 0.40 * (1.0 + 0.0254 / 0.1023))).abs() < 1e-12);
+    // Crane 4" f_T ≈ 0.017 → 30·f_T ≈ 0.5
+    let ft = crane_friction_factor(0.1023);
+    assert!((ft - 0.0163).abs() < 1e-3, "f_T={ft}");
+    assert!((elbow.k_crane(0.1023) - 30.0 * ft).abs() < 1e-12);
+    // 입·출구는 크기 항이 없고 Crane에서도 고정 K
+    let exit = fittings::find_fitting("exit").unwrap();
+    assert_eq!(exit.k_two_k(1.0e5, 0.05), 1.0);
+    assert_eq!(exit.k_crane(0.05), 1.0);
+    for f in fittings::catalog() {
+        assert!(f.k(FittingMethod::TwoK, 1.0e5, 0.1) > 0.0, "{}", f.code);
+        assert!(f.k(FittingMethod::Crane, 1.0e5, 0.1) > 0.0, "{}", f.code);
+    }
+}
+
+#[test]
+fn fitting_list_parses_counts_and_merges_duplicates() {
+    let list = FittingList::parse("elbow90-lr:4, gate*2; elbow90-lr, exit").unwrap();
+    let counts: Vec<(&str, u32)> = list.items().iter().map(|(f, n)| (f.code, *n)).collect();
+    assert_eq!(counts, vec![("elbow90-lr", 5), ("gate", 2), ("exit", 1)]);
+    assert!(FittingList::parse("").unwrap().is_empty());
+    assert!(matches!(
+        FittingList::parse("elbow90:2"),
+        Err(PipeCalcError::UnknownFitting(code)) if code == "elbow90"
+    ));
+    assert!(matches!(
+        FittingList::parse("gate:two"),
+        Err(PipeCalcError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn fittings_add_their_k_to_the_pressure_loss() {
+    let list = FittingList::new()
+        .add_code("elbow90-lr", 4)
+        .unwrap()
+        .add_code("globe", 1)
+        .unwrap();
+    let plain = pressure_loss(water_line()).unwrap();
+    let with = pressure_loss_with_fittings(water_line(), &list, FittingMethod::TwoK).unwrap();
+    let k = list.k_sum(FittingMethod::TwoK, plain.reynolds_number, 0.1023);
+    let manual = pressure_loss(PressureLossInput {
+        fittings_k_sum: k,
+        ..water_line()
+    })
+    .unwrap();
+    assert!((with.pressure_drop_bar - manual.pressure_drop_bar).abs() < 1e-12);
+    assert!(with.pressure_drop_bar > plain.pressure_drop_bar);
+    // 등가 길이 = ΣK·D/f 이면 직관 길이로 더해도 같은 ΔP
+    let leq = list.equivalent_length_m(
+        FittingMethod::TwoK,
+        plain.reynolds_number,
+        0.1023,
+        plain.friction_factor,
+    );
+    let by_length = pressure_loss(PressureLossInput {
+        equivalent_length_m: leq,
+        ..water_line()
+    })
+    .unwrap();
+    assert!((by_length.pressure_drop_bar - with.pressure_drop_bar).abs() < 1e-9);
+}
@@ -0,0 +1,87 @@
+use steam_engineering_toolbox::plant_piping::network::{
+    FluidProps, Link, Node, PipeNetwork, PipeNetworkError,
+};
+use steam_engineering_toolbox::water::water_piping::{water_pressure_loss, WaterPressureLossInput};
+
+const WATER: FluidProps = FluidProps {
+    density_kg_per_m3: 998.0,
+    dynamic_viscosity_pa_s: 1.0e-3,
+};
+
+fn pipe(name: &str, from: usize, to: usize, length_m: f64, diameter_m: f64) -> Link {
+    Link::Pipe {
+        name: name.into(),
+        from,
+        to,
+        length_m,
+        diameter_m,
+        roughness_m: 4.5e-5,
+        fittings_k_sum: 0.0,
+    }
+}
+
+#[test]
+fn single_pipe_matches_water_pressure_loss() {
+    let mut net = PipeNetwork::new(WATER);
+    let src = net.add_node(Node::fixed("src", 0.0, 5.0));
+    let user = net.add_node(Node::junction("user", 0.0, 40.0));
+    net.add_link(pipe("P1", src, user, 200.0, 0.1));
+    let res = net.solve().unwrap();
+
+    let single = water_pressure_loss(WaterPressureLossInput {
+        flow_m3_per_h: 40.0,
+        density_kg_per_m3: 998.0,
+        dynamic_viscosity_pa_s: 1.0e-3,
+        diameter_m: 0.1,
+        length_m: 200.0,
+        roughness_m: 4.5e-5,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    });
+    assert!((res.links[0].flow_m3_per_h - 40.0).abs() < 1e-6);
+    assert!((res.links[0].pressure_drop_bar - single.pressure_drop_bar).abs() < 1e-6);
+    assert!((res.nodes[0].external_outflow_m3_per_h + 40.0).abs() < 1e-6);
+}
+
+#[test]
+fn pumped_loop_conserves_mass_and_splits_symmetrically() {
+    // 탱크 → 펌프 → A, A에서 두 갈래(B, C)로 나뉘어 D에서 합류 후 수요처 2곳
+    let mut net = PipeNetwork::new(WATER);
+    let tank = net.add_node(Node::fixed("tank", 0.0, 0.0));
+    let a = net.add_node(Node::junction("A", 0.0, 0.0));
+    let b = net.add_node(Node::junction("B", 5.0, 10.0));
+    let c = net.add_node(Node::junction("C", 5.0, 10.0));
+    let d = net.add_node(Node::junction("D", 5.0, 30.0));
+    net.add_link(Link::Pump {
+        name: "pump".into(),
+        from: tank,
+        to: a,
+        shutoff_head_m: 60.0,
+        rated_flow_m3_per_h: 50.0,
+        rated_head_m: 45.0,
+    });
+    net.add_link(pipe("A-B", a, b, 100.0, 0.08));
+    net.add_link(pipe("A-C", a, c, 100.0, 0.08));
+    net.add_link(pipe("B-D", b, d, 80.0, 0.08));
+    net.add_link(pipe("C-D", c, d, 80.0, 0.08));
+    let res = net.solve().unwrap();
+
+    assert!((res.links[0].flow_m3_per_h - 50.0).abs() < 1e-6);
+    assert!((res.links[1].flow_m3_per_h - res.links[2].flow_m3_per_h).abs() < 1e-6);
+    assert!((res.links[1].flow_m3_per_h - 25.0).abs() < 1e-6);
+    // 펌프 정격점과 같으므로 A 수두는 45 m
+    assert!((res.nodes[a].hydraulic_head_m - 45.0).abs() < 1e-6);
+    assert!(res.links[0].pressure_drop_bar < 0.0);
+    assert!(res.nodes[d].pressure_bar_g < res.nodes[b].pressure_bar_g);
+    assert!(res.warnings.is_empty());
+
+    let total: f64 = res.nodes.iter().map(|n| n.external_outflow_m3_per_h).sum();
+    assert!(total.abs() < 1e-6);
+
+    let mut isolated = PipeNetwork::new(WATER);
+    isolated.add_node(Node::junction("X", 0.0, 1.0));
+    assert_eq!(
+        isolated.solve().unwrap_err(),
+        PipeNetworkError::InvalidInput("배관 또는 펌프가 최소 1개 필요합니다.")
+    );
+}
@@ -0,0 +1,158 @@
+use steam_engineering_toolbox::steam::steam_piping::fittings::{FittingList, FittingMethod};
+use steam_engineering_toolbox::steam::steam_piping::pipe_run::{
+    parse_pipe_run_csv, pipe_run, PipeRunInput, PipeSegment,
+};
+use steam_engineering_toolbox::steam::steam_piping::{
+    pressure_loss, PipeCalcError, PressureLossInput,
+};
+
+fn segment(name: &str, diameter_m: f64, length_m: f64, rise_m: f64) -> PipeSegment {
+    PipeSegment {
+        name: name.to_string(),
+        diameter_m,
+        length_m,
+        elevation_change_m: rise_m,
+        roughness_m: 0.045e-3,
+        fittings: FittingList::new(),
+        extra_k: 0.0,
+    }
+}
+
+fn steam_run(segments: Vec<PipeSegment>) -> PipeRunInput {
+    PipeRunInput {
+        mass_flow_kg_per_h: 5000.0,
+        inlet_pressure_bar_abs: 11.0,
+        inlet_temperature_c: 250.0,
+        fitting_method: FittingMethod::TwoK,
+        dynamic_viscosity_pa_s: 1.6e-5,
+        sound_speed_m_per_s: 500.0,
+        segments,
+    }
+}
+
+#[test]
+fn single_horizontal_segment_matches_darcy() {
+    let run = pipe_run(&steam_run(vec![segment("main", 0.1023, 100.0, 0.0)])).unwrap();
+    let direct = pressure_loss(PressureLossInput {
+        mass_flow_kg_per_h: 5000.0,
+        steam_density_kg_per_m3: 5.0,
+        diameter_m: 0.1023,
+        length_m: 100.0,
+        fittings_k_sum: 0.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 0.045e-3,
+        dynamic_viscosity_pa_s: 1.6e-5,
+        sound_speed_m_per_s: 500.0,
+        state_pressure_bar_abs: Some(11.0),
+        state_temperature_c: Some(250.0),
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    })
+    .unwrap();
+    let seg = &run.segments[0];
+    assert_eq!(seg.substeps, 1);
+    assert_eq!(seg.elevation_drop_bar, 0.0);
+    assert!(
+        (seg.friction_drop_bar - direct.pressure_drop_bar).abs() < 1e-4 * direct.pressure_drop_bar
+    );
+    assert!((run.outlet_pressure_bar_abs - (11.0 - seg.friction_drop_bar)).abs() < 1e-12);
+}
+
+#[test]
+fn segments_chain_pressures_and_sum_drops() {
+    let mut riser = segment("riser", 0.0779, 30.0, 20.0);
+    riser.fittings = FittingList::parse("elbow90-lr:2, gate").unwrap();
+    let run = pipe_run(&steam_run(vec![
+        segment("header", 0.1541, 200.0, 0.0),
+        riser,
+        segment("drop", 0.1023, 40.0, -20.0),
+    ]))
+    .unwrap();
+    assert_eq!(run.segments.len(), 3);
+    for pair in run.segments.windows(2) {
+        assert_eq!(
+            pair[0].outlet_pressure_bar_abs,
+            pair[1].inlet_pressure_bar_abs
+        );
+    }
+    let total: f64 = run.segments.iter().map(|s| s.total_drop_bar()).sum();
+    assert!((run.total_drop_bar - total).abs() < 1e-12);
+    assert!((run.friction_drop_bar + run.elevation_drop_bar - total).abs() < 1e-12);
+    assert!(run.segments[1].fittings_k > 0.0);
+    // 증기 정수두: 오르막 +, 내리막 -, 크기는 ρgΔz
+    let riser = &run.segments[1];
+    assert!(riser.elevation_drop_bar > 0.0 && run.segments[2].elevation_drop_bar < 0.0);
+    let expected = riser.density_kg_per_m3 * 9.80665 * 20.0 / 1e5;
+    assert!((riser.elevation_drop_bar - expected).abs() < 0.05 * expected);
+}
+
+#[test]
+fn water_riser_carries_static_head() {
+    let run = pipe_run(&PipeRunInput {
+        mass_flow_kg_per_h: 100.0,
+        inlet_pressure_bar_abs: 5.0,
+        inlet_temperature_c: 20.0,
+        fitting_method: FittingMethod::Crane,
+        dynamic_viscosity_pa_s: 1.0e-3,
+        sound_speed_m_per_s: 1480.0,
+        segments: vec![segment("riser", 0.0525, 10.0, 10.0)],
+    })
+    .unwrap();
+    // 20 °C 물 998.4 kg/m³ × g × 10 m ≈ 0.979 bar
+    assert!(
+        (run.elevation_drop_bar - 0.979).abs() < 2e-3,
+        "{}",
+        run.elevation_drop_bar
+    );
+    assert!(run.friction_drop_bar < 1e-3);
+}
+
+#[test]
+fn large_drops_are_split_into_substeps() {
+    let mut input = steam_run(vec![segment("long", 0.0525, 400.0, 0.0)]);
+    input.mass_flow_kg_per_h = 1000.0;
+    let run = pipe_run(&input).unwrap();
+    let seg = &run.segments[0];
+    assert!(seg.substeps > 1, "substeps={}", seg.substeps);
+    assert!(run.outlet_pressure_bar_abs > 0.0 && run.outlet_pressure_bar_abs < 11.0);
+
+    input.mass_flow_kg_per_h = 20_000.0;
+    assert!(matches!(
+        pipe_run(&input),
+        Err(PipeCalcError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn csv_reads_segments_with_presets_and_fittings() {
+    let text = "name,diameter_mm,length_m,rise_m,roughness,extra_k,fittings\n\
+                # 보일러 헤더\n\
+                header,154.1,200,0\n\
+                riser,77.9,30,20,stainless,0.5,elbow90-lr:2, gate\n\
+                \n\
+                drop,102.3,40,-20,0.1,,exit\n";
+    let segments = parse_pipe_run_csv(text).unwrap();
+    assert_eq!(segments.len(), 3);
+    assert!((segments[0].diameter_m - 0.1541).abs() < 1e-12);
+    assert!((segments[0].roughness_m - 0.045e-3).abs() < 1e-15);
+    assert!((segments[1].roughness_m - 0.015e-3).abs() < 1e-15);
+    assert_eq!(segments[1].extra_k, 0.5);
+    let counts: Vec<(&str, u32)> = segments[1]
+        .fittings
+        .items()
+        .iter()
+        .map(|(f, n)| (f.code, *n))
+        .collect();
+    assert_eq!(counts, vec![("elbow90-lr", 2), ("gate", 1)]);
+    assert!((segments[2].roughness_m - 0.1e-3).abs() < 1e-15);
+    assert_eq!(segments[2].elevation_change_m, -20.0);
+
+    match parse_pipe_run_csv("a,100,10,0\nb,80,x,0\n") {
+        Err(PipeCalcError::Csv { line, .. }) => assert_eq!(line, 2),
+        other => panic!("{other:?}"),
+    }
+    assert!(matches!(
+        parse_pipe_run_csv("a,100,10,0,,,elbow91"),
+        Err(PipeCalcError::Csv { line: 1, .. })
+    ));
+}
@@ -0,0 +1,116 @@
+use steam_engineering_toolbox::i18n::Translator;
+use steam_engineering_toolbox::steam::steam_piping::velocity_check::{
+    check_sized_pipe, check_velocity, erosional_velocity_m_per_s, LimitingCriterion, PipeService,
+    VelocityCheckInput, VelocityVerdict,
+};
+use steam_engineering_toolbox::steam::steam_piping::{size_by_velocity, PipeSizingByVelocityInput};
+use steam_engineering_toolbox::warning::{CalcWarning, Severity};
+
+fn input(service: PipeService, diameter_m: f64, velocity_m_per_s: f64) -> VelocityCheckInput {
+    VelocityCheckInput {
+        service,
+        pressure_bar_abs: 10.0,
+        temperature_c: None,
+        quality: None,
+        diameter_m,
+        velocity_m_per_s,
+        density_kg_per_m3: None,
+        c_factor: None,
+    }
+}
+
+#[test]
+fn saturated_steam_main_follows_guidance_bands() {
+    let verdict = |v| check_velocity(&input(PipeService::SaturatedSteam, 0.1023, v)).unwrap();
+    let pass = verdict(20.0);
+    assert_eq!(pass.verdict, VelocityVerdict::Pass);
+    assert_eq!(pass.severity(), Severity::Info);
+    // 10 bar(a) 포화 증기 ρ≈5.15 kg/m³ → Ve = 1.22·100/√5.15 ≈ 53.8 m/s
+    assert!((pass.density_kg_per_m3 - 5.15).abs() < 0.02);
+    assert!((pass.erosional_m_per_s - 53.8).abs() < 0.2);
+    assert_eq!(pass.limiting, LimitingCriterion::Guidance);
+    assert_eq!(verdict(30.0).verdict, VelocityVerdict::Warn);
+    let fail = verdict(45.0);
+    assert_eq!(fail.verdict, VelocityVerdict::Fail);
+    assert_eq!(fail.i18n_key(), "warning.velocity.above_max");
+    assert!((fail.utilization() - 45.0 / 40.0).abs() < 1e-12);
+
+    // 소구경은 권장/상한이 낮다
+    let small = check_velocity(&input(PipeService::SaturatedSteam, 0.0409, 30.0)).unwrap();
+    assert_eq!(small.verdict, VelocityVerdict::Fail);
+}
+
+#[test]
+fn low_c_factor_makes_erosion_the_limit_for_water() {
+    let mut water = input(PipeService::Water, 0.1023, 1.8);
+    water.c_factor = Some(50.0);
+    let check = check_velocity(&water).unwrap();
+    assert!(
+        (check.erosional_m_per_s - erosional_velocity_m_per_s(check.density_kg_per_m3, 50.0)).abs()
+            < 1e-12
+    );
+    assert_eq!(check.limiting, LimitingCriterion::Erosional);
+    assert_eq!(check.verdict, VelocityVerdict::Pass);
+
+    water.velocity_m_per_s = 2.5;
+    let check = check_velocity(&water).unwrap();
+    assert_eq!(check.verdict, VelocityVerdict::Fail);
+    assert_eq!(check.i18n_key(), "warning.velocity.erosional");
+}
+
+#[test]
+fn service_from_state_and_required_temperature() {
+    assert_eq!(
+        PipeService::from_state(10.0, 150.0).unwrap(),
+        PipeService::Water
+    );
+    assert_eq!(
+        PipeService::from_state(10.0, 179.9).unwrap(),
+        PipeService::SaturatedSteam
+    );
+    assert_eq!(
+        PipeService::from_state(10.0, 250.0).unwrap(),
+        PipeService::SuperheatedSteam
+    );
+    assert_eq!(PipeService::from_code(" Wet "), Some(PipeService::WetSteam));
+
+    let mut superheated = input(PipeService::SuperheatedSteam, 0.2027, 40.0);
+    assert!(check_velocity(&superheated).is_err());
+    superheated.temperature_c = Some(250.0);
+    assert_eq!(
+        check_velocity(&superheated).unwrap().verdict,
+        VelocityVerdict::Pass
+    );
+}
+
+#[test]
+fn sized_pipe_verdict_uses_state_service() {
+    // 10 bar(a), 250 °C 과열 증기를 55 m/s로 사이징 → 대구경 과열 증기 권장 50 m/s 초과, Ve≈58.8 m/s 이하
+    let sizing = size_by_velocity(PipeSizingByVelocityInput {
+        mass_flow_kg_per_h: 20_000.0,
+        steam_density_kg_per_m3: 4.3,
+        target_velocity_m_per_s: 55.0,
+    })
+    .unwrap();
+    let check = check_sized_pipe(&sizing, None, 10.0, 250.0, 4.3).unwrap();
+    assert_eq!(check.service, PipeService::SuperheatedSteam);
+    assert_eq!(check.verdict, VelocityVerdict::Warn);
+    assert!((check.velocity_m_per_s - 55.0).abs() < 1e-6);
+
+    let forced = check_sized_pipe(&sizing, Some(PipeService::WetSteam), 10.0, 250.0, 4.3).unwrap();
+    assert_eq!(forced.verdict, VelocityVerdict::Fail);
+}
+
+#[test]
+fn verdict_text_follows_the_translator_language() {
+    let fail = check_velocity(&input(PipeService::SaturatedSteam, 0.1023, 45.0)).unwrap();
+    // 언어팩 없이 만든 영어 번역기도 한국어 기본 문구 대신 내장 영어 문구를 쓴다
+    assert_eq!(
+        fail.localized(&Translator::new("en-us")),
+        "Velocity 45.0 m/s exceeds the guidance maximum 40.0 m/s (Spirax Sarco). Increase the pipe size."
+    );
+    assert!(fail
+        .localized(&Translator::new_with_pack("de-de", None))
+        .starts_with("Geschwindigkeit 45.0 m/s"));
+    assert_eq!(fail.localized(&Translator::new("ko-kr")), fail.to_string());
+}
@@ -40,3 +40,51 @@ fn mmhg_abs_to_gauge_mm_hg_roundtrip() {
     );
     assert!(mmhg_g.abs() < 5e-2, "expected ~0 mmHg(g), got {mmhg_g}");
 }
+
+#[test]
+fn vacuum_table_follows_site_atmosphere() {
+    use steam_engineering_toolbox::steam::{vacuum_saturation_table, VACUUM_TABLE_MMHG_GAUGE};
+    let sea_level = vacuum_saturation_table(&VACUUM_TABLE_MMHG_GAUGE, 1.01325);
+    assert_eq!(sea_level.len(), VACUUM_TABLE_MMHG_GAUGE.len());
+    let atm = sea_level[0]
+        .saturation_temperature_c
+        .expect("Tsat at 1 atm");
+    assert!((atm - 100.0).abs() < 0.05, "Tsat(1 atm) = {atm}");
+    assert!(sea_level.last().unwrap().saturation_temperature_c.is_none());
+
+    // 고지대(0.9 bar a)에서는 같은 게이지 행이 더 낮은 절대압·포화온도가 된다
+    let highland = vacuum_saturation_table(&[0.0, -600.0, -700.0], 0.9);
+    assert!((highland[0].pressure_bar_abs - 0.9).abs() < 1e-12);
+    assert!(highland[0].saturation_temperature_c.unwrap() < atm);
+    assert!(highland[1].saturation_temperature_c.is_some());
+    assert!(highland[2].pressure_bar_abs < 0.0);
+    assert!(highland[2].saturation_temperature_c.is_none());
+}
+
+#[test]
+fn vacuum_table_rows_walk_from_atmosphere_to_full_vacuum() {
+    use steam_engineering_toolbox::steam::{vacuum_saturation_table, VACUUM_TABLE_MMHG_GAUGE};
+    assert_eq!(VACUUM_TABLE_MMHG_GAUGE.len(), 36);
+    assert_eq!(VACUUM_TABLE_MMHG_GAUGE[0], 0.0);
+    assert_eq!(*VACUUM_TABLE_MMHG_GAUGE.last().unwrap(), -760.0);
+    let table = vacuum_saturation_table(&VACUUM_TABLE_MMHG_GAUGE, 1.01325);
+    for (row, &mmhg) in table.iter().zip(VACUUM_TABLE_MMHG_GAUGE.iter()) {
+        assert_eq!(row.mmhg_gauge, mmhg);
+    }
+    for pair in table.windows(2) {
+        assert!(pair[1].mmhg_gauge < pair[0].mmhg_gauge);
+        assert!(pair[1].pressure_bar_abs < pair[0].pressure_bar_abs);
+        if let (Some(t0), Some(t1)) = (
+            pair[0].saturation_temperature_c,
+            pair[1].saturation_temperature_c,
+        ) {
+            assert!(t1 < t0, "{} mmHg(g): {t1} >= {t0}", pair[1].mmhg_gauge);
+        }
+    }
+    // 마지막 행(완전 진공)만 포화온도가 없다
+    let without_tsat = table
+        .iter()
+        .filter(|r| r.saturation_temperature_c.is_none())
+        .count();
+    assert_eq!(without_tsat, 1);
+}
@@ -0,0 +1,70 @@
+use steam_engineering_toolbox::plant_piping::pressure_test::{
+    pneumatic_stored_energy, test_pressure, PressureTestError, StoredEnergyInput, TestCode,
+    TestMedium, TestPressureInput,
+};
+
+fn a106_line(code: TestCode, medium: TestMedium) -> TestPressureInput {
+    TestPressureInput {
+        code,
+        medium,
+        design_pressure_bar_g: 40.0,
+        design_temp_c: 400.0,
+        test_temp_c: 20.0,
+        material: "A106B".into(),
+        planned_hold_minutes: 30.0,
+    }
+}
+
+#[test]
+fn hydrotest_pressure_is_corrected_by_stress_ratio_for_b31_3_only() {
+    let r = test_pressure(&a106_line(TestCode::ProcessPiping, TestMedium::Hydrostatic)).unwrap();
+    // S(20 °C) / S(400 °C) = 138 / 93
+    assert!((r.stress_ratio - 138.0 / 93.0).abs() < 1e-12);
+    assert!((r.test_pressure_bar_g - 1.5 * 40.0 * 138.0 / 93.0).abs() < 1e-9);
+    assert_eq!(r.examination_pressure_bar_g, r.test_pressure_bar_g);
+    assert!(r.hold_ok && r.pressure_steps_bar_g.is_empty() && r.warnings.is_empty());
+
+    let mut b31_1 = a106_line(TestCode::PowerPiping, TestMedium::Hydrostatic);
+    b31_1.planned_hold_minutes = 5.0;
+    let r = test_pressure(&b31_1).unwrap();
+    assert_eq!(r.test_pressure_bar_g, 60.0);
+    assert!(!r.hold_ok);
+    assert_eq!(r.warnings.len(), 1);
+
+    b31_1.material = "unobtainium".into();
+    b31_1.code = TestCode::ProcessPiping;
+    assert!(matches!(
+        test_pressure(&b31_1),
+        Err(PressureTestError::UnknownMaterial(_))
+    ));
+}
+
+#[test]
+fn pneumatic_test_steps_and_stored_energy() {
+    let mut input = a106_line(TestCode::ProcessPiping, TestMedium::Pneumatic);
+    input.design_pressure_bar_g = 10.0;
+    let r = test_pressure(&input).unwrap();
+    assert!((r.test_pressure_bar_g - 11.0).abs() < 1e-12);
+    assert_eq!(r.examination_pressure_bar_g, 10.0);
+    // 1.7 bar 예비 검사 후 1.1 bar씩 승압해 11 bar에서 끝난다.
+    let steps = &r.pressure_steps_bar_g;
+    assert_eq!(steps.first(), Some(&1.7));
+    assert_eq!(steps.last(), Some(&11.0));
+    assert_eq!(steps.len(), 10);
+
+    let e = pneumatic_stored_energy(&StoredEnergyInput {
+        test_pressure_bar_g: r.test_pressure_bar_g,
+        volume_m3: 1.0,
+        gamma: 1.4,
+        ambient_pressure_bar_abs: 1.01325,
+    })
+    .unwrap();
+    // PCC-2: 2.5·P·V·[1 − (Pa/P)^0.286] ≈ 1.52 MJ → TNT 0.36 kg, 반경 ≈ 14 m
+    assert!((e.energy_j / 1e6 - 1.52).abs() < 0.01, "{}", e.energy_j);
+    assert!(
+        (e.exclusion_radius_m - 14.3).abs() < 0.1,
+        "{}",
+        e.exclusion_radius_m
+    );
+    assert!(e.exceeds_threshold);
+}
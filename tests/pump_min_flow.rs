@@ -0,0 +1,116 @@
+use steam_engineering_toolbox::cooling::condenser::CoolingWarning;
+use steam_engineering_toolbox::cooling::pump_min_flow::{
+    compute_pump_min_flow, temperature_rise_k, thermal_min_flow_m3_per_h, PumpCurve,
+    PumpMinFlowInput, RecirculationInput,
+};
+use steam_engineering_toolbox::plant_piping::flow_meter::{
+    liquid_flow_m3_per_h, permanent_loss_ratio, MeterType,
+};
+use steam_engineering_toolbox::warning::CalcWarning;
+
+fn boiler_feed_pump(flow_m3_per_h: f64) -> PumpMinFlowInput {
+    PumpMinFlowInput {
+        curve: PumpCurve {
+            bep_flow_m3_per_h: 100.0,
+            bep_head_m: 800.0,
+            bep_efficiency: 0.75,
+            shutoff_head_m: 960.0,
+        },
+        suction_pressure_bar_abs: 6.0,
+        liquid_temp_c: 150.0,
+        flow_m3_per_h,
+        max_temp_rise_k: 8.0,
+        stable_flow_fraction: 0.25,
+        recirculation: None,
+    }
+}
+
+#[test]
+fn temperature_rise_grows_as_flow_falls() {
+    // 100 m 양정, 효율 50%: g·H/c_p = 9.80665·100/4180
+    assert!((temperature_rise_k(100.0, 0.5, 4180.0) - 0.2346).abs() < 1e-4);
+    assert_eq!(temperature_rise_k(100.0, 1.0, 4180.0), 0.0);
+
+    let input = boiler_feed_pump(100.0);
+    let curve = input.curve;
+    assert_eq!(curve.head_m(0.0), 960.0);
+    assert_eq!(curve.head_m(100.0), 800.0);
+    assert!((curve.efficiency(100.0) - 0.75).abs() < 1e-12);
+
+    let cp = 4310.0;
+    let q_min = thermal_min_flow_m3_per_h(&curve, cp, 8.0).unwrap();
+    let rise = temperature_rise_k(curve.head_m(q_min), curve.efficiency(q_min), cp);
+    assert!((rise - 8.0).abs() < 1e-6, "{rise}");
+    assert!(q_min > 10.0 && q_min < 20.0, "{q_min}");
+    // BEP에서의 온도 상승보다 작은 허용값은 받을 수 없다
+    assert!(thermal_min_flow_m3_per_h(&curve, cp, 0.1).is_err());
+}
+
+#[test]
+fn low_flow_below_minimum_warns_of_flashing() {
+    let bep = compute_pump_min_flow(&boiler_feed_pump(60.0)).unwrap();
+    assert!(bep.warnings.is_empty(), "{:?}", bep.warnings);
+    assert!((bep.saturation_margin_k - 8.83).abs() < 0.05);
+    // 안정 운전 최소 유량(BEP 25%)이 열적 최소 유량보다 크다
+    assert!((bep.stable_min_flow_m3_per_h - 25.0).abs() < 1e-12);
+    assert!(bep.thermal_min_flow_m3_per_h < bep.stable_min_flow_m3_per_h);
+    assert_eq!(bep.min_flow_m3_per_h, bep.stable_min_flow_m3_per_h);
+
+    let low = compute_pump_min_flow(&boiler_feed_pump(10.0)).unwrap();
+    assert!(low.temp_rise_k > low.saturation_margin_k);
+    assert!(matches!(
+        low.warnings[0],
+        CoolingWarning::PumpBelowMinimumFlow { .. }
+    ));
+    assert_eq!(low.warnings[1].i18n_key(), "warning.cooling.pump_flashing");
+}
+
+#[test]
+fn recirculation_orifice_drops_discharge_to_return_pressure() {
+    let mut input = boiler_feed_pump(60.0);
+    input.recirculation = Some(RecirculationInput {
+        return_pressure_bar_abs: 5.0,
+        pipe_diameter_m: 0.05,
+        stages: 1,
+    });
+    let r = compute_pump_min_flow(&input).unwrap();
+    let orifice = r.recirculation.unwrap();
+    assert_eq!(orifice.flow_m3_per_h, r.min_flow_m3_per_h);
+    // 토출 약 6 + 917·g·950/1e5 ≈ 91.5 bar(a)
+    assert!((orifice.discharge_pressure_bar_abs - 91.5).abs() < 0.5);
+    assert!((orifice.differential_bar - (orifice.discharge_pressure_bar_abs - 5.0)).abs() < 1e-12);
+    assert!((orifice.bore_mm - orifice.beta * 50.0).abs() < 1e-9);
+    assert!(
+        orifice.beta > 0.15 && orifice.beta < 0.25,
+        "{}",
+        orifice.beta
+    );
+
+    // 구한 β에서 영구 손실을 다시 계산하면 단당 손실이 나온다
+    let c = MeterType::Orifice.typical_discharge_coefficient();
+    let ratio = permanent_loss_ratio(MeterType::Orifice, orifice.beta, c);
+    let dp = orifice.stage_loss_bar / ratio;
+    let q = liquid_flow_m3_per_h(orifice.beta, c, 0.05, dp, 917.0);
+    assert!((q / orifice.flow_m3_per_h - 1.0).abs() < 0.01, "{q}");
+    // 탈기기 포화수는 오리피스 하류에서 플래시한다
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, CoolingWarning::OrificeOutletFlashing { .. })));
+
+    let mut staged = input;
+    staged.recirculation.as_mut().unwrap().stages = 3;
+    let staged = compute_pump_min_flow(&staged)
+        .unwrap()
+        .recirculation
+        .unwrap();
+    assert!(staged.beta > orifice.beta);
+
+    let mut too_high = input;
+    too_high
+        .recirculation
+        .as_mut()
+        .unwrap()
+        .return_pressure_bar_abs = 120.0;
+    assert!(compute_pump_min_flow(&too_high).is_err());
+}
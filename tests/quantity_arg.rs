@@ -0,0 +1,108 @@
+use steam_engineering_toolbox::quantity_arg::{self, FieldQuantity, QuantityArgError};
+use steam_engineering_toolbox::typed_quantity::Atmosphere;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9 * b.abs().max(1.0)
+}
+
+#[test]
+fn pressure_suffixes_select_reference() {
+    let atm = Atmosphere::STANDARD;
+    let abs = quantity_arg::parse_pressure("10bar_a", atm).unwrap();
+    assert!(close(abs.bar_abs(), 10.0));
+    for gauge in ["10barg", "10 bar(g)", "10bar_g"] {
+        let p = quantity_arg::parse_pressure(gauge, atm).unwrap();
+        assert!(close(p.bar_abs(), 11.01325), "{gauge}");
+    }
+    let vac = quantity_arg::parse_pressure("700mmHg_v", atm).unwrap();
+    assert!((vac.pa_abs() - (101_325.0 - 700.0 * 133.322)).abs() < 50.0);
+    let mpa = quantity_arg::parse_pressure("1MPa", atm).unwrap();
+    assert!(close(mpa.bar_abs(), 10.0));
+    let bare = quantity_arg::parse_pressure("5", atm).unwrap();
+    assert!(close(bare.bar_abs(), 5.0));
+    assert_eq!(
+        quantity_arg::parse_pressure("10furlong", atm),
+        Err(QuantityArgError::UnknownUnit("furlong".into()))
+    );
+}
+
+#[test]
+fn flows_temperatures_and_numbers() {
+    let m = quantity_arg::parse_mass_flow("5t/h").unwrap();
+    assert!(close(m.kg_per_h(), 5000.0));
+    let bare = quantity_arg::parse_mass_flow("1200").unwrap();
+    assert!(close(bare.kg_per_h(), 1200.0));
+    let q = quantity_arg::parse_volume_flow("12m3/h").unwrap();
+    assert!(close(q.m3_per_h(), 12.0));
+    for t in ["200C", "473.15 K", "392°F"] {
+        let c = quantity_arg::parse_temperature(t).unwrap().celsius();
+        assert!(close(c, 200.0), "{t}");
+    }
+    for dp in ["1.5bar", "150kPa", "1.5"] {
+        let bar = quantity_arg::parse_pressure_difference(dp).unwrap().bar();
+        assert!(close(bar, 1.5), "{dp}");
+    }
+    let split = quantity_arg::split_value_unit("2.5e-3 m").unwrap();
+    assert_eq!(split, (2.5e-3, "m"));
+    assert!(quantity_arg::split_value_unit("bar").is_err());
+}
+
+#[test]
+fn fuzzy_unit_match_and_suggestion() {
+    let pressure = [
+        ("bar(g)", "bar"),
+        ("bar(a)", "bara"),
+        ("kPa", "kPa"),
+        ("psi", "psi"),
+        ("mmHg", "mmHg"),
+    ];
+    assert_eq!(quantity_arg::parse_unit_fuzzy("barg", &pressure), Ok("bar"));
+    assert_eq!(
+        quantity_arg::parse_unit_fuzzy("bar a", &pressure),
+        Ok("bara")
+    );
+    assert_eq!(quantity_arg::parse_unit_fuzzy("KPA", &pressure), Ok("kPa"));
+    assert_eq!(quantity_arg::parse_unit_fuzzy("ps", &pressure), Ok("psi"));
+    assert_eq!(
+        quantity_arg::parse_unit_fuzzy("mmhgg", &pressure),
+        Err(QuantityArgError::UnitSuggestion {
+            unit: "mmhgg".into(),
+            suggestion: "mmHg".into(),
+        })
+    );
+    assert_eq!(
+        quantity_arg::parse_unit_fuzzy("furlong", &pressure),
+        Err(QuantityArgError::UnknownUnit("furlong".into()))
+    );
+    let volume = [("m³", "m3"), ("L", "l")];
+    assert_eq!(quantity_arg::parse_unit_fuzzy("M3", &volume), Ok("m3"));
+}
+
+#[test]
+fn field_quantity_converts_into_working_unit() {
+    let atm = Atmosphere::STANDARD;
+    let p = FieldQuantity::PressureBarAbs;
+    assert!(close(p.parse("10 barg", atm).unwrap(), 11.01325));
+    let psig = quantity_arg::parse_pressure("150psig", atm)
+        .unwrap()
+        .bar_abs();
+    assert!(close(p.parse("150 PSI-G", atm).unwrap(), psig));
+    assert!(close(p.parse("7", atm).unwrap(), 7.0));
+    assert_eq!(
+        p.parse("10 bsrg", atm),
+        Err(QuantityArgError::UnitSuggestion {
+            unit: "bsrg".into(),
+            suggestion: "bar(g)".into(),
+        })
+    );
+    let mm = FieldQuantity::LengthMm.parse("4 inch", atm).unwrap();
+    assert!(close(mm, 101.6));
+    assert!(close(
+        FieldQuantity::LengthM.parse("4\"", atm).unwrap(),
+        0.1016
+    ));
+    let t = FieldQuantity::TemperatureC.parse("392 °F", atm).unwrap();
+    assert!(close(t, 200.0));
+    let m = FieldQuantity::MassFlowKgPerH.parse("5 t/h", atm).unwrap();
+    assert!(close(m, 5000.0));
+}
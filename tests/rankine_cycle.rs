@@ -0,0 +1,72 @@
+use steam_engineering_toolbox::steam::cycle::{
+    compute_rankine_cycle, FeedwaterHeaterInput, FeedwaterHeaterKind, RankineCycleInput,
+    ReheatInput,
+};
+
+fn ideal(throttle_bar: f64, throttle_c: f64, condenser_bar: f64) -> RankineCycleInput {
+    RankineCycleInput {
+        throttle_pressure_bar_abs: throttle_bar,
+        throttle_temp_c: throttle_c,
+        condenser_pressure_bar_abs: condenser_bar,
+        turbine_efficiency: 1.0,
+        pump_efficiency: 1.0,
+        reheat: None,
+        feedwater_heater: None,
+        steam_flow_kg_per_h: None,
+    }
+}
+
+#[test]
+fn simple_ideal_cycle_matches_textbook() {
+    // 30 bar/350 °C → 0.75 bar 이상 사이클: η ≈ 26.0 %, 배기 건도 ≈ 0.886
+    let res = compute_rankine_cycle(ideal(30.0, 350.0, 0.75)).unwrap();
+    assert!((res.thermal_efficiency - 0.260).abs() < 0.003);
+    assert!((res.exhaust_quality - 0.886).abs() < 0.003);
+    assert!((res.heat_rate_kj_per_kwh - 3600.0 / res.thermal_efficiency).abs() < 1e-9);
+
+    let real = compute_rankine_cycle(RankineCycleInput {
+        turbine_efficiency: 0.85,
+        pump_efficiency: 0.8,
+        steam_flow_kg_per_h: Some(36_000.0),
+        ..ideal(30.0, 350.0, 0.75)
+    })
+    .unwrap();
+    assert!(real.thermal_efficiency < res.thermal_efficiency);
+    assert!((real.net_power_kw.unwrap() - 10.0 * real.net_work_kj_per_kg).abs() < 1e-6);
+}
+
+#[test]
+fn reheat_and_open_heater_raise_efficiency() {
+    let base = compute_rankine_cycle(ideal(150.0, 600.0, 0.1)).unwrap();
+
+    // 1.2 MPa 개방형 급수 가열기: y ≈ 0.227, η ≈ 46.3 %
+    let regen = compute_rankine_cycle(RankineCycleInput {
+        feedwater_heater: Some(FeedwaterHeaterInput {
+            extraction_pressure_bar_abs: 12.0,
+            kind: FeedwaterHeaterKind::Open,
+        }),
+        ..ideal(150.0, 600.0, 0.1)
+    })
+    .unwrap();
+    assert!((regen.extraction_fraction - 0.227).abs() < 0.003);
+    assert!((regen.thermal_efficiency - 0.463).abs() < 0.003);
+    assert!(regen.thermal_efficiency > base.thermal_efficiency);
+
+    let reheat = compute_rankine_cycle(RankineCycleInput {
+        reheat: Some(ReheatInput {
+            pressure_bar_abs: 40.0,
+            temp_c: 600.0,
+        }),
+        feedwater_heater: Some(FeedwaterHeaterInput {
+            extraction_pressure_bar_abs: 12.0,
+            kind: FeedwaterHeaterKind::Closed {
+                terminal_temp_difference_k: 3.0,
+            },
+        }),
+        ..ideal(150.0, 600.0, 0.1)
+    })
+    .unwrap();
+    assert!(reheat.exhaust_quality > base.exhaust_quality);
+    assert!(reheat.thermal_efficiency > base.thermal_efficiency);
+    assert!(reheat.feedwater_temp_c < 188.0 - 2.9);
+}
@@ -0,0 +1,33 @@
+use steam_engineering_toolbox::steam::reference_table::{
+    compare_all, compare_saturation, compare_superheated, ReferencePointKind,
+};
+
+#[test]
+fn if97_matches_reference_table_within_rounding() {
+    let all = compare_all();
+    assert_eq!(all.len(), 34);
+    for c in &all {
+        assert!(
+            c.max_abs_deviation_pct() < 0.15,
+            "{:?}: {}",
+            c.point,
+            c.max_abs_deviation_pct()
+        );
+    }
+}
+
+#[test]
+fn nearest_point_is_used_for_comparison() {
+    let sat = compare_saturation(184.1);
+    assert_eq!(sat.point.kind, ReferencePointKind::Saturation);
+    assert_eq!(sat.point.t_c, 180.0);
+    assert_eq!(sat.properties[0].property, "p");
+    assert!((sat.properties[0].reference - 10.028).abs() < 1e-9);
+
+    let sh = compare_superheated(9.0, 290.0);
+    assert_eq!(sh.point.kind, ReferencePointKind::Superheated);
+    assert_eq!((sh.point.p_bar_abs, sh.point.t_c), (10.0, 300.0));
+    let h = &sh.properties[1];
+    assert_eq!((h.property, h.unit), ("h", "kJ/kg"));
+    assert!((h.deviation_pct - (h.if97 - 3051.6) / 3051.6 * 100.0).abs() < 1e-12);
+}
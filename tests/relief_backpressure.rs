@@ -0,0 +1,44 @@
+use steam_engineering_toolbox::steam::relief::{
+    relief_backpressure, ReliefBackpressureInput, ReliefError, ReliefValveType,
+};
+
+fn base(diameter_m: f64) -> ReliefBackpressureInput {
+    ReliefBackpressureInput {
+        relief_flow_kg_per_h: 10_000.0,
+        set_pressure_bar_g: 10.0,
+        overpressure_percent: 10.0,
+        relieving_temp_c: None,
+        tailpipe_diameter_m: diameter_m,
+        tailpipe_length_m: 15.0,
+        fittings_k_sum: 1.5,
+        roughness_m: 4.5e-5,
+        exit_pressure_bar_abs: 1.01325,
+        valve_type: ReliefValveType::Conventional,
+    }
+}
+
+#[test]
+fn larger_tailpipe_lowers_backpressure() {
+    let big = relief_backpressure(base(0.2)).unwrap();
+    let small = relief_backpressure(base(0.1)).unwrap();
+    assert!(big.built_up_backpressure_bar_g > 0.0);
+    assert!(big.backpressure_percent_of_set < 10.0);
+    assert!(big.warnings.is_empty());
+    assert!(!big.choked_at_exit);
+
+    assert!(small.built_up_backpressure_bar_g > big.built_up_backpressure_bar_g);
+    assert!(small.choked_at_exit);
+    assert!((small.exit_mach - 1.0).abs() < 1e-6);
+    assert!(small.exit_pressure_bar_abs > 1.01325);
+    assert!(small.outlet_mach < small.exit_mach);
+    assert!(small.warnings.iter().any(|w| w.contains("10%")));
+}
+
+#[test]
+fn undersized_tailpipe_is_rejected() {
+    let res = relief_backpressure(ReliefBackpressureInput {
+        valve_type: ReliefValveType::BalancedBellows,
+        ..base(0.02)
+    });
+    assert!(matches!(res, Err(ReliefError::TailpipeTooSmall { .. })));
+}
@@ -0,0 +1,133 @@
+use steam_engineering_toolbox::steam::critical_flow::{steam_critical_flow, SteamStagnation};
+use steam_engineering_toolbox::steam::fire_case::fire_heat_input_w;
+use steam_engineering_toolbox::steam::relief_scenario::{
+    evaluate, ReliefScenarioError, ReliefStudy,
+};
+use steam_engineering_toolbox::steam::{if97, steam_valves};
+
+const ATM: f64 = 1.01325;
+
+const STUDY: &str = r#"
+[[system]]
+name = "LP header"
+set_pressure_bar_g = 5.0
+
+[[system]]
+name = "Flash vessel"
+set_pressure_bar_g = 3.5
+
+[[scenario]]
+system = "LP header"
+name = "PRV fail open"
+type = "control_valve_failure"
+kv = 400
+upstream_pressure_bar_abs = 16
+upstream_temp_c = 250
+normal_outflow_kg_per_h = 200
+
+[[scenario]]
+system = "LP header"
+name = "Header blocked"
+type = "blocked_outlet"
+source_flow_kg_per_h = 300
+
+[[scenario]]
+system = "Flash vessel"
+name = "Pool fire"
+type = "fire"
+wetted_area_m2 = 12.5
+
+[[scenario]]
+system = "Flash vessel"
+name = "Blocked vent"
+type = "blocked_outlet"
+source_flow_kg_per_h = 500
+"#;
+
+#[test]
+fn governing_load_is_picked_per_system() {
+    let study = ReliefStudy::from_toml_str(STUDY).unwrap();
+    let res = evaluate(&study, ATM).unwrap();
+    assert_eq!(res.systems.len(), 2);
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+
+    let lp = &res.systems[0];
+    let valve = &lp.loads[0];
+    assert_eq!(valve.kind, "control_valve_failure");
+    // 16 bar(a) → 6.51 bar(a)는 임계 압력비 아래라 choked
+    assert!(valve.note.contains("choked"), "{}", valve.note);
+    assert!((valve.relieving_pressure_bar_abs - 6.51325).abs() < 1e-9);
+    // 유입량은 밸브 모듈의 Kv 식(임계 차압)으로 구하고 정상 유출량을 뺀다
+    let (_, v, _) = if97::region_props(16.0, 250.0).unwrap();
+    let crit = steam_critical_flow(16.0, SteamStagnation::Temperature(250.0)).unwrap();
+    let dp = 16.0 * (1.0 - crit.critical_pressure_ratio);
+    let inflow = steam_valves::mass_flow_from_kv(400.0, dp, 1.0 / v).unwrap();
+    assert!((valve.load_kg_per_h - (inflow - 200.0)).abs() < 1e-9);
+    assert!(valve.load_kg_per_h > 300.0, "{}", valve.load_kg_per_h);
+    assert_eq!(lp.governing_load().unwrap().scenario, "PRV fail open");
+
+    // 화재는 21 % 과압에서 분출한다
+    let flash = &res.systems[1];
+    let fire = &flash.loads[0];
+    assert!((fire.relieving_pressure_bar_abs - (3.5 * 1.21 + ATM)).abs() < 1e-9);
+    assert_eq!(flash.governing, Some(0));
+}
+
+#[test]
+fn fire_load_follows_api_521_wetted_area_formula() {
+    let q = fire_heat_input_w(12.5, 1.0, true);
+    assert!((q - 43_200.0 * 12.5f64.powf(0.82)).abs() < 1e-6);
+    assert!((fire_heat_input_w(12.5, 1.0, false) / q - 70_900.0 / 43_200.0).abs() < 1e-12);
+
+    let study = ReliefStudy::from_toml_str(&format!(
+        "{STUDY}\n[[scenario]]\nsystem = \"Flash vessel\"\nname = \"Insulated fire\"\n\
+         type = \"fire\"\nwetted_area_m2 = 12.5\nenvironment_factor = 0.3\n\
+         latent_heat_kj_per_kg = 2000\n\
+         [[scenario]]\nsystem = \"Flash vessel\"\nname = \"Vessel fire\"\ntype = \"fire\"\n\
+         vessel = {{ orientation = \"horizontal\", inner_diameter_m = 2.0, length_m = 6.0, \
+         liquid_level_m = 1.0 }}\n"
+    ))
+    .unwrap();
+    let res = evaluate(&study, ATM).unwrap();
+    let insulated = &res.systems[1].loads[2];
+    let expected = 0.3 * q / 1000.0 / 2000.0 * 3600.0;
+    assert!((insulated.load_kg_per_h - expected).abs() < 1e-9);
+    // IF97 잠열(약 2100 kJ/kg)로 구한 비단열 화재 부하가 더 크다
+    assert!(res.systems[1].loads[0].load_kg_per_h > 3.0 * insulated.load_kg_per_h);
+    // 반만 찬 수평 용기의 젖음 면적 7π m²가 화재 부하가 되어 지배한다
+    let vessel = &res.systems[1].loads[3];
+    assert!(vessel.note.starts_with("A = 21.99 m²"), "{}", vessel.note);
+    assert_eq!(res.systems[1].governing, Some(3));
+}
+
+#[test]
+fn invalid_studies_and_zero_loads() {
+    let unknown = STUDY.replace(
+        "system = \"Flash vessel\"\nname = \"Blocked vent\"",
+        "system = \"HP\"\nname = \"Blocked vent\"",
+    );
+    assert!(matches!(
+        ReliefStudy::from_toml_str(&unknown),
+        Err(ReliefScenarioError::Invalid(_))
+    ));
+    let both = STUDY.replace("kv = 400", "kv = 400\ncv = 460");
+    assert!(matches!(
+        ReliefStudy::from_toml_str(&both),
+        Err(ReliefScenarioError::Invalid(_))
+    ));
+    assert!(matches!(
+        ReliefStudy::from_toml_str("[[system]]\nname = \"A\"\nset_pressure_bar_g = \"5\"\n"),
+        Err(ReliefScenarioError::Parse(_))
+    ));
+
+    // 상류 압력이 분출 압력보다 낮으면 밸브 고장 부하는 0이고 경고한다
+    let low = STUDY.replace(
+        "upstream_pressure_bar_abs = 16",
+        "upstream_pressure_bar_abs = 6",
+    );
+    let res = evaluate(&ReliefStudy::from_toml_str(&low).unwrap(), ATM).unwrap();
+    let lp = &res.systems[0];
+    assert_eq!(lp.loads[0].load_kg_per_h, 0.0);
+    assert_eq!(lp.governing_load().unwrap().scenario, "Header blocked");
+    assert_eq!(res.warnings.len(), 1);
+}
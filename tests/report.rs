@@ -0,0 +1,60 @@
+use steam_engineering_toolbox::cli_commands::{self, Command, SteamCommand};
+use steam_engineering_toolbox::i18n::Translator;
+use steam_engineering_toolbox::number_format::NumberFormat;
+use steam_engineering_toolbox::report::{CalcReport, ReportValue, REPORT_SCHEMA_VERSION};
+use steam_engineering_toolbox::typed_quantity::Atmosphere;
+use steam_engineering_toolbox::warning::Severity;
+
+fn sample() -> CalcReport {
+    CalcReport::new("pipe size")
+        .input(ReportValue::new("mass_flow", 5000.0, "kg/h"))
+        .intermediate(ReportValue::new("density", 5.16, "kg/m3").labeled("ρ", 2))
+        .result(ReportValue::new("inner_diameter", 117.3, "mm").labeled("Recommended ID:", 1))
+        .result(ReportValue::new("reynolds", 2.5e6, ""))
+        .warning(Severity::Caution, "velocity above 30 m/s")
+}
+
+#[test]
+fn text_lists_intermediates_results_and_warnings() {
+    assert_eq!(
+        sample().to_text(&NumberFormat::default()),
+        "ρ 5.16 kg/m3\nRecommended ID: 117.3 mm\nreynolds 2.500e6\n⚠ velocity above 30 m/s"
+    );
+}
+
+#[test]
+fn serialized_schema_omits_labels_and_round_trips() {
+    let report = sample();
+    let doc = toml::to_string(&report).unwrap();
+    assert!(doc.contains(&format!("schema_version = {REPORT_SCHEMA_VERSION}")));
+    assert!(doc.contains("name = \"inner_diameter\""));
+    assert!(doc.contains("severity = \"caution\""));
+    assert!(!doc.contains("Recommended ID"));
+    assert!(!doc.contains("tables"));
+
+    let back: CalcReport = toml::from_str(&doc).unwrap();
+    assert_eq!(back.results.len(), 2);
+    assert_eq!(back.results[0].value, 117.3);
+    assert_eq!(back.warnings, report.warnings);
+}
+
+#[test]
+fn steam_sat_report_gives_enthalpy_and_entropy_in_kj() {
+    let command = Command::Steam(SteamCommand::Sat {
+        p: Some("10bar_a".into()),
+        t: None,
+        compare: false,
+    });
+    let report =
+        cli_commands::report(&command, Atmosphere::STANDARD, &Translator::new("en-us")).unwrap();
+    let value = |name: &str| {
+        let v = report.results.iter().find(|v| v.name == name).unwrap();
+        (v.value, v.unit.as_str())
+    };
+    // 10 bar(a) 포화: h_g 2777.1 kJ/kg, h_f 762.7 kJ/kg, s_g 6.585 kJ/kgK
+    let (h_g, unit) = value("sat_vapor_enthalpy");
+    assert_eq!(unit, "kJ/kg");
+    assert!((h_g - 2777.0).abs() < 2.0, "{h_g}");
+    assert!((value("sat_liquid_enthalpy").0 - 762.7).abs() < 2.0);
+    assert!((value("sat_vapor_entropy").0 - 6.585).abs() < 0.01);
+}
@@ -0,0 +1,28 @@
+use steam_engineering_toolbox::result_text::result_to_tsv;
+
+#[test]
+fn result_items_become_name_value_unit_rows() {
+    let text = "Tsat=179.88 °C, Psat=10.0000 bar(a), LMTD=8.20 K, Q≈1234.5 kW\n\
+                Compressible: Q≈0.512 m³/h, m≈2.10 kg/h (Cd=0.61, dp=0.250 bar)";
+    assert_eq!(
+        result_to_tsv(text),
+        "Tsat\t179.88\t°C\n\
+         Psat\t10.0000\tbar(a)\n\
+         LMTD\t8.20\tK\n\
+         Q\t1234.5\tkW\n\
+         Compressible: Q\t0.512\tm³/h\n\
+         m\t2.10\tkg/h\n\
+         Cd\t0.61\t\n\
+         dp\t0.250\tbar"
+    );
+}
+
+#[test]
+fn locale_numbers_and_warnings_stay_intact() {
+    let text = "Q=0,50 kW, m=1.234,5 kg/h, x = 1 234.5 kg\n⚠ NPSH margin low: 0,8 m";
+    assert_eq!(
+        result_to_tsv(text),
+        "Q\t0,50\tkW\nm\t1.234,5\tkg/h\nx\t1 234.5\tkg\n⚠ NPSH margin low: 0,8 m"
+    );
+    assert_eq!(result_to_tsv("Model: Barlow"), "Model\tBarlow");
+}
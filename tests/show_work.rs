@@ -0,0 +1,104 @@
+use steam_engineering_toolbox::conversion::PressureMode;
+use steam_engineering_toolbox::cooling::condenser::{compute_condenser, CondenserInput};
+use steam_engineering_toolbox::number_format::NumberFormat;
+use steam_engineering_toolbox::report::CalcReport;
+use steam_engineering_toolbox::show_work::{
+    self, condenser_work, pressure_loss_work, valve_kv_work,
+};
+use steam_engineering_toolbox::steam::steam_piping::{pressure_loss, PressureLossInput};
+use steam_engineering_toolbox::steam::steam_valves::required_kv;
+use steam_engineering_toolbox::units::PressureUnit;
+
+#[test]
+fn pressure_loss_work_repeats_result_values() {
+    let input = PressureLossInput {
+        mass_flow_kg_per_h: 5000.0,
+        steam_density_kg_per_m3: 5.0,
+        diameter_m: 0.1,
+        length_m: 100.0,
+        fittings_k_sum: 2.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 4.5e-5,
+        dynamic_viscosity_pa_s: 1.5e-5,
+        sound_speed_m_per_s: 500.0,
+        state_pressure_bar_abs: None,
+        state_temperature_c: None,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    };
+    let result = pressure_loss(input.clone()).unwrap();
+    let steps = pressure_loss_work(&input, &result, &NumberFormat::default());
+    let names: Vec<&str> = steps.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(
+        names,
+        [
+            "area",
+            "velocity",
+            "reynolds",
+            "friction_factor",
+            "fittings_equivalent_length",
+            "total_length",
+            "pressure_drop",
+            "mach"
+        ]
+    );
+    assert_eq!(steps[2].value, result.reynolds_number);
+    assert_eq!(steps[3].value, result.friction_factor);
+    assert_eq!(steps[6].value, result.pressure_drop_bar);
+    assert!(
+        steps[2].substituted.ends_with("/ 1.500e-5"),
+        "{}",
+        steps[2].substituted
+    );
+    assert!(steps[3].formula.contains("6.9/Re"));
+}
+
+#[test]
+fn valve_work_substitutes_inputs_with_number_format() {
+    let kv = required_kv(10.0, 2.0, 5.0).unwrap();
+    let steps = valve_kv_work(10.0, 2.0, 5.0, kv, &NumberFormat::default());
+    assert_eq!(
+        steps[0].line(&NumberFormat::default()),
+        "Kv = Q·√(ρref/(ρ·ΔP)) = 10.00 × √(1000 / (5.000 × 2.000)) = 100.0"
+    );
+
+    let comma = NumberFormat {
+        decimal_separator: ',',
+        ..NumberFormat::default()
+    };
+    let steps = valve_kv_work(10.0, 2.0, 5.0, kv, &comma);
+    assert!(steps[0].substituted.starts_with("10,00 × "));
+}
+
+#[test]
+fn condenser_work_shows_lmtd_and_goes_into_reports() {
+    let input = CondenserInput {
+        steam_pressure: 0.1,
+        steam_pressure_unit: PressureUnit::Bar,
+        steam_pressure_mode: PressureMode::Absolute,
+        steam_temp_c: Some(40.0),
+        cw_inlet_temp_c: 25.0,
+        cw_outlet_temp_c: 35.0,
+        cw_flow_m3_per_h: 360.0,
+        ua_kw_per_k: None,
+        area_m2: None,
+        overall_u_w_m2k: None,
+        target_back_pressure_bar_abs: None,
+    };
+    let result = compute_condenser(input.clone()).unwrap();
+    let nf = NumberFormat::default();
+    let steps = condenser_work(&input, &result, &nf);
+    assert_eq!(steps[2].name, "lmtd");
+    assert_eq!(
+        steps[2].line(&nf),
+        "LMTD = (ΔT₁ − ΔT₂)/ln(ΔT₁/ΔT₂) = (5.000 − 15.00) / ln(5.000 / 15.00) = 9.102 K"
+    );
+    assert_eq!(steps[3].formula, "Q = ṁcw·cp·(Tcw,out − Tcw,in)");
+
+    let report = CalcReport::new("condenser").work(steps.clone());
+    assert_eq!(report.to_text(&nf), show_work::to_text(&steps, &nf));
+    assert!(toml::to_string(&report).unwrap().contains("[[work]]"));
+    assert!(!toml::to_string(&CalcReport::new("condenser"))
+        .unwrap()
+        .contains("work"));
+}
@@ -0,0 +1,55 @@
+use steam_engineering_toolbox::steam::critical_flow::SteamStagnation;
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::sparger::{
+    compute_sparger, SpargerError, SpargerInput, MIN_SUBCOOLING_K,
+};
+
+fn heater(target_temp_c: f64, submergence_m: f64) -> SpargerInput {
+    SpargerInput {
+        water_flow_kg_per_h: 10_000.0,
+        water_inlet_temp_c: 20.0,
+        target_temp_c,
+        liquid_pressure_bar_abs: 1.01325,
+        submergence_m,
+        steam_pressure_bar_abs: 5.0,
+        steam_state: SteamStagnation::Quality(1.0),
+        hole_diameter_m: 0.006,
+        hole_count: 30,
+        discharge_coefficient: 0.7,
+    }
+}
+
+#[test]
+fn steam_flow_closes_energy_balance() {
+    let res = compute_sparger(heater(80.0, 1.0)).unwrap();
+    let (h_in, _, _) = if97::region_props(1.01325, 20.0).unwrap();
+    let (h_out, _, _) = if97::region_props(res.local_pressure_bar_abs, 80.0).unwrap();
+    let (_, hg) = if97::saturation_enthalpies(5.0).unwrap();
+    let gained = 10_000.0 * (h_out - h_in);
+    let given = res.steam_flow_kg_per_h * (hg - h_out);
+    assert!((gained - given).abs() / gained < 1e-9);
+    assert!(res.steam_flow_kg_per_h > 1_000.0 && res.steam_flow_kg_per_h < 1_100.0);
+    assert!((res.outlet_flow_kg_per_h - 10_000.0 - res.steam_flow_kg_per_h).abs() < 1e-9);
+    assert!(res.choked);
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+}
+
+#[test]
+fn low_subcooling_warns_with_min_submergence() {
+    let shallow = compute_sparger(heater(95.0, 0.0)).unwrap();
+    assert!(shallow.subcooling_k < MIN_SUBCOOLING_K);
+    assert!(shallow.min_submergence_m > 0.0);
+    assert_eq!(shallow.warnings.len(), 1);
+
+    let deep = compute_sparger(heater(95.0, shallow.min_submergence_m)).unwrap();
+    assert!(
+        (deep.subcooling_k - MIN_SUBCOOLING_K).abs() < 0.1,
+        "{}",
+        deep.subcooling_k
+    );
+
+    assert!(matches!(
+        compute_sparger(heater(101.0, 0.0)),
+        Err(SpargerError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,49 @@
+use steam_engineering_toolbox::steam::accumulator::{
+    release_capacity_kg, required_volume_m3, simulate, AccumulatorSimInput, FlowStep,
+};
+
+#[test]
+fn release_capacity_matches_flash_estimate() {
+    // 100 m³, 수위 90%, 16 → 6 bar(a). 경험식: 물 1 m³당 약 100 kg 수준
+    let released = release_capacity_kg(100.0, 0.9, 16.0, 6.0).unwrap();
+    assert!(released > 6_000.0 && released < 12_000.0, "{released}");
+    let v = required_volume_m3(released, 0.9, 16.0, 6.0).unwrap();
+    assert!((v - 100.0).abs() < 1e-6);
+}
+
+#[test]
+fn discharge_then_charge_round_trip() {
+    let res = simulate(AccumulatorSimInput {
+        volume_m3: 50.0,
+        initial_pressure_bar_abs: 12.0,
+        initial_level_fraction: 0.8,
+        charge_enthalpy_kj_per_kg: None,
+        steps: vec![
+            FlowStep {
+                duration_s: 600.0,
+                charge_kg_per_h: 0.0,
+                discharge_kg_per_h: 10_000.0,
+            },
+            FlowStep {
+                duration_s: 600.0,
+                charge_kg_per_h: 10_000.0,
+                discharge_kg_per_h: 0.0,
+            },
+        ],
+        time_step_s: 5.0,
+        min_pressure_bar_abs: Some(11.0),
+        max_pressure_bar_abs: None,
+    })
+    .unwrap();
+    let mid = res.points[120].state;
+    let last = res.points.last().unwrap().state;
+    assert!(mid.pressure_bar_abs < 12.0);
+    assert!((res.min_pressure_bar_abs - mid.pressure_bar_abs).abs() < 1e-9);
+    // 같은 양을 건포화 증기로 되돌리면 거의 원래 압력으로 돌아온다.
+    assert!(
+        (last.pressure_bar_abs - 12.0).abs() < 0.1,
+        "{}",
+        last.pressure_bar_abs
+    );
+    assert!(res.warnings.iter().any(|w| w.contains("11.00")));
+}
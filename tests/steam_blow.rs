@@ -0,0 +1,95 @@
+use steam_engineering_toolbox::steam::steam_blow::{
+    steam_blow, SteamBlowError, SteamBlowInput, SteamBlowWarning,
+};
+use steam_engineering_toolbox::steam::steam_piping::fittings::{FittingList, FittingMethod};
+use steam_engineering_toolbox::steam::steam_piping::pipe_run::PipeSegment;
+
+fn segment(name: &str, diameter_m: f64, length_m: f64) -> PipeSegment {
+    PipeSegment {
+        name: name.to_string(),
+        diameter_m,
+        length_m,
+        elevation_change_m: 0.0,
+        roughness_m: 0.045e-3,
+        fittings: FittingList::new(),
+        extra_k: 0.0,
+    }
+}
+
+/// DN250 주증기관(설계 200 t/h, 100 bar(a), 540 °C)을 40 bar(a), 420 °C로 블로한다.
+fn main_steam_blow(capacity_kg_per_h: f64, temporary_id_m: f64) -> SteamBlowInput {
+    SteamBlowInput {
+        design_flow_kg_per_h: 200_000.0,
+        design_pressure_bar_abs: 100.0,
+        design_temperature_c: 540.0,
+        design_diameter_m: 0.22,
+        boiler_pressure_bar_abs: 40.0,
+        boiler_temperature_c: 420.0,
+        blow_valve_capacity_kg_per_h: capacity_kg_per_h,
+        atmospheric_pressure_bar_abs: 1.01325,
+        target_cfr: 1.2,
+        fitting_method: FittingMethod::TwoK,
+        dynamic_viscosity_pa_s: 2.0e-5,
+        segments: vec![
+            segment("main steam", 0.22, 150.0),
+            segment("temporary", temporary_id_m, 60.0),
+        ],
+    }
+}
+
+#[test]
+fn valve_limited_blow_meets_target_and_recommends_minimum_flow() {
+    let res = steam_blow(&main_steam_blow(80_000.0, 0.30)).unwrap();
+    assert!(res.valve_limited);
+    assert_eq!(res.blow_flow_kg_per_h, 80_000.0);
+    assert!(res.route_inlet_pressure_bar_abs < 40.0);
+    assert!(res.cfr_ok(1.2), "min CFR {}", res.min_cfr);
+    // 큰 가설 배관은 압력이 낮아도 단면이 커서 CFR이 가장 낮다
+    assert_eq!(res.worst_station, 1);
+    let flow = res.recommended_flow_kg_per_h.unwrap();
+    assert!(flow < 80_000.0 && flow > 70_000.0, "{flow}");
+    let p = res.recommended_inlet_pressure_bar_abs.unwrap();
+    assert!(p < res.route_inlet_pressure_bar_abs);
+}
+
+#[test]
+fn cfr_grows_with_flow_and_choked_exit_is_reported() {
+    let low = steam_blow(&main_steam_blow(60_000.0, 0.30)).unwrap();
+    let high = steam_blow(&main_steam_blow(120_000.0, 0.30)).unwrap();
+    assert!(!low.cfr_ok(1.2));
+    assert!(low.warnings.iter().any(|w| matches!(
+        w,
+        SteamBlowWarning::CfrBelowTarget { station, .. } if station.contains("temporary")
+    )));
+    assert!(high.min_cfr > low.min_cfr);
+    assert!(high.exit_choked);
+    assert!(high.exit_pressure_bar_abs > 1.01325);
+    let exit = high.stations.last().unwrap();
+    assert!((exit.mach - 1.0).abs() < 0.01, "{}", exit.mach);
+    // 권장 유량은 밸브 용량과 무관하게 같은 경로면 같다
+    assert_eq!(
+        low.recommended_flow_kg_per_h,
+        high.recommended_flow_kg_per_h
+    );
+}
+
+#[test]
+fn boiler_pressure_limits_large_valve_and_invalid_input_is_rejected() {
+    let res = steam_blow(&main_steam_blow(500_000.0, 0.25)).unwrap();
+    assert!(!res.valve_limited);
+    assert!(res.blow_flow_kg_per_h < 500_000.0);
+    assert_eq!(res.route_inlet_pressure_bar_abs, 40.0);
+
+    let mut empty = main_steam_blow(80_000.0, 0.30);
+    empty.segments.clear();
+    assert!(matches!(
+        steam_blow(&empty),
+        Err(SteamBlowError::InvalidInput(_))
+    ));
+    let mut vacuum = main_steam_blow(80_000.0, 0.30);
+    vacuum.boiler_pressure_bar_abs = 1.0;
+    assert!(matches!(
+        steam_blow(&vacuum),
+        Err(SteamBlowError::InvalidInput(_))
+    ));
+}
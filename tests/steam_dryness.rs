@@ -0,0 +1,53 @@
+use steam_engineering_toolbox::steam::steam_dryness::{
+    separating_calorimeter, separating_throttling_calorimeter, throttling_calorimeter,
+    CalorimeterError, SeparatingCalorimeterInput, ThrottlingCalorimeterInput,
+};
+
+#[test]
+fn throttling_calorimeter_matches_hand_calculation() {
+    // 10 bar(a) 주관 → 1 atm, 120 °C: h₂ ≈ 2716.6, h_f ≈ 762.7, h_fg ≈ 2014.6 kJ/kg → x ≈ 0.970
+    let input = ThrottlingCalorimeterInput {
+        main_pressure_bar_abs: 10.0,
+        calorimeter_pressure_bar_abs: 1.01325,
+        calorimeter_temp_c: 120.0,
+    };
+    let res = throttling_calorimeter(input.clone()).unwrap();
+    assert!((res.dryness - 0.970).abs() < 0.003);
+    assert!((res.superheat_after_throttling_k.unwrap() - 20.0).abs() < 0.1);
+    // 1 atm 교축으로는 약 95 % 이상 건도만 측정 가능
+    let x_min = res.min_measurable_dryness.unwrap();
+    assert!(x_min > 0.94 && x_min < 0.96);
+    assert!(res.warnings.is_empty());
+
+    // 출구가 포화 온도 이하면 측정 불가
+    let wet = throttling_calorimeter(ThrottlingCalorimeterInput {
+        calorimeter_temp_c: 99.0,
+        ..input
+    });
+    assert!(matches!(wet, Err(CalorimeterError::NotSuperheated(_))));
+}
+
+#[test]
+fn separating_and_combined_calorimeters() {
+    let sep = SeparatingCalorimeterInput {
+        separated_water_kg: 0.5,
+        condensed_steam_kg: 9.5,
+    };
+    let x_sep = separating_calorimeter(sep.clone()).unwrap().dryness;
+    assert!((x_sep - 0.95).abs() < 1e-12);
+
+    let thr = ThrottlingCalorimeterInput {
+        main_pressure_bar_abs: 10.0,
+        calorimeter_pressure_bar_abs: 1.01325,
+        calorimeter_temp_c: 120.0,
+    };
+    let x_thr = throttling_calorimeter(thr.clone()).unwrap().dryness;
+    let combined = separating_throttling_calorimeter(sep, thr).unwrap();
+    assert!((combined.dryness - x_sep * x_thr).abs() < 1e-12);
+
+    assert!(separating_calorimeter(SeparatingCalorimeterInput {
+        separated_water_kg: 1.0,
+        condensed_steam_kg: 0.0,
+    })
+    .is_err());
+}
@@ -0,0 +1,58 @@
+use steam_engineering_toolbox::steam::steam_cost::EnergyUnitCostInput;
+use steam_engineering_toolbox::steam::steam_leak::{
+    analyze_leaks, parse_trap_survey_csv, steam_leak_flow_kg_per_h, LeakEconomics, LeakError,
+    LeakFormula, LeakKind,
+};
+
+#[test]
+fn napier_and_grashof_agree_and_low_pressure_uses_nozzle_flow() {
+    // 7 bar(g), 3 mm 구멍: Napier ≈ 29.7 kg/h
+    let (napier, sub_n) =
+        steam_leak_flow_kg_per_h(8.01325, 0.003, 1.0, LeakFormula::Napier).unwrap();
+    let (grashof, _) = steam_leak_flow_kg_per_h(8.01325, 0.003, 1.0, LeakFormula::Grashof).unwrap();
+    assert!(!sub_n);
+    assert!((napier - 29.7).abs() < 0.3, "{napier}");
+    assert!(
+        (grashof / napier - 1.0).abs() < 0.03,
+        "{grashof} vs {napier}"
+    );
+
+    let (low, sub_l) = steam_leak_flow_kg_per_h(1.3, 0.003, 1.0, LeakFormula::Napier).unwrap();
+    assert!(sub_l && low > 0.0 && low < napier);
+}
+
+#[test]
+fn survey_csv_is_ranked_by_cost_with_totals() {
+    let csv = "tag,kind,pressure_bar_g,orifice_mm,hours_per_year,cd\n\
+               # 1차 조사\n\
+               T-101,trap,10,4,8000\n\
+               L-7,leak,7,2,8760,0.7\n\
+               T-205,trap,3,6,4000,\n";
+    let items = parse_trap_survey_csv(csv).unwrap();
+    assert_eq!(items.len(), 3);
+    assert_eq!(items[0].kind, LeakKind::FailedTrap);
+    assert!((items[0].discharge_coefficient - 0.4).abs() < 1e-12);
+
+    let economics = LeakEconomics {
+        energy: EnergyUnitCostInput {
+            fuel_price_per_unit: 1000.0,
+            fuel_lhv_kj_per_unit: 39000.0,
+            boiler_efficiency: 0.85,
+        },
+        loss_factor: 0.0,
+        makeup_water_temp_c: 20.0,
+    };
+    let survey = analyze_leaks(&items, &economics, LeakFormula::Napier).unwrap();
+    assert!(survey
+        .items
+        .windows(2)
+        .all(|w| w[0].annual_cost >= w[1].annual_cost));
+    let sum: f64 = survey.items.iter().map(|r| r.annual_cost).sum();
+    assert!((survey.total_annual_cost - sum).abs() < 1e-6);
+    assert!(survey.steam_cost_per_t > 60_000.0 && survey.steam_cost_per_t < 90_000.0);
+
+    assert!(matches!(
+        parse_trap_survey_csv("T-1,valve,5,3,8000"),
+        Err(LeakError::Csv { line: 1, .. })
+    ));
+}
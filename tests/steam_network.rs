@@ -0,0 +1,75 @@
+use steam_engineering_toolbox::steam::network::{
+    Element, Header, LetdownFlow, SteamNetwork, TurbineExhaust,
+};
+
+fn plant() -> SteamNetwork {
+    let mut net = SteamNetwork::new();
+    let hp = net.add_header(Header::new("HP", 41.0, Some(400.0)));
+    let lp = net.add_header(Header::new("LP", 5.0, Some(180.0)));
+    net.add(Element::Boiler {
+        name: "B-1".into(),
+        header: hp,
+        flow_kg_h: 50_000.0,
+        outlet_temp_c: None,
+    });
+    net.add(Element::Turbine {
+        name: "TG-1".into(),
+        from: hp,
+        exhaust: TurbineExhaust::Header(lp),
+        flow_kg_h: 30_000.0,
+        isentropic_efficiency: 0.75,
+    });
+    net.add(Element::Letdown {
+        name: "PRDS HP/LP".into(),
+        from: hp,
+        to: lp,
+        flow: LetdownFlow::Balance,
+        spray_water_temp_c: 105.0,
+        outlet_temp_c: None,
+    });
+    net.add(Element::User {
+        name: "Process".into(),
+        header: lp,
+        flow_kg_h: 40_000.0,
+    });
+    net
+}
+
+#[test]
+fn letdown_balances_lp_header() {
+    let res = plant().solve().unwrap();
+    let lp = &res.headers[1];
+    assert!(lp.imbalance_kg_h.abs() < 1e-6);
+    // 감압 출구는 LP 헤더 조건과 같으므로 에너지 불균형은 터빈 배기 과열/부족분만 남는다.
+    let tg = res.elements.iter().find(|e| e.kind == "turbine").unwrap();
+    let expected_kw =
+        tg.outlet_flow_kg_h * (tg.outlet_enthalpy_kj_per_kg - lp.enthalpy_kj_per_kg) / 3600.0;
+    assert!((lp.energy_imbalance_kw - expected_kw).abs() < 1e-6);
+
+    let prds = res.elements.iter().find(|e| e.kind == "letdown").unwrap();
+    assert!(prds.spray_water_kg_h > 0.0);
+    assert!(prds.outlet_flow_kg_h > prds.steam_flow_kg_h);
+    assert!(res.total_turbine_power_kw > 1000.0);
+
+    // HP 잉여 = 보일러 - 터빈 - 감압 상류 증기
+    let hp = &res.headers[0];
+    let expected = 50_000.0 - 30_000.0 - prds.steam_flow_kg_h;
+    assert!((hp.imbalance_kg_h - expected).abs() < 1e-6);
+    assert!(res.warnings.iter().any(|w| w.contains("HP")));
+}
+
+#[test]
+fn letdown_must_reduce_pressure() {
+    let mut net = SteamNetwork::new();
+    let a = net.add_header(Header::new("A", 5.0, None));
+    let b = net.add_header(Header::new("B", 10.0, None));
+    net.add(Element::Letdown {
+        name: "bad".into(),
+        from: a,
+        to: b,
+        flow: LetdownFlow::Fixed(100.0),
+        spray_water_temp_c: 100.0,
+        outlet_temp_c: None,
+    });
+    assert!(net.solve().is_err());
+}
@@ -0,0 +1,35 @@
+use steam_engineering_toolbox::steam::steam_purity::{
+    asme_boiler_water_limits, boiler_water_concentration, estimate_steam_purity, SteamPurityInput,
+};
+
+#[test]
+fn silica_vaporous_carryover_grows_with_pressure() {
+    // 급수 TDS 5 mg/kg, 블로다운 2 % → 보일러수 255 mg/kg
+    let tds = boiler_water_concentration(5.0, 0.02);
+    assert!((tds - 255.0).abs() < 1e-9);
+
+    let input = SteamPurityInput {
+        drum_pressure_bar_abs: 42.0,
+        boiler_water_tds_mg_per_kg: tds,
+        boiler_water_silica_mg_per_kg: 5.0,
+        mechanical_carryover_percent: 0.05,
+        steam_flow_kg_per_h: Some(50_000.0),
+    };
+    let low = estimate_steam_purity(input.clone()).unwrap();
+    // 약 41 bar(g)에서 실리카 휘발 분배비는 0.01~0.03 % 수준
+    assert!(low.silica_distribution_ratio > 1e-4 && low.silica_distribution_ratio < 3e-4);
+    assert!(
+        (low.steam_tds_mg_per_kg - 255.0 * (0.0005 + low.salt_distribution_ratio)).abs() < 1e-9
+    );
+    assert_eq!(asme_boiler_water_limits(42.0), Some((2500.0, 40.0)));
+
+    let high = estimate_steam_purity(SteamPurityInput {
+        drum_pressure_bar_abs: 140.0,
+        ..input
+    })
+    .unwrap();
+    assert!(high.silica_distribution_ratio > 20.0 * low.silica_distribution_ratio);
+    assert!(high.steam_silica_mg_per_kg > 0.02);
+    assert!(high.max_boiler_water_silica_mg_per_kg < 5.0);
+    assert!(!high.warnings.is_empty());
+}
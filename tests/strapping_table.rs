@@ -0,0 +1,91 @@
+use steam_engineering_toolbox::strapping_table::{
+    strapping_table, strapping_table_csv, volume_correction_factor, LiquidExpansion,
+    StrappingError, StrappingInput,
+};
+use steam_engineering_toolbox::vessel_geometry::{HeadType, Vessel, VesselOrientation};
+
+fn condensate_tank(increment_m: f64, liquid_temp_c: f64) -> StrappingInput {
+    StrappingInput {
+        vessel: Vessel {
+            orientation: VesselOrientation::Horizontal,
+            inner_diameter_m: 2.0,
+            length_m: 6.0,
+            heads: HeadType::Ellipsoidal,
+        },
+        increment_m,
+        liquid_temp_c,
+        reference_temp_c: 15.0,
+        expansion: LiquidExpansion::Water {
+            pressure_bar_abs: 1.01325,
+        },
+    }
+}
+
+#[test]
+fn rows_cover_bottom_to_top_with_last_row_at_full_height() {
+    let table = strapping_table(&condensate_tank(0.3, 15.0)).unwrap();
+    // 2.0 m를 0.3 m 간격으로: 0, 0.3 … 1.8, 2.0
+    assert_eq!(table.rows.len(), 8);
+    assert_eq!(table.rows[0].volume_m3, 0.0);
+    let last = table.rows.last().unwrap();
+    assert_eq!(last.level_m, 2.0);
+    assert!((last.volume_m3 - table.total_volume_m3).abs() < 1e-12);
+    assert!((last.percent_full - 100.0).abs() < 1e-9);
+    // 기준 온도에서 측정하면 보정 용적이 같다
+    assert!((table.volume_correction_factor - 1.0).abs() < 1e-12);
+
+    let exact = strapping_table(&condensate_tank(0.5, 15.0)).unwrap();
+    assert_eq!(exact.rows.len(), 5);
+    assert!((exact.rows[2].percent_full - 50.0).abs() < 1e-9);
+}
+
+#[test]
+fn hot_liquid_is_corrected_to_reference_volume() {
+    let table = strapping_table(&condensate_tank(0.5, 90.0)).unwrap();
+    // 물 90 °C → 15 °C 밀도비 약 0.966
+    let vcf = table.volume_correction_factor;
+    assert!((vcf - 965.3 / 999.1).abs() < 1e-3, "{vcf}");
+    let mid = &table.rows[2];
+    assert!((mid.corrected_volume_m3 - mid.volume_m3 * vcf).abs() < 1e-12);
+
+    let oil = volume_correction_factor(
+        LiquidExpansion::Coefficient { beta_per_k: 0.0007 },
+        65.0,
+        15.0,
+    )
+    .unwrap();
+    assert!((oil - 1.0 / 1.035).abs() < 1e-12);
+
+    let csv = strapping_table_csv(&table);
+    let mut lines = csv.lines();
+    assert_eq!(
+        lines.next().unwrap(),
+        "level_mm,volume_m3_at_90.0c,volume_m3_at_15.0c,percent_full"
+    );
+    assert!(lines.nth(2).unwrap().starts_with("1000.0,"));
+    assert_eq!(csv.lines().count(), table.rows.len() + 1);
+}
+
+#[test]
+fn invalid_inputs_are_rejected() {
+    assert!(matches!(
+        strapping_table(&condensate_tank(0.0, 15.0)),
+        Err(StrappingError::InvalidInput(_))
+    ));
+    assert!(matches!(
+        strapping_table(&condensate_tank(1e-6, 15.0)),
+        Err(StrappingError::InvalidInput(_))
+    ));
+    // 대기압에서 120 °C 물은 액체가 아니다
+    assert!(matches!(
+        strapping_table(&condensate_tank(0.5, 120.0)),
+        Err(StrappingError::InvalidInput(_))
+    ));
+    let mut flat = condensate_tank(0.5, 15.0);
+    flat.vessel.heads = HeadType::Flat;
+    flat.vessel.length_m = 0.0;
+    assert!(matches!(
+        strapping_table(&flat),
+        Err(StrappingError::Geometry(_))
+    ));
+}
@@ -0,0 +1,54 @@
+use steam_engineering_toolbox::conversion::PressureMode;
+use steam_engineering_toolbox::steam::steam_tables::{
+    saturation_by_pressure_mode, superheated_at_mode,
+};
+use steam_engineering_toolbox::stream_state::{StreamField, StreamState, StreamTarget};
+use steam_engineering_toolbox::units::{PressureUnit, TemperatureUnit};
+
+#[test]
+fn saturated_steam_table_result_feeds_valve_sizing() {
+    let sat =
+        saturation_by_pressure_mode(10.0, PressureUnit::BarA, PressureMode::Absolute).unwrap();
+    let state = StreamState::from_steam_state("gui.steam.heading", &sat);
+    assert_eq!(state.quality, Some(1.0));
+    // 10 bar(a) 포화 증기: Tsat ≈ 179.9 °C, ρ ≈ 5.15 kg/m³
+    assert!((state.temp_c.unwrap() - 179.9).abs() < 0.3);
+    assert!((state.density_kg_per_m3.unwrap() - 5.15).abs() < 0.05);
+
+    // 유량이 없으면 밸브 카드로는 압력·밀도만 간다.
+    let fields: Vec<_> = state
+        .transfer(StreamTarget::ValveSizing)
+        .iter()
+        .map(|(f, _)| *f)
+        .collect();
+    assert_eq!(fields, vec![StreamField::Pressure, StreamField::Density]);
+
+    let state = state.with_mass_flow(1000.0);
+    let transfer = state.transfer(StreamTarget::ValveSizing);
+    assert_eq!(transfer[0].0, StreamField::VolumeFlow);
+    assert!((transfer[0].1 - 1000.0 / state.density_kg_per_m3.unwrap()).abs() < 1e-9);
+    assert_eq!(state.transfer(StreamTarget::PipeSizing).len(), 3);
+    assert!(state.to_string().contains("ṁ=1000.0 kg/h"));
+}
+
+#[test]
+fn superheated_state_gets_density_from_if97() {
+    let sup = superheated_at_mode(
+        10.0,
+        PressureUnit::BarA,
+        PressureMode::Absolute,
+        250.0,
+        TemperatureUnit::Celsius,
+    )
+    .unwrap();
+    let state = StreamState::from_steam_state("gui.steam.heading", &sup);
+    assert_eq!(state.quality, None);
+    // 10 bar(a), 250 °C: v ≈ 0.2328 m³/kg
+    assert!((state.density_kg_per_m3.unwrap() - 1.0 / 0.2328).abs() < 0.02);
+    // 증기표 카드의 엔탈피는 그대로 둔다.
+    assert_eq!(state.enthalpy_kj_per_kg, sup.superheated_enthalpy_kj_per_kg);
+
+    let empty = StreamState::new("gui.pipe.heading");
+    assert!(empty.transfer(StreamTarget::LineCase).is_empty());
+    assert_eq!(empty.clone().complete_from_if97(), empty);
+}
@@ -0,0 +1,53 @@
+use steam_engineering_toolbox::steam::steam_piping::PressureLossInput;
+use steam_engineering_toolbox::sweep::{self, SweepRange};
+
+fn base_loss_input() -> PressureLossInput {
+    PressureLossInput {
+        mass_flow_kg_per_h: 5000.0,
+        steam_density_kg_per_m3: 5.0,
+        diameter_m: 0.1,
+        length_m: 50.0,
+        fittings_k_sum: 2.0,
+        equivalent_length_m: 0.0,
+        roughness_m: 4.5e-5,
+        dynamic_viscosity_pa_s: 1.5e-5,
+        sound_speed_m_per_s: 450.0,
+        state_pressure_bar_abs: None,
+        state_temperature_c: None,
+        inlet_elevation_m: 0.0,
+        outlet_elevation_m: 0.0,
+    }
+}
+
+#[test]
+fn range_includes_end_and_rejects_bad_step() {
+    let pts = SweepRange::new(0.0, 1.0, 0.25).points().unwrap();
+    assert_eq!(pts, vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+    let down = SweepRange::new(1.0, 0.0, 0.3).points().unwrap();
+    assert_eq!(down.first(), Some(&1.0));
+    assert_eq!(down.last(), Some(&0.0));
+    assert!(SweepRange::new(0.0, 1.0, 0.0).points().is_err());
+    assert!(SweepRange::new(0.0, 1.0e6, 1.0).points().is_err());
+}
+
+#[test]
+fn pressure_drop_falls_with_diameter() {
+    let table =
+        sweep::pressure_loss_vs_diameter(&base_loss_input(), SweepRange::new(0.05, 0.2, 0.05))
+            .unwrap();
+    assert_eq!(table.rows.len(), 4);
+    assert_eq!(table.failed_count(), 0);
+    let dp = table.series(0);
+    assert!(dp.windows(2).all(|w| w[1][1] < w[0][1]));
+}
+
+#[test]
+fn failed_points_are_kept_as_rows() {
+    // ΔP=0 지점은 계산 오류지만 나머지 점은 계속 계산되어야 한다.
+    let table = sweep::required_cv_vs_dp(10.0, 1000.0, SweepRange::new(0.0, 2.0, 1.0)).unwrap();
+    assert_eq!(table.rows.len(), 3);
+    assert_eq!(table.failed_count(), 1);
+    assert_eq!(table.series(1).len(), 2);
+    let kv_at_1bar = table.series(0)[0][1];
+    assert!((kv_at_1bar - 10.0).abs() < 1e-9);
+}
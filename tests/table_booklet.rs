@@ -0,0 +1,100 @@
+use steam_engineering_toolbox::config::UnitSystem;
+use steam_engineering_toolbox::number_format::NumberFormat;
+use steam_engineering_toolbox::steam::table_booklet::{
+    generate, BookletError, BookletOptions, PRESSURES_PER_PAGE, ROWS_PER_PAGE,
+};
+
+#[test]
+fn standard_si_booklet_covers_triple_to_near_critical() {
+    let options = BookletOptions::standard(UnitSystem::SIBar, "Site steam tables");
+    assert_eq!(options.saturation_temperatures.first(), Some(&0.01));
+    assert_eq!(options.saturation_temperatures.last(), Some(&370.0));
+    assert_eq!(options.saturation_pressures.first(), Some(&0.01));
+    assert_eq!(options.saturation_pressures.last(), Some(&200.0));
+    assert!(options.saturation_pressures.contains(&0.3));
+    assert!(options.superheat_pressures.contains(&10.0));
+
+    let booklet = generate(&options).unwrap();
+    let row = booklet
+        .saturation_by_temperature
+        .iter()
+        .find(|r| r.temperature_c == 100.0)
+        .unwrap();
+    assert!((row.pressure_bar_abs - 1.014_18).abs() < 1e-4);
+    assert!(((row.hg_j_per_kg - row.hf_j_per_kg) / 1000.0 - 2256.5).abs() < 0.5);
+
+    // 10 bar(a) 과열표: 포화온도 이하 행은 비고, 250 °C는 h ≈ 2944 kJ/kg
+    let column = booklet
+        .superheat
+        .iter()
+        .find(|c| c.pressure_bar_abs == 10.0)
+        .unwrap();
+    assert!((column.saturation.temperature_c - 179.88).abs() < 0.02);
+    let at = |t: f64| {
+        let i = options
+            .superheat_temperatures
+            .iter()
+            .position(|&x| x == t)
+            .unwrap();
+        column.points[i]
+    };
+    assert!(at(175.0).is_none());
+    assert!((at(250.0).unwrap().h_j_per_kg / 1000.0 - 2943.9).abs() < 1.0);
+}
+
+#[test]
+fn imperial_booklet_uses_psia_and_fahrenheit_rows() {
+    let options = BookletOptions::standard(UnitSystem::Imperial, "Steam tables");
+    assert_eq!(options.units.pressure.label, "psia");
+    assert_eq!(options.superheat_temperatures.first(), Some(&200.0));
+    let booklet = generate(&options).unwrap();
+    let units = &options.units;
+
+    let row = booklet
+        .saturation_by_temperature
+        .iter()
+        .find(|r| (units.temperature.from_base(r.temperature_c) - 210.0).abs() < 1e-9)
+        .unwrap();
+    assert!((units.pressure.from_base(row.pressure_bar_abs) - 14.13).abs() < 0.02);
+
+    // 100 psia, 500 °F: v ≈ 5.59 ft³/lb, h ≈ 1279 Btu/lb
+    let column = booklet
+        .superheat
+        .iter()
+        .find(|c| (units.pressure.from_base(c.pressure_bar_abs) - 100.0).abs() < 1e-9)
+        .unwrap();
+    let i = options
+        .superheat_temperatures
+        .iter()
+        .position(|&t| t == 500.0)
+        .unwrap();
+    let point = column.points[i].unwrap();
+    assert!((units.specific_volume.from_base(point.v_m3_per_kg) - 5.59).abs() < 0.02);
+    assert!((units.enthalpy.from_base(point.h_j_per_kg) - 1279.1).abs() < 1.0);
+}
+
+#[test]
+fn html_has_one_section_per_page_and_rejects_rows_past_critical() {
+    let mut options = BookletOptions::standard(UnitSystem::SIBar, "Plant <A>");
+    let booklet = generate(&options).unwrap();
+    let titles = booklet.page_titles();
+    let expected = booklet
+        .saturation_by_temperature
+        .len()
+        .div_ceil(ROWS_PER_PAGE)
+        + booklet.saturation_by_pressure.len().div_ceil(ROWS_PER_PAGE)
+        + booklet.superheat.len().div_ceil(PRESSURES_PER_PAGE);
+    assert_eq!(titles.len(), expected);
+
+    let html = booklet.to_html(&NumberFormat::default());
+    assert_eq!(html.matches("<section class=\"page\">").count(), expected);
+    assert!(html.contains("page-break-after: always"));
+    assert!(html.contains("Plant &lt;A&gt;"));
+    assert!(html.contains(&format!("{expected} / {expected}")));
+
+    options.saturation_pressures.push(250.0);
+    assert!(matches!(
+        generate(&options),
+        Err(BookletError::OutOfRange { value, .. }) if value == 250.0
+    ));
+}
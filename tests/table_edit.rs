@@ -0,0 +1,51 @@
+use steam_engineering_toolbox::table_edit::{parse_table, table_to_tsv, TablePasteError};
+
+#[test]
+fn excel_paste_skips_header_and_reads_decimal_comma() {
+    let text = "Stroke %\tCv\r\n0\t0\r\n25%\t12,5\r\n\r\n50\t\"40,2\"\r\n100\t95\t(spare)\r\n";
+    let table = parse_table(text, 2).unwrap();
+    assert_eq!(table[0], vec![0.0, 25.0, 50.0, 100.0]);
+    assert_eq!(table[1], vec![0.0, 12.5, 40.2, 95.0]);
+}
+
+#[test]
+fn csv_and_whitespace_rows_use_decimal_point() {
+    let csv = parse_table("stroke,cv\n10,1.5\n90,30.25", 2).unwrap();
+    assert_eq!(csv, vec![vec![10.0, 90.0], vec![1.5, 30.25]]);
+    let semicolon = parse_table("10;1,5\n90;30,25", 2).unwrap();
+    assert_eq!(semicolon, csv);
+    let spaced = parse_table("  10   1.5\n90 30.25  ", 2).unwrap();
+    assert_eq!(spaced, csv);
+}
+
+#[test]
+fn bad_rows_are_reported_with_line_numbers_and_tsv_round_trips() {
+    assert_eq!(
+        parse_table("Stroke\tCv\n\n", 2),
+        Err(TablePasteError::Empty)
+    );
+    assert_eq!(
+        parse_table("0\t0\n50\n", 2),
+        Err(TablePasteError::MissingColumns {
+            line: 2,
+            expected: 2,
+            found: 1
+        })
+    );
+    assert_eq!(
+        parse_table("0\t0\n50\tn/a\n", 2),
+        Err(TablePasteError::NotNumber {
+            line: 2,
+            cell: "n/a".to_string()
+        })
+    );
+
+    let strokes = [0.0, 50.0, 100.0];
+    let cvs = [0.0, 22.5, 80.0];
+    let tsv = table_to_tsv(&["Stroke %", "Cv"], &[&strokes, &cvs]);
+    assert_eq!(tsv, "Stroke %\tCv\n0\t0\n50\t22.5\n100\t80");
+    assert_eq!(
+        parse_table(&tsv, 2).unwrap(),
+        vec![strokes.to_vec(), cvs.to_vec()]
+    );
+}
@@ -0,0 +1,91 @@
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::tank_heating::{
+    compute_tank_heating, HeatingMethod, TankHeatingError, TankHeatingInput,
+};
+
+fn water_tank(method: HeatingMethod, heat_up_time_h: Option<f64>, wall_u: f64) -> TankHeatingInput {
+    TankHeatingInput {
+        liquid_mass_kg: 10_000.0,
+        specific_heat_kj_per_kgk: 4.19,
+        initial_temp_c: 15.0,
+        target_temp_c: 80.0,
+        heat_up_time_h,
+        steam_pressure_bar_abs: 5.0,
+        method,
+        wall_area_m2: 40.0,
+        wall_loss_u_w_m2k: wall_u,
+        ambient_temp_c: 15.0,
+    }
+}
+
+#[test]
+fn coil_area_roundtrip_and_lossless_closed_form() {
+    let sized = compute_tank_heating(water_tank(
+        HeatingMethod::Coil {
+            u_w_m2k: 500.0,
+            area_m2: None,
+        },
+        Some(2.0),
+        1.0,
+    ))
+    .unwrap();
+    let area = sized.coil_area_m2.unwrap();
+    assert!((sized.heat_up_time_h - 2.0).abs() < 1e-6);
+    assert!(sized.mean_heat_loss_kw > 0.0 && sized.holding_loss_kw > sized.mean_heat_loss_kw);
+
+    let check = compute_tank_heating(water_tank(
+        HeatingMethod::Coil {
+            u_w_m2k: 500.0,
+            area_m2: Some(area),
+        },
+        None,
+        1.0,
+    ))
+    .unwrap();
+    assert!((check.heat_up_time_h - 2.0).abs() < 1e-6);
+
+    // 손실이 없으면 t = M·cp/UA · ln((Ts − T0)/(Ts − T1))
+    let lossless = compute_tank_heating(water_tank(
+        HeatingMethod::Coil {
+            u_w_m2k: 500.0,
+            area_m2: Some(area),
+        },
+        None,
+        0.0,
+    ))
+    .unwrap();
+    let ts = lossless.steam_saturation_temp_c;
+    let ua = lossless.coil_ua_kw_per_k.unwrap();
+    let expected_h = 10_000.0 * 4.19 / ua * ((ts - 15.0) / (ts - 80.0)).ln() / 3600.0;
+    assert!((lossless.heat_up_time_h - expected_h).abs() < 1e-9);
+}
+
+#[test]
+fn direct_injection_mass_balance_and_unreachable_target() {
+    let res = compute_tank_heating(water_tank(
+        HeatingMethod::DirectInjection {
+            steam_flow_kg_per_h: Some(500.0),
+        },
+        None,
+        0.0,
+    ))
+    .unwrap();
+    let (_, hg) = if97::saturation_enthalpies(5.0).unwrap();
+    let hg = hg / 1000.0;
+    let m1 = 10_000.0 * (hg - 4.19 * 15.0) / (hg - 4.19 * 80.0);
+    assert!(
+        (res.final_liquid_mass_kg - m1).abs() < 1e-3,
+        "{}",
+        res.final_liquid_mass_kg
+    );
+    assert!((res.heat_up_time_h - (m1 - 10_000.0) / 500.0).abs() < 1e-6);
+
+    let weak = compute_tank_heating(water_tank(
+        HeatingMethod::DirectInjection {
+            steam_flow_kg_per_h: Some(1.0),
+        },
+        None,
+        10.0,
+    ));
+    assert!(matches!(weak, Err(TankHeatingError::Unreachable)));
+}
@@ -0,0 +1,86 @@
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::totalizer::{
+    parse_timestamp_s, parse_totalizer_csv, totalize, TotalizerError, TotalizerInput,
+    TotalizerWarning,
+};
+
+#[test]
+fn timestamps_parse_as_seconds() {
+    assert_eq!(parse_timestamp_s("1970-01-01 00:00"), Some(0.0));
+    assert_eq!(
+        parse_timestamp_s("1970-01-02T01:00:30Z"),
+        Some(86_400.0 + 3630.0)
+    );
+    // 윤년 2월 29일 다음 날
+    let feb29 = parse_timestamp_s("2024/02/29 12:00").unwrap();
+    let mar01 = parse_timestamp_s("2024-03-01 12:00").unwrap();
+    assert_eq!(mar01 - feb29, 86_400.0);
+    assert_eq!(parse_timestamp_s("3600"), Some(3600.0));
+    assert_eq!(parse_timestamp_s("2024-13-01 00:00"), None);
+    assert_eq!(parse_timestamp_s("yesterday"), None);
+}
+
+#[test]
+fn constant_flow_integrates_to_mass_and_energy() {
+    // 10 bar g 포화, 5 t/h로 2시간 (30분 간격), 급수 105 °C 기준
+    let text = "timestamp,flow_kg_h,pressure_bar_g\n\
+                2024-05-01 00:00,5000,10\n\
+                2024-05-01 00:30,5000,10\n\
+                2024-05-01 01:00,5000,10\n\
+                2024-05-01 01:30,5000,10\n\
+                2024-05-01 02:00,5000,10\n";
+    let rows = parse_totalizer_csv(text, 1.0).unwrap();
+    let r = totalize(&TotalizerInput {
+        rows,
+        reference_temp_c: Some(105.0),
+        mcr_kg_per_h: Some(6000.0),
+    })
+    .unwrap();
+    assert!((r.duration_h - 2.0).abs() < 1e-9);
+    assert!((r.total_mass_t - 10.0).abs() < 1e-9);
+
+    let (_, hg) = if97::saturation_enthalpies(11.0).unwrap();
+    let p_ref = if97::saturation_pressure_bar_abs_from_temp_c(105.0).unwrap();
+    let (hf_ref, _) = if97::saturation_enthalpies(p_ref).unwrap();
+    let expected_mwh = 10_000.0 * (hg - hf_ref) / 1000.0 / 3.6e6;
+    assert!((r.total_energy_mwh - expected_mwh).abs() < 1e-6 * expected_mwh);
+    assert!((r.average_enthalpy_kj_per_kg - hg / 1000.0).abs() < 1e-6);
+    assert!((r.pressure_bar_abs.mean - 11.0).abs() < 1e-12);
+
+    let mcr = r.mcr.unwrap();
+    assert!((mcr.average_load_frac - 5.0 / 6.0).abs() < 1e-12);
+    assert_eq!(mcr.hours_above_mcr, 0.0);
+    assert!(r.warnings.is_empty(), "{:?}", r.warnings);
+}
+
+#[test]
+fn ramp_stats_and_bad_rows() {
+    let text = "0,1000,10,250\n3600,3000,10,250\n7200,10000,10,250\n";
+    let r = totalize(&TotalizerInput {
+        rows: parse_totalizer_csv(text, 1.0).unwrap(),
+        reference_temp_c: None,
+        mcr_kg_per_h: Some(6000.0),
+    })
+    .unwrap();
+    // 사다리꼴: (1000+3000)/2 + (3000+10000)/2 = 8500 kg, 두 번째 구간만 MCR 초과
+    assert!((r.total_mass_t - 8.5).abs() < 1e-9);
+    assert_eq!(r.flow_kg_per_h.min, 1000.0);
+    assert_eq!(r.flow_kg_per_h.max, 10000.0);
+    assert!((r.flow_kg_per_h.mean - 4250.0).abs() < 1e-9);
+    assert!((r.temperature_c.mean - 250.0).abs() < 1e-12);
+    let mcr = r.mcr.unwrap();
+    assert!((mcr.hours_above_mcr - 1.0).abs() < 1e-12);
+    assert!(r
+        .warnings
+        .iter()
+        .any(|w| matches!(w, TotalizerWarning::AboveMcr { .. })));
+
+    let backwards = "0,1000,10\n3600,1000,10\n1800,1000,10\n";
+    let err = totalize(&TotalizerInput {
+        rows: parse_totalizer_csv(backwards, 1.0).unwrap(),
+        reference_temp_c: None,
+        mcr_kg_per_h: None,
+    })
+    .unwrap_err();
+    assert!(matches!(err, TotalizerError::Csv { line: 3, .. }));
+}
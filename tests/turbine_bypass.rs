@@ -0,0 +1,119 @@
+use steam_engineering_toolbox::steam::if97;
+use steam_engineering_toolbox::steam::turbine_bypass::{
+    compute_bypass, required_spray_flow, spray_for_target_temperature, BypassInput, BypassStage,
+    BypassWarning, SprayWater, SteamInlet, TurbineBypassError, SPRAY_TOLERANCE_K,
+};
+
+fn hp_bypass(spray_flow_kg_per_h: f64) -> BypassInput {
+    BypassInput {
+        upstream_pressure_bar_abs: 100.0,
+        upstream_temp_c: 500.0,
+        steam_enthalpy_j_per_kg: None,
+        downstream_pressure_bar_abs: 10.0,
+        kv: 10.0,
+        spray_flow_kg_per_h,
+        spray_temp_c: 40.0,
+        spray_enthalpy_j_per_kg: None,
+    }
+}
+
+#[test]
+fn letdown_is_isenthalpic_and_inverse_spray_hits_target_temperature() {
+    let dry = compute_bypass(&hp_bypass(0.0)).unwrap();
+    assert!(dry.choked);
+    let [up, letdown, mixed] = [dry.profile[0], dry.profile[1], dry.profile[2]];
+    assert_eq!(letdown.stage, BypassStage::AfterLetdown);
+    assert_eq!(up.enthalpy_j_per_kg, letdown.enthalpy_j_per_kg);
+    // 등엔탈피 감압으로 온도는 내려가지만 과열은 커진다 (100 → 10 bar, 500 °C → 약 440 °C).
+    assert!(letdown.temp_c < up.temp_c - 40.0 && letdown.temp_c > 420.0);
+    assert!(letdown.superheat_k > up.superheat_k);
+    assert_eq!(mixed.enthalpy_j_per_kg, letdown.enthalpy_j_per_kg);
+    assert!(
+        (dry.total_heat_kw - dry.steam_flow_kg_per_h * up.enthalpy_j_per_kg / 3.6e6).abs() < 1e-9
+    );
+    assert!(dry.spray_heat_absorbed_kw.abs() < 1e-9);
+
+    let req = spray_for_target_temperature(&hp_bypass(0.0), 250.0).unwrap();
+    assert!((req.steam_flow_kg_per_h - dry.steam_flow_kg_per_h).abs() < 1e-9);
+    assert!(
+        req.spray_ratio > 0.1 && req.spray_ratio < 0.3,
+        "{}",
+        req.spray_ratio
+    );
+    let sprayed = compute_bypass(&hp_bypass(req.spray_flow_kg_per_h)).unwrap();
+    assert!((sprayed.profile[2].temp_c - 250.0).abs() < 0.01);
+    assert!(!sprayed
+        .warnings
+        .iter()
+        .any(|w| matches!(w, BypassWarning::WetAfterSpray { .. })));
+}
+
+#[test]
+fn oversprayed_mix_is_flagged_wet_and_bad_pressures_are_rejected() {
+    let dry = compute_bypass(&hp_bypass(0.0)).unwrap();
+    let wet = compute_bypass(&hp_bypass(dry.steam_flow_kg_per_h)).unwrap();
+    let mixed = wet.profile[2];
+    assert!(mixed.quality.is_some());
+    assert_eq!(mixed.superheat_k, 0.0);
+    assert!(wet
+        .warnings
+        .iter()
+        .any(|w| matches!(w, BypassWarning::WetAfterSpray { .. })));
+
+    let mut bad = hp_bypass(0.0);
+    bad.downstream_pressure_bar_abs = 120.0;
+    assert!(matches!(
+        compute_bypass(&bad),
+        Err(TurbineBypassError::InvalidInput(_))
+    ));
+    // 하류 포화온도(약 180 °C) 이하 목표는 역산하지 않는다.
+    assert!(spray_for_target_temperature(&hp_bypass(0.0), 170.0).is_err());
+}
+
+#[test]
+fn spray_solver_reports_convergence_and_closes_energy_balance() {
+    // 감압 없는 감온기: 40 bar(a), 450 °C 증기 10 t/h를 350 °C로.
+    let steam_in = SteamInlet {
+        flow_kg_per_h: 10_000.0,
+        pressure_bar_abs: 40.0,
+        temp_c: 450.0,
+        enthalpy_j_per_kg: None,
+    };
+    let water = SprayWater {
+        temp_c: 105.0,
+        enthalpy_j_per_kg: None,
+    };
+    let req = required_spray_flow(&steam_in, 40.0, 350.0, &water).unwrap();
+    assert!(req.converged);
+    assert!(
+        req.iterations > 0 && req.iterations < 20,
+        "{}",
+        req.iterations
+    );
+    assert!(req.residual_k <= SPRAY_TOLERANCE_K);
+    assert!((req.mixed_temp_c - 350.0).abs() <= SPRAY_TOLERANCE_K);
+    // 약 0.07 kg/kg 스프레이
+    assert!(
+        req.spray_ratio > 0.05 && req.spray_ratio < 0.09,
+        "{}",
+        req.spray_ratio
+    );
+
+    // 물 엔탈피를 직접 주면 해가 엔탈피 수지와 맞는다 (0.01 K ≈ 수십 J/kg).
+    let h_w = 450.0e3;
+    let fixed = SprayWater {
+        temp_c: 105.0,
+        enthalpy_j_per_kg: Some(h_w),
+    };
+    let req = required_spray_flow(&steam_in, 40.0, 350.0, &fixed).unwrap();
+    let h_s = if97::region_props(40.0, 450.0).unwrap().0;
+    let h_mix = (steam_in.flow_kg_per_h * h_s + req.spray_flow_kg_per_h * h_w)
+        / (steam_in.flow_kg_per_h + req.spray_flow_kg_per_h);
+    assert!((h_mix - req.target_enthalpy_j_per_kg).abs() < 50.0);
+
+    let no_flow = SteamInlet {
+        flow_kg_per_h: 0.0,
+        ..steam_in
+    };
+    assert!(required_spray_flow(&no_flow, 40.0, 350.0, &water).is_err());
+}
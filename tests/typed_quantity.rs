@@ -0,0 +1,75 @@
+//! 강타입 물리량 래퍼와 typed API 회귀 테스트.
+use steam_engineering_toolbox::steam::{self, steam_valves};
+use steam_engineering_toolbox::typed_quantity::{
+    Atmosphere, Density, MassFlow, MassFlowUnit, Pressure, PressureDifference, PressureReference,
+    PressureValue, Temperature, VolumeFlow,
+};
+use steam_engineering_toolbox::units::PressureUnit;
+
+#[test]
+fn pressure_gauge_and_absolute_roundtrip() {
+    let p = Pressure::from_bar_gauge(0.0);
+    assert!((p.bar_abs() - 1.01325).abs() < 1e-9);
+    assert!(p.bar_gauge().abs() < 1e-9);
+    let dp = Pressure::from_bar_abs(6.0).minus(Pressure::from_bar_abs(4.5));
+    assert!((dp.bar() - 1.5).abs() < 1e-9);
+}
+
+#[test]
+fn pressure_difference_units_ignore_gauge_reference() {
+    // 차압은 배율만 바뀐다: 1 bar = 100 kPa = 14.5 psi, bar(g)/bar(a) 코드도 같은 배율
+    let dp = PressureDifference::from_unit(1.0, "bar").unwrap();
+    assert!((dp.in_unit("kPa").unwrap() - 100.0).abs() < 1e-9);
+    assert!((dp.in_unit("psi").unwrap() - 14.503_774).abs() < 1e-5);
+    for code in ["bara", "barg", "bar(g)"] {
+        assert_eq!(PressureDifference::from_unit(1.0, code), Some(dp));
+    }
+    let water = PressureDifference::from_unit(1000.0, "mmH2O").unwrap();
+    assert!((water.kpa() - 9.806_65).abs() < 1e-9);
+    assert!(PressureDifference::from_unit(1.0, "degC").is_none());
+}
+
+#[test]
+fn pressure_value_carries_reference_and_site_atmosphere() {
+    let atm = Atmosphere::STANDARD;
+    let vacuum = PressureValue::new(700.0, PressureUnit::MmHg, PressureReference::Vacuum);
+    let abs = vacuum.convert(PressureUnit::MmHg, PressureReference::Absolute, atm);
+    assert!((abs.value - 60.0).abs() < 0.01);
+    let gauge = vacuum.convert(PressureUnit::MmHg, PressureReference::Gauge, atm);
+    assert!((gauge.value + 700.0).abs() < 1e-6);
+
+    // 해발 1000 m: 대기압 ≈ 0.8988 bar
+    let site = Atmosphere::from_elevation_m(1000.0);
+    assert!((site.bar_abs() - 0.8988).abs() < 1e-3);
+    let p = PressureValue::new(10.0, PressureUnit::Bar, PressureReference::Gauge).to_pressure(site);
+    assert!((p.bar_abs() - (10.0 + site.bar_abs())).abs() < 1e-9);
+    assert!((p.bar_gauge_at(site) - 10.0).abs() < 1e-9);
+    assert!((Pressure::from_bar_gauge_at(0.0, site).bar_abs() - site.bar_abs()).abs() < 1e-12);
+}
+
+#[test]
+fn mass_flow_units() {
+    let m = MassFlow::new(3.6, MassFlowUnit::TonnePerHour);
+    assert!((m.kg_per_s() - 1.0).abs() < 1e-9);
+    assert!((m.to(MassFlowUnit::KgPerHour) - 3600.0).abs() < 1e-6);
+}
+
+#[test]
+fn typed_saturation_matches_atmospheric_boiling() {
+    let state = steam::saturation_at(Pressure::from_bar_gauge(0.0)).expect("saturation");
+    assert!((state.saturation_temperature_c - 100.0).abs() < 0.5);
+    let by_t = steam::saturation_at_temperature(Temperature::from_celsius(100.0)).expect("by T");
+    assert!((by_t.pressure_bar - 1.01325).abs() < 0.02);
+}
+
+#[test]
+fn typed_valve_api_matches_untyped() {
+    let typed = steam_valves::required_kv_typed(
+        VolumeFlow::from_m3_per_h(10.0),
+        PressureDifference::from_bar(1.0),
+        Density::from_kg_per_m3(1000.0),
+    )
+    .unwrap();
+    let plain = steam_valves::required_kv(10.0, 1.0, 1000.0).unwrap();
+    assert!((typed - plain).abs() < 1e-12);
+}
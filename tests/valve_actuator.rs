@@ -0,0 +1,80 @@
+use steam_engineering_toolbox::steam::valve_actuator::{
+    breakout_torque, required_thrust, select_actuator, ActuatorKind, ActuatorSelectionInput,
+    LeakageClass, PackingType, ThrustInput, TorqueInput,
+};
+
+fn globe_50mm(balance_seal_diameter_m: Option<f64>) -> ThrustInput {
+    ThrustInput {
+        shutoff_dp_bar: 10.0,
+        port_diameter_m: 0.05,
+        balance_seal_diameter_m,
+        stem_diameter_m: 0.0127,
+        leakage_class: LeakageClass::IV,
+        packing: PackingType::Ptfe,
+    }
+}
+
+#[test]
+fn unbalanced_globe_thrust_and_diaphragm_spring_selection() {
+    let t = required_thrust(&globe_50mm(None)).unwrap();
+    // 10 bar × π/4 × (50 mm)² ≈ 1.96 kN, Class IV 80 lbf/in × π × 50 mm ≈ 2.2 kN
+    assert!((t.unbalance_force_n - 1_963.5).abs() < 0.1);
+    assert!((t.seat_load_n - 2_200.7).abs() < 1.0);
+    assert!((t.packing_friction_n - 222.4).abs() < 0.5);
+
+    let input = |kind| ActuatorSelectionInput {
+        kind,
+        required_thrust_n: t.required_thrust_n,
+        supply_pressure_bar_g: 4.0,
+        margin: 1.25,
+    };
+    // 공기 상실 시 닫힘은 스프링 하한이 시트를 눌러야 하므로 350 cm², 2.1–3.3 bar가 된다.
+    let fc = select_actuator(&input(ActuatorKind::DiaphragmFailClose)).unwrap();
+    assert!((fc.effective_area_m2 - 0.035).abs() < 1e-12);
+    assert_eq!(fc.spring_range_bar, Some((2.1, 3.3)));
+    assert!(fc.margin >= 1.25);
+    // 공기 상실 시 열림은 공급 압력 − 스프링 상한으로 닫으므로 약한 스프링이 유리하다.
+    let fo = select_actuator(&input(ActuatorKind::DiaphragmFailOpen)).unwrap();
+    assert_eq!(fo.spring_range_bar, Some((0.2, 1.0)));
+    assert!((fo.effective_area_m2 - 0.024).abs() < 1e-12);
+    assert!((fo.available_thrust_n - 7_200.0).abs() < 1e-6);
+}
+
+#[test]
+fn balanced_plug_fits_small_piston_and_rotary_margin_is_checked() {
+    let t = required_thrust(&globe_50mm(Some(0.048))).unwrap();
+    assert!(
+        t.unbalance_force_n
+            < 0.1
+                * required_thrust(&globe_50mm(None))
+                    .unwrap()
+                    .unbalance_force_n
+    );
+    let piston = select_actuator(&ActuatorSelectionInput {
+        kind: ActuatorKind::Piston,
+        required_thrust_n: t.required_thrust_n,
+        supply_pressure_bar_g: 6.0,
+        margin: 1.25,
+    })
+    .unwrap();
+    assert_eq!(piston.piston_bore_mm, Some(100.0));
+    assert_eq!(piston.spring_range_bar, None);
+
+    let torque = breakout_torque(&TorqueInput {
+        shutoff_dp_bar: 10.0,
+        disc_diameter_m: 0.2,
+        shaft_diameter_m: 0.03,
+        bearing_friction: 0.15,
+        seat_torque_nm: 100.0,
+        packing: PackingType::Graphite,
+        actuator_torque_nm: 200.0,
+    })
+    .unwrap();
+    assert!((torque.bearing_torque_nm - 70.7).abs() < 0.1);
+    assert!(
+        (torque.breakout_torque_nm - 196.7).abs() < 0.2,
+        "{}",
+        torque.breakout_torque_nm
+    );
+    assert_eq!(torque.warnings.len(), 1);
+}
@@ -0,0 +1,51 @@
+use steam_engineering_toolbox::steam::valve_authority::{
+    analyze_authority, installed_flow, valve_authority, AuthorityInput, InherentCharacteristic,
+};
+
+fn circuit(
+    characteristic: InherentCharacteristic,
+    valve_dp: f64,
+    circuit_dp: f64,
+) -> AuthorityInput {
+    AuthorityInput {
+        characteristic,
+        rangeability: 50.0,
+        valve_dp_bar: valve_dp,
+        circuit_dp_bar: circuit_dp,
+        steps: 10,
+    }
+}
+
+#[test]
+fn linear_valve_distorts_toward_quick_opening_as_authority_drops() {
+    assert!((valve_authority(1.0, 3.0) - 0.25).abs() < 1e-12);
+    // N = 0.5, f = 0.5 → q = 0.5 / √(0.5 + 0.5·0.25)
+    assert!((installed_flow(0.5, 0.5) - 0.632_456).abs() < 1e-6);
+
+    let stiff = analyze_authority(&circuit(InherentCharacteristic::Linear, 1.0, 0.0)).unwrap();
+    assert_eq!(stiff.authority, 1.0);
+    assert!((stiff.points[5].installed_flow - 0.5).abs() < 1e-12);
+    assert!((stiff.gain_ratio - 1.0).abs() < 1e-9);
+    assert!(stiff.warnings.is_empty());
+
+    let weak = analyze_authority(&circuit(InherentCharacteristic::Linear, 1.0, 9.0)).unwrap();
+    assert!(weak.poor_authority);
+    assert!(weak.points[2].installed_flow > 2.0 * weak.points[2].inherent_flow);
+    assert_eq!(weak.points.last().unwrap().installed_flow, 1.0);
+    assert_eq!(weak.warnings.len(), 2);
+}
+
+#[test]
+fn equal_percentage_is_linearised_by_moderate_authority() {
+    let ideal =
+        analyze_authority(&circuit(InherentCharacteristic::EqualPercentage, 1.0, 0.0)).unwrap();
+    let installed =
+        analyze_authority(&circuit(InherentCharacteristic::EqualPercentage, 1.0, 2.0)).unwrap();
+    assert!(installed.gain_ratio < ideal.gain_ratio);
+    // 행정 50%에서 고유 유량 R^(−0.5) ≈ 0.141이 설치 후 커진다.
+    assert!((ideal.points[5].inherent_flow - 50f64.powf(-0.5)).abs() < 1e-12);
+    assert!(installed.points[5].installed_flow > ideal.points[5].installed_flow);
+    assert!(!installed.poor_authority);
+
+    assert!(analyze_authority(&circuit(InherentCharacteristic::Linear, 0.0, 1.0)).is_err());
+}
@@ -0,0 +1,69 @@
+use steam_engineering_toolbox::steam::valve_authority::InherentCharacteristic;
+use steam_engineering_toolbox::valve_db::{ValveDb, ValveDbError, ValveRecord};
+
+const SAMPLE: &str = r#"
+[[valve]]
+tag = "PCV-101"
+description = "HP bypass"
+rated_cv = 120
+characteristic = "equal_percentage"
+stroke_cv = [[100, 120], [0, 0], [50, 24]]
+fl = 0.9
+xt = 0.72
+
+[[valve]]
+tag = "TCV-7"
+rated_cv = 8.5
+"#;
+
+#[test]
+fn toml_file_round_trips_with_integer_cells() {
+    let db = ValveDb::from_toml_str(SAMPLE).unwrap();
+    let pcv = db.get("pcv-101").unwrap();
+    assert_eq!(pcv.characteristic, Some(InherentCharacteristic::EqualPercentage));
+    assert_eq!(pcv.stroke_cv_points(), vec![(0.0, 0.0), (50.0, 24.0), (100.0, 120.0)]);
+    assert!((pcv.rated_kv() - 103.797).abs() < 1e-3);
+    let again = ValveDb::from_toml_str(&db.to_toml_string().unwrap()).unwrap();
+    assert_eq!(again, db);
+}
+
+#[test]
+fn upsert_replaces_same_tag_and_remove_deletes() {
+    let mut db = ValveDb::default();
+    assert!(!db.upsert(ValveRecord::new("TCV-7", 8.5)).unwrap());
+    assert!(!db.upsert(ValveRecord::new(" FCV-2 ", 30.0)).unwrap());
+    assert!(db.upsert(ValveRecord::new("tcv-7", 10.0)).unwrap());
+    let tags: Vec<&str> = db.valves().iter().map(|r| r.tag.as_str()).collect();
+    assert_eq!(tags, ["FCV-2", "tcv-7"]);
+    assert_eq!(db.get("TCV-7").unwrap().rated_cv, 10.0);
+    assert_eq!(db.remove("fcv-2").unwrap().rated_cv, 30.0);
+    assert!(db.remove("FCV-2").is_none());
+    assert_eq!(db.valves().len(), 1);
+}
+
+#[test]
+fn invalid_records_are_rejected_and_missing_table_follows_characteristic() {
+    let mut db = ValveDb::default();
+    assert!(matches!(
+        db.upsert(ValveRecord::new("", 10.0)),
+        Err(ValveDbError::Invalid(_))
+    ));
+    let mut bad = ValveRecord::new("XV-1", 10.0);
+    bad.fl = Some(1.4);
+    assert!(matches!(db.upsert(bad), Err(ValveDbError::Invalid(_))));
+    assert!(matches!(
+        ValveDb::from_toml_str(&format!("{SAMPLE}\n[[valve]]\ntag = \"tcv-7\"\nrated_cv = 1\n")),
+        Err(ValveDbError::DuplicateTag(_))
+    ));
+
+    let mut eq = ValveRecord::new("FCV-3", 50.0);
+    eq.characteristic = Some(InherentCharacteristic::EqualPercentage);
+    eq.rangeability = Some(50.0);
+    let points = eq.stroke_cv_points();
+    assert_eq!(points.len(), 5);
+    assert_eq!(points[0], (0.0, 0.0));
+    assert!((points[2].1 - 50.0 / 50f64.sqrt()).abs() < 1e-9);
+    assert!((points[4].1 - 50.0).abs() < 1e-9);
+    let linear = ValveRecord::new("FCV-4", 40.0).stroke_cv_points();
+    assert!((linear[1].1 - 10.0).abs() < 1e-9);
+}
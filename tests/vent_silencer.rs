@@ -0,0 +1,69 @@
+use steam_engineering_toolbox::steam::critical_flow::SteamStagnation;
+use steam_engineering_toolbox::steam::vent_silencer::{
+    a_weighting_db, sound_pressure_at_distance_db, vent_silencer, VentInput, VentSilencerError,
+};
+
+fn blow(vent_pipe_id_m: f64, distance_m: f64) -> VentInput {
+    VentInput {
+        upstream_pressure_bar_abs: 41.0,
+        stagnation: SteamStagnation::Temperature(400.0),
+        valve_throat_diameter_m: 0.05,
+        discharge_coefficient: 0.9,
+        vent_pipe_id_m,
+        atmospheric_pressure_bar_abs: 1.01325,
+        distance_m,
+        noise_limit_dba: 85.0,
+    }
+}
+
+#[test]
+fn large_vent_pipe_exits_subsonic_at_atmospheric_pressure() {
+    let res = vent_silencer(&blow(0.30, 30.0)).unwrap();
+    assert!(res.valve_choked);
+    assert!((res.mass_flow_kg_per_h - 32_047.0).abs() < 50.0);
+    assert!(!res.exit_choked);
+    assert_eq!(res.exit_pressure_bar_abs, 1.01325);
+    assert!(res.exit_mach > 0.5 && res.exit_mach < 0.7);
+    assert_eq!(res.jet_mach, res.exit_mach);
+    assert_eq!(res.required_attenuation_db, 0.0);
+    assert!(res.warnings.is_empty(), "{:?}", res.warnings);
+}
+
+#[test]
+fn small_vent_pipe_chokes_and_needs_more_attenuation() {
+    let dn150 = vent_silencer(&blow(0.1541, 30.0)).unwrap();
+    assert!(dn150.exit_choked);
+    assert!((dn150.exit_mach - 1.0).abs() < 1e-9);
+    assert!(dn150.exit_pressure_bar_abs > 2.0 && dn150.exit_pressure_bar_abs < 2.3);
+    assert!(dn150.jet_mach > 1.0);
+    assert!(dn150.required_attenuation_db > 25.0 && dn150.required_attenuation_db < 40.0);
+
+    let dn50 = vent_silencer(&blow(0.0525, 30.0)).unwrap();
+    assert!(dn50.exit_pressure_bar_abs > dn150.exit_pressure_bar_abs);
+    assert!(dn50.sound_power_db > dn150.sound_power_db);
+    assert_eq!(dn50.warnings.len(), 2);
+
+    // 거리가 4배면 반구 확산으로 약 12 dB 줄어든다
+    let far = vent_silencer(&blow(0.1541, 120.0)).unwrap();
+    assert!((dn150.sound_pressure_dba - far.sound_pressure_dba - 12.04).abs() < 0.01);
+}
+
+#[test]
+fn weighting_spreading_and_invalid_input() {
+    assert!(a_weighting_db(1000.0).abs() < 0.01);
+    assert!((a_weighting_db(100.0) + 19.1).abs() < 0.1);
+    assert!((sound_pressure_at_distance_db(120.0, 1.0) - 112.02).abs() < 0.01);
+
+    let mut low = blow(0.1541, 30.0);
+    low.upstream_pressure_bar_abs = 1.0;
+    assert!(matches!(
+        vent_silencer(&low),
+        Err(VentSilencerError::InvalidInput(_))
+    ));
+    let mut bad_cd = blow(0.1541, 30.0);
+    bad_cd.discharge_coefficient = 1.5;
+    assert!(matches!(
+        vent_silencer(&bad_cd),
+        Err(VentSilencerError::Flow(_))
+    ));
+}
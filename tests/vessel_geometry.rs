@@ -0,0 +1,80 @@
+use std::f64::consts::PI;
+
+use steam_engineering_toolbox::vessel_geometry::{HeadType, Vessel, VesselOrientation};
+
+fn vessel(orientation: VesselOrientation, heads: HeadType) -> Vessel {
+    Vessel {
+        orientation,
+        inner_diameter_m: 2.0,
+        length_m: 4.0,
+        heads,
+    }
+}
+
+#[test]
+fn volumes_and_level_inverse() {
+    let vertical = vessel(VesselOrientation::Vertical, HeadType::Ellipsoidal);
+    assert_eq!(vertical.height_m(), 5.0);
+    let total = 4.0 * PI + 4.0 / 3.0 * PI * 0.5;
+    assert!((vertical.volume_m3() - total).abs() < 1e-12);
+    assert!((vertical.volume_at_level_m3(5.0) - total).abs() < 1e-12);
+    // 헤드가 위아래 같으므로 가운데 액위에서 용적의 절반
+    assert!((vertical.volume_at_level_m3(2.5) - total / 2.0).abs() < 1e-12);
+    assert!((vertical.volume_at_level_m3(0.5) - PI / 3.0).abs() < 1e-12);
+
+    let horizontal = vessel(VesselOrientation::Horizontal, HeadType::Hemispherical);
+    let total = 4.0 * PI + 4.0 / 3.0 * PI;
+    assert!((horizontal.volume_at_level_m3(2.0) - total).abs() < 1e-12);
+    assert!((horizontal.volume_at_level_m3(1.0) - total / 2.0).abs() < 1e-12);
+    let flat = vessel(VesselOrientation::Horizontal, HeadType::Flat);
+    assert!((flat.volume_at_level_m3(1.0) - 2.0 * PI).abs() < 1e-12);
+
+    for v in [vertical, horizontal, flat] {
+        for level in [0.3, 0.9, 1.7] {
+            let back = v.level_at_volume_m(v.volume_at_level_m3(level));
+            assert!((back - level).abs() < 1e-9, "{v:?} {level} {back}");
+        }
+        let curve = v.level_volume_curve(11);
+        assert_eq!(curve.len(), 11);
+        assert!(curve.windows(2).all(|w| w[1].volume_m3 > w[0].volume_m3));
+        assert_eq!(curve[10].level_m, v.height_m());
+    }
+}
+
+#[test]
+fn wetted_area_of_heads_and_shell() {
+    // 2:1 반타원 헤드 표면적 ≈ 1.084·D²
+    let ellipsoidal = vessel(VesselOrientation::Vertical, HeadType::Ellipsoidal);
+    assert!((ellipsoidal.head_area_m2() / 4.0 - 1.084).abs() < 0.001);
+    assert!(
+        (ellipsoidal.wetted_area_m2(5.0) - ellipsoidal.surface_area_m2()).abs() < 1e-6,
+        "{}",
+        ellipsoidal.wetted_area_m2(5.0)
+    );
+    assert!((ellipsoidal.wetted_area_m2(0.5) - ellipsoidal.head_area_m2()).abs() < 1e-6);
+
+    let hemi = vessel(VesselOrientation::Vertical, HeadType::Hemispherical);
+    // 구면 띠 면적 2πR·z
+    assert!((hemi.wetted_area_m2(0.4) - 2.0 * PI * 0.4).abs() < 1e-6);
+    assert!((hemi.wetted_area_m2(2.0) - (2.0 * PI + 2.0 * PI)).abs() < 1e-6);
+
+    let horizontal = vessel(VesselOrientation::Horizontal, HeadType::Hemispherical);
+    assert!((horizontal.wetted_area_m2(1.0) - horizontal.surface_area_m2() / 2.0).abs() < 1e-9);
+    let flat = vessel(VesselOrientation::Vertical, HeadType::Flat);
+    assert!((flat.wetted_area_m2(1.0) - (PI + 2.0 * PI)).abs() < 1e-9);
+    assert_eq!(flat.wetted_area_m2(0.0), 0.0);
+}
+
+#[test]
+fn invalid_dimensions_are_rejected() {
+    let mut v = vessel(VesselOrientation::Vertical, HeadType::Flat);
+    assert!(v.validate().is_ok());
+    v.length_m = 0.0;
+    assert!(v.validate().is_err());
+    // 반구 헤드 두 개만 있는 구형 용기는 동체 길이 0을 허용한다
+    v.heads = HeadType::Hemispherical;
+    assert!(v.validate().is_ok());
+    assert!((v.volume_m3() - 4.0 / 3.0 * PI).abs() < 1e-12);
+    v.inner_diameter_m = -1.0;
+    assert!(v.validate().is_err());
+}
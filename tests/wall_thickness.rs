@@ -0,0 +1,56 @@
+use steam_engineering_toolbox::plant_piping::wall_thickness::{
+    pressure_for_thickness_bar_g, required_wall_thickness, y_coefficient, WallThicknessError,
+    WallThicknessInput,
+};
+
+fn nps4_a106(pressure_bar_g: f64) -> WallThicknessInput {
+    WallThicknessInput {
+        design_pressure_bar_g: pressure_bar_g,
+        design_temp_c: 200.0,
+        outer_diameter_m: 0.1143,
+        material: "A106B".into(),
+        weld_joint_efficiency: 1.0,
+        weld_strength_reduction: 1.0,
+        corrosion_allowance_m: 0.001,
+        mill_tolerance_frac: 0.125,
+    }
+}
+
+#[test]
+fn b31_thickness_for_nps4_carbon_steel() {
+    let r = required_wall_thickness(&nps4_a106(40.0)).unwrap();
+    assert_eq!(r.allowable_stress_mpa, 124.0);
+    assert_eq!(r.y_coefficient, 0.4);
+    // t = 4 MPa × 114.3 mm / (2 × (124 + 0.4 × 4)) ≈ 1.82 mm
+    assert!((r.pressure_design_thickness_m - 1.820e-3).abs() < 1e-6);
+    assert!((r.minimum_required_m - 2.820e-3).abs() < 1e-6);
+    assert!((r.nominal_required_m - r.minimum_required_m / 0.875).abs() < 1e-12);
+    assert!(r.warnings.is_empty());
+
+    // 두께 식을 P에 대해 풀면 원래 설계 압력이 나온다.
+    let p = pressure_for_thickness_bar_g(r.pressure_design_thickness_m, 0.1143, 124.0, 1.0, 0.4);
+    assert!((p - 40.0).abs() < 1e-9);
+}
+
+#[test]
+fn y_coefficient_depends_on_material_family_and_bad_input_is_rejected() {
+    assert_eq!(y_coefficient(false, 510.0), 0.5);
+    assert_eq!(y_coefficient(false, 560.0), 0.7);
+    assert_eq!(y_coefficient(true, 560.0), 0.4);
+    assert!((y_coefficient(true, 607.0) - 0.6).abs() < 1e-12);
+
+    let mut input = nps4_a106(40.0);
+    input.material = "TP316".into();
+    input.design_temp_c = 593.0;
+    assert_eq!(required_wall_thickness(&input).unwrap().y_coefficient, 0.5);
+
+    input.material = "unobtainium".into();
+    assert!(matches!(
+        required_wall_thickness(&input),
+        Err(WallThicknessError::UnknownMaterial(_))
+    ));
+    assert!(matches!(
+        required_wall_thickness(&nps4_a106(0.0)),
+        Err(WallThicknessError::InvalidInput(_))
+    ));
+}
@@ -0,0 +1,29 @@
+#![cfg(feature = "wasm")]
+
+use steam_engineering_toolbox::units::{PressureUnit, TemperatureUnit};
+use steam_engineering_toolbox::wasm::{
+    parse_pressure_unit, parse_temperature_unit, UnitParseError,
+};
+
+#[test]
+fn known_units_parse_case_insensitively() {
+    assert_eq!(parse_pressure_unit("kPa"), Ok(PressureUnit::KiloPascal));
+    assert_eq!(parse_pressure_unit("bar(a)"), Ok(PressureUnit::BarA));
+    assert_eq!(parse_pressure_unit("mmHg"), Ok(PressureUnit::MmHg));
+    assert_eq!(parse_temperature_unit("C"), Ok(TemperatureUnit::Celsius));
+    assert_eq!(parse_temperature_unit("K"), Ok(TemperatureUnit::Kelvin));
+}
+
+#[test]
+fn unknown_units_are_rejected_instead_of_defaulting() {
+    // 예전에는 bar / °C로 바뀌어 조용히 계산됐다
+    let err = parse_pressure_unit("furlong").unwrap_err();
+    assert_eq!(err, UnitParseError::Pressure("furlong".into()));
+    assert_eq!(err.to_string(), "unknown pressure unit 'furlong'");
+    assert!(parse_pressure_unit("").is_err());
+    assert!(parse_pressure_unit("psig").is_err());
+    assert_eq!(
+        parse_temperature_unit("celsius").unwrap_err().to_string(),
+        "unknown temperature unit 'celsius'"
+    );
+}